@@ -0,0 +1,63 @@
+//! `sign-audit-record` command
+
+use crate::signer::can_sign_message;
+use crate::{Cli, CliResult};
+use clap::Args;
+use security_token_client::rotation::{RotationAuditRecord, SignedRotationAuditRecord};
+use solana_sdk::signature::Signature;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct SignAuditRecordArgs {
+    /// Unsigned audit record written by `rotate-verification-program`
+    #[arg(long)]
+    record: PathBuf,
+
+    /// Signature over the record message produced elsewhere, instead of signing with `--keypair`
+    #[arg(long, conflicts_with = "print_message")]
+    signature: Option<Signature>,
+
+    /// Print the message to sign and exit
+    #[arg(long)]
+    print_message: bool,
+
+    /// Write the signed audit record to this file instead of stdout
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+pub fn run(cli: &Cli, args: &SignAuditRecordArgs) -> CliResult {
+    let record: RotationAuditRecord =
+        serde_json::from_str(&std::fs::read_to_string(&args.record)?)?;
+    if args.print_message {
+        print!("{}", String::from_utf8_lossy(&record.message()));
+        return Ok(());
+    }
+
+    let signed = match args.signature {
+        Some(signature) => SignedRotationAuditRecord { record, signature },
+        None => {
+            let authority = cli.authority()?;
+            if !can_sign_message(&*authority) {
+                return Err("hardware wallets cannot sign audit records, \
+                    sign the printed message with the authority keypair and pass --signature"
+                    .into());
+            }
+            record.try_sign(&*authority)?
+        }
+    };
+    if !signed.verify() {
+        return Err(format!(
+            "signature does not verify for authority {}",
+            signed.record.authority
+        )
+        .into());
+    }
+
+    let json = serde_json::to_string_pretty(&signed)?;
+    match &args.out {
+        Some(path) => std::fs::write(path, json)?,
+        None => println!("{json}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,49 @@
+//! `export-cap-table` command
+
+use crate::CliResult;
+use clap::{Args, ValueEnum};
+use security_token_client::cap_table::fetch_cap_table;
+use solana_client::rpc_client::RpcClient;
+use solana_pubkey::Pubkey;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+#[derive(Args)]
+pub struct ExportCapTableArgs {
+    /// Security token mint
+    #[arg(long)]
+    mint: Pubkey,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "csv")]
+    format: Format,
+
+    /// Write the export to this file instead of stdout
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+pub fn run(rpc: &RpcClient, args: &ExportCapTableArgs) -> CliResult {
+    let table = fetch_cap_table(rpc, &args.mint)?;
+    eprintln!(
+        "Exported {} accounts of mint {} at slot {}",
+        table.rows.len(),
+        table.mint,
+        table.slot
+    );
+
+    let output = match args.format {
+        Format::Csv => table.to_csv(),
+        Format::Json => serde_json::to_string_pretty(&table)?,
+    };
+    match &args.out {
+        Some(path) => std::fs::write(path, output)?,
+        None => print!("{output}"),
+    }
+    Ok(())
+}
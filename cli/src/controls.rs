@@ -0,0 +1,136 @@
+//! `pause`, `resume`, `freeze` and `thaw` commands
+
+use crate::{CliResult, Context, TokenAccountArg};
+use clap::Args;
+use security_token_client::account::MaybeAccount;
+use security_token_client::accounts::fetch_maybe_freeze_expiry;
+use security_token_client::instructions::{
+    Freeze, FreezeInstructionArgs, Pause, Resume, Thaw, FREEZE_DISCRIMINATOR, PAUSE_DISCRIMINATOR,
+    RESUME_DISCRIMINATOR, THAW_DISCRIMINATOR,
+};
+use security_token_client::pda::{
+    find_freeze_authority_pda, find_freeze_expiry_pda, find_pause_authority_pda,
+    find_verification_config_pda, TOKEN_2022_PROGRAM_ID,
+};
+use solana_pubkey::Pubkey;
+
+#[derive(Args)]
+pub struct PauseArgs {
+    #[arg(long)]
+    mint: Pubkey,
+}
+
+pub fn pause(context: &Context, args: &PauseArgs) -> CliResult {
+    let pause = Pause {
+        mint: args.mint,
+        verification_config: find_verification_config_pda(&args.mint, PAUSE_DISCRIMINATOR).0,
+        instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+        pause_authority: find_pause_authority_pda(&args.mint).0,
+        mint_account: args.mint,
+        token_program: TOKEN_2022_PROGRAM_ID,
+    }
+    .instruction();
+
+    let signature = context.send(context.prepare_verified(pause, &args.mint)?, &[])?;
+    eprintln!("Paused {}", args.mint);
+    println!("{signature}");
+    Ok(())
+}
+
+pub fn resume(context: &Context, args: &PauseArgs) -> CliResult {
+    let resume = Resume {
+        mint: args.mint,
+        verification_config: find_verification_config_pda(&args.mint, RESUME_DISCRIMINATOR).0,
+        instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+        pause_authority: find_pause_authority_pda(&args.mint).0,
+        mint_account: args.mint,
+        token_program: TOKEN_2022_PROGRAM_ID,
+    }
+    .instruction();
+
+    let signature = context.send(context.prepare_verified(resume, &args.mint)?, &[])?;
+    eprintln!("Resumed {}", args.mint);
+    println!("{signature}");
+    Ok(())
+}
+
+#[derive(Args)]
+pub struct FreezeArgs {
+    #[arg(long)]
+    mint: Pubkey,
+
+    #[command(flatten)]
+    account: TokenAccountArg,
+
+    /// Unix timestamp after which anyone can thaw the account
+    #[arg(long)]
+    auto_thaw_at: Option<i64>,
+}
+
+pub fn freeze(context: &Context, args: &FreezeArgs) -> CliResult {
+    let token_account = args.account.resolve(&args.mint);
+    // The freeze expiry account is only created with an auto-thaw time
+    let expiry = args.auto_thaw_at.map(|_| {
+        (
+            context.fee_payer().pubkey(),
+            find_freeze_expiry_pda(&token_account).0,
+            solana_sdk::system_program::ID,
+        )
+    });
+    let freeze = Freeze {
+        mint: args.mint,
+        verification_config: find_verification_config_pda(&args.mint, FREEZE_DISCRIMINATOR).0,
+        instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+        freeze_authority: find_freeze_authority_pda(&args.mint).0,
+        mint_account: args.mint,
+        token_account,
+        token_program: TOKEN_2022_PROGRAM_ID,
+        payer: expiry.map(|(payer, _, _)| payer),
+        freeze_expiry_account: expiry.map(|(_, account, _)| account),
+        system_program: expiry.map(|(_, _, system_program)| system_program),
+    }
+    .instruction(FreezeInstructionArgs {
+        auto_thaw_at: args.auto_thaw_at,
+    });
+
+    let signature = context.send(context.prepare_verified(freeze, &args.mint)?, &[])?;
+    eprintln!("Froze {token_account}");
+    println!("{signature}");
+    Ok(())
+}
+
+#[derive(Args)]
+pub struct ThawArgs {
+    #[arg(long)]
+    mint: Pubkey,
+
+    #[command(flatten)]
+    account: TokenAccountArg,
+}
+
+pub fn thaw(context: &Context, args: &ThawArgs) -> CliResult {
+    let token_account = args.account.resolve(&args.mint);
+    // An auto-thaw freeze expiry is closed with the thaw, its rent goes back to its payer
+    let (expiry_address, _) = find_freeze_expiry_pda(&token_account);
+    let expiry = match fetch_maybe_freeze_expiry(&context.rpc, &expiry_address)? {
+        MaybeAccount::Exists(expiry) => Some((expiry_address, expiry.data.payer)),
+        MaybeAccount::NotFound(_) => None,
+    };
+    let thaw = Thaw {
+        mint: args.mint,
+        verification_config: find_verification_config_pda(&args.mint, THAW_DISCRIMINATOR).0,
+        instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+        freeze_authority: find_freeze_authority_pda(&args.mint).0,
+        mint_account: args.mint,
+        token_account,
+        token_program: TOKEN_2022_PROGRAM_ID,
+        freeze_expiry_account: expiry.map(|(account, _)| account),
+        rent_destination: expiry.map(|(_, payer)| payer),
+    }
+    .instruction();
+
+    let signature = context.send(context.prepare_verified(thaw, &args.mint)?, &[])?;
+    eprintln!("Thawed {token_account}");
+    println!("{signature}");
+    Ok(())
+}
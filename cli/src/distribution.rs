@@ -0,0 +1,209 @@
+//! `create-distribution` and `claim` commands
+
+use crate::mint::create_associated_token_account_idempotent;
+use crate::{CliResult, Context};
+use clap::{Args, ValueEnum};
+use security_token_client::account::ProgramAccount;
+use security_token_client::accounts::VerificationConfig;
+use security_token_client::batch_claim::{
+    plan_batch_claim, send_batch_claim, BatchClaim, ClaimEntry,
+};
+use security_token_client::claim_tracking::ClaimTracking;
+use security_token_client::instructions::{
+    CreateDistributionEscrow, CreateDistributionEscrowInstructionArgs,
+    CLAIM_DISTRIBUTION_DISCRIMINATOR,
+};
+use security_token_client::pda::{
+    find_associated_token_address, find_claim_bitmap_pda, find_distribution_escrow_authority_pda,
+    find_mint_authority_pda, find_verification_config_pda, ASSOCIATED_TOKEN_PROGRAM_ID,
+    TOKEN_2022_PROGRAM_ID,
+};
+use security_token_client::proof_file::DistributionProofFile;
+use security_token_client::types::CreateDistributionEscrowArgs;
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::signature::Signer;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Tracking {
+    /// A claim receipt account per claim
+    Receipt,
+    /// Claim bitmap pages created with the escrow
+    Bitmap,
+}
+
+impl From<Tracking> for ClaimTracking {
+    fn from(tracking: Tracking) -> Self {
+        match tracking {
+            Tracking::Receipt => ClaimTracking::Receipt,
+            Tracking::Bitmap => ClaimTracking::Bitmap,
+        }
+    }
+}
+
+fn read_proof_file(path: &Path) -> CliResult<DistributionProofFile> {
+    let proof_file: DistributionProofFile = serde_json::from_slice(&std::fs::read(path)?)?;
+    proof_file.verify()?;
+    Ok(proof_file)
+}
+
+#[derive(Args)]
+pub struct CreateDistributionArgs {
+    /// Distribution proof file, see `docs/proof-file-format.md`
+    #[arg(long)]
+    proof_file: PathBuf,
+
+    /// How claims are tracked on-chain
+    #[arg(long, value_enum, default_value = "receipt")]
+    tracking: Tracking,
+}
+
+pub fn create_distribution(context: &Context, args: &CreateDistributionArgs) -> CliResult {
+    let tree = read_proof_file(&args.proof_file)?.to_tree()?;
+    let tracking = ClaimTracking::from(args.tracking);
+    let mint = tree.mint;
+    let merkle_root = tree.root();
+    let creator = context.authority.pubkey();
+    let (escrow_authority, _) =
+        find_distribution_escrow_authority_pda(&mint, tree.action_id, &merkle_root);
+    let escrow = find_associated_token_address(&escrow_authority, &mint).0;
+
+    // The first bitmap page is the claim_bitmap_account, the others are remaining accounts
+    let pages = tracking.claim_bitmap_pages(&tree);
+    let remaining_accounts: Vec<AccountMeta> = pages
+        .iter()
+        .skip(1)
+        .map(|page| AccountMeta::new(*page, false))
+        .collect();
+    let mut instruction = CreateDistributionEscrow {
+        mint,
+        verification_config_or_mint_authority: find_mint_authority_pda(&mint, &creator).0,
+        instructions_sysvar_or_creator: creator,
+        distribution_escrow_authority: escrow_authority,
+        payer: context.fee_payer().pubkey(),
+        distribution_token_account: escrow,
+        distribution_mint: mint,
+        token_program: TOKEN_2022_PROGRAM_ID,
+        associated_token_account_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+        system_program: solana_sdk::system_program::ID,
+        claim_bitmap_account: pages.first().copied(),
+    }
+    .instruction_with_remaining_accounts(
+        CreateDistributionEscrowInstructionArgs {
+            create_distribution_escrow_args: CreateDistributionEscrowArgs {
+                action_id: tree.action_id,
+                merkle_root,
+                claim_bitmap_leaves: tracking.claim_bitmap_leaves(&tree),
+            },
+        },
+        &remaining_accounts,
+    );
+    // The mint creator authorizes the distribution and has to sign
+    instruction.accounts[2].is_signer = true;
+
+    let signature = context.send(vec![instruction], &[])?;
+    eprintln!(
+        "Created distribution {} of {} leaves, fund the escrow token account {escrow}",
+        tree.action_id,
+        tree.leaves.len()
+    );
+    println!("{signature}");
+    Ok(())
+}
+
+#[derive(Args)]
+pub struct ClaimArgs {
+    /// Distribution proof file, see `docs/proof-file-format.md`
+    #[arg(long)]
+    proof_file: PathBuf,
+
+    /// Only claim for this eligible token account, can be repeated
+    #[arg(long = "token-account")]
+    token_accounts: Vec<Pubkey>,
+
+    /// Create the associated token accounts of these owners before claiming, can be repeated
+    #[arg(long = "create-account-for")]
+    owners: Vec<Pubkey>,
+
+    /// Attempts per transaction before giving up
+    #[arg(long, default_value_t = 3)]
+    max_attempts: u32,
+}
+
+pub fn claim(context: &Context, args: &ClaimArgs) -> CliResult {
+    let proof_file = read_proof_file(&args.proof_file)?;
+    let payer = context.fee_payer().pubkey();
+    let entries: Vec<ClaimEntry> = proof_file
+        .claims
+        .iter()
+        .filter(|claim| {
+            args.token_accounts.is_empty()
+                || args.token_accounts.contains(&claim.eligible_token_account)
+        })
+        .map(ClaimEntry::from)
+        .collect();
+    if entries.is_empty() {
+        return Err("No claim of the proof file matches".into());
+    }
+
+    if !args.owners.is_empty() {
+        let instructions = args
+            .owners
+            .iter()
+            .map(|owner| {
+                create_associated_token_account_idempotent(&payer, owner, &proof_file.mint)
+            })
+            .collect();
+        context.send(instructions, &[])?;
+    }
+
+    // Bitmap tracked distributions have their first page created with the escrow
+    let first_page = find_claim_bitmap_pda(
+        &proof_file.mint,
+        proof_file.action_id,
+        &proof_file.merkle_root,
+        0,
+    )
+    .0;
+    let tracking = match context.rpc.get_account(&first_page) {
+        Ok(_) => ClaimTracking::Bitmap,
+        Err(_) => ClaimTracking::Receipt,
+    };
+    let batch = BatchClaim {
+        mint: proof_file.mint,
+        action_id: proof_file.action_id,
+        merkle_root: proof_file.merkle_root,
+        tracking,
+        payer,
+    };
+    let (config_address, _) =
+        find_verification_config_pda(&proof_file.mint, CLAIM_DISTRIBUTION_DISCRIMINATOR);
+    let config = VerificationConfig::fetch(&context.rpc, &config_address)?.data;
+
+    let mut plan = plan_batch_claim(&batch, &entries, &config)?;
+    eprintln!(
+        "Claiming {} leaves in {} transactions",
+        entries.len(),
+        plan.transactions.len()
+    );
+    let result = send_batch_claim(
+        &context.rpc,
+        &mut plan,
+        context.fee_payer(),
+        args.max_attempts,
+    );
+    for transaction in &plan.transactions {
+        match (&transaction.signature, &transaction.last_error) {
+            (Some(signature), _) => println!("{signature}"),
+            (None, Some(error)) => eprintln!(
+                "Leaves {:?} failed after {} attempts: {error}",
+                transaction.leaf_indexes, transaction.attempts
+            ),
+            (None, None) => {}
+        }
+    }
+    result?;
+    eprintln!("Claimed {} leaves", plan.claimed_leaves().len());
+    Ok(())
+}
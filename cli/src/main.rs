@@ -0,0 +1,225 @@
+//! Command line tool for Security Token issuer operations.
+
+mod audit;
+mod cap_table;
+mod controls;
+mod distribution;
+mod mint;
+mod rate;
+mod rotate;
+mod signer;
+mod verification_config;
+
+use clap::{Args, Parser, Subcommand};
+use security_token_client::flows::{pack_instruction_groups, send_flow};
+use security_token_client::halt::with_program_config;
+use security_token_client::pda::find_associated_token_address;
+use security_token_client::verification::fetch_and_prepare_verified_instructions;
+use solana_client::rpc_client::RpcClient;
+use solana_pubkey::Pubkey;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{Signature, Signer};
+use std::path::Path;
+use std::process::ExitCode;
+
+type CliResult<T = ()> = Result<T, Box<dyn std::error::Error>>;
+
+#[derive(Parser)]
+#[command(name = "security-token-cli", version, about)]
+struct Cli {
+    /// RPC URL of the cluster
+    #[arg(
+        long,
+        short = 'u',
+        env = "SOLANA_URL",
+        default_value = "http://localhost:8899",
+        global = true
+    )]
+    url: String,
+
+    /// Issuer (mint creator) keypair file or hardware wallet URI (e.g. `usb://ledger?key=0`),
+    /// defaults to the Solana CLI keypair
+    #[arg(long, short = 'k', env = "SECURITY_TOKEN_KEYPAIR", global = true)]
+    keypair: Option<String>,
+
+    /// Fee payer keypair file or hardware wallet URI, defaults to the issuer
+    #[arg(long, global = true)]
+    fee_payer: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Replace a verification program key in every config of the given mints
+    RotateVerificationProgram(rotate::RotateArgs),
+    /// Export holders, balances and frozen status of a mint as CSV or JSON
+    ExportCapTable(cap_table::ExportCapTableArgs),
+    /// Sign an unsigned rotation audit record, e.g. offline for hardware wallet issuers
+    SignAuditRecord(audit::SignAuditRecordArgs),
+    /// Create a security token mint, optionally with token metadata
+    InitMint(mint::InitMintArgs),
+    /// Create or update the verification config of an instruction on the given mints
+    SetVerificationConfig(verification_config::SetVerificationConfigArgs),
+    /// Pause all transfers of a mint
+    Pause(controls::PauseArgs),
+    /// Resume the transfers of a paused mint
+    Resume(controls::PauseArgs),
+    /// Freeze a token account, optionally until an automatic thaw time
+    Freeze(controls::FreezeArgs),
+    /// Thaw a frozen token account
+    Thaw(controls::ThawArgs),
+    /// Mint tokens to a token account
+    Mint(mint::MintArgs),
+    /// Burn tokens from a token account
+    Burn(mint::BurnArgs),
+    /// Create the rate account of a split or a conversion
+    CreateRate(rate::CreateRateArgs),
+    /// Split the balance of a token account at a rate
+    Split(rate::SplitArgs),
+    /// Convert tokens of one mint into another at a rate
+    Convert(rate::ConvertArgs),
+    /// Create the escrow of a distribution from its proof file
+    CreateDistribution(distribution::CreateDistributionArgs),
+    /// Claim the leaves of a distribution proof file on behalf of their holders
+    Claim(distribution::ClaimArgs),
+}
+
+/// Token account given directly or as the associated token account of its owner
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+pub struct TokenAccountArg {
+    #[arg(long)]
+    token_account: Option<Pubkey>,
+
+    /// Owner of the associated token account
+    #[arg(long)]
+    owner: Option<Pubkey>,
+}
+
+impl TokenAccountArg {
+    pub fn owner(&self) -> Option<Pubkey> {
+        self.owner
+    }
+
+    pub fn resolve(&self, mint: &Pubkey) -> Pubkey {
+        match (self.token_account, self.owner) {
+            (Some(token_account), _) => token_account,
+            (None, Some(owner)) => find_associated_token_address(&owner, mint).0,
+            (None, None) => unreachable!("clap requires one of the group"),
+        }
+    }
+}
+
+/// Resources shared by all commands
+pub struct Context {
+    pub rpc: RpcClient,
+    pub authority: Box<dyn Signer>,
+    fee_payer: Option<Box<dyn Signer>>,
+}
+
+fn rpc_client(url: &str) -> RpcClient {
+    RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed())
+}
+
+fn default_keypair_path() -> String {
+    let home = std::env::var_os("HOME").unwrap_or_default();
+    Path::new(&home)
+        .join(".config/solana/id.json")
+        .to_string_lossy()
+        .into_owned()
+}
+
+impl Cli {
+    fn authority(&self) -> CliResult<Box<dyn Signer>> {
+        let path = self.keypair.clone().unwrap_or_else(default_keypair_path);
+        signer::signer_from_path(&path, "issuer")
+    }
+}
+
+impl Context {
+    fn new(cli: &Cli) -> CliResult<Self> {
+        let fee_payer = match &cli.fee_payer {
+            Some(path) => Some(signer::signer_from_path(path, "fee payer")?),
+            None => None,
+        };
+        Ok(Self {
+            rpc: rpc_client(&cli.url),
+            authority: cli.authority()?,
+            fee_payer,
+        })
+    }
+
+    /// Fee payer, the issuer unless `--fee-payer` is set
+    pub fn fee_payer(&self) -> &dyn Signer {
+        self.fee_payer.as_deref().unwrap_or(&*self.authority)
+    }
+
+    /// Fetches the verification config of `instruction` and builds its instruction bundle
+    pub fn prepare_verified(
+        &self,
+        instruction: Instruction,
+        mint: &Pubkey,
+    ) -> CliResult<Vec<Instruction>> {
+        Ok(fetch_and_prepare_verified_instructions(
+            &self.rpc,
+            instruction,
+            mint,
+        )?)
+    }
+
+    /// Sends `instructions` in one transaction, signed by the fee payer, the issuer and
+    /// the `signers` it needs besides them. Haltable instructions get the ProgramConfig.
+    pub fn send(
+        &self,
+        instructions: Vec<Instruction>,
+        signers: &[&dyn Signer],
+    ) -> CliResult<Signature> {
+        let instructions: Vec<Instruction> =
+            instructions.into_iter().map(with_program_config).collect();
+        let transactions = pack_instruction_groups([instructions], &self.fee_payer().pubkey());
+        let mut pool = vec![self.fee_payer(), &*self.authority];
+        pool.extend_from_slice(signers);
+        let signatures = send_flow(&self.rpc, &transactions, &pool)?;
+        Ok(signatures[0])
+    }
+}
+
+fn run(cli: Cli) -> CliResult {
+    match cli.command {
+        // Read-only, doesn't need keypairs
+        Command::ExportCapTable(ref args) => cap_table::run(&rpc_client(&cli.url), args),
+        // Runs offline, the signer is only loaded when needed
+        Command::SignAuditRecord(ref args) => audit::run(&cli, args),
+        Command::RotateVerificationProgram(ref args) => rotate::run(&Context::new(&cli)?, args),
+        Command::InitMint(ref args) => mint::init_mint(&Context::new(&cli)?, args),
+        Command::SetVerificationConfig(ref args) => {
+            verification_config::run(&Context::new(&cli)?, args)
+        }
+        Command::Pause(ref args) => controls::pause(&Context::new(&cli)?, args),
+        Command::Resume(ref args) => controls::resume(&Context::new(&cli)?, args),
+        Command::Freeze(ref args) => controls::freeze(&Context::new(&cli)?, args),
+        Command::Thaw(ref args) => controls::thaw(&Context::new(&cli)?, args),
+        Command::Mint(ref args) => mint::mint(&Context::new(&cli)?, args),
+        Command::Burn(ref args) => mint::burn(&Context::new(&cli)?, args),
+        Command::CreateRate(ref args) => rate::create_rate(&Context::new(&cli)?, args),
+        Command::Split(ref args) => rate::split(&Context::new(&cli)?, args),
+        Command::Convert(ref args) => rate::convert(&Context::new(&cli)?, args),
+        Command::CreateDistribution(ref args) => {
+            distribution::create_distribution(&Context::new(&cli)?, args)
+        }
+        Command::Claim(ref args) => distribution::claim(&Context::new(&cli)?, args),
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! `init-mint`, `mint` and `burn` commands
+
+use crate::{CliResult, Context, TokenAccountArg};
+use clap::Args;
+use security_token_client::flows::{plan_mint_setup, send_flow, MintSetup};
+use security_token_client::instructions::{
+    Burn, BurnInstructionArgs, Mint, MintInstructionArgs, BURN_DISCRIMINATOR, MINT_DISCRIMINATOR,
+};
+use security_token_client::pda::{
+    find_associated_token_address, find_mint_authority_pda, find_permanent_delegate_pda,
+    find_verification_config_pda, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
+};
+use security_token_client::types::{MetadataPointerArgs, TokenMetadataArgs};
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::signature::{read_keypair_file, Signer};
+
+#[derive(Args)]
+pub struct InitMintArgs {
+    /// Keypair file of the new mint
+    #[arg(long)]
+    mint_keypair: String,
+
+    #[arg(long)]
+    decimals: u8,
+
+    /// Token metadata name, stored in the mint with the symbol and URI
+    #[arg(long, requires_all = ["symbol", "uri"])]
+    name: Option<String>,
+
+    #[arg(long, requires = "name")]
+    symbol: Option<String>,
+
+    #[arg(long, requires = "name")]
+    uri: Option<String>,
+}
+
+pub fn init_mint(context: &Context, args: &InitMintArgs) -> CliResult {
+    let mint_keypair = read_keypair_file(&args.mint_keypair)
+        .map_err(|err| format!("{}: {err}", args.mint_keypair))?;
+    let mint = mint_keypair.pubkey();
+    let creator = context.authority.pubkey();
+
+    let mut setup = MintSetup::new(mint, creator, context.fee_payer().pubkey(), args.decimals);
+    if let (Some(name), Some(symbol), Some(uri)) = (&args.name, &args.symbol, &args.uri) {
+        setup.mint_args.ix_metadata_pointer = Some(MetadataPointerArgs {
+            authority: creator,
+            metadata_address: mint,
+        });
+        setup.mint_args.ix_metadata = Some(TokenMetadataArgs {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            additional_metadata: Vec::new(),
+        });
+    }
+
+    let plan = plan_mint_setup(&setup);
+    send_flow(
+        &context.rpc,
+        &plan.transactions,
+        &[context.fee_payer(), &*context.authority, &mint_keypair],
+    )?;
+    eprintln!(
+        "Initialized mint {mint}, mint authority {}",
+        plan.pdas.mint_authority
+    );
+    println!("{mint}");
+    Ok(())
+}
+
+#[derive(Args)]
+pub struct MintArgs {
+    #[arg(long)]
+    mint: Pubkey,
+
+    #[command(flatten)]
+    destination: TokenAccountArg,
+
+    /// Amount in base units
+    #[arg(long)]
+    amount: u64,
+}
+
+pub fn mint(context: &Context, args: &MintArgs) -> CliResult {
+    let destination = args.destination.resolve(&args.mint);
+    let mut instructions = Vec::new();
+    // The associated token account of an owner is created if missing
+    if let Some(owner) = args.destination.owner() {
+        instructions.push(create_associated_token_account_idempotent(
+            &context.fee_payer().pubkey(),
+            &owner,
+            &args.mint,
+        ));
+    }
+
+    let mint = Mint {
+        mint: args.mint,
+        verification_config: find_verification_config_pda(&args.mint, MINT_DISCRIMINATOR).0,
+        instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+        mint_authority: find_mint_authority_pda(&args.mint, &context.authority.pubkey()).0,
+        mint_account: args.mint,
+        destination,
+        token_program: TOKEN_2022_PROGRAM_ID,
+        lot_payer: None,
+        holding_period_account: None,
+        restricted_holding_account: None,
+        holding_lot_account: None,
+        system_program: None,
+    }
+    .instruction(MintInstructionArgs {
+        amount: args.amount,
+    });
+    instructions.extend(context.prepare_verified(mint, &args.mint)?);
+
+    let signature = context.send(instructions, &[])?;
+    eprintln!("Minted {} to {destination}", args.amount);
+    println!("{signature}");
+    Ok(())
+}
+
+#[derive(Args)]
+pub struct BurnArgs {
+    #[arg(long)]
+    mint: Pubkey,
+
+    #[command(flatten)]
+    source: TokenAccountArg,
+
+    /// Amount in base units
+    #[arg(long)]
+    amount: u64,
+}
+
+pub fn burn(context: &Context, args: &BurnArgs) -> CliResult {
+    let token_account = args.source.resolve(&args.mint);
+    let burn = Burn {
+        mint: args.mint,
+        verification_config: find_verification_config_pda(&args.mint, BURN_DISCRIMINATOR).0,
+        instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+        permanent_delegate: find_permanent_delegate_pda(&args.mint).0,
+        mint_account: args.mint,
+        token_account,
+        token_program: TOKEN_2022_PROGRAM_ID,
+    }
+    .instruction(BurnInstructionArgs {
+        amount: args.amount,
+    });
+
+    let signature = context.send(context.prepare_verified(burn, &args.mint)?, &[])?;
+    eprintln!("Burned {} from {token_account}", args.amount);
+    println!("{signature}");
+    Ok(())
+}
+
+/// Associated token program `CreateIdempotent` of the Token-2022 account of `owner`
+pub fn create_associated_token_account_idempotent(
+    payer: &Pubkey,
+    owner: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(find_associated_token_address(owner, mint).0, false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(solana_sdk::system_program::ID, false),
+            AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+        ],
+        data: vec![1],
+    }
+}
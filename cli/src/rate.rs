@@ -0,0 +1,186 @@
+//! `create-rate`, `split` and `convert` commands
+
+use crate::mint::create_associated_token_account_idempotent;
+use crate::{CliResult, Context, TokenAccountArg};
+use clap::{Args, ValueEnum};
+use security_token_client::account::ProgramAccount;
+use security_token_client::accounts::Rate;
+use security_token_client::instructions::{
+    Convert, ConvertInstructionArgs, CreateRateAccount, CreateRateAccountInstructionArgs, Split,
+    SplitInstructionArgs, CONVERT_DISCRIMINATOR, SPLIT_DISCRIMINATOR,
+};
+use security_token_client::pda::{
+    find_associated_token_address, find_mint_authority_pda, find_permanent_delegate_pda,
+    find_rate_pda, find_receipt_pda, find_verification_config_pda, TOKEN_2022_PROGRAM_ID,
+};
+use security_token_client::types::{self, RateConfig};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Rounding {
+    Up,
+    Down,
+}
+
+#[derive(Args)]
+pub struct CreateRateArgs {
+    /// Action id of the split or conversion using the rate
+    #[arg(long)]
+    action_id: u64,
+
+    /// Source mint, the split mint for a split
+    #[arg(long)]
+    mint_from: Pubkey,
+
+    /// Destination mint, defaults to the source mint (split)
+    #[arg(long)]
+    mint_to: Option<Pubkey>,
+
+    #[arg(long)]
+    numerator: u8,
+
+    #[arg(long)]
+    denominator: u8,
+
+    #[arg(long, value_enum, default_value = "down")]
+    rounding: Rounding,
+}
+
+pub fn create_rate(context: &Context, args: &CreateRateArgs) -> CliResult {
+    let mint_to = args.mint_to.unwrap_or(args.mint_from);
+    let creator = context.authority.pubkey();
+    let (rate_account, _) = find_rate_pda(args.action_id, &args.mint_from, &mint_to);
+
+    let mut instruction = CreateRateAccount {
+        mint: mint_to,
+        verification_config_or_mint_authority: find_mint_authority_pda(&mint_to, &creator).0,
+        instructions_sysvar_or_creator: creator,
+        payer: context.fee_payer().pubkey(),
+        rate_account,
+        mint_from: args.mint_from,
+        mint_to,
+        system_program: solana_sdk::system_program::ID,
+    }
+    .instruction(CreateRateAccountInstructionArgs {
+        create_rate_args: types::CreateRateArgs {
+            action_id: args.action_id,
+            rate: RateConfig {
+                rounding: args.rounding as u8,
+                numerator: args.numerator,
+                denominator: args.denominator,
+            },
+        },
+    });
+    // The mint creator authorizes the rate and has to sign
+    instruction.accounts[2].is_signer = true;
+
+    let signature = context.send(vec![instruction], &[])?;
+    eprintln!("Created rate account {rate_account}");
+    println!("{signature}");
+    Ok(())
+}
+
+#[derive(Args)]
+pub struct SplitArgs {
+    #[arg(long)]
+    mint: Pubkey,
+
+    /// Action id of the rate account
+    #[arg(long)]
+    action_id: u64,
+
+    #[command(flatten)]
+    account: TokenAccountArg,
+}
+
+pub fn split(context: &Context, args: &SplitArgs) -> CliResult {
+    let token_account = args.account.resolve(&args.mint);
+    let split = Split {
+        mint: args.mint,
+        verification_config: find_verification_config_pda(&args.mint, SPLIT_DISCRIMINATOR).0,
+        instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+        mint_authority: find_mint_authority_pda(&args.mint, &context.authority.pubkey()).0,
+        permanent_delegate: find_permanent_delegate_pda(&args.mint).0,
+        payer: context.fee_payer().pubkey(),
+        mint_account: args.mint,
+        token_account,
+        rate_account: find_rate_pda(args.action_id, &args.mint, &args.mint).0,
+        receipt_account: find_receipt_pda(&args.mint, args.action_id).0,
+        token_program: TOKEN_2022_PROGRAM_ID,
+        system_program: solana_sdk::system_program::ID,
+    }
+    .instruction(SplitInstructionArgs {
+        split_args: types::SplitArgs {
+            action_id: args.action_id,
+        },
+    });
+
+    let signature = context.send(context.prepare_verified(split, &args.mint)?, &[])?;
+    eprintln!("Split {token_account}");
+    println!("{signature}");
+    Ok(())
+}
+
+#[derive(Args)]
+pub struct ConvertArgs {
+    #[arg(long)]
+    mint_from: Pubkey,
+
+    #[arg(long)]
+    mint_to: Pubkey,
+
+    /// Action id of the rate account
+    #[arg(long)]
+    action_id: u64,
+
+    /// Owner of the converted associated token accounts, the destination one is created if
+    /// missing
+    #[arg(long)]
+    owner: Pubkey,
+
+    /// Amount of the source mint in base units
+    #[arg(long)]
+    amount: u64,
+}
+
+pub fn convert(context: &Context, args: &ConvertArgs) -> CliResult {
+    let (rate_account, _) = find_rate_pda(args.action_id, &args.mint_from, &args.mint_to);
+    let rate = Rate::fetch(&context.rpc, &rate_account)?.data;
+    let token_account_to = find_associated_token_address(&args.owner, &args.mint_to).0;
+
+    let convert = Convert {
+        mint: args.mint_to,
+        verification_config: find_verification_config_pda(&args.mint_to, CONVERT_DISCRIMINATOR).0,
+        instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+        mint_authority: find_mint_authority_pda(&args.mint_to, &context.authority.pubkey()).0,
+        permanent_delegate: find_permanent_delegate_pda(&args.mint_from).0,
+        payer: context.fee_payer().pubkey(),
+        mint_from: args.mint_from,
+        mint_to: args.mint_to,
+        token_account_from: find_associated_token_address(&args.owner, &args.mint_from).0,
+        token_account_to,
+        rate_account,
+        receipt_account: find_receipt_pda(&args.mint_to, args.action_id).0,
+        token_program: TOKEN_2022_PROGRAM_ID,
+        system_program: solana_sdk::system_program::ID,
+        oracle: rate.has_oracle().then_some(rate.oracle),
+    }
+    .instruction(ConvertInstructionArgs {
+        convert_args: types::ConvertArgs {
+            action_id: args.action_id,
+            amount_to_convert: args.amount,
+        },
+    });
+
+    let mut instructions = vec![create_associated_token_account_idempotent(
+        &context.fee_payer().pubkey(),
+        &args.owner,
+        &args.mint_to,
+    )];
+    instructions.extend(context.prepare_verified(convert, &args.mint_to)?);
+    let signature = context.send(instructions, &[])?;
+    eprintln!("Converted {} into {token_account_to}", args.amount);
+    println!("{signature}");
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! `rotate-verification-program` command
+
+use crate::signer::can_sign_message;
+use crate::{CliResult, Context};
+use clap::Args;
+use security_token_client::rotation::{
+    apply_verification_program_rotation, fetch_mint_verification_configs, plan_config_rotation,
+    ProgramRotation,
+};
+use solana_pubkey::Pubkey;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct RotateArgs {
+    /// Mint to rotate, can be repeated
+    #[arg(long = "mint", required = true)]
+    mints: Vec<Pubkey>,
+
+    /// Verification program key being retired
+    #[arg(long)]
+    old_program: Pubkey,
+
+    /// Verification program key replacing it
+    #[arg(long)]
+    new_program: Pubkey,
+
+    /// Write the signed audit record to this file instead of stdout
+    #[arg(long)]
+    audit_out: Option<PathBuf>,
+
+    /// Only print the planned config changes
+    #[arg(long)]
+    dry_run: bool,
+}
+
+pub fn run(context: &Context, args: &RotateArgs) -> CliResult {
+    let rotation = ProgramRotation {
+        old_program: args.old_program,
+        new_program: args.new_program,
+    };
+
+    if args.dry_run {
+        for mint in &args.mints {
+            let configs = fetch_mint_verification_configs(&context.rpc, mint)?;
+            for change in plan_config_rotation(mint, &configs, &rotation) {
+                println!(
+                    "{} config {}: {:?} -> {:?}",
+                    change.mint, change.instruction_discriminator, change.before, change.after
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    let record = apply_verification_program_rotation(
+        &context.rpc,
+        &args.mints,
+        &rotation,
+        &*context.authority,
+        context.fee_payer(),
+    )?;
+    eprintln!(
+        "Rotated {} configs in {} transactions, authority {}",
+        record.changes.len(),
+        record.transactions.len(),
+        record.authority
+    );
+
+    let json = if can_sign_message(&*context.authority) {
+        serde_json::to_string_pretty(&record.try_sign(&*context.authority)?)?
+    } else {
+        eprintln!(
+            "Hardware wallets cannot sign audit records, the record is written unsigned. \
+             Sign it offline with `security-token-cli sign-audit-record`."
+        );
+        serde_json::to_string_pretty(&record)?
+    };
+    match &args.audit_out {
+        Some(path) => std::fs::write(path, json)?,
+        None => println!("{json}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! Signer resolution: keypair files and hardware wallets.
+//!
+//! A signer path is either a keypair file or a Ledger URI in the Solana CLI format,
+//! e.g. `usb://ledger`, `usb://ledger?key=1/0` or `usb://ledger/<WALLET_PUBKEY>?key=1`.
+//! Ledger support requires the `ledger` feature.
+
+use crate::CliResult;
+use solana_derivation_path::DerivationPath;
+use solana_remote_wallet::locator::Locator;
+use solana_remote_wallet::remote_keypair::generate_remote_keypair;
+use solana_remote_wallet::remote_wallet::initialize_wallet_manager;
+use solana_sdk::signature::{read_keypair_file, Signer};
+use std::path::Path;
+
+const USB_SCHEME: &str = "usb://";
+
+/// Whether `signer` can sign arbitrary messages such as audit records.
+///
+/// Hardware wallets only sign transactions (and off-chain messages in their own
+/// envelope), so their audit records go through the offline signing flow.
+pub fn can_sign_message(signer: &dyn Signer) -> bool {
+    !signer.is_interactive()
+}
+
+fn is_hardware_wallet_path(path: &str) -> bool {
+    path.starts_with(USB_SCHEME)
+}
+
+/// Resolves `path` to a signer; `name` is shown on the device confirmation prompt
+pub fn signer_from_path(path: &str, name: &str) -> CliResult<Box<dyn Signer>> {
+    if !is_hardware_wallet_path(path) {
+        return read_keypair_file(Path::new(path))
+            .map(|keypair| Box::new(keypair) as Box<dyn Signer>)
+            .map_err(|err| format!("{path}: {err}").into());
+    }
+
+    if !cfg!(feature = "ledger") {
+        return Err(format!("{path}: hardware wallets require the `ledger` feature").into());
+    }
+    let uri = uriparse::URIReference::try_from(path).map_err(|err| format!("{path}: {err}"))?;
+    let locator = Locator::new_from_uri(&uri).map_err(|err| format!("{path}: {err}"))?;
+    let derivation_path = DerivationPath::from_uri_key_query(&uri)
+        .map_err(|err| format!("{path}: {err}"))?
+        .unwrap_or_default();
+    let wallet_manager = initialize_wallet_manager().map_err(|err| format!("{path}: {err}"))?;
+    wallet_manager
+        .update_devices()
+        .map_err(|err| format!("{path}: {err}"))?;
+    let keypair = generate_remote_keypair(locator, derivation_path, &wallet_manager, false, name)
+        .map_err(|err| format!("{path}: {err}"))?;
+    Ok(Box::new(keypair))
+}
@@ -0,0 +1,83 @@
+//! `set-verification-config` command
+
+use crate::{CliResult, Context};
+use clap::Args;
+use security_token_client::discriminators::SecurityTokenInstructionDiscriminators;
+use security_token_client::rollout::{rollout_verification_config, DesiredVerificationConfig};
+use solana_pubkey::Pubkey;
+
+#[derive(Args)]
+pub struct SetVerificationConfigArgs {
+    /// Mint to configure, can be repeated
+    #[arg(long = "mint", required = true)]
+    mints: Vec<Pubkey>,
+
+    /// Verified instruction, by name (e.g. `transfer`) or discriminator
+    #[arg(long, value_parser = parse_instruction)]
+    instruction: u8,
+
+    /// Verification program, in call order, can be repeated
+    #[arg(long = "program")]
+    programs: Vec<Pubkey>,
+
+    /// Call the verification programs by CPI instead of introspecting the transaction
+    #[arg(long)]
+    cpi_mode: bool,
+
+    /// Number of programs that must pass, 0 requires all of them
+    #[arg(long, default_value_t = 0)]
+    threshold: u8,
+
+    /// Instructions before the verified one searched by introspection, 0 for all of them
+    #[arg(long, default_value_t = 0)]
+    lookback_window: u8,
+
+    /// Introspection requires the verified instruction to be top-level
+    #[arg(long)]
+    require_top_level: bool,
+}
+
+/// Parses an instruction discriminator or an instruction name in any case, with or without
+/// hyphens
+fn parse_instruction(value: &str) -> Result<u8, String> {
+    if let Ok(discriminator) = value.parse() {
+        return Ok(discriminator);
+    }
+    let name = value.replace(['-', '_'], "");
+    SecurityTokenInstructionDiscriminators::ALL
+        .iter()
+        .find(|instruction| format!("{instruction:?}").eq_ignore_ascii_case(&name))
+        .map(|instruction| *instruction as u8)
+        .ok_or_else(|| format!("unknown instruction `{value}`"))
+}
+
+pub fn run(context: &Context, args: &SetVerificationConfigArgs) -> CliResult {
+    let desired = DesiredVerificationConfig {
+        instruction_discriminator: args.instruction,
+        cpi_mode: args.cpi_mode,
+        program_addresses: args.programs.clone(),
+        threshold: args.threshold,
+        lookback_window: args.lookback_window,
+        require_top_level: args.require_top_level,
+    };
+
+    let report = rollout_verification_config(
+        &context.rpc,
+        &args.mints,
+        &desired,
+        &*context.authority,
+        context.fee_payer(),
+        |result, done, total| match &result.outcome {
+            Ok(Some(signature)) => {
+                eprintln!("[{done}/{total}] {} {:?}", result.mint, result.action);
+                println!("{signature}");
+            }
+            Ok(None) => eprintln!("[{done}/{total}] {} up to date", result.mint),
+            Err(err) => eprintln!("[{done}/{total}] {} failed: {err}", result.mint),
+        },
+    );
+    match report.failed().count() {
+        0 => Ok(()),
+        failed => Err(format!("{failed} mints failed").into()),
+    }
+}
@@ -0,0 +1,40 @@
+//! Builds the client's `sdk-v1` conversions against 1.x `solana-program`.
+
+security_token_client::sdk_v1_conversions!(sdk_v1, solana_program);
+
+#[cfg(test)]
+mod tests {
+    use super::sdk_v1;
+    use security_token_client::instructions::Pause;
+    use security_token_client::programs::SECURITY_TOKEN_PROGRAM_ID;
+    use solana_program::message::Message;
+    use solana_program::pubkey::Pubkey;
+
+    #[test]
+    fn test_client_instruction_into_sdk_v1() {
+        let mint = Pubkey::new_unique();
+        let pause_authority = Pubkey::new_unique();
+        let instruction = Pause {
+            mint: sdk_v1::from_pubkey(&mint),
+            verification_config: sdk_v1::from_pubkey(&Pubkey::new_unique()),
+            instructions_sysvar: sdk_v1::from_pubkey(&solana_program::sysvar::instructions::ID),
+            pause_authority: sdk_v1::from_pubkey(&pause_authority),
+            mint_account: sdk_v1::from_pubkey(&Pubkey::new_unique()),
+            token_program: sdk_v1::from_pubkey(&Pubkey::new_unique()),
+        }
+        .instruction();
+
+        let sdk_instruction = sdk_v1::into_instruction(instruction.clone());
+        assert_eq!(
+            sdk_instruction.program_id,
+            Pubkey::new_from_array(SECURITY_TOKEN_PROGRAM_ID.to_bytes())
+        );
+        assert_eq!(sdk_instruction.accounts[0].pubkey, mint);
+        assert_eq!(sdk_instruction.data, instruction.data);
+        assert_eq!(sdk_v1::from_instruction(&sdk_instruction), instruction);
+
+        let message = Message::new(&[sdk_instruction], Some(&pause_authority));
+        assert_eq!(message.account_keys[0], pause_authority);
+        assert!(message.account_keys.contains(&mint));
+    }
+}
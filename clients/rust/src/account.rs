@@ -0,0 +1,577 @@
+//! Typed decoding of program state accounts.
+//!
+//! [`ProgramAccount`] checks that an account is owned by the security token program and
+//! starts with the expected discriminator before decoding it, so consumers don't have to
+//! repeat the owner check next to `from_bytes`. With the `rpc` feature it also pulls the
+//! account from an RPC client:
+//!
+//! ```ignore
+//! let config = VerificationConfig::fetch(&rpc, &config_pda)?;
+//! let rate = Rate::fetch_maybe(&rpc, &rate_pda)?;
+//! ```
+//!
+//! `gpa_filters` builds the getProgramAccounts filters enumerating every account of a type.
+//! Verification configs, rates, proofs and receipts don't store their mint, only their PDA
+//! seeds do: the accounts of a mint are found by deriving their addresses with [`crate::pda`].
+//!
+//! The distribution escrow authority is a signing PDA without account data, so it has no
+//! decoder; its escrow is a token account.
+
+use crate::accounts::{MintAuthority, Proof, Rate, VerificationConfig};
+use crate::discriminators::SecurityTokenDiscriminators;
+use crate::programs::SECURITY_TOKEN_PROGRAM_ID;
+use solana_pubkey::Pubkey;
+use thiserror::Error;
+
+#[cfg(feature = "rpc")]
+pub use crate::shared::{DecodedAccount, MaybeAccount};
+#[cfg(feature = "rpc")]
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+
+/// Account decoding errors
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum AccountError {
+    #[error("RPC error: {0}")]
+    Rpc(String),
+    #[error("Account not found: {0}")]
+    NotFound(Pubkey),
+    #[error("Account {address} is owned by {owner}, not the security token program")]
+    InvalidOwner { address: Pubkey, owner: Pubkey },
+    #[error("Account {address} has discriminator {actual:?}, expected {expected}")]
+    InvalidDiscriminator {
+        address: Pubkey,
+        expected: u8,
+        actual: Option<u8>,
+    },
+    #[error("Account {address} could not be decoded: {reason}")]
+    InvalidData { address: Pubkey, reason: String },
+}
+
+/// Program state account with a one byte discriminator
+pub trait ProgramAccount: Sized {
+    const DISCRIMINATOR: u8;
+
+    /// Account size, for accounts that have a single size
+    const DATA_SIZE: Option<usize> = None;
+
+    /// Decode the account data, discriminator included, without any check
+    fn decode_data(data: &[u8]) -> Result<Self, std::io::Error>;
+
+    /// Check the owner and discriminator of the account at `address` and decode its data
+    fn decode(address: &Pubkey, owner: &Pubkey, data: &[u8]) -> Result<Self, AccountError> {
+        if owner != &SECURITY_TOKEN_PROGRAM_ID {
+            return Err(AccountError::InvalidOwner {
+                address: *address,
+                owner: *owner,
+            });
+        }
+        if data.first() != Some(&Self::DISCRIMINATOR) {
+            return Err(AccountError::InvalidDiscriminator {
+                address: *address,
+                expected: Self::DISCRIMINATOR,
+                actual: data.first().copied(),
+            });
+        }
+        Self::decode_data(data).map_err(|err| AccountError::InvalidData {
+            address: *address,
+            reason: err.to_string(),
+        })
+    }
+
+    /// getProgramAccounts filters matching every account of this type
+    #[cfg(feature = "rpc")]
+    fn gpa_filters() -> Vec<RpcFilterType> {
+        let mut filters = vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            0,
+            vec![Self::DISCRIMINATOR],
+        ))];
+        if let Some(data_size) = Self::DATA_SIZE {
+            filters.push(RpcFilterType::DataSize(data_size as u64));
+        }
+        filters
+    }
+
+    /// Fetch and decode every account of this type matching `filters`, see `gpa_filters`
+    #[cfg(feature = "rpc")]
+    fn fetch_program_accounts(
+        rpc: &solana_client::rpc_client::RpcClient,
+        filters: Vec<RpcFilterType>,
+    ) -> Result<Vec<DecodedAccount<Self>>, AccountError> {
+        use solana_account_decoder_client_types::UiAccountEncoding;
+        use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+
+        let accounts = rpc
+            .get_program_accounts_with_config(
+                &SECURITY_TOKEN_PROGRAM_ID,
+                RpcProgramAccountsConfig {
+                    filters: Some(filters),
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        ..RpcAccountInfoConfig::default()
+                    },
+                    ..RpcProgramAccountsConfig::default()
+                },
+            )
+            .map_err(|err| AccountError::Rpc(err.to_string()))?;
+        accounts
+            .into_iter()
+            .map(|(address, account)| {
+                let data = Self::decode(&address, &account.owner, &account.data)?;
+                Ok(DecodedAccount {
+                    address,
+                    account,
+                    data,
+                })
+            })
+            .collect()
+    }
+
+    /// Fetch and decode the account at `address`, failing if it doesn't exist
+    #[cfg(feature = "rpc")]
+    fn fetch(
+        rpc: &solana_client::rpc_client::RpcClient,
+        address: &Pubkey,
+    ) -> Result<DecodedAccount<Self>, AccountError> {
+        match Self::fetch_maybe(rpc, address)? {
+            MaybeAccount::Exists(account) => Ok(account),
+            MaybeAccount::NotFound(address) => Err(AccountError::NotFound(address)),
+        }
+    }
+
+    /// Fetch and decode the account at `address`, `NotFound` if it doesn't exist
+    #[cfg(feature = "rpc")]
+    fn fetch_maybe(
+        rpc: &solana_client::rpc_client::RpcClient,
+        address: &Pubkey,
+    ) -> Result<MaybeAccount<Self>, AccountError> {
+        let account = rpc
+            .get_account_with_commitment(address, rpc.commitment())
+            .map_err(|err| AccountError::Rpc(err.to_string()))?
+            .value;
+        let Some(account) = account else {
+            return Ok(MaybeAccount::NotFound(*address));
+        };
+        let data = Self::decode(address, &account.owner, &account.data)?;
+        Ok(MaybeAccount::Exists(DecodedAccount {
+            address: *address,
+            account,
+            data,
+        }))
+    }
+}
+
+macro_rules! generated_program_account {
+    ($account:ty, $discriminator:ident, $data_size:expr) => {
+        impl ProgramAccount for $account {
+            const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::$discriminator as u8;
+            const DATA_SIZE: Option<usize> = $data_size;
+
+            fn decode_data(data: &[u8]) -> Result<Self, std::io::Error> {
+                Self::from_bytes(data)
+            }
+        }
+    };
+}
+
+// Discriminator (1) + mint (32) + mint creator (32) + bump (1), followed by the controller and
+// pending controller (64) once a creator transfer was proposed or a multisig was set, then by
+// the multisig threshold (1), signer count (4) and signers (32 each)
+generated_program_account!(MintAuthority, MintAuthorityDiscriminator, None);
+generated_program_account!(VerificationConfig, VerificationConfigDiscriminator, None);
+// Discriminator, rounding, numerator, denominator, bump (5) + oracle (32)
+// + oracle value offset (4) + oracle value decimals (1) + oracle bounds (16)
+generated_program_account!(Rate, RateDiscriminator, Some(58));
+generated_program_account!(Proof, ProofDiscriminator, None);
+
+impl MintAuthority {
+    /// Key controlling the mint and the creator proposed by ProposeMintCreatorTransfer, read
+    /// from the raw account `data`. The controller is the mint creator until a transfer is
+    /// accepted; the MintAuthority PDA stays derived from the original creator.
+    pub fn decode_controllers(&self, data: &[u8]) -> (Pubkey, Option<Pubkey>) {
+        let pubkey_at = |offset: usize| {
+            data.get(offset..offset + 32)
+                .and_then(|bytes| Pubkey::try_from(bytes).ok())
+        };
+        match (pubkey_at(66), pubkey_at(98)) {
+            (Some(controller), Some(pending)) => (
+                controller,
+                Some(pending).filter(|pending| *pending != Pubkey::default()),
+            ),
+            _ => (self.mint_creator, None),
+        }
+    }
+
+    /// Threshold and signers set by SetMintAuthorityMultisig, read from the raw account `data`.
+    /// `None` while the mint is controlled by a single key
+    pub fn decode_multisig(&self, data: &[u8]) -> Option<(u8, Vec<Pubkey>)> {
+        let threshold = *data.get(130)?;
+        let count = u32::from_le_bytes(data.get(131..135)?.try_into().ok()?) as usize;
+        let signers = data
+            .get(135..135 + count.checked_mul(32)?)?
+            .chunks_exact(32)
+            .map(|bytes| Pubkey::try_from(bytes).ok())
+            .collect::<Option<Vec<_>>>()?;
+        Some((threshold, signers))
+    }
+}
+
+#[cfg(feature = "rpc")]
+impl MintAuthority {
+    /// getProgramAccounts filters matching the mint authority of `mint`
+    pub fn gpa_filters_for_mint(mint: &Pubkey) -> Vec<RpcFilterType> {
+        let mut filters = Self::gpa_filters();
+        filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            1,
+            mint.to_bytes().to_vec(),
+        )));
+        filters
+    }
+
+    /// getProgramAccounts filters matching the mint authorities of the mints of `creator`
+    pub fn gpa_filters_for_creator(creator: &Pubkey) -> Vec<RpcFilterType> {
+        let mut filters = Self::gpa_filters();
+        filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            33,
+            creator.to_bytes().to_vec(),
+        )));
+        filters
+    }
+}
+
+#[cfg(feature = "rpc")]
+impl VerificationConfig {
+    /// getProgramAccounts filters matching the configs of `instruction_discriminator` of every
+    /// mint
+    pub fn gpa_filters_for_instruction(instruction_discriminator: u8) -> Vec<RpcFilterType> {
+        let mut filters = Self::gpa_filters();
+        filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            1,
+            vec![instruction_discriminator],
+        )));
+        filters
+    }
+}
+
+#[cfg(feature = "rpc")]
+impl Rate {
+    /// getProgramAccounts filters matching the rates priced by `oracle`
+    pub fn gpa_filters_for_oracle(oracle: &Pubkey) -> Vec<RpcFilterType> {
+        let mut filters = Self::gpa_filters();
+        filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            5,
+            oracle.to_bytes().to_vec(),
+        )));
+        filters
+    }
+}
+
+/// Amounts recorded by a claim receipt
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClaimAmounts {
+    /// Tokens received by the holder
+    pub net_amount: u64,
+    /// Tokens withheld to the tax escrow
+    pub withheld_amount: u64,
+    /// Jurisdiction of the applied withholding rate, 0 without withholding
+    pub jurisdiction: u16,
+}
+
+/// Receipt of a split, convert, migration or claim; only claim receipts carry data
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Receipt {
+    pub claim: Option<ClaimAmounts>,
+}
+
+impl Receipt {
+    /// Discriminator
+    pub const LEN: usize = 1;
+    /// Discriminator + net_amount (8 bytes) + withheld_amount (8 bytes) + jurisdiction (2 bytes)
+    pub const CLAIM_LEN: usize = Self::LEN + 8 + 8 + 2;
+
+    /// getProgramAccounts filters matching the split, convert and migration receipts
+    #[cfg(feature = "rpc")]
+    pub fn gpa_filters_for_actions() -> Vec<RpcFilterType> {
+        let mut filters = Self::gpa_filters();
+        filters.push(RpcFilterType::DataSize(Self::LEN as u64));
+        filters
+    }
+
+    /// getProgramAccounts filters matching the claim receipts
+    #[cfg(feature = "rpc")]
+    pub fn gpa_filters_for_claims() -> Vec<RpcFilterType> {
+        let mut filters = Self::gpa_filters();
+        filters.push(RpcFilterType::DataSize(Self::CLAIM_LEN as u64));
+        filters
+    }
+}
+
+impl ProgramAccount for Receipt {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::ReceiptDiscriminator as u8;
+
+    fn decode_data(data: &[u8]) -> Result<Self, std::io::Error> {
+        let u64_at =
+            |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        match data.len() {
+            Self::LEN => Ok(Self { claim: None }),
+            Self::CLAIM_LEN => Ok(Self {
+                claim: Some(ClaimAmounts {
+                    net_amount: u64_at(1),
+                    withheld_amount: u64_at(9),
+                    jurisdiction: u16::from_le_bytes([data[17], data[18]]),
+                }),
+            }),
+            len => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("unexpected receipt length {len}"),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_mint_authority_controllers() {
+        let creator = Pubkey::new_unique();
+        let mint_authority = MintAuthority {
+            discriminator: MintAuthority::DISCRIMINATOR,
+            mint: Pubkey::new_unique(),
+            mint_creator: creator,
+            bump: 255,
+        };
+        let mut data = borsh::to_vec(&mint_authority).unwrap();
+        assert_eq!(mint_authority.decode_controllers(&data), (creator, None));
+
+        let new_creator = Pubkey::new_unique();
+        data.extend_from_slice(creator.as_ref());
+        data.extend_from_slice(new_creator.as_ref());
+        let decoded =
+            MintAuthority::decode(&Pubkey::new_unique(), &SECURITY_TOKEN_PROGRAM_ID, &data)
+                .unwrap();
+        assert_eq!(decoded, mint_authority);
+        assert_eq!(
+            decoded.decode_controllers(&data),
+            (creator, Some(new_creator))
+        );
+        assert_eq!(decoded.decode_multisig(&data), None);
+
+        let signers = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        data.push(2);
+        data.extend_from_slice(&2u32.to_le_bytes());
+        signers
+            .iter()
+            .for_each(|signer| data.extend_from_slice(signer.as_ref()));
+        assert_eq!(decoded.decode_multisig(&data), Some((2, signers)));
+    }
+
+    #[test]
+    fn test_decode_checks_owner_and_discriminator() {
+        let address = Pubkey::new_unique();
+        let mint_authority = MintAuthority {
+            discriminator: MintAuthority::DISCRIMINATOR,
+            mint: Pubkey::new_unique(),
+            mint_creator: Pubkey::new_unique(),
+            bump: 255,
+        };
+        let data = borsh::to_vec(&mint_authority).unwrap();
+        assert_eq!(
+            MintAuthority::decode(&address, &SECURITY_TOKEN_PROGRAM_ID, &data),
+            Ok(mint_authority)
+        );
+
+        let owner = Pubkey::new_unique();
+        assert_eq!(
+            MintAuthority::decode(&address, &owner, &data),
+            Err(AccountError::InvalidOwner { address, owner })
+        );
+        assert_eq!(
+            Rate::decode(&address, &SECURITY_TOKEN_PROGRAM_ID, &data),
+            Err(AccountError::InvalidDiscriminator {
+                address,
+                expected: Rate::DISCRIMINATOR,
+                actual: Some(MintAuthority::DISCRIMINATOR),
+            })
+        );
+        assert!(matches!(
+            MintAuthority::decode(&address, &SECURITY_TOKEN_PROGRAM_ID, &data[..40]),
+            Err(AccountError::InvalidData { .. })
+        ));
+        assert!(matches!(
+            MintAuthority::decode(&address, &SECURITY_TOKEN_PROGRAM_ID, &[]),
+            Err(AccountError::InvalidDiscriminator { actual: None, .. })
+        ));
+    }
+
+    #[test]
+    fn test_decode_receipt() {
+        let address = Pubkey::new_unique();
+        let receipt = Receipt::decode(
+            &address,
+            &SECURITY_TOKEN_PROGRAM_ID,
+            &[Receipt::DISCRIMINATOR],
+        );
+        assert_eq!(receipt, Ok(Receipt { claim: None }));
+
+        let mut data = vec![Receipt::DISCRIMINATOR];
+        data.extend_from_slice(&900u64.to_le_bytes());
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.extend_from_slice(&840u16.to_le_bytes());
+        let receipt = Receipt::decode(&address, &SECURITY_TOKEN_PROGRAM_ID, &data).unwrap();
+        assert_eq!(
+            receipt.claim,
+            Some(ClaimAmounts {
+                net_amount: 900,
+                withheld_amount: 100,
+                jurisdiction: 840,
+            })
+        );
+
+        assert!(matches!(
+            Receipt::decode(&address, &SECURITY_TOKEN_PROGRAM_ID, &data[..10]),
+            Err(AccountError::InvalidData { .. })
+        ));
+    }
+
+    #[cfg(feature = "rpc")]
+    fn matches(filters: &[RpcFilterType], data: &[u8]) -> bool {
+        filters.iter().all(|filter| match filter {
+            RpcFilterType::DataSize(size) => data.len() as u64 == *size,
+            RpcFilterType::Memcmp(memcmp) => memcmp.bytes_match(data),
+            _ => false,
+        })
+    }
+
+    #[cfg(feature = "rpc")]
+    #[test]
+    fn test_gpa_filters_match_account_layouts() {
+        let mint = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let data = borsh::to_vec(&MintAuthority {
+            discriminator: MintAuthority::DISCRIMINATOR,
+            mint,
+            mint_creator: creator,
+            bump: 255,
+        })
+        .unwrap();
+        assert!(matches(&MintAuthority::gpa_filters(), &data));
+        assert!(matches(&MintAuthority::gpa_filters_for_mint(&mint), &data));
+        assert!(matches(
+            &MintAuthority::gpa_filters_for_creator(&creator),
+            &data
+        ));
+        assert!(!matches(
+            &MintAuthority::gpa_filters_for_mint(&creator),
+            &data
+        ));
+        assert!(!matches(&Rate::gpa_filters(), &data));
+
+        let oracle = Pubkey::new_unique();
+        let data = borsh::to_vec(&Rate {
+            discriminator: Rate::DISCRIMINATOR,
+            rounding: crate::types::Rounding::Down,
+            numerator: 1,
+            denominator: 1,
+            bump: 255,
+            oracle,
+            oracle_value_offset: 8,
+            oracle_value_decimals: 6,
+            oracle_min_value: 0,
+            oracle_max_value: u64::MAX,
+        })
+        .unwrap();
+        assert!(matches(&Rate::gpa_filters_for_oracle(&oracle), &data));
+        assert!(!matches(&Rate::gpa_filters_for_oracle(&mint), &data));
+
+        let data = borsh::to_vec(&VerificationConfig {
+            discriminator: VerificationConfig::DISCRIMINATOR,
+            instruction_discriminator: 12,
+            cpi_mode: false,
+            bump: 255,
+            verification_programs: vec![Pubkey::new_unique()],
+            threshold: 0,
+            lookback_window: 0,
+            require_top_level: false,
+        })
+        .unwrap();
+        assert!(matches(
+            &VerificationConfig::gpa_filters_for_instruction(12),
+            &data
+        ));
+        assert!(!matches(
+            &VerificationConfig::gpa_filters_for_instruction(13),
+            &data
+        ));
+
+        let action_receipt = [Receipt::DISCRIMINATOR];
+        let claim_receipt = [&[Receipt::DISCRIMINATOR][..], &[0u8; 18]].concat();
+        assert!(matches(&Receipt::gpa_filters(), &claim_receipt));
+        assert!(matches(
+            &Receipt::gpa_filters_for_actions(),
+            &action_receipt
+        ));
+        assert!(!matches(
+            &Receipt::gpa_filters_for_actions(),
+            &claim_receipt
+        ));
+        assert!(matches(&Receipt::gpa_filters_for_claims(), &claim_receipt));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_accounts_and_args_round_trip_through_json() {
+        use crate::accounts::DistributionRoot;
+        use crate::types::{ClaimDistributionArgs, DistributionRootStatus, Rounding};
+        use serde::{de::DeserializeOwned, Serialize};
+        use std::fmt::Debug;
+
+        fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T) -> String {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value);
+            json
+        }
+
+        let program = Pubkey::new_unique();
+        let config_json = round_trip(VerificationConfig {
+            discriminator: VerificationConfig::DISCRIMINATOR,
+            instruction_discriminator: 12,
+            cpi_mode: false,
+            bump: 254,
+            verification_programs: vec![program],
+            threshold: 1,
+            lookback_window: 0,
+            require_top_level: true,
+        });
+        // Addresses are persisted as base58 strings
+        assert!(config_json.contains(&format!("\"{program}\"")));
+
+        round_trip(Rate {
+            discriminator: Rate::DISCRIMINATOR,
+            rounding: Rounding::Down,
+            numerator: 3,
+            denominator: 2,
+            bump: 253,
+            oracle: Pubkey::new_unique(),
+            oracle_value_offset: 8,
+            oracle_value_decimals: 6,
+            oracle_min_value: 1,
+            oracle_max_value: 1_000_000,
+        });
+        round_trip(DistributionRoot {
+            discriminator: SecurityTokenDiscriminators::DistributionRootDiscriminator as u8,
+            mint: Pubkey::new_unique(),
+            merkle_root: [7; 32],
+            action_id: 42,
+            status: DistributionRootStatus::Supplemental,
+            bump: 252,
+        });
+        round_trip(ClaimDistributionArgs {
+            action_id: 42,
+            amount: 100,
+            merkle_root: [7; 32],
+            leaf_index: 3,
+            merkle_proof: Some(vec![[1; 32], [2; 32]]),
+        });
+    }
+}
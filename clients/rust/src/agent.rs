@@ -0,0 +1,14 @@
+//! Agent permission scopes, mirroring the on-chain `agent_permissions` flags.
+
+/// Freeze and Thaw token accounts
+pub const FREEZE: u8 = 1 << 0;
+/// Create distribution escrows and close distribution receipts
+pub const DISTRIBUTION: u8 = 1 << 1;
+/// Manage investor identities (registrar)
+pub const IDENTITY: u8 = 1 << 2;
+/// Approve and reject queued transfer requests (compliance officer)
+pub const TRANSFER_APPROVAL: u8 = 1 << 3;
+/// Suspend and unsuspend token accounts (investigative holds)
+pub const SUSPEND: u8 = 1 << 4;
+/// All known scopes
+pub const ALL: u8 = FREEZE | DISTRIBUTION | IDENTITY | TRANSFER_APPROVAL | SUSPEND;
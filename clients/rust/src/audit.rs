@@ -0,0 +1,104 @@
+//! AuditMint helpers.
+//!
+//! `AuditMint` recomputes the invariants of a mint over a sample of its accounts and logs the
+//! outcome in an [`AuditEvent`], giving auditors an on-chain health check they can verify from
+//! the transaction logs of the program.
+
+use solana_pubkey::Pubkey;
+
+/// Discriminator of the audit event logged by the program
+pub const AUDIT_EVENT_DISCRIMINATOR: u8 = 2;
+
+/// Discriminator (1) + mint (32) + slot (8) + supply (8) + sampled balance (8)
+/// + holder count (4) + escrow balance (8) + escrow entitlements (8) + failed invariants (1)
+pub const AUDIT_EVENT_LEN: usize = 78;
+
+/// Sampled token account balances exceed the mint supply
+pub const AUDIT_SUPPLY_FAILED: u8 = 1 << 0;
+/// Transfer escrow holds less than the sampled vesting grants and pending transfers
+pub const AUDIT_ESCROW_FAILED: u8 = 1 << 1;
+/// An omnibus token account holds less than the sub-account balances of its ledger
+pub const AUDIT_SUB_ACCOUNTS_FAILED: u8 = 1 << 2;
+
+/// Audit outcome decoded from `Program data:` logs
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditEvent {
+    pub mint: Pubkey,
+    pub slot: u64,
+    pub supply: u64,
+    /// Sum of the sampled token account balances
+    pub sampled_balance: u64,
+    /// Sampled token accounts with a non-zero balance
+    pub holder_count: u32,
+    /// Balance of the transfer escrow, `0` when it was not sampled
+    pub escrow_balance: u64,
+    /// Unreleased vesting grants and pending transfers sampled
+    pub escrow_entitlements: u64,
+    /// `AUDIT_*_FAILED` flags of the invariants that do not hold
+    pub failed_invariants: u8,
+}
+
+impl AuditEvent {
+    /// Decode base64-decoded `Program data:` bytes, `None` if they are not an audit event
+    pub fn from_log_data(data: &[u8]) -> Option<Self> {
+        if data.len() != AUDIT_EVENT_LEN || data[0] != AUDIT_EVENT_DISCRIMINATOR {
+            return None;
+        }
+        Some(Self {
+            mint: Pubkey::try_from(&data[1..33]).ok()?,
+            slot: u64::from_le_bytes(data[33..41].try_into().ok()?),
+            supply: u64::from_le_bytes(data[41..49].try_into().ok()?),
+            sampled_balance: u64::from_le_bytes(data[49..57].try_into().ok()?),
+            holder_count: u32::from_le_bytes(data[57..61].try_into().ok()?),
+            escrow_balance: u64::from_le_bytes(data[61..69].try_into().ok()?),
+            escrow_entitlements: u64::from_le_bytes(data[69..77].try_into().ok()?),
+            failed_invariants: data[77],
+        })
+    }
+
+    /// Whether every invariant holds
+    pub fn passed(&self) -> bool {
+        self.failed_invariants == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use security_token_program::events::audit_invariants;
+
+    #[test]
+    fn test_audit_event_matches_program_layout() {
+        let event = security_token_program::events::AuditEvent {
+            mint: [1u8; 32],
+            slot: 7,
+            supply: 1_000,
+            sampled_balance: 1_200,
+            holder_count: 4,
+            escrow_balance: 50,
+            escrow_entitlements: 50,
+            failed_invariants: audit_invariants::SUPPLY,
+        };
+
+        let decoded = AuditEvent::from_log_data(&event.to_bytes()).unwrap();
+        assert_eq!(decoded.mint, Pubkey::new_from_array([1u8; 32]));
+        assert_eq!(decoded.slot, 7);
+        assert_eq!(decoded.supply, 1_000);
+        assert_eq!(decoded.sampled_balance, 1_200);
+        assert_eq!(decoded.holder_count, 4);
+        assert_eq!(decoded.escrow_balance, 50);
+        assert_eq!(decoded.escrow_entitlements, 50);
+        assert_eq!(decoded.failed_invariants, AUDIT_SUPPLY_FAILED);
+        assert!(!decoded.passed());
+        assert_eq!(audit_invariants::ESCROW, AUDIT_ESCROW_FAILED);
+        assert_eq!(audit_invariants::SUB_ACCOUNTS, AUDIT_SUB_ACCOUNTS_FAILED);
+    }
+
+    #[test]
+    fn test_from_log_data_rejects_other_data() {
+        assert_eq!(AuditEvent::from_log_data(&[2u8; 10]), None);
+        let mut data = [0u8; AUDIT_EVENT_LEN];
+        data[0] = 1;
+        assert_eq!(AuditEvent::from_log_data(&data), None);
+    }
+}
@@ -0,0 +1,453 @@
+//! Batch claims of distributions.
+//!
+//! Issuers claim large distributions on behalf of their holders. [`plan_batch_claim`] builds
+//! the `ClaimDistribution` of every (leaf, proof) entry, with its tracking account derived for
+//! the [`ClaimTracking`] mode of the distribution and its verification bundle prepared, and
+//! packs them into as few transactions as fit. The payer of the plan signs every transaction.
+//!
+//! A [`BatchClaimPlan`] keeps the outcome of every transaction: sending it again only sends
+//! the transactions that did not land. A transaction that landed without being recorded
+//! fails again with its claims already tracked.
+//!
+//! Claims of distributions with withholding, payouts in another mint or custodians need the
+//! accounts of those features and are not planned here.
+
+use crate::accounts::VerificationConfig;
+use crate::claim_tracking::{claim_bitmap_page, ClaimTracking};
+use crate::flows::{pack_instruction_groups, FlowTransaction};
+use crate::halt::with_program_config;
+use crate::instructions::{ClaimDistribution, ClaimDistributionInstructionArgs};
+use crate::merkle::{DistributionTree, MerkleTreeNode, MerkleTreeRoot};
+use crate::pda::{
+    find_associated_token_address, find_claim_bitmap_pda, find_claim_deadline_pda,
+    find_claim_receipt_pda, find_distribution_escrow_authority_pda, find_distribution_root_pda,
+    find_permanent_delegate_pda, find_verification_config_pda, TOKEN_2022_PROGRAM_ID,
+    TRANSFER_HOOK_PROGRAM_ID,
+};
+use crate::types::ClaimDistributionArgs;
+use crate::verification::{prepare_verified_instructions, VerificationError};
+use solana_pubkey::Pubkey;
+use solana_signature::Signature;
+
+/// Claim of a single leaf of a distribution
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClaimEntry {
+    pub leaf_index: u32,
+    pub eligible_token_account: Pubkey,
+    pub amount: u64,
+    pub proof: Vec<MerkleTreeNode>,
+}
+
+impl ClaimEntry {
+    /// Claims of every leaf of `tree`
+    pub fn from_tree(tree: &DistributionTree) -> Vec<Self> {
+        tree.leaves
+            .iter()
+            .enumerate()
+            .map(|(idx, leaf)| Self {
+                leaf_index: idx as u32,
+                eligible_token_account: leaf.eligible_token_account,
+                amount: leaf.amount,
+                proof: tree.proof(idx),
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<&crate::proof_file::ProofFileClaim> for ClaimEntry {
+    fn from(claim: &crate::proof_file::ProofFileClaim) -> Self {
+        Self {
+            leaf_index: claim.leaf_index,
+            eligible_token_account: claim.eligible_token_account,
+            amount: claim.amount,
+            proof: claim.proof.clone(),
+        }
+    }
+}
+
+/// Distribution claimed by a batch
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchClaim {
+    pub mint: Pubkey,
+    pub action_id: u64,
+    pub merkle_root: MerkleTreeRoot,
+    pub tracking: ClaimTracking,
+    pub payer: Pubkey,
+}
+
+impl BatchClaim {
+    /// `receipt_account` of the claim of `entry`
+    pub fn tracking_account(&self, entry: &ClaimEntry) -> Pubkey {
+        match self.tracking {
+            ClaimTracking::Receipt => {
+                find_claim_receipt_pda(
+                    &self.mint,
+                    &entry.eligible_token_account,
+                    self.action_id,
+                    &entry.proof,
+                )
+                .0
+            }
+            ClaimTracking::Bitmap => self.claim_bitmap_account(entry),
+        }
+    }
+
+    fn claim_bitmap_account(&self, entry: &ClaimEntry) -> Pubkey {
+        find_claim_bitmap_pda(
+            &self.mint,
+            self.action_id,
+            &self.merkle_root,
+            claim_bitmap_page(entry.leaf_index as usize),
+        )
+        .0
+    }
+
+    /// `ClaimDistribution` of `entry`, before verification
+    pub fn claim_instruction(&self, entry: &ClaimEntry) -> solana_instruction::Instruction {
+        let (escrow_authority, _) =
+            find_distribution_escrow_authority_pda(&self.mint, self.action_id, &self.merkle_root);
+        let instruction = ClaimDistribution {
+            mint: self.mint,
+            verification_config: find_verification_config_pda(
+                &self.mint,
+                crate::instructions::CLAIM_DISTRIBUTION_DISCRIMINATOR,
+            )
+            .0,
+            instructions_sysvar: solana_sdk_ids::sysvar::instructions::ID,
+            permanent_delegate_authority: find_permanent_delegate_pda(&self.mint).0,
+            payer: self.payer,
+            mint_account: self.mint,
+            eligible_token_account: entry.eligible_token_account,
+            escrow_token_account: Some(
+                find_associated_token_address(&escrow_authority, &self.mint).0,
+            ),
+            receipt_account: self.tracking_account(entry),
+            proof_account: None,
+            transfer_hook_program: TRANSFER_HOOK_PROGRAM_ID,
+            token_program: TOKEN_2022_PROGRAM_ID,
+            system_program: solana_sdk_ids::system_program::ID,
+            withholding_rate_account: None,
+            tax_escrow_token_account: None,
+            distribution_payout_account: None,
+            payout_mint: None,
+            payout_escrow_token_account: None,
+            payout_token_account: None,
+            payout_token_program: None,
+            custodian: None,
+            instructions_sysvar_account: None,
+            claim_deadline_account: find_claim_deadline_pda(&self.mint, self.action_id).0,
+            distribution_root_account: find_distribution_root_pda(
+                &self.mint,
+                self.action_id,
+                &self.merkle_root,
+            )
+            .0,
+            claim_bitmap_account: self.claim_bitmap_account(entry),
+        }
+        .instruction(ClaimDistributionInstructionArgs {
+            claim_distribution_args: ClaimDistributionArgs {
+                action_id: self.action_id,
+                amount: entry.amount,
+                merkle_root: self.merkle_root,
+                leaf_index: entry.leaf_index,
+                merkle_proof: Some(entry.proof.clone()),
+            },
+        });
+        with_program_config(instruction)
+    }
+}
+
+/// Transaction of a batch claim and its outcome
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchClaimTransaction {
+    pub transaction: FlowTransaction,
+    /// Leaves claimed by the transaction
+    pub leaf_indexes: Vec<u32>,
+    /// Send attempts so far
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// Signature of the transaction once it landed
+    pub signature: Option<Signature>,
+}
+
+/// Transactions of a batch claim, to send in any order
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchClaimPlan {
+    pub transactions: Vec<BatchClaimTransaction>,
+}
+
+impl BatchClaimPlan {
+    /// Indexes of the transactions that did not land yet
+    pub fn pending(&self) -> Vec<usize> {
+        (0..self.transactions.len())
+            .filter(|index| self.transactions[*index].signature.is_none())
+            .collect()
+    }
+
+    /// Whether every transaction landed
+    pub fn is_complete(&self) -> bool {
+        self.transactions
+            .iter()
+            .all(|transaction| transaction.signature.is_some())
+    }
+
+    /// Records that the transaction at `index` landed with `signature`
+    pub fn record_success(&mut self, index: usize, signature: Signature) {
+        let transaction = &mut self.transactions[index];
+        transaction.attempts += 1;
+        transaction.signature = Some(signature);
+    }
+
+    /// Records a failed attempt of the transaction at `index`
+    pub fn record_failure(&mut self, index: usize, error: String) {
+        let transaction = &mut self.transactions[index];
+        transaction.attempts += 1;
+        transaction.last_error = Some(error);
+    }
+
+    /// Leaves claimed by the transactions that landed
+    pub fn claimed_leaves(&self) -> Vec<u32> {
+        self.transactions
+            .iter()
+            .filter(|transaction| transaction.signature.is_some())
+            .flat_map(|transaction| transaction.leaf_indexes.iter().copied())
+            .collect()
+    }
+}
+
+/// Plans the transactions claiming `entries` of `claim`, verified according to the
+/// `ClaimDistribution` verification `config` of the mint
+pub fn plan_batch_claim(
+    claim: &BatchClaim,
+    entries: &[ClaimEntry],
+    config: &VerificationConfig,
+) -> Result<BatchClaimPlan, VerificationError> {
+    let bundles = entries
+        .iter()
+        .map(|entry| {
+            prepare_verified_instructions(claim.claim_instruction(entry), &claim.mint, config)
+                .map(|bundle| (entry.leaf_index, bundle))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Bundles are packed in entry order, so the leaves of a transaction follow each other
+    let mut leaf_indexes = bundles.iter().map(|(leaf_index, _)| *leaf_index);
+    let transactions = pack_instruction_groups(
+        bundles.iter().map(|(_, bundle)| bundle.clone()),
+        &claim.payer,
+    )
+    .into_iter()
+    .map(|transaction| {
+        let claims = transaction
+            .instructions
+            .iter()
+            .filter(|instruction| {
+                instruction.program_id == crate::programs::SECURITY_TOKEN_PROGRAM_ID
+            })
+            .count();
+        BatchClaimTransaction {
+            transaction,
+            leaf_indexes: leaf_indexes.by_ref().take(claims).collect(),
+            attempts: 0,
+            last_error: None,
+            signature: None,
+        }
+    })
+    .collect();
+    Ok(BatchClaimPlan { transactions })
+}
+
+#[cfg(feature = "rpc")]
+pub use self::send::*;
+
+#[cfg(feature = "rpc")]
+mod send {
+    use super::*;
+    use solana_client::rpc_client::RpcClient;
+    use solana_sdk::signature::Signer;
+    use solana_sdk::transaction::Transaction;
+    use thiserror::Error;
+
+    /// Batch claim errors
+    #[derive(Clone, Debug, Error, PartialEq, Eq)]
+    pub enum BatchClaimError {
+        #[error("Missing signer {0}")]
+        MissingSigner(Pubkey),
+        #[error("{failed} transactions did not land")]
+        Incomplete { failed: usize },
+    }
+
+    /// Sends the pending transactions of `plan`, each up to `max_attempts` times overall, and
+    /// records their outcome in the plan
+    pub fn send_batch_claim(
+        rpc: &RpcClient,
+        plan: &mut BatchClaimPlan,
+        payer: &dyn Signer,
+        max_attempts: u32,
+    ) -> Result<(), BatchClaimError> {
+        // Every transaction of a plan is signed by its payer only
+        if let Some(missing) = plan
+            .transactions
+            .iter()
+            .flat_map(|transaction| &transaction.transaction.signers)
+            .find(|signer| **signer != payer.pubkey())
+        {
+            return Err(BatchClaimError::MissingSigner(*missing));
+        }
+
+        loop {
+            let retried: Vec<usize> = plan
+                .pending()
+                .into_iter()
+                .filter(|index| plan.transactions[*index].attempts < max_attempts)
+                .collect();
+            if retried.is_empty() {
+                break;
+            }
+            for index in retried {
+                let result = rpc.get_latest_blockhash().and_then(|blockhash| {
+                    let transaction = Transaction::new_signed_with_payer(
+                        &plan.transactions[index].transaction.instructions,
+                        Some(&payer.pubkey()),
+                        &[payer],
+                        blockhash,
+                    );
+                    rpc.send_and_confirm_transaction(&transaction)
+                });
+                match result {
+                    Ok(signature) => plan.record_success(index, signature),
+                    Err(err) => plan.record_failure(index, err.to_string()),
+                }
+            }
+        }
+
+        match plan.pending().len() {
+            0 => Ok(()),
+            failed => Err(BatchClaimError::Incomplete { failed }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flows::transaction_size;
+    use crate::instructions::CLAIM_DISTRIBUTION_DISCRIMINATOR;
+    use crate::merkle::DistributionLeaf;
+    use crate::programs::SECURITY_TOKEN_PROGRAM_ID;
+    use borsh::BorshDeserialize;
+    use solana_packet::PACKET_DATA_SIZE;
+
+    fn fixture(holders: u64, cpi_mode: bool) -> (BatchClaim, Vec<ClaimEntry>, VerificationConfig) {
+        let tree = DistributionTree::new(
+            Pubkey::new_unique(),
+            7,
+            (0..holders)
+                .map(|amount| DistributionLeaf::new(Pubkey::new_unique(), amount + 1))
+                .collect(),
+        );
+        let claim = BatchClaim {
+            mint: tree.mint,
+            action_id: tree.action_id,
+            merkle_root: tree.root(),
+            tracking: ClaimTracking::Receipt,
+            payer: Pubkey::new_unique(),
+        };
+        let config = VerificationConfig {
+            discriminator: 1,
+            instruction_discriminator: CLAIM_DISTRIBUTION_DISCRIMINATOR,
+            cpi_mode,
+            bump: 255,
+            verification_programs: vec![Pubkey::new_unique()],
+            threshold: 0,
+            lookback_window: 0,
+            require_top_level: false,
+        };
+        (claim, ClaimEntry::from_tree(&tree), config)
+    }
+
+    #[test]
+    fn test_claim_instruction_accounts() {
+        let (claim, entries, _) = fixture(4, true);
+        let tree = DistributionTree::new(
+            claim.mint,
+            claim.action_id,
+            entries
+                .iter()
+                .map(|entry| DistributionLeaf::new(entry.eligible_token_account, entry.amount))
+                .collect(),
+        );
+        for tracking in [ClaimTracking::Receipt, ClaimTracking::Bitmap] {
+            let claim = BatchClaim {
+                tracking,
+                ..claim.clone()
+            };
+            assert_eq!(
+                claim.tracking_account(&entries[2]),
+                tracking.tracking_account(&tree, 2)
+            );
+        }
+
+        let instruction = claim.claim_instruction(&entries[2]);
+        assert_eq!(instruction.data[0], CLAIM_DISTRIBUTION_DISCRIMINATOR);
+        assert_eq!(
+            instruction.accounts[6].pubkey,
+            entries[2].eligible_token_account
+        );
+        assert_eq!(
+            ClaimDistributionArgs::try_from_slice(&instruction.data[1..]).unwrap(),
+            tree.claim_args(2).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_plan_batch_claim_packs_bundles() {
+        for cpi_mode in [true, false] {
+            let (claim, entries, config) = fixture(50, cpi_mode);
+            let plan = plan_batch_claim(&claim, &entries, &config).unwrap();
+
+            assert!(plan.transactions.len() > 1);
+            let mut leaf_indexes: Vec<u32> = Vec::new();
+            for transaction in &plan.transactions {
+                let instructions = &transaction.transaction.instructions;
+                assert!(transaction_size(instructions, &claim.payer) <= PACKET_DATA_SIZE);
+                assert_eq!(transaction.transaction.signers, vec![claim.payer]);
+                // Verification calls stay right before their claim
+                if !cpi_mode {
+                    for pair in instructions.chunks(2) {
+                        assert_eq!(pair[0].program_id, config.verification_programs[0]);
+                        assert_eq!(pair[1].program_id, SECURITY_TOKEN_PROGRAM_ID);
+                    }
+                }
+                leaf_indexes.extend(&transaction.leaf_indexes);
+            }
+            assert_eq!(leaf_indexes, (0..50).collect::<Vec<u32>>());
+        }
+    }
+
+    #[test]
+    fn test_batch_claim_plan_bookkeeping() {
+        let (claim, entries, config) = fixture(20, true);
+        let mut plan = plan_batch_claim(&claim, &entries, &config).unwrap();
+        let count = plan.transactions.len();
+        assert_eq!(plan.pending(), (0..count).collect::<Vec<_>>());
+
+        plan.record_failure(0, "blockhash not found".to_string());
+        plan.record_success(1, Signature::default());
+        assert_eq!(plan.transactions[0].attempts, 1);
+        assert_eq!(
+            plan.transactions[0].last_error.as_deref(),
+            Some("blockhash not found")
+        );
+        assert!(!plan.pending().contains(&1));
+        assert!(plan.pending().contains(&0));
+        assert_eq!(plan.claimed_leaves(), plan.transactions[1].leaf_indexes);
+
+        for index in plan.pending() {
+            plan.record_success(index, Signature::default());
+        }
+        assert!(plan.is_complete());
+        assert_eq!(plan.claimed_leaves().len(), 20);
+    }
+}
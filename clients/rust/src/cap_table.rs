@@ -0,0 +1,277 @@
+//! Cap table export.
+//!
+//! Enumerates every Token-2022 account of a security token mint and produces a
+//! registry extract with owner, balance and frozen status per account. The CSV
+//! and JSON schemas are documented in `docs/cap-table-export.md`.
+
+use solana_pubkey::Pubkey;
+use thiserror::Error;
+
+/// Base token account length (without extensions)
+pub const TOKEN_ACCOUNT_LEN: usize = 165;
+/// Offset of the account type byte in extended Token-2022 accounts
+const ACCOUNT_TYPE_OFFSET: usize = TOKEN_ACCOUNT_LEN;
+/// `AccountType::Account`
+const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+/// `AccountState::Frozen`
+const ACCOUNT_STATE_FROZEN: u8 = 2;
+
+/// Cap table errors
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum CapTableError {
+    #[error("RPC error: {0}")]
+    Rpc(String),
+    #[error("Account {0} is not a Token-2022 mint")]
+    InvalidMint(Pubkey),
+}
+
+/// Decoded fields of a token account relevant for the cap table
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct CapTableRow {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub token_account: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub owner: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub amount: u64,
+    pub frozen: bool,
+}
+
+/// Registry extract of a mint
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct CapTable {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub mint: Pubkey,
+    /// Slot the accounts were read at
+    pub slot: u64,
+    pub decimals: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub supply: u64,
+    pub rows: Vec<CapTableRow>,
+}
+
+/// Parses a Token-2022 account of `mint`, `None` for other accounts
+pub fn parse_token_account(
+    token_account: &Pubkey,
+    mint: &Pubkey,
+    data: &[u8],
+) -> Option<CapTableRow> {
+    if data.len() < TOKEN_ACCOUNT_LEN
+        || (data.len() > TOKEN_ACCOUNT_LEN && data[ACCOUNT_TYPE_OFFSET] != ACCOUNT_TYPE_ACCOUNT)
+        || data[..32] != mint.to_bytes()
+    {
+        return None;
+    }
+    Some(CapTableRow {
+        token_account: *token_account,
+        owner: Pubkey::try_from(&data[32..64]).ok()?,
+        amount: u64::from_le_bytes(data[64..72].try_into().ok()?),
+        frozen: data[108] == ACCOUNT_STATE_FROZEN,
+    })
+}
+
+/// Parses supply and decimals of a Token-2022 mint
+pub fn parse_mint_supply(data: &[u8]) -> Option<(u64, u8)> {
+    let supply = u64::from_le_bytes(data.get(36..44)?.try_into().ok()?);
+    let decimals = *data.get(44)?;
+    Some((supply, decimals))
+}
+
+/// Formats a raw amount with `decimals` fractional digits
+pub fn format_ui_amount(amount: u64, decimals: u8) -> String {
+    if decimals == 0 {
+        return amount.to_string();
+    }
+    let divisor = 10u128.pow(decimals as u32);
+    let amount = amount as u128;
+    format!(
+        "{}.{:0width$}",
+        amount / divisor,
+        amount % divisor,
+        width = decimals as usize
+    )
+}
+
+impl CapTable {
+    /// Builds the cap table, sorting rows by balance (largest first)
+    pub fn new(mint: Pubkey, slot: u64, supply: u64, decimals: u8, rows: Vec<CapTableRow>) -> Self {
+        let mut rows = rows;
+        rows.sort_by(|a, b| {
+            b.amount
+                .cmp(&a.amount)
+                .then(a.token_account.cmp(&b.token_account))
+        });
+        Self {
+            mint,
+            slot,
+            decimals,
+            supply,
+            rows,
+        }
+    }
+
+    /// Sum of balances of all accounts
+    pub fn total_amount(&self) -> u64 {
+        self.rows.iter().map(|row| row.amount).sum()
+    }
+
+    /// CSV export: `token_account,owner,amount,ui_amount,ownership_bps,frozen`
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("token_account,owner,amount,ui_amount,ownership_bps,frozen\n");
+        for row in &self.rows {
+            let ownership_bps = if self.supply == 0 {
+                0
+            } else {
+                row.amount as u128 * 10_000 / self.supply as u128
+            };
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                row.token_account,
+                row.owner,
+                row.amount,
+                format_ui_amount(row.amount, self.decimals),
+                ownership_bps,
+                row.frozen
+            ));
+        }
+        csv
+    }
+}
+
+/// Reads every token account of `mint` and builds its cap table
+#[cfg(feature = "rpc")]
+pub fn fetch_cap_table(
+    rpc: &solana_client::rpc_client::RpcClient,
+    mint: &Pubkey,
+) -> Result<CapTable, CapTableError> {
+    use crate::pda::TOKEN_2022_PROGRAM_ID;
+    use solana_account_decoder_client_types::UiAccountEncoding;
+    use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+    use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+
+    let rpc_error =
+        |err: solana_client::client_error::ClientError| CapTableError::Rpc(err.to_string());
+    let slot = rpc.get_slot().map_err(rpc_error)?;
+    let mint_account = rpc.get_account(mint).map_err(rpc_error)?;
+    let (supply, decimals) =
+        parse_mint_supply(&mint_account.data).ok_or(CapTableError::InvalidMint(*mint))?;
+
+    let accounts = rpc
+        .get_program_accounts_with_config(
+            &TOKEN_2022_PROGRAM_ID,
+            RpcProgramAccountsConfig {
+                filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                    0,
+                    mint.to_bytes().to_vec(),
+                ))]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    min_context_slot: Some(slot),
+                    ..RpcAccountInfoConfig::default()
+                },
+                ..RpcProgramAccountsConfig::default()
+            },
+        )
+        .map_err(rpc_error)?;
+    let rows = accounts
+        .iter()
+        .filter_map(|(address, account)| parse_token_account(address, mint, &account.data))
+        .collect();
+
+    Ok(CapTable::new(*mint, slot, supply, decimals, rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token_account_data(mint: &Pubkey, owner: &Pubkey, amount: u64, frozen: bool) -> Vec<u8> {
+        let mut data = vec![0u8; TOKEN_ACCOUNT_LEN + 1];
+        data[..32].copy_from_slice(mint.as_ref());
+        data[32..64].copy_from_slice(owner.as_ref());
+        data[64..72].copy_from_slice(&amount.to_le_bytes());
+        data[108] = if frozen { 2 } else { 1 };
+        data[ACCOUNT_TYPE_OFFSET] = ACCOUNT_TYPE_ACCOUNT;
+        data
+    }
+
+    #[test]
+    fn test_parse_token_account() {
+        let (mint, owner, account) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let data = token_account_data(&mint, &owner, 500, true);
+
+        assert_eq!(
+            parse_token_account(&account, &mint, &data),
+            Some(CapTableRow {
+                token_account: account,
+                owner,
+                amount: 500,
+                frozen: true,
+            })
+        );
+        assert_eq!(
+            parse_token_account(&account, &Pubkey::new_unique(), &data),
+            None
+        );
+        let mut mint_data = data.clone();
+        mint_data[ACCOUNT_TYPE_OFFSET] = 1;
+        assert_eq!(parse_token_account(&account, &mint, &mint_data), None);
+    }
+
+    #[test]
+    fn test_cap_table_csv() {
+        let mint = Pubkey::new_unique();
+        let rows = vec![
+            CapTableRow {
+                token_account: Pubkey::new_unique(),
+                owner: Pubkey::new_unique(),
+                amount: 250_000,
+                frozen: false,
+            },
+            CapTableRow {
+                token_account: Pubkey::new_unique(),
+                owner: Pubkey::new_unique(),
+                amount: 750_000,
+                frozen: true,
+            },
+        ];
+        let table = CapTable::new(mint, 10, 1_000_000, 6, rows);
+
+        assert_eq!(table.total_amount(), 1_000_000);
+        let csv = table.to_csv();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].ends_with(",750000,0.750000,7500,true"));
+        assert!(lines[2].ends_with(",250000,0.250000,2500,false"));
+    }
+
+    #[test]
+    fn test_format_ui_amount() {
+        assert_eq!(format_ui_amount(1_234_500, 6), "1.234500");
+        assert_eq!(format_ui_amount(5, 2), "0.05");
+        assert_eq!(format_ui_amount(42, 0), "42");
+    }
+}
@@ -0,0 +1,160 @@
+//! Claim tracking modes of distributions.
+//!
+//! A distribution tracks its claims either with a claim `Receipt` per claim or, when its
+//! escrow is created with `claim_bitmap_leaves`, with `ClaimBitmap` pages indexed by merkle
+//! leaf index. `ClaimDistribution` takes the tracking account of the claim as
+//! `receipt_account` and the bitmap page of the leaf as `claim_bitmap_account` in both modes,
+//! so callers only pick the mode when creating the escrow.
+
+use crate::accounts::ClaimBitmap;
+use crate::merkle::DistributionTree;
+use crate::pda::{find_claim_bitmap_pda, find_claim_receipt_pda};
+use solana_pubkey::Pubkey;
+
+/// Leaves tracked by a full `ClaimBitmap` page (`ClaimBitmap::PAGE_LEAVES`)
+pub const CLAIM_BITMAP_PAGE_LEAVES: u32 = 65_536;
+
+/// How the claims of a distribution are tracked on-chain
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClaimTracking {
+    /// A claim `Receipt` account per claim
+    #[default]
+    Receipt,
+    /// `ClaimBitmap` pages created with the escrow
+    Bitmap,
+}
+
+impl ClaimTracking {
+    /// `claim_bitmap_leaves` argument of `CreateDistributionEscrow` for the tree
+    pub fn claim_bitmap_leaves(self, tree: &DistributionTree) -> u32 {
+        match self {
+            ClaimTracking::Receipt => 0,
+            ClaimTracking::Bitmap => tree.leaves.len() as u32,
+        }
+    }
+
+    /// `ClaimBitmap` pages created by `CreateDistributionEscrow`: the first page is the
+    /// `claim_bitmap_account`, the following pages are passed as remaining accounts
+    pub fn claim_bitmap_pages(self, tree: &DistributionTree) -> Vec<Pubkey> {
+        let page_count = self
+            .claim_bitmap_leaves(tree)
+            .div_ceil(CLAIM_BITMAP_PAGE_LEAVES);
+        (0..page_count)
+            .map(|page| find_claim_bitmap_pda(&tree.mint, tree.action_id, &tree.root(), page).0)
+            .collect()
+    }
+
+    /// Account passed as `receipt_account` when claiming the leaf at `leaf_index`
+    pub fn tracking_account(self, tree: &DistributionTree, leaf_index: usize) -> Pubkey {
+        match self {
+            ClaimTracking::Receipt => {
+                find_claim_receipt_pda(
+                    &tree.mint,
+                    &tree.leaves[leaf_index].eligible_token_account,
+                    tree.action_id,
+                    &tree.proof(leaf_index),
+                )
+                .0
+            }
+            ClaimTracking::Bitmap => claim_bitmap_account(tree, leaf_index),
+        }
+    }
+}
+
+/// Page tracking the leaf at `leaf_index`
+pub fn claim_bitmap_page(leaf_index: usize) -> u32 {
+    (leaf_index as u64 / CLAIM_BITMAP_PAGE_LEAVES as u64) as u32
+}
+
+/// `claim_bitmap_account` of a claim of the leaf at `leaf_index`, required in both modes
+pub fn claim_bitmap_account(tree: &DistributionTree, leaf_index: usize) -> Pubkey {
+    find_claim_bitmap_pda(
+        &tree.mint,
+        tree.action_id,
+        &tree.root(),
+        claim_bitmap_page(leaf_index),
+    )
+    .0
+}
+
+/// Whether the leaf at `leaf_index` of the distribution is marked claimed in `page`.
+/// Returns `false` for leaves the page does not track.
+pub fn is_leaf_claimed(page: &ClaimBitmap, leaf_index: usize) -> bool {
+    if claim_bitmap_page(leaf_index) != page.page {
+        return false;
+    }
+    let page_leaf = leaf_index % CLAIM_BITMAP_PAGE_LEAVES as usize;
+    if page_leaf >= page.leaf_count as usize {
+        return false;
+    }
+    page.bits
+        .get(page_leaf / 8)
+        .is_some_and(|byte| byte & (1 << (page_leaf % 8)) != 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle::DistributionLeaf;
+
+    fn tree(holders: u64) -> DistributionTree {
+        DistributionTree::new(
+            Pubkey::new_unique(),
+            3,
+            (0..holders)
+                .map(|_| DistributionLeaf::new(Pubkey::new_unique(), 10))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_claim_tracking_accounts() {
+        let tree = tree(3);
+
+        assert_eq!(ClaimTracking::Receipt.claim_bitmap_leaves(&tree), 0);
+        assert!(ClaimTracking::Receipt.claim_bitmap_pages(&tree).is_empty());
+        assert_eq!(
+            ClaimTracking::Receipt.tracking_account(&tree, 1),
+            find_claim_receipt_pda(
+                &tree.mint,
+                &tree.leaves[1].eligible_token_account,
+                tree.action_id,
+                &tree.proof(1),
+            )
+            .0
+        );
+
+        let pages = ClaimTracking::Bitmap.claim_bitmap_pages(&tree);
+        assert_eq!(ClaimTracking::Bitmap.claim_bitmap_leaves(&tree), 3);
+        assert_eq!(pages.len(), 1);
+        assert_eq!(ClaimTracking::Bitmap.tracking_account(&tree, 2), pages[0]);
+        assert_eq!(claim_bitmap_account(&tree, 2), pages[0]);
+    }
+
+    #[test]
+    fn test_claim_bitmap_page_of_leaf() {
+        assert_eq!(claim_bitmap_page(0), 0);
+        assert_eq!(claim_bitmap_page(65_535), 0);
+        assert_eq!(claim_bitmap_page(65_536), 1);
+        assert_eq!(claim_bitmap_page(99_999), 1);
+    }
+
+    #[test]
+    fn test_is_leaf_claimed() {
+        let page = ClaimBitmap {
+            discriminator: 35,
+            mint: Pubkey::new_unique(),
+            action_id: 3,
+            merkle_root: [1; 32],
+            page: 1,
+            leaf_count: 10,
+            bump: 255,
+            bits: vec![0b0000_0100, 0],
+        };
+
+        assert!(is_leaf_claimed(&page, 65_538));
+        assert!(!is_leaf_claimed(&page, 65_539));
+        assert!(!is_leaf_claimed(&page, 2));
+        assert!(!is_leaf_claimed(&page, 65_546));
+    }
+}
@@ -0,0 +1,192 @@
+//! Solana SDK version interop.
+//!
+//! The client is built on the split 2.x crates (`solana-pubkey`, `solana-instruction`),
+//! which 2.x `solana-sdk` re-exports, so 2.x applications pass the client types directly.
+//! Conversions for other SDK versions are enabled per version:
+//!
+//! - `sdk-v2` (default): conversions between the split crate types and the SDK independent
+//!   [`RawInstruction`], for exchanging instructions as plain bytes.
+//! - `sdk-v1`: [`sdk_v1_conversions!`](crate::sdk_v1_conversions), generating conversions
+//!   to and from the 1.x `solana-sdk` or `solana-program` types of the application:
+//!
+//! ```ignore
+//! security_token_client::sdk_v1_conversions!(sdk_v1, solana_sdk);
+//!
+//! let instruction = sdk_v1::into_instruction(client_instruction);
+//! let mint = sdk_v1::from_pubkey(&mint);
+//! ```
+//!
+//! The 1.x crates are not dependencies of the client: 1.x `solana-program` cannot share a
+//! lockfile with the 2.x crates (curve25519-dalek 3.2.1 pins `zeroize < 1.4`), so the macro
+//! expands against the SDK the application already depends on. `clients/rust/sdk-v1-tests`
+//! builds it against 1.x `solana-program` outside the workspace.
+
+pub use solana_instruction::{AccountMeta, Instruction};
+pub use solana_pubkey::Pubkey;
+
+/// Account meta as plain bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RawAccountMeta {
+    pub pubkey: [u8; 32],
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// Instruction as plain bytes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawInstruction {
+    pub program_id: [u8; 32],
+    pub accounts: Vec<RawAccountMeta>,
+    pub data: Vec<u8>,
+}
+
+/// Client pubkey from the bytes of a pubkey of any SDK version
+pub fn pubkey(bytes: [u8; 32]) -> Pubkey {
+    Pubkey::new_from_array(bytes)
+}
+
+#[cfg(feature = "sdk-v2")]
+impl From<AccountMeta> for RawAccountMeta {
+    fn from(meta: AccountMeta) -> Self {
+        Self {
+            pubkey: meta.pubkey.to_bytes(),
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        }
+    }
+}
+
+#[cfg(feature = "sdk-v2")]
+impl From<RawAccountMeta> for AccountMeta {
+    fn from(meta: RawAccountMeta) -> Self {
+        Self {
+            pubkey: Pubkey::new_from_array(meta.pubkey),
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        }
+    }
+}
+
+#[cfg(feature = "sdk-v2")]
+impl From<Instruction> for RawInstruction {
+    fn from(instruction: Instruction) -> Self {
+        Self {
+            program_id: instruction.program_id.to_bytes(),
+            accounts: instruction.accounts.into_iter().map(Into::into).collect(),
+            data: instruction.data,
+        }
+    }
+}
+
+#[cfg(feature = "sdk-v2")]
+impl From<RawInstruction> for Instruction {
+    fn from(instruction: RawInstruction) -> Self {
+        Self {
+            program_id: Pubkey::new_from_array(instruction.program_id),
+            accounts: instruction.accounts.into_iter().map(Into::into).collect(),
+            data: instruction.data,
+        }
+    }
+}
+
+/// Generates conversion functions between the client types and the types of a 1.x SDK.
+///
+/// Arguments: name of the generated module and the SDK crate of the caller, `solana_sdk`
+/// or `solana_program`. Both provide `pubkey::Pubkey` and `instruction::{Instruction,
+/// AccountMeta}` with the field layout these functions rely on.
+#[cfg(feature = "sdk-v1")]
+#[macro_export]
+macro_rules! sdk_v1_conversions {
+    ($name:ident, $sdk:ident) => {
+        mod $name {
+            use $crate::compat::{AccountMeta, Instruction, Pubkey};
+
+            pub fn into_pubkey(pubkey: &Pubkey) -> $sdk::pubkey::Pubkey {
+                $sdk::pubkey::Pubkey::new_from_array(pubkey.to_bytes())
+            }
+
+            pub fn from_pubkey(pubkey: &$sdk::pubkey::Pubkey) -> Pubkey {
+                Pubkey::new_from_array(pubkey.to_bytes())
+            }
+
+            pub fn into_instruction(instruction: Instruction) -> $sdk::instruction::Instruction {
+                $sdk::instruction::Instruction {
+                    program_id: into_pubkey(&instruction.program_id),
+                    accounts: instruction
+                        .accounts
+                        .into_iter()
+                        .map(|meta| $sdk::instruction::AccountMeta {
+                            pubkey: into_pubkey(&meta.pubkey),
+                            is_signer: meta.is_signer,
+                            is_writable: meta.is_writable,
+                        })
+                        .collect(),
+                    data: instruction.data,
+                }
+            }
+
+            pub fn from_instruction(instruction: &$sdk::instruction::Instruction) -> Instruction {
+                Instruction {
+                    program_id: from_pubkey(&instruction.program_id),
+                    accounts: instruction
+                        .accounts
+                        .iter()
+                        .map(|meta| AccountMeta {
+                            pubkey: from_pubkey(&meta.pubkey),
+                            is_signer: meta.is_signer,
+                            is_writable: meta.is_writable,
+                        })
+                        .collect(),
+                    data: instruction.data.clone(),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(all(test, any(feature = "sdk-v1", feature = "sdk-v2")))]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "sdk-v2")]
+    #[test]
+    fn test_instruction_round_trip() {
+        let instruction = Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![
+                AccountMeta::new(Pubkey::new_unique(), true),
+                AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            ],
+            data: vec![6, 1, 2, 3],
+        };
+
+        let raw = RawInstruction::from(instruction.clone());
+        assert_eq!(raw.program_id, instruction.program_id.to_bytes());
+        assert!(raw.accounts[0].is_signer && !raw.accounts[1].is_writable);
+        assert_eq!(Instruction::from(raw), instruction);
+        assert_eq!(
+            pubkey(instruction.program_id.to_bytes()),
+            instruction.program_id
+        );
+    }
+
+    // 2.x `solana-sdk` shares the module layout of the 1.x SDKs the macro targets
+    #[cfg(feature = "sdk-v1")]
+    #[test]
+    fn test_sdk_v1_conversions_round_trip() {
+        crate::sdk_v1_conversions!(sdk, solana_sdk);
+
+        let instruction = Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+            data: vec![1, 2],
+        };
+
+        let sdk_instruction = sdk::into_instruction(instruction.clone());
+        assert_eq!(sdk::from_instruction(&sdk_instruction), instruction);
+        assert_eq!(
+            sdk::from_pubkey(&sdk::into_pubkey(&instruction.program_id)),
+            instruction.program_id
+        );
+    }
+}
@@ -0,0 +1,319 @@
+//! Compute budget and priority fee instructions.
+//!
+//! Transfers with many verification CPIs exceed the default 200k compute units of a
+//! transaction. A [`ComputeBudget`] chooses the compute unit limit and the priority fee
+//! of a transaction: fixed, from a simulation of the transaction (limit) or from a
+//! percentile of the fees recently paid for the accounts it writes (fee). The chosen values
+//! are prepended as `ComputeBudget` instructions.
+
+use solana_compute_budget_interface::ComputeBudgetInstruction;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+use solana_transaction_error::TransactionError;
+use thiserror::Error;
+
+/// Highest compute unit limit of a transaction
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Accounts accepted by a prioritization fee query
+const MAX_PRIORITIZATION_FEE_ACCOUNTS: usize = 128;
+
+// `ComputeBudgetInstruction` variant indexes
+const SET_COMPUTE_UNIT_LIMIT: u8 = 2;
+const SET_COMPUTE_UNIT_PRICE: u8 = 3;
+
+/// Compute budget errors
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum ComputeBudgetError {
+    #[error("RPC error: {0}")]
+    Rpc(String),
+    #[error("Simulation failed: {error}")]
+    Simulation {
+        error: TransactionError,
+        logs: Vec<String>,
+    },
+    #[error("Simulation did not report consumed compute units")]
+    MissingUnitsConsumed,
+}
+
+/// Compute unit limit of a transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComputeUnitLimit {
+    Fixed(u32),
+    /// Units consumed by a simulation of the transaction, plus `margin_percent`
+    Simulated {
+        margin_percent: u32,
+    },
+}
+
+/// Priority fee of a transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriorityFee {
+    /// Micro-lamports per compute unit
+    Fixed(u64),
+    /// Percentile (0 to 100) of the fees recently paid for the accounts the transaction writes
+    Percentile(u8),
+}
+
+/// Compute budget instructions to prepend to a transaction, none by default
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComputeBudget {
+    pub unit_limit: Option<ComputeUnitLimit>,
+    pub priority_fee: Option<PriorityFee>,
+}
+
+impl ComputeBudget {
+    pub fn with_unit_limit(mut self, unit_limit: ComputeUnitLimit) -> Self {
+        self.unit_limit = Some(unit_limit);
+        self
+    }
+
+    pub fn with_priority_fee(mut self, priority_fee: PriorityFee) -> Self {
+        self.priority_fee = Some(priority_fee);
+        self
+    }
+}
+
+/// Prepends a compute unit limit and a compute unit price instruction to `instructions`,
+/// replacing the ones they already carry
+pub fn prepend_compute_budget(
+    instructions: Vec<Instruction>,
+    unit_limit: Option<u32>,
+    micro_lamports: Option<u64>,
+) -> Vec<Instruction> {
+    let replaced = |instruction: &Instruction, variant: u8, set: bool| {
+        set && instruction.program_id == solana_compute_budget_interface::ID
+            && instruction.data.first() == Some(&variant)
+    };
+
+    let mut result = Vec::with_capacity(instructions.len() + 2);
+    if let Some(units) = unit_limit {
+        result.push(ComputeBudgetInstruction::set_compute_unit_limit(units));
+    }
+    if let Some(micro_lamports) = micro_lamports {
+        result.push(ComputeBudgetInstruction::set_compute_unit_price(
+            micro_lamports,
+        ));
+    }
+    result.extend(instructions.into_iter().filter(|instruction| {
+        !replaced(instruction, SET_COMPUTE_UNIT_LIMIT, unit_limit.is_some())
+            && !replaced(
+                instruction,
+                SET_COMPUTE_UNIT_PRICE,
+                micro_lamports.is_some(),
+            )
+    }));
+    result
+}
+
+/// Compute unit limit covering `units_consumed` with `margin_percent` to spare
+pub fn simulated_unit_limit(units_consumed: u64, margin_percent: u32) -> u32 {
+    let units = units_consumed.saturating_mul(100 + u64::from(margin_percent)) / 100;
+    u32::try_from(units)
+        .unwrap_or(u32::MAX)
+        .min(MAX_COMPUTE_UNIT_LIMIT)
+}
+
+/// Nearest rank `percentile` of `fees`, zero without fees
+pub fn percentile_fee(fees: &[u64], percentile: u8) -> u64 {
+    let mut fees = fees.to_vec();
+    fees.sort_unstable();
+    let rank = (fees.len() * usize::from(percentile.min(100))).div_ceil(100);
+    fees.get(rank.saturating_sub(1)).copied().unwrap_or(0)
+}
+
+/// Accounts written by `instructions`, the ones their priority fee competes for
+pub fn writable_accounts(instructions: &[Instruction]) -> Vec<Pubkey> {
+    let mut accounts: Vec<Pubkey> = Vec::new();
+    for meta in instructions
+        .iter()
+        .flat_map(|instruction| &instruction.accounts)
+    {
+        if meta.is_writable && !accounts.contains(&meta.pubkey) {
+            accounts.push(meta.pubkey);
+        }
+    }
+    accounts.truncate(MAX_PRIORITIZATION_FEE_ACCOUNTS);
+    accounts
+}
+
+#[cfg(feature = "rpc")]
+mod send {
+    use super::*;
+    use solana_client::rpc_client::RpcClient;
+    use solana_client::rpc_config::RpcSimulateTransactionConfig;
+    use solana_sdk::transaction::Transaction;
+
+    impl ComputeBudget {
+        /// Prepends the compute budget instructions to `instructions` paid by `payer`,
+        /// simulating them or querying recent fees as needed
+        pub fn apply(
+            &self,
+            rpc: &RpcClient,
+            instructions: Vec<Instruction>,
+            payer: &Pubkey,
+        ) -> Result<Vec<Instruction>, ComputeBudgetError> {
+            let micro_lamports = match self.priority_fee {
+                None => None,
+                Some(PriorityFee::Fixed(micro_lamports)) => Some(micro_lamports),
+                Some(PriorityFee::Percentile(percentile)) => {
+                    let fees = rpc
+                        .get_recent_prioritization_fees(&writable_accounts(&instructions))
+                        .map_err(|err| ComputeBudgetError::Rpc(err.to_string()))?;
+                    let fees: Vec<u64> = fees.iter().map(|fee| fee.prioritization_fee).collect();
+                    Some(percentile_fee(&fees, percentile))
+                }
+            };
+
+            let unit_limit = match self.unit_limit {
+                None => None,
+                Some(ComputeUnitLimit::Fixed(units)) => Some(units),
+                Some(ComputeUnitLimit::Simulated { margin_percent }) => {
+                    // Simulate with the budget instructions so their own units are counted
+                    let simulated = prepend_compute_budget(
+                        instructions.clone(),
+                        Some(MAX_COMPUTE_UNIT_LIMIT),
+                        micro_lamports,
+                    );
+                    let transaction = Transaction::new_with_payer(&simulated, Some(payer));
+                    let simulation = rpc
+                        .simulate_transaction_with_config(
+                            &transaction,
+                            RpcSimulateTransactionConfig {
+                                sig_verify: false,
+                                replace_recent_blockhash: true,
+                                ..RpcSimulateTransactionConfig::default()
+                            },
+                        )
+                        .map_err(|err| ComputeBudgetError::Rpc(err.to_string()))?
+                        .value;
+                    if let Some(error) = simulation.err {
+                        return Err(ComputeBudgetError::Simulation {
+                            error,
+                            logs: simulation.logs.unwrap_or_default(),
+                        });
+                    }
+                    let units_consumed = simulation
+                        .units_consumed
+                        .ok_or(ComputeBudgetError::MissingUnitsConsumed)?;
+                    Some(simulated_unit_limit(units_consumed, margin_percent))
+                }
+            };
+
+            Ok(prepend_compute_budget(
+                instructions,
+                unit_limit,
+                micro_lamports,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_instruction::AccountMeta;
+
+    fn instruction(accounts: Vec<AccountMeta>) -> Instruction {
+        Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts,
+            data: vec![1],
+        }
+    }
+
+    #[test]
+    fn test_prepend_compute_budget_replaces_existing_instructions() {
+        let operation = instruction(vec![]);
+        let instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+            ComputeBudgetInstruction::set_compute_unit_price(5),
+            operation.clone(),
+        ];
+
+        assert_eq!(
+            prepend_compute_budget(instructions.clone(), Some(600_000), None),
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(600_000),
+                ComputeBudgetInstruction::set_compute_unit_price(5),
+                operation.clone(),
+            ]
+        );
+        assert_eq!(
+            prepend_compute_budget(instructions.clone(), None, None),
+            instructions
+        );
+    }
+
+    #[test]
+    fn test_simulated_unit_limit() {
+        assert_eq!(simulated_unit_limit(250_000, 10), 275_000);
+        assert_eq!(simulated_unit_limit(1_300_000, 20), MAX_COMPUTE_UNIT_LIMIT);
+        assert_eq!(simulated_unit_limit(u64::MAX, 0), MAX_COMPUTE_UNIT_LIMIT);
+    }
+
+    #[test]
+    fn test_percentile_fee() {
+        let fees = [50, 10, 40, 20, 30];
+        assert_eq!(percentile_fee(&fees, 0), 10);
+        assert_eq!(percentile_fee(&fees, 20), 10);
+        assert_eq!(percentile_fee(&fees, 50), 30);
+        assert_eq!(percentile_fee(&fees, 75), 40);
+        assert_eq!(percentile_fee(&fees, 100), 50);
+        assert_eq!(percentile_fee(&[], 75), 0);
+    }
+
+    #[test]
+    fn test_writable_accounts() {
+        let (writable, readonly) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let instructions = vec![
+            instruction(vec![
+                AccountMeta::new(writable, false),
+                AccountMeta::new_readonly(readonly, false),
+            ]),
+            instruction(vec![AccountMeta::new(writable, true)]),
+        ];
+        assert_eq!(writable_accounts(&instructions), vec![writable]);
+    }
+
+    #[cfg(feature = "rpc")]
+    #[test]
+    fn test_apply_simulated_limit_and_percentile_fee() {
+        use solana_client::rpc_client::RpcClient;
+        use solana_client::rpc_request::RpcRequest;
+        use std::collections::HashMap;
+
+        let mut mocks = HashMap::new();
+        mocks.insert(
+            RpcRequest::SimulateTransaction,
+            serde_json::json!({
+                "context": { "slot": 1 },
+                "value": { "err": null, "logs": [], "unitsConsumed": 300_000 },
+            }),
+        );
+        mocks.insert(
+            RpcRequest::GetRecentPrioritizationFees,
+            serde_json::json!([
+                { "slot": 1, "prioritizationFee": 100 },
+                { "slot": 2, "prioritizationFee": 0 },
+                { "slot": 3, "prioritizationFee": 300 },
+                { "slot": 4, "prioritizationFee": 200 },
+            ]),
+        );
+        let rpc = RpcClient::new_mock_with_mocks("succeeds".to_string(), mocks);
+        let payer = Pubkey::new_unique();
+        let operation = instruction(vec![AccountMeta::new(payer, true)]);
+
+        let budget = ComputeBudget::default()
+            .with_unit_limit(ComputeUnitLimit::Simulated { margin_percent: 10 })
+            .with_priority_fee(PriorityFee::Percentile(75));
+        assert_eq!(
+            budget.apply(&rpc, vec![operation.clone()], &payer).unwrap(),
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(330_000),
+                ComputeBudgetInstruction::set_compute_unit_price(200),
+                operation,
+            ]
+        );
+    }
+}
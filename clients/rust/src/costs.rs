@@ -0,0 +1,325 @@
+//! Account size and rent estimation for configuration changes and corporate actions.
+//!
+//! Sizes mirror the on-chain serialization (`VerificationConfig::serialized_size`,
+//! `Proof::MIN_LEN`, `Receipt::LEN`, `Receipt::CLAIM_LEN`, `ClaimBitmap::size_of` and
+//! `ExtraAccountMetaList::size_of`). Rent is computed with the supplied [`Rent`] so
+//! estimates can use cluster values (`Rent::default()` matches mainnet).
+
+use crate::claim_tracking::CLAIM_BITMAP_PAGE_LEAVES;
+use crate::instructions::TRANSFER_DISCRIMINATOR;
+use solana_rent::Rent;
+
+/// Split/Convert receipt account size: discriminator only
+pub const RECEIPT_ACCOUNT_SIZE: usize = 1;
+/// Claim receipt account size:
+/// discriminator (1) + net amount (8) + withheld amount (8) + jurisdiction (2)
+pub const CLAIM_RECEIPT_ACCOUNT_SIZE: usize = 19;
+/// Verification config size without programs:
+/// discriminator (1) + instruction discriminator (1) + cpi_mode (1) + bump (1) + vector length (4)
+/// + threshold (1) + lookback_window (1) + require_top_level (1)
+pub const VERIFICATION_CONFIG_BASE_SIZE: usize = 11;
+/// Proof account size without nodes: discriminator (1) + bump (1) + vector length (4)
+pub const PROOF_ACCOUNT_BASE_SIZE: usize = 6;
+/// Claim bitmap page size without claimed flags: discriminator (1) + mint (32) + action id (8)
+/// + merkle root (32) + page (4) + leaf count (4) + bump (1) + vector length (4)
+pub const CLAIM_BITMAP_BASE_SIZE: usize = 86;
+/// Extra account metas TLV header (8) + pod slice length (4)
+pub const EXTRA_ACCOUNT_METAS_BASE_SIZE: usize = 16;
+/// Size of a single `ExtraAccountMeta`
+pub const EXTRA_ACCOUNT_META_SIZE: usize = 35;
+
+/// Account affected by an operation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CostedAccount {
+    VerificationConfig,
+    /// Transfer hook extra account metas (only for the Transfer config)
+    ExtraAccountMetas,
+    Proof,
+    Receipt,
+    ClaimBitmap,
+}
+
+/// Size and rent change of a group of accounts
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountCostChange {
+    pub account: CostedAccount,
+    /// Number of accounts of this kind affected
+    pub count: u64,
+    /// Size of a single account before the operation (0 if it doesn't exist)
+    pub old_size: usize,
+    /// Size of a single account after the operation (0 if it is closed)
+    pub new_size: usize,
+    /// Lamports paid (positive) or recovered (negative) for all accounts
+    pub rent_delta: i128,
+}
+
+/// Cost estimate of an operation
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CostEstimate {
+    pub changes: Vec<AccountCostChange>,
+}
+
+impl CostEstimate {
+    /// Lamports the payer has to fund (negative if rent is recovered)
+    pub fn total_rent_delta(&self) -> i128 {
+        self.changes.iter().map(|change| change.rent_delta).sum()
+    }
+
+    fn push(
+        &mut self,
+        rent: &Rent,
+        account: CostedAccount,
+        count: u64,
+        old_size: usize,
+        new_size: usize,
+    ) {
+        let balance = |size: usize| {
+            if size == 0 {
+                0
+            } else {
+                rent.minimum_balance(size) as i128
+            }
+        };
+        self.changes.push(AccountCostChange {
+            account,
+            count,
+            old_size,
+            new_size,
+            rent_delta: (balance(new_size) - balance(old_size)) * count as i128,
+        });
+    }
+}
+
+pub fn verification_config_size(program_count: usize) -> usize {
+    VERIFICATION_CONFIG_BASE_SIZE + program_count * 32
+}
+
+/// Extra account metas hold the verification config followed by every verification program
+pub fn extra_account_metas_size(program_count: usize) -> usize {
+    EXTRA_ACCOUNT_METAS_BASE_SIZE + (program_count + 1) * EXTRA_ACCOUNT_META_SIZE
+}
+
+pub fn proof_account_size(proof_len: usize) -> usize {
+    PROOF_ACCOUNT_BASE_SIZE + proof_len * 32
+}
+
+/// Proof length of a distribution tree with `holders` leaves
+pub fn distribution_proof_len(holders: u64) -> usize {
+    if holders <= 1 {
+        0
+    } else {
+        (u64::BITS - (holders - 1).leading_zeros()) as usize
+    }
+}
+
+pub fn estimate_initialize_verification_config(
+    rent: &Rent,
+    instruction_discriminator: u8,
+    program_count: usize,
+) -> CostEstimate {
+    let mut estimate = CostEstimate::default();
+    estimate.push(
+        rent,
+        CostedAccount::VerificationConfig,
+        1,
+        0,
+        verification_config_size(program_count),
+    );
+    if instruction_discriminator == TRANSFER_DISCRIMINATOR {
+        estimate.push(
+            rent,
+            CostedAccount::ExtraAccountMetas,
+            1,
+            0,
+            extra_account_metas_size(program_count),
+        );
+    }
+    estimate
+}
+
+/// Update writes `new_program_count` programs at `offset`; the config never shrinks
+pub fn estimate_update_verification_config(
+    rent: &Rent,
+    instruction_discriminator: u8,
+    current_program_count: usize,
+    offset: usize,
+    new_program_count: usize,
+) -> CostEstimate {
+    let program_count = current_program_count.max(offset + new_program_count);
+    let mut estimate = CostEstimate::default();
+    estimate.push(
+        rent,
+        CostedAccount::VerificationConfig,
+        1,
+        verification_config_size(current_program_count),
+        verification_config_size(program_count),
+    );
+    if instruction_discriminator == TRANSFER_DISCRIMINATOR {
+        estimate.push(
+            rent,
+            CostedAccount::ExtraAccountMetas,
+            1,
+            extra_account_metas_size(current_program_count),
+            extra_account_metas_size(program_count),
+        );
+    }
+    estimate
+}
+
+/// Trim truncates the program list to `new_size`, or closes the config when `close` is set
+pub fn estimate_trim_verification_config(
+    rent: &Rent,
+    instruction_discriminator: u8,
+    current_program_count: usize,
+    new_size: usize,
+    close: bool,
+) -> CostEstimate {
+    let program_count = if close {
+        0
+    } else {
+        new_size.min(current_program_count)
+    };
+    let mut estimate = CostEstimate::default();
+    estimate.push(
+        rent,
+        CostedAccount::VerificationConfig,
+        1,
+        verification_config_size(current_program_count),
+        if close {
+            0
+        } else {
+            verification_config_size(program_count)
+        },
+    );
+    if instruction_discriminator == TRANSFER_DISCRIMINATOR {
+        estimate.push(
+            rent,
+            CostedAccount::ExtraAccountMetas,
+            1,
+            extra_account_metas_size(current_program_count),
+            extra_account_metas_size(program_count),
+        );
+    }
+    estimate
+}
+
+/// Receipts (and optionally proof accounts) created when `holders` claim a distribution
+pub fn estimate_distribution_claims(
+    rent: &Rent,
+    holders: u64,
+    with_proof_accounts: bool,
+) -> CostEstimate {
+    let mut estimate = CostEstimate::default();
+    if with_proof_accounts {
+        estimate.push(
+            rent,
+            CostedAccount::Proof,
+            holders,
+            0,
+            proof_account_size(distribution_proof_len(holders)),
+        );
+    }
+    estimate.push(
+        rent,
+        CostedAccount::Receipt,
+        holders,
+        0,
+        CLAIM_RECEIPT_ACCOUNT_SIZE,
+    );
+    estimate
+}
+
+/// Size of the claim bitmap page tracking `leaf_count` leaves
+pub fn claim_bitmap_size(leaf_count: u64) -> usize {
+    CLAIM_BITMAP_BASE_SIZE + leaf_count.div_ceil(8) as usize
+}
+
+/// Claim bitmap pages created with the escrow of a distribution to `holders`,
+/// replacing the receipts of [`estimate_distribution_claims`]
+pub fn estimate_claim_bitmap(rent: &Rent, holders: u64) -> CostEstimate {
+    let mut estimate = CostEstimate::default();
+    let page_leaves = CLAIM_BITMAP_PAGE_LEAVES as u64;
+    let full_pages = holders / page_leaves;
+    if full_pages > 0 {
+        estimate.push(
+            rent,
+            CostedAccount::ClaimBitmap,
+            full_pages,
+            0,
+            claim_bitmap_size(page_leaves),
+        );
+    }
+    if holders % page_leaves > 0 {
+        estimate.push(
+            rent,
+            CostedAccount::ClaimBitmap,
+            1,
+            0,
+            claim_bitmap_size(holders % page_leaves),
+        );
+    }
+    estimate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle::{DistributionLeaf, DistributionTree};
+    use solana_pubkey::Pubkey;
+
+    #[test]
+    fn test_distribution_proof_len_matches_tree() {
+        for holders in 1..=40u64 {
+            let leaves = (0..holders)
+                .map(|_| DistributionLeaf::new(Pubkey::new_unique(), 1))
+                .collect();
+            let tree = DistributionTree::new(Pubkey::new_unique(), 1, leaves);
+            for idx in 0..holders as usize {
+                assert_eq!(tree.proof(idx).len(), distribution_proof_len(holders));
+            }
+        }
+    }
+
+    #[test]
+    fn test_verification_config_estimates() {
+        let rent = Rent::default();
+        let init = estimate_initialize_verification_config(&rent, TRANSFER_DISCRIMINATOR, 2);
+        assert_eq!(init.changes.len(), 2);
+        assert_eq!(init.changes[0].new_size, 75);
+        assert_eq!(init.changes[1].new_size, 16 + 3 * 35);
+        assert_eq!(
+            init.total_rent_delta(),
+            (rent.minimum_balance(75) + rent.minimum_balance(121)) as i128
+        );
+
+        let update = estimate_update_verification_config(&rent, 6, 2, 1, 3);
+        assert_eq!(update.changes.len(), 1);
+        assert_eq!(update.changes[0].new_size, verification_config_size(4));
+        assert!(update.total_rent_delta() > 0);
+
+        let trim = estimate_trim_verification_config(&rent, 6, 4, 1, false);
+        assert_eq!(
+            trim.total_rent_delta(),
+            rent.minimum_balance(43) as i128 - rent.minimum_balance(139) as i128
+        );
+
+        let close = estimate_trim_verification_config(&rent, 6, 4, 0, true);
+        assert_eq!(
+            close.total_rent_delta(),
+            -(rent.minimum_balance(139) as i128)
+        );
+    }
+
+    #[test]
+    fn test_claim_bitmap_estimate() {
+        let rent = Rent::default();
+        let bitmap = estimate_claim_bitmap(&rent, 100_000);
+        assert_eq!(bitmap.changes.len(), 2);
+        assert_eq!(bitmap.changes[0].new_size, 86 + 8_192);
+        assert_eq!(bitmap.changes[1].new_size, 86 + 4_308);
+
+        let receipts = estimate_distribution_claims(&rent, 100_000, false);
+        assert!(bitmap.total_rent_delta() * 100 < receipts.total_rent_delta());
+        assert!(estimate_claim_bitmap(&rent, 0).changes.is_empty());
+    }
+}
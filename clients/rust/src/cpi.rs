@@ -0,0 +1,382 @@
+//! Anchor CPI helpers
+//!
+//! Accounts structs and cpi functions mirroring the `anchor_spl` modules, so Anchor programs
+//! invoke the Security Token program with a `CpiContext`. The verification programs of configs
+//! in CPI mode, followed by the ConfigTemplate of linked configs, are passed as the context
+//! remaining accounts. Instructions the emergency authority can halt take the ProgramConfig
+//! PDA, passed to the program after the remaining accounts.
+
+use anchor_lang::prelude::*;
+
+use crate::instructions::{
+    BurnCpi, BurnCpiAccounts, BurnInstructionArgs, FreezeCpi, FreezeCpiAccounts,
+    FreezeInstructionArgs, MintCpi, MintCpiAccounts, MintInstructionArgs, PauseCpi,
+    PauseCpiAccounts, ResumeCpi, ResumeCpiAccounts, ThawCpi, ThawCpiAccounts, TransferCpi,
+    TransferCpiAccounts, TransferInstructionArgs, VerifyCpi, VerifyCpiAccounts,
+    VerifyInstructionArgs,
+};
+use crate::types::VerifyArgs;
+
+pub fn verify<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, Verify<'info>>,
+    ix: u8,
+    instruction_data: Vec<u8>,
+) -> Result<()> {
+    VerifyCpi::new(
+        &ctx.program,
+        VerifyCpiAccounts {
+            mint: &ctx.accounts.mint,
+            verification_config: &ctx.accounts.verification_config,
+            instructions_sysvar: &ctx.accounts.instructions_sysvar,
+        },
+        VerifyInstructionArgs {
+            verify_args: VerifyArgs {
+                ix,
+                instruction_data,
+            },
+        },
+    )
+    .invoke_signed_with_remaining_accounts(
+        ctx.signer_seeds,
+        &remaining_accounts(&ctx.remaining_accounts),
+    )
+    .map_err(Into::into)
+}
+
+pub fn mint<'info>(ctx: CpiContext<'_, '_, '_, 'info, Mint<'info>>, amount: u64) -> Result<()> {
+    MintCpi::new(
+        &ctx.program,
+        MintCpiAccounts {
+            mint: &ctx.accounts.mint,
+            verification_config: &ctx.accounts.verification_config,
+            instructions_sysvar: &ctx.accounts.instructions_sysvar,
+            mint_authority: &ctx.accounts.mint_authority,
+            mint_account: &ctx.accounts.mint_account,
+            destination: &ctx.accounts.destination,
+            token_program: &ctx.accounts.token_program,
+            lot_payer: ctx.accounts.lot_payer.as_ref(),
+            holding_period_account: ctx.accounts.holding_period_account.as_ref(),
+            restricted_holding_account: ctx.accounts.restricted_holding_account.as_ref(),
+            holding_lot_account: ctx.accounts.holding_lot_account.as_ref(),
+            system_program: ctx.accounts.system_program.as_ref(),
+        },
+        MintInstructionArgs { amount },
+    )
+    .invoke_signed_with_remaining_accounts(
+        ctx.signer_seeds,
+        &haltable_remaining_accounts(&ctx.remaining_accounts, &ctx.accounts.program_config),
+    )
+    .map_err(Into::into)
+}
+
+pub fn burn<'info>(ctx: CpiContext<'_, '_, '_, 'info, Burn<'info>>, amount: u64) -> Result<()> {
+    BurnCpi::new(
+        &ctx.program,
+        BurnCpiAccounts {
+            mint: &ctx.accounts.mint,
+            verification_config: &ctx.accounts.verification_config,
+            instructions_sysvar: &ctx.accounts.instructions_sysvar,
+            permanent_delegate: &ctx.accounts.permanent_delegate,
+            mint_account: &ctx.accounts.mint_account,
+            token_account: &ctx.accounts.token_account,
+            token_program: &ctx.accounts.token_program,
+        },
+        BurnInstructionArgs { amount },
+    )
+    .invoke_signed_with_remaining_accounts(
+        ctx.signer_seeds,
+        &haltable_remaining_accounts(&ctx.remaining_accounts, &ctx.accounts.program_config),
+    )
+    .map_err(Into::into)
+}
+
+pub fn pause<'info>(ctx: CpiContext<'_, '_, '_, 'info, Pause<'info>>) -> Result<()> {
+    PauseCpi::new(
+        &ctx.program,
+        PauseCpiAccounts {
+            mint: &ctx.accounts.mint,
+            verification_config: &ctx.accounts.verification_config,
+            instructions_sysvar: &ctx.accounts.instructions_sysvar,
+            pause_authority: &ctx.accounts.pause_authority,
+            mint_account: &ctx.accounts.mint_account,
+            token_program: &ctx.accounts.token_program,
+        },
+    )
+    .invoke_signed_with_remaining_accounts(
+        ctx.signer_seeds,
+        &haltable_remaining_accounts(&ctx.remaining_accounts, &ctx.accounts.program_config),
+    )
+    .map_err(Into::into)
+}
+
+pub fn resume<'info>(ctx: CpiContext<'_, '_, '_, 'info, Resume<'info>>) -> Result<()> {
+    ResumeCpi::new(
+        &ctx.program,
+        ResumeCpiAccounts {
+            mint: &ctx.accounts.mint,
+            verification_config: &ctx.accounts.verification_config,
+            instructions_sysvar: &ctx.accounts.instructions_sysvar,
+            pause_authority: &ctx.accounts.pause_authority,
+            mint_account: &ctx.accounts.mint_account,
+            token_program: &ctx.accounts.token_program,
+        },
+    )
+    .invoke_signed_with_remaining_accounts(
+        ctx.signer_seeds,
+        &haltable_remaining_accounts(&ctx.remaining_accounts, &ctx.accounts.program_config),
+    )
+    .map_err(Into::into)
+}
+
+pub fn freeze<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, Freeze<'info>>,
+    auto_thaw_at: Option<i64>,
+) -> Result<()> {
+    FreezeCpi::new(
+        &ctx.program,
+        FreezeCpiAccounts {
+            mint: &ctx.accounts.mint,
+            verification_config: &ctx.accounts.verification_config,
+            instructions_sysvar: &ctx.accounts.instructions_sysvar,
+            freeze_authority: &ctx.accounts.freeze_authority,
+            mint_account: &ctx.accounts.mint_account,
+            token_account: &ctx.accounts.token_account,
+            token_program: &ctx.accounts.token_program,
+            payer: ctx.accounts.payer.as_ref(),
+            freeze_expiry_account: ctx.accounts.freeze_expiry_account.as_ref(),
+            system_program: ctx.accounts.system_program.as_ref(),
+        },
+        FreezeInstructionArgs { auto_thaw_at },
+    )
+    .invoke_signed_with_remaining_accounts(
+        ctx.signer_seeds,
+        &haltable_remaining_accounts(&ctx.remaining_accounts, &ctx.accounts.program_config),
+    )
+    .map_err(Into::into)
+}
+
+pub fn thaw<'info>(ctx: CpiContext<'_, '_, '_, 'info, Thaw<'info>>) -> Result<()> {
+    ThawCpi::new(
+        &ctx.program,
+        ThawCpiAccounts {
+            mint: &ctx.accounts.mint,
+            verification_config: &ctx.accounts.verification_config,
+            instructions_sysvar: &ctx.accounts.instructions_sysvar,
+            freeze_authority: &ctx.accounts.freeze_authority,
+            mint_account: &ctx.accounts.mint_account,
+            token_account: &ctx.accounts.token_account,
+            token_program: &ctx.accounts.token_program,
+            freeze_expiry_account: ctx.accounts.freeze_expiry_account.as_ref(),
+            rent_destination: ctx.accounts.rent_destination.as_ref(),
+        },
+    )
+    .invoke_signed_with_remaining_accounts(
+        ctx.signer_seeds,
+        &haltable_remaining_accounts(&ctx.remaining_accounts, &ctx.accounts.program_config),
+    )
+    .map_err(Into::into)
+}
+
+pub fn transfer<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, Transfer<'info>>,
+    amount: u64,
+    memo_hash: Option<[u8; 32]>,
+    memo: Option<String>,
+) -> Result<()> {
+    TransferCpi::new(
+        &ctx.program,
+        TransferCpiAccounts {
+            mint: &ctx.accounts.mint,
+            verification_config: &ctx.accounts.verification_config,
+            instructions_sysvar: &ctx.accounts.instructions_sysvar,
+            permanent_delegate_authority: &ctx.accounts.permanent_delegate_authority,
+            mint_account: &ctx.accounts.mint_account,
+            from_token_account: &ctx.accounts.from_token_account,
+            to_token_account: &ctx.accounts.to_token_account,
+            transfer_hook_program: &ctx.accounts.transfer_hook_program,
+            token_program: &ctx.accounts.token_program,
+            lot_payer: ctx.accounts.lot_payer.as_ref(),
+            holding_period_account: ctx.accounts.holding_period_account.as_ref(),
+            restricted_holding_account: ctx.accounts.restricted_holding_account.as_ref(),
+            holding_lot_account: ctx.accounts.holding_lot_account.as_ref(),
+            system_program: ctx.accounts.system_program.as_ref(),
+            memo_program: ctx.accounts.memo_program.as_ref(),
+        },
+        TransferInstructionArgs {
+            amount,
+            memo_hash,
+            memo,
+        },
+    )
+    .invoke_signed_with_remaining_accounts(
+        ctx.signer_seeds,
+        &haltable_remaining_accounts(&ctx.remaining_accounts, &ctx.accounts.program_config),
+    )
+    .map_err(Into::into)
+}
+
+/// Remaining accounts keep the signer and writable flags they were passed with
+fn remaining_accounts<'a, 'b>(
+    accounts: &'b [AccountInfo<'a>],
+) -> Vec<(&'b AccountInfo<'a>, bool, bool)> {
+    accounts
+        .iter()
+        .map(|account| (account, account.is_writable, account.is_signer))
+        .collect()
+}
+
+/// Remaining accounts of a haltable instruction, followed by the ProgramConfig
+fn haltable_remaining_accounts<'a, 'b>(
+    accounts: &'b [AccountInfo<'a>],
+    program_config: &'b AccountInfo<'a>,
+) -> Vec<(&'b AccountInfo<'a>, bool, bool)> {
+    let mut remaining = remaining_accounts(accounts);
+    remaining.push((program_config, false, false));
+    remaining
+}
+
+#[derive(Accounts)]
+pub struct Verify<'info> {
+    pub mint: AccountInfo<'info>,
+    pub verification_config: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Mint<'info> {
+    pub mint: AccountInfo<'info>,
+    pub verification_config: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub mint_authority: AccountInfo<'info>,
+    pub mint_account: AccountInfo<'info>,
+    pub destination: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub lot_payer: Option<AccountInfo<'info>>,
+    pub holding_period_account: Option<AccountInfo<'info>>,
+    pub restricted_holding_account: Option<AccountInfo<'info>>,
+    pub holding_lot_account: Option<AccountInfo<'info>>,
+    pub system_program: Option<AccountInfo<'info>>,
+    pub program_config: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Burn<'info> {
+    pub mint: AccountInfo<'info>,
+    pub verification_config: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub permanent_delegate: AccountInfo<'info>,
+    pub mint_account: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub program_config: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    pub mint: AccountInfo<'info>,
+    pub verification_config: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub pause_authority: AccountInfo<'info>,
+    pub mint_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub program_config: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Resume<'info> {
+    pub mint: AccountInfo<'info>,
+    pub verification_config: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub pause_authority: AccountInfo<'info>,
+    pub mint_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub program_config: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Freeze<'info> {
+    pub mint: AccountInfo<'info>,
+    pub verification_config: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub freeze_authority: AccountInfo<'info>,
+    pub mint_account: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub payer: Option<AccountInfo<'info>>,
+    pub freeze_expiry_account: Option<AccountInfo<'info>>,
+    pub system_program: Option<AccountInfo<'info>>,
+    pub program_config: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Thaw<'info> {
+    pub mint: AccountInfo<'info>,
+    pub verification_config: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub freeze_authority: AccountInfo<'info>,
+    pub mint_account: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub freeze_expiry_account: Option<AccountInfo<'info>>,
+    pub rent_destination: Option<AccountInfo<'info>>,
+    pub program_config: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Transfer<'info> {
+    pub mint: AccountInfo<'info>,
+    pub verification_config: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub permanent_delegate_authority: AccountInfo<'info>,
+    pub mint_account: AccountInfo<'info>,
+    pub from_token_account: AccountInfo<'info>,
+    pub to_token_account: AccountInfo<'info>,
+    pub transfer_hook_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub lot_payer: Option<AccountInfo<'info>>,
+    pub holding_period_account: Option<AccountInfo<'info>>,
+    pub restricted_holding_account: Option<AccountInfo<'info>>,
+    pub holding_lot_account: Option<AccountInfo<'info>>,
+    pub system_program: Option<AccountInfo<'info>>,
+    pub memo_program: Option<AccountInfo<'info>>,
+    pub program_config: AccountInfo<'info>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remaining_accounts_keep_flags() {
+        let (program, signer) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (mut program_lamports, mut signer_lamports) = (0, 0);
+        let (mut program_data, mut signer_data) = (vec![], vec![]);
+        let accounts = [
+            AccountInfo::new(
+                &program,
+                false,
+                false,
+                &mut program_lamports,
+                &mut program_data,
+                &program,
+                true,
+                0,
+            ),
+            AccountInfo::new(
+                &signer,
+                true,
+                true,
+                &mut signer_lamports,
+                &mut signer_data,
+                &program,
+                false,
+                0,
+            ),
+        ];
+
+        let remaining = remaining_accounts(&accounts);
+        assert_eq!(remaining.len(), 2);
+        assert_eq!((remaining[0].1, remaining[0].2), (false, false));
+        assert_eq!((remaining[1].1, remaining[1].2), (true, true));
+    }
+}
@@ -0,0 +1,931 @@
+//! Instruction decoder for explorers and indexers.
+//!
+//! [`decode_instruction`] maps the data and accounts of a security token instruction back
+//! to its typed arguments and named accounts. Account names and order follow the IDL;
+//! optional accounts are in brackets in the table below. Builders pass the program id in
+//! place of an omitted optional account, such accounts decode to `None`.
+
+use crate::instructions::*;
+use crate::programs::SECURITY_TOKEN_PROGRAM_ID;
+use borsh::BorshDeserialize;
+use security_token_core::SecurityTokenInstructionDiscriminators;
+use solana_instruction::AccountMeta;
+use thiserror::Error;
+
+/// Decoder errors
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum DecodeError {
+    #[error("Instruction data is empty")]
+    EmptyData,
+    #[error("Unknown instruction discriminator {0}")]
+    UnknownDiscriminator(u8),
+    #[error("Invalid {instruction:?} arguments: {reason}")]
+    InvalidArgs {
+        instruction: SecurityTokenInstructionDiscriminators,
+        reason: String,
+    },
+    #[error("{instruction:?} is missing the {name} account")]
+    MissingAccount {
+        instruction: SecurityTokenInstructionDiscriminators,
+        name: &'static str,
+    },
+}
+
+/// Account of an instruction as declared in the IDL
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InstructionAccount {
+    pub name: &'static str,
+    pub optional: bool,
+}
+
+/// Named account of a decoded instruction, `meta` is `None` for an omitted optional account
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedInstructionAccount {
+    pub name: &'static str,
+    pub meta: Option<AccountMeta>,
+}
+
+/// Decoded security token instruction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecurityTokenInstructionDecoded {
+    pub instruction: SecurityTokenInstructionDiscriminators,
+    pub args: SecurityTokenInstructionArgs,
+    /// Accounts declared by the instruction, in instruction order
+    pub accounts: Vec<DecodedInstructionAccount>,
+    /// Accounts passed after the declared ones, e.g. verification programs in CPI mode
+    pub remaining_accounts: Vec<AccountMeta>,
+}
+
+impl SecurityTokenInstructionDecoded {
+    /// Account named `name`, `None` if it is unknown or omitted
+    pub fn account(&self, name: &str) -> Option<&AccountMeta> {
+        self.accounts
+            .iter()
+            .find(|account| account.name == name)
+            .and_then(|account| account.meta.as_ref())
+    }
+}
+
+/// Decodes the `data` and `accounts` of a security token instruction
+pub fn decode_instruction(
+    data: &[u8],
+    accounts: &[AccountMeta],
+) -> Result<SecurityTokenInstructionDecoded, DecodeError> {
+    let (&discriminator, args) = data.split_first().ok_or(DecodeError::EmptyData)?;
+    let instruction = SecurityTokenInstructionDiscriminators::from_u8(discriminator)
+        .ok_or(DecodeError::UnknownDiscriminator(discriminator))?;
+    let args = decode_args(instruction, args).map_err(|err| DecodeError::InvalidArgs {
+        instruction,
+        reason: err.to_string(),
+    })?;
+
+    let declared = instruction_accounts(instruction);
+    let mut named = Vec::with_capacity(declared.len());
+    for (index, account) in declared.iter().enumerate() {
+        let meta = match accounts.get(index) {
+            Some(meta) if account.optional && meta.pubkey == SECURITY_TOKEN_PROGRAM_ID => None,
+            Some(meta) => Some(meta.clone()),
+            None if account.optional => None,
+            None => {
+                return Err(DecodeError::MissingAccount {
+                    instruction,
+                    name: account.name,
+                })
+            }
+        };
+        named.push(DecodedInstructionAccount {
+            name: account.name,
+            meta,
+        });
+    }
+
+    Ok(SecurityTokenInstructionDecoded {
+        instruction,
+        args,
+        accounts: named,
+        remaining_accounts: accounts.get(declared.len()..).unwrap_or_default().to_vec(),
+    })
+}
+
+macro_rules! instruction_account {
+    ([$name:ident]) => {
+        InstructionAccount {
+            name: stringify!($name),
+            optional: true,
+        }
+    };
+    ($name:ident) => {
+        InstructionAccount {
+            name: stringify!($name),
+            optional: false,
+        }
+    };
+}
+
+macro_rules! instruction_args {
+    ($name:ident ($args:ty), $data:expr) => {
+        <$args>::try_from_slice($data).map(SecurityTokenInstructionArgs::$name)
+    };
+    ($name:ident, $data:expr) => {
+        if $data.is_empty() {
+            Ok(SecurityTokenInstructionArgs::$name)
+        } else {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "instruction takes no arguments",
+            ))
+        }
+    };
+}
+
+macro_rules! instruction_decoder {
+    ($($name:ident $(($args:ty))? { $($account:tt),* $(,)? },)*) => {
+        /// Typed arguments of a decoded instruction, one variant per instruction
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum SecurityTokenInstructionArgs {
+            $($name $(($args))?,)*
+        }
+
+        /// Accounts declared by `instruction`, in instruction order
+        pub fn instruction_accounts(
+            instruction: SecurityTokenInstructionDiscriminators,
+        ) -> &'static [InstructionAccount] {
+            match instruction {
+                $(SecurityTokenInstructionDiscriminators::$name => {
+                    &[$(instruction_account!($account)),*]
+                })*
+            }
+        }
+
+        fn decode_args(
+            instruction: SecurityTokenInstructionDiscriminators,
+            data: &[u8],
+        ) -> Result<SecurityTokenInstructionArgs, std::io::Error> {
+            match instruction {
+                $(SecurityTokenInstructionDiscriminators::$name => {
+                    instruction_args!($name $(($args))?, data)
+                })*
+            }
+        }
+    };
+}
+
+instruction_decoder! {
+    InitializeMint(InitializeMintInstructionArgs) {
+        mint, authority, payer, token_program, system_program, rent_sysvar, [creator],
+        [external_metadata_account], [metadata_program]
+    },
+    UpdateMetadata(UpdateMetadataInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_authority, payer, mint_account, token_program, system_program,
+        [metadata_schema_account], [external_metadata_account], [metadata_program]
+    },
+    InitializeVerificationConfig(InitializeVerificationConfigInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, config_account, system_program, [account_metas_pda],
+        [transfer_hook_pda], [transfer_hook_program]
+    },
+    UpdateVerificationConfig(UpdateVerificationConfigInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, config_account, system_program, [account_metas_pda],
+        [transfer_hook_pda], [transfer_hook_program], [pending_config_account]
+    },
+    TrimVerificationConfig(TrimVerificationConfigInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, config_account, rent_recipient, system_program, [account_metas_pda],
+        [transfer_hook_pda], [transfer_hook_program]
+    },
+    Verify(VerifyInstructionArgs) {
+        mint, verification_config, instructions_sysvar
+    },
+    Mint(MintInstructionArgs) {
+        mint, verification_config, instructions_sysvar, mint_authority, mint_account,
+        destination, token_program, [lot_payer], [holding_period_account],
+        [restricted_holding_account], [holding_lot_account], [system_program]
+    },
+    Burn(BurnInstructionArgs) {
+        mint, verification_config, instructions_sysvar, permanent_delegate, mint_account,
+        token_account, token_program
+    },
+    Pause {
+        mint, verification_config, instructions_sysvar, pause_authority, mint_account,
+        token_program
+    },
+    Resume {
+        mint, verification_config, instructions_sysvar, pause_authority, mint_account,
+        token_program
+    },
+    Freeze(FreezeInstructionArgs) {
+        mint, verification_config, instructions_sysvar, freeze_authority, mint_account,
+        token_account, token_program, [payer], [freeze_expiry_account], [system_program]
+    },
+    Thaw {
+        mint, verification_config, instructions_sysvar, freeze_authority, mint_account,
+        token_account, token_program, [freeze_expiry_account], [rent_destination]
+    },
+    Transfer(TransferInstructionArgs) {
+        mint, verification_config, instructions_sysvar, permanent_delegate_authority,
+        mint_account, from_token_account, to_token_account, transfer_hook_program,
+        token_program, [lot_payer], [holding_period_account], [restricted_holding_account],
+        [holding_lot_account], [system_program], [memo_program]
+    },
+    CreateRateAccount(CreateRateAccountInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        rate_account, mint_from, mint_to, system_program
+    },
+    UpdateRateAccount(UpdateRateAccountInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        rate_account, mint_from, mint_to
+    },
+    CloseRateAccount(CloseRateAccountInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        rate_account, rent_recipient, mint_from, mint_to
+    },
+    Split(SplitInstructionArgs) {
+        mint, verification_config, instructions_sysvar, mint_authority, permanent_delegate,
+        payer, mint_account, token_account, rate_account, receipt_account, token_program,
+        system_program
+    },
+    Convert(ConvertInstructionArgs) {
+        mint, verification_config, instructions_sysvar, mint_authority, permanent_delegate,
+        payer, mint_from, mint_to, token_account_from, token_account_to, rate_account,
+        receipt_account, token_program, system_program, [oracle]
+    },
+    CreateProofAccount(CreateProofAccountInstructionArgs) {
+        mint, verification_config, instructions_sysvar, payer, mint_account, proof_account,
+        token_account, system_program
+    },
+    UpdateProofAccount(UpdateProofAccountInstructionArgs) {
+        mint, verification_config, instructions_sysvar, payer, mint_account, proof_account,
+        token_account, system_program
+    },
+    CreateDistributionEscrow(CreateDistributionEscrowInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        distribution_escrow_authority, payer, distribution_token_account, distribution_mint,
+        token_program, associated_token_account_program, system_program,
+        [claim_bitmap_account]
+    },
+    ClaimDistribution(ClaimDistributionInstructionArgs) {
+        mint, verification_config, instructions_sysvar, permanent_delegate_authority, payer,
+        mint_account, eligible_token_account, [escrow_token_account], receipt_account,
+        [proof_account], transfer_hook_program, token_program, system_program,
+        [withholding_rate_account], [tax_escrow_token_account],
+        [distribution_payout_account], [payout_mint], [payout_escrow_token_account],
+        [payout_token_account], [payout_token_program], [custodian],
+        [instructions_sysvar_account], claim_deadline_account, distribution_root_account,
+        claim_bitmap_account
+    },
+    CloseActionReceiptAccount(CloseActionReceiptAccountInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        receipt_account, rent_recipient, mint_account
+    },
+    CloseClaimReceiptAccount(CloseClaimReceiptAccountInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        receipt_account, rent_recipient, mint_account, eligible_token_account,
+        [proof_account]
+    },
+    CreateWrapVault {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, underlying_mint, wrap_vault, vault_token_account,
+        underlying_token_program, associated_token_account_program, system_program
+    },
+    WrapToken(WrapTokenInstructionArgs) {
+        mint, verification_config, instructions_sysvar, mint_authority, wrap_vault, owner,
+        mint_account, underlying_mint, underlying_token_account, vault_token_account,
+        token_account, underlying_token_program, token_program
+    },
+    UnwrapToken(UnwrapTokenInstructionArgs) {
+        mint, verification_config, instructions_sysvar, permanent_delegate, wrap_vault,
+        owner, mint_account, underlying_mint, token_account, vault_token_account,
+        underlying_token_account, underlying_token_program, token_program,
+        permanent_delegate_config_account
+    },
+    CreateAgentAccount(CreateAgentAccountInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, agent_account, agent, system_program
+    },
+    UpdateAgentAccount(UpdateAgentAccountInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, agent_account
+    },
+    CloseAgentAccount {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, agent_account, rent_recipient
+    },
+    CreateIdentityAccount(CreateIdentityAccountInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, identity_account, system_program
+    },
+    AddIdentityWallet {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, identity_account, token_account, identity_wallet_account,
+        system_program
+    },
+    RemoveIdentityWallet {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        rent_recipient, mint_account, identity_account, token_account,
+        identity_wallet_account
+    },
+    CloseIdentityAccount {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, identity_account, rent_recipient
+    },
+    CreatePositionLimit(CreatePositionLimitInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, position_limit_account, transfer_verification_config, system_program,
+        account_metas_pda, transfer_hook_pda, transfer_hook_program
+    },
+    UpdatePositionLimit(UpdatePositionLimitInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, position_limit_account
+    },
+    ClosePositionLimit {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, position_limit_account, transfer_verification_config, rent_recipient,
+        system_program, account_metas_pda, transfer_hook_pda, transfer_hook_program
+    },
+    CreateTransferAcceptance {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, transfer_acceptance_account, transfer_verification_config,
+        escrow_authority, escrow_token_account, system_program, token_program,
+        associated_token_program, account_metas_pda, transfer_hook_pda,
+        transfer_hook_program
+    },
+    CloseTransferAcceptance {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, transfer_acceptance_account, transfer_verification_config,
+        rent_recipient, system_program, account_metas_pda, transfer_hook_pda,
+        transfer_hook_program
+    },
+    OfferTransfer(OfferTransferInstructionArgs) {
+        mint, verification_config, instructions_sysvar, payer, owner, mint_account,
+        source_token_account, destination_token_account, pending_transfer_account,
+        escrow_token_account, permanent_delegate, transfer_hook_program, token_program,
+        system_program, source_suspension, destination_suspension
+    },
+    AcceptTransfer {
+        mint, verification_config, instructions_sysvar, owner, mint_account,
+        pending_transfer_account, destination_token_account, escrow_token_account,
+        rent_destination, permanent_delegate, transfer_hook_program, token_program,
+        destination_suspension
+    },
+    CancelTransfer {
+        mint, verification_config, instructions_sysvar, authority, mint_account,
+        pending_transfer_account, source_token_account, destination_token_account,
+        escrow_token_account, rent_destination, permanent_delegate, transfer_hook_program,
+        token_program
+    },
+    CreateTransferApproval(CreateTransferApprovalInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, transfer_approval_account, transfer_verification_config,
+        escrow_authority, escrow_token_account, system_program, token_program,
+        associated_token_program, account_metas_pda, transfer_hook_pda,
+        transfer_hook_program
+    },
+    UpdateTransferApproval(UpdateTransferApprovalInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, transfer_approval_account
+    },
+    CloseTransferApproval {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, transfer_approval_account, transfer_verification_config,
+        rent_recipient, system_program, account_metas_pda, transfer_hook_pda,
+        transfer_hook_program
+    },
+    RequestTransfer(RequestTransferInstructionArgs) {
+        mint, verification_config, instructions_sysvar, payer, owner, mint_account,
+        source_token_account, destination_token_account, transfer_request_account,
+        escrow_token_account, permanent_delegate, transfer_hook_program, token_program,
+        system_program, source_suspension, destination_suspension
+    },
+    ApproveTransfer {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, transfer_request_account, destination_token_account,
+        escrow_token_account, rent_destination, permanent_delegate, transfer_hook_program,
+        token_program, destination_suspension
+    },
+    RejectTransfer {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, transfer_request_account, source_token_account, escrow_token_account,
+        rent_destination, permanent_delegate, transfer_hook_program, token_program
+    },
+    CreateAccrualConfig(CreateAccrualConfigInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, accrual_config_account, payout_mint, coupon_vault,
+        payout_token_program, associated_token_account_program, system_program,
+        transfer_verification_config, account_metas_pda, transfer_hook_pda,
+        transfer_hook_program
+    },
+    ClaimCoupon {
+        mint, verification_config, instructions_sysvar, payer, mint_account,
+        accrual_config_account, token_account, coupon_claim_account, payout_mint,
+        coupon_vault, payout_token_account, payout_token_program, system_program
+    },
+    CreateMaturity(CreateMaturityInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, maturity_account, payout_mint, redemption_vault, payout_token_program,
+        associated_token_account_program, system_program
+    },
+    RedeemAtMaturity(RedeemAtMaturityInstructionArgs) {
+        mint, verification_config, instructions_sysvar, permanent_delegate, owner,
+        mint_account, maturity_account, token_account, payout_mint, redemption_vault,
+        payout_token_account, payout_token_program, token_program,
+        permanent_delegate_config_account
+    },
+    WithdrawUnclaimedRedemption {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, maturity_account, payout_mint, redemption_vault, destination,
+        payout_token_program
+    },
+    CreateNavOracle(CreateNavOracleInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, nav_oracle_account, oracle, system_program
+    },
+    UpdateMultiplierFromOracle {
+        mint, verification_config, instructions_sysvar, mint_account, nav_oracle_account,
+        oracle, scaled_ui_amount_authority, token_program
+    },
+    SetRateOracle(SetRateOracleInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        rate_account, mint_from, mint_to, oracle
+    },
+    RemoveRateOracle(RemoveRateOracleInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        rate_account, mint_from, mint_to
+    },
+    OpenSubscription(OpenSubscriptionInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, subscription_account, payment_mint, payment_vault, proceeds_account,
+        payment_token_program, associated_token_account_program, system_program
+    },
+    Commit(CommitInstructionArgs) {
+        mint, verification_config, instructions_sysvar, payer, investor, mint_account,
+        subscription_account, commitment_account, payment_mint, payment_vault,
+        investor_payment_account, payment_token_program, system_program
+    },
+    Allocate(AllocateInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, subscription_account, commitment_account
+    },
+    Settle {
+        mint, verification_config, instructions_sysvar, mint_authority, investor,
+        mint_account, subscription_account, commitment_account, token_account, payment_mint,
+        payment_vault, proceeds_account, investor_payment_account, payment_token_program,
+        token_program
+    },
+    CreateAuction(CreateAuctionInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, auction_account, payment_mint, payment_vault, proceeds_account,
+        payment_token_program, associated_token_account_program, system_program
+    },
+    PlaceBid(PlaceBidInstructionArgs) {
+        mint, verification_config, instructions_sysvar, payer, bidder, mint_account,
+        auction_account, bid_account, payment_mint, payment_vault, bidder_payment_account,
+        payment_token_program, system_program
+    },
+    ClearAuction {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, auction_account
+    },
+    SettleBid {
+        mint, verification_config, instructions_sysvar, mint_authority, bidder,
+        mint_account, auction_account, bid_account, token_account, payment_mint,
+        payment_vault, proceeds_account, bidder_payment_account, payment_token_program,
+        token_program
+    },
+    CreateHoldingPeriod(CreateHoldingPeriodInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, holding_period_account, transfer_verification_config, system_program,
+        account_metas_pda, transfer_hook_pda, transfer_hook_program
+    },
+    CloseHoldingPeriod {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, holding_period_account, transfer_verification_config, rent_recipient,
+        system_program, account_metas_pda, transfer_hook_pda, transfer_hook_program
+    },
+    UnlockLot {
+        restricted_holding_account, holding_lot_account, rent_destination
+    },
+    Suspend {
+        mint, verification_config, instructions_sysvar, payer, mint_account, token_account,
+        suspension_account, system_program
+    },
+    Unsuspend {
+        mint, verification_config, instructions_sysvar, mint_account, suspension_account,
+        rent_destination
+    },
+    ThawExpired {
+        mint_account, freeze_authority, token_account, freeze_expiry_account,
+        rent_destination, token_program
+    },
+    CreateVesting(CreateVestingInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_authority, mint_account, beneficiary_token_account, vesting_account,
+        escrow_authority, escrow_token_account, system_program, token_program,
+        associated_token_program
+    },
+    ReleaseVested {
+        mint_account, vesting_account, beneficiary_token_account, escrow_token_account,
+        beneficiary_suspension, rent_destination, permanent_delegate_authority,
+        transfer_hook_program, token_program
+    },
+    CreateWithholdingRate(CreateWithholdingRateInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, withholding_rate_account, tax_escrow_token_account, system_program,
+        token_program, associated_token_program
+    },
+    UpdateWithholdingRate(UpdateWithholdingRateInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, withholding_rate_account
+    },
+    CreateDistributionPayout(CreateDistributionPayoutInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, distribution_payout_account, payout_mint, payout_escrow_token_account,
+        payout_token_program, associated_token_program, system_program
+    },
+    CreateClaimDeadline(CreateClaimDeadlineInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, claim_deadline_account, system_program
+    },
+    ReclaimUnclaimed(ReclaimUnclaimedInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        permanent_delegate_authority, mint_account, claim_deadline_account,
+        [escrow_token_account], [destination_token_account], transfer_hook_program,
+        token_program, [distribution_payout_account], [payout_mint],
+        [payout_escrow_token_account], [payout_destination_token_account],
+        [payout_token_program]
+    },
+    AmendDistributionRoot(AmendDistributionRootInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        permanent_delegate_authority, mint_account, claim_deadline_account,
+        distribution_root_account, [escrow_token_account], new_escrow_authority,
+        [new_escrow_token_account], [distribution_payout_account], transfer_hook_program,
+        token_program, associated_token_program, system_program
+    },
+    TransferWithPermit(TransferWithPermitInstructionArgs) {
+        mint, verification_config, instructions_sysvar, payer, account_delegate_authority,
+        mint_account, from_token_account, to_token_account, permit_nonce_account,
+        instructions_sysvar_account, transfer_hook_program, token_program, system_program,
+        account_metas_pda
+    },
+    CreateSessionKey(CreateSessionKeyInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, session_key_account, session_key, system_program
+    },
+    CloseSessionKey {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, session_key_account, rent_recipient
+    },
+    RecoverTokens(RecoverTokensInstructionArgs) {
+        mint, verification_config, instructions_sysvar, payer, permanent_delegate_authority,
+        mint_account, source_token_account, source_owner, recovery_token_account,
+        suspension_account, recovery_receipt_account, transfer_hook_program, token_program,
+        system_program
+    },
+    CreateMintMigration(CreateMintMigrationInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_from, mint_to, mint_from_authority, mint_to_authority, rate_account,
+        mint_migration_account, system_program
+    },
+    MigrateBalances {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_authority, permanent_delegate, mint_from, mint_to, rate_account,
+        mint_migration_account, token_program, system_program
+    },
+    CreateMetadataSchema(CreateMetadataSchemaInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, metadata_schema_account, system_program
+    },
+    UpdateMetadataSchema(UpdateMetadataSchemaInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, metadata_schema_account, system_program
+    },
+    CloseMetadataSchema {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, metadata_schema_account, rent_recipient
+    },
+    FreezeMetadata {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_authority, payer, mint_account, metadata_freeze_account, token_program,
+        system_program
+    },
+    CreateConfigTemplate(CreateConfigTemplateInstructionArgs) {
+        issuer, config_template_account, system_program
+    },
+    UpdateConfigTemplate(UpdateConfigTemplateInstructionArgs) {
+        issuer, config_template_account, system_program
+    },
+    CloseConfigTemplate {
+        issuer, config_template_account, rent_recipient
+    },
+    SetConfigTemplate(SetConfigTemplateInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, verification_config, [config_template_account], system_program
+    },
+    CanTransfer(CanTransferInstructionArgs) {
+        mint, verification_config, instructions_sysvar, permanent_delegate_authority,
+        mint_account, from_token_account, to_token_account, transfer_hook_program,
+        token_program, [holding_period_account], [restricted_holding_account]
+    },
+    GetHolderStatus {
+        mint, token_account, suspension_account, [holding_period_account],
+        [restricted_holding_account]
+    },
+    GetEffectiveRate(GetEffectiveRateInstructionArgs) {
+        mint_from, mint_to, rate_account, [oracle]
+    },
+    GetDistributionStatus(GetDistributionStatusInstructionArgs) {
+        mint, claim_deadline_account, distribution_root_account, [escrow_token_account]
+    },
+    AttestCollateral {
+        payer, mint, token_account, suspension_account, collateral_attestation_account,
+        system_program, [holding_period_account], [restricted_holding_account]
+    },
+    PublishReserveAttestation(PublishReserveAttestationInstructionArgs) {
+        custodian, mint, reserve_attestation_account, system_program
+    },
+    CreateSubAccountLedger {
+        custodian, mint, token_account, sub_account_ledger_account, system_program
+    },
+    CreditSubAccount(CreditSubAccountInstructionArgs) {
+        custodian, mint, token_account, sub_account_ledger_account, sub_account,
+        system_program
+    },
+    DebitSubAccount(DebitSubAccountInstructionArgs) {
+        custodian, mint, token_account, sub_account_ledger_account, sub_account
+    },
+    CloseSubAccountLedger {
+        custodian, mint, token_account, sub_account_ledger_account
+    },
+    CreateSuccessionPolicy(CreateSuccessionPolicyInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, succession_policy_account, system_program
+    },
+    Heartbeat {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, succession_policy_account
+    },
+    ClaimSuccession {
+        successor, mint, succession_policy_account
+    },
+    CloseSuccessionPolicy {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, succession_policy_account, rent_recipient
+    },
+    CreateMetadataTranslator {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, metadata_translator_account, translator, system_program
+    },
+    UpdateTranslation(UpdateTranslationInstructionArgs) {
+        translator, payer, mint, mint_authority, metadata_translator_account, token_program,
+        system_program
+    },
+    CloseMetadataTranslator {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_account, metadata_translator_account, rent_recipient
+    },
+    AuditMint {
+        mint
+    },
+    SetTransferFee(SetTransferFeeInstructionArgs) {
+        mint, verification_config, instructions_sysvar, transfer_fee_authority, mint_account,
+        token_program
+    },
+    WithdrawWithheldTokensFromAccounts {
+        mint, verification_config, instructions_sysvar, transfer_fee_authority, mint_account,
+        destination_account, token_program
+    },
+    HarvestWithheldTokensToMint {
+        mint, verification_config, instructions_sysvar, mint_account, token_program
+    },
+    UpdateDefaultAccountState(UpdateDefaultAccountStateInstructionArgs) {
+        mint, verification_config, instructions_sysvar, freeze_authority, mint_account,
+        token_program
+    },
+    UpdateScaledUiAmountMultiplier(UpdateScaledUiAmountMultiplierInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_authority, mint_account, token_program
+    },
+    RemoveMetadataField(RemoveMetadataFieldInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator,
+        mint_authority, mint_account, token_program, [metadata_schema_account],
+        [external_metadata_account], [metadata_program]
+    },
+    AnchorDocument(AnchorDocumentInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, document_registry_account, system_program
+    },
+    UpdateDocument(UpdateDocumentInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, document_registry_account, system_program
+    },
+    RemoveDocument(RemoveDocumentInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, document_registry_account, system_program
+    },
+    ProposeMintCreatorTransfer(ProposeMintCreatorTransferInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, mint_authority_account, system_program
+    },
+    AcceptMintCreatorTransfer {
+        new_creator, mint, mint_authority_account
+    },
+    SetMintAuthorityMultisig(SetMintAuthorityMultisigInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, mint_authority_account, system_program
+    },
+    RotatePermanentDelegate(RotatePermanentDelegateInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, permanent_delegate, permanent_delegate_config_account,
+        transfer_verification_config, token_program, system_program, account_metas_pda,
+        transfer_hook_pda, transfer_hook_program
+    },
+    DisablePermanentDelegate {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, permanent_delegate, permanent_delegate_config_account,
+        transfer_verification_config, token_program, system_program, account_metas_pda,
+        transfer_hook_pda, transfer_hook_program
+    },
+    SetPauseDelegate(SetPauseDelegateInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, pause_delegate_account, system_program
+    },
+    CloseMintAuthority {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, mint_account,
+        mint_authority_account, rent_recipient
+    },
+    SetConfigAdmin(SetConfigAdminInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, config_admin_account, system_program
+    },
+    ApplyPendingConfig(ApplyPendingConfigInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, config_account, pending_config_account, rent_recipient, system_program,
+        [account_metas_pda], [transfer_hook_pda], [transfer_hook_program]
+    },
+    CancelPendingConfig(CancelPendingConfigInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, mint_account,
+        pending_config_account, rent_recipient
+    },
+    SetEmergencyAuthority(SetEmergencyAuthorityInstructionArgs) {
+        payer, upgrade_authority, program_data, program_config_account, system_program
+    },
+    SetProgramHalt(SetProgramHaltInstructionArgs) {
+        emergency_authority, program_config_account
+    },
+    AddToAllowlist(AddToAllowlistInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, allowlist_entry_account, system_program
+    },
+    RemoveFromAllowlist(RemoveFromAllowlistInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, mint_account,
+        allowlist_entry_account, rent_recipient
+    },
+    SetAllowlistMode(SetAllowlistModeInstructionArgs) {
+        mint, verification_config_or_mint_authority, instructions_sysvar_or_creator, payer,
+        mint_account, transfer_verification_config, rent_recipient, system_program,
+        account_metas_pda, transfer_hook_pda, transfer_hook_program
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ClaimDistributionArgs, ConvertArgs};
+    use solana_pubkey::Pubkey;
+
+    #[test]
+    fn test_instruction_accounts_match_idl() {
+        let idl: serde_json::Value =
+            serde_json::from_str(include_str!("../../../idl/security_token_program.json")).unwrap();
+        let instructions = idl["instructions"].as_array().unwrap();
+        assert_eq!(
+            instructions.len(),
+            SecurityTokenInstructionDiscriminators::ALL.len()
+        );
+
+        for instruction in instructions {
+            let discriminator = instruction["discriminant"]["value"].as_u64().unwrap() as u8;
+            let decoded = SecurityTokenInstructionDiscriminators::from_u8(discriminator).unwrap();
+            let expected: Vec<(String, bool)> = instruction["accounts"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|account| {
+                    let name = account["name"].as_str().unwrap();
+                    let mut snake = String::new();
+                    for c in name.chars() {
+                        if c.is_ascii_uppercase() {
+                            snake.push('_');
+                        }
+                        snake.push(c.to_ascii_lowercase());
+                    }
+                    (snake, account["isOptional"].as_bool().unwrap_or(false))
+                })
+                .collect();
+            let actual: Vec<(String, bool)> = instruction_accounts(decoded)
+                .iter()
+                .map(|account| (account.name.to_string(), account.optional))
+                .collect();
+            assert_eq!(actual, expected, "accounts of {decoded:?}");
+        }
+    }
+
+    #[test]
+    fn test_decode_builder_instructions() {
+        let convert_args = ConvertArgs {
+            action_id: 3,
+            amount_to_convert: 1_000,
+        };
+        let instruction = ConvertBuilder::new()
+            .mint(Pubkey::new_unique())
+            .verification_config(Pubkey::new_unique())
+            .mint_authority(Pubkey::new_unique())
+            .permanent_delegate(Pubkey::new_unique())
+            .payer(Pubkey::new_unique())
+            .mint_from(Pubkey::new_unique())
+            .mint_to(Pubkey::new_unique())
+            .token_account_from(Pubkey::new_unique())
+            .token_account_to(Pubkey::new_unique())
+            .rate_account(Pubkey::new_unique())
+            .receipt_account(Pubkey::new_unique())
+            .convert_args(convert_args.clone())
+            .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+            .instruction();
+
+        let decoded = decode_instruction(&instruction.data, &instruction.accounts).unwrap();
+        assert_eq!(
+            decoded.instruction,
+            SecurityTokenInstructionDiscriminators::Convert
+        );
+        assert_eq!(
+            decoded.args,
+            SecurityTokenInstructionArgs::Convert(ConvertInstructionArgs { convert_args })
+        );
+        assert_eq!(decoded.account("payer"), Some(&instruction.accounts[5]));
+        assert_eq!(decoded.account("oracle"), None);
+        assert_eq!(decoded.accounts.len(), 15);
+        assert_eq!(decoded.remaining_accounts, instruction.accounts[15..]);
+
+        let claim_args = ClaimDistributionArgs {
+            action_id: 1,
+            amount: 50,
+            merkle_root: [7; 32],
+            leaf_index: 2,
+            merkle_proof: Some(vec![[1; 32], [2; 32]]),
+        };
+        let instruction = ClaimDistributionInstructionArgs {
+            claim_distribution_args: claim_args.clone(),
+        };
+        let mut data = vec![CLAIM_DISTRIBUTION_DISCRIMINATOR];
+        data.extend(borsh::to_vec(&instruction).unwrap());
+        let declared =
+            instruction_accounts(SecurityTokenInstructionDiscriminators::ClaimDistribution);
+        let accounts: Vec<_> = declared
+            .iter()
+            .map(|account| match account.optional {
+                true => AccountMeta::new_readonly(SECURITY_TOKEN_PROGRAM_ID, false),
+                false => AccountMeta::new(Pubkey::new_unique(), false),
+            })
+            .collect();
+
+        let decoded = decode_instruction(&data, &accounts).unwrap();
+        assert_eq!(
+            decoded.args,
+            SecurityTokenInstructionArgs::ClaimDistribution(ClaimDistributionInstructionArgs {
+                claim_distribution_args: claim_args,
+            })
+        );
+        for ((account, declared), meta) in decoded.accounts.iter().zip(declared).zip(&accounts) {
+            assert_eq!(account.name, declared.name);
+            let expected = (!declared.optional).then(|| meta.clone());
+            assert_eq!(account.meta, expected);
+        }
+        assert!(decoded.remaining_accounts.is_empty());
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(decode_instruction(&[], &[]), Err(DecodeError::EmptyData));
+        assert_eq!(
+            decode_instruction(&[200], &[]),
+            Err(DecodeError::UnknownDiscriminator(200))
+        );
+        assert!(matches!(
+            decode_instruction(&[PAUSE_DISCRIMINATOR, 1], &[]),
+            Err(DecodeError::InvalidArgs { .. })
+        ));
+        assert!(matches!(
+            decode_instruction(&[CONVERT_DISCRIMINATOR, 1, 2], &[]),
+            Err(DecodeError::InvalidArgs { .. })
+        ));
+        assert_eq!(
+            decode_instruction(&[PAUSE_DISCRIMINATOR], &[]),
+            Err(DecodeError::MissingAccount {
+                instruction: SecurityTokenInstructionDiscriminators::Pause,
+                name: instruction_accounts(SecurityTokenInstructionDiscriminators::Pause)[0].name,
+            })
+        );
+    }
+}
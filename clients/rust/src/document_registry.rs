@@ -0,0 +1,77 @@
+//! Document registry decoding.
+//!
+//! A DocumentRegistry anchors the legal documents of a mint, such as its prospectus,
+//! by name, URI and SHA-256 hash. Its `documents` are a raw byte encoding; these
+//! helpers read them and check a downloaded document against its anchored hash.
+
+use solana_sha256_hasher::hash;
+
+/// Document anchored in a DocumentRegistry
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnchoredDocument {
+    pub name: String,
+    pub uri: String,
+    pub sha256: [u8; 32],
+}
+
+impl AnchoredDocument {
+    /// Whether `content` is the document anchored on chain
+    pub fn matches(&self, content: &[u8]) -> bool {
+        hash(content).to_bytes() == self.sha256
+    }
+}
+
+/// Decodes the `documents` of a DocumentRegistry account, `None` if malformed.
+/// Each document is encoded as `name_len (u32 LE) + name + uri_len (u32 LE) + uri + sha256`
+pub fn decode_documents(data: &[u8]) -> Option<Vec<AnchoredDocument>> {
+    let read_str = |offset: usize| -> Option<(String, usize)> {
+        let len = u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?) as usize;
+        let end = offset + 4 + len;
+        let value = std::str::from_utf8(data.get(offset + 4..end)?).ok()?;
+        Some((value.to_string(), end))
+    };
+
+    let mut documents = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() {
+        let (name, name_end) = read_str(offset)?;
+        let (uri, uri_end) = read_str(name_end)?;
+        let sha256 = data.get(uri_end..uri_end + 32)?.try_into().ok()?;
+        offset = uri_end + 32;
+        documents.push(AnchoredDocument { name, uri, sha256 });
+    }
+    Some(documents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(name: &str, uri: &str, sha256: &[u8; 32]) -> Vec<u8> {
+        let mut data = (name.len() as u32).to_le_bytes().to_vec();
+        data.extend_from_slice(name.as_bytes());
+        data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
+        data.extend_from_slice(uri.as_bytes());
+        data.extend_from_slice(sha256);
+        data
+    }
+
+    #[test]
+    fn test_decode_documents() {
+        let prospectus_hash = hash(b"prospectus").to_bytes();
+        let data = [
+            encode("prospectus", "ipfs://prospectus", &prospectus_hash),
+            encode("subscription", "ipfs://subscription", &[7; 32]),
+        ]
+        .concat();
+
+        let documents = decode_documents(&data).unwrap();
+        assert_eq!(documents.len(), 2);
+        assert_eq!(documents[0].name, "prospectus");
+        assert_eq!(documents[1].uri, "ipfs://subscription");
+        assert!(documents[0].matches(b"prospectus"));
+        assert!(!documents[0].matches(b"amended prospectus"));
+
+        assert_eq!(decode_documents(&data[..data.len() - 1]), None);
+    }
+}
@@ -0,0 +1,243 @@
+//! Typed event streams over `logsSubscribe`.
+//!
+//! Programs log structured events with `sol_log_data`, which the runtime prints as
+//! `Program data: <base64> ...` lines. [`event_frames`] attributes every such line to the
+//! program executing it, following the `invoke` and `success`/`failed` lines of the log,
+//! and [`subscribe_events`] streams the frames of successful transactions decoded into a
+//! [`DecodeEvent`] type, usually an enum of the events of a program.
+//!
+//! Only [`subscribe_events`] needs a connection: Geyser plugins and indexers reading the log
+//! messages of confirmed transactions decode them with [`event_frames`] and the same
+//! [`DecodeEvent`] type.
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+use futures_util::future::BoxFuture;
+use futures_util::stream::{BoxStream, StreamExt};
+use solana_client::nonblocking::pubsub_client::{PubsubClient, PubsubClientError};
+use solana_client::rpc_config::{RpcTransactionLogsConfig, RpcTransactionLogsFilter};
+use solana_client::rpc_response::RpcLogsResponse;
+use solana_pubkey::Pubkey;
+use std::str::FromStr;
+
+const PROGRAM_PREFIX: &str = "Program ";
+const INVOKE_PREFIX: &str = "invoke [";
+const DATA_PREFIX: &str = "Program data: ";
+
+/// Data logged by a program with `sol_log_data`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventFrame {
+    /// Program executing when the data was logged
+    pub program_id: Pubkey,
+    /// Invocation depth of the program, 1 for a top-level instruction
+    pub depth: usize,
+    /// The slices passed to `sol_log_data`, concatenated
+    pub data: Vec<u8>,
+}
+
+/// Decodes the events of a program from logged data
+pub trait DecodeEvent: Sized {
+    /// The event logged in `frame`, `None` if it is not one
+    fn decode(frame: &EventFrame) -> Option<Self>;
+}
+
+/// Events logged in a transaction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionEvents<E> {
+    /// Base58 signature of the transaction
+    pub signature: String,
+    pub slot: u64,
+    pub events: Vec<E>,
+}
+
+/// Decoded events of the transactions of a subscription
+pub type EventStream<'a, E> = BoxStream<'a, TransactionEvents<E>>;
+
+/// Ends a subscription
+pub type Unsubscribe = Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send>;
+
+/// Data logged in a transaction log, in log order. Lines that are not valid base64 are
+/// skipped, as are logs truncated before the first invocation.
+pub fn event_frames(logs: &[String]) -> Vec<EventFrame> {
+    let mut stack: Vec<Pubkey> = Vec::new();
+    let mut frames = Vec::new();
+    for line in logs {
+        if let Some(encoded) = line.strip_prefix(DATA_PREFIX) {
+            let Some(program_id) = stack.last() else {
+                continue;
+            };
+            let data = encoded
+                .split_whitespace()
+                .map(|field| BASE64_STANDARD.decode(field))
+                .collect::<Result<Vec<_>, _>>();
+            if let Ok(data) = data {
+                frames.push(EventFrame {
+                    program_id: *program_id,
+                    depth: stack.len(),
+                    data: data.concat(),
+                });
+            }
+            continue;
+        }
+        let Some((program, rest)) = line
+            .strip_prefix(PROGRAM_PREFIX)
+            .and_then(|line| line.split_once(' '))
+        else {
+            continue;
+        };
+        let Ok(program_id) = Pubkey::from_str(program) else {
+            continue;
+        };
+        if rest.starts_with(INVOKE_PREFIX) {
+            stack.push(program_id);
+        } else if rest == "success" || rest.starts_with("failed") {
+            stack.pop();
+        }
+    }
+    frames
+}
+
+/// Decoded events of a logs notification, `None` for a failed transaction, whose events
+/// were rolled back, or one without events
+pub fn decode_logs_notification<E: DecodeEvent>(
+    slot: u64,
+    logs: &RpcLogsResponse,
+) -> Option<TransactionEvents<E>> {
+    if logs.err.is_some() {
+        return None;
+    }
+    let events: Vec<E> = event_frames(&logs.logs)
+        .iter()
+        .filter_map(E::decode)
+        .collect();
+    if events.is_empty() {
+        return None;
+    }
+    Some(TransactionEvents {
+        signature: logs.signature.clone(),
+        slot,
+        events,
+    })
+}
+
+/// Streams the decoded events of the successful transactions mentioning `address`, usually
+/// the id of the program logging them
+pub async fn subscribe_events<'a, E: DecodeEvent + Send + 'a>(
+    client: &'a PubsubClient,
+    address: &Pubkey,
+    config: RpcTransactionLogsConfig,
+) -> Result<(EventStream<'a, E>, Unsubscribe), PubsubClientError> {
+    let (stream, unsubscribe) = client
+        .logs_subscribe(
+            RpcTransactionLogsFilter::Mentions(vec![address.to_string()]),
+            config,
+        )
+        .await?;
+    let stream = stream
+        .filter_map(|response| {
+            futures_util::future::ready(decode_logs_notification(
+                response.context.slot,
+                &response.value,
+            ))
+        })
+        .boxed();
+    Ok((stream, unsubscribe))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::transaction::TransactionError;
+
+    #[derive(Debug, PartialEq)]
+    struct Amount(u64);
+
+    impl DecodeEvent for Amount {
+        fn decode(frame: &EventFrame) -> Option<Self> {
+            Some(Amount(u64::from_le_bytes(
+                frame.data.as_slice().try_into().ok()?,
+            )))
+        }
+    }
+
+    fn data_line(fields: &[&[u8]]) -> String {
+        let fields: Vec<String> = fields
+            .iter()
+            .map(|field| BASE64_STANDARD.encode(field))
+            .collect();
+        format!("{DATA_PREFIX}{}", fields.join(" "))
+    }
+
+    #[test]
+    fn test_event_frames_follow_invocations() {
+        let program = Pubkey::new_unique();
+        let inner = Pubkey::new_unique();
+        let logs = vec![
+            data_line(&[b"before any invocation"]),
+            format!("Program {program} invoke [1]"),
+            format!("Program {inner} invoke [2]"),
+            data_line(&[&1u32.to_le_bytes(), &2u32.to_le_bytes()]),
+            format!("Program {inner} consumed 100 of 200000 compute units"),
+            format!("Program {inner} success"),
+            "Program data: not base64!".to_string(),
+            data_line(&[&3u64.to_le_bytes()]),
+            format!("Program {program} success"),
+        ];
+
+        assert_eq!(
+            event_frames(&logs),
+            vec![
+                EventFrame {
+                    program_id: inner,
+                    depth: 2,
+                    data: [1u32.to_le_bytes(), 2u32.to_le_bytes()].concat(),
+                },
+                EventFrame {
+                    program_id: program,
+                    depth: 1,
+                    data: 3u64.to_le_bytes().to_vec(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_logs_notification() {
+        let program = Pubkey::new_unique();
+        let logs = vec![
+            format!("Program {program} invoke [1]"),
+            data_line(&[&10u64.to_le_bytes()]),
+            data_line(&[b"not an amount"]),
+            format!("Program {program} success"),
+        ];
+        let response = RpcLogsResponse {
+            signature: "signature".to_string(),
+            err: None,
+            logs: logs.clone(),
+        };
+
+        assert_eq!(
+            decode_logs_notification(42, &response),
+            Some(TransactionEvents {
+                signature: "signature".to_string(),
+                slot: 42,
+                events: vec![Amount(10)],
+            })
+        );
+
+        // Events of a failed transaction were rolled back
+        let failed = RpcLogsResponse {
+            err: Some(TransactionError::AccountInUse),
+            ..response.clone()
+        };
+        assert_eq!(decode_logs_notification::<Amount>(42, &failed), None);
+
+        let without_events = RpcLogsResponse {
+            logs: logs[..1].to_vec(),
+            ..response
+        };
+        assert_eq!(
+            decode_logs_notification::<Amount>(42, &without_events),
+            None
+        );
+    }
+}
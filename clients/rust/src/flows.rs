@@ -0,0 +1,374 @@
+//! Multi-instruction transaction flows for common setups.
+//!
+//! [`plan_mint_setup`] initializes a mint and its verification configs in as few
+//! transactions as possible. The Transfer config also initializes the transfer hook extra
+//! account metas, so a planned setup is ready for transfers. Every transaction fits in a
+//! packet, with room left for a compute unit limit and a priority fee instruction;
+//! transactions must be sent in order since the configs need the mint.
+
+use crate::compute_budget::{prepend_compute_budget, MAX_COMPUTE_UNIT_LIMIT};
+use crate::halt::with_program_config;
+use crate::instructions::InitializeMintBuilder;
+use crate::pda::{
+    find_extra_account_metas_pda, find_freeze_authority_pda, find_mint_authority_pda,
+    find_pause_authority_pda, find_permanent_delegate_pda, find_transfer_hook_pda,
+    find_verification_config_pda,
+};
+use crate::rollout::{rollout_instructions, DesiredVerificationConfig, RolloutAction};
+use crate::types::{InitializeMintArgs, MintArgs};
+use solana_instruction::Instruction;
+use solana_message::Message;
+use solana_packet::PACKET_DATA_SIZE;
+use solana_pubkey::Pubkey;
+
+/// Mint to create and the verification configs it starts with
+#[derive(Clone, Debug)]
+pub struct MintSetup {
+    /// Keypair address of the new mint, it signs the first transaction
+    pub mint: Pubkey,
+    /// Mint creator, authorizes the verification configs
+    pub creator: Pubkey,
+    pub payer: Pubkey,
+    pub mint_args: InitializeMintArgs,
+    pub verification_configs: Vec<DesiredVerificationConfig>,
+}
+
+impl MintSetup {
+    /// Setup of a mint without metadata, scaled UI amount, transfer fee or default account
+    /// state, frozen through the freeze authority PDA, and without verification configs
+    pub fn new(mint: Pubkey, creator: Pubkey, payer: Pubkey, decimals: u8) -> Self {
+        Self {
+            mint,
+            creator,
+            payer,
+            mint_args: InitializeMintArgs {
+                ix_mint: MintArgs {
+                    decimals,
+                    mint_authority: creator,
+                    freeze_authority: find_freeze_authority_pda(&mint).0,
+                },
+                ix_metadata_pointer: None,
+                ix_metadata: None,
+                ix_scaled_ui_amount: None,
+                ix_transfer_fee: None,
+                ix_default_account_state: None,
+            },
+            verification_configs: Vec::new(),
+        }
+    }
+
+    pub fn with_verification_config(mut self, config: DesiredVerificationConfig) -> Self {
+        self.verification_configs.push(config);
+        self
+    }
+}
+
+/// Addresses derived for a mint setup
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MintSetupPdas {
+    pub mint_authority: Pubkey,
+    pub freeze_authority: Pubkey,
+    pub pause_authority: Pubkey,
+    pub permanent_delegate: Pubkey,
+    pub transfer_hook: Pubkey,
+    pub extra_account_metas: Pubkey,
+    /// Verification config of every configured instruction, by instruction discriminator
+    pub verification_configs: Vec<(u8, Pubkey)>,
+}
+
+/// Transaction of a flow, `signers` lists the keys that must sign it, fee payer first
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlowTransaction {
+    pub instructions: Vec<Instruction>,
+    pub signers: Vec<Pubkey>,
+}
+
+/// Transactions to send in order and the addresses they create
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MintSetupPlan {
+    pub transactions: Vec<FlowTransaction>,
+    pub pdas: MintSetupPdas,
+}
+
+/// Plans the transactions creating the mint of `setup` and its verification configs
+pub fn plan_mint_setup(setup: &MintSetup) -> MintSetupPlan {
+    let mint = &setup.mint;
+    let (mint_authority, _) = find_mint_authority_pda(mint, &setup.creator);
+
+    let mut builder = InitializeMintBuilder::new();
+    builder
+        .mint(*mint)
+        .authority(mint_authority)
+        .payer(setup.payer)
+        .initialize_mint_args(setup.mint_args.clone());
+    // Without a creator account the payer is the mint creator
+    if setup.creator != setup.payer {
+        builder.creator(Some(setup.creator));
+    }
+
+    let mut instructions = vec![with_program_config(builder.instruction())];
+    for config in &setup.verification_configs {
+        instructions.extend(rollout_instructions(
+            mint,
+            &setup.creator,
+            &setup.payer,
+            config,
+            RolloutAction::Initialize,
+        ));
+    }
+
+    MintSetupPlan {
+        transactions: pack_transactions(instructions, &setup.payer),
+        pdas: MintSetupPdas {
+            mint_authority,
+            freeze_authority: find_freeze_authority_pda(mint).0,
+            pause_authority: find_pause_authority_pda(mint).0,
+            permanent_delegate: find_permanent_delegate_pda(mint).0,
+            transfer_hook: find_transfer_hook_pda(mint).0,
+            extra_account_metas: find_extra_account_metas_pda(mint).0,
+            verification_configs: setup
+                .verification_configs
+                .iter()
+                .map(|config| {
+                    let discriminator = config.instruction_discriminator;
+                    (
+                        discriminator,
+                        find_verification_config_pda(mint, discriminator).0,
+                    )
+                })
+                .collect(),
+        },
+    }
+}
+
+/// Size of a legacy transaction carrying `instructions`: signature count and signatures,
+/// then the message
+pub fn transaction_size(instructions: &[Instruction], payer: &Pubkey) -> usize {
+    let message = Message::new(instructions, Some(payer));
+    let signatures = usize::from(message.header.num_required_signatures);
+    // Short vec length of the signatures, one byte below 128 signatures
+    1 + 64 * signatures + message.serialize().len()
+}
+
+/// Groups consecutive `instructions` into as few transactions as fit in a packet, leaving
+/// room for compute budget instructions
+///
+/// An instruction too large for a transaction of its own still gets one, sending it reports
+/// the error.
+pub fn pack_transactions(instructions: Vec<Instruction>, payer: &Pubkey) -> Vec<FlowTransaction> {
+    pack_instruction_groups(
+        instructions
+            .into_iter()
+            .map(|instruction| vec![instruction]),
+        payer,
+    )
+}
+
+/// Packs consecutive instruction `groups` like [`pack_transactions`], never splitting a group
+/// across transactions, as verification calls must stay with the instruction they verify
+pub fn pack_instruction_groups(
+    groups: impl IntoIterator<Item = Vec<Instruction>>,
+    payer: &Pubkey,
+) -> Vec<FlowTransaction> {
+    let mut packed: Vec<Vec<Instruction>> = Vec::new();
+    for group in groups.into_iter().filter(|group| !group.is_empty()) {
+        match packed.last_mut() {
+            Some(instructions) => {
+                let len = instructions.len();
+                instructions.extend(group);
+                let budgeted = prepend_compute_budget(
+                    instructions.clone(),
+                    Some(MAX_COMPUTE_UNIT_LIMIT),
+                    Some(0),
+                );
+                if transaction_size(&budgeted, payer) > PACKET_DATA_SIZE {
+                    let group = instructions.split_off(len);
+                    packed.push(group);
+                }
+            }
+            None => packed.push(group),
+        }
+    }
+
+    packed
+        .into_iter()
+        .map(|instructions| {
+            let message = Message::new(&instructions, Some(payer));
+            let signers = message.account_keys
+                [..usize::from(message.header.num_required_signatures)]
+                .to_vec();
+            FlowTransaction {
+                instructions,
+                signers,
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "rpc")]
+pub use self::send::*;
+
+#[cfg(feature = "rpc")]
+mod send {
+    use super::*;
+    use crate::compute_budget::ComputeBudget;
+    use solana_client::rpc_client::RpcClient;
+    use solana_sdk::signature::{Signature, Signer};
+    use solana_sdk::transaction::Transaction;
+    use thiserror::Error;
+
+    /// Flow errors
+    #[derive(Clone, Debug, Error, PartialEq, Eq)]
+    pub enum FlowError {
+        #[error("Missing signer {0}")]
+        MissingSigner(Pubkey),
+        #[error("Transaction {index} failed: {reason}")]
+        Failed { index: usize, reason: String },
+    }
+
+    /// Sends the transactions of a flow in order, signing each with the `signers` it needs
+    pub fn send_flow(
+        rpc: &RpcClient,
+        transactions: &[FlowTransaction],
+        signers: &[&dyn Signer],
+    ) -> Result<Vec<Signature>, FlowError> {
+        send_flow_with_compute_budget(rpc, transactions, signers, &ComputeBudget::default())
+    }
+
+    /// Sends the transactions of a flow like [`send_flow`], prepending the compute budget
+    /// instructions `budget` picks for each transaction
+    pub fn send_flow_with_compute_budget(
+        rpc: &RpcClient,
+        transactions: &[FlowTransaction],
+        signers: &[&dyn Signer],
+        budget: &ComputeBudget,
+    ) -> Result<Vec<Signature>, FlowError> {
+        let mut signatures = Vec::with_capacity(transactions.len());
+        for (index, transaction) in transactions.iter().enumerate() {
+            let transaction_signers = transaction
+                .signers
+                .iter()
+                .map(|key| {
+                    signers
+                        .iter()
+                        .copied()
+                        .find(|signer| signer.pubkey() == *key)
+                        .ok_or(FlowError::MissingSigner(*key))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let failed = |reason: String| FlowError::Failed { index, reason };
+            let payer = &transaction.signers[0];
+            let instructions = budget
+                .apply(rpc, transaction.instructions.clone(), payer)
+                .map_err(|err| failed(err.to_string()))?;
+
+            let blockhash = rpc
+                .get_latest_blockhash()
+                .map_err(|err| failed(err.to_string()))?;
+            let transaction = Transaction::new_signed_with_payer(
+                &instructions,
+                Some(payer),
+                &transaction_signers,
+                blockhash,
+            );
+            signatures.push(
+                rpc.send_and_confirm_transaction(&transaction)
+                    .map_err(|err| failed(err.to_string()))?,
+            );
+        }
+        Ok(signatures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::{
+        BURN_DISCRIMINATOR, INITIALIZE_MINT_DISCRIMINATOR, MINT_DISCRIMINATOR,
+        TRANSFER_DISCRIMINATOR,
+    };
+
+    fn config(instruction_discriminator: u8, programs: usize) -> DesiredVerificationConfig {
+        DesiredVerificationConfig {
+            instruction_discriminator,
+            cpi_mode: false,
+            program_addresses: (0..programs).map(|_| Pubkey::new_unique()).collect(),
+            threshold: 0,
+            lookback_window: 0,
+            require_top_level: false,
+        }
+    }
+
+    #[test]
+    fn test_plan_mint_setup_in_one_transaction() {
+        let (mint, creator) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let setup = MintSetup::new(mint, creator, creator, 6)
+            .with_verification_config(config(MINT_DISCRIMINATOR, 1))
+            .with_verification_config(config(TRANSFER_DISCRIMINATOR, 1));
+        let plan = plan_mint_setup(&setup);
+
+        assert_eq!(plan.transactions.len(), 1);
+        let transaction = &plan.transactions[0];
+        assert_eq!(transaction.instructions.len(), 3);
+        assert_eq!(
+            transaction.instructions[0].data[0],
+            INITIALIZE_MINT_DISCRIMINATOR
+        );
+        assert_eq!(transaction.signers, vec![creator, mint]);
+
+        assert_eq!(
+            plan.pdas.mint_authority,
+            find_mint_authority_pda(&mint, &creator).0
+        );
+        assert_eq!(
+            plan.pdas.verification_configs,
+            vec![
+                (
+                    MINT_DISCRIMINATOR,
+                    find_verification_config_pda(&mint, MINT_DISCRIMINATOR).0
+                ),
+                (
+                    TRANSFER_DISCRIMINATOR,
+                    find_verification_config_pda(&mint, TRANSFER_DISCRIMINATOR).0
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_mint_setup_splits_large_setups() {
+        let (mint, creator, payer) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut setup = MintSetup::new(mint, creator, payer, 6);
+        for discriminator in [
+            MINT_DISCRIMINATOR,
+            BURN_DISCRIMINATOR,
+            TRANSFER_DISCRIMINATOR,
+        ] {
+            setup = setup.with_verification_config(config(discriminator, 8));
+        }
+        let plan = plan_mint_setup(&setup);
+
+        assert!(plan.transactions.len() > 1);
+        let instructions: usize = plan
+            .transactions
+            .iter()
+            .map(|transaction| transaction.instructions.len())
+            .sum();
+        assert_eq!(instructions, 4);
+        for transaction in &plan.transactions {
+            let budgeted = prepend_compute_budget(
+                transaction.instructions.clone(),
+                Some(MAX_COMPUTE_UNIT_LIMIT),
+                Some(u64::MAX),
+            );
+            assert!(transaction_size(&budgeted, &payer) <= PACKET_DATA_SIZE);
+            assert_eq!(transaction.signers[0], payer);
+            assert!(transaction.signers.contains(&creator));
+        }
+        assert!(plan.transactions[0].signers.contains(&mint));
+    }
+}
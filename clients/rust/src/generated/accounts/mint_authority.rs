@@ -61,14 +61,14 @@ pub fn fetch_all_mint_authority(
 ) -> Result<Vec<crate::shared::DecodedAccount<MintAuthority>>, std::io::Error> {
     let accounts = rpc
         .get_multiple_accounts(addresses)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
     let mut decoded_accounts: Vec<crate::shared::DecodedAccount<MintAuthority>> = Vec::new();
     for i in 0..addresses.len() {
         let address = addresses[i];
-        let account = accounts[i].as_ref().ok_or(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            format!("Account not found: {}", address),
-        ))?;
+        let account = accounts[i].as_ref().ok_or(std::io::Error::other(format!(
+            "Account not found: {}",
+            address
+        )))?;
         let data = MintAuthority::from_bytes(&account.data)?;
         decoded_accounts.push(crate::shared::DecodedAccount {
             address,
@@ -95,7 +95,7 @@ pub fn fetch_all_maybe_mint_authority(
 ) -> Result<Vec<crate::shared::MaybeAccount<MintAuthority>>, std::io::Error> {
     let accounts = rpc
         .get_multiple_accounts(addresses)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
     let mut decoded_accounts: Vec<crate::shared::MaybeAccount<MintAuthority>> = Vec::new();
     for i in 0..addresses.len() {
         let address = addresses[i];
@@ -127,7 +127,7 @@ impl anchor_lang::AccountSerialize for MintAuthority {}
 
 #[cfg(feature = "anchor")]
 impl anchor_lang::Owner for MintAuthority {
-    fn owner() -> Pubkey {
+    fn owner() -> solana_pubkey::Pubkey {
         crate::SECURITY_TOKEN_PROGRAM_ID
     }
 }
@@ -5,12 +5,108 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#accrual_config;
+pub(crate) mod r#agent;
+pub(crate) mod r#allowlist_entry;
+pub(crate) mod r#auction;
+pub(crate) mod r#auction_bid;
+pub(crate) mod r#claim_bitmap;
+pub(crate) mod r#claim_deadline;
+pub(crate) mod r#collateral_attestation;
+pub(crate) mod r#config_admin;
+pub(crate) mod r#config_template;
+pub(crate) mod r#coupon_claim;
+pub(crate) mod r#distribution_payout;
+pub(crate) mod r#distribution_root;
+pub(crate) mod r#document_registry;
+pub(crate) mod r#freeze_expiry;
+pub(crate) mod r#holding_lot;
+pub(crate) mod r#holding_period;
+pub(crate) mod r#identity;
+pub(crate) mod r#identity_wallet;
+pub(crate) mod r#maturity;
+pub(crate) mod r#metadata_freeze;
+pub(crate) mod r#metadata_schema;
+pub(crate) mod r#metadata_translator;
 pub(crate) mod r#mint_authority;
+pub(crate) mod r#mint_migration;
+pub(crate) mod r#nav_oracle;
+pub(crate) mod r#pause_delegate;
+pub(crate) mod r#pending_transfer;
+pub(crate) mod r#pending_verification_config;
+pub(crate) mod r#permanent_delegate_config;
+pub(crate) mod r#permit_nonce;
+pub(crate) mod r#position_limit;
+pub(crate) mod r#program_config;
 pub(crate) mod r#proof;
 pub(crate) mod r#rate;
+pub(crate) mod r#recovery_receipt;
+pub(crate) mod r#reserve_attestation;
+pub(crate) mod r#restricted_holding;
+pub(crate) mod r#session_key;
+pub(crate) mod r#sub_account;
+pub(crate) mod r#sub_account_ledger;
+pub(crate) mod r#subscription;
+pub(crate) mod r#subscription_commitment;
+pub(crate) mod r#succession_policy;
+pub(crate) mod r#suspension;
+pub(crate) mod r#transfer_acceptance;
+pub(crate) mod r#transfer_approval;
+pub(crate) mod r#transfer_request;
 pub(crate) mod r#verification_config;
+pub(crate) mod r#vesting;
+pub(crate) mod r#withholding_rate;
+pub(crate) mod r#wrap_vault;
 
+pub use self::r#accrual_config::*;
+pub use self::r#agent::*;
+pub use self::r#allowlist_entry::*;
+pub use self::r#auction::*;
+pub use self::r#auction_bid::*;
+pub use self::r#claim_bitmap::*;
+pub use self::r#claim_deadline::*;
+pub use self::r#collateral_attestation::*;
+pub use self::r#config_admin::*;
+pub use self::r#config_template::*;
+pub use self::r#coupon_claim::*;
+pub use self::r#distribution_payout::*;
+pub use self::r#distribution_root::*;
+pub use self::r#document_registry::*;
+pub use self::r#freeze_expiry::*;
+pub use self::r#holding_lot::*;
+pub use self::r#holding_period::*;
+pub use self::r#identity::*;
+pub use self::r#identity_wallet::*;
+pub use self::r#maturity::*;
+pub use self::r#metadata_freeze::*;
+pub use self::r#metadata_schema::*;
+pub use self::r#metadata_translator::*;
 pub use self::r#mint_authority::*;
+pub use self::r#mint_migration::*;
+pub use self::r#nav_oracle::*;
+pub use self::r#pause_delegate::*;
+pub use self::r#pending_transfer::*;
+pub use self::r#pending_verification_config::*;
+pub use self::r#permanent_delegate_config::*;
+pub use self::r#permit_nonce::*;
+pub use self::r#position_limit::*;
+pub use self::r#program_config::*;
 pub use self::r#proof::*;
 pub use self::r#rate::*;
+pub use self::r#recovery_receipt::*;
+pub use self::r#reserve_attestation::*;
+pub use self::r#restricted_holding::*;
+pub use self::r#session_key::*;
+pub use self::r#sub_account::*;
+pub use self::r#sub_account_ledger::*;
+pub use self::r#subscription::*;
+pub use self::r#subscription_commitment::*;
+pub use self::r#succession_policy::*;
+pub use self::r#suspension::*;
+pub use self::r#transfer_acceptance::*;
+pub use self::r#transfer_approval::*;
+pub use self::r#transfer_request::*;
 pub use self::r#verification_config::*;
+pub use self::r#vesting::*;
+pub use self::r#withholding_rate::*;
+pub use self::r#wrap_vault::*;
@@ -49,14 +49,14 @@ pub fn fetch_all_proof(
 ) -> Result<Vec<crate::shared::DecodedAccount<Proof>>, std::io::Error> {
     let accounts = rpc
         .get_multiple_accounts(addresses)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
     let mut decoded_accounts: Vec<crate::shared::DecodedAccount<Proof>> = Vec::new();
     for i in 0..addresses.len() {
         let address = addresses[i];
-        let account = accounts[i].as_ref().ok_or(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            format!("Account not found: {}", address),
-        ))?;
+        let account = accounts[i].as_ref().ok_or(std::io::Error::other(format!(
+            "Account not found: {}",
+            address
+        )))?;
         let data = Proof::from_bytes(&account.data)?;
         decoded_accounts.push(crate::shared::DecodedAccount {
             address,
@@ -83,7 +83,7 @@ pub fn fetch_all_maybe_proof(
 ) -> Result<Vec<crate::shared::MaybeAccount<Proof>>, std::io::Error> {
     let accounts = rpc
         .get_multiple_accounts(addresses)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
     let mut decoded_accounts: Vec<crate::shared::MaybeAccount<Proof>> = Vec::new();
     for i in 0..addresses.len() {
         let address = addresses[i];
@@ -115,7 +115,7 @@ impl anchor_lang::AccountSerialize for Proof {}
 
 #[cfg(feature = "anchor")]
 impl anchor_lang::Owner for Proof {
-    fn owner() -> Pubkey {
+    fn owner() -> solana_pubkey::Pubkey {
         crate::SECURITY_TOKEN_PROGRAM_ID
     }
 }
@@ -8,6 +8,7 @@
 use crate::generated::types::Rounding;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_pubkey::Pubkey;
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -17,10 +18,19 @@ pub struct Rate {
     pub numerator: u8,
     pub denominator: u8,
     pub bump: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub oracle: Pubkey,
+    pub oracle_value_offset: u32,
+    pub oracle_value_decimals: u8,
+    pub oracle_min_value: u64,
+    pub oracle_max_value: u64,
 }
 
 impl Rate {
-    pub const LEN: usize = 4;
+    pub const LEN: usize = 57;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -54,14 +64,14 @@ pub fn fetch_all_rate(
 ) -> Result<Vec<crate::shared::DecodedAccount<Rate>>, std::io::Error> {
     let accounts = rpc
         .get_multiple_accounts(addresses)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
     let mut decoded_accounts: Vec<crate::shared::DecodedAccount<Rate>> = Vec::new();
     for i in 0..addresses.len() {
         let address = addresses[i];
-        let account = accounts[i].as_ref().ok_or(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            format!("Account not found: {}", address),
-        ))?;
+        let account = accounts[i].as_ref().ok_or(std::io::Error::other(format!(
+            "Account not found: {}",
+            address
+        )))?;
         let data = Rate::from_bytes(&account.data)?;
         decoded_accounts.push(crate::shared::DecodedAccount {
             address,
@@ -88,7 +98,7 @@ pub fn fetch_all_maybe_rate(
 ) -> Result<Vec<crate::shared::MaybeAccount<Rate>>, std::io::Error> {
     let accounts = rpc
         .get_multiple_accounts(addresses)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
     let mut decoded_accounts: Vec<crate::shared::MaybeAccount<Rate>> = Vec::new();
     for i in 0..addresses.len() {
         let address = addresses[i];
@@ -120,7 +130,7 @@ impl anchor_lang::AccountSerialize for Rate {}
 
 #[cfg(feature = "anchor")]
 impl anchor_lang::Owner for Rate {
-    fn owner() -> Pubkey {
+    fn owner() -> solana_pubkey::Pubkey {
         crate::SECURITY_TOKEN_PROGRAM_ID
     }
 }
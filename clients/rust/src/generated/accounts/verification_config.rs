@@ -21,6 +21,9 @@ pub struct VerificationConfig {
         serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
     )]
     pub verification_programs: Vec<Pubkey>,
+    pub threshold: u8,
+    pub lookback_window: u8,
+    pub require_top_level: bool,
 }
 
 impl VerificationConfig {
@@ -56,14 +59,14 @@ pub fn fetch_all_verification_config(
 ) -> Result<Vec<crate::shared::DecodedAccount<VerificationConfig>>, std::io::Error> {
     let accounts = rpc
         .get_multiple_accounts(addresses)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
     let mut decoded_accounts: Vec<crate::shared::DecodedAccount<VerificationConfig>> = Vec::new();
     for i in 0..addresses.len() {
         let address = addresses[i];
-        let account = accounts[i].as_ref().ok_or(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            format!("Account not found: {}", address),
-        ))?;
+        let account = accounts[i].as_ref().ok_or(std::io::Error::other(format!(
+            "Account not found: {}",
+            address
+        )))?;
         let data = VerificationConfig::from_bytes(&account.data)?;
         decoded_accounts.push(crate::shared::DecodedAccount {
             address,
@@ -90,7 +93,7 @@ pub fn fetch_all_maybe_verification_config(
 ) -> Result<Vec<crate::shared::MaybeAccount<VerificationConfig>>, std::io::Error> {
     let accounts = rpc
         .get_multiple_accounts(addresses)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
     let mut decoded_accounts: Vec<crate::shared::MaybeAccount<VerificationConfig>> = Vec::new();
     for i in 0..addresses.len() {
         let address = addresses[i];
@@ -122,7 +125,7 @@ impl anchor_lang::AccountSerialize for VerificationConfig {}
 
 #[cfg(feature = "anchor")]
 impl anchor_lang::Owner for VerificationConfig {
-    fn owner() -> Pubkey {
+    fn owner() -> solana_pubkey::Pubkey {
         crate::SECURITY_TOKEN_PROGRAM_ID
     }
 }
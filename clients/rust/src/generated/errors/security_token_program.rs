@@ -31,6 +31,192 @@ pub enum SecurityTokenProgramError {
     /// 7 - External metadata storage cannot accept metadata data in this instruction
     #[error("External metadata storage cannot accept metadata data in this instruction")]
     ExternalMetadataForbidsData = 0x7,
+    /// 8 - Underlying mint cannot be wrapped into this security token
+    #[error("Underlying mint cannot be wrapped into this security token")]
+    InvalidUnderlyingMint = 0x8,
+    /// 9 - Agent is not permitted to perform this instruction
+    #[error("Agent is not permitted to perform this instruction")]
+    AgentPermissionDenied = 0x9,
+    /// 10 - Token account cannot be linked to or unlinked from this identity
+    #[error("Token account cannot be linked to or unlinked from this identity")]
+    InvalidIdentityWallet = 0xa,
+    /// 11 - Transfer would exceed the investor position limit
+    #[error("Transfer would exceed the investor position limit")]
+    PositionLimitExceeded = 0xb,
+    /// 12 - Transfers of this mint must be accepted by the recipient
+    #[error("Transfers of this mint must be accepted by the recipient")]
+    TransferAcceptanceRequired = 0xc,
+    /// 13 - Transfers above the approval threshold must be requested and approved
+    #[error("Transfers above the approval threshold must be requested and approved")]
+    TransferApprovalRequired = 0xd,
+    /// 14 - Redemption is only open between maturity and the redemption cutoff
+    #[error("Redemption is only open between maturity and the redemption cutoff")]
+    RedemptionNotOpen = 0xe,
+    /// 15 - Unclaimed redemption funds can only be withdrawn after the redemption cutoff
+    #[error("Unclaimed redemption funds can only be withdrawn after the redemption cutoff")]
+    RedemptionCutoffNotReached = 0xf,
+    /// 16 - Oracle value is outside of the configured bounds
+    #[error("Oracle value is outside of the configured bounds")]
+    OracleValueOutOfBounds = 0x10,
+    /// 17 - Multiplier was updated too recently
+    #[error("Multiplier was updated too recently")]
+    MultiplierUpdateTooFrequent = 0x11,
+    /// 18 - Subscription is closed for commitments
+    #[error("Subscription is closed for commitments")]
+    SubscriptionClosed = 0x12,
+    /// 19 - Subscription has not closed yet
+    #[error("Subscription has not closed yet")]
+    SubscriptionNotClosed = 0x13,
+    /// 20 - Allocation exceeds the committed payment
+    #[error("Allocation exceeds the committed payment")]
+    AllocationExceedsCommitment = 0x14,
+    /// 21 - Auction is not accepting bids
+    #[error("Auction is not accepting bids")]
+    AuctionNotOpen = 0x15,
+    /// 22 - Auction is still running or already cleared
+    #[error("Auction is still running or already cleared")]
+    AuctionNotClearable = 0x16,
+    /// 23 - Auction has not been cleared yet
+    #[error("Auction has not been cleared yet")]
+    AuctionNotCleared = 0x17,
+    /// 24 - Holding period of the restricted lot has not elapsed
+    #[error("Holding period of the restricted lot has not elapsed")]
+    HoldingPeriodNotElapsed = 0x18,
+    /// 25 - Token account is suspended
+    #[error("Token account is suspended")]
+    AccountSuspended = 0x19,
+    /// 26 - Freeze has not reached its auto-thaw timestamp
+    #[error("Freeze has not reached its auto-thaw timestamp")]
+    FreezeNotExpired = 0x1a,
+    /// 27 - No newly vested tokens to release
+    #[error("No newly vested tokens to release")]
+    NothingVested = 0x1b,
+    /// 28 - Claim deadline of the distribution has passed
+    #[error("Claim deadline of the distribution has passed")]
+    ClaimDeadlinePassed = 0x1c,
+    /// 29 - Claim window of the distribution is still open
+    #[error("Claim window of the distribution is still open")]
+    ClaimWindowOpen = 0x1d,
+    /// 30 - Claim window of the distribution has not opened
+    #[error("Claim window of the distribution has not opened")]
+    ClaimWindowNotOpen = 0x1e,
+    /// 31 - Claim window of the distribution has already opened
+    #[error("Claim window of the distribution has already opened")]
+    ClaimWindowStarted = 0x1f,
+    /// 32 - Merkle root of the distribution was replaced
+    #[error("Merkle root of the distribution was replaced")]
+    DistributionRootSuperseded = 0x20,
+    /// 33 - Permit is missing or not signed by the holder
+    #[error("Permit is missing or not signed by the holder")]
+    InvalidPermit = 0x21,
+    /// 34 - Permit has expired
+    #[error("Permit has expired")]
+    PermitExpired = 0x22,
+    /// 35 - Permit nonce is not the next nonce of the token account
+    #[error("Permit nonce is not the next nonce of the token account")]
+    InvalidPermitNonce = 0x23,
+    /// 36 - Session key has expired
+    #[error("Session key has expired")]
+    SessionKeyExpired = 0x24,
+    /// 37 - Session key is not allowed to execute this instruction
+    #[error("Session key is not allowed to execute this instruction")]
+    SessionKeyPermissionDenied = 0x25,
+    /// 38 - Operation amount exceeds the session key limit
+    #[error("Operation amount exceeds the session key limit")]
+    SessionKeyLimitExceeded = 0x26,
+    /// 39 - Token account is not recoverable
+    #[error("Token account is not recoverable")]
+    AccountNotRecoverable = 0x27,
+    /// 40 - Leaf already claimed
+    #[error("Leaf already claimed")]
+    LeafAlreadyClaimed = 0x28,
+    /// 41 - Invalid rent recipient
+    #[error("Invalid rent recipient")]
+    InvalidRentRecipient = 0x29,
+    /// 42 - Mint migration creator mismatch
+    #[error("Mint migration creator mismatch")]
+    MintMigrationCreatorMismatch = 0x2a,
+    /// 43 - Required metadata field is missing
+    #[error("Required metadata field is missing")]
+    MissingMetadataField = 0x2b,
+    /// 44 - Required metadata field is malformed
+    #[error("Required metadata field is malformed")]
+    MalformedMetadataField = 0x2c,
+    /// 45 - Metadata is frozen
+    #[error("Metadata is frozen")]
+    MetadataFrozen = 0x2d,
+    /// 46 - Invalid external metadata program
+    #[error("Invalid external metadata program")]
+    InvalidMetadataProgram = 0x2e,
+    /// 47 - Config template mismatch
+    #[error("Config template mismatch")]
+    ConfigTemplateMismatch = 0x2f,
+    /// 48 - Config template not supported for transfer
+    #[error("Config template not supported for transfer")]
+    ConfigTemplateNotSupported = 0x30,
+    /// 49 - Instruction is not top-level
+    #[error("Instruction is not top-level")]
+    InstructionNotTopLevel = 0x31,
+    /// 50 - Mint is paused
+    #[error("Mint is paused")]
+    MintPaused = 0x32,
+    /// 51 - Token account is frozen
+    #[error("Token account is frozen")]
+    AccountFrozen = 0x33,
+    /// 52 - Token program CPI failed
+    #[error("Token program CPI failed")]
+    TokenCpiFailed = 0x34,
+    /// 53 - Invalid reserve token account
+    #[error("Invalid reserve token account")]
+    InvalidReserveAccount = 0x35,
+    /// 54 - Sub-account balances exceed omnibus balance
+    #[error("Sub-account balances exceed omnibus balance")]
+    SubAccountBalanceExceeded = 0x36,
+    /// 55 - Sub-account ledger is not empty
+    #[error("Sub-account ledger is not empty")]
+    SubAccountLedgerNotEmpty = 0x37,
+    /// 56 - Succession not claimable yet
+    #[error("Succession not claimable yet")]
+    SuccessionNotClaimable = 0x38,
+    /// 57 - Succession already claimed
+    #[error("Succession already claimed")]
+    SuccessionClaimed = 0x39,
+    /// 58 - Invalid translation key
+    #[error("Invalid translation key")]
+    InvalidTranslationKey = 0x3a,
+    /// 59 - Document already anchored
+    #[error("Document already anchored")]
+    DocumentAlreadyAnchored = 0x3b,
+    /// 60 - Document not found
+    #[error("Document not found")]
+    DocumentNotFound = 0x3c,
+    /// 61 - Permanent delegate disabled
+    #[error("Permanent delegate disabled")]
+    PermanentDelegateDisabled = 0x3d,
+    /// 62 - Mint supply not zero
+    #[error("Mint supply not zero")]
+    MintSupplyNotZero = 0x3e,
+    /// 63 - Verification config timelocked
+    #[error("Verification config timelocked")]
+    VerificationConfigTimelocked = 0x3f,
+    /// 64 - Pending config not active
+    #[error("Pending config not active")]
+    PendingConfigNotActive = 0x40,
+    /// 65 - Program halted
+    #[error("Program halted")]
+    ProgramHalted = 0x41,
+    /// 66 - Wallet not allowlisted
+    #[error("Wallet not allowlisted")]
+    WalletNotAllowlisted = 0x42,
+    /// 67 - Program config required
+    #[error("Program config required")]
+    ProgramConfigRequired = 0x43,
+    /// 68 - Unwrap amount exceeds the wrapped supply of the wrap vault
+    #[error("Unwrap amount exceeds the wrapped supply of the wrap vault")]
+    WrapVaultSupplyExceeded = 0x44,
+    /// 69 - Coupon claim outdated
+    #[error("Coupon claim outdated")]
+    CouponClaimOutdated = 0x45,
 }
 
 impl From<SecurityTokenProgramError> for solana_program_error::ProgramError {
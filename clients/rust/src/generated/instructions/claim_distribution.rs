@@ -39,6 +39,30 @@ pub struct ClaimDistribution {
     pub token_program: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub withholding_rate_account: Option<solana_pubkey::Pubkey>,
+
+    pub tax_escrow_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub distribution_payout_account: Option<solana_pubkey::Pubkey>,
+
+    pub payout_mint: Option<solana_pubkey::Pubkey>,
+
+    pub payout_escrow_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub payout_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub payout_token_program: Option<solana_pubkey::Pubkey>,
+
+    pub custodian: Option<solana_pubkey::Pubkey>,
+
+    pub instructions_sysvar_account: Option<solana_pubkey::Pubkey>,
+
+    pub claim_deadline_account: solana_pubkey::Pubkey,
+
+    pub distribution_root_account: solana_pubkey::Pubkey,
+
+    pub claim_bitmap_account: solana_pubkey::Pubkey,
 }
 
 impl ClaimDistribution {
@@ -55,7 +79,7 @@ impl ClaimDistribution {
         args: ClaimDistributionInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(25 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
@@ -118,6 +142,116 @@ impl ClaimDistribution {
             self.system_program,
             false,
         ));
+        if let Some(withholding_rate_account) = self.withholding_rate_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                withholding_rate_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(tax_escrow_token_account) = self.tax_escrow_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                tax_escrow_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(distribution_payout_account) = self.distribution_payout_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                distribution_payout_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(payout_mint) = self.payout_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                payout_mint,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(payout_escrow_token_account) = self.payout_escrow_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                payout_escrow_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(payout_token_account) = self.payout_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                payout_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(payout_token_program) = self.payout_token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                payout_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(custodian) = self.custodian {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                custodian, true,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(instructions_sysvar_account) = self.instructions_sysvar_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                instructions_sysvar_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.claim_deadline_account,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.distribution_root_account,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.claim_bitmap_account,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&ClaimDistributionInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -172,6 +306,18 @@ pub struct ClaimDistributionInstructionArgs {
 ///   10. `[]` transfer_hook_program
 ///   11. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 ///   12. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   13. `[optional]` withholding_rate_account
+///   14. `[writable, optional]` tax_escrow_token_account
+///   15. `[optional]` distribution_payout_account
+///   16. `[optional]` payout_mint
+///   17. `[writable, optional]` payout_escrow_token_account
+///   18. `[writable, optional]` payout_token_account
+///   19. `[optional]` payout_token_program
+///   20. `[signer, optional]` custodian
+///   21. `[optional]` instructions_sysvar_account
+///   22. `[]` claim_deadline_account
+///   23. `[]` distribution_root_account
+///   24. `[writable]` claim_bitmap_account
 #[derive(Clone, Debug, Default)]
 pub struct ClaimDistributionBuilder {
     mint: Option<solana_pubkey::Pubkey>,
@@ -187,6 +333,18 @@ pub struct ClaimDistributionBuilder {
     transfer_hook_program: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    withholding_rate_account: Option<solana_pubkey::Pubkey>,
+    tax_escrow_token_account: Option<solana_pubkey::Pubkey>,
+    distribution_payout_account: Option<solana_pubkey::Pubkey>,
+    payout_mint: Option<solana_pubkey::Pubkey>,
+    payout_escrow_token_account: Option<solana_pubkey::Pubkey>,
+    payout_token_account: Option<solana_pubkey::Pubkey>,
+    payout_token_program: Option<solana_pubkey::Pubkey>,
+    custodian: Option<solana_pubkey::Pubkey>,
+    instructions_sysvar_account: Option<solana_pubkey::Pubkey>,
+    claim_deadline_account: Option<solana_pubkey::Pubkey>,
+    distribution_root_account: Option<solana_pubkey::Pubkey>,
+    claim_bitmap_account: Option<solana_pubkey::Pubkey>,
     claim_distribution_args: Option<ClaimDistributionArgs>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -277,6 +435,105 @@ impl ClaimDistributionBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn withholding_rate_account(
+        &mut self,
+        withholding_rate_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.withholding_rate_account = withholding_rate_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn tax_escrow_token_account(
+        &mut self,
+        tax_escrow_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.tax_escrow_token_account = tax_escrow_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn distribution_payout_account(
+        &mut self,
+        distribution_payout_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.distribution_payout_account = distribution_payout_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payout_mint(&mut self, payout_mint: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.payout_mint = payout_mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payout_escrow_token_account(
+        &mut self,
+        payout_escrow_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.payout_escrow_token_account = payout_escrow_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payout_token_account(
+        &mut self,
+        payout_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.payout_token_account = payout_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payout_token_program(
+        &mut self,
+        payout_token_program: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.payout_token_program = payout_token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn custodian(&mut self, custodian: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.custodian = custodian;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn instructions_sysvar_account(
+        &mut self,
+        instructions_sysvar_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.instructions_sysvar_account = instructions_sysvar_account;
+        self
+    }
+    #[inline(always)]
+    pub fn claim_deadline_account(
+        &mut self,
+        claim_deadline_account: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.claim_deadline_account = Some(claim_deadline_account);
+        self
+    }
+    #[inline(always)]
+    pub fn distribution_root_account(
+        &mut self,
+        distribution_root_account: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.distribution_root_account = Some(distribution_root_account);
+        self
+    }
+    #[inline(always)]
+    pub fn claim_bitmap_account(
+        &mut self,
+        claim_bitmap_account: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.claim_bitmap_account = Some(claim_bitmap_account);
+        self
+    }
     #[inline(always)]
     pub fn claim_distribution_args(
         &mut self,
@@ -330,6 +587,24 @@ impl ClaimDistributionBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            withholding_rate_account: self.withholding_rate_account,
+            tax_escrow_token_account: self.tax_escrow_token_account,
+            distribution_payout_account: self.distribution_payout_account,
+            payout_mint: self.payout_mint,
+            payout_escrow_token_account: self.payout_escrow_token_account,
+            payout_token_account: self.payout_token_account,
+            payout_token_program: self.payout_token_program,
+            custodian: self.custodian,
+            instructions_sysvar_account: self.instructions_sysvar_account,
+            claim_deadline_account: self
+                .claim_deadline_account
+                .expect("claim_deadline_account is not set"),
+            distribution_root_account: self
+                .distribution_root_account
+                .expect("distribution_root_account is not set"),
+            claim_bitmap_account: self
+                .claim_bitmap_account
+                .expect("claim_bitmap_account is not set"),
         };
         let args = ClaimDistributionInstructionArgs {
             claim_distribution_args: self
@@ -369,6 +644,30 @@ pub struct ClaimDistributionCpiAccounts<'a, 'b> {
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub withholding_rate_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub tax_escrow_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub distribution_payout_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub payout_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub payout_escrow_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub payout_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub payout_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub custodian: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub instructions_sysvar_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub claim_deadline_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub distribution_root_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub claim_bitmap_account: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `claim_distribution` CPI instruction.
@@ -401,6 +700,30 @@ pub struct ClaimDistributionCpi<'a, 'b> {
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub withholding_rate_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub tax_escrow_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub distribution_payout_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub payout_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub payout_escrow_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub payout_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub payout_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub custodian: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub instructions_sysvar_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub claim_deadline_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub distribution_root_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub claim_bitmap_account: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: ClaimDistributionInstructionArgs,
 }
@@ -426,6 +749,18 @@ impl<'a, 'b> ClaimDistributionCpi<'a, 'b> {
             transfer_hook_program: accounts.transfer_hook_program,
             token_program: accounts.token_program,
             system_program: accounts.system_program,
+            withholding_rate_account: accounts.withholding_rate_account,
+            tax_escrow_token_account: accounts.tax_escrow_token_account,
+            distribution_payout_account: accounts.distribution_payout_account,
+            payout_mint: accounts.payout_mint,
+            payout_escrow_token_account: accounts.payout_escrow_token_account,
+            payout_token_account: accounts.payout_token_account,
+            payout_token_program: accounts.payout_token_program,
+            custodian: accounts.custodian,
+            instructions_sysvar_account: accounts.instructions_sysvar_account,
+            claim_deadline_account: accounts.claim_deadline_account,
+            distribution_root_account: accounts.distribution_root_account,
+            claim_bitmap_account: accounts.claim_bitmap_account,
             __args: args,
         }
     }
@@ -452,7 +787,7 @@ impl<'a, 'b> ClaimDistributionCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(25 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.mint.key,
             false,
@@ -516,6 +851,117 @@ impl<'a, 'b> ClaimDistributionCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(withholding_rate_account) = self.withholding_rate_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *withholding_rate_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(tax_escrow_token_account) = self.tax_escrow_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *tax_escrow_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(distribution_payout_account) = self.distribution_payout_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *distribution_payout_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(payout_mint) = self.payout_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *payout_mint.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(payout_escrow_token_account) = self.payout_escrow_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *payout_escrow_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(payout_token_account) = self.payout_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *payout_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(payout_token_program) = self.payout_token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *payout_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(custodian) = self.custodian {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *custodian.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(instructions_sysvar_account) = self.instructions_sysvar_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *instructions_sysvar_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.claim_deadline_account.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.distribution_root_account.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.claim_bitmap_account.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -532,7 +978,7 @@ impl<'a, 'b> ClaimDistributionCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(14 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(26 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.verification_config.clone());
@@ -551,6 +997,36 @@ impl<'a, 'b> ClaimDistributionCpi<'a, 'b> {
         account_infos.push(self.transfer_hook_program.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(withholding_rate_account) = self.withholding_rate_account {
+            account_infos.push(withholding_rate_account.clone());
+        }
+        if let Some(tax_escrow_token_account) = self.tax_escrow_token_account {
+            account_infos.push(tax_escrow_token_account.clone());
+        }
+        if let Some(distribution_payout_account) = self.distribution_payout_account {
+            account_infos.push(distribution_payout_account.clone());
+        }
+        if let Some(payout_mint) = self.payout_mint {
+            account_infos.push(payout_mint.clone());
+        }
+        if let Some(payout_escrow_token_account) = self.payout_escrow_token_account {
+            account_infos.push(payout_escrow_token_account.clone());
+        }
+        if let Some(payout_token_account) = self.payout_token_account {
+            account_infos.push(payout_token_account.clone());
+        }
+        if let Some(payout_token_program) = self.payout_token_program {
+            account_infos.push(payout_token_program.clone());
+        }
+        if let Some(custodian) = self.custodian {
+            account_infos.push(custodian.clone());
+        }
+        if let Some(instructions_sysvar_account) = self.instructions_sysvar_account {
+            account_infos.push(instructions_sysvar_account.clone());
+        }
+        account_infos.push(self.claim_deadline_account.clone());
+        account_infos.push(self.distribution_root_account.clone());
+        account_infos.push(self.claim_bitmap_account.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -580,6 +1056,18 @@ impl<'a, 'b> ClaimDistributionCpi<'a, 'b> {
 ///   10. `[]` transfer_hook_program
 ///   11. `[]` token_program
 ///   12. `[]` system_program
+///   13. `[optional]` withholding_rate_account
+///   14. `[writable, optional]` tax_escrow_token_account
+///   15. `[optional]` distribution_payout_account
+///   16. `[optional]` payout_mint
+///   17. `[writable, optional]` payout_escrow_token_account
+///   18. `[writable, optional]` payout_token_account
+///   19. `[optional]` payout_token_program
+///   20. `[signer, optional]` custodian
+///   21. `[optional]` instructions_sysvar_account
+///   22. `[]` claim_deadline_account
+///   23. `[]` distribution_root_account
+///   24. `[writable]` claim_bitmap_account
 #[derive(Clone, Debug)]
 pub struct ClaimDistributionCpiBuilder<'a, 'b> {
     instruction: Box<ClaimDistributionCpiBuilderInstruction<'a, 'b>>,
@@ -602,6 +1090,18 @@ impl<'a, 'b> ClaimDistributionCpiBuilder<'a, 'b> {
             transfer_hook_program: None,
             token_program: None,
             system_program: None,
+            withholding_rate_account: None,
+            tax_escrow_token_account: None,
+            distribution_payout_account: None,
+            payout_mint: None,
+            payout_escrow_token_account: None,
+            payout_token_account: None,
+            payout_token_program: None,
+            custodian: None,
+            instructions_sysvar_account: None,
+            claim_deadline_account: None,
+            distribution_root_account: None,
+            claim_bitmap_account: None,
             claim_distribution_args: None,
             __remaining_accounts: Vec::new(),
         });
@@ -707,6 +1207,111 @@ impl<'a, 'b> ClaimDistributionCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn withholding_rate_account(
+        &mut self,
+        withholding_rate_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.withholding_rate_account = withholding_rate_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn tax_escrow_token_account(
+        &mut self,
+        tax_escrow_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.tax_escrow_token_account = tax_escrow_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn distribution_payout_account(
+        &mut self,
+        distribution_payout_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.distribution_payout_account = distribution_payout_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payout_mint(
+        &mut self,
+        payout_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.payout_mint = payout_mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payout_escrow_token_account(
+        &mut self,
+        payout_escrow_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.payout_escrow_token_account = payout_escrow_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payout_token_account(
+        &mut self,
+        payout_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.payout_token_account = payout_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payout_token_program(
+        &mut self,
+        payout_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.payout_token_program = payout_token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn custodian(
+        &mut self,
+        custodian: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.custodian = custodian;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn instructions_sysvar_account(
+        &mut self,
+        instructions_sysvar_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.instructions_sysvar_account = instructions_sysvar_account;
+        self
+    }
+    #[inline(always)]
+    pub fn claim_deadline_account(
+        &mut self,
+        claim_deadline_account: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.claim_deadline_account = Some(claim_deadline_account);
+        self
+    }
+    #[inline(always)]
+    pub fn distribution_root_account(
+        &mut self,
+        distribution_root_account: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.distribution_root_account = Some(distribution_root_account);
+        self
+    }
+    #[inline(always)]
+    pub fn claim_bitmap_account(
+        &mut self,
+        claim_bitmap_account: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.claim_bitmap_account = Some(claim_bitmap_account);
+        self
+    }
     #[inline(always)]
     pub fn claim_distribution_args(
         &mut self,
@@ -811,6 +1416,39 @@ impl<'a, 'b> ClaimDistributionCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            withholding_rate_account: self.instruction.withholding_rate_account,
+
+            tax_escrow_token_account: self.instruction.tax_escrow_token_account,
+
+            distribution_payout_account: self.instruction.distribution_payout_account,
+
+            payout_mint: self.instruction.payout_mint,
+
+            payout_escrow_token_account: self.instruction.payout_escrow_token_account,
+
+            payout_token_account: self.instruction.payout_token_account,
+
+            payout_token_program: self.instruction.payout_token_program,
+
+            custodian: self.instruction.custodian,
+
+            instructions_sysvar_account: self.instruction.instructions_sysvar_account,
+
+            claim_deadline_account: self
+                .instruction
+                .claim_deadline_account
+                .expect("claim_deadline_account is not set"),
+
+            distribution_root_account: self
+                .instruction
+                .distribution_root_account
+                .expect("distribution_root_account is not set"),
+
+            claim_bitmap_account: self
+                .instruction
+                .claim_bitmap_account
+                .expect("claim_bitmap_account is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -836,6 +1474,18 @@ struct ClaimDistributionCpiBuilderInstruction<'a, 'b> {
     transfer_hook_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    withholding_rate_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    tax_escrow_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    distribution_payout_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    payout_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    payout_escrow_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    payout_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    payout_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    custodian: Option<&'b solana_account_info::AccountInfo<'a>>,
+    instructions_sysvar_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    claim_deadline_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    distribution_root_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    claim_bitmap_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     claim_distribution_args: Option<ClaimDistributionArgs>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -22,7 +22,7 @@ pub struct CloseActionReceiptAccount {
 
     pub receipt_account: solana_pubkey::Pubkey,
 
-    pub destination: solana_pubkey::Pubkey,
+    pub rent_recipient: solana_pubkey::Pubkey,
 
     pub mint_account: solana_pubkey::Pubkey,
 }
@@ -58,7 +58,7 @@ impl CloseActionReceiptAccount {
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(
-            self.destination,
+            self.rent_recipient,
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -110,7 +110,7 @@ pub struct CloseActionReceiptAccountInstructionArgs {
 ///   1. `[]` verification_config_or_mint_authority
 ///   2. `[]` instructions_sysvar_or_creator
 ///   3. `[writable]` receipt_account
-///   4. `[writable]` destination
+///   4. `[writable]` rent_recipient
 ///   5. `[]` mint_account
 #[derive(Clone, Debug, Default)]
 pub struct CloseActionReceiptAccountBuilder {
@@ -118,7 +118,7 @@ pub struct CloseActionReceiptAccountBuilder {
     verification_config_or_mint_authority: Option<solana_pubkey::Pubkey>,
     instructions_sysvar_or_creator: Option<solana_pubkey::Pubkey>,
     receipt_account: Option<solana_pubkey::Pubkey>,
-    destination: Option<solana_pubkey::Pubkey>,
+    rent_recipient: Option<solana_pubkey::Pubkey>,
     mint_account: Option<solana_pubkey::Pubkey>,
     close_action_receipt_args: Option<CloseActionReceiptArgs>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -155,8 +155,8 @@ impl CloseActionReceiptAccountBuilder {
         self
     }
     #[inline(always)]
-    pub fn destination(&mut self, destination: solana_pubkey::Pubkey) -> &mut Self {
-        self.destination = Some(destination);
+    pub fn rent_recipient(&mut self, rent_recipient: solana_pubkey::Pubkey) -> &mut Self {
+        self.rent_recipient = Some(rent_recipient);
         self
     }
     #[inline(always)]
@@ -198,7 +198,7 @@ impl CloseActionReceiptAccountBuilder {
                 .instructions_sysvar_or_creator
                 .expect("instructions_sysvar_or_creator is not set"),
             receipt_account: self.receipt_account.expect("receipt_account is not set"),
-            destination: self.destination.expect("destination is not set"),
+            rent_recipient: self.rent_recipient.expect("rent_recipient is not set"),
             mint_account: self.mint_account.expect("mint_account is not set"),
         };
         let args = CloseActionReceiptAccountInstructionArgs {
@@ -222,7 +222,7 @@ pub struct CloseActionReceiptAccountCpiAccounts<'a, 'b> {
 
     pub receipt_account: &'b solana_account_info::AccountInfo<'a>,
 
-    pub destination: &'b solana_account_info::AccountInfo<'a>,
+    pub rent_recipient: &'b solana_account_info::AccountInfo<'a>,
 
     pub mint_account: &'b solana_account_info::AccountInfo<'a>,
 }
@@ -240,7 +240,7 @@ pub struct CloseActionReceiptAccountCpi<'a, 'b> {
 
     pub receipt_account: &'b solana_account_info::AccountInfo<'a>,
 
-    pub destination: &'b solana_account_info::AccountInfo<'a>,
+    pub rent_recipient: &'b solana_account_info::AccountInfo<'a>,
 
     pub mint_account: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
@@ -259,7 +259,7 @@ impl<'a, 'b> CloseActionReceiptAccountCpi<'a, 'b> {
             verification_config_or_mint_authority: accounts.verification_config_or_mint_authority,
             instructions_sysvar_or_creator: accounts.instructions_sysvar_or_creator,
             receipt_account: accounts.receipt_account,
-            destination: accounts.destination,
+            rent_recipient: accounts.rent_recipient,
             mint_account: accounts.mint_account,
             __args: args,
         }
@@ -305,7 +305,7 @@ impl<'a, 'b> CloseActionReceiptAccountCpi<'a, 'b> {
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(
-            *self.destination.key,
+            *self.rent_recipient.key,
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -334,7 +334,7 @@ impl<'a, 'b> CloseActionReceiptAccountCpi<'a, 'b> {
         account_infos.push(self.verification_config_or_mint_authority.clone());
         account_infos.push(self.instructions_sysvar_or_creator.clone());
         account_infos.push(self.receipt_account.clone());
-        account_infos.push(self.destination.clone());
+        account_infos.push(self.rent_recipient.clone());
         account_infos.push(self.mint_account.clone());
         remaining_accounts
             .iter()
@@ -356,7 +356,7 @@ impl<'a, 'b> CloseActionReceiptAccountCpi<'a, 'b> {
 ///   1. `[]` verification_config_or_mint_authority
 ///   2. `[]` instructions_sysvar_or_creator
 ///   3. `[writable]` receipt_account
-///   4. `[writable]` destination
+///   4. `[writable]` rent_recipient
 ///   5. `[]` mint_account
 #[derive(Clone, Debug)]
 pub struct CloseActionReceiptAccountCpiBuilder<'a, 'b> {
@@ -371,7 +371,7 @@ impl<'a, 'b> CloseActionReceiptAccountCpiBuilder<'a, 'b> {
             verification_config_or_mint_authority: None,
             instructions_sysvar_or_creator: None,
             receipt_account: None,
-            destination: None,
+            rent_recipient: None,
             mint_account: None,
             close_action_receipt_args: None,
             __remaining_accounts: Vec::new(),
@@ -409,11 +409,11 @@ impl<'a, 'b> CloseActionReceiptAccountCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn destination(
+    pub fn rent_recipient(
         &mut self,
-        destination: &'b solana_account_info::AccountInfo<'a>,
+        rent_recipient: &'b solana_account_info::AccountInfo<'a>,
     ) -> &mut Self {
-        self.instruction.destination = Some(destination);
+        self.instruction.rent_recipient = Some(rent_recipient);
         self
     }
     #[inline(always)]
@@ -493,10 +493,10 @@ impl<'a, 'b> CloseActionReceiptAccountCpiBuilder<'a, 'b> {
                 .receipt_account
                 .expect("receipt_account is not set"),
 
-            destination: self
+            rent_recipient: self
                 .instruction
-                .destination
-                .expect("destination is not set"),
+                .rent_recipient
+                .expect("rent_recipient is not set"),
 
             mint_account: self
                 .instruction
@@ -518,7 +518,7 @@ struct CloseActionReceiptAccountCpiBuilderInstruction<'a, 'b> {
     verification_config_or_mint_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     instructions_sysvar_or_creator: Option<&'b solana_account_info::AccountInfo<'a>>,
     receipt_account: Option<&'b solana_account_info::AccountInfo<'a>>,
-    destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    rent_recipient: Option<&'b solana_account_info::AccountInfo<'a>>,
     mint_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     close_action_receipt_args: Option<CloseActionReceiptArgs>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -22,7 +22,7 @@ pub struct CloseClaimReceiptAccount {
 
     pub receipt_account: solana_pubkey::Pubkey,
 
-    pub destination: solana_pubkey::Pubkey,
+    pub rent_recipient: solana_pubkey::Pubkey,
 
     pub mint_account: solana_pubkey::Pubkey,
 
@@ -62,7 +62,7 @@ impl CloseClaimReceiptAccount {
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(
-            self.destination,
+            self.rent_recipient,
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -129,7 +129,7 @@ pub struct CloseClaimReceiptAccountInstructionArgs {
 ///   1. `[]` verification_config_or_mint_authority
 ///   2. `[]` instructions_sysvar_or_creator
 ///   3. `[writable]` receipt_account
-///   4. `[writable]` destination
+///   4. `[writable]` rent_recipient
 ///   5. `[]` mint_account
 ///   6. `[]` eligible_token_account
 ///   7. `[optional]` proof_account
@@ -139,7 +139,7 @@ pub struct CloseClaimReceiptAccountBuilder {
     verification_config_or_mint_authority: Option<solana_pubkey::Pubkey>,
     instructions_sysvar_or_creator: Option<solana_pubkey::Pubkey>,
     receipt_account: Option<solana_pubkey::Pubkey>,
-    destination: Option<solana_pubkey::Pubkey>,
+    rent_recipient: Option<solana_pubkey::Pubkey>,
     mint_account: Option<solana_pubkey::Pubkey>,
     eligible_token_account: Option<solana_pubkey::Pubkey>,
     proof_account: Option<solana_pubkey::Pubkey>,
@@ -178,8 +178,8 @@ impl CloseClaimReceiptAccountBuilder {
         self
     }
     #[inline(always)]
-    pub fn destination(&mut self, destination: solana_pubkey::Pubkey) -> &mut Self {
-        self.destination = Some(destination);
+    pub fn rent_recipient(&mut self, rent_recipient: solana_pubkey::Pubkey) -> &mut Self {
+        self.rent_recipient = Some(rent_recipient);
         self
     }
     #[inline(always)]
@@ -235,7 +235,7 @@ impl CloseClaimReceiptAccountBuilder {
                 .instructions_sysvar_or_creator
                 .expect("instructions_sysvar_or_creator is not set"),
             receipt_account: self.receipt_account.expect("receipt_account is not set"),
-            destination: self.destination.expect("destination is not set"),
+            rent_recipient: self.rent_recipient.expect("rent_recipient is not set"),
             mint_account: self.mint_account.expect("mint_account is not set"),
             eligible_token_account: self
                 .eligible_token_account
@@ -263,7 +263,7 @@ pub struct CloseClaimReceiptAccountCpiAccounts<'a, 'b> {
 
     pub receipt_account: &'b solana_account_info::AccountInfo<'a>,
 
-    pub destination: &'b solana_account_info::AccountInfo<'a>,
+    pub rent_recipient: &'b solana_account_info::AccountInfo<'a>,
 
     pub mint_account: &'b solana_account_info::AccountInfo<'a>,
 
@@ -285,7 +285,7 @@ pub struct CloseClaimReceiptAccountCpi<'a, 'b> {
 
     pub receipt_account: &'b solana_account_info::AccountInfo<'a>,
 
-    pub destination: &'b solana_account_info::AccountInfo<'a>,
+    pub rent_recipient: &'b solana_account_info::AccountInfo<'a>,
 
     pub mint_account: &'b solana_account_info::AccountInfo<'a>,
 
@@ -308,7 +308,7 @@ impl<'a, 'b> CloseClaimReceiptAccountCpi<'a, 'b> {
             verification_config_or_mint_authority: accounts.verification_config_or_mint_authority,
             instructions_sysvar_or_creator: accounts.instructions_sysvar_or_creator,
             receipt_account: accounts.receipt_account,
-            destination: accounts.destination,
+            rent_recipient: accounts.rent_recipient,
             mint_account: accounts.mint_account,
             eligible_token_account: accounts.eligible_token_account,
             proof_account: accounts.proof_account,
@@ -356,7 +356,7 @@ impl<'a, 'b> CloseClaimReceiptAccountCpi<'a, 'b> {
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(
-            *self.destination.key,
+            *self.rent_recipient.key,
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -400,7 +400,7 @@ impl<'a, 'b> CloseClaimReceiptAccountCpi<'a, 'b> {
         account_infos.push(self.verification_config_or_mint_authority.clone());
         account_infos.push(self.instructions_sysvar_or_creator.clone());
         account_infos.push(self.receipt_account.clone());
-        account_infos.push(self.destination.clone());
+        account_infos.push(self.rent_recipient.clone());
         account_infos.push(self.mint_account.clone());
         account_infos.push(self.eligible_token_account.clone());
         if let Some(proof_account) = self.proof_account {
@@ -426,7 +426,7 @@ impl<'a, 'b> CloseClaimReceiptAccountCpi<'a, 'b> {
 ///   1. `[]` verification_config_or_mint_authority
 ///   2. `[]` instructions_sysvar_or_creator
 ///   3. `[writable]` receipt_account
-///   4. `[writable]` destination
+///   4. `[writable]` rent_recipient
 ///   5. `[]` mint_account
 ///   6. `[]` eligible_token_account
 ///   7. `[optional]` proof_account
@@ -443,7 +443,7 @@ impl<'a, 'b> CloseClaimReceiptAccountCpiBuilder<'a, 'b> {
             verification_config_or_mint_authority: None,
             instructions_sysvar_or_creator: None,
             receipt_account: None,
-            destination: None,
+            rent_recipient: None,
             mint_account: None,
             eligible_token_account: None,
             proof_account: None,
@@ -483,11 +483,11 @@ impl<'a, 'b> CloseClaimReceiptAccountCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn destination(
+    pub fn rent_recipient(
         &mut self,
-        destination: &'b solana_account_info::AccountInfo<'a>,
+        rent_recipient: &'b solana_account_info::AccountInfo<'a>,
     ) -> &mut Self {
-        self.instruction.destination = Some(destination);
+        self.instruction.rent_recipient = Some(rent_recipient);
         self
     }
     #[inline(always)]
@@ -584,10 +584,10 @@ impl<'a, 'b> CloseClaimReceiptAccountCpiBuilder<'a, 'b> {
                 .receipt_account
                 .expect("receipt_account is not set"),
 
-            destination: self
+            rent_recipient: self
                 .instruction
-                .destination
-                .expect("destination is not set"),
+                .rent_recipient
+                .expect("rent_recipient is not set"),
 
             mint_account: self
                 .instruction
@@ -616,7 +616,7 @@ struct CloseClaimReceiptAccountCpiBuilderInstruction<'a, 'b> {
     verification_config_or_mint_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     instructions_sysvar_or_creator: Option<&'b solana_account_info::AccountInfo<'a>>,
     receipt_account: Option<&'b solana_account_info::AccountInfo<'a>>,
-    destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    rent_recipient: Option<&'b solana_account_info::AccountInfo<'a>>,
     mint_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     eligible_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     proof_account: Option<&'b solana_account_info::AccountInfo<'a>>,
@@ -22,7 +22,7 @@ pub struct CloseRateAccount {
 
     pub rate_account: solana_pubkey::Pubkey,
 
-    pub destination: solana_pubkey::Pubkey,
+    pub rent_recipient: solana_pubkey::Pubkey,
 
     pub mint_from: solana_pubkey::Pubkey,
 
@@ -60,7 +60,7 @@ impl CloseRateAccount {
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(
-            self.destination,
+            self.rent_recipient,
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -116,7 +116,7 @@ pub struct CloseRateAccountInstructionArgs {
 ///   1. `[]` verification_config_or_mint_authority
 ///   2. `[]` instructions_sysvar_or_creator
 ///   3. `[writable]` rate_account
-///   4. `[writable]` destination
+///   4. `[writable]` rent_recipient
 ///   5. `[]` mint_from
 ///   6. `[]` mint_to
 #[derive(Clone, Debug, Default)]
@@ -125,7 +125,7 @@ pub struct CloseRateAccountBuilder {
     verification_config_or_mint_authority: Option<solana_pubkey::Pubkey>,
     instructions_sysvar_or_creator: Option<solana_pubkey::Pubkey>,
     rate_account: Option<solana_pubkey::Pubkey>,
-    destination: Option<solana_pubkey::Pubkey>,
+    rent_recipient: Option<solana_pubkey::Pubkey>,
     mint_from: Option<solana_pubkey::Pubkey>,
     mint_to: Option<solana_pubkey::Pubkey>,
     close_rate_args: Option<CloseRateArgs>,
@@ -163,8 +163,8 @@ impl CloseRateAccountBuilder {
         self
     }
     #[inline(always)]
-    pub fn destination(&mut self, destination: solana_pubkey::Pubkey) -> &mut Self {
-        self.destination = Some(destination);
+    pub fn rent_recipient(&mut self, rent_recipient: solana_pubkey::Pubkey) -> &mut Self {
+        self.rent_recipient = Some(rent_recipient);
         self
     }
     #[inline(always)]
@@ -208,7 +208,7 @@ impl CloseRateAccountBuilder {
                 .instructions_sysvar_or_creator
                 .expect("instructions_sysvar_or_creator is not set"),
             rate_account: self.rate_account.expect("rate_account is not set"),
-            destination: self.destination.expect("destination is not set"),
+            rent_recipient: self.rent_recipient.expect("rent_recipient is not set"),
             mint_from: self.mint_from.expect("mint_from is not set"),
             mint_to: self.mint_to.expect("mint_to is not set"),
         };
@@ -233,7 +233,7 @@ pub struct CloseRateAccountCpiAccounts<'a, 'b> {
 
     pub rate_account: &'b solana_account_info::AccountInfo<'a>,
 
-    pub destination: &'b solana_account_info::AccountInfo<'a>,
+    pub rent_recipient: &'b solana_account_info::AccountInfo<'a>,
 
     pub mint_from: &'b solana_account_info::AccountInfo<'a>,
 
@@ -253,7 +253,7 @@ pub struct CloseRateAccountCpi<'a, 'b> {
 
     pub rate_account: &'b solana_account_info::AccountInfo<'a>,
 
-    pub destination: &'b solana_account_info::AccountInfo<'a>,
+    pub rent_recipient: &'b solana_account_info::AccountInfo<'a>,
 
     pub mint_from: &'b solana_account_info::AccountInfo<'a>,
 
@@ -274,7 +274,7 @@ impl<'a, 'b> CloseRateAccountCpi<'a, 'b> {
             verification_config_or_mint_authority: accounts.verification_config_or_mint_authority,
             instructions_sysvar_or_creator: accounts.instructions_sysvar_or_creator,
             rate_account: accounts.rate_account,
-            destination: accounts.destination,
+            rent_recipient: accounts.rent_recipient,
             mint_from: accounts.mint_from,
             mint_to: accounts.mint_to,
             __args: args,
@@ -321,7 +321,7 @@ impl<'a, 'b> CloseRateAccountCpi<'a, 'b> {
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(
-            *self.destination.key,
+            *self.rent_recipient.key,
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -354,7 +354,7 @@ impl<'a, 'b> CloseRateAccountCpi<'a, 'b> {
         account_infos.push(self.verification_config_or_mint_authority.clone());
         account_infos.push(self.instructions_sysvar_or_creator.clone());
         account_infos.push(self.rate_account.clone());
-        account_infos.push(self.destination.clone());
+        account_infos.push(self.rent_recipient.clone());
         account_infos.push(self.mint_from.clone());
         account_infos.push(self.mint_to.clone());
         remaining_accounts
@@ -377,7 +377,7 @@ impl<'a, 'b> CloseRateAccountCpi<'a, 'b> {
 ///   1. `[]` verification_config_or_mint_authority
 ///   2. `[]` instructions_sysvar_or_creator
 ///   3. `[writable]` rate_account
-///   4. `[writable]` destination
+///   4. `[writable]` rent_recipient
 ///   5. `[]` mint_from
 ///   6. `[]` mint_to
 #[derive(Clone, Debug)]
@@ -393,7 +393,7 @@ impl<'a, 'b> CloseRateAccountCpiBuilder<'a, 'b> {
             verification_config_or_mint_authority: None,
             instructions_sysvar_or_creator: None,
             rate_account: None,
-            destination: None,
+            rent_recipient: None,
             mint_from: None,
             mint_to: None,
             close_rate_args: None,
@@ -432,11 +432,11 @@ impl<'a, 'b> CloseRateAccountCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn destination(
+    pub fn rent_recipient(
         &mut self,
-        destination: &'b solana_account_info::AccountInfo<'a>,
+        rent_recipient: &'b solana_account_info::AccountInfo<'a>,
     ) -> &mut Self {
-        self.instruction.destination = Some(destination);
+        self.instruction.rent_recipient = Some(rent_recipient);
         self
     }
     #[inline(always)]
@@ -515,10 +515,10 @@ impl<'a, 'b> CloseRateAccountCpiBuilder<'a, 'b> {
                 .rate_account
                 .expect("rate_account is not set"),
 
-            destination: self
+            rent_recipient: self
                 .instruction
-                .destination
-                .expect("destination is not set"),
+                .rent_recipient
+                .expect("rent_recipient is not set"),
 
             mint_from: self.instruction.mint_from.expect("mint_from is not set"),
 
@@ -539,7 +539,7 @@ struct CloseRateAccountCpiBuilderInstruction<'a, 'b> {
     verification_config_or_mint_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     instructions_sysvar_or_creator: Option<&'b solana_account_info::AccountInfo<'a>>,
     rate_account: Option<&'b solana_account_info::AccountInfo<'a>>,
-    destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    rent_recipient: Option<&'b solana_account_info::AccountInfo<'a>>,
     mint_from: Option<&'b solana_account_info::AccountInfo<'a>>,
     mint_to: Option<&'b solana_account_info::AccountInfo<'a>>,
     close_rate_args: Option<CloseRateArgs>,
@@ -41,6 +41,8 @@ pub struct Convert {
     pub token_program: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub oracle: Option<solana_pubkey::Pubkey>,
 }
 
 impl Convert {
@@ -54,7 +56,7 @@ impl Convert {
         args: ConvertInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
@@ -101,6 +103,14 @@ impl Convert {
             self.system_program,
             false,
         ));
+        if let Some(oracle) = self.oracle {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(oracle, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&ConvertInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -156,6 +166,7 @@ pub struct ConvertInstructionArgs {
 ///   11. `[writable]` receipt_account
 ///   12. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 ///   13. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   14. `[optional]` oracle
 #[derive(Clone, Debug, Default)]
 pub struct ConvertBuilder {
     mint: Option<solana_pubkey::Pubkey>,
@@ -172,6 +183,7 @@ pub struct ConvertBuilder {
     receipt_account: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    oracle: Option<solana_pubkey::Pubkey>,
     convert_args: Option<ConvertArgs>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -253,6 +265,12 @@ impl ConvertBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn oracle(&mut self, oracle: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.oracle = oracle;
+        self
+    }
     #[inline(always)]
     pub fn convert_args(&mut self, convert_args: ConvertArgs) -> &mut Self {
         self.convert_args = Some(convert_args);
@@ -302,6 +320,7 @@ impl ConvertBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            oracle: self.oracle,
         };
         let args = ConvertInstructionArgs {
             convert_args: self.convert_args.clone().expect("convert_args is not set"),
@@ -340,6 +359,8 @@ pub struct ConvertCpiAccounts<'a, 'b> {
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `convert` CPI instruction.
@@ -374,6 +395,8 @@ pub struct ConvertCpi<'a, 'b> {
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: ConvertInstructionArgs,
 }
@@ -400,6 +423,7 @@ impl<'a, 'b> ConvertCpi<'a, 'b> {
             receipt_account: accounts.receipt_account,
             token_program: accounts.token_program,
             system_program: accounts.system_program,
+            oracle: accounts.oracle,
             __args: args,
         }
     }
@@ -426,7 +450,7 @@ impl<'a, 'b> ConvertCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.mint.key,
             false,
@@ -480,6 +504,17 @@ impl<'a, 'b> ConvertCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(oracle) = self.oracle {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *oracle.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -496,7 +531,7 @@ impl<'a, 'b> ConvertCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(15 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(16 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.verification_config.clone());
@@ -512,6 +547,9 @@ impl<'a, 'b> ConvertCpi<'a, 'b> {
         account_infos.push(self.receipt_account.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(oracle) = self.oracle {
+            account_infos.push(oracle.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -542,6 +580,7 @@ impl<'a, 'b> ConvertCpi<'a, 'b> {
 ///   11. `[writable]` receipt_account
 ///   12. `[]` token_program
 ///   13. `[]` system_program
+///   14. `[optional]` oracle
 #[derive(Clone, Debug)]
 pub struct ConvertCpiBuilder<'a, 'b> {
     instruction: Box<ConvertCpiBuilderInstruction<'a, 'b>>,
@@ -565,6 +604,7 @@ impl<'a, 'b> ConvertCpiBuilder<'a, 'b> {
             receipt_account: None,
             token_program: None,
             system_program: None,
+            oracle: None,
             convert_args: None,
             __remaining_accounts: Vec::new(),
         });
@@ -670,6 +710,15 @@ impl<'a, 'b> ConvertCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn oracle(
+        &mut self,
+        oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.oracle = oracle;
+        self
+    }
     #[inline(always)]
     pub fn convert_args(&mut self, convert_args: ConvertArgs) -> &mut Self {
         self.instruction.convert_args = Some(convert_args);
@@ -776,6 +825,8 @@ impl<'a, 'b> ConvertCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            oracle: self.instruction.oracle,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -802,6 +853,7 @@ struct ConvertCpiBuilderInstruction<'a, 'b> {
     receipt_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
     convert_args: Option<ConvertArgs>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -33,6 +33,8 @@ pub struct CreateDistributionEscrow {
     pub associated_token_account_program: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub claim_bitmap_account: Option<solana_pubkey::Pubkey>,
 }
 
 impl CreateDistributionEscrow {
@@ -49,7 +51,7 @@ impl CreateDistributionEscrow {
         args: CreateDistributionEscrowInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
@@ -86,6 +88,17 @@ impl CreateDistributionEscrow {
             self.system_program,
             false,
         ));
+        if let Some(claim_bitmap_account) = self.claim_bitmap_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                claim_bitmap_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&CreateDistributionEscrowInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -137,6 +150,7 @@ pub struct CreateDistributionEscrowInstructionArgs {
 ///   7. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 ///   8. `[]` associated_token_account_program
 ///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   10. `[writable, optional]` claim_bitmap_account
 #[derive(Clone, Debug, Default)]
 pub struct CreateDistributionEscrowBuilder {
     mint: Option<solana_pubkey::Pubkey>,
@@ -149,6 +163,7 @@ pub struct CreateDistributionEscrowBuilder {
     token_program: Option<solana_pubkey::Pubkey>,
     associated_token_account_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    claim_bitmap_account: Option<solana_pubkey::Pubkey>,
     create_distribution_escrow_args: Option<CreateDistributionEscrowArgs>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -224,6 +239,15 @@ impl CreateDistributionEscrowBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn claim_bitmap_account(
+        &mut self,
+        claim_bitmap_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.claim_bitmap_account = claim_bitmap_account;
+        self
+    }
     #[inline(always)]
     pub fn create_distribution_escrow_args(
         &mut self,
@@ -276,6 +300,7 @@ impl CreateDistributionEscrowBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            claim_bitmap_account: self.claim_bitmap_account,
         };
         let args = CreateDistributionEscrowInstructionArgs {
             create_distribution_escrow_args: self
@@ -309,6 +334,8 @@ pub struct CreateDistributionEscrowCpiAccounts<'a, 'b> {
     pub associated_token_account_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub claim_bitmap_account: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `create_distribution_escrow` CPI instruction.
@@ -335,6 +362,8 @@ pub struct CreateDistributionEscrowCpi<'a, 'b> {
     pub associated_token_account_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub claim_bitmap_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: CreateDistributionEscrowInstructionArgs,
 }
@@ -357,6 +386,7 @@ impl<'a, 'b> CreateDistributionEscrowCpi<'a, 'b> {
             token_program: accounts.token_program,
             associated_token_account_program: accounts.associated_token_account_program,
             system_program: accounts.system_program,
+            claim_bitmap_account: accounts.claim_bitmap_account,
             __args: args,
         }
     }
@@ -383,7 +413,7 @@ impl<'a, 'b> CreateDistributionEscrowCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.mint.key,
             false,
@@ -421,6 +451,17 @@ impl<'a, 'b> CreateDistributionEscrowCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(claim_bitmap_account) = self.claim_bitmap_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *claim_bitmap_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -437,7 +478,7 @@ impl<'a, 'b> CreateDistributionEscrowCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.verification_config_or_mint_authority.clone());
@@ -449,6 +490,9 @@ impl<'a, 'b> CreateDistributionEscrowCpi<'a, 'b> {
         account_infos.push(self.token_program.clone());
         account_infos.push(self.associated_token_account_program.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(claim_bitmap_account) = self.claim_bitmap_account {
+            account_infos.push(claim_bitmap_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -475,6 +519,7 @@ impl<'a, 'b> CreateDistributionEscrowCpi<'a, 'b> {
 ///   7. `[]` token_program
 ///   8. `[]` associated_token_account_program
 ///   9. `[]` system_program
+///   10. `[writable, optional]` claim_bitmap_account
 #[derive(Clone, Debug)]
 pub struct CreateDistributionEscrowCpiBuilder<'a, 'b> {
     instruction: Box<CreateDistributionEscrowCpiBuilderInstruction<'a, 'b>>,
@@ -494,6 +539,7 @@ impl<'a, 'b> CreateDistributionEscrowCpiBuilder<'a, 'b> {
             token_program: None,
             associated_token_account_program: None,
             system_program: None,
+            claim_bitmap_account: None,
             create_distribution_escrow_args: None,
             __remaining_accounts: Vec::new(),
         });
@@ -574,6 +620,15 @@ impl<'a, 'b> CreateDistributionEscrowCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn claim_bitmap_account(
+        &mut self,
+        claim_bitmap_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.claim_bitmap_account = claim_bitmap_account;
+        self
+    }
     #[inline(always)]
     pub fn create_distribution_escrow_args(
         &mut self,
@@ -669,6 +724,8 @@ impl<'a, 'b> CreateDistributionEscrowCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            claim_bitmap_account: self.instruction.claim_bitmap_account,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -691,6 +748,7 @@ struct CreateDistributionEscrowCpiBuilderInstruction<'a, 'b> {
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     associated_token_account_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    claim_bitmap_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     create_distribution_escrow_args: Option<CreateDistributionEscrowArgs>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
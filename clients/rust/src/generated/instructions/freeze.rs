@@ -26,19 +26,26 @@ pub struct Freeze {
     pub token_account: solana_pubkey::Pubkey,
 
     pub token_program: solana_pubkey::Pubkey,
+
+    pub payer: Option<solana_pubkey::Pubkey>,
+
+    pub freeze_expiry_account: Option<solana_pubkey::Pubkey>,
+
+    pub system_program: Option<solana_pubkey::Pubkey>,
 }
 
 impl Freeze {
-    pub fn instruction(&self) -> solana_instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(&self, args: FreezeInstructionArgs) -> solana_instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: FreezeInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
@@ -66,8 +73,40 @@ impl Freeze {
             self.token_program,
             false,
         ));
+        if let Some(payer) = self.payer {
+            accounts.push(solana_instruction::AccountMeta::new(payer, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(freeze_expiry_account) = self.freeze_expiry_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                freeze_expiry_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                system_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
-        let data = borsh::to_vec(&FreezeInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&FreezeInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
 
         solana_instruction::Instruction {
             program_id: crate::SECURITY_TOKEN_PROGRAM_ID,
@@ -95,6 +134,12 @@ impl Default for FreezeInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FreezeInstructionArgs {
+    pub auto_thaw_at: Option<i64>,
+}
+
 /// Instruction builder for `Freeze`.
 ///
 /// ### Accounts:
@@ -106,6 +151,9 @@ impl Default for FreezeInstructionData {
 ///   4. `[]` mint_account
 ///   5. `[writable]` token_account
 ///   6. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   7. `[writable, signer, optional]` payer
+///   8. `[writable, optional]` freeze_expiry_account
+///   9. `[optional]` system_program
 #[derive(Clone, Debug, Default)]
 pub struct FreezeBuilder {
     mint: Option<solana_pubkey::Pubkey>,
@@ -115,6 +163,10 @@ pub struct FreezeBuilder {
     mint_account: Option<solana_pubkey::Pubkey>,
     token_account: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
+    payer: Option<solana_pubkey::Pubkey>,
+    freeze_expiry_account: Option<solana_pubkey::Pubkey>,
+    system_program: Option<solana_pubkey::Pubkey>,
+    auto_thaw_at: Option<i64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -159,6 +211,33 @@ impl FreezeBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn freeze_expiry_account(
+        &mut self,
+        freeze_expiry_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.freeze_expiry_account = freeze_expiry_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.system_program = system_program;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn auto_thaw_at(&mut self, auto_thaw_at: i64) -> &mut Self {
+        self.auto_thaw_at = Some(auto_thaw_at);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -190,9 +269,15 @@ impl FreezeBuilder {
             token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!(
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
+            payer: self.payer,
+            freeze_expiry_account: self.freeze_expiry_account,
+            system_program: self.system_program,
+        };
+        let args = FreezeInstructionArgs {
+            auto_thaw_at: self.auto_thaw_at.clone(),
         };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -211,6 +296,12 @@ pub struct FreezeCpiAccounts<'a, 'b> {
     pub token_account: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub freeze_expiry_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `freeze` CPI instruction.
@@ -231,12 +322,21 @@ pub struct FreezeCpi<'a, 'b> {
     pub token_account: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub freeze_expiry_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: FreezeInstructionArgs,
 }
 
 impl<'a, 'b> FreezeCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_account_info::AccountInfo<'a>,
         accounts: FreezeCpiAccounts<'a, 'b>,
+        args: FreezeInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -247,6 +347,10 @@ impl<'a, 'b> FreezeCpi<'a, 'b> {
             mint_account: accounts.mint_account,
             token_account: accounts.token_account,
             token_program: accounts.token_program,
+            payer: accounts.payer,
+            freeze_expiry_account: accounts.freeze_expiry_account,
+            system_program: accounts.system_program,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -272,7 +376,7 @@ impl<'a, 'b> FreezeCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.mint.key,
             false,
@@ -301,6 +405,36 @@ impl<'a, 'b> FreezeCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        if let Some(payer) = self.payer {
+            accounts.push(solana_instruction::AccountMeta::new(*payer.key, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(freeze_expiry_account) = self.freeze_expiry_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *freeze_expiry_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *system_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -308,14 +442,16 @@ impl<'a, 'b> FreezeCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = borsh::to_vec(&FreezeInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&FreezeInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
 
         let instruction = solana_instruction::Instruction {
             program_id: crate::SECURITY_TOKEN_PROGRAM_ID,
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(11 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.verification_config.clone());
@@ -324,6 +460,15 @@ impl<'a, 'b> FreezeCpi<'a, 'b> {
         account_infos.push(self.mint_account.clone());
         account_infos.push(self.token_account.clone());
         account_infos.push(self.token_program.clone());
+        if let Some(payer) = self.payer {
+            account_infos.push(payer.clone());
+        }
+        if let Some(freeze_expiry_account) = self.freeze_expiry_account {
+            account_infos.push(freeze_expiry_account.clone());
+        }
+        if let Some(system_program) = self.system_program {
+            account_infos.push(system_program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -347,6 +492,9 @@ impl<'a, 'b> FreezeCpi<'a, 'b> {
 ///   4. `[]` mint_account
 ///   5. `[writable]` token_account
 ///   6. `[]` token_program
+///   7. `[writable, signer, optional]` payer
+///   8. `[writable, optional]` freeze_expiry_account
+///   9. `[optional]` system_program
 #[derive(Clone, Debug)]
 pub struct FreezeCpiBuilder<'a, 'b> {
     instruction: Box<FreezeCpiBuilderInstruction<'a, 'b>>,
@@ -363,6 +511,10 @@ impl<'a, 'b> FreezeCpiBuilder<'a, 'b> {
             mint_account: None,
             token_account: None,
             token_program: None,
+            payer: None,
+            freeze_expiry_account: None,
+            system_program: None,
+            auto_thaw_at: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -420,6 +572,36 @@ impl<'a, 'b> FreezeCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+        self.instruction.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn freeze_expiry_account(
+        &mut self,
+        freeze_expiry_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.freeze_expiry_account = freeze_expiry_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system_program = system_program;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn auto_thaw_at(&mut self, auto_thaw_at: i64) -> &mut Self {
+        self.instruction.auto_thaw_at = Some(auto_thaw_at);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -454,6 +636,9 @@ impl<'a, 'b> FreezeCpiBuilder<'a, 'b> {
     #[allow(clippy::clone_on_copy)]
     #[allow(clippy::vec_init_then_push)]
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
+        let args = FreezeInstructionArgs {
+            auto_thaw_at: self.instruction.auto_thaw_at.clone(),
+        };
         let instruction = FreezeCpi {
             __program: self.instruction.__program,
 
@@ -488,6 +673,13 @@ impl<'a, 'b> FreezeCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            payer: self.instruction.payer,
+
+            freeze_expiry_account: self.instruction.freeze_expiry_account,
+
+            system_program: self.instruction.system_program,
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -506,6 +698,10 @@ struct FreezeCpiBuilderInstruction<'a, 'b> {
     mint_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    freeze_expiry_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    auto_thaw_at: Option<i64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
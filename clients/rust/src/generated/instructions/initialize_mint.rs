@@ -25,6 +25,12 @@ pub struct InitializeMint {
     pub system_program: solana_pubkey::Pubkey,
 
     pub rent_sysvar: solana_pubkey::Pubkey,
+
+    pub creator: Option<solana_pubkey::Pubkey>,
+
+    pub external_metadata_account: Option<solana_pubkey::Pubkey>,
+
+    pub metadata_program: Option<solana_pubkey::Pubkey>,
 }
 
 impl InitializeMint {
@@ -41,7 +47,7 @@ impl InitializeMint {
         args: InitializeMintInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.mint, true));
         accounts.push(solana_instruction::AccountMeta::new(self.authority, false));
         accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
@@ -57,6 +63,36 @@ impl InitializeMint {
             self.rent_sysvar,
             false,
         ));
+        if let Some(creator) = self.creator {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(creator, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(external_metadata_account) = self.external_metadata_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                external_metadata_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(metadata_program) = self.metadata_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                metadata_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&InitializeMintInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -104,6 +140,9 @@ pub struct InitializeMintInstructionArgs {
 ///   3. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   5. `[optional]` rent_sysvar (default to `SysvarRent111111111111111111111111111111111`)
+///   6. `[signer, optional]` creator
+///   7. `[writable, optional]` external_metadata_account
+///   8. `[optional]` metadata_program
 #[derive(Clone, Debug, Default)]
 pub struct InitializeMintBuilder {
     mint: Option<solana_pubkey::Pubkey>,
@@ -112,6 +151,9 @@ pub struct InitializeMintBuilder {
     token_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     rent_sysvar: Option<solana_pubkey::Pubkey>,
+    creator: Option<solana_pubkey::Pubkey>,
+    external_metadata_account: Option<solana_pubkey::Pubkey>,
+    metadata_program: Option<solana_pubkey::Pubkey>,
     initialize_mint_args: Option<InitializeMintArgs>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -153,6 +195,30 @@ impl InitializeMintBuilder {
         self.rent_sysvar = Some(rent_sysvar);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn creator(&mut self, creator: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.creator = creator;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn external_metadata_account(
+        &mut self,
+        external_metadata_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.external_metadata_account = external_metadata_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn metadata_program(
+        &mut self,
+        metadata_program: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.metadata_program = metadata_program;
+        self
+    }
     #[inline(always)]
     pub fn initialize_mint_args(&mut self, initialize_mint_args: InitializeMintArgs) -> &mut Self {
         self.initialize_mint_args = Some(initialize_mint_args);
@@ -188,6 +254,9 @@ impl InitializeMintBuilder {
             rent_sysvar: self.rent_sysvar.unwrap_or(solana_pubkey::pubkey!(
                 "SysvarRent111111111111111111111111111111111"
             )),
+            creator: self.creator,
+            external_metadata_account: self.external_metadata_account,
+            metadata_program: self.metadata_program,
         };
         let args = InitializeMintInstructionArgs {
             initialize_mint_args: self
@@ -213,6 +282,12 @@ pub struct InitializeMintCpiAccounts<'a, 'b> {
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub rent_sysvar: &'b solana_account_info::AccountInfo<'a>,
+
+    pub creator: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub external_metadata_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub metadata_program: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `initialize_mint` CPI instruction.
@@ -231,6 +306,12 @@ pub struct InitializeMintCpi<'a, 'b> {
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub rent_sysvar: &'b solana_account_info::AccountInfo<'a>,
+
+    pub creator: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub external_metadata_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub metadata_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: InitializeMintInstructionArgs,
 }
@@ -249,6 +330,9 @@ impl<'a, 'b> InitializeMintCpi<'a, 'b> {
             token_program: accounts.token_program,
             system_program: accounts.system_program,
             rent_sysvar: accounts.rent_sysvar,
+            creator: accounts.creator,
+            external_metadata_account: accounts.external_metadata_account,
+            metadata_program: accounts.metadata_program,
             __args: args,
         }
     }
@@ -275,7 +359,7 @@ impl<'a, 'b> InitializeMintCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.mint.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.authority.key,
@@ -294,6 +378,39 @@ impl<'a, 'b> InitializeMintCpi<'a, 'b> {
             *self.rent_sysvar.key,
             false,
         ));
+        if let Some(creator) = self.creator {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *creator.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(external_metadata_account) = self.external_metadata_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *external_metadata_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(metadata_program) = self.metadata_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *metadata_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -310,7 +427,7 @@ impl<'a, 'b> InitializeMintCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.authority.clone());
@@ -318,6 +435,15 @@ impl<'a, 'b> InitializeMintCpi<'a, 'b> {
         account_infos.push(self.token_program.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.rent_sysvar.clone());
+        if let Some(creator) = self.creator {
+            account_infos.push(creator.clone());
+        }
+        if let Some(external_metadata_account) = self.external_metadata_account {
+            account_infos.push(external_metadata_account.clone());
+        }
+        if let Some(metadata_program) = self.metadata_program {
+            account_infos.push(metadata_program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -340,6 +466,9 @@ impl<'a, 'b> InitializeMintCpi<'a, 'b> {
 ///   3. `[]` token_program
 ///   4. `[]` system_program
 ///   5. `[]` rent_sysvar
+///   6. `[signer, optional]` creator
+///   7. `[writable, optional]` external_metadata_account
+///   8. `[optional]` metadata_program
 #[derive(Clone, Debug)]
 pub struct InitializeMintCpiBuilder<'a, 'b> {
     instruction: Box<InitializeMintCpiBuilderInstruction<'a, 'b>>,
@@ -355,6 +484,9 @@ impl<'a, 'b> InitializeMintCpiBuilder<'a, 'b> {
             token_program: None,
             system_program: None,
             rent_sysvar: None,
+            creator: None,
+            external_metadata_account: None,
+            metadata_program: None,
             initialize_mint_args: None,
             __remaining_accounts: Vec::new(),
         });
@@ -399,6 +531,33 @@ impl<'a, 'b> InitializeMintCpiBuilder<'a, 'b> {
         self.instruction.rent_sysvar = Some(rent_sysvar);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn creator(
+        &mut self,
+        creator: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.creator = creator;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn external_metadata_account(
+        &mut self,
+        external_metadata_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.external_metadata_account = external_metadata_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn metadata_program(
+        &mut self,
+        metadata_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.metadata_program = metadata_program;
+        self
+    }
     #[inline(always)]
     pub fn initialize_mint_args(&mut self, initialize_mint_args: InitializeMintArgs) -> &mut Self {
         self.instruction.initialize_mint_args = Some(initialize_mint_args);
@@ -468,6 +627,12 @@ impl<'a, 'b> InitializeMintCpiBuilder<'a, 'b> {
                 .instruction
                 .rent_sysvar
                 .expect("rent_sysvar is not set"),
+
+            creator: self.instruction.creator,
+
+            external_metadata_account: self.instruction.external_metadata_account,
+
+            metadata_program: self.instruction.metadata_program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -486,6 +651,9 @@ struct InitializeMintCpiBuilderInstruction<'a, 'b> {
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     rent_sysvar: Option<&'b solana_account_info::AccountInfo<'a>>,
+    creator: Option<&'b solana_account_info::AccountInfo<'a>>,
+    external_metadata_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    metadata_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     initialize_mint_args: Option<InitializeMintArgs>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -26,6 +26,16 @@ pub struct Mint {
     pub destination: solana_pubkey::Pubkey,
 
     pub token_program: solana_pubkey::Pubkey,
+
+    pub lot_payer: Option<solana_pubkey::Pubkey>,
+
+    pub holding_period_account: Option<solana_pubkey::Pubkey>,
+
+    pub restricted_holding_account: Option<solana_pubkey::Pubkey>,
+
+    pub holding_lot_account: Option<solana_pubkey::Pubkey>,
+
+    pub system_program: Option<solana_pubkey::Pubkey>,
 }
 
 impl Mint {
@@ -39,7 +49,7 @@ impl Mint {
         args: MintInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
@@ -67,6 +77,58 @@ impl Mint {
             self.token_program,
             false,
         ));
+        if let Some(lot_payer) = self.lot_payer {
+            accounts.push(solana_instruction::AccountMeta::new(lot_payer, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(holding_period_account) = self.holding_period_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                holding_period_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(restricted_holding_account) = self.restricted_holding_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                restricted_holding_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(holding_lot_account) = self.holding_lot_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                holding_lot_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                system_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&MintInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -115,6 +177,11 @@ pub struct MintInstructionArgs {
 ///   4. `[writable]` mint_account
 ///   5. `[writable]` destination
 ///   6. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   7. `[writable, signer, optional]` lot_payer
+///   8. `[optional]` holding_period_account
+///   9. `[writable, optional]` restricted_holding_account
+///   10. `[writable, optional]` holding_lot_account
+///   11. `[optional]` system_program
 #[derive(Clone, Debug, Default)]
 pub struct MintBuilder {
     mint: Option<solana_pubkey::Pubkey>,
@@ -124,6 +191,11 @@ pub struct MintBuilder {
     mint_account: Option<solana_pubkey::Pubkey>,
     destination: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
+    lot_payer: Option<solana_pubkey::Pubkey>,
+    holding_period_account: Option<solana_pubkey::Pubkey>,
+    restricted_holding_account: Option<solana_pubkey::Pubkey>,
+    holding_lot_account: Option<solana_pubkey::Pubkey>,
+    system_program: Option<solana_pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -169,6 +241,45 @@ impl MintBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn lot_payer(&mut self, lot_payer: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.lot_payer = lot_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn holding_period_account(
+        &mut self,
+        holding_period_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.holding_period_account = holding_period_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn restricted_holding_account(
+        &mut self,
+        restricted_holding_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.restricted_holding_account = restricted_holding_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn holding_lot_account(
+        &mut self,
+        holding_lot_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.holding_lot_account = holding_lot_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.system_program = system_program;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
@@ -205,6 +316,11 @@ impl MintBuilder {
             token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!(
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
+            lot_payer: self.lot_payer,
+            holding_period_account: self.holding_period_account,
+            restricted_holding_account: self.restricted_holding_account,
+            holding_lot_account: self.holding_lot_account,
+            system_program: self.system_program,
         };
         let args = MintInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
@@ -229,6 +345,16 @@ pub struct MintCpiAccounts<'a, 'b> {
     pub destination: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub lot_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub holding_period_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub restricted_holding_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub holding_lot_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `mint` CPI instruction.
@@ -249,6 +375,16 @@ pub struct MintCpi<'a, 'b> {
     pub destination: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub lot_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub holding_period_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub restricted_holding_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub holding_lot_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: MintInstructionArgs,
 }
@@ -268,6 +404,11 @@ impl<'a, 'b> MintCpi<'a, 'b> {
             mint_account: accounts.mint_account,
             destination: accounts.destination,
             token_program: accounts.token_program,
+            lot_payer: accounts.lot_payer,
+            holding_period_account: accounts.holding_period_account,
+            restricted_holding_account: accounts.restricted_holding_account,
+            holding_lot_account: accounts.holding_lot_account,
+            system_program: accounts.system_program,
             __args: args,
         }
     }
@@ -294,7 +435,7 @@ impl<'a, 'b> MintCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.mint.key,
             false,
@@ -323,6 +464,58 @@ impl<'a, 'b> MintCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        if let Some(lot_payer) = self.lot_payer {
+            accounts.push(solana_instruction::AccountMeta::new(*lot_payer.key, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(holding_period_account) = self.holding_period_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *holding_period_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(restricted_holding_account) = self.restricted_holding_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *restricted_holding_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(holding_lot_account) = self.holding_lot_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *holding_lot_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *system_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -339,7 +532,7 @@ impl<'a, 'b> MintCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.verification_config.clone());
@@ -348,6 +541,21 @@ impl<'a, 'b> MintCpi<'a, 'b> {
         account_infos.push(self.mint_account.clone());
         account_infos.push(self.destination.clone());
         account_infos.push(self.token_program.clone());
+        if let Some(lot_payer) = self.lot_payer {
+            account_infos.push(lot_payer.clone());
+        }
+        if let Some(holding_period_account) = self.holding_period_account {
+            account_infos.push(holding_period_account.clone());
+        }
+        if let Some(restricted_holding_account) = self.restricted_holding_account {
+            account_infos.push(restricted_holding_account.clone());
+        }
+        if let Some(holding_lot_account) = self.holding_lot_account {
+            account_infos.push(holding_lot_account.clone());
+        }
+        if let Some(system_program) = self.system_program {
+            account_infos.push(system_program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -371,6 +579,11 @@ impl<'a, 'b> MintCpi<'a, 'b> {
 ///   4. `[writable]` mint_account
 ///   5. `[writable]` destination
 ///   6. `[]` token_program
+///   7. `[writable, signer, optional]` lot_payer
+///   8. `[optional]` holding_period_account
+///   9. `[writable, optional]` restricted_holding_account
+///   10. `[writable, optional]` holding_lot_account
+///   11. `[optional]` system_program
 #[derive(Clone, Debug)]
 pub struct MintCpiBuilder<'a, 'b> {
     instruction: Box<MintCpiBuilderInstruction<'a, 'b>>,
@@ -387,6 +600,11 @@ impl<'a, 'b> MintCpiBuilder<'a, 'b> {
             mint_account: None,
             destination: None,
             token_program: None,
+            lot_payer: None,
+            holding_period_account: None,
+            restricted_holding_account: None,
+            holding_lot_account: None,
+            system_program: None,
             amount: None,
             __remaining_accounts: Vec::new(),
         });
@@ -445,6 +663,51 @@ impl<'a, 'b> MintCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn lot_payer(
+        &mut self,
+        lot_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.lot_payer = lot_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn holding_period_account(
+        &mut self,
+        holding_period_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.holding_period_account = holding_period_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn restricted_holding_account(
+        &mut self,
+        restricted_holding_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.restricted_holding_account = restricted_holding_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn holding_lot_account(
+        &mut self,
+        holding_lot_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.holding_lot_account = holding_lot_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system_program = system_program;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
@@ -521,6 +784,16 @@ impl<'a, 'b> MintCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            lot_payer: self.instruction.lot_payer,
+
+            holding_period_account: self.instruction.holding_period_account,
+
+            restricted_holding_account: self.instruction.restricted_holding_account,
+
+            holding_lot_account: self.instruction.holding_lot_account,
+
+            system_program: self.instruction.system_program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -540,6 +813,11 @@ struct MintCpiBuilderInstruction<'a, 'b> {
     mint_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     destination: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    lot_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    holding_period_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    restricted_holding_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    holding_lot_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
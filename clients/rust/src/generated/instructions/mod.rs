@@ -5,52 +5,274 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#accept_mint_creator_transfer;
+pub(crate) mod r#accept_transfer;
+pub(crate) mod r#add_identity_wallet;
+pub(crate) mod r#add_to_allowlist;
+pub(crate) mod r#allocate;
+pub(crate) mod r#amend_distribution_root;
+pub(crate) mod r#anchor_document;
+pub(crate) mod r#apply_pending_config;
+pub(crate) mod r#approve_transfer;
+pub(crate) mod r#attest_collateral;
+pub(crate) mod r#audit_mint;
 pub(crate) mod r#burn;
+pub(crate) mod r#can_transfer;
+pub(crate) mod r#cancel_pending_config;
+pub(crate) mod r#cancel_transfer;
+pub(crate) mod r#claim_coupon;
 pub(crate) mod r#claim_distribution;
+pub(crate) mod r#claim_succession;
+pub(crate) mod r#clear_auction;
 pub(crate) mod r#close_action_receipt_account;
+pub(crate) mod r#close_agent_account;
 pub(crate) mod r#close_claim_receipt_account;
+pub(crate) mod r#close_config_template;
+pub(crate) mod r#close_holding_period;
+pub(crate) mod r#close_identity_account;
+pub(crate) mod r#close_metadata_schema;
+pub(crate) mod r#close_metadata_translator;
+pub(crate) mod r#close_mint_authority;
+pub(crate) mod r#close_position_limit;
 pub(crate) mod r#close_rate_account;
+pub(crate) mod r#close_session_key;
+pub(crate) mod r#close_sub_account_ledger;
+pub(crate) mod r#close_succession_policy;
+pub(crate) mod r#close_transfer_acceptance;
+pub(crate) mod r#close_transfer_approval;
+pub(crate) mod r#commit;
 pub(crate) mod r#convert;
+pub(crate) mod r#create_accrual_config;
+pub(crate) mod r#create_agent_account;
+pub(crate) mod r#create_auction;
+pub(crate) mod r#create_claim_deadline;
+pub(crate) mod r#create_config_template;
 pub(crate) mod r#create_distribution_escrow;
+pub(crate) mod r#create_distribution_payout;
+pub(crate) mod r#create_holding_period;
+pub(crate) mod r#create_identity_account;
+pub(crate) mod r#create_maturity;
+pub(crate) mod r#create_metadata_schema;
+pub(crate) mod r#create_metadata_translator;
+pub(crate) mod r#create_mint_migration;
+pub(crate) mod r#create_nav_oracle;
+pub(crate) mod r#create_position_limit;
 pub(crate) mod r#create_proof_account;
 pub(crate) mod r#create_rate_account;
+pub(crate) mod r#create_session_key;
+pub(crate) mod r#create_sub_account_ledger;
+pub(crate) mod r#create_succession_policy;
+pub(crate) mod r#create_transfer_acceptance;
+pub(crate) mod r#create_transfer_approval;
+pub(crate) mod r#create_vesting;
+pub(crate) mod r#create_withholding_rate;
+pub(crate) mod r#create_wrap_vault;
+pub(crate) mod r#credit_sub_account;
+pub(crate) mod r#debit_sub_account;
+pub(crate) mod r#disable_permanent_delegate;
 pub(crate) mod r#freeze;
+pub(crate) mod r#freeze_metadata;
+pub(crate) mod r#get_distribution_status;
+pub(crate) mod r#get_effective_rate;
+pub(crate) mod r#get_holder_status;
+pub(crate) mod r#harvest_withheld_tokens_to_mint;
+pub(crate) mod r#heartbeat;
 pub(crate) mod r#initialize_mint;
 pub(crate) mod r#initialize_verification_config;
+pub(crate) mod r#migrate_balances;
 pub(crate) mod r#mint;
+pub(crate) mod r#offer_transfer;
+pub(crate) mod r#open_subscription;
 pub(crate) mod r#pause;
+pub(crate) mod r#place_bid;
+pub(crate) mod r#propose_mint_creator_transfer;
+pub(crate) mod r#publish_reserve_attestation;
+pub(crate) mod r#reclaim_unclaimed;
+pub(crate) mod r#recover_tokens;
+pub(crate) mod r#redeem_at_maturity;
+pub(crate) mod r#reject_transfer;
+pub(crate) mod r#release_vested;
+pub(crate) mod r#remove_document;
+pub(crate) mod r#remove_from_allowlist;
+pub(crate) mod r#remove_identity_wallet;
+pub(crate) mod r#remove_metadata_field;
+pub(crate) mod r#remove_rate_oracle;
+pub(crate) mod r#request_transfer;
 pub(crate) mod r#resume;
+pub(crate) mod r#rotate_permanent_delegate;
+pub(crate) mod r#set_allowlist_mode;
+pub(crate) mod r#set_config_admin;
+pub(crate) mod r#set_config_template;
+pub(crate) mod r#set_emergency_authority;
+pub(crate) mod r#set_mint_authority_multisig;
+pub(crate) mod r#set_pause_delegate;
+pub(crate) mod r#set_program_halt;
+pub(crate) mod r#set_rate_oracle;
+pub(crate) mod r#set_transfer_fee;
+pub(crate) mod r#settle;
+pub(crate) mod r#settle_bid;
 pub(crate) mod r#split;
+pub(crate) mod r#suspend;
 pub(crate) mod r#thaw;
+pub(crate) mod r#thaw_expired;
 pub(crate) mod r#transfer;
+pub(crate) mod r#transfer_with_permit;
 pub(crate) mod r#trim_verification_config;
+pub(crate) mod r#unlock_lot;
+pub(crate) mod r#unsuspend;
+pub(crate) mod r#unwrap_token;
+pub(crate) mod r#update_agent_account;
+pub(crate) mod r#update_config_template;
+pub(crate) mod r#update_default_account_state;
+pub(crate) mod r#update_document;
 pub(crate) mod r#update_metadata;
+pub(crate) mod r#update_metadata_schema;
+pub(crate) mod r#update_multiplier_from_oracle;
+pub(crate) mod r#update_position_limit;
 pub(crate) mod r#update_proof_account;
 pub(crate) mod r#update_rate_account;
+pub(crate) mod r#update_scaled_ui_amount_multiplier;
+pub(crate) mod r#update_transfer_approval;
+pub(crate) mod r#update_translation;
 pub(crate) mod r#update_verification_config;
+pub(crate) mod r#update_withholding_rate;
 pub(crate) mod r#verify;
+pub(crate) mod r#withdraw_unclaimed_redemption;
+pub(crate) mod r#withdraw_withheld_tokens_from_accounts;
+pub(crate) mod r#wrap_token;
 
+pub use self::r#accept_mint_creator_transfer::*;
+pub use self::r#accept_transfer::*;
+pub use self::r#add_identity_wallet::*;
+pub use self::r#add_to_allowlist::*;
+pub use self::r#allocate::*;
+pub use self::r#amend_distribution_root::*;
+pub use self::r#anchor_document::*;
+pub use self::r#apply_pending_config::*;
+pub use self::r#approve_transfer::*;
+pub use self::r#attest_collateral::*;
+pub use self::r#audit_mint::*;
 pub use self::r#burn::*;
+pub use self::r#can_transfer::*;
+pub use self::r#cancel_pending_config::*;
+pub use self::r#cancel_transfer::*;
+pub use self::r#claim_coupon::*;
 pub use self::r#claim_distribution::*;
+pub use self::r#claim_succession::*;
+pub use self::r#clear_auction::*;
 pub use self::r#close_action_receipt_account::*;
+pub use self::r#close_agent_account::*;
 pub use self::r#close_claim_receipt_account::*;
+pub use self::r#close_config_template::*;
+pub use self::r#close_holding_period::*;
+pub use self::r#close_identity_account::*;
+pub use self::r#close_metadata_schema::*;
+pub use self::r#close_metadata_translator::*;
+pub use self::r#close_mint_authority::*;
+pub use self::r#close_position_limit::*;
 pub use self::r#close_rate_account::*;
+pub use self::r#close_session_key::*;
+pub use self::r#close_sub_account_ledger::*;
+pub use self::r#close_succession_policy::*;
+pub use self::r#close_transfer_acceptance::*;
+pub use self::r#close_transfer_approval::*;
+pub use self::r#commit::*;
 pub use self::r#convert::*;
+pub use self::r#create_accrual_config::*;
+pub use self::r#create_agent_account::*;
+pub use self::r#create_auction::*;
+pub use self::r#create_claim_deadline::*;
+pub use self::r#create_config_template::*;
 pub use self::r#create_distribution_escrow::*;
+pub use self::r#create_distribution_payout::*;
+pub use self::r#create_holding_period::*;
+pub use self::r#create_identity_account::*;
+pub use self::r#create_maturity::*;
+pub use self::r#create_metadata_schema::*;
+pub use self::r#create_metadata_translator::*;
+pub use self::r#create_mint_migration::*;
+pub use self::r#create_nav_oracle::*;
+pub use self::r#create_position_limit::*;
 pub use self::r#create_proof_account::*;
 pub use self::r#create_rate_account::*;
+pub use self::r#create_session_key::*;
+pub use self::r#create_sub_account_ledger::*;
+pub use self::r#create_succession_policy::*;
+pub use self::r#create_transfer_acceptance::*;
+pub use self::r#create_transfer_approval::*;
+pub use self::r#create_vesting::*;
+pub use self::r#create_withholding_rate::*;
+pub use self::r#create_wrap_vault::*;
+pub use self::r#credit_sub_account::*;
+pub use self::r#debit_sub_account::*;
+pub use self::r#disable_permanent_delegate::*;
 pub use self::r#freeze::*;
+pub use self::r#freeze_metadata::*;
+pub use self::r#get_distribution_status::*;
+pub use self::r#get_effective_rate::*;
+pub use self::r#get_holder_status::*;
+pub use self::r#harvest_withheld_tokens_to_mint::*;
+pub use self::r#heartbeat::*;
 pub use self::r#initialize_mint::*;
 pub use self::r#initialize_verification_config::*;
+pub use self::r#migrate_balances::*;
 pub use self::r#mint::*;
+pub use self::r#offer_transfer::*;
+pub use self::r#open_subscription::*;
 pub use self::r#pause::*;
+pub use self::r#place_bid::*;
+pub use self::r#propose_mint_creator_transfer::*;
+pub use self::r#publish_reserve_attestation::*;
+pub use self::r#reclaim_unclaimed::*;
+pub use self::r#recover_tokens::*;
+pub use self::r#redeem_at_maturity::*;
+pub use self::r#reject_transfer::*;
+pub use self::r#release_vested::*;
+pub use self::r#remove_document::*;
+pub use self::r#remove_from_allowlist::*;
+pub use self::r#remove_identity_wallet::*;
+pub use self::r#remove_metadata_field::*;
+pub use self::r#remove_rate_oracle::*;
+pub use self::r#request_transfer::*;
 pub use self::r#resume::*;
+pub use self::r#rotate_permanent_delegate::*;
+pub use self::r#set_allowlist_mode::*;
+pub use self::r#set_config_admin::*;
+pub use self::r#set_config_template::*;
+pub use self::r#set_emergency_authority::*;
+pub use self::r#set_mint_authority_multisig::*;
+pub use self::r#set_pause_delegate::*;
+pub use self::r#set_program_halt::*;
+pub use self::r#set_rate_oracle::*;
+pub use self::r#set_transfer_fee::*;
+pub use self::r#settle::*;
+pub use self::r#settle_bid::*;
 pub use self::r#split::*;
+pub use self::r#suspend::*;
 pub use self::r#thaw::*;
+pub use self::r#thaw_expired::*;
 pub use self::r#transfer::*;
+pub use self::r#transfer_with_permit::*;
 pub use self::r#trim_verification_config::*;
+pub use self::r#unlock_lot::*;
+pub use self::r#unsuspend::*;
+pub use self::r#unwrap_token::*;
+pub use self::r#update_agent_account::*;
+pub use self::r#update_config_template::*;
+pub use self::r#update_default_account_state::*;
+pub use self::r#update_document::*;
 pub use self::r#update_metadata::*;
+pub use self::r#update_metadata_schema::*;
+pub use self::r#update_multiplier_from_oracle::*;
+pub use self::r#update_position_limit::*;
 pub use self::r#update_proof_account::*;
 pub use self::r#update_rate_account::*;
+pub use self::r#update_scaled_ui_amount_multiplier::*;
+pub use self::r#update_transfer_approval::*;
+pub use self::r#update_translation::*;
 pub use self::r#update_verification_config::*;
+pub use self::r#update_withholding_rate::*;
 pub use self::r#verify::*;
+pub use self::r#withdraw_unclaimed_redemption::*;
+pub use self::r#withdraw_withheld_tokens_from_accounts::*;
+pub use self::r#wrap_token::*;
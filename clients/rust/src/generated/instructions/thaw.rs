@@ -26,6 +26,10 @@ pub struct Thaw {
     pub token_account: solana_pubkey::Pubkey,
 
     pub token_program: solana_pubkey::Pubkey,
+
+    pub freeze_expiry_account: Option<solana_pubkey::Pubkey>,
+
+    pub rent_destination: Option<solana_pubkey::Pubkey>,
 }
 
 impl Thaw {
@@ -38,7 +42,7 @@ impl Thaw {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
@@ -66,6 +70,28 @@ impl Thaw {
             self.token_program,
             false,
         ));
+        if let Some(freeze_expiry_account) = self.freeze_expiry_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                freeze_expiry_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(rent_destination) = self.rent_destination {
+            accounts.push(solana_instruction::AccountMeta::new(
+                rent_destination,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = borsh::to_vec(&ThawInstructionData::new()).unwrap();
 
@@ -106,6 +132,8 @@ impl Default for ThawInstructionData {
 ///   4. `[]` mint_account
 ///   5. `[writable]` token_account
 ///   6. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   7. `[writable, optional]` freeze_expiry_account
+///   8. `[writable, optional]` rent_destination
 #[derive(Clone, Debug, Default)]
 pub struct ThawBuilder {
     mint: Option<solana_pubkey::Pubkey>,
@@ -115,6 +143,8 @@ pub struct ThawBuilder {
     mint_account: Option<solana_pubkey::Pubkey>,
     token_account: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
+    freeze_expiry_account: Option<solana_pubkey::Pubkey>,
+    rent_destination: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -159,6 +189,24 @@ impl ThawBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn freeze_expiry_account(
+        &mut self,
+        freeze_expiry_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.freeze_expiry_account = freeze_expiry_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn rent_destination(
+        &mut self,
+        rent_destination: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.rent_destination = rent_destination;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -190,6 +238,8 @@ impl ThawBuilder {
             token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!(
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
+            freeze_expiry_account: self.freeze_expiry_account,
+            rent_destination: self.rent_destination,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -211,6 +261,10 @@ pub struct ThawCpiAccounts<'a, 'b> {
     pub token_account: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub freeze_expiry_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub rent_destination: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `thaw` CPI instruction.
@@ -231,6 +285,10 @@ pub struct ThawCpi<'a, 'b> {
     pub token_account: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub freeze_expiry_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub rent_destination: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> ThawCpi<'a, 'b> {
@@ -247,6 +305,8 @@ impl<'a, 'b> ThawCpi<'a, 'b> {
             mint_account: accounts.mint_account,
             token_account: accounts.token_account,
             token_program: accounts.token_program,
+            freeze_expiry_account: accounts.freeze_expiry_account,
+            rent_destination: accounts.rent_destination,
         }
     }
     #[inline(always)]
@@ -272,7 +332,7 @@ impl<'a, 'b> ThawCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.mint.key,
             false,
@@ -301,6 +361,28 @@ impl<'a, 'b> ThawCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        if let Some(freeze_expiry_account) = self.freeze_expiry_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *freeze_expiry_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(rent_destination) = self.rent_destination {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *rent_destination.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -315,7 +397,7 @@ impl<'a, 'b> ThawCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.verification_config.clone());
@@ -324,6 +406,12 @@ impl<'a, 'b> ThawCpi<'a, 'b> {
         account_infos.push(self.mint_account.clone());
         account_infos.push(self.token_account.clone());
         account_infos.push(self.token_program.clone());
+        if let Some(freeze_expiry_account) = self.freeze_expiry_account {
+            account_infos.push(freeze_expiry_account.clone());
+        }
+        if let Some(rent_destination) = self.rent_destination {
+            account_infos.push(rent_destination.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -347,6 +435,8 @@ impl<'a, 'b> ThawCpi<'a, 'b> {
 ///   4. `[]` mint_account
 ///   5. `[writable]` token_account
 ///   6. `[]` token_program
+///   7. `[writable, optional]` freeze_expiry_account
+///   8. `[writable, optional]` rent_destination
 #[derive(Clone, Debug)]
 pub struct ThawCpiBuilder<'a, 'b> {
     instruction: Box<ThawCpiBuilderInstruction<'a, 'b>>,
@@ -363,6 +453,8 @@ impl<'a, 'b> ThawCpiBuilder<'a, 'b> {
             mint_account: None,
             token_account: None,
             token_program: None,
+            freeze_expiry_account: None,
+            rent_destination: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -420,6 +512,24 @@ impl<'a, 'b> ThawCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn freeze_expiry_account(
+        &mut self,
+        freeze_expiry_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.freeze_expiry_account = freeze_expiry_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn rent_destination(
+        &mut self,
+        rent_destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rent_destination = rent_destination;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -488,6 +598,10 @@ impl<'a, 'b> ThawCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            freeze_expiry_account: self.instruction.freeze_expiry_account,
+
+            rent_destination: self.instruction.rent_destination,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -506,6 +620,8 @@ struct ThawCpiBuilderInstruction<'a, 'b> {
     mint_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    freeze_expiry_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    rent_destination: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
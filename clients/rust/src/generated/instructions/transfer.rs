@@ -30,6 +30,17 @@ pub struct Transfer {
     pub transfer_hook_program: solana_pubkey::Pubkey,
 
     pub token_program: solana_pubkey::Pubkey,
+
+    pub lot_payer: Option<solana_pubkey::Pubkey>,
+
+    pub holding_period_account: Option<solana_pubkey::Pubkey>,
+
+    pub restricted_holding_account: Option<solana_pubkey::Pubkey>,
+
+    pub holding_lot_account: Option<solana_pubkey::Pubkey>,
+
+    pub system_program: Option<solana_pubkey::Pubkey>,
+    pub memo_program: Option<solana_pubkey::Pubkey>,
 }
 
 impl Transfer {
@@ -43,7 +54,7 @@ impl Transfer {
         args: TransferInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
@@ -79,6 +90,69 @@ impl Transfer {
             self.token_program,
             false,
         ));
+        if let Some(lot_payer) = self.lot_payer {
+            accounts.push(solana_instruction::AccountMeta::new(lot_payer, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(holding_period_account) = self.holding_period_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                holding_period_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(restricted_holding_account) = self.restricted_holding_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                restricted_holding_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(holding_lot_account) = self.holding_lot_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                holding_lot_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                system_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(memo_program) = self.memo_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                memo_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&TransferInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -114,6 +188,8 @@ impl Default for TransferInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TransferInstructionArgs {
     pub amount: u64,
+    pub memo_hash: Option<[u8; 32]>,
+    pub memo: Option<String>,
 }
 
 /// Instruction builder for `Transfer`.
@@ -129,6 +205,12 @@ pub struct TransferInstructionArgs {
 ///   6. `[writable]` to_token_account
 ///   7. `[]` transfer_hook_program
 ///   8. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   9. `[writable, signer, optional]` lot_payer
+///   10. `[optional]` holding_period_account
+///   11. `[writable, optional]` restricted_holding_account
+///   12. `[writable, optional]` holding_lot_account
+///   13. `[optional]` system_program
+///   14. `[optional]` memo_program
 #[derive(Clone, Debug, Default)]
 pub struct TransferBuilder {
     mint: Option<solana_pubkey::Pubkey>,
@@ -140,7 +222,15 @@ pub struct TransferBuilder {
     to_token_account: Option<solana_pubkey::Pubkey>,
     transfer_hook_program: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
+    lot_payer: Option<solana_pubkey::Pubkey>,
+    holding_period_account: Option<solana_pubkey::Pubkey>,
+    restricted_holding_account: Option<solana_pubkey::Pubkey>,
+    holding_lot_account: Option<solana_pubkey::Pubkey>,
+    system_program: Option<solana_pubkey::Pubkey>,
+    memo_program: Option<solana_pubkey::Pubkey>,
     amount: Option<u64>,
+    memo_hash: Option<[u8; 32]>,
+    memo: Option<String>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -201,11 +291,68 @@ impl TransferBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn lot_payer(&mut self, lot_payer: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.lot_payer = lot_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn holding_period_account(
+        &mut self,
+        holding_period_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.holding_period_account = holding_period_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn restricted_holding_account(
+        &mut self,
+        restricted_holding_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.restricted_holding_account = restricted_holding_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn holding_lot_account(
+        &mut self,
+        holding_lot_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.holding_lot_account = holding_lot_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.system_program = system_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn memo_program(&mut self, memo_program: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.memo_program = memo_program;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn memo_hash(&mut self, memo_hash: [u8; 32]) -> &mut Self {
+        self.memo_hash = Some(memo_hash);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn memo(&mut self, memo: String) -> &mut Self {
+        self.memo = Some(memo);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -245,9 +392,17 @@ impl TransferBuilder {
             token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!(
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
+            lot_payer: self.lot_payer,
+            holding_period_account: self.holding_period_account,
+            restricted_holding_account: self.restricted_holding_account,
+            holding_lot_account: self.holding_lot_account,
+            system_program: self.system_program,
+            memo_program: self.memo_program,
         };
         let args = TransferInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
+            memo_hash: self.memo_hash.clone(),
+            memo: self.memo.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -273,6 +428,17 @@ pub struct TransferCpiAccounts<'a, 'b> {
     pub transfer_hook_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub lot_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub holding_period_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub restricted_holding_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub holding_lot_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    pub memo_program: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `transfer` CPI instruction.
@@ -297,6 +463,17 @@ pub struct TransferCpi<'a, 'b> {
     pub transfer_hook_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub lot_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub holding_period_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub restricted_holding_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub holding_lot_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    pub memo_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: TransferInstructionArgs,
 }
@@ -318,6 +495,12 @@ impl<'a, 'b> TransferCpi<'a, 'b> {
             to_token_account: accounts.to_token_account,
             transfer_hook_program: accounts.transfer_hook_program,
             token_program: accounts.token_program,
+            lot_payer: accounts.lot_payer,
+            holding_period_account: accounts.holding_period_account,
+            restricted_holding_account: accounts.restricted_holding_account,
+            holding_lot_account: accounts.holding_lot_account,
+            system_program: accounts.system_program,
+            memo_program: accounts.memo_program,
             __args: args,
         }
     }
@@ -344,7 +527,7 @@ impl<'a, 'b> TransferCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.mint.key,
             false,
@@ -381,6 +564,69 @@ impl<'a, 'b> TransferCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        if let Some(lot_payer) = self.lot_payer {
+            accounts.push(solana_instruction::AccountMeta::new(*lot_payer.key, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(holding_period_account) = self.holding_period_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *holding_period_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(restricted_holding_account) = self.restricted_holding_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *restricted_holding_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(holding_lot_account) = self.holding_lot_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *holding_lot_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *system_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(memo_program) = self.memo_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *memo_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -397,7 +643,7 @@ impl<'a, 'b> TransferCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(16 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.verification_config.clone());
@@ -408,6 +654,24 @@ impl<'a, 'b> TransferCpi<'a, 'b> {
         account_infos.push(self.to_token_account.clone());
         account_infos.push(self.transfer_hook_program.clone());
         account_infos.push(self.token_program.clone());
+        if let Some(lot_payer) = self.lot_payer {
+            account_infos.push(lot_payer.clone());
+        }
+        if let Some(holding_period_account) = self.holding_period_account {
+            account_infos.push(holding_period_account.clone());
+        }
+        if let Some(restricted_holding_account) = self.restricted_holding_account {
+            account_infos.push(restricted_holding_account.clone());
+        }
+        if let Some(holding_lot_account) = self.holding_lot_account {
+            account_infos.push(holding_lot_account.clone());
+        }
+        if let Some(system_program) = self.system_program {
+            account_infos.push(system_program.clone());
+        }
+        if let Some(memo_program) = self.memo_program {
+            account_infos.push(memo_program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -433,6 +697,12 @@ impl<'a, 'b> TransferCpi<'a, 'b> {
 ///   6. `[writable]` to_token_account
 ///   7. `[]` transfer_hook_program
 ///   8. `[]` token_program
+///   9. `[writable, signer, optional]` lot_payer
+///   10. `[optional]` holding_period_account
+///   11. `[writable, optional]` restricted_holding_account
+///   12. `[writable, optional]` holding_lot_account
+///   13. `[optional]` system_program
+///   14. `[optional]` memo_program
 #[derive(Clone, Debug)]
 pub struct TransferCpiBuilder<'a, 'b> {
     instruction: Box<TransferCpiBuilderInstruction<'a, 'b>>,
@@ -451,7 +721,15 @@ impl<'a, 'b> TransferCpiBuilder<'a, 'b> {
             to_token_account: None,
             transfer_hook_program: None,
             token_program: None,
+            lot_payer: None,
+            holding_period_account: None,
+            restricted_holding_account: None,
+            holding_lot_account: None,
+            system_program: None,
+            memo_program: None,
             amount: None,
+            memo_hash: None,
+            memo: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -525,11 +803,77 @@ impl<'a, 'b> TransferCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn lot_payer(
+        &mut self,
+        lot_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.lot_payer = lot_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn holding_period_account(
+        &mut self,
+        holding_period_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.holding_period_account = holding_period_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn restricted_holding_account(
+        &mut self,
+        restricted_holding_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.restricted_holding_account = restricted_holding_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn holding_lot_account(
+        &mut self,
+        holding_lot_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.holding_lot_account = holding_lot_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system_program = system_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn memo_program(
+        &mut self,
+        memo_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.memo_program = memo_program;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn memo_hash(&mut self, memo_hash: [u8; 32]) -> &mut Self {
+        self.instruction.memo_hash = Some(memo_hash);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn memo(&mut self, memo: String) -> &mut Self {
+        self.instruction.memo = Some(memo);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -566,6 +910,8 @@ impl<'a, 'b> TransferCpiBuilder<'a, 'b> {
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
         let args = TransferInstructionArgs {
             amount: self.instruction.amount.clone().expect("amount is not set"),
+            memo_hash: self.instruction.memo_hash.clone(),
+            memo: self.instruction.memo.clone(),
         };
         let instruction = TransferCpi {
             __program: self.instruction.__program,
@@ -611,6 +957,17 @@ impl<'a, 'b> TransferCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            lot_payer: self.instruction.lot_payer,
+
+            holding_period_account: self.instruction.holding_period_account,
+
+            restricted_holding_account: self.instruction.restricted_holding_account,
+
+            holding_lot_account: self.instruction.holding_lot_account,
+
+            system_program: self.instruction.system_program,
+            memo_program: self.instruction.memo_program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -632,7 +989,15 @@ struct TransferCpiBuilderInstruction<'a, 'b> {
     to_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     transfer_hook_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    lot_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    holding_period_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    restricted_holding_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    holding_lot_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    memo_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     amount: Option<u64>,
+    memo_hash: Option<[u8; 32]>,
+    memo: Option<String>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -20,11 +20,13 @@ pub struct TrimVerificationConfig {
 
     pub instructions_sysvar_or_creator: solana_pubkey::Pubkey,
 
+    pub payer: solana_pubkey::Pubkey,
+
     pub mint_account: solana_pubkey::Pubkey,
 
     pub config_account: solana_pubkey::Pubkey,
 
-    pub recipient: solana_pubkey::Pubkey,
+    pub rent_recipient: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
 
@@ -49,7 +51,7 @@ impl TrimVerificationConfig {
         args: TrimVerificationConfigInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
@@ -61,6 +63,7 @@ impl TrimVerificationConfig {
             self.instructions_sysvar_or_creator,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.mint_account,
             false,
@@ -69,7 +72,10 @@ impl TrimVerificationConfig {
             self.config_account,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new(self.recipient, false));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.rent_recipient,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -151,21 +157,23 @@ pub struct TrimVerificationConfigInstructionArgs {
 ///   0. `[]` mint
 ///   1. `[]` verification_config_or_mint_authority
 ///   2. `[]` instructions_sysvar_or_creator
-///   3. `[]` mint_account
-///   4. `[writable]` config_account
-///   5. `[writable]` recipient
-///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   7. `[writable, optional]` account_metas_pda
-///   8. `[optional]` transfer_hook_pda
-///   9. `[optional]` transfer_hook_program
+///   3. `[writable, signer]` payer
+///   4. `[]` mint_account
+///   5. `[writable]` config_account
+///   6. `[writable]` rent_recipient
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[writable, optional]` account_metas_pda
+///   9. `[optional]` transfer_hook_pda
+///   10. `[optional]` transfer_hook_program
 #[derive(Clone, Debug, Default)]
 pub struct TrimVerificationConfigBuilder {
     mint: Option<solana_pubkey::Pubkey>,
     verification_config_or_mint_authority: Option<solana_pubkey::Pubkey>,
     instructions_sysvar_or_creator: Option<solana_pubkey::Pubkey>,
+    payer: Option<solana_pubkey::Pubkey>,
     mint_account: Option<solana_pubkey::Pubkey>,
     config_account: Option<solana_pubkey::Pubkey>,
-    recipient: Option<solana_pubkey::Pubkey>,
+    rent_recipient: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     account_metas_pda: Option<solana_pubkey::Pubkey>,
     transfer_hook_pda: Option<solana_pubkey::Pubkey>,
@@ -200,6 +208,11 @@ impl TrimVerificationConfigBuilder {
         self
     }
     #[inline(always)]
+    pub fn payer(&mut self, payer: solana_pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    #[inline(always)]
     pub fn mint_account(&mut self, mint_account: solana_pubkey::Pubkey) -> &mut Self {
         self.mint_account = Some(mint_account);
         self
@@ -210,8 +223,8 @@ impl TrimVerificationConfigBuilder {
         self
     }
     #[inline(always)]
-    pub fn recipient(&mut self, recipient: solana_pubkey::Pubkey) -> &mut Self {
-        self.recipient = Some(recipient);
+    pub fn rent_recipient(&mut self, rent_recipient: solana_pubkey::Pubkey) -> &mut Self {
+        self.rent_recipient = Some(rent_recipient);
         self
     }
     /// `[optional account, default to '11111111111111111111111111111111']`
@@ -280,9 +293,10 @@ impl TrimVerificationConfigBuilder {
             instructions_sysvar_or_creator: self
                 .instructions_sysvar_or_creator
                 .expect("instructions_sysvar_or_creator is not set"),
+            payer: self.payer.expect("payer is not set"),
             mint_account: self.mint_account.expect("mint_account is not set"),
             config_account: self.config_account.expect("config_account is not set"),
-            recipient: self.recipient.expect("recipient is not set"),
+            rent_recipient: self.rent_recipient.expect("rent_recipient is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
@@ -309,11 +323,13 @@ pub struct TrimVerificationConfigCpiAccounts<'a, 'b> {
 
     pub instructions_sysvar_or_creator: &'b solana_account_info::AccountInfo<'a>,
 
+    pub payer: &'b solana_account_info::AccountInfo<'a>,
+
     pub mint_account: &'b solana_account_info::AccountInfo<'a>,
 
     pub config_account: &'b solana_account_info::AccountInfo<'a>,
 
-    pub recipient: &'b solana_account_info::AccountInfo<'a>,
+    pub rent_recipient: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
@@ -335,11 +351,13 @@ pub struct TrimVerificationConfigCpi<'a, 'b> {
 
     pub instructions_sysvar_or_creator: &'b solana_account_info::AccountInfo<'a>,
 
+    pub payer: &'b solana_account_info::AccountInfo<'a>,
+
     pub mint_account: &'b solana_account_info::AccountInfo<'a>,
 
     pub config_account: &'b solana_account_info::AccountInfo<'a>,
 
-    pub recipient: &'b solana_account_info::AccountInfo<'a>,
+    pub rent_recipient: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
@@ -363,9 +381,10 @@ impl<'a, 'b> TrimVerificationConfigCpi<'a, 'b> {
             mint: accounts.mint,
             verification_config_or_mint_authority: accounts.verification_config_or_mint_authority,
             instructions_sysvar_or_creator: accounts.instructions_sysvar_or_creator,
+            payer: accounts.payer,
             mint_account: accounts.mint_account,
             config_account: accounts.config_account,
-            recipient: accounts.recipient,
+            rent_recipient: accounts.rent_recipient,
             system_program: accounts.system_program,
             account_metas_pda: accounts.account_metas_pda,
             transfer_hook_pda: accounts.transfer_hook_pda,
@@ -396,7 +415,7 @@ impl<'a, 'b> TrimVerificationConfigCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.mint.key,
             false,
@@ -409,6 +428,7 @@ impl<'a, 'b> TrimVerificationConfigCpi<'a, 'b> {
             *self.instructions_sysvar_or_creator.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(*self.payer.key, true));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.mint_account.key,
             false,
@@ -418,7 +438,7 @@ impl<'a, 'b> TrimVerificationConfigCpi<'a, 'b> {
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(
-            *self.recipient.key,
+            *self.rent_recipient.key,
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -474,14 +494,15 @@ impl<'a, 'b> TrimVerificationConfigCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.verification_config_or_mint_authority.clone());
         account_infos.push(self.instructions_sysvar_or_creator.clone());
+        account_infos.push(self.payer.clone());
         account_infos.push(self.mint_account.clone());
         account_infos.push(self.config_account.clone());
-        account_infos.push(self.recipient.clone());
+        account_infos.push(self.rent_recipient.clone());
         account_infos.push(self.system_program.clone());
         if let Some(account_metas_pda) = self.account_metas_pda {
             account_infos.push(account_metas_pda.clone());
@@ -511,13 +532,14 @@ impl<'a, 'b> TrimVerificationConfigCpi<'a, 'b> {
 ///   0. `[]` mint
 ///   1. `[]` verification_config_or_mint_authority
 ///   2. `[]` instructions_sysvar_or_creator
-///   3. `[]` mint_account
-///   4. `[writable]` config_account
-///   5. `[writable]` recipient
-///   6. `[]` system_program
-///   7. `[writable, optional]` account_metas_pda
-///   8. `[optional]` transfer_hook_pda
-///   9. `[optional]` transfer_hook_program
+///   3. `[writable, signer]` payer
+///   4. `[]` mint_account
+///   5. `[writable]` config_account
+///   6. `[writable]` rent_recipient
+///   7. `[]` system_program
+///   8. `[writable, optional]` account_metas_pda
+///   9. `[optional]` transfer_hook_pda
+///   10. `[optional]` transfer_hook_program
 #[derive(Clone, Debug)]
 pub struct TrimVerificationConfigCpiBuilder<'a, 'b> {
     instruction: Box<TrimVerificationConfigCpiBuilderInstruction<'a, 'b>>,
@@ -530,9 +552,10 @@ impl<'a, 'b> TrimVerificationConfigCpiBuilder<'a, 'b> {
             mint: None,
             verification_config_or_mint_authority: None,
             instructions_sysvar_or_creator: None,
+            payer: None,
             mint_account: None,
             config_account: None,
-            recipient: None,
+            rent_recipient: None,
             system_program: None,
             account_metas_pda: None,
             transfer_hook_pda: None,
@@ -565,6 +588,11 @@ impl<'a, 'b> TrimVerificationConfigCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    #[inline(always)]
     pub fn mint_account(
         &mut self,
         mint_account: &'b solana_account_info::AccountInfo<'a>,
@@ -581,8 +609,11 @@ impl<'a, 'b> TrimVerificationConfigCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn recipient(&mut self, recipient: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-        self.instruction.recipient = Some(recipient);
+    pub fn rent_recipient(
+        &mut self,
+        rent_recipient: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rent_recipient = Some(rent_recipient);
         self
     }
     #[inline(always)]
@@ -684,6 +715,8 @@ impl<'a, 'b> TrimVerificationConfigCpiBuilder<'a, 'b> {
                 .instructions_sysvar_or_creator
                 .expect("instructions_sysvar_or_creator is not set"),
 
+            payer: self.instruction.payer.expect("payer is not set"),
+
             mint_account: self
                 .instruction
                 .mint_account
@@ -694,7 +727,10 @@ impl<'a, 'b> TrimVerificationConfigCpiBuilder<'a, 'b> {
                 .config_account
                 .expect("config_account is not set"),
 
-            recipient: self.instruction.recipient.expect("recipient is not set"),
+            rent_recipient: self
+                .instruction
+                .rent_recipient
+                .expect("rent_recipient is not set"),
 
             system_program: self
                 .instruction
@@ -721,9 +757,10 @@ struct TrimVerificationConfigCpiBuilderInstruction<'a, 'b> {
     mint: Option<&'b solana_account_info::AccountInfo<'a>>,
     verification_config_or_mint_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     instructions_sysvar_or_creator: Option<&'b solana_account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     mint_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     config_account: Option<&'b solana_account_info::AccountInfo<'a>>,
-    recipient: Option<&'b solana_account_info::AccountInfo<'a>>,
+    rent_recipient: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     account_metas_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
     transfer_hook_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
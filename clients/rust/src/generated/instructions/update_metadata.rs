@@ -29,6 +29,12 @@ pub struct UpdateMetadata {
     pub token_program: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub metadata_schema_account: Option<solana_pubkey::Pubkey>,
+
+    pub external_metadata_account: Option<solana_pubkey::Pubkey>,
+
+    pub metadata_program: Option<solana_pubkey::Pubkey>,
 }
 
 impl UpdateMetadata {
@@ -45,7 +51,7 @@ impl UpdateMetadata {
         args: UpdateMetadataInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
@@ -74,6 +80,39 @@ impl UpdateMetadata {
             self.system_program,
             false,
         ));
+        if let Some(metadata_schema_account) = self.metadata_schema_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                metadata_schema_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(external_metadata_account) = self.external_metadata_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                external_metadata_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(metadata_program) = self.metadata_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                metadata_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&UpdateMetadataInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -123,6 +162,9 @@ pub struct UpdateMetadataInstructionArgs {
 ///   5. `[writable]` mint_account
 ///   6. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 ///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[optional]` metadata_schema_account
+///   9. `[writable, optional]` external_metadata_account
+///   10. `[optional]` metadata_program
 #[derive(Clone, Debug, Default)]
 pub struct UpdateMetadataBuilder {
     mint: Option<solana_pubkey::Pubkey>,
@@ -133,6 +175,9 @@ pub struct UpdateMetadataBuilder {
     mint_account: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    metadata_schema_account: Option<solana_pubkey::Pubkey>,
+    external_metadata_account: Option<solana_pubkey::Pubkey>,
+    metadata_program: Option<solana_pubkey::Pubkey>,
     update_metadata_args: Option<UpdateMetadataArgs>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -189,6 +234,33 @@ impl UpdateMetadataBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn metadata_schema_account(
+        &mut self,
+        metadata_schema_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.metadata_schema_account = metadata_schema_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn external_metadata_account(
+        &mut self,
+        external_metadata_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.external_metadata_account = external_metadata_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn metadata_program(
+        &mut self,
+        metadata_program: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.metadata_program = metadata_program;
+        self
+    }
     #[inline(always)]
     pub fn update_metadata_args(&mut self, update_metadata_args: UpdateMetadataArgs) -> &mut Self {
         self.update_metadata_args = Some(update_metadata_args);
@@ -228,6 +300,9 @@ impl UpdateMetadataBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            metadata_schema_account: self.metadata_schema_account,
+            external_metadata_account: self.external_metadata_account,
+            metadata_program: self.metadata_program,
         };
         let args = UpdateMetadataInstructionArgs {
             update_metadata_args: self
@@ -257,6 +332,12 @@ pub struct UpdateMetadataCpiAccounts<'a, 'b> {
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub metadata_schema_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub external_metadata_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub metadata_program: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `update_metadata` CPI instruction.
@@ -279,6 +360,12 @@ pub struct UpdateMetadataCpi<'a, 'b> {
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub metadata_schema_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub external_metadata_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub metadata_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: UpdateMetadataInstructionArgs,
 }
@@ -299,6 +386,9 @@ impl<'a, 'b> UpdateMetadataCpi<'a, 'b> {
             mint_account: accounts.mint_account,
             token_program: accounts.token_program,
             system_program: accounts.system_program,
+            metadata_schema_account: accounts.metadata_schema_account,
+            external_metadata_account: accounts.external_metadata_account,
+            metadata_program: accounts.metadata_program,
             __args: args,
         }
     }
@@ -325,7 +415,7 @@ impl<'a, 'b> UpdateMetadataCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.mint.key,
             false,
@@ -355,6 +445,39 @@ impl<'a, 'b> UpdateMetadataCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(metadata_schema_account) = self.metadata_schema_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *metadata_schema_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(external_metadata_account) = self.external_metadata_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *external_metadata_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(metadata_program) = self.metadata_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *metadata_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -371,7 +494,7 @@ impl<'a, 'b> UpdateMetadataCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.verification_config_or_mint_authority.clone());
@@ -381,6 +504,15 @@ impl<'a, 'b> UpdateMetadataCpi<'a, 'b> {
         account_infos.push(self.mint_account.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(metadata_schema_account) = self.metadata_schema_account {
+            account_infos.push(metadata_schema_account.clone());
+        }
+        if let Some(external_metadata_account) = self.external_metadata_account {
+            account_infos.push(external_metadata_account.clone());
+        }
+        if let Some(metadata_program) = self.metadata_program {
+            account_infos.push(metadata_program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -405,6 +537,9 @@ impl<'a, 'b> UpdateMetadataCpi<'a, 'b> {
 ///   5. `[writable]` mint_account
 ///   6. `[]` token_program
 ///   7. `[]` system_program
+///   8. `[optional]` metadata_schema_account
+///   9. `[writable, optional]` external_metadata_account
+///   10. `[optional]` metadata_program
 #[derive(Clone, Debug)]
 pub struct UpdateMetadataCpiBuilder<'a, 'b> {
     instruction: Box<UpdateMetadataCpiBuilderInstruction<'a, 'b>>,
@@ -422,6 +557,9 @@ impl<'a, 'b> UpdateMetadataCpiBuilder<'a, 'b> {
             mint_account: None,
             token_program: None,
             system_program: None,
+            metadata_schema_account: None,
+            external_metadata_account: None,
+            metadata_program: None,
             update_metadata_args: None,
             __remaining_accounts: Vec::new(),
         });
@@ -486,6 +624,33 @@ impl<'a, 'b> UpdateMetadataCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn metadata_schema_account(
+        &mut self,
+        metadata_schema_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.metadata_schema_account = metadata_schema_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn external_metadata_account(
+        &mut self,
+        external_metadata_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.external_metadata_account = external_metadata_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn metadata_program(
+        &mut self,
+        metadata_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.metadata_program = metadata_program;
+        self
+    }
     #[inline(always)]
     pub fn update_metadata_args(&mut self, update_metadata_args: UpdateMetadataArgs) -> &mut Self {
         self.instruction.update_metadata_args = Some(update_metadata_args);
@@ -568,6 +733,12 @@ impl<'a, 'b> UpdateMetadataCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            metadata_schema_account: self.instruction.metadata_schema_account,
+
+            external_metadata_account: self.instruction.external_metadata_account,
+
+            metadata_program: self.instruction.metadata_program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -588,6 +759,9 @@ struct UpdateMetadataCpiBuilderInstruction<'a, 'b> {
     mint_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    metadata_schema_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    external_metadata_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    metadata_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     update_metadata_args: Option<UpdateMetadataArgs>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
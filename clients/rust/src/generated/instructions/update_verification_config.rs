@@ -33,6 +33,7 @@ pub struct UpdateVerificationConfig {
     pub transfer_hook_pda: Option<solana_pubkey::Pubkey>,
 
     pub transfer_hook_program: Option<solana_pubkey::Pubkey>,
+    pub pending_config_account: Option<solana_pubkey::Pubkey>,
 }
 
 impl UpdateVerificationConfig {
@@ -107,6 +108,17 @@ impl UpdateVerificationConfig {
                 false,
             ));
         }
+        if let Some(pending_config_account) = self.pending_config_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                pending_config_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&UpdateVerificationConfigInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -158,6 +170,7 @@ pub struct UpdateVerificationConfigInstructionArgs {
 ///   7. `[writable, optional]` account_metas_pda
 ///   8. `[optional]` transfer_hook_pda
 ///   9. `[optional]` transfer_hook_program
+///   10. `[writable, optional]` pending_config_account
 #[derive(Clone, Debug, Default)]
 pub struct UpdateVerificationConfigBuilder {
     mint: Option<solana_pubkey::Pubkey>,
@@ -170,6 +183,7 @@ pub struct UpdateVerificationConfigBuilder {
     account_metas_pda: Option<solana_pubkey::Pubkey>,
     transfer_hook_pda: Option<solana_pubkey::Pubkey>,
     transfer_hook_program: Option<solana_pubkey::Pubkey>,
+    pending_config_account: Option<solana_pubkey::Pubkey>,
     update_verification_config_args: Option<UpdateVerificationConfigArgs>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -247,6 +261,15 @@ impl UpdateVerificationConfigBuilder {
         self.transfer_hook_program = transfer_hook_program;
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn pending_config_account(
+        &mut self,
+        pending_config_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.pending_config_account = pending_config_account;
+        self
+    }
     #[inline(always)]
     pub fn update_verification_config_args(
         &mut self,
@@ -289,6 +312,7 @@ impl UpdateVerificationConfigBuilder {
             account_metas_pda: self.account_metas_pda,
             transfer_hook_pda: self.transfer_hook_pda,
             transfer_hook_program: self.transfer_hook_program,
+            pending_config_account: self.pending_config_account,
         };
         let args = UpdateVerificationConfigInstructionArgs {
             update_verification_config_args: self
@@ -322,6 +346,7 @@ pub struct UpdateVerificationConfigCpiAccounts<'a, 'b> {
     pub transfer_hook_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
 
     pub transfer_hook_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    pub pending_config_account: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `update_verification_config` CPI instruction.
@@ -348,6 +373,7 @@ pub struct UpdateVerificationConfigCpi<'a, 'b> {
     pub transfer_hook_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
 
     pub transfer_hook_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    pub pending_config_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: UpdateVerificationConfigInstructionArgs,
 }
@@ -370,6 +396,7 @@ impl<'a, 'b> UpdateVerificationConfigCpi<'a, 'b> {
             account_metas_pda: accounts.account_metas_pda,
             transfer_hook_pda: accounts.transfer_hook_pda,
             transfer_hook_program: accounts.transfer_hook_program,
+            pending_config_account: accounts.pending_config_account,
             __args: args,
         }
     }
@@ -455,6 +482,17 @@ impl<'a, 'b> UpdateVerificationConfigCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(pending_config_account) = self.pending_config_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *pending_config_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SECURITY_TOKEN_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -489,6 +527,9 @@ impl<'a, 'b> UpdateVerificationConfigCpi<'a, 'b> {
         if let Some(transfer_hook_program) = self.transfer_hook_program {
             account_infos.push(transfer_hook_program.clone());
         }
+        if let Some(pending_config_account) = self.pending_config_account {
+            account_infos.push(pending_config_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -515,6 +556,7 @@ impl<'a, 'b> UpdateVerificationConfigCpi<'a, 'b> {
 ///   7. `[writable, optional]` account_metas_pda
 ///   8. `[optional]` transfer_hook_pda
 ///   9. `[optional]` transfer_hook_program
+///   10. `[writable, optional]` pending_config_account
 #[derive(Clone, Debug)]
 pub struct UpdateVerificationConfigCpiBuilder<'a, 'b> {
     instruction: Box<UpdateVerificationConfigCpiBuilderInstruction<'a, 'b>>,
@@ -534,6 +576,7 @@ impl<'a, 'b> UpdateVerificationConfigCpiBuilder<'a, 'b> {
             account_metas_pda: None,
             transfer_hook_pda: None,
             transfer_hook_program: None,
+            pending_config_account: None,
             update_verification_config_args: None,
             __remaining_accounts: Vec::new(),
         });
@@ -617,6 +660,15 @@ impl<'a, 'b> UpdateVerificationConfigCpiBuilder<'a, 'b> {
         self.instruction.transfer_hook_program = transfer_hook_program;
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn pending_config_account(
+        &mut self,
+        pending_config_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.pending_config_account = pending_config_account;
+        self
+    }
     #[inline(always)]
     pub fn update_verification_config_args(
         &mut self,
@@ -703,6 +755,7 @@ impl<'a, 'b> UpdateVerificationConfigCpiBuilder<'a, 'b> {
             transfer_hook_pda: self.instruction.transfer_hook_pda,
 
             transfer_hook_program: self.instruction.transfer_hook_program,
+            pending_config_account: self.instruction.pending_config_account,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -725,6 +778,7 @@ struct UpdateVerificationConfigCpiBuilderInstruction<'a, 'b> {
     account_metas_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
     transfer_hook_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
     transfer_hook_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    pending_config_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     update_verification_config_args: Option<UpdateVerificationConfigArgs>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -13,4 +13,5 @@ use borsh::BorshSerialize;
 pub struct CloseClaimReceiptArgs {
     pub action_id: u64,
     pub merkle_proof: Option<Vec<[u8; 32]>>,
+    pub merkle_root: Option<[u8; 32]>,
 }
@@ -13,4 +13,5 @@ use borsh::BorshSerialize;
 pub struct CreateDistributionEscrowArgs {
     pub action_id: u64,
     pub merkle_root: [u8; 32],
+    pub claim_bitmap_leaves: u32,
 }
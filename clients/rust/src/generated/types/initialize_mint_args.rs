@@ -5,10 +5,12 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::DefaultAccountStateArgs;
 use crate::generated::types::MetadataPointerArgs;
 use crate::generated::types::MintArgs;
 use crate::generated::types::ScaledUiAmountConfigArgs;
 use crate::generated::types::TokenMetadataArgs;
+use crate::generated::types::TransferFeeConfigArgs;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -19,4 +21,6 @@ pub struct InitializeMintArgs {
     pub ix_metadata_pointer: Option<MetadataPointerArgs>,
     pub ix_metadata: Option<TokenMetadataArgs>,
     pub ix_scaled_ui_amount: Option<ScaledUiAmountConfigArgs>,
+    pub ix_transfer_fee: Option<TransferFeeConfigArgs>,
+    pub ix_default_account_state: Option<DefaultAccountStateArgs>,
 }
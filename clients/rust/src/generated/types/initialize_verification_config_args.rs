@@ -19,4 +19,8 @@ pub struct InitializeVerificationConfigArgs {
         serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
     )]
     pub program_addresses: Vec<Pubkey>,
+    pub threshold: u8,
+    pub lookback_window: u8,
+    pub require_top_level: bool,
+    pub timelock_seconds: u64,
 }
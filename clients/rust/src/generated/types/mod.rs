@@ -10,18 +10,32 @@ pub(crate) mod r#close_action_receipt_args;
 pub(crate) mod r#close_claim_receipt_args;
 pub(crate) mod r#close_rate_args;
 pub(crate) mod r#convert_args;
+pub(crate) mod r#create_accrual_config_args;
+pub(crate) mod r#create_auction_args;
 pub(crate) mod r#create_distribution_escrow_args;
+pub(crate) mod r#create_nav_oracle_args;
 pub(crate) mod r#create_proof_args;
 pub(crate) mod r#create_rate_args;
+pub(crate) mod r#day_count_convention;
+pub(crate) mod r#default_account_state_args;
+pub(crate) mod r#distribution_root_status;
+pub(crate) mod r#distribution_status;
+pub(crate) mod r#effective_rate;
+pub(crate) mod r#holder_status;
 pub(crate) mod r#initialize_mint_args;
 pub(crate) mod r#initialize_verification_config_args;
+pub(crate) mod r#metadata_field_format;
 pub(crate) mod r#metadata_pointer_args;
 pub(crate) mod r#mint_args;
 pub(crate) mod r#rate_config;
+pub(crate) mod r#recovery_reason;
+pub(crate) mod r#remove_rate_oracle_args;
 pub(crate) mod r#rounding;
 pub(crate) mod r#scaled_ui_amount_config_args;
+pub(crate) mod r#set_rate_oracle_args;
 pub(crate) mod r#split_args;
 pub(crate) mod r#token_metadata_args;
+pub(crate) mod r#transfer_fee_config_args;
 pub(crate) mod r#trim_verification_config_args;
 pub(crate) mod r#update_metadata_args;
 pub(crate) mod r#update_proof_args;
@@ -34,18 +48,32 @@ pub use self::r#close_action_receipt_args::*;
 pub use self::r#close_claim_receipt_args::*;
 pub use self::r#close_rate_args::*;
 pub use self::r#convert_args::*;
+pub use self::r#create_accrual_config_args::*;
+pub use self::r#create_auction_args::*;
 pub use self::r#create_distribution_escrow_args::*;
+pub use self::r#create_nav_oracle_args::*;
 pub use self::r#create_proof_args::*;
 pub use self::r#create_rate_args::*;
+pub use self::r#day_count_convention::*;
+pub use self::r#default_account_state_args::*;
+pub use self::r#distribution_root_status::*;
+pub use self::r#distribution_status::*;
+pub use self::r#effective_rate::*;
+pub use self::r#holder_status::*;
 pub use self::r#initialize_mint_args::*;
 pub use self::r#initialize_verification_config_args::*;
+pub use self::r#metadata_field_format::*;
 pub use self::r#metadata_pointer_args::*;
 pub use self::r#mint_args::*;
 pub use self::r#rate_config::*;
+pub use self::r#recovery_reason::*;
+pub use self::r#remove_rate_oracle_args::*;
 pub use self::r#rounding::*;
 pub use self::r#scaled_ui_amount_config_args::*;
+pub use self::r#set_rate_oracle_args::*;
 pub use self::r#split_args::*;
 pub use self::r#token_metadata_args::*;
+pub use self::r#transfer_fee_config_args::*;
 pub use self::r#trim_verification_config_args::*;
 pub use self::r#update_metadata_args::*;
 pub use self::r#update_proof_args::*;
@@ -20,4 +20,8 @@ pub struct UpdateVerificationConfigArgs {
         serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
     )]
     pub program_addresses: Vec<Pubkey>,
+    pub threshold: u8,
+    pub lookback_window: u8,
+    pub require_top_level: bool,
+    pub timelock_seconds: u64,
 }
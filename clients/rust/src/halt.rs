@@ -0,0 +1,109 @@
+//! ProgramConfig account of haltable instructions.
+//!
+//! The emergency authority can halt every instruction except the read-only ones and the
+//! halt instructions themselves. Haltable instructions take the ProgramConfig PDA after all
+//! their other accounts, including the CPI mode verification programs, and fail with
+//! `ProgramConfigRequired` without it.
+//!
+//! The generated builders don't know about the account: pass finished instructions through
+//! [`with_program_config`] before sending them. The helpers of this crate building ready
+//! instructions already do.
+//!
+//! Instructions built for program versions before the halt lack the account; rebuilding them
+//! with this crate, or appending the PDA, is the only change needed. See the CHANGELOG.
+
+use crate::pda::find_program_config_pda;
+use crate::programs::SECURITY_TOKEN_PROGRAM_ID;
+use security_token_core::SecurityTokenInstructionDiscriminators;
+use solana_instruction::{AccountMeta, Instruction};
+
+/// Whether `instruction` is a security token instruction the emergency authority can halt
+pub fn is_haltable(instruction: &Instruction) -> bool {
+    instruction.program_id == SECURITY_TOKEN_PROGRAM_ID
+        && instruction
+            .data
+            .first()
+            .and_then(|discriminator| {
+                SecurityTokenInstructionDiscriminators::from_u8(*discriminator)
+            })
+            .is_some_and(SecurityTokenInstructionDiscriminators::is_haltable)
+}
+
+/// Whether the last account of `instruction` is the ProgramConfig PDA
+pub fn has_program_config(instruction: &Instruction) -> bool {
+    instruction
+        .accounts
+        .last()
+        .is_some_and(|meta| meta.pubkey == find_program_config_pda().0)
+}
+
+/// Appends the ProgramConfig PDA to a haltable `instruction` missing it, other
+/// instructions are returned unchanged
+pub fn with_program_config(instruction: Instruction) -> Instruction {
+    let mut instruction = instruction;
+    if is_haltable(&instruction) && !has_program_config(&instruction) {
+        instruction.accounts.push(AccountMeta::new_readonly(
+            find_program_config_pda().0,
+            false,
+        ));
+    }
+    instruction
+}
+
+/// Removes the trailing ProgramConfig PDA of `instruction`, returning it
+pub(crate) fn take_program_config(instruction: &mut Instruction) -> Option<AccountMeta> {
+    if has_program_config(instruction) {
+        instruction.accounts.pop()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::{CAN_TRANSFER_DISCRIMINATOR, TRANSFER_DISCRIMINATOR};
+    use solana_pubkey::Pubkey;
+
+    fn instruction(program_id: Pubkey, discriminator: u8) -> Instruction {
+        Instruction {
+            program_id,
+            accounts: vec![AccountMeta::new_readonly(Pubkey::new_unique(), false)],
+            data: vec![discriminator],
+        }
+    }
+
+    #[test]
+    fn test_with_program_config() {
+        let (program_config, _) = find_program_config_pda();
+
+        let transfer = with_program_config(instruction(
+            SECURITY_TOKEN_PROGRAM_ID,
+            TRANSFER_DISCRIMINATOR,
+        ));
+        assert_eq!(transfer.accounts.len(), 2);
+        assert_eq!(
+            transfer.accounts[1],
+            AccountMeta::new_readonly(program_config, false)
+        );
+        // Appending twice keeps a single ProgramConfig
+        assert_eq!(with_program_config(transfer.clone()), transfer);
+
+        let can_transfer = instruction(SECURITY_TOKEN_PROGRAM_ID, CAN_TRANSFER_DISCRIMINATOR);
+        assert_eq!(with_program_config(can_transfer.clone()), can_transfer);
+
+        let other_program = instruction(Pubkey::new_unique(), TRANSFER_DISCRIMINATOR);
+        assert_eq!(with_program_config(other_program.clone()), other_program);
+    }
+
+    #[test]
+    fn test_take_program_config() {
+        let mut transfer = with_program_config(instruction(
+            SECURITY_TOKEN_PROGRAM_ID,
+            TRANSFER_DISCRIMINATOR,
+        ));
+        assert!(take_program_config(&mut transfer).is_some());
+        assert_eq!(transfer.accounts.len(), 1);
+        assert!(take_program_config(&mut transfer).is_none());
+    }
+}
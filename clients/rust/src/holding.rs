@@ -0,0 +1,258 @@
+//! Rule 144 sellable balance queries.
+//!
+//! Tokens recorded as restricted lots stay in their token account until the holding period
+//! of the lot elapses and the lot is unlocked with `UnlockLot`. The transfer hook only lets
+//! the balance above the locked amount of the RestrictedHolding leave the account.
+
+use crate::accounts::{HoldingLot, RestrictedHolding};
+use crate::pda::find_holding_lot_pda;
+use solana_pubkey::Pubkey;
+use thiserror::Error;
+
+/// Holding query errors
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum HoldingError {
+    #[error("RPC error: {0}")]
+    Rpc(String),
+    #[error("Invalid account: {0}")]
+    InvalidAccount(Pubkey),
+}
+
+/// Sellable balance of a token account
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SellableBalance {
+    /// Token account balance
+    pub balance: u64,
+    /// Amount of the lots not unlocked yet
+    pub locked_amount: u64,
+    /// Amount of the locked lots whose holding period elapsed, sellable once unlocked
+    pub unlockable_amount: u64,
+    /// Lots whose holding period elapsed, to unlock with `UnlockLot`
+    pub unlockable_lots: Vec<u64>,
+    /// Earliest unlock timestamp of the lots still in their holding period
+    pub next_unlock_timestamp: Option<i64>,
+}
+
+impl SellableBalance {
+    /// Amount the transfer hook lets leave the token account now
+    pub fn sellable_now(&self) -> u64 {
+        self.balance.saturating_sub(self.locked_amount)
+    }
+
+    /// Amount the transfer hook lets leave the token account once the unlockable lots are unlocked
+    pub fn sellable_after_unlock(&self) -> u64 {
+        self.balance
+            .saturating_sub(self.locked_amount.saturating_sub(self.unlockable_amount))
+    }
+}
+
+/// Addresses of the lots a RestrictedHolding recorded, unlocked lots are closed
+pub fn holding_lot_addresses(token_account: &Pubkey, holding: &RestrictedHolding) -> Vec<Pubkey> {
+    (0..holding.next_lot_id)
+        .map(|lot_id| find_holding_lot_pda(token_account, lot_id).0)
+        .collect()
+}
+
+/// Compute the sellable balance of a token account at `timestamp` from its balance,
+/// RestrictedHolding (`None` if it never received a lot) and open lots
+pub fn sellable_balance(
+    balance: u64,
+    holding: Option<&RestrictedHolding>,
+    lots: &[HoldingLot],
+    timestamp: i64,
+) -> SellableBalance {
+    let mut sellable = SellableBalance {
+        balance,
+        locked_amount: holding.map_or(0, |holding| holding.locked_amount),
+        ..SellableBalance::default()
+    };
+    for lot in lots {
+        if lot.unlock_timestamp <= timestamp {
+            sellable.unlockable_amount = sellable.unlockable_amount.saturating_add(lot.amount);
+            sellable.unlockable_lots.push(lot.lot_id);
+        } else {
+            sellable.next_unlock_timestamp = Some(
+                sellable
+                    .next_unlock_timestamp
+                    .map_or(lot.unlock_timestamp, |next| next.min(lot.unlock_timestamp)),
+            );
+        }
+    }
+    sellable.unlockable_lots.sort_unstable();
+    sellable
+}
+
+/// Fetch the sellable balance of a token account at the cluster clock
+#[cfg(feature = "rpc")]
+pub fn fetch_sellable_balance(
+    rpc: &solana_client::rpc_client::RpcClient,
+    token_account: &Pubkey,
+) -> Result<SellableBalance, HoldingError> {
+    use crate::pda::find_restricted_holding_pda;
+    use crate::programs::SECURITY_TOKEN_PROGRAM_ID;
+
+    // getMultipleAccounts accepts at most 100 keys per request
+    const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+    // Token account layout: mint (32) + owner (32) + amount (8)
+    const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+    // Clock sysvar layout: slot (8) + epoch_start_timestamp (8) + epoch (8) + leader_schedule_epoch (8) + unix_timestamp (8)
+    const CLOCK_UNIX_TIMESTAMP_OFFSET: usize = 32;
+
+    let read_bytes = |address: &Pubkey, data: &[u8], offset: usize| -> Result<[u8; 8], _> {
+        data.get(offset..offset + 8)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(HoldingError::InvalidAccount(*address))
+    };
+
+    let clock_id = solana_sdk_ids::sysvar::clock::ID;
+    let restricted_holding = find_restricted_holding_pda(token_account).0;
+    let accounts = rpc
+        .get_multiple_accounts(&[*token_account, restricted_holding, clock_id])
+        .map_err(|err| HoldingError::Rpc(err.to_string()))?;
+    let [token, holding, clock] = accounts.as_slice() else {
+        return Err(HoldingError::Rpc(
+            "unexpected number of accounts".to_string(),
+        ));
+    };
+
+    let token = token
+        .as_ref()
+        .ok_or(HoldingError::InvalidAccount(*token_account))?;
+    let balance = u64::from_le_bytes(read_bytes(
+        token_account,
+        &token.data,
+        TOKEN_ACCOUNT_AMOUNT_OFFSET,
+    )?);
+    let clock = clock
+        .as_ref()
+        .ok_or(HoldingError::InvalidAccount(clock_id))?;
+    let timestamp = i64::from_le_bytes(read_bytes(
+        &clock_id,
+        &clock.data,
+        CLOCK_UNIX_TIMESTAMP_OFFSET,
+    )?);
+
+    let holding = match holding {
+        None => None,
+        Some(account) if account.owner == SECURITY_TOKEN_PROGRAM_ID => Some(
+            RestrictedHolding::from_bytes(&account.data)
+                .map_err(|_| HoldingError::InvalidAccount(restricted_holding))?,
+        ),
+        Some(_) => return Err(HoldingError::InvalidAccount(restricted_holding)),
+    };
+
+    let mut lots = Vec::new();
+    if let Some(holding) = &holding {
+        let addresses = holding_lot_addresses(token_account, holding);
+        for chunk in addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let accounts = rpc
+                .get_multiple_accounts(chunk)
+                .map_err(|err| HoldingError::Rpc(err.to_string()))?;
+            for (address, account) in chunk.iter().zip(accounts) {
+                // Unlocked lots are closed
+                let Some(account) = account else {
+                    continue;
+                };
+                if account.owner != SECURITY_TOKEN_PROGRAM_ID {
+                    return Err(HoldingError::InvalidAccount(*address));
+                }
+                lots.push(
+                    HoldingLot::from_bytes(&account.data)
+                        .map_err(|_| HoldingError::InvalidAccount(*address))?,
+                );
+            }
+        }
+    }
+
+    Ok(sellable_balance(
+        balance,
+        holding.as_ref(),
+        &lots,
+        timestamp,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holding(token_account: Pubkey, locked_amount: u64, next_lot_id: u64) -> RestrictedHolding {
+        RestrictedHolding {
+            discriminator: 23,
+            mint: Pubkey::new_unique(),
+            token_account,
+            locked_amount,
+            next_lot_id,
+            bump: 255,
+        }
+    }
+
+    fn lot(token_account: Pubkey, lot_id: u64, amount: u64, unlock_timestamp: i64) -> HoldingLot {
+        HoldingLot {
+            discriminator: 24,
+            token_account,
+            payer: Pubkey::new_unique(),
+            lot_id,
+            amount,
+            acquired_timestamp: unlock_timestamp - 100,
+            unlock_timestamp,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_sellable_balance_without_lots() {
+        let sellable = sellable_balance(500, None, &[], 1_000);
+
+        assert_eq!(sellable.sellable_now(), 500);
+        assert_eq!(sellable.sellable_after_unlock(), 500);
+        assert!(sellable.unlockable_lots.is_empty());
+        assert_eq!(sellable.next_unlock_timestamp, None);
+    }
+
+    #[test]
+    fn test_sellable_balance_with_locked_and_unlockable_lots() {
+        let token_account = Pubkey::new_unique();
+        let holding = holding(token_account, 300, 3);
+        let lots = [
+            lot(token_account, 2, 100, 2_000),
+            lot(token_account, 0, 150, 900),
+            lot(token_account, 1, 50, 1_500),
+        ];
+
+        let sellable = sellable_balance(400, Some(&holding), &lots, 1_000);
+
+        assert_eq!(sellable.locked_amount, 300);
+        assert_eq!(sellable.unlockable_amount, 150);
+        assert_eq!(sellable.unlockable_lots, vec![0]);
+        assert_eq!(sellable.next_unlock_timestamp, Some(1_500));
+        assert_eq!(sellable.sellable_now(), 100);
+        assert_eq!(sellable.sellable_after_unlock(), 250);
+    }
+
+    #[test]
+    fn test_sellable_balance_below_locked_amount() {
+        let token_account = Pubkey::new_unique();
+        // Burns and forced transfers can leave less than the locked amount
+        let holding = holding(token_account, 300, 1);
+
+        let sellable = sellable_balance(200, Some(&holding), &[], 1_000);
+
+        assert_eq!(sellable.sellable_now(), 0);
+        assert_eq!(sellable.sellable_after_unlock(), 0);
+    }
+
+    #[test]
+    fn test_holding_lot_addresses() {
+        let token_account = Pubkey::new_unique();
+        let addresses = holding_lot_addresses(&token_account, &holding(token_account, 0, 2));
+
+        assert_eq!(
+            addresses,
+            vec![
+                find_holding_lot_pda(&token_account, 0).0,
+                find_holding_lot_pda(&token_account, 1).0,
+            ]
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Investor identity helpers.
+//!
+//! The program stores an opaque 32-byte investor identifier per `Identity` account.
+//! Registrars that key investors by DID or an internal reference can derive it with
+//! [`investor_id`] so the same identifier always maps to the same account.
+
+use solana_keccak_hasher::hash;
+use solana_pubkey::Pubkey;
+
+use crate::pda::find_identity_pda;
+
+/// Maximum number of token accounts the program allows per identity
+pub const MAX_IDENTITY_WALLETS: usize = 6;
+
+/// Derive the on-chain investor identifier from an off-chain reference (e.g. a DID)
+pub fn investor_id(reference: &str) -> [u8; 32] {
+    hash(reference.as_bytes()).to_bytes()
+}
+
+/// Identity PDA of the investor known by `reference` on `mint`
+pub fn find_identity_for_reference(mint: &Pubkey, reference: &str) -> (Pubkey, u8) {
+    find_identity_pda(mint, &investor_id(reference))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_investor_id_is_deterministic() {
+        let did = "did:example:123456789abcdefghi";
+        assert_eq!(investor_id(did), investor_id(did));
+        assert_ne!(investor_id(did), investor_id("did:example:other"));
+
+        let mint = Pubkey::new_unique();
+        assert_eq!(
+            find_identity_for_reference(&mint, did),
+            find_identity_pda(&mint, &investor_id(did))
+        );
+    }
+
+    #[test]
+    fn test_max_identity_wallets_matches_program() {
+        assert_eq!(
+            MAX_IDENTITY_WALLETS,
+            security_token_program::constants::MAX_IDENTITY_WALLETS
+        );
+    }
+}
@@ -0,0 +1,167 @@
+//! Instruction discriminator constants without the instruction builders.
+//!
+//! Built without the `client` feature the crate exposes these as `instructions`, next to
+//! the Borsh arg types, for verification programs matching on the instruction they verify.
+//! The values come from the discriminator registry shared with the program.
+
+use security_token_core::SecurityTokenInstructionDiscriminators;
+
+macro_rules! instruction_discriminators {
+    ($($name:ident => $variant:ident),* $(,)?) => {
+        $(
+            pub const $name: u8 = SecurityTokenInstructionDiscriminators::$variant as u8;
+        )*
+
+        #[cfg(all(test, feature = "client"))]
+        mod tests {
+            #[test]
+            fn test_match_generated_constants() {
+                $(
+                    assert_eq!(
+                        super::$name,
+                        crate::generated::instructions::$name,
+                        stringify!($name)
+                    );
+                )*
+            }
+        }
+    };
+}
+
+instruction_discriminators! {
+    INITIALIZE_MINT_DISCRIMINATOR => InitializeMint,
+    UPDATE_METADATA_DISCRIMINATOR => UpdateMetadata,
+    INITIALIZE_VERIFICATION_CONFIG_DISCRIMINATOR => InitializeVerificationConfig,
+    UPDATE_VERIFICATION_CONFIG_DISCRIMINATOR => UpdateVerificationConfig,
+    TRIM_VERIFICATION_CONFIG_DISCRIMINATOR => TrimVerificationConfig,
+    VERIFY_DISCRIMINATOR => Verify,
+    MINT_DISCRIMINATOR => Mint,
+    BURN_DISCRIMINATOR => Burn,
+    PAUSE_DISCRIMINATOR => Pause,
+    RESUME_DISCRIMINATOR => Resume,
+    FREEZE_DISCRIMINATOR => Freeze,
+    THAW_DISCRIMINATOR => Thaw,
+    TRANSFER_DISCRIMINATOR => Transfer,
+    CREATE_RATE_ACCOUNT_DISCRIMINATOR => CreateRateAccount,
+    UPDATE_RATE_ACCOUNT_DISCRIMINATOR => UpdateRateAccount,
+    CLOSE_RATE_ACCOUNT_DISCRIMINATOR => CloseRateAccount,
+    SPLIT_DISCRIMINATOR => Split,
+    CONVERT_DISCRIMINATOR => Convert,
+    CREATE_PROOF_ACCOUNT_DISCRIMINATOR => CreateProofAccount,
+    UPDATE_PROOF_ACCOUNT_DISCRIMINATOR => UpdateProofAccount,
+    CREATE_DISTRIBUTION_ESCROW_DISCRIMINATOR => CreateDistributionEscrow,
+    CLAIM_DISTRIBUTION_DISCRIMINATOR => ClaimDistribution,
+    CLOSE_ACTION_RECEIPT_ACCOUNT_DISCRIMINATOR => CloseActionReceiptAccount,
+    CLOSE_CLAIM_RECEIPT_ACCOUNT_DISCRIMINATOR => CloseClaimReceiptAccount,
+    CREATE_WRAP_VAULT_DISCRIMINATOR => CreateWrapVault,
+    WRAP_TOKEN_DISCRIMINATOR => WrapToken,
+    UNWRAP_TOKEN_DISCRIMINATOR => UnwrapToken,
+    CREATE_AGENT_ACCOUNT_DISCRIMINATOR => CreateAgentAccount,
+    UPDATE_AGENT_ACCOUNT_DISCRIMINATOR => UpdateAgentAccount,
+    CLOSE_AGENT_ACCOUNT_DISCRIMINATOR => CloseAgentAccount,
+    CREATE_IDENTITY_ACCOUNT_DISCRIMINATOR => CreateIdentityAccount,
+    ADD_IDENTITY_WALLET_DISCRIMINATOR => AddIdentityWallet,
+    REMOVE_IDENTITY_WALLET_DISCRIMINATOR => RemoveIdentityWallet,
+    CLOSE_IDENTITY_ACCOUNT_DISCRIMINATOR => CloseIdentityAccount,
+    CREATE_POSITION_LIMIT_DISCRIMINATOR => CreatePositionLimit,
+    UPDATE_POSITION_LIMIT_DISCRIMINATOR => UpdatePositionLimit,
+    CLOSE_POSITION_LIMIT_DISCRIMINATOR => ClosePositionLimit,
+    CREATE_TRANSFER_ACCEPTANCE_DISCRIMINATOR => CreateTransferAcceptance,
+    CLOSE_TRANSFER_ACCEPTANCE_DISCRIMINATOR => CloseTransferAcceptance,
+    OFFER_TRANSFER_DISCRIMINATOR => OfferTransfer,
+    ACCEPT_TRANSFER_DISCRIMINATOR => AcceptTransfer,
+    CANCEL_TRANSFER_DISCRIMINATOR => CancelTransfer,
+    CREATE_TRANSFER_APPROVAL_DISCRIMINATOR => CreateTransferApproval,
+    UPDATE_TRANSFER_APPROVAL_DISCRIMINATOR => UpdateTransferApproval,
+    CLOSE_TRANSFER_APPROVAL_DISCRIMINATOR => CloseTransferApproval,
+    REQUEST_TRANSFER_DISCRIMINATOR => RequestTransfer,
+    APPROVE_TRANSFER_DISCRIMINATOR => ApproveTransfer,
+    REJECT_TRANSFER_DISCRIMINATOR => RejectTransfer,
+    CREATE_ACCRUAL_CONFIG_DISCRIMINATOR => CreateAccrualConfig,
+    CLAIM_COUPON_DISCRIMINATOR => ClaimCoupon,
+    CREATE_MATURITY_DISCRIMINATOR => CreateMaturity,
+    REDEEM_AT_MATURITY_DISCRIMINATOR => RedeemAtMaturity,
+    WITHDRAW_UNCLAIMED_REDEMPTION_DISCRIMINATOR => WithdrawUnclaimedRedemption,
+    CREATE_NAV_ORACLE_DISCRIMINATOR => CreateNavOracle,
+    UPDATE_MULTIPLIER_FROM_ORACLE_DISCRIMINATOR => UpdateMultiplierFromOracle,
+    SET_RATE_ORACLE_DISCRIMINATOR => SetRateOracle,
+    REMOVE_RATE_ORACLE_DISCRIMINATOR => RemoveRateOracle,
+    OPEN_SUBSCRIPTION_DISCRIMINATOR => OpenSubscription,
+    COMMIT_DISCRIMINATOR => Commit,
+    ALLOCATE_DISCRIMINATOR => Allocate,
+    SETTLE_DISCRIMINATOR => Settle,
+    CREATE_AUCTION_DISCRIMINATOR => CreateAuction,
+    PLACE_BID_DISCRIMINATOR => PlaceBid,
+    CLEAR_AUCTION_DISCRIMINATOR => ClearAuction,
+    SETTLE_BID_DISCRIMINATOR => SettleBid,
+    CREATE_HOLDING_PERIOD_DISCRIMINATOR => CreateHoldingPeriod,
+    CLOSE_HOLDING_PERIOD_DISCRIMINATOR => CloseHoldingPeriod,
+    UNLOCK_LOT_DISCRIMINATOR => UnlockLot,
+    SUSPEND_DISCRIMINATOR => Suspend,
+    UNSUSPEND_DISCRIMINATOR => Unsuspend,
+    THAW_EXPIRED_DISCRIMINATOR => ThawExpired,
+    CREATE_VESTING_DISCRIMINATOR => CreateVesting,
+    RELEASE_VESTED_DISCRIMINATOR => ReleaseVested,
+    CREATE_WITHHOLDING_RATE_DISCRIMINATOR => CreateWithholdingRate,
+    UPDATE_WITHHOLDING_RATE_DISCRIMINATOR => UpdateWithholdingRate,
+    CREATE_DISTRIBUTION_PAYOUT_DISCRIMINATOR => CreateDistributionPayout,
+    CREATE_CLAIM_DEADLINE_DISCRIMINATOR => CreateClaimDeadline,
+    RECLAIM_UNCLAIMED_DISCRIMINATOR => ReclaimUnclaimed,
+    AMEND_DISTRIBUTION_ROOT_DISCRIMINATOR => AmendDistributionRoot,
+    TRANSFER_WITH_PERMIT_DISCRIMINATOR => TransferWithPermit,
+    CREATE_SESSION_KEY_DISCRIMINATOR => CreateSessionKey,
+    CLOSE_SESSION_KEY_DISCRIMINATOR => CloseSessionKey,
+    RECOVER_TOKENS_DISCRIMINATOR => RecoverTokens,
+    CREATE_MINT_MIGRATION_DISCRIMINATOR => CreateMintMigration,
+    MIGRATE_BALANCES_DISCRIMINATOR => MigrateBalances,
+    CREATE_METADATA_SCHEMA_DISCRIMINATOR => CreateMetadataSchema,
+    UPDATE_METADATA_SCHEMA_DISCRIMINATOR => UpdateMetadataSchema,
+    CLOSE_METADATA_SCHEMA_DISCRIMINATOR => CloseMetadataSchema,
+    FREEZE_METADATA_DISCRIMINATOR => FreezeMetadata,
+    CREATE_CONFIG_TEMPLATE_DISCRIMINATOR => CreateConfigTemplate,
+    UPDATE_CONFIG_TEMPLATE_DISCRIMINATOR => UpdateConfigTemplate,
+    CLOSE_CONFIG_TEMPLATE_DISCRIMINATOR => CloseConfigTemplate,
+    SET_CONFIG_TEMPLATE_DISCRIMINATOR => SetConfigTemplate,
+    CAN_TRANSFER_DISCRIMINATOR => CanTransfer,
+    GET_HOLDER_STATUS_DISCRIMINATOR => GetHolderStatus,
+    GET_EFFECTIVE_RATE_DISCRIMINATOR => GetEffectiveRate,
+    GET_DISTRIBUTION_STATUS_DISCRIMINATOR => GetDistributionStatus,
+    ATTEST_COLLATERAL_DISCRIMINATOR => AttestCollateral,
+    PUBLISH_RESERVE_ATTESTATION_DISCRIMINATOR => PublishReserveAttestation,
+    CREATE_SUB_ACCOUNT_LEDGER_DISCRIMINATOR => CreateSubAccountLedger,
+    CREDIT_SUB_ACCOUNT_DISCRIMINATOR => CreditSubAccount,
+    DEBIT_SUB_ACCOUNT_DISCRIMINATOR => DebitSubAccount,
+    CLOSE_SUB_ACCOUNT_LEDGER_DISCRIMINATOR => CloseSubAccountLedger,
+    CREATE_SUCCESSION_POLICY_DISCRIMINATOR => CreateSuccessionPolicy,
+    HEARTBEAT_DISCRIMINATOR => Heartbeat,
+    CLAIM_SUCCESSION_DISCRIMINATOR => ClaimSuccession,
+    CLOSE_SUCCESSION_POLICY_DISCRIMINATOR => CloseSuccessionPolicy,
+    CREATE_METADATA_TRANSLATOR_DISCRIMINATOR => CreateMetadataTranslator,
+    UPDATE_TRANSLATION_DISCRIMINATOR => UpdateTranslation,
+    CLOSE_METADATA_TRANSLATOR_DISCRIMINATOR => CloseMetadataTranslator,
+    AUDIT_MINT_DISCRIMINATOR => AuditMint,
+    SET_TRANSFER_FEE_DISCRIMINATOR => SetTransferFee,
+    WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_DISCRIMINATOR => WithdrawWithheldTokensFromAccounts,
+    HARVEST_WITHHELD_TOKENS_TO_MINT_DISCRIMINATOR => HarvestWithheldTokensToMint,
+    UPDATE_DEFAULT_ACCOUNT_STATE_DISCRIMINATOR => UpdateDefaultAccountState,
+    UPDATE_SCALED_UI_AMOUNT_MULTIPLIER_DISCRIMINATOR => UpdateScaledUiAmountMultiplier,
+    REMOVE_METADATA_FIELD_DISCRIMINATOR => RemoveMetadataField,
+    ANCHOR_DOCUMENT_DISCRIMINATOR => AnchorDocument,
+    UPDATE_DOCUMENT_DISCRIMINATOR => UpdateDocument,
+    REMOVE_DOCUMENT_DISCRIMINATOR => RemoveDocument,
+    PROPOSE_MINT_CREATOR_TRANSFER_DISCRIMINATOR => ProposeMintCreatorTransfer,
+    ACCEPT_MINT_CREATOR_TRANSFER_DISCRIMINATOR => AcceptMintCreatorTransfer,
+    SET_MINT_AUTHORITY_MULTISIG_DISCRIMINATOR => SetMintAuthorityMultisig,
+    ROTATE_PERMANENT_DELEGATE_DISCRIMINATOR => RotatePermanentDelegate,
+    DISABLE_PERMANENT_DELEGATE_DISCRIMINATOR => DisablePermanentDelegate,
+    SET_PAUSE_DELEGATE_DISCRIMINATOR => SetPauseDelegate,
+    CLOSE_MINT_AUTHORITY_DISCRIMINATOR => CloseMintAuthority,
+    SET_CONFIG_ADMIN_DISCRIMINATOR => SetConfigAdmin,
+    APPLY_PENDING_CONFIG_DISCRIMINATOR => ApplyPendingConfig,
+    CANCEL_PENDING_CONFIG_DISCRIMINATOR => CancelPendingConfig,
+    SET_EMERGENCY_AUTHORITY_DISCRIMINATOR => SetEmergencyAuthority,
+    SET_PROGRAM_HALT_DISCRIMINATOR => SetProgramHalt,
+    ADD_TO_ALLOWLIST_DISCRIMINATOR => AddToAllowlist,
+    REMOVE_FROM_ALLOWLIST_DISCRIMINATOR => RemoveFromAllowlist,
+    SET_ALLOWLIST_MODE_DISCRIMINATOR => SetAllowlistMode,
+}
@@ -0,0 +1,398 @@
+//! Keeper loops for cranking scheduled corporate actions.
+//!
+//! The program does not persist schedules on-chain yet, so a keeper is driven by
+//! [`CrankJob`]s supplied by the issuer. Each poll the keeper asks every job whether
+//! it is due, builds the crank transaction (optionally prefixed with compute budget
+//! instructions), sends it with retries and reports the outcome through
+//! [`KeeperAlerts`].
+
+use crate::compute_budget::prepend_compute_budget;
+use solana_client::rpc_client::RpcClient;
+use solana_instruction::Instruction;
+use solana_sdk::signature::{Signature, Signer};
+use solana_sdk::transaction::Transaction;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Keeper configuration
+#[derive(Clone, Debug)]
+pub struct KeeperConfig {
+    /// Delay between two polls of the job list
+    pub poll_interval: Duration,
+    /// Number of send attempts per crank before giving up
+    pub max_attempts: u32,
+    /// Delay between two send attempts, doubled after every failure
+    pub retry_backoff: Duration,
+    /// Optional compute unit limit prepended to every crank transaction
+    pub compute_unit_limit: Option<u32>,
+    /// Optional priority fee (micro-lamports per compute unit)
+    pub priority_fee_micro_lamports: Option<u64>,
+}
+
+impl Default for KeeperConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(10),
+            max_attempts: 3,
+            retry_backoff: Duration::from_millis(500),
+            compute_unit_limit: None,
+            priority_fee_micro_lamports: None,
+        }
+    }
+}
+
+/// Keeper errors
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum KeeperError {
+    #[error("RPC error: {0}")]
+    Rpc(String),
+    #[error("Job {job} failed: {reason}")]
+    Job { job: String, reason: String },
+    #[error("Job {job} failed after {attempts} attempts: {last_error}")]
+    RetriesExhausted {
+        job: String,
+        attempts: u32,
+        last_error: String,
+    },
+}
+
+/// A unit of scheduled work the keeper can crank
+pub trait CrankJob {
+    /// Human readable job name used in alerts
+    fn name(&self) -> &str;
+
+    /// Returns the instructions to send when the job is due at `now` (unix timestamp), `None` otherwise
+    fn due_instructions(
+        &mut self,
+        rpc: &RpcClient,
+        now: i64,
+    ) -> Result<Option<Vec<Instruction>>, KeeperError>;
+
+    /// Called once the crank transaction has been confirmed
+    fn on_confirmed(&mut self, _signature: &Signature) {}
+
+    /// Whether the job is finished and can be dropped from the keeper
+    fn is_complete(&self) -> bool {
+        false
+    }
+}
+
+/// Hooks for alerting on keeper activity. All methods default to no-ops.
+pub trait KeeperAlerts {
+    fn on_crank_success(&self, _job: &str, _signature: &Signature) {}
+    fn on_crank_retry(&self, _job: &str, _attempt: u32, _error: &KeeperError) {}
+    fn on_crank_failure(&self, _job: &str, _error: &KeeperError) {}
+}
+
+/// Alerts implementation that ignores every event
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopAlerts;
+
+impl KeeperAlerts for NoopAlerts {}
+
+/// Job that sends a fixed set of instructions once `execute_at` has passed
+pub struct ScheduledInstructions {
+    name: String,
+    execute_at: i64,
+    instructions: Vec<Instruction>,
+    done: bool,
+}
+
+impl ScheduledInstructions {
+    pub fn new(name: impl Into<String>, execute_at: i64, instructions: Vec<Instruction>) -> Self {
+        Self {
+            name: name.into(),
+            execute_at,
+            instructions,
+            done: false,
+        }
+    }
+
+    /// Unix timestamp after which the job is due
+    pub fn execute_at(&self) -> i64 {
+        self.execute_at
+    }
+}
+
+impl CrankJob for ScheduledInstructions {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn due_instructions(
+        &mut self,
+        _rpc: &RpcClient,
+        now: i64,
+    ) -> Result<Option<Vec<Instruction>>, KeeperError> {
+        if self.done || now < self.execute_at {
+            return Ok(None);
+        }
+        Ok(Some(self.instructions.clone()))
+    }
+
+    fn on_confirmed(&mut self, _signature: &Signature) {
+        self.done = true;
+    }
+
+    fn is_complete(&self) -> bool {
+        self.done
+    }
+}
+
+/// Result of cranking a single job
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CrankOutcome {
+    Confirmed { job: String, signature: Signature },
+    Failed { job: String, error: KeeperError },
+}
+
+/// Prepends compute budget instructions according to `config`
+pub fn with_compute_budget(
+    instructions: Vec<Instruction>,
+    config: &KeeperConfig,
+) -> Vec<Instruction> {
+    prepend_compute_budget(
+        instructions,
+        config.compute_unit_limit,
+        config.priority_fee_micro_lamports,
+    )
+}
+
+/// Polls registered jobs and sends their crank transactions
+pub struct Keeper<'a, A: KeeperAlerts = NoopAlerts> {
+    rpc: &'a RpcClient,
+    payer: &'a dyn Signer,
+    config: KeeperConfig,
+    alerts: A,
+    jobs: Vec<Box<dyn CrankJob + 'a>>,
+}
+
+impl<'a> Keeper<'a, NoopAlerts> {
+    pub fn new(rpc: &'a RpcClient, payer: &'a dyn Signer, config: KeeperConfig) -> Self {
+        Self::with_alerts(rpc, payer, config, NoopAlerts)
+    }
+}
+
+impl<'a, A: KeeperAlerts> Keeper<'a, A> {
+    pub fn with_alerts(
+        rpc: &'a RpcClient,
+        payer: &'a dyn Signer,
+        config: KeeperConfig,
+        alerts: A,
+    ) -> Self {
+        Self {
+            rpc,
+            payer,
+            config,
+            alerts,
+            jobs: Vec::new(),
+        }
+    }
+
+    /// Registers a job to be polled
+    pub fn add_job(&mut self, job: impl CrankJob + 'a) -> &mut Self {
+        self.jobs.push(Box::new(job));
+        self
+    }
+
+    /// Number of jobs still registered
+    pub fn pending_jobs(&self) -> usize {
+        self.jobs.len()
+    }
+
+    /// Current cluster time, taken from the latest slot's block time
+    pub fn cluster_time(&self) -> Result<i64, KeeperError> {
+        let slot = self
+            .rpc
+            .get_slot()
+            .map_err(|e| KeeperError::Rpc(e.to_string()))?;
+        self.rpc
+            .get_block_time(slot)
+            .map_err(|e| KeeperError::Rpc(e.to_string()))
+    }
+
+    /// Polls every job once at `now` and cranks the due ones
+    pub fn run_once_at(&mut self, now: i64) -> Vec<CrankOutcome> {
+        let mut outcomes = Vec::new();
+        let mut jobs = std::mem::take(&mut self.jobs);
+        for job in jobs.iter_mut() {
+            let instructions = match job.due_instructions(self.rpc, now) {
+                Ok(Some(instructions)) => instructions,
+                Ok(None) => continue,
+                Err(error) => {
+                    self.alerts.on_crank_failure(job.name(), &error);
+                    outcomes.push(CrankOutcome::Failed {
+                        job: job.name().to_string(),
+                        error,
+                    });
+                    continue;
+                }
+            };
+            match self.send_with_retries(job.name(), instructions) {
+                Ok(signature) => {
+                    job.on_confirmed(&signature);
+                    self.alerts.on_crank_success(job.name(), &signature);
+                    outcomes.push(CrankOutcome::Confirmed {
+                        job: job.name().to_string(),
+                        signature,
+                    });
+                }
+                Err(error) => {
+                    self.alerts.on_crank_failure(job.name(), &error);
+                    outcomes.push(CrankOutcome::Failed {
+                        job: job.name().to_string(),
+                        error,
+                    });
+                }
+            }
+        }
+        jobs.retain(|job| !job.is_complete());
+        self.jobs = jobs;
+        outcomes
+    }
+
+    /// Polls every job once using the cluster time
+    pub fn run_once(&mut self) -> Result<Vec<CrankOutcome>, KeeperError> {
+        let now = self.cluster_time()?;
+        Ok(self.run_once_at(now))
+    }
+
+    /// Runs the poll loop until `stop` is set or no jobs remain
+    pub fn run(&mut self, stop: &AtomicBool) {
+        while !stop.load(Ordering::Relaxed) && !self.jobs.is_empty() {
+            if let Err(error) = self.run_once() {
+                self.alerts.on_crank_failure("keeper", &error);
+            }
+            thread::sleep(self.config.poll_interval);
+        }
+    }
+
+    fn send_with_retries(
+        &self,
+        job: &str,
+        instructions: Vec<Instruction>,
+    ) -> Result<Signature, KeeperError> {
+        let instructions = with_compute_budget(instructions, &self.config);
+        let attempts = self.config.max_attempts.max(1);
+        let mut backoff = self.config.retry_backoff;
+        let mut last_error = KeeperError::Rpc("no attempt made".to_string());
+
+        for attempt in 1..=attempts {
+            match self.send(&instructions) {
+                Ok(signature) => return Ok(signature),
+                Err(error) => {
+                    if attempt < attempts {
+                        self.alerts.on_crank_retry(job, attempt, &error);
+                        thread::sleep(backoff);
+                        backoff = backoff.saturating_mul(2);
+                    }
+                    last_error = error;
+                }
+            }
+        }
+
+        Err(KeeperError::RetriesExhausted {
+            job: job.to_string(),
+            attempts,
+            last_error: last_error.to_string(),
+        })
+    }
+
+    fn send(&self, instructions: &[Instruction]) -> Result<Signature, KeeperError> {
+        let blockhash = self
+            .rpc
+            .get_latest_blockhash()
+            .map_err(|e| KeeperError::Rpc(e.to_string()))?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &[self.payer],
+            blockhash,
+        );
+        self.rpc
+            .send_and_confirm_transaction(&transaction)
+            .map_err(|e| KeeperError::Rpc(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::compute_budget::ComputeBudgetInstruction;
+    use solana_sdk::signature::Keypair;
+    use std::cell::RefCell;
+
+    fn dummy_instruction() -> Instruction {
+        Instruction {
+            program_id: crate::programs::SECURITY_TOKEN_PROGRAM_ID,
+            accounts: vec![],
+            data: vec![8],
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingAlerts {
+        successes: RefCell<Vec<String>>,
+    }
+
+    impl KeeperAlerts for &RecordingAlerts {
+        fn on_crank_success(&self, job: &str, _signature: &Signature) {
+            self.successes.borrow_mut().push(job.to_string());
+        }
+    }
+
+    #[test]
+    fn test_with_compute_budget_prepends_instructions() {
+        let config = KeeperConfig {
+            compute_unit_limit: Some(400_000),
+            priority_fee_micro_lamports: Some(1_000),
+            ..KeeperConfig::default()
+        };
+        let instructions = with_compute_budget(vec![dummy_instruction()], &config);
+        assert_eq!(instructions.len(), 3);
+        assert_eq!(
+            instructions[0],
+            ComputeBudgetInstruction::set_compute_unit_limit(400_000)
+        );
+        assert_eq!(
+            instructions[1],
+            ComputeBudgetInstruction::set_compute_unit_price(1_000)
+        );
+        assert_eq!(instructions[2], dummy_instruction());
+
+        let untouched = with_compute_budget(vec![dummy_instruction()], &KeeperConfig::default());
+        assert_eq!(untouched, vec![dummy_instruction()]);
+    }
+
+    #[test]
+    fn test_keeper_cranks_due_jobs_only() {
+        let rpc = RpcClient::new_mock("succeeds".to_string());
+        let payer = Keypair::new();
+        let alerts = RecordingAlerts::default();
+        let mut keeper = Keeper::with_alerts(&rpc, &payer, KeeperConfig::default(), &alerts);
+        keeper.add_job(ScheduledInstructions::new(
+            "pause",
+            100,
+            vec![dummy_instruction()],
+        ));
+        keeper.add_job(ScheduledInstructions::new(
+            "resume",
+            200,
+            vec![dummy_instruction()],
+        ));
+
+        assert!(keeper.run_once_at(50).is_empty());
+
+        let outcomes = keeper.run_once_at(150);
+        assert_eq!(outcomes.len(), 1);
+        assert!(matches!(&outcomes[0], CrankOutcome::Confirmed { job, .. } if job == "pause"));
+        assert_eq!(keeper.pending_jobs(), 1);
+
+        let outcomes = keeper.run_once_at(250);
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(keeper.pending_jobs(), 0);
+        assert_eq!(*alerts.successes.borrow(), vec!["pause", "resume"]);
+    }
+}
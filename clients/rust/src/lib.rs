@@ -1,15 +1,37 @@
+#[cfg(feature = "client")]
 mod generated;
 
+/// Without the `client` feature only the arg types and the program id are built
+#[cfg(not(feature = "client"))]
+mod generated {
+    pub mod programs;
+    pub mod types;
+}
+
+#[cfg(feature = "client")]
 use generated::*;
 
+#[cfg(feature = "client")]
 pub mod accounts {
     pub use super::generated::accounts::*;
 }
 
+#[cfg(feature = "client")]
 pub mod instructions {
     pub use super::generated::instructions::*;
 }
 
+/// Instruction discriminators of the program, the builders need the `client` feature
+#[cfg(not(feature = "client"))]
+pub mod instructions {
+    pub use super::instruction_discriminators::*;
+}
+
+#[cfg(any(test, not(feature = "client")))]
+#[cfg_attr(feature = "client", allow(dead_code))]
+mod instruction_discriminators;
+
+#[cfg(feature = "client")]
 pub mod errors {
     pub use super::generated::errors::*;
 }
@@ -18,6 +40,95 @@ pub mod programs {
     pub use super::generated::programs::*;
 }
 
+/// Program id under the name Anchor Accounts derives use for omitted optional accounts
+#[cfg(feature = "anchor")]
+pub use programs::SECURITY_TOKEN_PROGRAM_ID as ID;
+
 pub mod types {
     pub use super::generated::types::*;
 }
+/// Account and instruction discriminators and the transfer hook rules marker shared with the
+/// program and the transfer hook
+pub mod discriminators {
+    pub use security_token_core::*;
+}
+
+#[cfg(feature = "client")]
+pub mod account;
+#[cfg(feature = "client")]
+pub mod agent;
+#[cfg(feature = "client")]
+pub mod audit;
+#[cfg(feature = "client")]
+pub mod batch_claim;
+#[cfg(feature = "client")]
+pub mod cap_table;
+#[cfg(feature = "client")]
+pub mod claim_tracking;
+#[cfg(feature = "client")]
+pub mod compat;
+#[cfg(feature = "client")]
+pub mod compute_budget;
+#[cfg(feature = "client")]
+pub mod costs;
+#[cfg(feature = "anchor")]
+pub mod cpi;
+#[cfg(feature = "client")]
+pub mod decoder;
+#[cfg(feature = "client")]
+pub mod document_registry;
+#[cfg(feature = "events")]
+pub mod events;
+#[cfg(feature = "client")]
+pub mod flows;
+#[cfg(feature = "client")]
+pub mod halt;
+#[cfg(feature = "client")]
+pub mod holding;
+#[cfg(feature = "client")]
+pub mod identity;
+#[cfg(feature = "client")]
+pub mod logs;
+#[cfg(feature = "client")]
+pub mod lookup_table;
+#[cfg(feature = "client")]
+pub mod merkle;
+#[cfg(feature = "client")]
+pub mod metadata_schema;
+#[cfg(feature = "client")]
+pub mod migration;
+#[cfg(feature = "client")]
+pub mod pda;
+#[cfg(feature = "client")]
+pub mod permit;
+#[cfg(feature = "client")]
+pub mod preflight;
+#[cfg(feature = "client")]
+mod program_error;
+#[cfg(all(feature = "client", feature = "serde"))]
+pub mod proof_file;
+#[cfg(feature = "pubsub")]
+pub mod pubsub;
+#[cfg(feature = "client")]
+pub mod rate;
+#[cfg(feature = "client")]
+pub mod reconciliation;
+#[cfg(feature = "client")]
+pub mod reserve;
+#[cfg(feature = "client")]
+pub mod resolution;
+#[cfg(feature = "client")]
+pub mod rollout;
+#[cfg(feature = "client")]
+pub mod rotation;
+#[cfg(feature = "client")]
+pub mod session_key;
+#[cfg(feature = "client")]
+pub mod travel_rule;
+#[cfg(feature = "client")]
+pub mod ui_amount;
+#[cfg(feature = "client")]
+pub mod verification;
+
+#[cfg(feature = "keeper")]
+pub mod keeper;
@@ -0,0 +1,528 @@
+//! Transaction log and event parser.
+//!
+//! The program and the transfer hook log [`TransferEvent`], [`VerificationEvent`] and
+//! [`AuditEvent`] as `Program data:` lines; `Program log:` lines are debug output only.
+//! [`parse_program_events`] decodes the events of a transaction log, and
+//! [`parse_transaction_events`] combines them with the decoded instructions of the
+//! transaction into a [`SecurityTokenEvent`] history.
+//!
+//! With the `events` feature [`LoggedEvent`] decodes the frames of
+//! [`subscribe_events`](crate::events::subscribe_events), streaming the events of the
+//! transactions mentioning the program or the transfer hook.
+
+use crate::audit::AuditEvent;
+use crate::decoder::{decode_instruction, SecurityTokenInstructionArgs};
+use crate::pda::TRANSFER_HOOK_PROGRAM_ID;
+use crate::programs::SECURITY_TOKEN_PROGRAM_ID;
+use crate::travel_rule::TransferEvent;
+use crate::verification::VerificationEvent;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_instruction::AccountMeta;
+use solana_instruction::Instruction;
+use solana_message::compiled_instruction::CompiledInstruction;
+use solana_pubkey::Pubkey;
+use std::str::FromStr;
+
+const INVOKE_PREFIX: &str = "invoke [";
+const DATA_PREFIX: &str = "Program data: ";
+
+/// Event logged by the program or the transfer hook
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProgramEvent {
+    Transfer(TransferEvent),
+    Verification(VerificationEvent),
+    Audit(AuditEvent),
+}
+
+impl ProgramEvent {
+    /// Decode base64-decoded `Program data:` bytes, `None` if they are not a known event
+    pub fn from_log_data(data: &[u8]) -> Option<Self> {
+        TransferEvent::from_log_data(data)
+            .map(Self::Transfer)
+            .or_else(|| VerificationEvent::from_log_data(data).map(Self::Verification))
+            .or_else(|| AuditEvent::from_log_data(data).map(Self::Audit))
+    }
+}
+
+/// Event of a transaction log with the program that logged it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoggedEvent {
+    pub program_id: Pubkey,
+    /// Invocation depth of the logging program, `1` for a top-level instruction
+    pub depth: usize,
+    pub event: ProgramEvent,
+}
+
+#[cfg(feature = "events")]
+impl crate::events::DecodeEvent for LoggedEvent {
+    fn decode(frame: &crate::events::EventFrame) -> Option<Self> {
+        if frame.program_id != SECURITY_TOKEN_PROGRAM_ID
+            && frame.program_id != TRANSFER_HOOK_PROGRAM_ID
+        {
+            return None;
+        }
+        ProgramEvent::from_log_data(&frame.data).map(|event| LoggedEvent {
+            program_id: frame.program_id,
+            depth: frame.depth,
+            event,
+        })
+    }
+}
+
+/// Line of a transaction log
+enum LogLine {
+    Invoke(Pubkey),
+    Exit,
+    Failed(Pubkey),
+    Data(Vec<u8>),
+    Other,
+}
+
+impl LogLine {
+    fn parse(line: &str) -> Self {
+        if let Some(data) = line.strip_prefix(DATA_PREFIX) {
+            // Events are logged as a single field
+            return match STANDARD.decode(data) {
+                Ok(data) => LogLine::Data(data),
+                Err(_) => LogLine::Other,
+            };
+        }
+        let Some(rest) = line.strip_prefix("Program ") else {
+            return LogLine::Other;
+        };
+        let Some((program, status)) = rest.split_once(' ') else {
+            return LogLine::Other;
+        };
+        let Ok(program) = Pubkey::from_str(program) else {
+            return LogLine::Other;
+        };
+        if status.starts_with(INVOKE_PREFIX) {
+            LogLine::Invoke(program)
+        } else if status == "success" {
+            LogLine::Exit
+        } else if status.starts_with("failed") {
+            LogLine::Failed(program)
+        } else {
+            LogLine::Other
+        }
+    }
+}
+
+/// Walks a transaction log, calling `on_invoke` for every program invocation and
+/// `on_event` for every event logged by the program or the transfer hook
+fn walk_logs(
+    logs: &[String],
+    mut on_invoke: impl FnMut(&Pubkey),
+    mut on_event: impl FnMut(LoggedEvent),
+) {
+    let mut stack: Vec<Pubkey> = Vec::new();
+    for line in logs {
+        match LogLine::parse(line) {
+            LogLine::Invoke(program_id) => {
+                on_invoke(&program_id);
+                stack.push(program_id);
+            }
+            LogLine::Exit | LogLine::Failed(_) => {
+                stack.pop();
+            }
+            LogLine::Data(data) => {
+                let Some(program_id) = stack.last() else {
+                    continue;
+                };
+                if *program_id != SECURITY_TOKEN_PROGRAM_ID
+                    && *program_id != TRANSFER_HOOK_PROGRAM_ID
+                {
+                    continue;
+                }
+                if let Some(event) = ProgramEvent::from_log_data(&data) {
+                    on_event(LoggedEvent {
+                        program_id: *program_id,
+                        depth: stack.len(),
+                        event,
+                    });
+                }
+            }
+            LogLine::Other => {}
+        }
+    }
+}
+
+/// Events logged by the program and the transfer hook, in log order
+pub fn parse_program_events(logs: &[String]) -> Vec<LoggedEvent> {
+    let mut events = Vec::new();
+    walk_logs(logs, |_| {}, |event| events.push(event));
+    events
+}
+
+/// Program a failed transaction failed in: the first program reporting a failure, a CPI
+/// failure is reported by the invoked program before its callers
+pub fn failed_program(logs: &[String]) -> Option<Pubkey> {
+    logs.iter().find_map(|line| match LogLine::parse(line) {
+        LogLine::Failed(program_id) => Some(program_id),
+        _ => None,
+    })
+}
+
+/// How a verification config changed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigChange {
+    Initialized,
+    Updated,
+    Trimmed { closed: bool },
+}
+
+/// Structured history event of a security token
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecurityTokenEvent {
+    Minted {
+        mint: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    },
+    Burned {
+        mint: Pubkey,
+        token_account: Pubkey,
+        amount: u64,
+    },
+    /// Transfer logged by the program or, for holder transfers, by the transfer hook
+    Transferred(TransferEvent),
+    ConfigUpdated {
+        mint: Pubkey,
+        /// Instruction the verification config applies to
+        instruction_discriminator: u8,
+        change: ConfigChange,
+    },
+    /// Distribution claim, `amount` is the claimed entitlement before withholding
+    Claimed {
+        mint: Pubkey,
+        token_account: Pubkey,
+        action_id: u64,
+        amount: u64,
+    },
+}
+
+/// History event of a security token `instruction`, `None` for other instructions
+pub fn instruction_event(instruction: &Instruction) -> Option<SecurityTokenEvent> {
+    if instruction.program_id != SECURITY_TOKEN_PROGRAM_ID {
+        return None;
+    }
+    let decoded = decode_instruction(&instruction.data, &instruction.accounts).ok()?;
+    let account = |name: &str| decoded.account(name).map(|meta| meta.pubkey);
+    let mint = account("mint")?;
+
+    let config_updated = |instruction_discriminator, change| SecurityTokenEvent::ConfigUpdated {
+        mint,
+        instruction_discriminator,
+        change,
+    };
+    Some(match &decoded.args {
+        SecurityTokenInstructionArgs::Mint(args) => SecurityTokenEvent::Minted {
+            mint,
+            destination: account("destination")?,
+            amount: args.amount,
+        },
+        SecurityTokenInstructionArgs::Burn(args) => SecurityTokenEvent::Burned {
+            mint,
+            token_account: account("token_account")?,
+            amount: args.amount,
+        },
+        SecurityTokenInstructionArgs::InitializeVerificationConfig(args) => config_updated(
+            args.initialize_verification_config_args
+                .instruction_discriminator,
+            ConfigChange::Initialized,
+        ),
+        SecurityTokenInstructionArgs::UpdateVerificationConfig(args) => config_updated(
+            args.update_verification_config_args
+                .instruction_discriminator,
+            ConfigChange::Updated,
+        ),
+        SecurityTokenInstructionArgs::TrimVerificationConfig(args) => config_updated(
+            args.trim_verification_config_args.instruction_discriminator,
+            ConfigChange::Trimmed {
+                closed: args.trim_verification_config_args.close,
+            },
+        ),
+        SecurityTokenInstructionArgs::ClaimDistribution(args) => SecurityTokenEvent::Claimed {
+            mint,
+            token_account: account("eligible_token_account")?,
+            action_id: args.claim_distribution_args.action_id,
+            amount: args.claim_distribution_args.amount,
+        },
+        _ => return None,
+    })
+}
+
+/// Instructions of `compiled`, top-level or inner instructions of the transaction metadata,
+/// resolved against the `account_keys` of the transaction (loaded addresses included)
+///
+/// Signer and writable flags are not part of compiled instructions, the account metas are
+/// read-only non-signers. `None` if an index is out of bounds.
+pub fn resolve_compiled_instructions(
+    account_keys: &[Pubkey],
+    compiled: &[CompiledInstruction],
+) -> Option<Vec<Instruction>> {
+    compiled
+        .iter()
+        .map(|instruction| {
+            Some(Instruction {
+                program_id: *account_keys.get(usize::from(instruction.program_id_index))?,
+                accounts: instruction
+                    .accounts
+                    .iter()
+                    .map(|index| {
+                        let pubkey = account_keys.get(usize::from(*index))?;
+                        Some(AccountMeta::new_readonly(*pubkey, false))
+                    })
+                    .collect::<Option<_>>()?,
+                data: instruction.data.clone(),
+            })
+        })
+        .collect()
+}
+
+/// History of a successful transaction, in execution order
+///
+/// `instructions` lists the instructions in execution order: every top-level instruction
+/// followed by its inner instructions, as reported in the transaction metadata. Each
+/// invocation of the program in `logs` is matched with the next program instruction.
+pub fn parse_transaction_events(
+    instructions: &[Instruction],
+    logs: &[String],
+) -> Vec<SecurityTokenEvent> {
+    let mut program_instructions = instructions
+        .iter()
+        .filter(|instruction| instruction.program_id == SECURITY_TOKEN_PROGRAM_ID);
+    // Both callbacks push in log order
+    let events = std::cell::RefCell::new(Vec::new());
+    walk_logs(
+        logs,
+        |program_id| {
+            if *program_id != SECURITY_TOKEN_PROGRAM_ID {
+                return;
+            }
+            if let Some(event) = program_instructions.next().and_then(instruction_event) {
+                events.borrow_mut().push(event);
+            }
+        },
+        |logged| {
+            if let ProgramEvent::Transfer(transfer) = logged.event {
+                events
+                    .borrow_mut()
+                    .push(SecurityTokenEvent::Transferred(transfer));
+            }
+        },
+    );
+    events.into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::{BurnBuilder, MintBuilder, TrimVerificationConfigBuilder};
+    use crate::types::TrimVerificationConfigArgs;
+    use security_token_program::events;
+
+    fn data_line(data: &[u8]) -> String {
+        format!("{DATA_PREFIX}{}", STANDARD.encode(data))
+    }
+
+    fn transfer_event(amount: u64) -> events::TransferEvent {
+        events::TransferEvent {
+            mint: [1u8; 32],
+            from: [2u8; 32],
+            to: [3u8; 32],
+            amount,
+            memo_hash: None,
+        }
+    }
+
+    fn logs(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_program_events_keeps_program_and_hook_events() {
+        let program = SECURITY_TOKEN_PROGRAM_ID.to_string();
+        let hook = TRANSFER_HOOK_PROGRAM_ID.to_string();
+        let other = Pubkey::new_unique().to_string();
+        let token = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+        let program_event = data_line(&transfer_event(10).to_bytes());
+        let hook_event = data_line(&transfer_event(20).to_bytes());
+        let foreign_event = data_line(&transfer_event(30).to_bytes());
+
+        let logs = logs(&[
+            &format!("Program {program} invoke [1]"),
+            "Program log: Instruction: Transfer",
+            &format!("Program {token} invoke [2]"),
+            &format!("Program {hook} invoke [3]"),
+            &hook_event,
+            &format!("Program {hook} consumed 1000 of 190000 compute units"),
+            &format!("Program {hook} success"),
+            &format!("Program {token} success"),
+            &program_event,
+            &format!("Program {program} success"),
+            &format!("Program {other} invoke [1]"),
+            &foreign_event,
+            &format!("Program {other} success"),
+            "Program data: not base64!",
+        ]);
+
+        let events = parse_program_events(&logs);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].program_id, TRANSFER_HOOK_PROGRAM_ID);
+        assert_eq!(events[0].depth, 3);
+        assert!(matches!(&events[0].event, ProgramEvent::Transfer(event) if event.amount == 20));
+        assert_eq!(events[1].program_id, SECURITY_TOKEN_PROGRAM_ID);
+        assert_eq!(events[1].depth, 1);
+        assert!(matches!(&events[1].event, ProgramEvent::Transfer(event) if event.amount == 10));
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn test_event_frames_decode_like_parse_program_events() {
+        use crate::events::{event_frames, DecodeEvent};
+
+        let program = SECURITY_TOKEN_PROGRAM_ID.to_string();
+        let other = Pubkey::new_unique().to_string();
+        let logs = logs(&[
+            &format!("Program {program} invoke [1]"),
+            &data_line(&transfer_event(10).to_bytes()),
+            &format!("Program {program} success"),
+            &format!("Program {other} invoke [1]"),
+            &data_line(&transfer_event(30).to_bytes()),
+            &format!("Program {other} success"),
+        ]);
+
+        let events: Vec<LoggedEvent> = event_frames(&logs)
+            .iter()
+            .filter_map(LoggedEvent::decode)
+            .collect();
+        assert_eq!(events, parse_program_events(&logs));
+        assert_eq!(events.len(), 1);
+    }
+
+    #[test]
+    fn test_failed_program_is_innermost_failure() {
+        let program = SECURITY_TOKEN_PROGRAM_ID;
+        let verifier = Pubkey::new_unique();
+        let logs = logs(&[
+            &format!("Program {program} invoke [1]"),
+            &format!("Program {verifier} invoke [2]"),
+            &format!("Program {verifier} failed: custom program error: 0x1111"),
+            &format!("Program {program} failed: custom program error: 0x1111"),
+        ]);
+        assert_eq!(failed_program(&logs), Some(verifier));
+        assert_eq!(failed_program(&logs[..1]), None);
+    }
+
+    #[test]
+    fn test_parse_transaction_events_in_execution_order() {
+        let mint = Pubkey::new_unique();
+        let (destination, token_account) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mint_ix = MintBuilder::new()
+            .mint(mint)
+            .verification_config(Pubkey::new_unique())
+            .mint_authority(Pubkey::new_unique())
+            .mint_account(mint)
+            .destination(destination)
+            .amount(500)
+            .instruction();
+        let burn_ix = BurnBuilder::new()
+            .mint(mint)
+            .verification_config(Pubkey::new_unique())
+            .permanent_delegate(Pubkey::new_unique())
+            .mint_account(mint)
+            .token_account(token_account)
+            .amount(200)
+            .instruction();
+        let trim_ix = TrimVerificationConfigBuilder::new()
+            .mint(mint)
+            .verification_config_or_mint_authority(Pubkey::new_unique())
+            .instructions_sysvar_or_creator(Pubkey::new_unique())
+            .payer(Pubkey::new_unique())
+            .mint_account(mint)
+            .config_account(Pubkey::new_unique())
+            .rent_recipient(Pubkey::new_unique())
+            .trim_verification_config_args(TrimVerificationConfigArgs {
+                instruction_discriminator: 7,
+                size: 0,
+                close: true,
+            })
+            .instruction();
+        let verifier = Instruction::new_with_bytes(Pubkey::new_unique(), &[7], vec![]);
+
+        let program = SECURITY_TOKEN_PROGRAM_ID.to_string();
+        let verifier_id = verifier.program_id.to_string();
+        let logs = logs(&[
+            &format!("Program {verifier_id} invoke [1]"),
+            &format!("Program {verifier_id} success"),
+            &format!("Program {program} invoke [1]"),
+            &format!("Program {program} success"),
+            &format!("Program {program} invoke [1]"),
+            &data_line(&transfer_event(300).to_bytes()),
+            &format!("Program {program} success"),
+            &format!("Program {program} invoke [1]"),
+            &format!("Program {program} success"),
+        ]);
+        let transfer_ix = Instruction::new_with_bytes(SECURITY_TOKEN_PROGRAM_ID, &[255], vec![]);
+        let mut account_keys = vec![SECURITY_TOKEN_PROGRAM_ID];
+        let compiled = [mint_ix.clone(), burn_ix]
+            .iter()
+            .map(|instruction| {
+                let mut index = |key: &Pubkey| {
+                    let position = account_keys.iter().position(|k| k == key);
+                    position.unwrap_or_else(|| {
+                        account_keys.push(*key);
+                        account_keys.len() - 1
+                    }) as u8
+                };
+                CompiledInstruction::new_from_raw_parts(
+                    index(&instruction.program_id),
+                    instruction.data.clone(),
+                    instruction
+                        .accounts
+                        .iter()
+                        .map(|meta| index(&meta.pubkey))
+                        .collect(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let resolved = resolve_compiled_instructions(&account_keys, &compiled).unwrap();
+        assert_eq!(instruction_event(&resolved[0]), instruction_event(&mint_ix));
+        let instructions = [
+            verifier,
+            resolved[0].clone(),
+            transfer_ix,
+            resolved[1].clone(),
+        ];
+
+        let events = parse_transaction_events(&instructions, &logs);
+        assert_eq!(
+            events,
+            vec![
+                SecurityTokenEvent::Minted {
+                    mint,
+                    destination,
+                    amount: 500,
+                },
+                SecurityTokenEvent::Transferred(
+                    TransferEvent::from_log_data(&transfer_event(300).to_bytes()).unwrap()
+                ),
+                SecurityTokenEvent::Burned {
+                    mint,
+                    token_account,
+                    amount: 200,
+                },
+            ]
+        );
+
+        assert_eq!(
+            instruction_event(&trim_ix),
+            Some(SecurityTokenEvent::ConfigUpdated {
+                mint,
+                instruction_discriminator: 7,
+                change: ConfigChange::Trimmed { closed: true },
+            })
+        );
+    }
+}
@@ -0,0 +1,399 @@
+//! Address lookup tables and v0 transactions.
+//!
+//! Operations such as Convert or ClaimDistribution carry a dozen accounts on top of the
+//! verification overhead, and with introspection pre-instructions a legacy transaction no
+//! longer fits in a packet. [`mint_lookup_addresses`] lists the accounts every operation of
+//! a mint shares, [`plan_lookup_table`] creates a table holding them and
+//! [`compile_v0_message`] compiles instructions against the tables.
+//!
+//! Addresses appended to a table can only be looked up from the next slot on, so a table
+//! must not be used in the slot it is extended.
+
+use crate::flows::{pack_transactions, FlowTransaction};
+use crate::pda::{
+    find_extra_account_metas_pda, find_freeze_authority_pda, find_mint_authority_pda,
+    find_pause_authority_pda, find_permanent_delegate_pda, find_transfer_hook_pda,
+    find_verification_config_pda, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
+    TRANSFER_HOOK_PROGRAM_ID,
+};
+use crate::programs::SECURITY_TOKEN_PROGRAM_ID;
+use solana_address_lookup_table_interface::instruction::{
+    create_lookup_table, extend_lookup_table,
+};
+use solana_address_lookup_table_interface::state::{
+    AddressLookupTable, LOOKUP_TABLE_MAX_ADDRESSES,
+};
+use solana_hash::Hash;
+use solana_instruction::Instruction;
+use solana_message::{v0, AddressLookupTableAccount, CompileError, VersionedMessage};
+use solana_packet::PACKET_DATA_SIZE;
+use solana_pubkey::Pubkey;
+use thiserror::Error;
+
+/// Addresses appended by one extend instruction, keeps the instruction well inside a packet
+pub const MAX_EXTEND_ADDRESSES: usize = 20;
+
+/// Lookup table errors
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum LookupTableError {
+    #[error("RPC error: {0}")]
+    Rpc(String),
+    #[error("Invalid lookup table account: {0}")]
+    InvalidAccount(Pubkey),
+    #[error("Lookup table {address} would hold {len} addresses")]
+    Full { address: Pubkey, len: usize },
+    #[error("Message compilation failed: {0}")]
+    Compile(#[from] CompileError),
+    #[error("Transaction of {0} bytes does not fit in a packet")]
+    TooLarge(usize),
+}
+
+/// Programs, sysvars and PDAs shared by the operations of `mint`, with the verification
+/// configs of `instruction_discriminators`
+pub fn mint_lookup_addresses(
+    mint: &Pubkey,
+    creator: &Pubkey,
+    instruction_discriminators: &[u8],
+) -> Vec<Pubkey> {
+    let mut addresses = vec![
+        SECURITY_TOKEN_PROGRAM_ID,
+        TOKEN_2022_PROGRAM_ID,
+        TRANSFER_HOOK_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        solana_sdk_ids::system_program::ID,
+        solana_sdk_ids::sysvar::instructions::ID,
+        *mint,
+        find_mint_authority_pda(mint, creator).0,
+        find_freeze_authority_pda(mint).0,
+        find_pause_authority_pda(mint).0,
+        find_permanent_delegate_pda(mint).0,
+        find_transfer_hook_pda(mint).0,
+        find_extra_account_metas_pda(mint).0,
+    ];
+    addresses.extend(
+        instruction_discriminators
+            .iter()
+            .map(|discriminator| find_verification_config_pda(mint, *discriminator).0),
+    );
+    addresses
+}
+
+/// Lookup table to create and the transactions creating and filling it, to send in order
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LookupTablePlan {
+    pub address: Pubkey,
+    pub transactions: Vec<FlowTransaction>,
+}
+
+/// Plans a lookup table of `authority` holding `addresses`, `recent_slot` seeds its address
+/// and must be a recent slot when the first transaction lands
+pub fn plan_lookup_table(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    recent_slot: u64,
+    addresses: &[Pubkey],
+) -> Result<LookupTablePlan, LookupTableError> {
+    let (create, address) = create_lookup_table(*authority, *payer, recent_slot);
+    let addresses = missing_addresses(&[], addresses);
+    if addresses.len() > LOOKUP_TABLE_MAX_ADDRESSES {
+        return Err(LookupTableError::Full {
+            address,
+            len: addresses.len(),
+        });
+    }
+
+    let mut instructions = vec![create];
+    instructions.extend(extend_instructions(&address, authority, payer, &addresses));
+    Ok(LookupTablePlan {
+        address,
+        transactions: pack_transactions(instructions, payer),
+    })
+}
+
+/// Plans the transactions appending the `addresses` missing from `table`
+pub fn plan_lookup_table_extension(
+    table: &AddressLookupTableAccount,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    addresses: &[Pubkey],
+) -> Result<Vec<FlowTransaction>, LookupTableError> {
+    let addresses = missing_addresses(&table.addresses, addresses);
+    let len = table.addresses.len() + addresses.len();
+    if len > LOOKUP_TABLE_MAX_ADDRESSES {
+        return Err(LookupTableError::Full {
+            address: table.key,
+            len,
+        });
+    }
+    if addresses.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let instructions = extend_instructions(&table.key, authority, payer, &addresses);
+    Ok(pack_transactions(instructions, payer))
+}
+
+/// `addresses` without duplicates and without the ones in `existing`, in order
+fn missing_addresses(existing: &[Pubkey], addresses: &[Pubkey]) -> Vec<Pubkey> {
+    let mut missing: Vec<Pubkey> = Vec::with_capacity(addresses.len());
+    for address in addresses {
+        if !existing.contains(address) && !missing.contains(address) {
+            missing.push(*address);
+        }
+    }
+    missing
+}
+
+fn extend_instructions(
+    table: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    addresses: &[Pubkey],
+) -> Vec<Instruction> {
+    addresses
+        .chunks(MAX_EXTEND_ADDRESSES)
+        .map(|chunk| extend_lookup_table(*table, *authority, Some(*payer), chunk.to_vec()))
+        .collect()
+}
+
+/// Decodes the lookup table account `address` from its data
+pub fn decode_lookup_table(
+    address: &Pubkey,
+    data: &[u8],
+) -> Result<AddressLookupTableAccount, LookupTableError> {
+    let table = AddressLookupTable::deserialize(data)
+        .map_err(|_| LookupTableError::InvalidAccount(*address))?;
+    Ok(AddressLookupTableAccount {
+        key: *address,
+        addresses: table.addresses.to_vec(),
+    })
+}
+
+/// Size of a transaction carrying `message`: signature count and signatures, then the message
+pub fn versioned_transaction_size(message: &VersionedMessage) -> usize {
+    let signatures = usize::from(message.header().num_required_signatures);
+    // Short vec length of the signatures, one byte below 128 signatures
+    1 + 64 * signatures + message.serialize().len()
+}
+
+/// Compiles `instructions` into a v0 message looking accounts up in `lookup_tables`, the
+/// resulting transaction must fit in a packet
+pub fn compile_v0_message(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+    recent_blockhash: Hash,
+) -> Result<VersionedMessage, LookupTableError> {
+    let message = VersionedMessage::V0(v0::Message::try_compile(
+        payer,
+        instructions,
+        lookup_tables,
+        recent_blockhash,
+    )?);
+    let size = versioned_transaction_size(&message);
+    if size > PACKET_DATA_SIZE {
+        return Err(LookupTableError::TooLarge(size));
+    }
+    Ok(message)
+}
+
+#[cfg(feature = "rpc")]
+pub use self::send::*;
+
+#[cfg(feature = "rpc")]
+mod send {
+    use super::*;
+    use solana_client::rpc_client::RpcClient;
+    use solana_sdk::signature::{Signature, Signer};
+    use solana_sdk::transaction::VersionedTransaction;
+
+    /// Fetches and decodes the lookup tables at `addresses`
+    pub fn fetch_lookup_tables(
+        rpc: &RpcClient,
+        addresses: &[Pubkey],
+    ) -> Result<Vec<AddressLookupTableAccount>, LookupTableError> {
+        let accounts = rpc
+            .get_multiple_accounts(addresses)
+            .map_err(|err| LookupTableError::Rpc(err.to_string()))?;
+        addresses
+            .iter()
+            .zip(accounts)
+            .map(|(address, account)| {
+                let account = account
+                    .filter(|account| account.owner == solana_sdk_ids::address_lookup_table::ID)
+                    .ok_or(LookupTableError::InvalidAccount(*address))?;
+                decode_lookup_table(address, &account.data)
+            })
+            .collect()
+    }
+
+    /// Sends `instructions` in a v0 transaction paid by `payer` and looking accounts up in
+    /// `lookup_tables`, signed by `signers`
+    pub fn send_v0_transaction(
+        rpc: &RpcClient,
+        payer: &Pubkey,
+        instructions: &[Instruction],
+        lookup_tables: &[AddressLookupTableAccount],
+        signers: &[&dyn Signer],
+    ) -> Result<Signature, LookupTableError> {
+        let blockhash = rpc
+            .get_latest_blockhash()
+            .map_err(|err| LookupTableError::Rpc(err.to_string()))?;
+        let message = compile_v0_message(payer, instructions, lookup_tables, blockhash)?;
+        let transaction = VersionedTransaction::try_new(message, signers)
+            .map_err(|err| LookupTableError::Rpc(err.to_string()))?;
+        rpc.send_and_confirm_transaction(&transaction)
+            .map_err(|err| LookupTableError::Rpc(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flows::transaction_size;
+    use crate::instructions::{ConvertBuilder, CONVERT_DISCRIMINATOR, TRANSFER_DISCRIMINATOR};
+    use crate::types::ConvertArgs;
+    use solana_address_lookup_table_interface::state::LookupTableMeta;
+    use solana_instruction::AccountMeta;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_plan_lookup_table() {
+        let (mint, creator) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut addresses = mint_lookup_addresses(
+            &mint,
+            &creator,
+            &[CONVERT_DISCRIMINATOR, TRANSFER_DISCRIMINATOR],
+        );
+        addresses.extend((0..40).map(|_| Pubkey::new_unique()));
+        addresses.push(mint);
+
+        let plan = plan_lookup_table(&creator, &creator, 42, &addresses).unwrap();
+        assert_eq!(plan.address, create_lookup_table(creator, creator, 42).1);
+        let instructions: Vec<&Instruction> = plan
+            .transactions
+            .iter()
+            .flat_map(|transaction| &transaction.instructions)
+            .collect();
+        // Create, then 55 unique addresses in chunks of 20
+        assert_eq!(instructions.len(), 4);
+        for transaction in &plan.transactions {
+            assert!(transaction_size(&transaction.instructions, &creator) <= PACKET_DATA_SIZE);
+            assert_eq!(transaction.signers, vec![creator]);
+        }
+
+        let too_many: Vec<Pubkey> = (0..=LOOKUP_TABLE_MAX_ADDRESSES)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        assert!(matches!(
+            plan_lookup_table(&creator, &creator, 42, &too_many),
+            Err(LookupTableError::Full { len: 257, .. })
+        ));
+    }
+
+    #[test]
+    fn test_plan_lookup_table_extension_skips_present_addresses() {
+        let (authority, present, missing) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: vec![present],
+        };
+
+        let transactions =
+            plan_lookup_table_extension(&table, &authority, &authority, &[present, missing])
+                .unwrap();
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(
+            transactions[0].instructions,
+            vec![extend_lookup_table(
+                table.key,
+                authority,
+                Some(authority),
+                vec![missing]
+            )]
+        );
+        assert!(
+            plan_lookup_table_extension(&table, &authority, &authority, &[present])
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_decode_lookup_table() {
+        let (address, authority) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let addresses = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let data = AddressLookupTable {
+            meta: LookupTableMeta::new(authority),
+            addresses: Cow::Borrowed(&addresses),
+        }
+        .serialize_for_tests()
+        .unwrap();
+
+        assert_eq!(
+            decode_lookup_table(&address, &data).unwrap(),
+            AddressLookupTableAccount {
+                key: address,
+                addresses,
+            }
+        );
+        assert_eq!(
+            decode_lookup_table(&address, &data[..10]),
+            Err(LookupTableError::InvalidAccount(address))
+        );
+    }
+
+    #[test]
+    fn test_compile_v0_message_fits_oversized_operation() {
+        let (mint, creator, payer) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        // Convert with a long tail of verification program accounts
+        let verification_programs: Vec<Pubkey> = (0..32).map(|_| Pubkey::new_unique()).collect();
+        let mut convert = ConvertBuilder::new()
+            .mint(mint)
+            .verification_config(find_verification_config_pda(&mint, CONVERT_DISCRIMINATOR).0)
+            .mint_authority(find_mint_authority_pda(&mint, &creator).0)
+            .permanent_delegate(find_permanent_delegate_pda(&mint).0)
+            .payer(payer)
+            .mint_from(mint)
+            .mint_to(Pubkey::new_unique())
+            .token_account_from(Pubkey::new_unique())
+            .token_account_to(Pubkey::new_unique())
+            .rate_account(Pubkey::new_unique())
+            .receipt_account(Pubkey::new_unique())
+            .convert_args(ConvertArgs {
+                action_id: 1,
+                amount_to_convert: 10,
+            })
+            .instruction();
+        convert.accounts.extend(
+            verification_programs
+                .iter()
+                .map(|program| AccountMeta::new_readonly(*program, false)),
+        );
+        assert!(transaction_size(std::slice::from_ref(&convert), &payer) > PACKET_DATA_SIZE);
+
+        let mut addresses = mint_lookup_addresses(&mint, &creator, &[CONVERT_DISCRIMINATOR]);
+        addresses.extend(&verification_programs);
+        let table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses,
+        };
+        let message =
+            compile_v0_message(&payer, &[convert.clone()], &[table], Hash::default()).unwrap();
+        assert!(versioned_transaction_size(&message) <= PACKET_DATA_SIZE);
+        assert_eq!(message.address_table_lookups().map(<[_]>::len), Some(1));
+
+        assert!(matches!(
+            compile_v0_message(&payer, &[convert], &[], Hash::default()),
+            Err(LookupTableError::TooLarge(_))
+        ));
+    }
+}
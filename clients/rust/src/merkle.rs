@@ -0,0 +1,285 @@
+//! Merkle tree helpers for distribution claims.
+//!
+//! Leaves use the same layout as `create_merkle_tree_leaf_node` in the program:
+//! `keccak(eligible_token_account || mint || action_id_le || amount_le)`.
+
+use crate::types::ClaimDistributionArgs;
+use solana_keccak_hasher::hashv;
+use solana_pubkey::Pubkey;
+use spl_merkle_tree_reference::{MerkleTree, EMPTY};
+use thiserror::Error;
+
+pub type MerkleTreeNode = [u8; 32];
+pub type MerkleTreeRoot = MerkleTreeNode;
+
+/// Maximum number of levels in a proof accepted by the program
+pub const MAX_PROOF_LEVELS: usize = 32;
+
+/// Merkle tree errors
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum MerkleError {
+    #[error("Leaf {0} of a single leaf tree has an empty proof")]
+    EmptyProof(usize),
+    #[error("Proof of leaf {0} contains a padding node")]
+    PaddingProofNode(usize),
+}
+
+/// Single entitlement of a distribution
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DistributionLeaf {
+    /// Token account eligible to claim
+    pub eligible_token_account: Pubkey,
+    /// Amount the token account is entitled to
+    pub amount: u64,
+}
+
+impl DistributionLeaf {
+    pub fn new(eligible_token_account: Pubkey, amount: u64) -> Self {
+        Self {
+            eligible_token_account,
+            amount,
+        }
+    }
+}
+
+/// Creates a hashed leaf node matching the on-chain leaf layout
+pub fn create_merkle_tree_leaf_node(
+    eligible_token_account: &Pubkey,
+    mint: &Pubkey,
+    action_id: u64,
+    amount: u64,
+) -> MerkleTreeNode {
+    let mut bytes = Vec::with_capacity(32 * 2 + 8 + 8);
+    bytes.extend_from_slice(eligible_token_account.as_ref());
+    bytes.extend_from_slice(mint.as_ref());
+    bytes.extend_from_slice(&action_id.to_le_bytes());
+    bytes.extend_from_slice(&amount.to_le_bytes());
+
+    hashv(&[&bytes]).to_bytes()
+}
+
+/// Verifies a proof the same way `verify_merkle_proof` does on-chain
+pub fn verify_merkle_proof(
+    node: &MerkleTreeNode,
+    root: &MerkleTreeRoot,
+    proof: &[MerkleTreeNode],
+    leaf_index: u32,
+) -> bool {
+    if !proof.is_empty() {
+        if proof.len() > MAX_PROOF_LEVELS {
+            return false;
+        }
+        if (leaf_index as u64) >= 1u64 << proof.len() {
+            return false;
+        }
+    }
+
+    let mut hash = *node;
+    for (i, sibling) in proof.iter().enumerate() {
+        if (leaf_index >> i) & 1 == 0 {
+            hash = hashv(&[&hash, sibling]).to_bytes();
+        } else {
+            hash = hashv(&[sibling, &hash]).to_bytes();
+        }
+    }
+    &hash == root
+}
+
+/// Verifies claim arguments carrying their proof the way `ClaimDistribution` does
+///
+/// Arguments without `merkle_proof` reference a Proof account and are not verified here.
+pub fn verify_claim_args(
+    eligible_token_account: &Pubkey,
+    mint: &Pubkey,
+    args: &ClaimDistributionArgs,
+) -> bool {
+    let Some(proof) = &args.merkle_proof else {
+        return false;
+    };
+    // A single leaf tree has an empty proof, its leaf is at index 0
+    if proof.is_empty() && args.leaf_index != 0 {
+        return false;
+    }
+    let node =
+        create_merkle_tree_leaf_node(eligible_token_account, mint, args.action_id, args.amount);
+    verify_merkle_proof(&node, &args.merkle_root, proof, args.leaf_index)
+}
+
+/// Merkle tree of a single distribution (mint + action id)
+pub struct DistributionTree {
+    pub mint: Pubkey,
+    pub action_id: u64,
+    pub leaves: Vec<DistributionLeaf>,
+    tree: MerkleTree,
+}
+
+impl DistributionTree {
+    pub fn new(mint: Pubkey, action_id: u64, leaves: Vec<DistributionLeaf>) -> Self {
+        let nodes: Vec<MerkleTreeNode> = leaves
+            .iter()
+            .map(|leaf| {
+                create_merkle_tree_leaf_node(
+                    &leaf.eligible_token_account,
+                    &mint,
+                    action_id,
+                    leaf.amount,
+                )
+            })
+            .collect();
+        let tree = MerkleTree::new(&nodes);
+        Self {
+            mint,
+            action_id,
+            leaves,
+            tree,
+        }
+    }
+
+    pub fn root(&self) -> MerkleTreeRoot {
+        self.tree.get_root()
+    }
+
+    /// Hashed leaf node at `leaf_index`
+    pub fn leaf_node(&self, leaf_index: usize) -> MerkleTreeNode {
+        let leaf = &self.leaves[leaf_index];
+        create_merkle_tree_leaf_node(
+            &leaf.eligible_token_account,
+            &self.mint,
+            self.action_id,
+            leaf.amount,
+        )
+    }
+
+    /// Proof of the leaf at `leaf_index`
+    pub fn proof(&self, leaf_index: usize) -> Vec<MerkleTreeNode> {
+        self.tree.get_proof_of_leaf(leaf_index)
+    }
+
+    /// Index of the first leaf of `eligible_token_account`
+    pub fn leaf_index(&self, eligible_token_account: &Pubkey) -> Option<usize> {
+        self.leaves
+            .iter()
+            .position(|leaf| leaf.eligible_token_account == *eligible_token_account)
+    }
+
+    /// ClaimDistribution arguments of the leaf at `leaf_index`, with the proof inline
+    ///
+    /// The program rejects empty proofs and zero proof nodes, so the leaf of a single leaf
+    /// tree and a last leaf paired with padding (odd leaf count) cannot be claimed.
+    pub fn claim_args(&self, leaf_index: usize) -> Result<ClaimDistributionArgs, MerkleError> {
+        let proof = self.proof(leaf_index);
+        if proof.is_empty() {
+            return Err(MerkleError::EmptyProof(leaf_index));
+        }
+        if proof.contains(&EMPTY) {
+            return Err(MerkleError::PaddingProofNode(leaf_index));
+        }
+        Ok(ClaimDistributionArgs {
+            action_id: self.action_id,
+            amount: self.leaves[leaf_index].amount,
+            merkle_root: self.root(),
+            leaf_index: leaf_index as u32,
+            merkle_proof: Some(proof),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spl_merkle_tree_reference::recompute;
+
+    #[test]
+    fn test_distribution_tree_proofs_recompute_root() {
+        let leaves: Vec<_> = (1..=5)
+            .map(|amount| DistributionLeaf::new(Pubkey::new_unique(), amount * 100))
+            .collect();
+        let tree = DistributionTree::new(Pubkey::new_unique(), 1, leaves.clone());
+
+        for (idx, leaf) in leaves.iter().enumerate() {
+            let node = create_merkle_tree_leaf_node(
+                &leaf.eligible_token_account,
+                &tree.mint,
+                1,
+                leaf.amount,
+            );
+            assert_eq!(node, tree.leaf_node(idx));
+            assert_eq!(recompute(node, &tree.proof(idx), idx as u32), tree.root());
+            assert!(verify_merkle_proof(
+                &node,
+                &tree.root(),
+                &tree.proof(idx),
+                idx as u32
+            ));
+        }
+    }
+
+    #[test]
+    fn test_claim_args_round_trip_with_program() {
+        use security_token_program::instructions::ClaimDistributionArgs as ProgramClaimArgs;
+        use security_token_program::merkle_tree_utils;
+
+        let mint = Pubkey::new_unique();
+        for count in [2, 4, 6, 8] {
+            let leaves: Vec<_> = (1..=count)
+                .map(|amount| DistributionLeaf::new(Pubkey::new_unique(), amount * 100))
+                .collect();
+            let tree = DistributionTree::new(mint, 7, leaves.clone());
+
+            for (idx, leaf) in leaves.iter().enumerate() {
+                assert_eq!(tree.leaf_index(&leaf.eligible_token_account), Some(idx));
+                let node = merkle_tree_utils::create_merkle_tree_leaf_node(
+                    &leaf.eligible_token_account.to_bytes(),
+                    &mint.to_bytes(),
+                    7,
+                    leaf.amount,
+                );
+                assert_eq!(node, tree.leaf_node(idx));
+
+                let args = tree.claim_args(idx).unwrap();
+                assert!(verify_claim_args(
+                    &leaf.eligible_token_account,
+                    &mint,
+                    &args
+                ));
+                let other = leaves[(idx + 1) % leaves.len()].eligible_token_account;
+                assert!(!verify_claim_args(&other, &mint, &args));
+
+                let parsed = ProgramClaimArgs::try_from_bytes(&borsh::to_vec(&args).unwrap())
+                    .expect("program parses client claim args");
+                assert_eq!(parsed.action_id, args.action_id);
+                assert_eq!(parsed.amount, args.amount);
+                assert_eq!(parsed.merkle_root, tree.root());
+                assert_eq!(parsed.leaf_index, idx as u32);
+                assert_eq!(parsed.merkle_proof, args.merkle_proof);
+                assert!(merkle_tree_utils::verify_merkle_proof(
+                    &node,
+                    &parsed.merkle_root,
+                    parsed.merkle_proof.as_ref().unwrap(),
+                    parsed.leaf_index
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_claim_args_reject_unclaimable_leaves() {
+        let leaves: Vec<_> = (1..=5)
+            .map(|amount| DistributionLeaf::new(Pubkey::new_unique(), amount * 100))
+            .collect();
+        let tree = DistributionTree::new(Pubkey::new_unique(), 1, leaves.clone());
+        assert!(tree.claim_args(3).is_ok());
+        assert_eq!(tree.claim_args(4), Err(MerkleError::PaddingProofNode(4)));
+
+        let single = DistributionTree::new(Pubkey::new_unique(), 1, vec![leaves[0].clone()]);
+        assert_eq!(single.claim_args(0), Err(MerkleError::EmptyProof(0)));
+
+        let mut args = tree.claim_args(0).unwrap();
+        args.merkle_proof = None;
+        assert!(!verify_claim_args(
+            &leaves[0].eligible_token_account,
+            &tree.mint,
+            &args
+        ));
+    }
+}
@@ -0,0 +1,96 @@
+//! Metadata schema encoding.
+//!
+//! A MetadataSchema lists the additional metadata fields UpdateMetadata requires
+//! for a mint. Its `fields` and the `additional_metadata` of UpdateMetadata are raw
+//! byte encodings; these helpers build and read them. The schema PDA should always
+//! be passed to UpdateMetadata, the program skips the check while it does not exist.
+
+use crate::types::MetadataFieldFormat;
+
+/// Required additional metadata field of a schema
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MetadataSchemaField {
+    pub key: String,
+    pub format: MetadataFieldFormat,
+}
+
+impl MetadataSchemaField {
+    pub fn new(key: impl Into<String>, format: MetadataFieldFormat) -> Self {
+        Self {
+            key: key.into(),
+            format,
+        }
+    }
+}
+
+/// Encodes schema fields as `key_len (u32 LE) + key + format (u8)` entries
+pub fn encode_metadata_schema_fields(fields: &[MetadataSchemaField]) -> Vec<u8> {
+    let mut data = Vec::new();
+    for field in fields {
+        data.extend_from_slice(&(field.key.len() as u32).to_le_bytes());
+        data.extend_from_slice(field.key.as_bytes());
+        data.push(field.format as u8);
+    }
+    data
+}
+
+/// Decodes the `fields` of a MetadataSchema account, `None` if malformed
+pub fn decode_metadata_schema_fields(data: &[u8]) -> Option<Vec<MetadataSchemaField>> {
+    let mut fields = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() {
+        let key_len = u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?) as usize;
+        offset += 4;
+        let key = std::str::from_utf8(data.get(offset..offset + key_len)?).ok()?;
+        offset += key_len;
+        let format = match data.get(offset)? {
+            0 => MetadataFieldFormat::Text,
+            1 => MetadataFieldFormat::Isin,
+            2 => MetadataFieldFormat::CountryCode,
+            3 => MetadataFieldFormat::Lei,
+            _ => return None,
+        };
+        offset += 1;
+        fields.push(MetadataSchemaField::new(key, format));
+    }
+    Some(fields)
+}
+
+/// Encodes additional metadata key/value pairs for `TokenMetadataArgs::additional_metadata`
+pub fn encode_additional_metadata(pairs: &[(&str, &str)]) -> Vec<u8> {
+    let mut data = Vec::new();
+    for (key, value) in pairs {
+        data.extend_from_slice(&(key.len() as u32).to_le_bytes());
+        data.extend_from_slice(key.as_bytes());
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(value.as_bytes());
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_schema_fields_round_trip() {
+        let fields = vec![
+            MetadataSchemaField::new("isin", MetadataFieldFormat::Isin),
+            MetadataSchemaField::new("jurisdiction", MetadataFieldFormat::CountryCode),
+            MetadataSchemaField::new("issuer_lei", MetadataFieldFormat::Lei),
+        ];
+        let data = encode_metadata_schema_fields(&fields);
+        assert_eq!(&data[..8], &[4, 0, 0, 0, b'i', b's', b'i', b'n']);
+        assert_eq!(data[8], 1);
+        assert_eq!(decode_metadata_schema_fields(&data), Some(fields));
+
+        assert_eq!(decode_metadata_schema_fields(&data[..data.len() - 1]), None);
+    }
+
+    #[test]
+    fn test_encode_additional_metadata() {
+        let data = encode_additional_metadata(&[("isin", "US0378331005")]);
+        assert_eq!(data.len(), 4 + 4 + 4 + 12);
+        assert_eq!(&data[8..12], &12u32.to_le_bytes());
+    }
+}
@@ -0,0 +1,264 @@
+//! Mint migration driver.
+//!
+//! Moves every holder of a security token to a new mint with a different
+//! extension set. The new mint is created with InitializeMint beforehand; the
+//! migration is then registered with CreateMintMigration and holders are moved in
+//! batches of MigrateBalances, each burning the old balance through the permanent
+//! delegate and minting the same amount on the new mint. Destination token accounts
+//! have to exist before their batch is sent.
+
+use crate::halt::with_program_config;
+use crate::instructions::{CreateMintMigrationBuilder, MigrateBalancesBuilder};
+use crate::pda::{
+    find_migration_receipt_pda, find_mint_authority_pda, find_mint_migration_pda,
+    find_permanent_delegate_pda, find_rate_pda,
+};
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+/// Holders migrated per MigrateBalances instruction, keeps the transaction under the size limit
+pub const MIGRATION_BATCH_SIZE: usize = 6;
+
+/// Mints taking part in a migration
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MintMigrationPlan {
+    pub mint_from: Pubkey,
+    pub mint_to: Pubkey,
+    /// Mint creator of both mints
+    pub creator: Pubkey,
+    /// Action id of the 1:1 rate between the mints
+    pub action_id: u64,
+}
+
+/// Token accounts of a single holder on both mints
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MigrationHolder {
+    pub token_account_from: Pubkey,
+    pub token_account_to: Pubkey,
+}
+
+/// Builds the CreateMintMigration instruction of `plan`
+pub fn create_mint_migration_instruction(plan: &MintMigrationPlan, payer: &Pubkey) -> Instruction {
+    let (mint_from_authority, _) = find_mint_authority_pda(&plan.mint_from, &plan.creator);
+    let (mint_to_authority, _) = find_mint_authority_pda(&plan.mint_to, &plan.creator);
+
+    let mut instruction = CreateMintMigrationBuilder::new()
+        .mint(plan.mint_to)
+        .verification_config_or_mint_authority(mint_to_authority)
+        .instructions_sysvar_or_creator(plan.creator)
+        .payer(*payer)
+        .mint_from(plan.mint_from)
+        .mint_to(plan.mint_to)
+        .mint_from_authority(mint_from_authority)
+        .mint_to_authority(mint_to_authority)
+        .rate_account(find_rate_pda(plan.action_id, &plan.mint_from, &plan.mint_to).0)
+        .mint_migration_account(find_mint_migration_pda(&plan.mint_from).0)
+        .action_id(plan.action_id)
+        .instruction();
+    // The mint creator authorizes the migration and has to sign
+    instruction.accounts[2].is_signer = true;
+    with_program_config(instruction)
+}
+
+/// Builds the MigrateBalances instructions moving `holders`, one per batch
+pub fn migrate_balances_instructions(
+    plan: &MintMigrationPlan,
+    payer: &Pubkey,
+    holders: &[MigrationHolder],
+) -> Vec<Instruction> {
+    let (mint_authority, _) = find_mint_authority_pda(&plan.mint_to, &plan.creator);
+    let (permanent_delegate, _) = find_permanent_delegate_pda(&plan.mint_from);
+    let (rate_account, _) = find_rate_pda(plan.action_id, &plan.mint_from, &plan.mint_to);
+    let (mint_migration_account, _) = find_mint_migration_pda(&plan.mint_from);
+
+    holders
+        .chunks(MIGRATION_BATCH_SIZE)
+        .map(|batch| {
+            let remaining_accounts: Vec<AccountMeta> = batch
+                .iter()
+                .flat_map(|holder| {
+                    let (receipt, _) = find_migration_receipt_pda(
+                        &plan.mint_to,
+                        &holder.token_account_from,
+                        plan.action_id,
+                    );
+                    [
+                        AccountMeta::new(holder.token_account_from, false),
+                        AccountMeta::new(holder.token_account_to, false),
+                        AccountMeta::new(receipt, false),
+                    ]
+                })
+                .collect();
+
+            let mut instruction = MigrateBalancesBuilder::new()
+                .mint(plan.mint_to)
+                .verification_config_or_mint_authority(mint_authority)
+                .instructions_sysvar_or_creator(plan.creator)
+                .payer(*payer)
+                .mint_authority(mint_authority)
+                .permanent_delegate(permanent_delegate)
+                .mint_from(plan.mint_from)
+                .mint_to(plan.mint_to)
+                .rate_account(rate_account)
+                .mint_migration_account(mint_migration_account)
+                .add_remaining_accounts(&remaining_accounts)
+                .instruction();
+            instruction.accounts[2].is_signer = true;
+            with_program_config(instruction)
+        })
+        .collect()
+}
+
+#[cfg(feature = "rpc")]
+pub use self::send::*;
+
+#[cfg(feature = "rpc")]
+mod send {
+    use super::*;
+    use solana_client::rpc_client::RpcClient;
+    use solana_sdk::signature::{Signature, Signer};
+    use solana_sdk::transaction::Transaction;
+
+    /// Result of a single MigrateBalances batch
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct MigrationBatchResult {
+        pub holders: Vec<MigrationHolder>,
+        pub outcome: Result<Signature, String>,
+    }
+
+    /// Sends CreateMintMigration followed by every MigrateBalances batch.
+    ///
+    /// Set `create` to false to resume a migration whose account already exists.
+    /// A failed batch does not stop the migration; already migrated holders fail
+    /// their batch again because their receipt exists, so retries should only
+    /// contain the holders of failed batches.
+    pub fn migrate_mint(
+        rpc: &RpcClient,
+        plan: &MintMigrationPlan,
+        holders: &[MigrationHolder],
+        create: bool,
+        creator: &dyn Signer,
+        payer: &dyn Signer,
+        mut progress: impl FnMut(&MigrationBatchResult, usize, usize),
+    ) -> Result<Vec<MigrationBatchResult>, String> {
+        if create {
+            send(
+                rpc,
+                &create_mint_migration_instruction(plan, &payer.pubkey()),
+                creator,
+                payer,
+            )?;
+        }
+
+        let instructions = migrate_balances_instructions(plan, &payer.pubkey(), holders);
+        let mut results = Vec::with_capacity(instructions.len());
+        for (instruction, batch) in instructions
+            .iter()
+            .zip(holders.chunks(MIGRATION_BATCH_SIZE))
+        {
+            results.push(MigrationBatchResult {
+                holders: batch.to_vec(),
+                outcome: send(rpc, instruction, creator, payer),
+            });
+            progress(
+                results.last().expect("result pushed above"),
+                results.len(),
+                instructions.len(),
+            );
+        }
+        Ok(results)
+    }
+
+    fn send(
+        rpc: &RpcClient,
+        instruction: &Instruction,
+        creator: &dyn Signer,
+        payer: &dyn Signer,
+    ) -> Result<Signature, String> {
+        let blockhash = rpc.get_latest_blockhash().map_err(|err| err.to_string())?;
+        let transaction = Transaction::new_signed_with_payer(
+            std::slice::from_ref(instruction),
+            Some(&payer.pubkey()),
+            &[payer, creator],
+            blockhash,
+        );
+        rpc.send_and_confirm_transaction(&transaction)
+            .map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::{
+        CREATE_MINT_MIGRATION_DISCRIMINATOR, MIGRATE_BALANCES_DISCRIMINATOR,
+    };
+
+    fn plan() -> MintMigrationPlan {
+        MintMigrationPlan {
+            mint_from: Pubkey::new_unique(),
+            mint_to: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            action_id: 7,
+        }
+    }
+
+    #[test]
+    fn test_create_mint_migration_instruction() {
+        let plan = plan();
+        let instruction = create_mint_migration_instruction(&plan, &plan.creator);
+
+        assert_eq!(instruction.data[0], CREATE_MINT_MIGRATION_DISCRIMINATOR);
+        assert_eq!(instruction.data[1..], 7u64.to_le_bytes());
+        assert_eq!(instruction.accounts[0].pubkey, plan.mint_to);
+        assert_eq!(
+            instruction.accounts[6].pubkey,
+            find_mint_authority_pda(&plan.mint_from, &plan.creator).0
+        );
+        assert!(instruction.accounts[2].is_signer);
+    }
+
+    #[test]
+    fn test_migrate_balances_instructions() {
+        let plan = plan();
+        let holders: Vec<MigrationHolder> = (0..MIGRATION_BATCH_SIZE + 1)
+            .map(|_| MigrationHolder {
+                token_account_from: Pubkey::new_unique(),
+                token_account_to: Pubkey::new_unique(),
+            })
+            .collect();
+
+        let instructions = migrate_balances_instructions(&plan, &plan.creator, &holders);
+        assert_eq!(instructions.len(), 2);
+        assert!(instructions
+            .iter()
+            .all(|instruction| instruction.data == [MIGRATE_BALANCES_DISCRIMINATOR]));
+        assert!(instructions[0].accounts[2].is_signer);
+        // Holder accounts are followed by the ProgramConfig
+        assert_eq!(
+            instructions[0].accounts.len(),
+            12 + 3 * MIGRATION_BATCH_SIZE + 1
+        );
+        assert_eq!(instructions[1].accounts.len(), 12 + 3 + 1);
+
+        let last = &instructions[1].accounts[12..12 + 3];
+        assert_eq!(
+            last[0].pubkey,
+            holders[MIGRATION_BATCH_SIZE].token_account_from
+        );
+        assert_eq!(
+            last[1].pubkey,
+            holders[MIGRATION_BATCH_SIZE].token_account_to
+        );
+        assert_eq!(
+            last[2].pubkey,
+            find_migration_receipt_pda(
+                &plan.mint_to,
+                &holders[MIGRATION_BATCH_SIZE].token_account_from,
+                plan.action_id
+            )
+            .0
+        );
+        assert!(last.iter().all(|meta| meta.is_writable && !meta.is_signer));
+    }
+}
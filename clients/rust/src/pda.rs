@@ -0,0 +1,1115 @@
+//! PDA derivation helpers mirroring the on-chain seeds in `program/src/constants.rs`.
+
+use crate::programs::SECURITY_TOKEN_PROGRAM_ID;
+use solana_keccak_hasher::hashv;
+use solana_pubkey::{pubkey, Pubkey};
+
+/// Legacy SPL Token program ID
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+/// SPL Token-2022 program ID
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+/// Security token transfer hook program ID
+pub const TRANSFER_HOOK_PROGRAM_ID: Pubkey =
+    pubkey!("HookXqLKgPaNrHBJ9Jui7oQZz93vMbtA88JjsLa8bmfL");
+/// SPL Associated Token Account program ID
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+/// Upgradeable BPF loader program ID
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+pub mod seeds {
+    pub const MINT_AUTHORITY: &[u8] = b"mint.authority";
+    pub const PAUSE_AUTHORITY: &[u8] = b"mint.pause_authority";
+    pub const FREEZE_AUTHORITY: &[u8] = b"mint.freeze_authority";
+    pub const TRANSFER_HOOK: &[u8] = b"mint.transfer_hook";
+    pub const SCALED_UI_AMOUNT_AUTHORITY: &[u8] = b"mint.scaled_ui_amount_authority";
+    pub const TRANSFER_FEE_AUTHORITY: &[u8] = b"mint.transfer_fee_authority";
+    pub const PERMANENT_DELEGATE: &[u8] = b"mint.permanent_delegate";
+    pub const ACCOUNT_DELEGATE: &[u8] = b"account.delegate";
+    pub const EXTRA_ACCOUNT_METAS: &[u8] = b"extra-account-metas";
+    pub const VERIFICATION_CONFIG: &[u8] = b"verification_config";
+    pub const RATE_ACCOUNT: &[u8] = b"rate";
+    pub const RECEIPT_ACCOUNT: &[u8] = b"receipt";
+    pub const PROOF_ACCOUNT: &[u8] = b"proof";
+    pub const DISTRIBUTION_ESCROW_AUTHORITY: &[u8] = b"distribution_escrow_authority";
+    pub const WRAP_VAULT: &[u8] = b"wrap_vault";
+    pub const AGENT_ACCOUNT: &[u8] = b"agent";
+    pub const IDENTITY_ACCOUNT: &[u8] = b"identity";
+    pub const IDENTITY_WALLET_ACCOUNT: &[u8] = b"identity_wallet";
+    pub const POSITION_LIMIT_ACCOUNT: &[u8] = b"position_limit";
+    pub const TRANSFER_ACCEPTANCE_ACCOUNT: &[u8] = b"transfer_acceptance";
+    pub const PENDING_TRANSFER_ACCOUNT: &[u8] = b"pending_transfer";
+    pub const TRANSFER_ESCROW_AUTHORITY: &[u8] = b"transfer_escrow_authority";
+    pub const TRANSFER_APPROVAL_ACCOUNT: &[u8] = b"transfer_approval";
+    pub const TRANSFER_REQUEST_ACCOUNT: &[u8] = b"transfer_request";
+    pub const ACCRUAL_CONFIG_ACCOUNT: &[u8] = b"accrual_config";
+    pub const COUPON_CLAIM_ACCOUNT: &[u8] = b"coupon_claim";
+    pub const MATURITY_ACCOUNT: &[u8] = b"maturity";
+    pub const NAV_ORACLE_ACCOUNT: &[u8] = b"nav_oracle";
+    pub const SUBSCRIPTION_ACCOUNT: &[u8] = b"subscription";
+    pub const SUBSCRIPTION_COMMITMENT_ACCOUNT: &[u8] = b"subscription_commitment";
+    pub const AUCTION_ACCOUNT: &[u8] = b"auction";
+    pub const AUCTION_BID_ACCOUNT: &[u8] = b"auction_bid";
+    pub const HOLDING_PERIOD_ACCOUNT: &[u8] = b"holding_period";
+    pub const RESTRICTED_HOLDING_ACCOUNT: &[u8] = b"restricted_holding";
+    pub const HOLDING_LOT_ACCOUNT: &[u8] = b"holding_lot";
+    pub const SUSPENSION_ACCOUNT: &[u8] = b"suspension";
+    pub const FREEZE_EXPIRY_ACCOUNT: &[u8] = b"freeze_expiry";
+    pub const VESTING_ACCOUNT: &[u8] = b"vesting";
+    pub const WITHHOLDING_RATE_ACCOUNT: &[u8] = b"withholding_rate";
+    pub const DISTRIBUTION_PAYOUT_ACCOUNT: &[u8] = b"distribution_payout";
+    pub const CLAIM_DEADLINE_ACCOUNT: &[u8] = b"claim_deadline";
+    pub const DISTRIBUTION_ROOT_ACCOUNT: &[u8] = b"distribution_root";
+    pub const PERMIT_NONCE_ACCOUNT: &[u8] = b"permit_nonce";
+    pub const SESSION_KEY_ACCOUNT: &[u8] = b"session_key";
+    pub const RECOVERY_RECEIPT_ACCOUNT: &[u8] = b"recovery_receipt";
+    pub const CLAIM_BITMAP_ACCOUNT: &[u8] = b"claim_bitmap";
+    pub const MINT_MIGRATION_ACCOUNT: &[u8] = b"mint_migration";
+    pub const METADATA_SCHEMA_ACCOUNT: &[u8] = b"metadata_schema";
+    pub const METADATA_FREEZE_ACCOUNT: &[u8] = b"metadata_freeze";
+    pub const CONFIG_TEMPLATE_ACCOUNT: &[u8] = b"config_template";
+    pub const COLLATERAL_ATTESTATION_ACCOUNT: &[u8] = b"collateral_attestation";
+    pub const RESERVE_ATTESTATION_ACCOUNT: &[u8] = b"reserve_attestation";
+    pub const SUB_ACCOUNT_LEDGER_ACCOUNT: &[u8] = b"sub_account_ledger";
+    pub const SUB_ACCOUNT_ACCOUNT: &[u8] = b"sub_account";
+    pub const SUCCESSION_POLICY_ACCOUNT: &[u8] = b"succession_policy";
+    pub const METADATA_TRANSLATOR_ACCOUNT: &[u8] = b"metadata_translator";
+    pub const DOCUMENT_REGISTRY_ACCOUNT: &[u8] = b"document_registry";
+    pub const PERMANENT_DELEGATE_CONFIG_ACCOUNT: &[u8] = b"permanent_delegate_config";
+    pub const PAUSE_DELEGATE_ACCOUNT: &[u8] = b"pause_delegate";
+    pub const CONFIG_ADMIN_ACCOUNT: &[u8] = b"config_admin";
+    pub const PENDING_VERIFICATION_CONFIG: &[u8] = b"pending_verification_config";
+    pub const PROGRAM_CONFIG: &[u8] = b"program_config";
+    pub const ALLOWLIST_ENTRY_ACCOUNT: &[u8] = b"allowlist_entry";
+}
+
+/// Derive mint authority PDA
+/// Seeds: ["mint.authority", mint, creator]
+pub fn find_mint_authority_pda(mint: &Pubkey, creator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::MINT_AUTHORITY, mint.as_ref(), creator.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive pause authority PDA
+/// Seeds: ["mint.pause_authority", mint]
+pub fn find_pause_authority_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::PAUSE_AUTHORITY, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive freeze authority PDA
+/// Seeds: ["mint.freeze_authority", mint]
+pub fn find_freeze_authority_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::FREEZE_AUTHORITY, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive transfer hook authority PDA
+/// Seeds: ["mint.transfer_hook", mint]
+pub fn find_transfer_hook_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::TRANSFER_HOOK, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive scaled UI amount authority PDA, the authority expected by UpdateMultiplierFromOracle
+/// Seeds: ["mint.scaled_ui_amount_authority", mint]
+pub fn find_scaled_ui_amount_authority_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::SCALED_UI_AMOUNT_AUTHORITY, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive transfer fee authority PDA, both fee authorities of mints created with a transfer fee
+/// Seeds: ["mint.transfer_fee_authority", mint]
+pub fn find_transfer_fee_authority_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::TRANSFER_FEE_AUTHORITY, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive permanent delegate PDA
+/// Seeds: ["mint.permanent_delegate", mint]
+pub fn find_permanent_delegate_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::PERMANENT_DELEGATE, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive account delegate PDA of a token account, the delegate a holder approves
+/// for TransferWithPermit
+/// Seeds: ["account.delegate", token_account]
+pub fn find_account_delegate_pda(token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::ACCOUNT_DELEGATE, token_account.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive transfer hook extra account metas PDA (owned by the transfer hook program)
+/// Seeds: ["extra-account-metas", mint]
+pub fn find_extra_account_metas_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::EXTRA_ACCOUNT_METAS, mint.as_ref()],
+        &TRANSFER_HOOK_PROGRAM_ID,
+    )
+}
+
+/// Derive verification config PDA
+/// Seeds: ["verification_config", mint, instruction_discriminator]
+pub fn find_verification_config_pda(mint: &Pubkey, instruction_discriminator: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::VERIFICATION_CONFIG,
+            mint.as_ref(),
+            &[instruction_discriminator],
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive rate PDA
+/// Seeds: ["rate", action_id, mint_from, mint_to]
+pub fn find_rate_pda(action_id: u64, mint_from: &Pubkey, mint_to: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::RATE_ACCOUNT,
+            action_id.to_le_bytes().as_ref(),
+            mint_from.as_ref(),
+            mint_to.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive receipt PDA of a split or convert action
+/// Seeds: ["receipt", mint, action_id]
+pub fn find_receipt_pda(mint: &Pubkey, action_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::RECEIPT_ACCOUNT,
+            mint.as_ref(),
+            action_id.to_le_bytes().as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive receipt PDA of a token account migrated to a new mint
+/// Seeds: ["receipt", mint_to, token_account, action_id]
+pub fn find_migration_receipt_pda(
+    mint_to: &Pubkey,
+    token_account: &Pubkey,
+    action_id: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::RECEIPT_ACCOUNT,
+            mint_to.as_ref(),
+            token_account.as_ref(),
+            action_id.to_le_bytes().as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive claim receipt PDA
+/// Seeds: ["receipt", mint, token_account, action_id, keccak(proof)]
+pub fn find_claim_receipt_pda(
+    mint: &Pubkey,
+    token_account: &Pubkey,
+    action_id: u64,
+    proof: &[[u8; 32]],
+) -> (Pubkey, u8) {
+    let proof_hash = hash_from_proof_data(proof);
+    Pubkey::find_program_address(
+        &[
+            seeds::RECEIPT_ACCOUNT,
+            mint.as_ref(),
+            token_account.as_ref(),
+            action_id.to_le_bytes().as_ref(),
+            proof_hash.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive claim receipt PDA of a claim against a supplemental merkle root
+/// Seeds: ["receipt", mint, token_account, action_id, keccak(keccak(proof), merkle_root)]
+pub fn find_root_claim_receipt_pda(
+    mint: &Pubkey,
+    token_account: &Pubkey,
+    action_id: u64,
+    proof: &[[u8; 32]],
+    merkle_root: &[u8; 32],
+) -> (Pubkey, u8) {
+    let proof_hash = hashv(&[&hash_from_proof_data(proof), merkle_root]).to_bytes();
+    Pubkey::find_program_address(
+        &[
+            seeds::RECEIPT_ACCOUNT,
+            mint.as_ref(),
+            token_account.as_ref(),
+            action_id.to_le_bytes().as_ref(),
+            proof_hash.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive proof PDA of a token account and action
+/// Seeds: ["proof", token_account, action_id]
+pub fn find_proof_pda(token_account: &Pubkey, action_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::PROOF_ACCOUNT,
+            token_account.as_ref(),
+            action_id.to_le_bytes().as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive distribution escrow authority PDA
+/// Seeds: ["distribution_escrow_authority", mint, action_id, merkle_root]
+pub fn find_distribution_escrow_authority_pda(
+    mint: &Pubkey,
+    action_id: u64,
+    merkle_root: &[u8; 32],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::DISTRIBUTION_ESCROW_AUTHORITY,
+            mint.as_ref(),
+            action_id.to_le_bytes().as_ref(),
+            merkle_root.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive distribution payout PDA of a distribution and payout mint
+/// Seeds: ["distribution_payout", mint, action_id, payout_mint]
+pub fn find_distribution_payout_pda(
+    mint: &Pubkey,
+    action_id: u64,
+    payout_mint: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::DISTRIBUTION_PAYOUT_ACCOUNT,
+            mint.as_ref(),
+            &action_id.to_le_bytes(),
+            payout_mint.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive claim deadline PDA of a distribution
+/// Seeds: ["claim_deadline", mint, action_id]
+pub fn find_claim_deadline_pda(mint: &Pubkey, action_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::CLAIM_DEADLINE_ACCOUNT,
+            mint.as_ref(),
+            &action_id.to_le_bytes(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive distribution root PDA of an amended merkle root
+/// Seeds: ["distribution_root", mint, action_id, merkle_root]
+pub fn find_distribution_root_pda(
+    mint: &Pubkey,
+    action_id: u64,
+    merkle_root: &[u8; 32],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::DISTRIBUTION_ROOT_ACCOUNT,
+            mint.as_ref(),
+            &action_id.to_le_bytes(),
+            merkle_root.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive claim bitmap page PDA
+/// Seeds: ["claim_bitmap", mint, action_id, merkle_root, page]
+pub fn find_claim_bitmap_pda(
+    mint: &Pubkey,
+    action_id: u64,
+    merkle_root: &[u8; 32],
+    page: u32,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::CLAIM_BITMAP_ACCOUNT,
+            mint.as_ref(),
+            &action_id.to_le_bytes(),
+            merkle_root.as_ref(),
+            &page.to_le_bytes(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive permit nonce PDA of a token account
+/// Seeds: ["permit_nonce", token_account]
+pub fn find_permit_nonce_pda(token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::PERMIT_NONCE_ACCOUNT, token_account.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive recovery receipt PDA
+/// Seeds: ["recovery_receipt", mint, recovery_id]
+pub fn find_recovery_receipt_pda(mint: &Pubkey, recovery_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::RECOVERY_RECEIPT_ACCOUNT,
+            mint.as_ref(),
+            &recovery_id.to_le_bytes(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive mint migration PDA
+/// Seeds: ["mint_migration", mint_from]
+pub fn find_mint_migration_pda(mint_from: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::MINT_MIGRATION_ACCOUNT, mint_from.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive metadata schema PDA
+/// Seeds: ["metadata_schema", mint]
+pub fn find_metadata_schema_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::METADATA_SCHEMA_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive document registry PDA
+/// Seeds: ["document_registry", mint]
+pub fn find_document_registry_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::DOCUMENT_REGISTRY_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive permanent delegate config PDA
+/// Seeds: ["permanent_delegate_config", mint]
+pub fn find_permanent_delegate_config_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::PERMANENT_DELEGATE_CONFIG_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive pause delegate PDA
+/// Seeds: ["pause_delegate", mint]
+pub fn find_pause_delegate_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::PAUSE_DELEGATE_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive config admin PDA
+/// Seeds: ["config_admin", mint]
+pub fn find_config_admin_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::CONFIG_ADMIN_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive pending verification config PDA
+/// Seeds: ["pending_verification_config", mint, instruction_discriminator]
+pub fn find_pending_verification_config_pda(
+    mint: &Pubkey,
+    instruction_discriminator: u8,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::PENDING_VERIFICATION_CONFIG,
+            mint.as_ref(),
+            &[instruction_discriminator],
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive program config PDA
+/// Seeds: ["program_config"]
+pub fn find_program_config_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seeds::PROGRAM_CONFIG], &SECURITY_TOKEN_PROGRAM_ID)
+}
+
+/// Derive ProgramData account of the security token program, naming its upgrade authority
+/// Seeds: [program_id] under the upgradeable BPF loader
+pub fn find_program_data_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SECURITY_TOKEN_PROGRAM_ID.as_ref()],
+        &BPF_LOADER_UPGRADEABLE_ID,
+    )
+}
+
+/// Derive allowlist entry PDA of a wallet
+/// Seeds: ["allowlist_entry", mint, wallet]
+pub fn find_allowlist_entry_pda(mint: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::ALLOWLIST_ENTRY_ACCOUNT,
+            mint.as_ref(),
+            wallet.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive metadata freeze PDA
+/// Seeds: ["metadata_freeze", mint]
+pub fn find_metadata_freeze_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::METADATA_FREEZE_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive config template PDA
+/// Seeds: ["config_template", issuer, template_id]
+pub fn find_config_template_pda(issuer: &Pubkey, template_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::CONFIG_TEMPLATE_ACCOUNT,
+            issuer.as_ref(),
+            &template_id.to_le_bytes(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive collateral attestation PDA of a token account
+/// Seeds: ["collateral_attestation", token_account]
+pub fn find_collateral_attestation_pda(token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::COLLATERAL_ATTESTATION_ACCOUNT,
+            token_account.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive reserve attestation PDA of a custodian
+/// Seeds: ["reserve_attestation", mint, custodian]
+pub fn find_reserve_attestation_pda(mint: &Pubkey, custodian: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::RESERVE_ATTESTATION_ACCOUNT,
+            mint.as_ref(),
+            custodian.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive sub-account ledger PDA of an omnibus token account
+/// Seeds: ["sub_account_ledger", token_account]
+pub fn find_sub_account_ledger_pda(token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::SUB_ACCOUNT_LEDGER_ACCOUNT, token_account.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive sub-account PDA of a beneficial owner
+/// Seeds: ["sub_account", sub_account_ledger, beneficial_owner]
+pub fn find_sub_account_pda(
+    sub_account_ledger: &Pubkey,
+    beneficial_owner: &[u8; 32],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::SUB_ACCOUNT_ACCOUNT,
+            sub_account_ledger.as_ref(),
+            beneficial_owner.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive succession policy PDA of a mint
+/// Seeds: ["succession_policy", mint]
+pub fn find_succession_policy_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::SUCCESSION_POLICY_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive metadata translator PDA
+/// Seeds: ["metadata_translator", mint, translator]
+pub fn find_metadata_translator_pda(mint: &Pubkey, translator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::METADATA_TRANSLATOR_ACCOUNT,
+            mint.as_ref(),
+            translator.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive wrap vault PDA
+/// Seeds: ["wrap_vault", mint, underlying_mint]
+pub fn find_wrap_vault_pda(mint: &Pubkey, underlying_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::WRAP_VAULT, mint.as_ref(), underlying_mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive the wrap vault token account holding locked underlying tokens
+pub fn find_wrap_vault_token_account(
+    mint: &Pubkey,
+    underlying_mint: &Pubkey,
+    underlying_token_program: &Pubkey,
+) -> (Pubkey, u8) {
+    let (wrap_vault, _) = find_wrap_vault_pda(mint, underlying_mint);
+    find_associated_token_address_with_program(
+        &wrap_vault,
+        underlying_mint,
+        underlying_token_program,
+    )
+}
+
+/// Derive agent PDA
+/// Seeds: ["agent", mint, agent]
+pub fn find_agent_pda(mint: &Pubkey, agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::AGENT_ACCOUNT, mint.as_ref(), agent.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive session key PDA
+/// Seeds: ["session_key", mint, session_key]
+pub fn find_session_key_pda(mint: &Pubkey, session_key: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::SESSION_KEY_ACCOUNT,
+            mint.as_ref(),
+            session_key.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive identity PDA
+/// Seeds: ["identity", mint, investor_id]
+pub fn find_identity_pda(mint: &Pubkey, investor_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::IDENTITY_ACCOUNT, mint.as_ref(), investor_id.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive identity wallet PDA linking a token account to its identity
+/// Seeds: ["identity_wallet", token_account]
+pub fn find_identity_wallet_pda(token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::IDENTITY_WALLET_ACCOUNT, token_account.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive position limit PDA
+/// Seeds: ["position_limit", mint]
+pub fn find_position_limit_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::POSITION_LIMIT_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive transfer acceptance PDA
+/// Seeds: ["transfer_acceptance", mint]
+pub fn find_transfer_acceptance_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::TRANSFER_ACCEPTANCE_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive pending transfer PDA
+/// Seeds: ["pending_transfer", source_token_account, destination_token_account, transfer_id]
+pub fn find_pending_transfer_pda(
+    source_token_account: &Pubkey,
+    destination_token_account: &Pubkey,
+    transfer_id: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::PENDING_TRANSFER_ACCOUNT,
+            source_token_account.as_ref(),
+            destination_token_account.as_ref(),
+            transfer_id.to_le_bytes().as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive transfer escrow authority PDA, owner of the escrow token account holding pending transfers
+/// Seeds: ["transfer_escrow_authority", mint]
+pub fn find_transfer_escrow_authority_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::TRANSFER_ESCROW_AUTHORITY, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive transfer approval PDA
+/// Seeds: ["transfer_approval", mint]
+pub fn find_transfer_approval_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::TRANSFER_APPROVAL_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive transfer request PDA
+/// Seeds: ["transfer_request", source_token_account, destination_token_account, request_id]
+pub fn find_transfer_request_pda(
+    source_token_account: &Pubkey,
+    destination_token_account: &Pubkey,
+    request_id: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::TRANSFER_REQUEST_ACCOUNT,
+            source_token_account.as_ref(),
+            destination_token_account.as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive coupon accrual config PDA
+/// Seeds: ["accrual_config", mint]
+pub fn find_accrual_config_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::ACCRUAL_CONFIG_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive coupon claim PDA
+/// Seeds: ["coupon_claim", token_account]
+pub fn find_coupon_claim_pda(token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::COUPON_CLAIM_ACCOUNT, token_account.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive bond maturity PDA
+/// Seeds: ["maturity", mint]
+pub fn find_maturity_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::MATURITY_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive NAV oracle PDA
+/// Seeds: ["nav_oracle", mint]
+pub fn find_nav_oracle_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::NAV_ORACLE_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive primary issuance subscription PDA
+/// Seeds: ["subscription", mint, subscription_id]
+pub fn find_subscription_pda(mint: &Pubkey, subscription_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::SUBSCRIPTION_ACCOUNT,
+            mint.as_ref(),
+            &subscription_id.to_le_bytes(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive subscription commitment PDA
+/// Seeds: ["subscription_commitment", subscription, investor]
+pub fn find_subscription_commitment_pda(subscription: &Pubkey, investor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::SUBSCRIPTION_COMMITMENT_ACCOUNT,
+            subscription.as_ref(),
+            investor.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive Dutch auction PDA
+/// Seeds: ["auction", mint, auction_id]
+pub fn find_auction_pda(mint: &Pubkey, auction_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::AUCTION_ACCOUNT,
+            mint.as_ref(),
+            &auction_id.to_le_bytes(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive auction bid escrow PDA
+/// Seeds: ["auction_bid", auction, bidder]
+pub fn find_auction_bid_pda(auction: &Pubkey, bidder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::AUCTION_BID_ACCOUNT,
+            auction.as_ref(),
+            bidder.as_ref(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive Rule 144 holding period PDA
+/// Seeds: ["holding_period", mint]
+pub fn find_holding_period_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::HOLDING_PERIOD_ACCOUNT, mint.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive restricted holding PDA of a token account
+/// Seeds: ["restricted_holding", token_account]
+pub fn find_restricted_holding_pda(token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::RESTRICTED_HOLDING_ACCOUNT, token_account.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive restricted holding lot PDA
+/// Seeds: ["holding_lot", token_account, lot_id]
+pub fn find_holding_lot_pda(token_account: &Pubkey, lot_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::HOLDING_LOT_ACCOUNT,
+            token_account.as_ref(),
+            &lot_id.to_le_bytes(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive suspension PDA of a token account
+/// Seeds: ["suspension", token_account]
+pub fn find_suspension_pda(token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::SUSPENSION_ACCOUNT, token_account.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive freeze expiry PDA of a token account
+/// Seeds: ["freeze_expiry", token_account]
+pub fn find_freeze_expiry_pda(token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::FREEZE_EXPIRY_ACCOUNT, token_account.as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive vesting PDA of a beneficiary token account
+/// Seeds: ["vesting", beneficiary, vesting_id]
+pub fn find_vesting_pda(beneficiary: &Pubkey, vesting_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::VESTING_ACCOUNT,
+            beneficiary.as_ref(),
+            &vesting_id.to_le_bytes(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive withholding rate PDA of a mint and jurisdiction
+/// Seeds: ["withholding_rate", mint, jurisdiction]
+pub fn find_withholding_rate_pda(mint: &Pubkey, jurisdiction: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::WITHHOLDING_RATE_ACCOUNT,
+            mint.as_ref(),
+            &jurisdiction.to_le_bytes(),
+        ],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Derive Token-2022 associated token account address
+pub fn find_associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    find_associated_token_address_with_program(wallet, mint, &TOKEN_2022_PROGRAM_ID)
+}
+
+/// Derive associated token account address of a legacy SPL Token or Token-2022 mint
+pub fn find_associated_token_address_with_program(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Proof hash used in claim receipt seeds
+pub fn hash_from_proof_data(proof: &[[u8; 32]]) -> [u8; 32] {
+    let proof_data = proof.iter().flatten().copied().collect::<Vec<u8>>();
+    hashv(&[&proof_data]).to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use security_token_program::constants::seeds as program_seeds;
+
+    #[test]
+    fn test_seeds_match_program() {
+        for (client, program) in [
+            (seeds::MINT_AUTHORITY, program_seeds::MINT_AUTHORITY),
+            (seeds::PAUSE_AUTHORITY, program_seeds::PAUSE_AUTHORITY),
+            (seeds::FREEZE_AUTHORITY, program_seeds::FREEZE_AUTHORITY),
+            (seeds::TRANSFER_HOOK, program_seeds::TRANSFER_HOOK),
+            (
+                seeds::SCALED_UI_AMOUNT_AUTHORITY,
+                program_seeds::SCALED_UI_AMOUNT_AUTHORITY,
+            ),
+            (
+                seeds::TRANSFER_FEE_AUTHORITY,
+                program_seeds::TRANSFER_FEE_AUTHORITY,
+            ),
+            (seeds::PERMANENT_DELEGATE, program_seeds::PERMANENT_DELEGATE),
+            (seeds::ACCOUNT_DELEGATE, program_seeds::ACCOUNT_DELEGATE),
+            (
+                seeds::EXTRA_ACCOUNT_METAS,
+                program_seeds::EXTRA_ACCOUNT_METAS,
+            ),
+            (
+                seeds::VERIFICATION_CONFIG,
+                program_seeds::VERIFICATION_CONFIG,
+            ),
+            (seeds::RATE_ACCOUNT, program_seeds::RATE_ACCOUNT),
+            (seeds::RECEIPT_ACCOUNT, program_seeds::RECEIPT_ACCOUNT),
+            (seeds::PROOF_ACCOUNT, program_seeds::PROOF_ACCOUNT),
+            (
+                seeds::DISTRIBUTION_ESCROW_AUTHORITY,
+                program_seeds::DISTRIBUTION_ESCROW_AUTHORITY,
+            ),
+            (seeds::WRAP_VAULT, program_seeds::WRAP_VAULT),
+            (seeds::AGENT_ACCOUNT, program_seeds::AGENT_ACCOUNT),
+            (seeds::IDENTITY_ACCOUNT, program_seeds::IDENTITY_ACCOUNT),
+            (
+                seeds::IDENTITY_WALLET_ACCOUNT,
+                program_seeds::IDENTITY_WALLET_ACCOUNT,
+            ),
+            (
+                seeds::POSITION_LIMIT_ACCOUNT,
+                program_seeds::POSITION_LIMIT_ACCOUNT,
+            ),
+            (
+                seeds::TRANSFER_ACCEPTANCE_ACCOUNT,
+                program_seeds::TRANSFER_ACCEPTANCE_ACCOUNT,
+            ),
+            (
+                seeds::PENDING_TRANSFER_ACCOUNT,
+                program_seeds::PENDING_TRANSFER_ACCOUNT,
+            ),
+            (
+                seeds::TRANSFER_ESCROW_AUTHORITY,
+                program_seeds::TRANSFER_ESCROW_AUTHORITY,
+            ),
+            (
+                seeds::TRANSFER_APPROVAL_ACCOUNT,
+                program_seeds::TRANSFER_APPROVAL_ACCOUNT,
+            ),
+            (
+                seeds::TRANSFER_REQUEST_ACCOUNT,
+                program_seeds::TRANSFER_REQUEST_ACCOUNT,
+            ),
+            (
+                seeds::ACCRUAL_CONFIG_ACCOUNT,
+                program_seeds::ACCRUAL_CONFIG_ACCOUNT,
+            ),
+            (
+                seeds::COUPON_CLAIM_ACCOUNT,
+                program_seeds::COUPON_CLAIM_ACCOUNT,
+            ),
+            (seeds::MATURITY_ACCOUNT, program_seeds::MATURITY_ACCOUNT),
+            (seeds::NAV_ORACLE_ACCOUNT, program_seeds::NAV_ORACLE_ACCOUNT),
+            (
+                seeds::SUBSCRIPTION_ACCOUNT,
+                program_seeds::SUBSCRIPTION_ACCOUNT,
+            ),
+            (
+                seeds::SUBSCRIPTION_COMMITMENT_ACCOUNT,
+                program_seeds::SUBSCRIPTION_COMMITMENT_ACCOUNT,
+            ),
+            (seeds::AUCTION_ACCOUNT, program_seeds::AUCTION_ACCOUNT),
+            (
+                seeds::AUCTION_BID_ACCOUNT,
+                program_seeds::AUCTION_BID_ACCOUNT,
+            ),
+            (
+                seeds::HOLDING_PERIOD_ACCOUNT,
+                program_seeds::HOLDING_PERIOD_ACCOUNT,
+            ),
+            (
+                seeds::RESTRICTED_HOLDING_ACCOUNT,
+                program_seeds::RESTRICTED_HOLDING_ACCOUNT,
+            ),
+            (
+                seeds::HOLDING_LOT_ACCOUNT,
+                program_seeds::HOLDING_LOT_ACCOUNT,
+            ),
+            (seeds::SUSPENSION_ACCOUNT, program_seeds::SUSPENSION_ACCOUNT),
+            (
+                seeds::FREEZE_EXPIRY_ACCOUNT,
+                program_seeds::FREEZE_EXPIRY_ACCOUNT,
+            ),
+            (seeds::VESTING_ACCOUNT, program_seeds::VESTING_ACCOUNT),
+            (
+                seeds::WITHHOLDING_RATE_ACCOUNT,
+                program_seeds::WITHHOLDING_RATE_ACCOUNT,
+            ),
+            (
+                seeds::DISTRIBUTION_PAYOUT_ACCOUNT,
+                program_seeds::DISTRIBUTION_PAYOUT_ACCOUNT,
+            ),
+            (
+                seeds::CLAIM_DEADLINE_ACCOUNT,
+                program_seeds::CLAIM_DEADLINE_ACCOUNT,
+            ),
+            (
+                seeds::DISTRIBUTION_ROOT_ACCOUNT,
+                program_seeds::DISTRIBUTION_ROOT_ACCOUNT,
+            ),
+            (
+                seeds::PERMIT_NONCE_ACCOUNT,
+                program_seeds::PERMIT_NONCE_ACCOUNT,
+            ),
+            (
+                seeds::SESSION_KEY_ACCOUNT,
+                program_seeds::SESSION_KEY_ACCOUNT,
+            ),
+            (
+                seeds::RECOVERY_RECEIPT_ACCOUNT,
+                program_seeds::RECOVERY_RECEIPT_ACCOUNT,
+            ),
+            (
+                seeds::CLAIM_BITMAP_ACCOUNT,
+                program_seeds::CLAIM_BITMAP_ACCOUNT,
+            ),
+            (
+                seeds::MINT_MIGRATION_ACCOUNT,
+                program_seeds::MINT_MIGRATION_ACCOUNT,
+            ),
+            (
+                seeds::METADATA_SCHEMA_ACCOUNT,
+                program_seeds::METADATA_SCHEMA_ACCOUNT,
+            ),
+            (
+                seeds::METADATA_FREEZE_ACCOUNT,
+                program_seeds::METADATA_FREEZE_ACCOUNT,
+            ),
+            (
+                seeds::CONFIG_TEMPLATE_ACCOUNT,
+                program_seeds::CONFIG_TEMPLATE_ACCOUNT,
+            ),
+            (
+                seeds::COLLATERAL_ATTESTATION_ACCOUNT,
+                program_seeds::COLLATERAL_ATTESTATION_ACCOUNT,
+            ),
+            (
+                seeds::RESERVE_ATTESTATION_ACCOUNT,
+                program_seeds::RESERVE_ATTESTATION_ACCOUNT,
+            ),
+            (
+                seeds::SUB_ACCOUNT_LEDGER_ACCOUNT,
+                program_seeds::SUB_ACCOUNT_LEDGER_ACCOUNT,
+            ),
+            (
+                seeds::SUB_ACCOUNT_ACCOUNT,
+                program_seeds::SUB_ACCOUNT_ACCOUNT,
+            ),
+            (
+                seeds::SUCCESSION_POLICY_ACCOUNT,
+                program_seeds::SUCCESSION_POLICY_ACCOUNT,
+            ),
+            (
+                seeds::METADATA_TRANSLATOR_ACCOUNT,
+                program_seeds::METADATA_TRANSLATOR_ACCOUNT,
+            ),
+            (
+                seeds::DOCUMENT_REGISTRY_ACCOUNT,
+                program_seeds::DOCUMENT_REGISTRY_ACCOUNT,
+            ),
+            (
+                seeds::PERMANENT_DELEGATE_CONFIG_ACCOUNT,
+                program_seeds::PERMANENT_DELEGATE_CONFIG_ACCOUNT,
+            ),
+            (
+                seeds::PAUSE_DELEGATE_ACCOUNT,
+                program_seeds::PAUSE_DELEGATE_ACCOUNT,
+            ),
+            (
+                seeds::CONFIG_ADMIN_ACCOUNT,
+                program_seeds::CONFIG_ADMIN_ACCOUNT,
+            ),
+            (
+                seeds::PENDING_VERIFICATION_CONFIG,
+                program_seeds::PENDING_VERIFICATION_CONFIG,
+            ),
+            (seeds::PROGRAM_CONFIG, program_seeds::PROGRAM_CONFIG),
+            (
+                seeds::ALLOWLIST_ENTRY_ACCOUNT,
+                program_seeds::ALLOWLIST_ENTRY_ACCOUNT,
+            ),
+        ] {
+            assert_eq!(client, program);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Holder permits signed with the holder wallet and verified on chain through an Ed25519
+//! program instruction placed before the permitted instruction.
+//!
+//! Claim permits use the same layout as `claim_permit_message` in the program:
+//! `"claim_permit" || mint || action_id_le || eligible_token_account || custodian`.
+//! Transfer permits use the layout of `transfer_permit_message`:
+//! `"transfer_permit" || mint || from || to || amount_le || nonce_le || expiry_le`.
+//!
+//! TransferWithPermit moves the tokens as the account delegate PDA of the source, so the
+//! holder first approves it with [`approve_permit_delegate_instruction`] for at most the
+//! amount permits may move. The transfer hook checks the permitted transfer like any other
+//! holder transfer: pass the ExtraAccountMetaList PDA as `account_metas_pda` and its extra
+//! accounts as remaining accounts.
+
+use crate::pda::{find_account_delegate_pda, TOKEN_2022_PROGRAM_ID};
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::ed25519_program;
+
+/// Domain prefix of claim permits
+pub const CLAIM_PERMIT_DOMAIN: &[u8] = b"claim_permit";
+
+/// Domain prefix of transfer permits
+pub const TRANSFER_PERMIT_DOMAIN: &[u8] = b"transfer_permit";
+
+/// Token-2022 `ApproveChecked` instruction discriminator
+const TOKEN_APPROVE_CHECKED_DISCRIMINATOR: u8 = 13;
+
+const SIGNATURE_OFFSETS_START: u16 = 2;
+const SIGNATURE_OFFSETS_LEN: u16 = 14;
+const PUBKEY_LEN: u16 = 32;
+const SIGNATURE_LEN: u16 = 64;
+
+/// Message the holder wallet signs to let `custodian` claim distribution `action_id`
+/// for `eligible_token_account`
+pub fn claim_permit_message(
+    mint: &Pubkey,
+    action_id: u64,
+    eligible_token_account: &Pubkey,
+    custodian: &Pubkey,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(CLAIM_PERMIT_DOMAIN.len() + 32 * 3 + 8);
+    message.extend_from_slice(CLAIM_PERMIT_DOMAIN);
+    message.extend_from_slice(mint.as_ref());
+    message.extend_from_slice(&action_id.to_le_bytes());
+    message.extend_from_slice(eligible_token_account.as_ref());
+    message.extend_from_slice(custodian.as_ref());
+    message
+}
+
+/// Message the holder wallet signs to let anyone transfer `amount` from `from_token_account`
+/// to `to_token_account`. `nonce` must be the next nonce of the permit nonce account
+/// of `from_token_account` and the permit is rejected after `expiry` (unix timestamp).
+pub fn transfer_permit_message(
+    mint: &Pubkey,
+    from_token_account: &Pubkey,
+    to_token_account: &Pubkey,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(TRANSFER_PERMIT_DOMAIN.len() + 32 * 3 + 24);
+    message.extend_from_slice(TRANSFER_PERMIT_DOMAIN);
+    message.extend_from_slice(mint.as_ref());
+    message.extend_from_slice(from_token_account.as_ref());
+    message.extend_from_slice(to_token_account.as_ref());
+    message.extend_from_slice(&amount.to_le_bytes());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message.extend_from_slice(&expiry.to_le_bytes());
+    message
+}
+
+/// Token-2022 `ApproveChecked` letting TransferWithPermit move up to `amount` tokens of
+/// `token_account`, signed by its `owner`
+pub fn approve_permit_delegate_instruction(
+    token_account: &Pubkey,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> Instruction {
+    let (account_delegate, _) = find_account_delegate_pda(token_account);
+    let mut data = Vec::with_capacity(10);
+    data.push(TOKEN_APPROVE_CHECKED_DISCRIMINATOR);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+    Instruction {
+        program_id: TOKEN_2022_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*token_account, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(account_delegate, false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        data,
+    }
+}
+
+/// Ed25519 program instruction verifying `signature` of `signer` over `message`.
+/// Must precede the permitted instruction in the same transaction.
+pub fn ed25519_permit_instruction(
+    signer: &Pubkey,
+    signature: &[u8; 64],
+    message: &[u8],
+) -> Instruction {
+    let public_key_offset = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN;
+    let signature_offset = public_key_offset + PUBKEY_LEN;
+    let message_data_offset = signature_offset + SIGNATURE_LEN;
+
+    // num_signatures + padding byte
+    let mut data = vec![1u8, 0];
+    for value in [
+        signature_offset,
+        u16::MAX,
+        public_key_offset,
+        u16::MAX,
+        message_data_offset,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use security_token_program::permit_utils;
+
+    #[test]
+    fn test_claim_permit_message_matches_program() {
+        let mint = Pubkey::new_unique();
+        let eligible_token_account = Pubkey::new_unique();
+        let custodian = Pubkey::new_unique();
+
+        assert_eq!(
+            claim_permit_message(&mint, 3, &eligible_token_account, &custodian),
+            permit_utils::claim_permit_message(
+                &mint.to_bytes(),
+                3,
+                &eligible_token_account.to_bytes(),
+                &custodian.to_bytes(),
+            )
+        );
+    }
+
+    #[test]
+    fn test_transfer_permit_message_matches_program() {
+        let mint = Pubkey::new_unique();
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+
+        assert_eq!(
+            transfer_permit_message(&mint, &from, &to, 500, 2, 1_700_000_000),
+            permit_utils::transfer_permit_message(
+                &mint.to_bytes(),
+                &from.to_bytes(),
+                &to.to_bytes(),
+                500,
+                2,
+                1_700_000_000,
+            )
+        );
+    }
+
+    #[test]
+    fn test_approve_permit_delegate_instruction() {
+        let token_account = Pubkey::new_unique();
+        let ix = approve_permit_delegate_instruction(
+            &token_account,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            500,
+            6,
+        );
+
+        assert_eq!(ix.program_id, TOKEN_2022_PROGRAM_ID);
+        assert_eq!(ix.data, [13, 244, 1, 0, 0, 0, 0, 0, 0, 6]);
+        assert_eq!(ix.accounts[0].pubkey, token_account);
+        assert_eq!(
+            ix.accounts[2].pubkey,
+            find_account_delegate_pda(&token_account).0
+        );
+        assert!(ix.accounts[3].is_signer);
+    }
+
+    #[test]
+    fn test_ed25519_permit_instruction_is_accepted_by_program() {
+        let signer = Pubkey::new_unique();
+        let message = claim_permit_message(
+            &Pubkey::new_unique(),
+            3,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+        );
+        let ix = ed25519_permit_instruction(&signer, &[7u8; 64], &message);
+
+        assert_eq!(
+            ix.program_id.to_bytes(),
+            security_token_program::constants::ED25519_PROGRAM_ID
+        );
+        assert!(permit_utils::ed25519_instruction_verifies(
+            &ix.data,
+            &signer.to_bytes(),
+            &message
+        ));
+    }
+}
@@ -0,0 +1,378 @@
+//! Preflight compliance checks.
+//!
+//! Wallets simulate an operation before asking the user to sign and show why it would be
+//! rejected. [`classify_failure`] maps a simulation failure to a [`ComplianceFailure`]: a
+//! verification program of the mint rejecting the operation (with the reason registered for
+//! its error code, e.g. "KYC missing"), the program or its transfer hook refusing it (paused
+//! mint, suspended account, holding period, ...), or any other failure.
+//!
+//! `SecurityTokenClient` (feature `rpc`) resolves the missing verification and transfer
+//! hook accounts of the operation, simulates it and classifies the failure.
+
+use crate::errors::SecurityTokenProgramError;
+use crate::instructions::TRANSFER_DISCRIMINATOR;
+use crate::logs::failed_program;
+use crate::pda::{find_verification_config_pda, TOKEN_2022_PROGRAM_ID, TRANSFER_HOOK_PROGRAM_ID};
+use crate::programs::SECURITY_TOKEN_PROGRAM_ID;
+use crate::resolution::TOKEN_TRANSFER_CHECKED_DISCRIMINATOR;
+use solana_instruction::error::InstructionError;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use solana_transaction_error::TransactionError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Reasons of verification program errors, by program and error code
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RejectionReasons(HashMap<(Pubkey, u32), String>);
+
+impl RejectionReasons {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `reason` for the error `code` of the verification `program`
+    pub fn with(mut self, program: Pubkey, code: u32, reason: impl Into<String>) -> Self {
+        self.0.insert((program, code), reason.into());
+        self
+    }
+
+    pub fn get(&self, program: &Pubkey, code: u32) -> Option<&str> {
+        self.0.get(&(*program, code)).map(String::as_str)
+    }
+}
+
+/// Why an operation would be rejected
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum ComplianceFailure {
+    /// A verification program of the mint rejected the operation
+    #[error("Rejected by verification program {program}: {}", rejection_reason(.code, .reason))]
+    Rejected {
+        program: Pubkey,
+        /// Custom error code of the program, `None` for other failures
+        code: Option<u32>,
+        /// Reason registered for `code`
+        reason: Option<String>,
+    },
+    /// The program or its transfer hook refused the operation
+    #[error("Refused by the security token program: {0}")]
+    Refused(SecurityTokenProgramError),
+    /// Any other failure, e.g. insufficient funds
+    #[error("Simulation failed: {error}")]
+    Failed {
+        error: TransactionError,
+        logs: Vec<String>,
+    },
+    /// The operation could not be prepared or simulated
+    #[error("Preflight failed: {0}")]
+    Preflight(String),
+}
+
+fn rejection_reason(code: &Option<u32>, reason: &Option<String>) -> String {
+    match (reason, code) {
+        (Some(reason), _) => reason.clone(),
+        (None, Some(code)) => format!("error {code:#x}"),
+        (None, None) => "unknown error".to_string(),
+    }
+}
+
+/// Maps the simulation failure `error` to a compliance failure, using the `logs` to find the
+/// program that failed. `verification_programs` are the programs of the verification configs
+/// of the operation.
+pub fn classify_failure(
+    error: TransactionError,
+    logs: Vec<String>,
+    verification_programs: &[Pubkey],
+    reasons: &RejectionReasons,
+) -> ComplianceFailure {
+    let code = match &error {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => Some(*code),
+        _ => None,
+    };
+    match failed_program(&logs) {
+        Some(program) if verification_programs.contains(&program) => ComplianceFailure::Rejected {
+            program,
+            code,
+            reason: code.and_then(|code| reasons.get(&program, code).map(str::to_string)),
+        },
+        // The transfer hook fails with security token program error codes
+        Some(program)
+            if program == SECURITY_TOKEN_PROGRAM_ID || program == TRANSFER_HOOK_PROGRAM_ID =>
+        {
+            match SecurityTokenProgramError::from_transaction_error(&error, &logs) {
+                Some(error) => ComplianceFailure::Refused(error),
+                None => ComplianceFailure::Failed { error, logs },
+            }
+        }
+        _ => ComplianceFailure::Failed { error, logs },
+    }
+}
+
+/// Verification configs checked by `instructions`: the config of every security token
+/// instruction and the transfer config of every Token-2022 `TransferChecked`
+pub fn verification_config_addresses(instructions: &[Instruction]) -> Vec<Pubkey> {
+    let mut configs: Vec<Pubkey> = Vec::new();
+    for instruction in instructions {
+        let config = if instruction.program_id == SECURITY_TOKEN_PROGRAM_ID {
+            match (instruction.accounts.first(), instruction.data.first()) {
+                (Some(mint), Some(discriminator)) => {
+                    find_verification_config_pda(&mint.pubkey, *discriminator).0
+                }
+                _ => continue,
+            }
+        } else if instruction.program_id == TOKEN_2022_PROGRAM_ID
+            && instruction.data.first() == Some(&TOKEN_TRANSFER_CHECKED_DISCRIMINATOR)
+            && instruction.accounts.len() >= 4
+        {
+            find_verification_config_pda(&instruction.accounts[1].pubkey, TRANSFER_DISCRIMINATOR).0
+        } else {
+            continue;
+        };
+        if !configs.contains(&config) {
+            configs.push(config);
+        }
+    }
+    configs
+}
+
+/// Token-2022 `TransferChecked` of a holder, without the transfer hook accounts
+pub fn transfer_checked_instruction(
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> Instruction {
+    let mut data = Vec::with_capacity(10);
+    data.push(TOKEN_TRANSFER_CHECKED_DISCRIMINATOR);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+    Instruction {
+        program_id: TOKEN_2022_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*source, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        data,
+    }
+}
+
+#[cfg(feature = "rpc")]
+pub use self::send::*;
+
+#[cfg(feature = "rpc")]
+mod send {
+    use super::*;
+    use crate::account::ProgramAccount;
+    use crate::accounts::VerificationConfig;
+    use crate::resolution::{resolve_missing_accounts, ResolutionError};
+    use crate::shared::MaybeAccount;
+    use solana_client::rpc_client::RpcClient;
+
+    /// Security token client running preflight compliance checks
+    pub struct SecurityTokenClient<'a> {
+        rpc: &'a RpcClient,
+        reasons: RejectionReasons,
+    }
+
+    impl<'a> SecurityTokenClient<'a> {
+        pub fn new(rpc: &'a RpcClient) -> Self {
+            Self {
+                rpc,
+                reasons: RejectionReasons::default(),
+            }
+        }
+
+        /// Reasons shown for the error codes of the verification programs
+        pub fn with_rejection_reasons(mut self, reasons: RejectionReasons) -> Self {
+            self.reasons = reasons;
+            self
+        }
+
+        /// Simulates a holder transfer of `amount` from `source` to `destination`
+        pub fn simulate_transfer(
+            &self,
+            source: &Pubkey,
+            mint: &Pubkey,
+            destination: &Pubkey,
+            owner: &Pubkey,
+            amount: u64,
+            decimals: u8,
+        ) -> Result<(), ComplianceFailure> {
+            let transfer =
+                transfer_checked_instruction(source, mint, destination, owner, amount, decimals);
+            self.simulate(vec![transfer], owner)
+        }
+
+        /// Simulates `instructions` paid by `payer`, once their missing verification and
+        /// transfer hook accounts are resolved
+        pub fn simulate(
+            &self,
+            instructions: Vec<Instruction>,
+            payer: &Pubkey,
+        ) -> Result<(), ComplianceFailure> {
+            let configs = verification_config_addresses(&instructions);
+            let (error, logs) = match resolve_missing_accounts(self.rpc, instructions, payer) {
+                Ok(_) => return Ok(()),
+                Err(ResolutionError::Unresolved { error, logs }) => (error, logs),
+                Err(err) => return Err(ComplianceFailure::Preflight(err.to_string())),
+            };
+
+            let mut verification_programs = Vec::new();
+            for config in configs {
+                if let MaybeAccount::Exists(config) =
+                    VerificationConfig::fetch_maybe(self.rpc, &config)
+                        .map_err(|err| ComplianceFailure::Preflight(err.to_string()))?
+                {
+                    verification_programs.extend(config.data.verification_programs);
+                }
+            }
+            Err(classify_failure(
+                error,
+                logs,
+                &verification_programs,
+                &self.reasons,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_error(code: u32) -> TransactionError {
+        TransactionError::InstructionError(0, InstructionError::Custom(code))
+    }
+
+    fn failure_logs(program: &Pubkey, code: u32) -> Vec<String> {
+        vec![
+            format!("Program {TOKEN_2022_PROGRAM_ID} invoke [1]"),
+            format!("Program {program} invoke [2]"),
+            format!("Program {program} failed: custom program error: {code:#x}"),
+            format!("Program {TOKEN_2022_PROGRAM_ID} failed: custom program error: {code:#x}"),
+        ]
+    }
+
+    #[test]
+    fn test_classify_verification_rejection() {
+        let kyc = Pubkey::new_unique();
+        let reasons = RejectionReasons::new().with(kyc, 0x10, "KYC missing");
+
+        let failure = classify_failure(
+            custom_error(0x10),
+            failure_logs(&kyc, 0x10),
+            &[kyc],
+            &reasons,
+        );
+        assert_eq!(
+            failure,
+            ComplianceFailure::Rejected {
+                program: kyc,
+                code: Some(0x10),
+                reason: Some("KYC missing".to_string()),
+            }
+        );
+        assert_eq!(
+            failure.to_string(),
+            format!("Rejected by verification program {kyc}: KYC missing")
+        );
+
+        let unknown = classify_failure(
+            custom_error(0x11),
+            failure_logs(&kyc, 0x11),
+            &[kyc],
+            &reasons,
+        );
+        assert_eq!(
+            unknown.to_string(),
+            format!("Rejected by verification program {kyc}: error 0x11")
+        );
+    }
+
+    #[test]
+    fn test_classify_program_and_other_failures() {
+        let reasons = RejectionReasons::new();
+        let code = SecurityTokenProgramError::AccountSuspended as u32;
+        assert_eq!(
+            classify_failure(
+                custom_error(code),
+                failure_logs(&TRANSFER_HOOK_PROGRAM_ID, code),
+                &[],
+                &reasons
+            ),
+            ComplianceFailure::Refused(SecurityTokenProgramError::AccountSuspended)
+        );
+
+        // Programs outside the verification configs are not verification rejections
+        let other = Pubkey::new_unique();
+        let logs = failure_logs(&other, 1);
+        assert_eq!(
+            classify_failure(custom_error(1), logs.clone(), &[], &reasons),
+            ComplianceFailure::Failed {
+                error: custom_error(1),
+                logs,
+            }
+        );
+    }
+
+    #[test]
+    fn test_verification_config_addresses() {
+        let mint = Pubkey::new_unique();
+        let (source, destination, owner) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let transfer = transfer_checked_instruction(&source, &mint, &destination, &owner, 5, 6);
+        assert_eq!(
+            transfer.data,
+            [
+                TOKEN_TRANSFER_CHECKED_DISCRIMINATOR,
+                5,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                6
+            ]
+        );
+        assert!(transfer.accounts[3].is_signer);
+
+        let burn = Instruction {
+            program_id: SECURITY_TOKEN_PROGRAM_ID,
+            accounts: vec![AccountMeta::new_readonly(mint, false)],
+            data: vec![7],
+        };
+        assert_eq!(
+            verification_config_addresses(&[transfer.clone(), burn, transfer]),
+            vec![
+                find_verification_config_pda(&mint, TRANSFER_DISCRIMINATOR).0,
+                find_verification_config_pda(&mint, 7).0,
+            ]
+        );
+    }
+
+    #[cfg(feature = "rpc")]
+    #[test]
+    fn test_simulate_transfer_passes_when_simulation_succeeds() {
+        let rpc = solana_client::rpc_client::RpcClient::new_mock("succeeds".to_string());
+        let client = SecurityTokenClient::new(&rpc);
+        let (source, mint, destination, owner) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        assert_eq!(
+            client.simulate_transfer(&source, &mint, &destination, &owner, 10, 6),
+            Ok(())
+        );
+    }
+}
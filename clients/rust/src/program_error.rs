@@ -0,0 +1,98 @@
+//! Typed program errors of failed transactions.
+//!
+//! A failing security token instruction surfaces as `InstructionError::Custom(code)`.
+//! [`SecurityTokenProgramError::from_transaction_error`] maps it back to the typed error,
+//! using the transaction logs to tell it apart from the custom errors of other programs
+//! failing in the same transaction (verification programs, Token-2022).
+
+use crate::errors::SecurityTokenProgramError;
+use crate::logs::failed_program;
+use crate::pda::TRANSFER_HOOK_PROGRAM_ID;
+use crate::programs::SECURITY_TOKEN_PROGRAM_ID;
+use num_traits::FromPrimitive;
+use solana_instruction::error::InstructionError;
+use solana_transaction_error::TransactionError;
+
+impl SecurityTokenProgramError {
+    /// Program error a transaction failed with, `None` when it failed otherwise
+    ///
+    /// The transfer hook fails with the same error codes. Without a failure in the `logs`
+    /// a custom error is attributed to the security token program.
+    pub fn from_transaction_error(error: &TransactionError, logs: &[String]) -> Option<Self> {
+        let TransactionError::InstructionError(_, InstructionError::Custom(code)) = error else {
+            return None;
+        };
+        match failed_program(logs) {
+            Some(program)
+                if program != SECURITY_TOKEN_PROGRAM_ID && program != TRANSFER_HOOK_PROGRAM_ID =>
+            {
+                None
+            }
+            _ => Self::from_u32(*code),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_pubkey::Pubkey;
+
+    fn failure_logs(program: &Pubkey, code: u32) -> Vec<String> {
+        vec![
+            format!("Program {SECURITY_TOKEN_PROGRAM_ID} invoke [1]"),
+            format!("Program {program} failed: custom program error: {code:#x}"),
+        ]
+    }
+
+    #[test]
+    fn test_from_transaction_error() {
+        let code = SecurityTokenProgramError::InvalidVerificationConfigPda as u32;
+        let error = TransactionError::InstructionError(1, InstructionError::Custom(code));
+
+        for program in [SECURITY_TOKEN_PROGRAM_ID, TRANSFER_HOOK_PROGRAM_ID] {
+            assert_eq!(
+                SecurityTokenProgramError::from_transaction_error(
+                    &error,
+                    &failure_logs(&program, code)
+                ),
+                Some(SecurityTokenProgramError::InvalidVerificationConfigPda)
+            );
+        }
+        assert_eq!(
+            SecurityTokenProgramError::from_transaction_error(&error, &[]),
+            Some(SecurityTokenProgramError::InvalidVerificationConfigPda)
+        );
+
+        // Custom error of a verification program
+        let verifier = Pubkey::new_unique();
+        assert_eq!(
+            SecurityTokenProgramError::from_transaction_error(
+                &error,
+                &failure_logs(&verifier, code)
+            ),
+            None
+        );
+        assert_eq!(
+            SecurityTokenProgramError::from_transaction_error(
+                &TransactionError::InstructionError(0, InstructionError::Custom(u32::MAX)),
+                &[]
+            ),
+            None
+        );
+        assert_eq!(
+            SecurityTokenProgramError::from_transaction_error(
+                &TransactionError::InstructionError(0, InstructionError::MissingAccount),
+                &[]
+            ),
+            None
+        );
+        assert_eq!(
+            SecurityTokenProgramError::from_transaction_error(
+                &TransactionError::AccountNotFound,
+                &[]
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! Portable JSON proof file for distributions.
+//!
+//! Lets the party building a distribution tree (e.g. a transfer agent) hand the
+//! root, leaves and proofs to another party (e.g. a claim portal) without sharing
+//! Rust code. The schema is documented in `docs/proof-file-format.md`:
+//! - field names are camelCase
+//! - pubkeys are base58 strings
+//! - hashes (root, leaves, proof nodes) are lowercase hex strings
+//! - amounts and action ids are decimal strings, since u64 does not fit JSON numbers
+
+use crate::merkle::{
+    create_merkle_tree_leaf_node, verify_merkle_proof, DistributionLeaf, DistributionTree,
+    MerkleTreeNode, MerkleTreeRoot,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::{hex::Hex, serde_as, DisplayFromStr};
+use solana_pubkey::Pubkey;
+use thiserror::Error;
+
+/// Current proof file format version
+pub const PROOF_FILE_VERSION: u32 = 1;
+
+/// Proof file errors
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum ProofFileError {
+    #[error("Unsupported proof file version {0}")]
+    UnsupportedVersion(u32),
+    #[error("Claim {0} has an unexpected leaf index")]
+    LeafIndexMismatch(usize),
+    #[error("Leaf of claim {0} does not match its fields")]
+    LeafMismatch(usize),
+    #[error("Proof of claim {0} does not verify against the root")]
+    InvalidProof(usize),
+    #[error("Merkle root does not match the leaves")]
+    RootMismatch,
+}
+
+/// Single claim entry of a proof file
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofFileClaim {
+    pub leaf_index: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub eligible_token_account: Pubkey,
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: u64,
+    #[serde_as(as = "Hex")]
+    pub leaf: MerkleTreeNode,
+    #[serde_as(as = "Vec<Hex>")]
+    pub proof: Vec<MerkleTreeNode>,
+}
+
+/// Distribution proof file
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DistributionProofFile {
+    pub version: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub mint: Pubkey,
+    #[serde_as(as = "DisplayFromStr")]
+    pub action_id: u64,
+    #[serde_as(as = "Hex")]
+    pub merkle_root: MerkleTreeRoot,
+    pub claims: Vec<ProofFileClaim>,
+}
+
+impl DistributionProofFile {
+    /// Exports the root, leaves and proofs of `tree`
+    pub fn from_tree(tree: &DistributionTree) -> Self {
+        Self {
+            version: PROOF_FILE_VERSION,
+            mint: tree.mint,
+            action_id: tree.action_id,
+            merkle_root: tree.root(),
+            claims: tree
+                .leaves
+                .iter()
+                .enumerate()
+                .map(|(idx, leaf)| ProofFileClaim {
+                    leaf_index: idx as u32,
+                    eligible_token_account: leaf.eligible_token_account,
+                    amount: leaf.amount,
+                    leaf: tree.leaf_node(idx),
+                    proof: tree.proof(idx),
+                })
+                .collect(),
+        }
+    }
+
+    /// Checks every claim: leaf hash matches its fields and the proof verifies
+    /// against the root exactly as the program does.
+    pub fn verify(&self) -> Result<(), ProofFileError> {
+        if self.version != PROOF_FILE_VERSION {
+            return Err(ProofFileError::UnsupportedVersion(self.version));
+        }
+        for (idx, claim) in self.claims.iter().enumerate() {
+            let leaf = create_merkle_tree_leaf_node(
+                &claim.eligible_token_account,
+                &self.mint,
+                self.action_id,
+                claim.amount,
+            );
+            if leaf != claim.leaf {
+                return Err(ProofFileError::LeafMismatch(idx));
+            }
+            if !verify_merkle_proof(&leaf, &self.merkle_root, &claim.proof, claim.leaf_index) {
+                return Err(ProofFileError::InvalidProof(idx));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rebuilds the tree from a complete proof file (claims of every leaf, in leaf order)
+    pub fn to_tree(&self) -> Result<DistributionTree, ProofFileError> {
+        self.verify()?;
+        let leaves = self
+            .claims
+            .iter()
+            .enumerate()
+            .map(|(idx, claim)| {
+                if claim.leaf_index as usize != idx {
+                    return Err(ProofFileError::LeafIndexMismatch(idx));
+                }
+                Ok(DistributionLeaf::new(
+                    claim.eligible_token_account,
+                    claim.amount,
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let tree = DistributionTree::new(self.mint, self.action_id, leaves);
+        if tree.root() != self.merkle_root {
+            return Err(ProofFileError::RootMismatch);
+        }
+        Ok(tree)
+    }
+
+    /// Claim of `eligible_token_account`, if present
+    pub fn claim_for(&self, eligible_token_account: &Pubkey) -> Option<&ProofFileClaim> {
+        self.claims
+            .iter()
+            .find(|claim| claim.eligible_token_account == *eligible_token_account)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use security_token_program::merkle_tree_utils;
+
+    fn tree(holders: u64) -> DistributionTree {
+        DistributionTree::new(
+            Pubkey::new_unique(),
+            42,
+            (1..=holders)
+                .map(|amount| DistributionLeaf::new(Pubkey::new_unique(), amount * 1_000))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_proof_file_round_trip_verifies_on_chain() {
+        for holders in [1, 2, 7, 16, 33] {
+            let tree = tree(holders);
+            let json = serde_json::to_string(&DistributionProofFile::from_tree(&tree)).unwrap();
+            let file: DistributionProofFile = serde_json::from_str(&json).unwrap();
+
+            assert!(file.verify().is_ok());
+            assert_eq!(file.to_tree().unwrap().root(), tree.root());
+            for claim in &file.claims {
+                let node = merkle_tree_utils::create_merkle_tree_leaf_node(
+                    &claim.eligible_token_account.to_bytes(),
+                    &file.mint.to_bytes(),
+                    file.action_id,
+                    claim.amount,
+                );
+                assert_eq!(node, claim.leaf);
+                assert!(merkle_tree_utils::verify_merkle_proof(
+                    &node,
+                    &file.merkle_root,
+                    &claim.proof,
+                    claim.leaf_index
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_proof_file_schema() {
+        let tree = tree(2);
+        let value = serde_json::to_value(DistributionProofFile::from_tree(&tree)).unwrap();
+
+        assert_eq!(value["version"], 1);
+        assert_eq!(value["mint"], tree.mint.to_string());
+        assert_eq!(value["actionId"], "42");
+        assert_eq!(value["merkleRoot"].as_str().unwrap().len(), 64);
+        assert_eq!(value["claims"][1]["leafIndex"], 1);
+        assert_eq!(value["claims"][1]["amount"], "2000");
+        assert_eq!(value["claims"][1]["proof"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_proof_file_rejects_tampering() {
+        let mut file = DistributionProofFile::from_tree(&tree(4));
+        file.claims[2].amount += 1;
+        assert_eq!(file.verify(), Err(ProofFileError::LeafMismatch(2)));
+
+        let mut file = DistributionProofFile::from_tree(&tree(4));
+        file.claims[1].proof.swap(0, 1);
+        assert_eq!(file.verify(), Err(ProofFileError::InvalidProof(1)));
+
+        let mut file = DistributionProofFile::from_tree(&tree(4));
+        file.version = 2;
+        assert_eq!(file.verify(), Err(ProofFileError::UnsupportedVersion(2)));
+    }
+}
@@ -0,0 +1,203 @@
+//! WebSocket subscriptions to program accounts.
+//!
+//! The subscriptions wrap `accountSubscribe` and `programSubscribe` of a nonblocking
+//! [`PubsubClient`] and stream the accounts decoded like [`ProgramAccount::fetch_maybe`]:
+//! a closed account is streamed as `MaybeAccount::NotFound`. Every subscription comes with
+//! the function ending it.
+//!
+//! Receipts don't store their mint, so [`subscribe_receipts_for_mint`] keeps the split and
+//! convert receipts at the PDAs of the given action ids.
+
+use crate::account::{AccountError, DecodedAccount, MaybeAccount, ProgramAccount, Receipt};
+use crate::accounts::{MintAuthority, VerificationConfig};
+use crate::pda::{find_receipt_pda, find_verification_config_pda};
+use crate::programs::SECURITY_TOKEN_PROGRAM_ID;
+use futures_util::future::BoxFuture;
+use futures_util::stream::{BoxStream, StreamExt};
+use solana_account::Account;
+use solana_account_decoder_client_types::{UiAccount, UiAccountEncoding};
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::RpcFilterType;
+use solana_pubkey::Pubkey;
+use solana_sdk::commitment_config::CommitmentConfig;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+/// Decoded account updates of a subscription
+pub type AccountStream<'a, T> = BoxStream<'a, Result<MaybeAccount<T>, AccountError>>;
+
+/// Ends a subscription
+pub type Unsubscribe = Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send>;
+
+/// Decodes an account notification, closed accounts are `NotFound`
+pub fn decode_notification<T: ProgramAccount>(
+    address: &Pubkey,
+    account: &UiAccount,
+) -> Result<MaybeAccount<T>, AccountError> {
+    let account: Account = account.decode().ok_or_else(|| AccountError::InvalidData {
+        address: *address,
+        reason: "unsupported account encoding".to_string(),
+    })?;
+    if account.lamports == 0 {
+        return Ok(MaybeAccount::NotFound(*address));
+    }
+    let data = T::decode(address, &account.owner, &account.data)?;
+    Ok(MaybeAccount::Exists(DecodedAccount {
+        address: *address,
+        account,
+        data,
+    }))
+}
+
+fn account_config(commitment: CommitmentConfig) -> RpcAccountInfoConfig {
+    RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        commitment: Some(commitment),
+        ..RpcAccountInfoConfig::default()
+    }
+}
+
+/// Streams the account of type `T` at `address`
+pub async fn subscribe_account<'a, T: ProgramAccount + Send + 'a>(
+    client: &'a PubsubClient,
+    address: &Pubkey,
+    commitment: CommitmentConfig,
+) -> Result<(AccountStream<'a, T>, Unsubscribe), AccountError> {
+    let (stream, unsubscribe) = client
+        .account_subscribe(address, Some(account_config(commitment)))
+        .await
+        .map_err(|err| AccountError::Rpc(err.to_string()))?;
+    let address = *address;
+    let stream = stream
+        .map(move |response| decode_notification(&address, &response.value))
+        .boxed();
+    Ok((stream, unsubscribe))
+}
+
+/// Streams the program accounts of type `T` matching `filters` whose address passes `keep`
+pub async fn subscribe_program_accounts<'a, T: ProgramAccount + Send + 'a>(
+    client: &'a PubsubClient,
+    filters: Vec<RpcFilterType>,
+    commitment: CommitmentConfig,
+    keep: impl Fn(&Pubkey) -> bool + Send + 'a,
+) -> Result<(AccountStream<'a, T>, Unsubscribe), AccountError> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: account_config(commitment),
+        ..RpcProgramAccountsConfig::default()
+    };
+    let (stream, unsubscribe) = client
+        .program_subscribe(&SECURITY_TOKEN_PROGRAM_ID, Some(config))
+        .await
+        .map_err(|err| AccountError::Rpc(err.to_string()))?;
+    let stream = stream
+        .filter_map(move |response| {
+            let keyed = response.value;
+            let update = match Pubkey::from_str(&keyed.pubkey) {
+                Ok(address) if keep(&address) => {
+                    Some(decode_notification(&address, &keyed.account))
+                }
+                Ok(_) => None,
+                Err(err) => Some(Err(AccountError::Rpc(err.to_string()))),
+            };
+            futures_util::future::ready(update)
+        })
+        .boxed();
+    Ok((stream, unsubscribe))
+}
+
+/// Streams the verification config of `instruction_discriminator` of `mint`
+pub async fn subscribe_verification_config<'a>(
+    client: &'a PubsubClient,
+    mint: &Pubkey,
+    instruction_discriminator: u8,
+    commitment: CommitmentConfig,
+) -> Result<(AccountStream<'a, VerificationConfig>, Unsubscribe), AccountError> {
+    let (config_pda, _) = find_verification_config_pda(mint, instruction_discriminator);
+    subscribe_account(client, &config_pda, commitment).await
+}
+
+/// Streams the mint authority of `mint`, found by its mint since its PDA needs the creator
+pub async fn subscribe_mint_authority<'a>(
+    client: &'a PubsubClient,
+    mint: &Pubkey,
+    commitment: CommitmentConfig,
+) -> Result<(AccountStream<'a, MintAuthority>, Unsubscribe), AccountError> {
+    subscribe_program_accounts(
+        client,
+        MintAuthority::gpa_filters_for_mint(mint),
+        commitment,
+        |_| true,
+    )
+    .await
+}
+
+/// Streams the split and convert receipts of `action_ids` of `mint`
+pub async fn subscribe_receipts_for_mint<'a>(
+    client: &'a PubsubClient,
+    mint: &Pubkey,
+    action_ids: &[u64],
+    commitment: CommitmentConfig,
+) -> Result<(AccountStream<'a, Receipt>, Unsubscribe), AccountError> {
+    let receipts: HashSet<Pubkey> = action_ids
+        .iter()
+        .map(|action_id| find_receipt_pda(mint, *action_id).0)
+        .collect();
+    subscribe_program_accounts(
+        client,
+        Receipt::gpa_filters_for_actions(),
+        commitment,
+        move |address| receipts.contains(address),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::prelude::{Engine, BASE64_STANDARD};
+    use solana_account_decoder_client_types::UiAccountData;
+
+    fn ui_account(lamports: u64, owner: &Pubkey, data: &[u8]) -> UiAccount {
+        UiAccount {
+            lamports,
+            data: UiAccountData::Binary(BASE64_STANDARD.encode(data), UiAccountEncoding::Base64),
+            owner: owner.to_string(),
+            executable: false,
+            rent_epoch: 0,
+            space: Some(data.len() as u64),
+        }
+    }
+
+    #[test]
+    fn test_decode_notification() {
+        let address = Pubkey::new_unique();
+        let mint_authority = MintAuthority {
+            discriminator: MintAuthority::DISCRIMINATOR,
+            mint: Pubkey::new_unique(),
+            mint_creator: Pubkey::new_unique(),
+            bump: 255,
+        };
+        let data = borsh::to_vec(&mint_authority).unwrap();
+
+        let update = decode_notification::<MintAuthority>(
+            &address,
+            &ui_account(1_000_000, &SECURITY_TOKEN_PROGRAM_ID, &data),
+        )
+        .unwrap();
+        assert!(matches!(update, MaybeAccount::Exists(account) if account.data == mint_authority));
+
+        // Closed account
+        let update =
+            decode_notification::<MintAuthority>(&address, &ui_account(0, &Pubkey::default(), &[]))
+                .unwrap();
+        assert!(matches!(update, MaybeAccount::NotFound(closed) if closed == address));
+
+        let owner = Pubkey::new_unique();
+        assert_eq!(
+            decode_notification::<MintAuthority>(&address, &ui_account(1, &owner, &data)).err(),
+            Some(AccountError::InvalidOwner { address, owner })
+        );
+    }
+}
@@ -0,0 +1,363 @@
+//! Rate math of Split and Convert.
+//!
+//! Mirrors the program's `Rate::calculate` and `Rate::convert_from_to_amount` so UIs can
+//! preview the outcome of a Split or a Convert exactly: same intermediate widths, same
+//! rounding, same decimal scaling and the same rejections, including the zero output a
+//! `Rounding::Down` conversion of a small amount produces.
+
+use crate::accounts::Rate;
+use crate::types::Rounding;
+use solana_pubkey::Pubkey;
+use thiserror::Error;
+
+/// Rate math errors
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum RateError {
+    #[error("Arithmetic overflow")]
+    Overflow,
+    #[error("Oracle value {value} is outside {min}..={max}")]
+    OracleValueOutOfBounds { value: u64, min: u64, max: u64 },
+    #[error("Oracle account data does not hold a value at offset {0}")]
+    InvalidOracleData(u32),
+    #[error("Rate reads an oracle but no oracle data was given")]
+    MissingOracleData,
+    #[error("Token account balance is zero")]
+    EmptyBalance,
+    #[error("Conversion results in a zero amount")]
+    ZeroOutput,
+}
+
+impl Rate {
+    /// Whether the conversion ratio is read from a price oracle
+    pub fn has_oracle(&self) -> bool {
+        self.oracle != Pubkey::default()
+    }
+
+    /// Reads the price from the oracle account data
+    pub fn oracle_value(&self, oracle_data: &[u8]) -> Result<u64, RateError> {
+        let start = self.oracle_value_offset as usize;
+        oracle_data
+            .get(start..start + 8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(RateError::InvalidOracleData(self.oracle_value_offset))
+    }
+
+    /// Rate applied to `amount`, the balance a Split leaves
+    pub fn calculate(&self, amount: u64) -> Result<u64, RateError> {
+        let scaled = amount
+            .checked_mul(u64::from(self.numerator))
+            .ok_or(RateError::Overflow)?;
+        let denominator = u64::from(self.denominator);
+        match self.rounding {
+            Rounding::Up => Ok(scaled.div_ceil(denominator)),
+            Rounding::Down => scaled.checked_div(denominator).ok_or(RateError::Overflow),
+        }
+    }
+
+    /// Amount of the destination mint a Convert of `amount_from` mints at the static ratio
+    pub fn convert_from_to_amount(
+        &self,
+        amount_from: u64,
+        decimals_from: u8,
+        decimals_to: u8,
+    ) -> Result<u64, RateError> {
+        self.convert_at_ratio(
+            amount_from,
+            decimals_from,
+            decimals_to,
+            u128::from(self.numerator),
+            u128::from(self.denominator),
+        )
+    }
+
+    /// Amount of the destination mint a Convert of `amount_from` mints at the oracle price of
+    /// one source token, the price must be within the oracle bounds of the rate
+    pub fn convert_from_to_amount_at_oracle_value(
+        &self,
+        amount_from: u64,
+        decimals_from: u8,
+        decimals_to: u8,
+        oracle_value: u64,
+    ) -> Result<u64, RateError> {
+        if oracle_value < self.oracle_min_value || oracle_value > self.oracle_max_value {
+            return Err(RateError::OracleValueOutOfBounds {
+                value: oracle_value,
+                min: self.oracle_min_value,
+                max: self.oracle_max_value,
+            });
+        }
+        let oracle_scale = 10u128
+            .checked_pow(u32::from(self.oracle_value_decimals))
+            .ok_or(RateError::Overflow)?;
+        self.convert_at_ratio(
+            amount_from,
+            decimals_from,
+            decimals_to,
+            u128::from(oracle_value),
+            oracle_scale,
+        )
+    }
+
+    fn convert_at_ratio(
+        &self,
+        amount_from: u64,
+        decimals_from: u8,
+        decimals_to: u8,
+        numerator: u128,
+        denominator: u128,
+    ) -> Result<u64, RateError> {
+        if amount_from == 0 {
+            return Ok(0);
+        }
+
+        // The program scales in u64 before widening
+        let scale = |delta: u8| {
+            10u64
+                .checked_pow(u32::from(delta))
+                .map(u128::from)
+                .ok_or(RateError::Overflow)
+        };
+        let (numerator, denominator) = if decimals_to >= decimals_from {
+            let scale = scale(decimals_to - decimals_from)?;
+            let numerator = u128::from(amount_from)
+                .checked_mul(numerator)
+                .and_then(|value| value.checked_mul(scale))
+                .ok_or(RateError::Overflow)?;
+            (numerator, denominator)
+        } else {
+            let denominator = denominator
+                .checked_mul(scale(decimals_from - decimals_to)?)
+                .ok_or(RateError::Overflow)?;
+            let numerator = u128::from(amount_from)
+                .checked_mul(numerator)
+                .ok_or(RateError::Overflow)?;
+            (numerator, denominator)
+        };
+
+        let result = match self.rounding {
+            Rounding::Down => numerator
+                .checked_div(denominator)
+                .ok_or(RateError::Overflow)?,
+            Rounding::Up => numerator.div_ceil(denominator),
+        };
+        u64::try_from(result).map_err(|_| RateError::Overflow)
+    }
+}
+
+/// Balance a Split of `balance` at `rate` leaves, minting or burning the difference
+pub fn preview_split(rate: &Rate, balance: u64) -> Result<u64, RateError> {
+    if balance == 0 {
+        return Err(RateError::EmptyBalance);
+    }
+    rate.calculate(balance)
+}
+
+/// Amount a Convert of `amount_from` at `rate` mints, `oracle_data` is the data of the rate
+/// oracle account when the rate has one
+pub fn preview_convert(
+    rate: &Rate,
+    amount_from: u64,
+    decimals_from: u8,
+    decimals_to: u8,
+    oracle_data: Option<&[u8]>,
+) -> Result<u64, RateError> {
+    let amount_to = if rate.has_oracle() {
+        let oracle_value = rate.oracle_value(oracle_data.ok_or(RateError::MissingOracleData)?)?;
+        rate.convert_from_to_amount_at_oracle_value(
+            amount_from,
+            decimals_from,
+            decimals_to,
+            oracle_value,
+        )?
+    } else {
+        rate.convert_from_to_amount(amount_from, decimals_from, decimals_to)?
+    };
+    // The program rejects conversions minting nothing
+    if amount_to == 0 {
+        return Err(RateError::ZeroOutput);
+    }
+    Ok(amount_to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use security_token_program::state as program;
+
+    const AMOUNTS: [u64; 9] = [
+        0,
+        1,
+        2,
+        3,
+        999,
+        1_000_000,
+        123_456_789,
+        u64::MAX / 255,
+        u64::MAX,
+    ];
+    const RATIOS: [(u8, u8); 6] = [(1, 1), (1, 3), (2, 3), (3, 2), (255, 1), (1, 255)];
+    const DECIMALS: [(u8, u8); 6] = [(6, 6), (0, 9), (9, 0), (6, 9), (9, 6), (0, 20)];
+
+    fn rate(rounding: Rounding, numerator: u8, denominator: u8) -> Rate {
+        Rate {
+            discriminator: 2,
+            rounding,
+            numerator,
+            denominator,
+            bump: 255,
+            oracle: Pubkey::default(),
+            oracle_value_offset: 0,
+            oracle_value_decimals: 0,
+            oracle_min_value: 0,
+            oracle_max_value: 0,
+        }
+    }
+
+    fn program_rate(rounding: Rounding, numerator: u8, denominator: u8) -> program::Rate {
+        let rounding = match rounding {
+            Rounding::Up => program::Rounding::Up,
+            Rounding::Down => program::Rounding::Down,
+        };
+        program::Rate::new(rounding, numerator, denominator, 255).unwrap()
+    }
+
+    #[test]
+    fn test_rate_math_matches_program() {
+        for rounding in [Rounding::Up, Rounding::Down] {
+            for (numerator, denominator) in RATIOS {
+                let client = rate(rounding, numerator, denominator);
+                let program = program_rate(rounding, numerator, denominator);
+                for amount in AMOUNTS {
+                    assert_eq!(
+                        client.calculate(amount).ok(),
+                        program.calculate(amount).ok(),
+                        "calculate({amount}) at {numerator}/{denominator} {rounding:?}"
+                    );
+                    for (decimals_from, decimals_to) in DECIMALS {
+                        assert_eq!(
+                            client
+                                .convert_from_to_amount(amount, decimals_from, decimals_to)
+                                .ok(),
+                            program
+                                .convert_from_to_amount(amount, decimals_from, decimals_to)
+                                .ok(),
+                            "convert({amount}, {decimals_from}, {decimals_to}) at \
+                             {numerator}/{denominator} {rounding:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_oracle_rate_math_matches_program() {
+        let oracle = Pubkey::new_unique();
+        for rounding in [Rounding::Up, Rounding::Down] {
+            let mut client = rate(rounding, 1, 1);
+            client.oracle = oracle;
+            client.oracle_value_offset = 4;
+            client.oracle_value_decimals = 8;
+            client.oracle_min_value = 1;
+            client.oracle_max_value = 1_000_000_000_000;
+            let mut program = program_rate(rounding, 1, 1);
+            program
+                .set_oracle(oracle.to_bytes(), 4, 8, 1, 1_000_000_000_000)
+                .unwrap();
+
+            for oracle_value in [0, 1, 150_000_000, 1_000_000_000_000, u64::MAX] {
+                for amount in AMOUNTS {
+                    for (decimals_from, decimals_to) in DECIMALS {
+                        assert_eq!(
+                            client
+                                .convert_from_to_amount_at_oracle_value(
+                                    amount,
+                                    decimals_from,
+                                    decimals_to,
+                                    oracle_value
+                                )
+                                .ok(),
+                            program
+                                .convert_from_to_amount_at_oracle_value(
+                                    amount,
+                                    decimals_from,
+                                    decimals_to,
+                                    oracle_value
+                                )
+                                .ok(),
+                            "convert({amount}, {decimals_from}, {decimals_to}) at \
+                             {oracle_value} {rounding:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_preview_convert() {
+        // 1 base unit at 1/3 across equal decimals rounds down to nothing
+        let down = rate(Rounding::Down, 1, 3);
+        assert_eq!(
+            preview_convert(&down, 1, 6, 6, None),
+            Err(RateError::ZeroOutput)
+        );
+        assert_eq!(preview_convert(&down, 3, 6, 6, None), Ok(1));
+        assert_eq!(
+            preview_convert(&rate(Rounding::Up, 1, 3), 1, 6, 6, None),
+            Ok(1)
+        );
+        // Dropping decimals
+        assert_eq!(
+            preview_convert(&down, 2_999, 9, 6, None),
+            Err(RateError::ZeroOutput)
+        );
+        assert_eq!(preview_convert(&down, 3_000, 9, 6, None), Ok(1));
+
+        let mut oracle_rate = rate(Rounding::Down, 1, 1);
+        oracle_rate.oracle = Pubkey::new_unique();
+        oracle_rate.oracle_value_offset = 8;
+        oracle_rate.oracle_value_decimals = 2;
+        oracle_rate.oracle_min_value = 1;
+        oracle_rate.oracle_max_value = 10_000;
+        let mut oracle_data = vec![0; 16];
+        oracle_data[8..].copy_from_slice(&250u64.to_le_bytes());
+        // 2.50 per token
+        assert_eq!(
+            preview_convert(&oracle_rate, 10, 0, 0, Some(&oracle_data)),
+            Ok(25)
+        );
+        assert_eq!(
+            preview_convert(&oracle_rate, 10, 0, 0, None),
+            Err(RateError::MissingOracleData)
+        );
+        assert_eq!(
+            preview_convert(&oracle_rate, 10, 0, 0, Some(&oracle_data[..12])),
+            Err(RateError::InvalidOracleData(8))
+        );
+        oracle_data[8..].copy_from_slice(&20_000u64.to_le_bytes());
+        assert_eq!(
+            preview_convert(&oracle_rate, 10, 0, 0, Some(&oracle_data)),
+            Err(RateError::OracleValueOutOfBounds {
+                value: 20_000,
+                min: 1,
+                max: 10_000
+            })
+        );
+    }
+
+    #[test]
+    fn test_preview_split() {
+        assert_eq!(preview_split(&rate(Rounding::Down, 3, 2), 101), Ok(151));
+        assert_eq!(preview_split(&rate(Rounding::Up, 3, 2), 101), Ok(152));
+        assert_eq!(
+            preview_split(&rate(Rounding::Down, 1, 3), 0),
+            Err(RateError::EmptyBalance)
+        );
+        assert_eq!(
+            preview_split(&rate(Rounding::Down, 2, 1), u64::MAX),
+            Err(RateError::Overflow)
+        );
+    }
+}
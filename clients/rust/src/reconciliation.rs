@@ -0,0 +1,432 @@
+//! Escrow reconciliation for distributions.
+//!
+//! Cross-references the merkle leaves of a distribution with the claim receipts or claim
+//! bitmap pages on-chain and the balance of the distribution escrow token account.
+//! Receipts can be closed after a claim to reclaim rent, which makes a claimed leaf
+//! look unclaimed; such leaves surface as an escrow shortfall in the report.
+
+use crate::accounts::ClaimBitmap;
+use crate::claim_tracking::{claim_bitmap_page, is_leaf_claimed, ClaimTracking};
+use crate::discriminators::SecurityTokenDiscriminators;
+use crate::merkle::{DistributionLeaf, DistributionTree, MerkleTreeRoot};
+use crate::pda::{find_associated_token_address, find_distribution_escrow_authority_pda};
+use solana_pubkey::Pubkey;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Receipt account discriminator
+pub const RECEIPT_DISCRIMINATOR: u8 = SecurityTokenDiscriminators::ReceiptDiscriminator as u8;
+
+/// Reconciliation errors
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum ReconciliationError {
+    #[error("RPC error: {0}")]
+    Rpc(String),
+}
+
+/// Claim status of a single leaf
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeafStatus {
+    pub leaf_index: usize,
+    pub leaf: DistributionLeaf,
+    /// Receipt or claim bitmap page tracking the claim of the leaf
+    pub receipt: Pubkey,
+    pub claimed: bool,
+}
+
+/// Inconsistency found while reconciling a distribution
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Discrepancy {
+    /// Escrow holds less than the sum of unclaimed leaves
+    EscrowShortfall { expected: u64, actual: u64 },
+    /// Escrow holds more than the sum of unclaimed leaves
+    EscrowSurplus { expected: u64, actual: u64 },
+    /// Escrow token account does not exist while leaves are still unclaimed
+    /// (expected for distributions settled externally)
+    EscrowMissing { escrow: Pubkey, unclaimed: u64 },
+    /// The same token account appears in more than one leaf
+    DuplicateTokenAccount {
+        eligible_token_account: Pubkey,
+        leaf_indexes: Vec<usize>,
+    },
+    /// Receipt address exists but is not a valid Receipt account
+    InvalidReceipt { leaf_index: usize, receipt: Pubkey },
+}
+
+/// Reconciliation report of a distribution
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReconciliationReport {
+    pub mint: Pubkey,
+    pub action_id: u64,
+    pub merkle_root: MerkleTreeRoot,
+    pub tracking: ClaimTracking,
+    pub escrow_token_account: Pubkey,
+    /// Escrow balance, `None` if the escrow token account does not exist
+    pub escrow_balance: Option<u64>,
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub unclaimed_amount: u64,
+    pub leaves: Vec<LeafStatus>,
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+impl ReconciliationReport {
+    pub fn claimed(&self) -> impl Iterator<Item = &LeafStatus> {
+        self.leaves.iter().filter(|status| status.claimed)
+    }
+
+    pub fn unclaimed(&self) -> impl Iterator<Item = &LeafStatus> {
+        self.leaves.iter().filter(|status| !status.claimed)
+    }
+
+    pub fn is_balanced(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+/// State of a receipt address as observed on-chain
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReceiptState {
+    Missing,
+    Issued,
+    Invalid,
+}
+
+/// Receipt PDAs of every leaf, in leaf order
+pub fn receipt_addresses(tree: &DistributionTree) -> Vec<Pubkey> {
+    tracking_addresses(tree, ClaimTracking::Receipt)
+}
+
+/// Accounts tracking the claim of every leaf, in leaf order
+pub fn tracking_addresses(tree: &DistributionTree, tracking: ClaimTracking) -> Vec<Pubkey> {
+    (0..tree.leaves.len())
+        .map(|idx| tracking.tracking_account(tree, idx))
+        .collect()
+}
+
+/// Claim state of every leaf read from the claim bitmap pages, in leaf order.
+/// `pages` are in page order (see [`ClaimTracking::claim_bitmap_pages`]), leaves of a
+/// missing page are reported as invalid.
+pub fn claim_bitmap_states(
+    tree: &DistributionTree,
+    pages: &[Option<ClaimBitmap>],
+) -> Vec<ReceiptState> {
+    (0..tree.leaves.len())
+        .map(|idx| {
+            match pages
+                .get(claim_bitmap_page(idx) as usize)
+                .and_then(Option::as_ref)
+            {
+                None => ReceiptState::Invalid,
+                Some(page) if is_leaf_claimed(page, idx) => ReceiptState::Issued,
+                Some(_) => ReceiptState::Missing,
+            }
+        })
+        .collect()
+}
+
+/// Distribution escrow token account (ATA of the escrow authority PDA)
+pub fn escrow_token_account(tree: &DistributionTree) -> Pubkey {
+    let (authority, _) =
+        find_distribution_escrow_authority_pda(&tree.mint, tree.action_id, &tree.root());
+    find_associated_token_address(&authority, &tree.mint).0
+}
+
+/// Builds a reconciliation report from already fetched on-chain state.
+///
+/// `receipts` must be in leaf order (see [`tracking_addresses`] and [`claim_bitmap_states`]).
+pub fn build_reconciliation_report(
+    tree: &DistributionTree,
+    tracking: ClaimTracking,
+    receipts: &[ReceiptState],
+    escrow_balance: Option<u64>,
+) -> ReconciliationReport {
+    assert_eq!(
+        receipts.len(),
+        tree.leaves.len(),
+        "one receipt state per leaf expected"
+    );
+    let escrow = escrow_token_account(tree);
+    let mut discrepancies = Vec::new();
+    let mut leaves = Vec::with_capacity(tree.leaves.len());
+    let mut total_amount = 0u64;
+    let mut claimed_amount = 0u64;
+    let mut by_token_account: HashMap<Pubkey, Vec<usize>> = HashMap::new();
+
+    for ((leaf_index, (leaf, receipt)), state) in tree
+        .leaves
+        .iter()
+        .zip(tracking_addresses(tree, tracking))
+        .enumerate()
+        .zip(receipts)
+    {
+        let claimed = *state == ReceiptState::Issued;
+        if *state == ReceiptState::Invalid {
+            discrepancies.push(Discrepancy::InvalidReceipt {
+                leaf_index,
+                receipt,
+            });
+        }
+        total_amount = total_amount.saturating_add(leaf.amount);
+        if claimed {
+            claimed_amount = claimed_amount.saturating_add(leaf.amount);
+        }
+        by_token_account
+            .entry(leaf.eligible_token_account)
+            .or_default()
+            .push(leaf_index);
+        leaves.push(LeafStatus {
+            leaf_index,
+            leaf: leaf.clone(),
+            receipt,
+            claimed,
+        });
+    }
+
+    let mut duplicates: Vec<_> = by_token_account
+        .into_iter()
+        .filter(|(_, indexes)| indexes.len() > 1)
+        .collect();
+    duplicates.sort_by_key(|(_, indexes)| indexes[0]);
+    discrepancies.extend(
+        duplicates
+            .into_iter()
+            .map(
+                |(eligible_token_account, leaf_indexes)| Discrepancy::DuplicateTokenAccount {
+                    eligible_token_account,
+                    leaf_indexes,
+                },
+            ),
+    );
+
+    let unclaimed_amount = total_amount.saturating_sub(claimed_amount);
+    match escrow_balance {
+        None if unclaimed_amount > 0 => discrepancies.push(Discrepancy::EscrowMissing {
+            escrow,
+            unclaimed: unclaimed_amount,
+        }),
+        Some(actual) if actual < unclaimed_amount => {
+            discrepancies.push(Discrepancy::EscrowShortfall {
+                expected: unclaimed_amount,
+                actual,
+            })
+        }
+        Some(actual) if actual > unclaimed_amount => {
+            discrepancies.push(Discrepancy::EscrowSurplus {
+                expected: unclaimed_amount,
+                actual,
+            })
+        }
+        _ => {}
+    }
+
+    ReconciliationReport {
+        mint: tree.mint,
+        action_id: tree.action_id,
+        merkle_root: tree.root(),
+        tracking,
+        escrow_token_account: escrow,
+        escrow_balance,
+        total_amount,
+        claimed_amount,
+        unclaimed_amount,
+        leaves,
+        discrepancies,
+    }
+}
+
+/// Fetches the claim bitmap pages or receipts and the escrow balance and builds the
+/// reconciliation report. Distributions whose first claim bitmap page exists are reconciled
+/// against their claim bitmaps.
+#[cfg(feature = "rpc")]
+pub fn fetch_reconciliation_report(
+    rpc: &solana_client::rpc_client::RpcClient,
+    tree: &DistributionTree,
+) -> Result<ReconciliationReport, ReconciliationError> {
+    use crate::programs::SECURITY_TOKEN_PROGRAM_ID;
+
+    // getMultipleAccounts accepts at most 100 keys per request
+    const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+    // Token account layout: mint (32) + owner (32) + amount (8)
+    const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+
+    let fetch_accounts = |addresses: &[Pubkey]| {
+        let mut accounts = Vec::with_capacity(addresses.len());
+        for chunk in addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            accounts.extend(
+                rpc.get_multiple_accounts(chunk)
+                    .map_err(|err| ReconciliationError::Rpc(err.to_string()))?,
+            );
+        }
+        Ok::<_, ReconciliationError>(accounts)
+    };
+
+    let claim_bitmaps: Vec<_> = fetch_accounts(&ClaimTracking::Bitmap.claim_bitmap_pages(tree))?
+        .into_iter()
+        .map(|account| {
+            account
+                .filter(|account| account.owner == SECURITY_TOKEN_PROGRAM_ID)
+                .and_then(|account| ClaimBitmap::from_bytes(&account.data).ok())
+        })
+        .collect();
+    let (tracking, receipts) = match claim_bitmaps.first() {
+        Some(Some(_)) => (
+            ClaimTracking::Bitmap,
+            claim_bitmap_states(tree, &claim_bitmaps),
+        ),
+        _ => (
+            ClaimTracking::Receipt,
+            fetch_accounts(&receipt_addresses(tree))?
+                .into_iter()
+                .map(|account| match account {
+                    None => ReceiptState::Missing,
+                    Some(account)
+                        if account.owner == SECURITY_TOKEN_PROGRAM_ID
+                            && account.data == [RECEIPT_DISCRIMINATOR] =>
+                    {
+                        ReceiptState::Issued
+                    }
+                    Some(_) => ReceiptState::Invalid,
+                })
+                .collect(),
+        ),
+    };
+
+    let escrow = escrow_token_account(tree);
+    let escrow_balance = rpc
+        .get_account_with_commitment(&escrow, rpc.commitment())
+        .map_err(|err| ReconciliationError::Rpc(err.to_string()))?
+        .value
+        .and_then(|account| {
+            account
+                .data
+                .get(TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8)
+                .map(|amount| u64::from_le_bytes(amount.try_into().unwrap()))
+        });
+
+    Ok(build_reconciliation_report(
+        tree,
+        tracking,
+        &receipts,
+        escrow_balance,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> DistributionTree {
+        DistributionTree::new(
+            Pubkey::new_unique(),
+            7,
+            vec![
+                DistributionLeaf::new(Pubkey::new_unique(), 100),
+                DistributionLeaf::new(Pubkey::new_unique(), 250),
+                DistributionLeaf::new(Pubkey::new_unique(), 50),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_reconciliation_balanced() {
+        let tree = tree();
+        let receipts = [
+            ReceiptState::Issued,
+            ReceiptState::Missing,
+            ReceiptState::Missing,
+        ];
+        let report =
+            build_reconciliation_report(&tree, ClaimTracking::Receipt, &receipts, Some(300));
+
+        assert_eq!(report.total_amount, 400);
+        assert_eq!(report.claimed_amount, 100);
+        assert_eq!(report.unclaimed_amount, 300);
+        assert_eq!(report.claimed().count(), 1);
+        assert_eq!(report.unclaimed().count(), 2);
+        assert!(report.is_balanced());
+    }
+
+    #[test]
+    fn test_reconciliation_discrepancies() {
+        let mut tree = tree();
+        let duplicate = tree.leaves[0].eligible_token_account;
+        tree = DistributionTree::new(
+            tree.mint,
+            tree.action_id,
+            [
+                tree.leaves.clone(),
+                vec![DistributionLeaf::new(duplicate, 10)],
+            ]
+            .concat(),
+        );
+        let receipts = [
+            ReceiptState::Missing,
+            ReceiptState::Invalid,
+            ReceiptState::Issued,
+            ReceiptState::Missing,
+        ];
+        let report =
+            build_reconciliation_report(&tree, ClaimTracking::Receipt, &receipts, Some(100));
+
+        assert_eq!(
+            report.discrepancies,
+            vec![
+                Discrepancy::InvalidReceipt {
+                    leaf_index: 1,
+                    receipt: report.leaves[1].receipt,
+                },
+                Discrepancy::DuplicateTokenAccount {
+                    eligible_token_account: duplicate,
+                    leaf_indexes: vec![0, 3],
+                },
+                Discrepancy::EscrowShortfall {
+                    expected: 360,
+                    actual: 100,
+                },
+            ]
+        );
+
+        let report = build_reconciliation_report(&tree, ClaimTracking::Receipt, &receipts, None);
+        assert!(report.discrepancies.contains(&Discrepancy::EscrowMissing {
+            escrow: report.escrow_token_account,
+            unclaimed: 360,
+        }));
+    }
+
+    #[test]
+    fn test_reconciliation_with_claim_bitmap() {
+        let tree = tree();
+        let pages = ClaimTracking::Bitmap.claim_bitmap_pages(&tree);
+        let page = ClaimBitmap {
+            discriminator: 35,
+            mint: tree.mint,
+            action_id: tree.action_id,
+            merkle_root: tree.root(),
+            page: 0,
+            leaf_count: 3,
+            bump: 255,
+            bits: vec![0b0000_0010],
+        };
+        let receipts = claim_bitmap_states(&tree, &[Some(page)]);
+        assert_eq!(
+            receipts,
+            vec![
+                ReceiptState::Missing,
+                ReceiptState::Issued,
+                ReceiptState::Missing,
+            ]
+        );
+
+        let report =
+            build_reconciliation_report(&tree, ClaimTracking::Bitmap, &receipts, Some(150));
+        assert_eq!(report.claimed_amount, 250);
+        assert_eq!(report.leaves[1].receipt, pages[0]);
+        assert!(report.is_balanced());
+
+        assert_eq!(
+            claim_bitmap_states(&tree, &[None]),
+            vec![ReceiptState::Invalid; 3]
+        );
+    }
+}
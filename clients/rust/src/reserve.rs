@@ -0,0 +1,313 @@
+//! Proof of reserve for custodians of security tokens.
+//!
+//! A custodian publishes a ReserveAttestation binding the balances of its omnibus token
+//! accounts at a slot to the hash of its off-chain liabilities. The liability hash is the
+//! root of a merkle tree over the customer balances, so every customer can check the
+//! inclusion of its balance with [`LiabilityTree::proof`] and [`verify_merkle_proof`].
+//!
+//! [`verify_reserve_attestation`] cross-references an attestation with the current
+//! balances of the omnibus token accounts and the liabilities it commits to.
+//!
+//! [`verify_merkle_proof`]: crate::merkle::verify_merkle_proof
+
+use crate::accounts::ReserveAttestation;
+use crate::merkle::{MerkleTreeNode, MerkleTreeRoot};
+use solana_keccak_hasher::hashv;
+use solana_pubkey::Pubkey;
+use spl_merkle_tree_reference::MerkleTree;
+
+/// Balance a custodian owes to one of its customers
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Liability {
+    /// Customer identifier (e.g. hash of the exchange account id and a salt)
+    pub customer_id: [u8; 32],
+    /// Raw amount owed to the customer
+    pub amount: u64,
+}
+
+impl Liability {
+    pub fn new(customer_id: [u8; 32], amount: u64) -> Self {
+        Self {
+            customer_id,
+            amount,
+        }
+    }
+}
+
+/// Hashed liability leaf: `keccak(customer_id || mint || amount_le)`
+pub fn create_liability_leaf_node(
+    customer_id: &[u8; 32],
+    mint: &Pubkey,
+    amount: u64,
+) -> MerkleTreeNode {
+    hashv(&[customer_id, mint.as_ref(), &amount.to_le_bytes()]).to_bytes()
+}
+
+/// Merkle tree of the liabilities of a custodian for one mint
+pub struct LiabilityTree {
+    pub mint: Pubkey,
+    pub liabilities: Vec<Liability>,
+    tree: MerkleTree,
+}
+
+impl LiabilityTree {
+    pub fn new(mint: Pubkey, liabilities: Vec<Liability>) -> Self {
+        let nodes: Vec<MerkleTreeNode> = liabilities
+            .iter()
+            .map(|liability| {
+                create_liability_leaf_node(&liability.customer_id, &mint, liability.amount)
+            })
+            .collect();
+        let tree = MerkleTree::new(&nodes);
+        Self {
+            mint,
+            liabilities,
+            tree,
+        }
+    }
+
+    /// Liability hash to publish with PublishReserveAttestation
+    pub fn root(&self) -> MerkleTreeRoot {
+        self.tree.get_root()
+    }
+
+    /// Sum of the liabilities, `None` on overflow
+    pub fn total(&self) -> Option<u64> {
+        self.liabilities
+            .iter()
+            .try_fold(0u64, |total, liability| total.checked_add(liability.amount))
+    }
+
+    /// Hashed leaf node at `leaf_index`
+    pub fn leaf_node(&self, leaf_index: usize) -> MerkleTreeNode {
+        let liability = &self.liabilities[leaf_index];
+        create_liability_leaf_node(&liability.customer_id, &self.mint, liability.amount)
+    }
+
+    /// Inclusion proof of the liability at `leaf_index`
+    pub fn proof(&self, leaf_index: usize) -> Vec<MerkleTreeNode> {
+        self.tree.get_proof_of_leaf(leaf_index)
+    }
+}
+
+/// Inconsistency found while verifying a reserve attestation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReserveDiscrepancy {
+    /// Attestation does not list the same number of balances and token accounts
+    MalformedAttestation,
+    /// Attested total does not match the sum of the attested balances
+    TotalMismatch { attested: u64, sum: Option<u64> },
+    /// Omnibus token account balance changed since the attestation
+    BalanceChanged {
+        token_account: Pubkey,
+        attested: u64,
+        current: u64,
+    },
+    /// Omnibus token account no longer exists
+    AccountMissing { token_account: Pubkey },
+    /// Liabilities do not hash to the attested liability hash
+    LiabilityHashMismatch {
+        attested: MerkleTreeRoot,
+        computed: MerkleTreeRoot,
+    },
+    /// Attested reserves do not cover the liabilities
+    Undercollateralized { reserves: u64, liabilities: u64 },
+}
+
+/// Verification report of a reserve attestation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReserveReport {
+    pub mint: Pubkey,
+    pub custodian: Pubkey,
+    pub slot: u64,
+    pub reserves: u64,
+    /// Sum of the liabilities, `None` when verified without liabilities or on overflow
+    pub liabilities: Option<u64>,
+    pub discrepancies: Vec<ReserveDiscrepancy>,
+}
+
+impl ReserveReport {
+    pub fn is_verified(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+/// Builds a verification report of `attestation` from already fetched on-chain state.
+///
+/// `current_balances` are the balances of the attested token accounts in attestation
+/// order, `None` for accounts that no longer exist. A changed balance shows the
+/// attestation is stale rather than wrong. Without `liabilities` only the reserves are
+/// verified.
+pub fn verify_reserve_attestation(
+    attestation: &ReserveAttestation,
+    current_balances: &[Option<u64>],
+    liabilities: Option<&LiabilityTree>,
+) -> ReserveReport {
+    let mut discrepancies = Vec::new();
+
+    if attestation.balances.len() != attestation.token_accounts.len()
+        || current_balances.len() != attestation.token_accounts.len()
+    {
+        discrepancies.push(ReserveDiscrepancy::MalformedAttestation);
+    }
+
+    let sum = attestation
+        .balances
+        .iter()
+        .try_fold(0u64, |total, balance| total.checked_add(*balance));
+    if sum != Some(attestation.total_balance) {
+        discrepancies.push(ReserveDiscrepancy::TotalMismatch {
+            attested: attestation.total_balance,
+            sum,
+        });
+    }
+
+    for ((token_account, attested), current) in attestation
+        .token_accounts
+        .iter()
+        .zip(&attestation.balances)
+        .zip(current_balances)
+    {
+        match current {
+            None => discrepancies.push(ReserveDiscrepancy::AccountMissing {
+                token_account: *token_account,
+            }),
+            Some(current) if current != attested => {
+                discrepancies.push(ReserveDiscrepancy::BalanceChanged {
+                    token_account: *token_account,
+                    attested: *attested,
+                    current: *current,
+                })
+            }
+            Some(_) => {}
+        }
+    }
+
+    let total_liabilities = liabilities.and_then(LiabilityTree::total);
+    if let Some(tree) = liabilities {
+        let computed = tree.root();
+        if computed != attestation.liability_hash {
+            discrepancies.push(ReserveDiscrepancy::LiabilityHashMismatch {
+                attested: attestation.liability_hash,
+                computed,
+            });
+        }
+        match total_liabilities {
+            Some(total) if total <= attestation.total_balance => {}
+            total => discrepancies.push(ReserveDiscrepancy::Undercollateralized {
+                reserves: attestation.total_balance,
+                liabilities: total.unwrap_or(u64::MAX),
+            }),
+        }
+    }
+
+    ReserveReport {
+        mint: attestation.mint,
+        custodian: attestation.custodian,
+        slot: attestation.slot,
+        reserves: attestation.total_balance,
+        liabilities: total_liabilities,
+        discrepancies,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle::verify_merkle_proof;
+
+    fn attestation(liability_hash: MerkleTreeRoot) -> ReserveAttestation {
+        ReserveAttestation {
+            discriminator: 41,
+            mint: Pubkey::new_from_array([1; 32]),
+            custodian: Pubkey::new_from_array([2; 32]),
+            liability_hash,
+            total_balance: 1_500,
+            slot: 10,
+            unix_timestamp: 0,
+            bump: 255,
+            token_accounts: vec![
+                Pubkey::new_from_array([3; 32]),
+                Pubkey::new_from_array([4; 32]),
+            ],
+            balances: vec![1_000, 500],
+        }
+    }
+
+    fn liabilities(amounts: &[u64]) -> LiabilityTree {
+        LiabilityTree::new(
+            Pubkey::new_from_array([1; 32]),
+            amounts
+                .iter()
+                .enumerate()
+                .map(|(index, amount)| Liability::new([index as u8; 32], *amount))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_liability_proofs_verify_against_root() {
+        let tree = liabilities(&[100, 200, 300]);
+        assert_eq!(tree.total(), Some(600));
+        for index in 0..3 {
+            assert!(verify_merkle_proof(
+                &tree.leaf_node(index),
+                &tree.root(),
+                &tree.proof(index),
+                index as u32
+            ));
+        }
+    }
+
+    #[test]
+    fn test_verify_reserve_attestation() {
+        let tree = liabilities(&[1_000, 400]);
+        let report = verify_reserve_attestation(
+            &attestation(tree.root()),
+            &[Some(1_000), Some(500)],
+            Some(&tree),
+        );
+        assert!(report.is_verified());
+        assert_eq!(report.reserves, 1_500);
+        assert_eq!(report.liabilities, Some(1_400));
+
+        let report =
+            verify_reserve_attestation(&attestation(tree.root()), &[Some(900), None], None);
+        assert_eq!(
+            report.discrepancies,
+            vec![
+                ReserveDiscrepancy::BalanceChanged {
+                    token_account: Pubkey::new_from_array([3; 32]),
+                    attested: 1_000,
+                    current: 900,
+                },
+                ReserveDiscrepancy::AccountMissing {
+                    token_account: Pubkey::new_from_array([4; 32]),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_reserve_attestation_liabilities() {
+        let tree = liabilities(&[1_000, 600]);
+        let report = verify_reserve_attestation(
+            &attestation([9; 32]),
+            &[Some(1_000), Some(500)],
+            Some(&tree),
+        );
+        assert_eq!(
+            report.discrepancies,
+            vec![
+                ReserveDiscrepancy::LiabilityHashMismatch {
+                    attested: [9; 32],
+                    computed: tree.root(),
+                },
+                ReserveDiscrepancy::Undercollateralized {
+                    reserves: 1_500,
+                    liabilities: 1_600,
+                },
+            ]
+        );
+    }
+}
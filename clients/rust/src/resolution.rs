@@ -0,0 +1,443 @@
+//! Simulation-driven missing account resolution.
+//!
+//! Integrators often build a draft transaction without verification or transfer hook
+//! accounts and discover what is missing by trial and error. [`diagnose_missing_accounts`]
+//! maps a simulation failure to the accounts the failing instruction lacks:
+//! - haltable security token instructions without the ProgramConfig PDA get it appended,
+//!   see [`crate::halt`]
+//! - security token instructions failing verification get their verification bundle
+//!   (CPI programs or introspection instructions, see [`crate::verification`])
+//! - Token-2022 `TransferChecked` of a security token gets the transfer hook extra
+//!   accounts: verification config, verification programs, position limit accounts
+//!   when the mint has a position limit, hook program and the extra account metas PDA
+//!
+//! `resolve_missing_accounts` (feature `rpc`) simulates, resolves and retries until
+//! the simulation succeeds or the failure is not about missing accounts.
+
+use crate::errors::SecurityTokenProgramError;
+use crate::halt::{has_program_config, is_haltable};
+use crate::identity::MAX_IDENTITY_WALLETS;
+use crate::instructions::TRANSFER_DISCRIMINATOR;
+use crate::pda::{
+    find_extra_account_metas_pda, find_identity_wallet_pda, find_position_limit_pda,
+    find_verification_config_pda, TOKEN_2022_PROGRAM_ID, TRANSFER_HOOK_PROGRAM_ID,
+};
+use crate::programs::SECURITY_TOKEN_PROGRAM_ID;
+use solana_instruction::error::InstructionError;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use solana_transaction_error::TransactionError;
+
+/// Token-2022 `TransferChecked` instruction discriminator
+pub const TOKEN_TRANSFER_CHECKED_DISCRIMINATOR: u8 = 12;
+
+/// Accounts an instruction is missing
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MissingAccounts {
+    /// Trailing ProgramConfig PDA of a haltable security token instruction
+    ProgramConfig,
+    /// Verification programs or introspection instructions of a security token instruction
+    Verification { mint: Pubkey },
+    /// Transfer hook extra accounts of a Token-2022 transfer
+    TransferHook { mint: Pubkey },
+}
+
+/// Failing instruction and the accounts it is missing
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Diagnosis {
+    pub instruction_index: usize,
+    pub missing: MissingAccounts,
+}
+
+fn is_verification_error(error: &InstructionError) -> bool {
+    const VERIFICATION_ERRORS: [SecurityTokenProgramError; 4] = [
+        SecurityTokenProgramError::VerificationProgramNotFound,
+        SecurityTokenProgramError::NotEnoughAccountsForVerification,
+        SecurityTokenProgramError::AccountIntersectionMismatch,
+        SecurityTokenProgramError::InvalidVerificationConfigPda,
+    ];
+    match error {
+        InstructionError::NotEnoughAccountKeys | InstructionError::MissingAccount => true,
+        InstructionError::Custom(code) => VERIFICATION_ERRORS
+            .iter()
+            .any(|verification_error| verification_error.clone() as u32 == *code),
+        _ => false,
+    }
+}
+
+fn is_transfer_checked(instruction: &Instruction) -> bool {
+    instruction.program_id == TOKEN_2022_PROGRAM_ID
+        && instruction.data.first() == Some(&TOKEN_TRANSFER_CHECKED_DISCRIMINATOR)
+        && instruction.accounts.len() >= 4
+}
+
+/// Maps a simulation failure to the accounts the failing instruction is missing.
+///
+/// Returns `None` if the failure is not caused by missing accounts.
+pub fn diagnose_missing_accounts(
+    instructions: &[Instruction],
+    error: &TransactionError,
+) -> Option<Diagnosis> {
+    let TransactionError::InstructionError(index, instruction_error) = error else {
+        return None;
+    };
+    let instruction_index = *index as usize;
+    let instruction = instructions.get(instruction_index)?;
+
+    if is_haltable(instruction)
+        && !has_program_config(instruction)
+        && *instruction_error
+            == InstructionError::Custom(SecurityTokenProgramError::ProgramConfigRequired as u32)
+    {
+        return Some(Diagnosis {
+            instruction_index,
+            missing: MissingAccounts::ProgramConfig,
+        });
+    }
+    if instruction.program_id == SECURITY_TOKEN_PROGRAM_ID
+        && is_verification_error(instruction_error)
+    {
+        let mint = instruction.accounts.first()?.pubkey;
+        return Some(Diagnosis {
+            instruction_index,
+            missing: MissingAccounts::Verification { mint },
+        });
+    }
+    // Any failure of a hooked transfer without the hook program is a resolution failure
+    if is_transfer_checked(instruction)
+        && !instruction
+            .accounts
+            .iter()
+            .any(|meta| meta.pubkey == TRANSFER_HOOK_PROGRAM_ID)
+    {
+        return Some(Diagnosis {
+            instruction_index,
+            missing: MissingAccounts::TransferHook {
+                mint: instruction.accounts[1].pubkey,
+            },
+        });
+    }
+    None
+}
+
+/// Transfer hook extra accounts in the order Token-2022 expects them: resolved extra
+/// accounts (verification config and programs), hook program, extra account metas PDA
+pub fn transfer_hook_accounts(mint: &Pubkey, verification_programs: &[Pubkey]) -> Vec<AccountMeta> {
+    let (config_pda, _) = find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+    let (account_metas_pda, _) = find_extra_account_metas_pda(mint);
+    std::iter::once(config_pda)
+        .chain(verification_programs.iter().copied())
+        .chain([TRANSFER_HOOK_PROGRAM_ID, account_metas_pda])
+        .map(|pubkey| AccountMeta::new_readonly(pubkey, false))
+        .collect()
+}
+
+/// Position limit accounts resolved by the transfer hook for `destination`: security token
+/// program, position limit PDA, identity wallet PDA, identity and every identity wallet slot.
+/// They go right after the verification programs of [`transfer_hook_accounts`].
+pub fn position_limit_accounts(
+    mint: &Pubkey,
+    destination: &Pubkey,
+    identity: &Pubkey,
+    wallets: &[Pubkey],
+) -> Vec<AccountMeta> {
+    let (position_limit_pda, _) = find_position_limit_pda(mint);
+    let (identity_wallet_pda, _) = find_identity_wallet_pda(destination);
+    // Unused slots are zeroed in the identity account
+    let slots =
+        (0..MAX_IDENTITY_WALLETS).map(|slot| wallets.get(slot).copied().unwrap_or_default());
+    [
+        SECURITY_TOKEN_PROGRAM_ID,
+        position_limit_pda,
+        identity_wallet_pda,
+        *identity,
+    ]
+    .into_iter()
+    .chain(slots)
+    .map(|pubkey| AccountMeta::new_readonly(pubkey, false))
+    .collect()
+}
+
+#[cfg(feature = "rpc")]
+pub use self::send::*;
+
+#[cfg(feature = "rpc")]
+mod send {
+    use super::*;
+    use crate::accounts::{
+        fetch_identity, fetch_maybe_identity_wallet, fetch_maybe_position_limit,
+        fetch_maybe_verification_config,
+    };
+    use crate::halt::with_program_config;
+    use crate::shared::MaybeAccount;
+    use crate::verification::{fetch_and_prepare_verified_instructions, VerificationError};
+    use solana_client::rpc_client::RpcClient;
+    use solana_client::rpc_config::RpcSimulateTransactionConfig;
+    use solana_sdk::transaction::Transaction;
+    use thiserror::Error;
+
+    /// Resolution errors
+    #[derive(Clone, Debug, Error, PartialEq, Eq)]
+    pub enum ResolutionError {
+        #[error("RPC error: {0}")]
+        Rpc(String),
+        #[error("Simulation failed: {error}")]
+        Unresolved {
+            error: TransactionError,
+            logs: Vec<String>,
+        },
+        #[error("Accounts of instruction {0} could not be resolved: {1}")]
+        Verification(usize, VerificationError),
+        #[error("Mint {0} has no transfer verification config")]
+        TransferConfigNotFound(Pubkey),
+        #[error("Token account {0} is not linked to an identity required by the position limit")]
+        IdentityNotFound(Pubkey),
+    }
+
+    /// Simulates `instructions` and adds missing verification and transfer hook
+    /// accounts until the simulation succeeds. Each instruction is resolved at most once.
+    ///
+    /// Returns the resolved instructions, ready to be signed and sent.
+    pub fn resolve_missing_accounts(
+        rpc: &RpcClient,
+        instructions: Vec<Instruction>,
+        payer: &Pubkey,
+    ) -> Result<Vec<Instruction>, ResolutionError> {
+        let mut instructions = instructions;
+        // Instructions already resolved (or added by a resolution)
+        let mut resolved = vec![false; instructions.len()];
+
+        loop {
+            let transaction = Transaction::new_with_payer(&instructions, Some(payer));
+            let simulation = rpc
+                .simulate_transaction_with_config(
+                    &transaction,
+                    RpcSimulateTransactionConfig {
+                        sig_verify: false,
+                        replace_recent_blockhash: true,
+                        ..RpcSimulateTransactionConfig::default()
+                    },
+                )
+                .map_err(|err| ResolutionError::Rpc(err.to_string()))?
+                .value;
+            let Some(error) = simulation.err else {
+                return Ok(instructions);
+            };
+            let unresolved = || ResolutionError::Unresolved {
+                error: error.clone(),
+                logs: simulation.logs.clone().unwrap_or_default(),
+            };
+
+            let diagnosis =
+                diagnose_missing_accounts(&instructions, &error).ok_or_else(unresolved)?;
+            let index = diagnosis.instruction_index;
+            if resolved[index] {
+                return Err(unresolved());
+            }
+            match diagnosis.missing {
+                MissingAccounts::ProgramConfig => {
+                    instructions[index] = with_program_config(instructions[index].clone());
+                }
+                MissingAccounts::Verification { mint } => {
+                    let bundle = fetch_and_prepare_verified_instructions(
+                        rpc,
+                        instructions[index].clone(),
+                        &mint,
+                    )
+                    .map_err(|err| ResolutionError::Verification(index, err))?;
+                    let added = bundle.len();
+                    instructions.splice(index..=index, bundle);
+                    resolved.splice(index..=index, std::iter::repeat_n(true, added));
+                }
+                MissingAccounts::TransferHook { mint } => {
+                    let (config_pda, _) =
+                        find_verification_config_pda(&mint, TRANSFER_DISCRIMINATOR);
+                    let config = match fetch_maybe_verification_config(rpc, &config_pda)
+                        .map_err(|err| ResolutionError::Rpc(err.to_string()))?
+                    {
+                        MaybeAccount::Exists(config) => config.data,
+                        MaybeAccount::NotFound(_) => {
+                            return Err(ResolutionError::TransferConfigNotFound(mint))
+                        }
+                    };
+                    let destination = instructions[index].accounts[2].pubkey;
+                    let mut accounts = transfer_hook_accounts(&mint, &config.verification_programs);
+                    let limit_accounts = fetch_position_limit_accounts(rpc, &mint, &destination)?;
+                    let programs_end = 1 + config.verification_programs.len();
+                    accounts.splice(programs_end..programs_end, limit_accounts);
+                    instructions[index].accounts.extend(accounts);
+                    resolved[index] = true;
+                }
+            }
+        }
+    }
+
+    /// Position limit accounts of a transfer to `destination`, empty without a position limit
+    fn fetch_position_limit_accounts(
+        rpc: &RpcClient,
+        mint: &Pubkey,
+        destination: &Pubkey,
+    ) -> Result<Vec<AccountMeta>, ResolutionError> {
+        let rpc_error = |err: std::io::Error| ResolutionError::Rpc(err.to_string());
+        let (position_limit_pda, _) = find_position_limit_pda(mint);
+        if let MaybeAccount::NotFound(_) =
+            fetch_maybe_position_limit(rpc, &position_limit_pda).map_err(rpc_error)?
+        {
+            return Ok(Vec::new());
+        }
+        let (identity_wallet_pda, _) = find_identity_wallet_pda(destination);
+        let identity =
+            match fetch_maybe_identity_wallet(rpc, &identity_wallet_pda).map_err(rpc_error)? {
+                MaybeAccount::Exists(identity_wallet) => identity_wallet.data.identity,
+                MaybeAccount::NotFound(_) => {
+                    return Err(ResolutionError::IdentityNotFound(*destination))
+                }
+            };
+        let wallets = fetch_identity(rpc, &identity)
+            .map_err(rpc_error)?
+            .data
+            .wallets;
+        Ok(position_limit_accounts(
+            mint,
+            destination,
+            &identity,
+            &wallets,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer_checked(mint: &Pubkey) -> Instruction {
+        Instruction {
+            program_id: TOKEN_2022_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(Pubkey::new_unique(), false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new(Pubkey::new_unique(), false),
+                AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            ],
+            data: vec![
+                TOKEN_TRANSFER_CHECKED_DISCRIMINATOR,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                6,
+            ],
+        }
+    }
+
+    #[test]
+    fn test_diagnose_missing_accounts() {
+        let mint = Pubkey::new_unique();
+        let operation = Instruction {
+            program_id: SECURITY_TOKEN_PROGRAM_ID,
+            accounts: vec![AccountMeta::new_readonly(mint, false)],
+            data: vec![6],
+        };
+        let instructions = vec![operation, transfer_checked(&mint)];
+
+        let not_enough = TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                SecurityTokenProgramError::NotEnoughAccountsForVerification as u32,
+            ),
+        );
+        assert_eq!(
+            diagnose_missing_accounts(&instructions, &not_enough),
+            Some(Diagnosis {
+                instruction_index: 0,
+                missing: MissingAccounts::Verification { mint },
+            })
+        );
+
+        let without_program_config = TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SecurityTokenProgramError::ProgramConfigRequired as u32),
+        );
+        assert_eq!(
+            diagnose_missing_accounts(&instructions, &without_program_config),
+            Some(Diagnosis {
+                instruction_index: 0,
+                missing: MissingAccounts::ProgramConfig,
+            })
+        );
+        let mut with_config = instructions.clone();
+        with_config[0] = crate::halt::with_program_config(with_config[0].clone());
+        assert_eq!(
+            diagnose_missing_accounts(&with_config, &without_program_config),
+            None
+        );
+
+        let hook_failure =
+            TransactionError::InstructionError(1, InstructionError::IncorrectProgramId);
+        assert_eq!(
+            diagnose_missing_accounts(&instructions, &hook_failure),
+            Some(Diagnosis {
+                instruction_index: 1,
+                missing: MissingAccounts::TransferHook { mint },
+            })
+        );
+
+        let mut hooked = instructions.clone();
+        hooked[1]
+            .accounts
+            .extend(transfer_hook_accounts(&mint, &[]));
+        assert_eq!(diagnose_missing_accounts(&hooked, &hook_failure), None);
+
+        let insufficient_funds =
+            TransactionError::InstructionError(0, InstructionError::InsufficientFunds);
+        assert_eq!(
+            diagnose_missing_accounts(&instructions, &insufficient_funds),
+            None
+        );
+        assert_eq!(
+            diagnose_missing_accounts(&instructions, &TransactionError::AccountNotFound),
+            None
+        );
+    }
+
+    #[test]
+    fn test_position_limit_accounts() {
+        let mint = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let identity = Pubkey::new_unique();
+        let wallets = [destination, Pubkey::new_unique()];
+        let accounts = position_limit_accounts(&mint, &destination, &identity, &wallets);
+
+        assert_eq!(accounts.len(), 4 + MAX_IDENTITY_WALLETS);
+        assert_eq!(accounts[0].pubkey, SECURITY_TOKEN_PROGRAM_ID);
+        assert_eq!(accounts[1].pubkey, find_position_limit_pda(&mint).0);
+        assert_eq!(accounts[2].pubkey, find_identity_wallet_pda(&destination).0);
+        assert_eq!(accounts[3].pubkey, identity);
+        assert_eq!(accounts[4].pubkey, destination);
+        assert_eq!(accounts[5].pubkey, wallets[1]);
+        assert_eq!(accounts[6].pubkey, Pubkey::default());
+    }
+
+    #[test]
+    fn test_transfer_hook_accounts() {
+        let mint = Pubkey::new_unique();
+        let programs = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let accounts = transfer_hook_accounts(&mint, &programs);
+
+        assert_eq!(accounts.len(), programs.len() + 3);
+        assert_eq!(
+            accounts[0].pubkey,
+            find_verification_config_pda(&mint, TRANSFER_DISCRIMINATOR).0
+        );
+        assert_eq!(accounts[1].pubkey, programs[0]);
+        assert_eq!(accounts[3].pubkey, TRANSFER_HOOK_PROGRAM_ID);
+        assert_eq!(accounts[4].pubkey, find_extra_account_metas_pda(&mint).0);
+        assert!(accounts
+            .iter()
+            .all(|meta| !meta.is_signer && !meta.is_writable));
+    }
+}
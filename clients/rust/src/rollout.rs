@@ -0,0 +1,415 @@
+//! Fleet-wide verification config rollout.
+//!
+//! Applies one desired verification config to many mints. For every mint the
+//! current config is compared with the desired one and the minimal set of
+//! Initialize/Update/Trim instructions is generated. Instructions are authorized
+//! through the mint authority PDA, so `creator` must be the mint creator of every mint.
+//! Configs are rolled out without a timelock, updates of timelocked configs are staged
+//! and need ApplyPendingConfig once the delay has elapsed.
+
+use crate::accounts::VerificationConfig;
+use crate::halt::with_program_config;
+use crate::instructions::{
+    InitializeVerificationConfigBuilder, TrimVerificationConfigBuilder,
+    UpdateVerificationConfigBuilder, TRANSFER_DISCRIMINATOR,
+};
+use crate::pda::{
+    find_extra_account_metas_pda, find_mint_authority_pda, find_transfer_hook_pda,
+    find_verification_config_pda, TRANSFER_HOOK_PROGRAM_ID,
+};
+use crate::types::{
+    InitializeVerificationConfigArgs, TrimVerificationConfigArgs, UpdateVerificationConfigArgs,
+};
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+/// Config every mint of the fleet should end up with
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DesiredVerificationConfig {
+    pub instruction_discriminator: u8,
+    pub cpi_mode: bool,
+    pub program_addresses: Vec<Pubkey>,
+    /// Number of programs that must pass, 0 requires all of them
+    pub threshold: u8,
+    /// Number of instructions before the verified one searched by introspection, 0 for all of them
+    pub lookback_window: u8,
+    /// Introspection requires the verified instruction to be top-level
+    pub require_top_level: bool,
+}
+
+/// Change required to bring a mint in line with the desired config
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RolloutAction {
+    /// Config does not exist yet
+    Initialize,
+    /// Config exists but differs; `trim` is set when the current list is longer
+    Update { trim: bool },
+    /// Config already matches
+    UpToDate,
+}
+
+/// Compares the current on-chain config with the desired one
+pub fn plan_rollout_action(
+    current: Option<&VerificationConfig>,
+    desired: &DesiredVerificationConfig,
+) -> RolloutAction {
+    match current {
+        None => RolloutAction::Initialize,
+        Some(config)
+            if config.cpi_mode == desired.cpi_mode
+                && config.verification_programs == desired.program_addresses
+                && config.threshold == desired.threshold
+                && config.lookback_window == desired.lookback_window
+                && config.require_top_level == desired.require_top_level =>
+        {
+            RolloutAction::UpToDate
+        }
+        Some(config) => RolloutAction::Update {
+            trim: config.verification_programs.len() > desired.program_addresses.len(),
+        },
+    }
+}
+
+/// Builds the instructions applying `action` to `mint`
+pub fn rollout_instructions(
+    mint: &Pubkey,
+    creator: &Pubkey,
+    payer: &Pubkey,
+    desired: &DesiredVerificationConfig,
+    action: RolloutAction,
+) -> Vec<Instruction> {
+    let (mint_authority, _) = find_mint_authority_pda(mint, creator);
+    let (config_account, _) = find_verification_config_pda(mint, desired.instruction_discriminator);
+    // Transfer configs are mirrored into the transfer hook extra account metas
+    let (account_metas_pda, transfer_hook_pda, transfer_hook_program) =
+        if desired.instruction_discriminator == TRANSFER_DISCRIMINATOR {
+            (
+                Some(find_extra_account_metas_pda(mint).0),
+                Some(find_transfer_hook_pda(mint).0),
+                Some(TRANSFER_HOOK_PROGRAM_ID),
+            )
+        } else {
+            (None, None, None)
+        };
+
+    let mut instructions = Vec::new();
+    match action {
+        RolloutAction::UpToDate => {}
+        RolloutAction::Initialize => {
+            let mut builder = InitializeVerificationConfigBuilder::new();
+            builder
+                .mint(*mint)
+                .verification_config_or_mint_authority(mint_authority)
+                .instructions_sysvar_or_creator(*creator)
+                .payer(*payer)
+                .mint_account(*mint)
+                .config_account(config_account)
+                .initialize_verification_config_args(InitializeVerificationConfigArgs {
+                    instruction_discriminator: desired.instruction_discriminator,
+                    cpi_mode: desired.cpi_mode,
+                    program_addresses: desired.program_addresses.clone(),
+                    threshold: desired.threshold,
+                    lookback_window: desired.lookback_window,
+                    require_top_level: desired.require_top_level,
+                    timelock_seconds: 0,
+                })
+                .account_metas_pda(account_metas_pda)
+                .transfer_hook_pda(transfer_hook_pda)
+                .transfer_hook_program(transfer_hook_program);
+            instructions.push(builder.instruction());
+        }
+        RolloutAction::Update { trim } => {
+            let mut builder = UpdateVerificationConfigBuilder::new();
+            builder
+                .mint(*mint)
+                .verification_config_or_mint_authority(mint_authority)
+                .instructions_sysvar_or_creator(*creator)
+                .payer(*payer)
+                .mint_account(*mint)
+                .config_account(config_account)
+                .update_verification_config_args(UpdateVerificationConfigArgs {
+                    instruction_discriminator: desired.instruction_discriminator,
+                    cpi_mode: desired.cpi_mode,
+                    offset: 0,
+                    program_addresses: desired.program_addresses.clone(),
+                    threshold: desired.threshold,
+                    lookback_window: desired.lookback_window,
+                    require_top_level: desired.require_top_level,
+                    timelock_seconds: 0,
+                })
+                .account_metas_pda(account_metas_pda)
+                .transfer_hook_pda(transfer_hook_pda)
+                .transfer_hook_program(transfer_hook_program);
+            instructions.push(builder.instruction());
+
+            if trim {
+                let mut builder = TrimVerificationConfigBuilder::new();
+                builder
+                    .mint(*mint)
+                    .verification_config_or_mint_authority(mint_authority)
+                    .instructions_sysvar_or_creator(*creator)
+                    .payer(*payer)
+                    .mint_account(*mint)
+                    .config_account(config_account)
+                    .rent_recipient(*payer)
+                    .trim_verification_config_args(TrimVerificationConfigArgs {
+                        instruction_discriminator: desired.instruction_discriminator,
+                        size: desired.program_addresses.len() as u8,
+                        close: false,
+                    })
+                    .account_metas_pda(account_metas_pda)
+                    .transfer_hook_pda(transfer_hook_pda)
+                    .transfer_hook_program(transfer_hook_program);
+                instructions.push(builder.instruction());
+            }
+        }
+    }
+
+    // The mint creator authorizes the change and has to sign
+    for instruction in instructions.iter_mut() {
+        instruction.accounts[2].is_signer = true;
+    }
+    instructions.into_iter().map(with_program_config).collect()
+}
+
+#[cfg(feature = "rpc")]
+pub use self::send::*;
+
+#[cfg(feature = "rpc")]
+mod send {
+    use super::*;
+    use crate::accounts::fetch_all_maybe_verification_config;
+    use crate::shared::MaybeAccount;
+    use solana_client::rpc_client::RpcClient;
+    use solana_sdk::signature::{Signature, Signer};
+    use solana_sdk::transaction::Transaction;
+
+    // getMultipleAccounts accepts at most 100 keys per request
+    const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+    /// Result of the rollout for a single mint
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct MintRolloutResult {
+        pub mint: Pubkey,
+        pub action: Option<RolloutAction>,
+        /// Signature of the sent transaction, `None` if nothing had to be sent
+        pub outcome: Result<Option<Signature>, String>,
+    }
+
+    /// Rollout results for the whole fleet, in input order
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    pub struct RolloutReport {
+        pub results: Vec<MintRolloutResult>,
+    }
+
+    impl RolloutReport {
+        pub fn failed(&self) -> impl Iterator<Item = &MintRolloutResult> {
+            self.results.iter().filter(|result| result.outcome.is_err())
+        }
+
+        pub fn is_success(&self) -> bool {
+            self.failed().next().is_none()
+        }
+    }
+
+    /// Applies `desired` to every mint, one transaction per mint.
+    ///
+    /// A failure on one mint does not stop the rollout; `progress` is called after
+    /// every mint with its result and the number of processed mints.
+    pub fn rollout_verification_config(
+        rpc: &RpcClient,
+        mints: &[Pubkey],
+        desired: &DesiredVerificationConfig,
+        creator: &dyn Signer,
+        payer: &dyn Signer,
+        mut progress: impl FnMut(&MintRolloutResult, usize, usize),
+    ) -> RolloutReport {
+        let mut report = RolloutReport::default();
+        for chunk in mints.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let config_addresses: Vec<Pubkey> = chunk
+                .iter()
+                .map(|mint| find_verification_config_pda(mint, desired.instruction_discriminator).0)
+                .collect();
+            let configs = fetch_all_maybe_verification_config(rpc, &config_addresses)
+                .map_err(|err| err.to_string());
+
+            for (idx, mint) in chunk.iter().enumerate() {
+                let result = match &configs {
+                    Err(err) => MintRolloutResult {
+                        mint: *mint,
+                        action: None,
+                        outcome: Err(err.clone()),
+                    },
+                    Ok(configs) => {
+                        let current = match &configs[idx] {
+                            MaybeAccount::Exists(account) => Some(&account.data),
+                            MaybeAccount::NotFound(_) => None,
+                        };
+                        let action = plan_rollout_action(current, desired);
+                        let instructions = rollout_instructions(
+                            mint,
+                            &creator.pubkey(),
+                            &payer.pubkey(),
+                            desired,
+                            action,
+                        );
+                        MintRolloutResult {
+                            mint: *mint,
+                            action: Some(action),
+                            outcome: send(rpc, &instructions, creator, payer),
+                        }
+                    }
+                };
+                report.results.push(result);
+                progress(
+                    report.results.last().expect("result pushed above"),
+                    report.results.len(),
+                    mints.len(),
+                );
+            }
+        }
+        report
+    }
+
+    fn send(
+        rpc: &RpcClient,
+        instructions: &[Instruction],
+        creator: &dyn Signer,
+        payer: &dyn Signer,
+    ) -> Result<Option<Signature>, String> {
+        if instructions.is_empty() {
+            return Ok(None);
+        }
+        let blockhash = rpc.get_latest_blockhash().map_err(|err| err.to_string())?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &[payer, creator],
+            blockhash,
+        );
+        rpc.send_and_confirm_transaction(&transaction)
+            .map(Some)
+            .map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::{
+        INITIALIZE_VERIFICATION_CONFIG_DISCRIMINATOR, MINT_DISCRIMINATOR,
+        TRIM_VERIFICATION_CONFIG_DISCRIMINATOR, UPDATE_VERIFICATION_CONFIG_DISCRIMINATOR,
+    };
+
+    fn desired(programs: usize) -> DesiredVerificationConfig {
+        DesiredVerificationConfig {
+            instruction_discriminator: MINT_DISCRIMINATOR,
+            cpi_mode: false,
+            program_addresses: (0..programs).map(|_| Pubkey::new_unique()).collect(),
+            threshold: 0,
+            lookback_window: 0,
+            require_top_level: false,
+        }
+    }
+
+    fn current(desired: &DesiredVerificationConfig) -> VerificationConfig {
+        VerificationConfig {
+            discriminator: 1,
+            instruction_discriminator: desired.instruction_discriminator,
+            cpi_mode: desired.cpi_mode,
+            bump: 255,
+            verification_programs: desired.program_addresses.clone(),
+            threshold: desired.threshold,
+            lookback_window: desired.lookback_window,
+            require_top_level: desired.require_top_level,
+        }
+    }
+
+    #[test]
+    fn test_plan_rollout_action() {
+        let desired = desired(2);
+        let mut config = current(&desired);
+
+        assert_eq!(
+            plan_rollout_action(None, &desired),
+            RolloutAction::Initialize
+        );
+        assert_eq!(
+            plan_rollout_action(Some(&config), &desired),
+            RolloutAction::UpToDate
+        );
+
+        config.threshold = 1;
+        assert_eq!(
+            plan_rollout_action(Some(&config), &desired),
+            RolloutAction::Update { trim: false }
+        );
+
+        config.threshold = 0;
+        config.require_top_level = true;
+        assert_eq!(
+            plan_rollout_action(Some(&config), &desired),
+            RolloutAction::Update { trim: false }
+        );
+
+        config.require_top_level = false;
+        config.cpi_mode = true;
+        assert_eq!(
+            plan_rollout_action(Some(&config), &desired),
+            RolloutAction::Update { trim: false }
+        );
+
+        config.verification_programs.push(Pubkey::new_unique());
+        assert_eq!(
+            plan_rollout_action(Some(&config), &desired),
+            RolloutAction::Update { trim: true }
+        );
+    }
+
+    #[test]
+    fn test_rollout_instructions() {
+        let desired = desired(2);
+        let (mint, creator) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let initialize = rollout_instructions(
+            &mint,
+            &creator,
+            &creator,
+            &desired,
+            RolloutAction::Initialize,
+        );
+        assert_eq!(initialize.len(), 1);
+        assert_eq!(
+            initialize[0].data[0],
+            INITIALIZE_VERIFICATION_CONFIG_DISCRIMINATOR
+        );
+        assert_eq!(
+            initialize[0].accounts[1].pubkey,
+            find_mint_authority_pda(&mint, &creator).0
+        );
+        assert!(initialize[0].accounts[2].is_signer);
+
+        let update = rollout_instructions(
+            &mint,
+            &creator,
+            &creator,
+            &desired,
+            RolloutAction::Update { trim: true },
+        );
+        assert_eq!(
+            update
+                .iter()
+                .map(|instruction| instruction.data[0])
+                .collect::<Vec<_>>(),
+            vec![
+                UPDATE_VERIFICATION_CONFIG_DISCRIMINATOR,
+                TRIM_VERIFICATION_CONFIG_DISCRIMINATOR
+            ]
+        );
+
+        assert!(
+            rollout_instructions(&mint, &creator, &creator, &desired, RolloutAction::UpToDate)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,414 @@
+//! Key rotation workflow for verification program keys.
+//!
+//! Verification configs reference compliance programs by key. When an issuer
+//! rotates one of those keys (e.g. redeploys a compliance program or switches
+//! provider) every config of every mint referencing the old key must be updated.
+//! The workflow plans the updates, applies them, re-reads on-chain state to verify
+//! the result and produces an audit record signed by the rotating authority.
+//!
+//! The mint creator is handed over on chain with `ProposeMintCreatorTransfer` and
+//! `AcceptMintCreatorTransfer` (the `MintAuthority` PDA stays derived from the original
+//! creator) and the metadata update authority is fixed by the program, so they are not
+//! covered here.
+
+use crate::accounts::VerificationConfig;
+use crate::rollout::{rollout_instructions, DesiredVerificationConfig, RolloutAction};
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+use solana_signature::Signature;
+use solana_signer::{Signer, SignerError};
+
+/// Replacement of one verification program key by another
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProgramRotation {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub old_program: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub new_program: Pubkey,
+}
+
+/// Planned change of a single verification config
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfigRotation {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub mint: Pubkey,
+    pub instruction_discriminator: u8,
+    pub cpi_mode: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    pub before: Vec<Pubkey>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    pub after: Vec<Pubkey>,
+    /// Threshold of the config, kept by the rotation
+    pub threshold: u8,
+    /// Introspection lookback window of the config, kept by the rotation
+    pub lookback_window: u8,
+    /// Top-level requirement of the config, kept by the rotation
+    pub require_top_level: bool,
+}
+
+impl ConfigRotation {
+    /// Instructions applying the rotation, authorized by the mint creator
+    pub fn instructions(&self, creator: &Pubkey, payer: &Pubkey) -> Vec<Instruction> {
+        let desired = DesiredVerificationConfig {
+            instruction_discriminator: self.instruction_discriminator,
+            cpi_mode: self.cpi_mode,
+            program_addresses: self.after.clone(),
+            threshold: self.threshold,
+            lookback_window: self.lookback_window,
+            require_top_level: self.require_top_level,
+        };
+        rollout_instructions(
+            &self.mint,
+            creator,
+            payer,
+            &desired,
+            RolloutAction::Update { trim: false },
+        )
+    }
+}
+
+/// Plans the config changes of `mint` replacing `rotation.old_program`
+pub fn plan_config_rotation(
+    mint: &Pubkey,
+    configs: &[VerificationConfig],
+    rotation: &ProgramRotation,
+) -> Vec<ConfigRotation> {
+    configs
+        .iter()
+        .filter(|config| config.verification_programs.contains(&rotation.old_program))
+        .map(|config| ConfigRotation {
+            mint: *mint,
+            instruction_discriminator: config.instruction_discriminator,
+            cpi_mode: config.cpi_mode,
+            before: config.verification_programs.clone(),
+            after: config
+                .verification_programs
+                .iter()
+                .map(|program| {
+                    if *program == rotation.old_program {
+                        rotation.new_program
+                    } else {
+                        *program
+                    }
+                })
+                .collect(),
+            threshold: config.threshold,
+            lookback_window: config.lookback_window,
+            require_top_level: config.require_top_level,
+        })
+        .collect()
+}
+
+/// Checks that the on-chain config matches the planned state after rotation
+pub fn verify_config_rotation(
+    planned: &ConfigRotation,
+    current: Option<&VerificationConfig>,
+) -> Result<(), String> {
+    match current {
+        None => Err(format!(
+            "config {} of mint {} not found",
+            planned.instruction_discriminator, planned.mint
+        )),
+        Some(config) if config.verification_programs != planned.after => Err(format!(
+            "config {} of mint {} was not rotated",
+            planned.instruction_discriminator, planned.mint
+        )),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Audit record of a completed rotation
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RotationAuditRecord {
+    /// Unix timestamp of the rotation
+    pub timestamp: i64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub authority: Pubkey,
+    pub rotation: ProgramRotation,
+    pub changes: Vec<ConfigRotation>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    pub transactions: Vec<Signature>,
+}
+
+impl RotationAuditRecord {
+    /// Deterministic message signed by the authority
+    pub fn message(&self) -> Vec<u8> {
+        let mut message = format!(
+            "security-token key rotation\ntimestamp: {}\nauthority: {}\nold: {}\nnew: {}\n",
+            self.timestamp, self.authority, self.rotation.old_program, self.rotation.new_program
+        );
+        for change in &self.changes {
+            message.push_str(&format!(
+                "config: {} {}\n",
+                change.mint, change.instruction_discriminator
+            ));
+        }
+        for transaction in &self.transactions {
+            message.push_str(&format!("tx: {}\n", transaction));
+        }
+        message.into_bytes()
+    }
+
+    pub fn sign(self, signer: &dyn Signer) -> SignedRotationAuditRecord {
+        let signature = signer.sign_message(&self.message());
+        SignedRotationAuditRecord {
+            record: self,
+            signature,
+        }
+    }
+
+    /// Like [`Self::sign`], but surfaces signer failures (e.g. a rejected hardware wallet prompt)
+    pub fn try_sign(self, signer: &dyn Signer) -> Result<SignedRotationAuditRecord, SignerError> {
+        let signature = signer.try_sign_message(&self.message())?;
+        Ok(SignedRotationAuditRecord {
+            record: self,
+            signature,
+        })
+    }
+}
+
+/// Audit record with the authority signature over [`RotationAuditRecord::message`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedRotationAuditRecord {
+    pub record: RotationAuditRecord,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub signature: Signature,
+}
+
+impl SignedRotationAuditRecord {
+    pub fn verify(&self) -> bool {
+        self.signature
+            .verify(self.record.authority.as_ref(), &self.record.message())
+    }
+}
+
+#[cfg(feature = "rpc")]
+pub use self::send::*;
+
+#[cfg(feature = "rpc")]
+mod send {
+    use super::*;
+    use crate::accounts::fetch_all_maybe_verification_config;
+    use crate::pda::find_verification_config_pda;
+    use crate::shared::MaybeAccount;
+    use solana_client::rpc_client::RpcClient;
+    use solana_sdk::transaction::Transaction;
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use thiserror::Error;
+
+    // getMultipleAccounts accepts at most 100 keys per request
+    const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+    /// Rotation errors
+    #[derive(Clone, Debug, Error, PartialEq, Eq)]
+    pub enum RotationError {
+        #[error("RPC error: {0}")]
+        Rpc(String),
+        #[error("Rotation failed after {completed} transactions: {reason}")]
+        Failed { completed: usize, reason: String },
+        #[error("Verification failed: {0}")]
+        Verification(String),
+    }
+
+    /// Fetches every verification config of `mint`.
+    ///
+    /// All discriminator values are probed so configs of instructions added later are found too.
+    pub fn fetch_mint_verification_configs(
+        rpc: &RpcClient,
+        mint: &Pubkey,
+    ) -> Result<Vec<VerificationConfig>, RotationError> {
+        let addresses: Vec<Pubkey> = (0..=u8::MAX)
+            .map(|discriminator| find_verification_config_pda(mint, discriminator).0)
+            .collect();
+        let mut configs = Vec::new();
+        for chunk in addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            for account in fetch_all_maybe_verification_config(rpc, chunk)
+                .map_err(|err| RotationError::Rpc(err.to_string()))?
+            {
+                if let MaybeAccount::Exists(account) = account {
+                    configs.push(account.data);
+                }
+            }
+        }
+        Ok(configs)
+    }
+
+    /// Rotates `rotation.old_program` in every config of `mints`, verifies the
+    /// result on-chain and returns the unsigned audit record.
+    ///
+    /// Use when the audit record is signed separately, e.g. offline because the
+    /// authority is a hardware wallet.
+    pub fn apply_verification_program_rotation(
+        rpc: &RpcClient,
+        mints: &[Pubkey],
+        rotation: &ProgramRotation,
+        creator: &dyn Signer,
+        payer: &dyn Signer,
+    ) -> Result<RotationAuditRecord, RotationError> {
+        let mut changes = Vec::new();
+        for mint in mints {
+            let configs = fetch_mint_verification_configs(rpc, mint)?;
+            changes.extend(plan_config_rotation(mint, &configs, rotation));
+        }
+
+        // Signing twice with the same key would prompt hardware wallets twice
+        let signers: Vec<&dyn Signer> = if payer.pubkey() == creator.pubkey() {
+            vec![payer]
+        } else {
+            vec![payer, creator]
+        };
+        let mut transactions = Vec::with_capacity(changes.len());
+        for change in &changes {
+            let failed = |reason: String| RotationError::Failed {
+                completed: transactions.len(),
+                reason,
+            };
+            let blockhash = rpc
+                .get_latest_blockhash()
+                .map_err(|err| failed(err.to_string()))?;
+            let transaction = Transaction::new_signed_with_payer(
+                &change.instructions(&creator.pubkey(), &payer.pubkey()),
+                Some(&payer.pubkey()),
+                &signers,
+                blockhash,
+            );
+            let signature = rpc
+                .send_and_confirm_transaction(&transaction)
+                .map_err(|err| failed(err.to_string()))?;
+            transactions.push(signature);
+        }
+
+        for change in &changes {
+            let (address, _) =
+                find_verification_config_pda(&change.mint, change.instruction_discriminator);
+            let current = match fetch_all_maybe_verification_config(rpc, &[address])
+                .map_err(|err| RotationError::Rpc(err.to_string()))?
+                .pop()
+            {
+                Some(MaybeAccount::Exists(account)) => Some(account.data),
+                _ => None,
+            };
+            verify_config_rotation(change, current.as_ref())
+                .map_err(RotationError::Verification)?;
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs() as i64)
+            .unwrap_or_default();
+        Ok(RotationAuditRecord {
+            timestamp,
+            authority: creator.pubkey(),
+            rotation: *rotation,
+            changes,
+            transactions,
+        })
+    }
+
+    /// Rotates `rotation.old_program` in every config of `mints`, verifies the
+    /// result on-chain and returns the audit record signed by `creator`.
+    pub fn rotate_verification_program(
+        rpc: &RpcClient,
+        mints: &[Pubkey],
+        rotation: &ProgramRotation,
+        creator: &dyn Signer,
+        payer: &dyn Signer,
+    ) -> Result<SignedRotationAuditRecord, RotationError> {
+        apply_verification_program_rotation(rpc, mints, rotation, creator, payer)
+            .map(|record| record.sign(creator))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::signature::Keypair;
+
+    fn config(instruction_discriminator: u8, programs: Vec<Pubkey>) -> VerificationConfig {
+        VerificationConfig {
+            discriminator: 1,
+            instruction_discriminator,
+            cpi_mode: false,
+            bump: 255,
+            verification_programs: programs,
+            threshold: 0,
+            lookback_window: 0,
+            require_top_level: false,
+        }
+    }
+
+    #[test]
+    fn test_plan_and_verify_config_rotation() {
+        let mint = Pubkey::new_unique();
+        let (old_program, new_program, other) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let rotation = ProgramRotation {
+            old_program,
+            new_program,
+        };
+        let configs = vec![config(6, vec![other, old_program]), config(7, vec![other])];
+
+        let plan = plan_config_rotation(&mint, &configs, &rotation);
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].instruction_discriminator, 6);
+        assert_eq!(plan[0].after, vec![other, new_program]);
+
+        assert!(verify_config_rotation(&plan[0], Some(&configs[0])).is_err());
+        assert!(verify_config_rotation(&plan[0], None).is_err());
+        assert!(
+            verify_config_rotation(&plan[0], Some(&config(6, vec![other, new_program]))).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_signed_audit_record() {
+        let authority = Keypair::new();
+        let record = RotationAuditRecord {
+            timestamp: 1_700_000_000,
+            authority: authority.pubkey(),
+            rotation: ProgramRotation {
+                old_program: Pubkey::new_unique(),
+                new_program: Pubkey::new_unique(),
+            },
+            changes: vec![],
+            transactions: vec![Signature::default()],
+        };
+
+        let mut signed = record.sign(&authority);
+        assert!(signed.verify());
+
+        signed.record.timestamp += 1;
+        assert!(!signed.verify());
+    }
+}
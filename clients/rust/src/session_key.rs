@@ -0,0 +1,27 @@
+//! Session key instruction sets, encoded as the on-chain `allowed_instructions` bitmap
+//! where bit `n` allows the instruction with discriminator `n`.
+
+/// Bitmap allowing the instructions with the given discriminators
+pub fn allowed_instructions(discriminators: &[u8]) -> u128 {
+    discriminators
+        .iter()
+        .filter(|discriminator| **discriminator < 128)
+        .fold(0, |bitmap, discriminator| bitmap | 1u128 << discriminator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::{ALLOCATE_DISCRIMINATOR, CREATE_VESTING_DISCRIMINATOR};
+
+    #[test]
+    fn test_allowed_instructions_sets_discriminator_bits() {
+        let bitmap = allowed_instructions(&[ALLOCATE_DISCRIMINATOR, CREATE_VESTING_DISCRIMINATOR]);
+
+        assert_eq!(
+            bitmap,
+            1u128 << ALLOCATE_DISCRIMINATOR | 1u128 << CREATE_VESTING_DISCRIMINATOR
+        );
+        assert_eq!(allowed_instructions(&[]), 0);
+    }
+}
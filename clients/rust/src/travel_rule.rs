@@ -0,0 +1,105 @@
+//! Travel-rule memo helpers.
+//!
+//! VASPs exchange travel-rule data (originator and beneficiary information) off-chain.
+//! A `Transfer` only carries the [`memo_hash`] of the exchanged payload, which the program
+//! logs in a [`TransferEvent`] so auditors can match transfers against the off-chain
+//! records without any PII on-chain.
+
+use solana_keccak_hasher::hash;
+use solana_pubkey::Pubkey;
+
+/// Discriminator of the transfer event logged by the program and the transfer hook
+pub const TRANSFER_EVENT_DISCRIMINATOR: u8 = 0;
+
+/// Discriminator (1) + mint (32) + from (32) + to (32) + amount (8) + memo hash option (1 + 32)
+pub const TRANSFER_EVENT_LEN: usize = 138;
+
+/// Memo hash referencing an off-chain travel-rule payload (encrypted or opaque)
+pub fn memo_hash(payload: &[u8]) -> [u8; 32] {
+    hash(payload).to_bytes()
+}
+
+/// Transfer event decoded from `Program data:` logs
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferEvent {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    /// Travel-rule memo hash, `None` for holder transfers checked by the transfer hook
+    pub memo_hash: Option<[u8; 32]>,
+}
+
+impl TransferEvent {
+    /// Decode base64-decoded `Program data:` bytes, `None` if they are not a transfer event
+    pub fn from_log_data(data: &[u8]) -> Option<Self> {
+        if data.len() != TRANSFER_EVENT_LEN || data[0] != TRANSFER_EVENT_DISCRIMINATOR {
+            return None;
+        }
+        let memo_hash = match data[105] {
+            0 => None,
+            1 => Some(data[106..138].try_into().ok()?),
+            _ => return None,
+        };
+        Some(Self {
+            mint: Pubkey::try_from(&data[1..33]).ok()?,
+            from: Pubkey::try_from(&data[33..65]).ok()?,
+            to: Pubkey::try_from(&data[65..97]).ok()?,
+            amount: u64::from_le_bytes(data[97..105].try_into().ok()?),
+            memo_hash,
+        })
+    }
+
+    /// Whether the event references the travel-rule `payload`
+    pub fn matches_payload(&self, payload: &[u8]) -> bool {
+        self.memo_hash == Some(memo_hash(payload))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_event_matches_program_layout() {
+        let payload = b"encrypted travel rule payload";
+        let event = security_token_program::events::TransferEvent {
+            mint: [1u8; 32],
+            from: [2u8; 32],
+            to: [3u8; 32],
+            amount: 500,
+            memo_hash: Some(memo_hash(payload)),
+        };
+
+        let decoded = TransferEvent::from_log_data(&event.to_bytes()).unwrap();
+        assert_eq!(decoded.mint, Pubkey::new_from_array([1u8; 32]));
+        assert_eq!(decoded.from, Pubkey::new_from_array([2u8; 32]));
+        assert_eq!(decoded.to, Pubkey::new_from_array([3u8; 32]));
+        assert_eq!(decoded.amount, 500);
+        assert!(decoded.matches_payload(payload));
+        assert!(!decoded.matches_payload(b"other payload"));
+    }
+
+    #[test]
+    fn test_transfer_event_without_memo_hash() {
+        let event = security_token_program::events::TransferEvent {
+            mint: [1u8; 32],
+            from: [2u8; 32],
+            to: [3u8; 32],
+            amount: 500,
+            memo_hash: None,
+        };
+
+        let decoded = TransferEvent::from_log_data(&event.to_bytes()).unwrap();
+        assert_eq!(decoded.memo_hash, None);
+        assert!(!decoded.matches_payload(b""));
+    }
+
+    #[test]
+    fn test_from_log_data_rejects_other_data() {
+        assert_eq!(TransferEvent::from_log_data(&[0u8; 10]), None);
+        let mut data = [0u8; TRANSFER_EVENT_LEN];
+        data[0] = 1;
+        assert_eq!(TransferEvent::from_log_data(&data), None);
+    }
+}
@@ -0,0 +1,244 @@
+//! Scaled UI amount conversions.
+//!
+//! Mints with the Token-2022 ScaledUiAmount extension display `raw * multiplier` instead
+//! of the raw balance, while instructions still take raw amounts. The extension holds the
+//! current `multiplier` and a `new_multiplier` that replaces it from
+//! `new_multiplier_effective_timestamp` on, so the multiplier to apply depends on the
+//! time of the conversion.
+//!
+//! [`raw_to_ui_amount`] and [`ui_amount_to_raw`] apply the multiplier exactly once and
+//! round like Token-2022 `AmountToUiAmount` / `UiAmountToAmount`: displayed amounts are
+//! truncated to the mint decimals, raw amounts are rounded to the nearest base unit.
+
+use crate::cap_table::format_ui_amount;
+use crate::types::ScaledUiAmountConfigArgs;
+use thiserror::Error;
+
+/// Base mint length, extended Token-2022 mints are padded to the token account length
+const EXTENSIONS_OFFSET: usize = 166;
+/// `ExtensionType::ScaledUiAmount`
+const EXTENSION_TYPE_SCALED_UI_AMOUNT: u16 = 25;
+/// Authority (32) + multiplier (8) + new multiplier effective timestamp (8) + new multiplier (8)
+const SCALED_UI_AMOUNT_LEN: usize = 56;
+
+/// UI amount conversion errors
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum UiAmountError {
+    #[error("Invalid UI amount: {0}")]
+    InvalidAmount(String),
+    #[error("UI amount has more than {0} decimals")]
+    TooManyDecimals(u8),
+    #[error("Raw amount does not fit in a u64")]
+    Overflow,
+    #[error("Multiplier must be a positive finite number")]
+    InvalidMultiplier,
+}
+
+/// Multipliers of a ScaledUiAmount mint
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScaledUiAmount {
+    pub multiplier: f64,
+    pub new_multiplier_effective_timestamp: i64,
+    pub new_multiplier: f64,
+}
+
+impl ScaledUiAmount {
+    /// Parses the ScaledUiAmount extension of Token-2022 mint data, `None` for mints
+    /// without the extension
+    pub fn from_mint_data(data: &[u8]) -> Option<Self> {
+        let mut offset = EXTENSIONS_OFFSET;
+        while offset + 4 <= data.len() {
+            let extension_type = u16::from_le_bytes(data[offset..offset + 2].try_into().ok()?);
+            let length = u16::from_le_bytes(data[offset + 2..offset + 4].try_into().ok()?) as usize;
+            let value = data.get(offset + 4..offset + 4 + length)?;
+            if extension_type == EXTENSION_TYPE_SCALED_UI_AMOUNT {
+                if length != SCALED_UI_AMOUNT_LEN {
+                    return None;
+                }
+                return Some(Self {
+                    multiplier: f64::from_le_bytes(value[32..40].try_into().ok()?),
+                    new_multiplier_effective_timestamp: i64::from_le_bytes(
+                        value[40..48].try_into().ok()?,
+                    ),
+                    new_multiplier: f64::from_le_bytes(value[48..56].try_into().ok()?),
+                });
+            }
+            offset += 4 + length;
+        }
+        None
+    }
+
+    /// Multiplier in effect at `unix_timestamp`
+    pub fn multiplier_at(&self, unix_timestamp: i64) -> f64 {
+        if unix_timestamp >= self.new_multiplier_effective_timestamp {
+            self.new_multiplier
+        } else {
+            self.multiplier
+        }
+    }
+}
+
+impl From<&ScaledUiAmountConfigArgs> for ScaledUiAmount {
+    fn from(args: &ScaledUiAmountConfigArgs) -> Self {
+        Self {
+            multiplier: f64::from_le_bytes(args.multiplier),
+            new_multiplier_effective_timestamp: args.new_multiplier_effective_timestamp,
+            new_multiplier: f64::from_le_bytes(args.new_multiplier),
+        }
+    }
+}
+
+fn valid_multiplier(multiplier: f64) -> Result<f64, UiAmountError> {
+    if multiplier.is_finite() && multiplier > 0.0 {
+        Ok(multiplier)
+    } else {
+        Err(UiAmountError::InvalidMultiplier)
+    }
+}
+
+/// Formats a raw amount for display at `unix_timestamp`, applying the multiplier of
+/// `scaled_ui_amount` when the mint has one
+pub fn raw_to_ui_amount(
+    amount: u64,
+    decimals: u8,
+    scaled_ui_amount: Option<&ScaledUiAmount>,
+    unix_timestamp: i64,
+) -> Result<String, UiAmountError> {
+    let Some(scaled_ui_amount) = scaled_ui_amount else {
+        return Ok(format_ui_amount(amount, decimals));
+    };
+    let multiplier = valid_multiplier(scaled_ui_amount.multiplier_at(unix_timestamp))?;
+    let scaled = (amount as f64 * multiplier).trunc();
+    if scaled >= u64::MAX as f64 {
+        return Err(UiAmountError::Overflow);
+    }
+    Ok(format_ui_amount(scaled as u64, decimals))
+}
+
+/// Converts a displayed amount at `unix_timestamp` back to the raw amount instructions
+/// take, removing the multiplier of `scaled_ui_amount` when the mint has one
+pub fn ui_amount_to_raw(
+    ui_amount: &str,
+    decimals: u8,
+    scaled_ui_amount: Option<&ScaledUiAmount>,
+    unix_timestamp: i64,
+) -> Result<u64, UiAmountError> {
+    let invalid = || UiAmountError::InvalidAmount(ui_amount.to_string());
+    let (whole, fraction) = ui_amount.split_once('.').unwrap_or((ui_amount, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(invalid());
+    }
+    if !whole
+        .chars()
+        .chain(fraction.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > decimals as usize {
+        return Err(UiAmountError::TooManyDecimals(decimals));
+    }
+    let digits = format!("{whole}{fraction:0<width$}", width = decimals as usize);
+    let scaled = match digits.trim_start_matches('0') {
+        "" => 0,
+        digits => digits.parse::<u64>().map_err(|_| UiAmountError::Overflow)?,
+    };
+
+    let Some(scaled_ui_amount) = scaled_ui_amount else {
+        return Ok(scaled);
+    };
+    let multiplier = valid_multiplier(scaled_ui_amount.multiplier_at(unix_timestamp))?;
+    let raw = (scaled as f64 / multiplier).round();
+    if raw >= u64::MAX as f64 {
+        return Err(UiAmountError::Overflow);
+    }
+    Ok(raw as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCALED: ScaledUiAmount = ScaledUiAmount {
+        multiplier: 1.5,
+        new_multiplier_effective_timestamp: 100,
+        new_multiplier: 2.0,
+    };
+
+    #[test]
+    fn test_unscaled_conversions() {
+        assert_eq!(raw_to_ui_amount(1_500_000, 6, None, 0).unwrap(), "1.500000");
+        assert_eq!(ui_amount_to_raw("1.5", 6, None, 0).unwrap(), 1_500_000);
+        assert_eq!(ui_amount_to_raw("0.000000", 6, None, 0).unwrap(), 0);
+        assert_eq!(ui_amount_to_raw(".25", 2, None, 0).unwrap(), 25);
+        assert_eq!(
+            ui_amount_to_raw("1.0000001", 6, None, 0),
+            Err(UiAmountError::TooManyDecimals(6))
+        );
+        assert!(matches!(
+            ui_amount_to_raw("-1", 6, None, 0),
+            Err(UiAmountError::InvalidAmount(_))
+        ));
+        assert_eq!(
+            ui_amount_to_raw("18446744073709551616", 0, None, 0),
+            Err(UiAmountError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_pending_multiplier_applies_from_effective_timestamp() {
+        assert_eq!(
+            raw_to_ui_amount(1_000, 2, Some(&SCALED), 99).unwrap(),
+            "15.00"
+        );
+        assert_eq!(
+            raw_to_ui_amount(1_000, 2, Some(&SCALED), 100).unwrap(),
+            "20.00"
+        );
+        assert_eq!(ui_amount_to_raw("15", 2, Some(&SCALED), 99).unwrap(), 1_000);
+        assert_eq!(
+            ui_amount_to_raw("20", 2, Some(&SCALED), 100).unwrap(),
+            1_000
+        );
+    }
+
+    #[test]
+    fn test_scaled_amounts_truncate_and_round() {
+        // 3 * 1.5 = 4.5 base units, displayed truncated
+        assert_eq!(raw_to_ui_amount(3, 0, Some(&SCALED), 0).unwrap(), "4");
+        // 4 / 1.5 = 2.67 raw units, rounded to the nearest
+        assert_eq!(ui_amount_to_raw("4", 0, Some(&SCALED), 0).unwrap(), 3);
+
+        let invalid = ScaledUiAmount {
+            multiplier: 0.0,
+            ..SCALED
+        };
+        assert_eq!(
+            raw_to_ui_amount(1, 0, Some(&invalid), 0),
+            Err(UiAmountError::InvalidMultiplier)
+        );
+    }
+
+    #[test]
+    fn test_scaled_ui_amount_from_mint_data() {
+        let mut data = vec![0u8; EXTENSIONS_OFFSET];
+        data[EXTENSIONS_OFFSET - 1] = 1;
+        // MetadataPointer before the ScaledUiAmount extension
+        data.extend_from_slice(&18u16.to_le_bytes());
+        data.extend_from_slice(&64u16.to_le_bytes());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(&EXTENSION_TYPE_SCALED_UI_AMOUNT.to_le_bytes());
+        data.extend_from_slice(&(SCALED_UI_AMOUNT_LEN as u16).to_le_bytes());
+        data.extend_from_slice(&[0; 32]);
+        data.extend_from_slice(&1.5f64.to_le_bytes());
+        data.extend_from_slice(&100i64.to_le_bytes());
+        data.extend_from_slice(&2.0f64.to_le_bytes());
+
+        assert_eq!(ScaledUiAmount::from_mint_data(&data), Some(SCALED));
+        assert_eq!(
+            ScaledUiAmount::from_mint_data(&data[..EXTENSIONS_OFFSET]),
+            None
+        );
+    }
+}
@@ -0,0 +1,626 @@
+//! Verification-mode aware instruction preparation.
+//!
+//! Operations verified through a `VerificationConfig` need a different transaction
+//! shape depending on `cpi_mode`:
+//! - CPI mode: verification program ids are appended to the instruction accounts and
+//!   the program invokes them itself.
+//! - Introspection mode: every verification program is called in a preceding
+//!   instruction with the same instruction data and the operation accounts
+//!   (everything after mint, config and instructions sysvar) as a prefix.
+//!
+//! Configs with a `threshold` only need that many programs to pass; the bundle then
+//! uses the first `threshold` configured programs.
+//!
+//! [`prepare_verified_instructions`] produces the right bundle from a config so
+//! application code doesn't need to branch on `cpi_mode`.
+//!
+//! A `CanTransfer` pre-check is verified like the Transfer it stands for, see
+//! [`prepare_can_transfer_instructions`] and [`TransferCheck`].
+//!
+//! Operations authorized by a `MintAuthority` with a multisig are signed by `threshold` of
+//! its signers in place of the creator, see [`with_multisig_signers`].
+//!
+//! [`IntrospectionFlow`] composes a whole transaction of verified operations, placing the
+//! verification calls of every operation right before it.
+
+use crate::accounts::VerificationConfig;
+use crate::errors::SecurityTokenProgramError;
+use crate::halt::take_program_config;
+use crate::instructions::{CAN_TRANSFER_DISCRIMINATOR, TRANSFER_DISCRIMINATOR};
+use crate::pda::find_verification_config_pda;
+use num_traits::FromPrimitive;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use thiserror::Error;
+
+/// Accounts preceding the operation accounts: mint, verification config, instructions sysvar
+pub const INSTRUCTION_ACCOUNTS_OFFSET: usize = 3;
+
+/// Discriminator of the verification event logged by the program and the transfer hook
+pub const VERIFICATION_EVENT_DISCRIMINATOR: u8 = 1;
+
+/// Verification event size without programs: discriminator (1) + mint (32)
+/// + instruction discriminator (1) + cpi_mode (1) + threshold (1) + reason (8) + program count (1)
+pub const VERIFICATION_EVENT_MIN_LEN: usize = 45;
+
+/// How an operation is verified on-chain
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationMode {
+    Cpi,
+    Introspection,
+}
+
+impl From<&VerificationConfig> for VerificationMode {
+    fn from(config: &VerificationConfig) -> Self {
+        if config.cpi_mode {
+            VerificationMode::Cpi
+        } else {
+            VerificationMode::Introspection
+        }
+    }
+}
+
+/// Verification preparation errors
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum VerificationError {
+    #[error("Instruction has no data")]
+    MissingInstructionData,
+    #[error("Instruction does not have the verification overhead accounts")]
+    NotEnoughAccounts,
+    #[error("Config is for instruction {config}, but instruction is {instruction}")]
+    DiscriminatorMismatch { config: u8, instruction: u8 },
+    #[error("Verification config account {0} does not match instruction accounts")]
+    ConfigMismatch(Pubkey),
+    #[error("Verification config {0} not found")]
+    ConfigNotFound(Pubkey),
+    #[error("RPC error: {0}")]
+    Rpc(String),
+    #[error("Lookback window {lookback_window} is shorter than {required} verification calls")]
+    LookbackWindowTooSmall {
+        lookback_window: u8,
+        required: usize,
+    },
+}
+
+/// Verification outcome decoded from `Program data:` logs
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationEvent {
+    pub mint: Pubkey,
+    pub instruction_discriminator: u8,
+    pub mode: VerificationMode,
+    pub threshold: u8,
+    /// `0` when the verification passed, otherwise the program error it failed with
+    pub reason: u64,
+    /// Programs invoked in CPI mode, programs whose calls were found in introspection mode
+    pub programs: Vec<Pubkey>,
+}
+
+impl VerificationEvent {
+    /// Decode base64-decoded `Program data:` bytes, `None` if they are not a verification event
+    pub fn from_log_data(data: &[u8]) -> Option<Self> {
+        if data.len() < VERIFICATION_EVENT_MIN_LEN || data[0] != VERIFICATION_EVENT_DISCRIMINATOR {
+            return None;
+        }
+        let program_count = data[44] as usize;
+        if data.len() != VERIFICATION_EVENT_MIN_LEN + program_count * 32 {
+            return None;
+        }
+        let mode = match data[34] {
+            0 => VerificationMode::Introspection,
+            1 => VerificationMode::Cpi,
+            _ => return None,
+        };
+        Some(Self {
+            mint: Pubkey::try_from(&data[1..33]).ok()?,
+            instruction_discriminator: data[33],
+            mode,
+            threshold: data[35],
+            reason: u64::from_le_bytes(data[36..44].try_into().ok()?),
+            programs: data[VERIFICATION_EVENT_MIN_LEN..]
+                .chunks_exact(32)
+                .map(Pubkey::try_from)
+                .collect::<Result<_, _>>()
+                .ok()?,
+        })
+    }
+
+    /// Whether the verification passed
+    pub fn passed(&self) -> bool {
+        self.reason == 0
+    }
+
+    /// Security token program error the verification failed with, `None` for other errors
+    pub fn error(&self) -> Option<SecurityTokenProgramError> {
+        reason_error(self.reason)
+    }
+}
+
+/// Result of a simulated `CanTransfer`, decoded from its return data
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferCheck {
+    /// `0` when the transfer would succeed, otherwise the program error it would fail with
+    pub reason: u64,
+}
+
+impl TransferCheck {
+    /// Decode the return data of a `CanTransfer`, `None` if it is not a result code
+    pub fn from_return_data(data: &[u8]) -> Option<Self> {
+        Some(Self {
+            reason: u64::from_le_bytes(data.try_into().ok()?),
+        })
+    }
+
+    /// Whether the transfer would succeed
+    pub fn passed(&self) -> bool {
+        self.reason == 0
+    }
+
+    /// Security token program error the transfer would fail with, `None` for other errors
+    pub fn error(&self) -> Option<SecurityTokenProgramError> {
+        reason_error(self.reason)
+    }
+}
+
+/// Custom program errors are encoded as their plain code
+fn reason_error(reason: u64) -> Option<SecurityTokenProgramError> {
+    u32::try_from(reason)
+        .ok()
+        .filter(|code| *code != 0)
+        .and_then(SecurityTokenProgramError::from_u32)
+}
+
+/// Builds the instruction bundle verifying `instruction` according to `config`.
+///
+/// `instruction` must be built with the verification config PDA as its second
+/// account. A trailing ProgramConfig PDA is kept last, after the verification programs.
+/// The returned instructions must be sent in order within one transaction.
+pub fn prepare_verified_instructions(
+    instruction: Instruction,
+    mint: &Pubkey,
+    config: &VerificationConfig,
+) -> Result<Vec<Instruction>, VerificationError> {
+    let mut instruction = instruction;
+    // The ProgramConfig of a haltable instruction stays last, after the verification programs
+    let program_config = take_program_config(&mut instruction);
+    let ix_discriminator = *instruction
+        .data
+        .first()
+        .ok_or(VerificationError::MissingInstructionData)?;
+    if config.instruction_discriminator != ix_discriminator {
+        return Err(VerificationError::DiscriminatorMismatch {
+            config: config.instruction_discriminator,
+            instruction: ix_discriminator,
+        });
+    }
+    if instruction.accounts.len() < INSTRUCTION_ACCOUNTS_OFFSET {
+        return Err(VerificationError::NotEnoughAccounts);
+    }
+    let (config_pda, _) = find_verification_config_pda(mint, ix_discriminator);
+    if instruction.accounts[1].pubkey != config_pda {
+        return Err(VerificationError::ConfigMismatch(config_pda));
+    }
+
+    let required_programs = match config.threshold {
+        0 => config.verification_programs.len(),
+        threshold => config.verification_programs.len().min(threshold as usize),
+    };
+    let verification_programs = &config.verification_programs[..required_programs];
+
+    match VerificationMode::from(config) {
+        VerificationMode::Cpi => {
+            instruction.accounts.extend(
+                verification_programs
+                    .iter()
+                    .map(|program_id| AccountMeta::new_readonly(*program_id, false)),
+            );
+            instruction.accounts.extend(program_config);
+            Ok(vec![instruction])
+        }
+        VerificationMode::Introspection => {
+            let operation_accounts: Vec<AccountMeta> = instruction.accounts
+                [INSTRUCTION_ACCOUNTS_OFFSET..]
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: false,
+                })
+                .collect();
+            let mut instructions: Vec<Instruction> = verification_programs
+                .iter()
+                .map(|program_id| Instruction {
+                    program_id: *program_id,
+                    accounts: operation_accounts.clone(),
+                    data: instruction.data.clone(),
+                })
+                .collect();
+            instruction.accounts.extend(program_config);
+            instructions.push(instruction);
+            Ok(instructions)
+        }
+    }
+}
+
+/// Builds the instruction bundle of a `CanTransfer` pre-check from the Transfer `config`.
+///
+/// Verification programs see the Transfer instruction data, as the program verifies the check
+/// like the transfer. Simulate the bundle and decode the result with [`TransferCheck`].
+pub fn prepare_can_transfer_instructions(
+    instruction: Instruction,
+    mint: &Pubkey,
+    config: &VerificationConfig,
+) -> Result<Vec<Instruction>, VerificationError> {
+    match instruction.data.first() {
+        Some(&CAN_TRANSFER_DISCRIMINATOR) => {}
+        Some(&ix_discriminator) => {
+            return Err(VerificationError::DiscriminatorMismatch {
+                config: config.instruction_discriminator,
+                instruction: ix_discriminator,
+            })
+        }
+        None => return Err(VerificationError::MissingInstructionData),
+    }
+    let mut transfer = instruction;
+    transfer.data[0] = TRANSFER_DISCRIMINATOR;
+    let mut instructions = prepare_verified_instructions(transfer, mint, config)?;
+    if let Some(can_transfer) = instructions.last_mut() {
+        can_transfer.data[0] = CAN_TRANSFER_DISCRIMINATOR;
+    }
+    Ok(instructions)
+}
+
+/// Signs the mint authority mode `instruction` with the multisig `signers` of its
+/// `MintAuthority` instead of the creator.
+///
+/// The first signer takes the creator account and the others follow it, before the operation
+/// accounts. Pass `threshold` distinct signers set by `SetMintAuthorityMultisig`.
+pub fn with_multisig_signers(
+    instruction: Instruction,
+    signers: &[Pubkey],
+) -> Result<Instruction, VerificationError> {
+    if instruction.accounts.len() < INSTRUCTION_ACCOUNTS_OFFSET {
+        return Err(VerificationError::NotEnoughAccounts);
+    }
+    let Some((first, others)) = signers.split_first() else {
+        return Ok(instruction);
+    };
+    let mut instruction = instruction;
+    instruction.accounts[INSTRUCTION_ACCOUNTS_OFFSET - 1] = AccountMeta::new_readonly(*first, true);
+    instruction.accounts.splice(
+        INSTRUCTION_ACCOUNTS_OFFSET..INSTRUCTION_ACCOUNTS_OFFSET,
+        others
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true)),
+    );
+    Ok(instruction)
+}
+
+/// Instructions of a transaction verifying its operations by introspection.
+///
+/// Every verified operation is preceded by the calls to its verification programs, with the
+/// operation data and accounts, so each one finds its calls within its lookback window.
+/// Operations of CPI mode configs are added with their verification programs appended.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntrospectionFlow {
+    instructions: Vec<Instruction>,
+}
+
+impl IntrospectionFlow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an instruction needing no verification, such as a token account creation
+    pub fn with_instruction(mut self, instruction: Instruction) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    /// Adds `instruction` verified by `config`, a `CanTransfer` is verified by the Transfer config
+    pub fn with_verified_instruction(
+        mut self,
+        instruction: Instruction,
+        mint: &Pubkey,
+        config: &VerificationConfig,
+    ) -> Result<Self, VerificationError> {
+        let bundle = if instruction.data.first() == Some(&CAN_TRANSFER_DISCRIMINATOR) {
+            prepare_can_transfer_instructions(instruction, mint, config)?
+        } else {
+            prepare_verified_instructions(instruction, mint, config)?
+        };
+        let required = bundle.len() - 1;
+        if config.lookback_window != 0 && usize::from(config.lookback_window) < required {
+            return Err(VerificationError::LookbackWindowTooSmall {
+                lookback_window: config.lookback_window,
+                required,
+            });
+        }
+        self.instructions.extend(bundle);
+        Ok(self)
+    }
+
+    /// Instructions to send in order within one transaction
+    pub fn build(self) -> Vec<Instruction> {
+        self.instructions
+    }
+}
+
+/// Fetches the verification config of `instruction` and builds its instruction bundle
+#[cfg(feature = "rpc")]
+pub fn fetch_and_prepare_verified_instructions(
+    rpc: &solana_client::rpc_client::RpcClient,
+    instruction: Instruction,
+    mint: &Pubkey,
+) -> Result<Vec<Instruction>, VerificationError> {
+    use crate::account::{MaybeAccount, ProgramAccount};
+
+    let ix_discriminator = *instruction
+        .data
+        .first()
+        .ok_or(VerificationError::MissingInstructionData)?;
+    let (config_pda, _) = find_verification_config_pda(mint, ix_discriminator);
+    match VerificationConfig::fetch_maybe(rpc, &config_pda)
+        .map_err(|err| VerificationError::Rpc(err.to_string()))?
+    {
+        MaybeAccount::Exists(config) => {
+            prepare_verified_instructions(instruction, mint, &config.data)
+        }
+        MaybeAccount::NotFound(address) => Err(VerificationError::ConfigNotFound(address)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IX: u8 = 6;
+
+    fn fixture(cpi_mode: bool) -> (Pubkey, Instruction, VerificationConfig) {
+        let mint = Pubkey::new_unique();
+        let (config_pda, bump) = find_verification_config_pda(&mint, IX);
+        let instruction = Instruction {
+            program_id: crate::programs::SECURITY_TOKEN_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(config_pda, false),
+                AccountMeta::new_readonly(Pubkey::new_unique(), false),
+                AccountMeta::new(Pubkey::new_unique(), false),
+                AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            ],
+            data: vec![IX, 1, 2, 3],
+        };
+        let config = VerificationConfig {
+            discriminator: 1,
+            instruction_discriminator: IX,
+            cpi_mode,
+            bump,
+            verification_programs: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            threshold: 0,
+            lookback_window: 0,
+            require_top_level: false,
+        };
+        (mint, instruction, config)
+    }
+
+    #[test]
+    fn test_prepare_cpi_mode_appends_programs() {
+        let (mint, instruction, config) = fixture(true);
+        let prepared = prepare_verified_instructions(instruction.clone(), &mint, &config).unwrap();
+
+        assert_eq!(prepared.len(), 1);
+        assert_eq!(prepared[0].accounts.len(), instruction.accounts.len() + 2);
+        assert_eq!(
+            prepared[0].accounts[instruction.accounts.len()..]
+                .iter()
+                .map(|meta| meta.pubkey)
+                .collect::<Vec<_>>(),
+            config.verification_programs
+        );
+    }
+
+    #[test]
+    fn test_prepare_keeps_program_config_last() {
+        let (program_config, _) = crate::pda::find_program_config_pda();
+        for cpi_mode in [true, false] {
+            let (mint, instruction, config) = fixture(cpi_mode);
+            let prepared = prepare_verified_instructions(
+                crate::halt::with_program_config(instruction.clone()),
+                &mint,
+                &config,
+            )
+            .unwrap();
+
+            let operation = prepared.last().unwrap();
+            assert_eq!(operation.accounts.last().unwrap().pubkey, program_config);
+            assert!(prepared[..prepared.len() - 1]
+                .iter()
+                .all(
+                    |verify_ix| verify_ix.accounts.len() + INSTRUCTION_ACCOUNTS_OFFSET
+                        == instruction.accounts.len()
+                ));
+        }
+    }
+
+    #[test]
+    fn test_prepare_introspection_mode_prepends_verification_calls() {
+        let (mint, instruction, config) = fixture(false);
+        let prepared = prepare_verified_instructions(instruction.clone(), &mint, &config).unwrap();
+
+        assert_eq!(prepared.len(), 3);
+        assert_eq!(prepared[2], instruction);
+        for (verify_ix, program_id) in prepared.iter().zip(&config.verification_programs) {
+            assert_eq!(verify_ix.program_id, *program_id);
+            assert_eq!(verify_ix.data, instruction.data);
+            assert_eq!(
+                verify_ix
+                    .accounts
+                    .iter()
+                    .map(|meta| meta.pubkey)
+                    .collect::<Vec<_>>(),
+                instruction.accounts[INSTRUCTION_ACCOUNTS_OFFSET..]
+                    .iter()
+                    .map(|meta| meta.pubkey)
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_prepare_with_threshold_uses_first_programs() {
+        let (mint, instruction, mut config) = fixture(true);
+        config.threshold = 1;
+        let prepared = prepare_verified_instructions(instruction.clone(), &mint, &config).unwrap();
+        assert_eq!(prepared[0].accounts.len(), instruction.accounts.len() + 1);
+        assert_eq!(
+            prepared[0].accounts.last().unwrap().pubkey,
+            config.verification_programs[0]
+        );
+
+        config.cpi_mode = false;
+        let prepared = prepare_verified_instructions(instruction.clone(), &mint, &config).unwrap();
+        assert_eq!(prepared.len(), 2);
+        assert_eq!(prepared[0].program_id, config.verification_programs[0]);
+        assert_eq!(prepared[1], instruction);
+    }
+
+    #[test]
+    fn test_verification_event_from_log_data() {
+        let program = Pubkey::new_unique();
+        let event = security_token_program::events::VerificationEvent {
+            mint: [1u8; 32],
+            instruction_discriminator: IX,
+            cpi_mode: false,
+            threshold: 2,
+            reason: 1,
+            programs: vec![program.to_bytes()],
+        };
+
+        let decoded = VerificationEvent::from_log_data(&event.to_bytes()).unwrap();
+        assert_eq!(decoded.mint, Pubkey::new_from_array([1u8; 32]));
+        assert_eq!(decoded.instruction_discriminator, IX);
+        assert_eq!(decoded.mode, VerificationMode::Introspection);
+        assert_eq!(decoded.threshold, 2);
+        assert_eq!(decoded.programs, vec![program]);
+        assert!(!decoded.passed());
+        assert_eq!(
+            decoded.error(),
+            Some(SecurityTokenProgramError::VerificationProgramNotFound)
+        );
+
+        let mut data = event.to_bytes();
+        data.pop();
+        assert_eq!(VerificationEvent::from_log_data(&data), None);
+        assert_eq!(VerificationEvent::from_log_data(&[0u8; 45]), None);
+    }
+
+    #[test]
+    fn test_prepare_can_transfer_verifies_transfer_data() {
+        let (mint, mut instruction, mut config) = fixture(false);
+        let (config_pda, bump) = find_verification_config_pda(&mint, TRANSFER_DISCRIMINATOR);
+        instruction.accounts[1].pubkey = config_pda;
+        instruction.data[0] = CAN_TRANSFER_DISCRIMINATOR;
+        config.instruction_discriminator = TRANSFER_DISCRIMINATOR;
+        config.bump = bump;
+
+        let prepared =
+            prepare_can_transfer_instructions(instruction.clone(), &mint, &config).unwrap();
+        assert_eq!(prepared.len(), 3);
+        assert_eq!(prepared[0].data, vec![TRANSFER_DISCRIMINATOR, 1, 2, 3]);
+        assert_eq!(prepared[2], instruction);
+
+        // Only CanTransfer instructions are prepared
+        instruction.data[0] = TRANSFER_DISCRIMINATOR;
+        assert!(prepare_can_transfer_instructions(instruction, &mint, &config).is_err());
+
+        let check = TransferCheck::from_return_data(&25u64.to_le_bytes()).unwrap();
+        assert!(!check.passed());
+        assert_eq!(
+            check.error(),
+            Some(SecurityTokenProgramError::AccountSuspended)
+        );
+        assert!(TransferCheck::from_return_data(&[0u8; 8]).unwrap().passed());
+        assert_eq!(TransferCheck::from_return_data(&[0u8; 4]), None);
+    }
+
+    #[test]
+    fn test_introspection_flow_orders_verification_calls() {
+        let (mint, instruction, config) = fixture(false);
+        let setup = Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![],
+            data: vec![],
+        };
+        let mut second = instruction.clone();
+        second.data = vec![IX, 4, 5, 6];
+
+        let instructions = IntrospectionFlow::new()
+            .with_instruction(setup.clone())
+            .with_verified_instruction(instruction.clone(), &mint, &config)
+            .unwrap()
+            .with_verified_instruction(second.clone(), &mint, &config)
+            .unwrap()
+            .build();
+        assert_eq!(instructions.len(), 7);
+        assert_eq!(instructions[0], setup);
+        assert_eq!(instructions[3], instruction);
+        assert_eq!(instructions[6], second);
+        for (verify_ix, program_id) in instructions[4..6].iter().zip(&config.verification_programs)
+        {
+            assert_eq!(verify_ix.program_id, *program_id);
+            assert_eq!(verify_ix.data, second.data);
+        }
+
+        // Verification calls out of the lookback window
+        let mut narrow = config.clone();
+        narrow.lookback_window = 1;
+        assert_eq!(
+            IntrospectionFlow::new().with_verified_instruction(instruction.clone(), &mint, &narrow),
+            Err(VerificationError::LookbackWindowTooSmall {
+                lookback_window: 1,
+                required: 2,
+            })
+        );
+        narrow.threshold = 1;
+        assert!(IntrospectionFlow::new()
+            .with_verified_instruction(instruction, &mint, &narrow)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_prepare_rejects_mismatched_config() {
+        let (mint, instruction, mut config) = fixture(false);
+        config.instruction_discriminator = IX + 1;
+        assert_eq!(
+            prepare_verified_instructions(instruction.clone(), &mint, &config),
+            Err(VerificationError::DiscriminatorMismatch {
+                config: IX + 1,
+                instruction: IX,
+            })
+        );
+
+        config.instruction_discriminator = IX;
+        assert!(matches!(
+            prepare_verified_instructions(instruction, &Pubkey::new_unique(), &config),
+            Err(VerificationError::ConfigMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_with_multisig_signers_replaces_creator() {
+        let (_, instruction, _) = fixture(false);
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let signed = with_multisig_signers(instruction.clone(), &signers).unwrap();
+
+        assert_eq!(signed.accounts.len(), instruction.accounts.len() + 1);
+        assert_eq!(signed.accounts[..2], instruction.accounts[..2]);
+        assert_eq!(
+            signed.accounts[2],
+            AccountMeta::new_readonly(signers[0], true)
+        );
+        assert_eq!(
+            signed.accounts[3],
+            AccountMeta::new_readonly(signers[1], true)
+        );
+        assert_eq!(signed.accounts[4..], instruction.accounts[3..]);
+    }
+}
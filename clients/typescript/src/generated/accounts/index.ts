@@ -6,7 +6,55 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './accrualConfig';
+export * from './agent';
+export * from './allowlistEntry';
+export * from './auction';
+export * from './auctionBid';
+export * from './claimBitmap';
+export * from './claimDeadline';
+export * from './collateralAttestation';
+export * from './configAdmin';
+export * from './configTemplate';
+export * from './couponClaim';
+export * from './distributionPayout';
+export * from './distributionRoot';
+export * from './documentRegistry';
+export * from './freezeExpiry';
+export * from './holdingLot';
+export * from './holdingPeriod';
+export * from './identity';
+export * from './identityWallet';
+export * from './maturity';
+export * from './metadataFreeze';
+export * from './metadataSchema';
+export * from './metadataTranslator';
 export * from './mintAuthority';
+export * from './mintMigration';
+export * from './navOracle';
+export * from './pauseDelegate';
+export * from './pendingTransfer';
+export * from './pendingVerificationConfig';
+export * from './permanentDelegateConfig';
+export * from './permitNonce';
+export * from './positionLimit';
+export * from './programConfig';
 export * from './proof';
 export * from './rate';
+export * from './recoveryReceipt';
+export * from './reserveAttestation';
+export * from './restrictedHolding';
+export * from './sessionKey';
+export * from './subAccount';
+export * from './subAccountLedger';
+export * from './subscription';
+export * from './subscriptionCommitment';
+export * from './successionPolicy';
+export * from './suspension';
+export * from './transferAcceptance';
+export * from './transferApproval';
+export * from './transferRequest';
 export * from './verificationConfig';
+export * from './vesting';
+export * from './withholdingRate';
+export * from './wrapVault';
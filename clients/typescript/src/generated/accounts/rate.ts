@@ -13,8 +13,14 @@ import {
   decodeAccount,
   fetchEncodedAccount,
   fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Account,
@@ -41,6 +47,11 @@ export type Rate = {
   numerator: number;
   denominator: number;
   bump: number;
+  oracle: Address;
+  oracleValueOffset: number;
+  oracleValueDecimals: number;
+  oracleMinValue: bigint;
+  oracleMaxValue: bigint;
 };
 
 export type RateArgs = {
@@ -49,6 +60,11 @@ export type RateArgs = {
   numerator: number;
   denominator: number;
   bump: number;
+  oracle: Address;
+  oracleValueOffset: number;
+  oracleValueDecimals: number;
+  oracleMinValue: number | bigint;
+  oracleMaxValue: number | bigint;
 };
 
 export function getRateEncoder(): FixedSizeEncoder<RateArgs> {
@@ -58,6 +74,11 @@ export function getRateEncoder(): FixedSizeEncoder<RateArgs> {
     ['numerator', getU8Encoder()],
     ['denominator', getU8Encoder()],
     ['bump', getU8Encoder()],
+    ['oracle', getAddressEncoder()],
+    ['oracleValueOffset', getU32Encoder()],
+    ['oracleValueDecimals', getU8Encoder()],
+    ['oracleMinValue', getU64Encoder()],
+    ['oracleMaxValue', getU64Encoder()],
   ]);
 }
 
@@ -68,6 +89,11 @@ export function getRateDecoder(): FixedSizeDecoder<Rate> {
     ['numerator', getU8Decoder()],
     ['denominator', getU8Decoder()],
     ['bump', getU8Decoder()],
+    ['oracle', getAddressDecoder()],
+    ['oracleValueOffset', getU32Decoder()],
+    ['oracleValueDecimals', getU8Decoder()],
+    ['oracleMinValue', getU64Decoder()],
+    ['oracleMaxValue', getU64Decoder()],
   ]);
 }
 
@@ -129,5 +155,5 @@ export async function fetchAllMaybeRate(
 }
 
 export function getRateSize(): number {
-  return 4;
+  return 57;
 }
@@ -41,6 +41,9 @@ export type VerificationConfig = {
   cpiMode: boolean;
   bump: number;
   verificationPrograms: Array<Address>;
+  threshold: number;
+  lookbackWindow: number;
+  requireTopLevel: boolean;
 };
 
 export type VerificationConfigArgs = VerificationConfig;
@@ -52,6 +55,9 @@ export function getVerificationConfigEncoder(): Encoder<VerificationConfigArgs>
     ['cpiMode', getBooleanEncoder()],
     ['bump', getU8Encoder()],
     ['verificationPrograms', getArrayEncoder(getAddressEncoder())],
+    ['threshold', getU8Encoder()],
+    ['lookbackWindow', getU8Encoder()],
+    ['requireTopLevel', getBooleanEncoder()],
   ]);
 }
 
@@ -62,6 +68,9 @@ export function getVerificationConfigDecoder(): Decoder<VerificationConfig> {
     ['cpiMode', getBooleanDecoder()],
     ['bump', getU8Decoder()],
     ['verificationPrograms', getArrayDecoder(getAddressDecoder())],
+    ['threshold', getU8Decoder()],
+    ['lookbackWindow', getU8Decoder()],
+    ['requireTopLevel', getBooleanDecoder()],
   ]);
 }
 
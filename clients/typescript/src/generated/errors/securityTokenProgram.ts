@@ -28,28 +28,276 @@ export const SECURITY_TOKEN_PROGRAM_ERROR__CANNOT_MODIFY_EXTERNAL_METADATA_ACCOU
 export const SECURITY_TOKEN_PROGRAM_ERROR__INTERNAL_METADATA_REQUIRES_DATA = 0x6; // 6
 /** ExternalMetadataForbidsData: External metadata storage cannot accept metadata data in this instruction */
 export const SECURITY_TOKEN_PROGRAM_ERROR__EXTERNAL_METADATA_FORBIDS_DATA = 0x7; // 7
+/** InvalidUnderlyingMint: Underlying mint cannot be wrapped into this security token */
+export const SECURITY_TOKEN_PROGRAM_ERROR__INVALID_UNDERLYING_MINT = 0x8; // 8
+/** AgentPermissionDenied: Agent is not permitted to perform this instruction */
+export const SECURITY_TOKEN_PROGRAM_ERROR__AGENT_PERMISSION_DENIED = 0x9; // 9
+/** InvalidIdentityWallet: Token account cannot be linked to or unlinked from this identity */
+export const SECURITY_TOKEN_PROGRAM_ERROR__INVALID_IDENTITY_WALLET = 0xa; // 10
+/** PositionLimitExceeded: Transfer would exceed the investor position limit */
+export const SECURITY_TOKEN_PROGRAM_ERROR__POSITION_LIMIT_EXCEEDED = 0xb; // 11
+/** TransferAcceptanceRequired: Transfers of this mint must be accepted by the recipient */
+export const SECURITY_TOKEN_PROGRAM_ERROR__TRANSFER_ACCEPTANCE_REQUIRED = 0xc; // 12
+/** TransferApprovalRequired: Transfers above the approval threshold must be requested and approved */
+export const SECURITY_TOKEN_PROGRAM_ERROR__TRANSFER_APPROVAL_REQUIRED = 0xd; // 13
+/** RedemptionNotOpen: Redemption is only open between maturity and the redemption cutoff */
+export const SECURITY_TOKEN_PROGRAM_ERROR__REDEMPTION_NOT_OPEN = 0xe; // 14
+/** RedemptionCutoffNotReached: Unclaimed redemption funds can only be withdrawn after the redemption cutoff */
+export const SECURITY_TOKEN_PROGRAM_ERROR__REDEMPTION_CUTOFF_NOT_REACHED = 0xf; // 15
+/** OracleValueOutOfBounds: Oracle value is outside of the configured bounds */
+export const SECURITY_TOKEN_PROGRAM_ERROR__ORACLE_VALUE_OUT_OF_BOUNDS = 0x10; // 16
+/** MultiplierUpdateTooFrequent: Multiplier was updated too recently */
+export const SECURITY_TOKEN_PROGRAM_ERROR__MULTIPLIER_UPDATE_TOO_FREQUENT = 0x11; // 17
+/** SubscriptionClosed: Subscription is closed for commitments */
+export const SECURITY_TOKEN_PROGRAM_ERROR__SUBSCRIPTION_CLOSED = 0x12; // 18
+/** SubscriptionNotClosed: Subscription has not closed yet */
+export const SECURITY_TOKEN_PROGRAM_ERROR__SUBSCRIPTION_NOT_CLOSED = 0x13; // 19
+/** AllocationExceedsCommitment: Allocation exceeds the committed payment */
+export const SECURITY_TOKEN_PROGRAM_ERROR__ALLOCATION_EXCEEDS_COMMITMENT = 0x14; // 20
+/** AuctionNotOpen: Auction is not accepting bids */
+export const SECURITY_TOKEN_PROGRAM_ERROR__AUCTION_NOT_OPEN = 0x15; // 21
+/** AuctionNotClearable: Auction is still running or already cleared */
+export const SECURITY_TOKEN_PROGRAM_ERROR__AUCTION_NOT_CLEARABLE = 0x16; // 22
+/** AuctionNotCleared: Auction has not been cleared yet */
+export const SECURITY_TOKEN_PROGRAM_ERROR__AUCTION_NOT_CLEARED = 0x17; // 23
+/** HoldingPeriodNotElapsed: Holding period of the restricted lot has not elapsed */
+export const SECURITY_TOKEN_PROGRAM_ERROR__HOLDING_PERIOD_NOT_ELAPSED = 0x18; // 24
+/** AccountSuspended: Token account is suspended */
+export const SECURITY_TOKEN_PROGRAM_ERROR__ACCOUNT_SUSPENDED = 0x19; // 25
+/** FreezeNotExpired: Freeze has not reached its auto-thaw timestamp */
+export const SECURITY_TOKEN_PROGRAM_ERROR__FREEZE_NOT_EXPIRED = 0x1a; // 26
+/** NothingVested: No newly vested tokens to release */
+export const SECURITY_TOKEN_PROGRAM_ERROR__NOTHING_VESTED = 0x1b; // 27
+/** ClaimDeadlinePassed: Claim deadline of the distribution has passed */
+export const SECURITY_TOKEN_PROGRAM_ERROR__CLAIM_DEADLINE_PASSED = 0x1c; // 28
+/** ClaimWindowOpen: Claim window of the distribution is still open */
+export const SECURITY_TOKEN_PROGRAM_ERROR__CLAIM_WINDOW_OPEN = 0x1d; // 29
+/** ClaimWindowNotOpen: Claim window of the distribution has not opened */
+export const SECURITY_TOKEN_PROGRAM_ERROR__CLAIM_WINDOW_NOT_OPEN = 0x1e; // 30
+/** ClaimWindowStarted: Claim window of the distribution has already opened */
+export const SECURITY_TOKEN_PROGRAM_ERROR__CLAIM_WINDOW_STARTED = 0x1f; // 31
+/** DistributionRootSuperseded: Merkle root of the distribution was replaced */
+export const SECURITY_TOKEN_PROGRAM_ERROR__DISTRIBUTION_ROOT_SUPERSEDED = 0x20; // 32
+/** InvalidPermit: Permit is missing or not signed by the holder */
+export const SECURITY_TOKEN_PROGRAM_ERROR__INVALID_PERMIT = 0x21; // 33
+/** PermitExpired: Permit has expired */
+export const SECURITY_TOKEN_PROGRAM_ERROR__PERMIT_EXPIRED = 0x22; // 34
+/** InvalidPermitNonce: Permit nonce is not the next nonce of the token account */
+export const SECURITY_TOKEN_PROGRAM_ERROR__INVALID_PERMIT_NONCE = 0x23; // 35
+/** SessionKeyExpired: Session key has expired */
+export const SECURITY_TOKEN_PROGRAM_ERROR__SESSION_KEY_EXPIRED = 0x24; // 36
+/** SessionKeyPermissionDenied: Session key is not allowed to execute this instruction */
+export const SECURITY_TOKEN_PROGRAM_ERROR__SESSION_KEY_PERMISSION_DENIED = 0x25; // 37
+/** SessionKeyLimitExceeded: Operation amount exceeds the session key limit */
+export const SECURITY_TOKEN_PROGRAM_ERROR__SESSION_KEY_LIMIT_EXCEEDED = 0x26; // 38
+/** AccountNotRecoverable: Token account is not recoverable */
+export const SECURITY_TOKEN_PROGRAM_ERROR__ACCOUNT_NOT_RECOVERABLE = 0x27; // 39
+/** LeafAlreadyClaimed: Leaf already claimed */
+export const SECURITY_TOKEN_PROGRAM_ERROR__LEAF_ALREADY_CLAIMED = 0x28; // 40
+/** InvalidRentRecipient: Invalid rent recipient */
+export const SECURITY_TOKEN_PROGRAM_ERROR__INVALID_RENT_RECIPIENT = 0x29; // 41
+/** MintMigrationCreatorMismatch: Mint migration creator mismatch */
+export const SECURITY_TOKEN_PROGRAM_ERROR__MINT_MIGRATION_CREATOR_MISMATCH = 0x2a; // 42
+/** MissingMetadataField: Required metadata field is missing */
+export const SECURITY_TOKEN_PROGRAM_ERROR__MISSING_METADATA_FIELD = 0x2b; // 43
+/** MalformedMetadataField: Required metadata field is malformed */
+export const SECURITY_TOKEN_PROGRAM_ERROR__MALFORMED_METADATA_FIELD = 0x2c; // 44
+/** MetadataFrozen: Metadata is frozen */
+export const SECURITY_TOKEN_PROGRAM_ERROR__METADATA_FROZEN = 0x2d; // 45
+/** InvalidMetadataProgram: Invalid external metadata program */
+export const SECURITY_TOKEN_PROGRAM_ERROR__INVALID_METADATA_PROGRAM = 0x2e; // 46
+/** ConfigTemplateMismatch: Config template mismatch */
+export const SECURITY_TOKEN_PROGRAM_ERROR__CONFIG_TEMPLATE_MISMATCH = 0x2f; // 47
+/** ConfigTemplateNotSupported: Config template not supported for transfer */
+export const SECURITY_TOKEN_PROGRAM_ERROR__CONFIG_TEMPLATE_NOT_SUPPORTED = 0x30; // 48
+/** InstructionNotTopLevel: Instruction is not top-level */
+export const SECURITY_TOKEN_PROGRAM_ERROR__INSTRUCTION_NOT_TOP_LEVEL = 0x31; // 49
+/** MintPaused: Mint is paused */
+export const SECURITY_TOKEN_PROGRAM_ERROR__MINT_PAUSED = 0x32; // 50
+/** AccountFrozen: Token account is frozen */
+export const SECURITY_TOKEN_PROGRAM_ERROR__ACCOUNT_FROZEN = 0x33; // 51
+/** TokenCpiFailed: Token program CPI failed */
+export const SECURITY_TOKEN_PROGRAM_ERROR__TOKEN_CPI_FAILED = 0x34; // 52
+/** InvalidReserveAccount: Invalid reserve token account */
+export const SECURITY_TOKEN_PROGRAM_ERROR__INVALID_RESERVE_ACCOUNT = 0x35; // 53
+/** SubAccountBalanceExceeded: Sub-account balances exceed omnibus balance */
+export const SECURITY_TOKEN_PROGRAM_ERROR__SUB_ACCOUNT_BALANCE_EXCEEDED = 0x36; // 54
+/** SubAccountLedgerNotEmpty: Sub-account ledger is not empty */
+export const SECURITY_TOKEN_PROGRAM_ERROR__SUB_ACCOUNT_LEDGER_NOT_EMPTY = 0x37; // 55
+/** SuccessionNotClaimable: Succession not claimable yet */
+export const SECURITY_TOKEN_PROGRAM_ERROR__SUCCESSION_NOT_CLAIMABLE = 0x38; // 56
+/** SuccessionClaimed: Succession already claimed */
+export const SECURITY_TOKEN_PROGRAM_ERROR__SUCCESSION_CLAIMED = 0x39; // 57
+/** InvalidTranslationKey: Invalid translation key */
+export const SECURITY_TOKEN_PROGRAM_ERROR__INVALID_TRANSLATION_KEY = 0x3a; // 58
+/** DocumentAlreadyAnchored: Document already anchored */
+export const SECURITY_TOKEN_PROGRAM_ERROR__DOCUMENT_ALREADY_ANCHORED = 0x3b; // 59
+/** DocumentNotFound: Document not found */
+export const SECURITY_TOKEN_PROGRAM_ERROR__DOCUMENT_NOT_FOUND = 0x3c; // 60
+/** PermanentDelegateDisabled: Permanent delegate disabled */
+export const SECURITY_TOKEN_PROGRAM_ERROR__PERMANENT_DELEGATE_DISABLED = 0x3d; // 61
+/** MintSupplyNotZero: Mint supply not zero */
+export const SECURITY_TOKEN_PROGRAM_ERROR__MINT_SUPPLY_NOT_ZERO = 0x3e; // 62
+/** VerificationConfigTimelocked: Verification config timelocked */
+export const SECURITY_TOKEN_PROGRAM_ERROR__VERIFICATION_CONFIG_TIMELOCKED = 0x3f; // 63
+/** PendingConfigNotActive: Pending config not active */
+export const SECURITY_TOKEN_PROGRAM_ERROR__PENDING_CONFIG_NOT_ACTIVE = 0x40; // 64
+/** ProgramHalted: Program halted */
+export const SECURITY_TOKEN_PROGRAM_ERROR__PROGRAM_HALTED = 0x41; // 65
+/** WalletNotAllowlisted: Wallet not allowlisted */
+export const SECURITY_TOKEN_PROGRAM_ERROR__WALLET_NOT_ALLOWLISTED = 0x42; // 66
+/** ProgramConfigRequired: Program config required */
+export const SECURITY_TOKEN_PROGRAM_ERROR__PROGRAM_CONFIG_REQUIRED = 0x43; // 67
+/** WrapVaultSupplyExceeded: Unwrap amount exceeds the wrapped supply of the wrap vault */
+export const SECURITY_TOKEN_PROGRAM_ERROR__WRAP_VAULT_SUPPLY_EXCEEDED = 0x44; // 68
+/** CouponClaimOutdated: Coupon claim outdated */
+export const SECURITY_TOKEN_PROGRAM_ERROR__COUPON_CLAIM_OUTDATED = 0x45; // 69
 
 export type SecurityTokenProgramError =
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__ACCOUNT_FROZEN
   | typeof SECURITY_TOKEN_PROGRAM_ERROR__ACCOUNT_INTERSECTION_MISMATCH
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__ACCOUNT_NOT_RECOVERABLE
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__ACCOUNT_SUSPENDED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__AGENT_PERMISSION_DENIED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__ALLOCATION_EXCEEDS_COMMITMENT
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__AUCTION_NOT_CLEARABLE
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__AUCTION_NOT_CLEARED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__AUCTION_NOT_OPEN
   | typeof SECURITY_TOKEN_PROGRAM_ERROR__CANNOT_MODIFY_EXTERNAL_METADATA_ACCOUNT
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__CLAIM_DEADLINE_PASSED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__CLAIM_WINDOW_NOT_OPEN
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__CLAIM_WINDOW_OPEN
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__CLAIM_WINDOW_STARTED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__CONFIG_TEMPLATE_MISMATCH
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__CONFIG_TEMPLATE_NOT_SUPPORTED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__COUPON_CLAIM_OUTDATED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__DISTRIBUTION_ROOT_SUPERSEDED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__DOCUMENT_ALREADY_ANCHORED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__DOCUMENT_NOT_FOUND
   | typeof SECURITY_TOKEN_PROGRAM_ERROR__EXTERNAL_METADATA_FORBIDS_DATA
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__FREEZE_NOT_EXPIRED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__HOLDING_PERIOD_NOT_ELAPSED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__INSTRUCTION_NOT_TOP_LEVEL
   | typeof SECURITY_TOKEN_PROGRAM_ERROR__INTERNAL_METADATA_REQUIRES_DATA
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__INVALID_IDENTITY_WALLET
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__INVALID_METADATA_PROGRAM
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__INVALID_PERMIT
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__INVALID_PERMIT_NONCE
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__INVALID_RENT_RECIPIENT
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__INVALID_RESERVE_ACCOUNT
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__INVALID_TRANSLATION_KEY
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__INVALID_UNDERLYING_MINT
   | typeof SECURITY_TOKEN_PROGRAM_ERROR__INVALID_VERIFICATION_CONFIG_PDA
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__LEAF_ALREADY_CLAIMED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__MALFORMED_METADATA_FIELD
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__METADATA_FROZEN
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__MINT_MIGRATION_CREATOR_MISMATCH
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__MINT_PAUSED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__MINT_SUPPLY_NOT_ZERO
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__MISSING_METADATA_FIELD
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__MULTIPLIER_UPDATE_TOO_FREQUENT
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__NOTHING_VESTED
   | typeof SECURITY_TOKEN_PROGRAM_ERROR__NOT_ENOUGH_ACCOUNTS_FOR_VERIFICATION
-  | typeof SECURITY_TOKEN_PROGRAM_ERROR__VERIFICATION_PROGRAM_NOT_FOUND;
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__ORACLE_VALUE_OUT_OF_BOUNDS
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__PENDING_CONFIG_NOT_ACTIVE
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__PERMANENT_DELEGATE_DISABLED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__PERMIT_EXPIRED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__POSITION_LIMIT_EXCEEDED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__PROGRAM_CONFIG_REQUIRED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__PROGRAM_HALTED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__REDEMPTION_CUTOFF_NOT_REACHED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__REDEMPTION_NOT_OPEN
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__SESSION_KEY_EXPIRED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__SESSION_KEY_LIMIT_EXCEEDED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__SESSION_KEY_PERMISSION_DENIED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__SUBSCRIPTION_CLOSED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__SUBSCRIPTION_NOT_CLOSED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__SUB_ACCOUNT_BALANCE_EXCEEDED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__SUB_ACCOUNT_LEDGER_NOT_EMPTY
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__SUCCESSION_CLAIMED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__SUCCESSION_NOT_CLAIMABLE
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__TOKEN_CPI_FAILED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__TRANSFER_ACCEPTANCE_REQUIRED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__TRANSFER_APPROVAL_REQUIRED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__VERIFICATION_CONFIG_TIMELOCKED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__VERIFICATION_PROGRAM_NOT_FOUND
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__WALLET_NOT_ALLOWLISTED
+  | typeof SECURITY_TOKEN_PROGRAM_ERROR__WRAP_VAULT_SUPPLY_EXCEEDED;
 
 let securityTokenProgramErrorMessages:
   | Record<SecurityTokenProgramError, string>
   | undefined;
 if (process.env.NODE_ENV !== 'production') {
   securityTokenProgramErrorMessages = {
+    [SECURITY_TOKEN_PROGRAM_ERROR__ACCOUNT_FROZEN]: `Token account is frozen`,
     [SECURITY_TOKEN_PROGRAM_ERROR__ACCOUNT_INTERSECTION_MISMATCH]: `Account intersection mismatch`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__ACCOUNT_NOT_RECOVERABLE]: `Token account is not recoverable`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__ACCOUNT_SUSPENDED]: `Token account is suspended`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__AGENT_PERMISSION_DENIED]: `Agent is not permitted to perform this instruction`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__ALLOCATION_EXCEEDS_COMMITMENT]: `Allocation exceeds the committed payment`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__AUCTION_NOT_CLEARABLE]: `Auction is still running or already cleared`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__AUCTION_NOT_CLEARED]: `Auction has not been cleared yet`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__AUCTION_NOT_OPEN]: `Auction is not accepting bids`,
     [SECURITY_TOKEN_PROGRAM_ERROR__CANNOT_MODIFY_EXTERNAL_METADATA_ACCOUNT]: `Cannot modify external metadata account`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__CLAIM_DEADLINE_PASSED]: `Claim deadline of the distribution has passed`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__CLAIM_WINDOW_NOT_OPEN]: `Claim window of the distribution has not opened`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__CLAIM_WINDOW_OPEN]: `Claim window of the distribution is still open`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__CLAIM_WINDOW_STARTED]: `Claim window of the distribution has already opened`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__CONFIG_TEMPLATE_MISMATCH]: `Config template mismatch`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__CONFIG_TEMPLATE_NOT_SUPPORTED]: `Config template not supported for transfer`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__COUPON_CLAIM_OUTDATED]: `Coupon claim outdated`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__DISTRIBUTION_ROOT_SUPERSEDED]: `Merkle root of the distribution was replaced`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__DOCUMENT_ALREADY_ANCHORED]: `Document already anchored`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__DOCUMENT_NOT_FOUND]: `Document not found`,
     [SECURITY_TOKEN_PROGRAM_ERROR__EXTERNAL_METADATA_FORBIDS_DATA]: `External metadata storage cannot accept metadata data in this instruction`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__FREEZE_NOT_EXPIRED]: `Freeze has not reached its auto-thaw timestamp`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__HOLDING_PERIOD_NOT_ELAPSED]: `Holding period of the restricted lot has not elapsed`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__INSTRUCTION_NOT_TOP_LEVEL]: `Instruction is not top-level`,
     [SECURITY_TOKEN_PROGRAM_ERROR__INTERNAL_METADATA_REQUIRES_DATA]: `Internal metadata storage requires metadata to be provided`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__INVALID_IDENTITY_WALLET]: `Token account cannot be linked to or unlinked from this identity`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__INVALID_METADATA_PROGRAM]: `Invalid external metadata program`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__INVALID_PERMIT]: `Permit is missing or not signed by the holder`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__INVALID_PERMIT_NONCE]: `Permit nonce is not the next nonce of the token account`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__INVALID_RENT_RECIPIENT]: `Invalid rent recipient`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__INVALID_RESERVE_ACCOUNT]: `Invalid reserve token account`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__INVALID_TRANSLATION_KEY]: `Invalid translation key`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__INVALID_UNDERLYING_MINT]: `Underlying mint cannot be wrapped into this security token`,
     [SECURITY_TOKEN_PROGRAM_ERROR__INVALID_VERIFICATION_CONFIG_PDA]: `Invalid Verification Config PDA`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__LEAF_ALREADY_CLAIMED]: `Leaf already claimed`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__MALFORMED_METADATA_FIELD]: `Required metadata field is malformed`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__METADATA_FROZEN]: `Metadata is frozen`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__MINT_MIGRATION_CREATOR_MISMATCH]: `Mint migration creator mismatch`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__MINT_PAUSED]: `Mint is paused`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__MINT_SUPPLY_NOT_ZERO]: `Mint supply not zero`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__MISSING_METADATA_FIELD]: `Required metadata field is missing`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__MULTIPLIER_UPDATE_TOO_FREQUENT]: `Multiplier was updated too recently`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__NOTHING_VESTED]: `No newly vested tokens to release`,
     [SECURITY_TOKEN_PROGRAM_ERROR__NOT_ENOUGH_ACCOUNTS_FOR_VERIFICATION]: `Not enough accounts for verification`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__ORACLE_VALUE_OUT_OF_BOUNDS]: `Oracle value is outside of the configured bounds`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__PENDING_CONFIG_NOT_ACTIVE]: `Pending config not active`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__PERMANENT_DELEGATE_DISABLED]: `Permanent delegate disabled`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__PERMIT_EXPIRED]: `Permit has expired`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__POSITION_LIMIT_EXCEEDED]: `Transfer would exceed the investor position limit`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__PROGRAM_CONFIG_REQUIRED]: `Program config required`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__PROGRAM_HALTED]: `Program halted`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__REDEMPTION_CUTOFF_NOT_REACHED]: `Unclaimed redemption funds can only be withdrawn after the redemption cutoff`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__REDEMPTION_NOT_OPEN]: `Redemption is only open between maturity and the redemption cutoff`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__SESSION_KEY_EXPIRED]: `Session key has expired`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__SESSION_KEY_LIMIT_EXCEEDED]: `Operation amount exceeds the session key limit`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__SESSION_KEY_PERMISSION_DENIED]: `Session key is not allowed to execute this instruction`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__SUBSCRIPTION_CLOSED]: `Subscription is closed for commitments`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__SUBSCRIPTION_NOT_CLOSED]: `Subscription has not closed yet`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__SUB_ACCOUNT_BALANCE_EXCEEDED]: `Sub-account balances exceed omnibus balance`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__SUB_ACCOUNT_LEDGER_NOT_EMPTY]: `Sub-account ledger is not empty`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__SUCCESSION_CLAIMED]: `Succession already claimed`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__SUCCESSION_NOT_CLAIMABLE]: `Succession not claimable yet`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__TOKEN_CPI_FAILED]: `Token program CPI failed`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__TRANSFER_ACCEPTANCE_REQUIRED]: `Transfers of this mint must be accepted by the recipient`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__TRANSFER_APPROVAL_REQUIRED]: `Transfers above the approval threshold must be requested and approved`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__VERIFICATION_CONFIG_TIMELOCKED]: `Verification config timelocked`,
     [SECURITY_TOKEN_PROGRAM_ERROR__VERIFICATION_PROGRAM_NOT_FOUND]: `Verification program not found`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__WALLET_NOT_ALLOWLISTED]: `Wallet not allowlisted`,
+    [SECURITY_TOKEN_PROGRAM_ERROR__WRAP_VAULT_SUPPLY_EXCEEDED]: `Unwrap amount exceeds the wrapped supply of the wrap vault`,
   };
 }
 
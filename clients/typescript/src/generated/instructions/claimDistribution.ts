@@ -23,6 +23,7 @@ import {
   type InstructionWithAccounts,
   type InstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -66,6 +67,24 @@ export type ClaimDistributionInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountWithholdingRateAccount extends string | AccountMeta<string> = string,
+  TAccountTaxEscrowTokenAccount extends string | AccountMeta<string> = string,
+  TAccountDistributionPayoutAccount extends
+    | string
+    | AccountMeta<string> = string,
+  TAccountPayoutMint extends string | AccountMeta<string> = string,
+  TAccountPayoutEscrowTokenAccount extends
+    | string
+    | AccountMeta<string> = string,
+  TAccountPayoutTokenAccount extends string | AccountMeta<string> = string,
+  TAccountPayoutTokenProgram extends string | AccountMeta<string> = string,
+  TAccountCustodian extends string | AccountMeta<string> = string,
+  TAccountInstructionsSysvarAccount extends
+    | string
+    | AccountMeta<string> = string,
+  TAccountClaimDeadlineAccount extends string | AccountMeta<string> = string,
+  TAccountDistributionRootAccount extends string | AccountMeta<string> = string,
+  TAccountClaimBitmapAccount extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -111,6 +130,43 @@ export type ClaimDistributionInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountWithholdingRateAccount extends string
+        ? ReadonlyAccount<TAccountWithholdingRateAccount>
+        : TAccountWithholdingRateAccount,
+      TAccountTaxEscrowTokenAccount extends string
+        ? WritableAccount<TAccountTaxEscrowTokenAccount>
+        : TAccountTaxEscrowTokenAccount,
+      TAccountDistributionPayoutAccount extends string
+        ? ReadonlyAccount<TAccountDistributionPayoutAccount>
+        : TAccountDistributionPayoutAccount,
+      TAccountPayoutMint extends string
+        ? ReadonlyAccount<TAccountPayoutMint>
+        : TAccountPayoutMint,
+      TAccountPayoutEscrowTokenAccount extends string
+        ? WritableAccount<TAccountPayoutEscrowTokenAccount>
+        : TAccountPayoutEscrowTokenAccount,
+      TAccountPayoutTokenAccount extends string
+        ? WritableAccount<TAccountPayoutTokenAccount>
+        : TAccountPayoutTokenAccount,
+      TAccountPayoutTokenProgram extends string
+        ? ReadonlyAccount<TAccountPayoutTokenProgram>
+        : TAccountPayoutTokenProgram,
+      TAccountCustodian extends string
+        ? ReadonlySignerAccount<TAccountCustodian> &
+            AccountSignerMeta<TAccountCustodian>
+        : TAccountCustodian,
+      TAccountInstructionsSysvarAccount extends string
+        ? ReadonlyAccount<TAccountInstructionsSysvarAccount>
+        : TAccountInstructionsSysvarAccount,
+      TAccountClaimDeadlineAccount extends string
+        ? ReadonlyAccount<TAccountClaimDeadlineAccount>
+        : TAccountClaimDeadlineAccount,
+      TAccountDistributionRootAccount extends string
+        ? ReadonlyAccount<TAccountDistributionRootAccount>
+        : TAccountDistributionRootAccount,
+      TAccountClaimBitmapAccount extends string
+        ? WritableAccount<TAccountClaimBitmapAccount>
+        : TAccountClaimBitmapAccount,
       ...TRemainingAccounts,
     ]
   >;
@@ -165,6 +221,18 @@ export type ClaimDistributionInput<
   TAccountTransferHookProgram extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountWithholdingRateAccount extends string = string,
+  TAccountTaxEscrowTokenAccount extends string = string,
+  TAccountDistributionPayoutAccount extends string = string,
+  TAccountPayoutMint extends string = string,
+  TAccountPayoutEscrowTokenAccount extends string = string,
+  TAccountPayoutTokenAccount extends string = string,
+  TAccountPayoutTokenProgram extends string = string,
+  TAccountCustodian extends string = string,
+  TAccountInstructionsSysvarAccount extends string = string,
+  TAccountClaimDeadlineAccount extends string = string,
+  TAccountDistributionRootAccount extends string = string,
+  TAccountClaimBitmapAccount extends string = string,
 > = {
   mint: Address<TAccountMint>;
   verificationConfig: Address<TAccountVerificationConfig>;
@@ -179,6 +247,18 @@ export type ClaimDistributionInput<
   transferHookProgram: Address<TAccountTransferHookProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  withholdingRateAccount?: Address<TAccountWithholdingRateAccount>;
+  taxEscrowTokenAccount?: Address<TAccountTaxEscrowTokenAccount>;
+  distributionPayoutAccount?: Address<TAccountDistributionPayoutAccount>;
+  payoutMint?: Address<TAccountPayoutMint>;
+  payoutEscrowTokenAccount?: Address<TAccountPayoutEscrowTokenAccount>;
+  payoutTokenAccount?: Address<TAccountPayoutTokenAccount>;
+  payoutTokenProgram?: Address<TAccountPayoutTokenProgram>;
+  custodian?: TransactionSigner<TAccountCustodian>;
+  instructionsSysvarAccount?: Address<TAccountInstructionsSysvarAccount>;
+  claimDeadlineAccount: Address<TAccountClaimDeadlineAccount>;
+  distributionRootAccount: Address<TAccountDistributionRootAccount>;
+  claimBitmapAccount: Address<TAccountClaimBitmapAccount>;
   claimDistributionArgs: ClaimDistributionInstructionDataArgs['claimDistributionArgs'];
 };
 
@@ -196,6 +276,18 @@ export function getClaimDistributionInstruction<
   TAccountTransferHookProgram extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountWithholdingRateAccount extends string,
+  TAccountTaxEscrowTokenAccount extends string,
+  TAccountDistributionPayoutAccount extends string,
+  TAccountPayoutMint extends string,
+  TAccountPayoutEscrowTokenAccount extends string,
+  TAccountPayoutTokenAccount extends string,
+  TAccountPayoutTokenProgram extends string,
+  TAccountCustodian extends string,
+  TAccountInstructionsSysvarAccount extends string,
+  TAccountClaimDeadlineAccount extends string,
+  TAccountDistributionRootAccount extends string,
+  TAccountClaimBitmapAccount extends string,
   TProgramAddress extends
     Address = typeof SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS,
 >(
@@ -212,7 +304,19 @@ export function getClaimDistributionInstruction<
     TAccountProofAccount,
     TAccountTransferHookProgram,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountWithholdingRateAccount,
+    TAccountTaxEscrowTokenAccount,
+    TAccountDistributionPayoutAccount,
+    TAccountPayoutMint,
+    TAccountPayoutEscrowTokenAccount,
+    TAccountPayoutTokenAccount,
+    TAccountPayoutTokenProgram,
+    TAccountCustodian,
+    TAccountInstructionsSysvarAccount,
+    TAccountClaimDeadlineAccount,
+    TAccountDistributionRootAccount,
+    TAccountClaimBitmapAccount
   >,
   config?: { programAddress?: TProgramAddress }
 ): ClaimDistributionInstruction<
@@ -229,7 +333,19 @@ export function getClaimDistributionInstruction<
   TAccountProofAccount,
   TAccountTransferHookProgram,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountWithholdingRateAccount,
+  TAccountTaxEscrowTokenAccount,
+  TAccountDistributionPayoutAccount,
+  TAccountPayoutMint,
+  TAccountPayoutEscrowTokenAccount,
+  TAccountPayoutTokenAccount,
+  TAccountPayoutTokenProgram,
+  TAccountCustodian,
+  TAccountInstructionsSysvarAccount,
+  TAccountClaimDeadlineAccount,
+  TAccountDistributionRootAccount,
+  TAccountClaimBitmapAccount
 > {
   // Program address.
   const programAddress =
@@ -268,6 +384,48 @@ export function getClaimDistributionInstruction<
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    withholdingRateAccount: {
+      value: input.withholdingRateAccount ?? null,
+      isWritable: false,
+    },
+    taxEscrowTokenAccount: {
+      value: input.taxEscrowTokenAccount ?? null,
+      isWritable: true,
+    },
+    distributionPayoutAccount: {
+      value: input.distributionPayoutAccount ?? null,
+      isWritable: false,
+    },
+    payoutMint: { value: input.payoutMint ?? null, isWritable: false },
+    payoutEscrowTokenAccount: {
+      value: input.payoutEscrowTokenAccount ?? null,
+      isWritable: true,
+    },
+    payoutTokenAccount: {
+      value: input.payoutTokenAccount ?? null,
+      isWritable: true,
+    },
+    payoutTokenProgram: {
+      value: input.payoutTokenProgram ?? null,
+      isWritable: false,
+    },
+    custodian: { value: input.custodian ?? null, isWritable: false },
+    instructionsSysvarAccount: {
+      value: input.instructionsSysvarAccount ?? null,
+      isWritable: false,
+    },
+    claimDeadlineAccount: {
+      value: input.claimDeadlineAccount ?? null,
+      isWritable: false,
+    },
+    distributionRootAccount: {
+      value: input.distributionRootAccount ?? null,
+      isWritable: false,
+    },
+    claimBitmapAccount: {
+      value: input.claimBitmapAccount ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -307,6 +465,18 @@ export function getClaimDistributionInstruction<
       getAccountMeta(accounts.transferHookProgram),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.withholdingRateAccount),
+      getAccountMeta(accounts.taxEscrowTokenAccount),
+      getAccountMeta(accounts.distributionPayoutAccount),
+      getAccountMeta(accounts.payoutMint),
+      getAccountMeta(accounts.payoutEscrowTokenAccount),
+      getAccountMeta(accounts.payoutTokenAccount),
+      getAccountMeta(accounts.payoutTokenProgram),
+      getAccountMeta(accounts.custodian),
+      getAccountMeta(accounts.instructionsSysvarAccount),
+      getAccountMeta(accounts.claimDeadlineAccount),
+      getAccountMeta(accounts.distributionRootAccount),
+      getAccountMeta(accounts.claimBitmapAccount),
     ],
     data: getClaimDistributionInstructionDataEncoder().encode(
       args as ClaimDistributionInstructionDataArgs
@@ -326,7 +496,19 @@ export function getClaimDistributionInstruction<
     TAccountProofAccount,
     TAccountTransferHookProgram,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountWithholdingRateAccount,
+    TAccountTaxEscrowTokenAccount,
+    TAccountDistributionPayoutAccount,
+    TAccountPayoutMint,
+    TAccountPayoutEscrowTokenAccount,
+    TAccountPayoutTokenAccount,
+    TAccountPayoutTokenProgram,
+    TAccountCustodian,
+    TAccountInstructionsSysvarAccount,
+    TAccountClaimDeadlineAccount,
+    TAccountDistributionRootAccount,
+    TAccountClaimBitmapAccount
   >);
 }
 
@@ -349,6 +531,18 @@ export type ParsedClaimDistributionInstruction<
     transferHookProgram: TAccountMetas[10];
     tokenProgram: TAccountMetas[11];
     systemProgram: TAccountMetas[12];
+    withholdingRateAccount?: TAccountMetas[13] | undefined;
+    taxEscrowTokenAccount?: TAccountMetas[14] | undefined;
+    distributionPayoutAccount?: TAccountMetas[15] | undefined;
+    payoutMint?: TAccountMetas[16] | undefined;
+    payoutEscrowTokenAccount?: TAccountMetas[17] | undefined;
+    payoutTokenAccount?: TAccountMetas[18] | undefined;
+    payoutTokenProgram?: TAccountMetas[19] | undefined;
+    custodian?: TAccountMetas[20] | undefined;
+    instructionsSysvarAccount?: TAccountMetas[21] | undefined;
+    claimDeadlineAccount: TAccountMetas[22];
+    distributionRootAccount: TAccountMetas[23];
+    claimBitmapAccount: TAccountMetas[24];
   };
   data: ClaimDistributionInstructionData;
 };
@@ -361,7 +555,7 @@ export function parseClaimDistributionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedClaimDistributionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 13) {
+  if (instruction.accounts.length < 25) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -393,6 +587,18 @@ export function parseClaimDistributionInstruction<
       transferHookProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      withholdingRateAccount: getNextOptionalAccount(),
+      taxEscrowTokenAccount: getNextOptionalAccount(),
+      distributionPayoutAccount: getNextOptionalAccount(),
+      payoutMint: getNextOptionalAccount(),
+      payoutEscrowTokenAccount: getNextOptionalAccount(),
+      payoutTokenAccount: getNextOptionalAccount(),
+      payoutTokenProgram: getNextOptionalAccount(),
+      custodian: getNextOptionalAccount(),
+      instructionsSysvarAccount: getNextOptionalAccount(),
+      claimDeadlineAccount: getNextAccount(),
+      distributionRootAccount: getNextAccount(),
+      claimBitmapAccount: getNextAccount(),
     },
     data: getClaimDistributionInstructionDataDecoder().decode(instruction.data),
   };
@@ -50,7 +50,7 @@ export type CloseActionReceiptAccountInstruction<
     | string
     | AccountMeta<string> = string,
   TAccountReceiptAccount extends string | AccountMeta<string> = string,
-  TAccountDestination extends string | AccountMeta<string> = string,
+  TAccountRentRecipient extends string | AccountMeta<string> = string,
   TAccountMintAccount extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
@@ -69,9 +69,9 @@ export type CloseActionReceiptAccountInstruction<
       TAccountReceiptAccount extends string
         ? WritableAccount<TAccountReceiptAccount>
         : TAccountReceiptAccount,
-      TAccountDestination extends string
-        ? WritableAccount<TAccountDestination>
-        : TAccountDestination,
+      TAccountRentRecipient extends string
+        ? WritableAccount<TAccountRentRecipient>
+        : TAccountRentRecipient,
       TAccountMintAccount extends string
         ? ReadonlyAccount<TAccountMintAccount>
         : TAccountMintAccount,
@@ -123,14 +123,14 @@ export type CloseActionReceiptAccountInput<
   TAccountVerificationConfigOrMintAuthority extends string = string,
   TAccountInstructionsSysvarOrCreator extends string = string,
   TAccountReceiptAccount extends string = string,
-  TAccountDestination extends string = string,
+  TAccountRentRecipient extends string = string,
   TAccountMintAccount extends string = string,
 > = {
   mint: Address<TAccountMint>;
   verificationConfigOrMintAuthority: Address<TAccountVerificationConfigOrMintAuthority>;
   instructionsSysvarOrCreator: Address<TAccountInstructionsSysvarOrCreator>;
   receiptAccount: Address<TAccountReceiptAccount>;
-  destination: Address<TAccountDestination>;
+  rentRecipient: Address<TAccountRentRecipient>;
   mintAccount: Address<TAccountMintAccount>;
   closeActionReceiptArgs: CloseActionReceiptAccountInstructionDataArgs['closeActionReceiptArgs'];
 };
@@ -140,7 +140,7 @@ export function getCloseActionReceiptAccountInstruction<
   TAccountVerificationConfigOrMintAuthority extends string,
   TAccountInstructionsSysvarOrCreator extends string,
   TAccountReceiptAccount extends string,
-  TAccountDestination extends string,
+  TAccountRentRecipient extends string,
   TAccountMintAccount extends string,
   TProgramAddress extends
     Address = typeof SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS,
@@ -150,7 +150,7 @@ export function getCloseActionReceiptAccountInstruction<
     TAccountVerificationConfigOrMintAuthority,
     TAccountInstructionsSysvarOrCreator,
     TAccountReceiptAccount,
-    TAccountDestination,
+    TAccountRentRecipient,
     TAccountMintAccount
   >,
   config?: { programAddress?: TProgramAddress }
@@ -160,7 +160,7 @@ export function getCloseActionReceiptAccountInstruction<
   TAccountVerificationConfigOrMintAuthority,
   TAccountInstructionsSysvarOrCreator,
   TAccountReceiptAccount,
-  TAccountDestination,
+  TAccountRentRecipient,
   TAccountMintAccount
 > {
   // Program address.
@@ -179,7 +179,7 @@ export function getCloseActionReceiptAccountInstruction<
       isWritable: false,
     },
     receiptAccount: { value: input.receiptAccount ?? null, isWritable: true },
-    destination: { value: input.destination ?? null, isWritable: true },
+    rentRecipient: { value: input.rentRecipient ?? null, isWritable: true },
     mintAccount: { value: input.mintAccount ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -197,7 +197,7 @@ export function getCloseActionReceiptAccountInstruction<
       getAccountMeta(accounts.verificationConfigOrMintAuthority),
       getAccountMeta(accounts.instructionsSysvarOrCreator),
       getAccountMeta(accounts.receiptAccount),
-      getAccountMeta(accounts.destination),
+      getAccountMeta(accounts.rentRecipient),
       getAccountMeta(accounts.mintAccount),
     ],
     data: getCloseActionReceiptAccountInstructionDataEncoder().encode(
@@ -210,7 +210,7 @@ export function getCloseActionReceiptAccountInstruction<
     TAccountVerificationConfigOrMintAuthority,
     TAccountInstructionsSysvarOrCreator,
     TAccountReceiptAccount,
-    TAccountDestination,
+    TAccountRentRecipient,
     TAccountMintAccount
   >);
 }
@@ -225,7 +225,7 @@ export type ParsedCloseActionReceiptAccountInstruction<
     verificationConfigOrMintAuthority: TAccountMetas[1];
     instructionsSysvarOrCreator: TAccountMetas[2];
     receiptAccount: TAccountMetas[3];
-    destination: TAccountMetas[4];
+    rentRecipient: TAccountMetas[4];
     mintAccount: TAccountMetas[5];
   };
   data: CloseActionReceiptAccountInstructionData;
@@ -256,7 +256,7 @@ export function parseCloseActionReceiptAccountInstruction<
       verificationConfigOrMintAuthority: getNextAccount(),
       instructionsSysvarOrCreator: getNextAccount(),
       receiptAccount: getNextAccount(),
-      destination: getNextAccount(),
+      rentRecipient: getNextAccount(),
       mintAccount: getNextAccount(),
     },
     data: getCloseActionReceiptAccountInstructionDataDecoder().decode(
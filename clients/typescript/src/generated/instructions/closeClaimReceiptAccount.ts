@@ -50,7 +50,7 @@ export type CloseClaimReceiptAccountInstruction<
     | string
     | AccountMeta<string> = string,
   TAccountReceiptAccount extends string | AccountMeta<string> = string,
-  TAccountDestination extends string | AccountMeta<string> = string,
+  TAccountRentRecipient extends string | AccountMeta<string> = string,
   TAccountMintAccount extends string | AccountMeta<string> = string,
   TAccountEligibleTokenAccount extends string | AccountMeta<string> = string,
   TAccountProofAccount extends string | AccountMeta<string> = string,
@@ -71,9 +71,9 @@ export type CloseClaimReceiptAccountInstruction<
       TAccountReceiptAccount extends string
         ? WritableAccount<TAccountReceiptAccount>
         : TAccountReceiptAccount,
-      TAccountDestination extends string
-        ? WritableAccount<TAccountDestination>
-        : TAccountDestination,
+      TAccountRentRecipient extends string
+        ? WritableAccount<TAccountRentRecipient>
+        : TAccountRentRecipient,
       TAccountMintAccount extends string
         ? ReadonlyAccount<TAccountMintAccount>
         : TAccountMintAccount,
@@ -131,7 +131,7 @@ export type CloseClaimReceiptAccountInput<
   TAccountVerificationConfigOrMintAuthority extends string = string,
   TAccountInstructionsSysvarOrCreator extends string = string,
   TAccountReceiptAccount extends string = string,
-  TAccountDestination extends string = string,
+  TAccountRentRecipient extends string = string,
   TAccountMintAccount extends string = string,
   TAccountEligibleTokenAccount extends string = string,
   TAccountProofAccount extends string = string,
@@ -140,7 +140,7 @@ export type CloseClaimReceiptAccountInput<
   verificationConfigOrMintAuthority: Address<TAccountVerificationConfigOrMintAuthority>;
   instructionsSysvarOrCreator: Address<TAccountInstructionsSysvarOrCreator>;
   receiptAccount: Address<TAccountReceiptAccount>;
-  destination: Address<TAccountDestination>;
+  rentRecipient: Address<TAccountRentRecipient>;
   mintAccount: Address<TAccountMintAccount>;
   eligibleTokenAccount: Address<TAccountEligibleTokenAccount>;
   proofAccount?: Address<TAccountProofAccount>;
@@ -152,7 +152,7 @@ export function getCloseClaimReceiptAccountInstruction<
   TAccountVerificationConfigOrMintAuthority extends string,
   TAccountInstructionsSysvarOrCreator extends string,
   TAccountReceiptAccount extends string,
-  TAccountDestination extends string,
+  TAccountRentRecipient extends string,
   TAccountMintAccount extends string,
   TAccountEligibleTokenAccount extends string,
   TAccountProofAccount extends string,
@@ -164,7 +164,7 @@ export function getCloseClaimReceiptAccountInstruction<
     TAccountVerificationConfigOrMintAuthority,
     TAccountInstructionsSysvarOrCreator,
     TAccountReceiptAccount,
-    TAccountDestination,
+    TAccountRentRecipient,
     TAccountMintAccount,
     TAccountEligibleTokenAccount,
     TAccountProofAccount
@@ -176,7 +176,7 @@ export function getCloseClaimReceiptAccountInstruction<
   TAccountVerificationConfigOrMintAuthority,
   TAccountInstructionsSysvarOrCreator,
   TAccountReceiptAccount,
-  TAccountDestination,
+  TAccountRentRecipient,
   TAccountMintAccount,
   TAccountEligibleTokenAccount,
   TAccountProofAccount
@@ -197,7 +197,7 @@ export function getCloseClaimReceiptAccountInstruction<
       isWritable: false,
     },
     receiptAccount: { value: input.receiptAccount ?? null, isWritable: true },
-    destination: { value: input.destination ?? null, isWritable: true },
+    rentRecipient: { value: input.rentRecipient ?? null, isWritable: true },
     mintAccount: { value: input.mintAccount ?? null, isWritable: false },
     eligibleTokenAccount: {
       value: input.eligibleTokenAccount ?? null,
@@ -220,7 +220,7 @@ export function getCloseClaimReceiptAccountInstruction<
       getAccountMeta(accounts.verificationConfigOrMintAuthority),
       getAccountMeta(accounts.instructionsSysvarOrCreator),
       getAccountMeta(accounts.receiptAccount),
-      getAccountMeta(accounts.destination),
+      getAccountMeta(accounts.rentRecipient),
       getAccountMeta(accounts.mintAccount),
       getAccountMeta(accounts.eligibleTokenAccount),
       getAccountMeta(accounts.proofAccount),
@@ -235,7 +235,7 @@ export function getCloseClaimReceiptAccountInstruction<
     TAccountVerificationConfigOrMintAuthority,
     TAccountInstructionsSysvarOrCreator,
     TAccountReceiptAccount,
-    TAccountDestination,
+    TAccountRentRecipient,
     TAccountMintAccount,
     TAccountEligibleTokenAccount,
     TAccountProofAccount
@@ -252,7 +252,7 @@ export type ParsedCloseClaimReceiptAccountInstruction<
     verificationConfigOrMintAuthority: TAccountMetas[1];
     instructionsSysvarOrCreator: TAccountMetas[2];
     receiptAccount: TAccountMetas[3];
-    destination: TAccountMetas[4];
+    rentRecipient: TAccountMetas[4];
     mintAccount: TAccountMetas[5];
     eligibleTokenAccount: TAccountMetas[6];
     proofAccount?: TAccountMetas[7] | undefined;
@@ -291,7 +291,7 @@ export function parseCloseClaimReceiptAccountInstruction<
       verificationConfigOrMintAuthority: getNextAccount(),
       instructionsSysvarOrCreator: getNextAccount(),
       receiptAccount: getNextAccount(),
-      destination: getNextAccount(),
+      rentRecipient: getNextAccount(),
       mintAccount: getNextAccount(),
       eligibleTokenAccount: getNextAccount(),
       proofAccount: getNextOptionalAccount(),
@@ -50,7 +50,7 @@ export type CloseRateAccountInstruction<
     | string
     | AccountMeta<string> = string,
   TAccountRateAccount extends string | AccountMeta<string> = string,
-  TAccountDestination extends string | AccountMeta<string> = string,
+  TAccountRentRecipient extends string | AccountMeta<string> = string,
   TAccountMintFrom extends string | AccountMeta<string> = string,
   TAccountMintTo extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
@@ -70,9 +70,9 @@ export type CloseRateAccountInstruction<
       TAccountRateAccount extends string
         ? WritableAccount<TAccountRateAccount>
         : TAccountRateAccount,
-      TAccountDestination extends string
-        ? WritableAccount<TAccountDestination>
-        : TAccountDestination,
+      TAccountRentRecipient extends string
+        ? WritableAccount<TAccountRentRecipient>
+        : TAccountRentRecipient,
       TAccountMintFrom extends string
         ? ReadonlyAccount<TAccountMintFrom>
         : TAccountMintFrom,
@@ -124,7 +124,7 @@ export type CloseRateAccountInput<
   TAccountVerificationConfigOrMintAuthority extends string = string,
   TAccountInstructionsSysvarOrCreator extends string = string,
   TAccountRateAccount extends string = string,
-  TAccountDestination extends string = string,
+  TAccountRentRecipient extends string = string,
   TAccountMintFrom extends string = string,
   TAccountMintTo extends string = string,
 > = {
@@ -132,7 +132,7 @@ export type CloseRateAccountInput<
   verificationConfigOrMintAuthority: Address<TAccountVerificationConfigOrMintAuthority>;
   instructionsSysvarOrCreator: Address<TAccountInstructionsSysvarOrCreator>;
   rateAccount: Address<TAccountRateAccount>;
-  destination: Address<TAccountDestination>;
+  rentRecipient: Address<TAccountRentRecipient>;
   mintFrom: Address<TAccountMintFrom>;
   mintTo: Address<TAccountMintTo>;
   closeRateArgs: CloseRateAccountInstructionDataArgs['closeRateArgs'];
@@ -143,7 +143,7 @@ export function getCloseRateAccountInstruction<
   TAccountVerificationConfigOrMintAuthority extends string,
   TAccountInstructionsSysvarOrCreator extends string,
   TAccountRateAccount extends string,
-  TAccountDestination extends string,
+  TAccountRentRecipient extends string,
   TAccountMintFrom extends string,
   TAccountMintTo extends string,
   TProgramAddress extends
@@ -154,7 +154,7 @@ export function getCloseRateAccountInstruction<
     TAccountVerificationConfigOrMintAuthority,
     TAccountInstructionsSysvarOrCreator,
     TAccountRateAccount,
-    TAccountDestination,
+    TAccountRentRecipient,
     TAccountMintFrom,
     TAccountMintTo
   >,
@@ -165,7 +165,7 @@ export function getCloseRateAccountInstruction<
   TAccountVerificationConfigOrMintAuthority,
   TAccountInstructionsSysvarOrCreator,
   TAccountRateAccount,
-  TAccountDestination,
+  TAccountRentRecipient,
   TAccountMintFrom,
   TAccountMintTo
 > {
@@ -185,7 +185,7 @@ export function getCloseRateAccountInstruction<
       isWritable: false,
     },
     rateAccount: { value: input.rateAccount ?? null, isWritable: true },
-    destination: { value: input.destination ?? null, isWritable: true },
+    rentRecipient: { value: input.rentRecipient ?? null, isWritable: true },
     mintFrom: { value: input.mintFrom ?? null, isWritable: false },
     mintTo: { value: input.mintTo ?? null, isWritable: false },
   };
@@ -204,7 +204,7 @@ export function getCloseRateAccountInstruction<
       getAccountMeta(accounts.verificationConfigOrMintAuthority),
       getAccountMeta(accounts.instructionsSysvarOrCreator),
       getAccountMeta(accounts.rateAccount),
-      getAccountMeta(accounts.destination),
+      getAccountMeta(accounts.rentRecipient),
       getAccountMeta(accounts.mintFrom),
       getAccountMeta(accounts.mintTo),
     ],
@@ -218,7 +218,7 @@ export function getCloseRateAccountInstruction<
     TAccountVerificationConfigOrMintAuthority,
     TAccountInstructionsSysvarOrCreator,
     TAccountRateAccount,
-    TAccountDestination,
+    TAccountRentRecipient,
     TAccountMintFrom,
     TAccountMintTo
   >);
@@ -234,7 +234,7 @@ export type ParsedCloseRateAccountInstruction<
     verificationConfigOrMintAuthority: TAccountMetas[1];
     instructionsSysvarOrCreator: TAccountMetas[2];
     rateAccount: TAccountMetas[3];
-    destination: TAccountMetas[4];
+    rentRecipient: TAccountMetas[4];
     mintFrom: TAccountMetas[5];
     mintTo: TAccountMetas[6];
   };
@@ -266,7 +266,7 @@ export function parseCloseRateAccountInstruction<
       verificationConfigOrMintAuthority: getNextAccount(),
       instructionsSysvarOrCreator: getNextAccount(),
       rateAccount: getNextAccount(),
-      destination: getNextAccount(),
+      rentRecipient: getNextAccount(),
       mintFrom: getNextAccount(),
       mintTo: getNextAccount(),
     },
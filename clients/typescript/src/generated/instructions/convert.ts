@@ -65,6 +65,7 @@ export type ConvertInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountOracle extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -113,6 +114,9 @@ export type ConvertInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountOracle extends string
+        ? ReadonlyAccount<TAccountOracle>
+        : TAccountOracle,
       ...TRemainingAccounts,
     ]
   >;
@@ -166,6 +170,7 @@ export type ConvertInput<
   TAccountReceiptAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountOracle extends string = string,
 > = {
   mint: Address<TAccountMint>;
   verificationConfig: Address<TAccountVerificationConfig>;
@@ -181,6 +186,7 @@ export type ConvertInput<
   receiptAccount: Address<TAccountReceiptAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  oracle?: Address<TAccountOracle>;
   convertArgs: ConvertInstructionDataArgs['convertArgs'];
 };
 
@@ -199,6 +205,7 @@ export function getConvertInstruction<
   TAccountReceiptAccount extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountOracle extends string,
   TProgramAddress extends
     Address = typeof SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS,
 >(
@@ -216,7 +223,8 @@ export function getConvertInstruction<
     TAccountRateAccount,
     TAccountReceiptAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountOracle
   >,
   config?: { programAddress?: TProgramAddress }
 ): ConvertInstruction<
@@ -234,7 +242,8 @@ export function getConvertInstruction<
   TAccountRateAccount,
   TAccountReceiptAccount,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountOracle
 > {
   // Program address.
   const programAddress =
@@ -268,6 +277,7 @@ export function getConvertInstruction<
     receiptAccount: { value: input.receiptAccount ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    oracle: { value: input.oracle ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -308,6 +318,7 @@ export function getConvertInstruction<
       getAccountMeta(accounts.receiptAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.oracle),
     ],
     data: getConvertInstructionDataEncoder().encode(
       args as ConvertInstructionDataArgs
@@ -328,7 +339,8 @@ export function getConvertInstruction<
     TAccountRateAccount,
     TAccountReceiptAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountOracle
   >);
 }
 
@@ -352,6 +364,7 @@ export type ParsedConvertInstruction<
     receiptAccount: TAccountMetas[11];
     tokenProgram: TAccountMetas[12];
     systemProgram: TAccountMetas[13];
+    oracle?: TAccountMetas[14] | undefined;
   };
   data: ConvertInstructionData;
 };
@@ -364,7 +377,7 @@ export function parseConvertInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedConvertInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -374,6 +387,12 @@ export function parseConvertInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -391,6 +410,7 @@ export function parseConvertInstruction<
       receiptAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      oracle: getNextOptionalAccount(),
     },
     data: getConvertInstructionDataDecoder().decode(instruction.data),
   };
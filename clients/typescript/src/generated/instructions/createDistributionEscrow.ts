@@ -69,6 +69,7 @@ export type CreateDistributionEscrowInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountClaimBitmapAccount extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -105,6 +106,9 @@ export type CreateDistributionEscrowInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountClaimBitmapAccount extends string
+        ? WritableAccount<TAccountClaimBitmapAccount>
+        : TAccountClaimBitmapAccount,
       ...TRemainingAccounts,
     ]
   >;
@@ -162,6 +166,7 @@ export type CreateDistributionEscrowInput<
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenAccountProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountClaimBitmapAccount extends string = string,
 > = {
   mint: Address<TAccountMint>;
   verificationConfigOrMintAuthority: Address<TAccountVerificationConfigOrMintAuthority>;
@@ -173,6 +178,7 @@ export type CreateDistributionEscrowInput<
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenAccountProgram: Address<TAccountAssociatedTokenAccountProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  claimBitmapAccount?: Address<TAccountClaimBitmapAccount>;
   createDistributionEscrowArgs: CreateDistributionEscrowInstructionDataArgs['createDistributionEscrowArgs'];
 };
 
@@ -187,6 +193,7 @@ export function getCreateDistributionEscrowInstruction<
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenAccountProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountClaimBitmapAccount extends string,
   TProgramAddress extends
     Address = typeof SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS,
 >(
@@ -200,7 +207,8 @@ export function getCreateDistributionEscrowInstruction<
     TAccountDistributionMint,
     TAccountTokenProgram,
     TAccountAssociatedTokenAccountProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountClaimBitmapAccount
   >,
   config?: { programAddress?: TProgramAddress }
 ): CreateDistributionEscrowInstruction<
@@ -214,7 +222,8 @@ export function getCreateDistributionEscrowInstruction<
   TAccountDistributionMint,
   TAccountTokenProgram,
   TAccountAssociatedTokenAccountProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountClaimBitmapAccount
 > {
   // Program address.
   const programAddress =
@@ -250,6 +259,10 @@ export function getCreateDistributionEscrowInstruction<
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    claimBitmapAccount: {
+      value: input.claimBitmapAccount ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -282,6 +295,7 @@ export function getCreateDistributionEscrowInstruction<
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenAccountProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.claimBitmapAccount),
     ],
     data: getCreateDistributionEscrowInstructionDataEncoder().encode(
       args as CreateDistributionEscrowInstructionDataArgs
@@ -298,7 +312,8 @@ export function getCreateDistributionEscrowInstruction<
     TAccountDistributionMint,
     TAccountTokenProgram,
     TAccountAssociatedTokenAccountProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountClaimBitmapAccount
   >);
 }
 
@@ -318,6 +333,7 @@ export type ParsedCreateDistributionEscrowInstruction<
     tokenProgram: TAccountMetas[7];
     associatedTokenAccountProgram: TAccountMetas[8];
     systemProgram: TAccountMetas[9];
+    claimBitmapAccount?: TAccountMetas[10] | undefined;
   };
   data: CreateDistributionEscrowInstructionData;
 };
@@ -330,7 +346,7 @@ export function parseCreateDistributionEscrowInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateDistributionEscrowInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -340,6 +356,12 @@ export function parseCreateDistributionEscrowInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -353,6 +375,7 @@ export function parseCreateDistributionEscrowInstruction<
       tokenProgram: getNextAccount(),
       associatedTokenAccountProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      claimBitmapAccount: getNextOptionalAccount(),
     },
     data: getCreateDistributionEscrowInstructionDataDecoder().decode(
       instruction.data
@@ -8,22 +8,31 @@
 
 import {
   combineCodec,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
   type AccountMeta,
+  type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -47,6 +56,9 @@ export type FreezeInstruction<
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
+  TAccountPayer extends string | AccountMeta<string> = string,
+  TAccountFreezeExpiryAccount extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,26 +85,47 @@ export type FreezeInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            AccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountFreezeExpiryAccount extends string
+        ? WritableAccount<TAccountFreezeExpiryAccount>
+        : TAccountFreezeExpiryAccount,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
 
-export type FreezeInstructionData = { discriminator: number };
+export type FreezeInstructionData = {
+  discriminator: number;
+  autoThawAt: Option<bigint>;
+};
 
-export type FreezeInstructionDataArgs = {};
+export type FreezeInstructionDataArgs = {
+  autoThawAt: OptionOrNullable<number | bigint>;
+};
 
-export function getFreezeInstructionDataEncoder(): FixedSizeEncoder<FreezeInstructionDataArgs> {
+export function getFreezeInstructionDataEncoder(): Encoder<FreezeInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['autoThawAt', getOptionEncoder(getI64Encoder())],
+    ]),
     (value) => ({ ...value, discriminator: FREEZE_DISCRIMINATOR })
   );
 }
 
-export function getFreezeInstructionDataDecoder(): FixedSizeDecoder<FreezeInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+export function getFreezeInstructionDataDecoder(): Decoder<FreezeInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['autoThawAt', getOptionDecoder(getI64Decoder())],
+  ]);
 }
 
-export function getFreezeInstructionDataCodec(): FixedSizeCodec<
+export function getFreezeInstructionDataCodec(): Codec<
   FreezeInstructionDataArgs,
   FreezeInstructionData
 > {
@@ -110,6 +143,9 @@ export type FreezeInput<
   TAccountMintAccount extends string = string,
   TAccountTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountPayer extends string = string,
+  TAccountFreezeExpiryAccount extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   mint: Address<TAccountMint>;
   verificationConfig: Address<TAccountVerificationConfig>;
@@ -118,6 +154,10 @@ export type FreezeInput<
   mintAccount: Address<TAccountMintAccount>;
   tokenAccount: Address<TAccountTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  payer?: TransactionSigner<TAccountPayer>;
+  freezeExpiryAccount?: Address<TAccountFreezeExpiryAccount>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  autoThawAt: FreezeInstructionDataArgs['autoThawAt'];
 };
 
 export function getFreezeInstruction<
@@ -128,6 +168,9 @@ export function getFreezeInstruction<
   TAccountMintAccount extends string,
   TAccountTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountPayer extends string,
+  TAccountFreezeExpiryAccount extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends
     Address = typeof SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS,
 >(
@@ -138,7 +181,10 @@ export function getFreezeInstruction<
     TAccountFreezeAuthority,
     TAccountMintAccount,
     TAccountTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountPayer,
+    TAccountFreezeExpiryAccount,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): FreezeInstruction<
@@ -149,7 +195,10 @@ export function getFreezeInstruction<
   TAccountFreezeAuthority,
   TAccountMintAccount,
   TAccountTokenAccount,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountPayer,
+  TAccountFreezeExpiryAccount,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress =
@@ -173,12 +222,21 @@ export function getFreezeInstruction<
     mintAccount: { value: input.mintAccount ?? null, isWritable: false },
     tokenAccount: { value: input.tokenAccount ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    freezeExpiryAccount: {
+      value: input.freezeExpiryAccount ?? null,
+      isWritable: true,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.instructionsSysvar.value) {
     accounts.instructionsSysvar.value =
@@ -199,8 +257,13 @@ export function getFreezeInstruction<
       getAccountMeta(accounts.mintAccount),
       getAccountMeta(accounts.tokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.freezeExpiryAccount),
+      getAccountMeta(accounts.systemProgram),
     ],
-    data: getFreezeInstructionDataEncoder().encode({}),
+    data: getFreezeInstructionDataEncoder().encode(
+      args as FreezeInstructionDataArgs
+    ),
     programAddress,
   } as FreezeInstruction<
     TProgramAddress,
@@ -210,7 +273,10 @@ export function getFreezeInstruction<
     TAccountFreezeAuthority,
     TAccountMintAccount,
     TAccountTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountPayer,
+    TAccountFreezeExpiryAccount,
+    TAccountSystemProgram
   >);
 }
 
@@ -227,6 +293,9 @@ export type ParsedFreezeInstruction<
     mintAccount: TAccountMetas[4];
     tokenAccount: TAccountMetas[5];
     tokenProgram: TAccountMetas[6];
+    payer?: TAccountMetas[7] | undefined;
+    freezeExpiryAccount?: TAccountMetas[8] | undefined;
+    systemProgram?: TAccountMetas[9] | undefined;
   };
   data: FreezeInstructionData;
 };
@@ -239,7 +308,7 @@ export function parseFreezeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedFreezeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -249,6 +318,12 @@ export function parseFreezeInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -259,6 +334,9 @@ export function parseFreezeInstruction<
       mintAccount: getNextAccount(),
       tokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
+      payer: getNextOptionalAccount(),
+      freezeExpiryAccount: getNextOptionalAccount(),
+      systemProgram: getNextOptionalAccount(),
     },
     data: getFreezeInstructionDataDecoder().decode(instruction.data),
   };
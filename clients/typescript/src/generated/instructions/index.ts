@@ -6,27 +6,138 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptMintCreatorTransfer';
+export * from './acceptTransfer';
+export * from './addIdentityWallet';
+export * from './addToAllowlist';
+export * from './allocate';
+export * from './amendDistributionRoot';
+export * from './anchorDocument';
+export * from './applyPendingConfig';
+export * from './approveTransfer';
+export * from './attestCollateral';
+export * from './auditMint';
 export * from './burn';
+export * from './canTransfer';
+export * from './cancelPendingConfig';
+export * from './cancelTransfer';
+export * from './claimCoupon';
 export * from './claimDistribution';
+export * from './claimSuccession';
+export * from './clearAuction';
 export * from './closeActionReceiptAccount';
+export * from './closeAgentAccount';
 export * from './closeClaimReceiptAccount';
+export * from './closeConfigTemplate';
+export * from './closeHoldingPeriod';
+export * from './closeIdentityAccount';
+export * from './closeMetadataSchema';
+export * from './closeMetadataTranslator';
+export * from './closeMintAuthority';
+export * from './closePositionLimit';
 export * from './closeRateAccount';
+export * from './closeSessionKey';
+export * from './closeSubAccountLedger';
+export * from './closeSuccessionPolicy';
+export * from './closeTransferAcceptance';
+export * from './closeTransferApproval';
+export * from './commit';
 export * from './convert';
+export * from './createAccrualConfig';
+export * from './createAgentAccount';
+export * from './createAuction';
+export * from './createClaimDeadline';
+export * from './createConfigTemplate';
 export * from './createDistributionEscrow';
+export * from './createDistributionPayout';
+export * from './createHoldingPeriod';
+export * from './createIdentityAccount';
+export * from './createMaturity';
+export * from './createMetadataSchema';
+export * from './createMetadataTranslator';
+export * from './createMintMigration';
+export * from './createNavOracle';
+export * from './createPositionLimit';
 export * from './createProofAccount';
 export * from './createRateAccount';
+export * from './createSessionKey';
+export * from './createSubAccountLedger';
+export * from './createSuccessionPolicy';
+export * from './createTransferAcceptance';
+export * from './createTransferApproval';
+export * from './createVesting';
+export * from './createWithholdingRate';
+export * from './createWrapVault';
+export * from './creditSubAccount';
+export * from './debitSubAccount';
+export * from './disablePermanentDelegate';
 export * from './freeze';
+export * from './freezeMetadata';
+export * from './getDistributionStatus';
+export * from './getEffectiveRate';
+export * from './getHolderStatus';
+export * from './harvestWithheldTokensToMint';
+export * from './heartbeat';
 export * from './initializeMint';
 export * from './initializeVerificationConfig';
+export * from './migrateBalances';
 export * from './mint';
+export * from './offerTransfer';
+export * from './openSubscription';
 export * from './pause';
+export * from './placeBid';
+export * from './proposeMintCreatorTransfer';
+export * from './publishReserveAttestation';
+export * from './reclaimUnclaimed';
+export * from './recoverTokens';
+export * from './redeemAtMaturity';
+export * from './rejectTransfer';
+export * from './releaseVested';
+export * from './removeDocument';
+export * from './removeFromAllowlist';
+export * from './removeIdentityWallet';
+export * from './removeMetadataField';
+export * from './removeRateOracle';
+export * from './requestTransfer';
 export * from './resume';
+export * from './rotatePermanentDelegate';
+export * from './setAllowlistMode';
+export * from './setConfigAdmin';
+export * from './setConfigTemplate';
+export * from './setEmergencyAuthority';
+export * from './setMintAuthorityMultisig';
+export * from './setPauseDelegate';
+export * from './setProgramHalt';
+export * from './setRateOracle';
+export * from './setTransferFee';
+export * from './settle';
+export * from './settleBid';
 export * from './split';
+export * from './suspend';
 export * from './thaw';
+export * from './thawExpired';
 export * from './transfer';
+export * from './transferWithPermit';
 export * from './trimVerificationConfig';
+export * from './unlockLot';
+export * from './unsuspend';
+export * from './unwrapToken';
+export * from './updateAgentAccount';
+export * from './updateConfigTemplate';
+export * from './updateDefaultAccountState';
+export * from './updateDocument';
 export * from './updateMetadata';
+export * from './updateMetadataSchema';
+export * from './updateMultiplierFromOracle';
+export * from './updatePositionLimit';
 export * from './updateProofAccount';
 export * from './updateRateAccount';
+export * from './updateScaledUiAmountMultiplier';
+export * from './updateTransferApproval';
+export * from './updateTranslation';
 export * from './updateVerificationConfig';
+export * from './updateWithholdingRate';
 export * from './verify';
+export * from './withdrawUnclaimedRedemption';
+export * from './withdrawWithheldTokensFromAccounts';
+export * from './wrapToken';
@@ -23,6 +23,7 @@ import {
   type InstructionWithAccounts,
   type InstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -57,6 +58,9 @@ export type InitializeMintInstruction<
   TAccountRentSysvar extends
     | string
     | AccountMeta<string> = 'SysvarRent111111111111111111111111111111111',
+  TAccountCreator extends string | AccountMeta<string> = string,
+  TAccountExternalMetadataAccount extends string | AccountMeta<string> = string,
+  TAccountMetadataProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -81,6 +85,16 @@ export type InitializeMintInstruction<
       TAccountRentSysvar extends string
         ? ReadonlyAccount<TAccountRentSysvar>
         : TAccountRentSysvar,
+      TAccountCreator extends string
+        ? ReadonlySignerAccount<TAccountCreator> &
+            AccountSignerMeta<TAccountCreator>
+        : TAccountCreator,
+      TAccountExternalMetadataAccount extends string
+        ? WritableAccount<TAccountExternalMetadataAccount>
+        : TAccountExternalMetadataAccount,
+      TAccountMetadataProgram extends string
+        ? ReadonlyAccount<TAccountMetadataProgram>
+        : TAccountMetadataProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -128,6 +142,9 @@ export type InitializeMintInput<
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountRentSysvar extends string = string,
+  TAccountCreator extends string = string,
+  TAccountExternalMetadataAccount extends string = string,
+  TAccountMetadataProgram extends string = string,
 > = {
   mint: TransactionSigner<TAccountMint>;
   authority: Address<TAccountAuthority>;
@@ -135,6 +152,9 @@ export type InitializeMintInput<
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   rentSysvar?: Address<TAccountRentSysvar>;
+  creator?: TransactionSigner<TAccountCreator>;
+  externalMetadataAccount?: Address<TAccountExternalMetadataAccount>;
+  metadataProgram?: Address<TAccountMetadataProgram>;
   initializeMintArgs: InitializeMintInstructionDataArgs['initializeMintArgs'];
 };
 
@@ -145,6 +165,9 @@ export function getInitializeMintInstruction<
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TAccountRentSysvar extends string,
+  TAccountCreator extends string,
+  TAccountExternalMetadataAccount extends string,
+  TAccountMetadataProgram extends string,
   TProgramAddress extends
     Address = typeof SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS,
 >(
@@ -154,7 +177,10 @@ export function getInitializeMintInstruction<
     TAccountPayer,
     TAccountTokenProgram,
     TAccountSystemProgram,
-    TAccountRentSysvar
+    TAccountRentSysvar,
+    TAccountCreator,
+    TAccountExternalMetadataAccount,
+    TAccountMetadataProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): InitializeMintInstruction<
@@ -164,7 +190,10 @@ export function getInitializeMintInstruction<
   TAccountPayer,
   TAccountTokenProgram,
   TAccountSystemProgram,
-  TAccountRentSysvar
+  TAccountRentSysvar,
+  TAccountCreator,
+  TAccountExternalMetadataAccount,
+  TAccountMetadataProgram
 > {
   // Program address.
   const programAddress =
@@ -178,6 +207,15 @@ export function getInitializeMintInstruction<
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     rentSysvar: { value: input.rentSysvar ?? null, isWritable: false },
+    creator: { value: input.creator ?? null, isWritable: false },
+    externalMetadataAccount: {
+      value: input.externalMetadataAccount ?? null,
+      isWritable: true,
+    },
+    metadataProgram: {
+      value: input.metadataProgram ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -210,6 +248,9 @@ export function getInitializeMintInstruction<
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.rentSysvar),
+      getAccountMeta(accounts.creator),
+      getAccountMeta(accounts.externalMetadataAccount),
+      getAccountMeta(accounts.metadataProgram),
     ],
     data: getInitializeMintInstructionDataEncoder().encode(
       args as InitializeMintInstructionDataArgs
@@ -222,7 +263,10 @@ export function getInitializeMintInstruction<
     TAccountPayer,
     TAccountTokenProgram,
     TAccountSystemProgram,
-    TAccountRentSysvar
+    TAccountRentSysvar,
+    TAccountCreator,
+    TAccountExternalMetadataAccount,
+    TAccountMetadataProgram
   >);
 }
 
@@ -238,6 +282,9 @@ export type ParsedInitializeMintInstruction<
     tokenProgram: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
     rentSysvar: TAccountMetas[5];
+    creator?: TAccountMetas[6] | undefined;
+    externalMetadataAccount?: TAccountMetas[7] | undefined;
+    metadataProgram?: TAccountMetas[8] | undefined;
   };
   data: InitializeMintInstructionData;
 };
@@ -250,7 +297,7 @@ export function parseInitializeMintInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitializeMintInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -260,6 +307,12 @@ export function parseInitializeMintInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -269,6 +322,9 @@ export function parseInitializeMintInstruction<
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
       rentSysvar: getNextAccount(),
+      creator: getNextOptionalAccount(),
+      externalMetadataAccount: getNextOptionalAccount(),
+      metadataProgram: getNextOptionalAccount(),
     },
     data: getInitializeMintInstructionDataDecoder().decode(instruction.data),
   };
@@ -16,6 +16,7 @@ import {
   getU8Encoder,
   transformEncoder,
   type AccountMeta,
+  type AccountSignerMeta,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -25,7 +26,9 @@ import {
   type InstructionWithData,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -49,6 +52,13 @@ export type MintInstruction<
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
+  TAccountLotPayer extends string | AccountMeta<string> = string,
+  TAccountHoldingPeriodAccount extends string | AccountMeta<string> = string,
+  TAccountRestrictedHoldingAccount extends
+    | string
+    | AccountMeta<string> = string,
+  TAccountHoldingLotAccount extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -75,6 +85,22 @@ export type MintInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountLotPayer extends string
+        ? WritableSignerAccount<TAccountLotPayer> &
+            AccountSignerMeta<TAccountLotPayer>
+        : TAccountLotPayer,
+      TAccountHoldingPeriodAccount extends string
+        ? ReadonlyAccount<TAccountHoldingPeriodAccount>
+        : TAccountHoldingPeriodAccount,
+      TAccountRestrictedHoldingAccount extends string
+        ? WritableAccount<TAccountRestrictedHoldingAccount>
+        : TAccountRestrictedHoldingAccount,
+      TAccountHoldingLotAccount extends string
+        ? WritableAccount<TAccountHoldingLotAccount>
+        : TAccountHoldingLotAccount,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -118,6 +144,11 @@ export type MintInput<
   TAccountMintAccount extends string = string,
   TAccountDestination extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountLotPayer extends string = string,
+  TAccountHoldingPeriodAccount extends string = string,
+  TAccountRestrictedHoldingAccount extends string = string,
+  TAccountHoldingLotAccount extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   mint: Address<TAccountMint>;
   verificationConfig: Address<TAccountVerificationConfig>;
@@ -126,6 +157,11 @@ export type MintInput<
   mintAccount: Address<TAccountMintAccount>;
   destination: Address<TAccountDestination>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  lotPayer?: TransactionSigner<TAccountLotPayer>;
+  holdingPeriodAccount?: Address<TAccountHoldingPeriodAccount>;
+  restrictedHoldingAccount?: Address<TAccountRestrictedHoldingAccount>;
+  holdingLotAccount?: Address<TAccountHoldingLotAccount>;
+  systemProgram?: Address<TAccountSystemProgram>;
   amount: MintInstructionDataArgs['amount'];
 };
 
@@ -137,6 +173,11 @@ export function getMintInstruction<
   TAccountMintAccount extends string,
   TAccountDestination extends string,
   TAccountTokenProgram extends string,
+  TAccountLotPayer extends string,
+  TAccountHoldingPeriodAccount extends string,
+  TAccountRestrictedHoldingAccount extends string,
+  TAccountHoldingLotAccount extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends
     Address = typeof SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS,
 >(
@@ -147,7 +188,12 @@ export function getMintInstruction<
     TAccountMintAuthority,
     TAccountMintAccount,
     TAccountDestination,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountLotPayer,
+    TAccountHoldingPeriodAccount,
+    TAccountRestrictedHoldingAccount,
+    TAccountHoldingLotAccount,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): MintInstruction<
@@ -158,7 +204,12 @@ export function getMintInstruction<
   TAccountMintAuthority,
   TAccountMintAccount,
   TAccountDestination,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountLotPayer,
+  TAccountHoldingPeriodAccount,
+  TAccountRestrictedHoldingAccount,
+  TAccountHoldingLotAccount,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress =
@@ -179,6 +230,20 @@ export function getMintInstruction<
     mintAccount: { value: input.mintAccount ?? null, isWritable: true },
     destination: { value: input.destination ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    lotPayer: { value: input.lotPayer ?? null, isWritable: true },
+    holdingPeriodAccount: {
+      value: input.holdingPeriodAccount ?? null,
+      isWritable: false,
+    },
+    restrictedHoldingAccount: {
+      value: input.restrictedHoldingAccount ?? null,
+      isWritable: true,
+    },
+    holdingLotAccount: {
+      value: input.holdingLotAccount ?? null,
+      isWritable: true,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -208,6 +273,11 @@ export function getMintInstruction<
       getAccountMeta(accounts.mintAccount),
       getAccountMeta(accounts.destination),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.lotPayer),
+      getAccountMeta(accounts.holdingPeriodAccount),
+      getAccountMeta(accounts.restrictedHoldingAccount),
+      getAccountMeta(accounts.holdingLotAccount),
+      getAccountMeta(accounts.systemProgram),
     ],
     data: getMintInstructionDataEncoder().encode(
       args as MintInstructionDataArgs
@@ -221,7 +291,12 @@ export function getMintInstruction<
     TAccountMintAuthority,
     TAccountMintAccount,
     TAccountDestination,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountLotPayer,
+    TAccountHoldingPeriodAccount,
+    TAccountRestrictedHoldingAccount,
+    TAccountHoldingLotAccount,
+    TAccountSystemProgram
   >);
 }
 
@@ -238,6 +313,11 @@ export type ParsedMintInstruction<
     mintAccount: TAccountMetas[4];
     destination: TAccountMetas[5];
     tokenProgram: TAccountMetas[6];
+    lotPayer?: TAccountMetas[7] | undefined;
+    holdingPeriodAccount?: TAccountMetas[8] | undefined;
+    restrictedHoldingAccount?: TAccountMetas[9] | undefined;
+    holdingLotAccount?: TAccountMetas[10] | undefined;
+    systemProgram?: TAccountMetas[11] | undefined;
   };
   data: MintInstructionData;
 };
@@ -250,7 +330,7 @@ export function parseMintInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedMintInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -260,6 +340,12 @@ export function parseMintInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -270,6 +356,11 @@ export function parseMintInstruction<
       mintAccount: getNextAccount(),
       destination: getNextAccount(),
       tokenProgram: getNextAccount(),
+      lotPayer: getNextOptionalAccount(),
+      holdingPeriodAccount: getNextOptionalAccount(),
+      restrictedHoldingAccount: getNextOptionalAccount(),
+      holdingLotAccount: getNextOptionalAccount(),
+      systemProgram: getNextOptionalAccount(),
     },
     data: getMintInstructionDataDecoder().decode(instruction.data),
   };
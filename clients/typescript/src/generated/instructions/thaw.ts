@@ -47,6 +47,8 @@ export type ThawInstruction<
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
+  TAccountFreezeExpiryAccount extends string | AccountMeta<string> = string,
+  TAccountRentDestination extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,6 +75,12 @@ export type ThawInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountFreezeExpiryAccount extends string
+        ? WritableAccount<TAccountFreezeExpiryAccount>
+        : TAccountFreezeExpiryAccount,
+      TAccountRentDestination extends string
+        ? WritableAccount<TAccountRentDestination>
+        : TAccountRentDestination,
       ...TRemainingAccounts,
     ]
   >;
@@ -110,6 +118,8 @@ export type ThawInput<
   TAccountMintAccount extends string = string,
   TAccountTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountFreezeExpiryAccount extends string = string,
+  TAccountRentDestination extends string = string,
 > = {
   mint: Address<TAccountMint>;
   verificationConfig: Address<TAccountVerificationConfig>;
@@ -118,6 +128,8 @@ export type ThawInput<
   mintAccount: Address<TAccountMintAccount>;
   tokenAccount: Address<TAccountTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  freezeExpiryAccount?: Address<TAccountFreezeExpiryAccount>;
+  rentDestination?: Address<TAccountRentDestination>;
 };
 
 export function getThawInstruction<
@@ -128,6 +140,8 @@ export function getThawInstruction<
   TAccountMintAccount extends string,
   TAccountTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountFreezeExpiryAccount extends string,
+  TAccountRentDestination extends string,
   TProgramAddress extends
     Address = typeof SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS,
 >(
@@ -138,7 +152,9 @@ export function getThawInstruction<
     TAccountFreezeAuthority,
     TAccountMintAccount,
     TAccountTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountFreezeExpiryAccount,
+    TAccountRentDestination
   >,
   config?: { programAddress?: TProgramAddress }
 ): ThawInstruction<
@@ -149,7 +165,9 @@ export function getThawInstruction<
   TAccountFreezeAuthority,
   TAccountMintAccount,
   TAccountTokenAccount,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountFreezeExpiryAccount,
+  TAccountRentDestination
 > {
   // Program address.
   const programAddress =
@@ -173,6 +191,11 @@ export function getThawInstruction<
     mintAccount: { value: input.mintAccount ?? null, isWritable: false },
     tokenAccount: { value: input.tokenAccount ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    freezeExpiryAccount: {
+      value: input.freezeExpiryAccount ?? null,
+      isWritable: true,
+    },
+    rentDestination: { value: input.rentDestination ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -199,6 +222,8 @@ export function getThawInstruction<
       getAccountMeta(accounts.mintAccount),
       getAccountMeta(accounts.tokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.freezeExpiryAccount),
+      getAccountMeta(accounts.rentDestination),
     ],
     data: getThawInstructionDataEncoder().encode({}),
     programAddress,
@@ -210,7 +235,9 @@ export function getThawInstruction<
     TAccountFreezeAuthority,
     TAccountMintAccount,
     TAccountTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountFreezeExpiryAccount,
+    TAccountRentDestination
   >);
 }
 
@@ -227,6 +254,8 @@ export type ParsedThawInstruction<
     mintAccount: TAccountMetas[4];
     tokenAccount: TAccountMetas[5];
     tokenProgram: TAccountMetas[6];
+    freezeExpiryAccount?: TAccountMetas[7] | undefined;
+    rentDestination?: TAccountMetas[8] | undefined;
   };
   data: ThawInstructionData;
 };
@@ -239,7 +268,7 @@ export function parseThawInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedThawInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -249,6 +278,12 @@ export function parseThawInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -259,6 +294,8 @@ export function parseThawInstruction<
       mintAccount: getNextAccount(),
       tokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
+      freezeExpiryAccount: getNextOptionalAccount(),
+      rentDestination: getNextOptionalAccount(),
     },
     data: getThawInstructionDataDecoder().decode(instruction.data),
   };
@@ -7,25 +7,42 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
   getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   transformEncoder,
   type AccountMeta,
+  type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -53,6 +70,14 @@ export type TransferInstruction<
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
+  TAccountLotPayer extends string | AccountMeta<string> = string,
+  TAccountHoldingPeriodAccount extends string | AccountMeta<string> = string,
+  TAccountRestrictedHoldingAccount extends
+    | string
+    | AccountMeta<string> = string,
+  TAccountHoldingLotAccount extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends string | AccountMeta<string> = string,
+  TAccountMemoProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -85,32 +110,72 @@ export type TransferInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountLotPayer extends string
+        ? WritableSignerAccount<TAccountLotPayer> &
+            AccountSignerMeta<TAccountLotPayer>
+        : TAccountLotPayer,
+      TAccountHoldingPeriodAccount extends string
+        ? ReadonlyAccount<TAccountHoldingPeriodAccount>
+        : TAccountHoldingPeriodAccount,
+      TAccountRestrictedHoldingAccount extends string
+        ? WritableAccount<TAccountRestrictedHoldingAccount>
+        : TAccountRestrictedHoldingAccount,
+      TAccountHoldingLotAccount extends string
+        ? WritableAccount<TAccountHoldingLotAccount>
+        : TAccountHoldingLotAccount,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountMemoProgram extends string
+        ? ReadonlyAccount<TAccountMemoProgram>
+        : TAccountMemoProgram,
       ...TRemainingAccounts,
     ]
   >;
 
-export type TransferInstructionData = { discriminator: number; amount: bigint };
+export type TransferInstructionData = {
+  discriminator: number;
+  amount: bigint;
+  memoHash: Option<ReadonlyUint8Array>;
+  memo: Option<string>;
+};
 
-export type TransferInstructionDataArgs = { amount: number | bigint };
+export type TransferInstructionDataArgs = {
+  amount: number | bigint;
+  memoHash: OptionOrNullable<ReadonlyUint8Array>;
+  memo: OptionOrNullable<string>;
+};
 
-export function getTransferInstructionDataEncoder(): FixedSizeEncoder<TransferInstructionDataArgs> {
+export function getTransferInstructionDataEncoder(): Encoder<TransferInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['amount', getU64Encoder()],
+      ['memoHash', getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32))],
+      [
+        'memo',
+        getOptionEncoder(
+          addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
+        ),
+      ],
     ]),
     (value) => ({ ...value, discriminator: TRANSFER_DISCRIMINATOR })
   );
 }
 
-export function getTransferInstructionDataDecoder(): FixedSizeDecoder<TransferInstructionData> {
+export function getTransferInstructionDataDecoder(): Decoder<TransferInstructionData> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['amount', getU64Decoder()],
+    ['memoHash', getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32))],
+    [
+      'memo',
+      getOptionDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
+    ],
   ]);
 }
 
-export function getTransferInstructionDataCodec(): FixedSizeCodec<
+export function getTransferInstructionDataCodec(): Codec<
   TransferInstructionDataArgs,
   TransferInstructionData
 > {
@@ -130,6 +195,12 @@ export type TransferInput<
   TAccountToTokenAccount extends string = string,
   TAccountTransferHookProgram extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountLotPayer extends string = string,
+  TAccountHoldingPeriodAccount extends string = string,
+  TAccountRestrictedHoldingAccount extends string = string,
+  TAccountHoldingLotAccount extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountMemoProgram extends string = string,
 > = {
   mint: Address<TAccountMint>;
   verificationConfig: Address<TAccountVerificationConfig>;
@@ -140,7 +211,15 @@ export type TransferInput<
   toTokenAccount: Address<TAccountToTokenAccount>;
   transferHookProgram: Address<TAccountTransferHookProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  lotPayer?: TransactionSigner<TAccountLotPayer>;
+  holdingPeriodAccount?: Address<TAccountHoldingPeriodAccount>;
+  restrictedHoldingAccount?: Address<TAccountRestrictedHoldingAccount>;
+  holdingLotAccount?: Address<TAccountHoldingLotAccount>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  memoProgram?: Address<TAccountMemoProgram>;
   amount: TransferInstructionDataArgs['amount'];
+  memoHash: TransferInstructionDataArgs['memoHash'];
+  memo: TransferInstructionDataArgs['memo'];
 };
 
 export function getTransferInstruction<
@@ -153,6 +232,12 @@ export function getTransferInstruction<
   TAccountToTokenAccount extends string,
   TAccountTransferHookProgram extends string,
   TAccountTokenProgram extends string,
+  TAccountLotPayer extends string,
+  TAccountHoldingPeriodAccount extends string,
+  TAccountRestrictedHoldingAccount extends string,
+  TAccountHoldingLotAccount extends string,
+  TAccountSystemProgram extends string,
+  TAccountMemoProgram extends string,
   TProgramAddress extends
     Address = typeof SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS,
 >(
@@ -165,7 +250,13 @@ export function getTransferInstruction<
     TAccountFromTokenAccount,
     TAccountToTokenAccount,
     TAccountTransferHookProgram,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountLotPayer,
+    TAccountHoldingPeriodAccount,
+    TAccountRestrictedHoldingAccount,
+    TAccountHoldingLotAccount,
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): TransferInstruction<
@@ -178,7 +269,13 @@ export function getTransferInstruction<
   TAccountFromTokenAccount,
   TAccountToTokenAccount,
   TAccountTransferHookProgram,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountLotPayer,
+  TAccountHoldingPeriodAccount,
+  TAccountRestrictedHoldingAccount,
+  TAccountHoldingLotAccount,
+  TAccountSystemProgram,
+  TAccountMemoProgram
 > {
   // Program address.
   const programAddress =
@@ -210,6 +307,21 @@ export function getTransferInstruction<
       isWritable: false,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    lotPayer: { value: input.lotPayer ?? null, isWritable: true },
+    holdingPeriodAccount: {
+      value: input.holdingPeriodAccount ?? null,
+      isWritable: false,
+    },
+    restrictedHoldingAccount: {
+      value: input.restrictedHoldingAccount ?? null,
+      isWritable: true,
+    },
+    holdingLotAccount: {
+      value: input.holdingLotAccount ?? null,
+      isWritable: true,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    memoProgram: { value: input.memoProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -241,6 +353,12 @@ export function getTransferInstruction<
       getAccountMeta(accounts.toTokenAccount),
       getAccountMeta(accounts.transferHookProgram),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.lotPayer),
+      getAccountMeta(accounts.holdingPeriodAccount),
+      getAccountMeta(accounts.restrictedHoldingAccount),
+      getAccountMeta(accounts.holdingLotAccount),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.memoProgram),
     ],
     data: getTransferInstructionDataEncoder().encode(
       args as TransferInstructionDataArgs
@@ -256,7 +374,13 @@ export function getTransferInstruction<
     TAccountFromTokenAccount,
     TAccountToTokenAccount,
     TAccountTransferHookProgram,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountLotPayer,
+    TAccountHoldingPeriodAccount,
+    TAccountRestrictedHoldingAccount,
+    TAccountHoldingLotAccount,
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >);
 }
 
@@ -275,6 +399,12 @@ export type ParsedTransferInstruction<
     toTokenAccount: TAccountMetas[6];
     transferHookProgram: TAccountMetas[7];
     tokenProgram: TAccountMetas[8];
+    lotPayer?: TAccountMetas[9] | undefined;
+    holdingPeriodAccount?: TAccountMetas[10] | undefined;
+    restrictedHoldingAccount?: TAccountMetas[11] | undefined;
+    holdingLotAccount?: TAccountMetas[12] | undefined;
+    systemProgram?: TAccountMetas[13] | undefined;
+    memoProgram?: TAccountMetas[14] | undefined;
   };
   data: TransferInstructionData;
 };
@@ -287,7 +417,7 @@ export function parseTransferInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedTransferInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -297,6 +427,12 @@ export function parseTransferInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -309,6 +445,12 @@ export function parseTransferInstruction<
       toTokenAccount: getNextAccount(),
       transferHookProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
+      lotPayer: getNextOptionalAccount(),
+      holdingPeriodAccount: getNextOptionalAccount(),
+      restrictedHoldingAccount: getNextOptionalAccount(),
+      holdingLotAccount: getNextOptionalAccount(),
+      systemProgram: getNextOptionalAccount(),
+      memoProgram: getNextOptionalAccount(),
     },
     data: getTransferInstructionDataDecoder().decode(instruction.data),
   };
@@ -14,6 +14,7 @@ import {
   getU8Encoder,
   transformEncoder,
   type AccountMeta,
+  type AccountSignerMeta,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -23,7 +24,9 @@ import {
   type InstructionWithData,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -49,9 +52,10 @@ export type TrimVerificationConfigInstruction<
   TAccountInstructionsSysvarOrCreator extends
     | string
     | AccountMeta<string> = string,
+  TAccountPayer extends string | AccountMeta<string> = string,
   TAccountMintAccount extends string | AccountMeta<string> = string,
   TAccountConfigAccount extends string | AccountMeta<string> = string,
-  TAccountRecipient extends string | AccountMeta<string> = string,
+  TAccountRentRecipient extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -72,15 +76,19 @@ export type TrimVerificationConfigInstruction<
       TAccountInstructionsSysvarOrCreator extends string
         ? ReadonlyAccount<TAccountInstructionsSysvarOrCreator>
         : TAccountInstructionsSysvarOrCreator,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            AccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
       TAccountMintAccount extends string
         ? ReadonlyAccount<TAccountMintAccount>
         : TAccountMintAccount,
       TAccountConfigAccount extends string
         ? WritableAccount<TAccountConfigAccount>
         : TAccountConfigAccount,
-      TAccountRecipient extends string
-        ? WritableAccount<TAccountRecipient>
-        : TAccountRecipient,
+      TAccountRentRecipient extends string
+        ? WritableAccount<TAccountRentRecipient>
+        : TAccountRentRecipient,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -140,9 +148,10 @@ export type TrimVerificationConfigInput<
   TAccountMint extends string = string,
   TAccountVerificationConfigOrMintAuthority extends string = string,
   TAccountInstructionsSysvarOrCreator extends string = string,
+  TAccountPayer extends string = string,
   TAccountMintAccount extends string = string,
   TAccountConfigAccount extends string = string,
-  TAccountRecipient extends string = string,
+  TAccountRentRecipient extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountAccountMetasPda extends string = string,
   TAccountTransferHookPda extends string = string,
@@ -151,9 +160,10 @@ export type TrimVerificationConfigInput<
   mint: Address<TAccountMint>;
   verificationConfigOrMintAuthority: Address<TAccountVerificationConfigOrMintAuthority>;
   instructionsSysvarOrCreator: Address<TAccountInstructionsSysvarOrCreator>;
+  payer: TransactionSigner<TAccountPayer>;
   mintAccount: Address<TAccountMintAccount>;
   configAccount: Address<TAccountConfigAccount>;
-  recipient: Address<TAccountRecipient>;
+  rentRecipient: Address<TAccountRentRecipient>;
   systemProgram?: Address<TAccountSystemProgram>;
   accountMetasPda?: Address<TAccountAccountMetasPda>;
   transferHookPda?: Address<TAccountTransferHookPda>;
@@ -165,9 +175,10 @@ export function getTrimVerificationConfigInstruction<
   TAccountMint extends string,
   TAccountVerificationConfigOrMintAuthority extends string,
   TAccountInstructionsSysvarOrCreator extends string,
+  TAccountPayer extends string,
   TAccountMintAccount extends string,
   TAccountConfigAccount extends string,
-  TAccountRecipient extends string,
+  TAccountRentRecipient extends string,
   TAccountSystemProgram extends string,
   TAccountAccountMetasPda extends string,
   TAccountTransferHookPda extends string,
@@ -179,9 +190,10 @@ export function getTrimVerificationConfigInstruction<
     TAccountMint,
     TAccountVerificationConfigOrMintAuthority,
     TAccountInstructionsSysvarOrCreator,
+    TAccountPayer,
     TAccountMintAccount,
     TAccountConfigAccount,
-    TAccountRecipient,
+    TAccountRentRecipient,
     TAccountSystemProgram,
     TAccountAccountMetasPda,
     TAccountTransferHookPda,
@@ -193,9 +205,10 @@ export function getTrimVerificationConfigInstruction<
   TAccountMint,
   TAccountVerificationConfigOrMintAuthority,
   TAccountInstructionsSysvarOrCreator,
+  TAccountPayer,
   TAccountMintAccount,
   TAccountConfigAccount,
-  TAccountRecipient,
+  TAccountRentRecipient,
   TAccountSystemProgram,
   TAccountAccountMetasPda,
   TAccountTransferHookPda,
@@ -216,9 +229,10 @@ export function getTrimVerificationConfigInstruction<
       value: input.instructionsSysvarOrCreator ?? null,
       isWritable: false,
     },
+    payer: { value: input.payer ?? null, isWritable: true },
     mintAccount: { value: input.mintAccount ?? null, isWritable: false },
     configAccount: { value: input.configAccount ?? null, isWritable: true },
-    recipient: { value: input.recipient ?? null, isWritable: true },
+    rentRecipient: { value: input.rentRecipient ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     accountMetasPda: { value: input.accountMetasPda ?? null, isWritable: true },
     transferHookPda: {
@@ -250,9 +264,10 @@ export function getTrimVerificationConfigInstruction<
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.verificationConfigOrMintAuthority),
       getAccountMeta(accounts.instructionsSysvarOrCreator),
+      getAccountMeta(accounts.payer),
       getAccountMeta(accounts.mintAccount),
       getAccountMeta(accounts.configAccount),
-      getAccountMeta(accounts.recipient),
+      getAccountMeta(accounts.rentRecipient),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.accountMetasPda),
       getAccountMeta(accounts.transferHookPda),
@@ -267,9 +282,10 @@ export function getTrimVerificationConfigInstruction<
     TAccountMint,
     TAccountVerificationConfigOrMintAuthority,
     TAccountInstructionsSysvarOrCreator,
+    TAccountPayer,
     TAccountMintAccount,
     TAccountConfigAccount,
-    TAccountRecipient,
+    TAccountRentRecipient,
     TAccountSystemProgram,
     TAccountAccountMetasPda,
     TAccountTransferHookPda,
@@ -286,13 +302,14 @@ export type ParsedTrimVerificationConfigInstruction<
     mint: TAccountMetas[0];
     verificationConfigOrMintAuthority: TAccountMetas[1];
     instructionsSysvarOrCreator: TAccountMetas[2];
-    mintAccount: TAccountMetas[3];
-    configAccount: TAccountMetas[4];
-    recipient: TAccountMetas[5];
-    systemProgram: TAccountMetas[6];
-    accountMetasPda?: TAccountMetas[7] | undefined;
-    transferHookPda?: TAccountMetas[8] | undefined;
-    transferHookProgram?: TAccountMetas[9] | undefined;
+    payer: TAccountMetas[3];
+    mintAccount: TAccountMetas[4];
+    configAccount: TAccountMetas[5];
+    rentRecipient: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
+    accountMetasPda?: TAccountMetas[8] | undefined;
+    transferHookPda?: TAccountMetas[9] | undefined;
+    transferHookProgram?: TAccountMetas[10] | undefined;
   };
   data: TrimVerificationConfigInstructionData;
 };
@@ -305,7 +322,7 @@ export function parseTrimVerificationConfigInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedTrimVerificationConfigInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -327,9 +344,10 @@ export function parseTrimVerificationConfigInstruction<
       mint: getNextAccount(),
       verificationConfigOrMintAuthority: getNextAccount(),
       instructionsSysvarOrCreator: getNextAccount(),
+      payer: getNextAccount(),
       mintAccount: getNextAccount(),
       configAccount: getNextAccount(),
-      recipient: getNextAccount(),
+      rentRecipient: getNextAccount(),
       systemProgram: getNextAccount(),
       accountMetasPda: getNextOptionalAccount(),
       transferHookPda: getNextOptionalAccount(),
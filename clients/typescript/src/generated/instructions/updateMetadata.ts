@@ -61,6 +61,9 @@ export type UpdateMetadataInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountMetadataSchemaAccount extends string | AccountMeta<string> = string,
+  TAccountExternalMetadataAccount extends string | AccountMeta<string> = string,
+  TAccountMetadataProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -91,6 +94,15 @@ export type UpdateMetadataInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountMetadataSchemaAccount extends string
+        ? ReadonlyAccount<TAccountMetadataSchemaAccount>
+        : TAccountMetadataSchemaAccount,
+      TAccountExternalMetadataAccount extends string
+        ? WritableAccount<TAccountExternalMetadataAccount>
+        : TAccountExternalMetadataAccount,
+      TAccountMetadataProgram extends string
+        ? ReadonlyAccount<TAccountMetadataProgram>
+        : TAccountMetadataProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -140,6 +152,9 @@ export type UpdateMetadataInput<
   TAccountMintAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountMetadataSchemaAccount extends string = string,
+  TAccountExternalMetadataAccount extends string = string,
+  TAccountMetadataProgram extends string = string,
 > = {
   mint: Address<TAccountMint>;
   verificationConfigOrMintAuthority: Address<TAccountVerificationConfigOrMintAuthority>;
@@ -149,6 +164,9 @@ export type UpdateMetadataInput<
   mintAccount: Address<TAccountMintAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  metadataSchemaAccount?: Address<TAccountMetadataSchemaAccount>;
+  externalMetadataAccount?: Address<TAccountExternalMetadataAccount>;
+  metadataProgram?: Address<TAccountMetadataProgram>;
   updateMetadataArgs: UpdateMetadataInstructionDataArgs['updateMetadataArgs'];
 };
 
@@ -161,6 +179,9 @@ export function getUpdateMetadataInstruction<
   TAccountMintAccount extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountMetadataSchemaAccount extends string,
+  TAccountExternalMetadataAccount extends string,
+  TAccountMetadataProgram extends string,
   TProgramAddress extends
     Address = typeof SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS,
 >(
@@ -172,7 +193,10 @@ export function getUpdateMetadataInstruction<
     TAccountPayer,
     TAccountMintAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMetadataSchemaAccount,
+    TAccountExternalMetadataAccount,
+    TAccountMetadataProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): UpdateMetadataInstruction<
@@ -184,7 +208,10 @@ export function getUpdateMetadataInstruction<
   TAccountPayer,
   TAccountMintAccount,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountMetadataSchemaAccount,
+  TAccountExternalMetadataAccount,
+  TAccountMetadataProgram
 > {
   // Program address.
   const programAddress =
@@ -206,6 +233,18 @@ export function getUpdateMetadataInstruction<
     mintAccount: { value: input.mintAccount ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    metadataSchemaAccount: {
+      value: input.metadataSchemaAccount ?? null,
+      isWritable: false,
+    },
+    externalMetadataAccount: {
+      value: input.externalMetadataAccount ?? null,
+      isWritable: true,
+    },
+    metadataProgram: {
+      value: input.metadataProgram ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -236,6 +275,9 @@ export function getUpdateMetadataInstruction<
       getAccountMeta(accounts.mintAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.metadataSchemaAccount),
+      getAccountMeta(accounts.externalMetadataAccount),
+      getAccountMeta(accounts.metadataProgram),
     ],
     data: getUpdateMetadataInstructionDataEncoder().encode(
       args as UpdateMetadataInstructionDataArgs
@@ -250,7 +292,10 @@ export function getUpdateMetadataInstruction<
     TAccountPayer,
     TAccountMintAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMetadataSchemaAccount,
+    TAccountExternalMetadataAccount,
+    TAccountMetadataProgram
   >);
 }
 
@@ -268,6 +313,9 @@ export type ParsedUpdateMetadataInstruction<
     mintAccount: TAccountMetas[5];
     tokenProgram: TAccountMetas[6];
     systemProgram: TAccountMetas[7];
+    metadataSchemaAccount?: TAccountMetas[8] | undefined;
+    externalMetadataAccount?: TAccountMetas[9] | undefined;
+    metadataProgram?: TAccountMetas[10] | undefined;
   };
   data: UpdateMetadataInstructionData;
 };
@@ -280,7 +328,7 @@ export function parseUpdateMetadataInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUpdateMetadataInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -290,6 +338,12 @@ export function parseUpdateMetadataInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -301,6 +355,9 @@ export function parseUpdateMetadataInstruction<
       mintAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      metadataSchemaAccount: getNextOptionalAccount(),
+      externalMetadataAccount: getNextOptionalAccount(),
+      metadataProgram: getNextOptionalAccount(),
     },
     data: getUpdateMetadataInstructionDataDecoder().decode(instruction.data),
   };
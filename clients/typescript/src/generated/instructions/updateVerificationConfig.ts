@@ -61,6 +61,7 @@ export type UpdateVerificationConfigInstruction<
   TAccountAccountMetasPda extends string | AccountMeta<string> = string,
   TAccountTransferHookPda extends string | AccountMeta<string> = string,
   TAccountTransferHookProgram extends string | AccountMeta<string> = string,
+  TAccountPendingConfigAccount extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -97,6 +98,9 @@ export type UpdateVerificationConfigInstruction<
       TAccountTransferHookProgram extends string
         ? ReadonlyAccount<TAccountTransferHookProgram>
         : TAccountTransferHookProgram,
+      TAccountPendingConfigAccount extends string
+        ? WritableAccount<TAccountPendingConfigAccount>
+        : TAccountPendingConfigAccount,
       ...TRemainingAccounts,
     ]
   >;
@@ -154,6 +158,7 @@ export type UpdateVerificationConfigInput<
   TAccountAccountMetasPda extends string = string,
   TAccountTransferHookPda extends string = string,
   TAccountTransferHookProgram extends string = string,
+  TAccountPendingConfigAccount extends string = string,
 > = {
   mint: Address<TAccountMint>;
   verificationConfigOrMintAuthority: Address<TAccountVerificationConfigOrMintAuthority>;
@@ -165,6 +170,7 @@ export type UpdateVerificationConfigInput<
   accountMetasPda?: Address<TAccountAccountMetasPda>;
   transferHookPda?: Address<TAccountTransferHookPda>;
   transferHookProgram?: Address<TAccountTransferHookProgram>;
+  pendingConfigAccount?: Address<TAccountPendingConfigAccount>;
   updateVerificationConfigArgs: UpdateVerificationConfigInstructionDataArgs['updateVerificationConfigArgs'];
 };
 
@@ -179,6 +185,7 @@ export function getUpdateVerificationConfigInstruction<
   TAccountAccountMetasPda extends string,
   TAccountTransferHookPda extends string,
   TAccountTransferHookProgram extends string,
+  TAccountPendingConfigAccount extends string,
   TProgramAddress extends
     Address = typeof SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS,
 >(
@@ -192,7 +199,8 @@ export function getUpdateVerificationConfigInstruction<
     TAccountSystemProgram,
     TAccountAccountMetasPda,
     TAccountTransferHookPda,
-    TAccountTransferHookProgram
+    TAccountTransferHookProgram,
+    TAccountPendingConfigAccount
   >,
   config?: { programAddress?: TProgramAddress }
 ): UpdateVerificationConfigInstruction<
@@ -206,7 +214,8 @@ export function getUpdateVerificationConfigInstruction<
   TAccountSystemProgram,
   TAccountAccountMetasPda,
   TAccountTransferHookPda,
-  TAccountTransferHookProgram
+  TAccountTransferHookProgram,
+  TAccountPendingConfigAccount
 > {
   // Program address.
   const programAddress =
@@ -236,6 +245,10 @@ export function getUpdateVerificationConfigInstruction<
       value: input.transferHookProgram ?? null,
       isWritable: false,
     },
+    pendingConfigAccount: {
+      value: input.pendingConfigAccount ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -264,6 +277,7 @@ export function getUpdateVerificationConfigInstruction<
       getAccountMeta(accounts.accountMetasPda),
       getAccountMeta(accounts.transferHookPda),
       getAccountMeta(accounts.transferHookProgram),
+      getAccountMeta(accounts.pendingConfigAccount),
     ],
     data: getUpdateVerificationConfigInstructionDataEncoder().encode(
       args as UpdateVerificationConfigInstructionDataArgs
@@ -280,7 +294,8 @@ export function getUpdateVerificationConfigInstruction<
     TAccountSystemProgram,
     TAccountAccountMetasPda,
     TAccountTransferHookPda,
-    TAccountTransferHookProgram
+    TAccountTransferHookProgram,
+    TAccountPendingConfigAccount
   >);
 }
 
@@ -300,6 +315,7 @@ export type ParsedUpdateVerificationConfigInstruction<
     accountMetasPda?: TAccountMetas[7] | undefined;
     transferHookPda?: TAccountMetas[8] | undefined;
     transferHookProgram?: TAccountMetas[9] | undefined;
+    pendingConfigAccount?: TAccountMetas[10] | undefined;
   };
   data: UpdateVerificationConfigInstructionData;
 };
@@ -312,7 +328,7 @@ export function parseUpdateVerificationConfigInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUpdateVerificationConfigInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -341,6 +357,7 @@ export function parseUpdateVerificationConfigInstruction<
       accountMetasPda: getNextOptionalAccount(),
       transferHookPda: getNextOptionalAccount(),
       transferHookProgram: getNextOptionalAccount(),
+      pendingConfigAccount: getNextOptionalAccount(),
     },
     data: getUpdateVerificationConfigInstructionDataDecoder().decode(
       instruction.data
@@ -13,40 +13,199 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAcceptMintCreatorTransferInstruction,
+  type ParsedAcceptTransferInstruction,
+  type ParsedAddIdentityWalletInstruction,
+  type ParsedAddToAllowlistInstruction,
+  type ParsedAllocateInstruction,
+  type ParsedAmendDistributionRootInstruction,
+  type ParsedAnchorDocumentInstruction,
+  type ParsedApplyPendingConfigInstruction,
+  type ParsedApproveTransferInstruction,
+  type ParsedAttestCollateralInstruction,
+  type ParsedAuditMintInstruction,
   type ParsedBurnInstruction,
+  type ParsedCanTransferInstruction,
+  type ParsedCancelPendingConfigInstruction,
+  type ParsedCancelTransferInstruction,
+  type ParsedClaimCouponInstruction,
   type ParsedClaimDistributionInstruction,
+  type ParsedClaimSuccessionInstruction,
+  type ParsedClearAuctionInstruction,
   type ParsedCloseActionReceiptAccountInstruction,
+  type ParsedCloseAgentAccountInstruction,
   type ParsedCloseClaimReceiptAccountInstruction,
+  type ParsedCloseConfigTemplateInstruction,
+  type ParsedCloseHoldingPeriodInstruction,
+  type ParsedCloseIdentityAccountInstruction,
+  type ParsedCloseMetadataSchemaInstruction,
+  type ParsedCloseMetadataTranslatorInstruction,
+  type ParsedCloseMintAuthorityInstruction,
+  type ParsedClosePositionLimitInstruction,
   type ParsedCloseRateAccountInstruction,
+  type ParsedCloseSessionKeyInstruction,
+  type ParsedCloseSubAccountLedgerInstruction,
+  type ParsedCloseSuccessionPolicyInstruction,
+  type ParsedCloseTransferAcceptanceInstruction,
+  type ParsedCloseTransferApprovalInstruction,
+  type ParsedCommitInstruction,
   type ParsedConvertInstruction,
+  type ParsedCreateAccrualConfigInstruction,
+  type ParsedCreateAgentAccountInstruction,
+  type ParsedCreateAuctionInstruction,
+  type ParsedCreateClaimDeadlineInstruction,
+  type ParsedCreateConfigTemplateInstruction,
   type ParsedCreateDistributionEscrowInstruction,
+  type ParsedCreateDistributionPayoutInstruction,
+  type ParsedCreateHoldingPeriodInstruction,
+  type ParsedCreateIdentityAccountInstruction,
+  type ParsedCreateMaturityInstruction,
+  type ParsedCreateMetadataSchemaInstruction,
+  type ParsedCreateMetadataTranslatorInstruction,
+  type ParsedCreateMintMigrationInstruction,
+  type ParsedCreateNavOracleInstruction,
+  type ParsedCreatePositionLimitInstruction,
   type ParsedCreateProofAccountInstruction,
   type ParsedCreateRateAccountInstruction,
+  type ParsedCreateSessionKeyInstruction,
+  type ParsedCreateSubAccountLedgerInstruction,
+  type ParsedCreateSuccessionPolicyInstruction,
+  type ParsedCreateTransferAcceptanceInstruction,
+  type ParsedCreateTransferApprovalInstruction,
+  type ParsedCreateVestingInstruction,
+  type ParsedCreateWithholdingRateInstruction,
+  type ParsedCreateWrapVaultInstruction,
+  type ParsedCreditSubAccountInstruction,
+  type ParsedDebitSubAccountInstruction,
+  type ParsedDisablePermanentDelegateInstruction,
   type ParsedFreezeInstruction,
+  type ParsedFreezeMetadataInstruction,
+  type ParsedGetDistributionStatusInstruction,
+  type ParsedGetEffectiveRateInstruction,
+  type ParsedGetHolderStatusInstruction,
+  type ParsedHarvestWithheldTokensToMintInstruction,
+  type ParsedHeartbeatInstruction,
   type ParsedInitializeMintInstruction,
   type ParsedInitializeVerificationConfigInstruction,
+  type ParsedMigrateBalancesInstruction,
   type ParsedMintInstruction,
+  type ParsedOfferTransferInstruction,
+  type ParsedOpenSubscriptionInstruction,
   type ParsedPauseInstruction,
+  type ParsedPlaceBidInstruction,
+  type ParsedProposeMintCreatorTransferInstruction,
+  type ParsedPublishReserveAttestationInstruction,
+  type ParsedReclaimUnclaimedInstruction,
+  type ParsedRecoverTokensInstruction,
+  type ParsedRedeemAtMaturityInstruction,
+  type ParsedRejectTransferInstruction,
+  type ParsedReleaseVestedInstruction,
+  type ParsedRemoveDocumentInstruction,
+  type ParsedRemoveFromAllowlistInstruction,
+  type ParsedRemoveIdentityWalletInstruction,
+  type ParsedRemoveMetadataFieldInstruction,
+  type ParsedRemoveRateOracleInstruction,
+  type ParsedRequestTransferInstruction,
   type ParsedResumeInstruction,
+  type ParsedRotatePermanentDelegateInstruction,
+  type ParsedSetAllowlistModeInstruction,
+  type ParsedSetConfigAdminInstruction,
+  type ParsedSetConfigTemplateInstruction,
+  type ParsedSetEmergencyAuthorityInstruction,
+  type ParsedSetMintAuthorityMultisigInstruction,
+  type ParsedSetPauseDelegateInstruction,
+  type ParsedSetProgramHaltInstruction,
+  type ParsedSetRateOracleInstruction,
+  type ParsedSetTransferFeeInstruction,
+  type ParsedSettleBidInstruction,
+  type ParsedSettleInstruction,
   type ParsedSplitInstruction,
+  type ParsedSuspendInstruction,
+  type ParsedThawExpiredInstruction,
   type ParsedThawInstruction,
   type ParsedTransferInstruction,
+  type ParsedTransferWithPermitInstruction,
   type ParsedTrimVerificationConfigInstruction,
+  type ParsedUnlockLotInstruction,
+  type ParsedUnsuspendInstruction,
+  type ParsedUnwrapTokenInstruction,
+  type ParsedUpdateAgentAccountInstruction,
+  type ParsedUpdateConfigTemplateInstruction,
+  type ParsedUpdateDefaultAccountStateInstruction,
+  type ParsedUpdateDocumentInstruction,
   type ParsedUpdateMetadataInstruction,
+  type ParsedUpdateMetadataSchemaInstruction,
+  type ParsedUpdateMultiplierFromOracleInstruction,
+  type ParsedUpdatePositionLimitInstruction,
   type ParsedUpdateProofAccountInstruction,
   type ParsedUpdateRateAccountInstruction,
+  type ParsedUpdateScaledUiAmountMultiplierInstruction,
+  type ParsedUpdateTransferApprovalInstruction,
+  type ParsedUpdateTranslationInstruction,
   type ParsedUpdateVerificationConfigInstruction,
+  type ParsedUpdateWithholdingRateInstruction,
   type ParsedVerifyInstruction,
+  type ParsedWithdrawUnclaimedRedemptionInstruction,
+  type ParsedWithdrawWithheldTokensFromAccountsInstruction,
+  type ParsedWrapTokenInstruction,
 } from '../instructions';
 
 export const SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS =
   'SSTS8Qk2bW3aVaBEsY1Ras95YdbaaYQQx21JWHxvjap' as Address<'SSTS8Qk2bW3aVaBEsY1Ras95YdbaaYQQx21JWHxvjap'>;
 
 export enum SecurityTokenProgramAccount {
+  AccrualConfig,
+  Agent,
+  AllowlistEntry,
+  Auction,
+  AuctionBid,
+  ClaimBitmap,
+  ClaimDeadline,
+  CollateralAttestation,
+  ConfigAdmin,
+  ConfigTemplate,
+  CouponClaim,
+  DistributionPayout,
+  DistributionRoot,
+  DocumentRegistry,
+  FreezeExpiry,
+  HoldingLot,
+  HoldingPeriod,
+  Identity,
+  IdentityWallet,
+  Maturity,
+  MetadataFreeze,
+  MetadataSchema,
+  MetadataTranslator,
   MintAuthority,
+  MintMigration,
+  NavOracle,
+  PauseDelegate,
+  PendingTransfer,
+  PendingVerificationConfig,
+  PermanentDelegateConfig,
+  PermitNonce,
+  PositionLimit,
+  ProgramConfig,
   Proof,
   Rate,
+  RecoveryReceipt,
+  ReserveAttestation,
+  RestrictedHolding,
+  SessionKey,
+  SubAccount,
+  SubAccountLedger,
+  Subscription,
+  SubscriptionCommitment,
+  SuccessionPolicy,
+  Suspension,
+  TransferAcceptance,
+  TransferApproval,
+  TransferRequest,
   VerificationConfig,
+  Vesting,
+  WithholdingRate,
+  WrapVault,
 }
 
 export enum SecurityTokenProgramInstruction {
@@ -74,6 +233,117 @@ export enum SecurityTokenProgramInstruction {
   ClaimDistribution,
   CloseActionReceiptAccount,
   CloseClaimReceiptAccount,
+  CreateWrapVault,
+  WrapToken,
+  UnwrapToken,
+  CreateAgentAccount,
+  UpdateAgentAccount,
+  CloseAgentAccount,
+  CreateIdentityAccount,
+  AddIdentityWallet,
+  RemoveIdentityWallet,
+  CloseIdentityAccount,
+  CreatePositionLimit,
+  UpdatePositionLimit,
+  ClosePositionLimit,
+  CreateTransferAcceptance,
+  CloseTransferAcceptance,
+  OfferTransfer,
+  AcceptTransfer,
+  CancelTransfer,
+  CreateTransferApproval,
+  UpdateTransferApproval,
+  CloseTransferApproval,
+  RequestTransfer,
+  ApproveTransfer,
+  RejectTransfer,
+  CreateAccrualConfig,
+  ClaimCoupon,
+  CreateMaturity,
+  RedeemAtMaturity,
+  WithdrawUnclaimedRedemption,
+  CreateNavOracle,
+  UpdateMultiplierFromOracle,
+  SetRateOracle,
+  RemoveRateOracle,
+  OpenSubscription,
+  Commit,
+  Allocate,
+  Settle,
+  CreateAuction,
+  PlaceBid,
+  ClearAuction,
+  SettleBid,
+  CreateHoldingPeriod,
+  CloseHoldingPeriod,
+  UnlockLot,
+  Suspend,
+  Unsuspend,
+  ThawExpired,
+  CreateVesting,
+  ReleaseVested,
+  CreateWithholdingRate,
+  UpdateWithholdingRate,
+  CreateDistributionPayout,
+  CreateClaimDeadline,
+  ReclaimUnclaimed,
+  AmendDistributionRoot,
+  TransferWithPermit,
+  CreateSessionKey,
+  CloseSessionKey,
+  RecoverTokens,
+  CreateMintMigration,
+  MigrateBalances,
+  CreateMetadataSchema,
+  UpdateMetadataSchema,
+  CloseMetadataSchema,
+  FreezeMetadata,
+  CreateConfigTemplate,
+  UpdateConfigTemplate,
+  CloseConfigTemplate,
+  SetConfigTemplate,
+  CanTransfer,
+  GetHolderStatus,
+  GetEffectiveRate,
+  GetDistributionStatus,
+  AttestCollateral,
+  PublishReserveAttestation,
+  CreateSubAccountLedger,
+  CreditSubAccount,
+  DebitSubAccount,
+  CloseSubAccountLedger,
+  CreateSuccessionPolicy,
+  Heartbeat,
+  ClaimSuccession,
+  CloseSuccessionPolicy,
+  CreateMetadataTranslator,
+  UpdateTranslation,
+  CloseMetadataTranslator,
+  AuditMint,
+  SetTransferFee,
+  WithdrawWithheldTokensFromAccounts,
+  HarvestWithheldTokensToMint,
+  UpdateDefaultAccountState,
+  UpdateScaledUiAmountMultiplier,
+  RemoveMetadataField,
+  AnchorDocument,
+  UpdateDocument,
+  RemoveDocument,
+  ProposeMintCreatorTransfer,
+  AcceptMintCreatorTransfer,
+  SetMintAuthorityMultisig,
+  RotatePermanentDelegate,
+  DisablePermanentDelegate,
+  SetPauseDelegate,
+  CloseMintAuthority,
+  SetConfigAdmin,
+  ApplyPendingConfig,
+  CancelPendingConfig,
+  SetEmergencyAuthority,
+  SetProgramHalt,
+  AddToAllowlist,
+  RemoveFromAllowlist,
+  SetAllowlistMode,
 }
 
 export function identifySecurityTokenProgramInstruction(
@@ -152,6 +422,339 @@ export function identifySecurityTokenProgramInstruction(
   if (containsBytes(data, getU8Encoder().encode(23), 0)) {
     return SecurityTokenProgramInstruction.CloseClaimReceiptAccount;
   }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return SecurityTokenProgramInstruction.CreateWrapVault;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return SecurityTokenProgramInstruction.WrapToken;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return SecurityTokenProgramInstruction.UnwrapToken;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return SecurityTokenProgramInstruction.CreateAgentAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return SecurityTokenProgramInstruction.UpdateAgentAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return SecurityTokenProgramInstruction.CloseAgentAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return SecurityTokenProgramInstruction.CreateIdentityAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return SecurityTokenProgramInstruction.AddIdentityWallet;
+  }
+  if (containsBytes(data, getU8Encoder().encode(32), 0)) {
+    return SecurityTokenProgramInstruction.RemoveIdentityWallet;
+  }
+  if (containsBytes(data, getU8Encoder().encode(33), 0)) {
+    return SecurityTokenProgramInstruction.CloseIdentityAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(34), 0)) {
+    return SecurityTokenProgramInstruction.CreatePositionLimit;
+  }
+  if (containsBytes(data, getU8Encoder().encode(35), 0)) {
+    return SecurityTokenProgramInstruction.UpdatePositionLimit;
+  }
+  if (containsBytes(data, getU8Encoder().encode(36), 0)) {
+    return SecurityTokenProgramInstruction.ClosePositionLimit;
+  }
+  if (containsBytes(data, getU8Encoder().encode(37), 0)) {
+    return SecurityTokenProgramInstruction.CreateTransferAcceptance;
+  }
+  if (containsBytes(data, getU8Encoder().encode(38), 0)) {
+    return SecurityTokenProgramInstruction.CloseTransferAcceptance;
+  }
+  if (containsBytes(data, getU8Encoder().encode(39), 0)) {
+    return SecurityTokenProgramInstruction.OfferTransfer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(40), 0)) {
+    return SecurityTokenProgramInstruction.AcceptTransfer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(41), 0)) {
+    return SecurityTokenProgramInstruction.CancelTransfer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(42), 0)) {
+    return SecurityTokenProgramInstruction.CreateTransferApproval;
+  }
+  if (containsBytes(data, getU8Encoder().encode(43), 0)) {
+    return SecurityTokenProgramInstruction.UpdateTransferApproval;
+  }
+  if (containsBytes(data, getU8Encoder().encode(44), 0)) {
+    return SecurityTokenProgramInstruction.CloseTransferApproval;
+  }
+  if (containsBytes(data, getU8Encoder().encode(45), 0)) {
+    return SecurityTokenProgramInstruction.RequestTransfer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(46), 0)) {
+    return SecurityTokenProgramInstruction.ApproveTransfer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(47), 0)) {
+    return SecurityTokenProgramInstruction.RejectTransfer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(48), 0)) {
+    return SecurityTokenProgramInstruction.CreateAccrualConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(49), 0)) {
+    return SecurityTokenProgramInstruction.ClaimCoupon;
+  }
+  if (containsBytes(data, getU8Encoder().encode(50), 0)) {
+    return SecurityTokenProgramInstruction.CreateMaturity;
+  }
+  if (containsBytes(data, getU8Encoder().encode(51), 0)) {
+    return SecurityTokenProgramInstruction.RedeemAtMaturity;
+  }
+  if (containsBytes(data, getU8Encoder().encode(52), 0)) {
+    return SecurityTokenProgramInstruction.WithdrawUnclaimedRedemption;
+  }
+  if (containsBytes(data, getU8Encoder().encode(53), 0)) {
+    return SecurityTokenProgramInstruction.CreateNavOracle;
+  }
+  if (containsBytes(data, getU8Encoder().encode(54), 0)) {
+    return SecurityTokenProgramInstruction.UpdateMultiplierFromOracle;
+  }
+  if (containsBytes(data, getU8Encoder().encode(55), 0)) {
+    return SecurityTokenProgramInstruction.SetRateOracle;
+  }
+  if (containsBytes(data, getU8Encoder().encode(56), 0)) {
+    return SecurityTokenProgramInstruction.RemoveRateOracle;
+  }
+  if (containsBytes(data, getU8Encoder().encode(57), 0)) {
+    return SecurityTokenProgramInstruction.OpenSubscription;
+  }
+  if (containsBytes(data, getU8Encoder().encode(58), 0)) {
+    return SecurityTokenProgramInstruction.Commit;
+  }
+  if (containsBytes(data, getU8Encoder().encode(59), 0)) {
+    return SecurityTokenProgramInstruction.Allocate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(60), 0)) {
+    return SecurityTokenProgramInstruction.Settle;
+  }
+  if (containsBytes(data, getU8Encoder().encode(61), 0)) {
+    return SecurityTokenProgramInstruction.CreateAuction;
+  }
+  if (containsBytes(data, getU8Encoder().encode(62), 0)) {
+    return SecurityTokenProgramInstruction.PlaceBid;
+  }
+  if (containsBytes(data, getU8Encoder().encode(63), 0)) {
+    return SecurityTokenProgramInstruction.ClearAuction;
+  }
+  if (containsBytes(data, getU8Encoder().encode(64), 0)) {
+    return SecurityTokenProgramInstruction.SettleBid;
+  }
+  if (containsBytes(data, getU8Encoder().encode(65), 0)) {
+    return SecurityTokenProgramInstruction.CreateHoldingPeriod;
+  }
+  if (containsBytes(data, getU8Encoder().encode(66), 0)) {
+    return SecurityTokenProgramInstruction.CloseHoldingPeriod;
+  }
+  if (containsBytes(data, getU8Encoder().encode(67), 0)) {
+    return SecurityTokenProgramInstruction.UnlockLot;
+  }
+  if (containsBytes(data, getU8Encoder().encode(68), 0)) {
+    return SecurityTokenProgramInstruction.Suspend;
+  }
+  if (containsBytes(data, getU8Encoder().encode(69), 0)) {
+    return SecurityTokenProgramInstruction.Unsuspend;
+  }
+  if (containsBytes(data, getU8Encoder().encode(70), 0)) {
+    return SecurityTokenProgramInstruction.ThawExpired;
+  }
+  if (containsBytes(data, getU8Encoder().encode(71), 0)) {
+    return SecurityTokenProgramInstruction.CreateVesting;
+  }
+  if (containsBytes(data, getU8Encoder().encode(72), 0)) {
+    return SecurityTokenProgramInstruction.ReleaseVested;
+  }
+  if (containsBytes(data, getU8Encoder().encode(73), 0)) {
+    return SecurityTokenProgramInstruction.CreateWithholdingRate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(74), 0)) {
+    return SecurityTokenProgramInstruction.UpdateWithholdingRate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(75), 0)) {
+    return SecurityTokenProgramInstruction.CreateDistributionPayout;
+  }
+  if (containsBytes(data, getU8Encoder().encode(76), 0)) {
+    return SecurityTokenProgramInstruction.CreateClaimDeadline;
+  }
+  if (containsBytes(data, getU8Encoder().encode(77), 0)) {
+    return SecurityTokenProgramInstruction.ReclaimUnclaimed;
+  }
+  if (containsBytes(data, getU8Encoder().encode(78), 0)) {
+    return SecurityTokenProgramInstruction.AmendDistributionRoot;
+  }
+  if (containsBytes(data, getU8Encoder().encode(79), 0)) {
+    return SecurityTokenProgramInstruction.TransferWithPermit;
+  }
+  if (containsBytes(data, getU8Encoder().encode(80), 0)) {
+    return SecurityTokenProgramInstruction.CreateSessionKey;
+  }
+  if (containsBytes(data, getU8Encoder().encode(81), 0)) {
+    return SecurityTokenProgramInstruction.CloseSessionKey;
+  }
+  if (containsBytes(data, getU8Encoder().encode(82), 0)) {
+    return SecurityTokenProgramInstruction.RecoverTokens;
+  }
+  if (containsBytes(data, getU8Encoder().encode(83), 0)) {
+    return SecurityTokenProgramInstruction.CreateMintMigration;
+  }
+  if (containsBytes(data, getU8Encoder().encode(84), 0)) {
+    return SecurityTokenProgramInstruction.MigrateBalances;
+  }
+  if (containsBytes(data, getU8Encoder().encode(85), 0)) {
+    return SecurityTokenProgramInstruction.CreateMetadataSchema;
+  }
+  if (containsBytes(data, getU8Encoder().encode(86), 0)) {
+    return SecurityTokenProgramInstruction.UpdateMetadataSchema;
+  }
+  if (containsBytes(data, getU8Encoder().encode(87), 0)) {
+    return SecurityTokenProgramInstruction.CloseMetadataSchema;
+  }
+  if (containsBytes(data, getU8Encoder().encode(88), 0)) {
+    return SecurityTokenProgramInstruction.FreezeMetadata;
+  }
+  if (containsBytes(data, getU8Encoder().encode(89), 0)) {
+    return SecurityTokenProgramInstruction.CreateConfigTemplate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(90), 0)) {
+    return SecurityTokenProgramInstruction.UpdateConfigTemplate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(91), 0)) {
+    return SecurityTokenProgramInstruction.CloseConfigTemplate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(92), 0)) {
+    return SecurityTokenProgramInstruction.SetConfigTemplate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(93), 0)) {
+    return SecurityTokenProgramInstruction.CanTransfer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(94), 0)) {
+    return SecurityTokenProgramInstruction.GetHolderStatus;
+  }
+  if (containsBytes(data, getU8Encoder().encode(95), 0)) {
+    return SecurityTokenProgramInstruction.GetEffectiveRate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(96), 0)) {
+    return SecurityTokenProgramInstruction.GetDistributionStatus;
+  }
+  if (containsBytes(data, getU8Encoder().encode(97), 0)) {
+    return SecurityTokenProgramInstruction.AttestCollateral;
+  }
+  if (containsBytes(data, getU8Encoder().encode(98), 0)) {
+    return SecurityTokenProgramInstruction.PublishReserveAttestation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(99), 0)) {
+    return SecurityTokenProgramInstruction.CreateSubAccountLedger;
+  }
+  if (containsBytes(data, getU8Encoder().encode(100), 0)) {
+    return SecurityTokenProgramInstruction.CreditSubAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(101), 0)) {
+    return SecurityTokenProgramInstruction.DebitSubAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(102), 0)) {
+    return SecurityTokenProgramInstruction.CloseSubAccountLedger;
+  }
+  if (containsBytes(data, getU8Encoder().encode(103), 0)) {
+    return SecurityTokenProgramInstruction.CreateSuccessionPolicy;
+  }
+  if (containsBytes(data, getU8Encoder().encode(104), 0)) {
+    return SecurityTokenProgramInstruction.Heartbeat;
+  }
+  if (containsBytes(data, getU8Encoder().encode(105), 0)) {
+    return SecurityTokenProgramInstruction.ClaimSuccession;
+  }
+  if (containsBytes(data, getU8Encoder().encode(106), 0)) {
+    return SecurityTokenProgramInstruction.CloseSuccessionPolicy;
+  }
+  if (containsBytes(data, getU8Encoder().encode(107), 0)) {
+    return SecurityTokenProgramInstruction.CreateMetadataTranslator;
+  }
+  if (containsBytes(data, getU8Encoder().encode(108), 0)) {
+    return SecurityTokenProgramInstruction.UpdateTranslation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(109), 0)) {
+    return SecurityTokenProgramInstruction.CloseMetadataTranslator;
+  }
+  if (containsBytes(data, getU8Encoder().encode(110), 0)) {
+    return SecurityTokenProgramInstruction.AuditMint;
+  }
+  if (containsBytes(data, getU8Encoder().encode(111), 0)) {
+    return SecurityTokenProgramInstruction.SetTransferFee;
+  }
+  if (containsBytes(data, getU8Encoder().encode(112), 0)) {
+    return SecurityTokenProgramInstruction.WithdrawWithheldTokensFromAccounts;
+  }
+  if (containsBytes(data, getU8Encoder().encode(113), 0)) {
+    return SecurityTokenProgramInstruction.HarvestWithheldTokensToMint;
+  }
+  if (containsBytes(data, getU8Encoder().encode(114), 0)) {
+    return SecurityTokenProgramInstruction.UpdateDefaultAccountState;
+  }
+  if (containsBytes(data, getU8Encoder().encode(115), 0)) {
+    return SecurityTokenProgramInstruction.UpdateScaledUiAmountMultiplier;
+  }
+  if (containsBytes(data, getU8Encoder().encode(116), 0)) {
+    return SecurityTokenProgramInstruction.RemoveMetadataField;
+  }
+  if (containsBytes(data, getU8Encoder().encode(117), 0)) {
+    return SecurityTokenProgramInstruction.AnchorDocument;
+  }
+  if (containsBytes(data, getU8Encoder().encode(118), 0)) {
+    return SecurityTokenProgramInstruction.UpdateDocument;
+  }
+  if (containsBytes(data, getU8Encoder().encode(119), 0)) {
+    return SecurityTokenProgramInstruction.RemoveDocument;
+  }
+  if (containsBytes(data, getU8Encoder().encode(120), 0)) {
+    return SecurityTokenProgramInstruction.ProposeMintCreatorTransfer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(121), 0)) {
+    return SecurityTokenProgramInstruction.AcceptMintCreatorTransfer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(122), 0)) {
+    return SecurityTokenProgramInstruction.SetMintAuthorityMultisig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(123), 0)) {
+    return SecurityTokenProgramInstruction.RotatePermanentDelegate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(124), 0)) {
+    return SecurityTokenProgramInstruction.DisablePermanentDelegate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(125), 0)) {
+    return SecurityTokenProgramInstruction.SetPauseDelegate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(126), 0)) {
+    return SecurityTokenProgramInstruction.CloseMintAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(127), 0)) {
+    return SecurityTokenProgramInstruction.SetConfigAdmin;
+  }
+  if (containsBytes(data, getU8Encoder().encode(128), 0)) {
+    return SecurityTokenProgramInstruction.ApplyPendingConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(129), 0)) {
+    return SecurityTokenProgramInstruction.CancelPendingConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(130), 0)) {
+    return SecurityTokenProgramInstruction.SetEmergencyAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(131), 0)) {
+    return SecurityTokenProgramInstruction.SetProgramHalt;
+  }
+  if (containsBytes(data, getU8Encoder().encode(132), 0)) {
+    return SecurityTokenProgramInstruction.AddToAllowlist;
+  }
+  if (containsBytes(data, getU8Encoder().encode(133), 0)) {
+    return SecurityTokenProgramInstruction.RemoveFromAllowlist;
+  }
+  if (containsBytes(data, getU8Encoder().encode(134), 0)) {
+    return SecurityTokenProgramInstruction.SetAllowlistMode;
+  }
   throw new Error(
     'The provided instruction could not be identified as a securityTokenProgram instruction.'
   );
@@ -231,4 +834,337 @@ export type ParsedSecurityTokenProgramInstruction<
     } & ParsedCloseActionReceiptAccountInstruction<TProgram>)
   | ({
       instructionType: SecurityTokenProgramInstruction.CloseClaimReceiptAccount;
-    } & ParsedCloseClaimReceiptAccountInstruction<TProgram>);
+    } & ParsedCloseClaimReceiptAccountInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateWrapVault;
+    } & ParsedCreateWrapVaultInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.WrapToken;
+    } & ParsedWrapTokenInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.UnwrapToken;
+    } & ParsedUnwrapTokenInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateAgentAccount;
+    } & ParsedCreateAgentAccountInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.UpdateAgentAccount;
+    } & ParsedUpdateAgentAccountInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CloseAgentAccount;
+    } & ParsedCloseAgentAccountInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateIdentityAccount;
+    } & ParsedCreateIdentityAccountInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.AddIdentityWallet;
+    } & ParsedAddIdentityWalletInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.RemoveIdentityWallet;
+    } & ParsedRemoveIdentityWalletInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CloseIdentityAccount;
+    } & ParsedCloseIdentityAccountInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreatePositionLimit;
+    } & ParsedCreatePositionLimitInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.UpdatePositionLimit;
+    } & ParsedUpdatePositionLimitInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.ClosePositionLimit;
+    } & ParsedClosePositionLimitInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateTransferAcceptance;
+    } & ParsedCreateTransferAcceptanceInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CloseTransferAcceptance;
+    } & ParsedCloseTransferAcceptanceInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.OfferTransfer;
+    } & ParsedOfferTransferInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.AcceptTransfer;
+    } & ParsedAcceptTransferInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CancelTransfer;
+    } & ParsedCancelTransferInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateTransferApproval;
+    } & ParsedCreateTransferApprovalInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.UpdateTransferApproval;
+    } & ParsedUpdateTransferApprovalInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CloseTransferApproval;
+    } & ParsedCloseTransferApprovalInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.RequestTransfer;
+    } & ParsedRequestTransferInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.ApproveTransfer;
+    } & ParsedApproveTransferInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.RejectTransfer;
+    } & ParsedRejectTransferInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateAccrualConfig;
+    } & ParsedCreateAccrualConfigInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.ClaimCoupon;
+    } & ParsedClaimCouponInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateMaturity;
+    } & ParsedCreateMaturityInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.RedeemAtMaturity;
+    } & ParsedRedeemAtMaturityInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.WithdrawUnclaimedRedemption;
+    } & ParsedWithdrawUnclaimedRedemptionInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateNavOracle;
+    } & ParsedCreateNavOracleInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.UpdateMultiplierFromOracle;
+    } & ParsedUpdateMultiplierFromOracleInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.SetRateOracle;
+    } & ParsedSetRateOracleInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.RemoveRateOracle;
+    } & ParsedRemoveRateOracleInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.OpenSubscription;
+    } & ParsedOpenSubscriptionInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.Commit;
+    } & ParsedCommitInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.Allocate;
+    } & ParsedAllocateInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.Settle;
+    } & ParsedSettleInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateAuction;
+    } & ParsedCreateAuctionInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.PlaceBid;
+    } & ParsedPlaceBidInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.ClearAuction;
+    } & ParsedClearAuctionInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.SettleBid;
+    } & ParsedSettleBidInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateHoldingPeriod;
+    } & ParsedCreateHoldingPeriodInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CloseHoldingPeriod;
+    } & ParsedCloseHoldingPeriodInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.UnlockLot;
+    } & ParsedUnlockLotInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.Suspend;
+    } & ParsedSuspendInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.Unsuspend;
+    } & ParsedUnsuspendInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.ThawExpired;
+    } & ParsedThawExpiredInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateVesting;
+    } & ParsedCreateVestingInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.ReleaseVested;
+    } & ParsedReleaseVestedInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateWithholdingRate;
+    } & ParsedCreateWithholdingRateInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.UpdateWithholdingRate;
+    } & ParsedUpdateWithholdingRateInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateDistributionPayout;
+    } & ParsedCreateDistributionPayoutInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateClaimDeadline;
+    } & ParsedCreateClaimDeadlineInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.ReclaimUnclaimed;
+    } & ParsedReclaimUnclaimedInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.AmendDistributionRoot;
+    } & ParsedAmendDistributionRootInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.TransferWithPermit;
+    } & ParsedTransferWithPermitInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateSessionKey;
+    } & ParsedCreateSessionKeyInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CloseSessionKey;
+    } & ParsedCloseSessionKeyInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.RecoverTokens;
+    } & ParsedRecoverTokensInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateMintMigration;
+    } & ParsedCreateMintMigrationInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.MigrateBalances;
+    } & ParsedMigrateBalancesInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateMetadataSchema;
+    } & ParsedCreateMetadataSchemaInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.UpdateMetadataSchema;
+    } & ParsedUpdateMetadataSchemaInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CloseMetadataSchema;
+    } & ParsedCloseMetadataSchemaInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.FreezeMetadata;
+    } & ParsedFreezeMetadataInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateConfigTemplate;
+    } & ParsedCreateConfigTemplateInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.UpdateConfigTemplate;
+    } & ParsedUpdateConfigTemplateInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CloseConfigTemplate;
+    } & ParsedCloseConfigTemplateInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.SetConfigTemplate;
+    } & ParsedSetConfigTemplateInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CanTransfer;
+    } & ParsedCanTransferInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.GetHolderStatus;
+    } & ParsedGetHolderStatusInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.GetEffectiveRate;
+    } & ParsedGetEffectiveRateInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.GetDistributionStatus;
+    } & ParsedGetDistributionStatusInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.AttestCollateral;
+    } & ParsedAttestCollateralInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.PublishReserveAttestation;
+    } & ParsedPublishReserveAttestationInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateSubAccountLedger;
+    } & ParsedCreateSubAccountLedgerInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreditSubAccount;
+    } & ParsedCreditSubAccountInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.DebitSubAccount;
+    } & ParsedDebitSubAccountInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CloseSubAccountLedger;
+    } & ParsedCloseSubAccountLedgerInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateSuccessionPolicy;
+    } & ParsedCreateSuccessionPolicyInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.Heartbeat;
+    } & ParsedHeartbeatInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.ClaimSuccession;
+    } & ParsedClaimSuccessionInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CloseSuccessionPolicy;
+    } & ParsedCloseSuccessionPolicyInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CreateMetadataTranslator;
+    } & ParsedCreateMetadataTranslatorInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.UpdateTranslation;
+    } & ParsedUpdateTranslationInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CloseMetadataTranslator;
+    } & ParsedCloseMetadataTranslatorInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.AuditMint;
+    } & ParsedAuditMintInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.SetTransferFee;
+    } & ParsedSetTransferFeeInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.WithdrawWithheldTokensFromAccounts;
+    } & ParsedWithdrawWithheldTokensFromAccountsInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.HarvestWithheldTokensToMint;
+    } & ParsedHarvestWithheldTokensToMintInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.UpdateDefaultAccountState;
+    } & ParsedUpdateDefaultAccountStateInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.UpdateScaledUiAmountMultiplier;
+    } & ParsedUpdateScaledUiAmountMultiplierInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.RemoveMetadataField;
+    } & ParsedRemoveMetadataFieldInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.AnchorDocument;
+    } & ParsedAnchorDocumentInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.UpdateDocument;
+    } & ParsedUpdateDocumentInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.RemoveDocument;
+    } & ParsedRemoveDocumentInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.ProposeMintCreatorTransfer;
+    } & ParsedProposeMintCreatorTransferInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.AcceptMintCreatorTransfer;
+    } & ParsedAcceptMintCreatorTransferInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.SetMintAuthorityMultisig;
+    } & ParsedSetMintAuthorityMultisigInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.RotatePermanentDelegate;
+    } & ParsedRotatePermanentDelegateInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.DisablePermanentDelegate;
+    } & ParsedDisablePermanentDelegateInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.SetPauseDelegate;
+    } & ParsedSetPauseDelegateInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CloseMintAuthority;
+    } & ParsedCloseMintAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.SetConfigAdmin;
+    } & ParsedSetConfigAdminInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.ApplyPendingConfig;
+    } & ParsedApplyPendingConfigInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.CancelPendingConfig;
+    } & ParsedCancelPendingConfigInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.SetEmergencyAuthority;
+    } & ParsedSetEmergencyAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.SetProgramHalt;
+    } & ParsedSetProgramHaltInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.AddToAllowlist;
+    } & ParsedAddToAllowlistInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.RemoveFromAllowlist;
+    } & ParsedRemoveFromAllowlistInstruction<TProgram>)
+  | ({
+      instructionType: SecurityTokenProgramInstruction.SetAllowlistMode;
+    } & ParsedSetAllowlistModeInstruction<TProgram>);
@@ -31,11 +31,13 @@ import {
 export type CloseClaimReceiptArgs = {
   actionId: bigint;
   merkleProof: Option<Array<ReadonlyUint8Array>>;
+  merkleRoot: Option<ReadonlyUint8Array>;
 };
 
 export type CloseClaimReceiptArgsArgs = {
   actionId: number | bigint;
   merkleProof: OptionOrNullable<Array<ReadonlyUint8Array>>;
+  merkleRoot: OptionOrNullable<ReadonlyUint8Array>;
 };
 
 export function getCloseClaimReceiptArgsEncoder(): Encoder<CloseClaimReceiptArgsArgs> {
@@ -45,6 +47,7 @@ export function getCloseClaimReceiptArgsEncoder(): Encoder<CloseClaimReceiptArgs
       'merkleProof',
       getOptionEncoder(getArrayEncoder(fixEncoderSize(getBytesEncoder(), 32))),
     ],
+    ['merkleRoot', getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32))],
   ]);
 }
 
@@ -55,6 +58,7 @@ export function getCloseClaimReceiptArgsDecoder(): Decoder<CloseClaimReceiptArgs
       'merkleProof',
       getOptionDecoder(getArrayDecoder(fixDecoderSize(getBytesDecoder(), 32))),
     ],
+    ['merkleRoot', getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32))],
   ]);
 }
 
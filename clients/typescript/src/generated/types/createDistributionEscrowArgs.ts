@@ -14,6 +14,8 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   type FixedSizeCodec,
@@ -25,17 +27,20 @@ import {
 export type CreateDistributionEscrowArgs = {
   actionId: bigint;
   merkleRoot: ReadonlyUint8Array;
+  claimBitmapLeaves: number;
 };
 
 export type CreateDistributionEscrowArgsArgs = {
   actionId: number | bigint;
   merkleRoot: ReadonlyUint8Array;
+  claimBitmapLeaves: number;
 };
 
 export function getCreateDistributionEscrowArgsEncoder(): FixedSizeEncoder<CreateDistributionEscrowArgsArgs> {
   return getStructEncoder([
     ['actionId', getU64Encoder()],
     ['merkleRoot', fixEncoderSize(getBytesEncoder(), 32)],
+    ['claimBitmapLeaves', getU32Encoder()],
   ]);
 }
 
@@ -43,6 +48,7 @@ export function getCreateDistributionEscrowArgsDecoder(): FixedSizeDecoder<Creat
   return getStructDecoder([
     ['actionId', getU64Decoder()],
     ['merkleRoot', fixDecoderSize(getBytesDecoder(), 32)],
+    ['claimBitmapLeaves', getU32Decoder()],
   ]);
 }
 
@@ -11,18 +11,32 @@ export * from './closeActionReceiptArgs';
 export * from './closeClaimReceiptArgs';
 export * from './closeRateArgs';
 export * from './convertArgs';
+export * from './createAccrualConfigArgs';
+export * from './createAuctionArgs';
 export * from './createDistributionEscrowArgs';
+export * from './createNavOracleArgs';
 export * from './createProofArgs';
 export * from './createRateArgs';
+export * from './dayCountConvention';
+export * from './defaultAccountStateArgs';
+export * from './distributionRootStatus';
+export * from './distributionStatus';
+export * from './effectiveRate';
+export * from './holderStatus';
 export * from './initializeMintArgs';
 export * from './initializeVerificationConfigArgs';
+export * from './metadataFieldFormat';
 export * from './metadataPointerArgs';
 export * from './mintArgs';
 export * from './rateConfig';
+export * from './recoveryReason';
+export * from './removeRateOracleArgs';
 export * from './rounding';
 export * from './scaledUiAmountConfigArgs';
+export * from './setRateOracleArgs';
 export * from './splitArgs';
 export * from './tokenMetadataArgs';
+export * from './transferFeeConfigArgs';
 export * from './trimVerificationConfigArgs';
 export * from './updateMetadataArgs';
 export * from './updateProofArgs';
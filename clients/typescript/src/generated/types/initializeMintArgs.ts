@@ -19,6 +19,8 @@ import {
   type OptionOrNullable,
 } from '@solana/kit';
 import {
+  getDefaultAccountStateArgsDecoder,
+  getDefaultAccountStateArgsEncoder,
   getMetadataPointerArgsDecoder,
   getMetadataPointerArgsEncoder,
   getMintArgsDecoder,
@@ -27,6 +29,10 @@ import {
   getScaledUiAmountConfigArgsEncoder,
   getTokenMetadataArgsDecoder,
   getTokenMetadataArgsEncoder,
+  getTransferFeeConfigArgsDecoder,
+  getTransferFeeConfigArgsEncoder,
+  type DefaultAccountStateArgs,
+  type DefaultAccountStateArgsArgs,
   type MetadataPointerArgs,
   type MetadataPointerArgsArgs,
   type MintArgs,
@@ -35,6 +41,8 @@ import {
   type ScaledUiAmountConfigArgsArgs,
   type TokenMetadataArgs,
   type TokenMetadataArgsArgs,
+  type TransferFeeConfigArgs,
+  type TransferFeeConfigArgsArgs,
 } from '.';
 
 export type InitializeMintArgs = {
@@ -42,6 +50,8 @@ export type InitializeMintArgs = {
   ixMetadataPointer: Option<MetadataPointerArgs>;
   ixMetadata: Option<TokenMetadataArgs>;
   ixScaledUiAmount: Option<ScaledUiAmountConfigArgs>;
+  ixTransferFee: Option<TransferFeeConfigArgs>;
+  ixDefaultAccountState: Option<DefaultAccountStateArgs>;
 };
 
 export type InitializeMintArgsArgs = {
@@ -49,6 +59,8 @@ export type InitializeMintArgsArgs = {
   ixMetadataPointer: OptionOrNullable<MetadataPointerArgsArgs>;
   ixMetadata: OptionOrNullable<TokenMetadataArgsArgs>;
   ixScaledUiAmount: OptionOrNullable<ScaledUiAmountConfigArgsArgs>;
+  ixTransferFee: OptionOrNullable<TransferFeeConfigArgsArgs>;
+  ixDefaultAccountState: OptionOrNullable<DefaultAccountStateArgsArgs>;
 };
 
 export function getInitializeMintArgsEncoder(): Encoder<InitializeMintArgsArgs> {
@@ -60,6 +72,11 @@ export function getInitializeMintArgsEncoder(): Encoder<InitializeMintArgsArgs>
       'ixScaledUiAmount',
       getOptionEncoder(getScaledUiAmountConfigArgsEncoder()),
     ],
+    ['ixTransferFee', getOptionEncoder(getTransferFeeConfigArgsEncoder())],
+    [
+      'ixDefaultAccountState',
+      getOptionEncoder(getDefaultAccountStateArgsEncoder()),
+    ],
   ]);
 }
 
@@ -72,6 +89,11 @@ export function getInitializeMintArgsDecoder(): Decoder<InitializeMintArgs> {
       'ixScaledUiAmount',
       getOptionDecoder(getScaledUiAmountConfigArgsDecoder()),
     ],
+    ['ixTransferFee', getOptionDecoder(getTransferFeeConfigArgsDecoder())],
+    [
+      'ixDefaultAccountState',
+      getOptionDecoder(getDefaultAccountStateArgsDecoder()),
+    ],
   ]);
 }
 
@@ -16,6 +16,8 @@ import {
   getBooleanEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Address,
@@ -28,16 +30,31 @@ export type InitializeVerificationConfigArgs = {
   instructionDiscriminator: number;
   cpiMode: boolean;
   programAddresses: Array<Address>;
+  threshold: number;
+  lookbackWindow: number;
+  requireTopLevel: boolean;
+  timelockSeconds: bigint;
 };
 
-export type InitializeVerificationConfigArgsArgs =
-  InitializeVerificationConfigArgs;
+export type InitializeVerificationConfigArgsArgs = {
+  instructionDiscriminator: number;
+  cpiMode: boolean;
+  programAddresses: Array<Address>;
+  threshold: number;
+  lookbackWindow: number;
+  requireTopLevel: boolean;
+  timelockSeconds: number | bigint;
+};
 
 export function getInitializeVerificationConfigArgsEncoder(): Encoder<InitializeVerificationConfigArgsArgs> {
   return getStructEncoder([
     ['instructionDiscriminator', getU8Encoder()],
     ['cpiMode', getBooleanEncoder()],
     ['programAddresses', getArrayEncoder(getAddressEncoder())],
+    ['threshold', getU8Encoder()],
+    ['lookbackWindow', getU8Encoder()],
+    ['requireTopLevel', getBooleanEncoder()],
+    ['timelockSeconds', getU64Encoder()],
   ]);
 }
 
@@ -46,6 +63,10 @@ export function getInitializeVerificationConfigArgsDecoder(): Decoder<Initialize
     ['instructionDiscriminator', getU8Decoder()],
     ['cpiMode', getBooleanDecoder()],
     ['programAddresses', getArrayDecoder(getAddressDecoder())],
+    ['threshold', getU8Decoder()],
+    ['lookbackWindow', getU8Decoder()],
+    ['requireTopLevel', getBooleanDecoder()],
+    ['timelockSeconds', getU64Decoder()],
   ]);
 }
 
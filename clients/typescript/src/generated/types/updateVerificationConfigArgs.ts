@@ -16,6 +16,8 @@ import {
   getBooleanEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Address,
@@ -29,9 +31,22 @@ export type UpdateVerificationConfigArgs = {
   cpiMode: boolean;
   offset: number;
   programAddresses: Array<Address>;
+  threshold: number;
+  lookbackWindow: number;
+  requireTopLevel: boolean;
+  timelockSeconds: bigint;
 };
 
-export type UpdateVerificationConfigArgsArgs = UpdateVerificationConfigArgs;
+export type UpdateVerificationConfigArgsArgs = {
+  instructionDiscriminator: number;
+  cpiMode: boolean;
+  offset: number;
+  programAddresses: Array<Address>;
+  threshold: number;
+  lookbackWindow: number;
+  requireTopLevel: boolean;
+  timelockSeconds: number | bigint;
+};
 
 export function getUpdateVerificationConfigArgsEncoder(): Encoder<UpdateVerificationConfigArgsArgs> {
   return getStructEncoder([
@@ -39,6 +54,10 @@ export function getUpdateVerificationConfigArgsEncoder(): Encoder<UpdateVerifica
     ['cpiMode', getBooleanEncoder()],
     ['offset', getU8Encoder()],
     ['programAddresses', getArrayEncoder(getAddressEncoder())],
+    ['threshold', getU8Encoder()],
+    ['lookbackWindow', getU8Encoder()],
+    ['requireTopLevel', getBooleanEncoder()],
+    ['timelockSeconds', getU64Encoder()],
   ]);
 }
 
@@ -48,6 +67,10 @@ export function getUpdateVerificationConfigArgsDecoder(): Decoder<UpdateVerifica
     ['cpiMode', getBooleanDecoder()],
     ['offset', getU8Decoder()],
     ['programAddresses', getArrayDecoder(getAddressDecoder())],
+    ['threshold', getU8Decoder()],
+    ['lookbackWindow', getU8Decoder()],
+    ['requireTopLevel', getBooleanDecoder()],
+    ['timelockSeconds', getU64Decoder()],
   ]);
 }
 
@@ -0,0 +1,83 @@
+/**
+ * ProgramConfig account of haltable instructions.
+ *
+ * The emergency authority can halt every instruction except the read-only ones and the halt
+ * instructions themselves. Haltable instructions take the ProgramConfig PDA after all their
+ * other accounts, including the CPI mode verification programs, and fail with
+ * `ProgramConfigRequired` without it.
+ *
+ * The generated builders don't know about the account: pass finished instructions through
+ * `withProgramConfig` before sending them.
+ */
+
+import {
+  AccountRole,
+  address,
+  type AccountMeta,
+  type Address,
+  type Instruction,
+} from '@solana/kit';
+import {
+  AUDIT_MINT_DISCRIMINATOR,
+  CAN_TRANSFER_DISCRIMINATOR,
+  GET_DISTRIBUTION_STATUS_DISCRIMINATOR,
+  GET_EFFECTIVE_RATE_DISCRIMINATOR,
+  GET_HOLDER_STATUS_DISCRIMINATOR,
+  SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS,
+  SET_EMERGENCY_AUTHORITY_DISCRIMINATOR,
+  SET_PROGRAM_HALT_DISCRIMINATOR,
+  VERIFY_DISCRIMINATOR,
+} from './generated';
+
+/** ProgramConfig PDA, seeds `["program_config"]` */
+export const PROGRAM_CONFIG_ADDRESS: Address = address(
+  '7AZvGSbtGyWkdmSDJ1Y9v4YuSAkBvtxPqJE5BgS585hd'
+);
+
+/** Read-only and halt instructions, which keep working while the program is halted */
+const NOT_HALTABLE_DISCRIMINATORS: ReadonlySet<number> = new Set([
+  VERIFY_DISCRIMINATOR,
+  CAN_TRANSFER_DISCRIMINATOR,
+  GET_HOLDER_STATUS_DISCRIMINATOR,
+  GET_EFFECTIVE_RATE_DISCRIMINATOR,
+  GET_DISTRIBUTION_STATUS_DISCRIMINATOR,
+  AUDIT_MINT_DISCRIMINATOR,
+  SET_EMERGENCY_AUTHORITY_DISCRIMINATOR,
+  SET_PROGRAM_HALT_DISCRIMINATOR,
+]);
+
+/** Whether `instruction` is a security token instruction the emergency authority can halt */
+export function isHaltable(instruction: Instruction): boolean {
+  const discriminator = instruction.data?.[0];
+  return (
+    instruction.programAddress === SECURITY_TOKEN_PROGRAM_PROGRAM_ADDRESS &&
+    discriminator !== undefined &&
+    !NOT_HALTABLE_DISCRIMINATORS.has(discriminator)
+  );
+}
+
+/** Whether the last account of `instruction` is the ProgramConfig PDA */
+export function hasProgramConfig(instruction: Instruction): boolean {
+  const accounts = instruction.accounts ?? [];
+  return accounts[accounts.length - 1]?.address === PROGRAM_CONFIG_ADDRESS;
+}
+
+/**
+ * Appends the ProgramConfig PDA to a haltable `instruction` missing it, other instructions
+ * are returned unchanged.
+ */
+export function withProgramConfig<TInstruction extends Instruction>(
+  instruction: TInstruction
+): TInstruction {
+  if (!isHaltable(instruction) || hasProgramConfig(instruction)) {
+    return instruction;
+  }
+  const programConfig: AccountMeta = {
+    address: PROGRAM_CONFIG_ADDRESS,
+    role: AccountRole.READONLY,
+  };
+  return {
+    ...instruction,
+    accounts: [...(instruction.accounts ?? []), programConfig],
+  };
+}
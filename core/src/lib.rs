@@ -0,0 +1,605 @@
+//! Discriminators shared by the program, the transfer hook and the clients.
+//!
+//! Account data and instruction data start with a one byte discriminator. Both tables
+//! are append-only: deployed accounts, the transfer hook and its extra account metas
+//! depend on the existing values. The transfer hook rules marker of the extra account
+//! metas is shared here too.
+#![no_std]
+
+/// Account discriminators, the first byte of every program account
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecurityTokenDiscriminators {
+    MintAuthorityDiscriminator = 0,
+    VerificationConfigDiscriminator = 1,
+    RateDiscriminator = 2,
+    ReceiptDiscriminator = 3,
+    ProofDiscriminator = 4,
+    WrapVaultDiscriminator = 5,
+    AgentDiscriminator = 6,
+    IdentityDiscriminator = 7,
+    IdentityWalletDiscriminator = 8,
+    PositionLimitDiscriminator = 9,
+    TransferAcceptanceDiscriminator = 10,
+    PendingTransferDiscriminator = 11,
+    TransferApprovalDiscriminator = 12,
+    TransferRequestDiscriminator = 13,
+    AccrualConfigDiscriminator = 14,
+    CouponClaimDiscriminator = 15,
+    MaturityDiscriminator = 16,
+    NavOracleDiscriminator = 17,
+    SubscriptionDiscriminator = 18,
+    SubscriptionCommitmentDiscriminator = 19,
+    AuctionDiscriminator = 20,
+    AuctionBidDiscriminator = 21,
+    HoldingPeriodDiscriminator = 22,
+    RestrictedHoldingDiscriminator = 23,
+    HoldingLotDiscriminator = 24,
+    SuspensionDiscriminator = 25,
+    FreezeExpiryDiscriminator = 26,
+    VestingDiscriminator = 27,
+    WithholdingRateDiscriminator = 28,
+    DistributionPayoutDiscriminator = 29,
+    ClaimDeadlineDiscriminator = 30,
+    DistributionRootDiscriminator = 31,
+    PermitNonceDiscriminator = 32,
+    SessionKeyDiscriminator = 33,
+    RecoveryReceiptDiscriminator = 34,
+    ClaimBitmapDiscriminator = 35,
+    MintMigrationDiscriminator = 36,
+    MetadataSchemaDiscriminator = 37,
+    MetadataFreezeDiscriminator = 38,
+    ConfigTemplateDiscriminator = 39,
+    CollateralAttestationDiscriminator = 40,
+    ReserveAttestationDiscriminator = 41,
+    SubAccountLedgerDiscriminator = 42,
+    SubAccountDiscriminator = 43,
+    SuccessionPolicyDiscriminator = 44,
+    MetadataTranslatorDiscriminator = 45,
+    DocumentRegistryDiscriminator = 46,
+    PermanentDelegateConfigDiscriminator = 47,
+    PauseDelegateDiscriminator = 48,
+    ConfigAdminDiscriminator = 49,
+    PendingVerificationConfigDiscriminator = 50,
+    ProgramConfigDiscriminator = 51,
+    AllowlistEntryDiscriminator = 52,
+}
+
+impl SecurityTokenDiscriminators {
+    /// Every discriminator, indexed by its value
+    pub const ALL: [Self; 53] = [
+        Self::MintAuthorityDiscriminator,
+        Self::VerificationConfigDiscriminator,
+        Self::RateDiscriminator,
+        Self::ReceiptDiscriminator,
+        Self::ProofDiscriminator,
+        Self::WrapVaultDiscriminator,
+        Self::AgentDiscriminator,
+        Self::IdentityDiscriminator,
+        Self::IdentityWalletDiscriminator,
+        Self::PositionLimitDiscriminator,
+        Self::TransferAcceptanceDiscriminator,
+        Self::PendingTransferDiscriminator,
+        Self::TransferApprovalDiscriminator,
+        Self::TransferRequestDiscriminator,
+        Self::AccrualConfigDiscriminator,
+        Self::CouponClaimDiscriminator,
+        Self::MaturityDiscriminator,
+        Self::NavOracleDiscriminator,
+        Self::SubscriptionDiscriminator,
+        Self::SubscriptionCommitmentDiscriminator,
+        Self::AuctionDiscriminator,
+        Self::AuctionBidDiscriminator,
+        Self::HoldingPeriodDiscriminator,
+        Self::RestrictedHoldingDiscriminator,
+        Self::HoldingLotDiscriminator,
+        Self::SuspensionDiscriminator,
+        Self::FreezeExpiryDiscriminator,
+        Self::VestingDiscriminator,
+        Self::WithholdingRateDiscriminator,
+        Self::DistributionPayoutDiscriminator,
+        Self::ClaimDeadlineDiscriminator,
+        Self::DistributionRootDiscriminator,
+        Self::PermitNonceDiscriminator,
+        Self::SessionKeyDiscriminator,
+        Self::RecoveryReceiptDiscriminator,
+        Self::ClaimBitmapDiscriminator,
+        Self::MintMigrationDiscriminator,
+        Self::MetadataSchemaDiscriminator,
+        Self::MetadataFreezeDiscriminator,
+        Self::ConfigTemplateDiscriminator,
+        Self::CollateralAttestationDiscriminator,
+        Self::ReserveAttestationDiscriminator,
+        Self::SubAccountLedgerDiscriminator,
+        Self::SubAccountDiscriminator,
+        Self::SuccessionPolicyDiscriminator,
+        Self::MetadataTranslatorDiscriminator,
+        Self::DocumentRegistryDiscriminator,
+        Self::PermanentDelegateConfigDiscriminator,
+        Self::PauseDelegateDiscriminator,
+        Self::ConfigAdminDiscriminator,
+        Self::PendingVerificationConfigDiscriminator,
+        Self::ProgramConfigDiscriminator,
+        Self::AllowlistEntryDiscriminator,
+    ];
+
+    /// Discriminator with the byte value `value`
+    pub const fn from_u8(value: u8) -> Option<Self> {
+        if (value as usize) < Self::ALL.len() {
+            Some(Self::ALL[value as usize])
+        } else {
+            None
+        }
+    }
+}
+
+/// Instruction discriminators, the first byte of every instruction
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecurityTokenInstructionDiscriminators {
+    InitializeMint = 0,
+    UpdateMetadata = 1,
+    InitializeVerificationConfig = 2,
+    UpdateVerificationConfig = 3,
+    TrimVerificationConfig = 4,
+    Verify = 5,
+    Mint = 6,
+    Burn = 7,
+    Pause = 8,
+    Resume = 9,
+    Freeze = 10,
+    Thaw = 11,
+    Transfer = 12,
+    CreateRateAccount = 13,
+    UpdateRateAccount = 14,
+    CloseRateAccount = 15,
+    Split = 16,
+    Convert = 17,
+    CreateProofAccount = 18,
+    UpdateProofAccount = 19,
+    CreateDistributionEscrow = 20,
+    ClaimDistribution = 21,
+    CloseActionReceiptAccount = 22,
+    CloseClaimReceiptAccount = 23,
+    CreateWrapVault = 24,
+    WrapToken = 25,
+    UnwrapToken = 26,
+    CreateAgentAccount = 27,
+    UpdateAgentAccount = 28,
+    CloseAgentAccount = 29,
+    CreateIdentityAccount = 30,
+    AddIdentityWallet = 31,
+    RemoveIdentityWallet = 32,
+    CloseIdentityAccount = 33,
+    CreatePositionLimit = 34,
+    UpdatePositionLimit = 35,
+    ClosePositionLimit = 36,
+    CreateTransferAcceptance = 37,
+    CloseTransferAcceptance = 38,
+    OfferTransfer = 39,
+    AcceptTransfer = 40,
+    CancelTransfer = 41,
+    CreateTransferApproval = 42,
+    UpdateTransferApproval = 43,
+    CloseTransferApproval = 44,
+    RequestTransfer = 45,
+    ApproveTransfer = 46,
+    RejectTransfer = 47,
+    CreateAccrualConfig = 48,
+    ClaimCoupon = 49,
+    CreateMaturity = 50,
+    RedeemAtMaturity = 51,
+    WithdrawUnclaimedRedemption = 52,
+    CreateNavOracle = 53,
+    UpdateMultiplierFromOracle = 54,
+    SetRateOracle = 55,
+    RemoveRateOracle = 56,
+    OpenSubscription = 57,
+    Commit = 58,
+    Allocate = 59,
+    Settle = 60,
+    CreateAuction = 61,
+    PlaceBid = 62,
+    ClearAuction = 63,
+    SettleBid = 64,
+    CreateHoldingPeriod = 65,
+    CloseHoldingPeriod = 66,
+    UnlockLot = 67,
+    Suspend = 68,
+    Unsuspend = 69,
+    ThawExpired = 70,
+    CreateVesting = 71,
+    ReleaseVested = 72,
+    CreateWithholdingRate = 73,
+    UpdateWithholdingRate = 74,
+    CreateDistributionPayout = 75,
+    CreateClaimDeadline = 76,
+    ReclaimUnclaimed = 77,
+    AmendDistributionRoot = 78,
+    TransferWithPermit = 79,
+    CreateSessionKey = 80,
+    CloseSessionKey = 81,
+    RecoverTokens = 82,
+    CreateMintMigration = 83,
+    MigrateBalances = 84,
+    CreateMetadataSchema = 85,
+    UpdateMetadataSchema = 86,
+    CloseMetadataSchema = 87,
+    FreezeMetadata = 88,
+    CreateConfigTemplate = 89,
+    UpdateConfigTemplate = 90,
+    CloseConfigTemplate = 91,
+    SetConfigTemplate = 92,
+    CanTransfer = 93,
+    GetHolderStatus = 94,
+    GetEffectiveRate = 95,
+    GetDistributionStatus = 96,
+    AttestCollateral = 97,
+    PublishReserveAttestation = 98,
+    CreateSubAccountLedger = 99,
+    CreditSubAccount = 100,
+    DebitSubAccount = 101,
+    CloseSubAccountLedger = 102,
+    CreateSuccessionPolicy = 103,
+    Heartbeat = 104,
+    ClaimSuccession = 105,
+    CloseSuccessionPolicy = 106,
+    CreateMetadataTranslator = 107,
+    UpdateTranslation = 108,
+    CloseMetadataTranslator = 109,
+    AuditMint = 110,
+    SetTransferFee = 111,
+    WithdrawWithheldTokensFromAccounts = 112,
+    HarvestWithheldTokensToMint = 113,
+    UpdateDefaultAccountState = 114,
+    UpdateScaledUiAmountMultiplier = 115,
+    RemoveMetadataField = 116,
+    AnchorDocument = 117,
+    UpdateDocument = 118,
+    RemoveDocument = 119,
+    ProposeMintCreatorTransfer = 120,
+    AcceptMintCreatorTransfer = 121,
+    SetMintAuthorityMultisig = 122,
+    RotatePermanentDelegate = 123,
+    DisablePermanentDelegate = 124,
+    SetPauseDelegate = 125,
+    CloseMintAuthority = 126,
+    SetConfigAdmin = 127,
+    ApplyPendingConfig = 128,
+    CancelPendingConfig = 129,
+    SetEmergencyAuthority = 130,
+    SetProgramHalt = 131,
+    AddToAllowlist = 132,
+    RemoveFromAllowlist = 133,
+    SetAllowlistMode = 134,
+}
+
+impl SecurityTokenInstructionDiscriminators {
+    /// Every discriminator, indexed by its value
+    pub const ALL: [Self; 135] = [
+        Self::InitializeMint,
+        Self::UpdateMetadata,
+        Self::InitializeVerificationConfig,
+        Self::UpdateVerificationConfig,
+        Self::TrimVerificationConfig,
+        Self::Verify,
+        Self::Mint,
+        Self::Burn,
+        Self::Pause,
+        Self::Resume,
+        Self::Freeze,
+        Self::Thaw,
+        Self::Transfer,
+        Self::CreateRateAccount,
+        Self::UpdateRateAccount,
+        Self::CloseRateAccount,
+        Self::Split,
+        Self::Convert,
+        Self::CreateProofAccount,
+        Self::UpdateProofAccount,
+        Self::CreateDistributionEscrow,
+        Self::ClaimDistribution,
+        Self::CloseActionReceiptAccount,
+        Self::CloseClaimReceiptAccount,
+        Self::CreateWrapVault,
+        Self::WrapToken,
+        Self::UnwrapToken,
+        Self::CreateAgentAccount,
+        Self::UpdateAgentAccount,
+        Self::CloseAgentAccount,
+        Self::CreateIdentityAccount,
+        Self::AddIdentityWallet,
+        Self::RemoveIdentityWallet,
+        Self::CloseIdentityAccount,
+        Self::CreatePositionLimit,
+        Self::UpdatePositionLimit,
+        Self::ClosePositionLimit,
+        Self::CreateTransferAcceptance,
+        Self::CloseTransferAcceptance,
+        Self::OfferTransfer,
+        Self::AcceptTransfer,
+        Self::CancelTransfer,
+        Self::CreateTransferApproval,
+        Self::UpdateTransferApproval,
+        Self::CloseTransferApproval,
+        Self::RequestTransfer,
+        Self::ApproveTransfer,
+        Self::RejectTransfer,
+        Self::CreateAccrualConfig,
+        Self::ClaimCoupon,
+        Self::CreateMaturity,
+        Self::RedeemAtMaturity,
+        Self::WithdrawUnclaimedRedemption,
+        Self::CreateNavOracle,
+        Self::UpdateMultiplierFromOracle,
+        Self::SetRateOracle,
+        Self::RemoveRateOracle,
+        Self::OpenSubscription,
+        Self::Commit,
+        Self::Allocate,
+        Self::Settle,
+        Self::CreateAuction,
+        Self::PlaceBid,
+        Self::ClearAuction,
+        Self::SettleBid,
+        Self::CreateHoldingPeriod,
+        Self::CloseHoldingPeriod,
+        Self::UnlockLot,
+        Self::Suspend,
+        Self::Unsuspend,
+        Self::ThawExpired,
+        Self::CreateVesting,
+        Self::ReleaseVested,
+        Self::CreateWithholdingRate,
+        Self::UpdateWithholdingRate,
+        Self::CreateDistributionPayout,
+        Self::CreateClaimDeadline,
+        Self::ReclaimUnclaimed,
+        Self::AmendDistributionRoot,
+        Self::TransferWithPermit,
+        Self::CreateSessionKey,
+        Self::CloseSessionKey,
+        Self::RecoverTokens,
+        Self::CreateMintMigration,
+        Self::MigrateBalances,
+        Self::CreateMetadataSchema,
+        Self::UpdateMetadataSchema,
+        Self::CloseMetadataSchema,
+        Self::FreezeMetadata,
+        Self::CreateConfigTemplate,
+        Self::UpdateConfigTemplate,
+        Self::CloseConfigTemplate,
+        Self::SetConfigTemplate,
+        Self::CanTransfer,
+        Self::GetHolderStatus,
+        Self::GetEffectiveRate,
+        Self::GetDistributionStatus,
+        Self::AttestCollateral,
+        Self::PublishReserveAttestation,
+        Self::CreateSubAccountLedger,
+        Self::CreditSubAccount,
+        Self::DebitSubAccount,
+        Self::CloseSubAccountLedger,
+        Self::CreateSuccessionPolicy,
+        Self::Heartbeat,
+        Self::ClaimSuccession,
+        Self::CloseSuccessionPolicy,
+        Self::CreateMetadataTranslator,
+        Self::UpdateTranslation,
+        Self::CloseMetadataTranslator,
+        Self::AuditMint,
+        Self::SetTransferFee,
+        Self::WithdrawWithheldTokensFromAccounts,
+        Self::HarvestWithheldTokensToMint,
+        Self::UpdateDefaultAccountState,
+        Self::UpdateScaledUiAmountMultiplier,
+        Self::RemoveMetadataField,
+        Self::AnchorDocument,
+        Self::UpdateDocument,
+        Self::RemoveDocument,
+        Self::ProposeMintCreatorTransfer,
+        Self::AcceptMintCreatorTransfer,
+        Self::SetMintAuthorityMultisig,
+        Self::RotatePermanentDelegate,
+        Self::DisablePermanentDelegate,
+        Self::SetPauseDelegate,
+        Self::CloseMintAuthority,
+        Self::SetConfigAdmin,
+        Self::ApplyPendingConfig,
+        Self::CancelPendingConfig,
+        Self::SetEmergencyAuthority,
+        Self::SetProgramHalt,
+        Self::AddToAllowlist,
+        Self::RemoveFromAllowlist,
+        Self::SetAllowlistMode,
+    ];
+
+    /// Discriminator with the byte value `value`
+    pub const fn from_u8(value: u8) -> Option<Self> {
+        if (value as usize) < Self::ALL.len() {
+            Some(Self::ALL[value as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Whether the emergency authority can halt the instruction.
+    /// Haltable instructions take the ProgramConfig PDA after all other accounts;
+    /// read-only instructions and the ones managing the ProgramConfig itself keep working.
+    pub const fn is_haltable(self) -> bool {
+        !matches!(
+            self,
+            Self::Verify
+                | Self::CanTransfer
+                | Self::GetHolderStatus
+                | Self::GetEffectiveRate
+                | Self::GetDistributionStatus
+                | Self::AuditMint
+                | Self::SetEmergencyAuthority
+                | Self::SetProgramHalt
+        )
+    }
+}
+
+/// Transfer hook rules enabled for a mint.
+///
+/// The security token program records them in the transfer hook extra account metas as a
+/// literal account meta following the verification programs, and the instructions sysvar when
+/// a threshold is set. Its address is [`TransferHookRuleFlags::MARKER_PREFIX`] followed by the
+/// little-endian flags. The rule accounts follow the marker in the order of the flags, the
+/// TransferApproval, TransferAcceptance and PermanentDelegateConfig PDAs close the list.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TransferHookRuleFlags(u32);
+
+impl TransferHookRuleFlags {
+    /// First 28 bytes of the marker address
+    pub const MARKER_PREFIX: [u8; 28] = *b"security_token.hook_rules.v1";
+    /// Security token program and the Suspension PDAs of the source and destination
+    pub const SUSPENSION: u32 = 1 << 0;
+    /// SubAccountLedger PDA of the source
+    pub const SUB_ACCOUNT_LEDGER: u32 = 1 << 1;
+    /// Security token program and the AllowlistEntry PDAs of the source and destination owners
+    pub const ALLOWLIST: u32 = 1 << 2;
+    /// Security token program, HoldingPeriod and the RestrictedHolding of the source
+    pub const HOLDING_PERIOD: u32 = 1 << 3;
+    /// Security token program, AccrualConfig and the CouponClaim PDAs of the source and destination
+    pub const COUPON_ACCRUAL: u32 = 1 << 4;
+    /// Security token program, PositionLimit, destination IdentityWallet, Identity and its wallets
+    pub const POSITION_LIMIT: u32 = 1 << 5;
+    /// TransferApproval PDA
+    pub const TRANSFER_APPROVAL: u32 = 1 << 6;
+    /// TransferAcceptance PDA
+    pub const TRANSFER_ACCEPTANCE: u32 = 1 << 7;
+    /// PermanentDelegateConfig PDA
+    pub const PERMANENT_DELEGATE_CONFIG: u32 = 1 << 8;
+    /// Every known flag
+    pub const ALL: u32 = (1 << 9) - 1;
+
+    /// Rules with the given flags, unknown bits are dropped
+    pub const fn new(flags: u32) -> Self {
+        Self(flags & Self::ALL)
+    }
+
+    /// Flags of the enabled rules
+    pub const fn flags(self) -> u32 {
+        self.0
+    }
+
+    /// Whether every rule of `flags` is enabled
+    pub const fn contains(self, flags: u32) -> bool {
+        self.0 & flags == flags
+    }
+
+    /// Rules with `flags` enabled or disabled
+    pub const fn with(self, flags: u32, enabled: bool) -> Self {
+        if enabled {
+            Self::new(self.0 | flags)
+        } else {
+            Self(self.0 & !flags)
+        }
+    }
+
+    /// Address of the marker account meta
+    pub fn to_marker(self) -> [u8; 32] {
+        let mut marker = [0u8; 32];
+        marker[..28].copy_from_slice(&Self::MARKER_PREFIX);
+        marker[28..].copy_from_slice(&self.0.to_le_bytes());
+        marker
+    }
+
+    /// Rules of a marker address, `None` for other addresses and unknown flags
+    pub fn from_marker(marker: &[u8; 32]) -> Option<Self> {
+        if marker[..28] != Self::MARKER_PREFIX {
+            return None;
+        }
+        let flags = u32::from_le_bytes([marker[28], marker[29], marker[30], marker[31]]);
+        (flags & !Self::ALL == 0).then_some(Self(flags))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_discriminators_do_not_collide() {
+        for (index, discriminator) in SecurityTokenDiscriminators::ALL.iter().enumerate() {
+            assert_eq!(*discriminator as usize, index);
+            assert_eq!(
+                SecurityTokenDiscriminators::from_u8(index as u8),
+                Some(*discriminator)
+            );
+        }
+        let len = SecurityTokenDiscriminators::ALL.len() as u8;
+        assert_eq!(SecurityTokenDiscriminators::from_u8(len), None);
+    }
+
+    #[test]
+    fn test_instruction_discriminators_do_not_collide() {
+        for (index, discriminator) in SecurityTokenInstructionDiscriminators::ALL
+            .iter()
+            .enumerate()
+        {
+            assert_eq!(*discriminator as usize, index);
+            assert_eq!(
+                SecurityTokenInstructionDiscriminators::from_u8(index as u8),
+                Some(*discriminator)
+            );
+        }
+        let len = SecurityTokenInstructionDiscriminators::ALL.len() as u8;
+        assert_eq!(SecurityTokenInstructionDiscriminators::from_u8(len), None);
+    }
+
+    #[test]
+    fn test_transfer_hook_discriminators_are_stable() {
+        // Hard-coded by deployed transfer hooks
+        assert_eq!(
+            SecurityTokenDiscriminators::VerificationConfigDiscriminator as u8,
+            1
+        );
+        assert_eq!(SecurityTokenInstructionDiscriminators::Transfer as u8, 12);
+    }
+
+    #[test]
+    fn test_read_only_and_halt_instructions_are_not_haltable() {
+        use SecurityTokenInstructionDiscriminators::*;
+
+        let not_haltable = SecurityTokenInstructionDiscriminators::ALL
+            .iter()
+            .filter(|discriminator| !discriminator.is_haltable())
+            .count();
+        assert_eq!(not_haltable, 8);
+        for discriminator in [Verify, CanTransfer, AuditMint, SetProgramHalt] {
+            assert!(!discriminator.is_haltable());
+        }
+        for discriminator in [InitializeMint, Transfer, SetAllowlistMode] {
+            assert!(discriminator.is_haltable());
+        }
+    }
+
+    #[test]
+    fn test_transfer_hook_rules_marker_round_trip() {
+        let rules = TransferHookRuleFlags::default()
+            .with(TransferHookRuleFlags::SUSPENSION, true)
+            .with(TransferHookRuleFlags::POSITION_LIMIT, true)
+            .with(TransferHookRuleFlags::PERMANENT_DELEGATE_CONFIG, true);
+        assert!(rules.contains(TransferHookRuleFlags::SUSPENSION));
+        assert!(!rules.contains(TransferHookRuleFlags::ALLOWLIST));
+        assert_eq!(
+            TransferHookRuleFlags::from_marker(&rules.to_marker()),
+            Some(rules)
+        );
+        assert_eq!(
+            rules
+                .with(TransferHookRuleFlags::POSITION_LIMIT, false)
+                .flags(),
+            TransferHookRuleFlags::SUSPENSION | TransferHookRuleFlags::PERMANENT_DELEGATE_CONFIG
+        );
+
+        // Other addresses and unknown flags are not markers
+        assert_eq!(TransferHookRuleFlags::from_marker(&[0u8; 32]), None);
+        let mut unknown = TransferHookRuleFlags::new(TransferHookRuleFlags::ALL).to_marker();
+        unknown[29] |= 0x02;
+        assert_eq!(TransferHookRuleFlags::from_marker(&unknown), None);
+    }
+}
@@ -0,0 +1,147 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::slice_invoke_signed,
+    instruction::{AccountMeta, Instruction, Signer},
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// Attest the free balance of a token account for collateral, writing its
+/// CollateralAttestation PDA and setting the account data as return data
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` The payer of the attestation account.
+///   1. `[]` The security token mint.
+///   2. `[]` The attested token account.
+///   3. `[]` The Suspension PDA of the token account.
+///   4. `[WRITE]` The CollateralAttestation PDA of the token account.
+///   5. `[]` The System program.
+///   6. `[]` (Optional) The HoldingPeriod PDA of the mint.
+///   7. `[]` (Optional) The RestrictedHolding PDA of the token account.
+///   8. `[]` The ProgramConfig PDA, right after the System program without the lockup PDAs.
+pub struct AttestCollateral<'a> {
+    /// Payer of the attestation account.
+    pub payer: &'a AccountInfo,
+    /// Security token mint.
+    pub mint: &'a AccountInfo,
+    /// Attested token account.
+    pub token_account: &'a AccountInfo,
+    /// Suspension PDA of the token account.
+    pub suspension_account: &'a AccountInfo,
+    /// CollateralAttestation PDA of the token account.
+    pub collateral_attestation_account: &'a AccountInfo,
+    /// System Program.
+    pub system_program: &'a AccountInfo,
+    /// HoldingPeriod and RestrictedHolding PDAs, required to account for locked lots.
+    pub lockup: Option<(&'a AccountInfo, &'a AccountInfo)>,
+    /// ProgramConfig PDA, checked for the program halt.
+    pub program_config: &'a AccountInfo,
+}
+
+impl AttestCollateral<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut metas = vec![
+            AccountMeta::writable_signer(self.payer.key()),
+            AccountMeta::readonly(self.mint.key()),
+            AccountMeta::readonly(self.token_account.key()),
+            AccountMeta::readonly(self.suspension_account.key()),
+            AccountMeta::writable(self.collateral_attestation_account.key()),
+            AccountMeta::readonly(self.system_program.key()),
+        ];
+        let mut infos = vec![
+            self.payer,
+            self.mint,
+            self.token_account,
+            self.suspension_account,
+            self.collateral_attestation_account,
+            self.system_program,
+        ];
+        if let Some((holding_period_account, restricted_holding_account)) = self.lockup {
+            metas.push(AccountMeta::readonly(holding_period_account.key()));
+            metas.push(AccountMeta::readonly(restricted_holding_account.key()));
+            infos.push(holding_period_account);
+            infos.push(restricted_holding_account);
+        }
+        metas.push(AccountMeta::readonly(self.program_config.key()));
+        infos.push(self.program_config);
+
+        let instruction = Instruction {
+            program_id: &crate::ID,
+            accounts: &metas,
+            data: &[97],
+        };
+
+        slice_invoke_signed(&instruction, &infos, signers)
+    }
+}
+
+/// Fields of a CollateralAttestation lending programs rely on, read from the account data
+/// or the AttestCollateral return data
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AttestedCollateral {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    /// Balance the holder can transfer, `0` when frozen, suspended or paused
+    pub free_balance: u64,
+    /// Slot of the attestation
+    pub slot: u64,
+}
+
+impl AttestedCollateral {
+    /// CollateralAttestation account discriminator
+    pub const DISCRIMINATOR: u8 = 40;
+    /// CollateralAttestation account length
+    pub const LEN: usize = 141;
+
+    /// Read a CollateralAttestation, `None` for data of any other account
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        if data.len() != Self::LEN || data[0] != Self::DISCRIMINATOR {
+            return None;
+        }
+        // Layout: discriminator (1), mint (32), token account (32), owner (32), balance (8),
+        // locked amount (8), free balance (8), frozen (1), suspended (1), paused (1), slot (8),
+        // unix timestamp (8), bump (1)
+        Some(Self {
+            mint: data[1..33].try_into().ok()?,
+            token_account: data[33..65].try_into().ok()?,
+            owner: data[65..97].try_into().ok()?,
+            free_balance: u64::from_le_bytes(data[113..121].try_into().ok()?),
+            slot: u64::from_le_bytes(data[124..132].try_into().ok()?),
+        })
+    }
+
+    /// Free balance of an attestation made at `slot`, `None` for older attestations
+    pub fn free_balance_at(&self, slot: u64) -> Option<u64> {
+        (self.slot == slot).then_some(self.free_balance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use security_token_program::state::{AccountSerialize, CollateralAttestation};
+
+    #[test]
+    fn test_attested_collateral_reads_program_layout() {
+        let attestation = CollateralAttestation::new(
+            [1u8; 32], [2u8; 32], [3u8; 32], 1_000, 400, false, false, false, 77, 1_700, 254,
+        );
+        let data = attestation.to_bytes();
+        assert_eq!(data.len(), AttestedCollateral::LEN);
+
+        let attested = AttestedCollateral::from_bytes(&data).unwrap();
+        assert_eq!(attested.mint, [1u8; 32]);
+        assert_eq!(attested.token_account, [2u8; 32]);
+        assert_eq!(attested.owner, [3u8; 32]);
+        assert_eq!(attested.free_balance_at(77), Some(600));
+        assert_eq!(attested.free_balance_at(78), None);
+
+        assert_eq!(AttestedCollateral::from_bytes(&data[..140]), None);
+    }
+}
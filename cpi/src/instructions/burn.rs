@@ -0,0 +1,60 @@
+use pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult};
+
+use super::{InstructionAccounts, Verification};
+
+/// Burn tokens from a token account with the permanent delegate
+///
+/// ### Accounts:
+///   0. `[]` The security token mint.
+///   1. `[]` The VerificationConfig PDA of Burn.
+///   2. `[]` The instructions sysvar.
+///   3. `[]` The PermanentDelegate PDA.
+///   4. `[WRITE]` The mint account.
+///   5. `[WRITE]` The token account to burn from.
+///   6. `[]` The Token 2022 program.
+///   7. ..`[]` The trailing verification accounts, then the ProgramConfig PDA.
+pub struct Burn<'a> {
+    /// Verification overhead accounts.
+    pub verification: Verification<'a>,
+    /// ProgramConfig PDA, checked for the program halt.
+    pub program_config: &'a AccountInfo,
+    /// PermanentDelegate PDA.
+    pub permanent_delegate: &'a AccountInfo,
+    /// Mint Account.
+    pub mint_account: &'a AccountInfo,
+    /// Token account to burn from.
+    pub token_account: &'a AccountInfo,
+    /// Token 2022 Program.
+    pub token_program: &'a AccountInfo,
+    /// Amount of tokens to burn.
+    pub amount: u64,
+}
+
+impl Burn<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut accounts = InstructionAccounts::new(&self.verification);
+        accounts.readonly(self.permanent_delegate);
+        accounts.writable(self.mint_account);
+        accounts.writable(self.token_account);
+        accounts.readonly(self.token_program);
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..9]: amount (8 bytes, u64)
+        let mut instruction_data = [0u8; 9];
+        instruction_data[0] = 7;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+
+        accounts.invoke_signed(
+            &self.verification,
+            Some(self.program_config),
+            &instruction_data,
+            signers,
+        )
+    }
+}
@@ -0,0 +1,90 @@
+use pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult};
+
+use super::{InstructionAccounts, Verification};
+
+/// Accounts recording the auto-thaw timestamp of a freeze
+pub struct FreezeExpiryAccounts<'a> {
+    /// Payer of the FreezeExpiry account.
+    pub payer: &'a AccountInfo,
+    /// FreezeExpiry PDA of the token account.
+    pub freeze_expiry_account: &'a AccountInfo,
+    /// System Program.
+    pub system_program: &'a AccountInfo,
+}
+
+/// Freeze a token account, optionally until `auto_thaw_at`
+///
+/// ### Accounts:
+///   0. `[]` The security token mint.
+///   1. `[]` The VerificationConfig PDA of Freeze.
+///   2. `[]` The instructions sysvar.
+///   3. `[]` The FreezeAuthority PDA.
+///   4. `[]` The mint account.
+///   5. `[WRITE]` The token account to freeze.
+///   6. `[]` The Token 2022 program.
+///   7. ..`[]` The freeze expiry accounts with `auto_thaw_at`, then the trailing verification
+///      accounts, then the ProgramConfig PDA.
+pub struct Freeze<'a> {
+    /// Verification overhead accounts.
+    pub verification: Verification<'a>,
+    /// ProgramConfig PDA, checked for the program halt.
+    pub program_config: &'a AccountInfo,
+    /// FreezeAuthority PDA.
+    pub freeze_authority: &'a AccountInfo,
+    /// Mint Account.
+    pub mint_account: &'a AccountInfo,
+    /// Token account to freeze.
+    pub token_account: &'a AccountInfo,
+    /// Token 2022 Program.
+    pub token_program: &'a AccountInfo,
+    /// Accounts recording the auto-thaw timestamp, required with `auto_thaw_at`.
+    pub freeze_expiry: Option<FreezeExpiryAccounts<'a>>,
+    /// Timestamp after which anyone can thaw the account.
+    pub auto_thaw_at: Option<i64>,
+}
+
+impl Freeze<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut accounts = InstructionAccounts::new(&self.verification);
+        accounts.readonly(self.freeze_authority);
+        accounts.readonly(self.mint_account);
+        accounts.writable(self.token_account);
+        accounts.readonly(self.token_program);
+        if let Some(freeze_expiry) = &self.freeze_expiry {
+            accounts.push(freeze_expiry.payer, true, true);
+            accounts.writable(freeze_expiry.freeze_expiry_account);
+            accounts.readonly(freeze_expiry.system_program);
+        }
+
+        let (instruction_data, len) = instruction_data(self.auto_thaw_at);
+        accounts.invoke_signed(
+            &self.verification,
+            Some(self.program_config),
+            &instruction_data[..len],
+            signers,
+        )
+    }
+}
+
+/// Freeze instruction data and the length used, the timestamp is omitted when unset
+fn instruction_data(auto_thaw_at: Option<i64>) -> ([u8; 10], usize) {
+    // Instruction data layout:
+    // -  [0]: instruction discriminator (1 byte, u8)
+    // -  [1]: auto-thaw timestamp presence (1 byte, u8)
+    // -  [2..10]: auto-thaw timestamp, when present (8 bytes, i64)
+    let mut instruction_data = [0u8; 10];
+    instruction_data[0] = 10;
+    match auto_thaw_at {
+        Some(auto_thaw_at) => {
+            instruction_data[1] = 1;
+            instruction_data[2..10].copy_from_slice(&auto_thaw_at.to_le_bytes());
+            (instruction_data, 10)
+        }
+        None => (instruction_data, 2),
+    }
+}
@@ -0,0 +1,64 @@
+use pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult};
+
+use super::{HoldingLotAccounts, InstructionAccounts, Verification};
+
+/// Mint tokens to a token account
+///
+/// ### Accounts:
+///   0. `[]` The security token mint.
+///   1. `[]` The VerificationConfig PDA of Mint.
+///   2. `[]` The instructions sysvar.
+///   3. `[]` The MintAuthority PDA.
+///   4. `[WRITE]` The mint account.
+///   5. `[WRITE]` The destination token account.
+///   6. `[]` The Token 2022 program.
+///   7. ..`[]` The optional holding lot accounts, then the trailing verification accounts,
+///      then the ProgramConfig PDA.
+pub struct Mint<'a> {
+    /// Verification overhead accounts.
+    pub verification: Verification<'a>,
+    /// ProgramConfig PDA, checked for the program halt.
+    pub program_config: &'a AccountInfo,
+    /// MintAuthority PDA.
+    pub mint_authority: &'a AccountInfo,
+    /// Mint Account.
+    pub mint_account: &'a AccountInfo,
+    /// Destination token account.
+    pub destination: &'a AccountInfo,
+    /// Token 2022 Program.
+    pub token_program: &'a AccountInfo,
+    /// Accounts recording the minted tokens as a restricted holding lot.
+    pub holding_lot: Option<HoldingLotAccounts<'a>>,
+    /// Amount of tokens to mint.
+    pub amount: u64,
+}
+
+impl Mint<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut accounts = InstructionAccounts::new(&self.verification);
+        accounts.readonly(self.mint_authority);
+        accounts.writable(self.mint_account);
+        accounts.writable(self.destination);
+        accounts.readonly(self.token_program);
+        accounts.holding_lot(&self.holding_lot);
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..9]: amount (8 bytes, u64)
+        let mut instruction_data = [0u8; 9];
+        instruction_data[0] = 6;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+
+        accounts.invoke_signed(
+            &self.verification,
+            Some(self.program_config),
+            &instruction_data,
+            signers,
+        )
+    }
+}
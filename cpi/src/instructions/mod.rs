@@ -0,0 +1,120 @@
+mod attest_collateral;
+mod burn;
+mod freeze;
+mod mint;
+mod pause;
+mod thaw;
+mod transfer;
+mod verify;
+
+pub use attest_collateral::*;
+pub use burn::*;
+pub use freeze::*;
+pub use mint::*;
+pub use pause::*;
+pub use thaw::*;
+pub use transfer::*;
+pub use verify::*;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::slice_invoke_signed,
+    instruction::{AccountMeta, Instruction, Signer},
+    ProgramResult,
+};
+
+/// Verification overhead accounts leading every verified instruction
+pub struct Verification<'a> {
+    /// Security token mint.
+    pub mint: &'a AccountInfo,
+    /// VerificationConfig PDA of the invoked instruction.
+    pub verification_config: &'a AccountInfo,
+    /// Instructions sysvar.
+    pub instructions_sysvar: &'a AccountInfo,
+    /// Trailing accounts: the verification programs in CPI mode, followed by the
+    /// ConfigTemplate when the config is linked to one.
+    pub remaining_accounts: &'a [AccountInfo],
+}
+
+/// Accounts recording the received tokens as a restricted holding lot
+pub struct HoldingLotAccounts<'a> {
+    /// Payer of the lot accounts.
+    pub lot_payer: &'a AccountInfo,
+    /// HoldingPeriod PDA of the mint.
+    pub holding_period_account: &'a AccountInfo,
+    /// RestrictedHolding PDA of the destination token account.
+    pub restricted_holding_account: &'a AccountInfo,
+    /// HoldingLot PDA of the next lot.
+    pub holding_lot_account: &'a AccountInfo,
+    /// System Program.
+    pub system_program: &'a AccountInfo,
+}
+
+/// Account metas and infos of an instruction, in instruction order
+struct InstructionAccounts<'a> {
+    metas: Vec<AccountMeta<'a>>,
+    infos: Vec<&'a AccountInfo>,
+}
+
+impl<'a> InstructionAccounts<'a> {
+    /// Start with the verification overhead accounts
+    fn new(verification: &Verification<'a>) -> Self {
+        let mut accounts = Self {
+            metas: Vec::new(),
+            infos: Vec::new(),
+        };
+        accounts.readonly(verification.mint);
+        accounts.readonly(verification.verification_config);
+        accounts.readonly(verification.instructions_sysvar);
+        accounts
+    }
+
+    fn push(&mut self, account: &'a AccountInfo, is_writable: bool, is_signer: bool) {
+        self.metas
+            .push(AccountMeta::new(account.key(), is_writable, is_signer));
+        self.infos.push(account);
+    }
+
+    fn readonly(&mut self, account: &'a AccountInfo) {
+        self.push(account, false, false);
+    }
+
+    fn writable(&mut self, account: &'a AccountInfo) {
+        self.push(account, true, false);
+    }
+
+    fn holding_lot(&mut self, lot: &Option<HoldingLotAccounts<'a>>) {
+        if let Some(lot) = lot {
+            self.push(lot.lot_payer, true, true);
+            self.readonly(lot.holding_period_account);
+            self.writable(lot.restricted_holding_account);
+            self.writable(lot.holding_lot_account);
+            self.readonly(lot.system_program);
+        }
+    }
+
+    /// Append the trailing verification accounts and the ProgramConfig PDA of haltable
+    /// instructions, then invoke the program
+    fn invoke_signed(
+        mut self,
+        verification: &Verification<'a>,
+        program_config: Option<&'a AccountInfo>,
+        data: &[u8],
+        signers: &[Signer],
+    ) -> ProgramResult {
+        for account in verification.remaining_accounts {
+            self.readonly(account);
+        }
+        if let Some(program_config) = program_config {
+            self.readonly(program_config);
+        }
+
+        let instruction = Instruction {
+            program_id: &crate::ID,
+            accounts: &self.metas,
+            data,
+        };
+
+        slice_invoke_signed(&instruction, &self.infos, signers)
+    }
+}
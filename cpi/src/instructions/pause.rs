@@ -0,0 +1,110 @@
+use pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult};
+
+use super::{InstructionAccounts, Verification};
+
+/// Pause all transfers, mints and burns of the mint
+///
+/// ### Accounts:
+///   0. `[]` The security token mint.
+///   1. `[]` The VerificationConfig PDA of Pause.
+///   2. `[]` The instructions sysvar.
+///   3. `[]` The PauseAuthority PDA.
+///   4. `[WRITE]` The mint account.
+///   5. `[]` The Token 2022 program.
+///   6. ..`[]` The trailing verification accounts, then the ProgramConfig PDA.
+pub struct Pause<'a> {
+    /// Verification overhead accounts.
+    pub verification: Verification<'a>,
+    /// ProgramConfig PDA, checked for the program halt.
+    pub program_config: &'a AccountInfo,
+    /// PauseAuthority PDA.
+    pub pause_authority: &'a AccountInfo,
+    /// Mint Account.
+    pub mint_account: &'a AccountInfo,
+    /// Token 2022 Program.
+    pub token_program: &'a AccountInfo,
+}
+
+impl Pause<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_pause_authority_instruction(
+            &self.verification,
+            self.program_config,
+            self.pause_authority,
+            self.mint_account,
+            self.token_program,
+            8,
+            signers,
+        )
+    }
+}
+
+/// Resume a paused mint
+///
+/// ### Accounts:
+///   0. `[]` The security token mint.
+///   1. `[]` The VerificationConfig PDA of Resume.
+///   2. `[]` The instructions sysvar.
+///   3. `[]` The PauseAuthority PDA.
+///   4. `[WRITE]` The mint account.
+///   5. `[]` The Token 2022 program.
+///   6. ..`[]` The trailing verification accounts, then the ProgramConfig PDA.
+pub struct Resume<'a> {
+    /// Verification overhead accounts.
+    pub verification: Verification<'a>,
+    /// ProgramConfig PDA, checked for the program halt.
+    pub program_config: &'a AccountInfo,
+    /// PauseAuthority PDA.
+    pub pause_authority: &'a AccountInfo,
+    /// Mint Account.
+    pub mint_account: &'a AccountInfo,
+    /// Token 2022 Program.
+    pub token_program: &'a AccountInfo,
+}
+
+impl Resume<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_pause_authority_instruction(
+            &self.verification,
+            self.program_config,
+            self.pause_authority,
+            self.mint_account,
+            self.token_program,
+            9,
+            signers,
+        )
+    }
+}
+
+/// Pause and Resume share their accounts and have no arguments
+fn invoke_pause_authority_instruction<'a>(
+    verification: &Verification<'a>,
+    program_config: &'a AccountInfo,
+    pause_authority: &'a AccountInfo,
+    mint_account: &'a AccountInfo,
+    token_program: &'a AccountInfo,
+    discriminator: u8,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut accounts = InstructionAccounts::new(verification);
+    accounts.readonly(pause_authority);
+    accounts.writable(mint_account);
+    accounts.readonly(token_program);
+
+    accounts.invoke_signed(
+        verification,
+        Some(program_config),
+        &[discriminator],
+        signers,
+    )
+}
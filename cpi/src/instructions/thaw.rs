@@ -0,0 +1,66 @@
+use pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult};
+
+use super::{InstructionAccounts, Verification};
+
+/// Accounts closing the freeze expiry of a freeze with auto-thaw
+pub struct ThawExpiryAccounts<'a> {
+    /// FreezeExpiry PDA of the token account.
+    pub freeze_expiry_account: &'a AccountInfo,
+    /// Recipient of the FreezeExpiry rent.
+    pub rent_destination: &'a AccountInfo,
+}
+
+/// Thaw a frozen token account
+///
+/// ### Accounts:
+///   0. `[]` The security token mint.
+///   1. `[]` The VerificationConfig PDA of Thaw.
+///   2. `[]` The instructions sysvar.
+///   3. `[]` The FreezeAuthority PDA.
+///   4. `[]` The mint account.
+///   5. `[WRITE]` The token account to thaw.
+///   6. `[]` The Token 2022 program.
+///   7. ..`[]` The optional freeze expiry accounts, then the trailing verification accounts,
+///      then the ProgramConfig PDA.
+pub struct Thaw<'a> {
+    /// Verification overhead accounts.
+    pub verification: Verification<'a>,
+    /// ProgramConfig PDA, checked for the program halt.
+    pub program_config: &'a AccountInfo,
+    /// FreezeAuthority PDA.
+    pub freeze_authority: &'a AccountInfo,
+    /// Mint Account.
+    pub mint_account: &'a AccountInfo,
+    /// Token account to thaw.
+    pub token_account: &'a AccountInfo,
+    /// Token 2022 Program.
+    pub token_program: &'a AccountInfo,
+    /// Accounts closing the freeze expiry, for freezes with auto-thaw.
+    pub freeze_expiry: Option<ThawExpiryAccounts<'a>>,
+}
+
+impl Thaw<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut accounts = InstructionAccounts::new(&self.verification);
+        accounts.readonly(self.freeze_authority);
+        accounts.readonly(self.mint_account);
+        accounts.writable(self.token_account);
+        accounts.readonly(self.token_program);
+        if let Some(freeze_expiry) = &self.freeze_expiry {
+            accounts.writable(freeze_expiry.freeze_expiry_account);
+            accounts.writable(freeze_expiry.rent_destination);
+        }
+
+        accounts.invoke_signed(
+            &self.verification,
+            Some(self.program_config),
+            &[11],
+            signers,
+        )
+    }
+}
@@ -0,0 +1,111 @@
+use pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult};
+
+use super::{HoldingLotAccounts, InstructionAccounts, Verification};
+
+/// Transfer tokens between token accounts with the permanent delegate
+///
+/// ### Accounts:
+///   0. `[]` The security token mint.
+///   1. `[]` The VerificationConfig PDA of Transfer.
+///   2. `[]` The instructions sysvar.
+///   3. `[]` The PermanentDelegate PDA.
+///   4. `[]` The mint account.
+///   5. `[WRITE]` The source token account.
+///   6. `[WRITE]` The destination token account.
+///   7. `[]` The transfer hook program.
+///   8. `[]` The Token 2022 program.
+///   9. ..`[]` The optional holding lot accounts, then the trailing verification accounts,
+///      then the ProgramConfig PDA.
+pub struct Transfer<'a> {
+    /// Verification overhead accounts.
+    pub verification: Verification<'a>,
+    /// ProgramConfig PDA, checked for the program halt.
+    pub program_config: &'a AccountInfo,
+    /// PermanentDelegate PDA.
+    pub permanent_delegate_authority: &'a AccountInfo,
+    /// Mint Account.
+    pub mint_account: &'a AccountInfo,
+    /// Source token account.
+    pub from_token_account: &'a AccountInfo,
+    /// Destination token account.
+    pub to_token_account: &'a AccountInfo,
+    /// Transfer hook program.
+    pub transfer_hook_program: &'a AccountInfo,
+    /// Token 2022 Program.
+    pub token_program: &'a AccountInfo,
+    /// Accounts recording the received tokens as a restricted holding lot.
+    pub holding_lot: Option<HoldingLotAccounts<'a>>,
+    /// Amount of tokens to transfer.
+    pub amount: u64,
+    /// Hash of the off-chain travel-rule payload, logged in the TransferEvent.
+    pub memo_hash: Option<[u8; 32]>,
+}
+
+impl Transfer<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut accounts = InstructionAccounts::new(&self.verification);
+        accounts.readonly(self.permanent_delegate_authority);
+        accounts.readonly(self.mint_account);
+        accounts.writable(self.from_token_account);
+        accounts.writable(self.to_token_account);
+        accounts.readonly(self.transfer_hook_program);
+        accounts.readonly(self.token_program);
+        accounts.holding_lot(&self.holding_lot);
+
+        let (instruction_data, len) = instruction_data(self.amount, self.memo_hash.as_ref());
+        accounts.invoke_signed(
+            &self.verification,
+            Some(self.program_config),
+            &instruction_data[..len],
+            signers,
+        )
+    }
+}
+
+/// Transfer instruction data and the length used, the memo hash is omitted when unset
+fn instruction_data(amount: u64, memo_hash: Option<&[u8; 32]>) -> ([u8; 42], usize) {
+    // Instruction data layout:
+    // -  [0]: instruction discriminator (1 byte, u8)
+    // -  [1..9]: amount (8 bytes, u64)
+    // -  [9]: memo hash presence (1 byte, u8)
+    // -  [10..42]: memo hash, when present (32 bytes)
+    let mut instruction_data = [0u8; 42];
+    instruction_data[0] = 12;
+    instruction_data[1..9].copy_from_slice(&amount.to_le_bytes());
+    match memo_hash {
+        Some(memo_hash) => {
+            instruction_data[9] = 1;
+            instruction_data[10..42].copy_from_slice(memo_hash);
+            (instruction_data, 42)
+        }
+        None => (instruction_data, 10),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use security_token_program::instruction::SecurityTokenInstruction;
+
+    #[test]
+    fn test_transfer_instruction_data() {
+        let (data, len) = instruction_data(500, Some(&[7u8; 32]));
+        let (instruction, args_data) =
+            SecurityTokenInstruction::parse_instruction(&data[..len]).unwrap();
+        assert!(matches!(instruction, SecurityTokenInstruction::Transfer));
+        assert_eq!(&args_data[..8], &500u64.to_le_bytes());
+        assert_eq!(args_data[8], 1);
+        assert_eq!(&args_data[9..], &[7u8; 32]);
+
+        let (data, len) = instruction_data(500, None);
+        assert_eq!(
+            &data[..len],
+            &[&[12u8][..], &500u64.to_le_bytes(), &[0]].concat()
+        );
+    }
+}
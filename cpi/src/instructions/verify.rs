@@ -0,0 +1,68 @@
+use pinocchio::{instruction::Signer, ProgramResult};
+
+use super::{InstructionAccounts, Verification};
+
+/// Run the verification of a Security Token instruction without executing it
+///
+/// ### Accounts:
+///   0. `[]` The security token mint.
+///   1. `[]` The VerificationConfig PDA of the verified instruction.
+///   2. `[]` The instructions sysvar.
+///   3. ..`[]` The trailing verification accounts.
+pub struct Verify<'a, 'b> {
+    /// Verification overhead accounts.
+    pub verification: Verification<'a>,
+    /// Discriminator of the verified instruction.
+    pub ix: u8,
+    /// Instruction data of the verified instruction.
+    pub instruction_data: &'b [u8],
+}
+
+impl Verify<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        InstructionAccounts::new(&self.verification).invoke_signed(
+            &self.verification,
+            None,
+            &instruction_data(self.ix, self.instruction_data),
+            signers,
+        )
+    }
+}
+
+/// Verify instruction data, the verified instruction with its borsh-encoded data
+fn instruction_data(ix: u8, verified_instruction_data: &[u8]) -> Vec<u8> {
+    // Instruction data layout:
+    // -  [0]: instruction discriminator (1 byte, u8)
+    // -  [1]: verified instruction discriminator (1 byte, u8)
+    // -  [2..6]: verified instruction data length (4 bytes, u32)
+    // -  [6..]: verified instruction data
+    let mut data = Vec::with_capacity(6 + verified_instruction_data.len());
+    data.push(5);
+    data.push(ix);
+    data.extend_from_slice(&(verified_instruction_data.len() as u32).to_le_bytes());
+    data.extend_from_slice(verified_instruction_data);
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use security_token_program::instruction::SecurityTokenInstruction;
+    use security_token_program::instructions::VerifyArgs;
+
+    #[test]
+    fn test_verify_instruction_data() {
+        let data = instruction_data(12, &[1, 2, 3]);
+        let (instruction, args_data) = SecurityTokenInstruction::parse_instruction(&data).unwrap();
+        assert!(matches!(instruction, SecurityTokenInstruction::Verify));
+
+        let args = VerifyArgs::try_from_bytes(args_data).unwrap();
+        assert_eq!(args.ix, 12);
+        assert_eq!(args.instruction_data, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,18 @@
+//! Pinocchio helpers to invoke Security Token program instructions
+//!
+//! On-chain programs compose with the standard by filling the instruction structs with their
+//! accounts and calling `invoke` or `invoke_signed`, without building account metas by hand.
+
+pub mod instructions;
+
+use pinocchio_pubkey::declare_id;
+
+declare_id!("SSTS8Qk2bW3aVaBEsY1Ras95YdbaaYQQx21JWHxvjap");
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_program_id_matches_program() {
+        assert_eq!(crate::ID, security_token_program::ID);
+    }
+}
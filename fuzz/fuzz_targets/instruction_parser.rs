@@ -0,0 +1,51 @@
+//! Feeds arbitrary instruction data to the instruction parser and every argument parser.
+//! Malformed data must be rejected with an error, never a panic.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use security_token_program::instruction::SecurityTokenInstruction;
+use security_token_program::instructions::{
+    ClaimDistributionArgs, CloseActionReceiptArgs, CloseClaimReceiptArgs, CloseRateArgs,
+    ConvertArgs, CreateAccrualConfigArgs, CreateAuctionArgs, CreateDistributionEscrowArgs,
+    CreateNavOracleArgs, CreateProofArgs, CreateRateArgs, InitializeMintArgs,
+    InitializeVerificationConfigArgs, RemoveRateOracleArgs, SetRateOracleArgs, SplitArgs,
+    TrimVerificationConfigArgs, UpdateMetadataArgs, UpdateProofArgs, UpdateRateArgs,
+    UpdateVerificationConfigArgs, VerifyArgs,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok((instruction, args)) = SecurityTokenInstruction::parse_instruction(data) else {
+        return;
+    };
+    assert_eq!(instruction.discriminant(), data[0]);
+
+    if let Ok(parsed) = InitializeMintArgs::try_from_bytes(args) {
+        let _ = parsed.validate();
+    }
+    if let Ok(parsed) = InitializeVerificationConfigArgs::try_from_bytes(args) {
+        let _ = parsed.validate();
+    }
+    if let Ok(parsed) = UpdateVerificationConfigArgs::try_from_bytes(args) {
+        let _ = parsed.validate();
+    }
+    let _ = TrimVerificationConfigArgs::try_from_bytes(args);
+    let _ = UpdateMetadataArgs::try_from_bytes(args);
+    let _ = VerifyArgs::try_from_bytes(args);
+    let _ = CreateRateArgs::try_from_bytes(args);
+    let _ = UpdateRateArgs::try_from_bytes(args);
+    let _ = CloseRateArgs::try_from_bytes(args);
+    let _ = SetRateOracleArgs::try_from_bytes(args);
+    let _ = RemoveRateOracleArgs::try_from_bytes(args);
+    let _ = CreateProofArgs::try_from_bytes(args);
+    let _ = UpdateProofArgs::try_from_bytes(args);
+    let _ = ConvertArgs::try_from_bytes(args);
+    let _ = SplitArgs::try_from_bytes(args);
+    let _ = CreateDistributionEscrowArgs::try_from_bytes(args);
+    let _ = ClaimDistributionArgs::try_from_bytes(args);
+    let _ = CloseActionReceiptArgs::try_from_bytes(args);
+    let _ = CloseClaimReceiptArgs::try_from_bytes(args);
+    let _ = CreateAccrualConfigArgs::try_from_bytes(args);
+    let _ = CreateAuctionArgs::try_from_bytes(args);
+    let _ = CreateNavOracleArgs::try_from_bytes(args);
+});
@@ -0,0 +1,13 @@
+//! Canonical relational schema for Security Token indexers.
+//!
+//! Indexers (Geyser plugins, RPC pollers) decode program accounts and `Program data:`
+//! events with `security-token-client` and store them in a warehouse. This crate fixes
+//! the tables and columns they write to ([`schema`]) and converts decoded accounts and
+//! events into rows of those tables ([`rows`]), so every implementation produces the
+//! same data.
+
+pub mod rows;
+pub mod schema;
+
+pub use rows::{account_row, event_row, EventLocation, Row, Value};
+pub use schema::{Column, ColumnType, Table, TABLES};
@@ -0,0 +1,435 @@
+//! Conversions from decoded accounts and events to rows of the schema.
+
+use crate::schema::{
+    ColumnType, Table, DISTRIBUTION_ROOTS, FREEZE_EXPIRIES, HOLDING_LOTS, IDENTITIES,
+    IDENTITY_WALLETS, RATES, SUSPENSIONS, TRANSFERS, VERIFICATIONS, VERIFICATION_CONFIGS,
+};
+use security_token_client::accounts::{
+    DistributionRoot, FreezeExpiry, HoldingLot, Identity, IdentityWallet, Rate, Suspension,
+    VerificationConfig,
+};
+use security_token_client::discriminators::SecurityTokenDiscriminators;
+use security_token_client::travel_rule::TransferEvent;
+use security_token_client::verification::{VerificationEvent, VerificationMode};
+use solana_pubkey::Pubkey;
+
+/// Value of a row column
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    Null,
+    Pubkey(Pubkey),
+    PubkeyArray(Vec<Pubkey>),
+    Signature(String),
+    Bool(bool),
+    U8(u8),
+    U32(u32),
+    U64(u64),
+    I64(i64),
+    Bytes(Vec<u8>),
+}
+
+impl Value {
+    /// Column type the value is stored in, `None` for `Null`
+    pub fn column_type(&self) -> Option<ColumnType> {
+        Some(match self {
+            Value::Null => return None,
+            Value::Pubkey(_) => ColumnType::Pubkey,
+            Value::PubkeyArray(_) => ColumnType::PubkeyArray,
+            Value::Signature(_) => ColumnType::Signature,
+            Value::Bool(_) => ColumnType::Bool,
+            Value::U8(_) => ColumnType::U8,
+            Value::U32(_) => ColumnType::U32,
+            Value::U64(_) => ColumnType::U64,
+            Value::I64(_) => ColumnType::I64,
+            Value::Bytes(_) => ColumnType::Bytes,
+        })
+    }
+}
+
+/// Row of `table`, one value per column in column order
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Row {
+    pub table: &'static Table,
+    pub values: Vec<Value>,
+}
+
+impl Row {
+    /// Value of the column named `name`
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        let index = self
+            .table
+            .columns
+            .iter()
+            .position(|column| column.name == name)?;
+        self.values.get(index)
+    }
+}
+
+/// Where an event was logged
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventLocation {
+    /// Base58 signature of the transaction
+    pub signature: String,
+    pub slot: u64,
+    /// Index of the `Program data:` line among the data logs of the transaction
+    pub log_index: u32,
+}
+
+impl EventLocation {
+    fn values(&self) -> [Value; 3] {
+        [
+            Value::Signature(self.signature.clone()),
+            Value::U32(self.log_index),
+            Value::U64(self.slot),
+        ]
+    }
+}
+
+/// Row of a transfer event
+pub fn transfer_row(location: &EventLocation, event: &TransferEvent) -> Row {
+    let mut values = location.values().to_vec();
+    values.extend([
+        Value::Pubkey(event.mint),
+        Value::Pubkey(event.from),
+        Value::Pubkey(event.to),
+        Value::U64(event.amount),
+        event
+            .memo_hash
+            .map_or(Value::Null, |hash| Value::Bytes(hash.to_vec())),
+    ]);
+    Row {
+        table: &TRANSFERS,
+        values,
+    }
+}
+
+/// Row of a verification event
+pub fn verification_row(location: &EventLocation, event: &VerificationEvent) -> Row {
+    let mut values = location.values().to_vec();
+    values.extend([
+        Value::Pubkey(event.mint),
+        Value::U8(event.instruction_discriminator),
+        Value::Bool(event.mode == VerificationMode::Cpi),
+        Value::U8(event.threshold),
+        Value::U64(event.reason),
+        Value::PubkeyArray(event.programs.clone()),
+    ]);
+    Row {
+        table: &VERIFICATIONS,
+        values,
+    }
+}
+
+/// Row of base64-decoded `Program data:` bytes, `None` if they are not a known event
+pub fn event_row(location: &EventLocation, data: &[u8]) -> Option<Row> {
+    if let Some(event) = TransferEvent::from_log_data(data) {
+        return Some(transfer_row(location, &event));
+    }
+    VerificationEvent::from_log_data(data).map(|event| verification_row(location, &event))
+}
+
+/// Program account stored in a table of the schema
+pub trait AccountRow: Sized {
+    /// Account discriminator, the first byte of the account data
+    const DISCRIMINATOR: u8;
+    const TABLE: &'static Table;
+
+    fn from_data(data: &[u8]) -> Option<Self>;
+
+    /// Values of the columns after `address` and `slot`
+    fn values(&self) -> Vec<Value>;
+
+    /// Row of the account at `address` as of `slot`
+    fn to_row(&self, address: &Pubkey, slot: u64) -> Row {
+        let mut values = vec![Value::Pubkey(*address), Value::U64(slot)];
+        values.extend(self.values());
+        Row {
+            table: Self::TABLE,
+            values,
+        }
+    }
+}
+
+macro_rules! account_row {
+    ($account:ty, $discriminator:ident, $table:expr, |$state:ident| $values:expr) => {
+        impl AccountRow for $account {
+            const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::$discriminator as u8;
+            const TABLE: &'static Table = &$table;
+
+            fn from_data(data: &[u8]) -> Option<Self> {
+                Self::from_bytes(data).ok()
+            }
+
+            fn values(&self) -> Vec<Value> {
+                let $state = self;
+                $values
+            }
+        }
+    };
+}
+
+account_row!(
+    VerificationConfig,
+    VerificationConfigDiscriminator,
+    VERIFICATION_CONFIGS,
+    |config| vec![
+        Value::U8(config.instruction_discriminator),
+        Value::Bool(config.cpi_mode),
+        Value::PubkeyArray(config.verification_programs.clone()),
+        Value::U8(config.threshold),
+        Value::U8(config.lookback_window),
+        Value::Bool(config.require_top_level),
+    ]
+);
+
+account_row!(Rate, RateDiscriminator, RATES, |rate| {
+    let mut values = vec![
+        Value::U8(rate.rounding as u8),
+        Value::U8(rate.numerator),
+        Value::U8(rate.denominator),
+    ];
+    if rate.oracle == Pubkey::default() {
+        values.extend(std::iter::repeat_n(Value::Null, 5));
+    } else {
+        values.extend([
+            Value::Pubkey(rate.oracle),
+            Value::U32(rate.oracle_value_offset),
+            Value::U8(rate.oracle_value_decimals),
+            Value::U64(rate.oracle_min_value),
+            Value::U64(rate.oracle_max_value),
+        ]);
+    }
+    values
+});
+
+account_row!(
+    Identity,
+    IdentityDiscriminator,
+    IDENTITIES,
+    |identity| vec![
+        Value::Pubkey(identity.mint),
+        Value::Bytes(identity.investor_id.to_vec()),
+        Value::PubkeyArray(identity.wallets.clone()),
+    ]
+);
+
+account_row!(
+    IdentityWallet,
+    IdentityWalletDiscriminator,
+    IDENTITY_WALLETS,
+    |wallet| vec![Value::Pubkey(wallet.identity)]
+);
+
+account_row!(
+    HoldingLot,
+    HoldingLotDiscriminator,
+    HOLDING_LOTS,
+    |lot| vec![
+        Value::Pubkey(lot.token_account),
+        Value::U64(lot.lot_id),
+        Value::U64(lot.amount),
+        Value::I64(lot.acquired_timestamp),
+        Value::I64(lot.unlock_timestamp),
+    ]
+);
+
+account_row!(
+    Suspension,
+    SuspensionDiscriminator,
+    SUSPENSIONS,
+    |suspension| vec![
+        Value::Pubkey(suspension.mint),
+        Value::Pubkey(suspension.token_account),
+        Value::I64(suspension.suspended_at),
+    ]
+);
+
+account_row!(
+    FreezeExpiry,
+    FreezeExpiryDiscriminator,
+    FREEZE_EXPIRIES,
+    |expiry| vec![
+        Value::Pubkey(expiry.mint),
+        Value::Pubkey(expiry.token_account),
+        Value::I64(expiry.auto_thaw_at),
+    ]
+);
+
+account_row!(
+    DistributionRoot,
+    DistributionRootDiscriminator,
+    DISTRIBUTION_ROOTS,
+    |root| vec![
+        Value::Pubkey(root.mint),
+        Value::Bytes(root.merkle_root.to_vec()),
+        Value::U64(root.action_id),
+        Value::U8(root.status as u8),
+    ]
+);
+
+fn decode_row<T: AccountRow>(address: &Pubkey, slot: u64, data: &[u8]) -> Option<Row> {
+    T::from_data(data).map(|account| account.to_row(address, slot))
+}
+
+/// Row of the program account at `address` as of `slot`, `None` for accounts outside the
+/// schema or data that doesn't decode
+pub fn account_row(address: &Pubkey, slot: u64, data: &[u8]) -> Option<Row> {
+    match *data.first()? {
+        VerificationConfig::DISCRIMINATOR => decode_row::<VerificationConfig>(address, slot, data),
+        Rate::DISCRIMINATOR => decode_row::<Rate>(address, slot, data),
+        Identity::DISCRIMINATOR => decode_row::<Identity>(address, slot, data),
+        IdentityWallet::DISCRIMINATOR => decode_row::<IdentityWallet>(address, slot, data),
+        HoldingLot::DISCRIMINATOR => decode_row::<HoldingLot>(address, slot, data),
+        Suspension::DISCRIMINATOR => decode_row::<Suspension>(address, slot, data),
+        FreezeExpiry::DISCRIMINATOR => decode_row::<FreezeExpiry>(address, slot, data),
+        DistributionRoot::DISCRIMINATOR => decode_row::<DistributionRoot>(address, slot, data),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use borsh::BorshSerialize;
+    use security_token_client::types::{DistributionRootStatus, Rounding};
+
+    fn location() -> EventLocation {
+        EventLocation {
+            signature: "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW".to_string(),
+            slot: 42,
+            log_index: 1,
+        }
+    }
+
+    fn assert_matches_table(row: &Row) {
+        assert_eq!(
+            row.values.len(),
+            row.table.columns.len(),
+            "{}",
+            row.table.name
+        );
+        for (column, value) in row.table.columns.iter().zip(&row.values) {
+            match value.column_type() {
+                Some(column_type) => assert_eq!(column_type, column.column_type, "{}", column.name),
+                None => assert!(column.nullable, "{}", column.name),
+            }
+        }
+    }
+
+    fn account_data<T: BorshSerialize>(account: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_event_rows() {
+        let mint = Pubkey::new_unique();
+        let mut transfer = vec![0u8; 138];
+        transfer[1..33].copy_from_slice(mint.as_ref());
+        transfer[97..105].copy_from_slice(&5u64.to_le_bytes());
+        let row = event_row(&location(), &transfer).unwrap();
+        assert_matches_table(&row);
+        assert_eq!(row.table, &TRANSFERS);
+        assert_eq!(row.get("mint"), Some(&Value::Pubkey(mint)));
+        assert_eq!(row.get("memo_hash"), Some(&Value::Null));
+
+        let mut verification = vec![0u8; 45 + 32];
+        verification[0] = 1;
+        verification[34] = 1;
+        verification[44] = 1;
+        let row = event_row(&location(), &verification).unwrap();
+        assert_matches_table(&row);
+        assert_eq!(row.table, &VERIFICATIONS);
+        assert_eq!(row.get("cpi_mode"), Some(&Value::Bool(true)));
+
+        assert!(event_row(&location(), &[2]).is_none());
+    }
+
+    #[test]
+    fn test_account_rows() {
+        let address = Pubkey::new_unique();
+        let accounts = [
+            account_data(&VerificationConfig {
+                discriminator: 1,
+                instruction_discriminator: 12,
+                cpi_mode: false,
+                bump: 255,
+                verification_programs: vec![Pubkey::new_unique()],
+                threshold: 0,
+                lookback_window: 0,
+                require_top_level: false,
+            }),
+            account_data(&Rate {
+                discriminator: 2,
+                rounding: Rounding::Down,
+                numerator: 1,
+                denominator: 2,
+                bump: 255,
+                oracle: Pubkey::default(),
+                oracle_value_offset: 0,
+                oracle_value_decimals: 0,
+                oracle_min_value: 0,
+                oracle_max_value: 0,
+            }),
+            account_data(&Identity {
+                discriminator: 7,
+                mint: Pubkey::new_unique(),
+                investor_id: [3; 32],
+                bump: 255,
+                wallets: vec![],
+            }),
+            account_data(&IdentityWallet {
+                discriminator: 8,
+                identity: Pubkey::new_unique(),
+                bump: 255,
+            }),
+            account_data(&HoldingLot {
+                discriminator: 24,
+                token_account: Pubkey::new_unique(),
+                payer: Pubkey::new_unique(),
+                lot_id: 0,
+                amount: 10,
+                acquired_timestamp: 1,
+                unlock_timestamp: 2,
+                bump: 255,
+            }),
+            account_data(&Suspension {
+                discriminator: 25,
+                mint: Pubkey::new_unique(),
+                token_account: Pubkey::new_unique(),
+                payer: Pubkey::new_unique(),
+                suspended_at: 1,
+                bump: 255,
+            }),
+            account_data(&FreezeExpiry {
+                discriminator: 26,
+                mint: Pubkey::new_unique(),
+                token_account: Pubkey::new_unique(),
+                payer: Pubkey::new_unique(),
+                auto_thaw_at: 1,
+                bump: 255,
+            }),
+            account_data(&DistributionRoot {
+                discriminator: 31,
+                mint: Pubkey::new_unique(),
+                merkle_root: [4; 32],
+                action_id: 1,
+                status: DistributionRootStatus::Supplemental,
+                bump: 255,
+            }),
+        ];
+
+        for data in &accounts {
+            let row = account_row(&address, 7, data).unwrap();
+            assert_matches_table(&row);
+            assert_eq!(row.get("address"), Some(&Value::Pubkey(address)));
+        }
+        let rate = account_row(&address, 7, &accounts[1]).unwrap();
+        assert_eq!(rate.get("rounding"), Some(&Value::U8(1)));
+        assert_eq!(rate.get("oracle"), Some(&Value::Null));
+
+        assert!(account_row(&address, 7, &[3]).is_none());
+        assert!(account_row(&address, 7, &[]).is_none());
+    }
+}
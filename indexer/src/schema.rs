@@ -0,0 +1,279 @@
+//! Tables and columns of the indexer schema.
+//!
+//! Event tables are keyed by the [`EventLocation`](crate::EventLocation) of the log,
+//! account tables by the account address and hold the latest state seen at `slot`.
+//! Enums are stored as their borsh variant index.
+
+/// Type of a column value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnType {
+    /// Base58 encoded public key
+    Pubkey,
+    /// List of base58 encoded public keys
+    PubkeyArray,
+    /// Base58 encoded transaction signature
+    Signature,
+    Bool,
+    U8,
+    U32,
+    U64,
+    I64,
+    Bytes,
+}
+
+impl ColumnType {
+    /// PostgreSQL type storing the full value range
+    pub fn sql_type(&self) -> &'static str {
+        match self {
+            ColumnType::Pubkey | ColumnType::Signature => "TEXT",
+            ColumnType::PubkeyArray => "TEXT[]",
+            ColumnType::Bool => "BOOLEAN",
+            ColumnType::U8 => "SMALLINT",
+            ColumnType::U32 | ColumnType::I64 => "BIGINT",
+            ColumnType::U64 => "NUMERIC(20, 0)",
+            ColumnType::Bytes => "BYTEA",
+        }
+    }
+}
+
+/// Column of a table
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Column {
+    pub name: &'static str,
+    pub column_type: ColumnType,
+    pub nullable: bool,
+}
+
+const fn column(name: &'static str, column_type: ColumnType) -> Column {
+    Column {
+        name,
+        column_type,
+        nullable: false,
+    }
+}
+
+const fn nullable(name: &'static str, column_type: ColumnType) -> Column {
+    Column {
+        name,
+        column_type,
+        nullable: true,
+    }
+}
+
+/// Table of the schema, `primary_key` columns come first in `columns`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Table {
+    pub name: &'static str,
+    pub columns: &'static [Column],
+    pub primary_key: &'static [&'static str],
+}
+
+impl Table {
+    /// Column named `name`
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+
+    /// PostgreSQL statement creating the table
+    pub fn create_sql(&self) -> String {
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| {
+                let null = if column.nullable { "" } else { " NOT NULL" };
+                format!(
+                    "    {} {}{}",
+                    column.name,
+                    column.column_type.sql_type(),
+                    null
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+        format!(
+            "CREATE TABLE IF NOT EXISTS {} (\n{},\n    PRIMARY KEY ({})\n);",
+            self.name,
+            columns,
+            self.primary_key.join(", ")
+        )
+    }
+}
+
+const EVENT_KEY: [&str; 2] = ["signature", "log_index"];
+const ACCOUNT_KEY: [&str; 1] = ["address"];
+
+/// Transfers logged by the program and the transfer hook
+pub const TRANSFERS: Table = Table {
+    name: "transfers",
+    columns: &[
+        column("signature", ColumnType::Signature),
+        column("log_index", ColumnType::U32),
+        column("slot", ColumnType::U64),
+        column("mint", ColumnType::Pubkey),
+        column("from_token_account", ColumnType::Pubkey),
+        column("to_token_account", ColumnType::Pubkey),
+        column("amount", ColumnType::U64),
+        nullable("memo_hash", ColumnType::Bytes),
+    ],
+    primary_key: &EVENT_KEY,
+};
+
+/// Verification outcomes logged by the program and the transfer hook
+pub const VERIFICATIONS: Table = Table {
+    name: "verifications",
+    columns: &[
+        column("signature", ColumnType::Signature),
+        column("log_index", ColumnType::U32),
+        column("slot", ColumnType::U64),
+        column("mint", ColumnType::Pubkey),
+        column("instruction_discriminator", ColumnType::U8),
+        column("cpi_mode", ColumnType::Bool),
+        column("threshold", ColumnType::U8),
+        column("reason", ColumnType::U64),
+        column("programs", ColumnType::PubkeyArray),
+    ],
+    primary_key: &EVENT_KEY,
+};
+
+pub const VERIFICATION_CONFIGS: Table = Table {
+    name: "verification_configs",
+    columns: &[
+        column("address", ColumnType::Pubkey),
+        column("slot", ColumnType::U64),
+        column("instruction_discriminator", ColumnType::U8),
+        column("cpi_mode", ColumnType::Bool),
+        column("verification_programs", ColumnType::PubkeyArray),
+        column("threshold", ColumnType::U8),
+        column("lookback_window", ColumnType::U8),
+        column("require_top_level", ColumnType::Bool),
+    ],
+    primary_key: &ACCOUNT_KEY,
+};
+
+/// Rates, `oracle` and its bounds are `NULL` for static rates
+pub const RATES: Table = Table {
+    name: "rates",
+    columns: &[
+        column("address", ColumnType::Pubkey),
+        column("slot", ColumnType::U64),
+        column("rounding", ColumnType::U8),
+        column("numerator", ColumnType::U8),
+        column("denominator", ColumnType::U8),
+        nullable("oracle", ColumnType::Pubkey),
+        nullable("oracle_value_offset", ColumnType::U32),
+        nullable("oracle_value_decimals", ColumnType::U8),
+        nullable("oracle_min_value", ColumnType::U64),
+        nullable("oracle_max_value", ColumnType::U64),
+    ],
+    primary_key: &ACCOUNT_KEY,
+};
+
+pub const IDENTITIES: Table = Table {
+    name: "identities",
+    columns: &[
+        column("address", ColumnType::Pubkey),
+        column("slot", ColumnType::U64),
+        column("mint", ColumnType::Pubkey),
+        column("investor_id", ColumnType::Bytes),
+        column("wallets", ColumnType::PubkeyArray),
+    ],
+    primary_key: &ACCOUNT_KEY,
+};
+
+pub const IDENTITY_WALLETS: Table = Table {
+    name: "identity_wallets",
+    columns: &[
+        column("address", ColumnType::Pubkey),
+        column("slot", ColumnType::U64),
+        column("identity", ColumnType::Pubkey),
+    ],
+    primary_key: &ACCOUNT_KEY,
+};
+
+pub const SUSPENSIONS: Table = Table {
+    name: "suspensions",
+    columns: &[
+        column("address", ColumnType::Pubkey),
+        column("slot", ColumnType::U64),
+        column("mint", ColumnType::Pubkey),
+        column("token_account", ColumnType::Pubkey),
+        column("suspended_at", ColumnType::I64),
+    ],
+    primary_key: &ACCOUNT_KEY,
+};
+
+pub const FREEZE_EXPIRIES: Table = Table {
+    name: "freeze_expiries",
+    columns: &[
+        column("address", ColumnType::Pubkey),
+        column("slot", ColumnType::U64),
+        column("mint", ColumnType::Pubkey),
+        column("token_account", ColumnType::Pubkey),
+        column("auto_thaw_at", ColumnType::I64),
+    ],
+    primary_key: &ACCOUNT_KEY,
+};
+
+pub const HOLDING_LOTS: Table = Table {
+    name: "holding_lots",
+    columns: &[
+        column("address", ColumnType::Pubkey),
+        column("slot", ColumnType::U64),
+        column("token_account", ColumnType::Pubkey),
+        column("lot_id", ColumnType::U64),
+        column("amount", ColumnType::U64),
+        column("acquired_timestamp", ColumnType::I64),
+        column("unlock_timestamp", ColumnType::I64),
+    ],
+    primary_key: &ACCOUNT_KEY,
+};
+
+pub const DISTRIBUTION_ROOTS: Table = Table {
+    name: "distribution_roots",
+    columns: &[
+        column("address", ColumnType::Pubkey),
+        column("slot", ColumnType::U64),
+        column("mint", ColumnType::Pubkey),
+        column("merkle_root", ColumnType::Bytes),
+        column("action_id", ColumnType::U64),
+        column("status", ColumnType::U8),
+    ],
+    primary_key: &ACCOUNT_KEY,
+};
+
+/// Every table of the schema
+pub const TABLES: &[&Table] = &[
+    &TRANSFERS,
+    &VERIFICATIONS,
+    &VERIFICATION_CONFIGS,
+    &RATES,
+    &IDENTITIES,
+    &IDENTITY_WALLETS,
+    &SUSPENSIONS,
+    &FREEZE_EXPIRIES,
+    &HOLDING_LOTS,
+    &DISTRIBUTION_ROOTS,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primary_keys_lead_the_columns() {
+        for table in TABLES {
+            for (column, key) in table.columns.iter().zip(table.primary_key) {
+                assert_eq!(column.name, *key, "{}", table.name);
+                assert!(!column.nullable, "{}", table.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_create_sql() {
+        assert_eq!(
+            IDENTITY_WALLETS.create_sql(),
+            "CREATE TABLE IF NOT EXISTS identity_wallets (\n    address TEXT NOT NULL,\n    slot NUMERIC(20, 0) NOT NULL,\n    identity TEXT NOT NULL,\n    PRIMARY KEY (address)\n);"
+        );
+    }
+}
@@ -6,6 +6,10 @@ pub mod seeds {
     pub const MINT_AUTHORITY: &[u8] = b"mint.authority";
     /// Seed for pause authority PDA
     pub const PAUSE_AUTHORITY: &[u8] = b"mint.pause_authority";
+    /// Seed for scaled UI amount authority PDA
+    pub const SCALED_UI_AMOUNT_AUTHORITY: &[u8] = b"mint.scaled_ui_amount_authority";
+    /// Seed for transfer fee authority PDA
+    pub const TRANSFER_FEE_AUTHORITY: &[u8] = b"mint.transfer_fee_authority";
     /// Seed for freeze authority PDA
     pub const FREEZE_AUTHORITY: &[u8] = b"mint.freeze_authority";
     /// Seed for transfer hook PDA
@@ -26,6 +30,104 @@ pub mod seeds {
     pub const PROOF_ACCOUNT: &[u8] = b"proof";
     /// Seed for distribution escrow authority PDA
     pub const DISTRIBUTION_ESCROW_AUTHORITY: &[u8] = b"distribution_escrow_authority";
+    /// Seed for wrap vault PDA
+    pub const WRAP_VAULT: &[u8] = b"wrap_vault";
+    /// Seed for agent account PDA
+    pub const AGENT_ACCOUNT: &[u8] = b"agent";
+    /// Seed for identity account PDA
+    pub const IDENTITY_ACCOUNT: &[u8] = b"identity";
+    /// Seed for identity wallet (token account to identity link) PDA
+    pub const IDENTITY_WALLET_ACCOUNT: &[u8] = b"identity_wallet";
+    /// Seed for position limit account PDA
+    pub const POSITION_LIMIT_ACCOUNT: &[u8] = b"position_limit";
+    /// Seed for transfer acceptance (recipient opt-in mode) account PDA
+    pub const TRANSFER_ACCEPTANCE_ACCOUNT: &[u8] = b"transfer_acceptance";
+    /// Seed for pending transfer account PDA
+    pub const PENDING_TRANSFER_ACCOUNT: &[u8] = b"pending_transfer";
+    /// Seed for transfer escrow authority PDA
+    pub const TRANSFER_ESCROW_AUTHORITY: &[u8] = b"transfer_escrow_authority";
+    /// Seed for transfer approval account PDA
+    pub const TRANSFER_APPROVAL_ACCOUNT: &[u8] = b"transfer_approval";
+    /// Seed for transfer request account PDA
+    pub const TRANSFER_REQUEST_ACCOUNT: &[u8] = b"transfer_request";
+    /// Seed for coupon accrual config account PDA
+    pub const ACCRUAL_CONFIG_ACCOUNT: &[u8] = b"accrual_config";
+    /// Seed for coupon claim (balance snapshot) account PDA
+    pub const COUPON_CLAIM_ACCOUNT: &[u8] = b"coupon_claim";
+    /// Seed for bond maturity account PDA
+    pub const MATURITY_ACCOUNT: &[u8] = b"maturity";
+    /// Seed for NAV oracle account PDA
+    pub const NAV_ORACLE_ACCOUNT: &[u8] = b"nav_oracle";
+    /// Seed for primary issuance subscription account PDA
+    pub const SUBSCRIPTION_ACCOUNT: &[u8] = b"subscription";
+    /// Seed for subscription commitment account PDA
+    pub const SUBSCRIPTION_COMMITMENT_ACCOUNT: &[u8] = b"subscription_commitment";
+    /// Seed for Dutch auction account PDA
+    pub const AUCTION_ACCOUNT: &[u8] = b"auction";
+    /// Seed for auction bid escrow account PDA
+    pub const AUCTION_BID_ACCOUNT: &[u8] = b"auction_bid";
+    /// Seed for Rule 144 holding period account PDA
+    pub const HOLDING_PERIOD_ACCOUNT: &[u8] = b"holding_period";
+    /// Seed for restricted holding account PDA of a token account
+    pub const RESTRICTED_HOLDING_ACCOUNT: &[u8] = b"restricted_holding";
+    /// Seed for restricted holding lot account PDA
+    pub const HOLDING_LOT_ACCOUNT: &[u8] = b"holding_lot";
+    /// Seed for suspension account PDA of a token account
+    pub const SUSPENSION_ACCOUNT: &[u8] = b"suspension";
+    /// Seed for freeze expiry account PDA of a token account
+    pub const FREEZE_EXPIRY_ACCOUNT: &[u8] = b"freeze_expiry";
+    /// Seed for vesting account PDA of a beneficiary token account
+    pub const VESTING_ACCOUNT: &[u8] = b"vesting";
+    /// Seed for withholding rate account PDA of a mint and jurisdiction
+    pub const WITHHOLDING_RATE_ACCOUNT: &[u8] = b"withholding_rate";
+    /// Seed for distribution payout account PDA of a distribution and payout mint
+    pub const DISTRIBUTION_PAYOUT_ACCOUNT: &[u8] = b"distribution_payout";
+    /// Seed for claim deadline account PDA of a distribution
+    pub const CLAIM_DEADLINE_ACCOUNT: &[u8] = b"claim_deadline";
+    /// Seed for distribution root account PDA of an amended merkle root
+    pub const DISTRIBUTION_ROOT_ACCOUNT: &[u8] = b"distribution_root";
+    /// Seed for permit nonce account PDA of a token account
+    pub const PERMIT_NONCE_ACCOUNT: &[u8] = b"permit_nonce";
+    /// Seed for session key account PDA of a mint and session key
+    pub const SESSION_KEY_ACCOUNT: &[u8] = b"session_key";
+    /// Seed for recovery receipt account PDA of a token recovery
+    pub const RECOVERY_RECEIPT_ACCOUNT: &[u8] = b"recovery_receipt";
+    /// Seed for claim bitmap page PDA of a distribution
+    pub const CLAIM_BITMAP_ACCOUNT: &[u8] = b"claim_bitmap";
+    /// Seed for mint migration account PDA of the migrated mint
+    pub const MINT_MIGRATION_ACCOUNT: &[u8] = b"mint_migration";
+    /// Seed for metadata schema account PDA of a mint
+    pub const METADATA_SCHEMA_ACCOUNT: &[u8] = b"metadata_schema";
+    /// Seed for metadata freeze account PDA of a mint
+    pub const METADATA_FREEZE_ACCOUNT: &[u8] = b"metadata_freeze";
+    /// Seed for config template account PDA of an issuer and template id
+    pub const CONFIG_TEMPLATE_ACCOUNT: &[u8] = b"config_template";
+    /// Seed for collateral attestation account PDA of a token account
+    pub const COLLATERAL_ATTESTATION_ACCOUNT: &[u8] = b"collateral_attestation";
+    /// Seed for reserve attestation account PDA of a custodian
+    pub const RESERVE_ATTESTATION_ACCOUNT: &[u8] = b"reserve_attestation";
+    /// Seed for sub-account ledger PDA of an omnibus token account
+    pub const SUB_ACCOUNT_LEDGER_ACCOUNT: &[u8] = b"sub_account_ledger";
+    /// Seed for sub-account PDA of a beneficial owner in a sub-account ledger
+    pub const SUB_ACCOUNT_ACCOUNT: &[u8] = b"sub_account";
+    /// Seed for succession policy account PDA of a mint
+    pub const SUCCESSION_POLICY_ACCOUNT: &[u8] = b"succession_policy";
+    /// Seed for metadata translator account PDA of a mint and translator
+    pub const METADATA_TRANSLATOR_ACCOUNT: &[u8] = b"metadata_translator";
+    /// Seed for document registry account PDA of a mint
+    pub const DOCUMENT_REGISTRY_ACCOUNT: &[u8] = b"document_registry";
+    /// Seed for permanent delegate config account PDA of a mint
+    pub const PERMANENT_DELEGATE_CONFIG_ACCOUNT: &[u8] = b"permanent_delegate_config";
+    /// Seed for pause delegate account PDA of a mint
+    pub const PAUSE_DELEGATE_ACCOUNT: &[u8] = b"pause_delegate";
+    /// Seed for config admin account PDA of a mint
+    pub const CONFIG_ADMIN_ACCOUNT: &[u8] = b"config_admin";
+    /// Seed for pending verification config account PDA of a mint and instruction
+    pub const PENDING_VERIFICATION_CONFIG: &[u8] = b"pending_verification_config";
+    /// Seed for the program config account PDA, one per program
+    pub const PROGRAM_CONFIG: &[u8] = b"program_config";
+    /// Seed for allowlist entry account PDA of a mint and wallet
+    pub const ALLOWLIST_ENTRY_ACCOUNT: &[u8] = b"allowlist_entry";
 }
 
 /// Offset to skip verification overhead accounts (mint, verification_config/mint_authority, instructions_sysvar/signer)
@@ -35,8 +137,56 @@ pub const INSTRUCTION_ACCOUNTS_OFFSET: usize = 3;
 pub const TRANSFER_HOOK_PROGRAM_ID: Pubkey =
     pubkey!("HookXqLKgPaNrHBJ9Jui7oQZz93vMbtA88JjsLa8bmfL");
 
+/// ProgramConfig PDA, seeds ["program_config"], passed as the last account of every haltable instruction
+pub const PROGRAM_CONFIG_ADDRESS: Pubkey = pubkey!("7AZvGSbtGyWkdmSDJ1Y9v4YuSAkBvtxPqJE5BgS585hd");
+
+/// Ed25519 signature verification program ID, used for holder permits
+pub const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+/// SPL Memo program ID, invoked before transfers to token accounts requiring memos
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// Upgradeable BPF loader program ID, owner of the ProgramData account naming the upgrade authority
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// Size of the ProgramData header: state tag (4) + slot (8) + optional upgrade authority (1 + 32)
+pub const PROGRAM_DATA_METADATA_LEN: usize = 45;
+
+/// Transfer hook Execute instruction account indices (source, mint, destination, authority, validation state)
+pub const EXECUTE_SOURCE_INDEX: u8 = 0;
+pub const EXECUTE_MINT_INDEX: u8 = 1;
+pub const EXECUTE_DESTINATION_INDEX: u8 = 2;
+/// Index of the first extra account resolved from the extra account metas list
+pub const EXECUTE_EXTRA_ACCOUNTS_OFFSET: usize = 5;
+
 /// Size of action_id field (u64 type = 8 bytes)
 pub const ACTION_ID_LEN: usize = 8;
 
 /// Maximum number of verification programs that can be registered per instruction
 pub const MAX_VERIFICATION_PROGRAMS: usize = 10;
+
+/// Maximum number of token accounts that can be linked to one identity
+/// Bounded so every wallet slot can be resolved by the transfer hook (u8 account data offset)
+pub const MAX_IDENTITY_WALLETS: usize = 6;
+
+/// Maximum number of omnibus token accounts bound by one reserve attestation
+pub const MAX_RESERVE_ACCOUNTS: usize = 8;
+
+/// Maximum number of signers of a mint authority multisig, as for SPL Token multisigs
+pub const MAX_MULTISIG_SIGNERS: usize = 11;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_program_config_address_matches_pda() {
+        // pinocchio derives addresses with syscalls only, derive it like the clients do
+        let (program_config, _bump) = solana_pubkey::Pubkey::find_program_address(
+            &[seeds::PROGRAM_CONFIG],
+            &solana_pubkey::Pubkey::new_from_array(crate::ID),
+        );
+        assert_eq!(program_config.to_bytes(), PROGRAM_CONFIG_ADDRESS);
+    }
+}
@@ -28,6 +28,230 @@ pub enum SecurityTokenError {
     /// External metadata storage cannot accept metadata data in this instruction
     #[error("External metadata storage cannot accept metadata data in this instruction")]
     ExternalMetadataForbidsData = 7,
+    /// Wrapping Errors
+    /// Underlying mint cannot be wrapped into this security token
+    #[error("Underlying mint cannot be wrapped into this security token")]
+    InvalidUnderlyingMint = 8,
+    /// Agent Errors
+    /// Agent is not permitted to perform this instruction
+    #[error("Agent is not permitted to perform this instruction")]
+    AgentPermissionDenied = 9,
+    /// Identity Errors
+    /// Token account cannot be linked to or unlinked from this identity
+    #[error("Token account cannot be linked to or unlinked from this identity")]
+    InvalidIdentityWallet = 10,
+    /// Position Limit Errors
+    /// Transfer would exceed the investor position limit
+    #[error("Transfer would exceed the investor position limit")]
+    PositionLimitExceeded = 11,
+    /// Transfer Acceptance Errors
+    /// Mint requires transfers to be offered and accepted by the recipient
+    #[error("Transfers of this mint must be accepted by the recipient")]
+    TransferAcceptanceRequired = 12,
+    /// Transfer Approval Errors
+    /// Transfer amount is above the approval threshold of the mint
+    #[error("Transfers above the approval threshold must be requested and approved")]
+    TransferApprovalRequired = 13,
+    /// Maturity Errors
+    /// Redemption is attempted before maturity or after the redemption cutoff
+    #[error("Redemption is only open between maturity and the redemption cutoff")]
+    RedemptionNotOpen = 14,
+    /// Unclaimed redemption funds are withdrawn before the redemption cutoff
+    #[error("Unclaimed redemption funds can only be withdrawn after the redemption cutoff")]
+    RedemptionCutoffNotReached = 15,
+    /// NAV Oracle Errors
+    /// Oracle value is outside of the configured bounds
+    #[error("Oracle value is outside of the configured bounds")]
+    OracleValueOutOfBounds = 16,
+    /// Multiplier is updated before the minimum update interval has passed
+    #[error("Multiplier was updated too recently")]
+    MultiplierUpdateTooFrequent = 17,
+    /// Subscription Errors
+    /// Payment is committed after the subscription closed
+    #[error("Subscription is closed for commitments")]
+    SubscriptionClosed = 18,
+    /// Allocation or settlement is attempted while commitments are still accepted
+    #[error("Subscription has not closed yet")]
+    SubscriptionNotClosed = 19,
+    /// Payment owed for the allocation is above the committed payment
+    #[error("Allocation exceeds the committed payment")]
+    AllocationExceedsCommitment = 20,
+    /// Auction Errors
+    /// Bid is placed outside of the bidding window or after the supply sold out
+    #[error("Auction is not accepting bids")]
+    AuctionNotOpen = 21,
+    /// Clearing is attempted while bids are still accepted or after the auction cleared
+    #[error("Auction is still running or already cleared")]
+    AuctionNotClearable = 22,
+    /// Bid is settled before the clearing price is fixed
+    #[error("Auction has not been cleared yet")]
+    AuctionNotCleared = 23,
+    /// Holding Period Errors
+    /// Restricted lot is transferred or unlocked before its holding period elapsed
+    #[error("Holding period of the restricted lot has not elapsed")]
+    HoldingPeriodNotElapsed = 24,
+    /// Suspension Errors
+    /// Source or destination token account is under an investigative hold
+    #[error("Token account is suspended")]
+    AccountSuspended = 25,
+    /// Freeze Expiry Errors
+    /// Token account is thawed permissionlessly before its auto-thaw timestamp
+    #[error("Freeze has not reached its auto-thaw timestamp")]
+    FreezeNotExpired = 26,
+    /// Vesting Errors
+    /// Vesting release is cranked before any new tokens vested
+    #[error("No newly vested tokens to release")]
+    NothingVested = 27,
+    /// Distribution Errors
+    /// Distribution is claimed after its claim deadline
+    #[error("Claim deadline of the distribution has passed")]
+    ClaimDeadlinePassed = 28,
+    /// Unclaimed distribution remainder is reclaimed before the claim deadline
+    #[error("Claim window of the distribution is still open")]
+    ClaimWindowOpen = 29,
+    /// Distribution is claimed before its claim window opens
+    #[error("Claim window of the distribution has not opened")]
+    ClaimWindowNotOpen = 30,
+    /// Merkle root of the distribution is replaced after its claim window opened
+    #[error("Claim window of the distribution has already opened")]
+    ClaimWindowStarted = 31,
+    /// Distribution is claimed against a merkle root that was replaced
+    #[error("Merkle root of the distribution was replaced")]
+    DistributionRootSuperseded = 32,
+    /// Permit Errors
+    /// Permit is not signed by the holder in an Ed25519 instruction of the transaction
+    #[error("Permit is missing or not signed by the holder")]
+    InvalidPermit = 33,
+    /// Permit is executed after its expiry
+    #[error("Permit has expired")]
+    PermitExpired = 34,
+    /// Permit nonce is not the next nonce of the token account
+    #[error("Permit nonce is not the next nonce of the token account")]
+    InvalidPermitNonce = 35,
+    /// Session Key Errors
+    /// Session key is used after its expiry
+    #[error("Session key has expired")]
+    SessionKeyExpired = 36,
+    /// Session key is not allowed to execute the instruction
+    #[error("Session key is not allowed to execute this instruction")]
+    SessionKeyPermissionDenied = 37,
+    /// Operation amount exceeds the maximum amount of the session key
+    #[error("Operation amount exceeds the session key limit")]
+    SessionKeyLimitExceeded = 38,
+    /// Recovery Errors
+    /// Token account is neither owned by a program nor suspended
+    #[error("Token account is not recoverable")]
+    AccountNotRecoverable = 39,
+    /// Claim Bitmap Errors
+    /// Leaf of the distribution is already marked claimed in its claim bitmap
+    #[error("Leaf already claimed")]
+    LeafAlreadyClaimed = 40,
+    /// Rent Recipient Errors
+    /// Rent recipient is the account being closed or trimmed
+    #[error("Invalid rent recipient")]
+    InvalidRentRecipient = 41,
+    /// Mint Migration Errors
+    /// Mints of a migration are not issued by the same mint creator
+    #[error("Mint migration creator mismatch")]
+    MintMigrationCreatorMismatch = 42,
+    /// Metadata Schema Errors
+    /// Additional metadata lacks a field required by the metadata schema
+    #[error("Required metadata field is missing")]
+    MissingMetadataField = 43,
+    /// Additional metadata field does not match the format required by the metadata schema
+    #[error("Required metadata field is malformed")]
+    MalformedMetadataField = 44,
+    /// Token metadata update authority was removed by FreezeMetadata
+    #[error("Metadata is frozen")]
+    MetadataFrozen = 45,
+    /// External metadata program is not executable or is SPL Token 2022, the system program
+    /// or this program
+    #[error("Invalid external metadata program")]
+    InvalidMetadataProgram = 46,
+    /// Config Template Errors
+    /// Config template account is missing or does not match the one the verification config links
+    #[error("Config template mismatch")]
+    ConfigTemplateMismatch = 47,
+    /// Transfer verification configs are read by the transfer hook and cannot link a template
+    #[error("Config template not supported for transfer")]
+    ConfigTemplateNotSupported = 48,
+    /// Verification config requires the verified instruction to be top-level, but it was invoked via CPI
+    #[error("Instruction is not top-level")]
+    InstructionNotTopLevel = 49,
+    /// Transfer Check Errors
+    /// Mint is paused by its Pausable extension
+    #[error("Mint is paused")]
+    MintPaused = 50,
+    /// Source or destination token account is frozen
+    #[error("Token account is frozen")]
+    AccountFrozen = 51,
+    /// Token CPI Errors
+    /// Token program CPI returned an error, the operation stage and the original error are logged
+    #[error("Token program CPI failed")]
+    TokenCpiFailed = 52,
+    /// Reserve Attestation Errors
+    /// Omnibus token account is duplicated, of another mint or not owned by the custodian,
+    /// or the number of accounts is out of bounds
+    #[error("Invalid reserve token account")]
+    InvalidReserveAccount = 53,
+    /// Sub-Account Ledger Errors
+    /// Omnibus token account balance would drop below the sub-balances recorded in its ledger
+    #[error("Sub-account balances exceed omnibus balance")]
+    SubAccountBalanceExceeded = 54,
+    /// Sub-account ledger still records sub-accounts of beneficial owners
+    #[error("Sub-account ledger is not empty")]
+    SubAccountLedgerNotEmpty = 55,
+    /// Succession Errors
+    /// Primary authority performed a heartbeat within the heartbeat period
+    #[error("Succession not claimable yet")]
+    SuccessionNotClaimable = 56,
+    /// Fallback authority already claimed control of the mint
+    #[error("Succession already claimed")]
+    SuccessionClaimed = 57,
+    /// Metadata Translation Errors
+    /// Translators can only update `i18n.<locale>.<field>` additional metadata keys
+    #[error("Invalid translation key")]
+    InvalidTranslationKey = 58,
+    /// Document Registry Errors
+    /// A document with the same name is already anchored, UpdateDocument replaces it
+    #[error("Document already anchored")]
+    DocumentAlreadyAnchored = 59,
+    /// No document with the name is anchored in the document registry
+    #[error("Document not found")]
+    DocumentNotFound = 60,
+    /// Permanent Delegate Errors
+    /// The permanent delegate of the mint was renounced by DisablePermanentDelegate
+    #[error("Permanent delegate disabled")]
+    PermanentDelegateDisabled = 61,
+    /// Mint Authority Errors
+    /// CloseMintAuthority requires all tokens of the mint to be burned
+    #[error("Mint supply not zero")]
+    MintSupplyNotZero = 62,
+    /// Verification Config Timelock Errors
+    /// Timelocked configs change through UpdateVerificationConfig and ApplyPendingConfig only
+    #[error("Verification config timelocked")]
+    VerificationConfigTimelocked = 63,
+    /// The timelock of the pending config has not elapsed yet
+    #[error("Pending config not active")]
+    PendingConfigNotActive = 64,
+    /// Program Config Errors
+    /// The emergency authority halted state-mutating instructions of the program
+    #[error("Program halted")]
+    ProgramHalted = 65,
+    /// Allowlist Errors
+    /// A transfer party owner has no AllowlistEntry while the allowlist mode is enabled
+    #[error("Wallet not allowlisted")]
+    WalletNotAllowlisted = 66,
+    /// A haltable instruction lacks the ProgramConfig PDA as its last account
+    #[error("Program config required")]
+    ProgramConfigRequired = 67,
+    /// Unwrapping more than the security tokens minted by wrapping into the vault
+    #[error("Unwrap amount exceeds the wrapped supply of the wrap vault")]
+    WrapVaultSupplyExceeded = 68,
+    /// Coupon Errors
+    /// A transfer party has coupons of completed periods left to claim
+    #[error("Coupon claim outdated")]
+    CouponClaimOutdated = 69,
 }
 
 impl From<SecurityTokenError> for ProgramError {
@@ -0,0 +1,232 @@
+use pinocchio::log::sol_log_data;
+use pinocchio::pubkey::Pubkey;
+
+/// Event discriminators, the first byte of every event logged with `sol_log_data`
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecurityTokenEvent {
+    Transfer = 0,
+    Verification = 1,
+    Audit = 2,
+}
+
+/// Invariants checked by AuditMint (bit flags of [`AuditEvent::failed_invariants`])
+pub mod audit_invariants {
+    /// Sampled token account balances do not exceed the mint supply
+    pub const SUPPLY: u8 = 1 << 0;
+    /// Transfer escrow holds the unreleased vesting grants and pending transfers sampled
+    pub const ESCROW: u8 = 1 << 1;
+    /// Omnibus token accounts hold the sub-account balances of their sampled ledgers
+    pub const SUB_ACCOUNTS: u8 = 1 << 2;
+}
+
+/// Transfer executed by the security token program or checked by the transfer hook.
+///
+/// `memo_hash` references travel-rule data exchanged off-chain between VASPs.
+/// Only the hash is recorded, the payload itself never touches the chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferEvent {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub memo_hash: Option<[u8; 32]>,
+}
+
+impl TransferEvent {
+    /// Discriminator (1) + mint (32) + from (32) + to (32) + amount (8) + memo hash option (1 + 32)
+    pub const LEN: usize = 1 + 32 + 32 + 32 + 8 + 1 + 32;
+
+    /// Serialize the event, an absent memo hash keeps its bytes zeroed
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut data = [0u8; Self::LEN];
+        data[0] = SecurityTokenEvent::Transfer as u8;
+        data[1..33].copy_from_slice(&self.mint);
+        data[33..65].copy_from_slice(&self.from);
+        data[65..97].copy_from_slice(&self.to);
+        data[97..105].copy_from_slice(&self.amount.to_le_bytes());
+        if let Some(memo_hash) = &self.memo_hash {
+            data[105] = 1;
+            data[106..138].copy_from_slice(memo_hash);
+        }
+        data
+    }
+
+    /// Log the event as `Program data:` for indexers and auditors
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+/// Outcome of a verification by the configured verification programs, logged by the security
+/// token program and the transfer hook whether it passed or not.
+///
+/// `reason` is `0` when the verification passed, otherwise the error it failed with, encoded
+/// as a Solana program error (custom errors are the plain `SecurityTokenError` code).
+/// `programs` are the consulted verification programs: the ones invoked in CPI mode, the ones
+/// whose calls were found in introspection mode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationEvent {
+    pub mint: Pubkey,
+    pub instruction_discriminator: u8,
+    pub cpi_mode: bool,
+    pub threshold: u8,
+    pub reason: u64,
+    pub programs: Vec<Pubkey>,
+}
+
+impl VerificationEvent {
+    /// Size without programs: discriminator (1) + mint (32) + instruction discriminator (1)
+    /// + cpi_mode (1) + threshold (1) + reason (8) + program count (1)
+    pub const MIN_LEN: usize = 1 + 32 + 1 + 1 + 1 + 8 + 1;
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::MIN_LEN + self.programs.len() * 32);
+        data.push(SecurityTokenEvent::Verification as u8);
+        data.extend_from_slice(&self.mint);
+        data.push(self.instruction_discriminator);
+        data.push(self.cpi_mode as u8);
+        data.push(self.threshold);
+        data.extend_from_slice(&self.reason.to_le_bytes());
+        data.push(self.programs.len() as u8);
+        for program in &self.programs {
+            data.extend_from_slice(program);
+        }
+        data
+    }
+
+    /// Log the event as `Program data:` for surveillance systems
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+/// Health check of a mint computed by AuditMint over a sample of its accounts.
+///
+/// `failed_invariants` is `0` when every [`audit_invariants`] holds. Balances and entitlements
+/// only cover the sampled accounts; `holder_count` counts sampled token accounts with a
+/// non-zero balance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AuditEvent {
+    pub mint: Pubkey,
+    pub slot: u64,
+    pub supply: u64,
+    pub sampled_balance: u64,
+    pub holder_count: u32,
+    pub escrow_balance: u64,
+    pub escrow_entitlements: u64,
+    pub failed_invariants: u8,
+}
+
+impl AuditEvent {
+    /// Discriminator (1) + mint (32) + slot (8) + supply (8) + sampled balance (8)
+    /// + holder count (4) + escrow balance (8) + escrow entitlements (8) + failed invariants (1)
+    pub const LEN: usize = 1 + 32 + 8 + 8 + 8 + 4 + 8 + 8 + 1;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut data = [0u8; Self::LEN];
+        data[0] = SecurityTokenEvent::Audit as u8;
+        data[1..33].copy_from_slice(&self.mint);
+        data[33..41].copy_from_slice(&self.slot.to_le_bytes());
+        data[41..49].copy_from_slice(&self.supply.to_le_bytes());
+        data[49..57].copy_from_slice(&self.sampled_balance.to_le_bytes());
+        data[57..61].copy_from_slice(&self.holder_count.to_le_bytes());
+        data[61..69].copy_from_slice(&self.escrow_balance.to_le_bytes());
+        data[69..77].copy_from_slice(&self.escrow_entitlements.to_le_bytes());
+        data[77] = self.failed_invariants;
+        data
+    }
+
+    /// Log the event as `Program data:` for auditors
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_event_layout() {
+        let event = TransferEvent {
+            mint: [1u8; 32],
+            from: [2u8; 32],
+            to: [3u8; 32],
+            amount: 1_000,
+            memo_hash: Some([4u8; 32]),
+        };
+        let data = event.to_bytes();
+
+        assert_eq!(data[0], SecurityTokenEvent::Transfer as u8);
+        assert_eq!(&data[1..33], &[1u8; 32]);
+        assert_eq!(&data[33..65], &[2u8; 32]);
+        assert_eq!(&data[65..97], &[3u8; 32]);
+        assert_eq!(&data[97..105], &1_000u64.to_le_bytes());
+        assert_eq!(data[105], 1);
+        assert_eq!(&data[106..138], &[4u8; 32]);
+    }
+
+    #[test]
+    fn test_transfer_event_without_memo_hash() {
+        let event = TransferEvent {
+            mint: [1u8; 32],
+            from: [2u8; 32],
+            to: [3u8; 32],
+            amount: 1_000,
+            memo_hash: None,
+        };
+        let data = event.to_bytes();
+
+        assert_eq!(data[105], 0);
+        assert_eq!(&data[106..138], &[0u8; 32]);
+    }
+
+    #[test]
+    fn test_verification_event_layout() {
+        let event = VerificationEvent {
+            mint: [1u8; 32],
+            instruction_discriminator: 12,
+            cpi_mode: true,
+            threshold: 1,
+            reason: 0,
+            programs: vec![[2u8; 32]],
+        };
+        let data = event.to_bytes();
+
+        assert_eq!(data.len(), VerificationEvent::MIN_LEN + 32);
+        assert_eq!(data[0], SecurityTokenEvent::Verification as u8);
+        assert_eq!(&data[1..33], &[1u8; 32]);
+        assert_eq!(data[33], 12);
+        assert_eq!(data[34], 1);
+        assert_eq!(data[35], 1);
+        assert_eq!(&data[36..44], &0u64.to_le_bytes());
+        assert_eq!(data[44], 1);
+        assert_eq!(&data[45..77], &[2u8; 32]);
+    }
+
+    #[test]
+    fn test_audit_event_layout() {
+        let event = AuditEvent {
+            mint: [1u8; 32],
+            slot: 42,
+            supply: 1_000,
+            sampled_balance: 900,
+            holder_count: 3,
+            escrow_balance: 100,
+            escrow_entitlements: 150,
+            failed_invariants: audit_invariants::ESCROW,
+        };
+        let data = event.to_bytes();
+
+        assert_eq!(data[0], SecurityTokenEvent::Audit as u8);
+        assert_eq!(&data[1..33], &[1u8; 32]);
+        assert_eq!(&data[33..41], &42u64.to_le_bytes());
+        assert_eq!(&data[41..49], &1_000u64.to_le_bytes());
+        assert_eq!(&data[49..57], &900u64.to_le_bytes());
+        assert_eq!(&data[57..61], &3u32.to_le_bytes());
+        assert_eq!(&data[61..69], &100u64.to_le_bytes());
+        assert_eq!(&data[69..77], &150u64.to_le_bytes());
+        assert_eq!(data[77], audit_invariants::ESCROW);
+    }
+}
@@ -28,6 +28,117 @@ pub enum SecurityTokenInstruction {
     ClaimDistribution = 21,
     CloseActionReceiptAccount = 22,
     CloseClaimReceiptAccount = 23,
+    CreateWrapVault = 24,
+    WrapToken = 25,
+    UnwrapToken = 26,
+    CreateAgentAccount = 27,
+    UpdateAgentAccount = 28,
+    CloseAgentAccount = 29,
+    CreateIdentityAccount = 30,
+    AddIdentityWallet = 31,
+    RemoveIdentityWallet = 32,
+    CloseIdentityAccount = 33,
+    CreatePositionLimit = 34,
+    UpdatePositionLimit = 35,
+    ClosePositionLimit = 36,
+    CreateTransferAcceptance = 37,
+    CloseTransferAcceptance = 38,
+    OfferTransfer = 39,
+    AcceptTransfer = 40,
+    CancelTransfer = 41,
+    CreateTransferApproval = 42,
+    UpdateTransferApproval = 43,
+    CloseTransferApproval = 44,
+    RequestTransfer = 45,
+    ApproveTransfer = 46,
+    RejectTransfer = 47,
+    CreateAccrualConfig = 48,
+    ClaimCoupon = 49,
+    CreateMaturity = 50,
+    RedeemAtMaturity = 51,
+    WithdrawUnclaimedRedemption = 52,
+    CreateNavOracle = 53,
+    UpdateMultiplierFromOracle = 54,
+    SetRateOracle = 55,
+    RemoveRateOracle = 56,
+    OpenSubscription = 57,
+    Commit = 58,
+    Allocate = 59,
+    Settle = 60,
+    CreateAuction = 61,
+    PlaceBid = 62,
+    ClearAuction = 63,
+    SettleBid = 64,
+    CreateHoldingPeriod = 65,
+    CloseHoldingPeriod = 66,
+    UnlockLot = 67,
+    Suspend = 68,
+    Unsuspend = 69,
+    ThawExpired = 70,
+    CreateVesting = 71,
+    ReleaseVested = 72,
+    CreateWithholdingRate = 73,
+    UpdateWithholdingRate = 74,
+    CreateDistributionPayout = 75,
+    CreateClaimDeadline = 76,
+    ReclaimUnclaimed = 77,
+    AmendDistributionRoot = 78,
+    TransferWithPermit = 79,
+    CreateSessionKey = 80,
+    CloseSessionKey = 81,
+    RecoverTokens = 82,
+    CreateMintMigration = 83,
+    MigrateBalances = 84,
+    CreateMetadataSchema = 85,
+    UpdateMetadataSchema = 86,
+    CloseMetadataSchema = 87,
+    FreezeMetadata = 88,
+    CreateConfigTemplate = 89,
+    UpdateConfigTemplate = 90,
+    CloseConfigTemplate = 91,
+    SetConfigTemplate = 92,
+    CanTransfer = 93,
+    GetHolderStatus = 94,
+    GetEffectiveRate = 95,
+    GetDistributionStatus = 96,
+    AttestCollateral = 97,
+    PublishReserveAttestation = 98,
+    CreateSubAccountLedger = 99,
+    CreditSubAccount = 100,
+    DebitSubAccount = 101,
+    CloseSubAccountLedger = 102,
+    CreateSuccessionPolicy = 103,
+    Heartbeat = 104,
+    ClaimSuccession = 105,
+    CloseSuccessionPolicy = 106,
+    CreateMetadataTranslator = 107,
+    UpdateTranslation = 108,
+    CloseMetadataTranslator = 109,
+    AuditMint = 110,
+    SetTransferFee = 111,
+    WithdrawWithheldTokensFromAccounts = 112,
+    HarvestWithheldTokensToMint = 113,
+    UpdateDefaultAccountState = 114,
+    UpdateScaledUiAmountMultiplier = 115,
+    RemoveMetadataField = 116,
+    AnchorDocument = 117,
+    UpdateDocument = 118,
+    RemoveDocument = 119,
+    ProposeMintCreatorTransfer = 120,
+    AcceptMintCreatorTransfer = 121,
+    SetMintAuthorityMultisig = 122,
+    RotatePermanentDelegate = 123,
+    DisablePermanentDelegate = 124,
+    SetPauseDelegate = 125,
+    CloseMintAuthority = 126,
+    SetConfigAdmin = 127,
+    ApplyPendingConfig = 128,
+    CancelPendingConfig = 129,
+    SetEmergencyAuthority = 130,
+    SetProgramHalt = 131,
+    AddToAllowlist = 132,
+    RemoveFromAllowlist = 133,
+    SetAllowlistMode = 134,
 }
 
 impl TryFrom<u8> for SecurityTokenInstruction {
@@ -59,6 +170,117 @@ impl TryFrom<u8> for SecurityTokenInstruction {
             21 => Ok(SecurityTokenInstruction::ClaimDistribution),
             22 => Ok(SecurityTokenInstruction::CloseActionReceiptAccount),
             23 => Ok(SecurityTokenInstruction::CloseClaimReceiptAccount),
+            24 => Ok(SecurityTokenInstruction::CreateWrapVault),
+            25 => Ok(SecurityTokenInstruction::WrapToken),
+            26 => Ok(SecurityTokenInstruction::UnwrapToken),
+            27 => Ok(SecurityTokenInstruction::CreateAgentAccount),
+            28 => Ok(SecurityTokenInstruction::UpdateAgentAccount),
+            29 => Ok(SecurityTokenInstruction::CloseAgentAccount),
+            30 => Ok(SecurityTokenInstruction::CreateIdentityAccount),
+            31 => Ok(SecurityTokenInstruction::AddIdentityWallet),
+            32 => Ok(SecurityTokenInstruction::RemoveIdentityWallet),
+            33 => Ok(SecurityTokenInstruction::CloseIdentityAccount),
+            34 => Ok(SecurityTokenInstruction::CreatePositionLimit),
+            35 => Ok(SecurityTokenInstruction::UpdatePositionLimit),
+            36 => Ok(SecurityTokenInstruction::ClosePositionLimit),
+            37 => Ok(SecurityTokenInstruction::CreateTransferAcceptance),
+            38 => Ok(SecurityTokenInstruction::CloseTransferAcceptance),
+            39 => Ok(SecurityTokenInstruction::OfferTransfer),
+            40 => Ok(SecurityTokenInstruction::AcceptTransfer),
+            41 => Ok(SecurityTokenInstruction::CancelTransfer),
+            42 => Ok(SecurityTokenInstruction::CreateTransferApproval),
+            43 => Ok(SecurityTokenInstruction::UpdateTransferApproval),
+            44 => Ok(SecurityTokenInstruction::CloseTransferApproval),
+            45 => Ok(SecurityTokenInstruction::RequestTransfer),
+            46 => Ok(SecurityTokenInstruction::ApproveTransfer),
+            47 => Ok(SecurityTokenInstruction::RejectTransfer),
+            48 => Ok(SecurityTokenInstruction::CreateAccrualConfig),
+            49 => Ok(SecurityTokenInstruction::ClaimCoupon),
+            50 => Ok(SecurityTokenInstruction::CreateMaturity),
+            51 => Ok(SecurityTokenInstruction::RedeemAtMaturity),
+            52 => Ok(SecurityTokenInstruction::WithdrawUnclaimedRedemption),
+            53 => Ok(SecurityTokenInstruction::CreateNavOracle),
+            54 => Ok(SecurityTokenInstruction::UpdateMultiplierFromOracle),
+            55 => Ok(SecurityTokenInstruction::SetRateOracle),
+            56 => Ok(SecurityTokenInstruction::RemoveRateOracle),
+            57 => Ok(SecurityTokenInstruction::OpenSubscription),
+            58 => Ok(SecurityTokenInstruction::Commit),
+            59 => Ok(SecurityTokenInstruction::Allocate),
+            60 => Ok(SecurityTokenInstruction::Settle),
+            61 => Ok(SecurityTokenInstruction::CreateAuction),
+            62 => Ok(SecurityTokenInstruction::PlaceBid),
+            63 => Ok(SecurityTokenInstruction::ClearAuction),
+            64 => Ok(SecurityTokenInstruction::SettleBid),
+            65 => Ok(SecurityTokenInstruction::CreateHoldingPeriod),
+            66 => Ok(SecurityTokenInstruction::CloseHoldingPeriod),
+            67 => Ok(SecurityTokenInstruction::UnlockLot),
+            68 => Ok(SecurityTokenInstruction::Suspend),
+            69 => Ok(SecurityTokenInstruction::Unsuspend),
+            70 => Ok(SecurityTokenInstruction::ThawExpired),
+            71 => Ok(SecurityTokenInstruction::CreateVesting),
+            72 => Ok(SecurityTokenInstruction::ReleaseVested),
+            73 => Ok(SecurityTokenInstruction::CreateWithholdingRate),
+            74 => Ok(SecurityTokenInstruction::UpdateWithholdingRate),
+            75 => Ok(SecurityTokenInstruction::CreateDistributionPayout),
+            76 => Ok(SecurityTokenInstruction::CreateClaimDeadline),
+            77 => Ok(SecurityTokenInstruction::ReclaimUnclaimed),
+            78 => Ok(SecurityTokenInstruction::AmendDistributionRoot),
+            79 => Ok(SecurityTokenInstruction::TransferWithPermit),
+            80 => Ok(SecurityTokenInstruction::CreateSessionKey),
+            81 => Ok(SecurityTokenInstruction::CloseSessionKey),
+            82 => Ok(SecurityTokenInstruction::RecoverTokens),
+            83 => Ok(SecurityTokenInstruction::CreateMintMigration),
+            84 => Ok(SecurityTokenInstruction::MigrateBalances),
+            85 => Ok(SecurityTokenInstruction::CreateMetadataSchema),
+            86 => Ok(SecurityTokenInstruction::UpdateMetadataSchema),
+            87 => Ok(SecurityTokenInstruction::CloseMetadataSchema),
+            88 => Ok(SecurityTokenInstruction::FreezeMetadata),
+            89 => Ok(SecurityTokenInstruction::CreateConfigTemplate),
+            90 => Ok(SecurityTokenInstruction::UpdateConfigTemplate),
+            91 => Ok(SecurityTokenInstruction::CloseConfigTemplate),
+            92 => Ok(SecurityTokenInstruction::SetConfigTemplate),
+            93 => Ok(SecurityTokenInstruction::CanTransfer),
+            94 => Ok(SecurityTokenInstruction::GetHolderStatus),
+            95 => Ok(SecurityTokenInstruction::GetEffectiveRate),
+            96 => Ok(SecurityTokenInstruction::GetDistributionStatus),
+            97 => Ok(SecurityTokenInstruction::AttestCollateral),
+            98 => Ok(SecurityTokenInstruction::PublishReserveAttestation),
+            99 => Ok(SecurityTokenInstruction::CreateSubAccountLedger),
+            100 => Ok(SecurityTokenInstruction::CreditSubAccount),
+            101 => Ok(SecurityTokenInstruction::DebitSubAccount),
+            102 => Ok(SecurityTokenInstruction::CloseSubAccountLedger),
+            103 => Ok(SecurityTokenInstruction::CreateSuccessionPolicy),
+            104 => Ok(SecurityTokenInstruction::Heartbeat),
+            105 => Ok(SecurityTokenInstruction::ClaimSuccession),
+            106 => Ok(SecurityTokenInstruction::CloseSuccessionPolicy),
+            107 => Ok(SecurityTokenInstruction::CreateMetadataTranslator),
+            108 => Ok(SecurityTokenInstruction::UpdateTranslation),
+            109 => Ok(SecurityTokenInstruction::CloseMetadataTranslator),
+            110 => Ok(SecurityTokenInstruction::AuditMint),
+            111 => Ok(SecurityTokenInstruction::SetTransferFee),
+            112 => Ok(SecurityTokenInstruction::WithdrawWithheldTokensFromAccounts),
+            113 => Ok(SecurityTokenInstruction::HarvestWithheldTokensToMint),
+            114 => Ok(SecurityTokenInstruction::UpdateDefaultAccountState),
+            115 => Ok(SecurityTokenInstruction::UpdateScaledUiAmountMultiplier),
+            116 => Ok(SecurityTokenInstruction::RemoveMetadataField),
+            117 => Ok(SecurityTokenInstruction::AnchorDocument),
+            118 => Ok(SecurityTokenInstruction::UpdateDocument),
+            119 => Ok(SecurityTokenInstruction::RemoveDocument),
+            120 => Ok(SecurityTokenInstruction::ProposeMintCreatorTransfer),
+            121 => Ok(SecurityTokenInstruction::AcceptMintCreatorTransfer),
+            122 => Ok(SecurityTokenInstruction::SetMintAuthorityMultisig),
+            123 => Ok(SecurityTokenInstruction::RotatePermanentDelegate),
+            124 => Ok(SecurityTokenInstruction::DisablePermanentDelegate),
+            125 => Ok(SecurityTokenInstruction::SetPauseDelegate),
+            126 => Ok(SecurityTokenInstruction::CloseMintAuthority),
+            127 => Ok(SecurityTokenInstruction::SetConfigAdmin),
+            128 => Ok(SecurityTokenInstruction::ApplyPendingConfig),
+            129 => Ok(SecurityTokenInstruction::CancelPendingConfig),
+            130 => Ok(SecurityTokenInstruction::SetEmergencyAuthority),
+            131 => Ok(SecurityTokenInstruction::SetProgramHalt),
+            132 => Ok(SecurityTokenInstruction::AddToAllowlist),
+            133 => Ok(SecurityTokenInstruction::RemoveFromAllowlist),
+            134 => Ok(SecurityTokenInstruction::SetAllowlistMode),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
@@ -92,18 +314,22 @@ impl SecurityTokenInstruction {
 
 mod idl_gen {
 
+    use pinocchio::pubkey::Pubkey;
+
     use crate::instructions::{
         close_rate_account::CloseRateArgs, convert::ConvertArgs,
         create_proof_account::CreateProofArgs, split::SplitArgs,
         update_proof_account::UpdateProofArgs, update_rate_account::UpdateRateArgs,
         ClaimDistributionArgs, CloseActionReceiptArgs, CloseClaimReceiptArgs,
-        CreateDistributionEscrowArgs, CreateRateArgs, InitializeMintArgs,
-        InitializeVerificationConfigArgs, TrimVerificationConfigArgs, UpdateMetadataArgs,
+        CreateAccrualConfigArgs, CreateAuctionArgs, CreateDistributionEscrowArgs,
+        CreateNavOracleArgs, CreateRateArgs, InitializeMintArgs, InitializeVerificationConfigArgs,
+        RemoveRateOracleArgs, SetRateOracleArgs, TrimVerificationConfigArgs, UpdateMetadataArgs,
         UpdateVerificationConfigArgs, VerifyArgs,
     };
 
     #[derive(shank::ShankInstruction)]
     #[repr(u8)]
+    #[allow(clippy::large_enum_variant)]
     enum _SecurityTokenInstruction {
         // No verification overhead
         // Instruction accounts
@@ -113,6 +339,9 @@ mod idl_gen {
         #[account(3, name = "token_program")]
         #[account(4, name = "system_program")]
         #[account(5, name = "rent_sysvar")]
+        #[account(6, signer, optional, name = "creator")]
+        #[account(7, writable, optional, name = "external_metadata_account")]
+        #[account(8, optional, name = "metadata_program")]
         InitializeMint(InitializeMintArgs) = 0,
 
         // Verification overhead
@@ -125,6 +354,9 @@ mod idl_gen {
         #[account(5, writable, name = "mint_account")]
         #[account(6, name = "token_program")]
         #[account(7, name = "system_program")]
+        #[account(8, optional, name = "metadata_schema_account")]
+        #[account(9, writable, optional, name = "external_metadata_account")]
+        #[account(10, optional, name = "metadata_program")]
         UpdateMetadata(UpdateMetadataArgs) = 1,
 
         // Verification overhead
@@ -155,6 +387,8 @@ mod idl_gen {
         #[account(7, writable, optional, name = "account_metas_pda")]
         #[account(8, optional, name = "transfer_hook_pda")]
         #[account(9, optional, name = "transfer_hook_program")]
+        // Optional account, required to stage the update of a timelocked config
+        #[account(10, writable, optional, name = "pending_config_account")]
         UpdateVerificationConfig(UpdateVerificationConfigArgs) = 3,
 
         // Verification overhead
@@ -162,14 +396,15 @@ mod idl_gen {
         #[account(1, name = "verification_config_or_mint_authority")]
         #[account(2, name = "instructions_sysvar_or_creator")]
         // Instruction accounts
-        #[account(3, name = "mint_account")]
-        #[account(4, writable, name = "config_account")]
-        #[account(5, writable, name = "recipient")]
-        #[account(6, name = "system_program")]
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "config_account")]
+        #[account(6, writable, name = "rent_recipient")]
+        #[account(7, name = "system_program")]
         // Optional accounts, required by accounts meta management
-        #[account(7, writable, optional, name = "account_metas_pda")]
-        #[account(8, optional, name = "transfer_hook_pda")]
-        #[account(9, optional, name = "transfer_hook_program")]
+        #[account(8, writable, optional, name = "account_metas_pda")]
+        #[account(9, optional, name = "transfer_hook_pda")]
+        #[account(10, optional, name = "transfer_hook_program")]
         TrimVerificationConfig(TrimVerificationConfigArgs) = 4,
 
         // Verification overhead
@@ -187,6 +422,12 @@ mod idl_gen {
         #[account(4, writable, name = "mint_account")]
         #[account(5, writable, name = "destination")]
         #[account(6, name = "token_program")]
+        // Accounts recording the minted tokens as a restricted holding lot
+        #[account(7, writable, signer, optional, name = "lot_payer")]
+        #[account(8, optional, name = "holding_period_account")]
+        #[account(9, writable, optional, name = "restricted_holding_account")]
+        #[account(10, writable, optional, name = "holding_lot_account")]
+        #[account(11, optional, name = "system_program")]
         Mint { amount: u64 } = 6,
 
         // Verification overhead
@@ -229,7 +470,11 @@ mod idl_gen {
         #[account(4, name = "mint_account")]
         #[account(5, writable, name = "token_account")]
         #[account(6, name = "token_program")]
-        Freeze = 10,
+        // Accounts recording the auto-thaw timestamp, required when `auto_thaw_at` is set
+        #[account(7, writable, signer, optional, name = "payer")]
+        #[account(8, writable, optional, name = "freeze_expiry_account")]
+        #[account(9, optional, name = "system_program")]
+        Freeze { auto_thaw_at: Option<i64> } = 10,
 
         // Verification overhead
         #[account(0, name = "mint")]
@@ -240,6 +485,9 @@ mod idl_gen {
         #[account(4, name = "mint_account")]
         #[account(5, writable, name = "token_account")]
         #[account(6, name = "token_program")]
+        // Accounts closing the freeze expiry of a freeze with auto-thaw
+        #[account(7, writable, optional, name = "freeze_expiry_account")]
+        #[account(8, writable, optional, name = "rent_destination")]
         Thaw = 11,
 
         // Verification overhead
@@ -253,7 +501,21 @@ mod idl_gen {
         #[account(6, writable, name = "to_token_account")]
         #[account(7, name = "transfer_hook_program")]
         #[account(8, name = "token_program")]
-        Transfer { amount: u64 } = 12,
+        // Accounts recording the received tokens as a restricted holding lot
+        #[account(9, writable, signer, optional, name = "lot_payer")]
+        #[account(10, optional, name = "holding_period_account")]
+        #[account(11, writable, optional, name = "restricted_holding_account")]
+        #[account(12, writable, optional, name = "holding_lot_account")]
+        #[account(13, optional, name = "system_program")]
+        // Memo program invoked with the memo right before the transfer
+        #[account(14, optional, name = "memo_program")]
+        // Optional hash of the off-chain travel-rule payload, logged in the TransferEvent.
+        // Optional memo, required by destinations with the MemoTransfer extension enabled
+        Transfer {
+            amount: u64,
+            memo_hash: Option<[u8; 32]>,
+            memo: Option<String>,
+        } = 12,
 
         // Verification overhead
         #[account(0, name = "mint")]
@@ -283,7 +545,7 @@ mod idl_gen {
         #[account(2, name = "instructions_sysvar_or_creator")]
         // Instruction accounts
         #[account(3, writable, name = "rate_account")]
-        #[account(4, writable, name = "destination")]
+        #[account(4, writable, name = "rent_recipient")]
         #[account(5, name = "mint_from")]
         #[account(6, name = "mint_to")]
         CloseRateAccount(CloseRateArgs) = 15,
@@ -320,6 +582,7 @@ mod idl_gen {
         #[account(11, writable, name = "receipt_account")]
         #[account(12, name = "token_program")]
         #[account(13, name = "system_program")]
+        #[account(14, optional, name = "oracle")]
         Convert(ConvertArgs) = 17,
 
         // Verification overhead
@@ -358,6 +621,7 @@ mod idl_gen {
         #[account(7, name = "token_program")]
         #[account(8, name = "associated_token_account_program")]
         #[account(9, name = "system_program")]
+        #[account(10, writable, optional, name = "claim_bitmap_account")]
         CreateDistributionEscrow(CreateDistributionEscrowArgs) = 20,
 
         // Verification overhead
@@ -375,6 +639,18 @@ mod idl_gen {
         #[account(10, name = "transfer_hook_program")]
         #[account(11, name = "token_program")]
         #[account(12, name = "system_program")]
+        #[account(13, optional, name = "withholding_rate_account")]
+        #[account(14, writable, optional, name = "tax_escrow_token_account")]
+        #[account(15, optional, name = "distribution_payout_account")]
+        #[account(16, optional, name = "payout_mint")]
+        #[account(17, writable, optional, name = "payout_escrow_token_account")]
+        #[account(18, writable, optional, name = "payout_token_account")]
+        #[account(19, optional, name = "payout_token_program")]
+        #[account(20, signer, optional, name = "custodian")]
+        #[account(21, optional, name = "instructions_sysvar_account")]
+        #[account(22, name = "claim_deadline_account")]
+        #[account(23, name = "distribution_root_account")]
+        #[account(24, writable, name = "claim_bitmap_account")]
         ClaimDistribution(ClaimDistributionArgs) = 21,
 
         // Verification overhead
@@ -383,7 +659,7 @@ mod idl_gen {
         #[account(2, name = "instructions_sysvar_or_creator")]
         // Instruction accounts
         #[account(3, writable, name = "receipt_account")]
-        #[account(4, writable, name = "destination")]
+        #[account(4, writable, name = "rent_recipient")]
         #[account(5, name = "mint_account")]
         CloseActionReceiptAccount(CloseActionReceiptArgs) = 22,
 
@@ -393,10 +669,1508 @@ mod idl_gen {
         #[account(2, name = "instructions_sysvar_or_creator")]
         // Instruction accounts
         #[account(3, writable, name = "receipt_account")]
-        #[account(4, writable, name = "destination")]
+        #[account(4, writable, name = "rent_recipient")]
         #[account(5, name = "mint_account")]
         #[account(6, name = "eligible_token_account")]
         #[account(7, optional, name = "proof_account")]
         CloseClaimReceiptAccount(CloseClaimReceiptArgs) = 23,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, name = "underlying_mint")]
+        #[account(6, writable, name = "wrap_vault")]
+        #[account(7, writable, name = "vault_token_account")]
+        #[account(8, name = "underlying_token_program")]
+        #[account(9, name = "associated_token_account_program")]
+        #[account(10, name = "system_program")]
+        CreateWrapVault = 24,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, name = "mint_authority")]
+        #[account(4, writable, name = "wrap_vault")]
+        #[account(5, signer, name = "owner")]
+        #[account(6, writable, name = "mint_account")]
+        #[account(7, name = "underlying_mint")]
+        #[account(8, writable, name = "underlying_token_account")]
+        #[account(9, writable, name = "vault_token_account")]
+        #[account(10, writable, name = "token_account")]
+        #[account(11, name = "underlying_token_program")]
+        #[account(12, name = "token_program")]
+        WrapToken { amount: u64 } = 25,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, name = "permanent_delegate")]
+        #[account(4, writable, name = "wrap_vault")]
+        #[account(5, signer, name = "owner")]
+        #[account(6, writable, name = "mint_account")]
+        #[account(7, name = "underlying_mint")]
+        #[account(8, writable, name = "token_account")]
+        #[account(9, writable, name = "vault_token_account")]
+        #[account(10, writable, name = "underlying_token_account")]
+        #[account(11, name = "underlying_token_program")]
+        #[account(12, name = "token_program")]
+        #[account(13, name = "permanent_delegate_config_account")]
+        UnwrapToken { amount: u64 } = 26,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "agent_account")]
+        #[account(6, name = "agent")]
+        #[account(7, name = "system_program")]
+        CreateAgentAccount { permissions: u8 } = 27,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "agent_account")]
+        UpdateAgentAccount { permissions: u8 } = 28,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "agent_account")]
+        #[account(5, writable, name = "rent_recipient")]
+        CloseAgentAccount = 29,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "identity_account")]
+        #[account(6, name = "system_program")]
+        CreateIdentityAccount { investor_id: [u8; 32] } = 30,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "identity_account")]
+        #[account(6, name = "token_account")]
+        #[account(7, writable, name = "identity_wallet_account")]
+        #[account(8, name = "system_program")]
+        AddIdentityWallet = 31,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, name = "rent_recipient")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "identity_account")]
+        #[account(6, name = "token_account")]
+        #[account(7, writable, name = "identity_wallet_account")]
+        RemoveIdentityWallet = 32,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "identity_account")]
+        #[account(5, writable, name = "rent_recipient")]
+        CloseIdentityAccount = 33,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "position_limit_account")]
+        #[account(6, name = "transfer_verification_config")]
+        #[account(7, name = "system_program")]
+        // Accounts required by accounts meta management
+        #[account(8, writable, name = "account_metas_pda")]
+        #[account(9, name = "transfer_hook_pda")]
+        #[account(10, name = "transfer_hook_program")]
+        CreatePositionLimit { max_ownership_bps: u16 } = 34,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "position_limit_account")]
+        UpdatePositionLimit { max_ownership_bps: u16 } = 35,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "position_limit_account")]
+        #[account(6, name = "transfer_verification_config")]
+        #[account(7, writable, name = "rent_recipient")]
+        #[account(8, name = "system_program")]
+        // Accounts required by accounts meta management
+        #[account(9, writable, name = "account_metas_pda")]
+        #[account(10, name = "transfer_hook_pda")]
+        #[account(11, name = "transfer_hook_program")]
+        ClosePositionLimit = 36,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "transfer_acceptance_account")]
+        #[account(6, name = "transfer_verification_config")]
+        #[account(7, name = "escrow_authority")]
+        #[account(8, writable, name = "escrow_token_account")]
+        #[account(9, name = "system_program")]
+        #[account(10, name = "token_program")]
+        #[account(11, name = "associated_token_program")]
+        // Accounts required by accounts meta management
+        #[account(12, writable, name = "account_metas_pda")]
+        #[account(13, name = "transfer_hook_pda")]
+        #[account(14, name = "transfer_hook_program")]
+        CreateTransferAcceptance = 37,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "transfer_acceptance_account")]
+        #[account(6, name = "transfer_verification_config")]
+        #[account(7, writable, name = "rent_recipient")]
+        #[account(8, name = "system_program")]
+        // Accounts required by accounts meta management
+        #[account(9, writable, name = "account_metas_pda")]
+        #[account(10, name = "transfer_hook_pda")]
+        #[account(11, name = "transfer_hook_program")]
+        CloseTransferAcceptance = 38,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, signer, name = "owner")]
+        #[account(5, name = "mint_account")]
+        #[account(6, writable, name = "source_token_account")]
+        #[account(7, name = "destination_token_account")]
+        #[account(8, writable, name = "pending_transfer_account")]
+        #[account(9, writable, name = "escrow_token_account")]
+        #[account(10, name = "permanent_delegate")]
+        #[account(11, name = "transfer_hook_program")]
+        #[account(12, name = "token_program")]
+        #[account(13, name = "system_program")]
+        #[account(14, name = "source_suspension")]
+        #[account(15, name = "destination_suspension")]
+        OfferTransfer { transfer_id: u64, amount: u64 } = 39,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, signer, name = "owner")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "pending_transfer_account")]
+        #[account(6, writable, name = "destination_token_account")]
+        #[account(7, writable, name = "escrow_token_account")]
+        #[account(8, writable, name = "rent_destination")]
+        #[account(9, name = "permanent_delegate")]
+        #[account(10, name = "transfer_hook_program")]
+        #[account(11, name = "token_program")]
+        #[account(12, name = "destination_suspension")]
+        AcceptTransfer = 40,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, signer, name = "authority")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "pending_transfer_account")]
+        #[account(6, writable, name = "source_token_account")]
+        #[account(7, name = "destination_token_account")]
+        #[account(8, writable, name = "escrow_token_account")]
+        #[account(9, writable, name = "rent_destination")]
+        #[account(10, name = "permanent_delegate")]
+        #[account(11, name = "transfer_hook_program")]
+        #[account(12, name = "token_program")]
+        CancelTransfer = 41,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "transfer_approval_account")]
+        #[account(6, name = "transfer_verification_config")]
+        #[account(7, name = "escrow_authority")]
+        #[account(8, writable, name = "escrow_token_account")]
+        #[account(9, name = "system_program")]
+        #[account(10, name = "token_program")]
+        #[account(11, name = "associated_token_program")]
+        // Accounts required by accounts meta management
+        #[account(12, writable, name = "account_metas_pda")]
+        #[account(13, name = "transfer_hook_pda")]
+        #[account(14, name = "transfer_hook_program")]
+        CreateTransferApproval { threshold: u64 } = 42,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "transfer_approval_account")]
+        UpdateTransferApproval { threshold: u64 } = 43,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "transfer_approval_account")]
+        #[account(6, name = "transfer_verification_config")]
+        #[account(7, writable, name = "rent_recipient")]
+        #[account(8, name = "system_program")]
+        // Accounts required by accounts meta management
+        #[account(9, writable, name = "account_metas_pda")]
+        #[account(10, name = "transfer_hook_pda")]
+        #[account(11, name = "transfer_hook_program")]
+        CloseTransferApproval = 44,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, signer, name = "owner")]
+        #[account(5, name = "mint_account")]
+        #[account(6, writable, name = "source_token_account")]
+        #[account(7, name = "destination_token_account")]
+        #[account(8, writable, name = "transfer_request_account")]
+        #[account(9, writable, name = "escrow_token_account")]
+        #[account(10, name = "permanent_delegate")]
+        #[account(11, name = "transfer_hook_program")]
+        #[account(12, name = "token_program")]
+        #[account(13, name = "system_program")]
+        #[account(14, name = "source_suspension")]
+        #[account(15, name = "destination_suspension")]
+        RequestTransfer { request_id: u64, amount: u64 } = 45,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "transfer_request_account")]
+        #[account(5, writable, name = "destination_token_account")]
+        #[account(6, writable, name = "escrow_token_account")]
+        #[account(7, writable, name = "rent_destination")]
+        #[account(8, name = "permanent_delegate")]
+        #[account(9, name = "transfer_hook_program")]
+        #[account(10, name = "token_program")]
+        #[account(11, name = "destination_suspension")]
+        ApproveTransfer = 46,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "transfer_request_account")]
+        #[account(5, writable, name = "source_token_account")]
+        #[account(6, writable, name = "escrow_token_account")]
+        #[account(7, writable, name = "rent_destination")]
+        #[account(8, name = "permanent_delegate")]
+        #[account(9, name = "transfer_hook_program")]
+        #[account(10, name = "token_program")]
+        RejectTransfer = 47,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "accrual_config_account")]
+        #[account(6, name = "payout_mint")]
+        #[account(7, writable, name = "coupon_vault")]
+        #[account(8, name = "payout_token_program")]
+        #[account(9, name = "associated_token_account_program")]
+        #[account(10, name = "system_program")]
+        #[account(11, name = "transfer_verification_config")]
+        // Accounts required by accounts meta management
+        #[account(12, writable, name = "account_metas_pda")]
+        #[account(13, name = "transfer_hook_pda")]
+        #[account(14, name = "transfer_hook_program")]
+        CreateAccrualConfig(CreateAccrualConfigArgs) = 48,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, name = "accrual_config_account")]
+        #[account(6, name = "token_account")]
+        #[account(7, writable, name = "coupon_claim_account")]
+        #[account(8, name = "payout_mint")]
+        #[account(9, writable, name = "coupon_vault")]
+        #[account(10, writable, name = "payout_token_account")]
+        #[account(11, name = "payout_token_program")]
+        #[account(12, name = "system_program")]
+        ClaimCoupon = 49,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "maturity_account")]
+        #[account(6, name = "payout_mint")]
+        #[account(7, writable, name = "redemption_vault")]
+        #[account(8, name = "payout_token_program")]
+        #[account(9, name = "associated_token_account_program")]
+        #[account(10, name = "system_program")]
+        CreateMaturity {
+            maturity_timestamp: i64,
+            redemption_cutoff: i64,
+            principal_per_token: u64,
+        } = 50,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, name = "permanent_delegate")]
+        #[account(4, signer, name = "owner")]
+        #[account(5, writable, name = "mint_account")]
+        #[account(6, name = "maturity_account")]
+        #[account(7, writable, name = "token_account")]
+        #[account(8, name = "payout_mint")]
+        #[account(9, writable, name = "redemption_vault")]
+        #[account(10, writable, name = "payout_token_account")]
+        #[account(11, name = "payout_token_program")]
+        #[account(12, name = "token_program")]
+        #[account(13, name = "permanent_delegate_config_account")]
+        RedeemAtMaturity { amount: u64 } = 51,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, name = "maturity_account")]
+        #[account(5, name = "payout_mint")]
+        #[account(6, writable, name = "redemption_vault")]
+        #[account(7, writable, name = "destination")]
+        #[account(8, name = "payout_token_program")]
+        WithdrawUnclaimedRedemption = 52,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "nav_oracle_account")]
+        #[account(6, name = "oracle")]
+        #[account(7, name = "system_program")]
+        CreateNavOracle(CreateNavOracleArgs) = 53,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, writable, name = "mint_account")]
+        #[account(4, writable, name = "nav_oracle_account")]
+        #[account(5, name = "oracle")]
+        #[account(6, name = "scaled_ui_amount_authority")]
+        #[account(7, name = "token_program")]
+        UpdateMultiplierFromOracle = 54,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, name = "rate_account")]
+        #[account(4, name = "mint_from")]
+        #[account(5, name = "mint_to")]
+        #[account(6, name = "oracle")]
+        SetRateOracle(SetRateOracleArgs) = 55,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, name = "rate_account")]
+        #[account(4, name = "mint_from")]
+        #[account(5, name = "mint_to")]
+        RemoveRateOracle(RemoveRateOracleArgs) = 56,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "subscription_account")]
+        #[account(6, name = "payment_mint")]
+        #[account(7, writable, name = "payment_vault")]
+        #[account(8, name = "proceeds_account")]
+        #[account(9, name = "payment_token_program")]
+        #[account(10, name = "associated_token_account_program")]
+        #[account(11, name = "system_program")]
+        OpenSubscription {
+            subscription_id: u64,
+            price_per_token: u64,
+            close_timestamp: i64,
+        } = 57,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, signer, name = "investor")]
+        #[account(5, name = "mint_account")]
+        #[account(6, writable, name = "subscription_account")]
+        #[account(7, writable, name = "commitment_account")]
+        #[account(8, name = "payment_mint")]
+        #[account(9, writable, name = "payment_vault")]
+        #[account(10, writable, name = "investor_payment_account")]
+        #[account(11, name = "payment_token_program")]
+        #[account(12, name = "system_program")]
+        Commit { amount: u64 } = 58,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "subscription_account")]
+        #[account(5, writable, name = "commitment_account")]
+        Allocate { allocation: u64 } = 59,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, name = "mint_authority")]
+        #[account(4, writable, name = "investor")]
+        #[account(5, writable, name = "mint_account")]
+        #[account(6, name = "subscription_account")]
+        #[account(7, writable, name = "commitment_account")]
+        #[account(8, writable, name = "token_account")]
+        #[account(9, name = "payment_mint")]
+        #[account(10, writable, name = "payment_vault")]
+        #[account(11, writable, name = "proceeds_account")]
+        #[account(12, writable, name = "investor_payment_account")]
+        #[account(13, name = "payment_token_program")]
+        #[account(14, name = "token_program")]
+        Settle = 60,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "auction_account")]
+        #[account(6, name = "payment_mint")]
+        #[account(7, writable, name = "payment_vault")]
+        #[account(8, name = "proceeds_account")]
+        #[account(9, name = "payment_token_program")]
+        #[account(10, name = "associated_token_account_program")]
+        #[account(11, name = "system_program")]
+        CreateAuction(CreateAuctionArgs) = 61,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, signer, name = "bidder")]
+        #[account(5, name = "mint_account")]
+        #[account(6, writable, name = "auction_account")]
+        #[account(7, writable, name = "bid_account")]
+        #[account(8, name = "payment_mint")]
+        #[account(9, writable, name = "payment_vault")]
+        #[account(10, writable, name = "bidder_payment_account")]
+        #[account(11, name = "payment_token_program")]
+        #[account(12, name = "system_program")]
+        PlaceBid { quantity: u64 } = 62,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "auction_account")]
+        ClearAuction = 63,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, name = "mint_authority")]
+        #[account(4, writable, name = "bidder")]
+        #[account(5, writable, name = "mint_account")]
+        #[account(6, name = "auction_account")]
+        #[account(7, writable, name = "bid_account")]
+        #[account(8, writable, name = "token_account")]
+        #[account(9, name = "payment_mint")]
+        #[account(10, writable, name = "payment_vault")]
+        #[account(11, writable, name = "proceeds_account")]
+        #[account(12, writable, name = "bidder_payment_account")]
+        #[account(13, name = "payment_token_program")]
+        #[account(14, name = "token_program")]
+        SettleBid = 64,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "holding_period_account")]
+        #[account(6, name = "transfer_verification_config")]
+        #[account(7, name = "system_program")]
+        // Accounts required by accounts meta management
+        #[account(8, writable, name = "account_metas_pda")]
+        #[account(9, name = "transfer_hook_pda")]
+        #[account(10, name = "transfer_hook_program")]
+        CreateHoldingPeriod { holding_period: u64 } = 65,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "holding_period_account")]
+        #[account(6, name = "transfer_verification_config")]
+        #[account(7, writable, name = "rent_recipient")]
+        #[account(8, name = "system_program")]
+        // Accounts required by accounts meta management
+        #[account(9, writable, name = "account_metas_pda")]
+        #[account(10, name = "transfer_hook_pda")]
+        #[account(11, name = "transfer_hook_program")]
+        CloseHoldingPeriod = 66,
+
+        // Instruction accounts
+        #[account(0, writable, name = "restricted_holding_account")]
+        #[account(1, writable, name = "holding_lot_account")]
+        #[account(2, writable, name = "rent_destination")]
+        UnlockLot = 67,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, name = "token_account")]
+        #[account(6, writable, name = "suspension_account")]
+        #[account(7, name = "system_program")]
+        Suspend = 68,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "suspension_account")]
+        #[account(5, writable, name = "rent_destination")]
+        Unsuspend = 69,
+
+        // Instruction accounts
+        #[account(0, name = "mint_account")]
+        #[account(1, name = "freeze_authority")]
+        #[account(2, writable, name = "token_account")]
+        #[account(3, writable, name = "freeze_expiry_account")]
+        #[account(4, writable, name = "rent_destination")]
+        #[account(5, name = "token_program")]
+        ThawExpired = 70,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_authority")]
+        #[account(5, writable, name = "mint_account")]
+        #[account(6, name = "beneficiary_token_account")]
+        #[account(7, writable, name = "vesting_account")]
+        #[account(8, name = "escrow_authority")]
+        #[account(9, writable, name = "escrow_token_account")]
+        #[account(10, name = "system_program")]
+        #[account(11, name = "token_program")]
+        #[account(12, name = "associated_token_program")]
+        CreateVesting {
+            vesting_id: u64,
+            total_amount: u64,
+            start_timestamp: i64,
+            end_timestamp: i64,
+        } = 71,
+
+        // Instruction accounts
+        #[account(0, name = "mint_account")]
+        #[account(1, writable, name = "vesting_account")]
+        #[account(2, writable, name = "beneficiary_token_account")]
+        #[account(3, writable, name = "escrow_token_account")]
+        #[account(4, name = "beneficiary_suspension")]
+        #[account(5, writable, name = "rent_destination")]
+        #[account(6, name = "permanent_delegate_authority")]
+        #[account(7, name = "transfer_hook_program")]
+        #[account(8, name = "token_program")]
+        ReleaseVested = 72,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "withholding_rate_account")]
+        #[account(6, writable, name = "tax_escrow_token_account")]
+        #[account(7, name = "system_program")]
+        #[account(8, name = "token_program")]
+        #[account(9, name = "associated_token_program")]
+        CreateWithholdingRate { jurisdiction: u16, rate_bps: u16 } = 73,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "withholding_rate_account")]
+        UpdateWithholdingRate { rate_bps: u16 } = 74,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "distribution_payout_account")]
+        #[account(6, name = "payout_mint")]
+        #[account(7, writable, name = "payout_escrow_token_account")]
+        #[account(8, name = "payout_token_program")]
+        #[account(9, name = "associated_token_program")]
+        #[account(10, name = "system_program")]
+        CreateDistributionPayout {
+            action_id: u64,
+            merkle_root: [u8; 32],
+            rate_numerator: u64,
+            rate_denominator: u64,
+        } = 75,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "claim_deadline_account")]
+        #[account(6, name = "system_program")]
+        CreateClaimDeadline {
+            action_id: u64,
+            claim_deadline: i64,
+            claims_open_at: i64,
+        } = 76,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "permanent_delegate_authority")]
+        #[account(4, name = "mint_account")]
+        #[account(5, name = "claim_deadline_account")]
+        #[account(6, writable, optional, name = "escrow_token_account")]
+        #[account(7, writable, optional, name = "destination_token_account")]
+        #[account(8, name = "transfer_hook_program")]
+        #[account(9, name = "token_program")]
+        #[account(10, optional, name = "distribution_payout_account")]
+        #[account(11, optional, name = "payout_mint")]
+        #[account(12, writable, optional, name = "payout_escrow_token_account")]
+        #[account(13, writable, optional, name = "payout_destination_token_account")]
+        #[account(14, optional, name = "payout_token_program")]
+        ReclaimUnclaimed {
+            action_id: u64,
+            merkle_root: [u8; 32],
+        } = 77,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "permanent_delegate_authority")]
+        #[account(5, name = "mint_account")]
+        #[account(6, name = "claim_deadline_account")]
+        #[account(7, writable, name = "distribution_root_account")]
+        #[account(8, writable, optional, name = "escrow_token_account")]
+        #[account(9, name = "new_escrow_authority")]
+        #[account(10, writable, optional, name = "new_escrow_token_account")]
+        #[account(11, writable, optional, name = "distribution_payout_account")]
+        #[account(12, name = "transfer_hook_program")]
+        #[account(13, name = "token_program")]
+        #[account(14, name = "associated_token_program")]
+        #[account(15, name = "system_program")]
+        AmendDistributionRoot {
+            action_id: u64,
+            merkle_root: [u8; 32],
+            new_merkle_root: [u8; 32],
+            supplemental: bool,
+        } = 78,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "account_delegate_authority")]
+        #[account(5, name = "mint_account")]
+        #[account(6, writable, name = "from_token_account")]
+        #[account(7, writable, name = "to_token_account")]
+        #[account(8, writable, name = "permit_nonce_account")]
+        #[account(9, name = "instructions_sysvar_account")]
+        #[account(10, name = "transfer_hook_program")]
+        #[account(11, name = "token_program")]
+        #[account(12, name = "system_program")]
+        // Transfer hook accounts: the ExtraAccountMetaList PDA followed by its extra accounts
+        #[account(13, name = "account_metas_pda")]
+        TransferWithPermit {
+            amount: u64,
+            nonce: u64,
+            expiry: i64,
+        } = 79,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "session_key_account")]
+        #[account(6, name = "session_key")]
+        #[account(7, name = "system_program")]
+        CreateSessionKey {
+            allowed_instructions: u128,
+            max_amount: u64,
+            expiry: i64,
+        } = 80,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "session_key_account")]
+        #[account(5, writable, name = "rent_recipient")]
+        CloseSessionKey = 81,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "permanent_delegate_authority")]
+        #[account(5, name = "mint_account")]
+        #[account(6, writable, name = "source_token_account")]
+        #[account(7, name = "source_owner")]
+        #[account(8, writable, name = "recovery_token_account")]
+        #[account(9, name = "suspension_account")]
+        #[account(10, writable, name = "recovery_receipt_account")]
+        #[account(11, name = "transfer_hook_program")]
+        #[account(12, name = "token_program")]
+        #[account(13, name = "system_program")]
+        RecoverTokens { recovery_id: u64 } = 82,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_from")]
+        #[account(5, name = "mint_to")]
+        #[account(6, name = "mint_from_authority")]
+        #[account(7, name = "mint_to_authority")]
+        #[account(8, writable, name = "rate_account")]
+        #[account(9, writable, name = "mint_migration_account")]
+        #[account(10, name = "system_program")]
+        CreateMintMigration { action_id: u64 } = 83,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_authority")]
+        #[account(5, name = "permanent_delegate")]
+        #[account(6, writable, name = "mint_from")]
+        #[account(7, writable, name = "mint_to")]
+        #[account(8, name = "rate_account")]
+        #[account(9, writable, name = "mint_migration_account")]
+        #[account(10, name = "token_program")]
+        #[account(11, name = "system_program")]
+        MigrateBalances = 84,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "metadata_schema_account")]
+        #[account(6, name = "system_program")]
+        CreateMetadataSchema { fields: Vec<u8> } = 85,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "metadata_schema_account")]
+        #[account(6, name = "system_program")]
+        UpdateMetadataSchema { fields: Vec<u8> } = 86,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "metadata_schema_account")]
+        #[account(5, writable, name = "rent_recipient")]
+        CloseMetadataSchema = 87,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_authority")]
+        #[account(4, writable, signer, name = "payer")]
+        #[account(5, writable, name = "mint_account")]
+        #[account(6, writable, name = "metadata_freeze_account")]
+        #[account(7, name = "token_program")]
+        #[account(8, name = "system_program")]
+        FreezeMetadata = 88,
+
+        // No verification overhead
+        // Instruction accounts
+        #[account(0, writable, signer, name = "issuer")]
+        #[account(1, writable, name = "config_template_account")]
+        #[account(2, name = "system_program")]
+        CreateConfigTemplate {
+            template_id: u64,
+            program_addresses: Vec<Pubkey>,
+        } = 89,
+
+        // No verification overhead
+        // Instruction accounts
+        #[account(0, writable, signer, name = "issuer")]
+        #[account(1, writable, name = "config_template_account")]
+        #[account(2, name = "system_program")]
+        UpdateConfigTemplate { program_addresses: Vec<Pubkey> } = 90,
+
+        // No verification overhead
+        // Instruction accounts
+        #[account(0, signer, name = "issuer")]
+        #[account(1, writable, name = "config_template_account")]
+        #[account(2, writable, name = "rent_recipient")]
+        CloseConfigTemplate = 91,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "verification_config")]
+        #[account(6, optional, name = "config_template_account")]
+        #[account(7, name = "system_program")]
+        SetConfigTemplate { instruction_discriminator: u8 } = 92,
+
+        // Verification overhead, checked against the Transfer verification config
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts, the Transfer ones
+        #[account(3, name = "permanent_delegate_authority")]
+        #[account(4, name = "mint_account")]
+        #[account(5, name = "from_token_account")]
+        #[account(6, name = "to_token_account")]
+        #[account(7, name = "transfer_hook_program")]
+        #[account(8, name = "token_program")]
+        // Accounts checking the locked amount of the source restricted lots
+        #[account(9, optional, name = "holding_period_account")]
+        #[account(10, optional, name = "restricted_holding_account")]
+        // Read-only Transfer pre-check, sets the result code as return data
+        CanTransfer {
+            amount: u64,
+            memo_hash: Option<[u8; 32]>,
+        } = 93,
+
+        #[account(0, name = "mint")]
+        #[account(1, name = "token_account")]
+        #[account(2, name = "suspension_account")]
+        // Accounts reading the locked amount of the restricted lots
+        #[account(3, optional, name = "holding_period_account")]
+        #[account(4, optional, name = "restricted_holding_account")]
+        // Sets the borsh-encoded HolderStatus as return data
+        GetHolderStatus = 94,
+
+        #[account(0, name = "mint_from")]
+        #[account(1, name = "mint_to")]
+        #[account(2, name = "rate_account")]
+        #[account(3, optional, name = "oracle")]
+        // Sets the borsh-encoded EffectiveRate as return data
+        GetEffectiveRate { action_id: u64 } = 95,
+
+        #[account(0, name = "mint")]
+        #[account(1, name = "claim_deadline_account")]
+        #[account(2, name = "distribution_root_account")]
+        #[account(3, optional, name = "escrow_token_account")]
+        // Sets the borsh-encoded DistributionStatus as return data
+        GetDistributionStatus {
+            action_id: u64,
+            merkle_root: [u8; 32],
+        } = 96,
+
+        // No verification overhead
+        // Instruction accounts
+        #[account(0, writable, signer, name = "payer")]
+        #[account(1, name = "mint")]
+        #[account(2, name = "token_account")]
+        #[account(3, name = "suspension_account")]
+        #[account(4, writable, name = "collateral_attestation_account")]
+        #[account(5, name = "system_program")]
+        // Accounts reading the locked amount of the restricted lots
+        #[account(6, optional, name = "holding_period_account")]
+        #[account(7, optional, name = "restricted_holding_account")]
+        // Writes the CollateralAttestation and sets its account data as return data
+        AttestCollateral = 97,
+
+        // No verification overhead
+        // Instruction accounts
+        #[account(0, writable, signer, name = "custodian")]
+        #[account(1, name = "mint")]
+        #[account(2, writable, name = "reserve_attestation_account")]
+        #[account(3, name = "system_program")]
+        // Omnibus token accounts of the custodian follow as trailing accounts
+        PublishReserveAttestation { liability_hash: [u8; 32] } = 98,
+
+        // No verification overhead, signed by the owner of the omnibus token account
+        // Instruction accounts
+        #[account(0, writable, signer, name = "custodian")]
+        #[account(1, name = "mint")]
+        #[account(2, name = "token_account")]
+        #[account(3, writable, name = "sub_account_ledger_account")]
+        #[account(4, name = "system_program")]
+        CreateSubAccountLedger = 99,
+
+        // No verification overhead, signed by the owner of the omnibus token account
+        // Instruction accounts
+        #[account(0, writable, signer, name = "custodian")]
+        #[account(1, name = "mint")]
+        #[account(2, name = "token_account")]
+        #[account(3, writable, name = "sub_account_ledger_account")]
+        #[account(4, writable, name = "sub_account")]
+        #[account(5, name = "system_program")]
+        CreditSubAccount {
+            beneficial_owner: [u8; 32],
+            amount: u64,
+        } = 100,
+
+        // No verification overhead, signed by the owner of the omnibus token account
+        // Instruction accounts
+        #[account(0, writable, signer, name = "custodian")]
+        #[account(1, name = "mint")]
+        #[account(2, name = "token_account")]
+        #[account(3, writable, name = "sub_account_ledger_account")]
+        #[account(4, writable, name = "sub_account")]
+        DebitSubAccount {
+            beneficial_owner: [u8; 32],
+            amount: u64,
+        } = 101,
+
+        // No verification overhead, signed by the owner of the omnibus token account
+        // Instruction accounts
+        #[account(0, writable, signer, name = "custodian")]
+        #[account(1, name = "mint")]
+        #[account(2, name = "token_account")]
+        #[account(3, writable, name = "sub_account_ledger_account")]
+        CloseSubAccountLedger = 102,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "succession_policy_account")]
+        #[account(6, name = "system_program")]
+        CreateSuccessionPolicy {
+            successor: Pubkey,
+            heartbeat_period: i64,
+        } = 103,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "succession_policy_account")]
+        Heartbeat = 104,
+
+        // No verification overhead, signed by the successor of the policy
+        // Instruction accounts
+        #[account(0, signer, name = "successor")]
+        #[account(1, name = "mint")]
+        #[account(2, writable, name = "succession_policy_account")]
+        ClaimSuccession = 105,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "succession_policy_account")]
+        #[account(5, writable, name = "rent_recipient")]
+        CloseSuccessionPolicy = 106,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "metadata_translator_account")]
+        #[account(6, name = "translator")]
+        #[account(7, name = "system_program")]
+        CreateMetadataTranslator = 107,
+
+        // No verification overhead, signed by the translator
+        // Instruction accounts
+        #[account(0, signer, name = "translator")]
+        #[account(1, writable, signer, name = "payer")]
+        #[account(2, writable, name = "mint")]
+        #[account(3, name = "mint_authority")]
+        #[account(4, name = "metadata_translator_account")]
+        #[account(5, name = "token_program")]
+        #[account(6, name = "system_program")]
+        UpdateTranslation { key: String, value: String } = 108,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "metadata_translator_account")]
+        #[account(5, writable, name = "rent_recipient")]
+        CloseMetadataTranslator = 109,
+
+        #[account(0, name = "mint")]
+        // Followed by the sampled token accounts of the mint and the Vesting, PendingTransfer and
+        // SubAccountLedger accounts of the mint; logs an AuditEvent
+        AuditMint = 110,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, name = "transfer_fee_authority")]
+        #[account(4, writable, name = "mint_account")]
+        #[account(5, name = "token_program")]
+        SetTransferFee {
+            transfer_fee_basis_points: u16,
+            maximum_fee: u64,
+        } = 111,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, name = "transfer_fee_authority")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "destination_account")]
+        #[account(6, name = "token_program")]
+        // Followed by the writable token accounts to withdraw the withheld fees from
+        WithdrawWithheldTokensFromAccounts = 112,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, writable, name = "mint_account")]
+        #[account(4, name = "token_program")]
+        // Followed by the writable token accounts to harvest the withheld fees from
+        HarvestWithheldTokensToMint = 113,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config")]
+        #[account(2, name = "instructions_sysvar")]
+        // Instruction accounts
+        #[account(3, name = "freeze_authority")]
+        #[account(4, writable, name = "mint_account")]
+        #[account(5, name = "token_program")]
+        UpdateDefaultAccountState { state: u8 } = 114,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_authority")]
+        #[account(4, writable, name = "mint_account")]
+        #[account(5, name = "token_program")]
+        // multiplier: f64 little-endian bytes
+        UpdateScaledUiAmountMultiplier {
+            multiplier: [u8; 8],
+            effective_timestamp: i64,
+        } = 115,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_authority")]
+        #[account(4, writable, name = "mint_account")]
+        #[account(5, name = "token_program")]
+        #[account(6, optional, name = "metadata_schema_account")]
+        #[account(7, writable, optional, name = "external_metadata_account")]
+        #[account(8, optional, name = "metadata_program")]
+        RemoveMetadataField { key: String } = 116,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "document_registry_account")]
+        #[account(6, name = "system_program")]
+        AnchorDocument {
+            name: String,
+            uri: String,
+            sha256: [u8; 32],
+        } = 117,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "document_registry_account")]
+        #[account(6, name = "system_program")]
+        UpdateDocument {
+            name: String,
+            uri: String,
+            sha256: [u8; 32],
+        } = 118,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "document_registry_account")]
+        #[account(6, name = "system_program")]
+        RemoveDocument { name: String } = 119,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "mint_authority_account")]
+        #[account(6, name = "system_program")]
+        ProposeMintCreatorTransfer { new_creator: Pubkey } = 120,
+
+        // No verification overhead, signed by the proposed creator
+        // Instruction accounts
+        #[account(0, writable, signer, name = "new_creator")]
+        #[account(1, name = "mint")]
+        #[account(2, writable, name = "mint_authority_account")]
+        AcceptMintCreatorTransfer = 121,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "mint_authority_account")]
+        #[account(6, name = "system_program")]
+        SetMintAuthorityMultisig { threshold: u8, signers: Vec<Pubkey> } = 122,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, writable, name = "mint_account")]
+        #[account(5, name = "permanent_delegate")]
+        #[account(6, writable, name = "permanent_delegate_config_account")]
+        #[account(7, name = "transfer_verification_config")]
+        #[account(8, name = "token_program")]
+        #[account(9, name = "system_program")]
+        // Accounts required by accounts meta management
+        #[account(10, writable, name = "account_metas_pda")]
+        #[account(11, name = "transfer_hook_pda")]
+        #[account(12, name = "transfer_hook_program")]
+        RotatePermanentDelegate { new_delegate: Pubkey } = 123,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, writable, name = "mint_account")]
+        #[account(5, name = "permanent_delegate")]
+        #[account(6, writable, name = "permanent_delegate_config_account")]
+        #[account(7, name = "transfer_verification_config")]
+        #[account(8, name = "token_program")]
+        #[account(9, name = "system_program")]
+        // Accounts required by accounts meta management
+        #[account(10, writable, name = "account_metas_pda")]
+        #[account(11, name = "transfer_hook_pda")]
+        #[account(12, name = "transfer_hook_program")]
+        DisablePermanentDelegate = 124,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "pause_delegate_account")]
+        #[account(6, name = "system_program")]
+        SetPauseDelegate { delegate: Pubkey } = 125,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "mint_authority_account")]
+        #[account(5, writable, name = "rent_recipient")]
+        CloseMintAuthority = 126,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "config_admin_account")]
+        #[account(6, name = "system_program")]
+        SetConfigAdmin { config_admin: Pubkey } = 127,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "config_account")]
+        #[account(6, writable, name = "pending_config_account")]
+        #[account(7, writable, name = "rent_recipient")]
+        #[account(8, name = "system_program")]
+        // Optional accounts, required by accounts meta management
+        #[account(9, writable, optional, name = "account_metas_pda")]
+        #[account(10, optional, name = "transfer_hook_pda")]
+        #[account(11, optional, name = "transfer_hook_program")]
+        ApplyPendingConfig { instruction_discriminator: u8 } = 128,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "pending_config_account")]
+        #[account(5, writable, name = "rent_recipient")]
+        CancelPendingConfig { instruction_discriminator: u8 } = 129,
+
+        // No verification overhead
+        // Instruction accounts
+        #[account(0, writable, signer, name = "payer")]
+        #[account(1, signer, name = "upgrade_authority")]
+        #[account(2, name = "program_data")]
+        #[account(3, writable, name = "program_config_account")]
+        #[account(4, name = "system_program")]
+        SetEmergencyAuthority { emergency_authority: Pubkey } = 130,
+
+        // No verification overhead
+        // Instruction accounts
+        #[account(0, signer, name = "emergency_authority")]
+        #[account(1, writable, name = "program_config_account")]
+        SetProgramHalt { halted: bool } = 131,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, writable, name = "allowlist_entry_account")]
+        #[account(6, name = "system_program")]
+        AddToAllowlist { wallet: Pubkey } = 132,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, name = "mint_account")]
+        #[account(4, writable, name = "allowlist_entry_account")]
+        #[account(5, writable, name = "rent_recipient")]
+        RemoveFromAllowlist { wallet: Pubkey } = 133,
+
+        // Verification overhead
+        #[account(0, name = "mint")]
+        #[account(1, name = "verification_config_or_mint_authority")]
+        #[account(2, name = "instructions_sysvar_or_creator")]
+        // Instruction accounts
+        #[account(3, writable, signer, name = "payer")]
+        #[account(4, name = "mint_account")]
+        #[account(5, name = "transfer_verification_config")]
+        #[account(6, writable, name = "rent_recipient")]
+        #[account(7, name = "system_program")]
+        // Accounts required by accounts meta management
+        #[account(8, writable, name = "account_metas_pda")]
+        #[account(9, name = "transfer_hook_pda")]
+        #[account(10, name = "transfer_hook_program")]
+        SetAllowlistMode { enabled: bool } = 134,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use security_token_core::SecurityTokenInstructionDiscriminators;
+
+    #[test]
+    fn test_instructions_match_discriminator_registry() {
+        for discriminator in SecurityTokenInstructionDiscriminators::ALL {
+            let instruction = SecurityTokenInstruction::try_from(discriminator as u8).unwrap();
+            assert_eq!(instruction.discriminant(), discriminator as u8);
+        }
+        let len = SecurityTokenInstructionDiscriminators::ALL.len() as u8;
+        assert!(SecurityTokenInstruction::try_from(len).is_err());
     }
 }
@@ -0,0 +1,136 @@
+use pinocchio::program_error::ProgramError;
+use shank::ShankType;
+
+use crate::state::DayCountConvention;
+
+/// Arguments to create a coupon Accrual Config
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, ShankType)]
+pub struct CreateAccrualConfigArgs {
+    /// Annual coupon rate in basis points
+    pub rate_bps: u16,
+    /// Day-count convention (0 = Actual/365, 1 = Actual/360)
+    pub day_count: u8,
+    /// Length of a coupon period in seconds
+    pub period_seconds: u32,
+    /// Unix timestamp the first coupon period starts at
+    pub start_timestamp: i64,
+    /// Principal in payout mint base units per whole debt token
+    pub principal_per_token: u64,
+}
+
+impl CreateAccrualConfigArgs {
+    /// rate_bps + day_count + period_seconds + start_timestamp + principal_per_token
+    pub const LEN: usize = 2 + 1 + 4 + 8 + 8;
+
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let rate_bps = u16::from_le_bytes([data[0], data[1]]);
+        let day_count = data[2];
+        DayCountConvention::try_from(day_count).map_err(|_| ProgramError::InvalidArgument)?;
+        let period_seconds = u32::from_le_bytes(
+            data[3..7]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+        let start_timestamp = i64::from_le_bytes(
+            data[7..15]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+        let principal_per_token = u64::from_le_bytes(
+            data[15..23]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        if rate_bps == 0 || period_seconds == 0 || principal_per_token == 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            rate_bps,
+            day_count,
+            period_seconds,
+            start_timestamp,
+            principal_per_token,
+        })
+    }
+
+    pub fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN);
+        data.extend_from_slice(&self.rate_bps.to_le_bytes());
+        data.push(self.day_count);
+        data.extend_from_slice(&self.period_seconds.to_le_bytes());
+        data.extend_from_slice(&self.start_timestamp.to_le_bytes());
+        data.extend_from_slice(&self.principal_per_token.to_le_bytes());
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(500u16, 0u8, 2_592_000u32, 1_700_000_000i64, 1_000_000u64)]
+    #[case(1u16, 1u8, 1u32, 0i64, 1u64)]
+    #[case(u16::MAX, 1u8, u32::MAX, i64::MAX, u64::MAX)]
+    fn test_create_accrual_config_args_to_bytes(
+        #[case] rate_bps: u16,
+        #[case] day_count: u8,
+        #[case] period_seconds: u32,
+        #[case] start_timestamp: i64,
+        #[case] principal_per_token: u64,
+    ) {
+        let original = CreateAccrualConfigArgs {
+            rate_bps,
+            day_count,
+            period_seconds,
+            start_timestamp,
+            principal_per_token,
+        };
+
+        let bytes = original.to_bytes_inner();
+        let deserialized = CreateAccrualConfigArgs::try_from_bytes(&bytes)
+            .expect("Should deserialize CreateAccrualConfigArgs");
+
+        assert_eq!(original, deserialized);
+    }
+
+    #[rstest]
+    #[case(0u16, 0u8, 2_592_000u32, 1_000_000u64, "Zero rate should be invalid")]
+    #[case(
+        500u16,
+        2u8,
+        2_592_000u32,
+        1_000_000u64,
+        "Day count (2u8) should be invalid"
+    )]
+    #[case(500u16, 0u8, 0u32, 1_000_000u64, "Zero period should be invalid")]
+    #[case(500u16, 0u8, 2_592_000u32, 0u64, "Zero principal should be invalid")]
+    fn test_create_accrual_config_args_validation(
+        #[case] rate_bps: u16,
+        #[case] day_count: u8,
+        #[case] period_seconds: u32,
+        #[case] principal_per_token: u64,
+        #[case] description: &str,
+    ) {
+        let original = CreateAccrualConfigArgs {
+            rate_bps,
+            day_count,
+            period_seconds,
+            start_timestamp: 0,
+            principal_per_token,
+        };
+
+        assert!(
+            CreateAccrualConfigArgs::try_from_bytes(&original.to_bytes_inner()).is_err(),
+            "{}",
+            description
+        );
+    }
+}
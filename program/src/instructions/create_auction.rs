@@ -0,0 +1,140 @@
+use pinocchio::program_error::ProgramError;
+use shank::ShankType;
+
+/// Arguments to create a Dutch auction
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, ShankType)]
+pub struct CreateAuctionArgs {
+    /// Issuer chosen identifier distinguishing auctions of the same mint
+    pub auction_id: u64,
+    /// Price in payment mint base units per whole token when bidding opens
+    pub start_price: u64,
+    /// Price in payment mint base units per whole token when bidding closes
+    pub floor_price: u64,
+    /// Unix timestamp bidding opens at
+    pub start_timestamp: i64,
+    /// Unix timestamp bidding closes at
+    pub end_timestamp: i64,
+    /// Security token base units for sale
+    pub supply: u64,
+}
+
+impl CreateAuctionArgs {
+    /// auction_id + start_price + floor_price + start_timestamp + end_timestamp + supply
+    pub const LEN: usize = 8 * 6;
+
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let read_u64 = |offset: usize| -> Result<u64, ProgramError> {
+            Ok(u64::from_le_bytes(
+                data[offset..offset + 8]
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidInstructionData)?,
+            ))
+        };
+        let auction_id = read_u64(0)?;
+        let start_price = read_u64(8)?;
+        let floor_price = read_u64(16)?;
+        let start_timestamp = read_u64(24)? as i64;
+        let end_timestamp = read_u64(32)? as i64;
+        let supply = read_u64(40)?;
+
+        if floor_price == 0
+            || start_price < floor_price
+            || end_timestamp <= start_timestamp
+            || supply == 0
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            auction_id,
+            start_price,
+            floor_price,
+            start_timestamp,
+            end_timestamp,
+            supply,
+        })
+    }
+
+    pub fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN);
+        data.extend_from_slice(&self.auction_id.to_le_bytes());
+        data.extend_from_slice(&self.start_price.to_le_bytes());
+        data.extend_from_slice(&self.floor_price.to_le_bytes());
+        data.extend_from_slice(&self.start_timestamp.to_le_bytes());
+        data.extend_from_slice(&self.end_timestamp.to_le_bytes());
+        data.extend_from_slice(&self.supply.to_le_bytes());
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1u64, 10_000_000u64, 5_000_000u64, 1_000i64, 2_000i64, 100u64)]
+    #[case(0u64, 1u64, 1u64, -1i64, 0i64, 1u64)]
+    #[case(u64::MAX, u64::MAX, 1u64, i64::MIN, i64::MAX, u64::MAX)]
+    fn test_create_auction_args_to_bytes(
+        #[case] auction_id: u64,
+        #[case] start_price: u64,
+        #[case] floor_price: u64,
+        #[case] start_timestamp: i64,
+        #[case] end_timestamp: i64,
+        #[case] supply: u64,
+    ) {
+        let original = CreateAuctionArgs {
+            auction_id,
+            start_price,
+            floor_price,
+            start_timestamp,
+            end_timestamp,
+            supply,
+        };
+
+        let bytes = original.to_bytes_inner();
+        let deserialized = CreateAuctionArgs::try_from_bytes(&bytes)
+            .expect("Should deserialize CreateAuctionArgs");
+
+        assert_eq!(original, deserialized);
+    }
+
+    #[rstest]
+    #[case(10u64, 0u64, 2_000i64, 1u64, "Zero floor price should be invalid")]
+    #[case(
+        4u64,
+        5u64,
+        2_000i64,
+        1u64,
+        "Start price below floor price should be invalid"
+    )]
+    #[case(10u64, 5u64, 1_000i64, 1u64, "Empty bidding window should be invalid")]
+    #[case(10u64, 5u64, 2_000i64, 0u64, "Zero supply should be invalid")]
+    fn test_create_auction_args_validation(
+        #[case] start_price: u64,
+        #[case] floor_price: u64,
+        #[case] end_timestamp: i64,
+        #[case] supply: u64,
+        #[case] description: &str,
+    ) {
+        let original = CreateAuctionArgs {
+            auction_id: 1,
+            start_price,
+            floor_price,
+            start_timestamp: 1_000,
+            end_timestamp,
+            supply,
+        };
+
+        assert!(
+            CreateAuctionArgs::try_from_bytes(&original.to_bytes_inner()).is_err(),
+            "{}",
+            description
+        );
+    }
+}
@@ -16,11 +16,13 @@ pub struct CreateDistributionEscrowArgs {
     /// Merkle tree root
     #[idl_type("[u8; 32]")]
     pub merkle_root: MerkleTreeRoot,
+    /// Number of merkle leaves tracked in ClaimBitmap pages, 0 issues a Receipt per claim
+    pub claim_bitmap_leaves: u32,
 }
 
 impl CreateDistributionEscrowArgs {
-    /// action_id + merkle_root
-    pub const LEN: usize = ACTION_ID_LEN + MERKLE_ROOT_LEN;
+    /// action_id + merkle_root + claim_bitmap_leaves
+    pub const LEN: usize = ACTION_ID_LEN + MERKLE_ROOT_LEN + 4;
 
     pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
         if data.len() != Self::LEN {
@@ -36,9 +38,16 @@ impl CreateDistributionEscrowArgs {
             return Err(ProgramError::InvalidArgument);
         }
 
+        let claim_bitmap_leaves = u32::from_le_bytes(
+            data[ACTION_ID_LEN + MERKLE_ROOT_LEN..Self::LEN]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
         Ok(Self {
             action_id,
             merkle_root,
+            claim_bitmap_leaves,
         })
     }
 
@@ -46,6 +55,7 @@ impl CreateDistributionEscrowArgs {
         let mut data = Vec::with_capacity(Self::LEN);
         data.extend_from_slice(self.action_id.to_le_bytes().as_ref());
         data.extend_from_slice(self.merkle_root.as_ref());
+        data.extend_from_slice(self.claim_bitmap_leaves.to_le_bytes().as_ref());
         data
     }
 }
@@ -57,16 +67,18 @@ mod tests {
     use rstest::rstest;
 
     #[rstest]
-    #[case(42u64, random_32_bytes())]
-    #[case(1u64, random_32_bytes())]
-    #[case(u64::MAX, random_32_bytes())]
+    #[case(42u64, random_32_bytes(), 0)]
+    #[case(1u64, random_32_bytes(), 100_000)]
+    #[case(u64::MAX, random_32_bytes(), u32::MAX)]
     fn test_create_distribution_escrow_args_to_bytes(
         #[case] action_id: u64,
         #[case] merkle_root: MerkleTreeRoot,
+        #[case] claim_bitmap_leaves: u32,
     ) {
         let original = CreateDistributionEscrowArgs {
             action_id,
             merkle_root,
+            claim_bitmap_leaves,
         };
 
         let bytes = original.to_bytes_inner();
@@ -75,6 +87,10 @@ mod tests {
 
         assert_eq!(original.action_id, deserialized.action_id);
         assert_eq!(original.merkle_root, deserialized.merkle_root);
+        assert_eq!(
+            original.claim_bitmap_leaves,
+            deserialized.claim_bitmap_leaves
+        );
     }
 
     #[rstest]
@@ -88,6 +104,7 @@ mod tests {
         let original = CreateDistributionEscrowArgs {
             action_id,
             merkle_root,
+            claim_bitmap_leaves: 0,
         };
 
         assert!(
@@ -0,0 +1,131 @@
+use pinocchio::program_error::ProgramError;
+use shank::ShankType;
+
+use crate::state::MAX_ORACLE_VALUE_DECIMALS;
+
+/// Arguments to create a NAV Oracle
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, ShankType)]
+pub struct CreateNavOracleArgs {
+    /// Byte offset of the u64 (LE) value in the oracle account data
+    pub value_offset: u32,
+    /// Fixed-point decimals of the oracle value
+    pub value_decimals: u8,
+    /// Lowest accepted oracle value
+    pub min_value: u64,
+    /// Highest accepted oracle value
+    pub max_value: u64,
+    /// Minimum number of seconds between two multiplier updates
+    pub min_update_interval: u32,
+}
+
+impl CreateNavOracleArgs {
+    /// value_offset + value_decimals + min_value + max_value + min_update_interval
+    pub const LEN: usize = 4 + 1 + 8 + 8 + 4;
+
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let value_offset = u32::from_le_bytes(
+            data[0..4]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+        let value_decimals = data[4];
+        let min_value = u64::from_le_bytes(
+            data[5..13]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+        let max_value = u64::from_le_bytes(
+            data[13..21]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+        let min_update_interval = u32::from_le_bytes(
+            data[21..25]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        if min_value == 0 || min_value > max_value || value_decimals > MAX_ORACLE_VALUE_DECIMALS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            value_offset,
+            value_decimals,
+            min_value,
+            max_value,
+            min_update_interval,
+        })
+    }
+
+    pub fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN);
+        data.extend_from_slice(&self.value_offset.to_le_bytes());
+        data.push(self.value_decimals);
+        data.extend_from_slice(&self.min_value.to_le_bytes());
+        data.extend_from_slice(&self.max_value.to_le_bytes());
+        data.extend_from_slice(&self.min_update_interval.to_le_bytes());
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(8u32, 6u8, 500_000u64, 2_000_000u64, 3_600u32)]
+    #[case(0u32, 0u8, 1u64, 1u64, 0u32)]
+    #[case(u32::MAX, 18u8, 1u64, u64::MAX, u32::MAX)]
+    fn test_create_nav_oracle_args_to_bytes(
+        #[case] value_offset: u32,
+        #[case] value_decimals: u8,
+        #[case] min_value: u64,
+        #[case] max_value: u64,
+        #[case] min_update_interval: u32,
+    ) {
+        let original = CreateNavOracleArgs {
+            value_offset,
+            value_decimals,
+            min_value,
+            max_value,
+            min_update_interval,
+        };
+
+        let bytes = original.to_bytes_inner();
+        let deserialized = CreateNavOracleArgs::try_from_bytes(&bytes)
+            .expect("Should deserialize CreateNavOracleArgs");
+
+        assert_eq!(original, deserialized);
+    }
+
+    #[rstest]
+    #[case(6u8, 0u64, 1u64, "Zero min value should be invalid")]
+    #[case(6u8, 2u64, 1u64, "Min value above max value should be invalid")]
+    #[case(19u8, 1u64, 1u64, "Value decimals (19u8) should be invalid")]
+    fn test_create_nav_oracle_args_validation(
+        #[case] value_decimals: u8,
+        #[case] min_value: u64,
+        #[case] max_value: u64,
+        #[case] description: &str,
+    ) {
+        let original = CreateNavOracleArgs {
+            value_offset: 0,
+            value_decimals,
+            min_value,
+            max_value,
+            min_update_interval: 0,
+        };
+
+        assert!(
+            CreateNavOracleArgs::try_from_bytes(&original.to_bytes_inner()).is_err(),
+            "{}",
+            description
+        );
+    }
+}
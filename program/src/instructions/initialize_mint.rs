@@ -186,6 +186,75 @@ impl ScaledUiAmountConfigArgs {
     }
 }
 
+#[repr(C)]
+#[derive(Clone, Debug, ShankType)]
+pub struct TransferFeeConfigArgs {
+    /// Amount of transfer collected as fees, expressed as basis points of the transfer amount
+    pub transfer_fee_basis_points: u16,
+    /// Maximum fee assessed on transfers
+    pub maximum_fee: u64,
+}
+
+impl TransferFeeConfigArgs {
+    /// Fixed size: transfer_fee_basis_points (2) + maximum_fee (8) = 10 bytes
+    pub const LEN: usize = 2 + 8;
+
+    /// Deserialize TransferFeeConfigArgs from bytes
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let transfer_fee_basis_points = u16::from_le_bytes(
+            <[u8; 2]>::try_from(&data[..2]).map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+        let maximum_fee = u64::from_le_bytes(
+            <[u8; 8]>::try_from(&data[2..Self::LEN])
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        Ok(Self {
+            transfer_fee_basis_points,
+            maximum_fee,
+        })
+    }
+
+    /// Serialize TransferFeeConfigArgs to bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::LEN);
+        buf.extend_from_slice(&self.transfer_fee_basis_points.to_le_bytes());
+        buf.extend_from_slice(&self.maximum_fee.to_le_bytes());
+        buf
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Debug, ShankType)]
+pub struct DefaultAccountStateArgs {
+    /// State new token accounts start in (1 = Initialized, 2 = Frozen)
+    pub state: u8,
+}
+
+impl DefaultAccountStateArgs {
+    /// Fixed size: state (1) = 1 byte
+    pub const LEN: usize = 1;
+    /// Token account state `Initialized`
+    pub const INITIALIZED: u8 = 1;
+    /// Token account state `Frozen`
+    pub const FROZEN: u8 = 2;
+
+    /// Deserialize DefaultAccountStateArgs from bytes
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        let state = *data.first().ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(Self { state })
+    }
+
+    /// Serialize DefaultAccountStateArgs to bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        vec![self.state]
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Debug, ShankType)]
 pub struct MetadataPointerArgs {
@@ -250,6 +319,10 @@ pub struct InitializeMintArgs {
     pub ix_metadata: Option<TokenMetadataArgs>, // pinocchio_token_2022::extensions::metadata::TokenMetadata
     /// Optional scaled UI amount configuration
     pub ix_scaled_ui_amount: Option<ScaledUiAmountConfigArgs>, //  pinocchio_token_2022::extensions::scaled_ui_amount::ScaledUiAmountConfig
+    /// Optional transfer fee configuration, both fee authorities are the transfer fee authority PDA
+    pub ix_transfer_fee: Option<TransferFeeConfigArgs>,
+    /// Optional default state of new token accounts, frozen accounts need a thaw before use
+    pub ix_default_account_state: Option<DefaultAccountStateArgs>,
 }
 
 impl MintArgs {
@@ -310,12 +383,15 @@ impl std::fmt::Debug for InitializeMintArgs {
             .field("ix_metadata_pointer", &self.ix_metadata_pointer)
             .field("ix_metadata", &self.ix_metadata)
             .field("ix_scaled_ui_amount", &self.ix_scaled_ui_amount)
+            .field("ix_transfer_fee", &self.ix_transfer_fee)
+            .field("ix_default_account_state", &self.ix_default_account_state)
             .finish()
     }
 }
 
 impl InitializeMintArgs {
     /// Create new InitializeArgs with optional metadata pointer and metadata
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         decimals: u8,
         mint_authority: Pubkey,
@@ -323,6 +399,8 @@ impl InitializeMintArgs {
         metadata_pointer: Option<MetadataPointerArgs>,
         metadata: Option<TokenMetadataArgs>,
         scaled_ui_amount: Option<ScaledUiAmountConfigArgs>,
+        transfer_fee: Option<TransferFeeConfigArgs>,
+        default_account_state: Option<DefaultAccountStateArgs>,
     ) -> Self {
         Self {
             ix_mint: MintArgs {
@@ -333,6 +411,8 @@ impl InitializeMintArgs {
             ix_metadata_pointer: metadata_pointer,
             ix_metadata: metadata,
             ix_scaled_ui_amount: scaled_ui_amount,
+            ix_transfer_fee: transfer_fee,
+            ix_default_account_state: default_account_state,
         }
     }
 
@@ -367,6 +447,22 @@ impl InitializeMintArgs {
             buf.push(0); // no scaled UI amount
         }
 
+        // Pack transfer fee presence flag and data if present
+        if let Some(transfer_fee) = &self.ix_transfer_fee {
+            buf.push(1); // has transfer fee
+            buf.extend_from_slice(&transfer_fee.to_bytes());
+        } else {
+            buf.push(0); // no transfer fee
+        }
+
+        // Pack default account state presence flag and data if present
+        if let Some(default_account_state) = &self.ix_default_account_state {
+            buf.push(1); // has default account state
+            buf.extend_from_slice(&default_account_state.to_bytes());
+        } else {
+            buf.push(0); // no default account state
+        }
+
         buf
     }
 
@@ -384,6 +480,8 @@ impl InitializeMintArgs {
                 ix_metadata_pointer: None,
                 ix_metadata: None,
                 ix_scaled_ui_amount: None,
+                ix_transfer_fee: None,
+                ix_default_account_state: None,
             });
         }
         // Check metadata pointer flag
@@ -405,6 +503,8 @@ impl InitializeMintArgs {
                 ix_metadata_pointer,
                 ix_metadata: None,
                 ix_scaled_ui_amount: None,
+                ix_transfer_fee: None,
+                ix_default_account_state: None,
             });
         }
 
@@ -420,33 +520,47 @@ impl InitializeMintArgs {
             None
         };
 
-        // Check scaled UI amount flag
-        let has_scaled_ui_amount = if data.len() > offset { data[offset] } else { 0 };
-
-        if has_scaled_ui_amount == 0 || data.len() <= offset + 1 {
-            // No scaled UI amount or not enough data
-            return Ok(Self {
-                ix_mint,
-                ix_metadata_pointer,
-                ix_metadata,
-                ix_scaled_ui_amount: None,
-            });
-        }
-
+        // Check scaled UI amount flag, older clients end the data before it
+        let has_scaled_ui_amount = data.get(offset).copied().unwrap_or(0);
         offset += 1;
 
         let ix_scaled_ui_amount = if has_scaled_ui_amount == 1 {
             let scaled_ui_amount = ScaledUiAmountConfigArgs::try_from_bytes(&data[offset..])?;
+            offset += ScaledUiAmountConfigArgs::LEN;
             Some(scaled_ui_amount)
         } else {
             None
         };
 
+        // Check transfer fee flag, older clients end the data before it
+        let has_transfer_fee = data.get(offset).copied().unwrap_or(0);
+        offset += 1;
+
+        let ix_transfer_fee = if has_transfer_fee == 1 {
+            let transfer_fee = TransferFeeConfigArgs::try_from_bytes(&data[offset..])?;
+            offset += TransferFeeConfigArgs::LEN;
+            Some(transfer_fee)
+        } else {
+            None
+        };
+
+        // Check default account state flag, older clients end the data before it
+        let has_default_account_state = data.get(offset).copied().unwrap_or(0);
+        offset += 1;
+
+        let ix_default_account_state = if has_default_account_state == 1 {
+            Some(DefaultAccountStateArgs::try_from_bytes(&data[offset..])?)
+        } else {
+            None
+        };
+
         Ok(Self {
             ix_mint,
             ix_metadata_pointer,
             ix_metadata,
             ix_scaled_ui_amount,
+            ix_transfer_fee,
+            ix_default_account_state,
         })
     }
 
@@ -455,6 +569,15 @@ impl InitializeMintArgs {
         if self.ix_metadata.is_some() && self.ix_metadata_pointer.is_none() {
             return Err(ProgramError::InvalidArgument);
         }
+        // New token accounts can only start initialized or frozen
+        if let Some(default_account_state) = &self.ix_default_account_state {
+            if !matches!(
+                default_account_state.state,
+                DefaultAccountStateArgs::INITIALIZED | DefaultAccountStateArgs::FROZEN
+            ) {
+                return Err(ProgramError::InvalidArgument);
+            }
+        }
         Ok(())
     }
 }
@@ -522,6 +645,13 @@ mod tests {
             Some(metadata_pointer.clone()),
             Some(metadata.clone()),
             Some(scaled_ui_amount.clone()),
+            Some(TransferFeeConfigArgs {
+                transfer_fee_basis_points: 25,
+                maximum_fee: 1_000_000,
+            }),
+            Some(DefaultAccountStateArgs {
+                state: DefaultAccountStateArgs::FROZEN,
+            }),
         );
 
         let inner_bytes = original.to_bytes_inner();
@@ -568,6 +698,18 @@ mod tests {
             scaled_ui_amount.multiplier,
             deserialized_scaled_ui_amount.multiplier
         );
+
+        // Verify TransferFee
+        let deserialized_transfer_fee = deserialized.ix_transfer_fee.unwrap();
+        assert_eq!(deserialized_transfer_fee.transfer_fee_basis_points, 25);
+        assert_eq!(deserialized_transfer_fee.maximum_fee, 1_000_000);
+
+        // Verify DefaultAccountState
+        let deserialized_default_account_state = deserialized.ix_default_account_state.unwrap();
+        assert_eq!(
+            deserialized_default_account_state.state,
+            DefaultAccountStateArgs::FROZEN
+        );
     }
 
     #[test]
@@ -583,6 +725,8 @@ mod tests {
             None, // no metadata pointer for this simpler test
             None, // no metadata for this simpler test
             None, // no scaled UI amount
+            None, // no transfer fee
+            None, // no default account state
         );
 
         let inner_bytes = original.to_bytes_inner();
@@ -601,6 +745,8 @@ mod tests {
         assert!(deserialized.ix_metadata_pointer.is_none());
         assert!(deserialized.ix_metadata.is_none());
         assert!(deserialized.ix_scaled_ui_amount.is_none());
+        assert!(deserialized.ix_transfer_fee.is_none());
+        assert!(deserialized.ix_default_account_state.is_none());
     }
 
     #[test]
@@ -626,6 +772,8 @@ mod tests {
                 additional_metadata: vec![],
             }),
             None,
+            None,
+            None,
         );
         assert!(args_valid.validate().is_ok());
 
@@ -642,7 +790,36 @@ mod tests {
                 additional_metadata: vec![],
             }),
             None,
+            None,
+            None,
         );
         assert_eq!(args_invalid.validate(), Err(ProgramError::InvalidArgument));
     }
+    #[test]
+    fn test_validate_default_account_state() {
+        let mint_authority = random_pubkey();
+        let freeze_authority = random_pubkey();
+        let with_state = |state| {
+            InitializeMintArgs::new(
+                6,
+                mint_authority,
+                freeze_authority,
+                None,
+                None,
+                None,
+                None,
+                Some(DefaultAccountStateArgs { state }),
+            )
+        };
+
+        assert!(with_state(DefaultAccountStateArgs::INITIALIZED)
+            .validate()
+            .is_ok());
+        assert!(with_state(DefaultAccountStateArgs::FROZEN)
+            .validate()
+            .is_ok());
+        // Uninitialized and unknown states are rejected
+        assert_eq!(with_state(0).validate(), Err(ProgramError::InvalidArgument));
+        assert_eq!(with_state(3).validate(), Err(ProgramError::InvalidArgument));
+    }
 }
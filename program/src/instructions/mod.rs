@@ -26,6 +26,14 @@ pub mod create_rate_account {
 pub mod close_rate_account {
     pub use super::rate_account::close_rate_account::*;
 }
+/// Set Rate oracle instruction arguments and implementations
+pub mod set_rate_oracle {
+    pub use super::rate_account::set_rate_oracle::*;
+}
+/// Remove Rate oracle instruction arguments and implementations
+pub mod remove_rate_oracle {
+    pub use super::rate_account::remove_rate_oracle::*;
+}
 /// Claim instruction arguments and implementations
 pub mod claim_distribution;
 /// Close Receipt account instruction arguments and implementations
@@ -35,8 +43,14 @@ pub mod close_receipt_account {
 }
 /// Convert instruction arguments and implementations
 pub mod convert;
+/// CreateAccrualConfig instruction arguments and implementations
+pub mod create_accrual_config;
+/// CreateAuction instruction arguments and implementations
+pub mod create_auction;
 /// CreateDistributionEscrow instruction arguments and implementations
 pub mod create_distribution_escrow;
+/// CreateNavOracle instruction arguments and implementations
+pub mod create_nav_oracle;
 /// Initialize mint instruction arguments and implementations
 pub mod initialize_mint;
 /// Split instruction arguments and implementations
@@ -55,10 +69,15 @@ pub use claim_distribution::*;
 pub use close_rate_account::*;
 pub use close_receipt_account::*;
 pub use convert::*;
+pub use create_accrual_config::*;
+pub use create_auction::*;
 pub use create_distribution_escrow::*;
+pub use create_nav_oracle::*;
 pub use create_proof_account::*;
 pub use create_rate_account::*;
 pub use initialize_mint::*;
+pub use remove_rate_oracle::*;
+pub use set_rate_oracle::*;
 pub use split::*;
 pub use token_wrappers::*;
 pub use update_metadata::*;
@@ -1,4 +1,6 @@
 pub mod close_rate_account;
 pub mod create_rate_account;
+pub mod remove_rate_oracle;
+pub mod set_rate_oracle;
 pub mod shared;
 pub mod update_rate_account;
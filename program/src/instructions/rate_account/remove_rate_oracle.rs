@@ -0,0 +1,20 @@
+use pinocchio::program_error::ProgramError;
+use shank::ShankType;
+
+use crate::instructions::rate_account::shared::parse_action_id_argument;
+
+/// Arguments to return a Rate account to its static ratio
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, ShankType)]
+pub struct RemoveRateOracleArgs {
+    /// Action ID of the Rate
+    pub action_id: u64,
+}
+
+impl RemoveRateOracleArgs {
+    /// Parse RemoveRateOracleArgs from bytes
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        let action_id = parse_action_id_argument(data)?;
+        Ok(Self { action_id })
+    }
+}
@@ -0,0 +1,132 @@
+use pinocchio::program_error::ProgramError;
+use shank::ShankType;
+
+use crate::constants::ACTION_ID_LEN;
+use crate::instructions::rate_account::shared::parse_action_id_argument;
+use crate::state::MAX_ORACLE_VALUE_DECIMALS;
+
+/// Arguments to price a Rate account from an oracle
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, ShankType)]
+pub struct SetRateOracleArgs {
+    /// Action ID of the Rate
+    pub action_id: u64,
+    /// Byte offset of the u64 (LE) price in the oracle account data
+    pub value_offset: u32,
+    /// Fixed-point decimals of the oracle price
+    pub value_decimals: u8,
+    /// Lowest accepted oracle price
+    pub min_value: u64,
+    /// Highest accepted oracle price
+    pub max_value: u64,
+}
+
+impl SetRateOracleArgs {
+    /// action_id + value_offset + value_decimals + min_value + max_value
+    pub const LEN: usize = ACTION_ID_LEN + 4 + 1 + 8 + 8;
+
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let action_id = parse_action_id_argument(&data[..ACTION_ID_LEN])?;
+        let data = &data[ACTION_ID_LEN..];
+        let value_offset = u32::from_le_bytes(
+            data[0..4]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+        let value_decimals = data[4];
+        let min_value = u64::from_le_bytes(
+            data[5..13]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+        let max_value = u64::from_le_bytes(
+            data[13..21]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        if min_value == 0 || min_value > max_value || value_decimals > MAX_ORACLE_VALUE_DECIMALS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            action_id,
+            value_offset,
+            value_decimals,
+            min_value,
+            max_value,
+        })
+    }
+
+    pub fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN);
+        data.extend_from_slice(&self.action_id.to_le_bytes());
+        data.extend_from_slice(&self.value_offset.to_le_bytes());
+        data.push(self.value_decimals);
+        data.extend_from_slice(&self.min_value.to_le_bytes());
+        data.extend_from_slice(&self.max_value.to_le_bytes());
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(42u64, 8u32, 6u8, 500_000u64, 2_000_000u64)]
+    #[case(1u64, 0u32, 0u8, 1u64, 1u64)]
+    #[case(u64::MAX, u32::MAX, 18u8, 1u64, u64::MAX)]
+    fn test_set_rate_oracle_args_to_bytes(
+        #[case] action_id: u64,
+        #[case] value_offset: u32,
+        #[case] value_decimals: u8,
+        #[case] min_value: u64,
+        #[case] max_value: u64,
+    ) {
+        let original = SetRateOracleArgs {
+            action_id,
+            value_offset,
+            value_decimals,
+            min_value,
+            max_value,
+        };
+
+        let bytes = original.to_bytes_inner();
+        let deserialized = SetRateOracleArgs::try_from_bytes(&bytes)
+            .expect("Should deserialize SetRateOracleArgs");
+
+        assert_eq!(original, deserialized);
+    }
+
+    #[rstest]
+    #[case(0u64, 6u8, 1u64, 1u64, "Zero action_id should be invalid")]
+    #[case(1u64, 6u8, 0u64, 1u64, "Zero min value should be invalid")]
+    #[case(1u64, 6u8, 2u64, 1u64, "Min value above max value should be invalid")]
+    #[case(1u64, 19u8, 1u64, 1u64, "Value decimals (19u8) should be invalid")]
+    fn test_set_rate_oracle_args_validation(
+        #[case] action_id: u64,
+        #[case] value_decimals: u8,
+        #[case] min_value: u64,
+        #[case] max_value: u64,
+        #[case] description: &str,
+    ) {
+        let original = SetRateOracleArgs {
+            action_id,
+            value_offset: 0,
+            value_decimals,
+            min_value,
+            max_value,
+        };
+
+        assert!(
+            SetRateOracleArgs::try_from_bytes(&original.to_bytes_inner()).is_err(),
+            "{}",
+            description
+        );
+    }
+}
@@ -4,8 +4,8 @@ use shank::ShankType;
 use crate::{
     constants::ACTION_ID_LEN,
     instructions::rate_account::shared::parse_action_id_argument,
-    merkle_tree_utils::ProofData,
-    state::{ProofDataDeserializer, ProofDataValidator},
+    merkle_tree_utils::{MerkleTreeRoot, ProofData, MERKLE_ROOT_LEN, MERKLE_TREE_NODE_LEN},
+    state::{Proof, ProofDataDeserializer, ProofDataValidator},
 };
 
 /// Arguments to closing Receipt account of claim_distribution operation
@@ -18,6 +18,10 @@ pub struct CloseClaimReceiptArgs {
     /// Provided either by argument or Proof account
     #[idl_type("Option<Vec<[u8; 32]>>")]
     pub merkle_proof: Option<ProofData>,
+    /// Supplemental merkle root the receipt is scoped to
+    /// None for claims against the original root
+    #[idl_type("Option<[u8; 32]>")]
+    pub merkle_root: Option<MerkleTreeRoot>,
 }
 
 impl ProofDataDeserializer for CloseClaimReceiptArgs {
@@ -52,14 +56,27 @@ impl CloseClaimReceiptArgs {
                 let proof_data = Self::try_proof_data_from_bytes(&data[offset..])?;
                 Self::validate_proof_data_len(&proof_data)?;
                 Self::validate_proof_data(&proof_data)?;
+                offset += Proof::VEC_LEN_PREFIX + proof_data.len() * MERKLE_TREE_NODE_LEN;
                 Some(proof_data)
             }
             _ => return Err(ProgramError::InvalidInstructionData),
         };
 
+        // Missing merkle_root keeps the encoding without it valid
+        let merkle_root = match data.get(offset) {
+            None | Some(0) => None,
+            Some(1) => Some(
+                data.get(offset + 1..offset + 1 + MERKLE_ROOT_LEN)
+                    .and_then(|slice| slice.try_into().ok())
+                    .ok_or(ProgramError::InvalidInstructionData)?,
+            ),
+            Some(_) => return Err(ProgramError::InvalidInstructionData),
+        };
+
         Ok(Self {
             action_id,
             merkle_proof,
+            merkle_root,
         })
     }
 
@@ -80,6 +97,13 @@ impl CloseClaimReceiptArgs {
                 data.extend_from_slice(node.as_ref());
             }
         }
+        match &self.merkle_root {
+            Some(merkle_root) => {
+                data.push(1);
+                data.extend_from_slice(merkle_root.as_ref());
+            }
+            None => data.push(0),
+        }
 
         data
     }
@@ -96,23 +120,42 @@ mod tests {
     use rstest::rstest;
 
     #[rstest]
-    #[case(42u64, None)]
-    #[case(1u64, Some(random_32_bytes_vec(3)))]
-    #[case(u64::MAX, Some(random_32_bytes_vec(32)))]
+    #[case(42u64, None, None)]
+    #[case(1u64, Some(random_32_bytes_vec(3)), None)]
+    #[case(u64::MAX, Some(random_32_bytes_vec(32)), None)]
+    #[case(42u64, None, Some(random_32_bytes()))]
+    #[case(7u64, Some(random_32_bytes_vec(3)), Some(random_32_bytes()))]
     fn test_close_claim_receipt_args_try_from_bytes(
         #[case] action_id: u64,
         #[case] merkle_proof: Option<ProofData>,
+        #[case] merkle_root: Option<MerkleTreeRoot>,
     ) {
         let original = CloseClaimReceiptArgs {
             action_id,
             merkle_proof,
+            merkle_root,
         };
 
         let bytes = original.to_bytes_inner();
         let deserialized = CloseClaimReceiptArgs::try_from_bytes(&bytes)
             .expect("Should deserialize CloseClaimReceiptArgs");
-        assert_eq!(original.action_id, deserialized.action_id);
-        assert_eq!(original.merkle_proof, deserialized.merkle_proof);
+        assert_eq!(original, deserialized);
+    }
+
+    #[test]
+    fn test_close_claim_receipt_args_without_merkle_root() {
+        let original = CloseClaimReceiptArgs {
+            action_id: 42,
+            merkle_proof: Some(random_32_bytes_vec(3)),
+            merkle_root: None,
+        };
+
+        let mut bytes = original.to_bytes_inner();
+        bytes.pop();
+        assert_eq!(
+            CloseClaimReceiptArgs::try_from_bytes(&bytes).unwrap(),
+            original
+        );
     }
 
     #[rstest]
@@ -132,6 +175,7 @@ mod tests {
         let original = CloseClaimReceiptArgs {
             action_id,
             merkle_proof,
+            merkle_root: None,
         };
 
         assert!(
@@ -1,7 +1,7 @@
 //! Token extension wrappers
 
 use pinocchio::account_info::AccountInfo;
-use pinocchio::cpi::invoke_signed;
+use pinocchio::cpi::slice_invoke_signed;
 use pinocchio::instruction::{AccountMeta, Instruction, Signer};
 use pinocchio::ProgramResult;
 
@@ -20,6 +20,9 @@ pub struct TransferCheckedWithHook<'a> {
     /// Mint decimals needed for checked transfer.
     pub decimals: u8,
     pub transfer_hook_program: &'a AccountInfo,
+    /// Transfer hook accounts forwarded to Token 2022: the ExtraAccountMetaList PDA and the
+    /// extra accounts it lists. Without them the hook runs without its extra accounts.
+    pub extra_accounts: &'a [AccountInfo],
 }
 
 impl<'a> TransferCheckedWithHook<'a> {
@@ -32,13 +35,19 @@ impl<'a> TransferCheckedWithHook<'a> {
     /// Invoke the TransferChecked instruction with signer seeds.
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
         // account metadata
-        let account_metas: [AccountMeta; 5] = [
+        let mut account_metas = Vec::with_capacity(5 + self.extra_accounts.len());
+        account_metas.extend([
             AccountMeta::writable(self.from.key()),
             AccountMeta::readonly(self.mint.key()),
             AccountMeta::writable(self.to.key()),
             AccountMeta::readonly_signer(self.authority.key()),
             AccountMeta::readonly(self.transfer_hook_program.key()),
-        ];
+        ]);
+        account_metas.extend(self.extra_accounts.iter().map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_writable: account.is_writable(),
+            is_signer: false,
+        }));
 
         // Instruction data layout:
         // -  [0]: instruction discriminator (1 byte, u8)
@@ -59,16 +68,16 @@ impl<'a> TransferCheckedWithHook<'a> {
             data: &instruction_data,
         };
 
-        invoke_signed(
-            &instruction,
-            &[
-                self.from,
-                self.mint,
-                self.to,
-                self.authority,
-                self.transfer_hook_program,
-            ],
-            signers,
-        )
+        let mut account_infos = Vec::with_capacity(5 + self.extra_accounts.len());
+        account_infos.extend([
+            self.from,
+            self.mint,
+            self.to,
+            self.authority,
+            self.transfer_hook_program,
+        ]);
+        account_infos.extend(self.extra_accounts);
+
+        slice_invoke_signed(&instruction, &account_infos, signers)
     }
 }
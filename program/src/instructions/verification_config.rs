@@ -15,6 +15,14 @@ pub struct InitializeVerificationConfigArgs {
     pub cpi_mode: bool,
     /// Vector of verification program addresses
     pub program_addresses: Vec<Pubkey>,
+    /// Number of programs that must pass, 0 requires all of them
+    pub threshold: u8,
+    /// Number of instructions right before the verified one the introspection looks at, 0 for all of them
+    pub lookback_window: u8,
+    /// Introspection requires the verified instruction to be a top-level instruction
+    pub require_top_level: bool,
+    /// Delay in seconds before later updates take effect, 0 applies them immediately
+    pub timelock_seconds: u64,
 }
 
 /// Arguments for UpdateVerificationConfig instruction
@@ -29,6 +37,14 @@ pub struct UpdateVerificationConfigArgs {
     pub offset: u8,
     /// Vector of new verification program addresses to add/replace
     pub program_addresses: Vec<Pubkey>,
+    /// Number of programs that must pass, 0 requires all of them
+    pub threshold: u8,
+    /// Number of instructions right before the verified one the introspection looks at, 0 for all of them
+    pub lookback_window: u8,
+    /// Introspection requires the verified instruction to be a top-level instruction
+    pub require_top_level: bool,
+    /// Delay in seconds before later updates take effect, 0 applies them immediately
+    pub timelock_seconds: u64,
 }
 
 impl InitializeVerificationConfigArgs {
@@ -40,11 +56,18 @@ impl InitializeVerificationConfigArgs {
         instruction_discriminator: u8,
         cpi_mode: bool,
         program_addresses: &[Pubkey],
+        threshold: u8,
+        lookback_window: u8,
+        require_top_level: bool,
     ) -> Result<Self, ProgramError> {
         Ok(Self {
             instruction_discriminator,
             cpi_mode,
             program_addresses: program_addresses.to_vec(),
+            threshold,
+            lookback_window,
+            require_top_level,
+            timelock_seconds: 0,
         })
     }
 
@@ -65,6 +88,18 @@ impl InitializeVerificationConfigArgs {
             data.extend_from_slice(program.as_ref());
         }
 
+        // Write threshold (1 byte)
+        data.push(self.threshold);
+
+        // Write lookback_window (1 byte)
+        data.push(self.lookback_window);
+
+        // Write require_top_level (1 byte)
+        data.push(self.require_top_level as u8);
+
+        // Write timelock_seconds (8 bytes)
+        data.extend(&self.timelock_seconds.to_le_bytes());
+
         data
     }
 
@@ -108,10 +143,26 @@ impl InitializeVerificationConfigArgs {
             offset += PUBKEY_BYTES;
         }
 
+        // Read threshold (1 byte), lookback_window (1 byte) and require_top_level (1 byte),
+        // omitted by older clients to require all programs anywhere in the transaction
+        let threshold = data.get(offset).copied().unwrap_or(0);
+        let lookback_window = data.get(offset + 1).copied().unwrap_or(0);
+        let require_top_level = data.get(offset + 2).is_some_and(|flag| *flag != 0);
+
+        // Read timelock_seconds (8 bytes), omitted by older clients for configs without a timelock
+        let timelock_seconds = data
+            .get(offset + 3..offset + 11)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map_or(0, u64::from_le_bytes);
+
         Ok(Self {
             instruction_discriminator,
             cpi_mode: cpi_mode != 0,
             program_addresses,
+            threshold,
+            lookback_window,
+            require_top_level,
+            timelock_seconds,
         })
     }
 
@@ -131,6 +182,20 @@ impl InitializeVerificationConfigArgs {
             }
         }
 
+        // Validate threshold doesn't exceed the program count
+        if self.threshold as usize > self.program_addresses.len() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate the lookback window can hold the programs that must pass
+        let required_programs = match self.threshold {
+            0 => self.program_addresses.len(),
+            threshold => threshold as usize,
+        };
+        if self.lookback_window != 0 && (self.lookback_window as usize) < required_programs {
+            return Err(ProgramError::InvalidArgument);
+        }
+
         Ok(())
     }
 
@@ -160,12 +225,19 @@ impl UpdateVerificationConfigArgs {
         cpi_mode: bool,
         program_addresses: &[Pubkey],
         offset: u8,
+        threshold: u8,
+        lookback_window: u8,
+        require_top_level: bool,
     ) -> Result<Self, ProgramError> {
         Ok(Self {
             instruction_discriminator,
             cpi_mode,
             program_addresses: program_addresses.to_vec(),
             offset,
+            threshold,
+            lookback_window,
+            require_top_level,
+            timelock_seconds: 0,
         })
     }
 
@@ -190,6 +262,18 @@ impl UpdateVerificationConfigArgs {
             data.extend_from_slice(program.as_ref());
         }
 
+        // Write threshold (1 byte)
+        data.push(self.threshold);
+
+        // Write lookback_window (1 byte)
+        data.push(self.lookback_window);
+
+        // Write require_top_level (1 byte)
+        data.push(self.require_top_level as u8);
+
+        // Write timelock_seconds (8 bytes)
+        data.extend(&self.timelock_seconds.to_le_bytes());
+
         data
     }
 
@@ -237,11 +321,27 @@ impl UpdateVerificationConfigArgs {
             offset_pos += PUBKEY_BYTES;
         }
 
+        // Read threshold (1 byte), lookback_window (1 byte) and require_top_level (1 byte),
+        // omitted by older clients to require all programs anywhere in the transaction
+        let threshold = data.get(offset_pos).copied().unwrap_or(0);
+        let lookback_window = data.get(offset_pos + 1).copied().unwrap_or(0);
+        let require_top_level = data.get(offset_pos + 2).is_some_and(|flag| *flag != 0);
+
+        // Read timelock_seconds (8 bytes), omitted by older clients for configs without a timelock
+        let timelock_seconds = data
+            .get(offset_pos + 3..offset_pos + 11)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map_or(0, u64::from_le_bytes);
+
         Ok(Self {
             instruction_discriminator,
             cpi_mode: cpi_mode != 0,
             program_addresses,
             offset,
+            threshold,
+            lookback_window,
+            require_top_level,
+            timelock_seconds,
         })
     }
 
@@ -357,6 +457,9 @@ mod tests {
             SecurityTokenInstruction::UpdateMetadata.discriminant(),
             false,
             &program_addresses,
+            0,
+            0,
+            false,
         )
         .unwrap();
 
@@ -389,6 +492,9 @@ mod tests {
             SecurityTokenInstruction::Mint.discriminant(),
             false,
             &programs,
+            0,
+            0,
+            false,
         )
         .unwrap();
 
@@ -419,6 +525,9 @@ mod tests {
             false,
             &programs,
             offset,
+            0,
+            0,
+            false,
         )
         .unwrap();
 
@@ -453,6 +562,9 @@ mod tests {
             SecurityTokenInstruction::Mint.discriminant(),
             false,
             &program_addresses,
+            0,
+            0,
+            false,
         )
         .unwrap();
 
@@ -473,6 +585,9 @@ mod tests {
             false,
             &program_addresses,
             0,
+            0,
+            0,
+            false,
         )
         .unwrap();
 
@@ -480,4 +595,107 @@ mod tests {
 
         assert!(matches!(result, Err(ProgramError::InvalidArgument)));
     }
+
+    #[rstest]
+    #[case(0, true)]
+    #[case(1, true)]
+    #[case(3, true)]
+    #[case(4, false)]
+    fn test_initialize_verification_config_threshold_limit(
+        #[case] threshold: u8,
+        #[case] should_succeed: bool,
+    ) {
+        let programs: Vec<Pubkey> = (0..3).map(|_| random_pubkey()).collect();
+        let args = InitializeVerificationConfigArgs::new(
+            SecurityTokenInstruction::Mint.discriminant(),
+            false,
+            &programs,
+            threshold,
+            0,
+            false,
+        )
+        .unwrap();
+
+        let deserialized =
+            InitializeVerificationConfigArgs::try_from_bytes(&args.to_bytes_inner()).unwrap();
+        assert_eq!(deserialized.threshold, threshold);
+        assert_eq!(deserialized.validate().is_ok(), should_succeed);
+    }
+
+    #[test]
+    fn test_verification_config_args_without_threshold_require_all() {
+        let args = InitializeVerificationConfigArgs::new(
+            SecurityTokenInstruction::Mint.discriminant(),
+            false,
+            &[random_pubkey()],
+            1,
+            1,
+            true,
+        )
+        .unwrap();
+        let mut bytes = args.to_bytes_inner();
+        // Drop the timelock, lookback window and top-level bytes
+        bytes.truncate(bytes.len() - 10);
+
+        let deserialized = InitializeVerificationConfigArgs::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.threshold, 1);
+        assert_eq!(deserialized.lookback_window, 0);
+        assert!(!deserialized.require_top_level);
+
+        bytes.pop();
+        let deserialized = InitializeVerificationConfigArgs::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.threshold, 0);
+    }
+
+    #[rstest]
+    #[case(0, 0, true)]
+    #[case(0, 1, false)]
+    #[case(0, 2, true)]
+    #[case(1, 1, true)]
+    fn test_initialize_verification_config_lookback_window(
+        #[case] threshold: u8,
+        #[case] lookback_window: u8,
+        #[case] should_succeed: bool,
+    ) {
+        let programs: Vec<Pubkey> = (0..2).map(|_| random_pubkey()).collect();
+        let args = InitializeVerificationConfigArgs::new(
+            SecurityTokenInstruction::Mint.discriminant(),
+            false,
+            &programs,
+            threshold,
+            lookback_window,
+            true,
+        )
+        .unwrap();
+
+        let deserialized =
+            InitializeVerificationConfigArgs::try_from_bytes(&args.to_bytes_inner()).unwrap();
+        assert_eq!(deserialized.lookback_window, lookback_window);
+        assert!(deserialized.require_top_level);
+        assert_eq!(deserialized.validate().is_ok(), should_succeed);
+    }
+
+    #[test]
+    fn test_update_verification_config_args_timelock() {
+        let mut args = UpdateVerificationConfigArgs::new(
+            SecurityTokenInstruction::Mint.discriminant(),
+            false,
+            &[random_pubkey()],
+            0,
+            0,
+            0,
+            false,
+        )
+        .unwrap();
+        args.timelock_seconds = 86_400;
+
+        let mut bytes = args.to_bytes_inner();
+        let deserialized = UpdateVerificationConfigArgs::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.timelock_seconds, 86_400);
+
+        // Older clients omit the timelock
+        bytes.truncate(bytes.len() - 8);
+        let deserialized = UpdateVerificationConfigArgs::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.timelock_seconds, 0);
+    }
 }
@@ -12,6 +12,8 @@ pub mod constants;
 pub mod entrypoint;
 /// Error types
 pub mod error;
+/// Events logged for off-chain indexers
+pub mod events;
 /// Instruction definitions
 pub mod instruction;
 /// Instruction wrappers
@@ -22,6 +24,8 @@ pub mod macros;
 pub mod merkle_tree_utils;
 /// Security Token program modules (verification & operations)
 pub mod modules;
+/// Ed25519 permit utilities
+pub mod permit_utils;
 /// Instruction processor
 pub mod processor;
 /// State structures
@@ -34,6 +38,8 @@ pub mod token22_extensions;
 pub mod test_utils;
 /// Utility functions
 pub mod utils;
+/// Results returned by view instructions
+pub mod views;
 use pinocchio_pubkey::declare_id;
 
 declare_id!("SSTS8Qk2bW3aVaBEsY1Ras95YdbaaYQQx21JWHxvjap");
@@ -101,9 +101,9 @@ mod tests {
         let merkle_tree = MerkleTree::new(&leaves);
         let root = merkle_tree.root;
 
-        for idx in 0..leaves.len() {
+        for (idx, leaf) in leaves.iter().enumerate() {
             let node = merkle_tree.get_node(idx);
-            assert_eq!(node, leaves[idx]);
+            assert_eq!(node, *leaf);
             let proof = merkle_tree.get_proof_of_leaf(idx);
             let is_valid = verify_merkle_proof(&node, &root, &proof, idx as u32);
             assert!(is_valid, "Merkle proof should be valid at index {}", idx);
@@ -134,7 +134,7 @@ mod tests {
             // Ensure random leaf is invalid for this proof
             let random_hash = hashv(&[&random_32_bytes()]).to_bytes();
             let invalid_node = leaves.get(idx + 1).unwrap_or(&random_hash);
-            let is_valid = verify_merkle_proof(&invalid_node, &root, &proof, idx as u32);
+            let is_valid = verify_merkle_proof(invalid_node, &root, &proof, idx as u32);
             assert!(
                 !is_valid,
                 "Merkle proof should not be valid at index {}",
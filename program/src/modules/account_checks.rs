@@ -1,6 +1,10 @@
 #[cfg(feature = "debug-logs")]
 use crate::acc_info_as_str;
-use crate::{constants::TRANSFER_HOOK_PROGRAM_ID, debug_log};
+use crate::{
+    constants::{MEMO_PROGRAM_ID, TRANSFER_HOOK_PROGRAM_ID},
+    debug_log,
+    error::SecurityTokenError,
+};
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
 /// Verify account as writable
@@ -97,6 +101,26 @@ pub fn verify_token22_program(info: &AccountInfo) -> Result<(), ProgramError> {
     Ok(())
 }
 
+/// Verify account as legacy SPL Token or Token 2022 program, returning an error if it is not.
+///
+/// # Arguments
+/// * `info` - The account to verify.
+///
+/// # Returns
+/// * `Result<(), ProgramError>` - The result of the operation
+#[inline(always)]
+pub fn verify_underlying_token_program(info: &AccountInfo) -> Result<(), ProgramError> {
+    if info.key().ne(&pinocchio_token::ID) && info.key().ne(&pinocchio_token_2022::ID) {
+        debug_log!(
+            "Account {} is not the SPL Token or Token 2022 program",
+            acc_info_as_str!(info),
+        );
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    Ok(())
+}
+
 /// Verify account as Associated Token program, returning an error if it is not.
 ///
 /// # Arguments
@@ -240,6 +264,41 @@ pub fn verify_pda_keys_match(
     Ok(())
 }
 
+/// Verify the account receiving the rent of a closed or trimmed account.
+///
+/// # Arguments
+/// * `rent_recipient` - The account receiving the recovered rent.
+/// * `released_account` - The account being closed or trimmed.
+///
+/// # Returns
+/// * `Result<(), ProgramError>` - The result of the operation
+#[inline(always)]
+pub fn verify_rent_recipient(
+    rent_recipient: &AccountInfo,
+    released_account: &AccountInfo,
+) -> Result<(), ProgramError> {
+    verify_writable(rent_recipient)?;
+    if rent_recipient.key().eq(released_account.key()) {
+        debug_log!(
+            "Rent recipient {} is the released account",
+            acc_info_as_str!(rent_recipient)
+        );
+        return Err(SecurityTokenError::InvalidRentRecipient.into());
+    }
+
+    Ok(())
+}
+
+#[inline(always)]
+pub fn verify_memo_program(info: &AccountInfo) -> Result<(), ProgramError> {
+    if info.key().ne(&MEMO_PROGRAM_ID) {
+        debug_log!("Account {} is not the memo program", acc_info_as_str!(info));
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    Ok(())
+}
+
 #[inline(always)]
 pub fn verify_transfer_hook_program(transfer_hook_pda: &AccountInfo) -> Result<(), ProgramError> {
     if transfer_hook_pda.key().ne(&TRANSFER_HOOK_PROGRAM_ID) {
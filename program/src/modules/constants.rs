@@ -2,4 +2,7 @@ pub enum VerificationProfile {
     None,
     VerificationPrograms,
     VerificationProgramsOrMintAuthority,
+    VerificationProgramsOrAgent,
+    VerificationProgramsOrPauseDelegate,
+    VerificationProgramsOrMintAuthorityOrConfigAdmin,
 }
@@ -5,30 +5,81 @@
 
 use crate::constants::seeds;
 use crate::debug_log;
+use crate::error::SecurityTokenError;
+use crate::events::{audit_invariants, AuditEvent, TransferEvent};
+use crate::instructions::{
+    CreateAccrualConfigArgs, CreateAuctionArgs, CreateNavOracleArgs, DefaultAccountStateArgs,
+    SetRateOracleArgs,
+};
 use crate::merkle_tree_utils::{
     create_merkle_tree_leaf_node, verify_merkle_proof, MerkleTreeRoot, ProofData, ProofNode,
 };
+use crate::modules::verification::{TransferHookRules, VerificationModule};
 use crate::modules::{
-    burn_checked, mint_to_checked, transfer_checked, verify_account_initialized,
-    verify_account_not_initialized, verify_associated_token_program, verify_mint_keys_match,
-    verify_owner, verify_pda_keys_match, verify_signer, verify_system_program,
-    verify_token22_program, verify_transfer_hook_program, verify_writable,
+    burn_checked, mint_to_checked, token_cpi_error, transfer_checked,
+    transfer_checked_as_account_delegate, transfer_from_auction_vault, transfer_from_coupon_vault,
+    transfer_from_payment_vault, transfer_from_payout_escrow, transfer_from_redemption_vault,
+    transfer_from_wrap_vault, underlying_mint_decimals, underlying_token_account_amount,
+    underlying_token_account_mint, underlying_token_account_owner, verify_account_initialized,
+    verify_account_not_initialized, verify_associated_token_program, verify_memo_program,
+    verify_mint_keys_match, verify_owner, verify_pda_keys_match, verify_rent_recipient,
+    verify_signer, verify_system_program, verify_token22_program, verify_transfer_hook_program,
+    verify_underlying_token_program, verify_writable, TokenCpiStage,
 };
+use crate::permit_utils::{claim_permit_message, transfer_permit_message, verify_ed25519_permit};
 use crate::state::{
-    DistributionEscrowAuthority, MintAuthority, ProgramAccount, Proof, Rate, Receipt, Rounding,
+    AccountDeserialize, AccountSerialize, AccrualConfig, Agent, AllowlistEntry, Auction,
+    AuctionBid, ClaimAmounts, ClaimBitmap, ClaimDeadline, CollateralAttestation, CouponClaim,
+    DayCountConvention, DistributionEscrowAuthority, DistributionPayout, DistributionRoot,
+    DistributionRootStatus, DocumentRegistry, FreezeExpiry, HoldingLot, HoldingPeriod, Identity,
+    IdentityWallet, Maturity, MetadataSchema, MetadataTranslator, MintAuthority, MintMigration,
+    NavOracle, PauseDelegate, PendingTransfer, PermanentDelegateConfig, PermitNonce, PositionLimit,
+    ProgramAccount, Proof, Rate, Receipt, RecoveryReason, RecoveryReceipt, ReserveAttestation,
+    RestrictedHolding, Rounding, SecurityTokenDiscriminators, SessionKey, SubAccount,
+    SubAccountLedger, Subscription, SubscriptionCommitment, SuccessionPolicy, Suspension,
+    TransferAcceptance, TransferApproval, TransferRequest, Vesting, WithholdingRate, WrapVault,
+    DOCUMENT_HASH_LEN,
+};
+use crate::token22_extensions::default_account_state::UpdateDefaultAccountState;
+use crate::token22_extensions::get_extension_from_bytes;
+use crate::token22_extensions::memo_transfer::Memo;
+use crate::token22_extensions::pausable::{Pausable, Pause, Resume};
+use crate::token22_extensions::permanent_delegate::SetPermanentDelegate;
+use crate::token22_extensions::scaled_ui_amount::UpdateMultiplier;
+use crate::token22_extensions::transfer_fee::{
+    HarvestWithheldTokensToMint, SetTransferFee, WithdrawWithheldTokensFromAccounts,
 };
-use crate::token22_extensions::pausable::{Pause, Resume};
 use crate::utils::{
-    find_associated_token_address, find_distribution_escrow_authority_pda,
-    find_freeze_authority_pda, find_pause_authority_pda, find_permanent_delegate_pda,
-    find_proof_pda, find_rate_pda,
+    find_account_delegate_pda, find_accrual_config_pda, find_agent_pda, find_allowlist_entry_pda,
+    find_associated_token_address, find_auction_bid_pda, find_auction_pda, find_claim_bitmap_pda,
+    find_claim_deadline_pda, find_collateral_attestation_pda, find_coupon_claim_pda,
+    find_distribution_escrow_authority_pda, find_distribution_payout_pda,
+    find_distribution_root_pda, find_document_registry_pda, find_extra_account_metas_pda,
+    find_freeze_authority_pda, find_freeze_expiry_pda, find_holding_lot_pda,
+    find_holding_period_pda, find_identity_pda, find_identity_wallet_pda, find_maturity_pda,
+    find_metadata_schema_pda, find_metadata_translator_pda, find_mint_migration_pda,
+    find_nav_oracle_pda, find_pause_authority_pda, find_pause_delegate_pda,
+    find_pending_transfer_pda, find_permanent_delegate_config_pda, find_permanent_delegate_pda,
+    find_permit_nonce_pda, find_position_limit_pda, find_proof_pda, find_rate_pda,
+    find_recovery_receipt_pda, find_reserve_attestation_pda, find_restricted_holding_pda,
+    find_scaled_ui_amount_authority_pda, find_session_key_pda, find_sub_account_ledger_pda,
+    find_sub_account_pda, find_subscription_commitment_pda, find_subscription_pda,
+    find_succession_policy_pda, find_suspension_pda, find_transfer_acceptance_pda,
+    find_transfer_approval_pda, find_transfer_escrow_authority_pda,
+    find_transfer_fee_authority_pda, find_transfer_request_pda, find_vesting_pda,
+    find_withholding_rate_pda, find_wrap_vault_pda,
 };
+use crate::views::{DistributionStatus, EffectiveRate, HolderStatus};
 use core::cmp::Ordering;
+use pinocchio::cpi::set_return_data;
 use pinocchio::instruction::{Seed, Signer};
 use pinocchio::program_error::ProgramError;
+use pinocchio::sysvars::{clock::Clock, Sysvar};
 use pinocchio::{account_info::AccountInfo, pubkey::Pubkey, ProgramResult};
 use pinocchio_associated_token_account::instructions::Create as CreateTokenAccount;
-use pinocchio_token_2022::instructions::{FreezeAccount, ThawAccount};
+use pinocchio_token_2022::instructions::{
+    BurnChecked, FreezeAccount, ThawAccount, TransferChecked,
+};
 use pinocchio_token_2022::state::{Mint, TokenAccount};
 
 /// Operations Module - executes token operations
@@ -36,7 +87,8 @@ pub struct OperationsModule;
 
 impl OperationsModule {
     /// Mint tokens to an account
-    /// Wrapper for SPL Token MintToChecked instruction
+    /// Wrapper for SPL Token MintToChecked instruction.
+    /// Trailing holding lot accounts record the minted tokens as a restricted lot.
     ///
     /// # Arguments
     /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
@@ -46,7 +98,9 @@ impl OperationsModule {
         accounts: &[AccountInfo],
         amount: u64,
     ) -> ProgramResult {
-        let [mint_authority, mint_info, destination_account_info, token_program] = accounts else {
+        let [mint_authority, mint_info, destination_account_info, token_program, lot_accounts @ ..] =
+            accounts
+        else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -74,9 +128,16 @@ impl OperationsModule {
             destination_account_info,
             mint_authority,
             &mint_authority_state,
-        )?;
+        )
+        .map_err(token_cpi_error(TokenCpiStage::Mint))?;
 
-        Ok(())
+        Self::record_holding_lot(
+            program_id,
+            mint_info,
+            destination_account_info,
+            amount,
+            lot_accounts,
+        )
     }
 
     /// Burn tokens from an account  
@@ -115,7 +176,8 @@ impl OperationsModule {
             token_account,
             permanent_delegate_authority,
             bump,
-        )?;
+        )
+        .map_err(token_cpi_error(TokenCpiStage::Burn))?;
 
         Ok(())
     }
@@ -153,7 +215,9 @@ impl OperationsModule {
         ];
 
         let pause_authority_signer = Signer::from(&seeds);
-        pause_instruction.invoke_signed(&[pause_authority_signer])?;
+        pause_instruction
+            .invoke_signed(&[pause_authority_signer])
+            .map_err(token_cpi_error(TokenCpiStage::Pause))?;
 
         Ok(())
     }
@@ -191,811 +255,7568 @@ impl OperationsModule {
         ];
 
         let resume_authority_signer = Signer::from(&seeds);
-        resume_instruction.invoke_signed(&[resume_authority_signer])?;
+        resume_instruction
+            .invoke_signed(&[resume_authority_signer])
+            .map_err(token_cpi_error(TokenCpiStage::Resume))?;
 
         Ok(())
     }
 
-    /// Freeze a token account
-    /// Wrapper for SPL Token FreezeAccount instruction
+    /// Name the pause delegate of the mint, an external key that signs Pause and Resume directly
+    /// without a VerificationConfig. The default pubkey removes the delegate and returns the rent
+    /// to the payer.
     ///
     /// # Arguments
     /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
-    pub fn execute_freeze_account(
+    pub fn execute_set_pause_delegate(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        delegate: Pubkey,
+    ) -> ProgramResult {
+        let [payer, mint_info, pause_delegate_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(pause_delegate_account)?;
+        verify_system_program(system_program_info)?;
+
+        let (pause_delegate_pda, bump) = find_pause_delegate_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(pause_delegate_account.key(), &pause_delegate_pda)?;
+
+        if delegate == Pubkey::default() {
+            verify_owner(pause_delegate_account, program_id)?;
+            verify_account_initialized(pause_delegate_account)?;
+            return PauseDelegate::close(pause_delegate_account, payer);
+        }
+
+        let pause_delegate = PauseDelegate::new(*mint_info.key(), delegate, bump);
+        if pause_delegate_account.data_is_empty() {
+            let bump_seed = &pause_delegate.bump_seed();
+            let seeds = pause_delegate.seeds(bump_seed);
+            pause_delegate.init(payer, pause_delegate_account, &seeds)?;
+        } else {
+            verify_owner(pause_delegate_account, program_id)?;
+        }
+        pause_delegate.write_data(pause_delegate_account)
+    }
+
+    /// Set the transfer fee of a mint created with the transfer fee extension
+    /// Wrapper for SPL Token SetTransferFee instruction, the new fee takes effect two epochs
+    /// after the current one
+    ///
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_set_transfer_fee(
         program_id: &Pubkey,
         verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
     ) -> ProgramResult {
-        let [freeze_authority, mint_info, token_account, token_program] = accounts else {
+        let [transfer_fee_authority, mint_info, token_program] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
         verify_mint_keys_match(verified_mint_info, &mint_info)?;
         verify_token22_program(token_program)?;
-        verify_writable(token_account)?;
+        verify_writable(mint_info)?;
 
-        let (freeze_authority_pda, bump) = find_freeze_authority_pda(mint_info.key(), program_id);
-        verify_pda_keys_match(freeze_authority.key(), &freeze_authority_pda)?;
-        let freeze_instruction = FreezeAccount {
-            account: token_account,
+        let (transfer_fee_authority_pda, bump) =
+            find_transfer_fee_authority_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(transfer_fee_authority.key(), &transfer_fee_authority_pda)?;
+
+        let set_transfer_fee_instruction = SetTransferFee {
             mint: mint_info,
-            freeze_authority,
-            token_program: token_program.key(),
+            authority: transfer_fee_authority,
+            transfer_fee_basis_points,
+            maximum_fee,
         };
         let bump_seed = [bump];
         let seeds = [
-            Seed::from(seeds::FREEZE_AUTHORITY),
+            Seed::from(seeds::TRANSFER_FEE_AUTHORITY),
             Seed::from(mint_info.key().as_ref()),
             Seed::from(bump_seed.as_ref()),
         ];
 
-        let freeze_authority_signer = Signer::from(&seeds);
-        freeze_instruction.invoke_signed(&[freeze_authority_signer])?;
+        let transfer_fee_authority_signer = Signer::from(&seeds);
+        set_transfer_fee_instruction
+            .invoke_signed(&[transfer_fee_authority_signer])
+            .map_err(token_cpi_error(TokenCpiStage::SetTransferFee))?;
+
         Ok(())
     }
 
-    /// Thaw a token account
-    /// Wrapper for SPL Token ThawAccount instruction
+    /// Withdraw the transfer fees withheld in token accounts of a mint to a treasury account
+    /// Wrapper for SPL Token WithdrawWithheldTokensFromAccounts instruction.
+    /// The token accounts to withdraw from follow the fixed accounts.
     ///
     /// # Arguments
     /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
-    pub fn execute_thaw_account(
+    pub fn execute_withdraw_withheld_tokens_from_accounts(
         program_id: &Pubkey,
         verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
     ) -> ProgramResult {
-        let [freeze_authority, mint_info, token_account, token_program] = accounts else {
+        let [transfer_fee_authority, mint_info, destination, token_program, sources @ ..] =
+            accounts
+        else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
         verify_mint_keys_match(verified_mint_info, &mint_info)?;
         verify_token22_program(token_program)?;
-        verify_writable(token_account)?;
+        verify_writable(destination)?;
+        if sources.is_empty() || sources.len() > u8::MAX as usize {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-        let (freeze_authority_pda, bump) = find_freeze_authority_pda(mint_info.key(), program_id);
-        verify_pda_keys_match(freeze_authority.key(), &freeze_authority_pda)?;
-        let thaw_instruction = ThawAccount {
-            account: token_account,
+        let (transfer_fee_authority_pda, bump) =
+            find_transfer_fee_authority_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(transfer_fee_authority.key(), &transfer_fee_authority_pda)?;
+
+        let decimals = Mint::from_account_info(mint_info)?.decimals();
+
+        let withdraw_instruction = WithdrawWithheldTokensFromAccounts {
             mint: mint_info,
-            freeze_authority,
-            token_program: token_program.key(),
+            destination,
+            authority: transfer_fee_authority,
+            sources,
+            decimals,
         };
         let bump_seed = [bump];
         let seeds = [
-            Seed::from(seeds::FREEZE_AUTHORITY),
+            Seed::from(seeds::TRANSFER_FEE_AUTHORITY),
             Seed::from(mint_info.key().as_ref()),
             Seed::from(bump_seed.as_ref()),
         ];
 
-        let thaw_authority_signer = Signer::from(&seeds);
-        thaw_instruction.invoke_signed(&[thaw_authority_signer])?;
+        let transfer_fee_authority_signer = Signer::from(&seeds);
+        withdraw_instruction
+            .invoke_signed(&[transfer_fee_authority_signer])
+            .map_err(token_cpi_error(TokenCpiStage::WithdrawWithheldTokens))?;
+
         Ok(())
     }
 
-    /// Transfer tokens between accounts
-    /// Wrapper for SPL Token TransferChecked instruction
-    pub fn execute_transfer(
-        program_id: &Pubkey,
+    /// Move the transfer fees withheld in token accounts of a mint to the mint
+    /// Wrapper for SPL Token HarvestWithheldTokensToMint instruction.
+    /// The token accounts to harvest follow the fixed accounts.
+    ///
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_harvest_withheld_tokens_to_mint(
         verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
-        amount: u64,
     ) -> ProgramResult {
-        let [permanent_delegate_authority, mint_info, from_token_account, to_token_account, transfer_hook_program, token_program] =
-            accounts
-        else {
+        let [mint_info, token_program, sources @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
         verify_mint_keys_match(verified_mint_info, &mint_info)?;
         verify_token22_program(token_program)?;
-        verify_transfer_hook_program(transfer_hook_program)?;
-        verify_writable(from_token_account)?;
-        verify_writable(to_token_account)?;
-
-        let (permanent_delegate_pda, permanent_delegate_bump) =
-            crate::utils::find_permanent_delegate_pda(mint_info.key(), program_id);
-        verify_pda_keys_match(permanent_delegate_authority.key(), &permanent_delegate_pda)?;
+        verify_writable(mint_info)?;
+        if sources.is_empty() {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-        let mint_account = Mint::from_account_info(mint_info)?;
-        let decimals = mint_account.decimals();
-        drop(mint_account);
+        HarvestWithheldTokensToMint {
+            mint: mint_info,
+            sources,
+        }
+        .invoke()
+        .map_err(token_cpi_error(TokenCpiStage::HarvestWithheldTokens))?;
 
-        transfer_checked(
-            amount,
-            decimals,
-            mint_info,
-            from_token_account,
-            to_token_account,
-            transfer_hook_program,
-            permanent_delegate_authority,
-            permanent_delegate_bump,
-        )?;
         Ok(())
     }
 
-    /// Create Rate account
+    /// Set the state new token accounts of a mint start in
+    /// Wrapper for SPL Token UpdateDefaultAccountState instruction, signed by the freeze
+    /// authority PDA. Existing token accounts keep their state.
+    ///
     /// # Arguments
     /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
-    pub fn execute_create_rate_account(
+    pub fn execute_update_default_account_state(
         program_id: &Pubkey,
         verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
-        action_id: u64,
-        numerator: u8,
-        denominator: u8,
-        rounding: u8,
+        state: u8,
     ) -> ProgramResult {
-        let [payer, rate_account, mint_from_account, mint_to_account, system_program_info] =
-            accounts
-        else {
+        let [freeze_authority, mint_info, token_program] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        // Ensure Rate account is being created for target mint_to account
-        // For Split operation mint_from == mint_to
-        // For Convert operation mint_to is verified so we ensure correct minting of new tokens
-        verify_mint_keys_match(verified_mint_info, &mint_to_account)?;
-
-        verify_system_program(system_program_info)?;
-        verify_signer(payer)?;
-        verify_writable(payer)?;
-        verify_writable(rate_account)?;
-        verify_account_not_initialized(rate_account)?;
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_token22_program(token_program)?;
+        verify_writable(mint_info)?;
+        if !matches!(
+            state,
+            DefaultAccountStateArgs::INITIALIZED | DefaultAccountStateArgs::FROZEN
+        ) {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-        let mint_from_key = mint_from_account.key();
-        let mint_to_key = mint_to_account.key();
+        let (freeze_authority_pda, bump) = find_freeze_authority_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(freeze_authority.key(), &freeze_authority_pda)?;
 
-        let (expected_rate_pda, bump) =
-            find_rate_pda(action_id, mint_from_key, mint_to_key, program_id);
+        let update_instruction = UpdateDefaultAccountState {
+            mint: mint_info,
+            freeze_authority,
+            state,
+        };
+        let bump_seed = [bump];
+        let seeds = [
+            Seed::from(seeds::FREEZE_AUTHORITY),
+            Seed::from(mint_info.key().as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ];
 
-        verify_pda_keys_match(rate_account.key(), &expected_rate_pda)?;
+        let freeze_authority_signer = Signer::from(&seeds);
+        update_instruction
+            .invoke_signed(&[freeze_authority_signer])
+            .map_err(token_cpi_error(TokenCpiStage::UpdateDefaultAccountState))?;
 
-        // Calculate rent and create Rate account
-        let rounding_enum = Rounding::try_from(rounding)?;
-        let rate = Rate::new(rounding_enum, numerator, denominator, bump)?;
-        let action_id_seed = &action_id.to_le_bytes();
-        let bump_seed = &rate.bump_seed();
-        let seeds = rate.seeds(action_id_seed, mint_from_key, mint_to_key, bump_seed);
-        rate.init(payer, rate_account, &seeds)?;
-        rate.write_data(rate_account)?;
         Ok(())
     }
 
-    /// Update Rate account
+    /// Set the ScaledUiAmount multiplier of a mint, taking effect at `effective_timestamp`
+    /// Wrapper for SPL Token UpdateMultiplier instruction, signed by the mint authority PDA,
+    /// which must be the ScaledUiAmount authority of the mint. Raw balances are unchanged.
+    ///
     /// # Arguments
     /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
-    pub fn execute_update_rate_account(
+    pub fn execute_update_scaled_ui_amount_multiplier(
         program_id: &Pubkey,
         verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
-        action_id: u64,
-        numerator: u8,
-        denominator: u8,
-        rounding: u8,
+        multiplier: f64,
+        effective_timestamp: i64,
     ) -> ProgramResult {
-        let [rate_account_info, mint_from_account, mint_to_info_account] = accounts else {
+        let [mint_authority, mint_info, token_program] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        // For Split operation mint_from == mint_to
-        // If Rate was created for Convert operation, then mint_to should be verified
-        verify_mint_keys_match(verified_mint_info, &mint_to_info_account)?;
-
-        verify_writable(rate_account_info)?;
-        verify_owner(rate_account_info, program_id)?;
-        verify_account_initialized(rate_account_info)?;
-
-        let mint_from_key = mint_from_account.key();
-        let mint_to_key = mint_to_info_account.key();
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_token22_program(token_program)?;
+        verify_owner(mint_authority, program_id)?;
+        verify_writable(mint_info)?;
+        if !multiplier.is_finite() || multiplier <= 0.0 {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-        let mut rate_account = Rate::from_account_info(rate_account_info)?;
-        let expected_rate_pda = rate_account.derive_pda(action_id, mint_from_key, mint_to_key)?;
-        verify_pda_keys_match(rate_account_info.key(), &expected_rate_pda)?;
+        let mint_authority_state = MintAuthority::from_account_info(mint_authority)?;
+        if mint_authority_state.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
 
-        let rounding_enum = Rounding::try_from(rounding)?;
-        rate_account.update(rounding_enum, numerator, denominator)?;
-        rate_account.write_data(rate_account_info)?;
-        Ok(())
+        let bump_seed = &mint_authority_state.bump_seed();
+        let seeds = mint_authority_state.seeds(bump_seed);
+        UpdateMultiplier {
+            mint: mint_info,
+            authority: mint_authority,
+            multiplier,
+            effective_timestamp,
+        }
+        .invoke_signed(&[Signer::from(&seeds)])
+        .map_err(token_cpi_error(TokenCpiStage::UpdateMultiplier))
     }
 
-    /// Close Rate account
+    /// Freeze a token account
+    /// Wrapper for SPL Token FreezeAccount instruction.
+    /// With `auto_thaw_at` set, trailing accounts record a FreezeExpiry allowing anyone to thaw
+    /// the token account once the timestamp passes.
+    ///
     /// # Arguments
     /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
-    pub fn execute_close_rate_account(
+    pub fn execute_freeze_account(
         program_id: &Pubkey,
         verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
-        action_id: u64,
+        auto_thaw_at: Option<i64>,
     ) -> ProgramResult {
-        let [rate_account_info, destination_account, mint_from_account, mint_to_info_account] =
+        let [freeze_authority, mint_info, token_account, token_program, expiry_accounts @ ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        // For Split operation mint_from == mint_to
-        // If Rate was created for Convert operation, then mint_to should be verified
-        verify_mint_keys_match(verified_mint_info, &mint_to_info_account)?;
-
-        verify_writable(destination_account)?;
-        verify_writable(rate_account_info)?;
-        verify_owner(rate_account_info, program_id)?;
-        verify_account_initialized(rate_account_info)?;
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_token22_program(token_program)?;
+        verify_writable(token_account)?;
 
-        let mint_from_key = mint_from_account.key();
-        let mint_to_key = mint_to_info_account.key();
+        let (freeze_authority_pda, bump) = find_freeze_authority_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(freeze_authority.key(), &freeze_authority_pda)?;
+        let freeze_instruction = FreezeAccount {
+            account: token_account,
+            mint: mint_info,
+            freeze_authority,
+            token_program: token_program.key(),
+        };
+        let bump_seed = [bump];
+        let seeds = [
+            Seed::from(seeds::FREEZE_AUTHORITY),
+            Seed::from(mint_info.key().as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ];
 
-        // Deserialize to ensure it's valid Rate account, verify PDA, then close
-        let rate = Rate::from_account_info(rate_account_info)?;
-        let expected_rate_pda = rate.derive_pda(action_id, mint_from_key, mint_to_key)?;
-        verify_pda_keys_match(rate_account_info.key(), &expected_rate_pda)?;
+        let freeze_authority_signer = Signer::from(&seeds);
+        freeze_instruction
+            .invoke_signed(&[freeze_authority_signer])
+            .map_err(token_cpi_error(TokenCpiStage::Freeze))?;
 
-        Rate::close(rate_account_info, destination_account)?;
-        Ok(())
+        match auto_thaw_at {
+            Some(auto_thaw_at) => Self::record_freeze_expiry(
+                program_id,
+                mint_info,
+                token_account,
+                auto_thaw_at,
+                expiry_accounts,
+            ),
+            None => Ok(()),
+        }
     }
 
-    /// Execute token split at predefined rate
+    /// Thaw a token account
+    /// Wrapper for SPL Token ThawAccount instruction.
+    /// Trailing accounts close the FreezeExpiry of a freeze with auto-thaw.
+    ///
     /// # Arguments
     /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
-    pub fn execute_split(
+    pub fn execute_thaw_account(
         program_id: &Pubkey,
         verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
-        action_id: u64,
     ) -> ProgramResult {
-        let [mint_authority, permanent_delegate, payer, mint_account, token_account, rate_account, receipt_account, token_program, system_program] =
+        let [freeze_authority, mint_info, token_account, token_program, expiry_accounts @ ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        verify_mint_keys_match(verified_mint_info, &mint_account)?;
-
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
         verify_token22_program(token_program)?;
-        verify_system_program(system_program)?;
-        verify_signer(payer)?;
         verify_writable(token_account)?;
-        verify_writable(receipt_account)?;
-        verify_writable(payer)?;
-        verify_writable(mint_account)?;
-        verify_owner(mint_authority, program_id)?;
-        verify_owner(rate_account, program_id)?;
-        verify_account_not_initialized(receipt_account)?;
-        verify_account_initialized(rate_account)?;
 
-        let mint_split_key = mint_account.key();
+        Self::thaw_token_account(
+            program_id,
+            freeze_authority,
+            mint_info,
+            token_account,
+            token_program,
+            TokenCpiStage::Thaw,
+        )?;
 
-        let (permanent_delegate_pda, permanent_delegate_bump) =
-            find_permanent_delegate_pda(mint_split_key, program_id);
-        verify_pda_keys_match(permanent_delegate.key(), &permanent_delegate_pda)?;
+        // Clients fill omitted optional accounts with the program id
+        match expiry_accounts {
+            [] => Ok(()),
+            [freeze_expiry_account, ..] if freeze_expiry_account.key() == program_id => Ok(()),
+            [freeze_expiry_account, rent_destination, ..] => {
+                Self::load_freeze_expiry(
+                    program_id,
+                    freeze_expiry_account,
+                    token_account,
+                    rent_destination,
+                )?;
+                FreezeExpiry::close(freeze_expiry_account, rent_destination)
+            }
+            _ => Err(ProgramError::NotEnoughAccountKeys),
+        }
+    }
 
-        let (expected_receipt_pda, receipt_bump) =
-            Receipt::find_common_action_pda(mint_split_key, action_id);
-        verify_pda_keys_match(receipt_account.key(), &expected_receipt_pda)?;
-
-        // Verify Rate account with optimized derive_pda
-        let rate = Rate::from_account_info(rate_account)?;
-        let expected_rate_pda = rate.derive_pda(action_id, mint_split_key, mint_split_key)?;
-        verify_pda_keys_match(rate_account.key(), &expected_rate_pda)?;
+    /// Thaw a token account whose freeze reached its auto-thaw timestamp.
+    /// Permissionless, the FreezeExpiry rent is returned to its payer.
+    /// A token account already thawed by the freeze authority only has its FreezeExpiry closed.
+    pub fn execute_thaw_expired(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let [mint_info, freeze_authority, token_account, freeze_expiry_account, rent_destination, token_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
 
-        let mint_split = Mint::from_account_info(mint_account)?;
-        let mint_decimals = mint_split.decimals();
-        drop(mint_split);
+        verify_token22_program(token_program)?;
+        verify_writable(token_account)?;
 
-        let mint_authority_state = MintAuthority::from_account_info(mint_authority)?;
-        if mint_split_key.ne(&mint_authority_state.mint) {
-            return Err(ProgramError::InvalidInstructionData);
+        let freeze_expiry = Self::load_freeze_expiry(
+            program_id,
+            freeze_expiry_account,
+            token_account,
+            rent_destination,
+        )?;
+        if freeze_expiry.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
         }
+        freeze_expiry.verify_expired(Clock::get()?.unix_timestamp)?;
 
-        let token = TokenAccount::from_account_info(token_account)?;
-        let current_amount = token.amount();
-        if token.mint().ne(mint_split_key) {
-            return Err(ProgramError::InvalidInstructionData);
-        }
-        if current_amount == 0 {
-            return Err(ProgramError::InsufficientFunds);
+        let is_frozen = TokenAccount::from_account_info(token_account)?.is_frozen();
+        if is_frozen {
+            Self::thaw_token_account(
+                program_id,
+                freeze_authority,
+                mint_info,
+                token_account,
+                token_program,
+                TokenCpiStage::ThawExpired,
+            )?;
         }
-        drop(token);
 
-        let new_amount = rate.calculate(current_amount)?;
+        FreezeExpiry::close(freeze_expiry_account, rent_destination)
+    }
 
-        match new_amount.cmp(&current_amount) {
-            Ordering::Equal => {
-                // Just log the message but create Receipt to prevent duplicate split attempts
-                debug_log!("No change in amount after split");
-            }
-            Ordering::Greater => {
-                // Mint additional tokens
-                let amount_diff = new_amount
-                    .checked_sub(current_amount)
-                    .ok_or(ProgramError::ArithmeticOverflow)?;
-                mint_to_checked(
-                    amount_diff,
-                    mint_decimals,
-                    mint_account,
-                    token_account,
-                    mint_authority,
-                    &mint_authority_state,
-                )?;
-            }
-            Ordering::Less => {
-                // Burn excess tokens
-                let amount_diff = current_amount
-                    .checked_sub(new_amount)
-                    .ok_or(ProgramError::ArithmeticOverflow)?;
-                burn_checked(
-                    amount_diff,
-                    mint_decimals,
-                    mint_account,
-                    token_account,
-                    permanent_delegate,
-                    permanent_delegate_bump,
-                )?;
-            }
+    /// Transfer tokens between accounts
+    /// Wrapper for SPL Token TransferChecked instruction.
+    /// Trailing holding lot accounts record the received tokens as a restricted lot.
+    /// With a memo, the memo program account follows the lot accounts and the memo is invoked
+    /// right before the transfer, as destinations requiring memos expect.
+    /// Emits a TransferEvent carrying the optional travel-rule memo hash.
+    pub fn execute_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        amount: u64,
+        memo_hash: Option<[u8; 32]>,
+        memo: Option<&str>,
+    ) -> ProgramResult {
+        let [permanent_delegate_authority, mint_info, from_token_account, to_token_account, transfer_hook_program, token_program, trailing_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        // Holding lot accounts: payer, HoldingPeriod, RestrictedHolding, HoldingLot, system program
+        let (lot_accounts, memo_accounts) =
+            trailing_accounts.split_at(trailing_accounts.len().min(5));
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_token22_program(token_program)?;
+        verify_transfer_hook_program(transfer_hook_program)?;
+        verify_writable(from_token_account)?;
+        verify_writable(to_token_account)?;
+
+        let (permanent_delegate_pda, permanent_delegate_bump) =
+            crate::utils::find_permanent_delegate_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(permanent_delegate_authority.key(), &permanent_delegate_pda)?;
+
+        let mint_account = Mint::from_account_info(mint_info)?;
+        let decimals = mint_account.decimals();
+        drop(mint_account);
+
+        if let Some(memo) = memo {
+            let memo_program = memo_accounts
+                .first()
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            verify_memo_program(memo_program)?;
+            Memo { memo }.invoke()?;
         }
 
-        // Create Receipt PDA account for Split operation
-        let action_id_seed = action_id.to_le_bytes();
-        let bump_seed = [receipt_bump];
-        let seeds = Receipt::common_action_seeds(mint_split_key, &action_id_seed, &bump_seed);
-        Receipt::issue(receipt_account, payer, &seeds)?;
+        transfer_checked(
+            amount,
+            decimals,
+            mint_info,
+            from_token_account,
+            to_token_account,
+            transfer_hook_program,
+            permanent_delegate_authority,
+            permanent_delegate_bump,
+        )
+        .map_err(token_cpi_error(TokenCpiStage::Transfer))?;
+
+        Self::record_holding_lot(
+            program_id,
+            mint_info,
+            to_token_account,
+            amount,
+            lot_accounts,
+        )?;
 
+        TransferEvent {
+            mint: *mint_info.key(),
+            from: *from_token_account.key(),
+            to: *to_token_account.key(),
+            amount,
+            memo_hash,
+        }
+        .emit();
         Ok(())
     }
 
-    /// Execute token conversion at predefined rate
+    /// Check a Transfer of `amount` would succeed without moving tokens: the mint is not paused,
+    /// neither token account is frozen, the source holds the amount and, with the optional
+    /// holding period accounts, keeps the locked amount of its restricted lots.
     /// # Arguments
     /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
-    pub fn execute_convert(
+    pub fn execute_can_transfer(
         program_id: &Pubkey,
         verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
-        action_id: u64,
-        amount_to_convert: u64,
+        amount: u64,
     ) -> ProgramResult {
-        let [mint_authority, permanent_delegate, payer, mint_from_account, mint_to_account, token_account_from, token_account_to, rate_account, receipt_account, token_program, system_program] =
+        let [_permanent_delegate_authority, mint_info, from_token_account, to_token_account, transfer_hook_program, token_program, lockup_accounts @ ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        verify_mint_keys_match(verified_mint_info, &mint_to_account)?;
-
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
         verify_token22_program(token_program)?;
-        verify_system_program(system_program)?;
-        verify_signer(payer)?;
-        verify_writable(token_account_from)?;
-        verify_writable(token_account_to)?;
-        verify_writable(receipt_account)?;
-        verify_writable(payer)?;
-        verify_writable(mint_from_account)?;
-        verify_writable(mint_to_account)?;
-        verify_owner(rate_account, program_id)?;
-        verify_owner(mint_authority, program_id)?;
-        verify_account_not_initialized(receipt_account)?;
-        verify_account_initialized(rate_account)?;
-
-        let verified_mint_key = verified_mint_info.key();
-        let mint_from_key = mint_from_account.key();
-        let mint_to_key = mint_to_account.key();
-
-        let (permanent_delegate_pda, permanent_delegate_bump) =
-            find_permanent_delegate_pda(mint_from_key, program_id);
-        verify_pda_keys_match(permanent_delegate.key(), &permanent_delegate_pda)?;
-
-        let (expected_receipt_pda, receipt_bump) =
-            Receipt::find_common_action_pda(verified_mint_key, action_id);
-        verify_pda_keys_match(receipt_account.key(), &expected_receipt_pda)?;
-
-        // Verify Rate account with optimized derive_pda
-        let rate = Rate::from_account_info(rate_account)?;
-        let expected_rate_pda = rate.derive_pda(action_id, mint_from_key, mint_to_key)?;
-        verify_pda_keys_match(rate_account.key(), &expected_rate_pda)?;
-
-        let mint_from = Mint::from_account_info(mint_from_account)?;
-        let mint_from_decimals = mint_from.decimals();
-        drop(mint_from);
-
-        let mint_to = Mint::from_account_info(mint_to_account)?;
-        let mint_to_decimals = mint_to.decimals();
-        drop(mint_to);
+        verify_transfer_hook_program(transfer_hook_program)?;
 
-        let token_from = TokenAccount::from_account_info(token_account_from)?;
-        let current_amount = token_from.amount();
+        let paused = get_extension_from_bytes::<Pausable>(&mint_info.try_borrow_data()?)
+            .is_some_and(|pausable| pausable.paused != 0);
+        if paused {
+            return Err(SecurityTokenError::MintPaused.into());
+        }
 
-        // Split should be used for the same mints instead
-        if token_from.mint().ne(mint_from_key) {
-            return Err(ProgramError::InvalidInstructionData);
+        let to_token = TokenAccount::from_account_info(to_token_account)?;
+        if to_token.mint() != mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
         }
-        if current_amount == 0 || current_amount < amount_to_convert {
-            return Err(ProgramError::InsufficientFunds);
+        if to_token.is_frozen() {
+            return Err(SecurityTokenError::AccountFrozen.into());
         }
-        drop(token_from);
+        drop(to_token);
 
-        let token_to = TokenAccount::from_account_info(token_account_to)?;
-        if token_to.mint().ne(mint_to_key) {
-            return Err(ProgramError::InvalidInstructionData);
+        let from_token = TokenAccount::from_account_info(from_token_account)?;
+        if from_token.mint() != mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
         }
-        drop(token_to);
-
-        // Mint authority should be for mint_to as we are minting new tokens at conversion rate
-        let mint_authority_state = MintAuthority::from_account_info(mint_authority)?;
-        if mint_to_key.ne(&mint_authority_state.mint) {
-            return Err(ProgramError::InvalidInstructionData);
+        if from_token.is_frozen() {
+            return Err(SecurityTokenError::AccountFrozen.into());
         }
-
-        let amount_to_mint =
-            rate.convert_from_to_amount(amount_to_convert, mint_from_decimals, mint_to_decimals)?;
-
-        if amount_to_mint.eq(&0) {
-            // Conversion of small amounts or big rate delta can result in zero output when Rounding::Down is used
-            return Err(ProgramError::InvalidInstructionData);
+        let remaining_amount = from_token
+            .amount()
+            .checked_sub(amount)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        drop(from_token);
+
+        let locked_amount =
+            Self::locked_amount(program_id, mint_info, from_token_account, lockup_accounts)?;
+        if remaining_amount < locked_amount {
+            return Err(SecurityTokenError::HoldingPeriodNotElapsed.into());
         }
-
-        // Burn tokens from source
-        burn_checked(
-            amount_to_convert,
-            mint_from_decimals,
-            mint_from_account,
-            token_account_from,
-            permanent_delegate,
-            permanent_delegate_bump,
-        )?;
-
-        // Mint tokens to target
-        mint_to_checked(
-            amount_to_mint,
-            mint_to_decimals,
-            mint_to_account,
-            token_account_to,
-            mint_authority,
-            &mint_authority_state,
-        )?;
-
-        // Create Receipt PDA account for Convert operation
-        let action_id_seed = action_id.to_le_bytes();
-        let bump_seed = [receipt_bump];
-        let seeds = Receipt::common_action_seeds(verified_mint_key, &action_id_seed, &bump_seed);
-        Receipt::issue(receipt_account, payer, &seeds)?;
-
         Ok(())
     }
 
-    /// Execute proof account creation
-    pub fn execute_create_proof_account(
+    /// Return the balance, frozen and suspended state and locked amount of a token account.
+    /// The locked amount is read with the optional holding period accounts.
+    pub fn execute_get_holder_status(
         program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
-        action_id: u64,
-        proof_data: ProofData,
     ) -> ProgramResult {
-        let [payer, mint_account, proof_account, token_account, system_program_info] = accounts
-        else {
+        let [mint_info, token_account, suspension_account, lockup_accounts @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        verify_mint_keys_match(verified_mint_info, &mint_account)?;
-
-        verify_system_program(system_program_info)?;
-        verify_writable(payer)?;
-        verify_writable(proof_account)?;
-        verify_signer(payer)?;
-        verify_account_not_initialized(proof_account)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
 
         let token = TokenAccount::from_account_info(token_account)?;
-        // Verify token account belongs to the mint
-        let token_account_key = token_account.key();
-        if token.mint().ne(mint_account.key()) {
-            return Err(ProgramError::InvalidInstructionData);
+        if token.mint() != mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
         }
+        let (balance, frozen) = (token.amount(), token.is_frozen());
+        drop(token);
 
-        let (expected_proof_pda, bump) = find_proof_pda(token_account_key, action_id, program_id);
-        verify_pda_keys_match(proof_account.key(), &expected_proof_pda)?;
-
-        // Create Proof account
-        let proof = Proof::new(&proof_data, bump)?;
-        let action_id_seed = &action_id.to_le_bytes();
-        let bump_seed = &proof.bump_seed();
-        let seeds = proof.seeds(token_account_key, action_id_seed, bump_seed);
-        proof.init(payer, proof_account, &seeds)?;
-        proof.write_data(proof_account)?;
+        let (expected_suspension_pda, _bump) = find_suspension_pda(token_account.key(), program_id);
+        verify_pda_keys_match(suspension_account.key(), &expected_suspension_pda)?;
+        let suspended =
+            suspension_account.is_owned_by(program_id) && !suspension_account.data_is_empty();
 
+        HolderStatus {
+            balance,
+            frozen,
+            suspended,
+            locked_amount: Self::locked_amount(
+                program_id,
+                mint_info,
+                token_account,
+                lockup_accounts,
+            )?,
+        }
+        .set_return_data();
         Ok(())
     }
 
-    /// Execute proof account update
-    pub fn execute_update_proof_account(
-        _program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
+    /// Return the ratio the Rate of `action_id` converts at, read from its oracle when set.
+    /// Oracle prices outside of the configured bounds fail as they would fail a conversion.
+    pub fn execute_get_effective_rate(
+        program_id: &Pubkey,
         accounts: &[AccountInfo],
         action_id: u64,
-        proof_node: ProofNode,
-        offset: u32,
     ) -> ProgramResult {
-        let [payer, mint_account, proof_account, token_account, system_program_info] = accounts
-        else {
+        let [mint_from, mint_to, rate_account, oracle_accounts @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        verify_mint_keys_match(verified_mint_info, &mint_account)?;
+        verify_owner(rate_account, program_id)?;
+        verify_account_initialized(rate_account)?;
 
-        verify_system_program(system_program_info)?;
-        verify_signer(payer)?;
-        verify_writable(payer)?;
-        verify_writable(proof_account)?;
-        verify_account_initialized(proof_account)?;
-
-        let token = TokenAccount::from_account_info(token_account)?;
-        // Verify token account belongs to the mint
-        let token_account_key = token_account.key();
-        if token.mint().ne(mint_account.key()) {
-            return Err(ProgramError::InvalidInstructionData);
-        }
-
-        let mut proof = Proof::from_account_info(proof_account)?;
-        let expected_proof_pda = proof.derive_pda(token_account_key, action_id)?;
-        verify_pda_keys_match(proof_account.key(), &expected_proof_pda)?;
-
-        // Update Proof account
-        let current_proof_account_len = proof_account.data_len();
-        proof.update_data_at_offset(proof_node, offset as usize)?;
-        let new_proof_account_len = proof.serialized_len();
-        // Update account size and pay rent difference
-        if new_proof_account_len != current_proof_account_len {
-            Proof::resize_account_and_rent(proof_account, new_proof_account_len, payer)?;
-        }
-        proof.write_data(proof_account)?;
+        let rate = Rate::from_account_info(rate_account)?;
+        let expected_rate_pda = rate.derive_pda(action_id, mint_from.key(), mint_to.key())?;
+        verify_pda_keys_match(rate_account.key(), &expected_rate_pda)?;
 
+        let effective_rate = if rate.has_oracle() {
+            let oracle = oracle_accounts
+                .first()
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            if oracle.key() != &rate.oracle {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            let oracle_value = rate.oracle_value(&oracle.try_borrow_data()?)?;
+            if oracle_value < rate.oracle_min_value || oracle_value > rate.oracle_max_value {
+                return Err(SecurityTokenError::OracleValueOutOfBounds.into());
+            }
+            EffectiveRate {
+                rounding: rate.rounding,
+                numerator: oracle_value,
+                denominator: 10u64
+                    .checked_pow(rate.oracle_value_decimals as u32)
+                    .ok_or(ProgramError::ArithmeticOverflow)?,
+                from_oracle: true,
+            }
+        } else {
+            EffectiveRate {
+                rounding: rate.rounding,
+                numerator: rate.numerator as u64,
+                denominator: rate.denominator as u64,
+                from_oracle: false,
+            }
+        };
+        effective_rate.set_return_data();
         Ok(())
     }
 
-    /// Create escrow for distributions
-    pub fn execute_create_distribution_escrow(
-        _program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
+    /// Return the claim window, amendment status and escrow balance of a distribution merkle
+    /// root and whether it can be claimed now. The escrow is omitted for external settlement.
+    pub fn execute_get_distribution_status(
+        program_id: &Pubkey,
         accounts: &[AccountInfo],
         action_id: u64,
         merkle_root: &MerkleTreeRoot,
     ) -> ProgramResult {
-        let [distribution_escrow_authority, payer, distribution_token_account, distribution_mint, token_program, associated_token_account_program, system_program] =
+        let [mint_info, claim_deadline_account, distribution_root_account, escrow_accounts @ ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        // Verify mint is valid
-        verify_mint_keys_match(verified_mint_info, &distribution_mint)?;
-        // Verify programs
-        verify_token22_program(token_program)?;
-        verify_associated_token_program(associated_token_account_program)?;
-        verify_system_program(system_program)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
 
-        verify_writable(distribution_token_account)?;
-        verify_writable(payer)?;
-        verify_signer(payer)?;
+        let claim_deadline =
+            Self::load_claim_deadline(program_id, mint_info, action_id, claim_deadline_account)?;
+        let distribution_root = Self::load_distribution_root(
+            program_id,
+            mint_info,
+            action_id,
+            merkle_root,
+            distribution_root_account,
+        )?;
 
-        verify_account_not_initialized(distribution_token_account)?;
+        let timestamp = Clock::get()?.unix_timestamp;
+        let claimable = claim_deadline
+            .as_ref()
+            .is_none_or(|claim_deadline| claim_deadline.verify_claimable(timestamp).is_ok())
+            && distribution_root
+                .as_ref()
+                .is_none_or(|distribution_root| distribution_root.verify_claimable().is_ok());
+
+        // Clients fill omitted optional accounts with the program id
+        let escrow_balance = match escrow_accounts.first() {
+            Some(escrow_token_account) if escrow_token_account.key() != program_id => {
+                let (distribution_escrow_authority, _bump) = find_distribution_escrow_authority_pda(
+                    mint_info.key(),
+                    action_id,
+                    merkle_root,
+                    program_id,
+                );
+                let (expected_escrow_ata, _ata_bump) = find_associated_token_address(
+                    &distribution_escrow_authority,
+                    mint_info.key(),
+                    &pinocchio_token_2022::ID,
+                );
+                verify_pda_keys_match(escrow_token_account.key(), &expected_escrow_ata)?;
+                Some(TokenAccount::from_account_info(escrow_token_account)?.amount())
+            }
+            _ => None,
+        };
 
-        let mint_pubkey = distribution_mint.key();
-        let (distribution_escrow_authority_pda, _) =
-            DistributionEscrowAuthority::find_pda(mint_pubkey, action_id, merkle_root);
-        verify_pda_keys_match(
-            distribution_escrow_authority.key(),
-            &distribution_escrow_authority_pda,
-        )?;
+        DistributionStatus {
+            claims_open_at: claim_deadline
+                .as_ref()
+                .map(|claim_deadline| claim_deadline.claims_open_at),
+            claim_deadline: claim_deadline
+                .as_ref()
+                .map(|claim_deadline| claim_deadline.claim_deadline),
+            root_status: distribution_root.map(|distribution_root| distribution_root.status),
+            claimable,
+            escrow_balance,
+        }
+        .set_return_data();
+        Ok(())
+    }
 
-        let (expected_ata, _) = find_associated_token_address(
-            &distribution_escrow_authority_pda,
-            mint_pubkey,
-            token_program.key(),
+    /// Recompute the invariants of the mint over a sample of its token accounts, vesting grants,
+    /// pending transfers and sub-account ledgers, and log the outcome as an AuditEvent.
+    /// Failed invariants are reported in the event, also set as return data for programs invoking
+    /// the audit by CPI; the audit itself succeeds.
+    pub fn execute_audit_mint(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let [mint_info, sample_accounts @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let supply = Mint::from_account_info(mint_info)?.supply();
+        let (escrow_authority, _bump) =
+            find_transfer_escrow_authority_pda(mint_info.key(), program_id);
+        let (escrow_token_account, _ata_bump) = find_associated_token_address(
+            &escrow_authority,
+            mint_info.key(),
+            &pinocchio_token_2022::ID,
         );
-        verify_pda_keys_match(distribution_token_account.key(), &expected_ata)?;
 
-        CreateTokenAccount {
-            funding_account: payer,
-            account: distribution_token_account,
-            wallet: distribution_escrow_authority,
-            mint: distribution_mint,
-            system_program,
-            token_program,
+        let mut sampled_balance: u64 = 0;
+        let mut holder_count: u32 = 0;
+        let mut escrow_balance: Option<u64> = None;
+        let mut escrow_entitlements: u64 = 0;
+        let mut token_balances: Vec<(Pubkey, u64)> = Vec::new();
+        let mut ledger_totals: Vec<(Pubkey, u64)> = Vec::new();
+
+        for (index, account) in sample_accounts.iter().enumerate() {
+            // Counting an account twice would skew the sampled totals
+            if account.key() == mint_info.key()
+                || sample_accounts[..index]
+                    .iter()
+                    .any(|sampled| sampled.key() == account.key())
+            {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            if account.is_owned_by(&pinocchio_token_2022::ID) {
+                let token = TokenAccount::from_account_info(account)?;
+                if token.mint() != mint_info.key() {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                let amount = token.amount();
+                sampled_balance = sampled_balance.saturating_add(amount);
+                if amount > 0 {
+                    holder_count += 1;
+                }
+                if account.key() == &escrow_token_account {
+                    escrow_balance = Some(amount);
+                }
+                token_balances.push((*account.key(), amount));
+                continue;
+            }
+
+            verify_owner(account, program_id)?;
+            let discriminator = account
+                .try_borrow_data()?
+                .first()
+                .copied()
+                .and_then(SecurityTokenDiscriminators::from_u8)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            match discriminator {
+                SecurityTokenDiscriminators::VestingDiscriminator => {
+                    let vesting = Vesting::from_account_info(account)?;
+                    if vesting.mint != *mint_info.key() {
+                        return Err(ProgramError::InvalidAccountData);
+                    }
+                    verify_pda_keys_match(account.key(), &vesting.derive_pda()?)?;
+                    escrow_entitlements = escrow_entitlements.saturating_add(
+                        vesting.total_amount.saturating_sub(vesting.released_amount),
+                    );
+                }
+                SecurityTokenDiscriminators::PendingTransferDiscriminator => {
+                    let pending_transfer = PendingTransfer::from_account_info(account)?;
+                    if pending_transfer.mint != *mint_info.key() {
+                        return Err(ProgramError::InvalidAccountData);
+                    }
+                    verify_pda_keys_match(account.key(), &pending_transfer.derive_pda()?)?;
+                    escrow_entitlements =
+                        escrow_entitlements.saturating_add(pending_transfer.amount);
+                }
+                SecurityTokenDiscriminators::SubAccountLedgerDiscriminator => {
+                    let ledger = SubAccountLedger::from_account_info(account)?;
+                    if ledger.mint != *mint_info.key() {
+                        return Err(ProgramError::InvalidAccountData);
+                    }
+                    verify_pda_keys_match(account.key(), &ledger.derive_pda()?)?;
+                    ledger_totals.push((ledger.token_account, ledger.total_balance));
+                }
+                _ => return Err(ProgramError::InvalidAccountData),
+            }
+        }
+
+        // Entitlements and ledgers can only be checked against their sampled token accounts
+        let escrow_balance = match escrow_balance {
+            Some(balance) => balance,
+            None if escrow_entitlements == 0 => 0,
+            None => return Err(ProgramError::NotEnoughAccountKeys),
+        };
+        let mut failed_invariants = 0;
+        if sampled_balance > supply {
+            failed_invariants |= audit_invariants::SUPPLY;
+        }
+        if escrow_balance < escrow_entitlements {
+            failed_invariants |= audit_invariants::ESCROW;
+        }
+        for (omnibus_token_account, total_balance) in ledger_totals {
+            let (_, omnibus_balance) = token_balances
+                .iter()
+                .find(|(key, _)| *key == omnibus_token_account)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            if *omnibus_balance < total_balance {
+                failed_invariants |= audit_invariants::SUB_ACCOUNTS;
+            }
         }
-        .invoke()?;
 
+        let event = AuditEvent {
+            mint: *mint_info.key(),
+            slot: Clock::get()?.slot,
+            supply,
+            sampled_balance,
+            holder_count,
+            escrow_balance,
+            escrow_entitlements,
+            failed_invariants,
+        };
+        event.emit();
+        set_return_data(&event.to_bytes());
         Ok(())
     }
 
-    /// Claim distribution (dividends/coupons)
-    #[allow(clippy::too_many_arguments)]
-    pub fn execute_claim_distribution(
+    /// Attest the free balance of a token account for collateral: its balance less the locked
+    /// amount of its restricted lots, `0` when the token account is frozen or suspended or
+    /// the mint is paused. Creates the CollateralAttestation of the token account or refreshes
+    /// it, and returns its account data. The locked amount is read with the optional holding
+    /// period accounts.
+    pub fn execute_attest_collateral(
         program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
-        amount: u64,
-        action_id: u64,
-        merkle_root: &MerkleTreeRoot,
-        leaf_index: u32,
-        merkle_proof: Option<ProofData>,
     ) -> ProgramResult {
-        let [permanent_delegate_authority, payer, mint_account, eligible_token_account, escrow_token_account, receipt_account, proof_account, transfer_hook_program, token_program, system_program] =
+        let [payer, mint_info, token_account, suspension_account, attestation_account, system_program_info, lockup_accounts @ ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        // Verify mint
-        verify_mint_keys_match(verified_mint_info, &mint_account)?;
-
-        // Verify programs
-        verify_transfer_hook_program(transfer_hook_program)?;
-        verify_token22_program(token_program)?;
-        verify_system_program(system_program)?;
-
+        verify_system_program(system_program_info)?;
         verify_signer(payer)?;
         verify_writable(payer)?;
-        verify_writable(receipt_account)?;
+        verify_writable(attestation_account)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
 
-        // With external settlement the escrow_token_account is not provided
-        let is_external_settlement = escrow_token_account.key().eq(program_id);
-        verify_writable(eligible_token_account)?;
-        // escrow_token_account only needs writable check if it's not external settlement
-        if !is_external_settlement {
-            verify_writable(escrow_token_account)?;
+        let token = TokenAccount::from_account_info(token_account)?;
+        if token.mint() != mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
         }
+        let (owner, balance, frozen) = (*token.owner(), token.amount(), token.is_frozen());
+        drop(token);
 
-        verify_account_not_initialized(receipt_account)?;
-        // Retrieve proof data either from argument or from account and verify proof account
-        let proof = Proof::get_proof_data_from_instruction(
-            eligible_token_account.key(),
-            action_id,
-            proof_account,
-            merkle_proof,
-        )?;
-        let mint_pubkey = mint_account.key();
-        let (expected_receipt_pda, receipt_bump) = Receipt::find_claim_action_pda(
-            mint_pubkey,
-            eligible_token_account.key(),
-            action_id,
-            &proof,
-        );
-        verify_pda_keys_match(receipt_account.key(), &expected_receipt_pda)?;
-
-        // Verify claimer node belongs to merkle tree
-        let node = create_merkle_tree_leaf_node(
-            eligible_token_account.key(),
-            mint_pubkey,
-            action_id,
-            amount,
+        let (expected_suspension_pda, _bump) = find_suspension_pda(token_account.key(), program_id);
+        verify_pda_keys_match(suspension_account.key(), &expected_suspension_pda)?;
+        let suspended =
+            suspension_account.is_owned_by(program_id) && !suspension_account.data_is_empty();
+
+        let paused = get_extension_from_bytes::<Pausable>(&mint_info.try_borrow_data()?)
+            .is_some_and(|pausable| pausable.paused != 0);
+        let locked_amount =
+            Self::locked_amount(program_id, mint_info, token_account, lockup_accounts)?;
+
+        let (expected_attestation_pda, bump) =
+            find_collateral_attestation_pda(token_account.key(), program_id);
+        verify_pda_keys_match(attestation_account.key(), &expected_attestation_pda)?;
+
+        let clock = Clock::get()?;
+        let attestation = CollateralAttestation::new(
+            *mint_info.key(),
+            *token_account.key(),
+            owner,
+            balance,
+            locked_amount,
+            frozen,
+            suspended,
+            paused,
+            clock.slot,
+            clock.unix_timestamp,
+            bump,
         );
-        if !verify_merkle_proof(&node, merkle_root, &proof, leaf_index) {
-            return Err(ProgramError::InvalidInstructionData);
+        if attestation_account.data_is_empty() {
+            let bump_seed = &attestation.bump_seed();
+            let seeds = attestation.seeds(bump_seed);
+            attestation.init(payer, attestation_account, &seeds)?;
+        } else {
+            verify_owner(attestation_account, program_id)?;
+            CollateralAttestation::from_account_info(attestation_account)?;
         }
+        attestation.write_data(attestation_account)?;
 
-        // With internal settlement tokens are transferred and Receipt is issued
-        if !is_external_settlement {
-            let (distribution_escrow_authority, _bump) = find_distribution_escrow_authority_pda(
-                mint_pubkey,
-                action_id,
-                merkle_root,
-                program_id,
-            );
-            let (expected_escrow_ata, _ata_bump) = find_associated_token_address(
-                &distribution_escrow_authority,
-                mint_pubkey,
-                &pinocchio_token_2022::ID,
-            );
-            verify_pda_keys_match(escrow_token_account.key(), &expected_escrow_ata)?;
-
-            let (permanent_delegate_pda, permanent_delegate_bump) =
-                find_permanent_delegate_pda(mint_pubkey, program_id);
-            verify_pda_keys_match(permanent_delegate_authority.key(), &permanent_delegate_pda)?;
+        set_return_data(&attestation.to_bytes());
+        Ok(())
+    }
 
-            let mint = Mint::from_account_info(mint_account)?;
-            let escrow_token = TokenAccount::from_account_info(escrow_token_account)?;
-            let eligible_token = TokenAccount::from_account_info(eligible_token_account)?;
-            let decimals = mint.decimals();
+    /// Publish the proof of reserve of a custodian: the balances of its omnibus token
+    /// accounts, passed as trailing accounts, bound to the hash of its off-chain liabilities.
+    /// Republishing overwrites the previous attestation of the custodian.
+    pub fn execute_publish_reserve_attestation(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        liability_hash: [u8; 32],
+    ) -> ProgramResult {
+        let [custodian, mint_info, attestation_account, system_program_info, omnibus_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
 
-            if escrow_token.mint() != mint_pubkey || eligible_token.mint() != mint_pubkey {
-                return Err(ProgramError::InvalidAccountData);
+        verify_system_program(system_program_info)?;
+        verify_signer(custodian)?;
+        verify_writable(custodian)?;
+        verify_writable(attestation_account)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
+
+        let mut reserves: Vec<(Pubkey, u64)> = Vec::with_capacity(omnibus_accounts.len());
+        for omnibus_account in omnibus_accounts {
+            if reserves.iter().any(|(key, _)| key == omnibus_account.key()) {
+                return Err(SecurityTokenError::InvalidReserveAccount.into());
             }
-            if escrow_token.amount() < amount {
-                return Err(ProgramError::InsufficientFunds);
+            let token = TokenAccount::from_account_info(omnibus_account)?;
+            if token.mint() != mint_info.key() || token.owner() != custodian.key() {
+                return Err(SecurityTokenError::InvalidReserveAccount.into());
             }
-            drop(mint);
-            drop(escrow_token);
-            drop(eligible_token);
-
-            // Transfer tokens from distribution escrow to eligible token account
-            transfer_checked(
-                amount,
-                decimals,
-                mint_account,
-                escrow_token_account,
-                eligible_token_account,
-                transfer_hook_program,
-                permanent_delegate_authority,
-                permanent_delegate_bump,
-            )?;
+            reserves.push((*omnibus_account.key(), token.amount()));
         }
 
-        // Issue Receipt
-        let action_id_seed = action_id.to_le_bytes();
-        let bump_seed = [receipt_bump];
-        let proof_seed = Receipt::proof_seed(&proof);
-        let receipt_seeds = Receipt::claim_action_seeds(
-            mint_pubkey,
-            eligible_token_account.key(),
-            &action_id_seed,
-            &proof_seed,
-            &bump_seed,
-        );
-        Receipt::issue(receipt_account, payer, &receipt_seeds)?;
-        Ok(())
+        let (expected_attestation_pda, bump) =
+            find_reserve_attestation_pda(mint_info.key(), custodian.key(), program_id);
+        verify_pda_keys_match(attestation_account.key(), &expected_attestation_pda)?;
+
+        let clock = Clock::get()?;
+        let attestation = ReserveAttestation::new(
+            *mint_info.key(),
+            *custodian.key(),
+            liability_hash,
+            &reserves,
+            clock.slot,
+            clock.unix_timestamp,
+            bump,
+        )?;
+        if attestation_account.data_is_empty() {
+            let bump_seed = &attestation.bump_seed();
+            let seeds = attestation.seeds(bump_seed);
+            attestation.init(custodian, attestation_account, &seeds)?;
+        } else {
+            verify_owner(attestation_account, program_id)?;
+            ReserveAttestation::from_account_info(attestation_account)?;
+        }
+        attestation.write_data(attestation_account)
     }
 
-    /// Close Receipt account of operation tied to the action_id (e.g. split, convert)
-    pub fn execute_close_action_receipt_account(
-        _program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
+    /// Create the SubAccountLedger of an omnibus token account, signed by its owner.
+    /// From then on the transfer hook keeps the omnibus balance above the recorded sub-balances.
+    pub fn execute_create_sub_account_ledger(
+        program_id: &Pubkey,
         accounts: &[AccountInfo],
-        action_id: u64,
     ) -> ProgramResult {
-        let [receipt_account, destination_account, mint_account] = accounts else {
+        let [custodian, mint_info, token_account, ledger_account, system_program_info] = accounts
+        else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        verify_mint_keys_match(verified_mint_info, &mint_account)?;
-        verify_writable(destination_account)?;
-        verify_writable(receipt_account)?;
-
-        // Validate Receipt
-        verify_account_initialized(receipt_account)?;
-        // Deserialize to ensure it's valid Receipt account (checks discriminator and ownership)
-        Receipt::from_account_info(receipt_account)?;
-        let (expected_receipt_pda, _bump) =
-            Receipt::find_common_action_pda(mint_account.key(), action_id);
-        verify_pda_keys_match(receipt_account.key(), &expected_receipt_pda)?;
-
-        Receipt::close(receipt_account, destination_account)?;
-        Ok(())
+        verify_system_program(system_program_info)?;
+        verify_writable(ledger_account)?;
+        verify_account_not_initialized(ledger_account)?;
+        Self::verify_omnibus_custodian(custodian, mint_info, token_account)?;
+
+        let (expected_ledger_pda, bump) =
+            find_sub_account_ledger_pda(token_account.key(), program_id);
+        verify_pda_keys_match(ledger_account.key(), &expected_ledger_pda)?;
+
+        let ledger = SubAccountLedger::new(*mint_info.key(), *token_account.key(), bump);
+        let bump_seed = &ledger.bump_seed();
+        let seeds = ledger.seeds(bump_seed);
+        ledger.init(custodian, ledger_account, &seeds)?;
+        ledger.write_data(ledger_account)
     }
 
-    /// Close Receipt account of claim_distribution action
-    pub fn execute_close_claim_receipt_account(
-        _program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
+    /// Record `amount` held in the omnibus token account for a beneficial owner, creating
+    /// its SubAccount on the first credit. The sub-balances have to stay covered by the
+    /// omnibus balance.
+    pub fn execute_credit_sub_account(
+        program_id: &Pubkey,
         accounts: &[AccountInfo],
-        action_id: u64,
-        merkle_proof: Option<ProofData>,
+        beneficial_owner: [u8; 32],
+        amount: u64,
     ) -> ProgramResult {
-        let [receipt_account, destination_account, mint_account, eligible_token_account, proof_account] =
+        let [custodian, mint_info, token_account, ledger_account, sub_account_info, system_program_info] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        verify_mint_keys_match(verified_mint_info, &mint_account)?;
-        verify_writable(destination_account)?;
-        verify_writable(receipt_account)?;
-        verify_account_initialized(receipt_account)?;
-        // Deserialize to ensure it's valid Receipt account (checks discriminator and ownership)
-        Receipt::from_account_info(receipt_account)?;
+        verify_system_program(system_program_info)?;
+        verify_writable(ledger_account)?;
+        verify_writable(sub_account_info)?;
+        let omnibus_balance = Self::verify_omnibus_custodian(custodian, mint_info, token_account)?;
+        let mut ledger =
+            Self::load_sub_account_ledger(program_id, ledger_account, mint_info, token_account)?;
+
+        let mut sub_account = if sub_account_info.data_is_empty() {
+            let (expected_sub_account_pda, bump) =
+                find_sub_account_pda(ledger_account.key(), &beneficial_owner, program_id);
+            verify_pda_keys_match(sub_account_info.key(), &expected_sub_account_pda)?;
+            let sub_account = SubAccount::new(*ledger_account.key(), beneficial_owner, bump);
+            let bump_seed = &sub_account.bump_seed();
+            let seeds = sub_account.seeds(bump_seed);
+            sub_account.init(custodian, sub_account_info, &seeds)?;
+            ledger.sub_account_count = ledger
+                .sub_account_count
+                .checked_add(1)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            sub_account
+        } else {
+            Self::load_sub_account(sub_account_info, ledger_account, &beneficial_owner)?
+        };
 
-        // Retrieve proof data either from argument or from account. Verify proof account
-        let proof = Proof::get_proof_data_from_instruction(
-            eligible_token_account.key(),
-            action_id,
-            proof_account,
-            merkle_proof,
-        )?;
-        let (expected_receipt_pda, _bump) = Receipt::find_claim_action_pda(
-            mint_account.key(),
-            eligible_token_account.key(),
-            action_id,
-            &proof,
-        );
-        verify_pda_keys_match(receipt_account.key(), &expected_receipt_pda)?;
+        ledger.credit(amount, omnibus_balance)?;
+        sub_account.balance = sub_account
+            .balance
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        sub_account.write_data(sub_account_info)?;
+        ledger.write_data(ledger_account)
+    }
 
-        Receipt::close(receipt_account, destination_account)?;
-        Ok(())
+    /// Release `amount` of a beneficial owner, typically before transferring it out of the
+    /// omnibus token account. A sub-account debited to zero is closed, its rent goes to the
+    /// custodian.
+    pub fn execute_debit_sub_account(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        beneficial_owner: [u8; 32],
+        amount: u64,
+    ) -> ProgramResult {
+        let [custodian, mint_info, token_account, ledger_account, sub_account_info] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_writable(ledger_account)?;
+        verify_writable(sub_account_info)?;
+        Self::verify_omnibus_custodian(custodian, mint_info, token_account)?;
+        let mut ledger =
+            Self::load_sub_account_ledger(program_id, ledger_account, mint_info, token_account)?;
+        let mut sub_account =
+            Self::load_sub_account(sub_account_info, ledger_account, &beneficial_owner)?;
+
+        sub_account.balance = sub_account
+            .balance
+            .checked_sub(amount)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        ledger.debit(amount)?;
+
+        if sub_account.balance == 0 {
+            ledger.sub_account_count = ledger.sub_account_count.saturating_sub(1);
+            SubAccount::close(sub_account_info, custodian)?;
+        } else {
+            sub_account.write_data(sub_account_info)?;
+        }
+        ledger.write_data(ledger_account)
+    }
+
+    /// Close the SubAccountLedger of an omnibus token account once every sub-account is
+    /// debited, lifting the transfer hook check
+    pub fn execute_close_sub_account_ledger(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [custodian, mint_info, token_account, ledger_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_writable(ledger_account)?;
+        Self::verify_omnibus_custodian(custodian, mint_info, token_account)?;
+        let ledger =
+            Self::load_sub_account_ledger(program_id, ledger_account, mint_info, token_account)?;
+        if ledger.sub_account_count != 0 || ledger.total_balance != 0 {
+            return Err(SecurityTokenError::SubAccountLedgerNotEmpty.into());
+        }
+
+        SubAccountLedger::close(ledger_account, custodian)
+    }
+
+    /// Check `custodian` signs and owns the omnibus `token_account` of the mint, return its balance
+    fn verify_omnibus_custodian(
+        custodian: &AccountInfo,
+        mint_info: &AccountInfo,
+        token_account: &AccountInfo,
+    ) -> Result<u64, ProgramError> {
+        verify_signer(custodian)?;
+        verify_writable(custodian)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
+
+        let token = TokenAccount::from_account_info(token_account)?;
+        if token.mint() != mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if token.owner() != custodian.key() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        Ok(token.amount())
+    }
+
+    fn load_sub_account_ledger(
+        program_id: &Pubkey,
+        ledger_account: &AccountInfo,
+        mint_info: &AccountInfo,
+        token_account: &AccountInfo,
+    ) -> Result<SubAccountLedger, ProgramError> {
+        verify_owner(ledger_account, program_id)?;
+        let ledger = SubAccountLedger::from_account_info(ledger_account)?;
+        if ledger.mint != *mint_info.key() || ledger.token_account != *token_account.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(ledger_account.key(), &ledger.derive_pda()?)?;
+        Ok(ledger)
+    }
+
+    fn load_sub_account(
+        sub_account_info: &AccountInfo,
+        ledger_account: &AccountInfo,
+        beneficial_owner: &[u8; 32],
+    ) -> Result<SubAccount, ProgramError> {
+        let sub_account = SubAccount::from_account_info(sub_account_info)?;
+        if sub_account.ledger != *ledger_account.key()
+            || sub_account.beneficial_owner != *beneficial_owner
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(sub_account_info.key(), &sub_account.derive_pda()?)?;
+        Ok(sub_account)
+    }
+
+    /// Transfer tokens on behalf of the owner of the source token account, authorized by a
+    /// permit the owner signed off-chain and verified by an Ed25519 instruction of the
+    /// transaction. Anyone can submit the permit, each permit nonce is consumed once.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_transfer_with_permit(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        amount: u64,
+        nonce: u64,
+        expiry: i64,
+    ) -> ProgramResult {
+        let [payer, account_delegate_authority, mint_info, from_token_account, to_token_account, permit_nonce_account, instructions_sysvar, transfer_hook_program, token_program, system_program, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_token22_program(token_program)?;
+        verify_transfer_hook_program(transfer_hook_program)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(from_token_account)?;
+        verify_writable(to_token_account)?;
+        verify_writable(permit_nonce_account)?;
+
+        if Clock::get()?.unix_timestamp > expiry {
+            return Err(SecurityTokenError::PermitExpired.into());
+        }
+
+        let from_token = TokenAccount::from_account_info(from_token_account)?;
+        if from_token.mint() != mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let holder = *from_token.owner();
+        drop(from_token);
+
+        let message = transfer_permit_message(
+            mint_info.key(),
+            from_token_account.key(),
+            to_token_account.key(),
+            amount,
+            nonce,
+            expiry,
+        );
+        verify_ed25519_permit(instructions_sysvar, &holder, &message)?;
+
+        let (expected_permit_nonce_pda, bump) =
+            find_permit_nonce_pda(from_token_account.key(), program_id);
+        verify_pda_keys_match(permit_nonce_account.key(), &expected_permit_nonce_pda)?;
+
+        if permit_nonce_account.data_is_empty() {
+            let mut permit_nonce =
+                PermitNonce::new(*mint_info.key(), *from_token_account.key(), bump);
+            permit_nonce.consume(nonce)?;
+            let bump_seed = &permit_nonce.bump_seed();
+            let seeds = permit_nonce.seeds(bump_seed);
+            permit_nonce.init(payer, permit_nonce_account, &seeds)?;
+            permit_nonce.write_data(permit_nonce_account)?;
+        } else {
+            verify_owner(permit_nonce_account, program_id)?;
+            let mut permit_nonce = PermitNonce::from_account_info(permit_nonce_account)?;
+            if permit_nonce.mint != *mint_info.key() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            permit_nonce.consume(nonce)?;
+            permit_nonce.write_data(permit_nonce_account)?;
+        }
+
+        // The holder approves the account delegate PDA, so the hook sees a delegated transfer
+        // and enforces every transfer rule, unlike for the permanent delegate
+        let (account_delegate_pda, account_delegate_bump) =
+            find_account_delegate_pda(from_token_account.key(), program_id);
+        verify_pda_keys_match(account_delegate_authority.key(), &account_delegate_pda)?;
+
+        let (account_metas_pda, _) = find_extra_account_metas_pda(mint_info.key());
+        if transfer_hook_accounts.first().map(AccountInfo::key) != Some(&account_metas_pda) {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        let decimals = Mint::from_account_info(mint_info)?.decimals();
+        transfer_checked_as_account_delegate(
+            amount,
+            decimals,
+            mint_info,
+            from_token_account,
+            to_token_account,
+            transfer_hook_program,
+            account_delegate_authority,
+            account_delegate_bump,
+            transfer_hook_accounts,
+        )
+        .map_err(token_cpi_error(TokenCpiStage::TransferWithPermit))?;
+        // The transfer hook logs the TransferEvent of the delegated transfer
+        Ok(())
+    }
+
+    /// Sweep the whole balance of a misdelivered or flagged token account to a recovery ATA
+    /// through the permanent delegate, documenting the sweep in a RecoveryReceipt.
+    /// The source is recoverable when its owner is a program or a program account that is not
+    /// a Security Token PDA, or when the source is suspended.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_recover_tokens(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        recovery_id: u64,
+    ) -> ProgramResult {
+        let [payer, permanent_delegate_authority, mint_info, source_token_account, source_owner, recovery_token_account, suspension_account, recovery_receipt_account, transfer_hook_program, token_program, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_token22_program(token_program)?;
+        verify_transfer_hook_program(transfer_hook_program)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(source_token_account)?;
+        verify_writable(recovery_token_account)?;
+        verify_writable(recovery_receipt_account)?;
+        verify_account_not_initialized(recovery_receipt_account)?;
+
+        let source = TokenAccount::from_account_info(source_token_account)?;
+        if source.mint() != mint_info.key() || source.owner() != source_owner.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let amount = source.amount();
+        drop(source);
+        if amount == 0 {
+            return Err(ProgramError::InsufficientFunds);
+        }
+
+        // The recovery destination must be an associated token account of its owner
+        let recovery = TokenAccount::from_account_info(recovery_token_account)?;
+        if recovery.mint() != mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let (expected_recovery_token_account, _) = find_associated_token_address(
+            recovery.owner(),
+            mint_info.key(),
+            &pinocchio_token_2022::ID,
+        );
+        drop(recovery);
+        verify_pda_keys_match(
+            recovery_token_account.key(),
+            &expected_recovery_token_account,
+        )?;
+        if recovery_token_account.key() == source_token_account.key() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let (expected_suspension_pda, _) =
+            find_suspension_pda(source_token_account.key(), program_id);
+        verify_pda_keys_match(suspension_account.key(), &expected_suspension_pda)?;
+
+        let reason = if !suspension_account.data_is_empty()
+            && Suspension::from_account_info(suspension_account)?.mint == *mint_info.key()
+        {
+            RecoveryReason::Suspended
+        } else if source_owner.executable()
+            || !(source_owner.is_owned_by(&pinocchio_system::ID)
+                || source_owner.is_owned_by(program_id))
+        {
+            RecoveryReason::ProgramOwner
+        } else {
+            return Err(SecurityTokenError::AccountNotRecoverable.into());
+        };
+
+        let (expected_receipt_pda, bump) =
+            find_recovery_receipt_pda(mint_info.key(), recovery_id, program_id);
+        verify_pda_keys_match(recovery_receipt_account.key(), &expected_receipt_pda)?;
+
+        let (permanent_delegate_pda, permanent_delegate_bump) =
+            find_permanent_delegate_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(permanent_delegate_authority.key(), &permanent_delegate_pda)?;
+
+        let decimals = Mint::from_account_info(mint_info)?.decimals();
+        transfer_checked(
+            amount,
+            decimals,
+            mint_info,
+            source_token_account,
+            recovery_token_account,
+            transfer_hook_program,
+            permanent_delegate_authority,
+            permanent_delegate_bump,
+        )
+        .map_err(token_cpi_error(TokenCpiStage::RecoverTokens))?;
+
+        let receipt = RecoveryReceipt::new(
+            *mint_info.key(),
+            *source_token_account.key(),
+            *source_owner.key(),
+            *recovery_token_account.key(),
+            amount,
+            reason,
+            Clock::get()?.unix_timestamp,
+            recovery_id,
+            bump,
+        );
+        let recovery_id_seed = &receipt.recovery_id_seed();
+        let bump_seed = &receipt.bump_seed();
+        let seeds = receipt.seeds(recovery_id_seed, bump_seed);
+        receipt.init(payer, recovery_receipt_account, &seeds)?;
+        receipt.write_data(recovery_receipt_account)?;
+
+        TransferEvent {
+            mint: *mint_info.key(),
+            from: *source_token_account.key(),
+            to: *recovery_token_account.key(),
+            amount,
+            memo_hash: None,
+        }
+        .emit();
+        Ok(())
+    }
+
+    /// Record `amount` acquired by `token_account` as a restricted lot locked for the mint
+    /// holding period. Nothing is recorded without lot accounts, the tokens are unrestricted.
+    ///
+    /// Lot accounts: payer, HoldingPeriod, RestrictedHolding of `token_account`, next HoldingLot
+    /// and system program. The RestrictedHolding is created with the first lot.
+    fn record_holding_lot(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        token_account: &AccountInfo,
+        amount: u64,
+        lot_accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        // Clients fill omitted optional accounts with the program id
+        match lot_accounts.first() {
+            None => return Ok(()),
+            Some(payer) if payer.key() == program_id => return Ok(()),
+            Some(_) => {}
+        }
+        let [payer, holding_period_account, restricted_holding_account, holding_lot_account, system_program_info] =
+            lot_accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(restricted_holding_account)?;
+        verify_writable(holding_lot_account)?;
+        verify_account_not_initialized(holding_lot_account)?;
+
+        if amount == 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let holding_period =
+            Self::load_holding_period(program_id, holding_period_account, mint_info)?;
+
+        let mut restricted_holding = if restricted_holding_account.data_is_empty() {
+            let (expected_restricted_holding_pda, bump) =
+                find_restricted_holding_pda(token_account.key(), program_id);
+            verify_pda_keys_match(
+                restricted_holding_account.key(),
+                &expected_restricted_holding_pda,
+            )?;
+            let restricted_holding =
+                RestrictedHolding::new(*mint_info.key(), *token_account.key(), bump);
+            let bump_seed = &restricted_holding.bump_seed();
+            let seeds = restricted_holding.seeds(bump_seed);
+            restricted_holding.init(payer, restricted_holding_account, &seeds)?;
+            restricted_holding
+        } else {
+            Self::load_restricted_holding(program_id, restricted_holding_account, token_account)?
+        };
+        if restricted_holding.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let lot_id = restricted_holding.add_lot(amount)?;
+        let (expected_holding_lot_pda, bump) =
+            find_holding_lot_pda(token_account.key(), lot_id, program_id);
+        verify_pda_keys_match(holding_lot_account.key(), &expected_holding_lot_pda)?;
+
+        let acquired_timestamp = Clock::get()?.unix_timestamp;
+        let holding_lot = HoldingLot::new(
+            *token_account.key(),
+            *payer.key(),
+            lot_id,
+            amount,
+            acquired_timestamp,
+            holding_period.unlock_timestamp(acquired_timestamp)?,
+            bump,
+        );
+        let lot_id_seed = &holding_lot.lot_id_seed();
+        let bump_seed = &holding_lot.bump_seed();
+        let seeds = holding_lot.seeds(lot_id_seed, bump_seed);
+        holding_lot.init(payer, holding_lot_account, &seeds)?;
+        holding_lot.write_data(holding_lot_account)?;
+
+        restricted_holding.write_data(restricted_holding_account)?;
+        Ok(())
+    }
+
+    /// Create Rate account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_rate_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+        numerator: u8,
+        denominator: u8,
+        rounding: u8,
+    ) -> ProgramResult {
+        let [payer, rate_account, mint_from_account, mint_to_account, system_program_info] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Ensure Rate account is being created for target mint_to account
+        // For Split operation mint_from == mint_to
+        // For Convert operation mint_to is verified so we ensure correct minting of new tokens
+        verify_mint_keys_match(verified_mint_info, &mint_to_account)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(rate_account)?;
+        verify_account_not_initialized(rate_account)?;
+
+        let mint_from_key = mint_from_account.key();
+        let mint_to_key = mint_to_account.key();
+
+        let (expected_rate_pda, bump) =
+            find_rate_pda(action_id, mint_from_key, mint_to_key, program_id);
+
+        verify_pda_keys_match(rate_account.key(), &expected_rate_pda)?;
+
+        // Calculate rent and create Rate account
+        let rounding_enum = Rounding::try_from(rounding)?;
+        let rate = Rate::new(rounding_enum, numerator, denominator, bump)?;
+        let action_id_seed = &action_id.to_le_bytes();
+        let bump_seed = &rate.bump_seed();
+        let seeds = rate.seeds(action_id_seed, mint_from_key, mint_to_key, bump_seed);
+        rate.init(payer, rate_account, &seeds)?;
+        rate.write_data(rate_account)?;
+        Ok(())
+    }
+
+    /// Update Rate account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_update_rate_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+        numerator: u8,
+        denominator: u8,
+        rounding: u8,
+    ) -> ProgramResult {
+        let [rate_account_info, mint_from_account, mint_to_info_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // For Split operation mint_from == mint_to
+        // If Rate was created for Convert operation, then mint_to should be verified
+        verify_mint_keys_match(verified_mint_info, &mint_to_info_account)?;
+
+        verify_writable(rate_account_info)?;
+        verify_owner(rate_account_info, program_id)?;
+        verify_account_initialized(rate_account_info)?;
+
+        let mint_from_key = mint_from_account.key();
+        let mint_to_key = mint_to_info_account.key();
+
+        let mut rate_account = Rate::from_account_info(rate_account_info)?;
+        let expected_rate_pda = rate_account.derive_pda(action_id, mint_from_key, mint_to_key)?;
+        verify_pda_keys_match(rate_account_info.key(), &expected_rate_pda)?;
+
+        let rounding_enum = Rounding::try_from(rounding)?;
+        rate_account.update(rounding_enum, numerator, denominator)?;
+        rate_account.write_data(rate_account_info)?;
+        Ok(())
+    }
+
+    /// Close Rate account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_close_rate_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+    ) -> ProgramResult {
+        let [rate_account_info, rent_recipient, mint_from_account, mint_to_info_account] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // For Split operation mint_from == mint_to
+        // If Rate was created for Convert operation, then mint_to should be verified
+        verify_mint_keys_match(verified_mint_info, &mint_to_info_account)?;
+
+        verify_rent_recipient(rent_recipient, rate_account_info)?;
+        verify_writable(rate_account_info)?;
+        verify_owner(rate_account_info, program_id)?;
+        verify_account_initialized(rate_account_info)?;
+
+        let mint_from_key = mint_from_account.key();
+        let mint_to_key = mint_to_info_account.key();
+
+        // Deserialize to ensure it's valid Rate account, verify PDA, then close
+        let rate = Rate::from_account_info(rate_account_info)?;
+        let expected_rate_pda = rate.derive_pda(action_id, mint_from_key, mint_to_key)?;
+        verify_pda_keys_match(rate_account_info.key(), &expected_rate_pda)?;
+
+        Rate::close(rate_account_info, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Price Rate account from an oracle instead of its static numerator / denominator
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_set_rate_oracle(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args: &SetRateOracleArgs,
+    ) -> ProgramResult {
+        let [rate_account_info, mint_from_account, mint_to_info_account, oracle] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_to_info_account)?;
+
+        let mint_from_key = mint_from_account.key();
+        let mint_to_key = mint_to_info_account.key();
+        // Split rates keep their static ratio
+        if mint_from_key == mint_to_key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        verify_writable(rate_account_info)?;
+        verify_owner(rate_account_info, program_id)?;
+        verify_account_initialized(rate_account_info)?;
+
+        let mut rate_account = Rate::from_account_info(rate_account_info)?;
+        let expected_rate_pda =
+            rate_account.derive_pda(args.action_id, mint_from_key, mint_to_key)?;
+        verify_pda_keys_match(rate_account_info.key(), &expected_rate_pda)?;
+
+        rate_account.set_oracle(
+            *oracle.key(),
+            args.value_offset,
+            args.value_decimals,
+            args.min_value,
+            args.max_value,
+        )?;
+        rate_account.write_data(rate_account_info)?;
+        Ok(())
+    }
+
+    /// Return Rate account to its static numerator / denominator
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_remove_rate_oracle(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+    ) -> ProgramResult {
+        let [rate_account_info, mint_from_account, mint_to_info_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_to_info_account)?;
+
+        verify_writable(rate_account_info)?;
+        verify_owner(rate_account_info, program_id)?;
+        verify_account_initialized(rate_account_info)?;
+
+        let mut rate_account = Rate::from_account_info(rate_account_info)?;
+        let expected_rate_pda = rate_account.derive_pda(
+            action_id,
+            mint_from_account.key(),
+            mint_to_info_account.key(),
+        )?;
+        verify_pda_keys_match(rate_account_info.key(), &expected_rate_pda)?;
+
+        rate_account.remove_oracle();
+        rate_account.write_data(rate_account_info)?;
+        Ok(())
+    }
+
+    /// Execute token split at predefined rate
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_split(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+    ) -> ProgramResult {
+        let [mint_authority, permanent_delegate, payer, mint_account, token_account, rate_account, receipt_account, token_program, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_account)?;
+
+        verify_token22_program(token_program)?;
+        verify_system_program(system_program)?;
+        verify_signer(payer)?;
+        verify_writable(token_account)?;
+        verify_writable(receipt_account)?;
+        verify_writable(payer)?;
+        verify_writable(mint_account)?;
+        verify_owner(mint_authority, program_id)?;
+        verify_owner(rate_account, program_id)?;
+        verify_account_not_initialized(receipt_account)?;
+        verify_account_initialized(rate_account)?;
+
+        let mint_split_key = mint_account.key();
+
+        let (permanent_delegate_pda, permanent_delegate_bump) =
+            find_permanent_delegate_pda(mint_split_key, program_id);
+        verify_pda_keys_match(permanent_delegate.key(), &permanent_delegate_pda)?;
+
+        let (expected_receipt_pda, receipt_bump) =
+            Receipt::find_common_action_pda(mint_split_key, action_id);
+        verify_pda_keys_match(receipt_account.key(), &expected_receipt_pda)?;
+
+        // Verify Rate account with optimized derive_pda
+        let rate = Rate::from_account_info(rate_account)?;
+        let expected_rate_pda = rate.derive_pda(action_id, mint_split_key, mint_split_key)?;
+        verify_pda_keys_match(rate_account.key(), &expected_rate_pda)?;
+
+        let mint_split = Mint::from_account_info(mint_account)?;
+        let mint_decimals = mint_split.decimals();
+        drop(mint_split);
+
+        let mint_authority_state = MintAuthority::from_account_info(mint_authority)?;
+        if mint_split_key.ne(&mint_authority_state.mint) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let token = TokenAccount::from_account_info(token_account)?;
+        let current_amount = token.amount();
+        if token.mint().ne(mint_split_key) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        if current_amount == 0 {
+            return Err(ProgramError::InsufficientFunds);
+        }
+        drop(token);
+
+        let new_amount = rate.calculate(current_amount)?;
+
+        match new_amount.cmp(&current_amount) {
+            Ordering::Equal => {
+                // Just log the message but create Receipt to prevent duplicate split attempts
+                debug_log!("No change in amount after split");
+            }
+            Ordering::Greater => {
+                // Mint additional tokens
+                let amount_diff = new_amount
+                    .checked_sub(current_amount)
+                    .ok_or(ProgramError::ArithmeticOverflow)?;
+                mint_to_checked(
+                    amount_diff,
+                    mint_decimals,
+                    mint_account,
+                    token_account,
+                    mint_authority,
+                    &mint_authority_state,
+                )
+                .map_err(token_cpi_error(TokenCpiStage::SplitMint))?;
+            }
+            Ordering::Less => {
+                // Burn excess tokens
+                let amount_diff = current_amount
+                    .checked_sub(new_amount)
+                    .ok_or(ProgramError::ArithmeticOverflow)?;
+                burn_checked(
+                    amount_diff,
+                    mint_decimals,
+                    mint_account,
+                    token_account,
+                    permanent_delegate,
+                    permanent_delegate_bump,
+                )
+                .map_err(token_cpi_error(TokenCpiStage::SplitBurn))?;
+            }
+        }
+
+        // Create Receipt PDA account for Split operation
+        let action_id_seed = action_id.to_le_bytes();
+        let bump_seed = [receipt_bump];
+        let seeds = Receipt::common_action_seeds(mint_split_key, &action_id_seed, &bump_seed);
+        Receipt::issue(receipt_account, payer, &seeds)?;
+
+        Ok(())
+    }
+
+    /// Execute token conversion at predefined rate.
+    /// Oracle priced rates read the ratio from the oracle account passed after the system program.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_convert(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+        amount_to_convert: u64,
+    ) -> ProgramResult {
+        let [mint_authority, permanent_delegate, payer, mint_from_account, mint_to_account, token_account_from, token_account_to, rate_account, receipt_account, token_program, system_program, oracle_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_to_account)?;
+
+        verify_token22_program(token_program)?;
+        verify_system_program(system_program)?;
+        verify_signer(payer)?;
+        verify_writable(token_account_from)?;
+        verify_writable(token_account_to)?;
+        verify_writable(receipt_account)?;
+        verify_writable(payer)?;
+        verify_writable(mint_from_account)?;
+        verify_writable(mint_to_account)?;
+        verify_owner(rate_account, program_id)?;
+        verify_owner(mint_authority, program_id)?;
+        verify_account_not_initialized(receipt_account)?;
+        verify_account_initialized(rate_account)?;
+
+        let verified_mint_key = verified_mint_info.key();
+        let mint_from_key = mint_from_account.key();
+        let mint_to_key = mint_to_account.key();
+
+        let (permanent_delegate_pda, permanent_delegate_bump) =
+            find_permanent_delegate_pda(mint_from_key, program_id);
+        verify_pda_keys_match(permanent_delegate.key(), &permanent_delegate_pda)?;
+
+        let (expected_receipt_pda, receipt_bump) =
+            Receipt::find_common_action_pda(verified_mint_key, action_id);
+        verify_pda_keys_match(receipt_account.key(), &expected_receipt_pda)?;
+
+        // Verify Rate account with optimized derive_pda
+        let rate = Rate::from_account_info(rate_account)?;
+        let expected_rate_pda = rate.derive_pda(action_id, mint_from_key, mint_to_key)?;
+        verify_pda_keys_match(rate_account.key(), &expected_rate_pda)?;
+
+        let mint_from = Mint::from_account_info(mint_from_account)?;
+        let mint_from_decimals = mint_from.decimals();
+        drop(mint_from);
+
+        let mint_to = Mint::from_account_info(mint_to_account)?;
+        let mint_to_decimals = mint_to.decimals();
+        drop(mint_to);
+
+        let token_from = TokenAccount::from_account_info(token_account_from)?;
+        let current_amount = token_from.amount();
+
+        // Split should be used for the same mints instead
+        if token_from.mint().ne(mint_from_key) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        if current_amount == 0 || current_amount < amount_to_convert {
+            return Err(ProgramError::InsufficientFunds);
+        }
+        drop(token_from);
+
+        let token_to = TokenAccount::from_account_info(token_account_to)?;
+        if token_to.mint().ne(mint_to_key) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        drop(token_to);
+
+        // Mint authority should be for mint_to as we are minting new tokens at conversion rate
+        let mint_authority_state = MintAuthority::from_account_info(mint_authority)?;
+        if mint_to_key.ne(&mint_authority_state.mint) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let amount_to_mint = if rate.has_oracle() {
+            let oracle = oracle_accounts
+                .first()
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            if oracle.key() != &rate.oracle {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            let oracle_value = rate.oracle_value(&oracle.try_borrow_data()?)?;
+            rate.convert_from_to_amount_at_oracle_value(
+                amount_to_convert,
+                mint_from_decimals,
+                mint_to_decimals,
+                oracle_value,
+            )?
+        } else {
+            rate.convert_from_to_amount(amount_to_convert, mint_from_decimals, mint_to_decimals)?
+        };
+
+        if amount_to_mint.eq(&0) {
+            // Conversion of small amounts or big rate delta can result in zero output when Rounding::Down is used
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // Burn tokens from source
+        burn_checked(
+            amount_to_convert,
+            mint_from_decimals,
+            mint_from_account,
+            token_account_from,
+            permanent_delegate,
+            permanent_delegate_bump,
+        )
+        .map_err(token_cpi_error(TokenCpiStage::ConvertBurnLeg))?;
+
+        // Mint tokens to target
+        mint_to_checked(
+            amount_to_mint,
+            mint_to_decimals,
+            mint_to_account,
+            token_account_to,
+            mint_authority,
+            &mint_authority_state,
+        )
+        .map_err(token_cpi_error(TokenCpiStage::ConvertMintLeg))?;
+
+        // Create Receipt PDA account for Convert operation
+        let action_id_seed = action_id.to_le_bytes();
+        let bump_seed = [receipt_bump];
+        let seeds = Receipt::common_action_seeds(verified_mint_key, &action_id_seed, &bump_seed);
+        Receipt::issue(receipt_account, payer, &seeds)?;
+
+        Ok(())
+    }
+
+    /// Start the migration of `mint_from` balances to the verified mint, a new security token
+    /// mint with a different extension set. Creates the 1:1 Rate between the mints and the
+    /// MintMigration record advanced by MigrateBalances.
+    /// Both mints must be controlled by the same mint creator.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_mint_migration(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+    ) -> ProgramResult {
+        let [payer, mint_from_account, mint_to_account, mint_from_authority, mint_to_authority, rate_account, mint_migration_account, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Balances are minted into the verified mint, so its issuer starts the migration
+        verify_mint_keys_match(verified_mint_info, &mint_to_account)?;
+
+        verify_system_program(system_program)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(rate_account)?;
+        verify_writable(mint_migration_account)?;
+        verify_account_not_initialized(rate_account)?;
+        verify_account_not_initialized(mint_migration_account)?;
+
+        let mint_from_key = mint_from_account.key();
+        let mint_to_key = mint_to_account.key();
+        if mint_from_key == mint_to_key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // The permanent delegate of any security token can burn, only the issuer of both
+        // mints may move holders from one to the other
+        let from_authority = MintAuthority::from_account_info(mint_from_authority)?;
+        let to_authority = MintAuthority::from_account_info(mint_to_authority)?;
+        if from_authority.mint != *mint_from_key || to_authority.mint != *mint_to_key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if from_authority.controller != to_authority.controller {
+            return Err(SecurityTokenError::MintMigrationCreatorMismatch.into());
+        }
+        // Only the issuer of `mint_to` was verified, so a multisig on `mint_from` must control
+        // `mint_to` as well or the controller alone could burn its holders
+        if from_authority.is_multisig() && !from_authority.has_same_multisig(&to_authority) {
+            return Err(SecurityTokenError::MintMigrationCreatorMismatch.into());
+        }
+        drop(from_authority);
+        drop(to_authority);
+
+        let (expected_rate_pda, rate_bump) =
+            find_rate_pda(action_id, mint_from_key, mint_to_key, program_id);
+        verify_pda_keys_match(rate_account.key(), &expected_rate_pda)?;
+        let (expected_migration_pda, migration_bump) =
+            find_mint_migration_pda(mint_from_key, program_id);
+        verify_pda_keys_match(mint_migration_account.key(), &expected_migration_pda)?;
+
+        let rate = Rate::new(Rounding::Down, 1, 1, rate_bump)?;
+        let action_id_seed = &action_id.to_le_bytes();
+        let bump_seed = &rate.bump_seed();
+        let seeds = rate.seeds(action_id_seed, mint_from_key, mint_to_key, bump_seed);
+        rate.init(payer, rate_account, &seeds)?;
+        rate.write_data(rate_account)?;
+
+        let migration = MintMigration::new(
+            *mint_from_key,
+            *mint_to_key,
+            action_id,
+            Clock::get()?.unix_timestamp,
+            migration_bump,
+        );
+        let bump_seed = &migration.bump_seed();
+        let seeds = migration.seeds(bump_seed);
+        migration.init(payer, mint_migration_account, &seeds)?;
+        migration.write_data(mint_migration_account)?;
+
+        Ok(())
+    }
+
+    /// Migrate a batch of token accounts to the new mint of a MintMigration.
+    /// Trailing accounts are `(token_account_from, token_account_to, receipt_account)` triples:
+    /// the whole `mint_from` balance is burned through the permanent delegate, the converted
+    /// amount is minted to the same owner and a Receipt marks the token account as migrated.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_migrate_balances(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [payer, mint_authority, permanent_delegate, mint_from_account, mint_to_account, rate_account, mint_migration_account, token_program, system_program, holder_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_to_account)?;
+
+        verify_token22_program(token_program)?;
+        verify_system_program(system_program)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(mint_from_account)?;
+        verify_writable(mint_to_account)?;
+        verify_writable(mint_migration_account)?;
+        verify_owner(rate_account, program_id)?;
+        verify_owner(mint_authority, program_id)?;
+        if holder_accounts.is_empty() || holder_accounts.len() % 3 != 0 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        let mint_from_key = mint_from_account.key();
+        let mint_to_key = mint_to_account.key();
+
+        let mut migration = MintMigration::from_account_info(mint_migration_account)?;
+        let expected_migration_pda = migration.derive_pda()?;
+        verify_pda_keys_match(mint_migration_account.key(), &expected_migration_pda)?;
+        if migration.mint_from != *mint_from_key || migration.mint_to != *mint_to_key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let rate = Rate::from_account_info(rate_account)?;
+        let expected_rate_pda = rate.derive_pda(migration.action_id, mint_from_key, mint_to_key)?;
+        verify_pda_keys_match(rate_account.key(), &expected_rate_pda)?;
+        if rate.has_oracle() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (permanent_delegate_pda, permanent_delegate_bump) =
+            find_permanent_delegate_pda(mint_from_key, program_id);
+        verify_pda_keys_match(permanent_delegate.key(), &permanent_delegate_pda)?;
+
+        let mint_authority_state = MintAuthority::from_account_info(mint_authority)?;
+        if mint_to_key.ne(&mint_authority_state.mint) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mint_from_decimals = Mint::from_account_info(mint_from_account)?.decimals();
+        let mint_to_decimals = Mint::from_account_info(mint_to_account)?.decimals();
+        let action_id_seed = migration.action_id.to_le_bytes();
+
+        for holder in holder_accounts.chunks_exact(3) {
+            let [token_account_from, token_account_to, receipt_account] = holder else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            verify_writable(token_account_from)?;
+            verify_writable(token_account_to)?;
+            verify_writable(receipt_account)?;
+            verify_account_not_initialized(receipt_account)?;
+
+            let (expected_receipt_pda, receipt_bump) = Receipt::find_migration_pda(
+                mint_to_key,
+                token_account_from.key(),
+                migration.action_id,
+            );
+            verify_pda_keys_match(receipt_account.key(), &expected_receipt_pda)?;
+
+            let token_from = TokenAccount::from_account_info(token_account_from)?;
+            if token_from.mint().ne(mint_from_key) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            let amount = token_from.amount();
+            let owner = *token_from.owner();
+            drop(token_from);
+
+            // Balances stay with their holder
+            let token_to = TokenAccount::from_account_info(token_account_to)?;
+            if token_to.mint().ne(mint_to_key) || token_to.owner().ne(&owner) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            drop(token_to);
+
+            if amount > 0 {
+                let amount_to_mint =
+                    rate.convert_from_to_amount(amount, mint_from_decimals, mint_to_decimals)?;
+                burn_checked(
+                    amount,
+                    mint_from_decimals,
+                    mint_from_account,
+                    token_account_from,
+                    permanent_delegate,
+                    permanent_delegate_bump,
+                )
+                .map_err(token_cpi_error(TokenCpiStage::MigrateBurnLeg))?;
+                if amount_to_mint > 0 {
+                    mint_to_checked(
+                        amount_to_mint,
+                        mint_to_decimals,
+                        mint_to_account,
+                        token_account_to,
+                        mint_authority,
+                        &mint_authority_state,
+                    )
+                    .map_err(token_cpi_error(TokenCpiStage::MigrateMintLeg))?;
+                }
+            }
+
+            let bump_seed = [receipt_bump];
+            let seeds = Receipt::migration_seeds(
+                mint_to_key,
+                token_account_from.key(),
+                &action_id_seed,
+                &bump_seed,
+            );
+            Receipt::issue(receipt_account, payer, &seeds)?;
+
+            migration.record(amount)?;
+        }
+
+        migration.write_data(mint_migration_account)?;
+        Ok(())
+    }
+
+    /// Create the MetadataSchema of a mint, enforced by every following UpdateMetadata
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_metadata_schema(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        fields: &[u8],
+    ) -> ProgramResult {
+        let [payer, mint_info, metadata_schema_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(metadata_schema_account)?;
+        verify_account_not_initialized(metadata_schema_account)?;
+
+        let (expected_schema_pda, bump) = find_metadata_schema_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(metadata_schema_account.key(), &expected_schema_pda)?;
+
+        let schema = MetadataSchema::new(*mint_info.key(), fields.to_vec(), bump)?;
+        let bump_seed = &schema.bump_seed();
+        let seeds = schema.seeds(bump_seed);
+        schema.init(payer, metadata_schema_account, &seeds)?;
+        schema.write_data(metadata_schema_account)?;
+
+        Ok(())
+    }
+
+    /// Replace the required fields of a MetadataSchema, resizing the account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_update_metadata_schema(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        fields: &[u8],
+    ) -> ProgramResult {
+        let [payer, mint_info, metadata_schema_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(metadata_schema_account)?;
+        verify_owner(metadata_schema_account, program_id)?;
+
+        let current = MetadataSchema::from_account_info(metadata_schema_account)?;
+        if current.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(metadata_schema_account.key(), &current.derive_pda()?)?;
+
+        let schema = MetadataSchema::new(current.mint, fields.to_vec(), current.bump)?;
+        MetadataSchema::resize_account_and_rent(
+            metadata_schema_account,
+            schema.space() as usize,
+            payer,
+        )?;
+        schema.write_data(metadata_schema_account)?;
+
+        Ok(())
+    }
+
+    /// Close the MetadataSchema of a mint, UpdateMetadata stops enforcing required fields
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_close_metadata_schema(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, metadata_schema_account, rent_recipient] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_rent_recipient(rent_recipient, metadata_schema_account)?;
+        verify_writable(metadata_schema_account)?;
+        verify_owner(metadata_schema_account, program_id)?;
+        verify_account_initialized(metadata_schema_account)?;
+
+        let schema = MetadataSchema::from_account_info(metadata_schema_account)?;
+        if schema.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(metadata_schema_account.key(), &schema.derive_pda()?)?;
+        MetadataSchema::close(metadata_schema_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Anchor a legal document of a mint by its name, URI and SHA-256 hash, creating the
+    /// DocumentRegistry of the mint with its first document
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_anchor_document(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        name: &str,
+        uri: &str,
+        sha256: &[u8; DOCUMENT_HASH_LEN],
+    ) -> ProgramResult {
+        let [payer, mint_info, document_registry_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(document_registry_account)?;
+
+        if document_registry_account.data_is_empty() {
+            let (expected_registry_pda, bump) =
+                find_document_registry_pda(mint_info.key(), program_id);
+            verify_pda_keys_match(document_registry_account.key(), &expected_registry_pda)?;
+            let mut registry = DocumentRegistry::new(*mint_info.key(), bump);
+            registry.anchor(name, uri, sha256)?;
+            let bump_seed = &registry.bump_seed();
+            let seeds = registry.seeds(bump_seed);
+            registry.init(payer, document_registry_account, &seeds)?;
+            return registry.write_data(document_registry_account);
+        }
+
+        let mut registry =
+            Self::load_document_registry(program_id, document_registry_account, mint_info)?;
+        registry.anchor(name, uri, sha256)?;
+        DocumentRegistry::resize_account_and_rent(
+            document_registry_account,
+            registry.space() as usize,
+            payer,
+        )?;
+        registry.write_data(document_registry_account)
+    }
+
+    /// Replace the URI and hash of an anchored document, e.g. after amending a prospectus
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_update_document(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        name: &str,
+        uri: &str,
+        sha256: &[u8; DOCUMENT_HASH_LEN],
+    ) -> ProgramResult {
+        let [payer, mint_info, document_registry_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(document_registry_account)?;
+
+        let mut registry =
+            Self::load_document_registry(program_id, document_registry_account, mint_info)?;
+        registry.update(name, uri, sha256)?;
+        DocumentRegistry::resize_account_and_rent(
+            document_registry_account,
+            registry.space() as usize,
+            payer,
+        )?;
+        registry.write_data(document_registry_account)
+    }
+
+    /// Remove an anchored document, the freed rent goes to the payer
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_remove_document(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        name: &str,
+    ) -> ProgramResult {
+        let [payer, mint_info, document_registry_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(document_registry_account)?;
+
+        let mut registry =
+            Self::load_document_registry(program_id, document_registry_account, mint_info)?;
+        registry.remove(name)?;
+        DocumentRegistry::resize_account_and_rent(
+            document_registry_account,
+            registry.space() as usize,
+            payer,
+        )?;
+        registry.write_data(document_registry_account)
+    }
+
+    /// Load the DocumentRegistry of `mint_info`, verifying its owner and PDA
+    fn load_document_registry(
+        program_id: &Pubkey,
+        document_registry_account: &AccountInfo,
+        mint_info: &AccountInfo,
+    ) -> Result<DocumentRegistry, ProgramError> {
+        verify_owner(document_registry_account, program_id)?;
+        let registry = DocumentRegistry::from_account_info(document_registry_account)?;
+        if registry.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(document_registry_account.key(), &registry.derive_pda()?)?;
+        Ok(registry)
+    }
+
+    /// Execute proof account creation
+    pub fn execute_create_proof_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+        proof_data: ProofData,
+    ) -> ProgramResult {
+        let [payer, mint_account, proof_account, token_account, system_program_info] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_account)?;
+
+        verify_system_program(system_program_info)?;
+        verify_writable(payer)?;
+        verify_writable(proof_account)?;
+        verify_signer(payer)?;
+        verify_account_not_initialized(proof_account)?;
+
+        let token = TokenAccount::from_account_info(token_account)?;
+        // Verify token account belongs to the mint
+        let token_account_key = token_account.key();
+        if token.mint().ne(mint_account.key()) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (expected_proof_pda, bump) = find_proof_pda(token_account_key, action_id, program_id);
+        verify_pda_keys_match(proof_account.key(), &expected_proof_pda)?;
+
+        // Create Proof account
+        let proof = Proof::new(&proof_data, bump)?;
+        let action_id_seed = &action_id.to_le_bytes();
+        let bump_seed = &proof.bump_seed();
+        let seeds = proof.seeds(token_account_key, action_id_seed, bump_seed);
+        proof.init(payer, proof_account, &seeds)?;
+        proof.write_data(proof_account)?;
+
+        Ok(())
+    }
+
+    /// Execute proof account update
+    pub fn execute_update_proof_account(
+        _program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+        proof_node: ProofNode,
+        offset: u32,
+    ) -> ProgramResult {
+        let [payer, mint_account, proof_account, token_account, system_program_info] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_account)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(proof_account)?;
+        verify_account_initialized(proof_account)?;
+
+        let token = TokenAccount::from_account_info(token_account)?;
+        // Verify token account belongs to the mint
+        let token_account_key = token_account.key();
+        if token.mint().ne(mint_account.key()) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut proof = Proof::from_account_info(proof_account)?;
+        let expected_proof_pda = proof.derive_pda(token_account_key, action_id)?;
+        verify_pda_keys_match(proof_account.key(), &expected_proof_pda)?;
+
+        // Update Proof account
+        let current_proof_account_len = proof_account.data_len();
+        proof.update_data_at_offset(proof_node, offset as usize)?;
+        let new_proof_account_len = proof.serialized_len();
+        // Update account size and pay rent difference
+        if new_proof_account_len != current_proof_account_len {
+            Proof::resize_account_and_rent(proof_account, new_proof_account_len, payer)?;
+        }
+        proof.write_data(proof_account)?;
+
+        Ok(())
+    }
+
+    /// Create escrow for distributions
+    /// With `claim_bitmap_leaves` the claims are tracked in ClaimBitmap pages instead of a
+    /// Receipt per claim. The pages follow as trailing accounts, one per
+    /// `ClaimBitmap::PAGE_LEAVES` leaves.
+    pub fn execute_create_distribution_escrow(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+        merkle_root: &MerkleTreeRoot,
+        claim_bitmap_leaves: u32,
+    ) -> ProgramResult {
+        let [distribution_escrow_authority, payer, distribution_token_account, distribution_mint, token_program, associated_token_account_program, system_program, claim_bitmap_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Verify mint is valid
+        verify_mint_keys_match(verified_mint_info, &distribution_mint)?;
+        // Verify programs
+        verify_token22_program(token_program)?;
+        verify_associated_token_program(associated_token_account_program)?;
+        verify_system_program(system_program)?;
+
+        verify_writable(distribution_token_account)?;
+        verify_writable(payer)?;
+        verify_signer(payer)?;
+
+        verify_account_not_initialized(distribution_token_account)?;
+
+        let mint_pubkey = distribution_mint.key();
+        let (distribution_escrow_authority_pda, _) =
+            DistributionEscrowAuthority::find_pda(mint_pubkey, action_id, merkle_root);
+        verify_pda_keys_match(
+            distribution_escrow_authority.key(),
+            &distribution_escrow_authority_pda,
+        )?;
+
+        let (expected_ata, _) = find_associated_token_address(
+            &distribution_escrow_authority_pda,
+            mint_pubkey,
+            token_program.key(),
+        );
+        verify_pda_keys_match(distribution_token_account.key(), &expected_ata)?;
+
+        CreateTokenAccount {
+            funding_account: payer,
+            account: distribution_token_account,
+            wallet: distribution_escrow_authority,
+            mint: distribution_mint,
+            system_program,
+            token_program,
+        }
+        .invoke()?;
+
+        if claim_bitmap_leaves == 0 {
+            return Ok(());
+        }
+        let page_count = ClaimBitmap::page_count(claim_bitmap_leaves);
+        if claim_bitmap_accounts.len() < page_count as usize {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        for (page, claim_bitmap_account) in (0..page_count).zip(claim_bitmap_accounts) {
+            verify_writable(claim_bitmap_account)?;
+            verify_account_not_initialized(claim_bitmap_account)?;
+            let (expected_claim_bitmap_pda, bump) =
+                find_claim_bitmap_pda(mint_pubkey, action_id, merkle_root, page, program_id);
+            verify_pda_keys_match(claim_bitmap_account.key(), &expected_claim_bitmap_pda)?;
+
+            let claim_bitmap = ClaimBitmap::new(
+                *mint_pubkey,
+                action_id,
+                *merkle_root,
+                page,
+                ClaimBitmap::page_leaf_count(claim_bitmap_leaves, page),
+                bump,
+            )?;
+            let action_id_seed = &claim_bitmap.action_id_seed();
+            let page_seed = &claim_bitmap.page_seed();
+            let bump_seed = &claim_bitmap.bump_seed();
+            let seeds = claim_bitmap.seeds(action_id_seed, page_seed, bump_seed);
+            claim_bitmap.init(payer, claim_bitmap_account, &seeds)?;
+            claim_bitmap.write_data(claim_bitmap_account)?;
+        }
+
+        Ok(())
+    }
+
+    /// Claim distribution (dividends/coupons)
+    /// Optional trailing accounts withhold tax at the jurisdiction rate into the tax escrow,
+    /// the receipt records the net and withheld amounts. Further optional accounts pay the
+    /// entitlement in an alternative payout mint at the rate published by the DistributionPayout.
+    /// The optional custodian accounts let a custodian claim on behalf of the holder with a permit
+    /// signed by the holder wallet in an Ed25519 instruction of the transaction.
+    /// The trailing claim_deadline_account and distribution_root_account are always required
+    /// so neither the claim window nor an amendment of the merkle root can be bypassed.
+    /// The trailing claim_bitmap_account is the ClaimBitmap page of the leaf; distributions
+    /// created with claim bitmaps pass it in place of the receipt and mark the leaf claimed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute_claim_distribution(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        amount: u64,
+        action_id: u64,
+        merkle_root: &MerkleTreeRoot,
+        leaf_index: u32,
+        merkle_proof: Option<ProofData>,
+    ) -> ProgramResult {
+        let [permanent_delegate_authority, payer, mint_account, eligible_token_account, escrow_token_account, receipt_account, proof_account, transfer_hook_program, token_program, system_program, optional_accounts @ .., claim_deadline_account, distribution_root_account, claim_bitmap_account] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        let (withholding_accounts, optional_accounts) =
+            optional_accounts.split_at(optional_accounts.len().min(2));
+        let (payout_accounts, permit_accounts) =
+            optional_accounts.split_at(optional_accounts.len().min(5));
+
+        // Verify mint
+        verify_mint_keys_match(verified_mint_info, &mint_account)?;
+
+        // Verify programs
+        verify_transfer_hook_program(transfer_hook_program)?;
+        verify_token22_program(token_program)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(receipt_account)?;
+
+        if let Some(claim_deadline) =
+            Self::load_claim_deadline(program_id, mint_account, action_id, claim_deadline_account)?
+        {
+            claim_deadline.verify_claimable(Clock::get()?.unix_timestamp)?;
+        }
+        let distribution_root = Self::load_distribution_root(
+            program_id,
+            mint_account,
+            action_id,
+            merkle_root,
+            distribution_root_account,
+        )?;
+        if let Some(distribution_root) = &distribution_root {
+            distribution_root.verify_claimable()?;
+        }
+
+        // With external settlement the escrow_token_account is not provided
+        let is_external_settlement = escrow_token_account.key().eq(program_id);
+        verify_writable(eligible_token_account)?;
+        // escrow_token_account only needs writable check if it's not external settlement
+        if !is_external_settlement {
+            verify_writable(escrow_token_account)?;
+        }
+
+        let claim_bitmap = Self::load_claim_bitmap(
+            program_id,
+            mint_account,
+            action_id,
+            merkle_root,
+            leaf_index,
+            claim_bitmap_account,
+        )?;
+        let custodian = Self::load_claim_permit(
+            program_id,
+            mint_account,
+            eligible_token_account,
+            action_id,
+            permit_accounts,
+        )?;
+        // Retrieve proof data either from argument or from account and verify proof account
+        let proof = Proof::get_proof_data_from_instruction(
+            eligible_token_account.key(),
+            action_id,
+            proof_account,
+            merkle_proof,
+        )?;
+        let mint_pubkey = mint_account.key();
+        // Claims against a supplemental root are receipted per root
+        let proof_seed = match distribution_root {
+            Some(distribution_root) if distribution_root.is_supplemental() => {
+                Receipt::root_proof_seed(&proof, merkle_root)
+            }
+            _ => Receipt::proof_seed(&proof),
+        };
+        // Distributions with claim bitmaps pass the bitmap page in place of the receipt
+        let receipt_bump = match &claim_bitmap {
+            Some(_) => {
+                verify_pda_keys_match(receipt_account.key(), claim_bitmap_account.key())?;
+                None
+            }
+            None => {
+                verify_account_not_initialized(receipt_account)?;
+                let (expected_receipt_pda, receipt_bump) =
+                    Receipt::find_claim_action_pda_with_proof_seed(
+                        mint_pubkey,
+                        eligible_token_account.key(),
+                        action_id,
+                        &proof_seed,
+                    );
+                verify_pda_keys_match(receipt_account.key(), &expected_receipt_pda)?;
+                Some(receipt_bump)
+            }
+        };
+
+        // A single leaf tree has an empty proof, its leaf is at index 0
+        if proof.is_empty() && leaf_index != 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        // Verify claimer node belongs to merkle tree
+        let node = create_merkle_tree_leaf_node(
+            eligible_token_account.key(),
+            mint_pubkey,
+            action_id,
+            amount,
+        );
+        if !verify_merkle_proof(&node, merkle_root, &proof, leaf_index) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let withholding =
+            Self::load_withholding_rate(program_id, mint_account, withholding_accounts)?;
+        // Withholding moves security tokens, it cannot apply to claims settled externally
+        // or in a payout mint
+        if is_external_settlement && withholding.is_some() {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let mut claim = ClaimAmounts {
+            net_amount: amount,
+            withheld_amount: 0,
+            jurisdiction: 0,
+        };
+
+        // With internal settlement tokens are transferred and Receipt is issued
+        if !is_external_settlement {
+            let (distribution_escrow_authority, _bump) = find_distribution_escrow_authority_pda(
+                mint_pubkey,
+                action_id,
+                merkle_root,
+                program_id,
+            );
+            let (expected_escrow_ata, _ata_bump) = find_associated_token_address(
+                &distribution_escrow_authority,
+                mint_pubkey,
+                &pinocchio_token_2022::ID,
+            );
+            verify_pda_keys_match(escrow_token_account.key(), &expected_escrow_ata)?;
+
+            let (permanent_delegate_pda, permanent_delegate_bump) =
+                find_permanent_delegate_pda(mint_pubkey, program_id);
+            verify_pda_keys_match(permanent_delegate_authority.key(), &permanent_delegate_pda)?;
+
+            let mint = Mint::from_account_info(mint_account)?;
+            let escrow_token = TokenAccount::from_account_info(escrow_token_account)?;
+            let eligible_token = TokenAccount::from_account_info(eligible_token_account)?;
+            let decimals = mint.decimals();
+
+            if escrow_token.mint() != mint_pubkey || eligible_token.mint() != mint_pubkey {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            if escrow_token.amount() < amount {
+                return Err(ProgramError::InsufficientFunds);
+            }
+            drop(mint);
+            drop(escrow_token);
+            drop(eligible_token);
+
+            if let Some((withholding_rate, tax_escrow_token_account)) = withholding {
+                claim.withheld_amount = withholding_rate.withheld_amount(amount);
+                claim.net_amount = amount - claim.withheld_amount;
+                claim.jurisdiction = withholding_rate.jurisdiction;
+
+                if claim.withheld_amount > 0 {
+                    // Transfer withheld tokens from distribution escrow to tax escrow
+                    transfer_checked(
+                        claim.withheld_amount,
+                        decimals,
+                        mint_account,
+                        escrow_token_account,
+                        tax_escrow_token_account,
+                        transfer_hook_program,
+                        permanent_delegate_authority,
+                        permanent_delegate_bump,
+                    )
+                    .map_err(token_cpi_error(TokenCpiStage::ClaimWithholding))?;
+                }
+            }
+
+            if claim.net_amount > 0 {
+                // Transfer tokens from distribution escrow to eligible token account
+                transfer_checked(
+                    claim.net_amount,
+                    decimals,
+                    mint_account,
+                    escrow_token_account,
+                    eligible_token_account,
+                    transfer_hook_program,
+                    permanent_delegate_authority,
+                    permanent_delegate_bump,
+                )
+                .map_err(token_cpi_error(TokenCpiStage::ClaimDistribution))?;
+            }
+        }
+
+        // Without security token escrow the claimant may choose a payout mint
+        if is_external_settlement {
+            Self::settle_claim_in_payout_mint(
+                program_id,
+                mint_account,
+                eligible_token_account,
+                amount,
+                action_id,
+                merkle_root,
+                payout_accounts,
+                custodian.as_ref(),
+            )?;
+        }
+
+        if let Some(mut claim_bitmap) = claim_bitmap {
+            claim_bitmap.set_claimed(leaf_index)?;
+            return claim_bitmap.write_data(claim_bitmap_account);
+        }
+
+        // Issue Receipt
+        let action_id_seed = action_id.to_le_bytes();
+        let bump_seed = [receipt_bump.ok_or(ProgramError::InvalidAccountData)?];
+        let receipt_seeds = Receipt::claim_action_seeds(
+            mint_pubkey,
+            eligible_token_account.key(),
+            &action_id_seed,
+            &proof_seed,
+            &bump_seed,
+        );
+        Receipt::issue_claim(receipt_account, payer, &receipt_seeds, claim)?;
+        Ok(())
+    }
+
+    /// Pay a claimed entitlement of `amount` base units from the payout escrow of the
+    /// DistributionPayout chosen by the claimant. No-op when the payout accounts are omitted.
+    /// A custodian holding a permit of the holder may receive the payout on the holder's behalf.
+    #[allow(clippy::too_many_arguments)]
+    fn settle_claim_in_payout_mint(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        eligible_token_account: &AccountInfo,
+        amount: u64,
+        action_id: u64,
+        merkle_root: &MerkleTreeRoot,
+        payout_accounts: &[AccountInfo],
+        custodian: Option<&Pubkey>,
+    ) -> ProgramResult {
+        // Clients fill omitted optional accounts with the program id
+        match payout_accounts.first() {
+            None => return Ok(()),
+            Some(distribution_payout_account)
+                if distribution_payout_account.key() == program_id =>
+            {
+                return Ok(())
+            }
+            Some(_) => {}
+        }
+        let [distribution_payout_account, payout_mint, payout_escrow_token_account, payout_token_account, payout_token_program, ..] =
+            payout_accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_writable(payout_token_account)?;
+        let distribution_payout = Self::load_distribution_payout(
+            program_id,
+            mint_info,
+            action_id,
+            merkle_root,
+            distribution_payout_account,
+            payout_mint,
+            payout_escrow_token_account,
+            payout_token_program,
+        )?;
+
+        // Payout is only made to the holder of the eligible token account or its custodian
+        let holder = *TokenAccount::from_account_info(eligible_token_account)?.owner();
+        verify_owner(payout_token_account, payout_token_program.key())?;
+        let payout_owner = underlying_token_account_owner(payout_token_account)?;
+        if payout_owner != holder && Some(&payout_owner) != custodian {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let payout_amount = distribution_payout.payout_amount(amount)?;
+        if underlying_token_account_amount(payout_escrow_token_account)? < payout_amount {
+            return Err(ProgramError::InsufficientFunds);
+        }
+        transfer_from_payout_escrow(
+            payout_amount,
+            underlying_mint_decimals(payout_mint)?,
+            payout_mint,
+            payout_escrow_token_account,
+            payout_token_account,
+            distribution_payout_account,
+            &distribution_payout,
+            payout_token_program,
+        )
+        .map_err(token_cpi_error(TokenCpiStage::ClaimPayout))
+    }
+
+    /// Verify the permit of a custodian claiming on behalf of the holder of the eligible token
+    /// account. Returns the custodian, `None` when the custodian accounts are omitted.
+    fn load_claim_permit(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        eligible_token_account: &AccountInfo,
+        action_id: u64,
+        permit_accounts: &[AccountInfo],
+    ) -> Result<Option<Pubkey>, ProgramError> {
+        // Clients fill omitted optional accounts with the program id
+        match permit_accounts.first() {
+            None => return Ok(None),
+            Some(custodian) if custodian.key() == program_id => return Ok(None),
+            Some(_) => {}
+        }
+        let [custodian, instructions_sysvar, ..] = permit_accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_signer(custodian)?;
+        let holder = *TokenAccount::from_account_info(eligible_token_account)?.owner();
+        let message = claim_permit_message(
+            mint_info.key(),
+            action_id,
+            eligible_token_account.key(),
+            custodian.key(),
+        );
+        verify_ed25519_permit(instructions_sysvar, &holder, &message)?;
+        Ok(Some(*custodian.key()))
+    }
+
+    /// Load a DistributionPayout of the distribution and verify its payout escrow token account
+    #[allow(clippy::too_many_arguments)]
+    fn load_distribution_payout(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        action_id: u64,
+        merkle_root: &MerkleTreeRoot,
+        distribution_payout_account: &AccountInfo,
+        payout_mint: &AccountInfo,
+        payout_escrow_token_account: &AccountInfo,
+        payout_token_program: &AccountInfo,
+    ) -> Result<DistributionPayout, ProgramError> {
+        verify_underlying_token_program(payout_token_program)?;
+        verify_writable(payout_escrow_token_account)?;
+        verify_owner(distribution_payout_account, program_id)?;
+        verify_account_initialized(distribution_payout_account)?;
+
+        let distribution_payout =
+            DistributionPayout::from_account_info(distribution_payout_account)?;
+        if distribution_payout.mint != *mint_info.key()
+            || distribution_payout.action_id != action_id
+            || distribution_payout.merkle_root != *merkle_root
+            || distribution_payout.payout_mint != *payout_mint.key()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            distribution_payout_account.key(),
+            &distribution_payout.derive_pda()?,
+        )?;
+        verify_owner(payout_mint, payout_token_program.key())?;
+        let (expected_payout_escrow, _) = find_associated_token_address(
+            distribution_payout_account.key(),
+            payout_mint.key(),
+            payout_token_program.key(),
+        );
+        verify_pda_keys_match(payout_escrow_token_account.key(), &expected_payout_escrow)?;
+
+        Ok(distribution_payout)
+    }
+
+    /// Load the ClaimDeadline of a distribution.
+    /// Returns `None` when the distribution has no claim deadline.
+    fn load_claim_deadline(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        action_id: u64,
+        claim_deadline_account: &AccountInfo,
+    ) -> Result<Option<ClaimDeadline>, ProgramError> {
+        let (expected_claim_deadline_pda, _) =
+            find_claim_deadline_pda(mint_info.key(), action_id, program_id);
+        verify_pda_keys_match(claim_deadline_account.key(), &expected_claim_deadline_pda)?;
+
+        if claim_deadline_account.data_is_empty() {
+            return Ok(None);
+        }
+        verify_owner(claim_deadline_account, program_id)?;
+        ClaimDeadline::from_account_info(claim_deadline_account).map(Some)
+    }
+
+    /// Load the ClaimBitmap page tracking the leaf at `leaf_index` of a distribution.
+    /// Returns `None` when the distribution issues a Receipt per claim.
+    fn load_claim_bitmap(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        action_id: u64,
+        merkle_root: &MerkleTreeRoot,
+        leaf_index: u32,
+        claim_bitmap_account: &AccountInfo,
+    ) -> Result<Option<ClaimBitmap>, ProgramError> {
+        let (expected_claim_bitmap_pda, _) = find_claim_bitmap_pda(
+            mint_info.key(),
+            action_id,
+            merkle_root,
+            ClaimBitmap::page_of(leaf_index),
+            program_id,
+        );
+        verify_pda_keys_match(claim_bitmap_account.key(), &expected_claim_bitmap_pda)?;
+
+        if claim_bitmap_account.data_is_empty() {
+            return Ok(None);
+        }
+        verify_owner(claim_bitmap_account, program_id)?;
+        verify_writable(claim_bitmap_account)?;
+        ClaimBitmap::from_account_info(claim_bitmap_account).map(Some)
+    }
+
+    /// Load the DistributionRoot of a merkle root of a distribution.
+    /// Returns `None` when the merkle root was never amended.
+    fn load_distribution_root(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        action_id: u64,
+        merkle_root: &MerkleTreeRoot,
+        distribution_root_account: &AccountInfo,
+    ) -> Result<Option<DistributionRoot>, ProgramError> {
+        let (expected_distribution_root_pda, _) =
+            find_distribution_root_pda(mint_info.key(), action_id, merkle_root, program_id);
+        verify_pda_keys_match(
+            distribution_root_account.key(),
+            &expected_distribution_root_pda,
+        )?;
+
+        if distribution_root_account.data_is_empty() {
+            return Ok(None);
+        }
+        verify_owner(distribution_root_account, program_id)?;
+        DistributionRoot::from_account_info(distribution_root_account).map(Some)
+    }
+
+    /// Amend the merkle root of a distribution to correct its eligibility list.
+    /// Without `supplemental` the merkle root is replaced before the claim window opens:
+    /// the old root is marked superseded, the escrow balance moves to the escrow of the new
+    /// root and the optional DistributionPayout is re-pointed to the new root.
+    /// With `supplemental` the new root is appended next to the existing one, its claims are
+    /// receipted per root and its escrow is funded separately by the issuer.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute_amend_distribution_root(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+        merkle_root: &MerkleTreeRoot,
+        new_merkle_root: &MerkleTreeRoot,
+        supplemental: bool,
+    ) -> ProgramResult {
+        let [payer, permanent_delegate_authority, mint_account, claim_deadline_account, distribution_root_account, escrow_token_account, new_escrow_authority, new_escrow_token_account, distribution_payout_account, transfer_hook_program, token_program, associated_token_program, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_account)?;
+        verify_transfer_hook_program(transfer_hook_program)?;
+        verify_token22_program(token_program)?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(distribution_root_account)?;
+        verify_account_not_initialized(distribution_root_account)?;
+
+        if merkle_root == new_merkle_root {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mint_pubkey = mint_account.key();
+        if !supplemental {
+            // Holders may only be re-targeted while nobody can have claimed yet
+            let claim_deadline = Self::load_claim_deadline(
+                program_id,
+                mint_account,
+                action_id,
+                claim_deadline_account,
+            )?
+            .ok_or(SecurityTokenError::ClaimWindowStarted)?;
+            claim_deadline.verify_not_opened(Clock::get()?.unix_timestamp)?;
+        }
+
+        // Replacing marks the old root superseded, appending marks the new root supplemental
+        let (amended_root, status) = if supplemental {
+            (new_merkle_root, DistributionRootStatus::Supplemental)
+        } else {
+            (merkle_root, DistributionRootStatus::Superseded)
+        };
+        let (expected_distribution_root_pda, bump) =
+            find_distribution_root_pda(mint_pubkey, action_id, amended_root, program_id);
+        verify_pda_keys_match(
+            distribution_root_account.key(),
+            &expected_distribution_root_pda,
+        )?;
+        let distribution_root =
+            DistributionRoot::new(*mint_pubkey, *amended_root, action_id, status, bump);
+        let action_id_seed = &distribution_root.action_id_seed();
+        let bump_seed = &distribution_root.bump_seed();
+        let seeds = distribution_root.seeds(action_id_seed, bump_seed);
+        distribution_root.init(payer, distribution_root_account, &seeds)?;
+        distribution_root.write_data(distribution_root_account)?;
+
+        // Clients fill omitted optional accounts with the program id
+        if new_escrow_token_account.key() != program_id {
+            verify_writable(new_escrow_token_account)?;
+
+            let (new_escrow_authority_pda, _) =
+                DistributionEscrowAuthority::find_pda(mint_pubkey, action_id, new_merkle_root);
+            verify_pda_keys_match(new_escrow_authority.key(), &new_escrow_authority_pda)?;
+            let (expected_new_escrow_ata, _) = find_associated_token_address(
+                &new_escrow_authority_pda,
+                mint_pubkey,
+                &pinocchio_token_2022::ID,
+            );
+            verify_pda_keys_match(new_escrow_token_account.key(), &expected_new_escrow_ata)?;
+
+            if new_escrow_token_account.data_is_empty() {
+                CreateTokenAccount {
+                    funding_account: payer,
+                    account: new_escrow_token_account,
+                    wallet: new_escrow_authority,
+                    mint: mint_account,
+                    system_program,
+                    token_program,
+                }
+                .invoke()?;
+            }
+        }
+
+        if supplemental {
+            return Ok(());
+        }
+
+        if escrow_token_account.key() != program_id {
+            if new_escrow_token_account.key() == program_id {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            }
+            verify_writable(escrow_token_account)?;
+
+            let (escrow_authority, _) = find_distribution_escrow_authority_pda(
+                mint_pubkey,
+                action_id,
+                merkle_root,
+                program_id,
+            );
+            let (expected_escrow_ata, _) = find_associated_token_address(
+                &escrow_authority,
+                mint_pubkey,
+                &pinocchio_token_2022::ID,
+            );
+            verify_pda_keys_match(escrow_token_account.key(), &expected_escrow_ata)?;
+
+            let (permanent_delegate_pda, permanent_delegate_bump) =
+                find_permanent_delegate_pda(mint_pubkey, program_id);
+            verify_pda_keys_match(permanent_delegate_authority.key(), &permanent_delegate_pda)?;
+
+            let decimals = Mint::from_account_info(mint_account)?.decimals();
+            let escrowed = TokenAccount::from_account_info(escrow_token_account)?.amount();
+            if escrowed > 0 {
+                transfer_checked(
+                    escrowed,
+                    decimals,
+                    mint_account,
+                    escrow_token_account,
+                    new_escrow_token_account,
+                    transfer_hook_program,
+                    permanent_delegate_authority,
+                    permanent_delegate_bump,
+                )
+                .map_err(token_cpi_error(TokenCpiStage::AmendDistributionRoot))?;
+            }
+        }
+
+        if distribution_payout_account.key() != program_id {
+            verify_writable(distribution_payout_account)?;
+            verify_owner(distribution_payout_account, program_id)?;
+            let mut distribution_payout =
+                DistributionPayout::from_account_info(distribution_payout_account)?;
+            if distribution_payout.mint != *mint_pubkey
+                || distribution_payout.action_id != action_id
+                || distribution_payout.merkle_root != *merkle_root
+            {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            verify_pda_keys_match(
+                distribution_payout_account.key(),
+                &distribution_payout.derive_pda()?,
+            )?;
+            distribution_payout.merkle_root = *new_merkle_root;
+            distribution_payout.write_data(distribution_payout_account)?;
+        }
+
+        Ok(())
+    }
+
+    /// Create ClaimDeadline account with the claim window of the distribution. Claims are
+    /// accepted from claims_open_at until the deadline, after which the distribution can no
+    /// longer be claimed. The window is immutable, so holders can rely on it once it is published.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_claim_deadline(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+        claim_deadline: i64,
+        claims_open_at: i64,
+    ) -> ProgramResult {
+        let [payer, mint_info, claim_deadline_account, system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(claim_deadline_account)?;
+        verify_account_not_initialized(claim_deadline_account)?;
+
+        if claim_deadline <= Clock::get()?.unix_timestamp {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let (expected_claim_deadline_pda, bump) =
+            find_claim_deadline_pda(mint_info.key(), action_id, program_id);
+        verify_pda_keys_match(claim_deadline_account.key(), &expected_claim_deadline_pda)?;
+
+        let claim_deadline = ClaimDeadline::new(
+            *mint_info.key(),
+            action_id,
+            claims_open_at,
+            claim_deadline,
+            bump,
+        )?;
+        let action_id_seed = &claim_deadline.action_id_seed();
+        let bump_seed = &claim_deadline.bump_seed();
+        let seeds = claim_deadline.seeds(action_id_seed, bump_seed);
+        claim_deadline.init(payer, claim_deadline_account, &seeds)?;
+        claim_deadline.write_data(claim_deadline_account)
+    }
+
+    /// Return the unclaimed remainder of a distribution to the issuer once its claim deadline
+    /// passed. Drains the security token escrow and, with the optional payout accounts,
+    /// the payout escrow of a DistributionPayout.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_reclaim_unclaimed(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+        merkle_root: &MerkleTreeRoot,
+    ) -> ProgramResult {
+        let [permanent_delegate_authority, mint_account, claim_deadline_account, escrow_token_account, destination_token_account, transfer_hook_program, token_program, payout_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_account)?;
+        verify_transfer_hook_program(transfer_hook_program)?;
+        verify_token22_program(token_program)?;
+
+        // Without a claim deadline the distribution stays claimable indefinitely
+        let claim_deadline =
+            Self::load_claim_deadline(program_id, mint_account, action_id, claim_deadline_account)?
+                .ok_or(ProgramError::UninitializedAccount)?;
+        claim_deadline.verify_passed(Clock::get()?.unix_timestamp)?;
+
+        // Clients fill omitted optional accounts with the program id
+        if escrow_token_account.key() != program_id {
+            verify_writable(escrow_token_account)?;
+            verify_writable(destination_token_account)?;
+
+            let mint_pubkey = mint_account.key();
+            let (distribution_escrow_authority, _) = find_distribution_escrow_authority_pda(
+                mint_pubkey,
+                action_id,
+                merkle_root,
+                program_id,
+            );
+            let (expected_escrow_ata, _) = find_associated_token_address(
+                &distribution_escrow_authority,
+                mint_pubkey,
+                &pinocchio_token_2022::ID,
+            );
+            verify_pda_keys_match(escrow_token_account.key(), &expected_escrow_ata)?;
+
+            let (permanent_delegate_pda, permanent_delegate_bump) =
+                find_permanent_delegate_pda(mint_pubkey, program_id);
+            verify_pda_keys_match(permanent_delegate_authority.key(), &permanent_delegate_pda)?;
+
+            let decimals = Mint::from_account_info(mint_account)?.decimals();
+            let unclaimed = TokenAccount::from_account_info(escrow_token_account)?.amount();
+            if unclaimed > 0 {
+                transfer_checked(
+                    unclaimed,
+                    decimals,
+                    mint_account,
+                    escrow_token_account,
+                    destination_token_account,
+                    transfer_hook_program,
+                    permanent_delegate_authority,
+                    permanent_delegate_bump,
+                )
+                .map_err(token_cpi_error(TokenCpiStage::ReclaimUnclaimed))?;
+            }
+        }
+
+        match payout_accounts.first() {
+            None => return Ok(()),
+            Some(distribution_payout_account)
+                if distribution_payout_account.key() == program_id =>
+            {
+                return Ok(())
+            }
+            Some(_) => {}
+        }
+        let [distribution_payout_account, payout_mint, payout_escrow_token_account, payout_destination_token_account, payout_token_program, ..] =
+            payout_accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_writable(payout_destination_token_account)?;
+        let distribution_payout = Self::load_distribution_payout(
+            program_id,
+            mint_account,
+            action_id,
+            merkle_root,
+            distribution_payout_account,
+            payout_mint,
+            payout_escrow_token_account,
+            payout_token_program,
+        )?;
+
+        let unclaimed = underlying_token_account_amount(payout_escrow_token_account)?;
+        if unclaimed == 0 {
+            return Ok(());
+        }
+        transfer_from_payout_escrow(
+            unclaimed,
+            underlying_mint_decimals(payout_mint)?,
+            payout_mint,
+            payout_escrow_token_account,
+            payout_destination_token_account,
+            distribution_payout_account,
+            &distribution_payout,
+            payout_token_program,
+        )
+        .map_err(token_cpi_error(TokenCpiStage::ReclaimPayout))
+    }
+
+    /// Create DistributionPayout account publishing the rate of an alternative payout mint of a
+    /// distribution, and its payout escrow token account to be funded by the issuer
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute_create_distribution_payout(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+        merkle_root: &MerkleTreeRoot,
+        rate_numerator: u64,
+        rate_denominator: u64,
+    ) -> ProgramResult {
+        let [payer, mint_info, distribution_payout_account, payout_mint, payout_escrow_token_account, payout_token_program, associated_token_program, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_underlying_token_program(payout_token_program)?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(distribution_payout_account)?;
+        verify_writable(payout_escrow_token_account)?;
+        verify_account_not_initialized(distribution_payout_account)?;
+        verify_account_not_initialized(payout_escrow_token_account)?;
+        verify_owner(payout_mint, payout_token_program.key())?;
+
+        let (expected_distribution_payout_pda, bump) =
+            find_distribution_payout_pda(mint_info.key(), action_id, payout_mint.key(), program_id);
+        verify_pda_keys_match(
+            distribution_payout_account.key(),
+            &expected_distribution_payout_pda,
+        )?;
+
+        let (expected_payout_escrow, _) = find_associated_token_address(
+            &expected_distribution_payout_pda,
+            payout_mint.key(),
+            payout_token_program.key(),
+        );
+        verify_pda_keys_match(payout_escrow_token_account.key(), &expected_payout_escrow)?;
+
+        let distribution_payout = DistributionPayout::new(
+            *mint_info.key(),
+            *payout_mint.key(),
+            *merkle_root,
+            action_id,
+            rate_numerator,
+            rate_denominator,
+            bump,
+        )?;
+        let action_id_seed = &distribution_payout.action_id_seed();
+        let bump_seed = &distribution_payout.bump_seed();
+        let seeds = distribution_payout.seeds(action_id_seed, bump_seed);
+        distribution_payout.init(payer, distribution_payout_account, &seeds)?;
+        distribution_payout.write_data(distribution_payout_account)?;
+
+        CreateTokenAccount {
+            funding_account: payer,
+            account: payout_escrow_token_account,
+            wallet: distribution_payout_account,
+            mint: payout_mint,
+            system_program,
+            token_program: payout_token_program,
+        }
+        .invoke()
+    }
+
+    /// Load the WithholdingRate applied to a claim and verify its tax escrow token account.
+    /// Returns `None` when the optional withholding accounts are omitted.
+    fn load_withholding_rate<'a>(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        withholding_accounts: &'a [AccountInfo],
+    ) -> Result<Option<(WithholdingRate, &'a AccountInfo)>, ProgramError> {
+        // Clients fill omitted optional accounts with the program id
+        match withholding_accounts.first() {
+            None => return Ok(None),
+            Some(withholding_rate_account) if withholding_rate_account.key() == program_id => {
+                return Ok(None)
+            }
+            Some(_) => {}
+        }
+        let [withholding_rate_account, tax_escrow_token_account, ..] = withholding_accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_writable(tax_escrow_token_account)?;
+        verify_owner(withholding_rate_account, program_id)?;
+        verify_account_initialized(withholding_rate_account)?;
+
+        let withholding_rate = WithholdingRate::from_account_info(withholding_rate_account)?;
+        if withholding_rate.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            withholding_rate_account.key(),
+            &withholding_rate.derive_pda()?,
+        )?;
+        Self::verify_tax_escrow(
+            withholding_rate_account,
+            mint_info,
+            tax_escrow_token_account,
+        )?;
+        Ok(Some((withholding_rate, tax_escrow_token_account)))
+    }
+
+    /// Verify `tax_escrow_token_account` is the associated token account of the WithholdingRate PDA
+    fn verify_tax_escrow(
+        withholding_rate_account: &AccountInfo,
+        mint_info: &AccountInfo,
+        tax_escrow_token_account: &AccountInfo,
+    ) -> ProgramResult {
+        let (expected_tax_escrow, _bump) = find_associated_token_address(
+            withholding_rate_account.key(),
+            mint_info.key(),
+            &pinocchio_token_2022::ID,
+        );
+        verify_pda_keys_match(tax_escrow_token_account.key(), &expected_tax_escrow)
+    }
+
+    /// Create WithholdingRate account of a jurisdiction and its tax escrow token account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_withholding_rate(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        jurisdiction: u16,
+        rate_bps: u16,
+    ) -> ProgramResult {
+        let [payer, mint_info, withholding_rate_account, tax_escrow_token_account, system_program_info, token_program, associated_token_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_token22_program(token_program)?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(withholding_rate_account)?;
+        verify_writable(tax_escrow_token_account)?;
+        verify_account_not_initialized(withholding_rate_account)?;
+
+        let (expected_withholding_rate_pda, bump) =
+            find_withholding_rate_pda(mint_info.key(), jurisdiction, program_id);
+        verify_pda_keys_match(
+            withholding_rate_account.key(),
+            &expected_withholding_rate_pda,
+        )?;
+
+        let withholding_rate =
+            WithholdingRate::new(*mint_info.key(), jurisdiction, rate_bps, bump)?;
+        let jurisdiction_seed = &withholding_rate.jurisdiction_seed();
+        let bump_seed = &withholding_rate.bump_seed();
+        let seeds = withholding_rate.seeds(jurisdiction_seed, bump_seed);
+        withholding_rate.init(payer, withholding_rate_account, &seeds)?;
+        withholding_rate.write_data(withholding_rate_account)?;
+
+        Self::verify_tax_escrow(
+            withholding_rate_account,
+            mint_info,
+            tax_escrow_token_account,
+        )?;
+        if !tax_escrow_token_account.data_is_empty() {
+            return Ok(());
+        }
+        CreateTokenAccount {
+            funding_account: payer,
+            account: tax_escrow_token_account,
+            wallet: withholding_rate_account,
+            mint: mint_info,
+            system_program: system_program_info,
+            token_program,
+        }
+        .invoke()
+    }
+
+    /// Update withheld share of WithholdingRate account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_update_withholding_rate(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        rate_bps: u16,
+    ) -> ProgramResult {
+        let [mint_info, withholding_rate_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_writable(withholding_rate_account)?;
+        verify_owner(withholding_rate_account, program_id)?;
+        verify_account_initialized(withholding_rate_account)?;
+
+        let mut withholding_rate = WithholdingRate::from_account_info(withholding_rate_account)?;
+        if withholding_rate.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            withholding_rate_account.key(),
+            &withholding_rate.derive_pda()?,
+        )?;
+        withholding_rate.update(rate_bps)?;
+        withholding_rate.write_data(withholding_rate_account)?;
+        Ok(())
+    }
+
+    /// Close Receipt account of operation tied to the action_id (e.g. split, convert)
+    pub fn execute_close_action_receipt_account(
+        _program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+    ) -> ProgramResult {
+        let [receipt_account, rent_recipient, mint_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_account)?;
+        verify_rent_recipient(rent_recipient, receipt_account)?;
+        verify_writable(receipt_account)?;
+
+        // Validate Receipt
+        verify_account_initialized(receipt_account)?;
+        // Deserialize to ensure it's valid Receipt account (checks discriminator and ownership)
+        Receipt::from_account_info(receipt_account)?;
+        let (expected_receipt_pda, _bump) =
+            Receipt::find_common_action_pda(mint_account.key(), action_id);
+        verify_pda_keys_match(receipt_account.key(), &expected_receipt_pda)?;
+
+        Receipt::close(receipt_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Close Receipt account of claim_distribution action
+    pub fn execute_close_claim_receipt_account(
+        _program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        action_id: u64,
+        merkle_proof: Option<ProofData>,
+        merkle_root: Option<MerkleTreeRoot>,
+    ) -> ProgramResult {
+        let [receipt_account, rent_recipient, mint_account, eligible_token_account, proof_account] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_account)?;
+        verify_rent_recipient(rent_recipient, receipt_account)?;
+        verify_writable(receipt_account)?;
+        verify_account_initialized(receipt_account)?;
+        // Deserialize to ensure it's valid Receipt account (checks discriminator and ownership)
+        Receipt::from_account_info(receipt_account)?;
+
+        // Retrieve proof data either from argument or from account. Verify proof account
+        let proof = Proof::get_proof_data_from_instruction(
+            eligible_token_account.key(),
+            action_id,
+            proof_account,
+            merkle_proof,
+        )?;
+        // Receipts of claims against a supplemental root are scoped to that root
+        let proof_seed = match merkle_root {
+            Some(merkle_root) => Receipt::root_proof_seed(&proof, &merkle_root),
+            None => Receipt::proof_seed(&proof),
+        };
+        let (expected_receipt_pda, _bump) = Receipt::find_claim_action_pda_with_proof_seed(
+            mint_account.key(),
+            eligible_token_account.key(),
+            action_id,
+            &proof_seed,
+        );
+        verify_pda_keys_match(receipt_account.key(), &expected_receipt_pda)?;
+
+        Receipt::close(receipt_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Create wrap vault approving an underlying legacy SPL or Token 2022 mint for wrapping
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_wrap_vault(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [payer, mint_info, underlying_mint, wrap_vault, vault_token_account, underlying_token_program, associated_token_program, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_underlying_token_program(underlying_token_program)?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(wrap_vault)?;
+        verify_writable(vault_token_account)?;
+        verify_account_not_initialized(wrap_vault)?;
+        verify_account_not_initialized(vault_token_account)?;
+
+        // Underlying tokens are exchanged 1:1, so both mints must share decimals
+        verify_owner(underlying_mint, underlying_token_program.key())?;
+        if underlying_mint.key() == mint_info.key()
+            || underlying_mint_decimals(underlying_mint)?
+                != Mint::from_account_info(mint_info)?.decimals()
+        {
+            return Err(SecurityTokenError::InvalidUnderlyingMint.into());
+        }
+
+        let (expected_wrap_vault_pda, bump) =
+            find_wrap_vault_pda(mint_info.key(), underlying_mint.key(), program_id);
+        verify_pda_keys_match(wrap_vault.key(), &expected_wrap_vault_pda)?;
+
+        let (expected_vault_ata, _) = find_associated_token_address(
+            &expected_wrap_vault_pda,
+            underlying_mint.key(),
+            underlying_token_program.key(),
+        );
+        verify_pda_keys_match(vault_token_account.key(), &expected_vault_ata)?;
+
+        let wrap_vault_state = WrapVault::new(*mint_info.key(), *underlying_mint.key(), bump);
+        let bump_seed = &wrap_vault_state.bump_seed();
+        let seeds = wrap_vault_state.seeds(bump_seed);
+        wrap_vault_state.init(payer, wrap_vault, &seeds)?;
+        wrap_vault_state.write_data(wrap_vault)?;
+
+        CreateTokenAccount {
+            funding_account: payer,
+            account: vault_token_account,
+            wallet: wrap_vault,
+            mint: underlying_mint,
+            system_program,
+            token_program: underlying_token_program,
+        }
+        .invoke()?;
+
+        Ok(())
+    }
+
+    /// Lock underlying tokens in the wrap vault and mint the received amount of security tokens
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_wrap(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let [mint_authority, wrap_vault, owner, mint_info, underlying_mint, underlying_token_account, vault_token_account, token_account, underlying_token_program, token_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_token22_program(token_program)?;
+        verify_underlying_token_program(underlying_token_program)?;
+        verify_owner(mint_authority, program_id)?;
+        verify_signer(owner)?;
+        verify_writable(mint_info)?;
+        verify_writable(underlying_token_account)?;
+        verify_writable(vault_token_account)?;
+        verify_writable(token_account)?;
+        verify_writable(wrap_vault)?;
+
+        let mut wrap_vault_state = Self::verify_wrap_vault(
+            wrap_vault,
+            mint_info,
+            underlying_mint,
+            vault_token_account,
+            underlying_token_program,
+        )?;
+
+        let mint_authority_state = MintAuthority::from_account_info(mint_authority)?;
+        if mint_authority_state.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let decimals = Mint::from_account_info(mint_info)?.decimals();
+        let underlying_decimals = underlying_mint_decimals(underlying_mint)?;
+
+        // Mint what the vault actually received, Token 2022 transfer fees are withheld from it
+        let vault_amount_before = underlying_token_account_amount(vault_token_account)?;
+        TransferChecked {
+            from: underlying_token_account,
+            mint: underlying_mint,
+            to: vault_token_account,
+            authority: owner,
+            amount,
+            decimals: underlying_decimals,
+            token_program: underlying_token_program.key(),
+        }
+        .invoke()
+        .map_err(token_cpi_error(TokenCpiStage::WrapDeposit))?;
+        let wrapped_amount = underlying_token_account_amount(vault_token_account)?
+            .checked_sub(vault_amount_before)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        mint_to_checked(
+            wrapped_amount,
+            decimals,
+            mint_info,
+            token_account,
+            mint_authority,
+            &mint_authority_state,
+        )
+        .map_err(token_cpi_error(TokenCpiStage::WrapMint))?;
+
+        wrap_vault_state.wrapped_supply = wrap_vault_state
+            .wrapped_supply
+            .checked_add(wrapped_amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        wrap_vault_state.write_data(wrap_vault)
+    }
+
+    /// Burn security tokens and release the same amount of underlying tokens from the wrap vault
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_unwrap(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let [permanent_delegate_authority, wrap_vault, owner, mint_info, underlying_mint, token_account, vault_token_account, underlying_token_account, underlying_token_program, token_program, permanent_delegate_config_account] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_token22_program(token_program)?;
+        verify_underlying_token_program(underlying_token_program)?;
+        verify_signer(owner)?;
+        verify_writable(mint_info)?;
+        verify_writable(token_account)?;
+        verify_writable(vault_token_account)?;
+        verify_writable(underlying_token_account)?;
+        verify_writable(wrap_vault)?;
+
+        let mut wrap_vault_state = Self::verify_wrap_vault(
+            wrap_vault,
+            mint_info,
+            underlying_mint,
+            vault_token_account,
+            underlying_token_program,
+        )?;
+
+        // Only what was wrapped into this vault can be released from it
+        wrap_vault_state.wrapped_supply = wrap_vault_state
+            .wrapped_supply
+            .checked_sub(amount)
+            .ok_or(SecurityTokenError::WrapVaultSupplyExceeded)?;
+
+        // Security tokens are burned with the permanent delegate, so the owner is checked here
+        {
+            let token_account_state = TokenAccount::from_account_info(token_account)?;
+            if token_account_state.owner() != owner.key() {
+                return Err(ProgramError::IllegalOwner);
+            }
+        }
+
+        let decimals = Mint::from_account_info(mint_info)?.decimals();
+        let underlying_decimals = underlying_mint_decimals(underlying_mint)?;
+
+        Self::burn_with_permanent_delegate(
+            program_id,
+            amount,
+            decimals,
+            mint_info,
+            token_account,
+            permanent_delegate_authority,
+            permanent_delegate_config_account,
+            TokenCpiStage::UnwrapBurn,
+        )?;
+
+        transfer_from_wrap_vault(
+            amount,
+            underlying_decimals,
+            underlying_mint,
+            vault_token_account,
+            underlying_token_account,
+            wrap_vault,
+            &wrap_vault_state,
+            underlying_token_program,
+        )
+        .map_err(token_cpi_error(TokenCpiStage::UnwrapWithdraw))?;
+
+        wrap_vault_state.write_data(wrap_vault)
+    }
+
+    /// Create Agent account granting `agent` the `permissions` scopes on the mint
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_agent_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        permissions: u8,
+    ) -> ProgramResult {
+        let [payer, mint_info, agent_account, agent, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(agent_account)?;
+        verify_account_not_initialized(agent_account)?;
+
+        let (expected_agent_pda, bump) = find_agent_pda(mint_info.key(), agent.key(), program_id);
+        verify_pda_keys_match(agent_account.key(), &expected_agent_pda)?;
+
+        let agent_state = Agent::new(*mint_info.key(), *agent.key(), permissions, bump)?;
+        let bump_seed = &agent_state.bump_seed();
+        let seeds = agent_state.seeds(bump_seed);
+        agent_state.init(payer, agent_account, &seeds)?;
+        agent_state.write_data(agent_account)?;
+        Ok(())
+    }
+
+    /// Update scopes granted by Agent account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_update_agent_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        permissions: u8,
+    ) -> ProgramResult {
+        let [mint_info, agent_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_writable(agent_account)?;
+        verify_owner(agent_account, program_id)?;
+        verify_account_initialized(agent_account)?;
+
+        let mut agent_state = Self::load_agent(agent_account, mint_info)?;
+        agent_state.update(permissions)?;
+        agent_state.write_data(agent_account)?;
+        Ok(())
+    }
+
+    /// Close Agent account, revoking all scopes of the agent
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_close_agent_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, agent_account, rent_recipient] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_rent_recipient(rent_recipient, agent_account)?;
+        verify_writable(agent_account)?;
+        verify_owner(agent_account, program_id)?;
+        verify_account_initialized(agent_account)?;
+
+        // Deserialize to ensure it's valid Agent account of the mint, then close
+        Self::load_agent(agent_account, mint_info)?;
+        Agent::close(agent_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Load the Agent account of `mint_info` and verify its PDA
+    fn load_agent(
+        agent_account: &AccountInfo,
+        mint_info: &AccountInfo,
+    ) -> Result<Agent, ProgramError> {
+        let agent_state = Agent::from_account_info(agent_account)?;
+        if agent_state.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(agent_account.key(), &agent_state.derive_pda()?)?;
+        Ok(agent_state)
+    }
+
+    /// Create SessionKey account letting `session_key` execute `allowed_instructions` of the mint
+    /// on behalf of the issuer until `expiry`, each operation moving at most `max_amount`
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_session_key(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        allowed_instructions: u128,
+        max_amount: u64,
+        expiry: i64,
+    ) -> ProgramResult {
+        let [payer, mint_info, session_key_account, session_key, system_program_info] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(session_key_account)?;
+        verify_account_not_initialized(session_key_account)?;
+
+        if expiry <= Clock::get()?.unix_timestamp {
+            return Err(SecurityTokenError::SessionKeyExpired.into());
+        }
+
+        let (expected_session_key_pda, bump) =
+            find_session_key_pda(mint_info.key(), session_key.key(), program_id);
+        verify_pda_keys_match(session_key_account.key(), &expected_session_key_pda)?;
+
+        let session_key_state = SessionKey::new(
+            *mint_info.key(),
+            *session_key.key(),
+            allowed_instructions,
+            max_amount,
+            expiry,
+            bump,
+        )?;
+        let bump_seed = &session_key_state.bump_seed();
+        let seeds = session_key_state.seeds(bump_seed);
+        session_key_state.init(payer, session_key_account, &seeds)?;
+        session_key_state.write_data(session_key_account)?;
+        Ok(())
+    }
+
+    /// Close SessionKey account, revoking the session key before its expiry
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_close_session_key(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, session_key_account, rent_recipient] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_rent_recipient(rent_recipient, session_key_account)?;
+        verify_writable(session_key_account)?;
+        verify_owner(session_key_account, program_id)?;
+        verify_account_initialized(session_key_account)?;
+
+        let session_key_state = SessionKey::from_account_info(session_key_account)?;
+        if session_key_state.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(session_key_account.key(), &session_key_state.derive_pda()?)?;
+        SessionKey::close(session_key_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Create the SuccessionPolicy of a mint naming the fallback authority
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_succession_policy(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        successor: Pubkey,
+        heartbeat_period: i64,
+    ) -> ProgramResult {
+        let [payer, mint_info, succession_policy_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(succession_policy_account)?;
+        verify_account_not_initialized(succession_policy_account)?;
+
+        let (expected_succession_policy_pda, bump) =
+            find_succession_policy_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(
+            succession_policy_account.key(),
+            &expected_succession_policy_pda,
+        )?;
+
+        let succession_policy = SuccessionPolicy::new(
+            *mint_info.key(),
+            successor,
+            heartbeat_period,
+            Clock::get()?.unix_timestamp,
+            bump,
+        )?;
+        let bump_seed = &succession_policy.bump_seed();
+        let seeds = succession_policy.seeds(bump_seed);
+        succession_policy.init(payer, succession_policy_account, &seeds)?;
+        succession_policy.write_data(succession_policy_account)?;
+        Ok(())
+    }
+
+    /// Record a heartbeat of the primary authority, restarting the heartbeat period
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_heartbeat(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, succession_policy_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_writable(succession_policy_account)?;
+        let mut succession_policy =
+            Self::load_succession_policy(program_id, mint_info, succession_policy_account)?;
+        succession_policy.heartbeat(Clock::get()?.unix_timestamp)?;
+        succession_policy.write_data(succession_policy_account)?;
+        Ok(())
+    }
+
+    /// Hand the authority of the mint creator over to the successor once the heartbeat
+    /// period elapsed without heartbeat
+    pub fn execute_claim_succession(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [successor, mint_info, succession_policy_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_signer(successor)?;
+        verify_writable(succession_policy_account)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
+        let mut succession_policy =
+            Self::load_succession_policy(program_id, mint_info, succession_policy_account)?;
+        if succession_policy.successor != *successor.key() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        succession_policy.claim(Clock::get()?.unix_timestamp)?;
+        succession_policy.write_data(succession_policy_account)?;
+        Ok(())
+    }
+
+    /// Close the SuccessionPolicy of a mint, revoking the successor
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_close_succession_policy(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, succession_policy_account, rent_recipient] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_rent_recipient(rent_recipient, succession_policy_account)?;
+        verify_writable(succession_policy_account)?;
+        Self::load_succession_policy(program_id, mint_info, succession_policy_account)?;
+        SuccessionPolicy::close(succession_policy_account, rent_recipient)?;
+        Ok(())
+    }
+
+    fn load_succession_policy(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        succession_policy_account: &AccountInfo,
+    ) -> Result<SuccessionPolicy, ProgramError> {
+        verify_owner(succession_policy_account, program_id)?;
+        verify_account_initialized(succession_policy_account)?;
+        let succession_policy = SuccessionPolicy::from_account_info(succession_policy_account)?;
+        if succession_policy.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            succession_policy_account.key(),
+            &succession_policy.derive_pda()?,
+        )?;
+        Ok(succession_policy)
+    }
+
+    /// Propose `new_creator` as the controller of the MintAuthority of the mint, taking over
+    /// once it accepts with AcceptMintCreatorTransfer. The default pubkey cancels a proposal.
+    /// The MintAuthority PDA stays derived from the original creator
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_propose_mint_creator_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        new_creator: Pubkey,
+    ) -> ProgramResult {
+        let [payer, mint_info, mint_authority_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(mint_authority_account)?;
+
+        let mut mint_authority =
+            Self::load_mint_authority(program_id, mint_info, mint_authority_account)?;
+        mint_authority.propose_controller(new_creator)?;
+        MintAuthority::resize_account_and_rent(
+            mint_authority_account,
+            mint_authority.space() as usize,
+            payer,
+        )?;
+        mint_authority.write_data(mint_authority_account)
+    }
+
+    /// Hand the control of the mint over to the creator proposed by ProposeMintCreatorTransfer,
+    /// revoking the previous creator
+    pub fn execute_accept_mint_creator_transfer(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [new_creator, mint_info, mint_authority_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_signer(new_creator)?;
+        verify_writable(new_creator)?;
+        verify_writable(mint_authority_account)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
+
+        let mut mint_authority =
+            Self::load_mint_authority(program_id, mint_info, mint_authority_account)?;
+        mint_authority.accept_controller(new_creator.key())?;
+        // Handing control back to the original creator restores the original layout
+        MintAuthority::resize_account_and_rent(
+            mint_authority_account,
+            mint_authority.space() as usize,
+            new_creator,
+        )?;
+        mint_authority.write_data(mint_authority_account)
+    }
+
+    /// Require `threshold` of `signers` to sign in place of the creator for every instruction
+    /// authorized by the MintAuthority of the mint. A zero threshold without signers hands
+    /// the control back to the creator
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_set_mint_authority_multisig(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        threshold: u8,
+        signers: Vec<Pubkey>,
+    ) -> ProgramResult {
+        let [payer, mint_info, mint_authority_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(mint_authority_account)?;
+
+        let mut mint_authority =
+            Self::load_mint_authority(program_id, mint_info, mint_authority_account)?;
+        mint_authority.set_multisig(threshold, signers)?;
+        MintAuthority::resize_account_and_rent(
+            mint_authority_account,
+            mint_authority.space() as usize,
+            payer,
+        )?;
+        mint_authority.write_data(mint_authority_account)
+    }
+
+    /// Rotate the permanent delegate of the mint to `new_delegate`, e.g. an external custodian.
+    /// The program PDA hands it over first, the rotated delegate signs later rotations. The
+    /// PermanentDelegateConfig records the delegate and joins the transfer hook extra accounts,
+    /// so the transfers of the delegate skip verification.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_rotate_permanent_delegate(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        new_delegate: Pubkey,
+    ) -> ProgramResult {
+        let [payer, mint_info, permanent_delegate, permanent_delegate_config_account, config_account, token_program, system_program_info, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // DisablePermanentDelegate renounces the delegate, the program PDA is not handed it back
+        let (permanent_delegate_pda, _bump) =
+            find_permanent_delegate_pda(mint_info.key(), program_id);
+        if new_delegate == Pubkey::default()
+            || new_delegate == permanent_delegate_pda
+            || new_delegate == *permanent_delegate.key()
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Self::set_permanent_delegate(
+            program_id,
+            verified_mint_info,
+            [
+                payer,
+                mint_info,
+                permanent_delegate,
+                permanent_delegate_config_account,
+                token_program,
+                system_program_info,
+            ],
+            new_delegate,
+        )?;
+
+        VerificationModule::sync_transfer_hook_rules(
+            program_id,
+            payer,
+            payer,
+            mint_info,
+            config_account,
+            system_program_info,
+            transfer_hook_accounts,
+            TransferHookRules {
+                permanent_delegate_config: Some(true),
+                ..TransferHookRules::default()
+            },
+        )
+    }
+
+    /// Renounce the permanent delegate of the mint for good, proving that nobody can move
+    /// holder tokens anymore. Recovery, splits, conversions and migrations stop working.
+    /// The PermanentDelegateConfig joins the transfer hook extra accounts like on a rotation,
+    /// so the hook matches the renounced delegate instead of the program PDA.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_disable_permanent_delegate(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [payer, mint_info, permanent_delegate, permanent_delegate_config_account, config_account, token_program, system_program_info, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Self::set_permanent_delegate(
+            program_id,
+            verified_mint_info,
+            [
+                payer,
+                mint_info,
+                permanent_delegate,
+                permanent_delegate_config_account,
+                token_program,
+                system_program_info,
+            ],
+            Pubkey::default(),
+        )?;
+
+        VerificationModule::sync_transfer_hook_rules(
+            program_id,
+            payer,
+            payer,
+            mint_info,
+            config_account,
+            system_program_info,
+            transfer_hook_accounts,
+            TransferHookRules {
+                permanent_delegate_config: Some(true),
+                ..TransferHookRules::default()
+            },
+        )
+    }
+
+    /// Hand the permanent delegate of the mint over to `new_delegate`, the default pubkey
+    /// renouncing it, and record it in the PermanentDelegateConfig of the mint
+    fn set_permanent_delegate(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: [&AccountInfo; 6],
+        new_delegate: Pubkey,
+    ) -> ProgramResult {
+        let [payer, mint_info, permanent_delegate, permanent_delegate_config_account, token_program, system_program_info] =
+            accounts;
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_token22_program(token_program)?;
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(mint_info)?;
+        verify_writable(permanent_delegate_config_account)?;
+
+        let set_permanent_delegate = SetPermanentDelegate {
+            mint: mint_info,
+            delegate: permanent_delegate,
+            new_delegate: (new_delegate != Pubkey::default()).then_some(&new_delegate),
+        };
+
+        // The program PDA is the delegate until the first rotation
+        if permanent_delegate_config_account.data_is_empty() {
+            let (expected_config_pda, bump) =
+                find_permanent_delegate_config_pda(mint_info.key(), program_id);
+            verify_pda_keys_match(
+                permanent_delegate_config_account.key(),
+                &expected_config_pda,
+            )?;
+            let (permanent_delegate_pda, permanent_delegate_bump) =
+                find_permanent_delegate_pda(mint_info.key(), program_id);
+            verify_pda_keys_match(permanent_delegate.key(), &permanent_delegate_pda)?;
+
+            let bump_seed = [permanent_delegate_bump];
+            let seeds = [
+                Seed::from(seeds::PERMANENT_DELEGATE),
+                Seed::from(mint_info.key().as_ref()),
+                Seed::from(bump_seed.as_ref()),
+            ];
+            set_permanent_delegate.invoke_signed(&[Signer::from(&seeds)])?;
+
+            let config = PermanentDelegateConfig::new(*mint_info.key(), new_delegate, bump);
+            let bump_seed = &config.bump_seed();
+            let seeds = config.seeds(bump_seed);
+            config.init(payer, permanent_delegate_config_account, &seeds)?;
+            return config.write_data(permanent_delegate_config_account);
+        }
+
+        verify_owner(permanent_delegate_config_account, program_id)?;
+        let mut config =
+            PermanentDelegateConfig::from_account_info(permanent_delegate_config_account)?;
+        if config.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            permanent_delegate_config_account.key(),
+            &config.derive_pda()?,
+        )?;
+        if config.is_disabled() {
+            return Err(SecurityTokenError::PermanentDelegateDisabled.into());
+        }
+        if *permanent_delegate.key() != config.delegate {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        verify_signer(permanent_delegate)?;
+
+        set_permanent_delegate.invoke()?;
+        config.delegate = new_delegate;
+        config.write_data(permanent_delegate_config_account)
+    }
+
+    fn load_mint_authority(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        mint_authority_account: &AccountInfo,
+    ) -> Result<MintAuthority, ProgramError> {
+        verify_owner(mint_authority_account, program_id)?;
+        let mint_authority =
+            MintAuthority::try_from_bytes(&mint_authority_account.try_borrow_data()?)?;
+        if mint_authority.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(mint_authority_account.key(), &mint_authority.derive_pda()?)?;
+        Ok(mint_authority)
+    }
+
+    /// Close the MintAuthority of a decommissioned mint, returning its rent to `rent_recipient`.
+    /// The whole supply must be burned first; the mint cannot be minted or reconfigured afterwards.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_close_mint_authority(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, mint_authority_account, rent_recipient] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_rent_recipient(rent_recipient, mint_authority_account)?;
+        verify_writable(mint_authority_account)?;
+        Self::load_mint_authority(program_id, mint_info, mint_authority_account)?;
+
+        if Mint::from_account_info(mint_info)?.supply() != 0 {
+            return Err(SecurityTokenError::MintSupplyNotZero.into());
+        }
+
+        MintAuthority::close(mint_authority_account, rent_recipient)
+    }
+
+    /// Create MetadataTranslator account letting `translator` maintain the `i18n.*` additional
+    /// metadata entries of the mint
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_metadata_translator(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [payer, mint_info, metadata_translator_account, translator, system_program_info] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(metadata_translator_account)?;
+        verify_account_not_initialized(metadata_translator_account)?;
+
+        let (expected_translator_pda, bump) =
+            find_metadata_translator_pda(mint_info.key(), translator.key(), program_id);
+        verify_pda_keys_match(metadata_translator_account.key(), &expected_translator_pda)?;
+
+        let metadata_translator =
+            MetadataTranslator::new(*mint_info.key(), *translator.key(), bump);
+        let bump_seed = &metadata_translator.bump_seed();
+        let seeds = metadata_translator.seeds(bump_seed);
+        metadata_translator.init(payer, metadata_translator_account, &seeds)?;
+        metadata_translator.write_data(metadata_translator_account)?;
+        Ok(())
+    }
+
+    /// Close MetadataTranslator account, the translator cannot update translations anymore
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_close_metadata_translator(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, metadata_translator_account, rent_recipient] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_rent_recipient(rent_recipient, metadata_translator_account)?;
+        verify_writable(metadata_translator_account)?;
+        verify_owner(metadata_translator_account, program_id)?;
+        verify_account_initialized(metadata_translator_account)?;
+
+        let metadata_translator =
+            MetadataTranslator::from_account_info(metadata_translator_account)?;
+        if metadata_translator.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            metadata_translator_account.key(),
+            &metadata_translator.derive_pda()?,
+        )?;
+        MetadataTranslator::close(metadata_translator_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Create Identity account for `investor_id` without linked token accounts
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_identity_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        investor_id: [u8; 32],
+    ) -> ProgramResult {
+        let [payer, mint_info, identity_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(identity_account)?;
+        verify_account_not_initialized(identity_account)?;
+
+        let (expected_identity_pda, bump) =
+            find_identity_pda(mint_info.key(), &investor_id, program_id);
+        verify_pda_keys_match(identity_account.key(), &expected_identity_pda)?;
+
+        let identity = Identity::new(*mint_info.key(), investor_id, bump);
+        let bump_seed = &identity.bump_seed();
+        let seeds = identity.seeds(bump_seed);
+        identity.init(payer, identity_account, &seeds)?;
+        identity.write_data(identity_account)?;
+        Ok(())
+    }
+
+    /// Link a token account of the mint to an Identity
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_add_identity_wallet(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [payer, mint_info, identity_account, token_account, identity_wallet_account, system_program_info] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(identity_account)?;
+        verify_writable(identity_wallet_account)?;
+        verify_owner(identity_account, program_id)?;
+        verify_account_initialized(identity_account)?;
+        // Token account can be linked to a single identity only
+        verify_account_not_initialized(identity_wallet_account)?;
+
+        {
+            let token = TokenAccount::from_account_info(token_account)?;
+            if token.mint().ne(mint_info.key()) {
+                return Err(SecurityTokenError::InvalidIdentityWallet.into());
+            }
+        }
+
+        let mut identity = Self::load_identity(identity_account, mint_info)?;
+        identity.add_wallet(*token_account.key())?;
+        identity.write_data(identity_account)?;
+
+        let (expected_identity_wallet_pda, bump) =
+            find_identity_wallet_pda(token_account.key(), program_id);
+        verify_pda_keys_match(identity_wallet_account.key(), &expected_identity_wallet_pda)?;
+
+        let identity_wallet = IdentityWallet::new(*identity_account.key(), bump);
+        let bump_seed = &identity_wallet.bump_seed();
+        let seeds = identity_wallet.seeds(token_account.key(), bump_seed);
+        identity_wallet.init(payer, identity_wallet_account, &seeds)?;
+        identity_wallet.write_data(identity_wallet_account)?;
+        Ok(())
+    }
+
+    /// Unlink a token account from an Identity, rent is returned to the rent recipient
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_remove_identity_wallet(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [rent_recipient, mint_info, identity_account, token_account, identity_wallet_account] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_rent_recipient(rent_recipient, identity_wallet_account)?;
+        verify_writable(identity_account)?;
+        verify_writable(identity_wallet_account)?;
+        verify_owner(identity_account, program_id)?;
+        verify_owner(identity_wallet_account, program_id)?;
+        verify_account_initialized(identity_account)?;
+        verify_account_initialized(identity_wallet_account)?;
+
+        // Token account itself may already be closed, only its key is used
+        let identity_wallet = IdentityWallet::from_account_info(identity_wallet_account)?;
+        if identity_wallet.identity != *identity_account.key() {
+            return Err(SecurityTokenError::InvalidIdentityWallet.into());
+        }
+        let expected_identity_wallet_pda = identity_wallet.derive_pda(token_account.key())?;
+        verify_pda_keys_match(identity_wallet_account.key(), &expected_identity_wallet_pda)?;
+
+        let mut identity = Self::load_identity(identity_account, mint_info)?;
+        identity.remove_wallet(token_account.key())?;
+        identity.write_data(identity_account)?;
+
+        IdentityWallet::close(identity_wallet_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Close Identity account without linked token accounts
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_close_identity_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, identity_account, rent_recipient] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_rent_recipient(rent_recipient, identity_account)?;
+        verify_writable(identity_account)?;
+        verify_owner(identity_account, program_id)?;
+        verify_account_initialized(identity_account)?;
+
+        let identity = Self::load_identity(identity_account, mint_info)?;
+        // Linked token accounts must be removed first so no IdentityWallet points to a closed identity
+        if !identity.wallets.is_empty() {
+            debug_log!("Identity still has linked token accounts");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Identity::close(identity_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Load the Identity account of `mint_info` and verify its PDA
+    fn load_identity(
+        identity_account: &AccountInfo,
+        mint_info: &AccountInfo,
+    ) -> Result<Identity, ProgramError> {
+        let identity = Identity::from_account_info(identity_account)?;
+        if identity.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(identity_account.key(), &identity.derive_pda()?)?;
+        Ok(identity)
+    }
+
+    /// Create PositionLimit account and make the transfer hook enforce it
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_position_limit(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        max_ownership_bps: u16,
+    ) -> ProgramResult {
+        let [payer, mint_info, position_limit_account, config_account, system_program_info, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(position_limit_account)?;
+        verify_account_not_initialized(position_limit_account)?;
+
+        let (expected_position_limit_pda, bump) =
+            find_position_limit_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(position_limit_account.key(), &expected_position_limit_pda)?;
+
+        let position_limit = PositionLimit::new(*mint_info.key(), max_ownership_bps, bump)?;
+        let bump_seed = &position_limit.bump_seed();
+        let seeds = position_limit.seeds(bump_seed);
+        position_limit.init(payer, position_limit_account, &seeds)?;
+        position_limit.write_data(position_limit_account)?;
+
+        VerificationModule::sync_transfer_hook_rules(
+            program_id,
+            payer,
+            payer,
+            mint_info,
+            config_account,
+            system_program_info,
+            transfer_hook_accounts,
+            TransferHookRules {
+                position_limit: Some(true),
+                ..TransferHookRules::default()
+            },
+        )
+    }
+
+    /// Update maximum ownership of PositionLimit account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_update_position_limit(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        max_ownership_bps: u16,
+    ) -> ProgramResult {
+        let [mint_info, position_limit_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_writable(position_limit_account)?;
+        verify_owner(position_limit_account, program_id)?;
+        verify_account_initialized(position_limit_account)?;
+
+        let mut position_limit = Self::load_position_limit(position_limit_account, mint_info)?;
+        position_limit.update(max_ownership_bps)?;
+        position_limit.write_data(position_limit_account)?;
+        Ok(())
+    }
+
+    /// Close PositionLimit account and stop the transfer hook from enforcing it
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_close_position_limit(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [payer, mint_info, position_limit_account, config_account, rent_recipient, system_program_info, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_rent_recipient(rent_recipient, position_limit_account)?;
+        verify_writable(position_limit_account)?;
+        verify_owner(position_limit_account, program_id)?;
+        verify_account_initialized(position_limit_account)?;
+
+        Self::load_position_limit(position_limit_account, mint_info)?;
+
+        // Remove hook accounts first, otherwise transfers would resolve a closed account
+        VerificationModule::sync_transfer_hook_rules(
+            program_id,
+            payer,
+            rent_recipient,
+            mint_info,
+            config_account,
+            system_program_info,
+            transfer_hook_accounts,
+            TransferHookRules {
+                position_limit: Some(false),
+                ..TransferHookRules::default()
+            },
+        )?;
+
+        PositionLimit::close(position_limit_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Load the PositionLimit account of `mint_info` and verify its PDA
+    fn load_position_limit(
+        position_limit_account: &AccountInfo,
+        mint_info: &AccountInfo,
+    ) -> Result<PositionLimit, ProgramError> {
+        let position_limit = PositionLimit::from_account_info(position_limit_account)?;
+        if position_limit.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(position_limit_account.key(), &position_limit.derive_pda()?)?;
+        Ok(position_limit)
+    }
+
+    /// Create the AllowlistEntry of `wallet`, allowing its token accounts to send and receive
+    /// tokens while the allowlist mode is enabled
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_add_to_allowlist(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        wallet: Pubkey,
+    ) -> ProgramResult {
+        let [payer, mint_info, allowlist_entry_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(allowlist_entry_account)?;
+        verify_account_not_initialized(allowlist_entry_account)?;
+
+        let (expected_allowlist_entry_pda, bump) =
+            find_allowlist_entry_pda(mint_info.key(), &wallet, program_id);
+        verify_pda_keys_match(allowlist_entry_account.key(), &expected_allowlist_entry_pda)?;
+
+        let allowlist_entry = AllowlistEntry::new(*mint_info.key(), wallet, bump);
+        let bump_seed = &allowlist_entry.bump_seed();
+        let seeds = allowlist_entry.seeds(bump_seed);
+        allowlist_entry.init(payer, allowlist_entry_account, &seeds)?;
+        allowlist_entry.write_data(allowlist_entry_account)
+    }
+
+    /// Close the AllowlistEntry of `wallet`
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_remove_from_allowlist(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        wallet: Pubkey,
+    ) -> ProgramResult {
+        let [mint_info, allowlist_entry_account, rent_recipient] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_rent_recipient(rent_recipient, allowlist_entry_account)?;
+        verify_writable(allowlist_entry_account)?;
+        verify_owner(allowlist_entry_account, program_id)?;
+        verify_account_initialized(allowlist_entry_account)?;
+
+        let allowlist_entry = AllowlistEntry::from_account_info(allowlist_entry_account)?;
+        if allowlist_entry.mint != *mint_info.key() || allowlist_entry.wallet != wallet {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            allowlist_entry_account.key(),
+            &allowlist_entry.derive_pda()?,
+        )?;
+
+        AllowlistEntry::close(allowlist_entry_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Make the transfer hook require an AllowlistEntry for the owners of the source and
+    /// destination token accounts, or stop requiring it
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_set_allowlist_mode(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        enabled: bool,
+    ) -> ProgramResult {
+        let [payer, mint_info, config_account, rent_recipient, system_program_info, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(rent_recipient)?;
+
+        VerificationModule::sync_transfer_hook_rules(
+            program_id,
+            payer,
+            rent_recipient,
+            mint_info,
+            config_account,
+            system_program_info,
+            transfer_hook_accounts,
+            TransferHookRules {
+                allowlist: Some(enabled),
+                ..TransferHookRules::default()
+            },
+        )
+    }
+
+    /// Create TransferAcceptance account, the transfer escrow token account and make the
+    /// transfer hook reject direct transfers
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_transfer_acceptance(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [payer, mint_info, transfer_acceptance_account, config_account, escrow_authority, escrow_token_account, system_program_info, token_program, associated_token_program, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_token22_program(token_program)?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(transfer_acceptance_account)?;
+        verify_account_not_initialized(transfer_acceptance_account)?;
+
+        let (expected_transfer_acceptance_pda, bump) =
+            find_transfer_acceptance_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(
+            transfer_acceptance_account.key(),
+            &expected_transfer_acceptance_pda,
+        )?;
+
+        let transfer_acceptance = TransferAcceptance::new(*mint_info.key(), bump);
+        let bump_seed = &transfer_acceptance.bump_seed();
+        let seeds = transfer_acceptance.seeds(bump_seed);
+        transfer_acceptance.init(payer, transfer_acceptance_account, &seeds)?;
+        transfer_acceptance.write_data(transfer_acceptance_account)?;
+
+        Self::create_transfer_escrow_if_missing(
+            program_id,
+            payer,
+            mint_info,
+            escrow_authority,
+            escrow_token_account,
+            system_program_info,
+            token_program,
+        )?;
+
+        VerificationModule::sync_transfer_hook_rules(
+            program_id,
+            payer,
+            payer,
+            mint_info,
+            config_account,
+            system_program_info,
+            transfer_hook_accounts,
+            TransferHookRules {
+                transfer_acceptance: Some(true),
+                ..TransferHookRules::default()
+            },
+        )
+    }
+
+    /// Close TransferAcceptance account, direct transfers are allowed again.
+    /// Transfers still pending can be accepted or cancelled afterwards.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_close_transfer_acceptance(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [payer, mint_info, transfer_acceptance_account, config_account, rent_recipient, system_program_info, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_rent_recipient(rent_recipient, transfer_acceptance_account)?;
+        verify_writable(transfer_acceptance_account)?;
+        verify_owner(transfer_acceptance_account, program_id)?;
+        verify_account_initialized(transfer_acceptance_account)?;
+
+        let transfer_acceptance =
+            TransferAcceptance::from_account_info(transfer_acceptance_account)?;
+        if transfer_acceptance.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            transfer_acceptance_account.key(),
+            &transfer_acceptance.derive_pda()?,
+        )?;
+
+        VerificationModule::sync_transfer_hook_rules(
+            program_id,
+            payer,
+            rent_recipient,
+            mint_info,
+            config_account,
+            system_program_info,
+            transfer_hook_accounts,
+            TransferHookRules {
+                transfer_acceptance: Some(false),
+                ..TransferHookRules::default()
+            },
+        )?;
+
+        TransferAcceptance::close(transfer_acceptance_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Move tokens from the source token account into the transfer escrow,
+    /// pending acceptance by the destination token account owner
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_offer_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        transfer_id: u64,
+        amount: u64,
+    ) -> ProgramResult {
+        let [payer, owner, mint_info, source_token_account, destination_token_account, pending_transfer_account, escrow_token_account, permanent_delegate_authority, transfer_hook_program, token_program, system_program_info, source_suspension, destination_suspension] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_token22_program(token_program)?;
+        verify_transfer_hook_program(transfer_hook_program)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_signer(owner)?;
+        verify_writable(source_token_account)?;
+        verify_writable(escrow_token_account)?;
+        verify_writable(pending_transfer_account)?;
+        verify_account_not_initialized(pending_transfer_account)?;
+
+        {
+            let source_token = TokenAccount::from_account_info(source_token_account)?;
+            if source_token.mint() != mint_info.key() || source_token.owner() != owner.key() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            let destination_token = TokenAccount::from_account_info(destination_token_account)?;
+            if destination_token.mint() != mint_info.key() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+        Self::verify_not_suspended(program_id, source_suspension, source_token_account)?;
+        Self::verify_not_suspended(
+            program_id,
+            destination_suspension,
+            destination_token_account,
+        )?;
+        Self::verify_transfer_escrow(program_id, mint_info, escrow_token_account)?;
+
+        let (expected_pending_transfer_pda, bump) = find_pending_transfer_pda(
+            source_token_account.key(),
+            destination_token_account.key(),
+            transfer_id,
+            program_id,
+        );
+        verify_pda_keys_match(
+            pending_transfer_account.key(),
+            &expected_pending_transfer_pda,
+        )?;
+
+        let pending_transfer = PendingTransfer::new(
+            *mint_info.key(),
+            *source_token_account.key(),
+            *destination_token_account.key(),
+            *payer.key(),
+            amount,
+            transfer_id,
+            bump,
+        )?;
+        let transfer_id_seed = &pending_transfer.transfer_id_seed();
+        let bump_seed = &pending_transfer.bump_seed();
+        let seeds = pending_transfer.seeds(transfer_id_seed, bump_seed);
+        pending_transfer.init(payer, pending_transfer_account, &seeds)?;
+        pending_transfer.write_data(pending_transfer_account)?;
+
+        Self::transfer_with_permanent_delegate(
+            program_id,
+            amount,
+            mint_info,
+            source_token_account,
+            escrow_token_account,
+            transfer_hook_program,
+            permanent_delegate_authority,
+            TokenCpiStage::OfferTransfer,
+        )
+    }
+
+    /// Release a pending transfer from the escrow to the destination token account,
+    /// signed by the destination token account owner
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_accept_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [owner, mint_info, pending_transfer_account, destination_token_account, escrow_token_account, rent_destination, permanent_delegate_authority, transfer_hook_program, token_program, destination_suspension] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_token22_program(token_program)?;
+        verify_transfer_hook_program(transfer_hook_program)?;
+        verify_signer(owner)?;
+        verify_writable(destination_token_account)?;
+        verify_writable(escrow_token_account)?;
+        verify_writable(rent_destination)?;
+
+        let pending_transfer =
+            Self::load_pending_transfer(program_id, pending_transfer_account, mint_info)?;
+        if pending_transfer.destination != *destination_token_account.key()
+            || pending_transfer.payer != *rent_destination.key()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if TokenAccount::from_account_info(destination_token_account)?.owner() != owner.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::verify_not_suspended(
+            program_id,
+            destination_suspension,
+            destination_token_account,
+        )?;
+        Self::verify_transfer_escrow(program_id, mint_info, escrow_token_account)?;
+
+        Self::transfer_with_permanent_delegate(
+            program_id,
+            pending_transfer.amount,
+            mint_info,
+            escrow_token_account,
+            destination_token_account,
+            transfer_hook_program,
+            permanent_delegate_authority,
+            TokenCpiStage::AcceptTransfer,
+        )?;
+
+        PendingTransfer::close(pending_transfer_account, rent_destination)?;
+        Ok(())
+    }
+
+    /// Return a pending transfer from the escrow to the source token account,
+    /// signed by the owner of the source or the destination token account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_cancel_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [authority, mint_info, pending_transfer_account, source_token_account, destination_token_account, escrow_token_account, rent_destination, permanent_delegate_authority, transfer_hook_program, token_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_token22_program(token_program)?;
+        verify_transfer_hook_program(transfer_hook_program)?;
+        verify_signer(authority)?;
+        verify_writable(source_token_account)?;
+        verify_writable(escrow_token_account)?;
+        verify_writable(rent_destination)?;
+
+        let pending_transfer =
+            Self::load_pending_transfer(program_id, pending_transfer_account, mint_info)?;
+        if pending_transfer.source != *source_token_account.key()
+            || pending_transfer.destination != *destination_token_account.key()
+            || pending_transfer.payer != *rent_destination.key()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // Either party may cancel, a closed destination leaves it to the sender
+        let is_source_owner =
+            TokenAccount::from_account_info(source_token_account)?.owner() == authority.key();
+        let is_destination_owner = !destination_token_account.data_is_empty()
+            && TokenAccount::from_account_info(destination_token_account)?.owner()
+                == authority.key();
+        if !is_source_owner && !is_destination_owner {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        Self::verify_transfer_escrow(program_id, mint_info, escrow_token_account)?;
+
+        Self::transfer_with_permanent_delegate(
+            program_id,
+            pending_transfer.amount,
+            mint_info,
+            escrow_token_account,
+            source_token_account,
+            transfer_hook_program,
+            permanent_delegate_authority,
+            TokenCpiStage::CancelTransfer,
+        )?;
+
+        PendingTransfer::close(pending_transfer_account, rent_destination)?;
+        Ok(())
+    }
+
+    /// Create TransferApproval account, the transfer escrow token account and make the
+    /// transfer hook reject direct transfers above `threshold`
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_transfer_approval(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        threshold: u64,
+    ) -> ProgramResult {
+        let [payer, mint_info, transfer_approval_account, config_account, escrow_authority, escrow_token_account, system_program_info, token_program, associated_token_program, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_token22_program(token_program)?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(transfer_approval_account)?;
+        verify_account_not_initialized(transfer_approval_account)?;
+
+        let (expected_transfer_approval_pda, bump) =
+            find_transfer_approval_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(
+            transfer_approval_account.key(),
+            &expected_transfer_approval_pda,
+        )?;
+
+        let transfer_approval = TransferApproval::new(*mint_info.key(), threshold, bump);
+        let bump_seed = &transfer_approval.bump_seed();
+        let seeds = transfer_approval.seeds(bump_seed);
+        transfer_approval.init(payer, transfer_approval_account, &seeds)?;
+        transfer_approval.write_data(transfer_approval_account)?;
+
+        Self::create_transfer_escrow_if_missing(
+            program_id,
+            payer,
+            mint_info,
+            escrow_authority,
+            escrow_token_account,
+            system_program_info,
+            token_program,
+        )?;
+
+        VerificationModule::sync_transfer_hook_rules(
+            program_id,
+            payer,
+            payer,
+            mint_info,
+            config_account,
+            system_program_info,
+            transfer_hook_accounts,
+            TransferHookRules {
+                transfer_approval: Some(true),
+                ..TransferHookRules::default()
+            },
+        )
+    }
+
+    /// Update the approval threshold of the TransferApproval account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_update_transfer_approval(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        threshold: u64,
+    ) -> ProgramResult {
+        let [mint_info, transfer_approval_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        let mut transfer_approval =
+            Self::load_transfer_approval(program_id, transfer_approval_account, mint_info)?;
+        transfer_approval.threshold = threshold;
+        transfer_approval.write_data(transfer_approval_account)?;
+        Ok(())
+    }
+
+    /// Close TransferApproval account, transfers of any amount are allowed again.
+    /// Queued requests can still be approved or rejected afterwards.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_close_transfer_approval(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [payer, mint_info, transfer_approval_account, config_account, rent_recipient, system_program_info, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_rent_recipient(rent_recipient, transfer_approval_account)?;
+        Self::load_transfer_approval(program_id, transfer_approval_account, mint_info)?;
+
+        VerificationModule::sync_transfer_hook_rules(
+            program_id,
+            payer,
+            rent_recipient,
+            mint_info,
+            config_account,
+            system_program_info,
+            transfer_hook_accounts,
+            TransferHookRules {
+                transfer_approval: Some(false),
+                ..TransferHookRules::default()
+            },
+        )?;
+
+        TransferApproval::close(transfer_approval_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Move tokens from the source token account into the transfer escrow,
+    /// queued for review by a compliance officer
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_request_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        request_id: u64,
+        amount: u64,
+    ) -> ProgramResult {
+        let [payer, owner, mint_info, source_token_account, destination_token_account, transfer_request_account, escrow_token_account, permanent_delegate_authority, transfer_hook_program, token_program, system_program_info, source_suspension, destination_suspension] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_token22_program(token_program)?;
+        verify_transfer_hook_program(transfer_hook_program)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_signer(owner)?;
+        verify_writable(source_token_account)?;
+        verify_writable(escrow_token_account)?;
+        verify_writable(transfer_request_account)?;
+        verify_account_not_initialized(transfer_request_account)?;
+
+        {
+            let source_token = TokenAccount::from_account_info(source_token_account)?;
+            if source_token.mint() != mint_info.key() || source_token.owner() != owner.key() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            let destination_token = TokenAccount::from_account_info(destination_token_account)?;
+            if destination_token.mint() != mint_info.key() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+        Self::verify_not_suspended(program_id, source_suspension, source_token_account)?;
+        Self::verify_not_suspended(
+            program_id,
+            destination_suspension,
+            destination_token_account,
+        )?;
+        Self::verify_transfer_escrow(program_id, mint_info, escrow_token_account)?;
+
+        let (expected_transfer_request_pda, bump) = find_transfer_request_pda(
+            source_token_account.key(),
+            destination_token_account.key(),
+            request_id,
+            program_id,
+        );
+        verify_pda_keys_match(
+            transfer_request_account.key(),
+            &expected_transfer_request_pda,
+        )?;
+
+        let transfer_request = TransferRequest::new(
+            *mint_info.key(),
+            *source_token_account.key(),
+            *destination_token_account.key(),
+            *payer.key(),
+            amount,
+            request_id,
+            bump,
+        )?;
+        let request_id_seed = &transfer_request.request_id_seed();
+        let bump_seed = &transfer_request.bump_seed();
+        let seeds = transfer_request.seeds(request_id_seed, bump_seed);
+        transfer_request.init(payer, transfer_request_account, &seeds)?;
+        transfer_request.write_data(transfer_request_account)?;
+
+        Self::transfer_with_permanent_delegate(
+            program_id,
+            amount,
+            mint_info,
+            source_token_account,
+            escrow_token_account,
+            transfer_hook_program,
+            permanent_delegate_authority,
+            TokenCpiStage::RequestTransfer,
+        )
+    }
+
+    /// Release a queued transfer request from the escrow to the destination token account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_approve_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, transfer_request_account, destination_token_account, escrow_token_account, rent_destination, permanent_delegate_authority, transfer_hook_program, token_program, destination_suspension] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_token22_program(token_program)?;
+        verify_transfer_hook_program(transfer_hook_program)?;
+        verify_writable(destination_token_account)?;
+        verify_writable(escrow_token_account)?;
+        verify_writable(rent_destination)?;
+
+        let transfer_request =
+            Self::load_transfer_request(program_id, transfer_request_account, mint_info)?;
+        if transfer_request.destination != *destination_token_account.key()
+            || transfer_request.payer != *rent_destination.key()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::verify_not_suspended(
+            program_id,
+            destination_suspension,
+            destination_token_account,
+        )?;
+        Self::verify_transfer_escrow(program_id, mint_info, escrow_token_account)?;
+
+        Self::transfer_with_permanent_delegate(
+            program_id,
+            transfer_request.amount,
+            mint_info,
+            escrow_token_account,
+            destination_token_account,
+            transfer_hook_program,
+            permanent_delegate_authority,
+            TokenCpiStage::ApproveTransfer,
+        )?;
+
+        TransferRequest::close(transfer_request_account, rent_destination)?;
+        Ok(())
+    }
+
+    /// Return a queued transfer request from the escrow to the source token account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_reject_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, transfer_request_account, source_token_account, escrow_token_account, rent_destination, permanent_delegate_authority, transfer_hook_program, token_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_token22_program(token_program)?;
+        verify_transfer_hook_program(transfer_hook_program)?;
+        verify_writable(source_token_account)?;
+        verify_writable(escrow_token_account)?;
+        verify_writable(rent_destination)?;
+
+        let transfer_request =
+            Self::load_transfer_request(program_id, transfer_request_account, mint_info)?;
+        if transfer_request.source != *source_token_account.key()
+            || transfer_request.payer != *rent_destination.key()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::verify_transfer_escrow(program_id, mint_info, escrow_token_account)?;
+
+        Self::transfer_with_permanent_delegate(
+            program_id,
+            transfer_request.amount,
+            mint_info,
+            escrow_token_account,
+            source_token_account,
+            transfer_hook_program,
+            permanent_delegate_authority,
+            TokenCpiStage::RejectTransfer,
+        )?;
+
+        TransferRequest::close(transfer_request_account, rent_destination)?;
+        Ok(())
+    }
+
+    /// Create AccrualConfig account holding the coupon terms of a debt token
+    /// and its coupon vault, funded by the issuer with payout tokens.
+    /// The transfer hook then keeps coupon claims of transfer parties up to date.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_accrual_config(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args: &CreateAccrualConfigArgs,
+    ) -> ProgramResult {
+        let [payer, mint_info, accrual_config_account, payout_mint, coupon_vault, payout_token_program, associated_token_program, system_program, config_account, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_underlying_token_program(payout_token_program)?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(accrual_config_account)?;
+        verify_writable(coupon_vault)?;
+        verify_account_not_initialized(accrual_config_account)?;
+        verify_account_not_initialized(coupon_vault)?;
+        verify_owner(payout_mint, payout_token_program.key())?;
+
+        let (expected_accrual_config_pda, bump) =
+            find_accrual_config_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(accrual_config_account.key(), &expected_accrual_config_pda)?;
+
+        let (expected_coupon_vault, _) = find_associated_token_address(
+            &expected_accrual_config_pda,
+            payout_mint.key(),
+            payout_token_program.key(),
+        );
+        verify_pda_keys_match(coupon_vault.key(), &expected_coupon_vault)?;
+
+        let accrual_config = AccrualConfig::new(
+            *mint_info.key(),
+            *payout_mint.key(),
+            args.rate_bps,
+            DayCountConvention::try_from(args.day_count)
+                .map_err(|_| ProgramError::InvalidArgument)?,
+            args.period_seconds,
+            args.start_timestamp,
+            args.principal_per_token,
+            bump,
+        )?;
+        let bump_seed = &accrual_config.bump_seed();
+        let seeds = accrual_config.seeds(bump_seed);
+        accrual_config.init(payer, accrual_config_account, &seeds)?;
+        accrual_config.write_data(accrual_config_account)?;
+
+        CreateTokenAccount {
+            funding_account: payer,
+            account: coupon_vault,
+            wallet: accrual_config_account,
+            mint: payout_mint,
+            system_program,
+            token_program: payout_token_program,
+        }
+        .invoke()?;
+
+        VerificationModule::sync_transfer_hook_rules(
+            program_id,
+            payer,
+            payer,
+            mint_info,
+            config_account,
+            system_program,
+            transfer_hook_accounts,
+            TransferHookRules {
+                coupon_accrual: Some(true),
+                ..TransferHookRules::default()
+            },
+        )
+    }
+
+    /// Pay the coupons accrued on a token account since its last claim.
+    /// Coupons of completed periods accrue on the lower of the balance snapshot taken at the
+    /// last claim and the current balance; the snapshot is then reset to the current balance.
+    /// The transfer hook rejects transfers of token accounts with unclaimed periods, so the
+    /// current balance was held through every period paid.
+    /// The first claim of a token account only takes the snapshot and starts accrual.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_claim_coupon(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [payer, mint_info, accrual_config_account, token_account, coupon_claim_account, payout_mint, coupon_vault, payout_token_account, payout_token_program, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_underlying_token_program(payout_token_program)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(coupon_claim_account)?;
+        verify_writable(coupon_vault)?;
+        verify_writable(payout_token_account)?;
+
+        let accrual_config =
+            Self::load_accrual_config(program_id, accrual_config_account, mint_info)?;
+        if payout_mint.key() != &accrual_config.payout_mint {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_owner(payout_mint, payout_token_program.key())?;
+        let (expected_coupon_vault, _) = find_associated_token_address(
+            accrual_config_account.key(),
+            payout_mint.key(),
+            payout_token_program.key(),
+        );
+        verify_pda_keys_match(coupon_vault.key(), &expected_coupon_vault)?;
+
+        let token = TokenAccount::from_account_info(token_account)?;
+        if token.mint() != mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let balance = token.amount();
+        let holder = *token.owner();
+        drop(token);
+
+        // Coupons are only paid to the debt token holder
+        verify_owner(payout_token_account, payout_token_program.key())?;
+        if underlying_token_account_owner(payout_token_account)? != holder {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (expected_coupon_claim_pda, bump) =
+            find_coupon_claim_pda(token_account.key(), program_id);
+        verify_pda_keys_match(coupon_claim_account.key(), &expected_coupon_claim_pda)?;
+
+        let current_period = accrual_config.completed_periods(Clock::get()?.unix_timestamp);
+
+        if coupon_claim_account.data_is_empty() {
+            let coupon_claim = CouponClaim::new(
+                *mint_info.key(),
+                *token_account.key(),
+                balance,
+                current_period,
+                bump,
+            );
+            let bump_seed = &coupon_claim.bump_seed();
+            let seeds = coupon_claim.seeds(bump_seed);
+            coupon_claim.init(payer, coupon_claim_account, &seeds)?;
+            coupon_claim.write_data(coupon_claim_account)?;
+            return Ok(());
+        }
+
+        verify_owner(coupon_claim_account, program_id)?;
+        let mut coupon_claim = CouponClaim::from_account_info(coupon_claim_account)?;
+        if coupon_claim.mint != *mint_info.key()
+            || coupon_claim.token_account != *token_account.key()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let periods = current_period.saturating_sub(coupon_claim.last_claimed_period);
+        let decimals = Mint::from_account_info(mint_info)?.decimals();
+        let amount = accrual_config.coupon_amount(
+            coupon_claim.balance_snapshot.min(balance),
+            periods,
+            decimals,
+        )?;
+
+        if amount > 0 {
+            if underlying_token_account_amount(coupon_vault)? < amount {
+                return Err(ProgramError::InsufficientFunds);
+            }
+            transfer_from_coupon_vault(
+                amount,
+                underlying_mint_decimals(payout_mint)?,
+                payout_mint,
+                coupon_vault,
+                payout_token_account,
+                accrual_config_account,
+                &accrual_config,
+                payout_token_program,
+            )
+            .map_err(token_cpi_error(TokenCpiStage::ClaimCoupon))?;
+        }
+
+        coupon_claim.balance_snapshot = balance;
+        coupon_claim.last_claimed_period = current_period;
+        coupon_claim.write_data(coupon_claim_account)?;
+        Ok(())
+    }
+
+    /// Create Maturity account holding the maturity terms of a debt token
+    /// and its redemption vault, pre-funded by the issuer with payout tokens
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_maturity(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        maturity_timestamp: i64,
+        redemption_cutoff: i64,
+        principal_per_token: u64,
+    ) -> ProgramResult {
+        let [payer, mint_info, maturity_account, payout_mint, redemption_vault, payout_token_program, associated_token_program, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_underlying_token_program(payout_token_program)?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(maturity_account)?;
+        verify_writable(redemption_vault)?;
+        verify_account_not_initialized(maturity_account)?;
+        verify_account_not_initialized(redemption_vault)?;
+        verify_owner(payout_mint, payout_token_program.key())?;
+
+        let (expected_maturity_pda, bump) = find_maturity_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(maturity_account.key(), &expected_maturity_pda)?;
+
+        let (expected_redemption_vault, _) = find_associated_token_address(
+            &expected_maturity_pda,
+            payout_mint.key(),
+            payout_token_program.key(),
+        );
+        verify_pda_keys_match(redemption_vault.key(), &expected_redemption_vault)?;
+
+        let maturity = Maturity::new(
+            *mint_info.key(),
+            *payout_mint.key(),
+            maturity_timestamp,
+            redemption_cutoff,
+            principal_per_token,
+            bump,
+        )?;
+        let bump_seed = &maturity.bump_seed();
+        let seeds = maturity.seeds(bump_seed);
+        maturity.init(payer, maturity_account, &seeds)?;
+        maturity.write_data(maturity_account)?;
+
+        CreateTokenAccount {
+            funding_account: payer,
+            account: redemption_vault,
+            wallet: maturity_account,
+            mint: payout_mint,
+            system_program,
+            token_program: payout_token_program,
+        }
+        .invoke()?;
+
+        Ok(())
+    }
+
+    /// Burn `amount` principal tokens of the owner and repay them at par from the redemption vault.
+    /// Only possible between maturity and the redemption cutoff.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_redeem_at_maturity(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let [permanent_delegate_authority, owner, mint_info, maturity_account, token_account, payout_mint, redemption_vault, payout_token_account, payout_token_program, token_program, permanent_delegate_config_account] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_token22_program(token_program)?;
+        verify_underlying_token_program(payout_token_program)?;
+        verify_signer(owner)?;
+        verify_writable(mint_info)?;
+        verify_writable(token_account)?;
+        verify_writable(redemption_vault)?;
+        verify_writable(payout_token_account)?;
+
+        let maturity = Self::load_maturity(
+            program_id,
+            maturity_account,
+            mint_info,
+            payout_mint,
+            redemption_vault,
+            payout_token_program,
+        )?;
+        if !maturity.is_redemption_open(Clock::get()?.unix_timestamp) {
+            return Err(SecurityTokenError::RedemptionNotOpen.into());
+        }
+
+        // Principal tokens are burned with the permanent delegate, so the owner is checked here
+        {
+            let token_account_state = TokenAccount::from_account_info(token_account)?;
+            if token_account_state.owner() != owner.key() {
+                return Err(ProgramError::IllegalOwner);
+            }
+        }
+
+        let decimals = Mint::from_account_info(mint_info)?.decimals();
+        let payout_amount = maturity.redemption_amount(amount, decimals)?;
+        if payout_amount == 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+        if underlying_token_account_amount(redemption_vault)? < payout_amount {
+            return Err(ProgramError::InsufficientFunds);
+        }
+
+        Self::burn_with_permanent_delegate(
+            program_id,
+            amount,
+            decimals,
+            mint_info,
+            token_account,
+            permanent_delegate_authority,
+            permanent_delegate_config_account,
+            TokenCpiStage::RedeemBurn,
+        )?;
+
+        transfer_from_redemption_vault(
+            payout_amount,
+            underlying_mint_decimals(payout_mint)?,
+            payout_mint,
+            redemption_vault,
+            payout_token_account,
+            maturity_account,
+            &maturity,
+            payout_token_program,
+        )
+        .map_err(token_cpi_error(TokenCpiStage::RedeemPayout))?;
+
+        Ok(())
+    }
+
+    /// Withdraw the funds left in the redemption vault once the redemption cutoff has passed
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_withdraw_unclaimed_redemption(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, maturity_account, payout_mint, redemption_vault, destination, payout_token_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_underlying_token_program(payout_token_program)?;
+        verify_writable(redemption_vault)?;
+        verify_writable(destination)?;
+
+        let maturity = Self::load_maturity(
+            program_id,
+            maturity_account,
+            mint_info,
+            payout_mint,
+            redemption_vault,
+            payout_token_program,
+        )?;
+        if Clock::get()?.unix_timestamp < maturity.redemption_cutoff {
+            return Err(SecurityTokenError::RedemptionCutoffNotReached.into());
+        }
+
+        let unclaimed = underlying_token_account_amount(redemption_vault)?;
+        if unclaimed == 0 {
+            return Ok(());
+        }
+
+        transfer_from_redemption_vault(
+            unclaimed,
+            underlying_mint_decimals(payout_mint)?,
+            payout_mint,
+            redemption_vault,
+            destination,
+            maturity_account,
+            &maturity,
+            payout_token_program,
+        )
+        .map_err(token_cpi_error(TokenCpiStage::WithdrawUnclaimedRedemption))
+    }
+
+    /// Create NavOracle account whitelisting the oracle the ScaledUiAmount multiplier is read from
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_nav_oracle(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args: &CreateNavOracleArgs,
+    ) -> ProgramResult {
+        let [payer, mint_info, nav_oracle_account, oracle, system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(nav_oracle_account)?;
+        verify_account_not_initialized(nav_oracle_account)?;
+
+        let (expected_nav_oracle_pda, bump) = find_nav_oracle_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(nav_oracle_account.key(), &expected_nav_oracle_pda)?;
+
+        let nav_oracle = NavOracle::new(
+            *mint_info.key(),
+            *oracle.key(),
+            args.value_offset,
+            args.value_decimals,
+            args.min_value,
+            args.max_value,
+            args.min_update_interval,
+            bump,
+        )?;
+        let bump_seed = &nav_oracle.bump_seed();
+        let seeds = nav_oracle.seeds(bump_seed);
+        nav_oracle.init(payer, nav_oracle_account, &seeds)?;
+        nav_oracle.write_data(nav_oracle_account)?;
+
+        Ok(())
+    }
+
+    /// Update the ScaledUiAmount multiplier from the whitelisted oracle of the mint.
+    /// The oracle value must be within the configured bounds and the minimum update interval
+    /// must have passed since the last update. The new multiplier takes effect immediately.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_update_multiplier_from_oracle(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, nav_oracle_account, oracle, scaled_ui_amount_authority, token_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_token22_program(token_program)?;
+        verify_writable(mint_info)?;
+        verify_writable(nav_oracle_account)?;
+
+        let mut nav_oracle = Self::load_nav_oracle(program_id, nav_oracle_account, mint_info)?;
+        if nav_oracle.oracle != *oracle.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (scaled_ui_amount_authority_pda, bump) =
+            find_scaled_ui_amount_authority_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(
+            scaled_ui_amount_authority.key(),
+            &scaled_ui_amount_authority_pda,
+        )?;
+
+        let value = nav_oracle.read_value(&oracle.try_borrow_data()?)?;
+        let timestamp = Clock::get()?.unix_timestamp;
+        nav_oracle.record_update(value, timestamp)?;
+        nav_oracle.write_data(nav_oracle_account)?;
+
+        let bump_seed = [bump];
+        let seeds = [
+            Seed::from(seeds::SCALED_UI_AMOUNT_AUTHORITY),
+            Seed::from(mint_info.key().as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ];
+        UpdateMultiplier {
+            mint: mint_info,
+            authority: scaled_ui_amount_authority,
+            multiplier: nav_oracle.multiplier(value),
+            effective_timestamp: timestamp,
+        }
+        .invoke_signed(&[Signer::from(&seeds)])
+        .map_err(token_cpi_error(TokenCpiStage::UpdateMultiplier))
+    }
+
+    /// Open a primary offering of the security token and create its payment vault.
+    /// Investors commit payment tokens until `close_timestamp`, the proceeds go to `proceeds_account`.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_open_subscription(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        subscription_id: u64,
+        price_per_token: u64,
+        close_timestamp: i64,
+    ) -> ProgramResult {
+        let [payer, mint_info, subscription_account, payment_mint, payment_vault, proceeds_account, payment_token_program, associated_token_program, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_underlying_token_program(payment_token_program)?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(subscription_account)?;
+        verify_writable(payment_vault)?;
+        verify_account_not_initialized(subscription_account)?;
+        verify_account_not_initialized(payment_vault)?;
+        verify_owner(payment_mint, payment_token_program.key())?;
+        verify_owner(proceeds_account, payment_token_program.key())?;
+        if underlying_token_account_mint(proceeds_account)? != *payment_mint.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if close_timestamp <= Clock::get()?.unix_timestamp {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let (expected_subscription_pda, bump) =
+            find_subscription_pda(mint_info.key(), subscription_id, program_id);
+        verify_pda_keys_match(subscription_account.key(), &expected_subscription_pda)?;
+
+        let (expected_payment_vault, _) = find_associated_token_address(
+            &expected_subscription_pda,
+            payment_mint.key(),
+            payment_token_program.key(),
+        );
+        verify_pda_keys_match(payment_vault.key(), &expected_payment_vault)?;
+
+        let subscription = Subscription::new(
+            *mint_info.key(),
+            *payment_mint.key(),
+            *proceeds_account.key(),
+            subscription_id,
+            price_per_token,
+            close_timestamp,
+            bump,
+        )?;
+        let subscription_id_seed = &subscription.subscription_id_seed();
+        let bump_seed = &subscription.bump_seed();
+        let seeds = subscription.seeds(subscription_id_seed, bump_seed);
+        subscription.init(payer, subscription_account, &seeds)?;
+        subscription.write_data(subscription_account)?;
+
+        CreateTokenAccount {
+            funding_account: payer,
+            account: payment_vault,
+            wallet: subscription_account,
+            mint: payment_mint,
+            system_program,
+            token_program: payment_token_program,
+        }
+        .invoke()?;
+
+        Ok(())
+    }
+
+    /// Escrow `amount` payment tokens of the investor in the subscription payment vault
+    /// while the subscription is open
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_commit(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let [payer, investor, mint_info, subscription_account, commitment_account, payment_mint, payment_vault, investor_payment_account, payment_token_program, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_underlying_token_program(payment_token_program)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_signer(investor)?;
+        verify_writable(subscription_account)?;
+        verify_writable(commitment_account)?;
+        verify_writable(payment_vault)?;
+        verify_writable(investor_payment_account)?;
+
+        if amount == 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut subscription =
+            Self::load_subscription(program_id, subscription_account, mint_info)?;
+        Self::verify_subscription_payment_vault(
+            subscription_account,
+            &subscription,
+            payment_mint,
+            payment_vault,
+            payment_token_program,
+        )?;
+        if !subscription.is_open(Clock::get()?.unix_timestamp) {
+            return Err(SecurityTokenError::SubscriptionClosed.into());
+        }
+
+        let (expected_commitment_pda, bump) = find_subscription_commitment_pda(
+            subscription_account.key(),
+            investor.key(),
+            program_id,
+        );
+        verify_pda_keys_match(commitment_account.key(), &expected_commitment_pda)?;
+
+        let mut commitment = if commitment_account.data_is_empty() {
+            let commitment =
+                SubscriptionCommitment::new(*subscription_account.key(), *investor.key(), bump);
+            let bump_seed = &commitment.bump_seed();
+            let seeds = commitment.seeds(bump_seed);
+            commitment.init(payer, commitment_account, &seeds)?;
+            commitment
+        } else {
+            Self::load_subscription_commitment(
+                program_id,
+                commitment_account,
+                subscription_account,
+            )?
+        };
+
+        // Credit what the vault actually received, Token 2022 transfer fees are withheld from it
+        let vault_amount_before = underlying_token_account_amount(payment_vault)?;
+        TransferChecked {
+            from: investor_payment_account,
+            mint: payment_mint,
+            to: payment_vault,
+            authority: investor,
+            amount,
+            decimals: underlying_mint_decimals(payment_mint)?,
+            token_program: payment_token_program.key(),
+        }
+        .invoke()
+        .map_err(token_cpi_error(TokenCpiStage::CommitPayment))?;
+        let committed_amount = underlying_token_account_amount(payment_vault)?
+            .checked_sub(vault_amount_before)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        commitment.amount = commitment
+            .amount
+            .checked_add(committed_amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        subscription.total_committed = subscription
+            .total_committed
+            .checked_add(committed_amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        commitment.write_data(commitment_account)?;
+        subscription.write_data(subscription_account)?;
+        Ok(())
+    }
+
+    /// Allocate `allocation` security token base units to a commitment once the subscription closed.
+    /// Replaces any previous allocation of the commitment.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_allocate(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        allocation: u64,
+    ) -> ProgramResult {
+        let [mint_info, subscription_account, commitment_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_writable(subscription_account)?;
+        verify_writable(commitment_account)?;
+
+        let mut subscription =
+            Self::load_subscription(program_id, subscription_account, mint_info)?;
+        if subscription.is_open(Clock::get()?.unix_timestamp) {
+            return Err(SecurityTokenError::SubscriptionNotClosed.into());
+        }
+        let mut commitment = Self::load_subscription_commitment(
+            program_id,
+            commitment_account,
+            subscription_account,
+        )?;
+
+        let decimals = Mint::from_account_info(mint_info)?.decimals();
+        if subscription.payment_amount(allocation, decimals)? > commitment.amount {
+            return Err(SecurityTokenError::AllocationExceedsCommitment.into());
+        }
+
+        subscription.total_allocated = subscription
+            .total_allocated
+            .checked_sub(commitment.allocation)
+            .and_then(|total| total.checked_add(allocation))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        commitment.allocation = allocation;
+        commitment.write_data(commitment_account)?;
+        subscription.write_data(subscription_account)?;
+        Ok(())
+    }
+
+    /// Settle a commitment once the subscription closed: mint the allocation to the investor,
+    /// pay its price to the issuer proceeds account, refund the rest of the payment to the investor
+    /// and close the commitment
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_settle(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_authority, investor, mint_info, subscription_account, commitment_account, token_account, payment_mint, payment_vault, proceeds_account, investor_payment_account, payment_token_program, token_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_token22_program(token_program)?;
+        verify_underlying_token_program(payment_token_program)?;
+        verify_owner(mint_authority, program_id)?;
+        verify_writable(investor)?;
+        verify_writable(mint_info)?;
+        verify_writable(commitment_account)?;
+        verify_writable(token_account)?;
+        verify_writable(payment_vault)?;
+        verify_writable(proceeds_account)?;
+        verify_writable(investor_payment_account)?;
+
+        let subscription = Self::load_subscription(program_id, subscription_account, mint_info)?;
+        Self::verify_subscription_payment_vault(
+            subscription_account,
+            &subscription,
+            payment_mint,
+            payment_vault,
+            payment_token_program,
+        )?;
+        if subscription.is_open(Clock::get()?.unix_timestamp) {
+            return Err(SecurityTokenError::SubscriptionNotClosed.into());
+        }
+        if proceeds_account.key() != &subscription.proceeds_account {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let commitment = Self::load_subscription_commitment(
+            program_id,
+            commitment_account,
+            subscription_account,
+        )?;
+        if investor.key() != &commitment.investor {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Allocation and refund only go to accounts of the investor
+        {
+            let token = TokenAccount::from_account_info(token_account)?;
+            if token.mint() != mint_info.key() || token.owner() != investor.key() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+        verify_owner(investor_payment_account, payment_token_program.key())?;
+        if underlying_token_account_owner(investor_payment_account)? != *investor.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint_authority_state = MintAuthority::from_account_info(mint_authority)?;
+        if mint_authority_state.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let decimals = Mint::from_account_info(mint_info)?.decimals();
+        let payment_decimals = underlying_mint_decimals(payment_mint)?;
+        let payment = subscription.payment_amount(commitment.allocation, decimals)?;
+        let refund = commitment
+            .amount
+            .checked_sub(payment)
+            .ok_or(SecurityTokenError::AllocationExceedsCommitment)?;
+
+        if commitment.allocation > 0 {
+            mint_to_checked(
+                commitment.allocation,
+                decimals,
+                mint_info,
+                token_account,
+                mint_authority,
+                &mint_authority_state,
+            )
+            .map_err(token_cpi_error(TokenCpiStage::SettleMint))?;
+        }
+        if payment > 0 {
+            transfer_from_payment_vault(
+                payment,
+                payment_decimals,
+                payment_mint,
+                payment_vault,
+                proceeds_account,
+                subscription_account,
+                &subscription,
+                payment_token_program,
+            )
+            .map_err(token_cpi_error(TokenCpiStage::SettlePayment))?;
+        }
+        if refund > 0 {
+            transfer_from_payment_vault(
+                refund,
+                payment_decimals,
+                payment_mint,
+                payment_vault,
+                investor_payment_account,
+                subscription_account,
+                &subscription,
+                payment_token_program,
+            )
+            .map_err(token_cpi_error(TokenCpiStage::SettleRefund))?;
+        }
+
+        SubscriptionCommitment::close(commitment_account, investor)?;
+        Ok(())
+    }
+
+    /// Create a Dutch auction of the security token and its payment vault.
+    /// Bidders escrow payment at the descending price until the supply sells out or the auction ends,
+    /// the proceeds go to `proceeds_account`.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_auction(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args: &CreateAuctionArgs,
+    ) -> ProgramResult {
+        let [payer, mint_info, auction_account, payment_mint, payment_vault, proceeds_account, payment_token_program, associated_token_program, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_underlying_token_program(payment_token_program)?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(auction_account)?;
+        verify_writable(payment_vault)?;
+        verify_account_not_initialized(auction_account)?;
+        verify_account_not_initialized(payment_vault)?;
+        verify_owner(payment_mint, payment_token_program.key())?;
+        verify_owner(proceeds_account, payment_token_program.key())?;
+        if underlying_token_account_mint(proceeds_account)? != *payment_mint.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if args.end_timestamp <= Clock::get()?.unix_timestamp {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let (expected_auction_pda, bump) =
+            find_auction_pda(mint_info.key(), args.auction_id, program_id);
+        verify_pda_keys_match(auction_account.key(), &expected_auction_pda)?;
+
+        let (expected_payment_vault, _) = find_associated_token_address(
+            &expected_auction_pda,
+            payment_mint.key(),
+            payment_token_program.key(),
+        );
+        verify_pda_keys_match(payment_vault.key(), &expected_payment_vault)?;
+
+        let auction = Auction::new(
+            *mint_info.key(),
+            *payment_mint.key(),
+            *proceeds_account.key(),
+            args.auction_id,
+            args.start_price,
+            args.floor_price,
+            args.start_timestamp,
+            args.end_timestamp,
+            args.supply,
+            bump,
+        )?;
+        let auction_id_seed = &auction.auction_id_seed();
+        let bump_seed = &auction.bump_seed();
+        let seeds = auction.seeds(auction_id_seed, bump_seed);
+        auction.init(payer, auction_account, &seeds)?;
+        auction.write_data(auction_account)?;
+
+        CreateTokenAccount {
+            funding_account: payer,
+            account: payment_vault,
+            wallet: auction_account,
+            mint: payment_mint,
+            system_program,
+            token_program: payment_token_program,
+        }
+        .invoke()?;
+
+        Ok(())
+    }
+
+    /// Bid for `quantity` security token base units at the current auction price and
+    /// escrow its payment in the auction payment vault. One bid per bidder.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_place_bid(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        quantity: u64,
+    ) -> ProgramResult {
+        let [payer, bidder, mint_info, auction_account, bid_account, payment_mint, payment_vault, bidder_payment_account, payment_token_program, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_underlying_token_program(payment_token_program)?;
+        verify_system_program(system_program)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_signer(bidder)?;
+        verify_writable(auction_account)?;
+        verify_writable(bid_account)?;
+        verify_writable(payment_vault)?;
+        verify_writable(bidder_payment_account)?;
+        verify_account_not_initialized(bid_account)?;
+
+        if quantity == 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut auction = Self::load_auction(program_id, auction_account, mint_info)?;
+        Self::verify_auction_payment_vault(
+            auction_account,
+            &auction,
+            payment_mint,
+            payment_vault,
+            payment_token_program,
+        )?;
+        let timestamp = Clock::get()?.unix_timestamp;
+        if !auction.is_open(timestamp) {
+            return Err(SecurityTokenError::AuctionNotOpen.into());
+        }
+
+        let (expected_bid_pda, bump) =
+            find_auction_bid_pda(auction_account.key(), bidder.key(), program_id);
+        verify_pda_keys_match(bid_account.key(), &expected_bid_pda)?;
+
+        let decimals = Mint::from_account_info(mint_info)?.decimals();
+        let bid_price = auction.current_price(timestamp);
+        let payment = auction.payment_amount(quantity, bid_price, decimals)?;
+        if payment == 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Escrow what the vault actually received, Token 2022 transfer fees are withheld from it
+        let vault_amount_before = underlying_token_account_amount(payment_vault)?;
+        TransferChecked {
+            from: bidder_payment_account,
+            mint: payment_mint,
+            to: payment_vault,
+            authority: bidder,
+            amount: payment,
+            decimals: underlying_mint_decimals(payment_mint)?,
+            token_program: payment_token_program.key(),
+        }
+        .invoke()
+        .map_err(token_cpi_error(TokenCpiStage::PlaceBid))?;
+        let escrow_amount = underlying_token_account_amount(payment_vault)?
+            .checked_sub(vault_amount_before)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        // The escrow has to cover the bid even if the clearing price doesn't fall below it
+        if escrow_amount < payment {
+            return Err(ProgramError::InsufficientFunds);
+        }
+
+        let bid = AuctionBid::new(
+            *auction_account.key(),
+            *bidder.key(),
+            quantity,
+            bid_price,
+            escrow_amount,
+            auction.total_bid_quantity,
+            bump,
+        );
+        let bump_seed = &bid.bump_seed();
+        let seeds = bid.seeds(bump_seed);
+        bid.init(payer, bid_account, &seeds)?;
+        bid.write_data(bid_account)?;
+
+        auction.total_bid_quantity = auction
+            .total_bid_quantity
+            .checked_add(quantity)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        auction.last_bid_price = bid_price;
+        auction.write_data(auction_account)?;
+        Ok(())
+    }
+
+    /// Fix the clearing price of an auction once its supply sold out or bidding ended
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_clear_auction(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, auction_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_writable(auction_account)?;
+
+        let mut auction = Self::load_auction(program_id, auction_account, mint_info)?;
+        if !auction.is_clearable(Clock::get()?.unix_timestamp) {
+            return Err(SecurityTokenError::AuctionNotClearable.into());
+        }
+
+        auction.clear();
+        auction.write_data(auction_account)?;
+        Ok(())
+    }
+
+    /// Settle a bid once the auction cleared: mint the filled quantity to the bidder,
+    /// pay it at the clearing price to the issuer proceeds account, refund the rest of the
+    /// escrow to the bidder and close the bid
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_settle_bid(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_authority, bidder, mint_info, auction_account, bid_account, token_account, payment_mint, payment_vault, proceeds_account, bidder_payment_account, payment_token_program, token_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+        verify_token22_program(token_program)?;
+        verify_underlying_token_program(payment_token_program)?;
+        verify_owner(mint_authority, program_id)?;
+        verify_writable(bidder)?;
+        verify_writable(mint_info)?;
+        verify_writable(bid_account)?;
+        verify_writable(token_account)?;
+        verify_writable(payment_vault)?;
+        verify_writable(proceeds_account)?;
+        verify_writable(bidder_payment_account)?;
+
+        let auction = Self::load_auction(program_id, auction_account, mint_info)?;
+        Self::verify_auction_payment_vault(
+            auction_account,
+            &auction,
+            payment_mint,
+            payment_vault,
+            payment_token_program,
+        )?;
+        if !auction.is_cleared() {
+            return Err(SecurityTokenError::AuctionNotCleared.into());
+        }
+        if proceeds_account.key() != &auction.proceeds_account {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let bid = Self::load_auction_bid(program_id, bid_account, auction_account)?;
+        if bidder.key() != &bid.bidder {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Tokens and refund only go to accounts of the bidder
+        {
+            let token = TokenAccount::from_account_info(token_account)?;
+            if token.mint() != mint_info.key() || token.owner() != bidder.key() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+        verify_owner(bidder_payment_account, payment_token_program.key())?;
+        if underlying_token_account_owner(bidder_payment_account)? != *bidder.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint_authority_state = MintAuthority::from_account_info(mint_authority)?;
+        if mint_authority_state.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let decimals = Mint::from_account_info(mint_info)?.decimals();
+        let payment_decimals = underlying_mint_decimals(payment_mint)?;
+        let allocation = bid.allocation(auction.supply);
+        let payment = auction.payment_amount(allocation, auction.clearing_price, decimals)?;
+        // Clearing price never exceeds the bid price, so the escrow covers the payment
+        let refund = bid
+            .escrow_amount
+            .checked_sub(payment)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        if allocation > 0 {
+            mint_to_checked(
+                allocation,
+                decimals,
+                mint_info,
+                token_account,
+                mint_authority,
+                &mint_authority_state,
+            )
+            .map_err(token_cpi_error(TokenCpiStage::SettleBidMint))?;
+        }
+        if payment > 0 {
+            transfer_from_auction_vault(
+                payment,
+                payment_decimals,
+                payment_mint,
+                payment_vault,
+                proceeds_account,
+                auction_account,
+                &auction,
+                payment_token_program,
+            )
+            .map_err(token_cpi_error(TokenCpiStage::SettleBidPayment))?;
+        }
+        if refund > 0 {
+            transfer_from_auction_vault(
+                refund,
+                payment_decimals,
+                payment_mint,
+                payment_vault,
+                bidder_payment_account,
+                auction_account,
+                &auction,
+                payment_token_program,
+            )
+            .map_err(token_cpi_error(TokenCpiStage::SettleBidRefund))?;
+        }
+
+        AuctionBid::close(bid_account, bidder)?;
+        Ok(())
+    }
+
+    /// Create HoldingPeriod account and make the transfer hook keep locked lots in their
+    /// token accounts
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_create_holding_period(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        holding_period: u64,
+    ) -> ProgramResult {
+        let [payer, mint_info, holding_period_account, config_account, system_program_info, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(holding_period_account)?;
+        verify_account_not_initialized(holding_period_account)?;
+
+        let (expected_holding_period_pda, bump) =
+            find_holding_period_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(holding_period_account.key(), &expected_holding_period_pda)?;
+
+        let holding_period = HoldingPeriod::new(*mint_info.key(), holding_period, bump)?;
+        let bump_seed = &holding_period.bump_seed();
+        let seeds = holding_period.seeds(bump_seed);
+        holding_period.init(payer, holding_period_account, &seeds)?;
+        holding_period.write_data(holding_period_account)?;
+
+        VerificationModule::sync_transfer_hook_rules(
+            program_id,
+            payer,
+            payer,
+            mint_info,
+            config_account,
+            system_program_info,
+            transfer_hook_accounts,
+            TransferHookRules {
+                holding_period: Some(true),
+                ..TransferHookRules::default()
+            },
+        )
+    }
+
+    /// Close HoldingPeriod account and stop the transfer hook from enforcing locked lots.
+    /// Existing lots stay recorded and can still be unlocked.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_close_holding_period(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [payer, mint_info, holding_period_account, config_account, rent_recipient, system_program_info, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_rent_recipient(rent_recipient, holding_period_account)?;
+        verify_writable(holding_period_account)?;
+
+        Self::load_holding_period(program_id, holding_period_account, mint_info)?;
+
+        // Remove hook accounts first, otherwise transfers would resolve a closed account
+        VerificationModule::sync_transfer_hook_rules(
+            program_id,
+            payer,
+            rent_recipient,
+            mint_info,
+            config_account,
+            system_program_info,
+            transfer_hook_accounts,
+            TransferHookRules {
+                holding_period: Some(false),
+                ..TransferHookRules::default()
+            },
+        )?;
+
+        HoldingPeriod::close(holding_period_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Release a lot whose holding period elapsed from the restricted balance of its token
+    /// account and return the lot rent to its payer. Anyone can unlock.
+    pub fn execute_unlock_lot(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let [restricted_holding_account, holding_lot_account, rent_destination] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_writable(restricted_holding_account)?;
+        verify_writable(holding_lot_account)?;
+        verify_writable(rent_destination)?;
+        verify_owner(holding_lot_account, program_id)?;
+        verify_account_initialized(holding_lot_account)?;
+
+        let holding_lot = HoldingLot::from_account_info(holding_lot_account)?;
+        verify_pda_keys_match(holding_lot_account.key(), &holding_lot.derive_pda()?)?;
+        if holding_lot.payer != *rent_destination.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut restricted_holding =
+            RestrictedHolding::from_account_info(restricted_holding_account)?;
+        if restricted_holding.token_account != holding_lot.token_account {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            restricted_holding_account.key(),
+            &restricted_holding.derive_pda()?,
+        )?;
+
+        holding_lot.verify_unlockable(Clock::get()?.unix_timestamp)?;
+
+        restricted_holding.release_lot(holding_lot.amount);
+        restricted_holding.write_data(restricted_holding_account)?;
+
+        HoldingLot::close(holding_lot_account, rent_destination)?;
+        Ok(())
+    }
+
+    /// Put a token account of the mint under an investigative hold.
+    /// The Suspension account blocks transfers from and to the token account in the transfer
+    /// hook and the escrow transfer flows, the Token-2022 freeze state is left untouched.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_suspend(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [payer, mint_info, token_account, suspension_account, system_program_info] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(suspension_account)?;
+        verify_account_not_initialized(suspension_account)?;
+
+        if TokenAccount::from_account_info(token_account)?.mint() != mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (expected_suspension_pda, bump) = find_suspension_pda(token_account.key(), program_id);
+        verify_pda_keys_match(suspension_account.key(), &expected_suspension_pda)?;
+
+        let suspension = Suspension::new(
+            *mint_info.key(),
+            *token_account.key(),
+            *payer.key(),
+            Clock::get()?.unix_timestamp,
+            bump,
+        );
+        let bump_seed = &suspension.bump_seed();
+        let seeds = suspension.seeds(bump_seed);
+        suspension.init(payer, suspension_account, &seeds)?;
+        suspension.write_data(suspension_account)?;
+        Ok(())
+    }
+
+    /// Lift the investigative hold of a token account and return the Suspension rent to its payer
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn execute_unsuspend(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_info, suspension_account, rent_destination] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_writable(suspension_account)?;
+        verify_writable(rent_destination)?;
+        verify_owner(suspension_account, program_id)?;
+        verify_account_initialized(suspension_account)?;
+
+        let suspension = Suspension::from_account_info(suspension_account)?;
+        if suspension.mint != *mint_info.key() || suspension.payer != *rent_destination.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(suspension_account.key(), &suspension.derive_pda()?)?;
+
+        Suspension::close(suspension_account, rent_destination)?;
+        Ok(())
+    }
+
+    /// Create a linear vesting grant of `total_amount` tokens for the beneficiary token account.
+    /// The granted tokens are minted into the transfer escrow and released with ReleaseVested.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute_create_vesting(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        vesting_id: u64,
+        total_amount: u64,
+        start_timestamp: i64,
+        end_timestamp: i64,
+    ) -> ProgramResult {
+        let [payer, mint_authority, mint_info, beneficiary_token_account, vesting_account, escrow_authority, escrow_token_account, system_program_info, token_program, associated_token_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_system_program(system_program_info)?;
+        verify_token22_program(token_program)?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_owner(mint_authority, program_id)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(mint_info)?;
+        verify_writable(vesting_account)?;
+        verify_writable(escrow_token_account)?;
+        verify_account_not_initialized(vesting_account)?;
+
+        if TokenAccount::from_account_info(beneficiary_token_account)?.mint() != mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint_authority_state = MintAuthority::from_account_info(mint_authority)?;
+        if mint_authority_state.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (expected_vesting_pda, bump) =
+            find_vesting_pda(beneficiary_token_account.key(), vesting_id, program_id);
+        verify_pda_keys_match(vesting_account.key(), &expected_vesting_pda)?;
+
+        let vesting = Vesting::new(
+            *mint_info.key(),
+            *beneficiary_token_account.key(),
+            *payer.key(),
+            total_amount,
+            start_timestamp,
+            end_timestamp,
+            vesting_id,
+            bump,
+        )?;
+        let vesting_id_seed = &vesting.vesting_id_seed();
+        let bump_seed = &vesting.bump_seed();
+        let seeds = vesting.seeds(vesting_id_seed, bump_seed);
+        vesting.init(payer, vesting_account, &seeds)?;
+        vesting.write_data(vesting_account)?;
+
+        Self::create_transfer_escrow_if_missing(
+            program_id,
+            payer,
+            mint_info,
+            escrow_authority,
+            escrow_token_account,
+            system_program_info,
+            token_program,
+        )?;
+
+        let decimals = Mint::from_account_info(mint_info)?.decimals();
+        mint_to_checked(
+            total_amount,
+            decimals,
+            mint_info,
+            escrow_token_account,
+            mint_authority,
+            &mint_authority_state,
+        )
+        .map_err(token_cpi_error(TokenCpiStage::CreateVesting))
+    }
+
+    /// Release the tokens vested since the last release from the transfer escrow to the
+    /// beneficiary token account. Permissionless, the Vesting rent is returned to its payer
+    /// once the whole grant is released.
+    pub fn execute_release_vested(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let [mint_info, vesting_account, beneficiary_token_account, escrow_token_account, beneficiary_suspension, rent_destination, permanent_delegate_authority, transfer_hook_program, token_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_token22_program(token_program)?;
+        verify_transfer_hook_program(transfer_hook_program)?;
+        verify_writable(vesting_account)?;
+        verify_writable(beneficiary_token_account)?;
+        verify_writable(escrow_token_account)?;
+        verify_writable(rent_destination)?;
+        verify_owner(vesting_account, program_id)?;
+        verify_account_initialized(vesting_account)?;
+
+        let mut vesting = Vesting::from_account_info(vesting_account)?;
+        if vesting.mint != *mint_info.key()
+            || vesting.beneficiary != *beneficiary_token_account.key()
+            || vesting.payer != *rent_destination.key()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(vesting_account.key(), &vesting.derive_pda()?)?;
+        Self::verify_not_suspended(
+            program_id,
+            beneficiary_suspension,
+            beneficiary_token_account,
+        )?;
+        Self::verify_transfer_escrow(program_id, mint_info, escrow_token_account)?;
+
+        let amount = vesting.release(Clock::get()?.unix_timestamp)?;
+        Self::transfer_with_permanent_delegate(
+            program_id,
+            amount,
+            mint_info,
+            escrow_token_account,
+            beneficiary_token_account,
+            transfer_hook_program,
+            permanent_delegate_authority,
+            TokenCpiStage::ReleaseVested,
+        )?;
+
+        if vesting.is_fully_released() {
+            Vesting::close(vesting_account, rent_destination)
+        } else {
+            vesting.write_data(vesting_account)
+        }
+    }
+
+    /// Thaw `token_account` signing with the freeze authority PDA of the mint
+    fn thaw_token_account(
+        program_id: &Pubkey,
+        freeze_authority: &AccountInfo,
+        mint_info: &AccountInfo,
+        token_account: &AccountInfo,
+        token_program: &AccountInfo,
+        stage: TokenCpiStage,
+    ) -> ProgramResult {
+        let (freeze_authority_pda, bump) = find_freeze_authority_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(freeze_authority.key(), &freeze_authority_pda)?;
+        let thaw_instruction = ThawAccount {
+            account: token_account,
+            mint: mint_info,
+            freeze_authority,
+            token_program: token_program.key(),
+        };
+        let bump_seed = [bump];
+        let seeds = [
+            Seed::from(seeds::FREEZE_AUTHORITY),
+            Seed::from(mint_info.key().as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ];
+
+        let thaw_authority_signer = Signer::from(&seeds);
+        thaw_instruction
+            .invoke_signed(&[thaw_authority_signer])
+            .map_err(token_cpi_error(stage))
+    }
+
+    /// Create the FreezeExpiry of a frozen token account
+    fn record_freeze_expiry(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        token_account: &AccountInfo,
+        auto_thaw_at: i64,
+        expiry_accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [payer, freeze_expiry_account, system_program_info, ..] = expiry_accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(freeze_expiry_account)?;
+        verify_account_not_initialized(freeze_expiry_account)?;
+
+        if auto_thaw_at <= Clock::get()?.unix_timestamp {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let (expected_freeze_expiry_pda, bump) =
+            find_freeze_expiry_pda(token_account.key(), program_id);
+        verify_pda_keys_match(freeze_expiry_account.key(), &expected_freeze_expiry_pda)?;
+
+        let freeze_expiry = FreezeExpiry::new(
+            *mint_info.key(),
+            *token_account.key(),
+            *payer.key(),
+            auto_thaw_at,
+            bump,
+        );
+        let bump_seed = &freeze_expiry.bump_seed();
+        let seeds = freeze_expiry.seeds(bump_seed);
+        freeze_expiry.init(payer, freeze_expiry_account, &seeds)?;
+        freeze_expiry.write_data(freeze_expiry_account)?;
+        Ok(())
+    }
+
+    /// Load the FreezeExpiry of `token_account`, verify its PDA and that `rent_destination` paid it
+    fn load_freeze_expiry(
+        program_id: &Pubkey,
+        freeze_expiry_account: &AccountInfo,
+        token_account: &AccountInfo,
+        rent_destination: &AccountInfo,
+    ) -> Result<FreezeExpiry, ProgramError> {
+        verify_writable(freeze_expiry_account)?;
+        verify_writable(rent_destination)?;
+        verify_owner(freeze_expiry_account, program_id)?;
+        verify_account_initialized(freeze_expiry_account)?;
+
+        let freeze_expiry = FreezeExpiry::from_account_info(freeze_expiry_account)?;
+        if freeze_expiry.token_account != *token_account.key()
+            || freeze_expiry.payer != *rent_destination.key()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(freeze_expiry_account.key(), &freeze_expiry.derive_pda()?)?;
+        Ok(freeze_expiry)
+    }
+
+    /// Load the NavOracle account of `mint_info` and verify its PDA
+    fn load_nav_oracle(
+        program_id: &Pubkey,
+        nav_oracle_account: &AccountInfo,
+        mint_info: &AccountInfo,
+    ) -> Result<NavOracle, ProgramError> {
+        verify_owner(nav_oracle_account, program_id)?;
+        verify_account_initialized(nav_oracle_account)?;
+
+        let nav_oracle = NavOracle::from_account_info(nav_oracle_account)?;
+        if nav_oracle.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(nav_oracle_account.key(), &nav_oracle.derive_pda()?)?;
+        Ok(nav_oracle)
+    }
+
+    /// Load the Subscription account of `mint_info` and verify its PDA
+    fn load_subscription(
+        program_id: &Pubkey,
+        subscription_account: &AccountInfo,
+        mint_info: &AccountInfo,
+    ) -> Result<Subscription, ProgramError> {
+        verify_owner(subscription_account, program_id)?;
+        verify_account_initialized(subscription_account)?;
+
+        let subscription = Subscription::from_account_info(subscription_account)?;
+        if subscription.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(subscription_account.key(), &subscription.derive_pda()?)?;
+        Ok(subscription)
+    }
+
+    /// Verify the payment mint and payment vault of a subscription
+    fn verify_subscription_payment_vault(
+        subscription_account: &AccountInfo,
+        subscription: &Subscription,
+        payment_mint: &AccountInfo,
+        payment_vault: &AccountInfo,
+        payment_token_program: &AccountInfo,
+    ) -> ProgramResult {
+        if subscription.payment_mint != *payment_mint.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_owner(payment_mint, payment_token_program.key())?;
+        let (expected_payment_vault, _) = find_associated_token_address(
+            subscription_account.key(),
+            payment_mint.key(),
+            payment_token_program.key(),
+        );
+        verify_pda_keys_match(payment_vault.key(), &expected_payment_vault)
+    }
+
+    /// Load the SubscriptionCommitment account of `subscription_account` and verify its PDA
+    fn load_subscription_commitment(
+        program_id: &Pubkey,
+        commitment_account: &AccountInfo,
+        subscription_account: &AccountInfo,
+    ) -> Result<SubscriptionCommitment, ProgramError> {
+        verify_owner(commitment_account, program_id)?;
+        verify_account_initialized(commitment_account)?;
+
+        let commitment = SubscriptionCommitment::from_account_info(commitment_account)?;
+        if commitment.subscription != *subscription_account.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(commitment_account.key(), &commitment.derive_pda()?)?;
+        Ok(commitment)
+    }
+
+    /// Load the Auction account of `mint_info` and verify its PDA
+    fn load_auction(
+        program_id: &Pubkey,
+        auction_account: &AccountInfo,
+        mint_info: &AccountInfo,
+    ) -> Result<Auction, ProgramError> {
+        verify_owner(auction_account, program_id)?;
+        verify_account_initialized(auction_account)?;
+
+        let auction = Auction::from_account_info(auction_account)?;
+        if auction.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(auction_account.key(), &auction.derive_pda()?)?;
+        Ok(auction)
+    }
+
+    /// Verify the payment mint and payment vault of an auction
+    fn verify_auction_payment_vault(
+        auction_account: &AccountInfo,
+        auction: &Auction,
+        payment_mint: &AccountInfo,
+        payment_vault: &AccountInfo,
+        payment_token_program: &AccountInfo,
+    ) -> ProgramResult {
+        if auction.payment_mint != *payment_mint.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_owner(payment_mint, payment_token_program.key())?;
+        let (expected_payment_vault, _) = find_associated_token_address(
+            auction_account.key(),
+            payment_mint.key(),
+            payment_token_program.key(),
+        );
+        verify_pda_keys_match(payment_vault.key(), &expected_payment_vault)
+    }
+
+    /// Load the AuctionBid account of `auction_account` and verify its PDA
+    fn load_auction_bid(
+        program_id: &Pubkey,
+        bid_account: &AccountInfo,
+        auction_account: &AccountInfo,
+    ) -> Result<AuctionBid, ProgramError> {
+        verify_owner(bid_account, program_id)?;
+        verify_account_initialized(bid_account)?;
+
+        let bid = AuctionBid::from_account_info(bid_account)?;
+        if bid.auction != *auction_account.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(bid_account.key(), &bid.derive_pda()?)?;
+        Ok(bid)
+    }
+
+    /// Load the HoldingPeriod account of `mint_info` and verify its PDA
+    fn load_holding_period(
+        program_id: &Pubkey,
+        holding_period_account: &AccountInfo,
+        mint_info: &AccountInfo,
+    ) -> Result<HoldingPeriod, ProgramError> {
+        verify_owner(holding_period_account, program_id)?;
+        verify_account_initialized(holding_period_account)?;
+
+        let holding_period = HoldingPeriod::from_account_info(holding_period_account)?;
+        if holding_period.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(holding_period_account.key(), &holding_period.derive_pda()?)?;
+        Ok(holding_period)
+    }
+
+    /// Load the RestrictedHolding account of `token_account` and verify its PDA
+    fn load_restricted_holding(
+        program_id: &Pubkey,
+        restricted_holding_account: &AccountInfo,
+        token_account: &AccountInfo,
+    ) -> Result<RestrictedHolding, ProgramError> {
+        verify_owner(restricted_holding_account, program_id)?;
+
+        let restricted_holding = RestrictedHolding::from_account_info(restricted_holding_account)?;
+        if restricted_holding.token_account != *token_account.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            restricted_holding_account.key(),
+            &restricted_holding.derive_pda()?,
+        )?;
+        Ok(restricted_holding)
+    }
+
+    /// Amount of the restricted lots of `token_account` still in their holding period.
+    /// Lockup accounts: HoldingPeriod and RestrictedHolding of `token_account`, the amount is
+    /// `0` without them, without a holding period or before the first lot.
+    fn locked_amount(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        token_account: &AccountInfo,
+        lockup_accounts: &[AccountInfo],
+    ) -> Result<u64, ProgramError> {
+        // Clients fill omitted optional accounts with the program id
+        let [holding_period_account, restricted_holding_account] = lockup_accounts else {
+            return Ok(0);
+        };
+        if holding_period_account.key() == program_id
+            || restricted_holding_account.key() == program_id
+            || holding_period_account.data_is_empty()
+            || restricted_holding_account.data_is_empty()
+        {
+            return Ok(0);
+        }
+        Self::load_holding_period(program_id, holding_period_account, mint_info)?;
+        let restricted_holding =
+            Self::load_restricted_holding(program_id, restricted_holding_account, token_account)?;
+        if restricted_holding.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(restricted_holding.locked_amount)
+    }
+
+    /// Check `token_account` is not suspended: `suspension_account` has to be its Suspension PDA
+    /// and hold no Suspension. Escrow transfers move tokens with the permanent delegate,
+    /// which the transfer hook lets through, so they check suspensions themselves.
+    fn verify_not_suspended(
+        program_id: &Pubkey,
+        suspension_account: &AccountInfo,
+        token_account: &AccountInfo,
+    ) -> ProgramResult {
+        let (expected_suspension_pda, _bump) = find_suspension_pda(token_account.key(), program_id);
+        verify_pda_keys_match(suspension_account.key(), &expected_suspension_pda)?;
+        if suspension_account.is_owned_by(program_id) && !suspension_account.data_is_empty() {
+            return Err(SecurityTokenError::AccountSuspended.into());
+        }
+        Ok(())
+    }
+
+    /// Load the Maturity account of `mint_info`, verify its PDA, payout mint and redemption vault
+    fn load_maturity(
+        program_id: &Pubkey,
+        maturity_account: &AccountInfo,
+        mint_info: &AccountInfo,
+        payout_mint: &AccountInfo,
+        redemption_vault: &AccountInfo,
+        payout_token_program: &AccountInfo,
+    ) -> Result<Maturity, ProgramError> {
+        verify_owner(maturity_account, program_id)?;
+        verify_account_initialized(maturity_account)?;
+
+        let maturity = Maturity::from_account_info(maturity_account)?;
+        if maturity.mint != *mint_info.key() || maturity.payout_mint != *payout_mint.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(maturity_account.key(), &maturity.derive_pda()?)?;
+
+        verify_owner(payout_mint, payout_token_program.key())?;
+        let (expected_redemption_vault, _) = find_associated_token_address(
+            maturity_account.key(),
+            payout_mint.key(),
+            payout_token_program.key(),
+        );
+        verify_pda_keys_match(redemption_vault.key(), &expected_redemption_vault)?;
+        Ok(maturity)
+    }
+
+    /// Load the AccrualConfig account of `mint_info` and verify its PDA
+    fn load_accrual_config(
+        program_id: &Pubkey,
+        accrual_config_account: &AccountInfo,
+        mint_info: &AccountInfo,
+    ) -> Result<AccrualConfig, ProgramError> {
+        verify_owner(accrual_config_account, program_id)?;
+        verify_account_initialized(accrual_config_account)?;
+
+        let accrual_config = AccrualConfig::from_account_info(accrual_config_account)?;
+        if accrual_config.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(accrual_config_account.key(), &accrual_config.derive_pda()?)?;
+        Ok(accrual_config)
+    }
+
+    /// Load the TransferApproval account of `mint_info` and verify its PDA
+    fn load_transfer_approval(
+        program_id: &Pubkey,
+        transfer_approval_account: &AccountInfo,
+        mint_info: &AccountInfo,
+    ) -> Result<TransferApproval, ProgramError> {
+        verify_writable(transfer_approval_account)?;
+        verify_owner(transfer_approval_account, program_id)?;
+        verify_account_initialized(transfer_approval_account)?;
+
+        let transfer_approval = TransferApproval::from_account_info(transfer_approval_account)?;
+        if transfer_approval.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            transfer_approval_account.key(),
+            &transfer_approval.derive_pda()?,
+        )?;
+        Ok(transfer_approval)
+    }
+
+    /// Load the TransferRequest account of `mint_info` and verify its PDA
+    fn load_transfer_request(
+        program_id: &Pubkey,
+        transfer_request_account: &AccountInfo,
+        mint_info: &AccountInfo,
+    ) -> Result<TransferRequest, ProgramError> {
+        verify_writable(transfer_request_account)?;
+        verify_owner(transfer_request_account, program_id)?;
+        verify_account_initialized(transfer_request_account)?;
+
+        let transfer_request = TransferRequest::from_account_info(transfer_request_account)?;
+        if transfer_request.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            transfer_request_account.key(),
+            &transfer_request.derive_pda()?,
+        )?;
+        Ok(transfer_request)
+    }
+
+    /// Create the transfer escrow token account of `mint_info` unless it exists.
+    /// The escrow is shared by the transfer acceptance and approval modes and survives
+    /// disabling them, so it is only created the first time.
+    fn create_transfer_escrow_if_missing(
+        program_id: &Pubkey,
+        payer: &AccountInfo,
+        mint_info: &AccountInfo,
+        escrow_authority: &AccountInfo,
+        escrow_token_account: &AccountInfo,
+        system_program_info: &AccountInfo,
+        token_program: &AccountInfo,
+    ) -> ProgramResult {
+        let (escrow_authority_pda, _bump) =
+            find_transfer_escrow_authority_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(escrow_authority.key(), &escrow_authority_pda)?;
+        Self::verify_transfer_escrow(program_id, mint_info, escrow_token_account)?;
+        if !escrow_token_account.data_is_empty() {
+            return Ok(());
+        }
+        verify_writable(escrow_token_account)?;
+        CreateTokenAccount {
+            funding_account: payer,
+            account: escrow_token_account,
+            wallet: escrow_authority,
+            mint: mint_info,
+            system_program: system_program_info,
+            token_program,
+        }
+        .invoke()
+    }
+
+    /// Load the PendingTransfer account of `mint_info` and verify its PDA
+    fn load_pending_transfer(
+        program_id: &Pubkey,
+        pending_transfer_account: &AccountInfo,
+        mint_info: &AccountInfo,
+    ) -> Result<PendingTransfer, ProgramError> {
+        verify_writable(pending_transfer_account)?;
+        verify_owner(pending_transfer_account, program_id)?;
+        verify_account_initialized(pending_transfer_account)?;
+
+        let pending_transfer = PendingTransfer::from_account_info(pending_transfer_account)?;
+        if pending_transfer.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            pending_transfer_account.key(),
+            &pending_transfer.derive_pda()?,
+        )?;
+        Ok(pending_transfer)
+    }
+
+    /// Verify `escrow_token_account` is the transfer escrow of `mint_info`
+    fn verify_transfer_escrow(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        escrow_token_account: &AccountInfo,
+    ) -> ProgramResult {
+        let (escrow_authority, _bump) =
+            find_transfer_escrow_authority_pda(mint_info.key(), program_id);
+        let (expected_escrow_ata, _bump) = find_associated_token_address(
+            &escrow_authority,
+            mint_info.key(),
+            &pinocchio_token_2022::ID,
+        );
+        verify_pda_keys_match(escrow_token_account.key(), &expected_escrow_ata)
+    }
+
+    /// Transfer `amount` between token accounts of `mint_info` with the permanent delegate,
+    /// which the transfer hook does not restrict
+    #[allow(clippy::too_many_arguments)]
+    fn transfer_with_permanent_delegate(
+        program_id: &Pubkey,
+        amount: u64,
+        mint_info: &AccountInfo,
+        from_token_account: &AccountInfo,
+        to_token_account: &AccountInfo,
+        transfer_hook_program: &AccountInfo,
+        permanent_delegate_authority: &AccountInfo,
+        stage: TokenCpiStage,
+    ) -> ProgramResult {
+        let (permanent_delegate_pda, permanent_delegate_bump) =
+            find_permanent_delegate_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(permanent_delegate_authority.key(), &permanent_delegate_pda)?;
+
+        let decimals = Mint::from_account_info(mint_info)?.decimals();
+        transfer_checked(
+            amount,
+            decimals,
+            mint_info,
+            from_token_account,
+            to_token_account,
+            transfer_hook_program,
+            permanent_delegate_authority,
+            permanent_delegate_bump,
+        )
+        .map_err(token_cpi_error(stage))
+    }
+
+    /// Burn `amount` tokens of `token_account` with the current permanent delegate of the mint:
+    /// the program PDA until the first rotation, then the delegate recorded in its
+    /// PermanentDelegateConfig, which has to sign
+    #[allow(clippy::too_many_arguments)]
+    fn burn_with_permanent_delegate(
+        program_id: &Pubkey,
+        amount: u64,
+        decimals: u8,
+        mint_info: &AccountInfo,
+        token_account: &AccountInfo,
+        permanent_delegate_authority: &AccountInfo,
+        permanent_delegate_config_account: &AccountInfo,
+        stage: TokenCpiStage,
+    ) -> ProgramResult {
+        let (expected_config_pda, _) =
+            find_permanent_delegate_config_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(
+            permanent_delegate_config_account.key(),
+            &expected_config_pda,
+        )?;
+
+        if permanent_delegate_config_account.data_is_empty() {
+            let (permanent_delegate_pda, permanent_delegate_bump) =
+                find_permanent_delegate_pda(mint_info.key(), program_id);
+            verify_pda_keys_match(permanent_delegate_authority.key(), &permanent_delegate_pda)?;
+            return burn_checked(
+                amount,
+                decimals,
+                mint_info,
+                token_account,
+                permanent_delegate_authority,
+                permanent_delegate_bump,
+            )
+            .map_err(token_cpi_error(stage));
+        }
+
+        verify_owner(permanent_delegate_config_account, program_id)?;
+        let config = PermanentDelegateConfig::from_account_info(permanent_delegate_config_account)?;
+        if config.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if config.is_disabled() {
+            return Err(SecurityTokenError::PermanentDelegateDisabled.into());
+        }
+        if *permanent_delegate_authority.key() != config.delegate {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        verify_signer(permanent_delegate_authority)?;
+
+        BurnChecked {
+            mint: mint_info,
+            account: token_account,
+            authority: permanent_delegate_authority,
+            amount,
+            decimals,
+            token_program: &pinocchio_token_2022::ID,
+        }
+        .invoke()
+        .map_err(token_cpi_error(stage))
+    }
+
+    /// Load the wrap vault of `mint_info` and `underlying_mint` and verify its vault token account
+    fn verify_wrap_vault(
+        wrap_vault: &AccountInfo,
+        mint_info: &AccountInfo,
+        underlying_mint: &AccountInfo,
+        vault_token_account: &AccountInfo,
+        underlying_token_program: &AccountInfo,
+    ) -> Result<WrapVault, ProgramError> {
+        verify_owner(underlying_mint, underlying_token_program.key())?;
+
+        let wrap_vault_state = WrapVault::from_account_info(wrap_vault)?;
+        if wrap_vault_state.mint != *mint_info.key()
+            || wrap_vault_state.underlying_mint != *underlying_mint.key()
+        {
+            return Err(SecurityTokenError::InvalidUnderlyingMint.into());
+        }
+        verify_pda_keys_match(wrap_vault.key(), &wrap_vault_state.derive_pda()?)?;
+
+        let (expected_vault_ata, _) = find_associated_token_address(
+            wrap_vault.key(),
+            underlying_mint.key(),
+            underlying_token_program.key(),
+        );
+        verify_pda_keys_match(vault_token_account.key(), &expected_vault_ata)?;
+
+        Ok(wrap_vault_state)
     }
 }
@@ -1,11 +1,94 @@
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
     ProgramResult,
 };
-use pinocchio_token_2022::instructions::{BurnChecked, MintToChecked};
+use pinocchio_token_2022::instructions::{BurnChecked, MintToChecked, TransferChecked};
 
-use crate::{constants::seeds, instructions::TransferCheckedWithHook, state::MintAuthority};
+use crate::{
+    constants::seeds,
+    error::SecurityTokenError,
+    instructions::TransferCheckedWithHook,
+    state::{
+        AccrualConfig, Auction, DistributionPayout, Maturity, MintAuthority, Subscription,
+        WrapVault,
+    },
+};
+
+/// Operation stage of a token program CPI, logged when the CPI fails
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenCpiStage {
+    Mint = 0,
+    Burn = 1,
+    Pause = 2,
+    Resume = 3,
+    Freeze = 4,
+    Thaw = 5,
+    ThawExpired = 6,
+    Transfer = 7,
+    TransferWithPermit = 8,
+    RecoverTokens = 9,
+    SplitMint = 10,
+    SplitBurn = 11,
+    ConvertBurnLeg = 12,
+    ConvertMintLeg = 13,
+    MigrateBurnLeg = 14,
+    MigrateMintLeg = 15,
+    ClaimWithholding = 16,
+    ClaimDistribution = 17,
+    ClaimPayout = 18,
+    AmendDistributionRoot = 19,
+    ReclaimUnclaimed = 20,
+    ReclaimPayout = 21,
+    WrapDeposit = 22,
+    WrapMint = 23,
+    UnwrapBurn = 24,
+    UnwrapWithdraw = 25,
+    OfferTransfer = 26,
+    AcceptTransfer = 27,
+    CancelTransfer = 28,
+    RequestTransfer = 29,
+    ApproveTransfer = 30,
+    RejectTransfer = 31,
+    ClaimCoupon = 32,
+    RedeemBurn = 33,
+    RedeemPayout = 34,
+    WithdrawUnclaimedRedemption = 35,
+    UpdateMultiplier = 36,
+    CommitPayment = 37,
+    SettleMint = 38,
+    SettlePayment = 39,
+    SettleRefund = 40,
+    PlaceBid = 41,
+    SettleBidMint = 42,
+    SettleBidPayment = 43,
+    SettleBidRefund = 44,
+    CreateVesting = 45,
+    ReleaseVested = 46,
+    SetTransferFee = 47,
+    WithdrawWithheldTokens = 48,
+    HarvestWithheldTokens = 49,
+    UpdateDefaultAccountState = 50,
+}
+
+/// Map the error of a failed token program CPI to `TokenCpiFailed`, logging the stage
+/// and the original error code.
+///
+/// Errors raised inside the token program abort the transaction with the original error,
+/// this covers the ones returned to the program, e.g. borrow failures of the CPI accounts.
+pub fn token_cpi_error(stage: TokenCpiStage) -> impl FnOnce(ProgramError) -> ProgramError {
+    move |error| {
+        pinocchio_log::log!(
+            "Token CPI failed at stage {}: {}",
+            stage as u8,
+            u64::from(error)
+        );
+        SecurityTokenError::TokenCpiFailed.into()
+    }
+}
 
 /// Burn tokens from token account using permanent delegate authority
 pub fn burn_checked(
@@ -85,6 +168,258 @@ pub fn transfer_checked(
         amount,
         decimals,
         transfer_hook_program,
+        extra_accounts: &[],
     }
     .invoke_signed(&[permanent_delegate_signer])
 }
+
+/// Transfer tokens as the account delegate PDA of `from_token_account`, which the owner
+/// approved for the amount. The transfer hook checks it like any holder transfer, so
+/// `transfer_hook_accounts` must hold the ExtraAccountMetaList PDA and its extra accounts.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked_as_account_delegate(
+    amount: u64,
+    decimals: u8,
+    mint_info: &AccountInfo,
+    from_token_account: &AccountInfo,
+    to_token_account: &AccountInfo,
+    transfer_hook_program: &AccountInfo,
+    account_delegate_authority: &AccountInfo,
+    account_delegate_bump: u8,
+    transfer_hook_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let bump_seed = [account_delegate_bump];
+    let seeds = [
+        Seed::from(seeds::ACCOUNT_DELEGATE),
+        Seed::from(from_token_account.key().as_ref()),
+        Seed::from(bump_seed.as_ref()),
+    ];
+    let account_delegate_signer = Signer::from(&seeds);
+
+    TransferCheckedWithHook {
+        mint: mint_info,
+        from: from_token_account,
+        to: to_token_account,
+        authority: account_delegate_authority,
+        amount,
+        decimals,
+        transfer_hook_program,
+        extra_accounts: transfer_hook_accounts,
+    }
+    .invoke_signed(&[account_delegate_signer])
+}
+
+/// Decimals of a legacy SPL Token or Token 2022 mint
+pub fn underlying_mint_decimals(mint_info: &AccountInfo) -> Result<u8, ProgramError> {
+    if mint_info.is_owned_by(&pinocchio_token_2022::ID) {
+        Ok(pinocchio_token_2022::state::Mint::from_account_info(mint_info)?.decimals())
+    } else {
+        Ok(pinocchio_token::state::Mint::from_account_info(mint_info)?.decimals())
+    }
+}
+
+/// Balance of a legacy SPL Token or Token 2022 token account
+pub fn underlying_token_account_amount(token_account: &AccountInfo) -> Result<u64, ProgramError> {
+    if token_account.is_owned_by(&pinocchio_token_2022::ID) {
+        Ok(pinocchio_token_2022::state::TokenAccount::from_account_info(token_account)?.amount())
+    } else {
+        Ok(pinocchio_token::state::TokenAccount::from_account_info(token_account)?.amount())
+    }
+}
+
+/// Owner of a legacy SPL Token or Token 2022 token account
+pub fn underlying_token_account_owner(token_account: &AccountInfo) -> Result<Pubkey, ProgramError> {
+    if token_account.is_owned_by(&pinocchio_token_2022::ID) {
+        Ok(*pinocchio_token_2022::state::TokenAccount::from_account_info(token_account)?.owner())
+    } else {
+        Ok(*pinocchio_token::state::TokenAccount::from_account_info(token_account)?.owner())
+    }
+}
+
+/// Mint of a legacy SPL Token or Token 2022 token account
+pub fn underlying_token_account_mint(token_account: &AccountInfo) -> Result<Pubkey, ProgramError> {
+    if token_account.is_owned_by(&pinocchio_token_2022::ID) {
+        Ok(*pinocchio_token_2022::state::TokenAccount::from_account_info(token_account)?.mint())
+    } else {
+        Ok(*pinocchio_token::state::TokenAccount::from_account_info(token_account)?.mint())
+    }
+}
+
+/// Transfer underlying tokens out of the wrap vault using wrap vault PDA authority
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_from_wrap_vault(
+    amount: u64,
+    decimals: u8,
+    underlying_mint: &AccountInfo,
+    vault_token_account: &AccountInfo,
+    to_token_account: &AccountInfo,
+    wrap_vault: &AccountInfo,
+    wrap_vault_state: &WrapVault,
+    underlying_token_program: &AccountInfo,
+) -> ProgramResult {
+    let bump_seed = &wrap_vault_state.bump_seed();
+    let seeds = &wrap_vault_state.seeds(bump_seed);
+    let wrap_vault_signer = Signer::from(seeds);
+    TransferChecked {
+        from: vault_token_account,
+        mint: underlying_mint,
+        to: to_token_account,
+        authority: wrap_vault,
+        amount,
+        decimals,
+        token_program: underlying_token_program.key(),
+    }
+    .invoke_signed(&[wrap_vault_signer])
+}
+
+/// Transfer payout tokens out of the coupon vault using accrual config PDA authority
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_from_coupon_vault(
+    amount: u64,
+    decimals: u8,
+    payout_mint: &AccountInfo,
+    coupon_vault: &AccountInfo,
+    to_token_account: &AccountInfo,
+    accrual_config: &AccountInfo,
+    accrual_config_state: &AccrualConfig,
+    payout_token_program: &AccountInfo,
+) -> ProgramResult {
+    let bump_seed = &accrual_config_state.bump_seed();
+    let seeds = &accrual_config_state.seeds(bump_seed);
+    let accrual_config_signer = Signer::from(seeds);
+    TransferChecked {
+        from: coupon_vault,
+        mint: payout_mint,
+        to: to_token_account,
+        authority: accrual_config,
+        amount,
+        decimals,
+        token_program: payout_token_program.key(),
+    }
+    .invoke_signed(&[accrual_config_signer])
+}
+
+/// Transfer payout tokens out of the distribution payout escrow using distribution payout PDA authority
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_from_payout_escrow(
+    amount: u64,
+    decimals: u8,
+    payout_mint: &AccountInfo,
+    payout_escrow: &AccountInfo,
+    to_token_account: &AccountInfo,
+    distribution_payout: &AccountInfo,
+    distribution_payout_state: &DistributionPayout,
+    payout_token_program: &AccountInfo,
+) -> ProgramResult {
+    let action_id_seed = &distribution_payout_state.action_id_seed();
+    let bump_seed = &distribution_payout_state.bump_seed();
+    let seeds = &distribution_payout_state.seeds(action_id_seed, bump_seed);
+    let distribution_payout_signer = Signer::from(seeds);
+    TransferChecked {
+        from: payout_escrow,
+        mint: payout_mint,
+        to: to_token_account,
+        authority: distribution_payout,
+        amount,
+        decimals,
+        token_program: payout_token_program.key(),
+    }
+    .invoke_signed(&[distribution_payout_signer])
+}
+
+/// Transfer payout tokens out of the redemption vault using maturity PDA authority
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_from_redemption_vault(
+    amount: u64,
+    decimals: u8,
+    payout_mint: &AccountInfo,
+    redemption_vault: &AccountInfo,
+    to_token_account: &AccountInfo,
+    maturity: &AccountInfo,
+    maturity_state: &Maturity,
+    payout_token_program: &AccountInfo,
+) -> ProgramResult {
+    let bump_seed = &maturity_state.bump_seed();
+    let seeds = &maturity_state.seeds(bump_seed);
+    let maturity_signer = Signer::from(seeds);
+    TransferChecked {
+        from: redemption_vault,
+        mint: payout_mint,
+        to: to_token_account,
+        authority: maturity,
+        amount,
+        decimals,
+        token_program: payout_token_program.key(),
+    }
+    .invoke_signed(&[maturity_signer])
+}
+
+/// Transfer payment tokens out of the subscription payment vault using subscription PDA authority
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_from_payment_vault(
+    amount: u64,
+    decimals: u8,
+    payment_mint: &AccountInfo,
+    payment_vault: &AccountInfo,
+    to_token_account: &AccountInfo,
+    subscription: &AccountInfo,
+    subscription_state: &Subscription,
+    payment_token_program: &AccountInfo,
+) -> ProgramResult {
+    let subscription_id_seed = &subscription_state.subscription_id_seed();
+    let bump_seed = &subscription_state.bump_seed();
+    let seeds = &subscription_state.seeds(subscription_id_seed, bump_seed);
+    let subscription_signer = Signer::from(seeds);
+    TransferChecked {
+        from: payment_vault,
+        mint: payment_mint,
+        to: to_token_account,
+        authority: subscription,
+        amount,
+        decimals,
+        token_program: payment_token_program.key(),
+    }
+    .invoke_signed(&[subscription_signer])
+}
+
+/// Transfer payment tokens out of the auction payment vault using auction PDA authority
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_from_auction_vault(
+    amount: u64,
+    decimals: u8,
+    payment_mint: &AccountInfo,
+    payment_vault: &AccountInfo,
+    to_token_account: &AccountInfo,
+    auction: &AccountInfo,
+    auction_state: &Auction,
+    payment_token_program: &AccountInfo,
+) -> ProgramResult {
+    let auction_id_seed = &auction_state.auction_id_seed();
+    let bump_seed = &auction_state.bump_seed();
+    let seeds = &auction_state.seeds(auction_id_seed, bump_seed);
+    let auction_signer = Signer::from(seeds);
+    TransferChecked {
+        from: payment_vault,
+        mint: payment_mint,
+        to: to_token_account,
+        authority: auction,
+        amount,
+        decimals,
+        token_program: payment_token_program.key(),
+    }
+    .invoke_signed(&[auction_signer])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_cpi_error_wraps_original_error() {
+        let error = token_cpi_error(TokenCpiStage::ConvertMintLeg)(ProgramError::Custom(1));
+        assert_eq!(
+            error,
+            ProgramError::Custom(SecurityTokenError::TokenCpiFailed as u32)
+        );
+    }
+}
@@ -3,37 +3,52 @@
 //! Handles authorization checks, compliance verification, and instruction validation
 //! according to the Security Token specification.
 
-use crate::token22_extensions::metadata::{Field, UpdateField};
+use crate::token22_extensions::default_account_state::InitializeDefaultAccountState;
+use crate::token22_extensions::metadata::{Field, UpdateAuthority, UpdateField};
 use crate::token22_extensions::pausable::InitializePausable;
 use crate::token22_extensions::permanent_delegate::InitializePermanentDelegate;
 use crate::token22_extensions::scaled_ui_amount::InitializeScaledUiAmount;
+use crate::token22_extensions::transfer_fee::InitializeTransferFeeConfig;
 use pinocchio::account_info::AccountInfo;
 use pinocchio::instruction::{Seed, Signer};
 use pinocchio::program_error::ProgramError;
 use pinocchio::pubkey::Pubkey;
-use pinocchio::sysvars::Sysvar;
-use pinocchio::sysvars::{instructions::Instructions, rent::Rent};
+use pinocchio::sysvars::{clock::Clock, Sysvar};
+use pinocchio::sysvars::{
+    instructions::{Instructions, INSTRUCTIONS_ID},
+    rent::Rent,
+};
 use pinocchio::ProgramResult;
 use pinocchio_system::instructions::{CreateAccount, Transfer};
 use pinocchio_token_2022::instructions::{AuthorityType, InitializeMint2, SetAuthority};
 use pinocchio_token_2022::state::Mint;
 use spl_pod::primitives::PodBool;
+use spl_tlv_account_resolution::pubkey_data::PubkeyData;
+use spl_tlv_account_resolution::seeds::Seed as ExtraAccountMetaSeed;
 use spl_tlv_account_resolution::state::ExtraAccountMetaList;
 
 use super::utils as verification_utils;
-use crate::constants::{seeds, INSTRUCTION_ACCOUNTS_OFFSET, TRANSFER_HOOK_PROGRAM_ID};
+use crate::constants::{
+    seeds, BPF_LOADER_UPGRADEABLE_ID, EXECUTE_DESTINATION_INDEX, EXECUTE_EXTRA_ACCOUNTS_OFFSET,
+    EXECUTE_MINT_INDEX, EXECUTE_SOURCE_INDEX, INSTRUCTION_ACCOUNTS_OFFSET, MAX_IDENTITY_WALLETS,
+    PROGRAM_DATA_METADATA_LEN, TRANSFER_HOOK_PROGRAM_ID,
+};
 use crate::error::SecurityTokenError;
+use crate::events::VerificationEvent;
 use crate::instruction::SecurityTokenInstruction;
 use crate::instructions::verification_config::TrimVerificationConfigArgs;
 use crate::instructions::{InitializeMintArgs, UpdateMetadataArgs, VerifyArgs};
 use crate::modules::{
     verify_account_initialized, verify_account_not_initialized, verify_instructions_sysvar,
-    verify_mint_keys_match, verify_owner, verify_pda_keys_match, verify_rent_sysvar, verify_signer,
-    verify_system_program, verify_token22_program, verify_transfer_hook_program, verify_writable,
+    verify_mint_keys_match, verify_owner, verify_pda_keys_match, verify_rent_recipient,
+    verify_rent_sysvar, verify_signer, verify_system_program, verify_token22_program,
+    verify_transfer_hook_program, verify_writable,
 };
 use crate::state::{
-    AccountDeserialize, AccountSerialize, MintAuthority, SecurityTokenDiscriminators,
-    VerificationConfig,
+    AccountDeserialize, AccountSerialize, Agent, ConfigAdmin, ConfigTemplate, Identity,
+    MetadataFreeze, MetadataSchema, MetadataTranslator, MintAuthority, PauseDelegate,
+    PendingVerificationConfig, ProgramAccount, ProgramConfig, SecurityTokenDiscriminators,
+    SessionKey, SuccessionPolicy, VerificationConfig,
 };
 use crate::token22_extensions::metadata::{InitializeTokenMetadata, RemoveKey, TokenMetadata};
 use crate::token22_extensions::metadata_pointer::{InitializeMetadataPointer, MetadataPointer};
@@ -42,42 +57,71 @@ use crate::token22_extensions::transfer_hook::{
 };
 use crate::token22_extensions::{
     get_extension_data_bytes_for_variable_pack, get_extension_from_bytes, ExtensionType,
+    EXTENSION_LENGTH_LEN, EXTENSION_TYPE_LEN,
 };
 use crate::utils::find_extra_account_metas_pda;
 use crate::{debug_log, utils};
+use security_token_core::TransferHookRuleFlags;
 use spl_tlv_account_resolution::account::ExtraAccountMeta;
 use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Verification Module - handles all authorization and compliance checks
 pub struct VerificationModule;
 
+/// Optional rules enforced by the transfer hook through extra account metas.
+/// `None` keeps the rule as currently present in the extra account metas list.
+#[derive(Clone, Copy, Default)]
+pub struct TransferHookRules {
+    pub allowlist: Option<bool>,
+    pub holding_period: Option<bool>,
+    pub coupon_accrual: Option<bool>,
+    pub position_limit: Option<bool>,
+    pub transfer_acceptance: Option<bool>,
+    pub transfer_approval: Option<bool>,
+    pub permanent_delegate_config: Option<bool>,
+}
+
 impl VerificationModule {
     /// Initialize mint with all extensions and metadata
     /// Creates initial configuration of the verification module  
+    /// The optional trailing creator becomes the initial mint authority, the payer funds the
+    /// accounts. Without it the payer is the creator.
     pub fn initialize_mint(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
         args: &InitializeMintArgs,
     ) -> ProgramResult {
+        args.validate()?;
+
         let decimals = args.ix_mint.decimals;
         let client_mint_authority = args.ix_mint.mint_authority;
         let freeze_authority = args.ix_mint.freeze_authority;
         let metadata_pointer_opt = &args.ix_metadata_pointer;
         let metadata_opt = &args.ix_metadata;
         let scaled_ui_amount_opt = &args.ix_scaled_ui_amount;
+        let transfer_fee_opt = &args.ix_transfer_fee;
+        let default_account_state_opt = &args.ix_default_account_state;
 
-        let [mint_info, mint_authority_account, creator_info, token_program_info, system_program_info, rent_info] =
+        let [mint_info, mint_authority_account, payer, token_program_info, system_program_info, rent_info, optional_accounts @ ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
+        // Clients fill omitted optional accounts with the program id
+        let creator_info = match optional_accounts.first() {
+            Some(creator) if creator.key() != program_id => creator,
+            _ => payer,
+        };
+        let external_metadata_accounts =
+            Self::external_metadata_accounts(program_id, optional_accounts.get(1..));
 
         verify_token22_program(token_program_info)?;
         verify_system_program(system_program_info)?;
         verify_rent_sysvar(rent_info)?;
+        verify_signer(payer)?;
         verify_signer(creator_info)?;
         verify_signer(mint_info)?;
-        verify_writable(creator_info)?;
+        verify_writable(payer)?;
         verify_writable(mint_info)?;
         verify_writable(mint_authority_account)?;
         verify_account_not_initialized(mint_authority_account)?;
@@ -98,10 +142,11 @@ impl VerificationModule {
         //    - Requires ix_metadata to initialize TokenMetadata extension
         //    - Program manages metadata lifecycle
         // 2. Externally owned: metadata_address != mint (metadata in separate account)
-        //    - Must NOT provide ix_metadata (client manages external account separately)
-        //    - Client is responsible for ensuring metadata_address is valid
-        //    - External metadata should be managed via Token-2022 directly
-
+        //    - Without external metadata accounts ix_metadata must NOT be provided
+        //      (client manages external account separately)
+        //    - With the external metadata account and its metadata program passed, ix_metadata
+        //      is initialized there through the token metadata interface of that program
+        let mut external_metadata = None;
         if let Some(client_metadata_pointer) = metadata_pointer_opt {
             let is_internal = client_metadata_pointer.metadata_address == *mint_info.key();
             match (is_internal, metadata_opt.is_some()) {
@@ -110,12 +155,24 @@ impl VerificationModule {
                     return Err(SecurityTokenError::InternalMetadataRequiresData.into())
                 }
                 // external + metadata provided
-                (false, true) => return Err(SecurityTokenError::ExternalMetadataForbidsData.into()),
+                (false, true) => {
+                    let Some((metadata_account, metadata_program)) = external_metadata_accounts
+                    else {
+                        return Err(SecurityTokenError::ExternalMetadataForbidsData.into());
+                    };
+                    Self::verify_external_metadata(
+                        program_id,
+                        &client_metadata_pointer.metadata_address,
+                        metadata_account,
+                        metadata_program,
+                    )?;
+                    external_metadata = Some((metadata_account, metadata_program));
+                }
                 _ => {} // valid combinations
             }
         }
 
-        let mut extensions_buf: [ExtensionType; 5] = [ExtensionType::Pausable; 5];
+        let mut extensions_buf: [ExtensionType; 7] = [ExtensionType::Pausable; 7];
         let mut ext_count: usize = 0;
         let required_extensions: &[ExtensionType] = &[
             ExtensionType::PermanentDelegate,
@@ -139,6 +196,18 @@ impl VerificationModule {
             ext_count += 1;
         }
 
+        // Add TransferFeeConfig if provided by client
+        if transfer_fee_opt.is_some() {
+            extensions_buf[ext_count] = ExtensionType::TransferFeeConfig;
+            ext_count += 1;
+        }
+
+        // Add DefaultAccountState if provided by client
+        if default_account_state_opt.is_some() {
+            extensions_buf[ext_count] = ExtensionType::DefaultAccountState;
+            ext_count += 1;
+        }
+
         // Calculate mint size with extensions (but without metadata TLV data)
         let mint_size = if ext_count == 0 {
             Mint::BASE_LEN
@@ -146,17 +215,18 @@ impl VerificationModule {
             utils::calculate_mint_size_with_extensions(&extensions_buf[..ext_count])
         };
 
-        let metadata_size = if let Some(metadata) = &metadata_opt {
-            utils::calculate_metadata_tlv_size(metadata)?
-        } else {
-            0
+        let metadata_size = match metadata_opt {
+            Some(metadata) if external_metadata.is_none() => {
+                utils::calculate_metadata_tlv_size(metadata)?
+            }
+            _ => 0,
         };
 
         let total_size = mint_size + metadata_size;
         let rent = Rent::from_account_info(rent_info)?;
         let required_lamports = rent.minimum_balance(total_size);
         let create_account_instruction = CreateAccount {
-            from: creator_info,              // from (payer)
+            from: payer,                     // from (payer)
             to: mint_info,                   // to (new account)
             lamports: required_lamports,     // amount
             space: mint_size as u64,         // space (full size including metadata)
@@ -216,6 +286,34 @@ impl VerificationModule {
             scaled_ui_amount_initialize.invoke()?;
         }
 
+        // Initialize TransferFeeConfig extension if provided by client.
+        // Both fee authorities are the transfer fee authority PDA so that fee updates and
+        // withdrawals go through the verification pipeline.
+        if let Some(transfer_fee_config) = transfer_fee_opt {
+            let (transfer_fee_authority_pda, _bump) =
+                utils::find_transfer_fee_authority_pda(mint_info.key(), program_id);
+            let transfer_fee_initialize = InitializeTransferFeeConfig {
+                mint: mint_info,
+                transfer_fee_config_authority: transfer_fee_authority_pda,
+                withdraw_withheld_authority: transfer_fee_authority_pda,
+                transfer_fee_basis_points: transfer_fee_config.transfer_fee_basis_points,
+                maximum_fee: transfer_fee_config.maximum_fee,
+            };
+
+            transfer_fee_initialize.invoke()?;
+        }
+
+        // Initialize DefaultAccountState extension if provided by client.
+        // Frozen token accounts are thawed by the freeze authority PDA through Thaw.
+        if let Some(default_account_state) = default_account_state_opt {
+            let default_account_state_initialize = InitializeDefaultAccountState {
+                mint: mint_info,
+                state: default_account_state.state,
+            };
+
+            default_account_state_initialize.invoke()?;
+        }
+
         // Use client-provided authorities for base initialize to match client expectations/tests
         let initialize_mint_instruction = InitializeMint2 {
             mint: mint_info,
@@ -239,7 +337,7 @@ impl VerificationModule {
 
         let authority_account_required_lamports = rent.minimum_balance(MintAuthority::LEN);
         let create_mint_authority_instruction = CreateAccount {
-            from: creator_info,                            // from (payer)
+            from: payer,                                   // from (payer)
             to: mint_authority_account,                    // to (new PDA account)
             lamports: authority_account_required_lamports, // amount
             space: MintAuthority::LEN as u64,              // space (serialized state size)
@@ -276,10 +374,22 @@ impl VerificationModule {
             return Ok(());
         };
 
-        // At this point we are guaranteed to initialize internally-stored metadata only
-        // The validation at the beginning ensures that
+        // Metadata lives in the mint unless an external metadata account was validated above
+        let (metadata_program, metadata_account) = match external_metadata {
+            Some((metadata_account, metadata_program)) => {
+                // The metadata program grows the account for every field, fund it up front
+                let metadata_len = metadata_account
+                    .data_len()
+                    .max(utils::calculate_external_metadata_tlv_size(metadata)?);
+                Self::top_up_rent(payer, metadata_account, &rent, metadata_len)?;
+                (metadata_program.key(), metadata_account)
+            }
+            None => (&pinocchio_token_2022::ID, mint_info),
+        };
+
         let metadata_init_instruction = InitializeTokenMetadata {
-            metadata: mint_info,
+            metadata_program,
+            metadata: metadata_account,
             update_authority: mint_authority_account,
             mint: mint_info,
             mint_authority: mint_authority_account,
@@ -297,7 +407,8 @@ impl VerificationModule {
                 metadata.additional_metadata.as_slice(),
                 |key, value| {
                     let update_field_instruction = UpdateField {
-                        metadata: mint_info,
+                        metadata_program,
+                        metadata: metadata_account,
                         update_authority: mint_authority_account,
                         field: Field::Key(key),
                         value,
@@ -320,7 +431,8 @@ impl VerificationModule {
         accounts: &[AccountInfo],
         args: &UpdateMetadataArgs,
     ) -> ProgramResult {
-        let [mint_authority, payer, mint_info, token_program_info, system_program_info] = accounts
+        let [mint_authority, payer, mint_info, token_program_info, system_program_info, optional_accounts @ ..] =
+            accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
@@ -340,52 +452,26 @@ impl VerificationModule {
             return Err(ProgramError::InvalidAccountData);
         }
 
-        // Get metadata account address from MetadataPointer extension
-        let metadata_address: Option<Pubkey> = {
-            let mint_data = mint_info.try_borrow_data()?;
-
-            // Use pinocchio's get_extension_from_bytes instead of StateWithExtensions
-            let metadata_pointer = get_extension_from_bytes::<MetadataPointer>(&mint_data)
-                .ok_or(ProgramError::InvalidAccountData)?;
-
-            metadata_pointer.metadata_address.into()
-        }; // Borrow is released here
-        let metadata_address = metadata_address.ok_or(ProgramError::InvalidAccountData)?;
+        let (metadata_program, metadata_account) =
+            Self::metadata_accounts(program_id, mint_info, optional_accounts.get(1..))?;
 
-        // We only support internally owned metadata (metadata stored in the mint account itself)
-        // External metadata should be managed directly
-        if metadata_address != *mint_info.key() {
-            return Err(SecurityTokenError::CannotModifyExternalMetadataAccount.into());
+        let (current_metadata, current_metadata_size) =
+            Self::load_metadata(metadata_program, metadata_account)?;
+        if current_metadata.update_authority == Pubkey::default() {
+            return Err(SecurityTokenError::MetadataFrozen.into());
         }
 
-        // NOTE: No need to verify TokenMetadata extension existence here because:
-        // - initialize_mint already validates that internally owned metadata pointer requires TokenMetadata
-        // - Since metadata_address == mint (checked above), the extension is guaranteed to exist
-        // - Token-2022 UpdateField will fail gracefully if extension is somehow missing
-        //
-        // {
-        //     let mint_data = mint_info.try_borrow_data()?;
-        //     get_extension_data_bytes_for_variable_pack::<TokenMetadata>(&mint_data)
-        //         .ok_or(ProgramError::InvalidAccountData)?;
-        // }
-
-        // Calculate current and new metadata sizes
-        let new_metadata_size = utils::calculate_metadata_tlv_size(&args.metadata)?;
-        // Get current metadata size to calculate the difference
-        let current_metadata_size = {
-            let mint_data = mint_info.try_borrow_data()?;
+        if let Some(schema) =
+            Self::load_metadata_schema(program_id, mint_info, optional_accounts.first())?
+        {
+            schema.validate(&args.metadata.additional_metadata)?;
+        }
 
-            // Use pinocchio's get_extension_data_bytes_for_variable_pack to get current metadata
-            if let Some(metadata_bytes) =
-                get_extension_data_bytes_for_variable_pack::<TokenMetadata>(&mint_data)
-            {
-                // The length of the raw extension data includes TLV headers
-                // For simplification, use the raw byte length as the current size
-                metadata_bytes.len() + 4 // Add 4 bytes for TLV header (type + length)
-            } else {
-                // No metadata currently, so current size is 0
-                0
-            }
+        // Calculate the new metadata size in the layout of the metadata account
+        let new_metadata_size = if metadata_program == &pinocchio_token_2022::ID {
+            utils::calculate_metadata_tlv_size(&args.metadata)?
+        } else {
+            utils::calculate_external_metadata_tlv_size(&args.metadata)?
         };
 
         if new_metadata_size > current_metadata_size {
@@ -394,7 +480,7 @@ impl VerificationModule {
             let additional_rent = rent.minimum_balance(additional_metadata_space);
             let transfer = Transfer {
                 from: payer,               // from (authority pays)
-                to: mint_info,             // to (mint account)
+                to: metadata_account,      // to (account holding the metadata)
                 lamports: additional_rent, // amount
             };
             transfer.invoke()?;
@@ -410,7 +496,8 @@ impl VerificationModule {
         let mint_authority_signer = Signer::from(&mint_authority_seeds);
 
         let update_field_instruction = UpdateField {
-            metadata: mint_info,
+            metadata_program,
+            metadata: metadata_account,
             update_authority: mint_authority,
             field: Field::Name,
             value: &args.metadata.name,
@@ -420,7 +507,8 @@ impl VerificationModule {
 
         // Update symbol
         let update_symbol_instruction = UpdateField {
-            metadata: mint_info,
+            metadata_program,
+            metadata: metadata_account,
             update_authority: mint_authority,
             field: Field::Symbol,
             value: &args.metadata.symbol,
@@ -430,7 +518,8 @@ impl VerificationModule {
 
         // Update URI
         let update_uri_instruction = UpdateField {
-            metadata: mint_info,
+            metadata_program,
+            metadata: metadata_account,
             update_authority: mint_authority,
             field: Field::Uri,
             value: &args.metadata.uri,
@@ -441,7 +530,9 @@ impl VerificationModule {
         // Handle additional metadata fields atomically
         let existing_additional_fields = {
             // Try to parse existing metadata using pinocchio's from_account_info
-            if let Ok(existing_metadata) = TokenMetadata::from_account_info(mint_info) {
+            if let Ok((existing_metadata, _)) =
+                Self::load_metadata(metadata_program, metadata_account)
+            {
                 let mut fields_buffer: [[u8; 64]; 16] = [[0u8; 64]; 16]; // Static buffer for field names
                 let mut field_lengths: [usize; 16] = [0; 16];
                 let mut field_count = 0;
@@ -499,7 +590,8 @@ impl VerificationModule {
 
                     if !found_in_new {
                         let remove_field_instruction = RemoveKey {
-                            metadata: mint_info,
+                            metadata_program,
+                            metadata: metadata_account,
                             update_authority: mint_authority,
                             key: existing_key,
                             idempotent: true, // don't error if key doesn't exist
@@ -520,7 +612,8 @@ impl VerificationModule {
             args.metadata.additional_metadata.as_slice(),
             |key, value| {
                 let update_field_instruction = UpdateField {
-                    metadata: mint_info,
+                    metadata_program,
+                    metadata: metadata_account,
                     update_authority: mint_authority,
                     field: Field::Key(key),
                     value,
@@ -533,200 +626,959 @@ impl VerificationModule {
         Ok(())
     }
 
-    /// Verify specific operation against configured verification programs
-    ///
-    /// Client is responsible for deriving and providing the correct VerificationConfig PDA
-    /// based on mint and instruction discriminator they want to verify.
-    ///
-    /// Accounts from index 3+ will be compared with accounts from verification program calls.
-    /// Verification programs should be called with at least a full set of accounts in the exact order.
-    pub fn verify_instruction(
+    /// Remove a single additional metadata field of a mint, signed by the mint authority PDA.
+    /// Fields required by the MetadataSchema of the mint cannot be removed
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn remove_metadata_field(
         program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
-        args: &VerifyArgs,
+        key: &str,
     ) -> ProgramResult {
-        let mut instruction_data = Vec::with_capacity(1 + args.instruction_data.len());
-        instruction_data.push(args.ix);
-        instruction_data.extend_from_slice(&args.instruction_data);
-        Self::verify_by_programs(program_id, accounts, args.ix, &instruction_data)?;
-        Ok(())
-    }
-
-    /// Verify specific operation either through configured verification programs or mint authority
-    /// Decides which method to use based on the PDA account provided in accounts[1]
-    ///
-    /// # Returns
-    /// * `verified_mint_info` - The authorized Mint account (prevents mint substitution attacks in operations)
-    /// * `cleaned_accounts` - Remaining instruction accounts after verification overhead
-    pub fn verify_by_strategy<'a>(
-        program_id: &Pubkey,
-        accounts: &'a [AccountInfo],
-        ix_discriminator: u8,
-        instruction_data: &[u8],
-    ) -> Result<(&'a AccountInfo, &'a [AccountInfo]), ProgramError> {
-        let [mint_info, verification_config_or_mint_authority, instructions_sysvar_or_signer, _instruction_accounts @ ..] =
-            accounts
+        let [mint_authority, mint_info, token_program_info, optional_accounts @ ..] = accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
-        let config_data = verification_config_or_mint_authority.try_borrow_data()?;
-        let state_discriminator = config_data
-            .first()
-            .ok_or(ProgramError::InvalidAccountData)?;
-        let disc = SecurityTokenDiscriminators::try_from(*state_discriminator)?;
-        match disc {
-            SecurityTokenDiscriminators::VerificationConfigDiscriminator => {
-                let (mint_info, cleaned_accounts) = Self::verify_by_programs(
-                    program_id,
-                    accounts,
-                    ix_discriminator,
-                    instruction_data,
-                )?;
-                Ok((mint_info, cleaned_accounts))
-            }
-            SecurityTokenDiscriminators::MintAuthorityDiscriminator => {
-                let mint_authority_account = verification_config_or_mint_authority;
-                let mint_creator_info = instructions_sysvar_or_signer;
-                let mint_info = Self::verify_by_mint_authority(
-                    program_id,
-                    mint_info,
-                    mint_authority_account,
-                    mint_creator_info,
-                )?;
-                Ok((mint_info, &accounts[INSTRUCTION_ACCOUNTS_OFFSET..]))
-            }
-            _ => Err(ProgramError::InvalidAccountData),
-        }
-    }
 
-    /// Verify that the provided signer corresponds to the original mint authority PDA.
-    ///
-    /// # Returns
-    /// * `verified_mint_info` - The authorized Mint account (prevents mint substitution attacks in operations)
-    pub fn verify_by_mint_authority<'a>(
-        program_id: &Pubkey,
-        mint_info: &'a AccountInfo,
-        mint_authority: &'a AccountInfo,
-        candidate_authority: &'a AccountInfo,
-    ) -> Result<&'a AccountInfo, ProgramError> {
-        verify_signer(candidate_authority)?;
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_token22_program(token_program_info)?;
         verify_owner(mint_authority, program_id)?;
-        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
+        verify_writable(mint_info)?;
+        if key.is_empty() {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-        let data = mint_authority.try_borrow_data()?;
-        if data.len() < MintAuthority::LEN {
+        let mint_authority_data = MintAuthority::from_account_info(mint_authority)?;
+        if &mint_authority_data.mint != mint_info.key() {
             return Err(ProgramError::InvalidAccountData);
         }
 
-        let mint_authority_state = MintAuthority::try_from_bytes(&data)?;
+        let (metadata_program, metadata_account) =
+            Self::metadata_accounts(program_id, mint_info, optional_accounts.get(1..))?;
 
-        // CRITICAL: Verify that the authority is for the correct mint and signed by correct creator
-        // These checks prevent using a valid MintAuthority PDA for a different mint/creator combination
-        if mint_authority_state.mint != *mint_info.key() {
-            return Err(ProgramError::InvalidAccountData);
+        let (current_metadata, _) = Self::load_metadata(metadata_program, metadata_account)?;
+        if current_metadata.update_authority == Pubkey::default() {
+            return Err(SecurityTokenError::MetadataFrozen.into());
         }
 
-        if mint_authority_state.mint_creator != *candidate_authority.key() {
-            return Err(ProgramError::MissingRequiredSignature);
+        if let Some(schema) =
+            Self::load_metadata_schema(program_id, mint_info, optional_accounts.first())?
+        {
+            if schema.is_required(key)? {
+                return Err(SecurityTokenError::MissingMetadataField.into());
+            }
         }
 
-        // Use stored bump with derive_pda for optimized PDA verification
-        let expected_pda = mint_authority_state.derive_pda()?;
-
-        verify_pda_keys_match(mint_authority.key(), &expected_pda)?;
+        let bump_seed = [mint_authority_data.bump];
+        let mint_authority_seeds = [
+            Seed::from(seeds::MINT_AUTHORITY),
+            Seed::from(mint_authority_data.mint.as_ref()),
+            Seed::from(mint_authority_data.mint_creator.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ];
 
-        Ok(mint_info)
+        // Not idempotent, removing a field the metadata does not have fails
+        RemoveKey {
+            metadata_program,
+            metadata: metadata_account,
+            update_authority: mint_authority,
+            key,
+            idempotent: false,
+        }
+        .invoke_signed(&[Signer::from(&mint_authority_seeds)])
     }
 
-    /// Verify specific operation against configured verification programs
-    ///
-    /// # Returns
-    /// * `verified_mint_info` - The authorized Mint account (prevents mint substitution attacks in operations)
-    /// * `cleaned_accounts` - Remaining instruction accounts after verification overhead
-    pub fn verify_by_programs<'a>(
+    /// Make the metadata of a mint immutable by removing its token metadata update authority
+    /// and record the freeze in a MetadataFreeze account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn freeze_metadata(
         program_id: &Pubkey,
-        accounts: &'a [AccountInfo],
-        ix_discriminator: u8,
-        instruction_data: &[u8],
-    ) -> Result<(&'a AccountInfo, &'a [AccountInfo]), ProgramError> {
-        let [mint_info, verification_config, instructions_sysvar, instruction_accounts @ ..] =
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let [mint_authority, payer, mint_info, metadata_freeze_account, token_program_info, system_program_info] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        verify_instructions_sysvar(instructions_sysvar)?;
-        verify_owner(verification_config, program_id)?;
-        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
-        verify_account_initialized(verification_config)?;
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
 
-        let config_data = VerificationConfig::from_account_info(verification_config)?;
+        verify_token22_program(token_program_info)?;
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(mint_info)?;
+        verify_writable(metadata_freeze_account)?;
+        verify_owner(mint_authority, program_id)?;
+        verify_account_not_initialized(metadata_freeze_account)?;
 
-        // CRITICAL: Verify that the config is for the expected instruction discriminator
-        // This prevents instruction substitution attacks where attacker provides
-        // a valid VerificationConfig PDA for instruction X when code expects instruction Y
-        if config_data.instruction_discriminator != ix_discriminator {
+        let mint_authority_data = MintAuthority::from_account_info(mint_authority)?;
+        if &mint_authority_data.mint != mint_info.key() {
             return Err(ProgramError::InvalidAccountData);
         }
 
-        // Use stored bump with derive_pda for optimized PDA verification
-        // PDA derivation includes mint and instruction_discriminator in seeds,
-        // so successful verification cryptographically guarantees this config
-        // is for the correct mint and instruction type
-        let expected_config_pda = config_data.derive_pda(mint_info.key())?;
+        // Only metadata stored in the mint is controlled by the mint authority
+        let metadata_address: Option<Pubkey> = {
+            let mint_data = mint_info.try_borrow_data()?;
+            let metadata_pointer = get_extension_from_bytes::<MetadataPointer>(&mint_data)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            metadata_pointer.metadata_address.into()
+        };
+        if metadata_address != Some(*mint_info.key()) {
+            return Err(SecurityTokenError::CannotModifyExternalMetadataAccount.into());
+        }
 
-        if verification_config.key().ne(&expected_config_pda) {
-            return Err(SecurityTokenError::InvalidVerificationConfigPda.into());
+        if Self::is_metadata_frozen(mint_info)? {
+            return Err(SecurityTokenError::MetadataFrozen.into());
         }
 
-        if config_data.verification_programs.is_empty() {
-            // If no verification programs configured, return error
+        let (expected_freeze_pda, freeze_bump) =
+            utils::find_metadata_freeze_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(metadata_freeze_account.key(), &expected_freeze_pda)?;
 
-            return Err(ProgramError::InvalidAccountData);
+        let bump_seed = [mint_authority_data.bump];
+        let mint_authority_seeds = [
+            Seed::from(seeds::MINT_AUTHORITY),
+            Seed::from(mint_authority_data.mint.as_ref()),
+            Seed::from(mint_authority_data.mint_creator.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ];
+        let mint_authority_signer = Signer::from(&mint_authority_seeds);
+
+        UpdateAuthority {
+            metadata: mint_info,
+            update_authority: mint_authority,
+            new_authority: None,
         }
+        .invoke_signed(&[mint_authority_signer])?;
 
-        let cleaned_accounts = if config_data.cpi_mode {
-            Self::execute_cpi_mode_verification(
-                &config_data,
-                instruction_accounts,
-                instruction_data,
-            )?
-        } else {
-            Self::execute_introspection_verification(
-                &config_data,
-                instructions_sysvar,
-                instruction_accounts,
-                instruction_data,
-            )?;
-            instruction_accounts
-        };
+        let freeze =
+            MetadataFreeze::new(*mint_info.key(), Clock::get()?.unix_timestamp, freeze_bump);
+        let bump_seed = &freeze.bump_seed();
+        let seeds = freeze.seeds(bump_seed);
+        freeze.init(payer, metadata_freeze_account, &seeds)?;
+        freeze.write_data(metadata_freeze_account)?;
 
-        Ok((mint_info, cleaned_accounts))
+        Ok(())
     }
 
-    fn execute_cpi_mode_verification<'a>(
-        config: &VerificationConfig,
-        instruction_accounts: &'a [AccountInfo],
-        target_instruction_data: &[u8],
-    ) -> Result<&'a [AccountInfo], ProgramError> {
-        let verification_programs_count = config.verification_programs.len();
-        if verification_programs_count > instruction_accounts.len() {
-            debug_log!(
-                "ERROR: Not enough instruction accounts provided for CPI mode verification. Expected at least {}, got {}",
-                verification_programs_count,
-                instruction_accounts.len()
-            );
+    /// Set or remove (empty `value`) a localized `i18n.<locale>.<field>` additional metadata
+    /// entry of the mint, signed by a translator registered in a MetadataTranslator account
+    pub fn update_translation(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        key: &str,
+        value: &str,
+    ) -> ProgramResult {
+        let [translator, payer, mint_info, mint_authority, metadata_translator_account, token_program_info, system_program_info] =
+            accounts
+        else {
             return Err(ProgramError::NotEnoughAccountKeys);
-        }
+        };
 
-        // NOTE: Remove verification program accounts from the end to the explicit instruction accounts
-        // As a side effect it will help in verification programs implementations
-        let target_accounts =
-            &instruction_accounts[..instruction_accounts.len() - verification_programs_count];
+        verify_token22_program(token_program_info)?;
+        verify_system_program(system_program_info)?;
+        verify_signer(translator)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(mint_info)?;
+        verify_owner(mint_authority, program_id)?;
+        verify_owner(metadata_translator_account, program_id)?;
 
-        let target_account_metas: Vec<pinocchio::instruction::AccountMeta> = target_accounts
-            .iter()
-            .map(|acc| pinocchio::instruction::AccountMeta {
+        if !MetadataTranslator::is_translation_key(key) {
+            return Err(SecurityTokenError::InvalidTranslationKey.into());
+        }
+
+        let metadata_translator =
+            MetadataTranslator::from_account_info(metadata_translator_account)?;
+        if &metadata_translator.mint != mint_info.key()
+            || &metadata_translator.translator != translator.key()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(
+            metadata_translator_account.key(),
+            &metadata_translator.derive_pda()?,
+        )?;
+
+        let mint_authority_data = MintAuthority::from_account_info(mint_authority)?;
+        if &mint_authority_data.mint != mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Translations are only maintained for metadata stored in the mint
+        let metadata_address: Option<Pubkey> = {
+            let mint_data = mint_info.try_borrow_data()?;
+            let metadata_pointer = get_extension_from_bytes::<MetadataPointer>(&mint_data)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            metadata_pointer.metadata_address.into()
+        };
+        if metadata_address != Some(*mint_info.key()) {
+            return Err(SecurityTokenError::CannotModifyExternalMetadataAccount.into());
+        }
+
+        if Self::is_metadata_frozen(mint_info)? {
+            return Err(SecurityTokenError::MetadataFrozen.into());
+        }
+
+        let bump_seed = [mint_authority_data.bump];
+        let mint_authority_seeds = [
+            Seed::from(seeds::MINT_AUTHORITY),
+            Seed::from(mint_authority_data.mint.as_ref()),
+            Seed::from(mint_authority_data.mint_creator.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ];
+        let mint_authority_signer = Signer::from(&mint_authority_seeds);
+
+        if value.is_empty() {
+            RemoveKey {
+                metadata_program: &pinocchio_token_2022::ID,
+                metadata: mint_info,
+                update_authority: mint_authority,
+                key,
+                idempotent: true,
+            }
+            .invoke_signed(&[mint_authority_signer])?;
+            return Ok(());
+        }
+
+        UpdateField {
+            metadata_program: &pinocchio_token_2022::ID,
+            metadata: mint_info,
+            update_authority: mint_authority,
+            field: Field::Key(key),
+            value,
+        }
+        .invoke_signed(&[mint_authority_signer])?;
+
+        // The metadata extension grew in place, keep the mint rent exempt
+        Self::top_up_rent(payer, mint_info, &Rent::get()?, mint_info.data_len())
+    }
+
+    /// Whether the token metadata stored in the mint has no update authority anymore
+    fn is_metadata_frozen(mint_info: &AccountInfo) -> Result<bool, ProgramError> {
+        let (metadata, _) = Self::load_metadata(&pinocchio_token_2022::ID, mint_info)?;
+        Ok(metadata.update_authority == Pubkey::default())
+    }
+
+    /// Token metadata stored in the mint or in an external metadata account, with the
+    /// length of its type-length-value entry
+    fn load_metadata<'a>(
+        metadata_program: &Pubkey,
+        metadata_account: &AccountInfo,
+    ) -> Result<(TokenMetadata<'a>, usize), ProgramError> {
+        if metadata_program != &pinocchio_token_2022::ID {
+            return TokenMetadata::from_external_account_info(metadata_account, metadata_program);
+        }
+
+        let metadata = TokenMetadata::from_account_info(metadata_account)?;
+        let metadata_size = {
+            let mint_data = metadata_account.try_borrow_data()?;
+            get_extension_data_bytes_for_variable_pack::<TokenMetadata>(&mint_data)
+                .ok_or(ProgramError::InvalidAccountData)?
+                .len()
+                + EXTENSION_TYPE_LEN
+                + EXTENSION_LENGTH_LEN
+        };
+        Ok((metadata, metadata_size))
+    }
+
+    /// Metadata program and account holding the metadata the MetadataPointer of the mint
+    /// targets. Metadata stored in the mint is updated through SPL Token 2022. External
+    /// metadata is only supported when its account and metadata program are passed;
+    /// otherwise it should be managed directly
+    fn metadata_accounts<'b>(
+        program_id: &Pubkey,
+        mint_info: &'b AccountInfo,
+        optional_accounts: Option<&'b [AccountInfo]>,
+    ) -> Result<(&'b Pubkey, &'b AccountInfo), ProgramError> {
+        let metadata_address: Option<Pubkey> = {
+            let mint_data = mint_info.try_borrow_data()?;
+            let metadata_pointer = get_extension_from_bytes::<MetadataPointer>(&mint_data)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            metadata_pointer.metadata_address.into()
+        };
+        let metadata_address = metadata_address.ok_or(ProgramError::InvalidAccountData)?;
+
+        if metadata_address == *mint_info.key() {
+            return Ok((&pinocchio_token_2022::ID, mint_info));
+        }
+        let Some((metadata_account, metadata_program)) =
+            Self::external_metadata_accounts(program_id, optional_accounts)
+        else {
+            return Err(SecurityTokenError::CannotModifyExternalMetadataAccount.into());
+        };
+        Self::verify_external_metadata(
+            program_id,
+            &metadata_address,
+            metadata_account,
+            metadata_program,
+        )?;
+        Ok((metadata_program.key(), metadata_account))
+    }
+
+    /// MetadataSchema of the mint, if the optional schema account is passed and initialized.
+    /// Clients fill omitted optional accounts with the program id. Mints without a schema
+    /// pass the uninitialized schema PDA, so clients can always include it.
+    fn load_metadata_schema(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        metadata_schema_account: Option<&AccountInfo>,
+    ) -> Result<Option<MetadataSchema>, ProgramError> {
+        let Some(metadata_schema_account) =
+            metadata_schema_account.filter(|account| account.key() != program_id)
+        else {
+            return Ok(None);
+        };
+        let (expected_schema_pda, _bump) =
+            utils::find_metadata_schema_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(metadata_schema_account.key(), &expected_schema_pda)?;
+        if metadata_schema_account.data_is_empty() {
+            return Ok(None);
+        }
+        MetadataSchema::from_account_info(metadata_schema_account).map(Some)
+    }
+
+    /// External metadata account and its metadata program from the optional accounts.
+    /// Clients fill omitted optional accounts with the program id
+    fn external_metadata_accounts<'b>(
+        program_id: &Pubkey,
+        optional_accounts: Option<&'b [AccountInfo]>,
+    ) -> Option<(&'b AccountInfo, &'b AccountInfo)> {
+        match optional_accounts? {
+            [metadata_account, metadata_program, ..]
+                if metadata_account.key() != program_id && metadata_program.key() != program_id =>
+            {
+                Some((metadata_account, metadata_program))
+            }
+            _ => None,
+        }
+    }
+
+    /// Verify the external metadata account is the MetadataPointer target and is owned by a
+    /// metadata program other than SPL Token 2022 and this program
+    fn verify_external_metadata(
+        program_id: &Pubkey,
+        metadata_address: &Pubkey,
+        metadata_account: &AccountInfo,
+        metadata_program: &AccountInfo,
+    ) -> ProgramResult {
+        if metadata_account.key() != metadata_address {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !metadata_program.executable()
+            || metadata_program.key() == &pinocchio_token_2022::ID
+            || metadata_program.key() == program_id
+            || metadata_program.key() == &pinocchio_system::ID
+        {
+            return Err(SecurityTokenError::InvalidMetadataProgram.into());
+        }
+        if !metadata_account.is_owned_by(metadata_program.key()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        verify_writable(metadata_account)?;
+        Ok(())
+    }
+
+    /// Fund `account` up to the rent exempt minimum of `data_len` bytes
+    fn top_up_rent(
+        payer: &AccountInfo,
+        account: &AccountInfo,
+        rent: &Rent,
+        data_len: usize,
+    ) -> ProgramResult {
+        let required_lamports = rent.minimum_balance(data_len);
+        let lamports = account.lamports();
+        if lamports < required_lamports {
+            Transfer {
+                from: payer,
+                to: account,
+                lamports: required_lamports - lamports,
+            }
+            .invoke()?;
+        }
+        Ok(())
+    }
+
+    /// Verify specific operation against configured verification programs
+    ///
+    /// Client is responsible for deriving and providing the correct VerificationConfig PDA
+    /// based on mint and instruction discriminator they want to verify.
+    ///
+    /// Accounts from index 3+ will be compared with accounts from verification program calls.
+    /// Verification programs should be called with at least a full set of accounts in the exact order.
+    pub fn verify_instruction(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args: &VerifyArgs,
+    ) -> ProgramResult {
+        let mut instruction_data = Vec::with_capacity(1 + args.instruction_data.len());
+        instruction_data.push(args.ix);
+        instruction_data.extend_from_slice(&args.instruction_data);
+        Self::verify_by_programs(program_id, accounts, args.ix, &instruction_data)?;
+        Ok(())
+    }
+
+    /// Verify specific operation either through configured verification programs, mint authority,
+    /// session key or agent
+    /// Decides which method to use based on the PDA account provided in accounts[1]
+    /// The mint authority and session key methods are only accepted if `allow_mint_authority` is set
+    ///
+    /// # Returns
+    /// * `verified_mint_info` - The authorized Mint account (prevents mint substitution attacks in operations)
+    /// * `cleaned_accounts` - Remaining instruction accounts after verification overhead
+    pub fn verify_by_strategy<'a>(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo],
+        ix_discriminator: u8,
+        instruction_data: &[u8],
+        allow_mint_authority: bool,
+    ) -> Result<(&'a AccountInfo, &'a [AccountInfo]), ProgramError> {
+        let [mint_info, verification_config_or_mint_authority, instructions_sysvar_or_signer, _instruction_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        let config_data = verification_config_or_mint_authority.try_borrow_data()?;
+        let state_discriminator = config_data
+            .first()
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let disc = SecurityTokenDiscriminators::from_u8(*state_discriminator)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        match disc {
+            SecurityTokenDiscriminators::VerificationConfigDiscriminator => {
+                let (mint_info, cleaned_accounts) = Self::verify_by_programs(
+                    program_id,
+                    accounts,
+                    ix_discriminator,
+                    instruction_data,
+                )?;
+                Ok((mint_info, cleaned_accounts))
+            }
+            SecurityTokenDiscriminators::MintAuthorityDiscriminator if allow_mint_authority => {
+                let mint_authority_account = verification_config_or_mint_authority;
+                // The creator, or the first multisig signer followed by the other signers
+                let signer_accounts = &accounts[INSTRUCTION_ACCOUNTS_OFFSET - 1..];
+                let (mint_info, signer_count) = Self::verify_by_mint_authority(
+                    program_id,
+                    mint_info,
+                    mint_authority_account,
+                    signer_accounts,
+                )?;
+                Ok((
+                    mint_info,
+                    &accounts[INSTRUCTION_ACCOUNTS_OFFSET - 1 + signer_count..],
+                ))
+            }
+            SecurityTokenDiscriminators::SessionKeyDiscriminator if allow_mint_authority => {
+                let session_key_account = verification_config_or_mint_authority;
+                let session_key_signer = instructions_sysvar_or_signer;
+                let mint_info = Self::verify_by_session_key(
+                    program_id,
+                    mint_info,
+                    session_key_account,
+                    session_key_signer,
+                    ix_discriminator,
+                    instruction_data,
+                )?;
+                Ok((mint_info, &accounts[INSTRUCTION_ACCOUNTS_OFFSET..]))
+            }
+            SecurityTokenDiscriminators::SuccessionPolicyDiscriminator if allow_mint_authority => {
+                let succession_policy_account = verification_config_or_mint_authority;
+                let successor_signer = instructions_sysvar_or_signer;
+                let mint_info = Self::verify_by_successor(
+                    program_id,
+                    mint_info,
+                    succession_policy_account,
+                    successor_signer,
+                )?;
+                Ok((mint_info, &accounts[INSTRUCTION_ACCOUNTS_OFFSET..]))
+            }
+            SecurityTokenDiscriminators::AgentDiscriminator => {
+                let agent_account = verification_config_or_mint_authority;
+                let agent_signer = instructions_sysvar_or_signer;
+                let mint_info = Self::verify_by_agent(
+                    program_id,
+                    mint_info,
+                    agent_account,
+                    agent_signer,
+                    ix_discriminator,
+                )?;
+                Ok((mint_info, &accounts[INSTRUCTION_ACCOUNTS_OFFSET..]))
+            }
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    /// Verify Pause and Resume by the configured verification programs, or directly by the pause
+    /// delegate of the mint when the overhead passes its PauseDelegate account.
+    pub fn verify_by_programs_or_pause_delegate<'a>(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo],
+        ix_discriminator: u8,
+        instruction_data: &[u8],
+    ) -> Result<(&'a AccountInfo, &'a [AccountInfo]), ProgramError> {
+        let [mint_info, verification_config_or_pause_delegate, instructions_sysvar_or_signer, _instruction_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        let is_pause_delegate = verification_config_or_pause_delegate
+            .try_borrow_data()?
+            .first()
+            .is_some_and(|disc| {
+                *disc == SecurityTokenDiscriminators::PauseDelegateDiscriminator as u8
+            });
+        if !is_pause_delegate {
+            return Self::verify_by_programs(
+                program_id,
+                accounts,
+                ix_discriminator,
+                instruction_data,
+            );
+        }
+
+        let mint_info = Self::verify_by_pause_delegate(
+            program_id,
+            mint_info,
+            verification_config_or_pause_delegate,
+            instructions_sysvar_or_signer,
+        )?;
+        Ok((mint_info, &accounts[INSTRUCTION_ACCOUNTS_OFFSET..]))
+    }
+
+    /// Verify that the provided signer is the pause delegate of the mint.
+    ///
+    /// # Returns
+    /// * `verified_mint_info` - The authorized Mint account (prevents mint substitution attacks in operations)
+    pub fn verify_by_pause_delegate<'a>(
+        program_id: &Pubkey,
+        mint_info: &'a AccountInfo,
+        pause_delegate_account: &'a AccountInfo,
+        candidate_delegate: &'a AccountInfo,
+    ) -> Result<&'a AccountInfo, ProgramError> {
+        verify_signer(candidate_delegate)?;
+        verify_owner(pause_delegate_account, program_id)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
+
+        let pause_delegate = PauseDelegate::from_account_info(pause_delegate_account)?;
+
+        // CRITICAL: Verify that the delegate is for the correct mint and signed by the delegate key
+        if pause_delegate.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if pause_delegate.delegate != *candidate_delegate.key() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        verify_pda_keys_match(pause_delegate_account.key(), &pause_delegate.derive_pda()?)?;
+
+        Ok(mint_info)
+    }
+
+    /// Verify InitializeVerificationConfig, UpdateVerificationConfig and TrimVerificationConfig by
+    /// the verification programs or the mint authority, or directly by the config admin of the mint
+    /// when the overhead passes its ConfigAdmin account.
+    pub fn verify_by_strategy_or_config_admin<'a>(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo],
+        ix_discriminator: u8,
+        instruction_data: &[u8],
+    ) -> Result<(&'a AccountInfo, &'a [AccountInfo]), ProgramError> {
+        let [mint_info, verification_config_or_config_admin, instructions_sysvar_or_signer, _instruction_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        let is_config_admin = verification_config_or_config_admin
+            .try_borrow_data()?
+            .first()
+            .is_some_and(|disc| {
+                *disc == SecurityTokenDiscriminators::ConfigAdminDiscriminator as u8
+            });
+        if !is_config_admin {
+            return Self::verify_by_strategy(
+                program_id,
+                accounts,
+                ix_discriminator,
+                instruction_data,
+                true,
+            );
+        }
+
+        let mint_info = Self::verify_by_config_admin(
+            program_id,
+            mint_info,
+            verification_config_or_config_admin,
+            instructions_sysvar_or_signer,
+        )?;
+        Ok((mint_info, &accounts[INSTRUCTION_ACCOUNTS_OFFSET..]))
+    }
+
+    /// Verify that the provided signer is the config admin of the mint.
+    ///
+    /// # Returns
+    /// * `verified_mint_info` - The authorized Mint account (prevents mint substitution attacks in operations)
+    pub fn verify_by_config_admin<'a>(
+        program_id: &Pubkey,
+        mint_info: &'a AccountInfo,
+        config_admin_account: &'a AccountInfo,
+        candidate_admin: &'a AccountInfo,
+    ) -> Result<&'a AccountInfo, ProgramError> {
+        verify_signer(candidate_admin)?;
+        verify_owner(config_admin_account, program_id)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
+
+        let config_admin = ConfigAdmin::from_account_info(config_admin_account)?;
+
+        // CRITICAL: Verify that the admin is for the correct mint and signed by the admin key
+        if config_admin.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if config_admin.admin != *candidate_admin.key() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        verify_pda_keys_match(config_admin_account.key(), &config_admin.derive_pda()?)?;
+
+        Ok(mint_info)
+    }
+
+    /// Verify that the provided signer is an agent of the mint granted the scope of the instruction.
+    ///
+    /// # Returns
+    /// * `verified_mint_info` - The authorized Mint account (prevents mint substitution attacks in operations)
+    pub fn verify_by_agent<'a>(
+        program_id: &Pubkey,
+        mint_info: &'a AccountInfo,
+        agent_account: &'a AccountInfo,
+        candidate_agent: &'a AccountInfo,
+        ix_discriminator: u8,
+    ) -> Result<&'a AccountInfo, ProgramError> {
+        verify_signer(candidate_agent)?;
+        verify_owner(agent_account, program_id)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
+
+        let agent = Agent::from_account_info(agent_account)?;
+
+        // CRITICAL: Verify that the agent is for the correct mint and signed by the agent key
+        if agent.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if agent.agent != *candidate_agent.key() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        verify_pda_keys_match(agent_account.key(), &agent.derive_pda()?)?;
+
+        if !agent.permits(ix_discriminator) {
+            return Err(SecurityTokenError::AgentPermissionDenied.into());
+        }
+
+        Ok(mint_info)
+    }
+
+    /// Verify that the provided signer is an unexpired session key of the mint allowed to execute
+    /// the instruction within its amount limit.
+    ///
+    /// # Returns
+    /// * `verified_mint_info` - The authorized Mint account (prevents mint substitution attacks in operations)
+    pub fn verify_by_session_key<'a>(
+        program_id: &Pubkey,
+        mint_info: &'a AccountInfo,
+        session_key_account: &'a AccountInfo,
+        candidate_session_key: &'a AccountInfo,
+        ix_discriminator: u8,
+        instruction_data: &[u8],
+    ) -> Result<&'a AccountInfo, ProgramError> {
+        verify_signer(candidate_session_key)?;
+        verify_owner(session_key_account, program_id)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
+
+        let session_key = SessionKey::from_account_info(session_key_account)?;
+
+        // CRITICAL: Verify that the session key is for the correct mint and signed by the session key
+        if session_key.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if session_key.session_key != *candidate_session_key.key() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        verify_pda_keys_match(session_key_account.key(), &session_key.derive_pda()?)?;
+
+        let args_data = instruction_data.get(1..).unwrap_or_default();
+        session_key.authorize(ix_discriminator, args_data, Clock::get()?.unix_timestamp)?;
+
+        Ok(mint_info)
+    }
+
+    /// Verify that the provided signer is the successor of the mint that claimed its
+    /// succession, taking over the authority of the mint creator.
+    ///
+    /// # Returns
+    /// * `verified_mint_info` - The authorized Mint account (prevents mint substitution attacks in operations)
+    pub fn verify_by_successor<'a>(
+        program_id: &Pubkey,
+        mint_info: &'a AccountInfo,
+        succession_policy_account: &'a AccountInfo,
+        candidate_successor: &'a AccountInfo,
+    ) -> Result<&'a AccountInfo, ProgramError> {
+        verify_signer(candidate_successor)?;
+        verify_owner(succession_policy_account, program_id)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
+
+        let succession_policy = SuccessionPolicy::from_account_info(succession_policy_account)?;
+
+        // CRITICAL: Verify that the policy is for the correct mint and signed by the successor
+        if succession_policy.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if succession_policy.successor != *candidate_successor.key() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        verify_pda_keys_match(
+            succession_policy_account.key(),
+            &succession_policy.derive_pda()?,
+        )?;
+
+        if !succession_policy.is_claimed() {
+            return Err(SecurityTokenError::SuccessionNotClaimable.into());
+        }
+
+        Ok(mint_info)
+    }
+
+    /// Verify that the provided signer controls the original mint authority PDA.
+    /// With a multisig, the first `multisig_threshold` of `signer_accounts` must be distinct
+    /// signers of the multisig instead.
+    ///
+    /// # Returns
+    /// * `verified_mint_info` - The authorized Mint account (prevents mint substitution attacks in operations)
+    /// * `signer_count` - Number of `signer_accounts` consumed by the verification
+    pub fn verify_by_mint_authority<'a>(
+        program_id: &Pubkey,
+        mint_info: &'a AccountInfo,
+        mint_authority: &'a AccountInfo,
+        signer_accounts: &'a [AccountInfo],
+    ) -> Result<(&'a AccountInfo, usize), ProgramError> {
+        let [candidate_authority, ..] = signer_accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        verify_signer(candidate_authority)?;
+        verify_owner(mint_authority, program_id)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
+
+        let data = mint_authority.try_borrow_data()?;
+        if data.len() < MintAuthority::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint_authority_state = MintAuthority::try_from_bytes(&data)?;
+
+        // CRITICAL: Verify that the authority is for the correct mint and signed by its controller,
+        // the creator until a creator transfer is accepted, or by its multisig
+        // These checks prevent using a valid MintAuthority PDA for a different mint/creator combination
+        if mint_authority_state.mint != *mint_info.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let signer_count = if mint_authority_state.is_multisig() {
+            let multisig_accounts = signer_accounts
+                .get(..mint_authority_state.multisig_threshold as usize)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            for (index, signer) in multisig_accounts.iter().enumerate() {
+                verify_signer(signer)?;
+                if !mint_authority_state.multisig_signers.contains(signer.key())
+                    || multisig_accounts[..index]
+                        .iter()
+                        .any(|other| other.key() == signer.key())
+                {
+                    return Err(ProgramError::MissingRequiredSignature);
+                }
+            }
+            multisig_accounts.len()
+        } else {
+            if mint_authority_state.controller != *candidate_authority.key() {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            1
+        };
+
+        // Use stored bump with derive_pda for optimized PDA verification
+        let expected_pda = mint_authority_state.derive_pda()?;
+
+        verify_pda_keys_match(mint_authority.key(), &expected_pda)?;
+
+        Ok((mint_info, signer_count))
+    }
+
+    /// Verify specific operation against configured verification programs
+    ///
+    /// # Returns
+    /// * `verified_mint_info` - The authorized Mint account (prevents mint substitution attacks in operations)
+    /// * `cleaned_accounts` - Remaining instruction accounts after verification overhead
+    pub fn verify_by_programs<'a>(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo],
+        ix_discriminator: u8,
+        instruction_data: &[u8],
+    ) -> Result<(&'a AccountInfo, &'a [AccountInfo]), ProgramError> {
+        let [mint_info, verification_config, instructions_sysvar, instruction_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_instructions_sysvar(instructions_sysvar)?;
+        verify_owner(verification_config, program_id)?;
+        verify_owner(mint_info, &pinocchio_token_2022::ID)?;
+        verify_account_initialized(verification_config)?;
+
+        let mut config_data = VerificationConfig::from_account_info(verification_config)?;
+
+        // CRITICAL: Verify that the config is for the expected instruction discriminator
+        // This prevents instruction substitution attacks where attacker provides
+        // a valid VerificationConfig PDA for instruction X when code expects instruction Y
+        if config_data.instruction_discriminator != ix_discriminator {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Use stored bump with derive_pda for optimized PDA verification
+        // PDA derivation includes mint and instruction_discriminator in seeds,
+        // so successful verification cryptographically guarantees this config
+        // is for the correct mint and instruction type
+        let expected_config_pda = config_data.derive_pda(mint_info.key())?;
+
+        if verification_config.key().ne(&expected_config_pda) {
+            return Err(SecurityTokenError::InvalidVerificationConfigPda.into());
+        }
+
+        let instruction_accounts =
+            Self::resolve_config_template(program_id, &mut config_data, instruction_accounts)?;
+
+        if config_data.verification_programs.is_empty() {
+            // If no verification programs configured, return error
+
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut consulted_programs = Vec::new();
+        let result = if config_data.cpi_mode {
+            Self::execute_cpi_mode_verification(
+                &config_data,
+                instruction_accounts,
+                instruction_data,
+                &mut consulted_programs,
+            )
+        } else {
+            Self::execute_introspection_verification(
+                &config_data,
+                instructions_sysvar,
+                instruction_accounts,
+                instruction_data,
+                &mut consulted_programs,
+            )
+            .map(|()| instruction_accounts)
+        };
+
+        // Logged for failures too, the logs of failed transactions stay available to surveillance
+        VerificationEvent {
+            mint: *mint_info.key(),
+            instruction_discriminator: ix_discriminator,
+            cpi_mode: config_data.cpi_mode,
+            threshold: config_data.threshold,
+            reason: result.as_ref().err().map_or(0, |error| u64::from(*error)),
+            programs: consulted_programs,
+        }
+        .emit();
+        let cleaned_accounts = result?;
+
+        Ok((mint_info, cleaned_accounts))
+    }
+
+    /// Use the programs of the ConfigTemplate a config is linked to, unless the config
+    /// overrides them with its own programs
+    ///
+    /// The template account follows all other instruction accounts and is cut from them.
+    fn resolve_config_template<'a>(
+        program_id: &Pubkey,
+        config: &mut VerificationConfig,
+        instruction_accounts: &'a [AccountInfo],
+    ) -> Result<&'a [AccountInfo], ProgramError> {
+        let Some(config_template) = config.config_template else {
+            return Ok(instruction_accounts);
+        };
+
+        let Some((template_account, remaining_accounts)) = instruction_accounts.split_last() else {
+            return Err(SecurityTokenError::ConfigTemplateMismatch.into());
+        };
+        if template_account.key().ne(&config_template) {
+            return Err(SecurityTokenError::ConfigTemplateMismatch.into());
+        }
+        verify_owner(template_account, program_id)?;
+
+        let template = ConfigTemplate::from_account_info(template_account)?;
+        verify_pda_keys_match(template_account.key(), &template.derive_pda()?)?;
+
+        if config.verification_programs.is_empty() {
+            config.verification_programs = template.verification_programs;
+        }
+        Ok(remaining_accounts)
+    }
+
+    fn execute_cpi_mode_verification<'a>(
+        config: &VerificationConfig,
+        instruction_accounts: &'a [AccountInfo],
+        target_instruction_data: &[u8],
+        consulted_programs: &mut Vec<Pubkey>,
+    ) -> Result<&'a [AccountInfo], ProgramError> {
+        let verification_programs_count =
+            config.required_programs(config.verification_programs.len());
+        if verification_programs_count > instruction_accounts.len() {
+            debug_log!(
+                "ERROR: Not enough instruction accounts provided for CPI mode verification. Expected at least {}, got {}",
+                verification_programs_count,
+                instruction_accounts.len()
+            );
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        // NOTE: Remove verification program accounts from the end to the explicit instruction accounts
+        // As a side effect it will help in verification programs implementations
+        let (target_accounts, program_accounts) =
+            instruction_accounts.split_at(instruction_accounts.len() - verification_programs_count);
+
+        // With a threshold the caller picks the programs to run by passing them as the trailing accounts
+        let selected_programs: Vec<Pubkey> = if config.threshold == 0 {
+            config.verification_programs.clone()
+        } else {
+            let mut selected_programs: Vec<Pubkey> = Vec::with_capacity(program_accounts.len());
+            for program_account in program_accounts {
+                if !config.verification_programs.contains(program_account.key())
+                    || selected_programs.contains(program_account.key())
+                {
+                    return Err(SecurityTokenError::VerificationProgramNotFound.into());
+                }
+                selected_programs.push(*program_account.key());
+            }
+            selected_programs
+        };
+        consulted_programs.clone_from(&selected_programs);
+
+        let target_account_metas: Vec<pinocchio::instruction::AccountMeta> = target_accounts
+            .iter()
+            .map(|acc| pinocchio::instruction::AccountMeta {
                 pubkey: acc.key(),
                 is_signer: acc.is_signer(),
                 is_writable: acc.is_writable(),
@@ -735,7 +1587,7 @@ impl VerificationModule {
 
         let account_refs: Vec<_> = target_accounts.iter().collect();
 
-        for program_id in config.verification_programs.iter() {
+        for program_id in selected_programs.iter() {
             let verification_instruction = pinocchio::instruction::Instruction {
                 program_id,
                 accounts: &target_account_metas,
@@ -755,11 +1607,30 @@ impl VerificationModule {
         instructions_sysvar: &AccountInfo,
         instruction_accounts: &[AccountInfo],
         target_instruction_data: &[u8],
+        consulted_programs: &mut Vec<Pubkey>,
     ) -> ProgramResult {
         // Get current instruction index
         let instructions = Instructions::try_from(instructions_sysvar)?;
         let current_index = instructions.load_current_index() as usize;
 
+        // Without a CPI in between, the current top-level instruction is the verified one
+        if config.require_top_level
+            && instructions
+                .load_instruction_at(current_index)?
+                .get_program_id()
+                .ne(&crate::ID)
+        {
+            debug_log!("ERROR: Verified instruction is not a top-level instruction");
+            return Err(SecurityTokenError::InstructionNotTopLevel.into());
+        }
+
+        // Verification calls must be among the `lookback_window` instructions right before this one
+        let first_index = match config.lookback_window {
+            0 => 0,
+            lookback_window => current_index.saturating_sub(lookback_window as usize),
+        };
+
+        let required_programs = config.required_programs(config.verification_programs.len());
         let mut collected_accounts: Vec<Option<Vec<Pubkey>>> =
             vec![None; config.verification_programs.len()];
         let mut remaining_indices: HashSet<usize> =
@@ -775,8 +1646,8 @@ impl VerificationModule {
         let mut verified_programs: Vec<(Pubkey, usize)> = Vec::new();
 
         if current_index > 0 {
-            for instr_idx in (0..current_index).rev() {
-                if remaining_indices.is_empty() {
+            for instr_idx in (first_index..current_index).rev() {
+                if verified_programs.len() >= required_programs {
                     break;
                 }
 
@@ -816,19 +1687,22 @@ impl VerificationModule {
             }
         }
 
+        consulted_programs.extend(verified_programs.iter().map(|(program, _)| *program));
+
         #[cfg_attr(not(feature = "debug-logs"), allow(unused_variables))]
-        if let Some(&missing_idx) = remaining_indices.iter().next() {
-            debug_log!(
-                "ERROR: Required verification program {} not found",
-                crate::key_as_str!(config.verification_programs[missing_idx])
-            );
+        if verified_programs.len() < required_programs {
+            if let Some(&missing_idx) = remaining_indices.iter().next() {
+                debug_log!(
+                    "ERROR: Required verification program {} not found",
+                    crate::key_as_str!(config.verification_programs[missing_idx])
+                );
+            }
             return Err(SecurityTokenError::VerificationProgramNotFound.into());
         }
 
-        let all_verification_accounts: Vec<Vec<Pubkey>> = collected_accounts
-            .into_iter()
-            .map(|entry| entry.expect("missing verification program accounted above"))
-            .collect();
+        // Only the programs that were called take part in the account verification
+        let all_verification_accounts: Vec<Vec<Pubkey>> =
+            collected_accounts.into_iter().flatten().collect();
 
         if !all_verification_accounts.is_empty() {
             let instruction_account_keys: Vec<Pubkey> =
@@ -881,8 +1755,16 @@ impl VerificationModule {
         }
 
         // Create the VerificationConfig data first to calculate exact size
-        let config =
-            VerificationConfig::new(discriminator, args.cpi_mode, bump, args.program_addresses())?;
+        let mut config = VerificationConfig::new(
+            discriminator,
+            args.cpi_mode,
+            bump,
+            args.program_addresses(),
+            args.threshold,
+            args.lookback_window,
+            args.require_top_level,
+        )?;
+        config.timelock_seconds = args.timelock_seconds;
 
         let account_size = config.serialized_size();
 
@@ -927,6 +1809,7 @@ impl VerificationModule {
                 transfer_hook_accounts,
                 *config_account.key(),
                 args.program_addresses(),
+                args.threshold,
             )?;
         }
         Ok(())
@@ -936,12 +1819,15 @@ impl VerificationModule {
     fn sync_transfer_hook_account_metas(
         program_id: &Pubkey,
         payer: &AccountInfo,
+        recipient: &AccountInfo,
         mint_info: &AccountInfo,
         system_program_info: &AccountInfo,
         transfer_hook_accounts: &[AccountInfo],
         verification_config_pda: Pubkey,
         program_addresses: &[Pubkey],
+        threshold: u8,
         is_initialization: bool,
+        rules: TransferHookRules,
     ) -> ProgramResult {
         let [account_metas_pda_info, transfer_hook_pda_info, transfer_hook_program] =
             transfer_hook_accounts
@@ -973,6 +1859,145 @@ impl VerificationModule {
             });
         }
 
+        // With a threshold the transfer hook looks for the verification calls in the transaction
+        if threshold != 0 && !program_addresses.is_empty() {
+            account_metas.push(ExtraAccountMeta {
+                discriminator: 0,
+                address_config: INSTRUCTIONS_ID,
+                is_signer: PodBool(0),
+                is_writable: PodBool(0),
+            });
+        }
+
+        // Rules not explicitly enabled or disabled are kept as they are
+        let current_rules = if is_initialization {
+            TransferHookRuleFlags::default()
+        } else {
+            Self::transfer_hook_rule_flags(program_id, mint_info, account_metas_pda_info)?
+        };
+        let set_rule = |flags: TransferHookRuleFlags, flag: u32, enabled: Option<bool>| {
+            enabled.map_or(flags, |enabled| flags.with(flag, enabled))
+        };
+        let mut rule_flags = current_rules.with(
+            TransferHookRuleFlags::SUSPENSION | TransferHookRuleFlags::SUB_ACCOUNT_LEDGER,
+            true,
+        );
+        rule_flags = set_rule(
+            rule_flags,
+            TransferHookRuleFlags::ALLOWLIST,
+            rules.allowlist,
+        );
+        rule_flags = set_rule(
+            rule_flags,
+            TransferHookRuleFlags::HOLDING_PERIOD,
+            rules.holding_period,
+        );
+        rule_flags = set_rule(
+            rule_flags,
+            TransferHookRuleFlags::COUPON_ACCRUAL,
+            rules.coupon_accrual,
+        );
+        rule_flags = set_rule(
+            rule_flags,
+            TransferHookRuleFlags::POSITION_LIMIT,
+            rules.position_limit,
+        );
+        rule_flags = set_rule(
+            rule_flags,
+            TransferHookRuleFlags::TRANSFER_APPROVAL,
+            rules.transfer_approval,
+        );
+        rule_flags = set_rule(
+            rule_flags,
+            TransferHookRuleFlags::TRANSFER_ACCEPTANCE,
+            rules.transfer_acceptance,
+        );
+        rule_flags = set_rule(
+            rule_flags,
+            TransferHookRuleFlags::PERMANENT_DELEGATE_CONFIG,
+            rules.permanent_delegate_config,
+        );
+
+        // The transfer hook reads the enabled rules from the marker and their accounts after it
+        account_metas.push(ExtraAccountMeta {
+            discriminator: 0,
+            address_config: rule_flags.to_marker(),
+            is_signer: PodBool(0),
+            is_writable: PodBool(0),
+        });
+
+        // Suspensions and sub-account ledgers are checked for every mint, so their accounts
+        // always follow the marker
+        let suspension_start = EXECUTE_EXTRA_ACCOUNTS_OFFSET + account_metas.len();
+        account_metas.extend(Self::suspension_account_metas(
+            program_id,
+            suspension_start,
+        )?);
+        if rule_flags.contains(TransferHookRuleFlags::SUB_ACCOUNT_LEDGER) {
+            account_metas.extend(Self::sub_account_ledger_account_metas(suspension_start)?);
+        }
+
+        if rule_flags.contains(TransferHookRuleFlags::ALLOWLIST) {
+            let tail_start = EXECUTE_EXTRA_ACCOUNTS_OFFSET + account_metas.len();
+            account_metas.extend(Self::allowlist_account_metas(program_id, tail_start)?);
+        }
+        if rule_flags.contains(TransferHookRuleFlags::HOLDING_PERIOD) {
+            let (holding_period_pda, _bump) =
+                utils::find_holding_period_pda(mint_info.key(), program_id);
+            let tail_start = EXECUTE_EXTRA_ACCOUNTS_OFFSET + account_metas.len();
+            account_metas.extend(Self::holding_period_account_metas(
+                program_id,
+                holding_period_pda,
+                tail_start,
+            )?);
+        }
+        if rule_flags.contains(TransferHookRuleFlags::COUPON_ACCRUAL) {
+            let (accrual_config_pda, _bump) =
+                utils::find_accrual_config_pda(mint_info.key(), program_id);
+            let tail_start = EXECUTE_EXTRA_ACCOUNTS_OFFSET + account_metas.len();
+            account_metas.extend(Self::coupon_claim_account_metas(
+                program_id,
+                accrual_config_pda,
+                tail_start,
+            )?);
+        }
+        if rule_flags.contains(TransferHookRuleFlags::POSITION_LIMIT) {
+            let tail_start = EXECUTE_EXTRA_ACCOUNTS_OFFSET + account_metas.len();
+            account_metas.extend(Self::position_limit_account_metas(program_id, tail_start)?);
+        }
+        // Rule markers close the list
+        if rule_flags.contains(TransferHookRuleFlags::TRANSFER_APPROVAL) {
+            let (transfer_approval_pda, _bump) =
+                utils::find_transfer_approval_pda(mint_info.key(), program_id);
+            account_metas.push(ExtraAccountMeta {
+                discriminator: 0,
+                address_config: transfer_approval_pda,
+                is_signer: PodBool(0),
+                is_writable: PodBool(0),
+            });
+        }
+        if rule_flags.contains(TransferHookRuleFlags::TRANSFER_ACCEPTANCE) {
+            let (transfer_acceptance_pda, _bump) =
+                utils::find_transfer_acceptance_pda(mint_info.key(), program_id);
+            account_metas.push(ExtraAccountMeta {
+                discriminator: 0,
+                address_config: transfer_acceptance_pda,
+                is_signer: PodBool(0),
+                is_writable: PodBool(0),
+            });
+        }
+        // The transfer hook lets transfers of a rotated permanent delegate through
+        if rule_flags.contains(TransferHookRuleFlags::PERMANENT_DELEGATE_CONFIG) {
+            let (permanent_delegate_config_pda, _bump) =
+                utils::find_permanent_delegate_config_pda(mint_info.key(), program_id);
+            account_metas.push(ExtraAccountMeta {
+                discriminator: 0,
+                address_config: permanent_delegate_config_pda,
+                is_signer: PodBool(0),
+                is_writable: PodBool(0),
+            });
+        }
+
         let new_account_size = ExtraAccountMetaList::size_of(account_metas.len())
             .map_err(|_| ProgramError::InvalidAccountData)?;
         let rent = Rent::get()?;
@@ -1025,7 +2050,7 @@ impl VerificationModule {
                 mint: mint_info,
                 authority: transfer_hook_pda_info,
                 system_program: system_program_info,
-                recipient: Some(payer),
+                recipient: Some(recipient),
                 metas: &account_metas,
             };
             instruction.invoke_signed(&[signer])?;
@@ -1033,49 +2058,511 @@ impl VerificationModule {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn update_transfer_hook_account_metas(
         program_id: &Pubkey,
         payer: &AccountInfo,
+        recipient: &AccountInfo,
         mint_info: &AccountInfo,
         system_program_info: &AccountInfo,
         transfer_hook_accounts: &[AccountInfo],
         verification_config_pda: Pubkey,
         new_program_addresses: &[Pubkey],
+        threshold: u8,
     ) -> ProgramResult {
         Self::sync_transfer_hook_account_metas(
             program_id,
             payer,
+            recipient,
             mint_info,
             system_program_info,
             transfer_hook_accounts,
             verification_config_pda,
             new_program_addresses,
+            threshold,
+            false,
+            TransferHookRules::default(),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn initialize_transfer_hook_account_metas(
+        program_id: &Pubkey,
+        payer: &AccountInfo,
+        mint_info: &AccountInfo,
+        system_program_info: &AccountInfo,
+        transfer_hook_accounts: &[AccountInfo],
+        verification_config_pda: Pubkey,
+        program_addresses: &[Pubkey],
+        threshold: u8,
+    ) -> ProgramResult {
+        Self::sync_transfer_hook_account_metas(
+            program_id,
+            payer,
+            payer,
+            mint_info,
+            system_program_info,
+            transfer_hook_accounts,
+            verification_config_pda,
+            program_addresses,
+            threshold,
+            true,
+            TransferHookRules {
+                allowlist: Some(false),
+                holding_period: Some(false),
+                coupon_accrual: Some(false),
+                position_limit: Some(false),
+                transfer_acceptance: Some(false),
+                transfer_approval: Some(false),
+                permanent_delegate_config: Some(false),
+            },
+        )
+    }
+
+    /// Enable or disable transfer hook rules in the extra account metas
+    ///
+    /// The transfer verification config has to be initialized, its programs are kept as is.
+    /// `payer` funds a larger account, `recipient` receives the rent of a smaller one.
+    #[allow(clippy::too_many_arguments)]
+    pub fn sync_transfer_hook_rules(
+        program_id: &Pubkey,
+        payer: &AccountInfo,
+        recipient: &AccountInfo,
+        mint_info: &AccountInfo,
+        config_account: &AccountInfo,
+        system_program_info: &AccountInfo,
+        transfer_hook_accounts: &[AccountInfo],
+        rules: TransferHookRules,
+    ) -> ProgramResult {
+        verify_system_program(system_program_info)?;
+        verify_owner(config_account, program_id)?;
+        verify_account_initialized(config_account)?;
+
+        let config = VerificationConfig::from_account_info(config_account)?;
+        if config.instruction_discriminator != SecurityTokenInstruction::Transfer as u8 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        verify_pda_keys_match(config_account.key(), &config.derive_pda(mint_info.key())?)?;
+
+        Self::sync_transfer_hook_account_metas(
+            program_id,
+            payer,
+            recipient,
+            mint_info,
+            system_program_info,
+            transfer_hook_accounts,
+            *config_account.key(),
+            config.verification_programs.as_slice(),
+            config.threshold,
+            false,
+            rules,
+        )
+    }
+
+    /// Extra account metas resolving the Suspension PDAs of the source and destination
+    ///
+    /// `tail_start` is the Execute instruction index of the first returned account:
+    /// security token program, source Suspension and destination Suspension.
+    /// The Suspension accounts are empty unless the token account is suspended.
+    fn suspension_account_metas(
+        program_id: &Pubkey,
+        tail_start: usize,
+    ) -> Result<Vec<ExtraAccountMeta>, ProgramError> {
+        let program_index = u8::try_from(tail_start).map_err(|_| ProgramError::InvalidArgument)?;
+
+        let mut account_metas = vec![ExtraAccountMeta {
+            discriminator: 0,
+            address_config: *program_id,
+            is_signer: PodBool(0),
+            is_writable: PodBool(0),
+        }];
+        for index in [EXECUTE_SOURCE_INDEX, EXECUTE_DESTINATION_INDEX] {
+            account_metas.push(
+                ExtraAccountMeta::new_external_pda_with_seeds(
+                    program_index,
+                    &[
+                        ExtraAccountMetaSeed::Literal {
+                            bytes: seeds::SUSPENSION_ACCOUNT.to_vec(),
+                        },
+                        ExtraAccountMetaSeed::AccountKey { index },
+                    ],
+                    false,
+                    false,
+                )
+                .map_err(|_| ProgramError::InvalidArgument)?,
+            );
+        }
+        Ok(account_metas)
+    }
+
+    /// Extra account metas resolving the SubAccountLedger of the source
+    ///
+    /// `program_start` is the Execute instruction index of the security token program account
+    /// of the [suspension accounts](Self::suspension_account_metas). The SubAccountLedger is
+    /// empty unless the source is a custodian omnibus account.
+    fn sub_account_ledger_account_metas(
+        program_start: usize,
+    ) -> Result<Vec<ExtraAccountMeta>, ProgramError> {
+        let program_index =
+            u8::try_from(program_start).map_err(|_| ProgramError::InvalidArgument)?;
+
+        Ok(vec![ExtraAccountMeta::new_external_pda_with_seeds(
+            program_index,
+            &[
+                ExtraAccountMetaSeed::Literal {
+                    bytes: seeds::SUB_ACCOUNT_LEDGER_ACCOUNT.to_vec(),
+                },
+                ExtraAccountMetaSeed::AccountKey {
+                    index: EXECUTE_SOURCE_INDEX,
+                },
+            ],
+            false,
             false,
         )
+        .map_err(|_| ProgramError::InvalidArgument)?])
+    }
+
+    /// Extra account metas resolving the AllowlistEntry PDAs of the source and destination owners
+    ///
+    /// `tail_start` is the Execute instruction index of the first returned account:
+    /// security token program, source owner AllowlistEntry and destination owner AllowlistEntry.
+    /// The AllowlistEntry accounts are empty unless the owner is allowlisted.
+    fn allowlist_account_metas(
+        program_id: &Pubkey,
+        tail_start: usize,
+    ) -> Result<Vec<ExtraAccountMeta>, ProgramError> {
+        let program_index = u8::try_from(tail_start).map_err(|_| ProgramError::InvalidArgument)?;
+
+        let mut account_metas = vec![ExtraAccountMeta {
+            discriminator: 0,
+            address_config: *program_id,
+            is_signer: PodBool(0),
+            is_writable: PodBool(0),
+        }];
+        for account_index in [EXECUTE_SOURCE_INDEX, EXECUTE_DESTINATION_INDEX] {
+            account_metas.push(
+                ExtraAccountMeta::new_external_pda_with_seeds(
+                    program_index,
+                    &[
+                        ExtraAccountMetaSeed::Literal {
+                            bytes: seeds::ALLOWLIST_ENTRY_ACCOUNT.to_vec(),
+                        },
+                        ExtraAccountMetaSeed::AccountKey {
+                            index: EXECUTE_MINT_INDEX,
+                        },
+                        // Token account layout: [0-31] mint, [32-63] owner
+                        ExtraAccountMetaSeed::AccountData {
+                            account_index,
+                            data_index: 32,
+                            length: 32,
+                        },
+                    ],
+                    false,
+                    false,
+                )
+                .map_err(|_| ProgramError::InvalidArgument)?,
+            );
+        }
+        Ok(account_metas)
+    }
+
+    /// Extra account metas resolving the holding period and the source restricted holding
+    ///
+    /// `tail_start` is the Execute instruction index of the first returned account:
+    /// security token program, HoldingPeriod and the RestrictedHolding of the source token account.
+    fn holding_period_account_metas(
+        program_id: &Pubkey,
+        holding_period_pda: Pubkey,
+        tail_start: usize,
+    ) -> Result<Vec<ExtraAccountMeta>, ProgramError> {
+        let program_index = u8::try_from(tail_start).map_err(|_| ProgramError::InvalidArgument)?;
+
+        Ok(vec![
+            ExtraAccountMeta {
+                discriminator: 0,
+                address_config: *program_id,
+                is_signer: PodBool(0),
+                is_writable: PodBool(0),
+            },
+            ExtraAccountMeta {
+                discriminator: 0,
+                address_config: holding_period_pda,
+                is_signer: PodBool(0),
+                is_writable: PodBool(0),
+            },
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                program_index,
+                &[
+                    ExtraAccountMetaSeed::Literal {
+                        bytes: seeds::RESTRICTED_HOLDING_ACCOUNT.to_vec(),
+                    },
+                    ExtraAccountMetaSeed::AccountKey {
+                        index: EXECUTE_SOURCE_INDEX,
+                    },
+                ],
+                false,
+                false,
+            )
+            .map_err(|_| ProgramError::InvalidArgument)?,
+        ])
+    }
+
+    /// Extra account metas resolving the coupon accrual and the source and destination coupon claims
+    ///
+    /// `tail_start` is the Execute instruction index of the first returned account:
+    /// security token program, AccrualConfig and the CouponClaim PDAs of the source and
+    /// destination token accounts, which are empty until their first claim.
+    fn coupon_claim_account_metas(
+        program_id: &Pubkey,
+        accrual_config_pda: Pubkey,
+        tail_start: usize,
+    ) -> Result<Vec<ExtraAccountMeta>, ProgramError> {
+        let program_index = u8::try_from(tail_start).map_err(|_| ProgramError::InvalidArgument)?;
+
+        let mut account_metas = vec![
+            ExtraAccountMeta {
+                discriminator: 0,
+                address_config: *program_id,
+                is_signer: PodBool(0),
+                is_writable: PodBool(0),
+            },
+            ExtraAccountMeta {
+                discriminator: 0,
+                address_config: accrual_config_pda,
+                is_signer: PodBool(0),
+                is_writable: PodBool(0),
+            },
+        ];
+        for index in [EXECUTE_SOURCE_INDEX, EXECUTE_DESTINATION_INDEX] {
+            account_metas.push(
+                ExtraAccountMeta::new_external_pda_with_seeds(
+                    program_index,
+                    &[
+                        ExtraAccountMetaSeed::Literal {
+                            bytes: seeds::COUPON_CLAIM_ACCOUNT.to_vec(),
+                        },
+                        ExtraAccountMetaSeed::AccountKey { index },
+                    ],
+                    false,
+                    false,
+                )
+                .map_err(|_| ProgramError::InvalidArgument)?,
+            );
+        }
+        Ok(account_metas)
+    }
+
+    /// Extra account metas resolving the position limit and the destination investor holdings
+    ///
+    /// `tail_start` is the Execute instruction index of the first returned account:
+    /// security token program, PositionLimit, destination IdentityWallet, Identity and
+    /// every wallet slot of the Identity.
+    fn position_limit_account_metas(
+        program_id: &Pubkey,
+        tail_start: usize,
+    ) -> Result<Vec<ExtraAccountMeta>, ProgramError> {
+        let program_index = u8::try_from(tail_start).map_err(|_| ProgramError::InvalidArgument)?;
+        let identity_wallet_index = program_index + 2;
+        let identity_index = program_index + 3;
+
+        let mut account_metas = vec![
+            ExtraAccountMeta {
+                discriminator: 0,
+                address_config: *program_id,
+                is_signer: PodBool(0),
+                is_writable: PodBool(0),
+            },
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                program_index,
+                &[
+                    ExtraAccountMetaSeed::Literal {
+                        bytes: seeds::POSITION_LIMIT_ACCOUNT.to_vec(),
+                    },
+                    ExtraAccountMetaSeed::AccountKey {
+                        index: EXECUTE_MINT_INDEX,
+                    },
+                ],
+                false,
+                false,
+            )
+            .map_err(|_| ProgramError::InvalidArgument)?,
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                program_index,
+                &[
+                    ExtraAccountMetaSeed::Literal {
+                        bytes: seeds::IDENTITY_WALLET_ACCOUNT.to_vec(),
+                    },
+                    ExtraAccountMetaSeed::AccountKey {
+                        index: EXECUTE_DESTINATION_INDEX,
+                    },
+                ],
+                false,
+                false,
+            )
+            .map_err(|_| ProgramError::InvalidArgument)?,
+            // IdentityWallet data: [discriminator, identity, bump]
+            ExtraAccountMeta::new_with_pubkey_data(
+                &PubkeyData::AccountData {
+                    account_index: identity_wallet_index,
+                    data_index: 1,
+                },
+                false,
+                false,
+            )
+            .map_err(|_| ProgramError::InvalidArgument)?,
+        ];
+
+        for slot in 0..MAX_IDENTITY_WALLETS {
+            let data_index = u8::try_from(Identity::WALLETS_OFFSET + slot * 32)
+                .map_err(|_| ProgramError::InvalidArgument)?;
+            account_metas.push(
+                ExtraAccountMeta::new_with_pubkey_data(
+                    &PubkeyData::AccountData {
+                        account_index: identity_index,
+                        data_index,
+                    },
+                    false,
+                    false,
+                )
+                .map_err(|_| ProgramError::InvalidArgument)?,
+            );
+        }
+        Ok(account_metas)
     }
 
-    fn initialize_transfer_hook_account_metas(
+    /// Transfer hook rules enabled in the extra account metas list.
+    ///
+    /// Lists written by this program version carry a rules marker. Lists written before it get
+    /// their rules inferred from the accounts once, the next sync writes the marker.
+    fn transfer_hook_rule_flags(
         program_id: &Pubkey,
-        payer: &AccountInfo,
         mint_info: &AccountInfo,
-        system_program_info: &AccountInfo,
-        transfer_hook_accounts: &[AccountInfo],
-        verification_config_pda: Pubkey,
-        program_addresses: &[Pubkey],
-    ) -> ProgramResult {
-        Self::sync_transfer_hook_account_metas(
-            program_id,
-            payer,
-            mint_info,
-            system_program_info,
-            transfer_hook_accounts,
-            verification_config_pda,
-            program_addresses,
-            true,
-        )
+        account_metas_pda_info: &AccountInfo,
+    ) -> Result<TransferHookRuleFlags, ProgramError> {
+        {
+            let data = account_metas_pda_info.try_borrow_data()?;
+            // TLV discriminator (8 bytes) + length (4 bytes) + count (4 bytes)
+            let metas = data.get(16..).unwrap_or_default();
+            let marker = metas
+                .chunks_exact(size_of::<ExtraAccountMeta>())
+                .filter(|meta| meta[0] == 0)
+                .find(|meta| meta[1..29] == TransferHookRuleFlags::MARKER_PREFIX);
+            if let Some(meta) = marker {
+                let address: [u8; 32] = meta[1..33]
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidAccountData)?;
+                return TransferHookRuleFlags::from_marker(&address)
+                    .ok_or(ProgramError::InvalidAccountData);
+            }
+        }
+        Self::legacy_transfer_hook_rule_flags(program_id, mint_info, account_metas_pda_info)
+    }
+
+    /// Transfer hook rules of an extra account metas list written before the rules marker
+    fn legacy_transfer_hook_rule_flags(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        account_metas_pda_info: &AccountInfo,
+    ) -> Result<TransferHookRuleFlags, ProgramError> {
+        let (transfer_acceptance_pda, _bump) =
+            utils::find_transfer_acceptance_pda(mint_info.key(), program_id);
+        let (transfer_approval_pda, _bump) =
+            utils::find_transfer_approval_pda(mint_info.key(), program_id);
+        let (holding_period_pda, _bump) =
+            utils::find_holding_period_pda(mint_info.key(), program_id);
+        let (permanent_delegate_config_pda, _bump) =
+            utils::find_permanent_delegate_config_pda(mint_info.key(), program_id);
+        let (accrual_config_pda, _bump) =
+            utils::find_accrual_config_pda(mint_info.key(), program_id);
+
+        let suspension =
+            Self::has_seeded_account_metas(account_metas_pda_info, seeds::SUSPENSION_ACCOUNT)?;
+        let allowlist =
+            Self::has_seeded_account_metas(account_metas_pda_info, seeds::ALLOWLIST_ENTRY_ACCOUNT)?;
+        let holding_period =
+            Self::has_literal_account_meta(account_metas_pda_info, &holding_period_pda)?;
+        let coupon_accrual =
+            Self::has_literal_account_meta(account_metas_pda_info, &accrual_config_pda)?;
+        // The suspension, allowlist, holding period and coupon accrual accounts start with the
+        // security token program too
+        let position_limit = Self::count_literal_account_metas(account_metas_pda_info, program_id)?
+            > usize::from(suspension)
+                + usize::from(allowlist)
+                + usize::from(holding_period)
+                + usize::from(coupon_accrual);
+
+        Ok(TransferHookRuleFlags::default()
+            .with(TransferHookRuleFlags::ALLOWLIST, allowlist)
+            .with(TransferHookRuleFlags::HOLDING_PERIOD, holding_period)
+            .with(TransferHookRuleFlags::COUPON_ACCRUAL, coupon_accrual)
+            .with(TransferHookRuleFlags::POSITION_LIMIT, position_limit)
+            .with(
+                TransferHookRuleFlags::TRANSFER_APPROVAL,
+                Self::has_literal_account_meta(account_metas_pda_info, &transfer_approval_pda)?,
+            )
+            .with(
+                TransferHookRuleFlags::TRANSFER_ACCEPTANCE,
+                Self::has_literal_account_meta(account_metas_pda_info, &transfer_acceptance_pda)?,
+            )
+            .with(
+                TransferHookRuleFlags::PERMANENT_DELEGATE_CONFIG,
+                Self::has_literal_account_meta(
+                    account_metas_pda_info,
+                    &permanent_delegate_config_pda,
+                )?,
+            ))
+    }
+
+    /// Whether the extra account metas list contains `address` as a literal account meta.
+    /// Legacy lists are recognized by their literals: the HoldingPeriod PDA marks the holding
+    /// period accounts, the AccrualConfig PDA the coupon claim accounts, the security token
+    /// program starts the position limit accounts, the TransferAcceptance and TransferApproval
+    /// PDAs mark their modes.
+    fn has_literal_account_meta(
+        account_metas_pda_info: &AccountInfo,
+        address: &Pubkey,
+    ) -> Result<bool, ProgramError> {
+        Ok(Self::count_literal_account_metas(account_metas_pda_info, address)? > 0)
+    }
+
+    /// Whether the extra account metas list resolves PDAs whose first seed is `seed`.
+    /// Legacy lists written before suspensions existed lack the Suspension PDAs,
+    /// AllowlistEntry PDAs mark the allowlist mode.
+    fn has_seeded_account_metas(
+        account_metas_pda_info: &AccountInfo,
+        seed: &[u8],
+    ) -> Result<bool, ProgramError> {
+        // Packed literal seed: [literal seed type (1), length, bytes]
+        let mut packed_seed = vec![1, seed.len() as u8];
+        packed_seed.extend_from_slice(seed);
+
+        let data = account_metas_pda_info.try_borrow_data()?;
+        // TLV discriminator (8 bytes) + length (4 bytes) + count (4 bytes)
+        let metas = data.get(16..).unwrap_or_default();
+        Ok(metas
+            .chunks_exact(size_of::<ExtraAccountMeta>())
+            // External PDA metas have discriminators from 128 on
+            .any(|meta| meta[0] >= 128 && meta[1..].starts_with(&packed_seed)))
+    }
+
+    /// Number of literal account metas of `address` in the extra account metas list
+    fn count_literal_account_metas(
+        account_metas_pda_info: &AccountInfo,
+        address: &Pubkey,
+    ) -> Result<usize, ProgramError> {
+        let data = account_metas_pda_info.try_borrow_data()?;
+        // TLV discriminator (8 bytes) + length (4 bytes) + count (4 bytes)
+        let metas = data.get(16..).unwrap_or_default();
+        Ok(metas
+            .chunks_exact(size_of::<ExtraAccountMeta>())
+            .filter(|meta| meta[0] == 0 && meta[1..33] == address[..])
+            .count())
     }
 
     /// Update verification configuration for an instruction
+    ///
+    /// Updates of a config with a timelock are staged in a PendingVerificationConfig instead,
+    /// applied by ApplyPendingConfig once the timelock has elapsed.
     /// # Arguments
     /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
     pub fn update_verification_config(
@@ -1084,11 +2571,14 @@ impl VerificationModule {
         accounts: &[AccountInfo],
         args: &crate::instructions::UpdateVerificationConfigArgs,
     ) -> ProgramResult {
-        let [payer, mint_account, config_account, system_program_info, transfer_hook_accounts @ ..] =
+        let [payer, mint_account, config_account, system_program_info, optional_accounts @ ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
+        // The pending config account follows the transfer hook accounts
+        let transfer_hook_accounts = optional_accounts.get(..3).unwrap_or(optional_accounts);
+        let pending_config_account = optional_accounts.get(3);
 
         verify_mint_keys_match(verified_mint_info, &mint_account)?;
 
@@ -1118,8 +2608,19 @@ impl VerificationModule {
             return Err(ProgramError::InvalidArgument);
         }
 
-        // Update cpi_mode
+        let current_timelock = existing_config.timelock_seconds;
+
+        // Update cpi_mode, threshold, introspection constraints and timelock
         existing_config.cpi_mode = args.cpi_mode;
+        existing_config.threshold = args.threshold;
+        existing_config.lookback_window = args.lookback_window;
+        existing_config.require_top_level = args.require_top_level;
+        existing_config.timelock_seconds = args.timelock_seconds;
+
+        // The template issuer could swap the programs of a linked config without delay
+        if existing_config.timelock_seconds != 0 && existing_config.config_template.is_some() {
+            return Err(SecurityTokenError::ConfigTemplateNotSupported.into());
+        }
 
         // Update verification programs starting at the specified offset
         let new_programs = args.program_addresses();
@@ -1137,6 +2638,19 @@ impl VerificationModule {
 
         existing_config.validate()?;
 
+        if current_timelock != 0 {
+            let pending_config_account =
+                pending_config_account.ok_or(ProgramError::NotEnoughAccountKeys)?;
+            return Self::stage_pending_config(
+                program_id,
+                payer,
+                mint_account,
+                pending_config_account,
+                &existing_config,
+                current_timelock,
+            );
+        }
+
         let new_size = existing_config.serialized_size();
         let current_size = config_account.data_len();
 
@@ -1165,11 +2679,13 @@ impl VerificationModule {
             Self::update_transfer_hook_account_metas(
                 program_id,
                 payer,
+                payer,
                 mint_account,
                 system_program_info,
                 transfer_hook_accounts,
                 *config_account.key(),
                 existing_config.verification_programs.as_slice(),
+                existing_config.threshold,
             )?;
         }
         Ok(())
@@ -1184,7 +2700,7 @@ impl VerificationModule {
         accounts: &[AccountInfo],
         args: &TrimVerificationConfigArgs,
     ) -> ProgramResult {
-        let [mint_account, config_account, recipient, system_program_info, transfer_hook_accounts @ ..] =
+        let [payer, mint_account, config_account, rent_recipient, system_program_info, transfer_hook_accounts @ ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -1193,9 +2709,11 @@ impl VerificationModule {
         verify_mint_keys_match(verified_mint_info, &mint_account)?;
 
         verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
         verify_owner(config_account, program_id)?;
         verify_owner(mint_account, &pinocchio_token_2022::ID)?;
-        verify_writable(recipient)?;
+        verify_rent_recipient(rent_recipient, config_account)?;
         verify_writable(config_account)?;
         verify_account_initialized(config_account)?;
 
@@ -1212,6 +2730,11 @@ impl VerificationModule {
             return Err(ProgramError::InvalidAccountData);
         }
 
+        // Trimming or closing would drop programs without the delay
+        if existing_config.timelock_seconds != 0 {
+            return Err(SecurityTokenError::VerificationConfigTimelocked.into());
+        }
+
         let current_program_count = existing_config.verification_programs.len();
         let new_size = args.size as usize;
 
@@ -1252,19 +2775,21 @@ impl VerificationModule {
         if discriminator == SecurityTokenInstruction::Transfer as u8 {
             Self::update_transfer_hook_account_metas(
                 program_id,
-                recipient,
+                payer,
+                rent_recipient,
                 mint_account,
                 system_program_info,
                 transfer_hook_accounts,
                 *config_account.key(),
                 new_program_list,
+                existing_config.threshold,
             )?;
         }
 
         if args.close {
             // Close the account completely
             *config_account.try_borrow_mut_lamports()? = 0;
-            *recipient.try_borrow_mut_lamports()? = recipient
+            *rent_recipient.try_borrow_mut_lamports()? = rent_recipient
                 .lamports()
                 .checked_add(recovered_rent)
                 .ok_or(ProgramError::InsufficientFunds)?;
@@ -1284,11 +2809,450 @@ impl VerificationModule {
                 .checked_sub(recovered_rent)
                 .ok_or(ProgramError::InsufficientFunds)?;
 
-            *recipient.try_borrow_mut_lamports()? = recipient
+            *rent_recipient.try_borrow_mut_lamports()? = rent_recipient
                 .lamports()
                 .checked_add(recovered_rent)
                 .ok_or(ProgramError::InsufficientFunds)?;
         }
         Ok(())
     }
+
+    /// Stage `config` in the pending config account, applicable once `timelock_seconds` elapsed
+    fn stage_pending_config(
+        program_id: &Pubkey,
+        payer: &AccountInfo,
+        mint_account: &AccountInfo,
+        pending_config_account: &AccountInfo,
+        config: &VerificationConfig,
+        timelock_seconds: u64,
+    ) -> ProgramResult {
+        verify_writable(pending_config_account)?;
+        // One staged update at a time, CancelPendingConfig clears the way for another one
+        verify_account_not_initialized(pending_config_account)?;
+
+        let (pending_config_pda, bump) = utils::find_pending_verification_config_pda(
+            mint_account.key(),
+            config.instruction_discriminator,
+            program_id,
+        );
+        verify_pda_keys_match(pending_config_account.key(), &pending_config_pda)?;
+
+        let activates_at = i64::try_from(timelock_seconds)
+            .ok()
+            .and_then(|timelock| Clock::get().ok()?.unix_timestamp.checked_add(timelock))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        let pending_config =
+            PendingVerificationConfig::new(*mint_account.key(), bump, activates_at, config);
+        let discriminator_seed = pending_config.instruction_discriminator_seed();
+        let bump_seed = pending_config.bump_seed();
+        let seeds = pending_config.seeds(&discriminator_seed, &bump_seed);
+        pending_config.init(payer, pending_config_account, &seeds)?;
+        pending_config.write_data(pending_config_account)
+    }
+
+    /// Load the pending config of the verification config for `instruction_discriminator`
+    fn load_pending_config(
+        program_id: &Pubkey,
+        mint_account: &AccountInfo,
+        pending_config_account: &AccountInfo,
+        instruction_discriminator: u8,
+    ) -> Result<PendingVerificationConfig, ProgramError> {
+        verify_owner(pending_config_account, program_id)?;
+        verify_writable(pending_config_account)?;
+        verify_account_initialized(pending_config_account)?;
+
+        let pending_config = PendingVerificationConfig::from_account_info(pending_config_account)?;
+        verify_pda_keys_match(pending_config_account.key(), &pending_config.derive_pda()?)?;
+        if pending_config.mint != *mint_account.key()
+            || pending_config.instruction_discriminator != instruction_discriminator
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(pending_config)
+    }
+
+    /// Apply the update staged for a timelocked verification config once the timelock has elapsed,
+    /// closing the pending config account
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn apply_pending_config(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        instruction_discriminator: u8,
+    ) -> ProgramResult {
+        let [payer, mint_account, config_account, pending_config_account, rent_recipient, system_program_info, transfer_hook_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_account)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_owner(mint_account, &pinocchio_token_2022::ID)?;
+        verify_owner(config_account, program_id)?;
+        verify_writable(config_account)?;
+        verify_account_initialized(config_account)?;
+        verify_rent_recipient(rent_recipient, pending_config_account)?;
+
+        let mut config = VerificationConfig::from_account_info(config_account)?;
+        verify_pda_keys_match(
+            config_account.key(),
+            &config.derive_pda(mint_account.key())?,
+        )?;
+        if config.instruction_discriminator != instruction_discriminator {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let pending_config = Self::load_pending_config(
+            program_id,
+            mint_account,
+            pending_config_account,
+            instruction_discriminator,
+        )?;
+        if Clock::get()?.unix_timestamp < pending_config.activates_at {
+            return Err(SecurityTokenError::PendingConfigNotActive.into());
+        }
+
+        pending_config.apply_to(&mut config);
+        config.validate()?;
+
+        VerificationConfig::resize_account_and_rent(
+            config_account,
+            config.serialized_size(),
+            payer,
+        )?;
+        config.write_data(config_account)?;
+
+        if instruction_discriminator == SecurityTokenInstruction::Transfer as u8 {
+            Self::update_transfer_hook_account_metas(
+                program_id,
+                payer,
+                rent_recipient,
+                mint_account,
+                system_program_info,
+                transfer_hook_accounts,
+                *config_account.key(),
+                config.verification_programs.as_slice(),
+                config.threshold,
+            )?;
+        }
+
+        PendingVerificationConfig::close(pending_config_account, rent_recipient)
+    }
+
+    /// Discard the update staged for a timelocked verification config, the config stays unchanged
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn cancel_pending_config(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        instruction_discriminator: u8,
+    ) -> ProgramResult {
+        let [mint_account, pending_config_account, rent_recipient] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_account)?;
+        verify_rent_recipient(rent_recipient, pending_config_account)?;
+
+        Self::load_pending_config(
+            program_id,
+            mint_account,
+            pending_config_account,
+            instruction_discriminator,
+        )?;
+        PendingVerificationConfig::close(pending_config_account, rent_recipient)
+    }
+
+    /// Name the config admin of the mint, an external key that initializes, updates and trims its
+    /// verification configs directly, apart from the keys signing other operations. The default
+    /// pubkey removes the admin and returns the rent to the payer.
+    ///
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn set_config_admin(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        admin: Pubkey,
+    ) -> ProgramResult {
+        let [payer, mint_info, config_admin_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_info)?;
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_writable(config_admin_account)?;
+        verify_system_program(system_program_info)?;
+
+        let (config_admin_pda, bump) = utils::find_config_admin_pda(mint_info.key(), program_id);
+        verify_pda_keys_match(config_admin_account.key(), &config_admin_pda)?;
+
+        if admin == Pubkey::default() {
+            verify_owner(config_admin_account, program_id)?;
+            verify_account_initialized(config_admin_account)?;
+            return ConfigAdmin::close(config_admin_account, payer);
+        }
+
+        let config_admin = ConfigAdmin::new(*mint_info.key(), admin, bump);
+        if config_admin_account.data_is_empty() {
+            let bump_seed = &config_admin.bump_seed();
+            let seeds = config_admin.seeds(bump_seed);
+            config_admin.init(payer, config_admin_account, &seeds)?;
+        } else {
+            verify_owner(config_admin_account, program_id)?;
+        }
+        config_admin.write_data(config_admin_account)
+    }
+
+    /// Name the emergency authority in the ProgramConfig, created if empty.
+    /// Signed by the upgrade authority of the program, a new authority keeps the halt flag.
+    pub fn set_emergency_authority(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        emergency_authority: Pubkey,
+    ) -> ProgramResult {
+        let [payer, upgrade_authority, program_data, program_config_account, system_program_info] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_signer(upgrade_authority)?;
+        verify_writable(program_config_account)?;
+        verify_system_program(system_program_info)?;
+        verify_owner(program_data, &BPF_LOADER_UPGRADEABLE_ID)?;
+
+        let (program_data_address, _) = utils::find_program_data_address(program_id);
+        verify_pda_keys_match(program_data.key(), &program_data_address)?;
+        if Self::program_upgrade_authority(program_data)? != Some(*upgrade_authority.key()) {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let (program_config_pda, bump) = utils::find_program_config_pda(program_id);
+        verify_pda_keys_match(program_config_account.key(), &program_config_pda)?;
+
+        let mut program_config = ProgramConfig::new(emergency_authority, bump);
+        if program_config_account.data_is_empty() {
+            let bump_seed = &program_config.bump_seed();
+            let seeds = program_config.seeds(bump_seed);
+            program_config.init(payer, program_config_account, &seeds)?;
+        } else {
+            verify_owner(program_config_account, program_id)?;
+            program_config.halted =
+                ProgramConfig::from_account_info(program_config_account)?.halted;
+        }
+        program_config.write_data(program_config_account)
+    }
+
+    /// Upgrade authority recorded in the ProgramData account, None once the program is immutable
+    fn program_upgrade_authority(
+        program_data: &AccountInfo,
+    ) -> Result<Option<Pubkey>, ProgramError> {
+        let data = program_data.try_borrow_data()?;
+        // State tag (u32, 3 = ProgramData) + slot (u64) + optional upgrade authority
+        if data.len() < PROGRAM_DATA_METADATA_LEN || data[..4] != 3u32.to_le_bytes() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if data[12] == 0 {
+            return Ok(None);
+        }
+
+        let upgrade_authority: Pubkey = data[13..PROGRAM_DATA_METADATA_LEN]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        Ok(Some(upgrade_authority))
+    }
+
+    /// Halt or resume the state-mutating instructions of all mints, signed by the emergency authority
+    pub fn set_program_halt(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        halted: bool,
+    ) -> ProgramResult {
+        let [emergency_authority, program_config_account] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_signer(emergency_authority)?;
+        verify_writable(program_config_account)?;
+        verify_owner(program_config_account, program_id)?;
+
+        let mut program_config = ProgramConfig::from_account_info(program_config_account)?;
+        verify_pda_keys_match(program_config_account.key(), &program_config.derive_pda()?)?;
+        if program_config.emergency_authority != *emergency_authority.key() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        program_config.halted = halted;
+        program_config.write_data(program_config_account)
+    }
+
+    /// Create a ConfigTemplate holding verification programs shared across an issuer's mints
+    pub fn create_config_template(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        template_id: u64,
+        program_addresses: &[Pubkey],
+    ) -> ProgramResult {
+        let [issuer, config_template_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_system_program(system_program_info)?;
+        verify_signer(issuer)?;
+        verify_writable(issuer)?;
+        verify_writable(config_template_account)?;
+        verify_account_not_initialized(config_template_account)?;
+
+        let (expected_template_pda, bump) =
+            utils::find_config_template_pda(issuer.key(), template_id, program_id);
+        verify_pda_keys_match(config_template_account.key(), &expected_template_pda)?;
+
+        let template = ConfigTemplate::new(*issuer.key(), template_id, program_addresses, bump)?;
+        let template_id_seed = template.template_id_seed();
+        let bump_seed = template.bump_seed();
+        let seeds = template.seeds(&template_id_seed, &bump_seed);
+        template.init(issuer, config_template_account, &seeds)?;
+        template.write_data(config_template_account)?;
+
+        Ok(())
+    }
+
+    /// Replace the programs of a ConfigTemplate, taking effect for every linked config at once
+    pub fn update_config_template(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        program_addresses: &[Pubkey],
+    ) -> ProgramResult {
+        let [issuer, config_template_account, system_program_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_system_program(system_program_info)?;
+        verify_signer(issuer)?;
+        verify_writable(issuer)?;
+        verify_writable(config_template_account)?;
+        verify_owner(config_template_account, program_id)?;
+
+        let current = ConfigTemplate::from_account_info(config_template_account)?;
+        if current.issuer != *issuer.key() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        verify_pda_keys_match(config_template_account.key(), &current.derive_pda()?)?;
+
+        let template = ConfigTemplate::new(
+            current.issuer,
+            current.template_id,
+            program_addresses,
+            current.bump,
+        )?;
+        ConfigTemplate::resize_account_and_rent(
+            config_template_account,
+            template.space() as usize,
+            issuer,
+        )?;
+        template.write_data(config_template_account)?;
+
+        Ok(())
+    }
+
+    /// Close a ConfigTemplate, configs still linked to it fail verification until unlinked
+    pub fn close_config_template(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let [issuer, config_template_account, rent_recipient] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_signer(issuer)?;
+        verify_rent_recipient(rent_recipient, config_template_account)?;
+        verify_writable(config_template_account)?;
+        verify_owner(config_template_account, program_id)?;
+        verify_account_initialized(config_template_account)?;
+
+        let template = ConfigTemplate::from_account_info(config_template_account)?;
+        if template.issuer != *issuer.key() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        verify_pda_keys_match(config_template_account.key(), &template.derive_pda()?)?;
+        ConfigTemplate::close(config_template_account, rent_recipient)?;
+        Ok(())
+    }
+
+    /// Link a verification config to a ConfigTemplate, or unlink it when the template is omitted
+    ///
+    /// Linking needs the template issuer as payer. Unlinking needs the config to have its own
+    /// programs. The Transfer config is read by the transfer hook and can't be linked.
+    /// # Arguments
+    /// * `verified_mint_info` - Mint account authorized by verification in processor (prevents mint substitution attacks)
+    pub fn set_config_template(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        instruction_discriminator: u8,
+    ) -> ProgramResult {
+        let [payer, mint_account, config_account, config_template_account, system_program_info] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        verify_mint_keys_match(verified_mint_info, &mint_account)?;
+
+        verify_system_program(system_program_info)?;
+        verify_signer(payer)?;
+        verify_writable(payer)?;
+        verify_owner(mint_account, &pinocchio_token_2022::ID)?;
+        verify_owner(config_account, program_id)?;
+        verify_writable(config_account)?;
+        verify_account_initialized(config_account)?;
+
+        let mut config = VerificationConfig::from_account_info(config_account)?;
+        verify_pda_keys_match(
+            config_account.key(),
+            &config.derive_pda(mint_account.key())?,
+        )?;
+        if config.instruction_discriminator != instruction_discriminator {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // Linking or unlinking swaps the programs without the delay
+        if config.timelock_seconds != 0 {
+            return Err(SecurityTokenError::VerificationConfigTimelocked.into());
+        }
+
+        // Omitted optional accounts are filled with the program id by clients
+        config.config_template = if config_template_account.key() == program_id {
+            None
+        } else {
+            if instruction_discriminator == SecurityTokenInstruction::Transfer as u8 {
+                return Err(SecurityTokenError::ConfigTemplateNotSupported.into());
+            }
+            verify_owner(config_template_account, program_id)?;
+            let template = ConfigTemplate::from_account_info(config_template_account)?;
+            verify_pda_keys_match(config_template_account.key(), &template.derive_pda()?)?;
+            if template.issuer != *payer.key() {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            Some(*config_template_account.key())
+        };
+        config.validate()?;
+
+        VerificationConfig::resize_account_and_rent(
+            config_account,
+            config.serialized_size(),
+            payer,
+        )?;
+        config.write_data(config_account)?;
+
+        Ok(())
+    }
 }
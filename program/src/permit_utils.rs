@@ -0,0 +1,234 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+    sysvars::instructions::Instructions,
+    ProgramResult,
+};
+
+use crate::{
+    constants::ED25519_PROGRAM_ID, error::SecurityTokenError, modules::verify_instructions_sysvar,
+};
+
+/// Domain prefix of claim permits, so a signed permit cannot be reused as another message
+pub const CLAIM_PERMIT_DOMAIN: &[u8] = b"claim_permit";
+/// Domain prefix of transfer permits
+pub const TRANSFER_PERMIT_DOMAIN: &[u8] = b"transfer_permit";
+
+/// Ed25519 program instruction layout: num_signatures (u8) + padding (u8), followed by
+/// one offsets entry per signature
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+/// Instruction index of offsets pointing into the Ed25519 instruction itself
+const CURRENT_INSTRUCTION_INDEX: u16 = u16::MAX;
+
+/// Message signed by the holder wallet to let `custodian` claim distribution `action_id`
+/// for `eligible_token_account`
+pub fn claim_permit_message(
+    mint: &Pubkey,
+    action_id: u64,
+    eligible_token_account: &Pubkey,
+    custodian: &Pubkey,
+) -> Vec<u8> {
+    // Capacity: domain + mint (32 bytes) + action_id (8 bytes) + eligible_token_account (32 bytes)
+    // + custodian (32 bytes)
+    let mut message = Vec::with_capacity(CLAIM_PERMIT_DOMAIN.len() + PUBKEY_BYTES * 3 + 8);
+    message.extend_from_slice(CLAIM_PERMIT_DOMAIN);
+    message.extend_from_slice(mint.as_ref());
+    message.extend_from_slice(action_id.to_le_bytes().as_ref());
+    message.extend_from_slice(eligible_token_account.as_ref());
+    message.extend_from_slice(custodian.as_ref());
+    message
+}
+
+/// Message signed by the owner of `from_token_account` to let anyone transfer `amount`
+/// to `to_token_account` with TransferWithPermit until `expiry`
+pub fn transfer_permit_message(
+    mint: &Pubkey,
+    from_token_account: &Pubkey,
+    to_token_account: &Pubkey,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Vec<u8> {
+    // Capacity: domain + mint, from and to (96 bytes) + amount, nonce and expiry (24 bytes)
+    let mut message = Vec::with_capacity(TRANSFER_PERMIT_DOMAIN.len() + PUBKEY_BYTES * 3 + 24);
+    message.extend_from_slice(TRANSFER_PERMIT_DOMAIN);
+    message.extend_from_slice(mint.as_ref());
+    message.extend_from_slice(from_token_account.as_ref());
+    message.extend_from_slice(to_token_account.as_ref());
+    message.extend_from_slice(amount.to_le_bytes().as_ref());
+    message.extend_from_slice(nonce.to_le_bytes().as_ref());
+    message.extend_from_slice(expiry.to_le_bytes().as_ref());
+    message
+}
+
+/// Checks whether Ed25519 program instruction data verifies a signature of `signer` over
+/// `message`. Only offsets pointing into the instruction itself are accepted.
+pub fn ed25519_instruction_verifies(data: &[u8], signer: &Pubkey, message: &[u8]) -> bool {
+    let Some(&num_signatures) = data.first() else {
+        return false;
+    };
+
+    (0..num_signatures as usize).any(|index| {
+        let start = SIGNATURE_OFFSETS_START + index * SIGNATURE_OFFSETS_LEN;
+        let Some(offsets) = data.get(start..start + SIGNATURE_OFFSETS_LEN) else {
+            return false;
+        };
+        let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+        let signature_instruction_index = read_u16(2);
+        let public_key_offset = read_u16(4) as usize;
+        let public_key_instruction_index = read_u16(6);
+        let message_data_offset = read_u16(8) as usize;
+        let message_data_size = read_u16(10) as usize;
+        let message_instruction_index = read_u16(12);
+
+        if signature_instruction_index != CURRENT_INSTRUCTION_INDEX
+            || public_key_instruction_index != CURRENT_INSTRUCTION_INDEX
+            || message_instruction_index != CURRENT_INSTRUCTION_INDEX
+        {
+            return false;
+        }
+
+        data.get(public_key_offset..public_key_offset + PUBKEY_BYTES) == Some(signer.as_ref())
+            && data.get(message_data_offset..message_data_offset + message_data_size)
+                == Some(message)
+    })
+}
+
+/// Verify an instruction preceding the current one is an Ed25519 program instruction
+/// verifying a signature of `signer` over `message`.
+/// The signature itself is verified by the Ed25519 program, the transaction fails otherwise.
+pub fn verify_ed25519_permit(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> ProgramResult {
+    verify_instructions_sysvar(instructions_sysvar)?;
+    let instructions = Instructions::try_from(instructions_sysvar)?;
+    let current_index = instructions.load_current_index() as usize;
+
+    for index in 0..current_index {
+        let instruction = instructions.load_instruction_at(index)?;
+        if instruction.get_program_id() == &ED25519_PROGRAM_ID
+            && ed25519_instruction_verifies(instruction.get_instruction_data(), signer, message)
+        {
+            return Ok(());
+        }
+    }
+
+    Err(SecurityTokenError::InvalidPermit.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::random_pubkey;
+    use rstest::rstest;
+
+    /// Ed25519 program instruction data with a single signature, laid out like the
+    /// instructions built by the Solana SDK
+    fn ed25519_instruction_data(
+        signer: &Pubkey,
+        message: &[u8],
+        instruction_index: u16,
+    ) -> Vec<u8> {
+        let public_key_offset = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN;
+        let signature_offset = public_key_offset + PUBKEY_BYTES;
+        let message_data_offset = signature_offset + 64;
+
+        let mut data = vec![1u8, 0];
+        for value in [
+            signature_offset as u16,
+            instruction_index,
+            public_key_offset as u16,
+            instruction_index,
+            message_data_offset as u16,
+            message.len() as u16,
+            instruction_index,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0u8; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn test_claim_permit_message_layout() {
+        let mint = random_pubkey();
+        let eligible_token_account = random_pubkey();
+        let custodian = random_pubkey();
+        let message = claim_permit_message(&mint, 7, &eligible_token_account, &custodian);
+
+        assert_eq!(
+            message.len(),
+            CLAIM_PERMIT_DOMAIN.len() + PUBKEY_BYTES * 3 + 8
+        );
+        assert!(message.starts_with(CLAIM_PERMIT_DOMAIN));
+        assert!(message.ends_with(custodian.as_ref()));
+    }
+
+    #[test]
+    fn test_transfer_permit_message_layout() {
+        let message = transfer_permit_message(
+            &random_pubkey(),
+            &random_pubkey(),
+            &random_pubkey(),
+            5,
+            1,
+            9,
+        );
+
+        assert_eq!(
+            message.len(),
+            TRANSFER_PERMIT_DOMAIN.len() + PUBKEY_BYTES * 3 + 24
+        );
+        assert!(message.starts_with(TRANSFER_PERMIT_DOMAIN));
+        assert!(message.ends_with(&9i64.to_le_bytes()));
+    }
+
+    #[rstest]
+    #[case(false, false, CURRENT_INSTRUCTION_INDEX, true)]
+    #[case(true, false, CURRENT_INSTRUCTION_INDEX, false)]
+    #[case(false, true, CURRENT_INSTRUCTION_INDEX, false)]
+    #[case(false, false, 0, false)]
+    fn test_ed25519_instruction_verifies(
+        #[case] other_signer: bool,
+        #[case] other_message: bool,
+        #[case] instruction_index: u16,
+        #[case] expected: bool,
+    ) {
+        let signer = random_pubkey();
+        let message = b"permit".to_vec();
+        let signed_by = if other_signer {
+            random_pubkey()
+        } else {
+            signer
+        };
+        let signed_message = if other_message {
+            b"other".to_vec()
+        } else {
+            message.clone()
+        };
+        let data = ed25519_instruction_data(&signed_by, &signed_message, instruction_index);
+
+        assert_eq!(
+            ed25519_instruction_verifies(&data, &signer, &message),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_ed25519_instruction_verifies_rejects_truncated_data() {
+        let signer = random_pubkey();
+        let data = ed25519_instruction_data(&signer, b"permit", CURRENT_INSTRUCTION_INDEX);
+
+        assert!(!ed25519_instruction_verifies(&[], &signer, b"permit"));
+        assert!(!ed25519_instruction_verifies(
+            &data[..data.len() - 1],
+            &signer,
+            b"permit"
+        ));
+    }
+}
@@ -1,19 +1,29 @@
 use crate::{
+    constants::PROGRAM_CONFIG_ADDRESS,
+    error::SecurityTokenError,
     instruction::SecurityTokenInstruction,
     instructions::{
         close_rate_account::CloseRateArgs, convert::ConvertArgs,
         create_proof_account::CreateProofArgs, split::SplitArgs,
         update_proof_account::UpdateProofArgs, update_rate_account::UpdateRateArgs,
         ClaimDistributionArgs, CloseActionReceiptArgs, CloseClaimReceiptArgs,
-        CreateDistributionEscrowArgs, CreateRateArgs, InitializeMintArgs,
-        InitializeVerificationConfigArgs, TrimVerificationConfigArgs, UpdateMetadataArgs,
+        CreateAccrualConfigArgs, CreateAuctionArgs, CreateDistributionEscrowArgs,
+        CreateNavOracleArgs, CreateRateArgs, InitializeMintArgs, InitializeVerificationConfigArgs,
+        RemoveRateOracleArgs, SetRateOracleArgs, TrimVerificationConfigArgs, UpdateMetadataArgs,
         UpdateVerificationConfigArgs, VerifyArgs,
     },
+    merkle_tree_utils::MerkleTreeRoot,
     modules::{verification::VerificationModule, OperationsModule, VerificationProfile},
+    state::{ProgramConfig, DOCUMENT_HASH_LEN},
 };
 use pinocchio::{
-    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    program_error::ProgramError,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+    ProgramResult,
 };
+use security_token_core::SecurityTokenInstructionDiscriminators;
 
 /// Program state handler
 pub struct Processor;
@@ -28,22 +38,207 @@ impl Processor {
         use VerificationProfile::*;
 
         match instruction {
-            InitializeMint | Verify => None,
+            InitializeMint
+            | Verify
+            | UnlockLot
+            | ThawExpired
+            | ReleaseVested
+            | CreateConfigTemplate
+            | UpdateConfigTemplate
+            | CloseConfigTemplate
+            | CanTransfer
+            | GetHolderStatus
+            | GetEffectiveRate
+            | GetDistributionStatus
+            | AttestCollateral
+            | PublishReserveAttestation
+            | CreateSubAccountLedger
+            | CreditSubAccount
+            | DebitSubAccount
+            | CloseSubAccountLedger
+            | ClaimSuccession
+            | AcceptMintCreatorTransfer
+            | UpdateTranslation
+            | AuditMint
+            | SetEmergencyAuthority
+            | SetProgramHalt => None,
             CreateDistributionEscrow
             | CloseActionReceiptAccount
             | CloseClaimReceiptAccount
             | CreateRateAccount
             | UpdateRateAccount
             | CloseRateAccount
-            | InitializeVerificationConfig
+            | SetRateOracle
+            | RemoveRateOracle
+            | UpdateMetadata
+            | CreateWrapVault
+            | CreateAgentAccount
+            | UpdateAgentAccount
+            | CloseAgentAccount
+            | CreateIdentityAccount
+            | AddIdentityWallet
+            | RemoveIdentityWallet
+            | CloseIdentityAccount
+            | CreatePositionLimit
+            | UpdatePositionLimit
+            | ClosePositionLimit
+            | CreateTransferAcceptance
+            | CloseTransferAcceptance
+            | CreateTransferApproval
+            | UpdateTransferApproval
+            | CloseTransferApproval
+            | ApproveTransfer
+            | RejectTransfer
+            | CreateAccrualConfig
+            | CreateMaturity
+            | WithdrawUnclaimedRedemption
+            | CreateNavOracle
+            | OpenSubscription
+            | Allocate
+            | CreateAuction
+            | ClearAuction
+            | CreateHoldingPeriod
+            | CloseHoldingPeriod
+            | CreateVesting
+            | CreateWithholdingRate
+            | UpdateWithholdingRate
+            | CreateDistributionPayout
+            | CreateClaimDeadline
+            | ReclaimUnclaimed
+            | AmendDistributionRoot
+            | CreateSessionKey
+            | CloseSessionKey
+            | CreateMintMigration
+            | MigrateBalances
+            | CreateMetadataSchema
+            | UpdateMetadataSchema
+            | CloseMetadataSchema
+            | FreezeMetadata
+            | SetConfigTemplate
+            | CreateSuccessionPolicy
+            | Heartbeat
+            | CloseSuccessionPolicy
+            | CreateMetadataTranslator
+            | CloseMetadataTranslator
+            | UpdateScaledUiAmountMultiplier
+            | RemoveMetadataField
+            | AnchorDocument
+            | UpdateDocument
+            | RemoveDocument
+            | ProposeMintCreatorTransfer
+            | SetMintAuthorityMultisig
+            | RotatePermanentDelegate
+            | DisablePermanentDelegate
+            | SetPauseDelegate
+            | CloseMintAuthority
+            | SetConfigAdmin
+            | AddToAllowlist
+            | RemoveFromAllowlist
+            | SetAllowlistMode => VerificationProgramsOrMintAuthority,
+            Freeze | Thaw | Suspend | Unsuspend => VerificationProgramsOrAgent,
+            Pause | Resume => VerificationProgramsOrPauseDelegate,
+            InitializeVerificationConfig
             | UpdateVerificationConfig
             | TrimVerificationConfig
-            | UpdateMetadata => VerificationProgramsOrMintAuthority,
-            Burn | Mint | Pause | Resume | Freeze | Thaw | Transfer | Split | Convert
-            | CreateProofAccount | UpdateProofAccount | ClaimDistribution => VerificationPrograms,
+            | ApplyPendingConfig
+            | CancelPendingConfig => VerificationProgramsOrMintAuthorityOrConfigAdmin,
+            Burn
+            | Mint
+            | Transfer
+            | Split
+            | Convert
+            | CreateProofAccount
+            | UpdateProofAccount
+            | ClaimDistribution
+            | WrapToken
+            | UnwrapToken
+            | OfferTransfer
+            | AcceptTransfer
+            | CancelTransfer
+            | RequestTransfer
+            | ClaimCoupon
+            | RedeemAtMaturity
+            | UpdateMultiplierFromOracle
+            | Commit
+            | Settle
+            | PlaceBid
+            | SettleBid
+            | TransferWithPermit
+            | RecoverTokens
+            | SetTransferFee
+            | WithdrawWithheldTokensFromAccounts
+            | HarvestWithheldTokensToMint
+            | UpdateDefaultAccountState => VerificationPrograms,
         }
     }
 
+    /// Whether the issuer can delegate the instruction to a session key.
+    /// Only instructions the mint authority can execute qualify, except those changing who is
+    /// authorized on the mint, the irreversible FreezeMetadata and those moving amounts read
+    /// from accounts instead of arguments, which the session key `max_amount` can't cap.
+    fn session_key_delegable(instruction: &SecurityTokenInstruction) -> bool {
+        use SecurityTokenInstruction::*;
+
+        matches!(
+            Self::instruction_verification_profile(instruction),
+            VerificationProfile::VerificationProgramsOrMintAuthority
+        ) && !matches!(
+            instruction,
+            InitializeVerificationConfig
+                | UpdateVerificationConfig
+                | TrimVerificationConfig
+                | SetConfigTemplate
+                | CreateAgentAccount
+                | UpdateAgentAccount
+                | CloseAgentAccount
+                | CreateSessionKey
+                | CloseSessionKey
+                | FreezeMetadata
+                | CreateSuccessionPolicy
+                | Heartbeat
+                | CloseSuccessionPolicy
+                | CreateMetadataTranslator
+                | CloseMetadataTranslator
+                | ProposeMintCreatorTransfer
+                | SetMintAuthorityMultisig
+                | RotatePermanentDelegate
+                | DisablePermanentDelegate
+                | SetPauseDelegate
+                | CloseMintAuthority
+                | SetConfigAdmin
+                | WithdrawUnclaimedRedemption
+                | ReclaimUnclaimed
+                | MigrateBalances
+        )
+    }
+
+    /// Whether the emergency authority can halt the instruction
+    fn haltable(instruction: &SecurityTokenInstruction) -> bool {
+        SecurityTokenInstructionDiscriminators::from_u8(instruction.discriminant())
+            .is_some_and(SecurityTokenInstructionDiscriminators::is_haltable)
+    }
+
+    /// Checks the ProgramConfig PDA passed after all other accounts and strips it.
+    /// Fails with ProgramConfigRequired if the last account is not the PDA and with ProgramHalted
+    /// while the emergency authority halts the program. Until SetEmergencyAuthority creates the
+    /// ProgramConfig, the PDA is empty and the program is not halted.
+    fn check_program_halt(accounts: &[AccountInfo]) -> Result<&[AccountInfo], ProgramError> {
+        let Some((program_config_account, remaining_accounts)) = accounts.split_last() else {
+            return Err(SecurityTokenError::ProgramConfigRequired.into());
+        };
+        if program_config_account.key() != &PROGRAM_CONFIG_ADDRESS {
+            return Err(SecurityTokenError::ProgramConfigRequired.into());
+        }
+
+        if program_config_account.data_is_empty() {
+            return Ok(remaining_accounts);
+        }
+        if ProgramConfig::from_account_info(program_config_account)?.halted {
+            return Err(SecurityTokenError::ProgramHalted.into());
+        }
+        Ok(remaining_accounts)
+    }
+
     /// Runs the verification process for the given instruction
     /// Explicit cuts the verification overhead if needed
     /// Returns mint AccountInfo and instruction accounts
@@ -71,9 +266,40 @@ impl Processor {
                     accounts,
                     ix_discriminator,
                     instruction_data,
+                    true,
+                )?;
+                Ok((mint_info, cleaned_accounts))
+            }
+            VerificationProfile::VerificationProgramsOrAgent => {
+                let (mint_info, cleaned_accounts) = VerificationModule::verify_by_strategy(
+                    program_id,
+                    accounts,
+                    ix_discriminator,
+                    instruction_data,
+                    false,
                 )?;
                 Ok((mint_info, cleaned_accounts))
             }
+            VerificationProfile::VerificationProgramsOrPauseDelegate => {
+                let (mint_info, cleaned_accounts) =
+                    VerificationModule::verify_by_programs_or_pause_delegate(
+                        program_id,
+                        accounts,
+                        ix_discriminator,
+                        instruction_data,
+                    )?;
+                Ok((mint_info, cleaned_accounts))
+            }
+            VerificationProfile::VerificationProgramsOrMintAuthorityOrConfigAdmin => {
+                let (mint_info, cleaned_accounts) =
+                    VerificationModule::verify_by_strategy_or_config_admin(
+                        program_id,
+                        accounts,
+                        ix_discriminator,
+                        instruction_data,
+                    )?;
+                Ok((mint_info, cleaned_accounts))
+            }
         }
     }
 
@@ -86,6 +312,12 @@ impl Processor {
         let (instruction, args_data) =
             SecurityTokenInstruction::parse_instruction(instruction_data)?;
 
+        let accounts = if Self::haltable(&instruction) {
+            Self::check_program_halt(accounts)?
+        } else {
+            accounts
+        };
+
         let verification_profile = Self::instruction_verification_profile(&instruction);
         let (verified_mint_info, instruction_accounts) = Self::verify(
             program_id,
@@ -150,9 +382,12 @@ impl Processor {
             SecurityTokenInstruction::Resume => {
                 Self::process_resume(program_id, verified_mint_info, instruction_accounts)
             }
-            SecurityTokenInstruction::Freeze => {
-                Self::process_freeze(program_id, verified_mint_info, instruction_accounts)
-            }
+            SecurityTokenInstruction::Freeze => Self::process_freeze(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
             SecurityTokenInstruction::Thaw => {
                 Self::process_thaw(program_id, verified_mint_info, instruction_accounts)
             }
@@ -234,359 +469,2478 @@ impl Processor {
                     args_data,
                 )
             }
-        }
-    }
-
-    fn process_update_metadata(
-        program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-        args_data: &[u8],
-    ) -> ProgramResult {
-        let args = UpdateMetadataArgs::try_from_bytes(args_data)
-            .map_err(|_| ProgramError::InvalidInstructionData)?;
-        VerificationModule::update_metadata(program_id, verified_mint_info, accounts, &args)
-    }
-
-    fn process_initialize_mint(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        args_data: &[u8],
-    ) -> ProgramResult {
-        let args = InitializeMintArgs::try_from_bytes(args_data)
-            .map_err(|_| ProgramError::InvalidInstructionData)?;
-        args.validate()?;
-        VerificationModule::initialize_mint(program_id, accounts, &args)
-    }
-
-    fn process_initialize_verification_config(
-        program_id: &Pubkey,
-        mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-        args_data: &[u8],
-    ) -> ProgramResult {
-        let args = InitializeVerificationConfigArgs::try_from_bytes(args_data)
-            .map_err(|_| ProgramError::InvalidInstructionData)?;
-        args.validate()?;
-
-        VerificationModule::initialize_verification_config(program_id, mint_info, accounts, &args)
-    }
-
-    fn process_update_verification_config(
-        program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-        args_data: &[u8],
-    ) -> ProgramResult {
-        let args = UpdateVerificationConfigArgs::try_from_bytes(args_data)
-            .map_err(|_| ProgramError::InvalidInstructionData)?;
-        args.validate()?;
-        VerificationModule::update_verification_config(
-            program_id,
-            verified_mint_info,
-            accounts,
-            &args,
-        )
-    }
-
-    /// Process TrimVerificationConfig instruction
-    fn process_trim_verification_config(
-        program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-        args_data: &[u8],
-    ) -> ProgramResult {
-        let args = TrimVerificationConfigArgs::try_from_bytes(args_data)
-            .map_err(|_| ProgramError::InvalidInstructionData)?;
-
-        VerificationModule::trim_verification_config(
-            program_id,
-            verified_mint_info,
-            accounts,
-            &args,
-        )
-    }
-
-    fn process_verify(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        args_data: &[u8],
-    ) -> ProgramResult {
-        let instruction_args = VerifyArgs::try_from_bytes(args_data)?;
-        VerificationModule::verify_instruction(program_id, accounts, &instruction_args)?;
-        Ok(())
-    }
-
-    fn process_mint(
-        program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-        args_data: &[u8],
-    ) -> ProgramResult {
-        // NOTE: Change to MintArgs structure?
-        let amount = args_data
-            .get(..8)
-            .and_then(|slice| slice.try_into().ok())
-            .map(u64::from_le_bytes)
-            .ok_or(ProgramError::InvalidInstructionData)?;
-        OperationsModule::execute_mint(program_id, verified_mint_info, accounts, amount)?;
-        Ok(())
-    }
-
-    fn process_burn(
-        program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-        args_data: &[u8],
-    ) -> ProgramResult {
-        // NOTE: Change to BurnArgs structure?
-        let amount = args_data
-            .get(..8)
-            .and_then(|slice| slice.try_into().ok())
-            .map(u64::from_le_bytes)
-            .ok_or(ProgramError::InvalidInstructionData)?;
-        OperationsModule::execute_burn(program_id, verified_mint_info, accounts, amount)?;
-        Ok(())
-    }
-
-    fn process_pause(
-        program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-    ) -> ProgramResult {
-        OperationsModule::execute_pause(program_id, verified_mint_info, accounts)?;
-        Ok(())
-    }
-
-    fn process_resume(
-        program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-    ) -> ProgramResult {
-        OperationsModule::execute_resume(program_id, verified_mint_info, accounts)?;
-        Ok(())
-    }
-
-    fn process_freeze(
-        program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-    ) -> ProgramResult {
-        OperationsModule::execute_freeze_account(program_id, verified_mint_info, accounts)?;
-        Ok(())
-    }
-
-    fn process_thaw(
-        program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-    ) -> ProgramResult {
-        OperationsModule::execute_thaw_account(program_id, verified_mint_info, accounts)?;
-        Ok(())
-    }
-
-    fn process_transfer(
-        program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-        args_data: &[u8],
-    ) -> ProgramResult {
-        let amount = args_data
-            .get(..8)
-            .and_then(|slice| slice.try_into().ok())
-            .map(u64::from_le_bytes)
-            .ok_or(ProgramError::InvalidInstructionData)?;
-        OperationsModule::execute_transfer(program_id, verified_mint_info, accounts, amount)?;
-        Ok(())
-    }
-
-    fn process_create_rate_account(
-        program_id: &Pubkey,
-        verified_mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-        args_data: &[u8],
-    ) -> ProgramResult {
-        let CreateRateArgs { action_id, rate } = CreateRateArgs::try_from_bytes(args_data)?;
-        OperationsModule::execute_create_rate_account(
-            program_id,
-            verified_mint_info,
-            accounts,
-            action_id,
-            rate.numerator,
-            rate.denominator,
-            rate.rounding,
-        )?;
-        Ok(())
-    }
-
-    fn process_update_rate_account(
-        program_id: &Pubkey,
-        mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-        args_data: &[u8],
-    ) -> ProgramResult {
-        let UpdateRateArgs { action_id, rate } = UpdateRateArgs::try_from_bytes(args_data)?;
-        OperationsModule::execute_update_rate_account(
-            program_id,
-            mint_info,
-            accounts,
-            action_id,
-            rate.numerator,
-            rate.denominator,
-            rate.rounding,
-        )?;
-        Ok(())
-    }
-
-    fn process_close_rate_account(
-        program_id: &Pubkey,
-        mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-        args_data: &[u8],
-    ) -> ProgramResult {
-        let CloseRateArgs { action_id } = CloseRateArgs::try_from_bytes(args_data)?;
-        OperationsModule::execute_close_rate_account(program_id, mint_info, accounts, action_id)?;
-        Ok(())
-    }
-
-    fn process_split(
-        program_id: &Pubkey,
-        mint_info: &AccountInfo,
-        accounts: &[AccountInfo],
-        args_data: &[u8],
-    ) -> ProgramResult {
-        let SplitArgs { action_id } = SplitArgs::try_from_bytes(args_data)?;
-        OperationsModule::execute_split(program_id, mint_info, accounts, action_id)?;
-        Ok(())
-    }
-
+            SecurityTokenInstruction::CreateWrapVault => Self::process_create_wrap_vault(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+            ),
+            SecurityTokenInstruction::WrapToken => Self::process_wrap_token(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::UnwrapToken => Self::process_unwrap_token(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::CreateAgentAccount => Self::process_create_agent_account(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::UpdateAgentAccount => Self::process_update_agent_account(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::CloseAgentAccount => Self::process_close_agent_account(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+            ),
+            SecurityTokenInstruction::CreateIdentityAccount => {
+                Self::process_create_identity_account(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::AddIdentityWallet => Self::process_add_identity_wallet(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+            ),
+            SecurityTokenInstruction::RemoveIdentityWallet => Self::process_remove_identity_wallet(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+            ),
+            SecurityTokenInstruction::CloseIdentityAccount => Self::process_close_identity_account(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+            ),
+            SecurityTokenInstruction::CreatePositionLimit => Self::process_create_position_limit(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::UpdatePositionLimit => Self::process_update_position_limit(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::ClosePositionLimit => Self::process_close_position_limit(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+            ),
+            SecurityTokenInstruction::CreateTransferAcceptance => {
+                Self::process_create_transfer_acceptance(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::CloseTransferAcceptance => {
+                Self::process_close_transfer_acceptance(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::OfferTransfer => Self::process_offer_transfer(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::AcceptTransfer => {
+                Self::process_accept_transfer(program_id, verified_mint_info, instruction_accounts)
+            }
+            SecurityTokenInstruction::CancelTransfer => {
+                Self::process_cancel_transfer(program_id, verified_mint_info, instruction_accounts)
+            }
+            SecurityTokenInstruction::CreateTransferApproval => {
+                Self::process_create_transfer_approval(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::UpdateTransferApproval => {
+                Self::process_update_transfer_approval(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::CloseTransferApproval => {
+                Self::process_close_transfer_approval(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::RequestTransfer => Self::process_request_transfer(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::ApproveTransfer => {
+                Self::process_approve_transfer(program_id, verified_mint_info, instruction_accounts)
+            }
+            SecurityTokenInstruction::RejectTransfer => {
+                Self::process_reject_transfer(program_id, verified_mint_info, instruction_accounts)
+            }
+            SecurityTokenInstruction::CreateAccrualConfig => Self::process_create_accrual_config(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::ClaimCoupon => {
+                Self::process_claim_coupon(program_id, verified_mint_info, instruction_accounts)
+            }
+            SecurityTokenInstruction::CreateMaturity => Self::process_create_maturity(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::RedeemAtMaturity => Self::process_redeem_at_maturity(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::WithdrawUnclaimedRedemption => {
+                Self::process_withdraw_unclaimed_redemption(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::CreateNavOracle => Self::process_create_nav_oracle(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::UpdateMultiplierFromOracle => {
+                Self::process_update_multiplier_from_oracle(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::SetRateOracle => Self::process_set_rate_oracle(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::RemoveRateOracle => Self::process_remove_rate_oracle(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::OpenSubscription => Self::process_open_subscription(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::Commit => Self::process_commit(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::Allocate => Self::process_allocate(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::Settle => {
+                Self::process_settle(program_id, verified_mint_info, instruction_accounts)
+            }
+            SecurityTokenInstruction::CreateAuction => Self::process_create_auction(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::PlaceBid => Self::process_place_bid(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::ClearAuction => {
+                Self::process_clear_auction(program_id, verified_mint_info, instruction_accounts)
+            }
+            SecurityTokenInstruction::SettleBid => {
+                Self::process_settle_bid(program_id, verified_mint_info, instruction_accounts)
+            }
+            SecurityTokenInstruction::CreateHoldingPeriod => Self::process_create_holding_period(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::CloseHoldingPeriod => Self::process_close_holding_period(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+            ),
+            SecurityTokenInstruction::UnlockLot => {
+                Self::process_unlock_lot(program_id, instruction_accounts)
+            }
+            SecurityTokenInstruction::Suspend => {
+                Self::process_suspend(program_id, verified_mint_info, instruction_accounts)
+            }
+            SecurityTokenInstruction::Unsuspend => {
+                Self::process_unsuspend(program_id, verified_mint_info, instruction_accounts)
+            }
+            SecurityTokenInstruction::ThawExpired => {
+                Self::process_thaw_expired(program_id, instruction_accounts)
+            }
+            SecurityTokenInstruction::CreateVesting => Self::process_create_vesting(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::ReleaseVested => {
+                Self::process_release_vested(program_id, instruction_accounts)
+            }
+            SecurityTokenInstruction::CreateWithholdingRate => {
+                Self::process_create_withholding_rate(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::UpdateWithholdingRate => {
+                Self::process_update_withholding_rate(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::CreateDistributionPayout => {
+                Self::process_create_distribution_payout(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::CreateClaimDeadline => Self::process_create_claim_deadline(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::ReclaimUnclaimed => Self::process_reclaim_unclaimed(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::AmendDistributionRoot => {
+                Self::process_amend_distribution_root(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::TransferWithPermit => Self::process_transfer_with_permit(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::CreateSessionKey => Self::process_create_session_key(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::CloseSessionKey => Self::process_close_session_key(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+            ),
+            SecurityTokenInstruction::RecoverTokens => Self::process_recover_tokens(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::CreateMintMigration => Self::process_create_mint_migration(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::MigrateBalances => {
+                Self::process_migrate_balances(program_id, verified_mint_info, instruction_accounts)
+            }
+            SecurityTokenInstruction::CreateMetadataSchema => Self::process_create_metadata_schema(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::UpdateMetadataSchema => Self::process_update_metadata_schema(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::CloseMetadataSchema => Self::process_close_metadata_schema(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+            ),
+            SecurityTokenInstruction::FreezeMetadata => VerificationModule::freeze_metadata(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+            ),
+            SecurityTokenInstruction::CreateConfigTemplate => {
+                Self::process_create_config_template(program_id, instruction_accounts, args_data)
+            }
+            SecurityTokenInstruction::UpdateConfigTemplate => {
+                Self::process_update_config_template(program_id, instruction_accounts, args_data)
+            }
+            SecurityTokenInstruction::CloseConfigTemplate => {
+                VerificationModule::close_config_template(program_id, instruction_accounts)
+            }
+            SecurityTokenInstruction::SetConfigTemplate => Self::process_set_config_template(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::CanTransfer => {
+                Self::process_can_transfer(program_id, instruction_accounts, args_data)
+            }
+            SecurityTokenInstruction::GetHolderStatus => {
+                OperationsModule::execute_get_holder_status(program_id, instruction_accounts)
+            }
+            SecurityTokenInstruction::GetEffectiveRate => {
+                Self::process_get_effective_rate(program_id, instruction_accounts, args_data)
+            }
+            SecurityTokenInstruction::GetDistributionStatus => {
+                Self::process_get_distribution_status(program_id, instruction_accounts, args_data)
+            }
+            SecurityTokenInstruction::AttestCollateral => {
+                OperationsModule::execute_attest_collateral(program_id, instruction_accounts)
+            }
+            SecurityTokenInstruction::PublishReserveAttestation => {
+                Self::process_publish_reserve_attestation(
+                    program_id,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::CreateSubAccountLedger => {
+                OperationsModule::execute_create_sub_account_ledger(
+                    program_id,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::CreditSubAccount => {
+                let (beneficial_owner, amount) = Self::parse_sub_account_args(args_data)?;
+                OperationsModule::execute_credit_sub_account(
+                    program_id,
+                    instruction_accounts,
+                    beneficial_owner,
+                    amount,
+                )
+            }
+            SecurityTokenInstruction::DebitSubAccount => {
+                let (beneficial_owner, amount) = Self::parse_sub_account_args(args_data)?;
+                OperationsModule::execute_debit_sub_account(
+                    program_id,
+                    instruction_accounts,
+                    beneficial_owner,
+                    amount,
+                )
+            }
+            SecurityTokenInstruction::CloseSubAccountLedger => {
+                OperationsModule::execute_close_sub_account_ledger(program_id, instruction_accounts)
+            }
+            SecurityTokenInstruction::CreateSuccessionPolicy => {
+                Self::process_create_succession_policy(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::Heartbeat => OperationsModule::execute_heartbeat(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+            ),
+            SecurityTokenInstruction::ClaimSuccession => {
+                OperationsModule::execute_claim_succession(program_id, instruction_accounts)
+            }
+            SecurityTokenInstruction::CloseSuccessionPolicy => {
+                OperationsModule::execute_close_succession_policy(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::CreateMetadataTranslator => {
+                OperationsModule::execute_create_metadata_translator(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::UpdateTranslation => {
+                Self::process_update_translation(program_id, instruction_accounts, args_data)
+            }
+            SecurityTokenInstruction::CloseMetadataTranslator => {
+                OperationsModule::execute_close_metadata_translator(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::AuditMint => {
+                OperationsModule::execute_audit_mint(program_id, instruction_accounts)
+            }
+            SecurityTokenInstruction::SetTransferFee => Self::process_set_transfer_fee(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::WithdrawWithheldTokensFromAccounts => {
+                OperationsModule::execute_withdraw_withheld_tokens_from_accounts(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::HarvestWithheldTokensToMint => {
+                OperationsModule::execute_harvest_withheld_tokens_to_mint(
+                    verified_mint_info,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::UpdateDefaultAccountState => {
+                Self::process_update_default_account_state(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::UpdateScaledUiAmountMultiplier => {
+                Self::process_update_scaled_ui_amount_multiplier(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::RemoveMetadataField => Self::process_remove_metadata_field(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::AnchorDocument => Self::process_anchor_document(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::UpdateDocument => Self::process_update_document(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::RemoveDocument => Self::process_remove_document(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::ProposeMintCreatorTransfer => {
+                Self::process_propose_mint_creator_transfer(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::AcceptMintCreatorTransfer => {
+                OperationsModule::execute_accept_mint_creator_transfer(
+                    program_id,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::SetMintAuthorityMultisig => {
+                Self::process_set_mint_authority_multisig(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::RotatePermanentDelegate => {
+                Self::process_rotate_permanent_delegate(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                    args_data,
+                )
+            }
+            SecurityTokenInstruction::DisablePermanentDelegate => {
+                OperationsModule::execute_disable_permanent_delegate(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::SetPauseDelegate => Self::process_set_pause_delegate(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::CloseMintAuthority => {
+                OperationsModule::execute_close_mint_authority(
+                    program_id,
+                    verified_mint_info,
+                    instruction_accounts,
+                )
+            }
+            SecurityTokenInstruction::SetConfigAdmin => Self::process_set_config_admin(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::ApplyPendingConfig => Self::process_apply_pending_config(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::CancelPendingConfig => Self::process_cancel_pending_config(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::SetEmergencyAuthority => {
+                Self::process_set_emergency_authority(program_id, instruction_accounts, args_data)
+            }
+            SecurityTokenInstruction::SetProgramHalt => {
+                Self::process_set_program_halt(program_id, instruction_accounts, args_data)
+            }
+            SecurityTokenInstruction::AddToAllowlist => Self::process_add_to_allowlist(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::RemoveFromAllowlist => Self::process_remove_from_allowlist(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+            SecurityTokenInstruction::SetAllowlistMode => Self::process_set_allowlist_mode(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                args_data,
+            ),
+        }
+    }
+
+    fn process_update_metadata(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let args = UpdateMetadataArgs::try_from_bytes(args_data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        VerificationModule::update_metadata(program_id, verified_mint_info, accounts, &args)
+    }
+
+    fn process_remove_metadata_field(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let (key, end) = Self::parse_str(args_data, 0)?;
+        if end != args_data.len() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        VerificationModule::remove_metadata_field(program_id, verified_mint_info, accounts, key)
+    }
+
+    fn process_initialize_mint(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let args = InitializeMintArgs::try_from_bytes(args_data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        args.validate()?;
+        VerificationModule::initialize_mint(program_id, accounts, &args)
+    }
+
+    fn process_initialize_verification_config(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let args = InitializeVerificationConfigArgs::try_from_bytes(args_data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        args.validate()?;
+
+        VerificationModule::initialize_verification_config(program_id, mint_info, accounts, &args)
+    }
+
+    fn process_update_verification_config(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let args = UpdateVerificationConfigArgs::try_from_bytes(args_data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        args.validate()?;
+        VerificationModule::update_verification_config(
+            program_id,
+            verified_mint_info,
+            accounts,
+            &args,
+        )
+    }
+
+    /// Process TrimVerificationConfig instruction
+    fn process_trim_verification_config(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let args = TrimVerificationConfigArgs::try_from_bytes(args_data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+        VerificationModule::trim_verification_config(
+            program_id,
+            verified_mint_info,
+            accounts,
+            &args,
+        )
+    }
+
+    fn process_verify(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let instruction_args = VerifyArgs::try_from_bytes(args_data)?;
+        VerificationModule::verify_instruction(program_id, accounts, &instruction_args)?;
+        Ok(())
+    }
+
+    fn process_mint(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        // NOTE: Change to MintArgs structure?
+        let amount = args_data
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_mint(program_id, verified_mint_info, accounts, amount)?;
+        Ok(())
+    }
+
+    fn process_burn(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        // NOTE: Change to BurnArgs structure?
+        let amount = args_data
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_burn(program_id, verified_mint_info, accounts, amount)?;
+        Ok(())
+    }
+
+    fn process_pause(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_pause(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_resume(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_resume(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_set_transfer_fee(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let transfer_fee_basis_points = Self::parse_u16(args_data, 0)?;
+        let maximum_fee = Self::parse_u64(args_data, 2)?;
+        OperationsModule::execute_set_transfer_fee(
+            program_id,
+            verified_mint_info,
+            accounts,
+            transfer_fee_basis_points,
+            maximum_fee,
+        )?;
+        Ok(())
+    }
+
+    fn process_update_default_account_state(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let state = *args_data
+            .first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_update_default_account_state(
+            program_id,
+            verified_mint_info,
+            accounts,
+            state,
+        )?;
+        Ok(())
+    }
+
+    fn process_update_scaled_ui_amount_multiplier(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let multiplier = f64::from_bits(Self::parse_u64(args_data, 0)?);
+        let effective_timestamp = Self::parse_i64(args_data, 8)?;
+        OperationsModule::execute_update_scaled_ui_amount_multiplier(
+            program_id,
+            verified_mint_info,
+            accounts,
+            multiplier,
+            effective_timestamp,
+        )?;
+        Ok(())
+    }
+
+    fn process_freeze(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let auto_thaw_at = Self::parse_auto_thaw_at(args_data)?;
+        OperationsModule::execute_freeze_account(
+            program_id,
+            verified_mint_info,
+            accounts,
+            auto_thaw_at,
+        )?;
+        Ok(())
+    }
+
+    fn process_thaw(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_thaw_account(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let amount = args_data
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        let memo_hash = Self::parse_memo_hash(args_data, 8)?;
+        let memo_offset = 8 + if memo_hash.is_some() { 33 } else { 1 };
+        let memo = Self::parse_memo(args_data, memo_offset)?;
+        OperationsModule::execute_transfer(
+            program_id,
+            verified_mint_info,
+            accounts,
+            amount,
+            memo_hash,
+            memo,
+        )?;
+        Ok(())
+    }
+
+    /// Run the Transfer verification and checks without moving tokens.
+    /// The result code is set as return data: 0 when the transfer would succeed, otherwise the
+    /// error it would fail with, encoded like the VerificationEvent reason.
+    fn process_can_transfer(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let amount = args_data
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Self::parse_memo_hash(args_data, 8)?;
+
+        // Verification programs are consulted about the Transfer the check stands for
+        let transfer = SecurityTokenInstruction::Transfer;
+        let transfer_data = [&[transfer.discriminant()], args_data].concat();
+        let result = Self::verify(
+            program_id,
+            accounts,
+            transfer.discriminant(),
+            &transfer_data,
+            Self::instruction_verification_profile(&transfer),
+        )
+        .and_then(|(verified_mint_info, instruction_accounts)| {
+            OperationsModule::execute_can_transfer(
+                program_id,
+                verified_mint_info,
+                instruction_accounts,
+                amount,
+            )
+        });
+
+        let result_code = result.err().map_or(0, u64::from);
+        set_return_data(&result_code.to_le_bytes());
+        Ok(())
+    }
+
+    fn process_create_rate_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let CreateRateArgs { action_id, rate } = CreateRateArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_create_rate_account(
+            program_id,
+            verified_mint_info,
+            accounts,
+            action_id,
+            rate.numerator,
+            rate.denominator,
+            rate.rounding,
+        )?;
+        Ok(())
+    }
+
+    fn process_update_rate_account(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let UpdateRateArgs { action_id, rate } = UpdateRateArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_update_rate_account(
+            program_id,
+            mint_info,
+            accounts,
+            action_id,
+            rate.numerator,
+            rate.denominator,
+            rate.rounding,
+        )?;
+        Ok(())
+    }
+
+    fn process_close_rate_account(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let CloseRateArgs { action_id } = CloseRateArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_close_rate_account(program_id, mint_info, accounts, action_id)?;
+        Ok(())
+    }
+
+    fn process_set_rate_oracle(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let args = SetRateOracleArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_set_rate_oracle(program_id, mint_info, accounts, &args)?;
+        Ok(())
+    }
+
+    fn process_remove_rate_oracle(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let RemoveRateOracleArgs { action_id } = RemoveRateOracleArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_remove_rate_oracle(program_id, mint_info, accounts, action_id)?;
+        Ok(())
+    }
+
+    fn process_split(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let SplitArgs { action_id } = SplitArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_split(program_id, mint_info, accounts, action_id)?;
+        Ok(())
+    }
+
     fn process_convert(
         program_id: &Pubkey,
-        mint_info: &AccountInfo,
+        mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let ConvertArgs {
+            action_id,
+            amount_to_convert,
+        } = ConvertArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_convert(
+            program_id,
+            mint_info,
+            accounts,
+            action_id,
+            amount_to_convert,
+        )?;
+        Ok(())
+    }
+
+    fn process_create_proof_account(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let CreateProofArgs { action_id, data } = CreateProofArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_create_proof_account(
+            program_id, mint_info, accounts, action_id, data,
+        )?;
+        Ok(())
+    }
+
+    fn process_update_proof_account(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let UpdateProofArgs {
+            action_id,
+            data,
+            offset,
+        } = UpdateProofArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_update_proof_account(
+            program_id, mint_info, accounts, action_id, data, offset,
+        )?;
+        Ok(())
+    }
+
+    fn process_create_distribution_escrow(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let CreateDistributionEscrowArgs {
+            action_id,
+            merkle_root,
+            claim_bitmap_leaves,
+        } = CreateDistributionEscrowArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_create_distribution_escrow(
+            program_id,
+            mint_info,
+            accounts,
+            action_id,
+            &merkle_root,
+            claim_bitmap_leaves,
+        )?;
+        Ok(())
+    }
+
+    fn process_claim_distribution(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let ClaimDistributionArgs {
+            action_id,
+            amount,
+            merkle_root,
+            leaf_index,
+            merkle_proof,
+        } = ClaimDistributionArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_claim_distribution(
+            program_id,
+            mint_info,
+            accounts,
+            amount,
+            action_id,
+            &merkle_root,
+            leaf_index,
+            merkle_proof,
+        )?;
+        Ok(())
+    }
+
+    fn process_close_action_receipt_account(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let CloseActionReceiptArgs { action_id } =
+            CloseActionReceiptArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_close_action_receipt_account(
+            program_id, mint_info, accounts, action_id,
+        )?;
+        Ok(())
+    }
+
+    fn process_close_claim_receipt_account(
+        program_id: &Pubkey,
+        mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let CloseClaimReceiptArgs {
+            action_id,
+            merkle_proof,
+            merkle_root,
+        } = CloseClaimReceiptArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_close_claim_receipt_account(
+            program_id,
+            mint_info,
+            accounts,
+            action_id,
+            merkle_proof,
+            merkle_root,
+        )?;
+        Ok(())
+    }
+
+    fn process_create_wrap_vault(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_create_wrap_vault(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_wrap_token(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let amount = args_data
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_wrap(program_id, verified_mint_info, accounts, amount)?;
+        Ok(())
+    }
+
+    fn process_unwrap_token(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let amount = args_data
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_unwrap(program_id, verified_mint_info, accounts, amount)?;
+        Ok(())
+    }
+
+    fn process_create_agent_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let permissions = *args_data
+            .first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_create_agent_account(
+            program_id,
+            verified_mint_info,
+            accounts,
+            permissions,
+        )?;
+        Ok(())
+    }
+
+    fn process_update_agent_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let permissions = *args_data
+            .first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_update_agent_account(
+            program_id,
+            verified_mint_info,
+            accounts,
+            permissions,
+        )?;
+        Ok(())
+    }
+
+    fn process_close_agent_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_close_agent_account(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_create_identity_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let investor_id: [u8; 32] = args_data
+            .get(..32)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_create_identity_account(
+            program_id,
+            verified_mint_info,
+            accounts,
+            investor_id,
+        )?;
+        Ok(())
+    }
+
+    fn process_add_identity_wallet(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_add_identity_wallet(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_remove_identity_wallet(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_remove_identity_wallet(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_close_identity_account(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_close_identity_account(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_create_position_limit(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let max_ownership_bps = Self::parse_max_ownership_bps(args_data)?;
+        OperationsModule::execute_create_position_limit(
+            program_id,
+            verified_mint_info,
+            accounts,
+            max_ownership_bps,
+        )?;
+        Ok(())
+    }
+
+    fn process_update_position_limit(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let max_ownership_bps = Self::parse_max_ownership_bps(args_data)?;
+        OperationsModule::execute_update_position_limit(
+            program_id,
+            verified_mint_info,
+            accounts,
+            max_ownership_bps,
+        )?;
+        Ok(())
+    }
+
+    fn process_close_position_limit(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_close_position_limit(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_create_transfer_acceptance(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_create_transfer_acceptance(
+            program_id,
+            verified_mint_info,
+            accounts,
+        )?;
+        Ok(())
+    }
+
+    fn process_close_transfer_acceptance(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_close_transfer_acceptance(
+            program_id,
+            verified_mint_info,
+            accounts,
+        )?;
+        Ok(())
+    }
+
+    fn process_offer_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let transfer_id = Self::parse_u64(args_data, 0)?;
+        let amount = Self::parse_u64(args_data, 8)?;
+        OperationsModule::execute_offer_transfer(
+            program_id,
+            verified_mint_info,
+            accounts,
+            transfer_id,
+            amount,
+        )?;
+        Ok(())
+    }
+
+    fn process_accept_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_accept_transfer(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_cancel_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_cancel_transfer(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_create_transfer_approval(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let threshold = Self::parse_u64(args_data, 0)?;
+        OperationsModule::execute_create_transfer_approval(
+            program_id,
+            verified_mint_info,
+            accounts,
+            threshold,
+        )?;
+        Ok(())
+    }
+
+    fn process_update_transfer_approval(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let threshold = Self::parse_u64(args_data, 0)?;
+        OperationsModule::execute_update_transfer_approval(
+            program_id,
+            verified_mint_info,
+            accounts,
+            threshold,
+        )?;
+        Ok(())
+    }
+
+    fn process_close_transfer_approval(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_close_transfer_approval(
+            program_id,
+            verified_mint_info,
+            accounts,
+        )?;
+        Ok(())
+    }
+
+    fn process_request_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let request_id = Self::parse_u64(args_data, 0)?;
+        let amount = Self::parse_u64(args_data, 8)?;
+        OperationsModule::execute_request_transfer(
+            program_id,
+            verified_mint_info,
+            accounts,
+            request_id,
+            amount,
+        )?;
+        Ok(())
+    }
+
+    fn process_approve_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_approve_transfer(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_reject_transfer(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_reject_transfer(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_create_accrual_config(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let args = CreateAccrualConfigArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_create_accrual_config(
+            program_id,
+            verified_mint_info,
+            accounts,
+            &args,
+        )?;
+        Ok(())
+    }
+
+    fn process_claim_coupon(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_claim_coupon(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_create_maturity(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let maturity_timestamp = Self::parse_i64(args_data, 0)?;
+        let redemption_cutoff = Self::parse_i64(args_data, 8)?;
+        let principal_per_token = Self::parse_u64(args_data, 16)?;
+        OperationsModule::execute_create_maturity(
+            program_id,
+            verified_mint_info,
+            accounts,
+            maturity_timestamp,
+            redemption_cutoff,
+            principal_per_token,
+        )?;
+        Ok(())
+    }
+
+    fn process_redeem_at_maturity(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let amount = Self::parse_u64(args_data, 0)?;
+        OperationsModule::execute_redeem_at_maturity(
+            program_id,
+            verified_mint_info,
+            accounts,
+            amount,
+        )?;
+        Ok(())
+    }
+
+    fn process_withdraw_unclaimed_redemption(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_withdraw_unclaimed_redemption(
+            program_id,
+            verified_mint_info,
+            accounts,
+        )?;
+        Ok(())
+    }
+
+    fn process_create_nav_oracle(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let args = CreateNavOracleArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_create_nav_oracle(
+            program_id,
+            verified_mint_info,
+            accounts,
+            &args,
+        )?;
+        Ok(())
+    }
+
+    fn process_update_multiplier_from_oracle(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_update_multiplier_from_oracle(
+            program_id,
+            verified_mint_info,
+            accounts,
+        )?;
+        Ok(())
+    }
+
+    fn process_open_subscription(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let subscription_id = Self::parse_u64(args_data, 0)?;
+        let price_per_token = Self::parse_u64(args_data, 8)?;
+        let close_timestamp = Self::parse_i64(args_data, 16)?;
+        OperationsModule::execute_open_subscription(
+            program_id,
+            verified_mint_info,
+            accounts,
+            subscription_id,
+            price_per_token,
+            close_timestamp,
+        )?;
+        Ok(())
+    }
+
+    fn process_commit(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let amount = Self::parse_u64(args_data, 0)?;
+        OperationsModule::execute_commit(program_id, verified_mint_info, accounts, amount)?;
+        Ok(())
+    }
+
+    fn process_allocate(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let allocation = Self::parse_u64(args_data, 0)?;
+        OperationsModule::execute_allocate(program_id, verified_mint_info, accounts, allocation)?;
+        Ok(())
+    }
+
+    fn process_settle(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_settle(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_create_auction(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let args = CreateAuctionArgs::try_from_bytes(args_data)?;
+        OperationsModule::execute_create_auction(program_id, verified_mint_info, accounts, &args)?;
+        Ok(())
+    }
+
+    fn process_place_bid(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let quantity = Self::parse_u64(args_data, 0)?;
+        OperationsModule::execute_place_bid(program_id, verified_mint_info, accounts, quantity)?;
+        Ok(())
+    }
+
+    fn process_clear_auction(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_clear_auction(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_settle_bid(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_settle_bid(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_create_holding_period(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let holding_period = Self::parse_u64(args_data, 0)?;
+        OperationsModule::execute_create_holding_period(
+            program_id,
+            verified_mint_info,
+            accounts,
+            holding_period,
+        )?;
+        Ok(())
+    }
+
+    fn process_close_holding_period(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_close_holding_period(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_unlock_lot(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        OperationsModule::execute_unlock_lot(program_id, accounts)?;
+        Ok(())
+    }
+
+    fn process_suspend(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_suspend(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_unsuspend(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_unsuspend(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_thaw_expired(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        OperationsModule::execute_thaw_expired(program_id, accounts)?;
+        Ok(())
+    }
+
+    fn process_create_vesting(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let vesting_id = Self::parse_u64(args_data, 0)?;
+        let total_amount = Self::parse_u64(args_data, 8)?;
+        let start_timestamp = Self::parse_i64(args_data, 16)?;
+        let end_timestamp = Self::parse_i64(args_data, 24)?;
+        OperationsModule::execute_create_vesting(
+            program_id,
+            verified_mint_info,
+            accounts,
+            vesting_id,
+            total_amount,
+            start_timestamp,
+            end_timestamp,
+        )?;
+        Ok(())
+    }
+
+    fn process_release_vested(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        OperationsModule::execute_release_vested(program_id, accounts)?;
+        Ok(())
+    }
+
+    fn process_create_withholding_rate(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let jurisdiction = Self::parse_u16(args_data, 0)?;
+        let rate_bps = Self::parse_u16(args_data, 2)?;
+        OperationsModule::execute_create_withholding_rate(
+            program_id,
+            verified_mint_info,
+            accounts,
+            jurisdiction,
+            rate_bps,
+        )?;
+        Ok(())
+    }
+
+    fn process_update_withholding_rate(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let rate_bps = Self::parse_u16(args_data, 0)?;
+        OperationsModule::execute_update_withholding_rate(
+            program_id,
+            verified_mint_info,
+            accounts,
+            rate_bps,
+        )?;
+        Ok(())
+    }
+
+    fn process_create_distribution_payout(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let action_id = Self::parse_u64(args_data, 0)?;
+        let merkle_root: MerkleTreeRoot = args_data
+            .get(8..40)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        let rate_numerator = Self::parse_u64(args_data, 40)?;
+        let rate_denominator = Self::parse_u64(args_data, 48)?;
+        OperationsModule::execute_create_distribution_payout(
+            program_id,
+            verified_mint_info,
+            accounts,
+            action_id,
+            &merkle_root,
+            rate_numerator,
+            rate_denominator,
+        )?;
+        Ok(())
+    }
+
+    fn process_create_claim_deadline(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let action_id = Self::parse_u64(args_data, 0)?;
+        let claim_deadline = Self::parse_i64(args_data, 8)?;
+        let claims_open_at = Self::parse_i64(args_data, 16)?;
+        OperationsModule::execute_create_claim_deadline(
+            program_id,
+            verified_mint_info,
+            accounts,
+            action_id,
+            claim_deadline,
+            claims_open_at,
+        )?;
+        Ok(())
+    }
+
+    fn process_reclaim_unclaimed(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let action_id = Self::parse_u64(args_data, 0)?;
+        let merkle_root: MerkleTreeRoot = args_data
+            .get(8..40)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_reclaim_unclaimed(
+            program_id,
+            verified_mint_info,
+            accounts,
+            action_id,
+            &merkle_root,
+        )?;
+        Ok(())
+    }
+
+    fn process_get_effective_rate(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let action_id = Self::parse_u64(args_data, 0)?;
+        OperationsModule::execute_get_effective_rate(program_id, accounts, action_id)
+    }
+
+    fn process_get_distribution_status(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let action_id = Self::parse_u64(args_data, 0)?;
+        let merkle_root: MerkleTreeRoot = args_data
+            .get(8..40)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_get_distribution_status(
+            program_id,
+            accounts,
+            action_id,
+            &merkle_root,
+        )
+    }
+
+    fn process_publish_reserve_attestation(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let liability_hash: [u8; 32] = args_data
+            .get(..32)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_publish_reserve_attestation(program_id, accounts, liability_hash)
+    }
+
+    /// Parse the beneficial owner and amount of CreditSubAccount and DebitSubAccount
+    fn parse_sub_account_args(args_data: &[u8]) -> Result<([u8; 32], u64), ProgramError> {
+        let beneficial_owner: [u8; 32] = args_data
+            .get(..32)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        let amount = Self::parse_u64(args_data, 32)?;
+        Ok((beneficial_owner, amount))
+    }
+
+    fn process_amend_distribution_root(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let action_id = Self::parse_u64(args_data, 0)?;
+        let merkle_root: MerkleTreeRoot = args_data
+            .get(8..40)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        let new_merkle_root: MerkleTreeRoot = args_data
+            .get(40..72)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        let supplemental = match args_data.get(72) {
+            Some(0) => false,
+            Some(1) => true,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+        OperationsModule::execute_amend_distribution_root(
+            program_id,
+            verified_mint_info,
+            accounts,
+            action_id,
+            &merkle_root,
+            &new_merkle_root,
+            supplemental,
+        )?;
+        Ok(())
+    }
+
+    fn process_transfer_with_permit(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let amount = Self::parse_u64(args_data, 0)?;
+        let nonce = Self::parse_u64(args_data, 8)?;
+        let expiry = Self::parse_i64(args_data, 16)?;
+        OperationsModule::execute_transfer_with_permit(
+            program_id,
+            verified_mint_info,
+            accounts,
+            amount,
+            nonce,
+            expiry,
+        )?;
+        Ok(())
+    }
+
+    fn process_create_session_key(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let allowed_instructions = args_data
+            .get(..16)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u128::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        let max_amount = Self::parse_u64(args_data, 16)?;
+        let expiry = Self::parse_i64(args_data, 24)?;
+
+        for discriminator in 0..128u8 {
+            if allowed_instructions & (1u128 << discriminator) == 0 {
+                continue;
+            }
+            let delegable = SecurityTokenInstruction::from_discriminant(discriminator)
+                .is_some_and(|instruction| Self::session_key_delegable(&instruction));
+            if !delegable {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+        }
+
+        OperationsModule::execute_create_session_key(
+            program_id,
+            verified_mint_info,
+            accounts,
+            allowed_instructions,
+            max_amount,
+            expiry,
+        )?;
+        Ok(())
+    }
+
+    fn process_close_session_key(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_close_session_key(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_create_succession_policy(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let successor: Pubkey = args_data
+            .get(..32)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        let heartbeat_period = Self::parse_i64(args_data, 32)?;
+        OperationsModule::execute_create_succession_policy(
+            program_id,
+            verified_mint_info,
+            accounts,
+            successor,
+            heartbeat_period,
+        )
+    }
+
+    fn process_update_translation(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let (key, value_offset) = Self::parse_str(args_data, 0)?;
+        let (value, end) = Self::parse_str(args_data, value_offset)?;
+        if end != args_data.len() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        VerificationModule::update_translation(program_id, accounts, key, value)
+    }
+
+    fn process_recover_tokens(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let recovery_id = Self::parse_u64(args_data, 0)?;
+        OperationsModule::execute_recover_tokens(
+            program_id,
+            verified_mint_info,
+            accounts,
+            recovery_id,
+        )?;
+        Ok(())
+    }
+
+    fn process_create_mint_migration(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let action_id = Self::parse_u64(args_data, 0)?;
+        OperationsModule::execute_create_mint_migration(
+            program_id,
+            verified_mint_info,
+            accounts,
+            action_id,
+        )?;
+        Ok(())
+    }
+
+    fn process_migrate_balances(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_migrate_balances(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_create_metadata_schema(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let fields = Self::parse_bytes(args_data, 0)?;
+        OperationsModule::execute_create_metadata_schema(
+            program_id,
+            verified_mint_info,
+            accounts,
+            fields,
+        )?;
+        Ok(())
+    }
+
+    fn process_update_metadata_schema(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let fields = Self::parse_bytes(args_data, 0)?;
+        OperationsModule::execute_update_metadata_schema(
+            program_id,
+            verified_mint_info,
+            accounts,
+            fields,
+        )?;
+        Ok(())
+    }
+
+    fn process_close_metadata_schema(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        OperationsModule::execute_close_metadata_schema(program_id, verified_mint_info, accounts)?;
+        Ok(())
+    }
+
+    fn process_anchor_document(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
         args_data: &[u8],
     ) -> ProgramResult {
-        let ConvertArgs {
-            action_id,
-            amount_to_convert,
-        } = ConvertArgs::try_from_bytes(args_data)?;
-        OperationsModule::execute_convert(
+        let (name, uri, sha256) = Self::parse_document_args(args_data)?;
+        OperationsModule::execute_anchor_document(
             program_id,
-            mint_info,
+            verified_mint_info,
             accounts,
-            action_id,
-            amount_to_convert,
-        )?;
-        Ok(())
+            name,
+            uri,
+            &sha256,
+        )
     }
 
-    fn process_create_proof_account(
+    fn process_update_document(
         program_id: &Pubkey,
-        mint_info: &AccountInfo,
+        verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
         args_data: &[u8],
     ) -> ProgramResult {
-        let CreateProofArgs { action_id, data } = CreateProofArgs::try_from_bytes(args_data)?;
-        OperationsModule::execute_create_proof_account(
-            program_id, mint_info, accounts, action_id, data,
-        )?;
-        Ok(())
+        let (name, uri, sha256) = Self::parse_document_args(args_data)?;
+        OperationsModule::execute_update_document(
+            program_id,
+            verified_mint_info,
+            accounts,
+            name,
+            uri,
+            &sha256,
+        )
     }
 
-    fn process_update_proof_account(
+    fn process_remove_document(
         program_id: &Pubkey,
-        mint_info: &AccountInfo,
+        verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
         args_data: &[u8],
     ) -> ProgramResult {
-        let UpdateProofArgs {
-            action_id,
-            data,
-            offset,
-        } = UpdateProofArgs::try_from_bytes(args_data)?;
-        OperationsModule::execute_update_proof_account(
-            program_id, mint_info, accounts, action_id, data, offset,
-        )?;
-        Ok(())
+        let (name, end) = Self::parse_str(args_data, 0)?;
+        if end != args_data.len() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        OperationsModule::execute_remove_document(program_id, verified_mint_info, accounts, name)
     }
 
-    fn process_create_distribution_escrow(
+    fn process_propose_mint_creator_transfer(
         program_id: &Pubkey,
-        mint_info: &AccountInfo,
+        verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
         args_data: &[u8],
     ) -> ProgramResult {
-        let CreateDistributionEscrowArgs {
-            action_id,
-            merkle_root,
-        } = CreateDistributionEscrowArgs::try_from_bytes(args_data)?;
-        OperationsModule::execute_create_distribution_escrow(
+        let new_creator: Pubkey = args_data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_propose_mint_creator_transfer(
             program_id,
-            mint_info,
+            verified_mint_info,
             accounts,
-            action_id,
-            &merkle_root,
-        )?;
-        Ok(())
+            new_creator,
+        )
     }
 
-    fn process_claim_distribution(
+    fn process_set_mint_authority_multisig(
         program_id: &Pubkey,
-        mint_info: &AccountInfo,
+        verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
         args_data: &[u8],
     ) -> ProgramResult {
-        let ClaimDistributionArgs {
-            action_id,
-            amount,
-            merkle_root,
-            leaf_index,
-            merkle_proof,
-        } = ClaimDistributionArgs::try_from_bytes(args_data)?;
-        OperationsModule::execute_claim_distribution(
+        let threshold = *args_data
+            .first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        let signers = Self::parse_pubkeys(args_data, 1)?;
+        OperationsModule::execute_set_mint_authority_multisig(
             program_id,
-            mint_info,
+            verified_mint_info,
             accounts,
-            amount,
-            action_id,
-            &merkle_root,
-            leaf_index,
-            merkle_proof,
-        )?;
-        Ok(())
+            threshold,
+            signers,
+        )
     }
 
-    fn process_close_action_receipt_account(
+    fn process_rotate_permanent_delegate(
         program_id: &Pubkey,
-        mint_info: &AccountInfo,
+        verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
         args_data: &[u8],
     ) -> ProgramResult {
-        let CloseActionReceiptArgs { action_id } =
-            CloseActionReceiptArgs::try_from_bytes(args_data)?;
-        OperationsModule::execute_close_action_receipt_account(
-            program_id, mint_info, accounts, action_id,
-        )?;
-        Ok(())
+        let new_delegate: Pubkey = args_data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_rotate_permanent_delegate(
+            program_id,
+            verified_mint_info,
+            accounts,
+            new_delegate,
+        )
     }
 
-    fn process_close_claim_receipt_account(
+    fn process_set_pause_delegate(
         program_id: &Pubkey,
-        mint_info: &AccountInfo,
+        verified_mint_info: &AccountInfo,
         accounts: &[AccountInfo],
         args_data: &[u8],
     ) -> ProgramResult {
-        let CloseClaimReceiptArgs {
-            action_id,
-            merkle_proof,
-        } = CloseClaimReceiptArgs::try_from_bytes(args_data)?;
-        OperationsModule::execute_close_claim_receipt_account(
+        let delegate: Pubkey = args_data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_set_pause_delegate(
             program_id,
-            mint_info,
+            verified_mint_info,
             accounts,
-            action_id,
-            merkle_proof,
-        )?;
-        Ok(())
+            delegate,
+        )
+    }
+
+    fn process_set_config_admin(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let config_admin: Pubkey = args_data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        VerificationModule::set_config_admin(program_id, verified_mint_info, accounts, config_admin)
+    }
+
+    fn process_apply_pending_config(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let instruction_discriminator = *args_data
+            .first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        VerificationModule::apply_pending_config(
+            program_id,
+            verified_mint_info,
+            accounts,
+            instruction_discriminator,
+        )
+    }
+
+    fn process_cancel_pending_config(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let instruction_discriminator = *args_data
+            .first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        VerificationModule::cancel_pending_config(
+            program_id,
+            verified_mint_info,
+            accounts,
+            instruction_discriminator,
+        )
+    }
+
+    fn process_set_emergency_authority(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let emergency_authority: Pubkey = args_data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        VerificationModule::set_emergency_authority(program_id, accounts, emergency_authority)
+    }
+
+    fn process_set_program_halt(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let halted = match args_data {
+            [0] => false,
+            [1] => true,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+        VerificationModule::set_program_halt(program_id, accounts, halted)
+    }
+
+    fn process_add_to_allowlist(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let wallet: Pubkey = args_data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_add_to_allowlist(program_id, verified_mint_info, accounts, wallet)
+    }
+
+    fn process_remove_from_allowlist(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let wallet: Pubkey = args_data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        OperationsModule::execute_remove_from_allowlist(
+            program_id,
+            verified_mint_info,
+            accounts,
+            wallet,
+        )
+    }
+
+    fn process_set_allowlist_mode(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let enabled = match args_data {
+            [0] => false,
+            [1] => true,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+        OperationsModule::execute_set_allowlist_mode(
+            program_id,
+            verified_mint_info,
+            accounts,
+            enabled,
+        )
+    }
+
+    /// Parse the name, URI and SHA-256 hash of AnchorDocument and UpdateDocument
+    fn parse_document_args(
+        args_data: &[u8],
+    ) -> Result<(&str, &str, [u8; DOCUMENT_HASH_LEN]), ProgramError> {
+        let (name, name_end) = Self::parse_str(args_data, 0)?;
+        let (uri, uri_end) = Self::parse_str(args_data, name_end)?;
+        let sha256: [u8; DOCUMENT_HASH_LEN] = args_data
+            .get(uri_end..)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok((name, uri, sha256))
+    }
+
+    fn process_create_config_template(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let template_id = Self::parse_u64(args_data, 0)?;
+        let program_addresses = Self::parse_pubkeys(args_data, 8)?;
+        VerificationModule::create_config_template(
+            program_id,
+            accounts,
+            template_id,
+            &program_addresses,
+        )
+    }
+
+    fn process_update_config_template(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let program_addresses = Self::parse_pubkeys(args_data, 0)?;
+        VerificationModule::update_config_template(program_id, accounts, &program_addresses)
+    }
+
+    fn process_set_config_template(
+        program_id: &Pubkey,
+        verified_mint_info: &AccountInfo,
+        accounts: &[AccountInfo],
+        args_data: &[u8],
+    ) -> ProgramResult {
+        let instruction_discriminator = *args_data
+            .first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        VerificationModule::set_config_template(
+            program_id,
+            verified_mint_info,
+            accounts,
+            instruction_discriminator,
+        )
+    }
+
+    /// Parse a borsh `Vec<Pubkey>` (u32 LE length prefix) that ends the instruction data
+    fn parse_pubkeys(args_data: &[u8], offset: usize) -> Result<Vec<Pubkey>, ProgramError> {
+        let count = args_data
+            .get(offset..offset + 4)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u32::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)? as usize;
+        match args_data.get(offset + 4..) {
+            Some(bytes) if bytes.len() == count * PUBKEY_BYTES => bytes
+                .chunks_exact(PUBKEY_BYTES)
+                .map(|chunk| {
+                    chunk
+                        .try_into()
+                        .map_err(|_| ProgramError::InvalidInstructionData)
+                })
+                .collect(),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    /// Parse a borsh `Vec<u8>` (u32 LE length prefix) that ends the instruction data
+    fn parse_bytes(args_data: &[u8], offset: usize) -> Result<&[u8], ProgramError> {
+        let len = args_data
+            .get(offset..offset + 4)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u32::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)? as usize;
+        match args_data.get(offset + 4..) {
+            Some(bytes) if bytes.len() == len => Ok(bytes),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    /// Parse a borsh string, returning it with the offset of the following argument
+    fn parse_str(args_data: &[u8], offset: usize) -> Result<(&str, usize), ProgramError> {
+        let len = args_data
+            .get(offset..offset + 4)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u32::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)? as usize;
+        let end = offset + 4 + len;
+        args_data
+            .get(offset + 4..end)
+            .and_then(|bytes| core::str::from_utf8(bytes).ok())
+            .map(|value| (value, end))
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+
+    fn parse_u16(args_data: &[u8], offset: usize) -> Result<u16, ProgramError> {
+        args_data
+            .get(offset..offset + 2)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u16::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+
+    fn parse_i64(args_data: &[u8], offset: usize) -> Result<i64, ProgramError> {
+        args_data
+            .get(offset..offset + 8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(i64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+
+    fn parse_u64(args_data: &[u8], offset: usize) -> Result<u64, ProgramError> {
+        args_data
+            .get(offset..offset + 8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+
+    /// Parse an optional travel-rule memo hash (borsh `Option<[u8; 32]>`).
+    /// Missing bytes mean no memo, keeping the amount-only encoding valid.
+    fn parse_memo_hash(args_data: &[u8], offset: usize) -> Result<Option<[u8; 32]>, ProgramError> {
+        match args_data.get(offset) {
+            None | Some(0) => Ok(None),
+            Some(1) => args_data
+                .get(offset + 1..offset + 33)
+                .and_then(|slice| slice.try_into().ok())
+                .map(Some)
+                .ok_or(ProgramError::InvalidInstructionData),
+            Some(_) => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    /// Parse an optional memo (borsh `Option<String>`) that ends the instruction data.
+    /// Missing bytes mean no memo, keeping the encodings without it valid.
+    fn parse_memo(args_data: &[u8], offset: usize) -> Result<Option<&str>, ProgramError> {
+        match args_data.get(offset) {
+            None | Some(0) => Ok(None),
+            Some(1) => match Self::parse_str(args_data, offset + 1)? {
+                (memo, end) if end == args_data.len() => Ok(Some(memo)),
+                _ => Err(ProgramError::InvalidInstructionData),
+            },
+            Some(_) => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    /// Parse an optional auto-thaw timestamp (borsh `Option<i64>`).
+    /// Missing bytes mean no expiry, keeping the argument-less encoding valid.
+    fn parse_auto_thaw_at(args_data: &[u8]) -> Result<Option<i64>, ProgramError> {
+        match args_data.first() {
+            None | Some(0) => Ok(None),
+            Some(1) => Self::parse_i64(args_data, 1).map(Some),
+            Some(_) => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    fn parse_max_ownership_bps(args_data: &[u8]) -> Result<u16, ProgramError> {
+        args_data
+            .get(..2)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u16::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)
     }
 }
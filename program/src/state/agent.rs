@@ -0,0 +1,308 @@
+//! Agent account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::AGENT_ACCOUNT;
+use crate::instruction::SecurityTokenInstruction;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Operation scopes an agent can be granted (bit flags)
+pub mod agent_permissions {
+    /// Freeze and Thaw token accounts
+    pub const FREEZE: u8 = 1 << 0;
+    /// Create distribution escrows and payouts, close distribution receipts
+    pub const DISTRIBUTION: u8 = 1 << 1;
+    /// Manage investor identities (registrar)
+    pub const IDENTITY: u8 = 1 << 2;
+    /// Approve and reject queued transfer requests (compliance officer)
+    pub const TRANSFER_APPROVAL: u8 = 1 << 3;
+    /// Suspend and unsuspend token accounts (investigative holds)
+    pub const SUSPEND: u8 = 1 << 4;
+    /// All known scopes
+    pub const ALL: u8 = FREEZE | DISTRIBUTION | IDENTITY | TRANSFER_APPROVAL | SUSPEND;
+}
+
+/// Third-party key allowed to perform a scoped set of operations on a mint
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct Agent {
+    /// Mint the agent operates on
+    pub mint: Pubkey,
+    /// Key that signs on behalf of the agent
+    pub agent: Pubkey,
+    /// Granted scopes, see [`agent_permissions`]
+    pub permissions: u8,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for Agent {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::AgentDiscriminator as u8;
+}
+
+impl AccountSerialize for Agent {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.agent.as_ref());
+        data.push(self.permissions);
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for Agent {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let agent: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let permissions = data[2 * PUBKEY_BYTES];
+        let bump = data[2 * PUBKEY_BYTES + 1];
+
+        let agent = Self {
+            mint,
+            agent,
+            permissions,
+            bump,
+        };
+        agent.validate()?;
+        Ok(agent)
+    }
+}
+
+impl ProgramAccount for Agent {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl Agent {
+    /// Serialized size of the account data (discriminator + mint + agent + permissions + bump)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 1 + 1;
+
+    /// Create a new Agent
+    pub fn new(
+        mint: Pubkey,
+        agent: Pubkey,
+        permissions: u8,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        let agent = Self {
+            mint,
+            agent,
+            permissions,
+            bump,
+        };
+        agent.validate()?;
+        Ok(agent)
+    }
+
+    /// Update granted scopes
+    pub fn update(&mut self, permissions: u8) -> Result<(), ProgramError> {
+        self.permissions = permissions;
+        self.validate()
+    }
+
+    /// Validate the Agent account data
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        if self.permissions == 0 || self.permissions & !agent_permissions::ALL != 0 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+
+    /// Scope required to execute the instruction, `None` if agents cannot execute it
+    pub fn required_permission(instruction: &SecurityTokenInstruction) -> Option<u8> {
+        use SecurityTokenInstruction::*;
+
+        match instruction {
+            Freeze | Thaw => Some(agent_permissions::FREEZE),
+            CreateDistributionEscrow
+            | CreateDistributionPayout
+            | CreateClaimDeadline
+            | CloseActionReceiptAccount
+            | CloseClaimReceiptAccount => Some(agent_permissions::DISTRIBUTION),
+            CreateIdentityAccount
+            | AddIdentityWallet
+            | RemoveIdentityWallet
+            | CloseIdentityAccount
+            | AddToAllowlist
+            | RemoveFromAllowlist => Some(agent_permissions::IDENTITY),
+            ApproveTransfer | RejectTransfer => Some(agent_permissions::TRANSFER_APPROVAL),
+            Suspend | Unsuspend => Some(agent_permissions::SUSPEND),
+            _ => None,
+        }
+    }
+
+    /// Whether the agent is granted the scope of the instruction
+    pub fn permits(&self, ix_discriminator: u8) -> bool {
+        SecurityTokenInstruction::from_discriminant(ix_discriminator)
+            .and_then(|instruction| Self::required_permission(&instruction))
+            .is_some_and(|permission| self.permissions & permission != 0)
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Agent, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 4] {
+        [
+            Seed::from(AGENT_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(self.agent.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[AGENT_ACCOUNT, &self.mint, &self.agent, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_agent_serialization_round_trip() {
+        let agent = Agent::new([1u8; 32], [2u8; 32], agent_permissions::FREEZE, 253).unwrap();
+
+        let bytes = agent.to_bytes();
+        assert_eq!(bytes.len(), Agent::LEN);
+
+        let deserialized = Agent::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, agent.mint);
+        assert_eq!(deserialized.agent, agent.agent);
+        assert_eq!(deserialized.permissions, agent.permissions);
+        assert_eq!(deserialized.bump, agent.bump);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(agent_permissions::ALL + 1)]
+    fn test_agent_invalid_permissions(#[case] permissions: u8) {
+        assert!(Agent::new([1u8; 32], [2u8; 32], permissions, 253).is_err());
+    }
+
+    #[rstest]
+    #[case(agent_permissions::FREEZE, SecurityTokenInstruction::Freeze, true)]
+    #[case(agent_permissions::FREEZE, SecurityTokenInstruction::Thaw, true)]
+    #[case(
+        agent_permissions::FREEZE,
+        SecurityTokenInstruction::CreateDistributionEscrow,
+        false
+    )]
+    #[case(
+        agent_permissions::DISTRIBUTION,
+        SecurityTokenInstruction::CreateDistributionEscrow,
+        true
+    )]
+    #[case(
+        agent_permissions::DISTRIBUTION,
+        SecurityTokenInstruction::CloseClaimReceiptAccount,
+        true
+    )]
+    #[case(
+        agent_permissions::DISTRIBUTION,
+        SecurityTokenInstruction::CreateDistributionPayout,
+        true
+    )]
+    #[case(
+        agent_permissions::DISTRIBUTION,
+        SecurityTokenInstruction::CreateClaimDeadline,
+        true
+    )]
+    #[case(
+        agent_permissions::DISTRIBUTION,
+        SecurityTokenInstruction::ReclaimUnclaimed,
+        false
+    )]
+    #[case(
+        agent_permissions::DISTRIBUTION,
+        SecurityTokenInstruction::AmendDistributionRoot,
+        false
+    )]
+    #[case(
+        agent_permissions::DISTRIBUTION,
+        SecurityTokenInstruction::Freeze,
+        false
+    )]
+    #[case(
+        agent_permissions::IDENTITY,
+        SecurityTokenInstruction::AddIdentityWallet,
+        true
+    )]
+    #[case(
+        agent_permissions::IDENTITY,
+        SecurityTokenInstruction::AddToAllowlist,
+        true
+    )]
+    #[case(
+        agent_permissions::IDENTITY,
+        SecurityTokenInstruction::SetAllowlistMode,
+        false
+    )]
+    #[case(agent_permissions::IDENTITY, SecurityTokenInstruction::Freeze, false)]
+    #[case(
+        agent_permissions::TRANSFER_APPROVAL,
+        SecurityTokenInstruction::ApproveTransfer,
+        true
+    )]
+    #[case(
+        agent_permissions::TRANSFER_APPROVAL,
+        SecurityTokenInstruction::RequestTransfer,
+        false
+    )]
+    #[case(agent_permissions::SUSPEND, SecurityTokenInstruction::Suspend, true)]
+    #[case(agent_permissions::SUSPEND, SecurityTokenInstruction::Unsuspend, true)]
+    #[case(agent_permissions::FREEZE, SecurityTokenInstruction::Suspend, false)]
+    #[case(agent_permissions::SUSPEND, SecurityTokenInstruction::Freeze, false)]
+    #[case(agent_permissions::ALL, SecurityTokenInstruction::Mint, false)]
+    #[case(
+        agent_permissions::ALL,
+        SecurityTokenInstruction::UpdateVerificationConfig,
+        false
+    )]
+    fn test_agent_permits(
+        #[case] permissions: u8,
+        #[case] instruction: SecurityTokenInstruction,
+        #[case] expected: bool,
+    ) {
+        let agent = Agent::new([1u8; 32], [2u8; 32], permissions, 253).unwrap();
+        assert_eq!(agent.permits(instruction.discriminant()), expected);
+    }
+}
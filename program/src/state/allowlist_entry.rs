@@ -0,0 +1,133 @@
+//! Allowlist entry state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::ALLOWLIST_ENTRY_ACCOUNT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Wallet allowlisted for a mint, e.g. after KYC by the issuer. While the allowlist mode is
+/// enabled the transfer hook requires an entry for the owners of both token accounts.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct AllowlistEntry {
+    /// Mint the wallet may hold
+    pub mint: Pubkey,
+    /// Allowlisted wallet, owner of token accounts of the mint
+    pub wallet: Pubkey,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for AllowlistEntry {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::AllowlistEntryDiscriminator as u8;
+}
+
+impl AccountSerialize for AllowlistEntry {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.wallet.as_ref());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for AllowlistEntry {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let wallet: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let bump = data[2 * PUBKEY_BYTES];
+
+        Ok(Self { mint, wallet, bump })
+    }
+}
+
+impl ProgramAccount for AllowlistEntry {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl AllowlistEntry {
+    /// Serialized size of the account data (discriminator + mint + wallet + bump)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + PUBKEY_BYTES + 1;
+
+    /// Create a new AllowlistEntry
+    pub fn new(mint: Pubkey, wallet: Pubkey, bump: u8) -> Self {
+        Self { mint, wallet, bump }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<AllowlistEntry, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 4] {
+        [
+            Seed::from(ALLOWLIST_ENTRY_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(self.wallet.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                ALLOWLIST_ENTRY_ACCOUNT,
+                &self.mint,
+                &self.wallet,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowlist_entry_serialization_round_trip() {
+        let allowlist_entry = AllowlistEntry::new([1u8; 32], [2u8; 32], 254);
+
+        let bytes = allowlist_entry.to_bytes();
+        assert_eq!(bytes.len(), AllowlistEntry::LEN);
+
+        let deserialized = AllowlistEntry::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, allowlist_entry.mint);
+        assert_eq!(deserialized.wallet, allowlist_entry.wallet);
+        assert_eq!(deserialized.bump, allowlist_entry.bump);
+    }
+}
@@ -0,0 +1,588 @@
+//! Dutch auction issuance state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::{AUCTION_ACCOUNT, AUCTION_BID_ACCOUNT};
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Payment in payment mint base units for `quantity` token base units at `price` per whole token. Rounded up.
+fn payment_at_price(quantity: u64, price: u64, decimals: u8) -> Result<u64, ProgramError> {
+    let scale = 10u128
+        .checked_pow(u32::from(decimals))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let payment = u128::from(quantity)
+        .checked_mul(u128::from(price))
+        .ok_or(ProgramError::ArithmeticOverflow)?
+        .div_ceil(scale);
+    u64::try_from(payment).map_err(|_| ProgramError::ArithmeticOverflow)
+}
+
+/// Descending price auction of a fixed supply of security tokens. The price falls linearly
+/// from `start_price` to `floor_price` between `start_timestamp` and `end_timestamp`; bids
+/// are taken at the current price until the supply is sold out or the auction ends, and
+/// every winning bid pays the clearing price, the price of the last bid.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct Auction {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Mint bidders pay in
+    pub payment_mint: Pubkey,
+    /// Issuer payment token account receiving the proceeds
+    pub proceeds_account: Pubkey,
+    /// Issuer chosen identifier distinguishing auctions of the same mint
+    pub auction_id: u64,
+    /// Price in payment mint base units per whole token at `start_timestamp`
+    pub start_price: u64,
+    /// Price in payment mint base units per whole token at `end_timestamp`
+    pub floor_price: u64,
+    /// Unix timestamp bidding opens at
+    pub start_timestamp: i64,
+    /// Unix timestamp bidding closes at
+    pub end_timestamp: i64,
+    /// Security token base units for sale
+    pub supply: u64,
+    /// Security token base units bid for by all bidders
+    pub total_bid_quantity: u64,
+    /// Price of the last bid, 0 without bids
+    pub last_bid_price: u64,
+    /// Price every winning bid pays, 0 until the auction is cleared
+    pub clearing_price: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for Auction {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::AuctionDiscriminator as u8;
+}
+
+impl AccountSerialize for Auction {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.payment_mint.as_ref());
+        data.extend_from_slice(self.proceeds_account.as_ref());
+        data.extend_from_slice(&self.auction_id.to_le_bytes());
+        data.extend_from_slice(&self.start_price.to_le_bytes());
+        data.extend_from_slice(&self.floor_price.to_le_bytes());
+        data.extend_from_slice(&self.start_timestamp.to_le_bytes());
+        data.extend_from_slice(&self.end_timestamp.to_le_bytes());
+        data.extend_from_slice(&self.supply.to_le_bytes());
+        data.extend_from_slice(&self.total_bid_quantity.to_le_bytes());
+        data.extend_from_slice(&self.last_bid_price.to_le_bytes());
+        data.extend_from_slice(&self.clearing_price.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for Auction {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let payment_mint: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let proceeds_account: [u8; PUBKEY_BYTES] = data[2 * PUBKEY_BYTES..3 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let mut offset = 3 * PUBKEY_BYTES;
+        let mut read_u64 = || -> Result<u64, ProgramError> {
+            let value = u64::from_le_bytes(
+                data[offset..offset + 8]
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidAccountData)?,
+            );
+            offset += 8;
+            Ok(value)
+        };
+        let auction_id = read_u64()?;
+        let start_price = read_u64()?;
+        let floor_price = read_u64()?;
+        let start_timestamp = read_u64()? as i64;
+        let end_timestamp = read_u64()? as i64;
+        let supply = read_u64()?;
+        let total_bid_quantity = read_u64()?;
+        let last_bid_price = read_u64()?;
+        let clearing_price = read_u64()?;
+
+        Ok(Self {
+            mint,
+            payment_mint,
+            proceeds_account,
+            auction_id,
+            start_price,
+            floor_price,
+            start_timestamp,
+            end_timestamp,
+            supply,
+            total_bid_quantity,
+            last_bid_price,
+            clearing_price,
+            bump: data[Self::LEN - 2],
+        })
+    }
+}
+
+impl ProgramAccount for Auction {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl Auction {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 3 pubkeys (96 bytes) + auction_id, start_price, floor_price,
+    /// start_timestamp, end_timestamp, supply, total_bid_quantity, last_bid_price, clearing_price (9 * 8 bytes)
+    /// + bump (1 byte)
+    pub const LEN: usize = 1 + (3 * PUBKEY_BYTES) + (9 * 8) + 1;
+
+    /// Create a new Auction
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mint: Pubkey,
+        payment_mint: Pubkey,
+        proceeds_account: Pubkey,
+        auction_id: u64,
+        start_price: u64,
+        floor_price: u64,
+        start_timestamp: i64,
+        end_timestamp: i64,
+        supply: u64,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        if floor_price == 0
+            || start_price < floor_price
+            || end_timestamp <= start_timestamp
+            || supply == 0
+            || mint == payment_mint
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(Self {
+            mint,
+            payment_mint,
+            proceeds_account,
+            auction_id,
+            start_price,
+            floor_price,
+            start_timestamp,
+            end_timestamp,
+            supply,
+            total_bid_quantity: 0,
+            last_bid_price: 0,
+            clearing_price: 0,
+            bump,
+        })
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Auction, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Price per whole token at `timestamp` on the descending schedule
+    pub fn current_price(&self, timestamp: i64) -> u64 {
+        if timestamp <= self.start_timestamp {
+            return self.start_price;
+        }
+        if timestamp >= self.end_timestamp {
+            return self.floor_price;
+        }
+        let elapsed = (timestamp - self.start_timestamp) as u128;
+        let duration = (self.end_timestamp - self.start_timestamp) as u128;
+        let decrease = u128::from(self.start_price - self.floor_price) * elapsed / duration;
+        self.start_price - decrease as u64
+    }
+
+    pub fn is_sold_out(&self) -> bool {
+        self.total_bid_quantity >= self.supply
+    }
+
+    pub fn is_cleared(&self) -> bool {
+        self.clearing_price != 0
+    }
+
+    /// Whether bids are accepted at `timestamp`
+    pub fn is_open(&self, timestamp: i64) -> bool {
+        timestamp >= self.start_timestamp
+            && timestamp < self.end_timestamp
+            && !self.is_sold_out()
+            && !self.is_cleared()
+    }
+
+    /// Whether the auction can be cleared at `timestamp`
+    pub fn is_clearable(&self, timestamp: i64) -> bool {
+        !self.is_cleared() && (self.is_sold_out() || timestamp >= self.end_timestamp)
+    }
+
+    /// Fix the clearing price at the price of the last, lowest bid
+    pub fn clear(&mut self) {
+        self.clearing_price = if self.last_bid_price == 0 {
+            self.floor_price
+        } else {
+            self.last_bid_price
+        };
+    }
+
+    /// Payment in payment mint base units for `quantity` token base units at `price`. Rounded up.
+    pub fn payment_amount(
+        &self,
+        quantity: u64,
+        price: u64,
+        decimals: u8,
+    ) -> Result<u64, ProgramError> {
+        payment_at_price(quantity, price, decimals)
+    }
+
+    pub fn auction_id_seed(&self) -> [u8; 8] {
+        self.auction_id.to_le_bytes()
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        auction_id_seed: &'a [u8; 8],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 4] {
+        [
+            Seed::from(AUCTION_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(auction_id_seed.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                AUCTION_ACCOUNT,
+                &self.mint,
+                &self.auction_id_seed(),
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+/// Bid escrowed in an auction payment vault at the price of the moment it was placed
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct AuctionBid {
+    /// Auction the bid is placed in
+    pub auction: Pubkey,
+    /// Bidder wallet, receives the tokens, the refund and the rent
+    pub bidder: Pubkey,
+    /// Security token base units bid for
+    pub quantity: u64,
+    /// Price per whole token when the bid was placed
+    pub bid_price: u64,
+    /// Payment tokens held in the payment vault for the bid
+    pub escrow_amount: u64,
+    /// Security token base units bid for by earlier bids, fills are first come first served
+    pub filled_before: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for AuctionBid {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::AuctionBidDiscriminator as u8;
+}
+
+impl AccountSerialize for AuctionBid {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.auction.as_ref());
+        data.extend_from_slice(self.bidder.as_ref());
+        data.extend_from_slice(&self.quantity.to_le_bytes());
+        data.extend_from_slice(&self.bid_price.to_le_bytes());
+        data.extend_from_slice(&self.escrow_amount.to_le_bytes());
+        data.extend_from_slice(&self.filled_before.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for AuctionBid {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let auction: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let bidder: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = 2 * PUBKEY_BYTES;
+        let quantity = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let bid_price = u64::from_le_bytes(
+            data[offset + 8..offset + 16]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let escrow_amount = u64::from_le_bytes(
+            data[offset + 16..offset + 24]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let filled_before = u64::from_le_bytes(
+            data[offset + 24..offset + 32]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            auction,
+            bidder,
+            quantity,
+            bid_price,
+            escrow_amount,
+            filled_before,
+            bump: data[offset + 32],
+        })
+    }
+}
+
+impl ProgramAccount for AuctionBid {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl AuctionBid {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 2 pubkeys (64 bytes) + quantity (8 bytes) + bid_price (8 bytes)
+    /// + escrow_amount (8 bytes) + filled_before (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 8 + 8 + 8 + 8 + 1;
+
+    /// Create a new AuctionBid
+    pub fn new(
+        auction: Pubkey,
+        bidder: Pubkey,
+        quantity: u64,
+        bid_price: u64,
+        escrow_amount: u64,
+        filled_before: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            auction,
+            bidder,
+            quantity,
+            bid_price,
+            escrow_amount,
+            filled_before,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<AuctionBid, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Security token base units filled out of `supply`
+    pub fn allocation(&self, supply: u64) -> u64 {
+        self.quantity.min(supply.saturating_sub(self.filled_before))
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 4] {
+        [
+            Seed::from(AUCTION_BID_ACCOUNT),
+            Seed::from(self.auction.as_ref()),
+            Seed::from(self.bidder.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                AUCTION_BID_ACCOUNT,
+                &self.auction,
+                &self.bidder,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auction() -> Auction {
+        // Price falls from 10 to 5 payment units (6 decimals) between 1_000 and 2_000
+        Auction::new(
+            [1u8; 32],
+            [2u8; 32],
+            [3u8; 32],
+            1,
+            10_000_000,
+            5_000_000,
+            1_000,
+            2_000,
+            100_000_000,
+            255,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_auction_serialization_round_trip() {
+        let mut auction = auction();
+        auction.total_bid_quantity = 60_000_000;
+        auction.last_bid_price = 7_500_000;
+        auction.clearing_price = 7_500_000;
+
+        let bytes = auction.to_bytes();
+        assert_eq!(bytes.len(), Auction::LEN);
+
+        let deserialized = Auction::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, auction.mint);
+        assert_eq!(deserialized.payment_mint, auction.payment_mint);
+        assert_eq!(deserialized.proceeds_account, auction.proceeds_account);
+        assert_eq!(deserialized.auction_id, auction.auction_id);
+        assert_eq!(deserialized.start_price, auction.start_price);
+        assert_eq!(deserialized.floor_price, auction.floor_price);
+        assert_eq!(deserialized.start_timestamp, auction.start_timestamp);
+        assert_eq!(deserialized.end_timestamp, auction.end_timestamp);
+        assert_eq!(deserialized.supply, auction.supply);
+        assert_eq!(deserialized.total_bid_quantity, auction.total_bid_quantity);
+        assert_eq!(deserialized.last_bid_price, auction.last_bid_price);
+        assert_eq!(deserialized.clearing_price, auction.clearing_price);
+        assert_eq!(deserialized.bump, auction.bump);
+    }
+
+    #[test]
+    fn test_auction_rejects_invalid_arguments() {
+        let new = |start_price, floor_price, end_timestamp, supply| {
+            Auction::new(
+                [1u8; 32],
+                [2u8; 32],
+                [3u8; 32],
+                1,
+                start_price,
+                floor_price,
+                1_000,
+                end_timestamp,
+                supply,
+                255,
+            )
+        };
+        assert!(new(10, 0, 2_000, 1).is_err());
+        assert!(new(4, 5, 2_000, 1).is_err());
+        assert!(new(10, 5, 1_000, 1).is_err());
+        assert!(new(10, 5, 2_000, 0).is_err());
+        assert!(new(5, 5, 2_000, 1).is_ok());
+    }
+
+    #[test]
+    fn test_auction_price_schedule() {
+        let auction = auction();
+
+        assert_eq!(auction.current_price(0), 10_000_000);
+        assert_eq!(auction.current_price(1_000), 10_000_000);
+        assert_eq!(auction.current_price(1_500), 7_500_000);
+        assert_eq!(auction.current_price(1_999), 5_005_000);
+        assert_eq!(auction.current_price(2_000), 5_000_000);
+        assert_eq!(auction.current_price(i64::MAX), 5_000_000);
+    }
+
+    #[test]
+    fn test_auction_lifecycle() {
+        let mut auction = auction();
+
+        assert!(!auction.is_open(999));
+        assert!(auction.is_open(1_000));
+        assert!(!auction.is_clearable(1_500));
+        assert!(auction.is_clearable(2_000));
+
+        auction.total_bid_quantity = auction.supply;
+        auction.last_bid_price = 7_500_000;
+        assert!(!auction.is_open(1_500));
+        assert!(auction.is_clearable(1_500));
+
+        auction.clear();
+        assert_eq!(auction.clearing_price, 7_500_000);
+        assert!(!auction.is_clearable(2_000));
+    }
+
+    #[test]
+    fn test_auction_bid_allocation_is_first_come_first_served() {
+        let bid = |quantity, filled_before| {
+            AuctionBid::new([1u8; 32], [2u8; 32], quantity, 1, 1, filled_before, 255)
+        };
+
+        assert_eq!(bid(40, 0).allocation(100), 40);
+        assert_eq!(bid(40, 80).allocation(100), 20);
+        assert_eq!(bid(40, 100).allocation(100), 0);
+    }
+
+    #[test]
+    fn test_auction_bid_serialization_round_trip() {
+        let bid = AuctionBid::new([1u8; 32], [2u8; 32], 40, 7_500_000, 300, 60, 254);
+
+        let bytes = bid.to_bytes();
+        assert_eq!(bytes.len(), AuctionBid::LEN);
+
+        let deserialized = AuctionBid::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.auction, bid.auction);
+        assert_eq!(deserialized.bidder, bid.bidder);
+        assert_eq!(deserialized.quantity, bid.quantity);
+        assert_eq!(deserialized.bid_price, bid.bid_price);
+        assert_eq!(deserialized.escrow_amount, bid.escrow_amount);
+        assert_eq!(deserialized.filled_before, bid.filled_before);
+        assert_eq!(deserialized.bump, bid.bump);
+    }
+}
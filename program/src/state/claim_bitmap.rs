@@ -0,0 +1,330 @@
+//! Claim bitmap account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use pinocchio::ProgramResult;
+use shank::ShankAccount;
+
+use crate::constants::seeds::CLAIM_BITMAP_ACCOUNT;
+use crate::error::SecurityTokenError;
+use crate::merkle_tree_utils::{MerkleTreeRoot, MERKLE_ROOT_LEN};
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Page of claimed flags of a distribution, indexed by merkle leaf index.
+/// Replaces the per-claim Receipt of distributions created with claim bitmaps:
+/// page `n` tracks the leaves `n * PAGE_LEAVES..(n + 1) * PAGE_LEAVES`.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct ClaimBitmap {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Action ID of the distribution
+    pub action_id: u64,
+    /// Merkle root of the distribution
+    pub merkle_root: MerkleTreeRoot,
+    /// Page number
+    pub page: u32,
+    /// Number of leaves tracked by this page
+    pub leaf_count: u32,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+    /// Claimed flags, bit `i % 8` of byte `i / 8` is set once leaf `i` of the page is claimed
+    pub bits: Vec<u8>,
+}
+
+impl Discriminator for ClaimBitmap {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::ClaimBitmapDiscriminator as u8;
+}
+
+impl AccountSerialize for ClaimBitmap {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::HEADER_LEN - 1 + self.bits.len());
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(&self.action_id.to_le_bytes());
+        data.extend_from_slice(self.merkle_root.as_ref());
+        data.extend_from_slice(&self.page.to_le_bytes());
+        data.extend_from_slice(&self.leaf_count.to_le_bytes());
+        data.push(self.bump);
+        data.extend_from_slice(&(self.bits.len() as u32).to_le_bytes());
+        data.extend_from_slice(&self.bits);
+
+        data
+    }
+}
+
+impl AccountDeserialize for ClaimBitmap {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::HEADER_LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let mut offset = PUBKEY_BYTES;
+        let action_id = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let merkle_root: MerkleTreeRoot = data[offset..offset + MERKLE_ROOT_LEN]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        offset += MERKLE_ROOT_LEN;
+        let page = u32::from_le_bytes(
+            data[offset..offset + 4]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 4;
+        let leaf_count = u32::from_le_bytes(
+            data[offset..offset + 4]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 4;
+        let bump = data[offset];
+        offset += 1;
+        let bits_len = u32::from_le_bytes(
+            data[offset..offset + 4]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        ) as usize;
+        offset += 4;
+
+        if bits_len != Self::bits_len(leaf_count) || data.len() - offset != bits_len {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            mint,
+            action_id,
+            merkle_root,
+            page,
+            leaf_count,
+            bump,
+            bits: data[offset..].to_vec(),
+        })
+    }
+}
+
+impl ProgramAccount for ClaimBitmap {
+    fn space(&self) -> u64 {
+        Self::size_of(self.leaf_count) as u64
+    }
+}
+
+impl ClaimBitmap {
+    /// Serialized size without the claimed flags
+    /// Discriminator (1 byte) + mint (32 bytes) + action_id (8 bytes) + merkle_root (32 bytes)
+    /// + page (4 bytes) + leaf_count (4 bytes) + bump (1 byte) + vector length (4 bytes)
+    pub const HEADER_LEN: usize = 1 + PUBKEY_BYTES + 8 + MERKLE_ROOT_LEN + 4 + 4 + 1 + 4;
+
+    /// Leaves tracked by a full page, keeps the account below the 10 KiB CPI allocation limit
+    pub const PAGE_LEAVES: u32 = 65_536;
+
+    /// Create a new ClaimBitmap page with no leaf claimed
+    pub fn new(
+        mint: Pubkey,
+        action_id: u64,
+        merkle_root: MerkleTreeRoot,
+        page: u32,
+        leaf_count: u32,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        if leaf_count == 0 || leaf_count > Self::PAGE_LEAVES {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(Self {
+            mint,
+            action_id,
+            merkle_root,
+            page,
+            leaf_count,
+            bump,
+            bits: vec![0; Self::bits_len(leaf_count)],
+        })
+    }
+
+    /// Bytes of claimed flags of a page tracking `leaf_count` leaves
+    pub fn bits_len(leaf_count: u32) -> usize {
+        leaf_count.div_ceil(8) as usize
+    }
+
+    /// Serialized size of a page tracking `leaf_count` leaves
+    pub fn size_of(leaf_count: u32) -> usize {
+        Self::HEADER_LEN + Self::bits_len(leaf_count)
+    }
+
+    /// Number of pages tracking `total_leaves` leaves
+    pub fn page_count(total_leaves: u32) -> u32 {
+        total_leaves.div_ceil(Self::PAGE_LEAVES)
+    }
+
+    /// Number of leaves tracked by `page` of a distribution with `total_leaves` leaves
+    pub fn page_leaf_count(total_leaves: u32, page: u32) -> u32 {
+        total_leaves
+            .saturating_sub(page.saturating_mul(Self::PAGE_LEAVES))
+            .min(Self::PAGE_LEAVES)
+    }
+
+    /// Page tracking the leaf at `leaf_index`
+    pub fn page_of(leaf_index: u32) -> u32 {
+        leaf_index / Self::PAGE_LEAVES
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<ClaimBitmap, ProgramError> {
+        if account_info.data_len() < Self::HEADER_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Whether the leaf at `leaf_index` of the distribution is claimed
+    pub fn is_claimed(&self, leaf_index: u32) -> Result<bool, ProgramError> {
+        let (byte, mask) = self.bit_position(leaf_index)?;
+        Ok(self.bits[byte] & mask != 0)
+    }
+
+    /// Mark the leaf at `leaf_index` of the distribution as claimed.
+    /// Fails with `LeafAlreadyClaimed` when it was claimed before.
+    pub fn set_claimed(&mut self, leaf_index: u32) -> ProgramResult {
+        let (byte, mask) = self.bit_position(leaf_index)?;
+        if self.bits[byte] & mask != 0 {
+            return Err(SecurityTokenError::LeafAlreadyClaimed.into());
+        }
+        self.bits[byte] |= mask;
+        Ok(())
+    }
+
+    fn bit_position(&self, leaf_index: u32) -> Result<(usize, u8), ProgramError> {
+        if Self::page_of(leaf_index) != self.page {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let page_leaf = leaf_index % Self::PAGE_LEAVES;
+        if page_leaf >= self.leaf_count {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(((page_leaf / 8) as usize, 1 << (page_leaf % 8)))
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn action_id_seed(&self) -> [u8; 8] {
+        self.action_id.to_le_bytes()
+    }
+
+    pub fn page_seed(&self) -> [u8; 4] {
+        self.page.to_le_bytes()
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        action_id_seed: &'a [u8; 8],
+        page_seed: &'a [u8; 4],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 6] {
+        [
+            Seed::from(CLAIM_BITMAP_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(action_id_seed.as_ref()),
+            Seed::from(self.merkle_root.as_ref()),
+            Seed::from(page_seed.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                CLAIM_BITMAP_ACCOUNT,
+                &self.mint,
+                &self.action_id_seed(),
+                &self.merkle_root,
+                &self.page_seed(),
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_claim_bitmap_serialization_round_trip() {
+        let mut claim_bitmap = ClaimBitmap::new([1u8; 32], 7, [2u8; 32], 1, 20, 253).unwrap();
+        claim_bitmap
+            .set_claimed(ClaimBitmap::PAGE_LEAVES + 9)
+            .unwrap();
+
+        let bytes = claim_bitmap.to_bytes();
+        assert_eq!(bytes.len(), ClaimBitmap::size_of(20));
+        assert_eq!(bytes[0], ClaimBitmap::DISCRIMINATOR);
+
+        let deserialized = ClaimBitmap::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, claim_bitmap.mint);
+        assert_eq!(deserialized.action_id, claim_bitmap.action_id);
+        assert_eq!(deserialized.merkle_root, claim_bitmap.merkle_root);
+        assert_eq!(deserialized.page, claim_bitmap.page);
+        assert_eq!(deserialized.leaf_count, claim_bitmap.leaf_count);
+        assert_eq!(deserialized.bump, claim_bitmap.bump);
+        assert_eq!(deserialized.bits, vec![0, 0b10, 0]);
+        assert!(ClaimBitmap::try_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_claim_bitmap_set_claimed_once() {
+        let mut claim_bitmap = ClaimBitmap::new([1u8; 32], 7, [2u8; 32], 0, 10, 253).unwrap();
+
+        assert_eq!(claim_bitmap.is_claimed(3), Ok(false));
+        assert_eq!(claim_bitmap.set_claimed(3), Ok(()));
+        assert_eq!(claim_bitmap.is_claimed(3), Ok(true));
+        assert_eq!(claim_bitmap.is_claimed(4), Ok(false));
+        assert_eq!(
+            claim_bitmap.set_claimed(3),
+            Err(SecurityTokenError::LeafAlreadyClaimed.into())
+        );
+        // Leaves beyond the page or its leaf count are rejected
+        assert!(claim_bitmap.set_claimed(10).is_err());
+        assert!(claim_bitmap.set_claimed(ClaimBitmap::PAGE_LEAVES).is_err());
+    }
+
+    #[rstest]
+    #[case(1, 1, &[1])]
+    #[case(65_536, 1, &[65_536])]
+    #[case(100_000, 2, &[65_536, 34_464])]
+    fn test_claim_bitmap_pages(
+        #[case] total_leaves: u32,
+        #[case] page_count: u32,
+        #[case] page_leaf_counts: &[u32],
+    ) {
+        assert_eq!(ClaimBitmap::page_count(total_leaves), page_count);
+        for (page, leaf_count) in page_leaf_counts.iter().enumerate() {
+            assert_eq!(
+                ClaimBitmap::page_leaf_count(total_leaves, page as u32),
+                *leaf_count
+            );
+        }
+        assert!(ClaimBitmap::size_of(ClaimBitmap::PAGE_LEAVES) <= 10_240);
+    }
+}
@@ -0,0 +1,253 @@
+//! Claim deadline account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::CLAIM_DEADLINE_ACCOUNT;
+use crate::error::SecurityTokenError;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Claim window of a distribution. ClaimDistribution fails before `claims_open_at` and once
+/// `claim_deadline` passes, from then on ReclaimUnclaimed returns the unclaimed remainder to
+/// the issuer. Until the window opens the merkle root can still be replaced.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct ClaimDeadline {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Distribution action identifier
+    pub action_id: u64,
+    /// Unix timestamp from which the distribution can be claimed
+    pub claims_open_at: i64,
+    /// Unix timestamp after which the distribution can no longer be claimed
+    pub claim_deadline: i64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for ClaimDeadline {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::ClaimDeadlineDiscriminator as u8;
+}
+
+impl AccountSerialize for ClaimDeadline {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(&self.action_id.to_le_bytes());
+        data.extend_from_slice(&self.claims_open_at.to_le_bytes());
+        data.extend_from_slice(&self.claim_deadline.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for ClaimDeadline {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let action_id = u64::from_le_bytes(
+            data[PUBKEY_BYTES..PUBKEY_BYTES + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let claims_open_at = i64::from_le_bytes(
+            data[PUBKEY_BYTES + 8..PUBKEY_BYTES + 16]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let claim_deadline = i64::from_le_bytes(
+            data[PUBKEY_BYTES + 16..PUBKEY_BYTES + 24]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        let claim_deadline = Self {
+            mint,
+            action_id,
+            claims_open_at,
+            claim_deadline,
+            bump: data[PUBKEY_BYTES + 24],
+        };
+        claim_deadline.validate()?;
+        Ok(claim_deadline)
+    }
+}
+
+impl ProgramAccount for ClaimDeadline {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl ClaimDeadline {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + mint (32 bytes) + action_id (8 bytes) + claims_open_at (8 bytes)
+    /// + claim_deadline (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + 8 + 8 + 8 + 1;
+
+    /// Create a new ClaimDeadline
+    pub fn new(
+        mint: Pubkey,
+        action_id: u64,
+        claims_open_at: i64,
+        claim_deadline: i64,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        let claim_deadline = Self {
+            mint,
+            action_id,
+            claims_open_at,
+            claim_deadline,
+            bump,
+        };
+        claim_deadline.validate()?;
+        Ok(claim_deadline)
+    }
+
+    /// Validate the ClaimDeadline account data
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        if self.claims_open_at >= self.claim_deadline {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<ClaimDeadline, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Check the distribution can be claimed at `timestamp`
+    pub fn verify_claimable(&self, timestamp: i64) -> Result<(), ProgramError> {
+        if timestamp < self.claims_open_at {
+            return Err(SecurityTokenError::ClaimWindowNotOpen.into());
+        }
+        if timestamp > self.claim_deadline {
+            return Err(SecurityTokenError::ClaimDeadlinePassed.into());
+        }
+        Ok(())
+    }
+
+    /// Check the claim window closed at `timestamp`
+    pub fn verify_passed(&self, timestamp: i64) -> Result<(), ProgramError> {
+        if timestamp <= self.claim_deadline {
+            return Err(SecurityTokenError::ClaimWindowOpen.into());
+        }
+        Ok(())
+    }
+
+    /// Check the claim window has not opened at `timestamp`
+    pub fn verify_not_opened(&self, timestamp: i64) -> Result<(), ProgramError> {
+        if timestamp >= self.claims_open_at {
+            return Err(SecurityTokenError::ClaimWindowStarted.into());
+        }
+        Ok(())
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn action_id_seed(&self) -> [u8; 8] {
+        self.action_id.to_le_bytes()
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        action_id_seed: &'a [u8; 8],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 4] {
+        [
+            Seed::from(CLAIM_DEADLINE_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(action_id_seed.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                CLAIM_DEADLINE_ACCOUNT,
+                &self.mint,
+                &self.action_id_seed(),
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_claim_deadline_serialization_round_trip() {
+        let claim_deadline =
+            ClaimDeadline::new([1u8; 32], 42, 1_699_000_000, 1_700_000_000, 253).unwrap();
+
+        let bytes = claim_deadline.to_bytes();
+        assert_eq!(bytes.len(), ClaimDeadline::LEN);
+        assert_eq!(bytes[0], ClaimDeadline::DISCRIMINATOR);
+
+        let deserialized = ClaimDeadline::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, claim_deadline.mint);
+        assert_eq!(deserialized.action_id, claim_deadline.action_id);
+        assert_eq!(deserialized.claims_open_at, claim_deadline.claims_open_at);
+        assert_eq!(deserialized.claim_deadline, claim_deadline.claim_deadline);
+        assert_eq!(deserialized.bump, claim_deadline.bump);
+    }
+
+    #[test]
+    fn test_claim_deadline_rejects_empty_window() {
+        assert!(ClaimDeadline::new([1u8; 32], 42, 1_000, 1_000, 253).is_err());
+        assert!(ClaimDeadline::new([1u8; 32], 42, 1_001, 1_000, 253).is_err());
+    }
+
+    #[rstest]
+    #[case(499, false, false, true)]
+    #[case(500, true, false, false)]
+    #[case(1_000, true, false, false)]
+    #[case(1_001, false, true, false)]
+    fn test_claim_window(
+        #[case] timestamp: i64,
+        #[case] claimable: bool,
+        #[case] passed: bool,
+        #[case] not_opened: bool,
+    ) {
+        let claim_deadline = ClaimDeadline::new([1u8; 32], 42, 500, 1_000, 253).unwrap();
+        assert_eq!(
+            claim_deadline.verify_claimable(timestamp).is_ok(),
+            claimable
+        );
+        assert_eq!(claim_deadline.verify_passed(timestamp).is_ok(), passed);
+        assert_eq!(
+            claim_deadline.verify_not_opened(timestamp).is_ok(),
+            not_opened
+        );
+    }
+}
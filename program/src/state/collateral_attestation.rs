@@ -0,0 +1,253 @@
+//! Collateral attestation state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::COLLATERAL_ATTESTATION_ACCOUNT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Balance of a token account that can be pledged as collateral, attested at `slot` by
+/// AttestCollateral. Lending programs invoke AttestCollateral and read this account, or the
+/// same bytes from the return data, instead of re-implementing the transfer restrictions.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct CollateralAttestation {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Attested token account
+    pub token_account: Pubkey,
+    /// Owner of the token account
+    pub owner: Pubkey,
+    /// Token account balance
+    pub balance: u64,
+    /// Amount of the restricted lots still in their holding period
+    pub locked_amount: u64,
+    /// Balance the holder can transfer: `0` when frozen, suspended or paused, the unlocked
+    /// balance otherwise
+    pub free_balance: u64,
+    /// Token account is frozen
+    pub frozen: bool,
+    /// Token account is suspended
+    pub suspended: bool,
+    /// Mint is paused
+    pub paused: bool,
+    /// Slot of the attestation
+    pub slot: u64,
+    /// Unix timestamp of the attestation
+    pub unix_timestamp: i64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for CollateralAttestation {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::CollateralAttestationDiscriminator as u8;
+}
+
+impl AccountSerialize for CollateralAttestation {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.token_account.as_ref());
+        data.extend_from_slice(self.owner.as_ref());
+        data.extend_from_slice(&self.balance.to_le_bytes());
+        data.extend_from_slice(&self.locked_amount.to_le_bytes());
+        data.extend_from_slice(&self.free_balance.to_le_bytes());
+        data.push(self.frozen as u8);
+        data.push(self.suspended as u8);
+        data.push(self.paused as u8);
+        data.extend_from_slice(&self.slot.to_le_bytes());
+        data.extend_from_slice(&self.unix_timestamp.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for CollateralAttestation {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let pubkey_at = |offset: usize| -> Result<Pubkey, ProgramError> {
+            data[offset..offset + PUBKEY_BYTES]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)
+        };
+        let u64_at = |offset: usize| -> Result<u64, ProgramError> {
+            data[offset..offset + 8]
+                .try_into()
+                .map(u64::from_le_bytes)
+                .map_err(|_| ProgramError::InvalidAccountData)
+        };
+
+        let offset = 3 * PUBKEY_BYTES;
+        let flags = offset + 24;
+        Ok(Self {
+            mint: pubkey_at(0)?,
+            token_account: pubkey_at(PUBKEY_BYTES)?,
+            owner: pubkey_at(2 * PUBKEY_BYTES)?,
+            balance: u64_at(offset)?,
+            locked_amount: u64_at(offset + 8)?,
+            free_balance: u64_at(offset + 16)?,
+            frozen: data[flags] != 0,
+            suspended: data[flags + 1] != 0,
+            paused: data[flags + 2] != 0,
+            slot: u64_at(flags + 3)?,
+            unix_timestamp: u64_at(flags + 11)? as i64,
+            bump: data[flags + 19],
+        })
+    }
+}
+
+impl ProgramAccount for CollateralAttestation {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl CollateralAttestation {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 3 pubkeys (96 bytes) + 3 amounts (24 bytes) + 3 flags (3 bytes)
+    /// + slot (8 bytes) + unix_timestamp (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (3 * PUBKEY_BYTES) + 24 + 3 + 8 + 8 + 1;
+
+    /// Attest the state of a token account, deriving the free balance
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mint: Pubkey,
+        token_account: Pubkey,
+        owner: Pubkey,
+        balance: u64,
+        locked_amount: u64,
+        frozen: bool,
+        suspended: bool,
+        paused: bool,
+        slot: u64,
+        unix_timestamp: i64,
+        bump: u8,
+    ) -> Self {
+        let free_balance = if frozen || suspended || paused {
+            0
+        } else {
+            balance.saturating_sub(locked_amount)
+        };
+        Self {
+            mint,
+            token_account,
+            owner,
+            balance,
+            locked_amount,
+            free_balance,
+            frozen,
+            suspended,
+            paused,
+            slot,
+            unix_timestamp,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<CollateralAttestation, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(COLLATERAL_ATTESTATION_ACCOUNT),
+            Seed::from(self.token_account.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                COLLATERAL_ATTESTATION_ACCOUNT,
+                &self.token_account,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attestation(frozen: bool, suspended: bool, paused: bool) -> CollateralAttestation {
+        CollateralAttestation::new(
+            [1u8; 32],
+            [2u8; 32],
+            [3u8; 32],
+            1_000,
+            400,
+            frozen,
+            suspended,
+            paused,
+            77,
+            1_700_000_000,
+            254,
+        )
+    }
+
+    #[test]
+    fn test_collateral_attestation_serialization_round_trip() {
+        let attestation = attestation(false, true, false);
+
+        let bytes = attestation.to_bytes();
+        assert_eq!(bytes.len(), CollateralAttestation::LEN);
+        assert_eq!(bytes[0], CollateralAttestation::DISCRIMINATOR);
+
+        let deserialized = CollateralAttestation::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, attestation.mint);
+        assert_eq!(deserialized.token_account, attestation.token_account);
+        assert_eq!(deserialized.owner, attestation.owner);
+        assert_eq!(deserialized.balance, attestation.balance);
+        assert_eq!(deserialized.locked_amount, attestation.locked_amount);
+        assert_eq!(deserialized.free_balance, attestation.free_balance);
+        assert!(!deserialized.frozen);
+        assert!(deserialized.suspended);
+        assert!(!deserialized.paused);
+        assert_eq!(deserialized.slot, attestation.slot);
+        assert_eq!(deserialized.unix_timestamp, attestation.unix_timestamp);
+        assert_eq!(deserialized.bump, attestation.bump);
+    }
+
+    #[test]
+    fn test_collateral_attestation_free_balance() {
+        assert_eq!(attestation(false, false, false).free_balance, 600);
+        assert_eq!(attestation(true, false, false).free_balance, 0);
+        assert_eq!(attestation(false, true, false).free_balance, 0);
+        assert_eq!(attestation(false, false, true).free_balance, 0);
+
+        let over_locked = CollateralAttestation::new(
+            [1u8; 32], [2u8; 32], [3u8; 32], 100, 400, false, false, false, 0, 0, 254,
+        );
+        assert_eq!(over_locked.free_balance, 0);
+    }
+}
@@ -0,0 +1,127 @@
+//! Config admin state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::CONFIG_ADMIN_ACCOUNT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// External key allowed to initialize, update and trim the verification configs of a mint,
+/// e.g. a compliance team managing verification programs apart from the treasury keys.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct ConfigAdmin {
+    /// Mint whose verification configs the admin manages
+    pub mint: Pubkey,
+    /// Config admin signer
+    pub admin: Pubkey,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for ConfigAdmin {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::ConfigAdminDiscriminator as u8;
+}
+
+impl AccountSerialize for ConfigAdmin {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.admin.as_ref());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for ConfigAdmin {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let admin: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let bump = data[2 * PUBKEY_BYTES];
+
+        Ok(Self { mint, admin, bump })
+    }
+}
+
+impl ProgramAccount for ConfigAdmin {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl ConfigAdmin {
+    /// Serialized size of the account data (discriminator + mint + admin + bump)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + PUBKEY_BYTES + 1;
+
+    /// Create a new ConfigAdmin
+    pub fn new(mint: Pubkey, admin: Pubkey, bump: u8) -> Self {
+        Self { mint, admin, bump }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<ConfigAdmin, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(CONFIG_ADMIN_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[CONFIG_ADMIN_ACCOUNT, &self.mint, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_admin_serialization_round_trip() {
+        let config_admin = ConfigAdmin::new([1u8; 32], [2u8; 32], 254);
+
+        let bytes = config_admin.to_bytes();
+        assert_eq!(bytes.len(), ConfigAdmin::LEN);
+
+        let deserialized = ConfigAdmin::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, config_admin.mint);
+        assert_eq!(deserialized.admin, config_admin.admin);
+        assert_eq!(deserialized.bump, config_admin.bump);
+    }
+}
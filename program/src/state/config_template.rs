@@ -0,0 +1,209 @@
+//! Config template account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::CONFIG_TEMPLATE_ACCOUNT;
+use crate::constants::MAX_VERIFICATION_PROGRAMS;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Issuer-level list of verification programs shared by the verification configs linked to it
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct ConfigTemplate {
+    /// Issuer managing the template
+    pub issuer: Pubkey,
+    /// Template identifier, unique per issuer
+    pub template_id: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+    /// Verification programs of the linked configs that don't override them
+    pub verification_programs: Vec<Pubkey>,
+}
+
+impl Discriminator for ConfigTemplate {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::ConfigTemplateDiscriminator as u8;
+}
+
+impl AccountSerialize for ConfigTemplate {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::size_of(self.verification_programs.len()) - 1);
+
+        data.extend_from_slice(self.issuer.as_ref());
+        data.extend_from_slice(&self.template_id.to_le_bytes());
+        data.push(self.bump);
+        data.extend_from_slice(&(self.verification_programs.len() as u32).to_le_bytes());
+        for program in &self.verification_programs {
+            data.extend_from_slice(program.as_ref());
+        }
+
+        data
+    }
+}
+
+impl AccountDeserialize for ConfigTemplate {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::MIN_LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let issuer: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let mut offset = PUBKEY_BYTES;
+        let template_id = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let bump = data[offset];
+        offset += 1;
+        let program_count = u32::from_le_bytes(
+            data[offset..offset + 4]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        ) as usize;
+        offset += 4;
+
+        if data.len() - offset != program_count * PUBKEY_BYTES {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let verification_programs = data[offset..]
+            .chunks_exact(PUBKEY_BYTES)
+            .map(|chunk| {
+                chunk
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidAccountData)
+            })
+            .collect::<Result<Vec<Pubkey>, ProgramError>>()?;
+
+        Ok(Self {
+            issuer,
+            template_id,
+            bump,
+            verification_programs,
+        })
+    }
+}
+
+impl ProgramAccount for ConfigTemplate {
+    fn space(&self) -> u64 {
+        Self::size_of(self.verification_programs.len()) as u64
+    }
+}
+
+impl ConfigTemplate {
+    /// Serialized size without programs
+    /// Discriminator (1 byte) + issuer (32 bytes) + template_id (8 bytes) + bump (1 byte) + vector length (4 bytes)
+    pub const MIN_LEN: usize = 1 + PUBKEY_BYTES + 8 + 1 + 4;
+
+    /// Create a new ConfigTemplate, rejecting an empty, oversized or zeroed program list
+    pub fn new(
+        issuer: Pubkey,
+        template_id: u64,
+        verification_programs: &[Pubkey],
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        if verification_programs.is_empty()
+            || verification_programs.len() > MAX_VERIFICATION_PROGRAMS
+            || verification_programs.contains(&Pubkey::default())
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(Self {
+            issuer,
+            template_id,
+            bump,
+            verification_programs: verification_programs.to_vec(),
+        })
+    }
+
+    /// Serialized size of a template with `program_count` programs
+    pub fn size_of(program_count: usize) -> usize {
+        Self::MIN_LEN + program_count * PUBKEY_BYTES
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<ConfigTemplate, ProgramError> {
+        if account_info.data_len() < Self::MIN_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn template_id_seed(&self) -> [u8; 8] {
+        self.template_id.to_le_bytes()
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        template_id_seed: &'a [u8; 8],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 4] {
+        [
+            Seed::from(CONFIG_TEMPLATE_ACCOUNT),
+            Seed::from(self.issuer.as_ref()),
+            Seed::from(template_id_seed.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                CONFIG_TEMPLATE_ACCOUNT,
+                &self.issuer,
+                &self.template_id_seed(),
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_template_serialization_round_trip() {
+        let programs = [[2u8; 32], [3u8; 32]];
+        let template = ConfigTemplate::new([1u8; 32], 7, &programs, 253).unwrap();
+
+        let bytes = template.to_bytes();
+        assert_eq!(bytes.len(), ConfigTemplate::size_of(programs.len()));
+        assert_eq!(bytes[0], ConfigTemplate::DISCRIMINATOR);
+
+        let deserialized = ConfigTemplate::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.issuer, [1u8; 32]);
+        assert_eq!(deserialized.template_id, 7);
+        assert_eq!(deserialized.bump, 253);
+        assert_eq!(deserialized.verification_programs, programs);
+    }
+
+    #[test]
+    fn test_config_template_rejects_invalid_programs() {
+        assert!(ConfigTemplate::new([1u8; 32], 7, &[], 253).is_err());
+        assert!(ConfigTemplate::new([1u8; 32], 7, &[Pubkey::default()], 253).is_err());
+        let too_many = vec![[2u8; 32]; MAX_VERIFICATION_PROGRAMS + 1];
+        assert!(ConfigTemplate::new([1u8; 32], 7, &too_many, 253).is_err());
+    }
+}
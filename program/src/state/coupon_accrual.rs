@@ -0,0 +1,489 @@
+//! Coupon accrual state for debt tokens
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::{ShankAccount, ShankType};
+
+use crate::constants::seeds::{ACCRUAL_CONFIG_ACCOUNT, COUPON_CLAIM_ACCOUNT};
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Seconds in a day, used to express day-count year lengths in seconds
+const SECONDS_PER_DAY: u128 = 86_400;
+/// Basis points denominator of the coupon rate
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Day-count convention used to turn a coupon period into a fraction of a year
+#[repr(u8)]
+#[derive(Clone, Debug, PartialEq, Eq, Copy, ShankType)]
+pub enum DayCountConvention {
+    /// Actual elapsed days over a 365 day year
+    Actual365 = 0,
+    /// Actual elapsed days over a 360 day year
+    Actual360 = 1,
+}
+
+impl DayCountConvention {
+    /// Number of days in the year of the convention
+    pub fn days_in_year(&self) -> u128 {
+        match self {
+            DayCountConvention::Actual365 => 365,
+            DayCountConvention::Actual360 => 360,
+        }
+    }
+}
+
+impl From<DayCountConvention> for u8 {
+    fn from(day_count: DayCountConvention) -> Self {
+        day_count as u8
+    }
+}
+
+impl TryFrom<u8> for DayCountConvention {
+    type Error = ProgramError;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            0 => Ok(DayCountConvention::Actual365),
+            1 => Ok(DayCountConvention::Actual360),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Coupon terms of a debt token. Coupons accrue per completed period since
+/// `start_timestamp` and are paid in `payout_mint` from the vault owned by this account.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct AccrualConfig {
+    /// Debt token mint
+    pub mint: Pubkey,
+    /// Mint the coupons are paid in
+    pub payout_mint: Pubkey,
+    /// Annual coupon rate in basis points
+    pub rate_bps: u16,
+    /// Day-count convention of the rate
+    pub day_count: DayCountConvention,
+    /// Length of a coupon period in seconds
+    pub period_seconds: u32,
+    /// Unix timestamp the first coupon period starts at
+    pub start_timestamp: i64,
+    /// Principal in payout mint base units per whole debt token
+    pub principal_per_token: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for AccrualConfig {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::AccrualConfigDiscriminator as u8;
+}
+
+impl AccountSerialize for AccrualConfig {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.payout_mint.as_ref());
+        data.extend_from_slice(&self.rate_bps.to_le_bytes());
+        data.push(self.day_count.into());
+        data.extend_from_slice(&self.period_seconds.to_le_bytes());
+        data.extend_from_slice(&self.start_timestamp.to_le_bytes());
+        data.extend_from_slice(&self.principal_per_token.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for AccrualConfig {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let payout_mint: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let mut offset = 2 * PUBKEY_BYTES;
+        let rate_bps = u16::from_le_bytes(
+            data[offset..offset + 2]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 2;
+        let day_count = DayCountConvention::try_from(data[offset])?;
+        offset += 1;
+        let period_seconds = u32::from_le_bytes(
+            data[offset..offset + 4]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 4;
+        let start_timestamp = i64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let principal_per_token = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+
+        Ok(Self {
+            mint,
+            payout_mint,
+            rate_bps,
+            day_count,
+            period_seconds,
+            start_timestamp,
+            principal_per_token,
+            bump: data[offset],
+        })
+    }
+}
+
+impl ProgramAccount for AccrualConfig {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl AccrualConfig {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 2 pubkeys (64 bytes) + rate_bps (2 bytes) + day_count (1 byte)
+    /// + period_seconds (4 bytes) + start_timestamp (8 bytes) + principal_per_token (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 2 + 1 + 4 + 8 + 8 + 1;
+
+    /// Create a new AccrualConfig
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mint: Pubkey,
+        payout_mint: Pubkey,
+        rate_bps: u16,
+        day_count: DayCountConvention,
+        period_seconds: u32,
+        start_timestamp: i64,
+        principal_per_token: u64,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        if rate_bps == 0 || period_seconds == 0 || principal_per_token == 0 || mint == payout_mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(Self {
+            mint,
+            payout_mint,
+            rate_bps,
+            day_count,
+            period_seconds,
+            start_timestamp,
+            principal_per_token,
+            bump,
+        })
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<AccrualConfig, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Number of coupon periods completed at `timestamp`
+    pub fn completed_periods(&self, timestamp: i64) -> u64 {
+        if timestamp <= self.start_timestamp {
+            return 0;
+        }
+        timestamp.abs_diff(self.start_timestamp) / u64::from(self.period_seconds)
+    }
+
+    /// Coupon in payout mint base units owed on `balance` debt token base units for `periods` periods.
+    /// Rounded down.
+    pub fn coupon_amount(
+        &self,
+        balance: u64,
+        periods: u64,
+        decimals: u8,
+    ) -> Result<u64, ProgramError> {
+        let numerator = u128::from(balance)
+            .checked_mul(u128::from(self.principal_per_token))
+            .and_then(|value| value.checked_mul(u128::from(self.rate_bps)))
+            .and_then(|value| value.checked_mul(u128::from(self.period_seconds)))
+            .and_then(|value| value.checked_mul(u128::from(periods)))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        let denominator = 10u128
+            .checked_pow(u32::from(decimals))
+            .and_then(|value| value.checked_mul(BPS_DENOMINATOR))
+            .and_then(|value| value.checked_mul(self.day_count.days_in_year() * SECONDS_PER_DAY))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        u64::try_from(numerator / denominator).map_err(|_| ProgramError::ArithmeticOverflow)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(ACCRUAL_CONFIG_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[ACCRUAL_CONFIG_ACCOUNT, &self.mint, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+/// Balance snapshot of a token account taken at its last coupon claim
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct CouponClaim {
+    /// Debt token mint
+    pub mint: Pubkey,
+    /// Token account the coupons accrue to
+    pub token_account: Pubkey,
+    /// Token account balance at the last claim
+    pub balance_snapshot: u64,
+    /// Number of completed coupon periods at the last claim
+    pub last_claimed_period: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for CouponClaim {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::CouponClaimDiscriminator as u8;
+}
+
+impl AccountSerialize for CouponClaim {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.token_account.as_ref());
+        data.extend_from_slice(&self.balance_snapshot.to_le_bytes());
+        data.extend_from_slice(&self.last_claimed_period.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for CouponClaim {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let token_account: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = 2 * PUBKEY_BYTES;
+        let balance_snapshot = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let last_claimed_period = u64::from_le_bytes(
+            data[offset + 8..offset + 16]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            mint,
+            token_account,
+            balance_snapshot,
+            last_claimed_period,
+            bump: data[offset + 16],
+        })
+    }
+}
+
+impl ProgramAccount for CouponClaim {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl CouponClaim {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 2 pubkeys (64 bytes) + balance_snapshot (8 bytes) + last_claimed_period (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 8 + 8 + 1;
+
+    /// Create a new CouponClaim
+    pub fn new(
+        mint: Pubkey,
+        token_account: Pubkey,
+        balance_snapshot: u64,
+        last_claimed_period: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            mint,
+            token_account,
+            balance_snapshot,
+            last_claimed_period,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<CouponClaim, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(COUPON_CLAIM_ACCOUNT),
+            Seed::from(self.token_account.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[COUPON_CLAIM_ACCOUNT, &self.token_account, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accrual_config(day_count: DayCountConvention) -> AccrualConfig {
+        // 5% on 100 payout units per token, paid every 30 days
+        AccrualConfig::new(
+            [1u8; 32],
+            [2u8; 32],
+            500,
+            day_count,
+            30 * 86_400,
+            1_000,
+            100_000_000,
+            255,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_accrual_config_serialization_round_trip() {
+        let config = accrual_config(DayCountConvention::Actual360);
+
+        let bytes = config.to_bytes();
+        assert_eq!(bytes.len(), AccrualConfig::LEN);
+
+        let deserialized = AccrualConfig::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, config.mint);
+        assert_eq!(deserialized.payout_mint, config.payout_mint);
+        assert_eq!(deserialized.rate_bps, config.rate_bps);
+        assert_eq!(deserialized.day_count, config.day_count);
+        assert_eq!(deserialized.period_seconds, config.period_seconds);
+        assert_eq!(deserialized.start_timestamp, config.start_timestamp);
+        assert_eq!(deserialized.principal_per_token, config.principal_per_token);
+        assert_eq!(deserialized.bump, config.bump);
+    }
+
+    #[test]
+    fn test_accrual_config_rejects_invalid_arguments() {
+        let new = |rate_bps, period_seconds, principal_per_token, payout_mint| {
+            AccrualConfig::new(
+                [1u8; 32],
+                payout_mint,
+                rate_bps,
+                DayCountConvention::Actual365,
+                period_seconds,
+                0,
+                principal_per_token,
+                255,
+            )
+        };
+        assert!(new(0, 86_400, 1, [2u8; 32]).is_err());
+        assert!(new(500, 0, 1, [2u8; 32]).is_err());
+        assert!(new(500, 86_400, 0, [2u8; 32]).is_err());
+        assert!(new(500, 86_400, 1, [1u8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_completed_periods() {
+        let config = accrual_config(DayCountConvention::Actual365);
+        let period = i64::from(config.period_seconds);
+
+        assert_eq!(config.completed_periods(0), 0);
+        assert_eq!(config.completed_periods(1_000), 0);
+        assert_eq!(config.completed_periods(1_000 + period - 1), 0);
+        assert_eq!(config.completed_periods(1_000 + period), 1);
+        assert_eq!(config.completed_periods(1_000 + 3 * period + 5), 3);
+    }
+
+    #[test]
+    fn test_coupon_amount_by_day_count() {
+        // 10 whole tokens with 6 decimals, 100 payout units principal each
+        let balance = 10_000_000;
+
+        // 1000 principal * 5% * 30/360
+        let config = accrual_config(DayCountConvention::Actual360);
+        assert_eq!(config.coupon_amount(balance, 1, 6).unwrap(), 4_166_666);
+        assert_eq!(config.coupon_amount(balance, 2, 6).unwrap(), 8_333_333);
+
+        // 1000 principal * 5% * 30/365
+        let config = accrual_config(DayCountConvention::Actual365);
+        assert_eq!(config.coupon_amount(balance, 1, 6).unwrap(), 4_109_589);
+        assert_eq!(config.coupon_amount(balance, 0, 6).unwrap(), 0);
+        assert_eq!(config.coupon_amount(0, 1, 6).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_coupon_claim_serialization_round_trip() {
+        let claim = CouponClaim::new([1u8; 32], [3u8; 32], 42, 7, 254);
+
+        let bytes = claim.to_bytes();
+        assert_eq!(bytes.len(), CouponClaim::LEN);
+
+        let deserialized = CouponClaim::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, claim.mint);
+        assert_eq!(deserialized.token_account, claim.token_account);
+        assert_eq!(deserialized.balance_snapshot, claim.balance_snapshot);
+        assert_eq!(deserialized.last_claimed_period, claim.last_claimed_period);
+        assert_eq!(deserialized.bump, claim.bump);
+    }
+}
@@ -8,29 +8,7 @@ pub trait Discriminator {
     const DISCRIMINATOR: u8;
 }
 
-#[repr(u8)]
-pub enum SecurityTokenDiscriminators {
-    MintAuthorityDiscriminator = 0,
-    VerificationConfigDiscriminator = 1,
-    RateDiscriminator = 2,
-    ReceiptDiscriminator = 3,
-    ProofDiscriminator = 4,
-}
-
-impl TryFrom<u8> for SecurityTokenDiscriminators {
-    type Error = ProgramError;
-
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(SecurityTokenDiscriminators::MintAuthorityDiscriminator),
-            1 => Ok(SecurityTokenDiscriminators::VerificationConfigDiscriminator),
-            2 => Ok(SecurityTokenDiscriminators::RateDiscriminator),
-            3 => Ok(SecurityTokenDiscriminators::ReceiptDiscriminator),
-            4 => Ok(SecurityTokenDiscriminators::ProofDiscriminator),
-            _ => Err(ProgramError::InvalidInstructionData),
-        }
-    }
-}
+pub use security_token_core::SecurityTokenDiscriminators;
 
 pub trait AccountSerialize: Discriminator {
     fn to_bytes(&self) -> Vec<u8> {
@@ -0,0 +1,275 @@
+//! Distribution payout account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::DISTRIBUTION_PAYOUT_ACCOUNT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Alternative payout mint of a distribution. Merkle leaves encode entitlements in
+/// security token base units, a claimant choosing this payout receives
+/// `amount * rate_numerator / rate_denominator` payout tokens from the payout escrow,
+/// the associated token account of this PDA.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct DistributionPayout {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Mint the payout is made in
+    pub payout_mint: Pubkey,
+    /// Merkle root of the distribution entitlements
+    pub merkle_root: [u8; 32],
+    /// Distribution action identifier
+    pub action_id: u64,
+    /// Payout tokens per `rate_denominator` base units
+    pub rate_numerator: u64,
+    /// Base units per `rate_numerator` payout tokens
+    pub rate_denominator: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for DistributionPayout {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::DistributionPayoutDiscriminator as u8;
+}
+
+impl AccountSerialize for DistributionPayout {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.payout_mint.as_ref());
+        data.extend_from_slice(self.merkle_root.as_ref());
+        data.extend_from_slice(&self.action_id.to_le_bytes());
+        data.extend_from_slice(&self.rate_numerator.to_le_bytes());
+        data.extend_from_slice(&self.rate_denominator.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for DistributionPayout {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let key_at = |index: usize| -> Result<[u8; 32], ProgramError> {
+            data[index * PUBKEY_BYTES..(index + 1) * PUBKEY_BYTES]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)
+        };
+        let word_at = |index: usize| -> Result<[u8; 8], ProgramError> {
+            let offset = 3 * PUBKEY_BYTES + index * 8;
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)
+        };
+
+        let distribution_payout = Self {
+            mint: key_at(0)?,
+            payout_mint: key_at(1)?,
+            merkle_root: key_at(2)?,
+            action_id: u64::from_le_bytes(word_at(0)?),
+            rate_numerator: u64::from_le_bytes(word_at(1)?),
+            rate_denominator: u64::from_le_bytes(word_at(2)?),
+            bump: data[3 * PUBKEY_BYTES + 24],
+        };
+        distribution_payout.validate()?;
+        Ok(distribution_payout)
+    }
+}
+
+impl ProgramAccount for DistributionPayout {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl DistributionPayout {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + mints (64 bytes) + merkle_root (32 bytes) + action_id (8 bytes)
+    /// + rate (16 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 32 + 8 + 16 + 1;
+
+    /// Create a new DistributionPayout
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mint: Pubkey,
+        payout_mint: Pubkey,
+        merkle_root: [u8; 32],
+        action_id: u64,
+        rate_numerator: u64,
+        rate_denominator: u64,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        let distribution_payout = Self {
+            mint,
+            payout_mint,
+            merkle_root,
+            action_id,
+            rate_numerator,
+            rate_denominator,
+            bump,
+        };
+        distribution_payout.validate()?;
+        Ok(distribution_payout)
+    }
+
+    /// Validate the DistributionPayout account data
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        // Paying out in the security token itself is the default claim settlement
+        if self.rate_numerator == 0 || self.rate_denominator == 0 || self.payout_mint == self.mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    /// Payout tokens for an entitlement of `amount` base units, rounded down
+    pub fn payout_amount(&self, amount: u64) -> Result<u64, ProgramError> {
+        let payout = amount as u128 * self.rate_numerator as u128 / self.rate_denominator as u128;
+        u64::try_from(payout).map_err(|_| ProgramError::ArithmeticOverflow)
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<DistributionPayout, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn action_id_seed(&self) -> [u8; 8] {
+        self.action_id.to_le_bytes()
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        action_id_seed: &'a [u8; 8],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 5] {
+        [
+            Seed::from(DISTRIBUTION_PAYOUT_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(action_id_seed.as_ref()),
+            Seed::from(self.payout_mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                DISTRIBUTION_PAYOUT_ACCOUNT,
+                &self.mint,
+                &self.action_id_seed(),
+                &self.payout_mint,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn distribution_payout(rate_numerator: u64, rate_denominator: u64) -> DistributionPayout {
+        DistributionPayout::new(
+            [1u8; 32],
+            [2u8; 32],
+            [3u8; 32],
+            42,
+            rate_numerator,
+            rate_denominator,
+            251,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_distribution_payout_serialization_round_trip() {
+        let distribution_payout = distribution_payout(3, 2);
+
+        let bytes = distribution_payout.to_bytes();
+        assert_eq!(bytes.len(), DistributionPayout::LEN);
+        assert_eq!(bytes[0], DistributionPayout::DISCRIMINATOR);
+
+        let deserialized = DistributionPayout::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, distribution_payout.mint);
+        assert_eq!(deserialized.payout_mint, distribution_payout.payout_mint);
+        assert_eq!(deserialized.merkle_root, distribution_payout.merkle_root);
+        assert_eq!(deserialized.action_id, distribution_payout.action_id);
+        assert_eq!(
+            deserialized.rate_numerator,
+            distribution_payout.rate_numerator
+        );
+        assert_eq!(
+            deserialized.rate_denominator,
+            distribution_payout.rate_denominator
+        );
+        assert_eq!(deserialized.bump, distribution_payout.bump);
+    }
+
+    #[rstest]
+    #[case(0, 1, [2u8; 32])]
+    #[case(1, 0, [2u8; 32])]
+    #[case(1, 1, [1u8; 32])]
+    fn test_distribution_payout_rejects_invalid_arguments(
+        #[case] rate_numerator: u64,
+        #[case] rate_denominator: u64,
+        #[case] payout_mint: Pubkey,
+    ) {
+        assert!(DistributionPayout::new(
+            [1u8; 32],
+            payout_mint,
+            [3u8; 32],
+            42,
+            rate_numerator,
+            rate_denominator,
+            251,
+        )
+        .is_err());
+    }
+
+    #[rstest]
+    #[case(1, 1, 1_000, 1_000)]
+    #[case(3, 2, 1_001, 1_501)]
+    #[case(1, 1_000, 999, 0)]
+    fn test_payout_amount(
+        #[case] rate_numerator: u64,
+        #[case] rate_denominator: u64,
+        #[case] amount: u64,
+        #[case] expected: u64,
+    ) {
+        let distribution_payout = distribution_payout(rate_numerator, rate_denominator);
+        assert_eq!(distribution_payout.payout_amount(amount).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_payout_amount_overflow() {
+        let distribution_payout = distribution_payout(2, 1);
+        assert!(distribution_payout.payout_amount(u64::MAX).is_err());
+    }
+}
@@ -0,0 +1,246 @@
+//! Distribution root account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::{ShankAccount, ShankType};
+
+use crate::constants::seeds::DISTRIBUTION_ROOT_ACCOUNT;
+use crate::error::SecurityTokenError;
+use crate::merkle_tree_utils::MerkleTreeRoot;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Status of an amended distribution merkle root
+#[repr(u8)]
+#[derive(Clone, Debug, PartialEq, Eq, Copy, ShankType)]
+pub enum DistributionRootStatus {
+    /// Root was replaced before the claim window opened and can no longer be claimed
+    Superseded = 0,
+    /// Root supplements the original root, its claims are receipted per root
+    Supplemental = 1,
+}
+
+impl From<DistributionRootStatus> for u8 {
+    fn from(status: DistributionRootStatus) -> Self {
+        status as u8
+    }
+}
+
+impl TryFrom<u8> for DistributionRootStatus {
+    type Error = ProgramError;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            0 => Ok(DistributionRootStatus::Superseded),
+            1 => Ok(DistributionRootStatus::Supplemental),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Amendment record of a distribution merkle root, created by AmendDistributionRoot.
+/// Roots without this account are the roots the distribution was created with.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct DistributionRoot {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Amended merkle root
+    pub merkle_root: [u8; 32],
+    /// Distribution action identifier
+    pub action_id: u64,
+    /// Status of the merkle root
+    pub status: DistributionRootStatus,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for DistributionRoot {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::DistributionRootDiscriminator as u8;
+}
+
+impl AccountSerialize for DistributionRoot {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.merkle_root.as_ref());
+        data.extend_from_slice(&self.action_id.to_le_bytes());
+        data.push(self.status.into());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for DistributionRoot {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let merkle_root: [u8; 32] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = 2 * PUBKEY_BYTES;
+        let action_id = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            mint,
+            merkle_root,
+            action_id,
+            status: DistributionRootStatus::try_from(data[offset + 8])?,
+            bump: data[offset + 9],
+        })
+    }
+}
+
+impl ProgramAccount for DistributionRoot {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl DistributionRoot {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + mint (32 bytes) + merkle_root (32 bytes) + action_id (8 bytes)
+    /// + status (1 byte) + bump (1 byte)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + 32 + 8 + 1 + 1;
+
+    /// Create a new DistributionRoot
+    pub fn new(
+        mint: Pubkey,
+        merkle_root: MerkleTreeRoot,
+        action_id: u64,
+        status: DistributionRootStatus,
+        bump: u8,
+    ) -> Self {
+        Self {
+            mint,
+            merkle_root,
+            action_id,
+            status,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<DistributionRoot, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Check the merkle root can be claimed against
+    pub fn verify_claimable(&self) -> Result<(), ProgramError> {
+        if self.status == DistributionRootStatus::Superseded {
+            return Err(SecurityTokenError::DistributionRootSuperseded.into());
+        }
+        Ok(())
+    }
+
+    /// Whether claims against the merkle root are receipted per root
+    pub fn is_supplemental(&self) -> bool {
+        self.status == DistributionRootStatus::Supplemental
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn action_id_seed(&self) -> [u8; 8] {
+        self.action_id.to_le_bytes()
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        action_id_seed: &'a [u8; 8],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 5] {
+        [
+            Seed::from(DISTRIBUTION_ROOT_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(action_id_seed.as_ref()),
+            Seed::from(self.merkle_root.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                DISTRIBUTION_ROOT_ACCOUNT,
+                &self.mint,
+                &self.action_id_seed(),
+                &self.merkle_root,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(DistributionRootStatus::Superseded)]
+    #[case(DistributionRootStatus::Supplemental)]
+    fn test_distribution_root_serialization_round_trip(#[case] status: DistributionRootStatus) {
+        let distribution_root = DistributionRoot::new([1u8; 32], [2u8; 32], 42, status, 254);
+
+        let bytes = distribution_root.to_bytes();
+        assert_eq!(bytes.len(), DistributionRoot::LEN);
+        assert_eq!(bytes[0], DistributionRoot::DISCRIMINATOR);
+
+        let deserialized = DistributionRoot::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, distribution_root.mint);
+        assert_eq!(deserialized.merkle_root, distribution_root.merkle_root);
+        assert_eq!(deserialized.action_id, distribution_root.action_id);
+        assert_eq!(deserialized.status, status);
+        assert_eq!(deserialized.bump, distribution_root.bump);
+    }
+
+    #[test]
+    fn test_superseded_root_is_not_claimable() {
+        let superseded = DistributionRoot::new(
+            [1u8; 32],
+            [2u8; 32],
+            42,
+            DistributionRootStatus::Superseded,
+            254,
+        );
+        assert!(superseded.verify_claimable().is_err());
+        assert!(!superseded.is_supplemental());
+
+        let supplemental = DistributionRoot::new(
+            [1u8; 32],
+            [2u8; 32],
+            42,
+            DistributionRootStatus::Supplemental,
+            254,
+        );
+        assert!(supplemental.verify_claimable().is_ok());
+        assert!(supplemental.is_supplemental());
+    }
+}
@@ -0,0 +1,366 @@
+//! Document registry account state
+use core::ops::Range;
+
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::DOCUMENT_REGISTRY_ACCOUNT;
+use crate::error::SecurityTokenError;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// SHA-256 hash length of an anchored document
+pub const DOCUMENT_HASH_LEN: usize = 32;
+
+/// Legal documents of a mint, such as a prospectus or subscription agreement, anchored
+/// by the SHA-256 hash of their content
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct DocumentRegistry {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+    /// Anchored documents, each encoded as name and URI (u32 LE length + UTF-8 bytes)
+    /// followed by the SHA-256 hash of the document (32 bytes)
+    pub documents: Vec<u8>,
+}
+
+impl Discriminator for DocumentRegistry {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::DocumentRegistryDiscriminator as u8;
+}
+
+impl AccountSerialize for DocumentRegistry {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::MIN_LEN - 1 + self.documents.len());
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.push(self.bump);
+        data.extend_from_slice(&(self.documents.len() as u32).to_le_bytes());
+        data.extend_from_slice(&self.documents);
+
+        data
+    }
+}
+
+impl AccountDeserialize for DocumentRegistry {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::MIN_LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let mut offset = PUBKEY_BYTES;
+        let bump = data[offset];
+        offset += 1;
+        let documents_len = u32::from_le_bytes(
+            data[offset..offset + 4]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        ) as usize;
+        offset += 4;
+
+        if data.len() - offset != documents_len {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            mint,
+            bump,
+            documents: data[offset..].to_vec(),
+        })
+    }
+}
+
+impl ProgramAccount for DocumentRegistry {
+    fn space(&self) -> u64 {
+        Self::size_of(self.documents.len()) as u64
+    }
+}
+
+impl DocumentRegistry {
+    /// Serialized size without documents
+    /// Discriminator (1 byte) + mint (32 bytes) + bump (1 byte) + vector length (4 bytes)
+    pub const MIN_LEN: usize = 1 + PUBKEY_BYTES + 1 + 4;
+
+    /// Maximum number of anchored documents
+    pub const MAX_DOCUMENTS: usize = 32;
+
+    /// Maximum length of a document name
+    pub const MAX_NAME_LEN: usize = 64;
+
+    /// Maximum length of a document URI
+    pub const MAX_URI_LEN: usize = 200;
+
+    /// Create an empty DocumentRegistry
+    pub fn new(mint: Pubkey, bump: u8) -> Self {
+        Self {
+            mint,
+            bump,
+            documents: Vec::new(),
+        }
+    }
+
+    /// Serialized size of a registry with `documents_len` bytes of documents
+    pub fn size_of(documents_len: usize) -> usize {
+        Self::MIN_LEN + documents_len
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<DocumentRegistry, ProgramError> {
+        if account_info.data_len() < Self::MIN_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Anchor a new document, names are unique within the registry
+    pub fn anchor(
+        &mut self,
+        name: &str,
+        uri: &str,
+        sha256: &[u8; DOCUMENT_HASH_LEN],
+    ) -> Result<(), ProgramError> {
+        Self::validate_document(name, uri, sha256)?;
+        let mut count = 0;
+        let mut anchored = false;
+        self.for_each_document(|existing_name, _uri, _sha256, _range| {
+            count += 1;
+            anchored |= existing_name == name;
+        })?;
+        if anchored {
+            return Err(SecurityTokenError::DocumentAlreadyAnchored.into());
+        }
+        if count == Self::MAX_DOCUMENTS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        self.documents
+            .extend_from_slice(&Self::encode_document(name, uri, sha256));
+        Ok(())
+    }
+
+    /// Replace the URI and hash of the document `name`, e.g. for an amended prospectus
+    pub fn update(
+        &mut self,
+        name: &str,
+        uri: &str,
+        sha256: &[u8; DOCUMENT_HASH_LEN],
+    ) -> Result<(), ProgramError> {
+        Self::validate_document(name, uri, sha256)?;
+        let range = self.find_document(name)?;
+        self.documents
+            .splice(range, Self::encode_document(name, uri, sha256));
+        Ok(())
+    }
+
+    /// Remove the document `name`
+    pub fn remove(&mut self, name: &str) -> Result<(), ProgramError> {
+        let range = self.find_document(name)?;
+        self.documents.drain(range);
+        Ok(())
+    }
+
+    /// URI and hash of the document `name`, if anchored
+    pub fn get(&self, name: &str) -> Result<Option<(&str, &[u8])>, ProgramError> {
+        let mut found = None;
+        self.for_each_document(|existing_name, uri, sha256, _range| {
+            if existing_name == name {
+                found = Some((uri, sha256));
+            }
+        })?;
+        Ok(found)
+    }
+
+    /// Byte range of the document `name` within `documents`
+    fn find_document(&self, name: &str) -> Result<Range<usize>, ProgramError> {
+        let mut found = None;
+        self.for_each_document(|existing_name, _uri, _sha256, range| {
+            if existing_name == name {
+                found = Some(range);
+            }
+        })?;
+        found.ok_or_else(|| SecurityTokenError::DocumentNotFound.into())
+    }
+
+    /// Call `callback` with the name, URI, hash and byte range of every encoded document
+    fn for_each_document<'a, F>(&'a self, mut callback: F) -> Result<(), ProgramError>
+    where
+        F: FnMut(&'a str, &'a str, &'a [u8], Range<usize>),
+    {
+        let read_str = |offset: usize| -> Result<(&'a str, usize), ProgramError> {
+            let len = self
+                .documents
+                .get(offset..offset + 4)
+                .and_then(|bytes| bytes.try_into().ok())
+                .map(u32::from_le_bytes)
+                .ok_or(ProgramError::InvalidAccountData)? as usize;
+            let end = offset + 4 + len;
+            self.documents
+                .get(offset + 4..end)
+                .and_then(|bytes| core::str::from_utf8(bytes).ok())
+                .map(|value| (value, end))
+                .ok_or(ProgramError::InvalidAccountData)
+        };
+
+        let mut offset = 0;
+        while offset < self.documents.len() {
+            let (name, name_end) = read_str(offset)?;
+            let (uri, uri_end) = read_str(name_end)?;
+            let end = uri_end + DOCUMENT_HASH_LEN;
+            let sha256 = self
+                .documents
+                .get(uri_end..end)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            callback(name, uri, sha256, offset..end);
+            offset = end;
+        }
+        Ok(())
+    }
+
+    /// Reject empty or too long names and URIs and the all-zero hash
+    fn validate_document(
+        name: &str,
+        uri: &str,
+        sha256: &[u8; DOCUMENT_HASH_LEN],
+    ) -> Result<(), ProgramError> {
+        if name.is_empty()
+            || name.len() > Self::MAX_NAME_LEN
+            || uri.is_empty()
+            || uri.len() > Self::MAX_URI_LEN
+            || sha256.iter().all(|&b| b == 0)
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    fn encode_document(name: &str, uri: &str, sha256: &[u8; DOCUMENT_HASH_LEN]) -> Vec<u8> {
+        let mut data = Vec::with_capacity(4 + name.len() + 4 + uri.len() + DOCUMENT_HASH_LEN);
+        data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        data.extend_from_slice(name.as_bytes());
+        data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
+        data.extend_from_slice(uri.as_bytes());
+        data.extend_from_slice(sha256);
+        data
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(DOCUMENT_REGISTRY_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[DOCUMENT_REGISTRY_ACCOUNT, &self.mint, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROSPECTUS_HASH: [u8; 32] = [0xab; 32];
+
+    #[test]
+    fn test_document_registry_serialization_round_trip() {
+        let mut registry = DocumentRegistry::new([1u8; 32], 254);
+        registry
+            .anchor("prospectus", "https://example.com/p.pdf", &PROSPECTUS_HASH)
+            .unwrap();
+
+        let bytes = registry.to_bytes();
+        assert_eq!(
+            bytes.len(),
+            DocumentRegistry::size_of(registry.documents.len())
+        );
+        assert_eq!(bytes[0], DocumentRegistry::DISCRIMINATOR);
+
+        let deserialized = DocumentRegistry::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, [1u8; 32]);
+        assert_eq!(deserialized.bump, 254);
+        assert_eq!(deserialized.documents, registry.documents);
+    }
+
+    #[test]
+    fn test_document_registry_anchor_update_remove() {
+        let mut registry = DocumentRegistry::new([1u8; 32], 254);
+        registry
+            .anchor("prospectus", "ipfs://prospectus", &PROSPECTUS_HASH)
+            .unwrap();
+        registry
+            .anchor("subscription", "ipfs://subscription", &[0xcd; 32])
+            .unwrap();
+        assert_eq!(
+            registry.anchor("prospectus", "ipfs://other", &[1; 32]),
+            Err(SecurityTokenError::DocumentAlreadyAnchored.into())
+        );
+
+        registry
+            .update("prospectus", "ipfs://prospectus-v2", &[0xef; 32])
+            .unwrap();
+        assert_eq!(
+            registry.get("prospectus").unwrap(),
+            Some(("ipfs://prospectus-v2", &[0xef; 32][..]))
+        );
+        assert_eq!(
+            registry.get("subscription").unwrap(),
+            Some(("ipfs://subscription", &[0xcd; 32][..]))
+        );
+
+        registry.remove("prospectus").unwrap();
+        assert_eq!(registry.get("prospectus").unwrap(), None);
+        assert_eq!(
+            registry.remove("prospectus"),
+            Err(SecurityTokenError::DocumentNotFound.into())
+        );
+        assert_eq!(
+            registry.update("prospectus", "ipfs://prospectus", &PROSPECTUS_HASH),
+            Err(SecurityTokenError::DocumentNotFound.into())
+        );
+    }
+
+    #[test]
+    fn test_document_registry_rejects_invalid_documents() {
+        let mut registry = DocumentRegistry::new([1u8; 32], 254);
+        assert!(registry.anchor("", "ipfs://x", &PROSPECTUS_HASH).is_err());
+        assert!(registry.anchor("prospectus", "", &PROSPECTUS_HASH).is_err());
+        assert!(registry.anchor("prospectus", "ipfs://x", &[0; 32]).is_err());
+        let long_uri = "u".repeat(DocumentRegistry::MAX_URI_LEN + 1);
+        assert!(registry
+            .anchor("prospectus", &long_uri, &PROSPECTUS_HASH)
+            .is_err());
+
+        for i in 0..DocumentRegistry::MAX_DOCUMENTS {
+            registry
+                .anchor(&format!("doc{i}"), "ipfs://x", &PROSPECTUS_HASH)
+                .unwrap();
+        }
+        assert!(registry
+            .anchor("one_too_many", "ipfs://x", &PROSPECTUS_HASH)
+            .is_err());
+    }
+}
@@ -0,0 +1,191 @@
+//! Freeze expiry state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::FREEZE_EXPIRY_ACCOUNT;
+use crate::error::SecurityTokenError;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Automatic expiry of a Token-2022 freeze. Once `auto_thaw_at` passes anyone can thaw the
+/// token account with ThawExpired, which closes this account.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct FreezeExpiry {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Frozen token account
+    pub token_account: Pubkey,
+    /// Account that paid the rent, receives it back when the account is thawed
+    pub payer: Pubkey,
+    /// Unix timestamp from which the token account can be thawed by anyone
+    pub auto_thaw_at: i64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for FreezeExpiry {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::FreezeExpiryDiscriminator as u8;
+}
+
+impl AccountSerialize for FreezeExpiry {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.token_account.as_ref());
+        data.extend_from_slice(self.payer.as_ref());
+        data.extend_from_slice(&self.auto_thaw_at.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for FreezeExpiry {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let token_account: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let payer: [u8; PUBKEY_BYTES] = data[2 * PUBKEY_BYTES..3 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = 3 * PUBKEY_BYTES;
+        let auto_thaw_at = i64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            mint,
+            token_account,
+            payer,
+            auto_thaw_at,
+            bump: data[offset + 8],
+        })
+    }
+}
+
+impl ProgramAccount for FreezeExpiry {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl FreezeExpiry {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 3 pubkeys (96 bytes) + auto_thaw_at (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (3 * PUBKEY_BYTES) + 8 + 1;
+
+    /// Create a new FreezeExpiry
+    pub fn new(
+        mint: Pubkey,
+        token_account: Pubkey,
+        payer: Pubkey,
+        auto_thaw_at: i64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            mint,
+            token_account,
+            payer,
+            auto_thaw_at,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<FreezeExpiry, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Check the freeze expired at `timestamp`
+    pub fn verify_expired(&self, timestamp: i64) -> Result<(), ProgramError> {
+        if timestamp < self.auto_thaw_at {
+            return Err(SecurityTokenError::FreezeNotExpired.into());
+        }
+        Ok(())
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(FREEZE_EXPIRY_ACCOUNT),
+            Seed::from(self.token_account.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                FREEZE_EXPIRY_ACCOUNT,
+                &self.token_account,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_freeze_expiry_serialization_round_trip() {
+        let freeze_expiry = FreezeExpiry::new([1u8; 32], [2u8; 32], [3u8; 32], 1_700_000_000, 254);
+
+        let bytes = freeze_expiry.to_bytes();
+        assert_eq!(bytes.len(), FreezeExpiry::LEN);
+        assert_eq!(bytes[0], FreezeExpiry::DISCRIMINATOR);
+
+        let deserialized = FreezeExpiry::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, freeze_expiry.mint);
+        assert_eq!(deserialized.token_account, freeze_expiry.token_account);
+        assert_eq!(deserialized.payer, freeze_expiry.payer);
+        assert_eq!(deserialized.auto_thaw_at, freeze_expiry.auto_thaw_at);
+        assert_eq!(deserialized.bump, freeze_expiry.bump);
+    }
+
+    #[test]
+    fn test_freeze_expiry_verify_expired() {
+        let freeze_expiry = FreezeExpiry::new([1u8; 32], [2u8; 32], [3u8; 32], 1_000, 254);
+
+        assert!(freeze_expiry.verify_expired(999).is_err());
+        assert!(freeze_expiry.verify_expired(1_000).is_ok());
+        assert!(freeze_expiry.verify_expired(1_001).is_ok());
+    }
+
+    #[test]
+    fn test_freeze_expiry_rejects_invalid_length() {
+        let bytes = FreezeExpiry::new([1u8; 32], [2u8; 32], [3u8; 32], 0, 254).to_bytes();
+        assert!(FreezeExpiry::try_from_bytes(&bytes[..FreezeExpiry::LEN - 1]).is_err());
+    }
+}
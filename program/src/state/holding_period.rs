@@ -0,0 +1,527 @@
+//! Rule 144 holding period state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::{
+    HOLDING_LOT_ACCOUNT, HOLDING_PERIOD_ACCOUNT, RESTRICTED_HOLDING_ACCOUNT,
+};
+use crate::error::SecurityTokenError;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Statutory holding period of restricted lots of a mint. While it exists the transfer hook
+/// keeps the locked amount of a source token account from leaving it.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct HoldingPeriod {
+    /// Mint the holding period applies to
+    pub mint: Pubkey,
+    /// Seconds a restricted lot stays locked after its acquisition
+    pub holding_period: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for HoldingPeriod {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::HoldingPeriodDiscriminator as u8;
+}
+
+impl AccountSerialize for HoldingPeriod {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(&self.holding_period.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for HoldingPeriod {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let holding_period = u64::from_le_bytes(
+            data[PUBKEY_BYTES..PUBKEY_BYTES + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            mint,
+            holding_period,
+            bump: data[PUBKEY_BYTES + 8],
+        })
+    }
+}
+
+impl ProgramAccount for HoldingPeriod {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl HoldingPeriod {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + mint (32 bytes) + holding_period (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + 8 + 1;
+
+    /// Create a new HoldingPeriod
+    pub fn new(mint: Pubkey, holding_period: u64, bump: u8) -> Result<Self, ProgramError> {
+        if holding_period == 0 || i64::try_from(holding_period).is_err() {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(Self {
+            mint,
+            holding_period,
+            bump,
+        })
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<HoldingPeriod, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Unix timestamp a lot acquired at `acquired_timestamp` unlocks at
+    pub fn unlock_timestamp(&self, acquired_timestamp: i64) -> Result<i64, ProgramError> {
+        acquired_timestamp
+            .checked_add(self.holding_period as i64)
+            .ok_or(ProgramError::ArithmeticOverflow)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(HOLDING_PERIOD_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[HOLDING_PERIOD_ACCOUNT, &self.mint, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+/// Restricted balance of a token account: the sum of its locked lots. Read by the transfer
+/// hook for the source token account of every transfer.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct RestrictedHolding {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Token account holding the lots
+    pub token_account: Pubkey,
+    /// Token base units of the lots not unlocked yet
+    pub locked_amount: u64,
+    /// Identifier of the next lot, lots are numbered from 0
+    pub next_lot_id: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for RestrictedHolding {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::RestrictedHoldingDiscriminator as u8;
+}
+
+impl AccountSerialize for RestrictedHolding {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.token_account.as_ref());
+        data.extend_from_slice(&self.locked_amount.to_le_bytes());
+        data.extend_from_slice(&self.next_lot_id.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for RestrictedHolding {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let token_account: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = 2 * PUBKEY_BYTES;
+        let locked_amount = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let next_lot_id = u64::from_le_bytes(
+            data[offset + 8..offset + 16]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            mint,
+            token_account,
+            locked_amount,
+            next_lot_id,
+            bump: data[offset + 16],
+        })
+    }
+}
+
+impl ProgramAccount for RestrictedHolding {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl RestrictedHolding {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 2 pubkeys (64 bytes) + locked_amount (8 bytes) + next_lot_id (8 bytes)
+    /// + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 8 + 8 + 1;
+
+    /// Create a new RestrictedHolding without lots
+    pub fn new(mint: Pubkey, token_account: Pubkey, bump: u8) -> Self {
+        Self {
+            mint,
+            token_account,
+            locked_amount: 0,
+            next_lot_id: 0,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<RestrictedHolding, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Lock `amount` in a new lot and return the lot identifier
+    pub fn add_lot(&mut self, amount: u64) -> Result<u64, ProgramError> {
+        let lot_id = self.next_lot_id;
+        self.locked_amount = self
+            .locked_amount
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.next_lot_id = lot_id
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(lot_id)
+    }
+
+    /// Release the amount of an unlocked lot
+    pub fn release_lot(&mut self, amount: u64) {
+        // Burns and forced transfers may leave less than the locked amount in the account
+        self.locked_amount = self.locked_amount.saturating_sub(amount);
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(RESTRICTED_HOLDING_ACCOUNT),
+            Seed::from(self.token_account.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                RESTRICTED_HOLDING_ACCOUNT,
+                &self.token_account,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+/// Restricted tokens acquired in a single mint or transfer-in, locked until `unlock_timestamp`
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct HoldingLot {
+    /// Token account holding the lot
+    pub token_account: Pubkey,
+    /// Account that paid the rent, receives it back on unlock
+    pub payer: Pubkey,
+    /// Identifier of the lot within the token account
+    pub lot_id: u64,
+    /// Token base units acquired
+    pub amount: u64,
+    /// Unix timestamp the lot was acquired at
+    pub acquired_timestamp: i64,
+    /// Unix timestamp the lot can be unlocked at
+    pub unlock_timestamp: i64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for HoldingLot {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::HoldingLotDiscriminator as u8;
+}
+
+impl AccountSerialize for HoldingLot {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.token_account.as_ref());
+        data.extend_from_slice(self.payer.as_ref());
+        data.extend_from_slice(&self.lot_id.to_le_bytes());
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data.extend_from_slice(&self.acquired_timestamp.to_le_bytes());
+        data.extend_from_slice(&self.unlock_timestamp.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for HoldingLot {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let token_account: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let payer: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = 2 * PUBKEY_BYTES;
+        let lot_id = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let amount = u64::from_le_bytes(
+            data[offset + 8..offset + 16]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let acquired_timestamp = i64::from_le_bytes(
+            data[offset + 16..offset + 24]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let unlock_timestamp = i64::from_le_bytes(
+            data[offset + 24..offset + 32]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            token_account,
+            payer,
+            lot_id,
+            amount,
+            acquired_timestamp,
+            unlock_timestamp,
+            bump: data[offset + 32],
+        })
+    }
+}
+
+impl ProgramAccount for HoldingLot {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl HoldingLot {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 2 pubkeys (64 bytes) + lot_id (8 bytes) + amount (8 bytes)
+    /// + acquired_timestamp (8 bytes) + unlock_timestamp (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 8 + 8 + 8 + 8 + 1;
+
+    /// Create a new HoldingLot
+    pub fn new(
+        token_account: Pubkey,
+        payer: Pubkey,
+        lot_id: u64,
+        amount: u64,
+        acquired_timestamp: i64,
+        unlock_timestamp: i64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            token_account,
+            payer,
+            lot_id,
+            amount,
+            acquired_timestamp,
+            unlock_timestamp,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<HoldingLot, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Check the holding period of the lot elapsed at `timestamp`
+    pub fn verify_unlockable(&self, timestamp: i64) -> Result<(), ProgramError> {
+        if timestamp < self.unlock_timestamp {
+            return Err(SecurityTokenError::HoldingPeriodNotElapsed.into());
+        }
+        Ok(())
+    }
+
+    pub fn lot_id_seed(&self) -> [u8; 8] {
+        self.lot_id.to_le_bytes()
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, lot_id_seed: &'a [u8; 8], bump_seed: &'a [u8; 1]) -> [Seed<'a>; 4] {
+        [
+            Seed::from(HOLDING_LOT_ACCOUNT),
+            Seed::from(self.token_account.as_ref()),
+            Seed::from(lot_id_seed.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                HOLDING_LOT_ACCOUNT,
+                &self.token_account,
+                &self.lot_id_seed(),
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_holding_period_serialization_round_trip() {
+        let holding_period = HoldingPeriod::new([1u8; 32], 31_536_000, 255).unwrap();
+
+        let bytes = holding_period.to_bytes();
+        assert_eq!(bytes.len(), HoldingPeriod::LEN);
+
+        let deserialized = HoldingPeriod::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, holding_period.mint);
+        assert_eq!(deserialized.holding_period, holding_period.holding_period);
+        assert_eq!(deserialized.bump, holding_period.bump);
+        assert_eq!(holding_period.unlock_timestamp(1_000).unwrap(), 31_537_000);
+    }
+
+    #[test]
+    fn test_holding_period_rejects_invalid_arguments() {
+        assert!(HoldingPeriod::new([1u8; 32], 0, 255).is_err());
+        assert!(HoldingPeriod::new([1u8; 32], u64::MAX, 255).is_err());
+    }
+
+    #[test]
+    fn test_restricted_holding_tracks_lots() {
+        let mut holding = RestrictedHolding::new([1u8; 32], [2u8; 32], 254);
+
+        assert_eq!(holding.add_lot(100).unwrap(), 0);
+        assert_eq!(holding.add_lot(50).unwrap(), 1);
+        assert_eq!(holding.locked_amount, 150);
+        assert_eq!(holding.next_lot_id, 2);
+
+        holding.release_lot(100);
+        assert_eq!(holding.locked_amount, 50);
+        holding.release_lot(100);
+        assert_eq!(holding.locked_amount, 0);
+
+        let bytes = holding.to_bytes();
+        assert_eq!(bytes.len(), RestrictedHolding::LEN);
+        let deserialized = RestrictedHolding::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, holding.mint);
+        assert_eq!(deserialized.token_account, holding.token_account);
+        assert_eq!(deserialized.locked_amount, holding.locked_amount);
+        assert_eq!(deserialized.next_lot_id, holding.next_lot_id);
+        assert_eq!(deserialized.bump, holding.bump);
+    }
+
+    #[test]
+    fn test_holding_lot_serialization_and_unlock() {
+        let lot = HoldingLot::new([1u8; 32], [2u8; 32], 3, 100, 1_000, 2_000, 253);
+
+        let bytes = lot.to_bytes();
+        assert_eq!(bytes.len(), HoldingLot::LEN);
+        let deserialized = HoldingLot::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.token_account, lot.token_account);
+        assert_eq!(deserialized.payer, lot.payer);
+        assert_eq!(deserialized.lot_id, lot.lot_id);
+        assert_eq!(deserialized.amount, lot.amount);
+        assert_eq!(deserialized.acquired_timestamp, lot.acquired_timestamp);
+        assert_eq!(deserialized.unlock_timestamp, lot.unlock_timestamp);
+        assert_eq!(deserialized.bump, lot.bump);
+
+        assert_eq!(
+            lot.verify_unlockable(1_999),
+            Err(SecurityTokenError::HoldingPeriodNotElapsed.into())
+        );
+        assert!(lot.verify_unlockable(2_000).is_ok());
+    }
+}
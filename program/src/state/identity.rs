@@ -0,0 +1,334 @@
+//! Identity registry account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::{IDENTITY_ACCOUNT, IDENTITY_WALLET_ACCOUNT};
+use crate::constants::MAX_IDENTITY_WALLETS;
+use crate::error::SecurityTokenError;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Investor identity linking multiple token accounts of a mint to one investor
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct Identity {
+    /// Mint the identity is registered for
+    pub mint: Pubkey,
+    /// Investor identifier (e.g. hash of a DID or registrar reference)
+    pub investor_id: [u8; 32],
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+    /// Token accounts of the investor
+    #[idl_type("Vec<Pubkey>")]
+    pub wallets: Vec<Pubkey>,
+}
+
+impl Discriminator for Identity {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::IdentityDiscriminator as u8;
+}
+
+impl AccountSerialize for Identity {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.investor_id.as_ref());
+        data.push(self.bump);
+        data.extend(&(self.wallets.len() as u32).to_le_bytes());
+        for wallet in &self.wallets {
+            data.extend_from_slice(wallet.as_ref());
+        }
+        // Unused wallet slots are zeroed
+        data.resize(Self::LEN - 1, 0);
+
+        data
+    }
+}
+
+impl AccountDeserialize for Identity {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let investor_id: [u8; 32] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let bump = data[2 * PUBKEY_BYTES];
+
+        let mut offset = 2 * PUBKEY_BYTES + 1;
+        let wallets_len = u32::from_le_bytes(
+            data[offset..offset + Self::VEC_LEN_PREFIX]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        ) as usize;
+        offset += Self::VEC_LEN_PREFIX;
+
+        if wallets_len > MAX_IDENTITY_WALLETS {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let wallets = data[offset..offset + wallets_len * PUBKEY_BYTES]
+            .chunks_exact(PUBKEY_BYTES)
+            .map(|chunk| {
+                chunk
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidAccountData)
+            })
+            .collect::<Result<Vec<Pubkey>, ProgramError>>()?;
+
+        Ok(Self {
+            mint,
+            investor_id,
+            bump,
+            wallets,
+        })
+    }
+}
+
+impl ProgramAccount for Identity {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl Identity {
+    pub const VEC_LEN_PREFIX: usize = 4;
+    /// Offset of the first wallet slot in the account data
+    /// Discriminator (1 byte) + mint (32 bytes) + investor_id (32 bytes) + bump (1 byte) + vector length prefix (4 bytes)
+    pub const WALLETS_OFFSET: usize = 1 + (2 * PUBKEY_BYTES) + 1 + Self::VEC_LEN_PREFIX;
+    /// Serialized size of the account data. All wallet slots are allocated upfront
+    /// so the transfer hook can resolve every slot from a fixed offset
+    pub const LEN: usize = Self::WALLETS_OFFSET + (MAX_IDENTITY_WALLETS * PUBKEY_BYTES);
+
+    /// Create a new Identity without linked wallets
+    pub fn new(mint: Pubkey, investor_id: [u8; 32], bump: u8) -> Self {
+        Self {
+            mint,
+            investor_id,
+            bump,
+            wallets: Vec::new(),
+        }
+    }
+
+    /// Link a token account to the identity
+    pub fn add_wallet(&mut self, wallet: Pubkey) -> Result<(), ProgramError> {
+        if self.wallets.contains(&wallet) || self.wallets.len() >= MAX_IDENTITY_WALLETS {
+            return Err(SecurityTokenError::InvalidIdentityWallet.into());
+        }
+        self.wallets.push(wallet);
+        Ok(())
+    }
+
+    /// Unlink a token account from the identity
+    pub fn remove_wallet(&mut self, wallet: &Pubkey) -> Result<(), ProgramError> {
+        let position = self
+            .wallets
+            .iter()
+            .position(|linked| linked == wallet)
+            .ok_or(SecurityTokenError::InvalidIdentityWallet)?;
+        self.wallets.swap_remove(position);
+        Ok(())
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Identity, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 4] {
+        [
+            Seed::from(IDENTITY_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(self.investor_id.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                IDENTITY_ACCOUNT,
+                &self.mint,
+                &self.investor_id,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+/// Reverse link from a token account to its Identity.
+/// A token account can only be linked to one identity at a time.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct IdentityWallet {
+    /// Identity the token account is linked to
+    pub identity: Pubkey,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for IdentityWallet {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::IdentityWalletDiscriminator as u8;
+}
+
+impl AccountSerialize for IdentityWallet {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.identity.as_ref());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for IdentityWallet {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let identity: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let bump = data[PUBKEY_BYTES];
+
+        Ok(Self { identity, bump })
+    }
+}
+
+impl ProgramAccount for IdentityWallet {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl IdentityWallet {
+    /// Serialized size of the account data (discriminator + identity + bump)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + 1;
+
+    /// Create a new IdentityWallet
+    pub fn new(identity: Pubkey, bump: u8) -> Self {
+        Self { identity, bump }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<IdentityWallet, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        token_account_address: &'a Pubkey,
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 3] {
+        [
+            Seed::from(IDENTITY_WALLET_ACCOUNT),
+            Seed::from(token_account_address.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self, token_account_address: &Pubkey) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                IDENTITY_WALLET_ACCOUNT,
+                token_account_address.as_ref(),
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity_serialization_round_trip() {
+        let mut identity = Identity::new([1u8; 32], [2u8; 32], 254);
+        identity.add_wallet([3u8; 32]).unwrap();
+        identity.add_wallet([4u8; 32]).unwrap();
+
+        let bytes = identity.to_bytes();
+        assert_eq!(bytes.len(), Identity::LEN);
+        assert_eq!(
+            &bytes[Identity::WALLETS_OFFSET..Identity::WALLETS_OFFSET + PUBKEY_BYTES],
+            &[3u8; 32]
+        );
+
+        let deserialized = Identity::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, identity.mint);
+        assert_eq!(deserialized.investor_id, identity.investor_id);
+        assert_eq!(deserialized.bump, identity.bump);
+        assert_eq!(deserialized.wallets, identity.wallets);
+    }
+
+    #[test]
+    fn test_identity_wallets() {
+        let mut identity = Identity::new([1u8; 32], [2u8; 32], 254);
+        identity.add_wallet([3u8; 32]).unwrap();
+        // Duplicate wallet
+        assert!(identity.add_wallet([3u8; 32]).is_err());
+
+        for wallet in 1..MAX_IDENTITY_WALLETS {
+            identity.add_wallet([10 + wallet as u8; 32]).unwrap();
+        }
+        assert!(identity.add_wallet([255u8; 32]).is_err());
+
+        identity.remove_wallet(&[3u8; 32]).unwrap();
+        assert_eq!(identity.wallets.len(), MAX_IDENTITY_WALLETS - 1);
+        assert!(identity.remove_wallet(&[3u8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_identity_wallet_serialization_round_trip() {
+        let identity_wallet = IdentityWallet::new([1u8; 32], 253);
+
+        let bytes = identity_wallet.to_bytes();
+        assert_eq!(bytes.len(), IdentityWallet::LEN);
+
+        let deserialized = IdentityWallet::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.identity, identity_wallet.identity);
+        assert_eq!(deserialized.bump, identity_wallet.bump);
+    }
+}
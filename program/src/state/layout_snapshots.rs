@@ -0,0 +1,188 @@
+//! Byte layout regression tests.
+//!
+//! Every account below is serialized and compared against the snapshot checked in under
+//! `state/snapshots`, then the snapshot is deserialized and serialized again. A failure
+//! means a layout change that existing accounts on chain would not survive.
+//!
+//! Intentional layout changes must stay readable from the old snapshot; regenerate the
+//! snapshots with `UPDATE_SNAPSHOTS=1 cargo test -p security-token-program layout_snapshots`
+//! only after adding a migration path.
+
+use std::path::PathBuf;
+
+use pinocchio::pubkey::Pubkey;
+
+use crate::state::{
+    AccountDeserialize, AccountSerialize, ClaimAmounts, CollateralAttestation, DocumentRegistry,
+    MetadataTranslator, MintAuthority, Proof, Rate, Receipt, ReserveAttestation, Rounding,
+    SubAccount, SubAccountLedger, SuccessionPolicy, VerificationConfig,
+};
+
+const BYTES_PER_LINE: usize = 16;
+
+fn key(byte: u8) -> Pubkey {
+    [byte; 32]
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .chunks(BYTES_PER_LINE)
+        .map(|line| line.iter().map(|byte| format!("{byte:02x}")).collect())
+        .collect::<Vec<String>>()
+        .join("\n")
+        + "\n"
+}
+
+fn from_hex(hex: &str) -> Vec<u8> {
+    let digits: Vec<u8> = hex.bytes().filter(|c| !c.is_ascii_whitespace()).collect();
+    digits
+        .chunks(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).unwrap(), 16).unwrap())
+        .collect()
+}
+
+/// Compares the serialized `account` with the snapshot `name`, rewriting it when `UPDATE_SNAPSHOTS` is set
+fn assert_snapshot<T: AccountSerialize + AccountDeserialize>(name: &str, account: &T) {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "src", "state", "snapshots"]
+        .iter()
+        .collect::<PathBuf>()
+        .join(format!("{name}.hex"));
+    let bytes = account.to_bytes();
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&path, to_hex(&bytes)).unwrap();
+        return;
+    }
+
+    let snapshot = std::fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("missing snapshot {}", path.display()));
+    let expected = from_hex(&snapshot);
+    assert_eq!(
+        to_hex(&bytes),
+        to_hex(&expected),
+        "{name} layout changed, existing accounts would no longer deserialize"
+    );
+
+    let decoded = T::try_from_bytes(&expected)
+        .unwrap_or_else(|err| panic!("{name} snapshot no longer deserializes: {err:?}"));
+    assert_eq!(decoded.to_bytes(), expected, "{name} round trip changed");
+}
+
+#[test]
+fn test_verification_config_layout() {
+    let config =
+        VerificationConfig::new(12, false, 254, &[key(1), key(2), key(3)], 2, 4, true).unwrap();
+    assert_snapshot("verification_config", &config);
+
+    let mut linked = VerificationConfig::new(6, true, 253, &[], 0, 0, false).unwrap();
+    linked.config_template = Some(key(9));
+    assert_snapshot("verification_config_template", &linked);
+
+    let mut timelocked = VerificationConfig::new(6, false, 252, &[key(1)], 1, 0, false).unwrap();
+    timelocked.config_template = Some(key(9));
+    timelocked.timelock_seconds = 86_400;
+    assert_snapshot("verification_config_timelock", &timelocked);
+}
+
+#[test]
+fn test_mint_authority_layout() {
+    let mint_authority = MintAuthority::new(key(4), key(5), 255).unwrap();
+    assert_snapshot("mint_authority", &mint_authority);
+}
+
+#[test]
+fn test_rate_layout() {
+    let rate = Rate::new(Rounding::Down, 2, 3, 252).unwrap();
+    assert_snapshot("rate", &rate);
+
+    let mut oracle_rate = Rate::new(Rounding::Up, 1, 1, 251).unwrap();
+    oracle_rate
+        .set_oracle(key(7), 8, 6, 500_000, 2_000_000)
+        .unwrap();
+    assert_snapshot("rate_oracle", &oracle_rate);
+}
+
+#[test]
+fn test_receipt_layout() {
+    assert_snapshot("receipt", &Receipt::new().unwrap());
+
+    let claim = Receipt::new_claim(ClaimAmounts {
+        net_amount: 9_000_000,
+        withheld_amount: 1_000_000,
+        jurisdiction: 840,
+    });
+    assert_snapshot("receipt_claim", &claim);
+}
+
+#[test]
+fn test_proof_layout() {
+    let proof = Proof::new(&[[0xaa; 32], [0xbb; 32]], 250).unwrap();
+    assert_snapshot("proof", &proof);
+}
+
+#[test]
+fn test_collateral_attestation_layout() {
+    let attestation = CollateralAttestation::new(
+        key(1),
+        key(2),
+        key(3),
+        1_000_000,
+        250_000,
+        false,
+        false,
+        false,
+        300_000_000,
+        1_700_000_000,
+        249,
+    );
+    assert_snapshot("collateral_attestation", &attestation);
+}
+
+#[test]
+fn test_reserve_attestation_layout() {
+    let attestation = ReserveAttestation::new(
+        key(1),
+        key(2),
+        [7; 32],
+        &[(key(3), 1_000_000), (key(4), 250_000)],
+        300_000_000,
+        1_700_000_000,
+        248,
+    )
+    .unwrap();
+    assert_snapshot("reserve_attestation", &attestation);
+}
+
+#[test]
+fn test_sub_account_ledger_layout() {
+    let mut ledger = SubAccountLedger::new(key(1), key(2), 247);
+    ledger.credit(750_000, 1_000_000).unwrap();
+    ledger.sub_account_count = 3;
+    assert_snapshot("sub_account_ledger", &ledger);
+
+    let mut sub_account = SubAccount::new(key(3), [8; 32], 246);
+    sub_account.balance = 250_000;
+    assert_snapshot("sub_account", &sub_account);
+}
+
+#[test]
+fn test_succession_policy_layout() {
+    let mut policy = SuccessionPolicy::new(key(1), key(2), 2_592_000, 1_700_000_000, 245).unwrap();
+    policy.claim(1_702_592_000).unwrap();
+    assert_snapshot("succession_policy", &policy);
+}
+
+#[test]
+fn test_metadata_translator_layout() {
+    let translator = MetadataTranslator::new(key(1), key(2), 244);
+    assert_snapshot("metadata_translator", &translator);
+}
+
+#[test]
+fn test_document_registry_layout() {
+    let mut registry = DocumentRegistry::new(key(1), 243);
+    registry
+        .anchor("prospectus", "ipfs://prospectus", &[0xab; 32])
+        .unwrap();
+    assert_snapshot("document_registry", &registry);
+}
@@ -0,0 +1,237 @@
+//! Bond maturity state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::MATURITY_ACCOUNT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Maturity terms of a debt token. Between `maturity_timestamp` and `redemption_cutoff`
+/// holders redeem principal tokens at par from the redemption vault owned by this account;
+/// afterwards the issuer can withdraw the unclaimed funds.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct Maturity {
+    /// Debt token mint
+    pub mint: Pubkey,
+    /// Mint the principal is repaid in
+    pub payout_mint: Pubkey,
+    /// Unix timestamp redemption opens at
+    pub maturity_timestamp: i64,
+    /// Unix timestamp redemption closes at
+    pub redemption_cutoff: i64,
+    /// Principal in payout mint base units per whole debt token
+    pub principal_per_token: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for Maturity {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::MaturityDiscriminator as u8;
+}
+
+impl AccountSerialize for Maturity {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.payout_mint.as_ref());
+        data.extend_from_slice(&self.maturity_timestamp.to_le_bytes());
+        data.extend_from_slice(&self.redemption_cutoff.to_le_bytes());
+        data.extend_from_slice(&self.principal_per_token.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for Maturity {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let payout_mint: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = 2 * PUBKEY_BYTES;
+        let maturity_timestamp = i64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let redemption_cutoff = i64::from_le_bytes(
+            data[offset + 8..offset + 16]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let principal_per_token = u64::from_le_bytes(
+            data[offset + 16..offset + 24]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            mint,
+            payout_mint,
+            maturity_timestamp,
+            redemption_cutoff,
+            principal_per_token,
+            bump: data[offset + 24],
+        })
+    }
+}
+
+impl ProgramAccount for Maturity {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl Maturity {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 2 pubkeys (64 bytes) + maturity_timestamp (8 bytes)
+    /// + redemption_cutoff (8 bytes) + principal_per_token (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 8 + 8 + 8 + 1;
+
+    /// Create a new Maturity
+    pub fn new(
+        mint: Pubkey,
+        payout_mint: Pubkey,
+        maturity_timestamp: i64,
+        redemption_cutoff: i64,
+        principal_per_token: u64,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        if redemption_cutoff <= maturity_timestamp
+            || principal_per_token == 0
+            || mint == payout_mint
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(Self {
+            mint,
+            payout_mint,
+            maturity_timestamp,
+            redemption_cutoff,
+            principal_per_token,
+            bump,
+        })
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Maturity, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Whether principal tokens can be redeemed at `timestamp`
+    pub fn is_redemption_open(&self, timestamp: i64) -> bool {
+        timestamp >= self.maturity_timestamp && timestamp < self.redemption_cutoff
+    }
+
+    /// Principal in payout mint base units repaid for `amount` debt token base units. Rounded down.
+    pub fn redemption_amount(&self, amount: u64, decimals: u8) -> Result<u64, ProgramError> {
+        let payout = u128::from(amount)
+            .checked_mul(u128::from(self.principal_per_token))
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            / 10u128
+                .checked_pow(u32::from(decimals))
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        u64::try_from(payout).map_err(|_| ProgramError::ArithmeticOverflow)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(MATURITY_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[MATURITY_ACCOUNT, &self.mint, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn maturity() -> Maturity {
+        // Par of 100 payout units per token
+        Maturity::new([1u8; 32], [2u8; 32], 1_000, 2_000, 100_000_000, 255).unwrap()
+    }
+
+    #[test]
+    fn test_maturity_serialization_round_trip() {
+        let maturity = maturity();
+
+        let bytes = maturity.to_bytes();
+        assert_eq!(bytes.len(), Maturity::LEN);
+
+        let deserialized = Maturity::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, maturity.mint);
+        assert_eq!(deserialized.payout_mint, maturity.payout_mint);
+        assert_eq!(deserialized.maturity_timestamp, maturity.maturity_timestamp);
+        assert_eq!(deserialized.redemption_cutoff, maturity.redemption_cutoff);
+        assert_eq!(
+            deserialized.principal_per_token,
+            maturity.principal_per_token
+        );
+        assert_eq!(deserialized.bump, maturity.bump);
+    }
+
+    #[test]
+    fn test_maturity_rejects_invalid_arguments() {
+        assert!(Maturity::new([1u8; 32], [2u8; 32], 1_000, 1_000, 1, 255).is_err());
+        assert!(Maturity::new([1u8; 32], [2u8; 32], 1_000, 2_000, 0, 255).is_err());
+        assert!(Maturity::new([1u8; 32], [1u8; 32], 1_000, 2_000, 1, 255).is_err());
+    }
+
+    #[test]
+    fn test_redemption_window() {
+        let maturity = maturity();
+
+        assert!(!maturity.is_redemption_open(999));
+        assert!(maturity.is_redemption_open(1_000));
+        assert!(maturity.is_redemption_open(1_999));
+        assert!(!maturity.is_redemption_open(2_000));
+    }
+
+    #[test]
+    fn test_redemption_amount_at_par() {
+        let maturity = maturity();
+
+        // 2.5 tokens with 6 decimals
+        assert_eq!(
+            maturity.redemption_amount(2_500_000, 6).unwrap(),
+            250_000_000
+        );
+        assert_eq!(maturity.redemption_amount(1, 9).unwrap(), 0);
+    }
+}
@@ -0,0 +1,139 @@
+//! Metadata freeze account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::METADATA_FREEZE_ACCOUNT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Record of a FreezeMetadata, the token metadata update authority of the mint was
+/// removed and its metadata cannot be modified anymore
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct MetadataFreeze {
+    /// Security token mint whose metadata is frozen
+    pub mint: Pubkey,
+    /// Unix timestamp of the freeze
+    pub frozen_at: i64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for MetadataFreeze {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::MetadataFreezeDiscriminator as u8;
+}
+
+impl AccountSerialize for MetadataFreeze {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(&self.frozen_at.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for MetadataFreeze {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = PUBKEY_BYTES;
+        let frozen_at = i64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            mint,
+            frozen_at,
+            bump: data[offset + 8],
+        })
+    }
+}
+
+impl ProgramAccount for MetadataFreeze {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl MetadataFreeze {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + mint (32 bytes) + frozen_at (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + 8 + 1;
+
+    /// Create a new MetadataFreeze
+    pub fn new(mint: Pubkey, frozen_at: i64, bump: u8) -> Self {
+        Self {
+            mint,
+            frozen_at,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<MetadataFreeze, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(METADATA_FREEZE_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[METADATA_FREEZE_ACCOUNT, &self.mint, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_freeze_serialization_round_trip() {
+        let freeze = MetadataFreeze::new([3u8; 32], 1_700_000_000, 253);
+
+        let bytes = freeze.to_bytes();
+        assert_eq!(bytes.len(), MetadataFreeze::LEN);
+        assert_eq!(bytes[0], MetadataFreeze::DISCRIMINATOR);
+
+        let deserialized = MetadataFreeze::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, freeze.mint);
+        assert_eq!(deserialized.frozen_at, 1_700_000_000);
+        assert_eq!(deserialized.bump, 253);
+    }
+}
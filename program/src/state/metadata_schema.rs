@@ -0,0 +1,422 @@
+//! Metadata schema account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::{ShankAccount, ShankType};
+
+use crate::constants::seeds::METADATA_SCHEMA_ACCOUNT;
+use crate::error::SecurityTokenError;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+use crate::utils::parse_additional_metadata;
+
+/// Format a required additional metadata value must have
+#[repr(u8)]
+#[derive(Clone, Debug, PartialEq, Eq, Copy, ShankType)]
+pub enum MetadataFieldFormat {
+    /// Any non-empty value
+    Text = 0,
+    /// ISO 6166 ISIN with a valid check digit
+    Isin = 1,
+    /// ISO 3166-1 alpha-2 country code
+    CountryCode = 2,
+    /// ISO 17442 LEI with valid check digits
+    Lei = 3,
+}
+
+impl From<MetadataFieldFormat> for u8 {
+    fn from(format: MetadataFieldFormat) -> Self {
+        format as u8
+    }
+}
+
+impl TryFrom<u8> for MetadataFieldFormat {
+    type Error = ProgramError;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            0 => Ok(MetadataFieldFormat::Text),
+            1 => Ok(MetadataFieldFormat::Isin),
+            2 => Ok(MetadataFieldFormat::CountryCode),
+            3 => Ok(MetadataFieldFormat::Lei),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
+
+impl MetadataFieldFormat {
+    /// Whether `value` is well-formed for this format
+    pub fn is_valid(&self, value: &str) -> bool {
+        let bytes = value.as_bytes();
+        match self {
+            MetadataFieldFormat::Text => !bytes.is_empty(),
+            MetadataFieldFormat::Isin => {
+                bytes.len() == 12
+                    && bytes[..2].iter().all(u8::is_ascii_uppercase)
+                    && bytes[2..11]
+                        .iter()
+                        .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+                    && bytes[11].is_ascii_digit()
+                    && isin_checksum_valid(bytes)
+            }
+            MetadataFieldFormat::CountryCode => {
+                bytes.len() == 2 && bytes.iter().all(u8::is_ascii_uppercase)
+            }
+            MetadataFieldFormat::Lei => {
+                bytes.len() == 20
+                    && bytes[..18]
+                        .iter()
+                        .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+                    && bytes[18..].iter().all(u8::is_ascii_digit)
+                    && lei_checksum_valid(bytes)
+            }
+        }
+    }
+}
+
+/// Numeric value of an ISIN/LEI character, letters map to 10..=35
+fn alphanumeric_value(b: u8) -> u32 {
+    if b.is_ascii_digit() {
+        (b - b'0') as u32
+    } else {
+        (b - b'A') as u32 + 10
+    }
+}
+
+/// Luhn check over the digits of the ISIN with letters expanded to two digits
+fn isin_checksum_valid(isin: &[u8]) -> bool {
+    let mut sum = 0;
+    let mut double = false;
+    for &b in isin.iter().rev() {
+        let value = alphanumeric_value(b);
+        let digits: &[u32] = if value >= 10 {
+            &[value % 10, value / 10]
+        } else {
+            &[value]
+        };
+        for &digit in digits {
+            let digit = if double { digit * 2 } else { digit };
+            sum += digit / 10 + digit % 10;
+            double = !double;
+        }
+    }
+    sum % 10 == 0
+}
+
+/// ISO 7064 MOD 97-10 check of the LEI with letters expanded to two digits
+fn lei_checksum_valid(lei: &[u8]) -> bool {
+    let remainder = lei.iter().fold(0u32, |acc, &b| {
+        let value = alphanumeric_value(b);
+        if value >= 10 {
+            (acc * 100 + value) % 97
+        } else {
+            (acc * 10 + value) % 97
+        }
+    });
+    remainder == 1
+}
+
+/// Required additional metadata fields of a mint, enforced by UpdateMetadata
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct MetadataSchema {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+    /// Required fields, each encoded as key (u32 LE length + UTF-8 bytes) followed by its format (u8)
+    pub fields: Vec<u8>,
+}
+
+impl Discriminator for MetadataSchema {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::MetadataSchemaDiscriminator as u8;
+}
+
+impl AccountSerialize for MetadataSchema {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::MIN_LEN - 1 + self.fields.len());
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.push(self.bump);
+        data.extend_from_slice(&(self.fields.len() as u32).to_le_bytes());
+        data.extend_from_slice(&self.fields);
+
+        data
+    }
+}
+
+impl AccountDeserialize for MetadataSchema {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::MIN_LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let mut offset = PUBKEY_BYTES;
+        let bump = data[offset];
+        offset += 1;
+        let fields_len = u32::from_le_bytes(
+            data[offset..offset + 4]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        ) as usize;
+        offset += 4;
+
+        if data.len() - offset != fields_len {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            mint,
+            bump,
+            fields: data[offset..].to_vec(),
+        })
+    }
+}
+
+impl ProgramAccount for MetadataSchema {
+    fn space(&self) -> u64 {
+        Self::size_of(self.fields.len()) as u64
+    }
+}
+
+impl MetadataSchema {
+    /// Serialized size without fields
+    /// Discriminator (1 byte) + mint (32 bytes) + bump (1 byte) + vector length (4 bytes)
+    pub const MIN_LEN: usize = 1 + PUBKEY_BYTES + 1 + 4;
+
+    /// Maximum number of required fields, matches the additional fields UpdateMetadata tracks
+    pub const MAX_FIELDS: usize = 16;
+
+    /// Maximum length of a required field key
+    pub const MAX_KEY_LEN: usize = 64;
+
+    /// Create a new MetadataSchema, rejecting malformed or duplicate fields
+    pub fn new(mint: Pubkey, fields: Vec<u8>, bump: u8) -> Result<Self, ProgramError> {
+        Self::validate_fields(&fields)?;
+        Ok(Self { mint, bump, fields })
+    }
+
+    /// Serialized size of a schema with `fields_len` bytes of fields
+    pub fn size_of(fields_len: usize) -> usize {
+        Self::MIN_LEN + fields_len
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<MetadataSchema, ProgramError> {
+        if account_info.data_len() < Self::MIN_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Call `callback` with the key and format of every encoded field
+    fn for_each_field<'a, F>(fields: &'a [u8], mut callback: F) -> Result<(), ProgramError>
+    where
+        F: FnMut(&'a str, MetadataFieldFormat) -> Result<(), ProgramError>,
+    {
+        let mut offset = 0;
+        while offset < fields.len() {
+            let key_len = fields
+                .get(offset..offset + 4)
+                .and_then(|bytes| bytes.try_into().ok())
+                .map(u32::from_le_bytes)
+                .ok_or(ProgramError::InvalidArgument)? as usize;
+            offset += 4;
+            let key = fields
+                .get(offset..offset + key_len)
+                .and_then(|bytes| core::str::from_utf8(bytes).ok())
+                .ok_or(ProgramError::InvalidArgument)?;
+            offset += key_len;
+            let format = fields
+                .get(offset)
+                .ok_or(ProgramError::InvalidArgument)
+                .and_then(|&format| MetadataFieldFormat::try_from(format))?;
+            offset += 1;
+            callback(key, format)?;
+        }
+        Ok(())
+    }
+
+    /// Reject empty, too long or duplicate keys and more than MAX_FIELDS fields
+    fn validate_fields(fields: &[u8]) -> Result<(), ProgramError> {
+        let mut keys: Vec<&str> = Vec::new();
+        Self::for_each_field(fields, |key, _format| {
+            if key.is_empty()
+                || key.len() > Self::MAX_KEY_LEN
+                || keys.len() == Self::MAX_FIELDS
+                || keys.contains(&key)
+            {
+                return Err(ProgramError::InvalidArgument);
+            }
+            keys.push(key);
+            Ok(())
+        })
+    }
+
+    /// Check that `additional_metadata` (UpdateMetadata encoding) contains every required
+    /// field with a well-formed value
+    pub fn validate(&self, additional_metadata: &[u8]) -> Result<(), ProgramError> {
+        Self::for_each_field(&self.fields, |required_key, format| {
+            let mut value_found = None;
+            parse_additional_metadata(additional_metadata, |key, value| {
+                if key == required_key {
+                    value_found = Some(format.is_valid(value));
+                }
+                Ok(())
+            })
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+            match value_found {
+                None => Err(SecurityTokenError::MissingMetadataField.into()),
+                Some(false) => Err(SecurityTokenError::MalformedMetadataField.into()),
+                Some(true) => Ok(()),
+            }
+        })
+    }
+
+    /// Whether `key` is a required field of the schema
+    pub fn is_required(&self, key: &str) -> Result<bool, ProgramError> {
+        let mut required = false;
+        Self::for_each_field(&self.fields, |required_key, _format| {
+            required |= required_key == key;
+            Ok(())
+        })?;
+        Ok(required)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(METADATA_SCHEMA_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[METADATA_SCHEMA_ACCOUNT, &self.mint, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(key: &str, format: MetadataFieldFormat) -> Vec<u8> {
+        let mut data = (key.len() as u32).to_le_bytes().to_vec();
+        data.extend_from_slice(key.as_bytes());
+        data.push(format.into());
+        data
+    }
+
+    fn additional_metadata(entries: &[(&str, &str)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for (key, value) in entries {
+            data.extend_from_slice(&(key.len() as u32).to_le_bytes());
+            data.extend_from_slice(key.as_bytes());
+            data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            data.extend_from_slice(value.as_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_metadata_field_formats() {
+        assert!(MetadataFieldFormat::Isin.is_valid("US0378331005"));
+        assert!(!MetadataFieldFormat::Isin.is_valid("US0378331006"));
+        assert!(!MetadataFieldFormat::Isin.is_valid("us0378331005"));
+        assert!(MetadataFieldFormat::Lei.is_valid("5493001KJTIIGC8Y1R12"));
+        assert!(!MetadataFieldFormat::Lei.is_valid("5493001KJTIIGC8Y1R13"));
+        assert!(MetadataFieldFormat::CountryCode.is_valid("DE"));
+        assert!(!MetadataFieldFormat::CountryCode.is_valid("DEU"));
+        assert!(MetadataFieldFormat::Text.is_valid("x"));
+        assert!(!MetadataFieldFormat::Text.is_valid(""));
+    }
+
+    #[test]
+    fn test_metadata_schema_serialization_round_trip() {
+        let fields = [
+            field("isin", MetadataFieldFormat::Isin),
+            field("jurisdiction", MetadataFieldFormat::CountryCode),
+        ]
+        .concat();
+        let schema = MetadataSchema::new([1u8; 32], fields.clone(), 254).unwrap();
+
+        let bytes = schema.to_bytes();
+        assert_eq!(bytes.len(), MetadataSchema::size_of(fields.len()));
+        assert_eq!(bytes[0], MetadataSchema::DISCRIMINATOR);
+
+        let deserialized = MetadataSchema::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, [1u8; 32]);
+        assert_eq!(deserialized.bump, 254);
+        assert_eq!(deserialized.fields, fields);
+    }
+
+    #[test]
+    fn test_metadata_schema_rejects_invalid_fields() {
+        let duplicate = [
+            field("isin", MetadataFieldFormat::Isin),
+            field("isin", MetadataFieldFormat::Text),
+        ]
+        .concat();
+        assert!(MetadataSchema::new([1u8; 32], duplicate, 254).is_err());
+        assert!(MetadataSchema::new([1u8; 32], field("", MetadataFieldFormat::Text), 254).is_err());
+
+        let mut unknown_format = field("isin", MetadataFieldFormat::Isin);
+        *unknown_format.last_mut().unwrap() = 9;
+        assert!(MetadataSchema::new([1u8; 32], unknown_format, 254).is_err());
+    }
+
+    #[test]
+    fn test_metadata_schema_validate() {
+        let fields = [
+            field("isin", MetadataFieldFormat::Isin),
+            field("issuer_lei", MetadataFieldFormat::Lei),
+        ]
+        .concat();
+        let schema = MetadataSchema::new([1u8; 32], fields, 254).unwrap();
+
+        let valid = additional_metadata(&[
+            ("isin", "US0378331005"),
+            ("issuer_lei", "5493001KJTIIGC8Y1R12"),
+            ("notes", "anything"),
+        ]);
+        assert!(schema.validate(&valid).is_ok());
+
+        let missing = additional_metadata(&[("isin", "US0378331005")]);
+        assert_eq!(
+            schema.validate(&missing),
+            Err(SecurityTokenError::MissingMetadataField.into())
+        );
+
+        assert_eq!(schema.is_required("isin"), Ok(true));
+        assert_eq!(schema.is_required("notes"), Ok(false));
+
+        let malformed = additional_metadata(&[
+            ("isin", "US0378331006"),
+            ("issuer_lei", "5493001KJTIIGC8Y1R12"),
+        ]);
+        assert_eq!(
+            schema.validate(&malformed),
+            Err(SecurityTokenError::MalformedMetadataField.into())
+        );
+    }
+}
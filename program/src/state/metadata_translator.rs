@@ -0,0 +1,207 @@
+//! Metadata translator account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::METADATA_TRANSLATOR_ACCOUNT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Key allowed to maintain the localized `i18n.<locale>.<field>` additional metadata entries
+/// of a mint, without authority over the rest of its metadata
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct MetadataTranslator {
+    /// Mint whose metadata is translated
+    pub mint: Pubkey,
+    /// Key that signs translation updates
+    pub translator: Pubkey,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for MetadataTranslator {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::MetadataTranslatorDiscriminator as u8;
+}
+
+impl AccountSerialize for MetadataTranslator {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.translator.as_ref());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for MetadataTranslator {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let translator: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+        Ok(Self {
+            mint,
+            translator,
+            bump: data[2 * PUBKEY_BYTES],
+        })
+    }
+}
+
+impl ProgramAccount for MetadataTranslator {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl MetadataTranslator {
+    /// Serialized size of the account data (discriminator + mint + translator + bump)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 1;
+
+    /// Namespace of the additional metadata keys a translator can update
+    pub const KEY_PREFIX: &'static str = "i18n.";
+
+    /// Maximum length of a translation key, keys tracked by UpdateMetadata are at most 64 bytes
+    pub const MAX_KEY_LEN: usize = 64;
+
+    /// Maximum length of the locale segment of a translation key (BCP 47 tag, e.g. `pt-BR`)
+    pub const MAX_LOCALE_LEN: usize = 16;
+
+    /// Create a new MetadataTranslator
+    pub fn new(mint: Pubkey, translator: Pubkey, bump: u8) -> Self {
+        Self {
+            mint,
+            translator,
+            bump,
+        }
+    }
+
+    /// Whether `key` is a namespaced locale key `i18n.<locale>.<field>`: the locale is an
+    /// ASCII alphanumeric tag that may contain `-`, the field is a non-empty ASCII
+    /// alphanumeric name that may contain `_`, `-` and `.`
+    pub fn is_translation_key(key: &str) -> bool {
+        if key.len() > Self::MAX_KEY_LEN {
+            return false;
+        }
+        let Some((locale, field)) = key
+            .strip_prefix(Self::KEY_PREFIX)
+            .and_then(|rest| rest.split_once('.'))
+        else {
+            return false;
+        };
+
+        let locale_valid = !locale.is_empty()
+            && locale.len() <= Self::MAX_LOCALE_LEN
+            && locale.starts_with(|c: char| c.is_ascii_alphabetic())
+            && locale
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-');
+        let field_valid = !field.is_empty()
+            && field
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b'.'));
+
+        locale_valid && field_valid
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<MetadataTranslator, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 4] {
+        [
+            Seed::from(METADATA_TRANSLATOR_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(self.translator.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                METADATA_TRANSLATOR_ACCOUNT,
+                &self.mint,
+                &self.translator,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_translator_serialization_round_trip() {
+        let translator = MetadataTranslator::new([1u8; 32], [2u8; 32], 253);
+
+        let bytes = translator.to_bytes();
+        assert_eq!(bytes.len(), MetadataTranslator::LEN);
+        assert_eq!(bytes[0], MetadataTranslator::DISCRIMINATOR);
+
+        let deserialized = MetadataTranslator::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, translator.mint);
+        assert_eq!(deserialized.translator, translator.translator);
+        assert_eq!(deserialized.bump, 253);
+    }
+
+    #[test]
+    fn test_is_translation_key() {
+        assert!(MetadataTranslator::is_translation_key(
+            "i18n.fr.description"
+        ));
+        assert!(MetadataTranslator::is_translation_key(
+            "i18n.pt-BR.risk_disclosure"
+        ));
+        assert!(MetadataTranslator::is_translation_key("i18n.de.terms.v2"));
+
+        assert!(!MetadataTranslator::is_translation_key("description"));
+        assert!(!MetadataTranslator::is_translation_key("i18n.description"));
+        assert!(!MetadataTranslator::is_translation_key("i18n..description"));
+        assert!(!MetadataTranslator::is_translation_key("i18n.fr."));
+        assert!(!MetadataTranslator::is_translation_key(
+            "i18n.1x.description"
+        ));
+        assert!(!MetadataTranslator::is_translation_key(
+            "i18n.fr.desc ription"
+        ));
+        assert!(!MetadataTranslator::is_translation_key(
+            "I18N.fr.description"
+        ));
+        let long_field = format!("i18n.fr.{}", "a".repeat(MetadataTranslator::MAX_KEY_LEN));
+        assert!(!MetadataTranslator::is_translation_key(&long_field));
+    }
+}
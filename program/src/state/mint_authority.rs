@@ -1,7 +1,8 @@
 //! Mint configuration account state
-use crate::constants::seeds;
+use crate::constants::{seeds, MAX_MULTISIG_SIGNERS};
 use crate::state::{
-    AccountDeserialize, AccountSerialize, Discriminator, SecurityTokenDiscriminators,
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
 };
 use pinocchio::account_info::{AccountInfo, Ref};
 use pinocchio::instruction::Seed;
@@ -19,6 +20,14 @@ pub struct MintAuthority {
     pub mint_creator: Pubkey,
     /// Bump seed used for mint authority PDA derivation
     pub bump: u8,
+    /// Key acting as the mint creator, the creator itself until a creator transfer is accepted
+    pub controller: Pubkey,
+    /// Controller proposed by ProposeMintCreatorTransfer, awaiting acceptance
+    pub pending_controller: Option<Pubkey>,
+    /// Number of `multisig_signers` that must sign in place of the controller, `0` without multisig
+    pub multisig_threshold: u8,
+    /// Keys of the M-of-N multisig controlling the mint
+    pub multisig_signers: Vec<Pubkey>,
 }
 
 impl Discriminator for MintAuthority {
@@ -33,13 +42,32 @@ impl AccountSerialize for MintAuthority {
         data.extend_from_slice(self.mint_creator.as_ref());
         data.push(self.bump);
 
+        // Write controller and pending controller (64 bytes), omitted until a creator transfer
+        // is proposed or a multisig is set so untransferred accounts keep their original layout
+        if self.controller != self.mint_creator
+            || self.pending_controller.is_some()
+            || self.is_multisig()
+        {
+            data.extend_from_slice(self.controller.as_ref());
+            data.extend_from_slice(self.pending_controller.unwrap_or_default().as_ref());
+        }
+
+        // Write multisig threshold (1 byte), signer count (4 bytes) and signers (32 bytes each)
+        if self.is_multisig() {
+            data.push(self.multisig_threshold);
+            data.extend(&(self.multisig_signers.len() as u32).to_le_bytes());
+            for signer in &self.multisig_signers {
+                data.extend_from_slice(signer.as_ref());
+            }
+        }
+
         data
     }
 }
 
 impl AccountDeserialize for MintAuthority {
     fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
-        if data.len() != Self::LEN - 1 {
+        if data.len() != Self::LEN - 1 && data.len() < Self::TRANSFER_LEN - 1 {
             return Err(ProgramError::InvalidAccountData);
         }
 
@@ -59,11 +87,61 @@ impl AccountDeserialize for MintAuthority {
 
         // Read bump (1 byte)
         let bump = data[offset];
+        offset += 1;
+
+        // Read controller and pending controller (64 bytes) if present
+        let mint_creator = Pubkey::from(mint_creator_bytes);
+        let (controller, pending_controller) = if data.len() > offset {
+            let controller: Pubkey = data[offset..offset + PUBKEY_BYTES]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            offset += PUBKEY_BYTES;
+            let pending_controller: Pubkey = data[offset..offset + PUBKEY_BYTES]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            offset += PUBKEY_BYTES;
+            (
+                controller,
+                Some(pending_controller).filter(|key| *key != Pubkey::default()),
+            )
+        } else {
+            (mint_creator, None)
+        };
+
+        // Read multisig threshold (1 byte), signer count (4 bytes) and signers if present
+        let (multisig_threshold, multisig_signers) = if data.len() > offset {
+            let threshold = data[offset];
+            offset += 1;
+            let signer_count = data
+                .get(offset..offset + 4)
+                .and_then(|slice| slice.try_into().ok())
+                .map(u32::from_le_bytes)
+                .ok_or(ProgramError::InvalidAccountData)? as usize;
+            offset += 4;
+            if data.len() != offset + signer_count * PUBKEY_BYTES {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            let signers = data[offset..]
+                .chunks_exact(PUBKEY_BYTES)
+                .map(|chunk| {
+                    chunk
+                        .try_into()
+                        .map_err(|_| ProgramError::InvalidAccountData)
+                })
+                .collect::<Result<Vec<Pubkey>, ProgramError>>()?;
+            (threshold, signers)
+        } else {
+            (0, Vec::new())
+        };
 
         let config = Self {
             mint: Pubkey::from(mint_bytes),
-            mint_creator: Pubkey::from(mint_creator_bytes),
+            mint_creator,
             bump,
+            controller,
+            pending_controller,
+            multisig_threshold,
+            multisig_signers,
         };
 
         config.validate()?;
@@ -72,16 +150,29 @@ impl AccountDeserialize for MintAuthority {
     }
 }
 
+impl ProgramAccount for MintAuthority {
+    fn space(&self) -> u64 {
+        self.to_bytes().len() as u64
+    }
+}
+
 impl MintAuthority {
     /// Serialized size of the account data (discriminator + mint + creator + bump)
     pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 1;
 
+    /// Serialized size once a creator transfer was proposed (+ controller + pending controller)
+    pub const TRANSFER_LEN: usize = Self::LEN + (2 * PUBKEY_BYTES);
+
     /// Create a new MintAuthority
     pub fn new(mint: Pubkey, mint_creator: Pubkey, bump: u8) -> Result<Self, ProgramError> {
         let config = Self {
             mint,
             mint_creator,
             bump,
+            controller: mint_creator,
+            pending_controller: None,
+            multisig_threshold: 0,
+            multisig_signers: Vec::new(),
         };
         config.validate()?;
         Ok(config)
@@ -93,7 +184,13 @@ impl MintAuthority {
             return Err(ProgramError::InvalidAccountData);
         }
 
-        if self.mint_creator == Pubkey::default() {
+        if self.mint_creator == Pubkey::default() || self.controller == Pubkey::default() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if (self.multisig_threshold == 0) != self.multisig_signers.is_empty()
+            || self.multisig_threshold as usize > self.multisig_signers.len()
+        {
             return Err(ProgramError::InvalidAccountData);
         }
 
@@ -113,12 +210,69 @@ impl MintAuthority {
         }
 
         let data_ref = account_info.try_borrow_data()?;
-        let mint_authority = Self::try_from_bytes(&data_ref[..MintAuthority::LEN])?;
+        let mint_authority = Self::try_from_bytes(&data_ref)?;
         Ok(Ref::map(account_info.try_borrow_data()?, |_| {
             &*Box::leak(Box::new(mint_authority))
         }))
     }
 
+    /// Propose `new_controller` to take over from the controller, the default pubkey cancels
+    /// a pending proposal
+    pub fn propose_controller(&mut self, new_controller: Pubkey) -> Result<(), ProgramError> {
+        if new_controller == self.controller {
+            return Err(ProgramError::InvalidArgument);
+        }
+        self.pending_controller = Some(new_controller).filter(|key| *key != Pubkey::default());
+        Ok(())
+    }
+
+    /// Hand control over to the pending controller, which must be `signer`
+    pub fn accept_controller(&mut self, signer: &Pubkey) -> Result<(), ProgramError> {
+        if self.pending_controller != Some(*signer) {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        self.controller = *signer;
+        self.pending_controller = None;
+        Ok(())
+    }
+
+    /// Whether an M-of-N multisig controls the mint in place of the controller
+    pub fn is_multisig(&self) -> bool {
+        self.multisig_threshold > 0
+    }
+
+    /// Whether `other` requires the same threshold of the same multisig signers
+    pub fn has_same_multisig(&self, other: &MintAuthority) -> bool {
+        self.multisig_threshold == other.multisig_threshold
+            && self.multisig_signers.len() == other.multisig_signers.len()
+            && self
+                .multisig_signers
+                .iter()
+                .all(|signer| other.multisig_signers.contains(signer))
+    }
+
+    /// Require `threshold` of `signers` to sign in place of the controller, a threshold of `0`
+    /// without signers removes the multisig
+    pub fn set_multisig(
+        &mut self,
+        threshold: u8,
+        signers: Vec<Pubkey>,
+    ) -> Result<(), ProgramError> {
+        let invalid_signers = signers.len() > MAX_MULTISIG_SIGNERS
+            || signers.iter().enumerate().any(|(index, signer)| {
+                *signer == Pubkey::default() || signers[..index].contains(signer)
+            });
+        if invalid_signers
+            || (threshold == 0) != signers.is_empty()
+            || threshold as usize > signers.len()
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        self.multisig_threshold = threshold;
+        self.multisig_signers = signers;
+        Ok(())
+    }
+
     pub fn bump_seed(&self) -> [u8; 1] {
         [self.bump]
     }
@@ -146,3 +300,89 @@ impl MintAuthority {
         checked_create_program_address(&seeds, &crate::id())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mint_authority_keeps_layout_until_transfer() {
+        let mint_authority = MintAuthority::new([1u8; 32], [2u8; 32], 255).unwrap();
+        let bytes = mint_authority.to_bytes();
+        assert_eq!(bytes.len(), MintAuthority::LEN);
+
+        let deserialized = MintAuthority::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.controller, [2u8; 32]);
+        assert_eq!(deserialized.pending_controller, None);
+    }
+
+    #[test]
+    fn test_mint_authority_creator_transfer() {
+        let mut mint_authority = MintAuthority::new([1u8; 32], [2u8; 32], 255).unwrap();
+        assert!(mint_authority.propose_controller([2u8; 32]).is_err());
+        assert!(mint_authority.accept_controller(&[3u8; 32]).is_err());
+
+        mint_authority.propose_controller([3u8; 32]).unwrap();
+        let bytes = mint_authority.to_bytes();
+        assert_eq!(bytes.len(), MintAuthority::TRANSFER_LEN);
+        let mut deserialized = MintAuthority::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.controller, [2u8; 32]);
+        assert_eq!(deserialized.pending_controller, Some([3u8; 32]));
+
+        assert!(deserialized.accept_controller(&[4u8; 32]).is_err());
+        deserialized.accept_controller(&[3u8; 32]).unwrap();
+        assert_eq!(deserialized.controller, [3u8; 32]);
+        assert_eq!(deserialized.pending_controller, None);
+        // The PDA stays derived from the original creator
+        assert_eq!(deserialized.mint_creator, [2u8; 32]);
+
+        let bytes = deserialized.to_bytes();
+        assert_eq!(bytes.len(), MintAuthority::TRANSFER_LEN);
+        let deserialized = MintAuthority::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.controller, [3u8; 32]);
+        assert_eq!(deserialized.pending_controller, None);
+    }
+
+    #[test]
+    fn test_mint_authority_multisig() {
+        let mut mint_authority = MintAuthority::new([1u8; 32], [2u8; 32], 255).unwrap();
+        assert!(mint_authority
+            .set_multisig(3, vec![[3u8; 32], [4u8; 32]])
+            .is_err());
+        assert!(mint_authority
+            .set_multisig(1, vec![[3u8; 32], [3u8; 32]])
+            .is_err());
+        assert!(mint_authority.set_multisig(0, vec![[3u8; 32]]).is_err());
+        assert!(mint_authority
+            .set_multisig(1, vec![[3u8; 32]; MAX_MULTISIG_SIGNERS + 1])
+            .is_err());
+
+        mint_authority
+            .set_multisig(2, vec![[3u8; 32], [4u8; 32], [5u8; 32]])
+            .unwrap();
+        let bytes = mint_authority.to_bytes();
+        assert_eq!(bytes.len(), MintAuthority::TRANSFER_LEN + 1 + 4 + 3 * 32);
+        let mut deserialized = MintAuthority::try_from_bytes(&bytes).unwrap();
+        assert!(deserialized.is_multisig());
+        assert_eq!(deserialized.multisig_threshold, 2);
+        assert_eq!(deserialized.multisig_signers.len(), 3);
+        assert_eq!(deserialized.controller, [2u8; 32]);
+        assert!(MintAuthority::try_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        // The same signers in another order form the same multisig
+        let mut other = MintAuthority::new([6u8; 32], [2u8; 32], 255).unwrap();
+        assert!(!deserialized.has_same_multisig(&other));
+        other
+            .set_multisig(2, vec![[5u8; 32], [3u8; 32], [4u8; 32]])
+            .unwrap();
+        assert!(deserialized.has_same_multisig(&other));
+        other
+            .set_multisig(1, vec![[5u8; 32], [3u8; 32], [4u8; 32]])
+            .unwrap();
+        assert!(!deserialized.has_same_multisig(&other));
+
+        // Removing the multisig restores the original layout
+        deserialized.set_multisig(0, Vec::new()).unwrap();
+        assert_eq!(deserialized.to_bytes().len(), MintAuthority::LEN);
+    }
+}
@@ -0,0 +1,214 @@
+//! Mint migration account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::MINT_MIGRATION_ACCOUNT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Migration of a security token to a new mint with a different extension set,
+/// created by CreateMintMigration and advanced by every MigrateBalances batch
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct MintMigration {
+    /// Security token mint being migrated away from
+    pub mint_from: Pubkey,
+    /// Security token mint receiving the migrated balances
+    pub mint_to: Pubkey,
+    /// Action id of the 1:1 Rate between the mints
+    pub action_id: u64,
+    /// Number of token accounts migrated so far
+    pub migrated_accounts: u64,
+    /// Amount of `mint_from` tokens burned so far
+    pub migrated_amount: u64,
+    /// Unix timestamp of the migration creation
+    pub created_at: i64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for MintMigration {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::MintMigrationDiscriminator as u8;
+}
+
+impl AccountSerialize for MintMigration {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint_from.as_ref());
+        data.extend_from_slice(self.mint_to.as_ref());
+        data.extend_from_slice(&self.action_id.to_le_bytes());
+        data.extend_from_slice(&self.migrated_accounts.to_le_bytes());
+        data.extend_from_slice(&self.migrated_amount.to_le_bytes());
+        data.extend_from_slice(&self.created_at.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for MintMigration {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint_from: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let mint_to: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let mut offset = 2 * PUBKEY_BYTES;
+        let action_id = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let migrated_accounts = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let migrated_amount = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let created_at = i64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+
+        Ok(Self {
+            mint_from,
+            mint_to,
+            action_id,
+            migrated_accounts,
+            migrated_amount,
+            created_at,
+            bump: data[offset],
+        })
+    }
+}
+
+impl ProgramAccount for MintMigration {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl MintMigration {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 2 pubkeys (64 bytes) + action_id (8 bytes)
+    /// + migrated_accounts (8 bytes) + migrated_amount (8 bytes) + created_at (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 8 + 8 + 8 + 8 + 1;
+
+    /// Create a new MintMigration without migrated balances
+    pub fn new(
+        mint_from: Pubkey,
+        mint_to: Pubkey,
+        action_id: u64,
+        created_at: i64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            mint_from,
+            mint_to,
+            action_id,
+            migrated_accounts: 0,
+            migrated_amount: 0,
+            created_at,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<MintMigration, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Record a migrated token account and its burned amount
+    pub fn record(&mut self, amount: u64) -> Result<(), ProgramError> {
+        self.migrated_accounts = self
+            .migrated_accounts
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.migrated_amount = self
+            .migrated_amount
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(MINT_MIGRATION_ACCOUNT),
+            Seed::from(self.mint_from.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[MINT_MIGRATION_ACCOUNT, &self.mint_from, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mint_migration_serialization_round_trip() {
+        let mut migration = MintMigration::new([1u8; 32], [2u8; 32], 7, 1_700_000_000, 254);
+        migration.record(1_000).unwrap();
+        migration.record(0).unwrap();
+
+        let bytes = migration.to_bytes();
+        assert_eq!(bytes.len(), MintMigration::LEN);
+        assert_eq!(bytes[0], MintMigration::DISCRIMINATOR);
+
+        let deserialized = MintMigration::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint_from, migration.mint_from);
+        assert_eq!(deserialized.mint_to, migration.mint_to);
+        assert_eq!(deserialized.action_id, 7);
+        assert_eq!(deserialized.migrated_accounts, 2);
+        assert_eq!(deserialized.migrated_amount, 1_000);
+        assert_eq!(deserialized.created_at, 1_700_000_000);
+        assert_eq!(deserialized.bump, 254);
+    }
+
+    #[test]
+    fn test_mint_migration_record_overflow() {
+        let mut migration = MintMigration::new([1u8; 32], [2u8; 32], 7, 0, 254);
+        migration.record(u64::MAX).unwrap();
+        assert_eq!(migration.record(1), Err(ProgramError::ArithmeticOverflow));
+    }
+}
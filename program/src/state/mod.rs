@@ -5,21 +5,102 @@
 //! - Verification configurations
 //! - Discriminator configurations
 
+pub mod agent;
+pub mod allowlist_entry;
+pub mod auction;
+pub mod claim_bitmap;
+pub mod claim_deadline;
+pub mod collateral_attestation;
+pub mod config_admin;
+pub mod config_template;
+pub mod coupon_accrual;
 pub mod discriminator;
 pub mod distribution_escrow_authority;
+pub mod distribution_payout;
+pub mod distribution_root;
+pub mod document_registry;
+pub mod freeze_expiry;
+pub mod holding_period;
+pub mod identity;
+pub mod maturity;
+pub mod metadata_freeze;
+pub mod metadata_schema;
+pub mod metadata_translator;
 pub mod mint_authority;
+pub mod mint_migration;
+pub mod nav_oracle;
+pub mod pause_delegate;
+pub mod pending_verification_config;
+pub mod permanent_delegate_config;
+pub mod permit_nonce;
+pub mod position_limit;
 pub mod program_account;
+pub mod program_config;
 pub mod proof;
 pub mod rate;
 pub mod receipt;
+pub mod recovery_receipt;
+pub mod reserve_attestation;
+pub mod session_key;
+pub mod sub_account_ledger;
+pub mod subscription;
+pub mod succession_policy;
+pub mod suspension;
+pub mod transfer_acceptance;
+pub mod transfer_approval;
 pub mod verification;
+pub mod vesting;
+pub mod withholding_rate;
+pub mod wrap_vault;
+
+#[cfg(test)]
+mod layout_snapshots;
 
 // Re-export all structures for convenience
+pub use agent::*;
+pub use allowlist_entry::*;
+pub use auction::*;
+pub use claim_bitmap::*;
+pub use claim_deadline::*;
+pub use collateral_attestation::*;
+pub use config_admin::*;
+pub use config_template::*;
+pub use coupon_accrual::*;
 pub use discriminator::*;
 pub use distribution_escrow_authority::*;
+pub use distribution_payout::*;
+pub use distribution_root::*;
+pub use document_registry::*;
+pub use freeze_expiry::*;
+pub use holding_period::*;
+pub use identity::*;
+pub use maturity::*;
+pub use metadata_freeze::*;
+pub use metadata_schema::*;
+pub use metadata_translator::*;
 pub use mint_authority::*;
+pub use mint_migration::*;
+pub use nav_oracle::*;
+pub use pause_delegate::*;
+pub use pending_verification_config::*;
+pub use permanent_delegate_config::*;
+pub use permit_nonce::*;
+pub use position_limit::*;
 pub use program_account::*;
+pub use program_config::*;
 pub use proof::*;
 pub use rate::*;
 pub use receipt::*;
+pub use recovery_receipt::*;
+pub use reserve_attestation::*;
+pub use session_key::*;
+pub use sub_account_ledger::*;
+pub use subscription::*;
+pub use succession_policy::*;
+pub use suspension::*;
+pub use transfer_acceptance::*;
+pub use transfer_approval::*;
 pub use verification::*;
+pub use vesting::*;
+pub use withholding_rate::*;
+pub use wrap_vault::*;
@@ -0,0 +1,316 @@
+//! NAV oracle state driving the ScaledUiAmount multiplier
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::NAV_ORACLE_ACCOUNT;
+use crate::error::SecurityTokenError;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Maximum fixed-point decimals of an oracle value
+pub const MAX_ORACLE_VALUE_DECIMALS: u8 = 18;
+
+/// Read the fixed-point u64 (LE) value stored at `value_offset` of an oracle account
+pub fn read_oracle_value(oracle_data: &[u8], value_offset: u32) -> Result<u64, ProgramError> {
+    let start = value_offset as usize;
+    let value_bytes = oracle_data
+        .get(start..start + 8)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    Ok(u64::from_le_bytes(
+        value_bytes
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?,
+    ))
+}
+
+/// Whitelisted oracle of a mint. The oracle account stores a fixed-point u64 (LE) value at
+/// `value_offset` which becomes the ScaledUiAmount multiplier once it passes the bounds and
+/// the minimum update interval.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct NavOracle {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Oracle account the multiplier is read from
+    pub oracle: Pubkey,
+    /// Byte offset of the value in the oracle account data
+    pub value_offset: u32,
+    /// Fixed-point decimals of the oracle value
+    pub value_decimals: u8,
+    /// Lowest accepted oracle value
+    pub min_value: u64,
+    /// Highest accepted oracle value
+    pub max_value: u64,
+    /// Minimum number of seconds between two multiplier updates
+    pub min_update_interval: u32,
+    /// Unix timestamp of the last multiplier update, 0 if never updated
+    pub last_update_timestamp: i64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for NavOracle {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::NavOracleDiscriminator as u8;
+}
+
+impl AccountSerialize for NavOracle {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.oracle.as_ref());
+        data.extend_from_slice(&self.value_offset.to_le_bytes());
+        data.push(self.value_decimals);
+        data.extend_from_slice(&self.min_value.to_le_bytes());
+        data.extend_from_slice(&self.max_value.to_le_bytes());
+        data.extend_from_slice(&self.min_update_interval.to_le_bytes());
+        data.extend_from_slice(&self.last_update_timestamp.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for NavOracle {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let oracle: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let mut offset = 2 * PUBKEY_BYTES;
+        let value_offset = u32::from_le_bytes(
+            data[offset..offset + 4]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 4;
+        let value_decimals = data[offset];
+        offset += 1;
+        let min_value = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let max_value = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let min_update_interval = u32::from_le_bytes(
+            data[offset..offset + 4]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 4;
+        let last_update_timestamp = i64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+
+        Ok(Self {
+            mint,
+            oracle,
+            value_offset,
+            value_decimals,
+            min_value,
+            max_value,
+            min_update_interval,
+            last_update_timestamp,
+            bump: data[offset],
+        })
+    }
+}
+
+impl ProgramAccount for NavOracle {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl NavOracle {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 2 pubkeys (64 bytes) + value_offset (4 bytes) + value_decimals (1 byte)
+    /// + min_value (8 bytes) + max_value (8 bytes) + min_update_interval (4 bytes)
+    /// + last_update_timestamp (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 4 + 1 + 8 + 8 + 4 + 8 + 1;
+
+    /// Create a new NavOracle
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mint: Pubkey,
+        oracle: Pubkey,
+        value_offset: u32,
+        value_decimals: u8,
+        min_value: u64,
+        max_value: u64,
+        min_update_interval: u32,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        if min_value == 0 || min_value > max_value || value_decimals > MAX_ORACLE_VALUE_DECIMALS {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(Self {
+            mint,
+            oracle,
+            value_offset,
+            value_decimals,
+            min_value,
+            max_value,
+            min_update_interval,
+            last_update_timestamp: 0,
+            bump,
+        })
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<NavOracle, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Read the oracle value from the oracle account data
+    pub fn read_value(&self, oracle_data: &[u8]) -> Result<u64, ProgramError> {
+        read_oracle_value(oracle_data, self.value_offset)
+    }
+
+    /// Validate an oracle value against the bounds and the update frequency and record the update
+    pub fn record_update(&mut self, value: u64, timestamp: i64) -> Result<(), ProgramError> {
+        if value < self.min_value || value > self.max_value {
+            return Err(SecurityTokenError::OracleValueOutOfBounds.into());
+        }
+        let next_update = self
+            .last_update_timestamp
+            .checked_add(i64::from(self.min_update_interval))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        if timestamp < next_update {
+            return Err(SecurityTokenError::MultiplierUpdateTooFrequent.into());
+        }
+        self.last_update_timestamp = timestamp;
+        Ok(())
+    }
+
+    /// ScaledUiAmount multiplier represented by an oracle value
+    pub fn multiplier(&self, value: u64) -> f64 {
+        value as f64 / 10f64.powi(i32::from(self.value_decimals))
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(NAV_ORACLE_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[NAV_ORACLE_ACCOUNT, &self.mint, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nav_oracle() -> NavOracle {
+        // NAV between 0.5 and 2.0 with 6 decimals, at most once per hour
+        NavOracle::new([1u8; 32], [2u8; 32], 8, 6, 500_000, 2_000_000, 3_600, 255).unwrap()
+    }
+
+    #[test]
+    fn test_nav_oracle_serialization_round_trip() {
+        let mut nav_oracle = nav_oracle();
+        nav_oracle.last_update_timestamp = 1_700_000_000;
+
+        let bytes = nav_oracle.to_bytes();
+        assert_eq!(bytes.len(), NavOracle::LEN);
+
+        let deserialized = NavOracle::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, nav_oracle.mint);
+        assert_eq!(deserialized.oracle, nav_oracle.oracle);
+        assert_eq!(deserialized.value_offset, nav_oracle.value_offset);
+        assert_eq!(deserialized.value_decimals, nav_oracle.value_decimals);
+        assert_eq!(deserialized.min_value, nav_oracle.min_value);
+        assert_eq!(deserialized.max_value, nav_oracle.max_value);
+        assert_eq!(
+            deserialized.min_update_interval,
+            nav_oracle.min_update_interval
+        );
+        assert_eq!(
+            deserialized.last_update_timestamp,
+            nav_oracle.last_update_timestamp
+        );
+        assert_eq!(deserialized.bump, nav_oracle.bump);
+    }
+
+    #[test]
+    fn test_nav_oracle_rejects_invalid_arguments() {
+        assert!(NavOracle::new([1u8; 32], [2u8; 32], 0, 6, 0, 1, 0, 255).is_err());
+        assert!(NavOracle::new([1u8; 32], [2u8; 32], 0, 6, 2, 1, 0, 255).is_err());
+        assert!(NavOracle::new([1u8; 32], [2u8; 32], 0, 19, 1, 1, 0, 255).is_err());
+    }
+
+    #[test]
+    fn test_read_value_at_offset() {
+        let nav_oracle = nav_oracle();
+        let mut data = vec![0u8; 16];
+        data[8..16].copy_from_slice(&1_250_000u64.to_le_bytes());
+
+        assert_eq!(nav_oracle.read_value(&data).unwrap(), 1_250_000);
+        assert_eq!(nav_oracle.multiplier(1_250_000), 1.25);
+        assert!(nav_oracle.read_value(&data[..15]).is_err());
+    }
+
+    #[test]
+    fn test_record_update_checks_bounds_and_frequency() {
+        let mut nav_oracle = nav_oracle();
+
+        assert_eq!(
+            nav_oracle.record_update(499_999, 10_000),
+            Err(SecurityTokenError::OracleValueOutOfBounds.into())
+        );
+        assert_eq!(
+            nav_oracle.record_update(2_000_001, 10_000),
+            Err(SecurityTokenError::OracleValueOutOfBounds.into())
+        );
+
+        nav_oracle.record_update(1_000_000, 10_000).unwrap();
+        assert_eq!(nav_oracle.last_update_timestamp, 10_000);
+
+        assert_eq!(
+            nav_oracle.record_update(1_000_000, 13_599),
+            Err(SecurityTokenError::MultiplierUpdateTooFrequent.into())
+        );
+        nav_oracle.record_update(2_000_000, 13_600).unwrap();
+    }
+}
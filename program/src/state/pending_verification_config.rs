@@ -0,0 +1,258 @@
+//! Pending verification config state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::PENDING_VERIFICATION_CONFIG;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators, VerificationConfig,
+};
+
+/// Update of a timelocked verification config staged by UpdateVerificationConfig.
+/// It replaces the config once `activates_at` has passed, until then it can only be cancelled.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct PendingVerificationConfig {
+    /// Mint of the verification config
+    pub mint: Pubkey,
+    /// Instruction discriminator of the verification config
+    pub instruction_discriminator: u8,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+    /// Unix timestamp from which the update can be applied
+    pub activates_at: i64,
+    /// CPI mode the config switches to
+    pub cpi_mode: bool,
+    /// Threshold the config switches to
+    pub threshold: u8,
+    /// Introspection lookback window the config switches to
+    pub lookback_window: u8,
+    /// Top-level requirement the config switches to
+    pub require_top_level: bool,
+    /// Timelock the config switches to
+    pub timelock_seconds: u64,
+    /// Complete program list the config switches to
+    pub verification_programs: Vec<Pubkey>,
+}
+
+impl Discriminator for PendingVerificationConfig {
+    const DISCRIMINATOR: u8 =
+        SecurityTokenDiscriminators::PendingVerificationConfigDiscriminator as u8;
+}
+
+impl AccountSerialize for PendingVerificationConfig {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::size_of(self.verification_programs.len()) - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.push(self.instruction_discriminator);
+        data.push(self.bump);
+        data.extend_from_slice(&self.activates_at.to_le_bytes());
+        data.push(self.cpi_mode as u8);
+        data.push(self.threshold);
+        data.push(self.lookback_window);
+        data.push(self.require_top_level as u8);
+        data.extend_from_slice(&self.timelock_seconds.to_le_bytes());
+        data.extend_from_slice(&(self.verification_programs.len() as u32).to_le_bytes());
+        for program in &self.verification_programs {
+            data.extend_from_slice(program.as_ref());
+        }
+
+        data
+    }
+}
+
+impl AccountDeserialize for PendingVerificationConfig {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::MIN_LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let mut offset = PUBKEY_BYTES;
+        let instruction_discriminator = data[offset];
+        let bump = data[offset + 1];
+        offset += 2;
+        let activates_at = i64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let cpi_mode = data[offset] != 0;
+        let threshold = data[offset + 1];
+        let lookback_window = data[offset + 2];
+        let require_top_level = data[offset + 3] != 0;
+        offset += 4;
+        let timelock_seconds = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let program_count = u32::from_le_bytes(
+            data[offset..offset + 4]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        ) as usize;
+        offset += 4;
+
+        if data.len() - offset != program_count * PUBKEY_BYTES {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let verification_programs = data[offset..]
+            .chunks_exact(PUBKEY_BYTES)
+            .map(|chunk| {
+                chunk
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidAccountData)
+            })
+            .collect::<Result<Vec<Pubkey>, ProgramError>>()?;
+
+        Ok(Self {
+            mint,
+            instruction_discriminator,
+            bump,
+            activates_at,
+            cpi_mode,
+            threshold,
+            lookback_window,
+            require_top_level,
+            timelock_seconds,
+            verification_programs,
+        })
+    }
+}
+
+impl ProgramAccount for PendingVerificationConfig {
+    fn space(&self) -> u64 {
+        Self::size_of(self.verification_programs.len()) as u64
+    }
+}
+
+impl PendingVerificationConfig {
+    /// Serialized size without programs
+    /// Discriminator (1) + mint (32) + instruction_discriminator (1) + bump (1) + activates_at (8)
+    /// + cpi_mode (1) + threshold (1) + lookback_window (1) + require_top_level (1)
+    /// + timelock_seconds (8) + vector length (4)
+    pub const MIN_LEN: usize = 1 + PUBKEY_BYTES + 1 + 1 + 8 + 1 + 1 + 1 + 1 + 8 + 4;
+
+    /// Stage the settings and programs of `config` to apply at `activates_at`
+    pub fn new(mint: Pubkey, bump: u8, activates_at: i64, config: &VerificationConfig) -> Self {
+        Self {
+            mint,
+            instruction_discriminator: config.instruction_discriminator,
+            bump,
+            activates_at,
+            cpi_mode: config.cpi_mode,
+            threshold: config.threshold,
+            lookback_window: config.lookback_window,
+            require_top_level: config.require_top_level,
+            timelock_seconds: config.timelock_seconds,
+            verification_programs: config.verification_programs.clone(),
+        }
+    }
+
+    /// Serialized size of a pending config with `program_count` programs
+    pub fn size_of(program_count: usize) -> usize {
+        Self::MIN_LEN + program_count * PUBKEY_BYTES
+    }
+
+    /// Write the staged settings and programs into `config`
+    pub fn apply_to(&self, config: &mut VerificationConfig) {
+        config.cpi_mode = self.cpi_mode;
+        config.threshold = self.threshold;
+        config.lookback_window = self.lookback_window;
+        config.require_top_level = self.require_top_level;
+        config.timelock_seconds = self.timelock_seconds;
+        config.verification_programs = self.verification_programs.clone();
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<PendingVerificationConfig, ProgramError> {
+        if account_info.data_len() < Self::MIN_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn instruction_discriminator_seed(&self) -> [u8; 1] {
+        [self.instruction_discriminator]
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        instruction_discriminator_seed: &'a [u8; 1],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 4] {
+        [
+            Seed::from(PENDING_VERIFICATION_CONFIG),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(instruction_discriminator_seed.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                PENDING_VERIFICATION_CONFIG,
+                &self.mint,
+                &self.instruction_discriminator_seed(),
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_verification_config_round_trip() {
+        let mut config =
+            VerificationConfig::new(12, true, 254, &[[2u8; 32], [3u8; 32]], 1, 2, true).unwrap();
+        config.timelock_seconds = 3_600;
+        let pending = PendingVerificationConfig::new([1u8; 32], 253, 1_700_000_000, &config);
+
+        let bytes = pending.to_bytes();
+        assert_eq!(bytes.len(), PendingVerificationConfig::size_of(2));
+
+        let deserialized = PendingVerificationConfig::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, [1u8; 32]);
+        assert_eq!(deserialized.instruction_discriminator, 12);
+        assert_eq!(deserialized.activates_at, 1_700_000_000);
+        assert_eq!(deserialized.timelock_seconds, 3_600);
+
+        let mut applied =
+            VerificationConfig::new(12, false, 254, &[[5u8; 32]], 0, 0, false).unwrap();
+        deserialized.apply_to(&mut applied);
+        assert!(applied.cpi_mode);
+        assert_eq!(applied.threshold, 1);
+        assert_eq!(applied.lookback_window, 2);
+        assert!(applied.require_top_level);
+        assert_eq!(applied.timelock_seconds, 3_600);
+        assert_eq!(applied.verification_programs, vec![[2u8; 32], [3u8; 32]]);
+    }
+}
@@ -0,0 +1,152 @@
+//! Permanent delegate rotation state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::PERMANENT_DELEGATE_CONFIG_ACCOUNT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Permanent delegate of a mint once it was rotated away from the program PDA.
+/// While it exists the transfer hook lets transfers of `delegate` through unverified;
+/// the default pubkey records a renounced delegate.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct PermanentDelegateConfig {
+    /// Mint the delegate belongs to
+    pub mint: Pubkey,
+    /// Current permanent delegate, default pubkey once renounced
+    pub delegate: Pubkey,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for PermanentDelegateConfig {
+    const DISCRIMINATOR: u8 =
+        SecurityTokenDiscriminators::PermanentDelegateConfigDiscriminator as u8;
+}
+
+impl AccountSerialize for PermanentDelegateConfig {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.delegate.as_ref());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for PermanentDelegateConfig {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let delegate: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let bump = data[2 * PUBKEY_BYTES];
+
+        Ok(Self {
+            mint,
+            delegate,
+            bump,
+        })
+    }
+}
+
+impl ProgramAccount for PermanentDelegateConfig {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl PermanentDelegateConfig {
+    /// Serialized size of the account data (discriminator + mint + delegate + bump)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + PUBKEY_BYTES + 1;
+
+    /// Create a new PermanentDelegateConfig
+    pub fn new(mint: Pubkey, delegate: Pubkey, bump: u8) -> Self {
+        Self {
+            mint,
+            delegate,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<PermanentDelegateConfig, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Whether the permanent delegate was renounced
+    pub fn is_disabled(&self) -> bool {
+        self.delegate == Pubkey::default()
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(PERMANENT_DELEGATE_CONFIG_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                PERMANENT_DELEGATE_CONFIG_ACCOUNT,
+                &self.mint,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permanent_delegate_config_serialization_round_trip() {
+        let config = PermanentDelegateConfig::new([1u8; 32], [2u8; 32], 254);
+        assert!(!config.is_disabled());
+
+        let bytes = config.to_bytes();
+        assert_eq!(bytes.len(), PermanentDelegateConfig::LEN);
+
+        let deserialized = PermanentDelegateConfig::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, config.mint);
+        assert_eq!(deserialized.delegate, config.delegate);
+        assert_eq!(deserialized.bump, config.bump);
+
+        let renounced = PermanentDelegateConfig::new([1u8; 32], Pubkey::default(), 254);
+        assert!(renounced.is_disabled());
+    }
+}
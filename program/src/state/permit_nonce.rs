@@ -0,0 +1,179 @@
+//! Permit nonce state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::PERMIT_NONCE_ACCOUNT;
+use crate::error::SecurityTokenError;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Next nonce of the transfer permits of a token account. Created with the first
+/// TransferWithPermit, each executed permit consumes one nonce so it cannot be replayed.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct PermitNonce {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Token account the permits transfer from
+    pub token_account: Pubkey,
+    /// Nonce the next permit must carry
+    pub next_nonce: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for PermitNonce {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::PermitNonceDiscriminator as u8;
+}
+
+impl AccountSerialize for PermitNonce {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.token_account.as_ref());
+        data.extend_from_slice(&self.next_nonce.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for PermitNonce {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let token_account: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = 2 * PUBKEY_BYTES;
+        let next_nonce = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            mint,
+            token_account,
+            next_nonce,
+            bump: data[offset + 8],
+        })
+    }
+}
+
+impl ProgramAccount for PermitNonce {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl PermitNonce {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 2 pubkeys (64 bytes) + next_nonce (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 8 + 1;
+
+    /// Create a new PermitNonce expecting nonce 0
+    pub fn new(mint: Pubkey, token_account: Pubkey, bump: u8) -> Self {
+        Self {
+            mint,
+            token_account,
+            next_nonce: 0,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<PermitNonce, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Consume `nonce`, which must be the next nonce of the token account
+    pub fn consume(&mut self, nonce: u64) -> Result<(), ProgramError> {
+        if nonce != self.next_nonce {
+            return Err(SecurityTokenError::InvalidPermitNonce.into());
+        }
+        self.next_nonce = self
+            .next_nonce
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(PERMIT_NONCE_ACCOUNT),
+            Seed::from(self.token_account.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[PERMIT_NONCE_ACCOUNT, &self.token_account, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permit_nonce_serialization_round_trip() {
+        let mut permit_nonce = PermitNonce::new([1u8; 32], [2u8; 32], 254);
+        permit_nonce.next_nonce = 7;
+
+        let bytes = permit_nonce.to_bytes();
+        assert_eq!(bytes.len(), PermitNonce::LEN);
+        assert_eq!(bytes[0], PermitNonce::DISCRIMINATOR);
+
+        let deserialized = PermitNonce::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, permit_nonce.mint);
+        assert_eq!(deserialized.token_account, permit_nonce.token_account);
+        assert_eq!(deserialized.next_nonce, permit_nonce.next_nonce);
+        assert_eq!(deserialized.bump, permit_nonce.bump);
+    }
+
+    #[test]
+    fn test_permit_nonce_consume_in_order() {
+        let mut permit_nonce = PermitNonce::new([1u8; 32], [2u8; 32], 254);
+
+        assert!(permit_nonce.consume(1).is_err());
+        assert!(permit_nonce.consume(0).is_ok());
+        assert!(permit_nonce.consume(0).is_err());
+        assert!(permit_nonce.consume(1).is_ok());
+        assert_eq!(permit_nonce.next_nonce, 2);
+    }
+
+    #[test]
+    fn test_permit_nonce_rejects_invalid_length() {
+        let bytes = PermitNonce::new([1u8; 32], [2u8; 32], 254).to_bytes();
+        assert!(PermitNonce::try_from_bytes(&bytes[..PermitNonce::LEN - 1]).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+//! Position limit account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::POSITION_LIMIT_ACCOUNT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Basis points in 100%
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+/// Maximum share of the mint supply a single investor may hold across
+/// the token accounts linked to their Identity. Enforced by the transfer hook.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct PositionLimit {
+    /// Mint the limit applies to
+    pub mint: Pubkey,
+    /// Maximum ownership in basis points of the supply
+    pub max_ownership_bps: u16,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for PositionLimit {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::PositionLimitDiscriminator as u8;
+}
+
+impl AccountSerialize for PositionLimit {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(&self.max_ownership_bps.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for PositionLimit {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let max_ownership_bps = u16::from_le_bytes(
+            data[PUBKEY_BYTES..PUBKEY_BYTES + 2]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let bump = data[PUBKEY_BYTES + 2];
+
+        let position_limit = Self {
+            mint,
+            max_ownership_bps,
+            bump,
+        };
+        position_limit.validate()?;
+        Ok(position_limit)
+    }
+}
+
+impl ProgramAccount for PositionLimit {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl PositionLimit {
+    /// Serialized size of the account data (discriminator + mint + max_ownership_bps + bump)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + 2 + 1;
+
+    /// Create a new PositionLimit
+    pub fn new(mint: Pubkey, max_ownership_bps: u16, bump: u8) -> Result<Self, ProgramError> {
+        let position_limit = Self {
+            mint,
+            max_ownership_bps,
+            bump,
+        };
+        position_limit.validate()?;
+        Ok(position_limit)
+    }
+
+    /// Update the maximum ownership
+    pub fn update(&mut self, max_ownership_bps: u16) -> Result<(), ProgramError> {
+        self.max_ownership_bps = max_ownership_bps;
+        self.validate()
+    }
+
+    /// Validate the PositionLimit account data
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        if self.max_ownership_bps == 0 || self.max_ownership_bps > BPS_DENOMINATOR {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<PositionLimit, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(POSITION_LIMIT_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[POSITION_LIMIT_ACCOUNT, &self.mint, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_position_limit_serialization_round_trip() {
+        let position_limit = PositionLimit::new([1u8; 32], 1_000, 254).unwrap();
+
+        let bytes = position_limit.to_bytes();
+        assert_eq!(bytes.len(), PositionLimit::LEN);
+
+        let deserialized = PositionLimit::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, position_limit.mint);
+        assert_eq!(
+            deserialized.max_ownership_bps,
+            position_limit.max_ownership_bps
+        );
+        assert_eq!(deserialized.bump, position_limit.bump);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(BPS_DENOMINATOR + 1)]
+    fn test_position_limit_invalid_bps(#[case] max_ownership_bps: u16) {
+        assert!(PositionLimit::new([1u8; 32], max_ownership_bps, 254).is_err());
+    }
+}
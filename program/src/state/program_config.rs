@@ -0,0 +1,128 @@
+//! Program config state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::PROGRAM_CONFIG;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Program-wide settings of the standard operator, one per program.
+/// The emergency authority halts state-mutating instructions across all mints,
+/// e.g. while a critical vulnerability in a verification integration is fixed.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct ProgramConfig {
+    /// Key allowed to halt and resume the program
+    pub emergency_authority: Pubkey,
+    /// Whether state-mutating instructions are halted
+    pub halted: bool,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for ProgramConfig {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::ProgramConfigDiscriminator as u8;
+}
+
+impl AccountSerialize for ProgramConfig {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.emergency_authority.as_ref());
+        data.push(self.halted as u8);
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for ProgramConfig {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let emergency_authority: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let halted = data[PUBKEY_BYTES] != 0;
+        let bump = data[PUBKEY_BYTES + 1];
+
+        Ok(Self {
+            emergency_authority,
+            halted,
+            bump,
+        })
+    }
+}
+
+impl ProgramAccount for ProgramConfig {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl ProgramConfig {
+    /// Serialized size of the account data (discriminator + emergency_authority + halted + bump)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + 1 + 1;
+
+    /// Create a new, not halted ProgramConfig
+    pub fn new(emergency_authority: Pubkey, bump: u8) -> Self {
+        Self {
+            emergency_authority,
+            halted: false,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<ProgramConfig, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 2] {
+        [Seed::from(PROGRAM_CONFIG), Seed::from(bump_seed.as_ref())]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(&[PROGRAM_CONFIG, &self.bump_seed()], &crate::id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_program_config_serialization_round_trip() {
+        let mut program_config = ProgramConfig::new([1u8; 32], 254);
+        program_config.halted = true;
+
+        let bytes = program_config.to_bytes();
+        assert_eq!(bytes.len(), ProgramConfig::LEN);
+
+        let deserialized = ProgramConfig::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.emergency_authority, [1u8; 32]);
+        assert!(deserialized.halted);
+        assert_eq!(deserialized.bump, 254);
+    }
+}
@@ -1,14 +1,15 @@
 //! Rate account state
 use pinocchio::instruction::Seed;
 use pinocchio::program_error::ProgramError;
-use pinocchio::pubkey::{create_program_address, Pubkey};
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
 use pinocchio::{account_info::AccountInfo, ProgramResult};
 use shank::{ShankAccount, ShankType};
 
 use crate::constants::seeds::RATE_ACCOUNT;
+use crate::error::SecurityTokenError;
 use crate::state::{
-    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
-    SecurityTokenDiscriminators,
+    read_oracle_value, AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators, MAX_ORACLE_VALUE_DECIMALS,
 };
 
 #[repr(u8)]
@@ -48,6 +49,16 @@ pub struct Rate {
     pub denominator: u8,
     /// Bump seed used for PDA derivation
     pub bump: u8,
+    /// Price oracle the conversion ratio is read from, default pubkey for a static rate
+    pub oracle: Pubkey,
+    /// Byte offset of the u64 (LE) price in the oracle account data
+    pub oracle_value_offset: u32,
+    /// Fixed-point decimals of the oracle price
+    pub oracle_value_decimals: u8,
+    /// Lowest accepted oracle price
+    pub oracle_min_value: u64,
+    /// Highest accepted oracle price
+    pub oracle_max_value: u64,
 }
 
 impl Discriminator for Rate {
@@ -62,6 +73,11 @@ impl AccountSerialize for Rate {
         data.push(self.numerator);
         data.push(self.denominator);
         data.push(self.bump);
+        data.extend_from_slice(self.oracle.as_ref());
+        data.extend_from_slice(&self.oracle_value_offset.to_le_bytes());
+        data.push(self.oracle_value_decimals);
+        data.extend_from_slice(&self.oracle_min_value.to_le_bytes());
+        data.extend_from_slice(&self.oracle_max_value.to_le_bytes());
 
         data
     }
@@ -78,11 +94,41 @@ impl AccountDeserialize for Rate {
         let denominator = data[2];
         let bump = data[3];
 
+        let mut offset = 4;
+        let oracle: [u8; PUBKEY_BYTES] = data[offset..offset + PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        offset += PUBKEY_BYTES;
+        let oracle_value_offset = u32::from_le_bytes(
+            data[offset..offset + 4]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 4;
+        let oracle_value_decimals = data[offset];
+        offset += 1;
+        let oracle_min_value = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let oracle_max_value = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
         Ok(Self {
             rounding,
             numerator,
             denominator,
             bump,
+            oracle,
+            oracle_value_offset,
+            oracle_value_decimals,
+            oracle_min_value,
+            oracle_max_value,
         })
     }
 }
@@ -95,7 +141,9 @@ impl ProgramAccount for Rate {
 
 impl Rate {
     /// Serialized size of the account data (discriminator + rounding enum + numerator + denominator + bump)
-    pub const LEN: usize = 1 + 1 + 1 + 1 + 1;
+    /// + oracle (32 bytes) + oracle_value_offset (4 bytes) + oracle_value_decimals (1 byte)
+    /// + oracle_min_value (8 bytes) + oracle_max_value (8 bytes)
+    pub const LEN: usize = 1 + 1 + 1 + 1 + 1 + PUBKEY_BYTES + 4 + 1 + 8 + 8;
 
     /// Create a new Rate
     pub fn new(
@@ -109,11 +157,59 @@ impl Rate {
             numerator,
             denominator,
             bump,
+            oracle: Pubkey::default(),
+            oracle_value_offset: 0,
+            oracle_value_decimals: 0,
+            oracle_min_value: 0,
+            oracle_max_value: 0,
         };
         rate.validate()?;
         Ok(rate)
     }
 
+    /// Whether the conversion ratio is read from a price oracle
+    pub fn has_oracle(&self) -> bool {
+        self.oracle != Pubkey::default()
+    }
+
+    /// Price the rate from `oracle` within `min_value..=max_value`
+    pub fn set_oracle(
+        &mut self,
+        oracle: Pubkey,
+        value_offset: u32,
+        value_decimals: u8,
+        min_value: u64,
+        max_value: u64,
+    ) -> ProgramResult {
+        if oracle == Pubkey::default()
+            || min_value == 0
+            || min_value > max_value
+            || value_decimals > MAX_ORACLE_VALUE_DECIMALS
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        self.oracle = oracle;
+        self.oracle_value_offset = value_offset;
+        self.oracle_value_decimals = value_decimals;
+        self.oracle_min_value = min_value;
+        self.oracle_max_value = max_value;
+        Ok(())
+    }
+
+    /// Read the price from the oracle account data
+    pub fn oracle_value(&self, oracle_data: &[u8]) -> Result<u64, ProgramError> {
+        read_oracle_value(oracle_data, self.oracle_value_offset)
+    }
+
+    /// Return to the static numerator / denominator ratio
+    pub fn remove_oracle(&mut self) {
+        self.oracle = Pubkey::default();
+        self.oracle_value_offset = 0;
+        self.oracle_value_decimals = 0;
+        self.oracle_min_value = 0;
+        self.oracle_max_value = 0;
+    }
+
     /// Update Rate data
     pub fn update(&mut self, rounding: Rounding, numerator: u8, denominator: u8) -> ProgramResult {
         self.rounding = rounding;
@@ -212,6 +308,48 @@ impl Rate {
         amount_from: u64,
         decimals_from: u8,
         decimals_to: u8,
+    ) -> Result<u64, ProgramError> {
+        self.convert_at_ratio(
+            amount_from,
+            decimals_from,
+            decimals_to,
+            self.numerator as u128,
+            self.denominator as u128,
+        )
+    }
+
+    /// Convert amount of token A (amount_from) to token B (amount_to) at the oracle price
+    /// of one token A in token B. The price must be within the configured oracle bounds.
+    pub fn convert_from_to_amount_at_oracle_value(
+        &self,
+        amount_from: u64,
+        decimals_from: u8,
+        decimals_to: u8,
+        oracle_value: u64,
+    ) -> Result<u64, ProgramError> {
+        if oracle_value < self.oracle_min_value || oracle_value > self.oracle_max_value {
+            return Err(SecurityTokenError::OracleValueOutOfBounds.into());
+        }
+        let oracle_scale = 10u128
+            .checked_pow(self.oracle_value_decimals as u32)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.convert_at_ratio(
+            amount_from,
+            decimals_from,
+            decimals_to,
+            oracle_value as u128,
+            oracle_scale,
+        )
+    }
+
+    /// Convert amount_from at numerator / denominator, adjusting for the decimals of both mints
+    fn convert_at_ratio(
+        &self,
+        amount_from: u64,
+        decimals_from: u8,
+        decimals_to: u8,
+        numerator: u128,
+        denominator: u128,
     ) -> Result<u64, ProgramError> {
         if amount_from == 0 {
             return Ok(0);
@@ -224,21 +362,21 @@ impl Rate {
                 .ok_or(ProgramError::ArithmeticOverflow)? as u128;
             // amount_from * numerator * 10^{delta}
             let numerator = (amount_from as u128)
-                .checked_mul(self.numerator as u128)
+                .checked_mul(numerator)
                 .and_then(|v| v.checked_mul(scale))
                 .ok_or(ProgramError::ArithmeticOverflow)?;
-            (numerator, self.denominator as u128)
+            (numerator, denominator)
         } else {
             let delta = decimals_from - decimals_to;
             let scale = 10u64
                 .checked_pow(delta as u32)
                 .ok_or(ProgramError::ArithmeticOverflow)? as u128;
             // denominator * 10^{delta}
-            let denominator = (self.denominator as u128)
+            let denominator = denominator
                 .checked_mul(scale)
                 .ok_or(ProgramError::ArithmeticOverflow)?;
             let numerator = (amount_from as u128)
-                .checked_mul(self.numerator as u128)
+                .checked_mul(numerator)
                 .ok_or(ProgramError::ArithmeticOverflow)?;
             (numerator, denominator)
         };
@@ -257,8 +395,12 @@ impl Rate {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
     use rstest::rstest;
 
+    /// Random cases checked by every property test
+    const PROPERTY_CASES: usize = 20_000;
+
     #[rstest]
     #[case(Rounding::Up, 1u8, 3u8, 100_000u64, 33_334u64)]
     #[case(Rounding::Up, 2u8, 3u8, 1000u64, 667u64)]
@@ -271,12 +413,7 @@ mod tests {
         #[case] amount: u64,
         #[case] expected: u64,
     ) {
-        let rate = Rate {
-            rounding,
-            numerator,
-            denominator,
-            bump: 0,
-        };
+        let rate = Rate::new(rounding, numerator, denominator, 0).unwrap();
 
         let result = rate.calculate(amount).unwrap();
         assert_eq!(result, expected);
@@ -317,12 +454,7 @@ mod tests {
         #[case] decimals_to: u8,
         #[case] expected: u64,
     ) {
-        let rate = Rate {
-            rounding,
-            numerator,
-            denominator,
-            bump: 0,
-        };
+        let rate = Rate::new(rounding, numerator, denominator, 0).unwrap();
         let calculated = rate
             .convert_from_to_amount(amount_from, decimals_from, decimals_to)
             .unwrap();
@@ -331,4 +463,317 @@ mod tests {
             "Conversion not matching expected value"
         );
     }
+
+    #[rstest]
+    // 1 share A = 1.5 share B
+    #[case(Rounding::Down, 1_000_000, 6, 6, 1_500_000, 1_500_000)]
+    // 1 share A = 0.333333 share B
+    #[case(Rounding::Down, 1_000_000, 6, 6, 333_333, 333_333)]
+    #[case(Rounding::Up, 1_000, 6, 3, 333_333, 1)]
+    #[case(Rounding::Down, 1_000, 6, 3, 333_333, 0)]
+    // 6 -> 9 decimals, 10 tokens at 1.25
+    #[case(Rounding::Down, 10_000_000, 6, 9, 1_250_000, 12_500_000_000)]
+    fn test_convert_from_to_amount_at_oracle_value_cases(
+        #[case] rounding: Rounding,
+        #[case] amount_from: u64,
+        #[case] decimals_from: u8,
+        #[case] decimals_to: u8,
+        #[case] oracle_value: u64,
+        #[case] expected: u64,
+    ) {
+        let mut rate = Rate::new(rounding, 1, 1, 0).unwrap();
+        rate.set_oracle([7u8; 32], 0, 6, 1, 10_000_000).unwrap();
+
+        let calculated = rate
+            .convert_from_to_amount_at_oracle_value(
+                amount_from,
+                decimals_from,
+                decimals_to,
+                oracle_value,
+            )
+            .unwrap();
+        assert_eq!(calculated, expected);
+    }
+
+    #[test]
+    fn test_oracle_rate_rejects_out_of_bounds_value() {
+        let mut rate = Rate::new(Rounding::Down, 1, 1, 0).unwrap();
+        assert!(!rate.has_oracle());
+        rate.set_oracle([7u8; 32], 0, 6, 500_000, 2_000_000)
+            .unwrap();
+        assert!(rate.has_oracle());
+
+        assert_eq!(
+            rate.convert_from_to_amount_at_oracle_value(1_000, 6, 6, 499_999),
+            Err(SecurityTokenError::OracleValueOutOfBounds.into())
+        );
+        assert_eq!(
+            rate.convert_from_to_amount_at_oracle_value(1_000, 6, 6, 2_000_001),
+            Err(SecurityTokenError::OracleValueOutOfBounds.into())
+        );
+
+        rate.remove_oracle();
+        assert!(!rate.has_oracle());
+    }
+
+    #[test]
+    fn test_set_oracle_validation() {
+        let mut rate = Rate::new(Rounding::Down, 1, 1, 0).unwrap();
+
+        assert!(rate.set_oracle([0u8; 32], 0, 6, 1, 1).is_err());
+        assert!(rate.set_oracle([7u8; 32], 0, 6, 0, 1).is_err());
+        assert!(rate.set_oracle([7u8; 32], 0, 6, 2, 1).is_err());
+        assert!(rate.set_oracle([7u8; 32], 0, 19, 1, 1).is_err());
+    }
+
+    #[test]
+    fn test_rate_serialization_round_trip_with_oracle() {
+        let mut rate = Rate::new(Rounding::Up, 2, 3, 254).unwrap();
+        rate.set_oracle([7u8; 32], 8, 6, 500_000, 2_000_000)
+            .unwrap();
+
+        let bytes = rate.to_bytes();
+        assert_eq!(bytes.len(), Rate::LEN);
+
+        let deserialized = Rate::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.rounding, rate.rounding);
+        assert_eq!(deserialized.numerator, rate.numerator);
+        assert_eq!(deserialized.denominator, rate.denominator);
+        assert_eq!(deserialized.bump, rate.bump);
+        assert_eq!(deserialized.oracle, rate.oracle);
+        assert_eq!(deserialized.oracle_value_offset, rate.oracle_value_offset);
+        assert_eq!(
+            deserialized.oracle_value_decimals,
+            rate.oracle_value_decimals
+        );
+        assert_eq!(deserialized.oracle_min_value, rate.oracle_min_value);
+        assert_eq!(deserialized.oracle_max_value, rate.oracle_max_value);
+    }
+
+    /// Amount from the full u64 domain, biased towards zero, powers of ten and u64::MAX
+    fn sample_amount(rng: &mut StdRng) -> u64 {
+        match rng.gen_range(0..4) {
+            0 => rng.gen(),
+            1 => rng.gen_range(0..10_000),
+            2 => 10u64
+                .pow(rng.gen_range(0..20))
+                .saturating_add_signed(rng.gen_range(-1..=1)),
+            _ => u64::MAX - rng.gen_range(0..10_000),
+        }
+    }
+
+    fn sample_rate(rng: &mut StdRng) -> (u8, u8) {
+        (rng.gen_range(1..=u8::MAX), rng.gen_range(1..=u8::MAX))
+    }
+
+    fn rate_with(rounding: Rounding, numerator: u8, denominator: u8) -> Rate {
+        Rate::new(rounding, numerator, denominator, 0).unwrap()
+    }
+
+    /// amount * numerator * 10^decimals_to / (denominator * 10^decimals_from), `None` when
+    /// u128 cannot hold the intermediate values
+    fn reference_conversion(
+        rounding: Rounding,
+        amount: u64,
+        numerator: u8,
+        denominator: u8,
+        decimals_from: u8,
+        decimals_to: u8,
+    ) -> Option<u128> {
+        let numerator = (amount as u128)
+            .checked_mul(numerator as u128)?
+            .checked_mul(10u128.checked_pow(decimals_to as u32)?)?;
+        let denominator =
+            (denominator as u128).checked_mul(10u128.checked_pow(decimals_from as u32)?)?;
+        Some(match rounding {
+            Rounding::Down => numerator / denominator,
+            Rounding::Up => numerator.div_ceil(denominator),
+        })
+    }
+
+    #[test]
+    fn test_calculate_matches_exact_rounding() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_0001);
+        for _ in 0..PROPERTY_CASES {
+            let amount = sample_amount(&mut rng);
+            let (numerator, denominator) = sample_rate(&mut rng);
+            let product = amount as u128 * numerator as u128;
+
+            let down = rate_with(Rounding::Down, numerator, denominator).calculate(amount);
+            let up = rate_with(Rounding::Up, numerator, denominator).calculate(amount);
+            if product > u64::MAX as u128 {
+                assert_eq!(down, Err(ProgramError::ArithmeticOverflow));
+                assert_eq!(up, Err(ProgramError::ArithmeticOverflow));
+                continue;
+            }
+
+            let (down, up) = (down.unwrap() as u128, up.unwrap() as u128);
+            let case = (amount, numerator, denominator);
+            assert!(
+                down * denominator as u128 <= product,
+                "down above exact {case:?}"
+            );
+            assert!(
+                product < (down + 1) * denominator as u128,
+                "down more than one unit below exact {case:?}"
+            );
+            assert!(
+                up * denominator as u128 >= product,
+                "up below exact {case:?}"
+            );
+            assert!(
+                up - down <= 1,
+                "up and down differ by more than one {case:?}"
+            );
+            assert_eq!(up == down, product % denominator as u128 == 0, "{case:?}");
+        }
+    }
+
+    #[test]
+    fn test_calculate_is_monotonic() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_0002);
+        for _ in 0..PROPERTY_CASES {
+            let (a, b) = (sample_amount(&mut rng), sample_amount(&mut rng));
+            let (low, high) = (a.min(b), a.max(b));
+            let (numerator, denominator) = sample_rate(&mut rng);
+            for rounding in [Rounding::Down, Rounding::Up] {
+                let rate = rate_with(rounding, numerator, denominator);
+                match (rate.calculate(low), rate.calculate(high)) {
+                    (Ok(low_result), Ok(high_result)) => assert!(
+                        low_result <= high_result,
+                        "not monotonic {low} {high} {numerator}/{denominator}"
+                    ),
+                    // a larger amount can overflow where a smaller one does not, never the reverse
+                    (Ok(_), Err(_)) | (Err(_), Err(_)) => {}
+                    (Err(_), Ok(_)) => {
+                        panic!("smaller amount overflowed {low} {high} {numerator}/{denominator}")
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_from_to_amount_matches_reference() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_0003);
+        for _ in 0..PROPERTY_CASES {
+            let amount = sample_amount(&mut rng);
+            let (numerator, denominator) = sample_rate(&mut rng);
+            // larger gaps are rejected outright, see test_convert_from_to_amount_never_panics
+            let decimals_from = rng.gen_range(0..=19);
+            let decimals_to = rng.gen_range(0..=19);
+            for rounding in [Rounding::Down, Rounding::Up] {
+                let result = rate_with(rounding, numerator, denominator).convert_from_to_amount(
+                    amount,
+                    decimals_from,
+                    decimals_to,
+                );
+                let Some(expected) = reference_conversion(
+                    rounding,
+                    amount,
+                    numerator,
+                    denominator,
+                    decimals_from,
+                    decimals_to,
+                ) else {
+                    continue;
+                };
+                let case = (amount, numerator, denominator, decimals_from, decimals_to);
+                match u64::try_from(expected) {
+                    Ok(expected) => assert_eq!(result, Ok(expected), "{case:?}"),
+                    Err(_) => assert_eq!(result, Err(ProgramError::ArithmeticOverflow), "{case:?}"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_from_to_amount_never_panics() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_0004);
+        for _ in 0..PROPERTY_CASES {
+            let amount = sample_amount(&mut rng);
+            let (numerator, denominator) = sample_rate(&mut rng);
+            let decimals_from = rng.gen();
+            let decimals_to = rng.gen();
+            for rounding in [Rounding::Down, Rounding::Up] {
+                let result = rate_with(rounding, numerator, denominator).convert_from_to_amount(
+                    amount,
+                    decimals_from,
+                    decimals_to,
+                );
+                if amount == 0 {
+                    assert_eq!(result, Ok(0));
+                } else if decimals_from.abs_diff(decimals_to) > 19 {
+                    // 10^20 does not fit in a u64
+                    assert_eq!(result, Err(ProgramError::ArithmeticOverflow));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_from_to_amount_round_trip_bounds() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_0005);
+        for _ in 0..PROPERTY_CASES {
+            let amount = sample_amount(&mut rng);
+            let (numerator, denominator) = sample_rate(&mut rng);
+            let decimals_from = rng.gen_range(0..=12);
+            let decimals_to = rng.gen_range(0..=12);
+            let case = (amount, numerator, denominator, decimals_from, decimals_to);
+
+            // converting down and back never creates tokens
+            let down = rate_with(Rounding::Down, numerator, denominator);
+            let inverse_down = rate_with(Rounding::Down, denominator, numerator);
+            if let Ok(converted) = down.convert_from_to_amount(amount, decimals_from, decimals_to) {
+                if let Ok(back) =
+                    inverse_down.convert_from_to_amount(converted, decimals_to, decimals_from)
+                {
+                    assert!(back <= amount, "round trip down created tokens {case:?}");
+                }
+            }
+
+            // converting up and back never loses tokens
+            let up = rate_with(Rounding::Up, numerator, denominator);
+            let inverse_up = rate_with(Rounding::Up, denominator, numerator);
+            if let Ok(converted) = up.convert_from_to_amount(amount, decimals_from, decimals_to) {
+                if let Ok(back) =
+                    inverse_up.convert_from_to_amount(converted, decimals_to, decimals_from)
+                {
+                    assert!(back >= amount, "round trip up lost tokens {case:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_from_to_amount_is_monotonic() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_0006);
+        for _ in 0..PROPERTY_CASES {
+            let (a, b) = (sample_amount(&mut rng), sample_amount(&mut rng));
+            let (low, high) = (a.min(b), a.max(b));
+            let (numerator, denominator) = sample_rate(&mut rng);
+            let decimals_from = rng.gen_range(0..=19);
+            let decimals_to = rng.gen_range(0..=19);
+            for rounding in [Rounding::Down, Rounding::Up] {
+                let rate = rate_with(rounding, numerator, denominator);
+                let low_result = rate.convert_from_to_amount(low, decimals_from, decimals_to);
+                let high_result = rate.convert_from_to_amount(high, decimals_from, decimals_to);
+                if let Ok(high_result) = high_result {
+                    let low_result = low_result.expect("smaller amount overflowed");
+                    assert!(
+                        low_result <= high_result,
+                        "not monotonic {:?}",
+                        (
+                            low,
+                            high,
+                            numerator,
+                            denominator,
+                            decimals_from,
+                            decimals_to
+                        )
+                    );
+                }
+            }
+        }
+    }
 }
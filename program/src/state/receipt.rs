@@ -6,19 +6,38 @@ use pinocchio::{
 
 use crate::{
     constants::seeds::RECEIPT_ACCOUNT,
-    merkle_tree_utils::ProofData,
+    merkle_tree_utils::{MerkleTreeRoot, ProofData},
     state::{
         AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
         SecurityTokenDiscriminators,
     },
-    utils::{find_claim_receipt_pda, find_common_action_receipt_pda, hash_from_proof_data},
+    utils::{
+        find_claim_receipt_pda, find_claim_receipt_pda_with_proof_hash,
+        find_common_action_receipt_pda, find_migration_receipt_pda, hash_from_proof_data,
+        hash_from_proof_data_and_root,
+    },
 };
 
+/// Amounts of a distribution claim, recorded for tax reporting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClaimAmounts {
+    /// Tokens received by the holder
+    pub net_amount: u64,
+    /// Tokens withheld to the tax escrow
+    pub withheld_amount: u64,
+    /// Jurisdiction of the applied withholding rate, 0 without withholding
+    pub jurisdiction: u16,
+}
+
 /// Receipt account structure
-/// To follow consistency with other account types, we define Receipt using common pattern, even though it stores only discriminator
+/// Split/Convert/migration receipts store only the discriminator, all relevant information is encoded in the PDA seeds.
+/// Claim receipts additionally record the claimed amounts.
 #[repr(C)]
 #[derive(Debug)]
-pub struct Receipt {}
+pub struct Receipt {
+    /// Amounts of a distribution claim, `None` for receipts of other operations
+    pub claim: Option<ClaimAmounts>,
+}
 
 impl Discriminator for Receipt {
     const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::ReceiptDiscriminator as u8;
@@ -26,32 +45,76 @@ impl Discriminator for Receipt {
 
 impl AccountSerialize for Receipt {
     fn to_bytes_inner(&self) -> Vec<u8> {
-        vec![]
+        let Some(claim) = self.claim else {
+            return vec![];
+        };
+        let mut data = Vec::with_capacity(Self::CLAIM_LEN - 1);
+
+        data.extend_from_slice(&claim.net_amount.to_le_bytes());
+        data.extend_from_slice(&claim.withheld_amount.to_le_bytes());
+        data.extend_from_slice(&claim.jurisdiction.to_le_bytes());
+
+        data
     }
 }
 
 impl AccountDeserialize for Receipt {
-    fn try_from_bytes_inner(_data: &[u8]) -> Result<Self, ProgramError> {
-        Ok(Self {})
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.is_empty() {
+            return Ok(Self { claim: None });
+        }
+        if data.len() != Self::CLAIM_LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let net_amount = u64::from_le_bytes(
+            data[..8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let withheld_amount = u64::from_le_bytes(
+            data[8..16]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let jurisdiction = u16::from_le_bytes([data[16], data[17]]);
+
+        Ok(Self {
+            claim: Some(ClaimAmounts {
+                net_amount,
+                withheld_amount,
+                jurisdiction,
+            }),
+        })
     }
 }
 
 impl ProgramAccount for Receipt {
     fn space(&self) -> u64 {
-        Self::LEN as u64
+        match self.claim {
+            Some(_) => Self::CLAIM_LEN as u64,
+            None => Self::LEN as u64,
+        }
     }
 }
 
 impl Receipt {
     /// Discriminator
     pub const LEN: usize = 1;
+    /// Discriminator + net_amount (8 bytes) + withheld_amount (8 bytes) + jurisdiction (2 bytes)
+    pub const CLAIM_LEN: usize = Self::LEN + 8 + 8 + 2;
 
     pub fn new() -> Result<Self, ProgramError> {
-        Ok(Self {})
+        Ok(Self { claim: None })
+    }
+
+    pub fn new_claim(claim: ClaimAmounts) -> Self {
+        Self { claim: Some(claim) }
     }
 
     pub fn from_account_info(account_info: &AccountInfo) -> Result<Receipt, ProgramError> {
-        if account_info.data_len() != Self::LEN {
+        let data_len = account_info.data_len();
+        if data_len != Self::LEN && data_len != Self::CLAIM_LEN {
             return Err(ProgramError::InvalidAccountData);
         }
         if !account_info.is_owned_by(&crate::ID) {
@@ -77,6 +140,20 @@ impl Receipt {
         Ok(())
     }
 
+    /// Issue new claim Receipt recording the claimed amounts
+    pub fn issue_claim(
+        receipt_account: &AccountInfo,
+        payer: &AccountInfo,
+        seeds: &[Seed],
+        claim: ClaimAmounts,
+    ) -> ProgramResult {
+        let receipt = Receipt::new_claim(claim);
+        receipt.init(payer, receipt_account, seeds)?;
+        receipt.write_data(receipt_account)?;
+
+        Ok(())
+    }
+
     /// Seeds for common operation connected to action id and mint (e.g. Split, Convert)
     pub fn common_action_seeds<'a>(
         mint: &'a Pubkey,
@@ -96,6 +173,31 @@ impl Receipt {
         find_common_action_receipt_pda(mint, action_id, &crate::id())
     }
 
+    /// Seeds for a token account migrated to a new mint
+    pub fn migration_seeds<'a>(
+        mint_to: &'a Pubkey,
+        token_account: &'a Pubkey,
+        action_id_seed: &'a [u8],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 5] {
+        [
+            Seed::from(RECEIPT_ACCOUNT),
+            Seed::from(mint_to.as_ref()),
+            Seed::from(token_account.as_ref()),
+            Seed::from(action_id_seed),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Find receipt PDA for a token account migrated to a new mint
+    pub fn find_migration_pda(
+        mint_to: &Pubkey,
+        token_account: &Pubkey,
+        action_id: u64,
+    ) -> (Pubkey, u8) {
+        find_migration_receipt_pda(mint_to, token_account, action_id, &crate::id())
+    }
+
     /// Seeds for Claim operation
     pub fn claim_action_seeds<'a>(
         mint: &'a Pubkey,
@@ -128,4 +230,54 @@ impl Receipt {
     ) -> (Pubkey, u8) {
         find_claim_receipt_pda(mint, token_account, action_id, proof, &crate::id())
     }
+
+    /// Helper to compute proof hash for claim_action_seeds of claims against a supplemental
+    /// merkle root, scoping their receipts per root
+    pub fn root_proof_seed(proof: &ProofData, merkle_root: &MerkleTreeRoot) -> [u8; 32] {
+        hash_from_proof_data_and_root(proof, merkle_root)
+    }
+
+    /// Find receipt PDA for Claim operation from the proof hash seed
+    pub fn find_claim_action_pda_with_proof_seed(
+        mint: &Pubkey,
+        token_account: &Pubkey,
+        action_id: u64,
+        proof_seed: &[u8; 32],
+    ) -> (Pubkey, u8) {
+        find_claim_receipt_pda_with_proof_hash(
+            mint,
+            token_account,
+            action_id,
+            proof_seed,
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_receipt_is_discriminator_only() {
+        let bytes = Receipt::new().unwrap().to_bytes();
+        assert_eq!(bytes, vec![Receipt::DISCRIMINATOR]);
+        assert!(Receipt::try_from_bytes(&bytes).unwrap().claim.is_none());
+    }
+
+    #[test]
+    fn test_claim_receipt_serialization_round_trip() {
+        let claim = ClaimAmounts {
+            net_amount: 700,
+            withheld_amount: 300,
+            jurisdiction: 840,
+        };
+        let receipt = Receipt::new_claim(claim);
+
+        let bytes = receipt.to_bytes();
+        assert_eq!(bytes.len(), Receipt::CLAIM_LEN);
+        assert_eq!(receipt.space(), Receipt::CLAIM_LEN as u64);
+        assert_eq!(Receipt::try_from_bytes(&bytes).unwrap().claim, Some(claim));
+        assert!(Receipt::try_from_bytes(&bytes[..Receipt::CLAIM_LEN - 1]).is_err());
+    }
 }
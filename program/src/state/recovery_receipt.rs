@@ -0,0 +1,289 @@
+//! Recovery receipt account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::{ShankAccount, ShankType};
+
+use crate::constants::seeds::RECOVERY_RECEIPT_ACCOUNT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Why the tokens of a token account were recovered
+#[repr(u8)]
+#[derive(Clone, Debug, PartialEq, Eq, Copy, ShankType)]
+pub enum RecoveryReason {
+    /// Token account is owned by a program or a program account that cannot sign for it
+    ProgramOwner = 0,
+    /// Token account is flagged by a Suspension
+    Suspended = 1,
+}
+
+impl From<RecoveryReason> for u8 {
+    fn from(reason: RecoveryReason) -> Self {
+        reason as u8
+    }
+}
+
+impl TryFrom<u8> for RecoveryReason {
+    type Error = ProgramError;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            0 => Ok(RecoveryReason::ProgramOwner),
+            1 => Ok(RecoveryReason::Suspended),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Record of tokens swept from a misdelivered or flagged token account, created by RecoverTokens
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct RecoveryReceipt {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Token account the tokens were swept from
+    pub source_token_account: Pubkey,
+    /// Owner of the source token account
+    pub source_owner: Pubkey,
+    /// Token account the tokens were swept to
+    pub recovery_token_account: Pubkey,
+    /// Swept amount
+    pub amount: u64,
+    /// Why the source token account was recoverable
+    pub reason: RecoveryReason,
+    /// Unix timestamp of the sweep
+    pub recovered_at: i64,
+    /// Recovery identifier, unique per mint
+    pub recovery_id: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for RecoveryReceipt {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::RecoveryReceiptDiscriminator as u8;
+}
+
+impl AccountSerialize for RecoveryReceipt {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.source_token_account.as_ref());
+        data.extend_from_slice(self.source_owner.as_ref());
+        data.extend_from_slice(self.recovery_token_account.as_ref());
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data.push(self.reason.into());
+        data.extend_from_slice(&self.recovered_at.to_le_bytes());
+        data.extend_from_slice(&self.recovery_id.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for RecoveryReceipt {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let source_token_account: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let source_owner: [u8; PUBKEY_BYTES] = data[2 * PUBKEY_BYTES..3 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let recovery_token_account: [u8; PUBKEY_BYTES] = data[3 * PUBKEY_BYTES..4 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let mut offset = 4 * PUBKEY_BYTES;
+        let amount = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let reason = RecoveryReason::try_from(data[offset])?;
+        offset += 1;
+        let recovered_at = i64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let recovery_id = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+
+        Ok(Self {
+            mint,
+            source_token_account,
+            source_owner,
+            recovery_token_account,
+            amount,
+            reason,
+            recovered_at,
+            recovery_id,
+            bump: data[offset],
+        })
+    }
+}
+
+impl ProgramAccount for RecoveryReceipt {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl RecoveryReceipt {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 4 pubkeys (128 bytes) + amount (8 bytes) + reason (1 byte)
+    /// + recovered_at (8 bytes) + recovery_id (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (4 * PUBKEY_BYTES) + 8 + 1 + 8 + 8 + 1;
+
+    /// Create a new RecoveryReceipt
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mint: Pubkey,
+        source_token_account: Pubkey,
+        source_owner: Pubkey,
+        recovery_token_account: Pubkey,
+        amount: u64,
+        reason: RecoveryReason,
+        recovered_at: i64,
+        recovery_id: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            mint,
+            source_token_account,
+            source_owner,
+            recovery_token_account,
+            amount,
+            reason,
+            recovered_at,
+            recovery_id,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<RecoveryReceipt, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn recovery_id_seed(&self) -> [u8; 8] {
+        self.recovery_id.to_le_bytes()
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        recovery_id_seed: &'a [u8; 8],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 4] {
+        [
+            Seed::from(RECOVERY_RECEIPT_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(recovery_id_seed.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                RECOVERY_RECEIPT_ACCOUNT,
+                &self.mint,
+                &self.recovery_id_seed(),
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(RecoveryReason::ProgramOwner)]
+    #[case(RecoveryReason::Suspended)]
+    fn test_recovery_receipt_serialization_round_trip(#[case] reason: RecoveryReason) {
+        let receipt = RecoveryReceipt::new(
+            [1u8; 32],
+            [2u8; 32],
+            [3u8; 32],
+            [4u8; 32],
+            1_000,
+            reason,
+            1_700_000_000,
+            7,
+            253,
+        );
+
+        let bytes = receipt.to_bytes();
+        assert_eq!(bytes.len(), RecoveryReceipt::LEN);
+        assert_eq!(bytes[0], RecoveryReceipt::DISCRIMINATOR);
+
+        let deserialized = RecoveryReceipt::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, receipt.mint);
+        assert_eq!(
+            deserialized.source_token_account,
+            receipt.source_token_account
+        );
+        assert_eq!(deserialized.source_owner, receipt.source_owner);
+        assert_eq!(
+            deserialized.recovery_token_account,
+            receipt.recovery_token_account
+        );
+        assert_eq!(deserialized.amount, receipt.amount);
+        assert_eq!(deserialized.reason, reason);
+        assert_eq!(deserialized.recovered_at, receipt.recovered_at);
+        assert_eq!(deserialized.recovery_id, receipt.recovery_id);
+        assert_eq!(deserialized.bump, receipt.bump);
+    }
+
+    #[test]
+    fn test_recovery_receipt_rejects_unknown_reason() {
+        let mut bytes = RecoveryReceipt::new(
+            [1u8; 32],
+            [2u8; 32],
+            [3u8; 32],
+            [4u8; 32],
+            1_000,
+            RecoveryReason::ProgramOwner,
+            1_700_000_000,
+            7,
+            253,
+        )
+        .to_bytes();
+        bytes[1 + 4 * PUBKEY_BYTES + 8] = 2;
+        assert!(RecoveryReceipt::try_from_bytes(&bytes).is_err());
+    }
+}
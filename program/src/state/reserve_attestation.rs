@@ -0,0 +1,279 @@
+//! Reserve attestation state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::RESERVE_ATTESTATION_ACCOUNT;
+use crate::constants::MAX_RESERVE_ACCOUNTS;
+use crate::error::SecurityTokenError;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Proof of reserve published by a custodian: the balances of its omnibus token accounts
+/// at `slot`, bound to the hash of its off-chain liabilities
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct ReserveAttestation {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Custodian owning the omnibus token accounts
+    pub custodian: Pubkey,
+    /// Hash of the custodian liabilities, computed off-chain
+    pub liability_hash: [u8; 32],
+    /// Sum of the omnibus token account balances
+    pub total_balance: u64,
+    /// Slot of the attestation
+    pub slot: u64,
+    /// Unix timestamp of the attestation
+    pub unix_timestamp: i64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+    /// Omnibus token accounts
+    #[idl_type("Vec<Pubkey>")]
+    pub token_accounts: Vec<Pubkey>,
+    /// Balance of every omnibus token account, in `token_accounts` order
+    #[idl_type("Vec<u64>")]
+    pub balances: Vec<u64>,
+}
+
+impl Discriminator for ReserveAttestation {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::ReserveAttestationDiscriminator as u8;
+}
+
+impl AccountSerialize for ReserveAttestation {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.custodian.as_ref());
+        data.extend_from_slice(self.liability_hash.as_ref());
+        data.extend_from_slice(&self.total_balance.to_le_bytes());
+        data.extend_from_slice(&self.slot.to_le_bytes());
+        data.extend_from_slice(&self.unix_timestamp.to_le_bytes());
+        data.push(self.bump);
+        data.extend(&(self.token_accounts.len() as u32).to_le_bytes());
+        for token_account in &self.token_accounts {
+            data.extend_from_slice(token_account.as_ref());
+        }
+        data.extend(&(self.balances.len() as u32).to_le_bytes());
+        for balance in &self.balances {
+            data.extend_from_slice(&balance.to_le_bytes());
+        }
+        // Unused account slots are zeroed
+        data.resize(Self::LEN - 1, 0);
+
+        data
+    }
+}
+
+impl AccountDeserialize for ReserveAttestation {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let bytes_at = |offset: usize| -> Result<[u8; 32], ProgramError> {
+            data[offset..offset + 32]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)
+        };
+        let u64_at = |offset: usize| -> Result<u64, ProgramError> {
+            data[offset..offset + 8]
+                .try_into()
+                .map(u64::from_le_bytes)
+                .map_err(|_| ProgramError::InvalidAccountData)
+        };
+        let vec_len_at = |offset: usize| -> Result<usize, ProgramError> {
+            let len = data[offset..offset + Self::VEC_LEN_PREFIX]
+                .try_into()
+                .map(u32::from_le_bytes)
+                .map_err(|_| ProgramError::InvalidAccountData)? as usize;
+            if len > MAX_RESERVE_ACCOUNTS {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            Ok(len)
+        };
+
+        let mut offset = 3 * PUBKEY_BYTES;
+        let total_balance = u64_at(offset)?;
+        let slot = u64_at(offset + 8)?;
+        let unix_timestamp = u64_at(offset + 16)? as i64;
+        let bump = data[offset + 24];
+        offset += 25;
+
+        let token_accounts_len = vec_len_at(offset)?;
+        offset += Self::VEC_LEN_PREFIX;
+        let token_accounts = (0..token_accounts_len)
+            .map(|index| bytes_at(offset + index * PUBKEY_BYTES))
+            .collect::<Result<Vec<Pubkey>, ProgramError>>()?;
+        offset += token_accounts_len * PUBKEY_BYTES;
+
+        let balances_len = vec_len_at(offset)?;
+        offset += Self::VEC_LEN_PREFIX;
+        let balances = (0..balances_len)
+            .map(|index| u64_at(offset + index * 8))
+            .collect::<Result<Vec<u64>, ProgramError>>()?;
+
+        if balances_len != token_accounts_len {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            mint: bytes_at(0)?,
+            custodian: bytes_at(PUBKEY_BYTES)?,
+            liability_hash: bytes_at(2 * PUBKEY_BYTES)?,
+            total_balance,
+            slot,
+            unix_timestamp,
+            bump,
+            token_accounts,
+            balances,
+        })
+    }
+}
+
+impl ProgramAccount for ReserveAttestation {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl ReserveAttestation {
+    pub const VEC_LEN_PREFIX: usize = 4;
+    /// Serialized size of the account data. All account slots are allocated upfront so a
+    /// custodian can republish with a different set of omnibus accounts
+    /// Discriminator (1 byte) + mint, custodian and liability hash (96 bytes) + total balance,
+    /// slot and unix_timestamp (24 bytes) + bump (1 byte) + 2 vector length prefixes (8 bytes)
+    /// + token account and balance slots
+    pub const LEN: usize = 1
+        + (3 * PUBKEY_BYTES)
+        + 24
+        + 1
+        + (2 * Self::VEC_LEN_PREFIX)
+        + (MAX_RESERVE_ACCOUNTS * (PUBKEY_BYTES + 8));
+
+    /// Attest the balances of the omnibus token accounts of a custodian
+    pub fn new(
+        mint: Pubkey,
+        custodian: Pubkey,
+        liability_hash: [u8; 32],
+        reserves: &[(Pubkey, u64)],
+        slot: u64,
+        unix_timestamp: i64,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        if reserves.is_empty() || reserves.len() > MAX_RESERVE_ACCOUNTS {
+            return Err(SecurityTokenError::InvalidReserveAccount.into());
+        }
+        let total_balance = reserves
+            .iter()
+            .try_fold(0u64, |total, (_, balance)| total.checked_add(*balance))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(Self {
+            mint,
+            custodian,
+            liability_hash,
+            total_balance,
+            slot,
+            unix_timestamp,
+            bump,
+            token_accounts: reserves.iter().map(|(key, _)| *key).collect(),
+            balances: reserves.iter().map(|(_, balance)| *balance).collect(),
+        })
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<ReserveAttestation, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 4] {
+        [
+            Seed::from(RESERVE_ATTESTATION_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(self.custodian.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                RESERVE_ATTESTATION_ACCOUNT,
+                &self.mint,
+                &self.custodian,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserve_attestation_serialization_round_trip() {
+        let attestation = ReserveAttestation::new(
+            [1u8; 32],
+            [2u8; 32],
+            [3u8; 32],
+            &[([4u8; 32], 1_000), ([5u8; 32], 250)],
+            77,
+            1_700_000_000,
+            253,
+        )
+        .unwrap();
+        assert_eq!(attestation.total_balance, 1_250);
+
+        let bytes = attestation.to_bytes();
+        assert_eq!(bytes.len(), ReserveAttestation::LEN);
+        assert_eq!(bytes[0], ReserveAttestation::DISCRIMINATOR);
+
+        let deserialized = ReserveAttestation::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, attestation.mint);
+        assert_eq!(deserialized.custodian, attestation.custodian);
+        assert_eq!(deserialized.liability_hash, attestation.liability_hash);
+        assert_eq!(deserialized.total_balance, attestation.total_balance);
+        assert_eq!(deserialized.slot, attestation.slot);
+        assert_eq!(deserialized.unix_timestamp, attestation.unix_timestamp);
+        assert_eq!(deserialized.bump, attestation.bump);
+        assert_eq!(deserialized.token_accounts, attestation.token_accounts);
+        assert_eq!(deserialized.balances, attestation.balances);
+    }
+
+    #[test]
+    fn test_reserve_attestation_bounds() {
+        let new = |reserves: &[(Pubkey, u64)]| {
+            ReserveAttestation::new([1u8; 32], [2u8; 32], [3u8; 32], reserves, 0, 0, 255)
+        };
+        assert!(new(&[]).is_err());
+        assert!(new(&[([4u8; 32], 1); MAX_RESERVE_ACCOUNTS + 1]).is_err());
+        assert_eq!(
+            new(&[([4u8; 32], u64::MAX), ([5u8; 32], 1)]).err(),
+            Some(ProgramError::ArithmeticOverflow)
+        );
+        assert!(new(&[([4u8; 32], 1); MAX_RESERVE_ACCOUNTS]).is_ok());
+    }
+}
@@ -0,0 +1,316 @@
+//! Session key account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::SESSION_KEY_ACCOUNT;
+use crate::error::SecurityTokenError;
+use crate::instruction::SecurityTokenInstruction;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Temporary key allowed to execute a set of issuer instructions of a mint until its expiry.
+/// Bit `n` of `allowed_instructions` allows the instruction with discriminator `n`.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct SessionKey {
+    /// Mint the session key operates on
+    pub mint: Pubkey,
+    /// Key that signs on behalf of the issuer
+    pub session_key: Pubkey,
+    /// Bitmap of allowed instruction discriminators
+    pub allowed_instructions: u128,
+    /// Maximum token amount of a single operation
+    pub max_amount: u64,
+    /// Unix timestamp after which the session key is rejected
+    pub expiry: i64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for SessionKey {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::SessionKeyDiscriminator as u8;
+}
+
+impl AccountSerialize for SessionKey {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.session_key.as_ref());
+        data.extend_from_slice(&self.allowed_instructions.to_le_bytes());
+        data.extend_from_slice(&self.max_amount.to_le_bytes());
+        data.extend_from_slice(&self.expiry.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for SessionKey {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let session_key: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let mut offset = 2 * PUBKEY_BYTES;
+        let allowed_instructions = u128::from_le_bytes(
+            data[offset..offset + 16]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 16;
+        let max_amount = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+        let expiry = i64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        offset += 8;
+
+        Ok(Self {
+            mint,
+            session_key,
+            allowed_instructions,
+            max_amount,
+            expiry,
+            bump: data[offset],
+        })
+    }
+}
+
+impl ProgramAccount for SessionKey {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl SessionKey {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 2 pubkeys (64 bytes) + allowed_instructions (16 bytes)
+    /// + max_amount (8 bytes) + expiry (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 16 + 8 + 8 + 1;
+
+    /// Create a new SessionKey
+    pub fn new(
+        mint: Pubkey,
+        session_key: Pubkey,
+        allowed_instructions: u128,
+        max_amount: u64,
+        expiry: i64,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        if allowed_instructions == 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(Self {
+            mint,
+            session_key,
+            allowed_instructions,
+            max_amount,
+            expiry,
+            bump,
+        })
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<SessionKey, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Whether the instruction with `ix_discriminator` is in the allowed set
+    pub fn allows(&self, ix_discriminator: u8) -> bool {
+        ix_discriminator < 128 && self.allowed_instructions & (1u128 << ix_discriminator) != 0
+    }
+
+    /// Token amount moved or committed by a single operation, `None` for instructions without one
+    pub fn operation_amount(
+        instruction: &SecurityTokenInstruction,
+        args_data: &[u8],
+    ) -> Result<Option<u64>, ProgramError> {
+        use SecurityTokenInstruction::*;
+
+        // Offset of the u64 amount in the instruction arguments
+        let offset = match instruction {
+            Allocate => 0,
+            CreateVesting => 8,
+            CreateAuction => 40,
+            _ => return Ok(None),
+        };
+        let amount = args_data
+            .get(offset..offset + 8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(Some(amount))
+    }
+
+    /// Check that the session key may execute the instruction at `now`
+    pub fn authorize(
+        &self,
+        ix_discriminator: u8,
+        args_data: &[u8],
+        now: i64,
+    ) -> Result<(), ProgramError> {
+        if now > self.expiry {
+            return Err(SecurityTokenError::SessionKeyExpired.into());
+        }
+        if !self.allows(ix_discriminator) {
+            return Err(SecurityTokenError::SessionKeyPermissionDenied.into());
+        }
+        let instruction = SecurityTokenInstruction::try_from(ix_discriminator)?;
+        if let Some(amount) = Self::operation_amount(&instruction, args_data)? {
+            if amount > self.max_amount {
+                return Err(SecurityTokenError::SessionKeyLimitExceeded.into());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 4] {
+        [
+            Seed::from(SESSION_KEY_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(self.session_key.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                SESSION_KEY_ACCOUNT,
+                &self.mint,
+                &self.session_key,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn allocate_bit() -> u128 {
+        1u128 << SecurityTokenInstruction::Allocate.discriminant()
+    }
+
+    #[test]
+    fn test_session_key_serialization_round_trip() {
+        let session_key = SessionKey::new(
+            [1u8; 32],
+            [2u8; 32],
+            allocate_bit(),
+            500,
+            1_700_000_000,
+            253,
+        )
+        .unwrap();
+
+        let bytes = session_key.to_bytes();
+        assert_eq!(bytes.len(), SessionKey::LEN);
+        assert_eq!(bytes[0], SessionKey::DISCRIMINATOR);
+
+        let deserialized = SessionKey::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, session_key.mint);
+        assert_eq!(deserialized.session_key, session_key.session_key);
+        assert_eq!(
+            deserialized.allowed_instructions,
+            session_key.allowed_instructions
+        );
+        assert_eq!(deserialized.max_amount, session_key.max_amount);
+        assert_eq!(deserialized.expiry, session_key.expiry);
+        assert_eq!(deserialized.bump, session_key.bump);
+    }
+
+    #[test]
+    fn test_session_key_requires_allowed_instructions() {
+        assert!(SessionKey::new([1u8; 32], [2u8; 32], 0, 500, 1_700_000_000, 253).is_err());
+    }
+
+    #[rstest]
+    #[case(SecurityTokenInstruction::Allocate, 500, 100, None)]
+    #[case(
+        SecurityTokenInstruction::Allocate,
+        501,
+        100,
+        Some(SecurityTokenError::SessionKeyLimitExceeded)
+    )]
+    #[case(
+        SecurityTokenInstruction::Allocate,
+        500,
+        1_001,
+        Some(SecurityTokenError::SessionKeyExpired)
+    )]
+    #[case(
+        SecurityTokenInstruction::CreateVesting,
+        0,
+        100,
+        Some(SecurityTokenError::SessionKeyPermissionDenied)
+    )]
+    fn test_session_key_authorize(
+        #[case] instruction: SecurityTokenInstruction,
+        #[case] amount: u64,
+        #[case] now: i64,
+        #[case] expected_error: Option<SecurityTokenError>,
+    ) {
+        let session_key =
+            SessionKey::new([1u8; 32], [2u8; 32], allocate_bit(), 500, 1_000, 253).unwrap();
+        let args = [amount.to_le_bytes(), amount.to_le_bytes()].concat();
+
+        let result = session_key.authorize(instruction.discriminant(), &args, now);
+        assert_eq!(result, expected_error.map_or(Ok(()), |e| Err(e.into())));
+    }
+
+    #[test]
+    fn test_session_key_operation_amount_offsets() {
+        let mut args = vec![0u8; 48];
+        args[8..16].copy_from_slice(&7u64.to_le_bytes());
+        args[40..48].copy_from_slice(&9u64.to_le_bytes());
+
+        assert_eq!(
+            SessionKey::operation_amount(&SecurityTokenInstruction::CreateVesting, &args),
+            Ok(Some(7))
+        );
+        assert_eq!(
+            SessionKey::operation_amount(&SecurityTokenInstruction::CreateAuction, &args),
+            Ok(Some(9))
+        );
+        assert_eq!(
+            SessionKey::operation_amount(&SecurityTokenInstruction::UpdateMetadata, &args),
+            Ok(None)
+        );
+    }
+}
@@ -0,0 +1,335 @@
+//! Sub-account ledger state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::{SUB_ACCOUNT_ACCOUNT, SUB_ACCOUNT_LEDGER_ACCOUNT};
+use crate::error::SecurityTokenError;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Beneficial owner bookkeeping of a custodian omnibus token account. The transfer hook
+/// rejects transfers leaving the omnibus balance below `total_balance`, so outbound
+/// transfers have to be debited from their beneficial owners first.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct SubAccountLedger {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Omnibus token account
+    pub token_account: Pubkey,
+    /// Sum of the sub-account balances
+    pub total_balance: u64,
+    /// Number of open sub-accounts
+    pub sub_account_count: u32,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for SubAccountLedger {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::SubAccountLedgerDiscriminator as u8;
+}
+
+impl AccountSerialize for SubAccountLedger {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.token_account.as_ref());
+        data.extend_from_slice(&self.total_balance.to_le_bytes());
+        data.extend_from_slice(&self.sub_account_count.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for SubAccountLedger {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let token_account: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = 2 * PUBKEY_BYTES;
+        let total_balance = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let sub_account_count = u32::from_le_bytes(
+            data[offset + 8..offset + 12]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            mint,
+            token_account,
+            total_balance,
+            sub_account_count,
+            bump: data[offset + 12],
+        })
+    }
+}
+
+impl ProgramAccount for SubAccountLedger {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl SubAccountLedger {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 2 pubkeys (64 bytes) + total_balance (8 bytes)
+    /// + sub_account_count (4 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 8 + 4 + 1;
+
+    /// Create an empty ledger of an omnibus token account
+    pub fn new(mint: Pubkey, token_account: Pubkey, bump: u8) -> Self {
+        Self {
+            mint,
+            token_account,
+            total_balance: 0,
+            sub_account_count: 0,
+            bump,
+        }
+    }
+
+    /// Record `amount` more for a beneficial owner. The sub-balances have to stay covered
+    /// by the `omnibus_balance`.
+    pub fn credit(&mut self, amount: u64, omnibus_balance: u64) -> Result<(), ProgramError> {
+        let total_balance = self
+            .total_balance
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        if total_balance > omnibus_balance {
+            return Err(SecurityTokenError::SubAccountBalanceExceeded.into());
+        }
+        self.total_balance = total_balance;
+        Ok(())
+    }
+
+    /// Release `amount` of a beneficial owner
+    pub fn debit(&mut self, amount: u64) -> Result<(), ProgramError> {
+        self.total_balance = self
+            .total_balance
+            .checked_sub(amount)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        Ok(())
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<SubAccountLedger, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(SUB_ACCOUNT_LEDGER_ACCOUNT),
+            Seed::from(self.token_account.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                SUB_ACCOUNT_LEDGER_ACCOUNT,
+                &self.token_account,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+/// Balance a custodian holds for one beneficial owner in its omnibus token account.
+/// Closed when its balance is debited to zero.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct SubAccount {
+    /// SubAccountLedger the sub-account is recorded in
+    pub ledger: Pubkey,
+    /// Beneficial owner identifier (e.g. hash of the custodian customer reference)
+    pub beneficial_owner: [u8; 32],
+    /// Balance held for the beneficial owner
+    pub balance: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for SubAccount {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::SubAccountDiscriminator as u8;
+}
+
+impl AccountSerialize for SubAccount {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.ledger.as_ref());
+        data.extend_from_slice(self.beneficial_owner.as_ref());
+        data.extend_from_slice(&self.balance.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for SubAccount {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let ledger: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let beneficial_owner: [u8; 32] = data[PUBKEY_BYTES..PUBKEY_BYTES + 32]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = PUBKEY_BYTES + 32;
+        let balance = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            ledger,
+            beneficial_owner,
+            balance,
+            bump: data[offset + 8],
+        })
+    }
+}
+
+impl ProgramAccount for SubAccount {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl SubAccount {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + ledger (32 bytes) + beneficial_owner (32 bytes)
+    /// + balance (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + 32 + 8 + 1;
+
+    /// Create an empty sub-account of a beneficial owner
+    pub fn new(ledger: Pubkey, beneficial_owner: [u8; 32], bump: u8) -> Self {
+        Self {
+            ledger,
+            beneficial_owner,
+            balance: 0,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<SubAccount, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 4] {
+        [
+            Seed::from(SUB_ACCOUNT_ACCOUNT),
+            Seed::from(self.ledger.as_ref()),
+            Seed::from(self.beneficial_owner.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                SUB_ACCOUNT_ACCOUNT,
+                &self.ledger,
+                &self.beneficial_owner,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sub_account_ledger_serialization_round_trip() {
+        let mut ledger = SubAccountLedger::new([1u8; 32], [2u8; 32], 254);
+        ledger.credit(700, 1_000).unwrap();
+        ledger.sub_account_count = 2;
+
+        let bytes = ledger.to_bytes();
+        assert_eq!(bytes.len(), SubAccountLedger::LEN);
+        assert_eq!(bytes[0], SubAccountLedger::DISCRIMINATOR);
+
+        let deserialized = SubAccountLedger::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, ledger.mint);
+        assert_eq!(deserialized.token_account, ledger.token_account);
+        assert_eq!(deserialized.total_balance, 700);
+        assert_eq!(deserialized.sub_account_count, 2);
+        assert_eq!(deserialized.bump, 254);
+
+        let sub_account = SubAccount::new([3u8; 32], [4u8; 32], 253);
+        let deserialized = SubAccount::try_from_bytes(&sub_account.to_bytes()).unwrap();
+        assert_eq!(deserialized.ledger, sub_account.ledger);
+        assert_eq!(deserialized.beneficial_owner, sub_account.beneficial_owner);
+        assert_eq!(deserialized.balance, 0);
+        assert_eq!(deserialized.bump, 253);
+    }
+
+    #[test]
+    fn test_sub_account_ledger_credit_and_debit() {
+        let mut ledger = SubAccountLedger::new([1u8; 32], [2u8; 32], 254);
+        ledger.credit(600, 1_000).unwrap();
+        assert_eq!(
+            ledger.credit(401, 1_000),
+            Err(SecurityTokenError::SubAccountBalanceExceeded.into())
+        );
+        ledger.credit(400, 1_000).unwrap();
+        assert_eq!(ledger.debit(1_001), Err(ProgramError::InsufficientFunds));
+        ledger.debit(1_000).unwrap();
+        assert_eq!(ledger.total_balance, 0);
+    }
+}
@@ -0,0 +1,425 @@
+//! Primary issuance subscription state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::{SUBSCRIPTION_ACCOUNT, SUBSCRIPTION_COMMITMENT_ACCOUNT};
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Primary offering of a security token. Investors commit payment tokens into the payment
+/// vault owned by this account until `close_timestamp`; afterwards the issuer allocates
+/// security tokens to each commitment and settlement mints them and releases the payment.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct Subscription {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Mint investors pay in
+    pub payment_mint: Pubkey,
+    /// Issuer payment token account receiving the proceeds
+    pub proceeds_account: Pubkey,
+    /// Issuer chosen identifier distinguishing offerings of the same mint
+    pub subscription_id: u64,
+    /// Price in payment mint base units per whole security token
+    pub price_per_token: u64,
+    /// Unix timestamp commitments close at
+    pub close_timestamp: i64,
+    /// Payment tokens committed by all investors
+    pub total_committed: u64,
+    /// Security tokens allocated to all investors, in base units
+    pub total_allocated: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for Subscription {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::SubscriptionDiscriminator as u8;
+}
+
+impl AccountSerialize for Subscription {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.payment_mint.as_ref());
+        data.extend_from_slice(self.proceeds_account.as_ref());
+        data.extend_from_slice(&self.subscription_id.to_le_bytes());
+        data.extend_from_slice(&self.price_per_token.to_le_bytes());
+        data.extend_from_slice(&self.close_timestamp.to_le_bytes());
+        data.extend_from_slice(&self.total_committed.to_le_bytes());
+        data.extend_from_slice(&self.total_allocated.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for Subscription {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let payment_mint: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let proceeds_account: [u8; PUBKEY_BYTES] = data[2 * PUBKEY_BYTES..3 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = 3 * PUBKEY_BYTES;
+        let subscription_id = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let price_per_token = u64::from_le_bytes(
+            data[offset + 8..offset + 16]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let close_timestamp = i64::from_le_bytes(
+            data[offset + 16..offset + 24]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let total_committed = u64::from_le_bytes(
+            data[offset + 24..offset + 32]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let total_allocated = u64::from_le_bytes(
+            data[offset + 32..offset + 40]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            mint,
+            payment_mint,
+            proceeds_account,
+            subscription_id,
+            price_per_token,
+            close_timestamp,
+            total_committed,
+            total_allocated,
+            bump: data[offset + 40],
+        })
+    }
+}
+
+impl ProgramAccount for Subscription {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl Subscription {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 3 pubkeys (96 bytes) + subscription_id (8 bytes) + price_per_token (8 bytes)
+    /// + close_timestamp (8 bytes) + total_committed (8 bytes) + total_allocated (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (3 * PUBKEY_BYTES) + 8 + 8 + 8 + 8 + 8 + 1;
+
+    /// Create a new Subscription
+    pub fn new(
+        mint: Pubkey,
+        payment_mint: Pubkey,
+        proceeds_account: Pubkey,
+        subscription_id: u64,
+        price_per_token: u64,
+        close_timestamp: i64,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        if price_per_token == 0 || mint == payment_mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(Self {
+            mint,
+            payment_mint,
+            proceeds_account,
+            subscription_id,
+            price_per_token,
+            close_timestamp,
+            total_committed: 0,
+            total_allocated: 0,
+            bump,
+        })
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Subscription, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Whether commitments are accepted at `timestamp`
+    pub fn is_open(&self, timestamp: i64) -> bool {
+        timestamp < self.close_timestamp
+    }
+
+    /// Payment in payment mint base units owed for `allocation` security token base units. Rounded up.
+    pub fn payment_amount(&self, allocation: u64, decimals: u8) -> Result<u64, ProgramError> {
+        let scale = 10u128
+            .checked_pow(u32::from(decimals))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        let payment = u128::from(allocation)
+            .checked_mul(u128::from(self.price_per_token))
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            .div_ceil(scale);
+        u64::try_from(payment).map_err(|_| ProgramError::ArithmeticOverflow)
+    }
+
+    pub fn subscription_id_seed(&self) -> [u8; 8] {
+        self.subscription_id.to_le_bytes()
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        subscription_id_seed: &'a [u8; 8],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 4] {
+        [
+            Seed::from(SUBSCRIPTION_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(subscription_id_seed.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                SUBSCRIPTION_ACCOUNT,
+                &self.mint,
+                &self.subscription_id_seed(),
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+/// Payment committed by an investor to a subscription and the allocation granted to it
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct SubscriptionCommitment {
+    /// Subscription the payment is committed to
+    pub subscription: Pubkey,
+    /// Investor wallet, receives the allocation, the refund and the rent
+    pub investor: Pubkey,
+    /// Payment tokens held in the payment vault for the investor
+    pub amount: u64,
+    /// Security tokens allocated by the issuer, in base units
+    pub allocation: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for SubscriptionCommitment {
+    const DISCRIMINATOR: u8 =
+        SecurityTokenDiscriminators::SubscriptionCommitmentDiscriminator as u8;
+}
+
+impl AccountSerialize for SubscriptionCommitment {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.subscription.as_ref());
+        data.extend_from_slice(self.investor.as_ref());
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data.extend_from_slice(&self.allocation.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for SubscriptionCommitment {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let subscription: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let investor: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = 2 * PUBKEY_BYTES;
+        let amount = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let allocation = u64::from_le_bytes(
+            data[offset + 8..offset + 16]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            subscription,
+            investor,
+            amount,
+            allocation,
+            bump: data[offset + 16],
+        })
+    }
+}
+
+impl ProgramAccount for SubscriptionCommitment {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl SubscriptionCommitment {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 2 pubkeys (64 bytes) + amount (8 bytes) + allocation (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 8 + 8 + 1;
+
+    /// Create a new, empty SubscriptionCommitment
+    pub fn new(subscription: Pubkey, investor: Pubkey, bump: u8) -> Self {
+        Self {
+            subscription,
+            investor,
+            amount: 0,
+            allocation: 0,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<SubscriptionCommitment, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 4] {
+        [
+            Seed::from(SUBSCRIPTION_COMMITMENT_ACCOUNT),
+            Seed::from(self.subscription.as_ref()),
+            Seed::from(self.investor.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                SUBSCRIPTION_COMMITMENT_ACCOUNT,
+                &self.subscription,
+                &self.investor,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subscription() -> Subscription {
+        // 2.5 payment units (6 decimals) per token, closing at 1_000
+        Subscription::new([1u8; 32], [2u8; 32], [3u8; 32], 7, 2_500_000, 1_000, 255).unwrap()
+    }
+
+    #[test]
+    fn test_subscription_serialization_round_trip() {
+        let mut subscription = subscription();
+        subscription.total_committed = 10_000_000;
+        subscription.total_allocated = 4_000_000;
+
+        let bytes = subscription.to_bytes();
+        assert_eq!(bytes.len(), Subscription::LEN);
+
+        let deserialized = Subscription::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, subscription.mint);
+        assert_eq!(deserialized.payment_mint, subscription.payment_mint);
+        assert_eq!(deserialized.proceeds_account, subscription.proceeds_account);
+        assert_eq!(deserialized.subscription_id, subscription.subscription_id);
+        assert_eq!(deserialized.price_per_token, subscription.price_per_token);
+        assert_eq!(deserialized.close_timestamp, subscription.close_timestamp);
+        assert_eq!(deserialized.total_committed, subscription.total_committed);
+        assert_eq!(deserialized.total_allocated, subscription.total_allocated);
+        assert_eq!(deserialized.bump, subscription.bump);
+    }
+
+    #[test]
+    fn test_subscription_rejects_invalid_arguments() {
+        assert!(Subscription::new([1u8; 32], [2u8; 32], [3u8; 32], 0, 0, 1_000, 255).is_err());
+        assert!(Subscription::new([1u8; 32], [1u8; 32], [3u8; 32], 0, 1, 1_000, 255).is_err());
+    }
+
+    #[test]
+    fn test_subscription_window_and_payment() {
+        let subscription = subscription();
+
+        assert!(subscription.is_open(999));
+        assert!(!subscription.is_open(1_000));
+
+        // 4 tokens with 6 decimals
+        assert_eq!(
+            subscription.payment_amount(4_000_000, 6).unwrap(),
+            10_000_000
+        );
+        // Smallest unit still costs one payment unit
+        assert_eq!(subscription.payment_amount(1, 6).unwrap(), 3);
+        assert_eq!(subscription.payment_amount(0, 6).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_subscription_commitment_serialization_round_trip() {
+        let mut commitment = SubscriptionCommitment::new([1u8; 32], [2u8; 32], 254);
+        commitment.amount = 10_000_000;
+        commitment.allocation = 4_000_000;
+
+        let bytes = commitment.to_bytes();
+        assert_eq!(bytes.len(), SubscriptionCommitment::LEN);
+
+        let deserialized = SubscriptionCommitment::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.subscription, commitment.subscription);
+        assert_eq!(deserialized.investor, commitment.investor);
+        assert_eq!(deserialized.amount, commitment.amount);
+        assert_eq!(deserialized.allocation, commitment.allocation);
+        assert_eq!(deserialized.bump, commitment.bump);
+    }
+}
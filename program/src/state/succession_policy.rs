@@ -0,0 +1,219 @@
+//! Succession policy state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::SUCCESSION_POLICY_ACCOUNT;
+use crate::error::SecurityTokenError;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Dead-man switch of a mint: the successor can claim the authority of the mint creator once
+/// no heartbeat was performed for `heartbeat_period` seconds
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct SuccessionPolicy {
+    /// Mint the policy applies to
+    pub mint: Pubkey,
+    /// Fallback authority
+    pub successor: Pubkey,
+    /// Seconds without heartbeat after which the successor can claim
+    pub heartbeat_period: i64,
+    /// Unix timestamp of the last heartbeat
+    pub last_heartbeat: i64,
+    /// Unix timestamp of the claim, `0` until the successor claims
+    pub claimed_at: i64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for SuccessionPolicy {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::SuccessionPolicyDiscriminator as u8;
+}
+
+impl AccountSerialize for SuccessionPolicy {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.successor.as_ref());
+        data.extend_from_slice(&self.heartbeat_period.to_le_bytes());
+        data.extend_from_slice(&self.last_heartbeat.to_le_bytes());
+        data.extend_from_slice(&self.claimed_at.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for SuccessionPolicy {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let pubkey_at = |offset: usize| -> Result<Pubkey, ProgramError> {
+            data[offset..offset + PUBKEY_BYTES]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)
+        };
+        let i64_at = |offset: usize| -> Result<i64, ProgramError> {
+            data[offset..offset + 8]
+                .try_into()
+                .map(i64::from_le_bytes)
+                .map_err(|_| ProgramError::InvalidAccountData)
+        };
+
+        let offset = 2 * PUBKEY_BYTES;
+        Ok(Self {
+            mint: pubkey_at(0)?,
+            successor: pubkey_at(PUBKEY_BYTES)?,
+            heartbeat_period: i64_at(offset)?,
+            last_heartbeat: i64_at(offset + 8)?,
+            claimed_at: i64_at(offset + 16)?,
+            bump: data[offset + 24],
+        })
+    }
+}
+
+impl ProgramAccount for SuccessionPolicy {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl SuccessionPolicy {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 2 pubkeys (64 bytes) + heartbeat_period, last_heartbeat and
+    /// claimed_at (24 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 24 + 1;
+
+    /// Create a policy whose heartbeat period starts at `now`
+    pub fn new(
+        mint: Pubkey,
+        successor: Pubkey,
+        heartbeat_period: i64,
+        now: i64,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        if heartbeat_period <= 0 || successor == Pubkey::default() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(Self {
+            mint,
+            successor,
+            heartbeat_period,
+            last_heartbeat: now,
+            claimed_at: 0,
+            bump,
+        })
+    }
+
+    /// Whether the successor claimed the authority of the mint
+    pub fn is_claimed(&self) -> bool {
+        self.claimed_at != 0
+    }
+
+    /// Record a heartbeat of the primary authority at `now`
+    pub fn heartbeat(&mut self, now: i64) -> Result<(), ProgramError> {
+        if self.is_claimed() {
+            return Err(SecurityTokenError::SuccessionClaimed.into());
+        }
+        self.last_heartbeat = now;
+        Ok(())
+    }
+
+    /// Hand the authority over to the successor at `now`
+    pub fn claim(&mut self, now: i64) -> Result<(), ProgramError> {
+        if self.is_claimed() {
+            return Err(SecurityTokenError::SuccessionClaimed.into());
+        }
+        if now < self.last_heartbeat.saturating_add(self.heartbeat_period) {
+            return Err(SecurityTokenError::SuccessionNotClaimable.into());
+        }
+        self.claimed_at = now;
+        Ok(())
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<SuccessionPolicy, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(SUCCESSION_POLICY_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[SUCCESSION_POLICY_ACCOUNT, &self.mint, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_succession_policy_serialization_round_trip() {
+        let mut policy = SuccessionPolicy::new([1u8; 32], [2u8; 32], 86_400, 1_000, 254).unwrap();
+        policy.claim(90_000).unwrap();
+
+        let bytes = policy.to_bytes();
+        assert_eq!(bytes.len(), SuccessionPolicy::LEN);
+        assert_eq!(bytes[0], SuccessionPolicy::DISCRIMINATOR);
+
+        let deserialized = SuccessionPolicy::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, policy.mint);
+        assert_eq!(deserialized.successor, policy.successor);
+        assert_eq!(deserialized.heartbeat_period, 86_400);
+        assert_eq!(deserialized.last_heartbeat, 1_000);
+        assert_eq!(deserialized.claimed_at, 90_000);
+        assert_eq!(deserialized.bump, 254);
+    }
+
+    #[test]
+    fn test_succession_policy_claim_after_heartbeat_period() {
+        let mut policy = SuccessionPolicy::new([1u8; 32], [2u8; 32], 100, 1_000, 254).unwrap();
+        assert_eq!(
+            policy.claim(1_099),
+            Err(SecurityTokenError::SuccessionNotClaimable.into())
+        );
+        policy.heartbeat(1_050).unwrap();
+        assert_eq!(
+            policy.claim(1_100),
+            Err(SecurityTokenError::SuccessionNotClaimable.into())
+        );
+        policy.claim(1_150).unwrap();
+        assert!(policy.is_claimed());
+        assert_eq!(
+            policy.heartbeat(1_200),
+            Err(SecurityTokenError::SuccessionClaimed.into())
+        );
+        assert!(SuccessionPolicy::new([1u8; 32], [2u8; 32], 0, 1_000, 254).is_err());
+    }
+}
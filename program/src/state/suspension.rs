@@ -0,0 +1,170 @@
+//! Account-level suspension state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::SUSPENSION_ACCOUNT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Investigative hold on a token account. While it exists the transfer hook and the escrow
+/// transfer flows reject transfers from and to the token account. Unlike a Token-2022 freeze,
+/// the token account state is left untouched.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct Suspension {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Suspended token account
+    pub token_account: Pubkey,
+    /// Account that paid the rent, receives it back on unsuspend
+    pub payer: Pubkey,
+    /// Unix timestamp the token account was suspended at
+    pub suspended_at: i64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for Suspension {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::SuspensionDiscriminator as u8;
+}
+
+impl AccountSerialize for Suspension {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.token_account.as_ref());
+        data.extend_from_slice(self.payer.as_ref());
+        data.extend_from_slice(&self.suspended_at.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for Suspension {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let token_account: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let payer: [u8; PUBKEY_BYTES] = data[2 * PUBKEY_BYTES..3 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let offset = 3 * PUBKEY_BYTES;
+        let suspended_at = i64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            mint,
+            token_account,
+            payer,
+            suspended_at,
+            bump: data[offset + 8],
+        })
+    }
+}
+
+impl ProgramAccount for Suspension {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl Suspension {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 3 pubkeys (96 bytes) + suspended_at (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (3 * PUBKEY_BYTES) + 8 + 1;
+
+    /// Create a new Suspension
+    pub fn new(
+        mint: Pubkey,
+        token_account: Pubkey,
+        payer: Pubkey,
+        suspended_at: i64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            mint,
+            token_account,
+            payer,
+            suspended_at,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Suspension, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(SUSPENSION_ACCOUNT),
+            Seed::from(self.token_account.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[SUSPENSION_ACCOUNT, &self.token_account, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suspension_serialization_round_trip() {
+        let suspension = Suspension::new([1u8; 32], [2u8; 32], [3u8; 32], 1_700_000_000, 254);
+
+        let bytes = suspension.to_bytes();
+        assert_eq!(bytes.len(), Suspension::LEN);
+        assert_eq!(bytes[0], Suspension::DISCRIMINATOR);
+
+        let deserialized = Suspension::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, suspension.mint);
+        assert_eq!(deserialized.token_account, suspension.token_account);
+        assert_eq!(deserialized.payer, suspension.payer);
+        assert_eq!(deserialized.suspended_at, suspension.suspended_at);
+        assert_eq!(deserialized.bump, suspension.bump);
+    }
+
+    #[test]
+    fn test_suspension_rejects_invalid_length() {
+        let bytes = Suspension::new([1u8; 32], [2u8; 32], [3u8; 32], 0, 254).to_bytes();
+        assert!(Suspension::try_from_bytes(&bytes[..Suspension::LEN - 1]).is_err());
+    }
+}
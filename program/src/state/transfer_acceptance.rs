@@ -0,0 +1,312 @@
+//! Recipient opt-in transfer state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::{PENDING_TRANSFER_ACCOUNT, TRANSFER_ACCEPTANCE_ACCOUNT};
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Marks a mint whose inbound transfers must be accepted by the recipient.
+/// While it exists the transfer hook rejects direct transfers of the mint.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct TransferAcceptance {
+    /// Mint the mode applies to
+    pub mint: Pubkey,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for TransferAcceptance {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::TransferAcceptanceDiscriminator as u8;
+}
+
+impl AccountSerialize for TransferAcceptance {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for TransferAcceptance {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let bump = data[PUBKEY_BYTES];
+
+        Ok(Self { mint, bump })
+    }
+}
+
+impl ProgramAccount for TransferAcceptance {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl TransferAcceptance {
+    /// Serialized size of the account data (discriminator + mint + bump)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + 1;
+
+    /// Create a new TransferAcceptance
+    pub fn new(mint: Pubkey, bump: u8) -> Self {
+        Self { mint, bump }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<TransferAcceptance, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(TRANSFER_ACCEPTANCE_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[TRANSFER_ACCEPTANCE_ACCOUNT, &self.mint, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+/// Transfer held in the mint transfer escrow until the recipient accepts it
+/// or either party cancels it.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct PendingTransfer {
+    /// Mint of the transferred tokens
+    pub mint: Pubkey,
+    /// Source token account, refunded on cancel
+    pub source: Pubkey,
+    /// Destination token account, credited on accept
+    pub destination: Pubkey,
+    /// Account that paid the rent and receives it back when the transfer settles
+    pub payer: Pubkey,
+    /// Amount held in escrow
+    pub amount: u64,
+    /// Sender chosen identifier distinguishing transfers between the same accounts
+    pub transfer_id: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for PendingTransfer {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::PendingTransferDiscriminator as u8;
+}
+
+impl AccountSerialize for PendingTransfer {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.source.as_ref());
+        data.extend_from_slice(self.destination.as_ref());
+        data.extend_from_slice(self.payer.as_ref());
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data.extend_from_slice(&self.transfer_id.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for PendingTransfer {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let pubkey_at = |index: usize| -> Result<Pubkey, ProgramError> {
+            data[index * PUBKEY_BYTES..(index + 1) * PUBKEY_BYTES]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)
+        };
+        let offset = 4 * PUBKEY_BYTES;
+        let amount = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let transfer_id = u64::from_le_bytes(
+            data[offset + 8..offset + 16]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            mint: pubkey_at(0)?,
+            source: pubkey_at(1)?,
+            destination: pubkey_at(2)?,
+            payer: pubkey_at(3)?,
+            amount,
+            transfer_id,
+            bump: data[offset + 16],
+        })
+    }
+}
+
+impl ProgramAccount for PendingTransfer {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl PendingTransfer {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 4 pubkeys (128 bytes) + amount (8 bytes) + transfer_id (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (4 * PUBKEY_BYTES) + 8 + 8 + 1;
+
+    /// Create a new PendingTransfer
+    pub fn new(
+        mint: Pubkey,
+        source: Pubkey,
+        destination: Pubkey,
+        payer: Pubkey,
+        amount: u64,
+        transfer_id: u64,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        if amount == 0 || source == destination {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(Self {
+            mint,
+            source,
+            destination,
+            payer,
+            amount,
+            transfer_id,
+            bump,
+        })
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<PendingTransfer, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn transfer_id_seed(&self) -> [u8; 8] {
+        self.transfer_id.to_le_bytes()
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        transfer_id_seed: &'a [u8; 8],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 5] {
+        [
+            Seed::from(PENDING_TRANSFER_ACCOUNT),
+            Seed::from(self.source.as_ref()),
+            Seed::from(self.destination.as_ref()),
+            Seed::from(transfer_id_seed.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                PENDING_TRANSFER_ACCOUNT,
+                &self.source,
+                &self.destination,
+                &self.transfer_id_seed(),
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_acceptance_serialization_round_trip() {
+        let acceptance = TransferAcceptance::new([1u8; 32], 254);
+
+        let bytes = acceptance.to_bytes();
+        assert_eq!(bytes.len(), TransferAcceptance::LEN);
+
+        let deserialized = TransferAcceptance::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, acceptance.mint);
+        assert_eq!(deserialized.bump, acceptance.bump);
+    }
+
+    #[test]
+    fn test_pending_transfer_serialization_round_trip() {
+        let pending =
+            PendingTransfer::new([1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32], 500, 7, 253).unwrap();
+
+        let bytes = pending.to_bytes();
+        assert_eq!(bytes.len(), PendingTransfer::LEN);
+
+        let deserialized = PendingTransfer::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, pending.mint);
+        assert_eq!(deserialized.source, pending.source);
+        assert_eq!(deserialized.destination, pending.destination);
+        assert_eq!(deserialized.payer, pending.payer);
+        assert_eq!(deserialized.amount, pending.amount);
+        assert_eq!(deserialized.transfer_id, pending.transfer_id);
+        assert_eq!(deserialized.bump, pending.bump);
+    }
+
+    #[test]
+    fn test_pending_transfer_rejects_invalid_arguments() {
+        assert!(
+            PendingTransfer::new([1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32], 0, 7, 253).is_err()
+        );
+        assert!(
+            PendingTransfer::new([1u8; 32], [2u8; 32], [2u8; 32], [4u8; 32], 500, 7, 253).is_err()
+        );
+    }
+}
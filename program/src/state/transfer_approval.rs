@@ -0,0 +1,327 @@
+//! Transfer approval queue state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::{TRANSFER_APPROVAL_ACCOUNT, TRANSFER_REQUEST_ACCOUNT};
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Marks a mint whose transfers above `threshold` must be reviewed by a compliance officer.
+/// While it exists the transfer hook rejects direct transfers above the threshold.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct TransferApproval {
+    /// Mint the review applies to
+    pub mint: Pubkey,
+    /// Largest amount transferable without approval
+    pub threshold: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for TransferApproval {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::TransferApprovalDiscriminator as u8;
+}
+
+impl AccountSerialize for TransferApproval {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(&self.threshold.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for TransferApproval {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let threshold = u64::from_le_bytes(
+            data[PUBKEY_BYTES..PUBKEY_BYTES + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let bump = data[PUBKEY_BYTES + 8];
+
+        Ok(Self {
+            mint,
+            threshold,
+            bump,
+        })
+    }
+}
+
+impl ProgramAccount for TransferApproval {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl TransferApproval {
+    /// Serialized size of the account data (discriminator + mint + threshold + bump)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + 8 + 1;
+
+    /// Create a new TransferApproval
+    pub fn new(mint: Pubkey, threshold: u64, bump: u8) -> Self {
+        Self {
+            mint,
+            threshold,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<TransferApproval, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 3] {
+        [
+            Seed::from(TRANSFER_APPROVAL_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[TRANSFER_APPROVAL_ACCOUNT, &self.mint, &self.bump_seed()],
+            &crate::id(),
+        )
+    }
+}
+
+/// Transfer held in the mint transfer escrow until a compliance officer
+/// approves or rejects it.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct TransferRequest {
+    /// Mint of the transferred tokens
+    pub mint: Pubkey,
+    /// Source token account, refunded on reject
+    pub source: Pubkey,
+    /// Destination token account, credited on approve
+    pub destination: Pubkey,
+    /// Account that paid the rent and receives it back when the transfer settles
+    pub payer: Pubkey,
+    /// Amount held in escrow
+    pub amount: u64,
+    /// Sender chosen identifier distinguishing requests between the same accounts
+    pub request_id: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for TransferRequest {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::TransferRequestDiscriminator as u8;
+}
+
+impl AccountSerialize for TransferRequest {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.source.as_ref());
+        data.extend_from_slice(self.destination.as_ref());
+        data.extend_from_slice(self.payer.as_ref());
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data.extend_from_slice(&self.request_id.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for TransferRequest {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let pubkey_at = |index: usize| -> Result<Pubkey, ProgramError> {
+            data[index * PUBKEY_BYTES..(index + 1) * PUBKEY_BYTES]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)
+        };
+        let offset = 4 * PUBKEY_BYTES;
+        let amount = u64::from_le_bytes(
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let request_id = u64::from_le_bytes(
+            data[offset + 8..offset + 16]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        Ok(Self {
+            mint: pubkey_at(0)?,
+            source: pubkey_at(1)?,
+            destination: pubkey_at(2)?,
+            payer: pubkey_at(3)?,
+            amount,
+            request_id,
+            bump: data[offset + 16],
+        })
+    }
+}
+
+impl ProgramAccount for TransferRequest {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl TransferRequest {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 4 pubkeys (128 bytes) + amount (8 bytes) + request_id (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (4 * PUBKEY_BYTES) + 8 + 8 + 1;
+
+    /// Create a new TransferRequest
+    pub fn new(
+        mint: Pubkey,
+        source: Pubkey,
+        destination: Pubkey,
+        payer: Pubkey,
+        amount: u64,
+        request_id: u64,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        if amount == 0 || source == destination {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(Self {
+            mint,
+            source,
+            destination,
+            payer,
+            amount,
+            request_id,
+            bump,
+        })
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<TransferRequest, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn request_id_seed(&self) -> [u8; 8] {
+        self.request_id.to_le_bytes()
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        request_id_seed: &'a [u8; 8],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 5] {
+        [
+            Seed::from(TRANSFER_REQUEST_ACCOUNT),
+            Seed::from(self.source.as_ref()),
+            Seed::from(self.destination.as_ref()),
+            Seed::from(request_id_seed.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                TRANSFER_REQUEST_ACCOUNT,
+                &self.source,
+                &self.destination,
+                &self.request_id_seed(),
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_approval_serialization_round_trip() {
+        let approval = TransferApproval::new([1u8; 32], 10_000, 254);
+
+        let bytes = approval.to_bytes();
+        assert_eq!(bytes.len(), TransferApproval::LEN);
+
+        let deserialized = TransferApproval::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, approval.mint);
+        assert_eq!(deserialized.threshold, approval.threshold);
+        assert_eq!(deserialized.bump, approval.bump);
+    }
+
+    #[test]
+    fn test_transfer_request_serialization_round_trip() {
+        let request =
+            TransferRequest::new([1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32], 500, 7, 253).unwrap();
+
+        let bytes = request.to_bytes();
+        assert_eq!(bytes.len(), TransferRequest::LEN);
+
+        let deserialized = TransferRequest::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, request.mint);
+        assert_eq!(deserialized.source, request.source);
+        assert_eq!(deserialized.destination, request.destination);
+        assert_eq!(deserialized.payer, request.payer);
+        assert_eq!(deserialized.amount, request.amount);
+        assert_eq!(deserialized.request_id, request.request_id);
+        assert_eq!(deserialized.bump, request.bump);
+    }
+
+    #[test]
+    fn test_transfer_request_rejects_invalid_arguments() {
+        assert!(
+            TransferRequest::new([1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32], 0, 7, 253).is_err()
+        );
+        assert!(
+            TransferRequest::new([1u8; 32], [2u8; 32], [2u8; 32], [4u8; 32], 500, 7, 253).is_err()
+        );
+    }
+}
@@ -2,7 +2,8 @@
 
 use crate::constants::seeds::VERIFICATION_CONFIG;
 use crate::state::{
-    AccountDeserialize, AccountSerialize, Discriminator, SecurityTokenDiscriminators,
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
 };
 use pinocchio::pubkey::{checked_create_program_address, Pubkey, PUBKEY_BYTES};
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
@@ -18,8 +19,18 @@ pub struct VerificationConfig {
     pub cpi_mode: bool,
     /// PDA bump seed used for address derivation
     pub bump: u8,
-    /// Required verification programs
+    /// Required verification programs, override the template programs when not empty
     pub verification_programs: Vec<Pubkey>,
+    /// Number of programs that must pass, 0 requires all of them (1 = ANY, K = K-of-N)
+    pub threshold: u8,
+    /// Number of instructions right before the verified one the introspection looks at, 0 for all of them
+    pub lookback_window: u8,
+    /// Introspection requires the verified instruction to be a top-level instruction
+    pub require_top_level: bool,
+    /// ConfigTemplate providing the programs, `None` for standalone configs
+    pub config_template: Option<Pubkey>,
+    /// Delay in seconds before updates take effect, 0 applies them immediately
+    pub timelock_seconds: u64,
 }
 
 impl Discriminator for VerificationConfig {
@@ -47,6 +58,25 @@ impl AccountSerialize for VerificationConfig {
             data.extend_from_slice(program.as_ref());
         }
 
+        // Write threshold (1 byte)
+        data.push(self.threshold);
+
+        // Write lookback_window (1 byte)
+        data.push(self.lookback_window);
+
+        // Write require_top_level (1 byte)
+        data.push(self.require_top_level as u8);
+
+        // Write the linked template (32 bytes), omitted for standalone configs
+        if let Some(config_template) = &self.config_template {
+            data.extend_from_slice(config_template.as_ref());
+        }
+
+        // Write timelock_seconds (8 bytes), omitted for configs without a timelock
+        if self.timelock_seconds != 0 {
+            data.extend(&self.timelock_seconds.to_le_bytes());
+        }
+
         data
     }
 }
@@ -92,11 +122,52 @@ impl AccountDeserialize for VerificationConfig {
             offset += PUBKEY_BYTES;
         }
 
+        // Read threshold (1 byte), lookback_window (1 byte) and require_top_level (1 byte),
+        // configs written before they were introduced keep the defaults
+        let (threshold, lookback_window, require_top_level) = match data.len() - offset {
+            1 | 33 => {
+                offset += 1;
+                (data[offset - 1], 0, false)
+            }
+            3 | 11 | 35 | 43 => {
+                offset += 3;
+                (data[offset - 3], data[offset - 2], data[offset - 1] != 0)
+            }
+            _ => (0, 0, false),
+        };
+
+        // Read the linked template (32 bytes) and timelock_seconds (8 bytes) if present
+        let (config_template, timelock_bytes) = match data.len() - offset {
+            0 | 8 => (None, &data[offset..]),
+            PUBKEY_BYTES | 40 => (
+                Some(
+                    data[offset..offset + PUBKEY_BYTES]
+                        .try_into()
+                        .map_err(|_| ProgramError::InvalidAccountData)?,
+                ),
+                &data[offset + PUBKEY_BYTES..],
+            ),
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+        let timelock_seconds = match timelock_bytes.len() {
+            0 => 0,
+            _ => u64::from_le_bytes(
+                timelock_bytes
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidAccountData)?,
+            ),
+        };
+
         let config = Self {
             instruction_discriminator,
             cpi_mode,
             bump,
             verification_programs,
+            threshold,
+            lookback_window,
+            require_top_level,
+            config_template,
+            timelock_seconds,
         };
 
         // Validate the configuration
@@ -106,8 +177,15 @@ impl AccountDeserialize for VerificationConfig {
     }
 }
 
+impl ProgramAccount for VerificationConfig {
+    fn space(&self) -> u64 {
+        self.serialized_size() as u64
+    }
+}
+
 impl VerificationConfig {
     /// Minimum size: discriminator (1) + instruction_discriminator (1) + cpi_mode (1) + bump (1) + vector length (4) = 8 bytes
+    /// Configs written before the threshold, lookback window and top-level flag lack them
     pub const MIN_LEN: usize = 1 + 1 + 1 + 1 + 4;
 
     /// Create new VerificationConfig
@@ -116,18 +194,27 @@ impl VerificationConfig {
         cpi_mode: bool,
         bump: u8,
         verification_program_addresses: &[Pubkey],
+        threshold: u8,
+        lookback_window: u8,
+        require_top_level: bool,
     ) -> Result<Self, ProgramError> {
         Ok(Self {
             instruction_discriminator,
             cpi_mode,
             bump,
             verification_programs: verification_program_addresses.to_vec(),
+            threshold,
+            lookback_window,
+            require_top_level,
+            config_template: None,
+            timelock_seconds: 0,
         })
     }
 
     /// Validate the configuration
+    /// Configs linked to a template may leave the programs empty to use the template ones
     pub fn validate(&self) -> Result<(), ProgramError> {
-        if self.verification_programs.is_empty() {
+        if self.verification_programs.is_empty() && self.config_template.is_none() {
             return Err(ProgramError::InvalidAccountData);
         }
         // Validate that all programs are non-zero (valid pubkeys)
@@ -137,9 +224,31 @@ impl VerificationConfig {
                 return Err(ProgramError::InvalidAccountData);
             }
         }
+        // Linked configs without programs are checked against the template programs on use
+        if !self.verification_programs.is_empty()
+            && self.threshold as usize > self.verification_programs.len()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // A window smaller than the programs to find can never be satisfied
+        if !self.verification_programs.is_empty()
+            && self.lookback_window != 0
+            && (self.lookback_window as usize)
+                < self.required_programs(self.verification_programs.len())
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
         Ok(())
     }
 
+    /// Number of programs that must pass out of `program_count` configured ones
+    pub fn required_programs(&self, program_count: usize) -> usize {
+        match self.threshold {
+            0 => program_count,
+            threshold => threshold as usize,
+        }
+    }
+
     /// Calculate the actual size needed for serialization
     pub fn serialized_size(&self) -> usize {
         1 // account discriminator
@@ -148,6 +257,11 @@ impl VerificationConfig {
             + 1 // bump
             + 4 // vector length prefix
             + (self.verification_programs.len() * PUBKEY_BYTES)
+            + 1 // threshold
+            + 1 // lookback_window
+            + 1 // require_top_level
+            + self.config_template.map_or(0, |_| PUBKEY_BYTES) // linked template
+            + if self.timelock_seconds != 0 { 8 } else { 0 } // timelock_seconds
     }
 
     pub fn from_account_info(account: &AccountInfo) -> Result<Self, ProgramError> {
@@ -174,3 +288,98 @@ impl VerificationConfig {
         checked_create_program_address(&seeds, &crate::id())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verification_config_threshold_round_trip() {
+        let mut config =
+            VerificationConfig::new(12, true, 254, &[[2u8; 32], [3u8; 32]], 1, 0, false).unwrap();
+        config.config_template = Some([4u8; 32]);
+
+        let bytes = config.to_bytes();
+        assert_eq!(bytes.len(), config.serialized_size());
+
+        let deserialized = VerificationConfig::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.threshold, 1);
+        assert_eq!(deserialized.config_template, Some([4u8; 32]));
+        assert_eq!(deserialized.required_programs(2), 1);
+    }
+
+    #[test]
+    fn test_verification_config_without_threshold_requires_all() {
+        let config =
+            VerificationConfig::new(12, false, 254, &[[2u8; 32], [3u8; 32]], 0, 0, false).unwrap();
+        let mut bytes = config.to_bytes();
+        // Drop the threshold, lookback window and top-level bytes to get the layout written before them
+        bytes.truncate(bytes.len() - 3);
+
+        let deserialized = VerificationConfig::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.threshold, 0);
+        assert_eq!(deserialized.config_template, None);
+        assert_eq!(deserialized.required_programs(2), 2);
+    }
+
+    #[test]
+    fn test_verification_config_lookback_window_round_trip() {
+        let mut config =
+            VerificationConfig::new(6, false, 254, &[[2u8; 32], [3u8; 32]], 0, 0, false).unwrap();
+        config.lookback_window = 2;
+        config.require_top_level = true;
+
+        let bytes = config.to_bytes();
+        assert_eq!(bytes.len(), config.serialized_size());
+
+        let deserialized = VerificationConfig::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.lookback_window, 2);
+        assert!(deserialized.require_top_level);
+
+        // Configs with only a threshold byte keep the defaults
+        let legacy = [&bytes[..bytes.len() - 2]].concat();
+        let deserialized = VerificationConfig::try_from_bytes(&legacy).unwrap();
+        assert_eq!(deserialized.lookback_window, 0);
+        assert!(!deserialized.require_top_level);
+    }
+
+    #[test]
+    fn test_verification_config_timelock_round_trip() {
+        let mut config =
+            VerificationConfig::new(6, false, 254, &[[2u8; 32], [3u8; 32]], 0, 0, false).unwrap();
+        let untimed_len = config.to_bytes().len();
+        config.timelock_seconds = 86_400;
+
+        let bytes = config.to_bytes();
+        assert_eq!(bytes.len(), untimed_len + 8);
+        assert_eq!(bytes.len(), config.serialized_size());
+        let deserialized = VerificationConfig::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.timelock_seconds, 86_400);
+        assert_eq!(deserialized.config_template, None);
+
+        // The timelock follows the linked template
+        config.config_template = Some([4u8; 32]);
+        let bytes = config.to_bytes();
+        assert_eq!(bytes.len(), config.serialized_size());
+        let deserialized = VerificationConfig::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.timelock_seconds, 86_400);
+        assert_eq!(deserialized.config_template, Some([4u8; 32]));
+    }
+
+    #[test]
+    fn test_verification_config_rejects_window_below_required_programs() {
+        let mut config =
+            VerificationConfig::new(6, false, 254, &[[2u8; 32], [3u8; 32]], 0, 0, false).unwrap();
+        config.lookback_window = 1;
+        assert!(config.validate().is_err());
+
+        config.threshold = 1;
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_verification_config_rejects_threshold_above_program_count() {
+        let config = VerificationConfig::new(12, false, 254, &[[2u8; 32]], 2, 0, false).unwrap();
+        assert!(config.validate().is_err());
+    }
+}
@@ -0,0 +1,275 @@
+//! Vesting state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::VESTING_ACCOUNT;
+use crate::error::SecurityTokenError;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Linear vesting grant of tokens locked in the transfer escrow.
+/// `total_amount` vests continuously between `start_timestamp` and `end_timestamp`,
+/// anyone can release the vested part to the beneficiary token account.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct Vesting {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Token account receiving the vested tokens
+    pub beneficiary: Pubkey,
+    /// Account that paid the rent, receives it back once everything is released
+    pub payer: Pubkey,
+    /// Tokens granted, held in the transfer escrow until released
+    pub total_amount: u64,
+    /// Tokens already released to the beneficiary
+    pub released_amount: u64,
+    /// Unix timestamp vesting starts at
+    pub start_timestamp: i64,
+    /// Unix timestamp the whole grant is vested at
+    pub end_timestamp: i64,
+    /// Issuer chosen identifier distinguishing grants of the same beneficiary
+    pub vesting_id: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for Vesting {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::VestingDiscriminator as u8;
+}
+
+impl AccountSerialize for Vesting {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.beneficiary.as_ref());
+        data.extend_from_slice(self.payer.as_ref());
+        data.extend_from_slice(&self.total_amount.to_le_bytes());
+        data.extend_from_slice(&self.released_amount.to_le_bytes());
+        data.extend_from_slice(&self.start_timestamp.to_le_bytes());
+        data.extend_from_slice(&self.end_timestamp.to_le_bytes());
+        data.extend_from_slice(&self.vesting_id.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for Vesting {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let pubkey_at = |index: usize| -> Result<Pubkey, ProgramError> {
+            data[index * PUBKEY_BYTES..(index + 1) * PUBKEY_BYTES]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)
+        };
+        let word_at = |index: usize| -> Result<[u8; 8], ProgramError> {
+            let offset = 3 * PUBKEY_BYTES + index * 8;
+            data[offset..offset + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)
+        };
+
+        Ok(Self {
+            mint: pubkey_at(0)?,
+            beneficiary: pubkey_at(1)?,
+            payer: pubkey_at(2)?,
+            total_amount: u64::from_le_bytes(word_at(0)?),
+            released_amount: u64::from_le_bytes(word_at(1)?),
+            start_timestamp: i64::from_le_bytes(word_at(2)?),
+            end_timestamp: i64::from_le_bytes(word_at(3)?),
+            vesting_id: u64::from_le_bytes(word_at(4)?),
+            bump: data[3 * PUBKEY_BYTES + 40],
+        })
+    }
+}
+
+impl ProgramAccount for Vesting {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl Vesting {
+    /// Serialized size of the account data
+    /// Discriminator (1 byte) + 3 pubkeys (96 bytes) + amounts (16 bytes) + timestamps (16 bytes)
+    /// + vesting_id (8 bytes) + bump (1 byte)
+    pub const LEN: usize = 1 + (3 * PUBKEY_BYTES) + 16 + 16 + 8 + 1;
+
+    /// Create a new Vesting
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mint: Pubkey,
+        beneficiary: Pubkey,
+        payer: Pubkey,
+        total_amount: u64,
+        start_timestamp: i64,
+        end_timestamp: i64,
+        vesting_id: u64,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        if total_amount == 0 || end_timestamp <= start_timestamp {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(Self {
+            mint,
+            beneficiary,
+            payer,
+            total_amount,
+            released_amount: 0,
+            start_timestamp,
+            end_timestamp,
+            vesting_id,
+            bump,
+        })
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Vesting, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    /// Tokens vested at `timestamp`, linear between the start and end timestamps
+    pub fn vested_amount(&self, timestamp: i64) -> u64 {
+        if timestamp <= self.start_timestamp {
+            return 0;
+        }
+        if timestamp >= self.end_timestamp {
+            return self.total_amount;
+        }
+        let elapsed = (timestamp - self.start_timestamp) as u128;
+        let duration = (self.end_timestamp - self.start_timestamp) as u128;
+        // elapsed < duration keeps the result below total_amount
+        (self.total_amount as u128 * elapsed / duration) as u64
+    }
+
+    /// Record the release of the tokens vested at `timestamp` and not released yet,
+    /// returns the released amount
+    pub fn release(&mut self, timestamp: i64) -> Result<u64, ProgramError> {
+        let releasable = self
+            .vested_amount(timestamp)
+            .saturating_sub(self.released_amount);
+        if releasable == 0 {
+            return Err(SecurityTokenError::NothingVested.into());
+        }
+        self.released_amount += releasable;
+        Ok(releasable)
+    }
+
+    /// Whether the whole grant was released
+    pub fn is_fully_released(&self) -> bool {
+        self.released_amount == self.total_amount
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn vesting_id_seed(&self) -> [u8; 8] {
+        self.vesting_id.to_le_bytes()
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        vesting_id_seed: &'a [u8; 8],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 4] {
+        [
+            Seed::from(VESTING_ACCOUNT),
+            Seed::from(self.beneficiary.as_ref()),
+            Seed::from(vesting_id_seed.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                VESTING_ACCOUNT,
+                &self.beneficiary,
+                &self.vesting_id_seed(),
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vesting() -> Vesting {
+        Vesting::new([1u8; 32], [2u8; 32], [3u8; 32], 1_000, 100, 200, 7, 254).unwrap()
+    }
+
+    #[test]
+    fn test_vesting_serialization_round_trip() {
+        let mut vesting = vesting();
+        vesting.released_amount = 250;
+
+        let bytes = vesting.to_bytes();
+        assert_eq!(bytes.len(), Vesting::LEN);
+        assert_eq!(bytes[0], Vesting::DISCRIMINATOR);
+
+        let deserialized = Vesting::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, vesting.mint);
+        assert_eq!(deserialized.beneficiary, vesting.beneficiary);
+        assert_eq!(deserialized.payer, vesting.payer);
+        assert_eq!(deserialized.total_amount, vesting.total_amount);
+        assert_eq!(deserialized.released_amount, vesting.released_amount);
+        assert_eq!(deserialized.start_timestamp, vesting.start_timestamp);
+        assert_eq!(deserialized.end_timestamp, vesting.end_timestamp);
+        assert_eq!(deserialized.vesting_id, vesting.vesting_id);
+        assert_eq!(deserialized.bump, vesting.bump);
+    }
+
+    #[test]
+    fn test_vesting_rejects_invalid_arguments() {
+        assert!(Vesting::new([1u8; 32], [2u8; 32], [3u8; 32], 0, 100, 200, 7, 254).is_err());
+        assert!(Vesting::new([1u8; 32], [2u8; 32], [3u8; 32], 1_000, 200, 200, 7, 254).is_err());
+    }
+
+    #[test]
+    fn test_vested_amount_is_linear() {
+        let vesting = vesting();
+
+        assert_eq!(vesting.vested_amount(0), 0);
+        assert_eq!(vesting.vested_amount(100), 0);
+        assert_eq!(vesting.vested_amount(125), 250);
+        assert_eq!(vesting.vested_amount(199), 990);
+        assert_eq!(vesting.vested_amount(200), 1_000);
+        assert_eq!(vesting.vested_amount(i64::MAX), 1_000);
+    }
+
+    #[test]
+    fn test_release_only_newly_vested() {
+        let mut vesting = vesting();
+
+        assert!(vesting.release(100).is_err());
+        assert_eq!(vesting.release(150).unwrap(), 500);
+        assert!(vesting.release(150).is_err());
+        assert_eq!(vesting.release(175).unwrap(), 250);
+        assert!(!vesting.is_fully_released());
+        assert_eq!(vesting.release(300).unwrap(), 250);
+        assert!(vesting.is_fully_released());
+    }
+}
@@ -0,0 +1,206 @@
+//! Withholding rate account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::WITHHOLDING_RATE_ACCOUNT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators, BPS_DENOMINATOR,
+};
+
+/// Tax withholding rate applied to distribution claims of holders in a jurisdiction.
+/// The withheld part of a claim is sent to the tax escrow, the associated token
+/// account of this PDA.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct WithholdingRate {
+    /// Security token mint
+    pub mint: Pubkey,
+    /// Jurisdiction code (ISO 3166-1 numeric)
+    pub jurisdiction: u16,
+    /// Withheld share of a claim in basis points
+    pub rate_bps: u16,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for WithholdingRate {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::WithholdingRateDiscriminator as u8;
+}
+
+impl AccountSerialize for WithholdingRate {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(&self.jurisdiction.to_le_bytes());
+        data.extend_from_slice(&self.rate_bps.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for WithholdingRate {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let jurisdiction = u16::from_le_bytes([data[PUBKEY_BYTES], data[PUBKEY_BYTES + 1]]);
+        let rate_bps = u16::from_le_bytes([data[PUBKEY_BYTES + 2], data[PUBKEY_BYTES + 3]]);
+        let bump = data[PUBKEY_BYTES + 4];
+
+        let withholding_rate = Self {
+            mint,
+            jurisdiction,
+            rate_bps,
+            bump,
+        };
+        withholding_rate.validate()?;
+        Ok(withholding_rate)
+    }
+}
+
+impl ProgramAccount for WithholdingRate {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl WithholdingRate {
+    /// Serialized size of the account data (discriminator + mint + jurisdiction + rate_bps + bump)
+    pub const LEN: usize = 1 + PUBKEY_BYTES + 2 + 2 + 1;
+
+    /// Create a new WithholdingRate
+    pub fn new(
+        mint: Pubkey,
+        jurisdiction: u16,
+        rate_bps: u16,
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        let withholding_rate = Self {
+            mint,
+            jurisdiction,
+            rate_bps,
+            bump,
+        };
+        withholding_rate.validate()?;
+        Ok(withholding_rate)
+    }
+
+    /// Update the withheld share
+    pub fn update(&mut self, rate_bps: u16) -> Result<(), ProgramError> {
+        self.rate_bps = rate_bps;
+        self.validate()
+    }
+
+    /// Validate the WithholdingRate account data
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        if self.rate_bps > BPS_DENOMINATOR {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    /// Part of `amount` withheld, rounded down in favor of the holder
+    pub fn withheld_amount(&self, amount: u64) -> u64 {
+        // rate_bps <= BPS_DENOMINATOR keeps the result within amount
+        (amount as u128 * self.rate_bps as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<WithholdingRate, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn jurisdiction_seed(&self) -> [u8; 2] {
+        self.jurisdiction.to_le_bytes()
+    }
+
+    pub fn seeds<'a>(
+        &'a self,
+        jurisdiction_seed: &'a [u8; 2],
+        bump_seed: &'a [u8; 1],
+    ) -> [Seed<'a>; 4] {
+        [
+            Seed::from(WITHHOLDING_RATE_ACCOUNT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(jurisdiction_seed.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                WITHHOLDING_RATE_ACCOUNT,
+                &self.mint,
+                &self.jurisdiction_seed(),
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_withholding_rate_serialization_round_trip() {
+        let withholding_rate = WithholdingRate::new([1u8; 32], 840, 3_000, 252).unwrap();
+
+        let bytes = withholding_rate.to_bytes();
+        assert_eq!(bytes.len(), WithholdingRate::LEN);
+        assert_eq!(bytes[0], WithholdingRate::DISCRIMINATOR);
+
+        let deserialized = WithholdingRate::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, withholding_rate.mint);
+        assert_eq!(deserialized.jurisdiction, withholding_rate.jurisdiction);
+        assert_eq!(deserialized.rate_bps, withholding_rate.rate_bps);
+        assert_eq!(deserialized.bump, withholding_rate.bump);
+    }
+
+    #[test]
+    fn test_withholding_rate_rejects_rate_above_100_percent() {
+        assert!(WithholdingRate::new([1u8; 32], 840, BPS_DENOMINATOR + 1, 252).is_err());
+
+        let mut withholding_rate = WithholdingRate::new([1u8; 32], 840, 0, 252).unwrap();
+        assert!(withholding_rate.update(BPS_DENOMINATOR + 1).is_err());
+        assert!(withholding_rate.update(BPS_DENOMINATOR).is_ok());
+    }
+
+    #[rstest]
+    #[case(0, 1_000, 0)]
+    #[case(3_000, 1_000, 300)]
+    #[case(3_000, 999, 299)]
+    #[case(BPS_DENOMINATOR, 1_000, 1_000)]
+    #[case(BPS_DENOMINATOR, u64::MAX, u64::MAX)]
+    fn test_withheld_amount(#[case] rate_bps: u16, #[case] amount: u64, #[case] expected: u64) {
+        let withholding_rate = WithholdingRate::new([1u8; 32], 840, rate_bps, 252).unwrap();
+        assert_eq!(withholding_rate.withheld_amount(amount), expected);
+    }
+}
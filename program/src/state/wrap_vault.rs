@@ -0,0 +1,163 @@
+//! Wrap vault account state
+use pinocchio::account_info::AccountInfo;
+use pinocchio::instruction::Seed;
+use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::{create_program_address, Pubkey, PUBKEY_BYTES};
+use shank::ShankAccount;
+
+use crate::constants::seeds::WRAP_VAULT;
+use crate::state::{
+    AccountDeserialize, AccountSerialize, Discriminator, ProgramAccount,
+    SecurityTokenDiscriminators,
+};
+
+/// Approval to wrap an underlying mint into a security token mint.
+/// The PDA owns the vault token account holding the locked underlying tokens.
+/// Unwrapping releases at most the `wrapped_supply` of the vault, so holders cannot drain
+/// the underlying tokens of another vault of the mint.
+#[repr(C)]
+#[derive(ShankAccount)]
+pub struct WrapVault {
+    /// Security token mint minted on wrap
+    pub mint: Pubkey,
+    /// Legacy SPL or Token 2022 mint locked in the vault
+    pub underlying_mint: Pubkey,
+    /// Security tokens minted by wrapping and not unwrapped yet
+    pub wrapped_supply: u64,
+    /// Bump seed used for PDA derivation
+    pub bump: u8,
+}
+
+impl Discriminator for WrapVault {
+    const DISCRIMINATOR: u8 = SecurityTokenDiscriminators::WrapVaultDiscriminator as u8;
+}
+
+impl AccountSerialize for WrapVault {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.underlying_mint.as_ref());
+        data.extend_from_slice(&self.wrapped_supply.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AccountDeserialize for WrapVault {
+    fn try_from_bytes_inner(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN - 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint: [u8; PUBKEY_BYTES] = data[..PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let underlying_mint: [u8; PUBKEY_BYTES] = data[PUBKEY_BYTES..2 * PUBKEY_BYTES]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let wrapped_supply = u64::from_le_bytes(
+            data[2 * PUBKEY_BYTES..2 * PUBKEY_BYTES + 8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        let bump = data[2 * PUBKEY_BYTES + 8];
+
+        Ok(Self {
+            mint,
+            underlying_mint,
+            wrapped_supply,
+            bump,
+        })
+    }
+}
+
+impl ProgramAccount for WrapVault {
+    fn space(&self) -> u64 {
+        Self::LEN as u64
+    }
+}
+
+impl WrapVault {
+    /// Serialized size of the account data (discriminator + mint + underlying mint +
+    /// wrapped supply + bump)
+    pub const LEN: usize = 1 + (2 * PUBKEY_BYTES) + 8 + 1;
+
+    /// Create a new WrapVault
+    pub fn new(mint: Pubkey, underlying_mint: Pubkey, bump: u8) -> Self {
+        Self {
+            mint,
+            underlying_mint,
+            wrapped_supply: 0,
+            bump,
+        }
+    }
+
+    /// Parse from account info
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<WrapVault, ProgramError> {
+        if account_info.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account_info.is_owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data_ref = account_info.try_borrow_data()?;
+        Self::try_from_bytes(&data_ref)
+    }
+
+    pub fn bump_seed(&self) -> [u8; 1] {
+        [self.bump]
+    }
+
+    pub fn seeds<'a>(&'a self, bump_seed: &'a [u8; 1]) -> [Seed<'a>; 4] {
+        [
+            Seed::from(WRAP_VAULT),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(self.underlying_mint.as_ref()),
+            Seed::from(bump_seed.as_ref()),
+        ]
+    }
+
+    /// Optimized PDA derivation with known bump seed
+    pub fn derive_pda(&self) -> Result<Pubkey, ProgramError> {
+        create_program_address(
+            &[
+                WRAP_VAULT,
+                &self.mint,
+                &self.underlying_mint,
+                &self.bump_seed(),
+            ],
+            &crate::id(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_vault_serialization_round_trip() {
+        let mut wrap_vault = WrapVault::new([1u8; 32], [2u8; 32], 254);
+        wrap_vault.wrapped_supply = 1_000;
+
+        let bytes = wrap_vault.to_bytes();
+        assert_eq!(bytes.len(), WrapVault::LEN);
+        assert_eq!(bytes[0], WrapVault::DISCRIMINATOR);
+
+        let deserialized = WrapVault::try_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.mint, wrap_vault.mint);
+        assert_eq!(deserialized.underlying_mint, wrap_vault.underlying_mint);
+        assert_eq!(deserialized.wrapped_supply, wrap_vault.wrapped_supply);
+        assert_eq!(deserialized.bump, wrap_vault.bump);
+    }
+
+    #[test]
+    fn test_wrap_vault_invalid_length() {
+        let bytes = WrapVault::new([1u8; 32], [2u8; 32], 254).to_bytes();
+        assert!(WrapVault::try_from_bytes(&bytes[..WrapVault::LEN - 1]).is_err());
+    }
+}
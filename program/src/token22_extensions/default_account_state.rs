@@ -0,0 +1,102 @@
+//! DefaultAccountState extension
+
+use crate::token22_extensions::{BaseState, Extension, ExtensionType};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    ProgramResult,
+};
+
+/// Token-2022 `DefaultAccountStateExtension` instruction discriminator
+const DEFAULT_ACCOUNT_STATE_EXTENSION: u8 = 28;
+
+/// DefaultAccountState extension data
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DefaultAccountState {
+    /// State new token accounts of the mint start in (1 = Initialized, 2 = Frozen)
+    pub state: u8,
+}
+
+impl Extension for DefaultAccountState {
+    const TYPE: ExtensionType = ExtensionType::DefaultAccountState;
+    const LEN: usize = 1;
+    const BASE_STATE: BaseState = BaseState::Mint;
+}
+
+pub struct InitializeDefaultAccountState<'a> {
+    /// The mint to initialize
+    pub mint: &'a AccountInfo,
+    /// State new token accounts of the mint start in
+    pub state: u8,
+}
+
+impl InitializeDefaultAccountState<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [AccountMeta::writable(self.mint.key())];
+
+        // Instruction data Layout:
+        // -  [0]: token instruction discriminator (DefaultAccountStateExtension = 28)
+        // -  [1]: default account state extension sub-instruction (Initialize = 0)
+        // -  [2]: account state
+        let instruction_data = [DEFAULT_ACCOUNT_STATE_EXTENSION, 0, self.state];
+
+        let instruction = Instruction {
+            program_id: &pinocchio_token_2022::ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(&instruction, &[self.mint], signers)?;
+
+        Ok(())
+    }
+}
+
+/// Wrapper for UpdateDefaultAccountState instruction
+pub struct UpdateDefaultAccountState<'a> {
+    /// The mint to update
+    pub mint: &'a AccountInfo,
+    /// The mint's freeze authority
+    pub freeze_authority: &'a AccountInfo,
+    /// State new token accounts of the mint start in
+    pub state: u8,
+}
+
+impl UpdateDefaultAccountState<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.mint.key()),
+            AccountMeta::readonly_signer(self.freeze_authority.key()),
+        ];
+
+        // Instruction data Layout:
+        // -  [0]: token instruction discriminator (DefaultAccountStateExtension = 28)
+        // -  [1]: default account state extension sub-instruction (Update = 1)
+        // -  [2]: account state
+        let instruction_data = [DEFAULT_ACCOUNT_STATE_EXTENSION, 1, self.state];
+
+        let instruction = Instruction {
+            program_id: &pinocchio_token_2022::ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(&instruction, &[self.mint, self.freeze_authority], signers)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+//! MemoTransfer extension
+
+use crate::constants::MEMO_PROGRAM_ID;
+use crate::token22_extensions::{BaseState, Extension, ExtensionType};
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::slice_invoke,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    ProgramResult,
+};
+
+/// Token-2022 `MemoTransferExtension` instruction discriminator
+const MEMO_TRANSFER_EXTENSION: u8 = 30;
+
+/// MemoTransfer extension data
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MemoTransfer {
+    /// Whether incoming transfers must be preceded by a memo
+    pub require_incoming_transfer_memos: u8,
+}
+
+impl Extension for MemoTransfer {
+    const TYPE: ExtensionType = ExtensionType::MemoTransfer;
+    const LEN: usize = 1;
+    const BASE_STATE: BaseState = BaseState::TokenAccount;
+}
+
+/// Wrapper for EnableRequiredTransferMemos instruction
+pub struct EnableRequiredTransferMemos<'a> {
+    /// The token account to require memos on
+    pub account: &'a AccountInfo,
+    /// The owner of the token account
+    pub owner: &'a AccountInfo,
+}
+
+impl EnableRequiredTransferMemos<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        toggle_required_transfer_memos(self.account, self.owner, 0, signers)
+    }
+}
+
+/// Wrapper for DisableRequiredTransferMemos instruction
+pub struct DisableRequiredTransferMemos<'a> {
+    /// The token account to stop requiring memos on
+    pub account: &'a AccountInfo,
+    /// The owner of the token account
+    pub owner: &'a AccountInfo,
+}
+
+impl DisableRequiredTransferMemos<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        toggle_required_transfer_memos(self.account, self.owner, 1, signers)
+    }
+}
+
+#[inline(always)]
+fn toggle_required_transfer_memos(
+    account: &AccountInfo,
+    owner: &AccountInfo,
+    extension_instruction: u8,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(account.key()),
+        AccountMeta::readonly_signer(owner.key()),
+    ];
+
+    // Instruction data Layout:
+    // -  [0]: token instruction discriminator (MemoTransferExtension = 30)
+    // -  [1]: memo transfer extension sub-instruction (Enable = 0, Disable = 1)
+    let instruction_data = [MEMO_TRANSFER_EXTENSION, extension_instruction];
+
+    let instruction = Instruction {
+        program_id: &pinocchio_token_2022::ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(&instruction, &[account, owner], signers)
+}
+
+/// Wrapper for the SPL Memo instruction.
+/// Invoked right before a transfer it satisfies the MemoTransfer requirement of the destination.
+pub struct Memo<'a> {
+    /// UTF-8 memo
+    pub memo: &'a str,
+}
+
+impl Memo<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        let instruction = Instruction {
+            program_id: &MEMO_PROGRAM_ID,
+            accounts: &[],
+            data: self.memo.as_bytes(),
+        };
+
+        slice_invoke(&instruction, &[])
+    }
+}
@@ -74,6 +74,29 @@ impl TokenMetadata<'_> {
         Self::from_bytes(metadata_bytes)
     }
 
+    /// Return a `TokenMetadata` stored in an account of an external metadata program.
+    ///
+    /// External metadata programs implementing the token metadata interface store it as a
+    /// type-length-value entry with an 8-byte discriminator and a u32 length, without the
+    /// mint base state. Also returns the full length of the entry including its header.
+    pub fn from_external_account_info<'a>(
+        account_info: &AccountInfo,
+        metadata_program: &Pubkey,
+    ) -> Result<(TokenMetadata<'a>, usize), ProgramError> {
+        if !account_info.is_owned_by(metadata_program) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let account_data = account_info.try_borrow_data()?;
+        let metadata_bytes = get_external_metadata_bytes(account_data.as_ref())
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        Ok((
+            Self::from_bytes(metadata_bytes)?,
+            EXTERNAL_TLV_HEADER_LEN + metadata_bytes.len(),
+        ))
+    }
+
     pub(crate) fn from_bytes<'a>(data: &[u8]) -> Result<TokenMetadata<'a>, ProgramError> {
         if data.len() < Self::SIZE_METADATA_LEN {
             return Err(ProgramError::InvalidAccountData);
@@ -147,6 +170,38 @@ impl TokenMetadata<'_> {
     }
 }
 
+/// Discriminator of the TokenMetadata entry in external metadata accounts
+/// Based on spl_token_metadata_interface:token_metadata hash
+pub const TOKEN_METADATA_DISCRIMINATOR: [u8; 8] = [112, 132, 90, 90, 11, 88, 157, 87];
+
+/// Header of a type-length-value entry in external metadata accounts:
+/// 8-byte discriminator + u32 length
+pub const EXTERNAL_TLV_HEADER_LEN: usize = 8 + 4;
+
+/// Find the TokenMetadata entry in the data of an external metadata account
+pub(crate) fn get_external_metadata_bytes(data: &[u8]) -> Option<&[u8]> {
+    let mut start = 0;
+    while start + EXTERNAL_TLV_HEADER_LEN <= data.len() {
+        let discriminator = &data[start..start + 8];
+        let len = u32::from_le_bytes(
+            data[start + 8..start + EXTERNAL_TLV_HEADER_LEN]
+                .try_into()
+                .ok()?,
+        ) as usize;
+        let value_start = start + EXTERNAL_TLV_HEADER_LEN;
+        let value = data.get(value_start..value_start.checked_add(len)?)?;
+        if discriminator == TOKEN_METADATA_DISCRIMINATOR {
+            return Some(value);
+        }
+        // An all-zero discriminator marks the uninitialized rest of the account
+        if discriminator == [0u8; 8] {
+            return None;
+        }
+        start = value_start + len;
+    }
+    None
+}
+
 impl Extension for TokenMetadata<'_> {
     const TYPE: ExtensionType = ExtensionType::TokenMetadata;
     const LEN: usize = Self::SIZE_METADATA_LEN;
@@ -180,6 +235,8 @@ impl Field<'_> {
 
 /// Wrapper for InitializeTokenMetadata instruction
 pub struct InitializeTokenMetadata<'a> {
+    /// The program storing the metadata, SPL Token 2022 for metadata in the mint
+    pub metadata_program: &'a Pubkey,
     /// The metadata account to initialize
     pub metadata: &'a AccountInfo,
     /// The authority that can update the metadata
@@ -241,7 +298,7 @@ impl<'a> InitializeTokenMetadata<'a> {
         ];
 
         let instruction = Instruction {
-            program_id: &pinocchio_token_2022::ID,
+            program_id: self.metadata_program,
             accounts: &account_metas,
             data: &ix_data[..ix_len],
         };
@@ -261,6 +318,8 @@ impl<'a> InitializeTokenMetadata<'a> {
 
 /// Wrapper for RemoveKey instruction
 pub struct RemoveKey<'a> {
+    /// The program storing the metadata, SPL Token 2022 for metadata in the mint
+    pub metadata_program: &'a Pubkey,
     /// The metadata account to update
     pub metadata: &'a AccountInfo,
     /// The account authorized to update the metadata
@@ -308,7 +367,7 @@ impl<'a> RemoveKey<'a> {
         ];
 
         let instruction = Instruction {
-            program_id: &pinocchio_token_2022::ID,
+            program_id: self.metadata_program,
             accounts: &account_metas,
             data: &ix_data,
         };
@@ -323,6 +382,8 @@ impl<'a> RemoveKey<'a> {
 
 /// Wrapper for UpdateField instruction
 pub struct UpdateField<'a> {
+    /// The program storing the metadata, SPL Token 2022 for metadata in the mint
+    pub metadata_program: &'a Pubkey,
     /// The metadata account to update
     pub metadata: &'a AccountInfo,
     /// The authority that can sign to update the metadata
@@ -389,6 +450,57 @@ impl UpdateField<'_> {
             AccountMeta::readonly_signer(self.update_authority.key()),
         ];
 
+        let instruction = Instruction {
+            program_id: self.metadata_program,
+            accounts: &account_metas,
+            data: &ix_data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.metadata, self.update_authority],
+            signers,
+        )
+    }
+}
+
+/// Wrapper for UpdateAuthority instruction
+pub struct UpdateAuthority<'a> {
+    /// The metadata account to update
+    pub metadata: &'a AccountInfo,
+    /// The current update authority (must sign)
+    pub update_authority: &'a AccountInfo,
+    /// The new update authority, `None` makes the metadata immutable
+    pub new_authority: Option<&'a Pubkey>,
+}
+
+impl UpdateAuthority<'_> {
+    /// Invoke the UpdateAuthority instruction
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invoke the UpdateAuthority instruction with signers
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction data layout:
+        // -  [0..8] [u8]: instruction discriminator
+        // -  [8..40] Pubkey: new authority, all zeros for None (OptionalNonZeroPubkey)
+        let mut ix_data = [0u8; 8 + PUBKEY_BYTES];
+
+        // Set 8-byte discriminator for UpdateAuthority
+        // Based on spl_token_metadata_interface:update_the_authority hash
+        let discriminator: [u8; 8] = [215, 228, 166, 228, 84, 100, 86, 123];
+        ix_data[..8].copy_from_slice(&discriminator);
+        if let Some(new_authority) = self.new_authority {
+            ix_data[8..].copy_from_slice(new_authority);
+        }
+
+        let account_metas: [AccountMeta; 2] = [
+            AccountMeta::writable(self.metadata.key()),
+            AccountMeta::readonly_signer(self.update_authority.key()),
+        ];
+
         let instruction = Instruction {
             program_id: &pinocchio_token_2022::ID,
             accounts: &account_metas,
@@ -1,10 +1,13 @@
 use pinocchio_token_2022::state::{Mint, TokenAccount};
 
+pub mod default_account_state;
+pub mod memo_transfer;
 pub mod metadata;
 pub mod metadata_pointer;
 pub mod pausable;
 pub mod permanent_delegate;
 pub mod scaled_ui_amount;
+pub mod transfer_fee;
 pub mod transfer_hook;
 
 use core::mem::MaybeUninit;
@@ -302,14 +305,14 @@ mod tests {
     #[test]
     fn test_metadata_pointer() {
         let metadata_pointer =
-            get_extension_from_bytes::<MetadataPointer>(&TEST_MINT_WITH_EXTENSIONS_SLICE);
+            get_extension_from_bytes::<MetadataPointer>(TEST_MINT_WITH_EXTENSIONS_SLICE);
         assert!(metadata_pointer.is_some());
     }
 
     #[test]
     fn test_permanent_delegate() {
         let permanent_delegate =
-            get_extension_from_bytes::<PermanentDelegate>(&TEST_MINT_WITH_EXTENSIONS_SLICE);
+            get_extension_from_bytes::<PermanentDelegate>(TEST_MINT_WITH_EXTENSIONS_SLICE);
         assert!(permanent_delegate.is_some());
     }
 
@@ -318,7 +321,7 @@ mod tests {
         use crate::token22_extensions::get_extension_data_bytes_for_variable_pack;
 
         let token_metadata = get_extension_data_bytes_for_variable_pack::<TokenMetadata>(
-            &TEST_MINT_WITH_EXTENSIONS_SLICE,
+            TEST_MINT_WITH_EXTENSIONS_SLICE,
         )
         .unwrap();
 
@@ -330,4 +333,34 @@ mod tests {
 
         assert_eq!(token_metadata.symbol, "PYUSD");
     }
+
+    #[test]
+    fn test_external_token_metadata() {
+        use crate::token22_extensions::get_extension_data_bytes_for_variable_pack;
+        use crate::token22_extensions::metadata::{
+            get_external_metadata_bytes, TOKEN_METADATA_DISCRIMINATOR,
+        };
+
+        let metadata_bytes = get_extension_data_bytes_for_variable_pack::<TokenMetadata>(
+            TEST_MINT_WITH_EXTENSIONS_SLICE,
+        )
+        .unwrap();
+
+        // Unrelated entry first, then the metadata and uninitialized space
+        let mut data = vec![9u8; 8];
+        data.extend_from_slice(&3u32.to_le_bytes());
+        data.extend_from_slice(&[1, 2, 3]);
+        data.extend_from_slice(&TOKEN_METADATA_DISCRIMINATOR);
+        data.extend_from_slice(&(metadata_bytes.len() as u32).to_le_bytes());
+        data.extend_from_slice(metadata_bytes);
+        data.extend_from_slice(&[0u8; 16]);
+
+        let external_bytes = get_external_metadata_bytes(&data).unwrap();
+        assert_eq!(external_bytes, metadata_bytes);
+        let token_metadata = TokenMetadata::from_bytes(external_bytes).unwrap();
+        assert_eq!(token_metadata.symbol, "PYUSD");
+
+        assert!(get_external_metadata_bytes(&data[..data.len() - 17]).is_none());
+        assert!(get_external_metadata_bytes(&[0u8; 32]).is_none());
+    }
 }
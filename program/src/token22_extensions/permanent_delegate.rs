@@ -61,3 +61,51 @@ impl InitializePermanentDelegate<'_> {
         Ok(())
     }
 }
+
+/// Wrapper for SetAuthority of the permanent delegate, missing from pinocchio_token_2022
+pub struct SetPermanentDelegate<'a> {
+    /// The mint to change the permanent delegate of
+    pub mint: &'a AccountInfo,
+    /// The current permanent delegate
+    pub delegate: &'a AccountInfo,
+    /// The new permanent delegate, `None` renounces it
+    pub new_delegate: Option<&'a Pubkey>,
+}
+
+impl SetPermanentDelegate<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 2] = [
+            AccountMeta::writable(self.mint.key()),
+            AccountMeta::readonly_signer(self.delegate.key()),
+        ];
+
+        // Instruction data Layout:
+        // -  [0]: instruction discriminator (SetAuthority = 6)
+        // -  [1]: authority type (PermanentDelegate = 8)
+        // -  [2]: new authority presence flag
+        // -  [3..35]: new authority (32 bytes, Pubkey)
+        let mut instruction_data = [0u8; 35];
+        instruction_data[0] = 6;
+        instruction_data[1] = 8;
+        if let Some(new_delegate) = self.new_delegate {
+            instruction_data[2] = 1;
+            instruction_data[3..35].copy_from_slice(new_delegate);
+        }
+
+        let instruction = Instruction {
+            program_id: &pinocchio_token_2022::ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(&instruction, &[self.mint, self.delegate], signers)?;
+
+        Ok(())
+    }
+}
@@ -99,3 +99,57 @@ impl InitializeScaledUiAmount<'_> {
         Ok(())
     }
 }
+
+/// Wrapper for UpdateMultiplier instruction
+pub struct UpdateMultiplier<'a> {
+    /// The mint to update
+    pub mint: &'a AccountInfo,
+    /// The mint's scaled UI amount authority
+    pub authority: &'a AccountInfo,
+    /// The new multiplier
+    pub multiplier: f64,
+    /// Unix timestamp at which the new multiplier takes effect
+    pub effective_timestamp: UnixTimestamp,
+}
+
+impl UpdateMultiplier<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.mint.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        // Instruction Layout
+        // - [0] u8: instruction discriminator
+        // - [1] u8: extension instruction discriminator
+        // - [2..10] f64: multiplier (8 bytes)
+        // - [10..18] i64: effective timestamp (8 bytes)
+
+        let mut instruction_data = [UNINIT_BYTE; 18];
+
+        // Set discriminator as u8 at offset [0] & Set extension discriminator as u8 at offset [1]
+        write_bytes(&mut instruction_data[0..2], &[43, 1]);
+        // Set multiplier as f64 at offset [2..10]
+        write_bytes(&mut instruction_data[2..10], &self.multiplier.to_le_bytes());
+        // Set effective timestamp as i64 at offset [10..18]
+        write_bytes(
+            &mut instruction_data[10..18],
+            &self.effective_timestamp.to_le_bytes(),
+        );
+        let instruction = Instruction {
+            program_id: &pinocchio_token_2022::ID,
+            accounts: &account_metas,
+            data: unsafe { core::slice::from_raw_parts(instruction_data.as_ptr() as _, 18) },
+        };
+
+        invoke_signed(&instruction, &[self.mint, self.authority], signers)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,299 @@
+//! TransferFeeConfig extension
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::{invoke_signed, slice_invoke_signed},
+    instruction::{AccountMeta, Instruction, Signer},
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::token22_extensions::{write_bytes, BaseState, Extension, ExtensionType, UNINIT_BYTE};
+
+/// Token-2022 `TransferFeeExtension` instruction discriminator
+const TRANSFER_FEE_EXTENSION: u8 = 26;
+
+/// Transfer fee of an epoch
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransferFee {
+    /// First epoch where the transfer fee takes effect
+    pub epoch: [u8; 8],
+    /// Maximum fee assessed on transfers, expressed as an amount of tokens
+    pub maximum_fee: [u8; 8],
+    /// Amount of transfer collected as fees, expressed as basis points of the transfer amount
+    pub transfer_fee_basis_points: [u8; 2],
+}
+
+impl TransferFee {
+    pub fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    pub fn maximum_fee(&self) -> u64 {
+        u64::from_le_bytes(self.maximum_fee)
+    }
+
+    pub fn transfer_fee_basis_points(&self) -> u16 {
+        u16::from_le_bytes(self.transfer_fee_basis_points)
+    }
+}
+
+/// TransferFeeConfig extension data
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransferFeeConfig {
+    /// Authority that can set the fee
+    pub transfer_fee_config_authority: Pubkey,
+    /// Authority that can withdraw withheld fees
+    pub withdraw_withheld_authority: Pubkey,
+    /// Withheld transfer fee tokens harvested to the mint
+    pub withheld_amount: [u8; 8],
+    /// Older transfer fee, used if the current epoch < newer_transfer_fee.epoch
+    pub older_transfer_fee: TransferFee,
+    /// Newer transfer fee, used if the current epoch >= newer_transfer_fee.epoch
+    pub newer_transfer_fee: TransferFee,
+}
+
+impl Extension for TransferFeeConfig {
+    const TYPE: ExtensionType = ExtensionType::TransferFeeConfig;
+    const LEN: usize = 108;
+    const BASE_STATE: BaseState = BaseState::Mint;
+}
+
+impl TransferFeeConfig {
+    /// Return a `TransferFeeConfig` from the given account info.
+    ///
+    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
+    /// the account data.
+    #[inline(always)]
+    pub fn from_account_info_unchecked(
+        account_info: &AccountInfo,
+    ) -> Result<&TransferFeeConfig, pinocchio::program_error::ProgramError> {
+        super::get_extension_from_bytes(unsafe { account_info.borrow_data_unchecked() })
+            .ok_or(pinocchio::program_error::ProgramError::InvalidAccountData)
+    }
+
+    /// Withheld transfer fee tokens harvested to the mint
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+}
+
+pub struct InitializeTransferFeeConfig<'a> {
+    /// The mint to initialize
+    pub mint: &'a AccountInfo,
+    /// The authority that can set the fee
+    pub transfer_fee_config_authority: Pubkey,
+    /// The authority that can withdraw withheld fees
+    pub withdraw_withheld_authority: Pubkey,
+    /// Amount of transfer collected as fees, expressed as basis points of the transfer amount
+    pub transfer_fee_basis_points: u16,
+    /// Maximum fee assessed on transfers
+    pub maximum_fee: u64,
+}
+
+impl InitializeTransferFeeConfig<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [AccountMeta::writable(self.mint.key())];
+
+        // Instruction Layout
+        // - [0] u8: instruction discriminator
+        // - [1] u8: extension instruction discriminator
+        // - [2] u8: transfer fee config authority option tag (always Some)
+        // - [3..35] Pubkey: transfer fee config authority
+        // - [35] u8: withdraw withheld authority option tag (always Some)
+        // - [36..68] Pubkey: withdraw withheld authority
+        // - [68..70] u16: transfer fee basis points
+        // - [70..78] u64: maximum fee
+
+        let mut instruction_data = [UNINIT_BYTE; 78];
+
+        write_bytes(&mut instruction_data[0..2], &[TRANSFER_FEE_EXTENSION, 0]);
+        write_bytes(&mut instruction_data[2..3], &[1]);
+        write_bytes(
+            &mut instruction_data[3..35],
+            &self.transfer_fee_config_authority,
+        );
+        write_bytes(&mut instruction_data[35..36], &[1]);
+        write_bytes(
+            &mut instruction_data[36..68],
+            &self.withdraw_withheld_authority,
+        );
+        write_bytes(
+            &mut instruction_data[68..70],
+            &self.transfer_fee_basis_points.to_le_bytes(),
+        );
+        write_bytes(
+            &mut instruction_data[70..78],
+            &self.maximum_fee.to_le_bytes(),
+        );
+
+        let instruction = Instruction {
+            program_id: &pinocchio_token_2022::ID,
+            accounts: &account_metas,
+            data: unsafe { core::slice::from_raw_parts(instruction_data.as_ptr() as _, 78) },
+        };
+
+        invoke_signed(&instruction, &[self.mint], signers)?;
+
+        Ok(())
+    }
+}
+
+/// Wrapper for SetTransferFee instruction
+pub struct SetTransferFee<'a> {
+    /// The mint to update
+    pub mint: &'a AccountInfo,
+    /// The mint's transfer fee config authority
+    pub authority: &'a AccountInfo,
+    /// Amount of transfer collected as fees, expressed as basis points of the transfer amount
+    pub transfer_fee_basis_points: u16,
+    /// Maximum fee assessed on transfers
+    pub maximum_fee: u64,
+}
+
+impl SetTransferFee<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.mint.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        // Instruction Layout
+        // - [0] u8: instruction discriminator
+        // - [1] u8: extension instruction discriminator
+        // - [2..4] u16: transfer fee basis points
+        // - [4..12] u64: maximum fee
+
+        let mut instruction_data = [UNINIT_BYTE; 12];
+
+        write_bytes(&mut instruction_data[0..2], &[TRANSFER_FEE_EXTENSION, 5]);
+        write_bytes(
+            &mut instruction_data[2..4],
+            &self.transfer_fee_basis_points.to_le_bytes(),
+        );
+        write_bytes(
+            &mut instruction_data[4..12],
+            &self.maximum_fee.to_le_bytes(),
+        );
+
+        let instruction = Instruction {
+            program_id: &pinocchio_token_2022::ID,
+            accounts: &account_metas,
+            data: unsafe { core::slice::from_raw_parts(instruction_data.as_ptr() as _, 12) },
+        };
+
+        invoke_signed(&instruction, &[self.mint, self.authority], signers)?;
+
+        Ok(())
+    }
+}
+
+/// Wrapper for WithdrawWithheldTokensFromAccounts instruction
+pub struct WithdrawWithheldTokensFromAccounts<'a> {
+    /// The mint of the token accounts
+    pub mint: &'a AccountInfo,
+    /// The token account receiving the withheld fees
+    pub destination: &'a AccountInfo,
+    /// The mint's withdraw withheld authority
+    pub authority: &'a AccountInfo,
+    /// Token accounts to withdraw the withheld fees from
+    pub sources: &'a [AccountInfo],
+    /// The decimals of the mint
+    pub decimals: u8,
+}
+
+impl WithdrawWithheldTokensFromAccounts<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut account_metas = Vec::with_capacity(3 + self.sources.len());
+        account_metas.push(AccountMeta::readonly(self.mint.key()));
+        account_metas.push(AccountMeta::writable(self.destination.key()));
+        account_metas.push(AccountMeta::readonly_signer(self.authority.key()));
+        account_metas.extend(
+            self.sources
+                .iter()
+                .map(|source| AccountMeta::writable(source.key())),
+        );
+
+        let mut account_infos = Vec::with_capacity(3 + self.sources.len());
+        account_infos.push(self.mint);
+        account_infos.push(self.destination);
+        account_infos.push(self.authority);
+        account_infos.extend(self.sources.iter());
+
+        // Instruction Layout
+        // - [0] u8: instruction discriminator
+        // - [1] u8: extension instruction discriminator
+        // - [2] u8: decimals
+        // - [3] u8: number of token accounts
+        let instruction_data = [
+            TRANSFER_FEE_EXTENSION,
+            3,
+            self.decimals,
+            self.sources.len() as u8,
+        ];
+
+        let instruction = Instruction {
+            program_id: &pinocchio_token_2022::ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        slice_invoke_signed(&instruction, &account_infos, signers)
+    }
+}
+
+/// Wrapper for HarvestWithheldTokensToMint instruction
+pub struct HarvestWithheldTokensToMint<'a> {
+    /// The mint receiving the withheld fees
+    pub mint: &'a AccountInfo,
+    /// Token accounts to harvest the withheld fees from
+    pub sources: &'a [AccountInfo],
+}
+
+impl HarvestWithheldTokensToMint<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        let mut account_metas = Vec::with_capacity(1 + self.sources.len());
+        account_metas.push(AccountMeta::writable(self.mint.key()));
+        account_metas.extend(
+            self.sources
+                .iter()
+                .map(|source| AccountMeta::writable(source.key())),
+        );
+
+        let mut account_infos = Vec::with_capacity(1 + self.sources.len());
+        account_infos.push(self.mint);
+        account_infos.extend(self.sources.iter());
+
+        // Instruction Layout
+        // - [0] u8: instruction discriminator
+        // - [1] u8: extension instruction discriminator
+        let instruction_data = [TRANSFER_FEE_EXTENSION, 4];
+
+        let instruction = Instruction {
+            program_id: &pinocchio_token_2022::ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        slice_invoke_signed(&instruction, &account_infos, &[])
+    }
+}
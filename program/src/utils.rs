@@ -1,9 +1,11 @@
 //! Utility functions for PDA derivation and common operations
 
 use crate::token22_extensions::{
+    default_account_state::DefaultAccountState, metadata::EXTERNAL_TLV_HEADER_LEN,
     metadata_pointer::MetadataPointer, pausable::Pausable, permanent_delegate::PermanentDelegate,
-    scaled_ui_amount::ScaledUiAmountConfig, transfer_hook::TransferHook, Extension, ExtensionType,
-    EXTENSIONS_PADDING, EXTENSION_LENGTH_LEN, EXTENSION_START_OFFSET, EXTENSION_TYPE_LEN,
+    scaled_ui_amount::ScaledUiAmountConfig, transfer_fee::TransferFeeConfig,
+    transfer_hook::TransferHook, Extension, ExtensionType, EXTENSIONS_PADDING,
+    EXTENSION_LENGTH_LEN, EXTENSION_START_OFFSET, EXTENSION_TYPE_LEN,
 };
 use pinocchio::{
     program_error::ProgramError,
@@ -13,7 +15,7 @@ use pinocchio_token_2022::state::Mint;
 use solana_keccak_hasher::hashv;
 
 use crate::{
-    constants::{seeds, ACTION_ID_LEN, TRANSFER_HOOK_PROGRAM_ID},
+    constants::{seeds, ACTION_ID_LEN, BPF_LOADER_UPGRADEABLE_ID, TRANSFER_HOOK_PROGRAM_ID},
     instructions::TokenMetadataArgs,
     merkle_tree_utils::{MerkleTreeRoot, ProofData},
 };
@@ -60,6 +62,21 @@ pub fn find_pause_authority_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey,
     find_program_address(&[seeds::PAUSE_AUTHORITY, mint.as_ref()], program_id)
 }
 
+/// Derive scaled UI amount authority PDA
+/// Seeds: ["mint.scaled_ui_amount_authority", mint_pubkey]
+pub fn find_scaled_ui_amount_authority_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::SCALED_UI_AMOUNT_AUTHORITY, mint.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive transfer fee authority PDA
+/// Seeds: ["mint.transfer_fee_authority", mint_pubkey]
+pub fn find_transfer_fee_authority_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[seeds::TRANSFER_FEE_AUTHORITY, mint.as_ref()], program_id)
+}
+
 /// Derive freeze authority PDA
 /// Seeds: ["mint.freeze_authority", mint_pubkey]
 pub fn find_freeze_authority_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
@@ -120,6 +137,25 @@ pub fn find_common_action_receipt_pda(
     )
 }
 
+/// Derive receipt PDA for a token account migrated to a new mint
+/// Seeds: ["receipt", mint_to, token_account, action_id]
+pub fn find_migration_receipt_pda(
+    mint_to: &Pubkey,
+    token_account: &Pubkey,
+    action_id: u64,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::RECEIPT_ACCOUNT,
+            mint_to.as_ref(),
+            token_account.as_ref(),
+            action_id.to_le_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
 /// Derive receipt PDA for Claim operation
 /// Seeds: ["receipt", mint, token_account, action_id, proof]
 pub fn find_claim_receipt_pda(
@@ -129,8 +165,42 @@ pub fn find_claim_receipt_pda(
     proof: &ProofData,
     program_id: &Pubkey,
 ) -> (Pubkey, u8) {
-    let proof_hash = hash_from_proof_data(proof);
+    find_claim_receipt_pda_with_proof_hash(
+        mint,
+        token_account,
+        action_id,
+        &hash_from_proof_data(proof),
+        program_id,
+    )
+}
+
+/// Derive receipt PDA for Claim operation against a supplemental merkle root
+/// Seeds: ["receipt", mint, token_account, action_id, hash(hash(proof), merkle_root)]
+pub fn find_root_claim_receipt_pda(
+    mint: &Pubkey,
+    token_account: &Pubkey,
+    action_id: u64,
+    proof: &ProofData,
+    merkle_root: &MerkleTreeRoot,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_claim_receipt_pda_with_proof_hash(
+        mint,
+        token_account,
+        action_id,
+        &hash_from_proof_data_and_root(proof, merkle_root),
+        program_id,
+    )
+}
 
+/// Derive receipt PDA for Claim operation from a precomputed proof hash
+pub fn find_claim_receipt_pda_with_proof_hash(
+    mint: &Pubkey,
+    token_account: &Pubkey,
+    action_id: u64,
+    proof_hash: &[u8; 32],
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
     find_program_address(
         &[
             seeds::RECEIPT_ACCOUNT,
@@ -152,6 +222,11 @@ pub fn hash_from_proof_data(proof: &ProofData) -> [u8; 32] {
     hashv(&[&proof_data]).to_bytes()
 }
 
+/// Helper to compute proof hash scoped to a supplemental merkle root
+pub fn hash_from_proof_data_and_root(proof: &ProofData, merkle_root: &MerkleTreeRoot) -> [u8; 32] {
+    hashv(&[&hash_from_proof_data(proof), merkle_root]).to_bytes()
+}
+
 /// Derive proof PDA
 /// Seeds: ["proof", token_account_address, action_id]
 pub fn find_proof_pda(
@@ -188,6 +263,609 @@ pub fn find_distribution_escrow_authority_pda(
     )
 }
 
+/// Derive distribution payout PDA of a distribution and payout mint
+/// Seeds: ["distribution_payout", mint, action_id, payout_mint]
+pub fn find_distribution_payout_pda(
+    mint: &Pubkey,
+    action_id: u64,
+    payout_mint: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::DISTRIBUTION_PAYOUT_ACCOUNT,
+            mint.as_ref(),
+            &action_id.to_le_bytes(),
+            payout_mint.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive claim deadline PDA of a distribution
+/// Seeds: ["claim_deadline", mint, action_id]
+pub fn find_claim_deadline_pda(mint: &Pubkey, action_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::CLAIM_DEADLINE_ACCOUNT,
+            mint.as_ref(),
+            &action_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive distribution root PDA of an amended merkle root
+/// Seeds: ["distribution_root", mint, action_id, merkle_root]
+pub fn find_distribution_root_pda(
+    mint: &Pubkey,
+    action_id: u64,
+    merkle_root: &MerkleTreeRoot,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::DISTRIBUTION_ROOT_ACCOUNT,
+            mint.as_ref(),
+            &action_id.to_le_bytes(),
+            merkle_root.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive claim bitmap page PDA
+/// Seeds: ["claim_bitmap", mint, action_id, merkle_root, page]
+pub fn find_claim_bitmap_pda(
+    mint: &Pubkey,
+    action_id: u64,
+    merkle_root: &MerkleTreeRoot,
+    page: u32,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::CLAIM_BITMAP_ACCOUNT,
+            mint.as_ref(),
+            &action_id.to_le_bytes(),
+            merkle_root.as_ref(),
+            &page.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive permit nonce PDA
+/// Seeds: ["permit_nonce", token_account]
+pub fn find_permit_nonce_pda(token_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::PERMIT_NONCE_ACCOUNT, token_account.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive session key PDA
+/// Seeds: ["session_key", mint, session_key]
+pub fn find_session_key_pda(
+    mint: &Pubkey,
+    session_key: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::SESSION_KEY_ACCOUNT,
+            mint.as_ref(),
+            session_key.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive recovery receipt PDA
+/// Seeds: ["recovery_receipt", mint, recovery_id]
+pub fn find_recovery_receipt_pda(
+    mint: &Pubkey,
+    recovery_id: u64,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::RECOVERY_RECEIPT_ACCOUNT,
+            mint.as_ref(),
+            &recovery_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive mint migration PDA
+/// Seeds: ["mint_migration", mint_from]
+pub fn find_mint_migration_pda(mint_from: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::MINT_MIGRATION_ACCOUNT, mint_from.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive metadata schema PDA
+/// Seeds: ["metadata_schema", mint]
+pub fn find_metadata_schema_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[seeds::METADATA_SCHEMA_ACCOUNT, mint.as_ref()], program_id)
+}
+
+/// Derive document registry PDA
+/// Seeds: ["document_registry", mint]
+pub fn find_document_registry_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::DOCUMENT_REGISTRY_ACCOUNT, mint.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive permanent delegate config PDA
+/// Seeds: ["permanent_delegate_config", mint]
+pub fn find_permanent_delegate_config_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::PERMANENT_DELEGATE_CONFIG_ACCOUNT, mint.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive pause delegate PDA
+/// Seeds: ["pause_delegate", mint]
+pub fn find_pause_delegate_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[seeds::PAUSE_DELEGATE_ACCOUNT, mint.as_ref()], program_id)
+}
+
+/// Derive config admin PDA
+/// Seeds: ["config_admin", mint]
+pub fn find_config_admin_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[seeds::CONFIG_ADMIN_ACCOUNT, mint.as_ref()], program_id)
+}
+
+/// Derive pending verification config PDA
+/// Seeds: ["pending_verification_config", mint, instruction_discriminator]
+pub fn find_pending_verification_config_pda(
+    mint: &Pubkey,
+    instruction_discriminator: u8,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::PENDING_VERIFICATION_CONFIG,
+            mint.as_ref(),
+            &[instruction_discriminator],
+        ],
+        program_id,
+    )
+}
+
+/// Derive program config PDA
+/// Seeds: ["program_config"]
+pub fn find_program_config_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[seeds::PROGRAM_CONFIG], program_id)
+}
+
+/// Derive ProgramData account of an upgradeable program
+/// Seeds: [program_id] under the upgradeable BPF loader
+pub fn find_program_data_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[program_id.as_ref()], &BPF_LOADER_UPGRADEABLE_ID)
+}
+
+/// Derive allowlist entry PDA
+/// Seeds: ["allowlist_entry", mint, wallet]
+pub fn find_allowlist_entry_pda(
+    mint: &Pubkey,
+    wallet: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::ALLOWLIST_ENTRY_ACCOUNT,
+            mint.as_ref(),
+            wallet.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive metadata freeze PDA
+/// Seeds: ["metadata_freeze", mint]
+pub fn find_metadata_freeze_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[seeds::METADATA_FREEZE_ACCOUNT, mint.as_ref()], program_id)
+}
+
+/// Derive config template PDA
+/// Seeds: ["config_template", issuer, template_id]
+pub fn find_config_template_pda(
+    issuer: &Pubkey,
+    template_id: u64,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::CONFIG_TEMPLATE_ACCOUNT,
+            issuer.as_ref(),
+            &template_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive wrap vault PDA
+/// Seeds: ["wrap_vault", mint, underlying_mint]
+pub fn find_wrap_vault_pda(
+    mint: &Pubkey,
+    underlying_mint: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::WRAP_VAULT, mint.as_ref(), underlying_mint.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive agent PDA
+/// Seeds: ["agent", mint, agent]
+pub fn find_agent_pda(mint: &Pubkey, agent: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::AGENT_ACCOUNT, mint.as_ref(), agent.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive identity PDA
+/// Seeds: ["identity", mint, investor_id]
+pub fn find_identity_pda(
+    mint: &Pubkey,
+    investor_id: &[u8; 32],
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::IDENTITY_ACCOUNT, mint.as_ref(), investor_id.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive identity wallet PDA
+/// Seeds: ["identity_wallet", token_account]
+pub fn find_identity_wallet_pda(token_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::IDENTITY_WALLET_ACCOUNT, token_account.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive transfer acceptance PDA
+/// Seeds: ["transfer_acceptance", mint]
+pub fn find_transfer_acceptance_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::TRANSFER_ACCEPTANCE_ACCOUNT, mint.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive pending transfer PDA
+/// Seeds: ["pending_transfer", source, destination, transfer_id]
+pub fn find_pending_transfer_pda(
+    source: &Pubkey,
+    destination: &Pubkey,
+    transfer_id: u64,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::PENDING_TRANSFER_ACCOUNT,
+            source.as_ref(),
+            destination.as_ref(),
+            &transfer_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive transfer escrow authority PDA owning the escrow token account of pending transfers
+/// Seeds: ["transfer_escrow_authority", mint]
+pub fn find_transfer_escrow_authority_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::TRANSFER_ESCROW_AUTHORITY, mint.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive transfer approval PDA
+/// Seeds: ["transfer_approval", mint]
+pub fn find_transfer_approval_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::TRANSFER_APPROVAL_ACCOUNT, mint.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive transfer request PDA
+/// Seeds: ["transfer_request", source, destination, request_id]
+pub fn find_transfer_request_pda(
+    source: &Pubkey,
+    destination: &Pubkey,
+    request_id: u64,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::TRANSFER_REQUEST_ACCOUNT,
+            source.as_ref(),
+            destination.as_ref(),
+            &request_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive coupon accrual config PDA
+/// Seeds: ["accrual_config", mint]
+pub fn find_accrual_config_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[seeds::ACCRUAL_CONFIG_ACCOUNT, mint.as_ref()], program_id)
+}
+
+/// Derive coupon claim PDA
+/// Seeds: ["coupon_claim", token_account]
+pub fn find_coupon_claim_pda(token_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::COUPON_CLAIM_ACCOUNT, token_account.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive bond maturity PDA
+/// Seeds: ["maturity", mint]
+pub fn find_maturity_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[seeds::MATURITY_ACCOUNT, mint.as_ref()], program_id)
+}
+
+/// Derive NAV oracle PDA
+/// Seeds: ["nav_oracle", mint]
+pub fn find_nav_oracle_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[seeds::NAV_ORACLE_ACCOUNT, mint.as_ref()], program_id)
+}
+
+/// Derive primary issuance subscription PDA
+/// Seeds: ["subscription", mint, subscription_id]
+pub fn find_subscription_pda(
+    mint: &Pubkey,
+    subscription_id: u64,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::SUBSCRIPTION_ACCOUNT,
+            mint.as_ref(),
+            &subscription_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive subscription commitment PDA
+/// Seeds: ["subscription_commitment", subscription, investor]
+pub fn find_subscription_commitment_pda(
+    subscription: &Pubkey,
+    investor: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::SUBSCRIPTION_COMMITMENT_ACCOUNT,
+            subscription.as_ref(),
+            investor.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive Dutch auction PDA
+/// Seeds: ["auction", mint, auction_id]
+pub fn find_auction_pda(mint: &Pubkey, auction_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::AUCTION_ACCOUNT,
+            mint.as_ref(),
+            &auction_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive auction bid escrow PDA
+/// Seeds: ["auction_bid", auction, bidder]
+pub fn find_auction_bid_pda(
+    auction: &Pubkey,
+    bidder: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::AUCTION_BID_ACCOUNT,
+            auction.as_ref(),
+            bidder.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive Rule 144 holding period PDA
+/// Seeds: ["holding_period", mint]
+pub fn find_holding_period_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[seeds::HOLDING_PERIOD_ACCOUNT, mint.as_ref()], program_id)
+}
+
+/// Derive restricted holding PDA of a token account
+/// Seeds: ["restricted_holding", token_account]
+pub fn find_restricted_holding_pda(token_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::RESTRICTED_HOLDING_ACCOUNT, token_account.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive restricted holding lot PDA
+/// Seeds: ["holding_lot", token_account, lot_id]
+pub fn find_holding_lot_pda(
+    token_account: &Pubkey,
+    lot_id: u64,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::HOLDING_LOT_ACCOUNT,
+            token_account.as_ref(),
+            &lot_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive suspension PDA of a token account
+/// Seeds: ["suspension", token_account]
+pub fn find_suspension_pda(token_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::SUSPENSION_ACCOUNT, token_account.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive freeze expiry PDA of a token account
+/// Seeds: ["freeze_expiry", token_account]
+pub fn find_freeze_expiry_pda(token_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::FREEZE_EXPIRY_ACCOUNT, token_account.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive collateral attestation PDA of a token account
+/// Seeds: ["collateral_attestation", token_account]
+pub fn find_collateral_attestation_pda(
+    token_account: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::COLLATERAL_ATTESTATION_ACCOUNT,
+            token_account.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive reserve attestation PDA of a custodian
+/// Seeds: ["reserve_attestation", mint, custodian]
+pub fn find_reserve_attestation_pda(
+    mint: &Pubkey,
+    custodian: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::RESERVE_ATTESTATION_ACCOUNT,
+            mint.as_ref(),
+            custodian.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive sub-account ledger PDA of an omnibus token account
+/// Seeds: ["sub_account_ledger", token_account]
+pub fn find_sub_account_ledger_pda(token_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::SUB_ACCOUNT_LEDGER_ACCOUNT, token_account.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive sub-account PDA of a beneficial owner
+/// Seeds: ["sub_account", sub_account_ledger, beneficial_owner]
+pub fn find_sub_account_pda(
+    sub_account_ledger: &Pubkey,
+    beneficial_owner: &[u8; 32],
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::SUB_ACCOUNT_ACCOUNT,
+            sub_account_ledger.as_ref(),
+            beneficial_owner.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive succession policy PDA of a mint
+/// Seeds: ["succession_policy", mint]
+pub fn find_succession_policy_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(
+        &[seeds::SUCCESSION_POLICY_ACCOUNT, mint.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive metadata translator PDA
+/// Seeds: ["metadata_translator", mint, translator]
+pub fn find_metadata_translator_pda(
+    mint: &Pubkey,
+    translator: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::METADATA_TRANSLATOR_ACCOUNT,
+            mint.as_ref(),
+            translator.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive vesting PDA of a beneficiary token account
+/// Seeds: ["vesting", beneficiary, vesting_id]
+pub fn find_vesting_pda(
+    beneficiary: &Pubkey,
+    vesting_id: u64,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::VESTING_ACCOUNT,
+            beneficiary.as_ref(),
+            &vesting_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive withholding rate PDA of a mint and jurisdiction
+/// Seeds: ["withholding_rate", mint, jurisdiction]
+pub fn find_withholding_rate_pda(
+    mint: &Pubkey,
+    jurisdiction: u16,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(
+        &[
+            seeds::WITHHOLDING_RATE_ACCOUNT,
+            mint.as_ref(),
+            &jurisdiction.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive position limit PDA
+/// Seeds: ["position_limit", mint]
+pub fn find_position_limit_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[seeds::POSITION_LIMIT_ACCOUNT, mint.as_ref()], program_id)
+}
+
 /// Parse additional metadata from raw bytes in TLV format
 /// Calls the provided callback for each key-value pair found
 pub fn parse_additional_metadata<F>(data: &[u8], mut callback: F) -> Result<(), ProgramError>
@@ -280,6 +958,8 @@ pub fn calculate_mint_size_with_extensions(extensions: &[ExtensionType]) -> usiz
                 ExtensionType::Pausable => Pausable::LEN,
                 ExtensionType::MetadataPointer => MetadataPointer::LEN,
                 ExtensionType::ScaledUiAmount => ScaledUiAmountConfig::LEN,
+                ExtensionType::TransferFeeConfig => TransferFeeConfig::LEN,
+                ExtensionType::DefaultAccountState => DefaultAccountState::LEN,
                 _ => unreachable!(),
             };
             EXTENSION_TYPE_LEN + EXTENSION_LENGTH_LEN + extension_data_size
@@ -312,6 +992,17 @@ pub fn calculate_metadata_tlv_size(metadata: &TokenMetadataArgs) -> Result<usize
     Ok(tlv_header_size + metadata_data_size)
 }
 
+/// Calculate TLV size for TokenMetadata in an external metadata account, whose entries use
+/// an 8-byte discriminator and a u32 length instead of the mint extension header
+pub fn calculate_external_metadata_tlv_size(
+    metadata: &TokenMetadataArgs,
+) -> Result<usize, ProgramError> {
+    Ok(
+        calculate_metadata_tlv_size(metadata)? - (EXTENSION_TYPE_LEN + EXTENSION_LENGTH_LEN)
+            + EXTERNAL_TLV_HEADER_LEN,
+    )
+}
+
 /// Parse action_id from bytes
 pub fn parse_action_id_bytes(data: &[u8]) -> Option<u64> {
     data.get(..ACTION_ID_LEN)
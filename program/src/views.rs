@@ -0,0 +1,156 @@
+use pinocchio::cpi::set_return_data;
+
+use crate::state::{DistributionRootStatus, Rounding};
+
+/// Token account state relevant to transfers, returned by GetHolderStatus.
+///
+/// `locked_amount` is the amount of restricted lots still in their holding period,
+/// `0` when the mint has no holding period.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HolderStatus {
+    pub balance: u64,
+    pub frozen: bool,
+    pub suspended: bool,
+    pub locked_amount: u64,
+}
+
+impl HolderStatus {
+    /// Balance (8) + frozen (1) + suspended (1) + locked amount (8)
+    pub const LEN: usize = 8 + 1 + 1 + 8;
+
+    /// Serialize the status with borsh
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN);
+        data.extend_from_slice(&self.balance.to_le_bytes());
+        data.push(self.frozen as u8);
+        data.push(self.suspended as u8);
+        data.extend_from_slice(&self.locked_amount.to_le_bytes());
+        data
+    }
+
+    /// Return the status to the caller
+    pub fn set_return_data(&self) {
+        set_return_data(&self.to_bytes());
+    }
+}
+
+/// Ratio a Rate converts at, returned by GetEffectiveRate.
+///
+/// One whole source token converts to `numerator / denominator` whole destination tokens.
+/// Rates with an oracle return the current oracle price over its fixed-point scale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EffectiveRate {
+    pub rounding: Rounding,
+    pub numerator: u64,
+    pub denominator: u64,
+    pub from_oracle: bool,
+}
+
+impl EffectiveRate {
+    /// Rounding (1) + numerator (8) + denominator (8) + from oracle (1)
+    pub const LEN: usize = 1 + 8 + 8 + 1;
+
+    /// Serialize the rate with borsh
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN);
+        data.push(self.rounding.into());
+        data.extend_from_slice(&self.numerator.to_le_bytes());
+        data.extend_from_slice(&self.denominator.to_le_bytes());
+        data.push(self.from_oracle as u8);
+        data
+    }
+
+    /// Return the rate to the caller
+    pub fn set_return_data(&self) {
+        set_return_data(&self.to_bytes());
+    }
+}
+
+/// Claim state of a distribution merkle root, returned by GetDistributionStatus.
+///
+/// The claim window is `None` without a ClaimDeadline, the root status is `None` for roots
+/// that were never amended and the escrow balance is `None` for external settlement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DistributionStatus {
+    pub claims_open_at: Option<i64>,
+    pub claim_deadline: Option<i64>,
+    pub root_status: Option<DistributionRootStatus>,
+    pub claimable: bool,
+    pub escrow_balance: Option<u64>,
+}
+
+impl DistributionStatus {
+    /// Serialize the status with borsh, options are prefixed by a presence byte
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        for timestamp in [self.claims_open_at, self.claim_deadline] {
+            push_option(&mut data, timestamp.map(i64::to_le_bytes).as_ref());
+        }
+        push_option(
+            &mut data,
+            self.root_status.map(|status| [u8::from(status)]).as_ref(),
+        );
+        data.push(self.claimable as u8);
+        push_option(
+            &mut data,
+            self.escrow_balance.map(u64::to_le_bytes).as_ref(),
+        );
+        data
+    }
+
+    /// Return the status to the caller
+    pub fn set_return_data(&self) {
+        set_return_data(&self.to_bytes());
+    }
+}
+
+fn push_option<const N: usize>(data: &mut Vec<u8>, value: Option<&[u8; N]>) {
+    match value {
+        Some(bytes) => {
+            data.push(1);
+            data.extend_from_slice(bytes);
+        }
+        None => data.push(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distribution_status_borsh_layout() {
+        let status = DistributionStatus {
+            claims_open_at: Some(10),
+            claim_deadline: None,
+            root_status: Some(DistributionRootStatus::Supplemental),
+            claimable: true,
+            escrow_balance: Some(500),
+        };
+        let data = status.to_bytes();
+
+        assert_eq!(data.len(), 9 + 1 + 2 + 1 + 9);
+        assert_eq!(data[0], 1);
+        assert_eq!(&data[1..9], &10i64.to_le_bytes());
+        assert_eq!(data[9], 0);
+        assert_eq!(&data[10..12], &[1, 1]);
+        assert_eq!(data[12], 1);
+        assert_eq!(&data[13..], &[&[1u8][..], &500u64.to_le_bytes()].concat());
+    }
+
+    #[test]
+    fn test_holder_status_borsh_layout() {
+        let status = HolderStatus {
+            balance: 7,
+            frozen: false,
+            suspended: true,
+            locked_amount: 3,
+        };
+        let data = status.to_bytes();
+
+        assert_eq!(data.len(), HolderStatus::LEN);
+        assert_eq!(&data[..8], &7u64.to_le_bytes());
+        assert_eq!(&data[8..10], &[0, 1]);
+        assert_eq!(&data[10..], &3u64.to_le_bytes());
+    }
+}
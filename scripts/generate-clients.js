@@ -3,6 +3,7 @@ const anchorIdl = require('@codama/nodes-from-anchor');
 const path = require('path');
 const renderers = require('@codama/renderers');
 const fs = require('fs');
+const { execSync } = require('child_process');
 
 // NOTE: rent sysvar, instruction sysvar, system program stay the same
 const TOKEN_2022_PROGRAM_ID = 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb';
@@ -18,6 +19,26 @@ const typescriptClientsDir = path.join(
   'typescript'
 );
 
+// Codama renders `std::io::Error::new(std::io::ErrorKind::Other, ..)`, which clippy rejects
+// as io_other_error. Rewrite it to `std::io::Error::other(..)` after rendering.
+function rewriteIoOtherErrors(dir) {
+  fs.readdirSync(dir, { withFileTypes: true }).forEach((entry) => {
+    const entryPath = path.join(dir, entry.name);
+    if (entry.isDirectory()) {
+      rewriteIoOtherErrors(entryPath);
+    } else if (entry.name.endsWith('.rs')) {
+      const source = fs.readFileSync(entryPath, 'utf8');
+      const rewritten = source.replace(
+        /std::io::Error::new\(\s*std::io::ErrorKind::Other,\s*/g,
+        'std::io::Error::other('
+      );
+      if (rewritten !== source) {
+        fs.writeFileSync(entryPath, rewritten);
+      }
+    }
+  });
+}
+
 function preserveConfigFiles() {
   const filesToPreserve = [
     'package.json',
@@ -106,6 +127,8 @@ sasCodama.accept(
     deleteFolderBeforeRendering: true,
   })
 );
+rewriteIoOtherErrors(path.join(rustClientsDir, 'src', 'generated'));
+execSync('cargo fmt', { cwd: rustClientsDir, stdio: 'inherit' });
 sasCodama.accept(
   renderers.renderJavaScriptVisitor(
     path.join(typescriptClientsDir, 'src', 'generated'),
@@ -0,0 +1,133 @@
+use security_token_client::{
+    instructions::{
+        CloseAgentAccount, CreateAgentAccount, CreateAgentAccountInstructionArgs, Freeze,
+        FreezeInstructionArgs, UpdateAgentAccount, UpdateAgentAccountInstructionArgs,
+    },
+    pda::find_agent_pda,
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{find_mint_authority_pda, find_mint_freeze_authority_pda, send_tx};
+
+/// Build and send CreateAgentAccount instruction authorized by mint authority
+pub async fn execute_create_agent_account(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    agent: Pubkey,
+    permissions: u8,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (agent_account, _) = find_agent_pda(&mint, &agent);
+
+    let ix = CreateAgentAccount {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        agent_account,
+        agent,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(CreateAgentAccountInstructionArgs { permissions });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send UpdateAgentAccount instruction authorized by mint authority
+pub async fn execute_update_agent_account(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    agent: Pubkey,
+    permissions: u8,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (agent_account, _) = find_agent_pda(&mint, &agent);
+
+    let ix = UpdateAgentAccount {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        mint_account: mint,
+        agent_account,
+    }
+    .instruction(UpdateAgentAccountInstructionArgs { permissions });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send CloseAgentAccount instruction authorized by mint authority
+pub async fn execute_close_agent_account(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    agent: Pubkey,
+    rent_recipient: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (agent_account, _) = find_agent_pda(&mint, &agent);
+
+    let ix = CloseAgentAccount {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        mint_account: mint,
+        agent_account,
+        rent_recipient,
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send Freeze instruction signed by an agent
+pub async fn execute_freeze_by_agent(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    token_account: Pubkey,
+    agent: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (agent_account, _) = find_agent_pda(&mint, &agent.pubkey());
+    let (freeze_authority, _) = find_mint_freeze_authority_pda(&mint);
+
+    let mut ix = Freeze {
+        mint,
+        verification_config: agent_account,
+        instructions_sysvar: agent.pubkey(),
+        freeze_authority,
+        mint_account: mint,
+        token_account,
+        token_program: TOKEN_22_PROGRAM_ID,
+        payer: None,
+        freeze_expiry_account: None,
+        system_program: None,
+    }
+    .instruction(FreezeInstructionArgs { auto_thaw_at: None });
+    // Agent signs in place of the instructions sysvar
+    ix.accounts[2].is_signer = true;
+
+    send_tx(banks_client, vec![ix], &agent.pubkey(), vec![agent]).await
+}
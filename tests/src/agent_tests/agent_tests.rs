@@ -0,0 +1,214 @@
+use security_token_client::{
+    agent::{DISTRIBUTION, FREEZE},
+    errors::SecurityTokenProgramError,
+    pda::find_agent_pda,
+};
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::state::AccountState;
+
+use crate::{
+    agent_tests::agent_helpers::{
+        execute_close_agent_account, execute_create_agent_account, execute_freeze_by_agent,
+        execute_update_agent_account,
+    },
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        get_token_account_state, start_with_context_and_accounts,
+    },
+};
+
+#[tokio::test]
+async fn test_agent_with_freeze_scope_should_freeze() {
+    let agent = Keypair::new();
+    let holder = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![
+        (&agent, 1_000_000_000),
+        (&holder, 1_000_000_000),
+    ])
+    .await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+    let token_account = create_spl_account(context, &mint_keypair, &holder).await;
+
+    let result = execute_create_agent_account(
+        &context.banks_client,
+        mint,
+        agent.pubkey(),
+        FREEZE,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    let (agent_account, _) = find_agent_pda(&mint, &agent.pubkey());
+    assert_account_exists(context, agent_account, true).await;
+
+    let result = execute_freeze_by_agent(&context.banks_client, mint, token_account, &agent).await;
+    assert_transaction_success(result);
+
+    let token_account_state =
+        get_token_account_state(&mut context.banks_client, token_account).await;
+    assert_eq!(token_account_state.base.state, AccountState::Frozen);
+}
+
+#[tokio::test]
+async fn test_agent_without_freeze_scope_should_not_freeze() {
+    let agent = Keypair::new();
+    let holder = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![
+        (&agent, 1_000_000_000),
+        (&holder, 1_000_000_000),
+    ])
+    .await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+    let token_account = create_spl_account(context, &mint_keypair, &holder).await;
+
+    let result = execute_create_agent_account(
+        &context.banks_client,
+        mint,
+        agent.pubkey(),
+        DISTRIBUTION,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_freeze_by_agent(&context.banks_client, mint, token_account, &agent).await;
+    assert_security_token_error(result, SecurityTokenProgramError::AgentPermissionDenied);
+
+    // Granting the scope afterwards allows the agent to freeze
+    let result = execute_update_agent_account(
+        &context.banks_client,
+        mint,
+        agent.pubkey(),
+        FREEZE | DISTRIBUTION,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_freeze_by_agent(&context.banks_client, mint, token_account, &agent).await;
+    assert_transaction_success(result);
+}
+
+#[tokio::test]
+async fn test_closed_agent_should_not_freeze() {
+    let agent = Keypair::new();
+    let holder = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![
+        (&agent, 1_000_000_000),
+        (&holder, 1_000_000_000),
+    ])
+    .await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+    let token_account = create_spl_account(context, &mint_keypair, &holder).await;
+
+    let result = execute_create_agent_account(
+        &context.banks_client,
+        mint,
+        agent.pubkey(),
+        FREEZE,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_close_agent_account(
+        &context.banks_client,
+        mint,
+        agent.pubkey(),
+        mint_creator.pubkey(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    let (agent_account, _) = find_agent_pda(&mint, &agent.pubkey());
+    assert_account_exists(context, agent_account, false).await;
+
+    let result = execute_freeze_by_agent(&context.banks_client, mint, token_account, &agent).await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_not_create_agent_without_permissions() {
+    let agent = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+
+    let result = execute_create_agent_account(
+        &context.banks_client,
+        mint_keypair.pubkey(),
+        agent.pubkey(),
+        0,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_close_agent_to_dedicated_rent_recipient() {
+    let agent = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![(&agent, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let treasury = Keypair::new().pubkey();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+
+    let result = execute_create_agent_account(
+        &context.banks_client,
+        mint,
+        agent.pubkey(),
+        FREEZE,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    let (agent_account, _) = find_agent_pda(&mint, &agent.pubkey());
+    let agent_rent = assert_account_exists(context, agent_account, true)
+        .await
+        .unwrap()
+        .lamports;
+
+    // The agent account cannot receive its own rent
+    let result = execute_close_agent_account(
+        &context.banks_client,
+        mint,
+        agent.pubkey(),
+        agent_account,
+        &mint_creator,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::InvalidRentRecipient);
+
+    let result = execute_close_agent_account(
+        &context.banks_client,
+        mint,
+        agent.pubkey(),
+        treasury,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_account_exists(context, agent_account, false).await;
+    let treasury_account = assert_account_exists(context, treasury, true)
+        .await
+        .unwrap();
+    assert_eq!(treasury_account.lamports, agent_rent);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod agent_tests;
+
+pub mod agent_helpers;
@@ -0,0 +1,112 @@
+use security_token_client::{
+    instructions::{
+        AddToAllowlist, AddToAllowlistInstructionArgs, RemoveFromAllowlist,
+        RemoveFromAllowlistInstructionArgs, SetAllowlistMode, SetAllowlistModeInstructionArgs,
+        TRANSFER_DISCRIMINATOR,
+    },
+    pda::find_allowlist_entry_pda,
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_transfer_hook_interface::get_extra_account_metas_address;
+
+use crate::helpers::{
+    find_mint_authority_pda, find_transfer_hook_pda, find_verification_config_pda, send_tx,
+};
+
+/// Build and send AddToAllowlist instruction authorized by mint authority
+pub async fn execute_add_to_allowlist(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    wallet: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (allowlist_entry_account, _) = find_allowlist_entry_pda(&mint, &wallet);
+
+    let ix = AddToAllowlist {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        allowlist_entry_account,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(AddToAllowlistInstructionArgs { wallet });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send RemoveFromAllowlist instruction authorized by mint authority
+pub async fn execute_remove_from_allowlist(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    wallet: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (allowlist_entry_account, _) = find_allowlist_entry_pda(&mint, &wallet);
+
+    let ix = RemoveFromAllowlist {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        mint_account: mint,
+        allowlist_entry_account,
+        rent_recipient: mint_creator.pubkey(),
+    }
+    .instruction(RemoveFromAllowlistInstructionArgs { wallet });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send SetAllowlistMode instruction authorized by mint authority
+pub async fn execute_set_allowlist_mode(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    enabled: bool,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (transfer_verification_config, _) =
+        find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+    let (transfer_hook_pda, _) = find_transfer_hook_pda(&mint);
+
+    let ix = SetAllowlistMode {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        transfer_verification_config,
+        rent_recipient: mint_creator.pubkey(),
+        system_program: solana_program::system_program::id(),
+        account_metas_pda: get_extra_account_metas_address(&mint, &transfer_hook_program),
+        transfer_hook_pda,
+        transfer_hook_program,
+    }
+    .instruction(SetAllowlistModeInstructionArgs { enabled });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
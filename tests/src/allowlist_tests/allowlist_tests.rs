@@ -0,0 +1,135 @@
+use security_token_client::{
+    accounts::AllowlistEntry, errors::SecurityTokenProgramError, pda::find_allowlist_entry_pda,
+};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    allowlist_tests::allowlist_helpers::{
+        execute_add_to_allowlist, execute_remove_from_allowlist, execute_set_allowlist_mode,
+    },
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_spl_account, setup_transfer_verified_mint,
+        start_with_transfer_hook,
+    },
+    position_limit_tests::position_limit_helpers::transfer,
+};
+
+const SUPPLY: u64 = 1_000_000;
+
+#[tokio::test]
+async fn test_allowlist_mode_should_require_allowlisted_owners() {
+    let context = &mut start_with_transfer_hook(&[]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let source_owner = Keypair::new();
+    let (mint_keypair, _, source_account) =
+        setup_transfer_verified_mint(context, &source_owner, SUPPLY).await;
+    let mint = mint_keypair.pubkey();
+    let banks_client = context.banks_client.clone();
+
+    let investor = Keypair::new();
+    let investor_account = create_spl_account(context, &mint_keypair, &investor).await;
+
+    let result = execute_set_allowlist_mode(&banks_client, mint, true, &mint_creator).await;
+    assert_transaction_success(result);
+
+    let result = transfer(
+        context,
+        mint,
+        source_account,
+        investor_account,
+        &source_owner,
+        1,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::WalletNotAllowlisted);
+
+    let result =
+        execute_add_to_allowlist(&banks_client, mint, source_owner.pubkey(), &mint_creator).await;
+    assert_transaction_success(result);
+    let (source_entry, _) = find_allowlist_entry_pda(&mint, &source_owner.pubkey());
+    let account = assert_account_exists(context, source_entry, true)
+        .await
+        .unwrap();
+    let allowlist_entry = AllowlistEntry::from_bytes(&account.data).unwrap();
+    assert_eq!(allowlist_entry.mint, mint);
+    assert_eq!(allowlist_entry.wallet, source_owner.pubkey());
+
+    // The destination owner is not allowlisted yet
+    let result = transfer(
+        context,
+        mint,
+        source_account,
+        investor_account,
+        &source_owner,
+        1,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::WalletNotAllowlisted);
+
+    let result =
+        execute_add_to_allowlist(&banks_client, mint, investor.pubkey(), &mint_creator).await;
+    assert_transaction_success(result);
+    let result = transfer(
+        context,
+        mint,
+        source_account,
+        investor_account,
+        &source_owner,
+        1,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result =
+        execute_remove_from_allowlist(&banks_client, mint, investor.pubkey(), &mint_creator).await;
+    assert_transaction_success(result);
+    let result = transfer(
+        context,
+        mint,
+        investor_account,
+        source_account,
+        &investor,
+        1,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::WalletNotAllowlisted);
+
+    let result = execute_set_allowlist_mode(&banks_client, mint, false, &mint_creator).await;
+    assert_transaction_success(result);
+    let result = transfer(
+        context,
+        mint,
+        investor_account,
+        source_account,
+        &investor,
+        1,
+    )
+    .await;
+    assert_transaction_success(result);
+}
+
+#[tokio::test]
+async fn test_should_not_allowlist_wallet_twice() {
+    let context = &mut start_with_transfer_hook(&[]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let (mint_keypair, _, _) = setup_transfer_verified_mint(context, &Keypair::new(), SUPPLY).await;
+    let mint = mint_keypair.pubkey();
+    let banks_client = context.banks_client.clone();
+    let wallet = Pubkey::new_unique();
+
+    let result = execute_add_to_allowlist(&banks_client, mint, wallet, &mint_creator).await;
+    assert_transaction_success(result);
+    let result = execute_add_to_allowlist(&banks_client, mint, wallet, &mint_creator).await;
+    assert_transaction_failure(result);
+
+    let result = execute_remove_from_allowlist(&banks_client, mint, wallet, &mint_creator).await;
+    assert_transaction_success(result);
+    let (allowlist_entry, _) = find_allowlist_entry_pda(&mint, &wallet);
+    assert_account_exists(context, allowlist_entry, false).await;
+
+    // Removing a wallet without an entry fails
+    let result = execute_remove_from_allowlist(&banks_client, mint, wallet, &mint_creator).await;
+    assert_transaction_failure(result);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod allowlist_tests;
+
+pub mod allowlist_helpers;
@@ -0,0 +1,195 @@
+use security_token_client::{
+    instructions::{
+        ClearAuction, CreateAuction, CreateAuctionInstructionArgs, PlaceBid,
+        PlaceBidInstructionArgs, SettleBid,
+    },
+    pda::{find_associated_token_address_with_program, find_auction_bid_pda, find_auction_pda},
+    types::CreateAuctionArgs,
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    sysvar,
+};
+use spl_associated_token_account::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{
+    create_dummy_verification_from_instruction, find_mint_authority_pda, send_tx,
+};
+
+/// Payment vault of the auction, owned by its Auction PDA
+pub fn find_auction_vault(
+    mint: &Pubkey,
+    auction_id: u64,
+    payment_mint: &Pubkey,
+    payment_token_program: &Pubkey,
+) -> Pubkey {
+    let (auction, _) = find_auction_pda(mint, auction_id);
+    find_associated_token_address_with_program(&auction, payment_mint, payment_token_program).0
+}
+
+/// Build and send CreateAuction instruction authorized by mint authority
+pub async fn execute_create_auction(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    payment_mint: Pubkey,
+    payment_token_program: Pubkey,
+    proceeds_account: Pubkey,
+    args: CreateAuctionArgs,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (auction_account, _) = find_auction_pda(&mint, args.auction_id);
+
+    let ix = CreateAuction {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        auction_account,
+        payment_mint,
+        payment_vault: find_auction_vault(
+            &mint,
+            args.auction_id,
+            &payment_mint,
+            &payment_token_program,
+        ),
+        proceeds_account,
+        payment_token_program,
+        associated_token_account_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(CreateAuctionInstructionArgs {
+        create_auction_args: args,
+    });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send PlaceBid instruction paid and signed by the bidder
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_place_bid(
+    banks_client: &BanksClient,
+    verification_config: Pubkey,
+    mint: Pubkey,
+    auction_id: u64,
+    payment_mint: Pubkey,
+    payment_token_program: Pubkey,
+    bidder_payment_account: Pubkey,
+    bidder: &Keypair,
+    quantity: u64,
+) -> Result<(), BanksClientError> {
+    let (auction_account, _) = find_auction_pda(&mint, auction_id);
+    let (bid_account, _) = find_auction_bid_pda(&auction_account, &bidder.pubkey());
+
+    let ix = PlaceBid {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        payer: bidder.pubkey(),
+        bidder: bidder.pubkey(),
+        mint_account: mint,
+        auction_account,
+        bid_account,
+        payment_mint,
+        payment_vault: find_auction_vault(&mint, auction_id, &payment_mint, &payment_token_program),
+        bidder_payment_account,
+        payment_token_program,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(PlaceBidInstructionArgs { quantity });
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &bidder.pubkey(),
+        vec![bidder],
+    )
+    .await
+}
+
+/// Build and send ClearAuction instruction authorized by mint authority
+pub async fn execute_clear_auction(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    auction_id: u64,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (auction_account, _) = find_auction_pda(&mint, auction_id);
+
+    let ix = ClearAuction {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        mint_account: mint,
+        auction_account,
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send SettleBid instruction
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_settle_bid(
+    banks_client: &BanksClient,
+    verification_config: Pubkey,
+    mint_authority: Pubkey,
+    mint: Pubkey,
+    auction_id: u64,
+    bidder: Pubkey,
+    token_account: Pubkey,
+    payment_mint: Pubkey,
+    payment_token_program: Pubkey,
+    proceeds_account: Pubkey,
+    bidder_payment_account: Pubkey,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (auction_account, _) = find_auction_pda(&mint, auction_id);
+    let (bid_account, _) = find_auction_bid_pda(&auction_account, &bidder);
+
+    let ix = SettleBid {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        mint_authority,
+        bidder,
+        mint_account: mint,
+        auction_account,
+        bid_account,
+        token_account,
+        payment_mint,
+        payment_vault: find_auction_vault(&mint, auction_id, &payment_mint, &payment_token_program),
+        proceeds_account,
+        bidder_payment_account,
+        payment_token_program,
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction();
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
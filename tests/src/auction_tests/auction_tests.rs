@@ -0,0 +1,371 @@
+use security_token_client::{
+    accounts::{Auction, AuctionBid},
+    errors::SecurityTokenProgramError,
+    instructions::{PLACE_BID_DISCRIMINATOR, SETTLE_BID_DISCRIMINATOR},
+    pda::{find_auction_bid_pda, find_auction_pda, TOKEN_PROGRAM_ID},
+    types::CreateAuctionArgs,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    auction_tests::auction_helpers::{
+        execute_clear_auction, execute_create_auction, execute_place_bid, execute_settle_bid,
+        find_auction_vault,
+    },
+    coupon_tests::coupon_helpers::{current_timestamp, warp_to_timestamp},
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        create_verification_config, get_default_verification_programs, get_token_account_state,
+        start_with_context_and_accounts,
+    },
+    wrap_tests::wrap_helpers::{create_underlying_mint, create_underlying_token_account},
+};
+
+const DECIMALS: u8 = 6;
+const AUCTION_ID: u64 = 1;
+/// Price falls from 10 to 5 payment tokens per security token over 1000 seconds
+const START_PRICE: u64 = 10_000_000;
+const FLOOR_PRICE: u64 = 5_000_000;
+const DURATION: i64 = 1_000;
+/// 100 security tokens for sale
+const SUPPLY: u64 = 100_000_000;
+
+struct AuctionSetup {
+    mint_keypair: Keypair,
+    mint_authority_pda: Pubkey,
+    place_bid_verification_config: Pubkey,
+    settle_bid_verification_config: Pubkey,
+    payment_mint: Pubkey,
+    proceeds_account: Pubkey,
+    start_timestamp: i64,
+}
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    get_token_account_state(&mut context.banks_client, token_account)
+        .await
+        .base
+        .amount
+}
+
+async fn setup_auction(context: &mut ProgramTestContext) -> AuctionSetup {
+    let mint_creator = context.payer.insecure_clone();
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), DECIMALS)
+            .await;
+    let place_bid_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        PLACE_BID_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+    let settle_bid_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        SETTLE_BID_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+
+    let payment_mint = create_underlying_mint(context, &TOKEN_PROGRAM_ID, DECIMALS).await;
+    let proceeds_account = create_underlying_token_account(
+        context,
+        &payment_mint,
+        &TOKEN_PROGRAM_ID,
+        &mint_creator.pubkey(),
+        DECIMALS,
+        0,
+    )
+    .await;
+
+    let start_timestamp = current_timestamp(context).await + 100;
+    let result = execute_create_auction(
+        &context.banks_client,
+        mint,
+        payment_mint,
+        TOKEN_PROGRAM_ID,
+        proceeds_account,
+        CreateAuctionArgs {
+            auction_id: AUCTION_ID,
+            start_price: START_PRICE,
+            floor_price: FLOOR_PRICE,
+            start_timestamp,
+            end_timestamp: start_timestamp + DURATION,
+            supply: SUPPLY,
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    AuctionSetup {
+        mint_keypair,
+        mint_authority_pda,
+        place_bid_verification_config,
+        settle_bid_verification_config,
+        payment_mint,
+        proceeds_account,
+        start_timestamp,
+    }
+}
+
+async fn get_auction(context: &mut ProgramTestContext, mint: &Pubkey) -> Auction {
+    let (auction_pda, _) = find_auction_pda(mint, AUCTION_ID);
+    let account = assert_account_exists(context, auction_pda, true)
+        .await
+        .unwrap();
+    Auction::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn test_should_bid_clear_and_settle_sold_out_auction() {
+    let bidder_a = Keypair::new();
+    let bidder_b = Keypair::new();
+    let bidder_c = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![
+        (&bidder_a, 1_000_000_000),
+        (&bidder_b, 1_000_000_000),
+        (&bidder_c, 1_000_000_000),
+    ])
+    .await;
+    let mint_creator = context.payer.insecure_clone();
+    let setup = setup_auction(context).await;
+    let mint = setup.mint_keypair.pubkey();
+
+    let mut bidder_payment_accounts = vec![];
+    for bidder in [&bidder_a, &bidder_b, &bidder_c] {
+        bidder_payment_accounts.push(
+            create_underlying_token_account(
+                context,
+                &setup.payment_mint,
+                &TOKEN_PROGRAM_ID,
+                &bidder.pubkey(),
+                DECIMALS,
+                1_000_000_000,
+            )
+            .await,
+        );
+    }
+    let banks_client = context.banks_client.clone();
+    let place_bid = |bidder: &Keypair, bidder_payment_account: Pubkey, quantity: u64| {
+        let banks_client = banks_client.clone();
+        let bidder = bidder.insecure_clone();
+        async move {
+            execute_place_bid(
+                &banks_client,
+                setup.place_bid_verification_config,
+                mint,
+                AUCTION_ID,
+                setup.payment_mint,
+                TOKEN_PROGRAM_ID,
+                bidder_payment_account,
+                &bidder,
+                quantity,
+            )
+            .await
+        }
+    };
+
+    // Bidding opens at the start price
+    let result = place_bid(&bidder_a, bidder_payment_accounts[0], 60_000_000).await;
+    assert_security_token_error(result, SecurityTokenProgramError::AuctionNotOpen);
+
+    // 60 tokens at 10, then 60 tokens at 7.5 sell out the 100 tokens on offer
+    warp_to_timestamp(context, setup.start_timestamp).await;
+    let result = place_bid(&bidder_a, bidder_payment_accounts[0], 60_000_000).await;
+    assert_transaction_success(result);
+    warp_to_timestamp(context, setup.start_timestamp + DURATION / 2).await;
+    let result = place_bid(&bidder_b, bidder_payment_accounts[1], 60_000_000).await;
+    assert_transaction_success(result);
+
+    let payment_vault =
+        find_auction_vault(&mint, AUCTION_ID, &setup.payment_mint, &TOKEN_PROGRAM_ID);
+    assert_eq!(token_amount(context, payment_vault).await, 1_050_000_000);
+
+    let (auction_pda, _) = find_auction_pda(&mint, AUCTION_ID);
+    let (bid_pda, _) = find_auction_bid_pda(&auction_pda, &bidder_b.pubkey());
+    let account = assert_account_exists(context, bid_pda, true).await.unwrap();
+    let bid = AuctionBid::from_bytes(&account.data).unwrap();
+    assert_eq!(bid.bid_price, 7_500_000);
+    assert_eq!(bid.escrow_amount, 450_000_000);
+    assert_eq!(bid.filled_before, 60_000_000);
+
+    let result = place_bid(&bidder_c, bidder_payment_accounts[2], 1_000_000).await;
+    assert_security_token_error(result, SecurityTokenProgramError::AuctionNotOpen);
+
+    let token_account_a = create_spl_account(context, &setup.mint_keypair, &bidder_a).await;
+    let result = execute_settle_bid(
+        &context.banks_client,
+        setup.settle_bid_verification_config,
+        setup.mint_authority_pda,
+        mint,
+        AUCTION_ID,
+        bidder_a.pubkey(),
+        token_account_a,
+        setup.payment_mint,
+        TOKEN_PROGRAM_ID,
+        setup.proceeds_account,
+        bidder_payment_accounts[0],
+        &mint_creator,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::AuctionNotCleared);
+
+    // Sold out auctions clear before the end at the price of the last bid
+    let result =
+        execute_clear_auction(&context.banks_client, mint, AUCTION_ID, &mint_creator).await;
+    assert_transaction_success(result);
+    assert_eq!(get_auction(context, &mint).await.clearing_price, 7_500_000);
+
+    // Bidder A gets the full 60 tokens, bidder B the remaining 40, both at 7.5
+    for (bidder, bidder_payment_account, allocation, payment_balance) in [
+        (
+            &bidder_a,
+            bidder_payment_accounts[0],
+            60_000_000,
+            550_000_000,
+        ),
+        (
+            &bidder_b,
+            bidder_payment_accounts[1],
+            40_000_000,
+            700_000_000,
+        ),
+    ] {
+        let token_account = if bidder.pubkey() == bidder_a.pubkey() {
+            token_account_a
+        } else {
+            create_spl_account(context, &setup.mint_keypair, bidder).await
+        };
+        context.get_new_latest_blockhash().await.unwrap();
+        let result = execute_settle_bid(
+            &context.banks_client,
+            setup.settle_bid_verification_config,
+            setup.mint_authority_pda,
+            mint,
+            AUCTION_ID,
+            bidder.pubkey(),
+            token_account,
+            setup.payment_mint,
+            TOKEN_PROGRAM_ID,
+            setup.proceeds_account,
+            bidder_payment_account,
+            &mint_creator,
+        )
+        .await;
+        assert_transaction_success(result);
+
+        assert_eq!(token_amount(context, token_account).await, allocation);
+        assert_eq!(
+            token_amount(context, bidder_payment_account).await,
+            payment_balance
+        );
+        let (bid_pda, _) = find_auction_bid_pda(&auction_pda, &bidder.pubkey());
+        assert_account_exists(context, bid_pda, false).await;
+    }
+
+    assert_eq!(
+        token_amount(context, setup.proceeds_account).await,
+        750_000_000
+    );
+    assert_eq!(token_amount(context, payment_vault).await, 0);
+}
+
+#[tokio::test]
+async fn test_undersubscribed_auction_clears_after_end() {
+    let bidder = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![(&bidder, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let setup = setup_auction(context).await;
+    let mint = setup.mint_keypair.pubkey();
+
+    let bidder_payment_account = create_underlying_token_account(
+        context,
+        &setup.payment_mint,
+        &TOKEN_PROGRAM_ID,
+        &bidder.pubkey(),
+        DECIMALS,
+        1_000_000_000,
+    )
+    .await;
+
+    // 20 tokens at 6
+    warp_to_timestamp(context, setup.start_timestamp + 800).await;
+    let result = execute_place_bid(
+        &context.banks_client,
+        setup.place_bid_verification_config,
+        mint,
+        AUCTION_ID,
+        setup.payment_mint,
+        TOKEN_PROGRAM_ID,
+        bidder_payment_account,
+        &bidder,
+        20_000_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result =
+        execute_clear_auction(&context.banks_client, mint, AUCTION_ID, &mint_creator).await;
+    assert_security_token_error(result, SecurityTokenProgramError::AuctionNotClearable);
+
+    warp_to_timestamp(context, setup.start_timestamp + DURATION).await;
+    let result = execute_place_bid(
+        &context.banks_client,
+        setup.place_bid_verification_config,
+        mint,
+        AUCTION_ID,
+        setup.payment_mint,
+        TOKEN_PROGRAM_ID,
+        bidder_payment_account,
+        &bidder,
+        1_000_000,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let result =
+        execute_clear_auction(&context.banks_client, mint, AUCTION_ID, &mint_creator).await;
+    assert_transaction_success(result);
+    let auction = get_auction(context, &mint).await;
+    assert_eq!(auction.clearing_price, 6_000_000);
+    assert_eq!(auction.total_bid_quantity, 20_000_000);
+
+    context.get_new_latest_blockhash().await.unwrap();
+    let result =
+        execute_clear_auction(&context.banks_client, mint, AUCTION_ID, &mint_creator).await;
+    assert_security_token_error(result, SecurityTokenProgramError::AuctionNotClearable);
+
+    let token_account = create_spl_account(context, &setup.mint_keypair, &bidder).await;
+    let result = execute_settle_bid(
+        &context.banks_client,
+        setup.settle_bid_verification_config,
+        setup.mint_authority_pda,
+        mint,
+        AUCTION_ID,
+        bidder.pubkey(),
+        token_account,
+        setup.payment_mint,
+        TOKEN_PROGRAM_ID,
+        setup.proceeds_account,
+        bidder_payment_account,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    assert_eq!(token_amount(context, token_account).await, 20_000_000);
+    assert_eq!(
+        token_amount(context, setup.proceeds_account).await,
+        120_000_000
+    );
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod auction_tests;
+
+pub mod auction_helpers;
@@ -0,0 +1,40 @@
+use security_token_client::{audit::AuditEvent, instructions::AuditMint};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    instruction::AccountMeta, signer::Signer, transaction::Transaction,
+    transaction::TransactionError,
+};
+
+/// Simulate AuditMint over `sample` and decode the AuditEvent it returns
+pub async fn simulate_audit_mint(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    sample: &[Pubkey],
+) -> Result<AuditEvent, TransactionError> {
+    let sample_metas: Vec<AccountMeta> = sample
+        .iter()
+        .map(|account| AccountMeta::new_readonly(*account, false))
+        .collect();
+    let ix = AuditMint { mint }.instruction_with_remaining_accounts(&sample_metas);
+
+    let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        recent_blockhash,
+    );
+    let simulation = context
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .map_err(|error: BanksClientError| error.unwrap())?;
+    simulation.result.unwrap()?;
+
+    let return_data = simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .expect("AuditMint sets the audit event as return data");
+    Ok(AuditEvent::from_log_data(&return_data.data).expect("Should decode the audit event"))
+}
@@ -0,0 +1,103 @@
+use security_token_client::{instructions::CreateVestingInstructionArgs, pda::find_vesting_pda};
+use solana_program::instruction::InstructionError;
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::TransactionError,
+};
+
+use crate::{
+    audit_tests::audit_helpers::simulate_audit_mint,
+    helpers::{
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        start_with_transfer_hook,
+    },
+    transfer_acceptance_tests::transfer_acceptance_helpers::find_transfer_escrow_token_account,
+    vesting_tests::vesting_helpers::execute_create_vesting,
+};
+
+const TOTAL_AMOUNT: u64 = 1_000;
+
+/// Mint with a vesting grant of `TOTAL_AMOUNT` held in the transfer escrow
+async fn create_mint_with_vesting(context: &mut ProgramTestContext) -> (Pubkey, Pubkey) {
+    let mint_creator = context.payer.insecure_clone();
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, None, 6).await;
+    let beneficiary_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+
+    let result = execute_create_vesting(
+        &context.banks_client,
+        mint,
+        beneficiary_account,
+        CreateVestingInstructionArgs {
+            vesting_id: 0,
+            total_amount: TOTAL_AMOUNT,
+            start_timestamp: 0,
+            end_timestamp: i64::MAX,
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    (mint, beneficiary_account)
+}
+
+#[tokio::test]
+async fn test_audit_should_pass_for_consistent_sample() {
+    let context = &mut start_with_transfer_hook(&[]).await;
+    let (mint, beneficiary_account) = create_mint_with_vesting(context).await;
+    let escrow_account = find_transfer_escrow_token_account(&mint);
+    let (vesting_pda, _) = find_vesting_pda(&beneficiary_account, 0);
+
+    let event = simulate_audit_mint(
+        context,
+        mint,
+        &[escrow_account, beneficiary_account, vesting_pda],
+    )
+    .await
+    .unwrap();
+    assert!(event.passed());
+    assert_eq!(event.mint, mint);
+    assert_eq!(event.supply, TOTAL_AMOUNT);
+    assert_eq!(event.sampled_balance, TOTAL_AMOUNT);
+    assert_eq!(event.holder_count, 1);
+    assert_eq!(event.escrow_balance, TOTAL_AMOUNT);
+    assert_eq!(event.escrow_entitlements, TOTAL_AMOUNT);
+}
+
+#[tokio::test]
+async fn test_audit_should_require_escrow_for_sampled_entitlements() {
+    let context = &mut start_with_transfer_hook(&[]).await;
+    let (mint, beneficiary_account) = create_mint_with_vesting(context).await;
+    let (vesting_pda, _) = find_vesting_pda(&beneficiary_account, 0);
+
+    let result = simulate_audit_mint(context, mint, &[vesting_pda]).await;
+    assert_eq!(
+        result,
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::NotEnoughAccountKeys
+        ))
+    );
+
+    let result =
+        simulate_audit_mint(context, mint, &[beneficiary_account, beneficiary_account]).await;
+    assert_eq!(
+        result,
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::InvalidArgument
+        ))
+    );
+
+    // Sampling the escrow without the grants checks no entitlement
+    let escrow_account = find_transfer_escrow_token_account(&mint);
+    let event = simulate_audit_mint(context, mint, &[escrow_account])
+        .await
+        .unwrap();
+    assert!(event.passed());
+    assert_eq!(event.escrow_balance, TOTAL_AMOUNT);
+    assert_eq!(event.escrow_entitlements, 0);
+}
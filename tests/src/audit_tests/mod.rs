@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod audit_tests;
+
+pub mod audit_helpers;
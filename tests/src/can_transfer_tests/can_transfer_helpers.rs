@@ -0,0 +1,59 @@
+use security_token_client::{instructions::CanTransferBuilder, verification::TransferCheck};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::{signer::Signer, sysvar, transaction::Transaction};
+
+use crate::helpers::{create_dummy_verification_from_instruction, find_permanent_delegate_pda};
+
+/// Simulate CanTransfer, preceded by the dummy verification of the Transfer it stands for
+/// when `verified`, and decode its result code
+pub async fn simulate_can_transfer(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    verification_config: Pubkey,
+    from_token_account: Pubkey,
+    to_token_account: Pubkey,
+    amount: u64,
+    verified: bool,
+) -> TransferCheck {
+    let ix = CanTransferBuilder::new()
+        .mint(mint)
+        .verification_config(verification_config)
+        .instructions_sysvar(sysvar::instructions::ID)
+        .permanent_delegate_authority(find_permanent_delegate_pda(&mint).0)
+        .mint_account(mint)
+        .from_token_account(from_token_account)
+        .to_token_account(to_token_account)
+        .transfer_hook_program(Pubkey::from(security_token_transfer_hook::id()))
+        .amount(amount)
+        .instruction();
+
+    let mut ixs = vec![];
+    if verified {
+        // Verification programs are called with the Transfer instruction data
+        let mut verification_ix = create_dummy_verification_from_instruction(&ix);
+        verification_ix.data[0] = security_token_client::instructions::TRANSFER_DISCRIMINATOR;
+        ixs.push(verification_ix);
+    }
+    ixs.push(ix);
+
+    let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        recent_blockhash,
+    );
+    let simulation = context
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    assert!(simulation.result.unwrap().is_ok());
+
+    let return_data = simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .expect("CanTransfer sets return data");
+    TransferCheck::from_return_data(&return_data.data).expect("Result code")
+}
@@ -0,0 +1,163 @@
+use security_token_client::{
+    errors::SecurityTokenProgramError,
+    instructions::{FreezeBuilder, FREEZE_DISCRIMINATOR, TRANSFER_DISCRIMINATOR},
+};
+use solana_program::program_error::ProgramError;
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    can_transfer_tests::can_transfer_helpers::simulate_can_transfer,
+    claim_tests::claim_helpers::start_with_context_and_transfer_hook,
+    helpers::{
+        assert_transaction_success, create_dummy_verification_from_instruction,
+        create_minimal_security_token_mint, create_spl_account, create_verification_config,
+        find_mint_freeze_authority_pda, get_default_verification_programs, get_token_account_state,
+        initialize_mint_verification_and_mint_to_account, send_tx,
+    },
+};
+
+const SUPPLY: u64 = 1_000_000;
+
+struct Setup {
+    mint: Pubkey,
+    holder_account: Pubkey,
+    recipient_account: Pubkey,
+    transfer_verification_config: Pubkey,
+    freeze_verification_config: Pubkey,
+}
+
+async fn setup_mint(context: &mut ProgramTestContext) -> Setup {
+    let mint_keypair = Keypair::new();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, None, 6).await;
+    let mut verification_configs = vec![];
+    for discriminator in [TRANSFER_DISCRIMINATOR, FREEZE_DISCRIMINATOR] {
+        let verification_config = create_verification_config(
+            context,
+            &mint_keypair,
+            mint_authority_pda,
+            discriminator,
+            get_default_verification_programs(),
+            None,
+        )
+        .await;
+        verification_configs.push(verification_config);
+    }
+    let holder_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+    let recipient_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+    initialize_mint_verification_and_mint_to_account(
+        &mint_keypair,
+        context,
+        mint_authority_pda,
+        holder_account,
+        SUPPLY,
+    )
+    .await;
+    Setup {
+        mint: mint_keypair.pubkey(),
+        holder_account,
+        recipient_account,
+        transfer_verification_config: verification_configs[0],
+        freeze_verification_config: verification_configs[1],
+    }
+}
+
+#[tokio::test]
+async fn test_should_pass_transfer_check_without_moving_tokens() {
+    let mut context = start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(&mut context).await;
+
+    let check = simulate_can_transfer(
+        &mut context,
+        setup.mint,
+        setup.transfer_verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        SUPPLY,
+        true,
+    )
+    .await;
+    assert!(check.passed());
+
+    let holder = get_token_account_state(&mut context.banks_client, setup.holder_account).await;
+    assert_eq!(holder.base.amount, SUPPLY);
+}
+
+#[tokio::test]
+async fn test_should_report_missing_verification() {
+    let mut context = start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(&mut context).await;
+
+    let check = simulate_can_transfer(
+        &mut context,
+        setup.mint,
+        setup.transfer_verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        1,
+        false,
+    )
+    .await;
+    assert_eq!(
+        check.error(),
+        Some(SecurityTokenProgramError::VerificationProgramNotFound)
+    );
+}
+
+#[tokio::test]
+async fn test_should_report_insufficient_balance() {
+    let mut context = start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(&mut context).await;
+
+    let check = simulate_can_transfer(
+        &mut context,
+        setup.mint,
+        setup.transfer_verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        SUPPLY + 1,
+        true,
+    )
+    .await;
+    assert_eq!(check.reason, u64::from(ProgramError::InsufficientFunds));
+}
+
+#[tokio::test]
+async fn test_should_report_frozen_recipient() {
+    let mut context = start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(&mut context).await;
+
+    let freeze_ix = FreezeBuilder::new()
+        .mint(setup.mint)
+        .verification_config(setup.freeze_verification_config)
+        .mint_account(setup.mint)
+        .freeze_authority(find_mint_freeze_authority_pda(&setup.mint).0)
+        .token_account(setup.recipient_account)
+        .instruction();
+    let dummy_freeze_ix = create_dummy_verification_from_instruction(&freeze_ix);
+    let result = send_tx(
+        &context.banks_client,
+        vec![dummy_freeze_ix, freeze_ix],
+        &context.payer.pubkey(),
+        vec![&context.payer],
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let check = simulate_can_transfer(
+        &mut context,
+        setup.mint,
+        setup.transfer_verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        1,
+        true,
+    )
+    .await;
+    assert_eq!(
+        check.error(),
+        Some(SecurityTokenProgramError::AccountFrozen)
+    );
+}
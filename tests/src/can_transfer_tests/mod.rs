@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod can_transfer_tests;
+
+pub mod can_transfer_helpers;
@@ -0,0 +1,202 @@
+use security_token_client::{
+    accounts::ClaimBitmap, claim_tracking::is_leaf_claimed, errors::SecurityTokenProgramError,
+    pda::find_claim_bitmap_pda, types::ClaimDistributionArgs,
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_merkle_tree_reference::MerkleTree;
+
+use crate::{
+    claim_tests::{
+        claim_helpers::{
+            create_distribution_with_claim_bitmap_leaves, create_leaves,
+            execute_claim_distribution, start_with_context_and_transfer_hook,
+        },
+        merkle_tree_helpers::Leaf,
+    },
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        get_token_account_state,
+    },
+    receipt_tests::receipt_helpers::find_claim_action_receipt_pda,
+};
+
+const DECIMALS: u8 = 6;
+const ACTION_ID: u64 = 21;
+
+struct BitmapDistribution {
+    mint: Pubkey,
+    mint_creator: Keypair,
+    holder_accounts: Vec<Pubkey>,
+    leaves: Vec<Leaf>,
+    merkle_tree: MerkleTree,
+    permanent_delegate: Pubkey,
+    escrow_token_account: Pubkey,
+    claim_verification_config: Pubkey,
+    claim_bitmap: Pubkey,
+}
+
+/// Funded distribution of 100, 50 and 25 base units to three holders tracked in a ClaimBitmap
+async fn setup_bitmap_distribution(context: &mut ProgramTestContext) -> BitmapDistribution {
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let mint_creator = context.payer.insecure_clone();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), DECIMALS)
+            .await;
+    let mut holder_accounts = Vec::new();
+    for _ in 0..3 {
+        holder_accounts.push(create_spl_account(context, &mint_keypair, &Keypair::new()).await);
+    }
+
+    let leaves = create_leaves(
+        &[
+            (&holder_accounts[0], 100u64),
+            (&holder_accounts[1], 50u64),
+            (&holder_accounts[2], 25u64),
+        ],
+        &mint,
+        DECIMALS,
+        ACTION_ID,
+    );
+    let (merkle_tree, permanent_delegate, escrow_token_account, claim_verification_config) =
+        create_distribution_with_claim_bitmap_leaves(
+            context,
+            &mint_keypair,
+            mint_authority_pda,
+            &mint_creator,
+            ACTION_ID,
+            175,
+            DECIMALS,
+            &leaves,
+            leaves.len() as u32,
+        )
+        .await;
+    let (claim_bitmap, _) = find_claim_bitmap_pda(&mint, ACTION_ID, &merkle_tree.get_root(), 0);
+
+    BitmapDistribution {
+        mint,
+        mint_creator,
+        holder_accounts,
+        leaves,
+        merkle_tree,
+        permanent_delegate,
+        escrow_token_account,
+        claim_verification_config,
+        claim_bitmap,
+    }
+}
+
+async fn claim(
+    context: &mut ProgramTestContext,
+    distribution: &BitmapDistribution,
+    leaf_index: usize,
+    receipt_account: Pubkey,
+) -> Result<(), BanksClientError> {
+    execute_claim_distribution(
+        &mut context.banks_client,
+        distribution.mint,
+        distribution.claim_verification_config,
+        distribution.permanent_delegate,
+        distribution.mint,
+        distribution.holder_accounts[leaf_index],
+        Some(distribution.escrow_token_account),
+        receipt_account,
+        None,
+        ClaimDistributionArgs {
+            action_id: ACTION_ID,
+            amount: distribution.leaves[leaf_index].amount,
+            merkle_root: distribution.merkle_tree.get_root(),
+            leaf_index: leaf_index as u32,
+            merkle_proof: Some(distribution.merkle_tree.get_proof_of_leaf(leaf_index)),
+        },
+        &distribution.mint_creator,
+    )
+    .await
+}
+
+async fn claim_bitmap(context: &mut ProgramTestContext, claim_bitmap_pda: Pubkey) -> ClaimBitmap {
+    let account = assert_account_exists(context, claim_bitmap_pda, true)
+        .await
+        .unwrap();
+    ClaimBitmap::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn test_should_create_claim_bitmap_with_escrow() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let distribution = setup_bitmap_distribution(context).await;
+
+    let page = claim_bitmap(context, distribution.claim_bitmap).await;
+    assert_eq!(page.mint, distribution.mint);
+    assert_eq!(page.action_id, ACTION_ID);
+    assert_eq!(page.merkle_root, distribution.merkle_tree.get_root());
+    assert_eq!(page.page, 0);
+    assert_eq!(page.leaf_count, 3);
+    assert_eq!(page.bits, vec![0]);
+}
+
+#[tokio::test]
+async fn test_should_track_claims_in_claim_bitmap() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let distribution = setup_bitmap_distribution(context).await;
+
+    let result = claim(context, &distribution, 1, distribution.claim_bitmap).await;
+    assert_transaction_success(result);
+
+    let holder =
+        get_token_account_state(&mut context.banks_client, distribution.holder_accounts[1]).await;
+    assert_eq!(holder.base.amount, distribution.leaves[1].amount);
+
+    let page = claim_bitmap(context, distribution.claim_bitmap).await;
+    assert!(!is_leaf_claimed(&page, 0));
+    assert!(is_leaf_claimed(&page, 1));
+    assert!(!is_leaf_claimed(&page, 2));
+
+    // No per-claim Receipt is issued in bitmap mode
+    let (receipt_account, _) = find_claim_action_receipt_pda(
+        &distribution.mint,
+        &distribution.holder_accounts[1],
+        ACTION_ID,
+        &distribution.merkle_tree.get_proof_of_leaf(1),
+    );
+    assert_account_exists(context, receipt_account, false).await;
+}
+
+#[tokio::test]
+async fn test_should_not_claim_leaf_twice_with_claim_bitmap() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let distribution = setup_bitmap_distribution(context).await;
+
+    let result = claim(context, &distribution, 0, distribution.claim_bitmap).await;
+    assert_transaction_success(result);
+
+    context.get_new_latest_blockhash().await.unwrap();
+    let result = claim(context, &distribution, 0, distribution.claim_bitmap).await;
+    assert_security_token_error(result, SecurityTokenProgramError::LeafAlreadyClaimed);
+
+    let holder =
+        get_token_account_state(&mut context.banks_client, distribution.holder_accounts[0]).await;
+    assert_eq!(holder.base.amount, distribution.leaves[0].amount);
+}
+
+#[tokio::test]
+async fn test_should_not_issue_receipt_for_claim_bitmap_distribution() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let distribution = setup_bitmap_distribution(context).await;
+
+    let (receipt_account, _) = find_claim_action_receipt_pda(
+        &distribution.mint,
+        &distribution.holder_accounts[2],
+        ACTION_ID,
+        &distribution.merkle_tree.get_proof_of_leaf(2),
+    );
+    let result = claim(context, &distribution, 2, receipt_account).await;
+    assert_transaction_failure(result);
+
+    assert_account_exists(context, receipt_account, false).await;
+    let page = claim_bitmap(context, distribution.claim_bitmap).await;
+    assert!(!is_leaf_claimed(&page, 2));
+}
@@ -0,0 +1,2 @@
+#[cfg(test)]
+pub mod claim_bitmap_tests;
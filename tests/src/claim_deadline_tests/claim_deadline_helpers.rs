@@ -0,0 +1,85 @@
+use security_token_client::{
+    instructions::{
+        CreateClaimDeadline, CreateClaimDeadlineInstructionArgs, ReclaimUnclaimed,
+        ReclaimUnclaimedInstructionArgs,
+    },
+    pda::find_claim_deadline_pda,
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{find_mint_authority_pda, find_permanent_delegate_pda, send_tx};
+
+/// Build and send CreateClaimDeadline instruction authorized by mint authority
+pub async fn execute_create_claim_deadline(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    args: CreateClaimDeadlineInstructionArgs,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (claim_deadline_account, _) = find_claim_deadline_pda(&mint, args.action_id);
+
+    let ix = CreateClaimDeadline {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        claim_deadline_account,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(args);
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send ReclaimUnclaimed instruction draining the distribution escrow
+/// into the issuer token account, authorized by mint authority
+pub async fn execute_reclaim_unclaimed(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    escrow_token_account: Pubkey,
+    destination_token_account: Pubkey,
+    args: ReclaimUnclaimedInstructionArgs,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (permanent_delegate_authority, _) = find_permanent_delegate_pda(&mint);
+    let (claim_deadline_account, _) = find_claim_deadline_pda(&mint, args.action_id);
+
+    let ix = ReclaimUnclaimed {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        permanent_delegate_authority,
+        mint_account: mint,
+        claim_deadline_account,
+        escrow_token_account: Some(escrow_token_account),
+        destination_token_account: Some(destination_token_account),
+        transfer_hook_program: Pubkey::from(security_token_transfer_hook::id()),
+        token_program: TOKEN_22_PROGRAM_ID,
+        distribution_payout_account: None,
+        payout_mint: None,
+        payout_escrow_token_account: None,
+        payout_destination_token_account: None,
+        payout_token_program: None,
+    }
+    .instruction(args);
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
@@ -0,0 +1,221 @@
+use security_token_client::{
+    accounts::ClaimDeadline,
+    errors::SecurityTokenProgramError,
+    instructions::{CreateClaimDeadlineInstructionArgs, ReclaimUnclaimedInstructionArgs},
+    pda::find_claim_deadline_pda,
+    types::ClaimDistributionArgs,
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_merkle_tree_reference::MerkleTree;
+
+use crate::{
+    claim_deadline_tests::claim_deadline_helpers::{
+        execute_create_claim_deadline, execute_reclaim_unclaimed,
+    },
+    claim_tests::{
+        claim_helpers::{
+            create_distribution_for_users, create_leaves, execute_claim_distribution,
+            start_with_context_and_transfer_hook,
+        },
+        merkle_tree_helpers::Leaf,
+    },
+    coupon_tests::coupon_helpers::{current_timestamp, warp_to_timestamp},
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        get_token_account_state,
+    },
+    receipt_tests::receipt_helpers::find_claim_action_receipt_pda,
+};
+
+const DECIMALS: u8 = 6;
+const ACTION_ID: u64 = 11;
+const CLAIM_WINDOW: i64 = 86_400;
+
+struct DeadlineDistribution {
+    mint: Pubkey,
+    mint_creator: Keypair,
+    issuer_account: Pubkey,
+    holder_accounts: Vec<Pubkey>,
+    leaves: Vec<Leaf>,
+    merkle_tree: MerkleTree,
+    permanent_delegate: Pubkey,
+    escrow_token_account: Pubkey,
+    claim_verification_config: Pubkey,
+    claim_deadline: i64,
+}
+
+/// Funded distribution of 100 and 50 base units to two holders, claimable for one day
+async fn setup_deadline_distribution(context: &mut ProgramTestContext) -> DeadlineDistribution {
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let mint_creator = context.payer.insecure_clone();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), DECIMALS)
+            .await;
+    let issuer_account = create_spl_account(context, &mint_keypair, &mint_creator).await;
+    let mut holder_accounts = Vec::new();
+    for _ in 0..2 {
+        holder_accounts.push(create_spl_account(context, &mint_keypair, &Keypair::new()).await);
+    }
+
+    let leaves = create_leaves(
+        &[(&holder_accounts[0], 100u64), (&holder_accounts[1], 50u64)],
+        &mint,
+        DECIMALS,
+        ACTION_ID,
+    );
+    let (merkle_tree, permanent_delegate, escrow_token_account, claim_verification_config) =
+        create_distribution_for_users(
+            context,
+            &mint_keypair,
+            mint_authority_pda,
+            &mint_creator,
+            ACTION_ID,
+            150,
+            DECIMALS,
+            &leaves,
+        )
+        .await;
+
+    let claim_deadline = current_timestamp(context).await + CLAIM_WINDOW;
+    let result = execute_create_claim_deadline(
+        &context.banks_client,
+        mint,
+        CreateClaimDeadlineInstructionArgs {
+            action_id: ACTION_ID,
+            claim_deadline,
+            claims_open_at: claim_deadline - CLAIM_WINDOW,
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (claim_deadline_pda, _) = find_claim_deadline_pda(&mint, ACTION_ID);
+    let account = assert_account_exists(context, claim_deadline_pda, true)
+        .await
+        .unwrap();
+    let state = ClaimDeadline::from_bytes(&account.data).unwrap();
+    assert_eq!(state.claim_deadline, claim_deadline);
+
+    DeadlineDistribution {
+        mint,
+        mint_creator,
+        issuer_account,
+        holder_accounts,
+        leaves,
+        merkle_tree,
+        permanent_delegate,
+        escrow_token_account,
+        claim_verification_config,
+        claim_deadline,
+    }
+}
+
+async fn claim(
+    context: &mut ProgramTestContext,
+    distribution: &DeadlineDistribution,
+    leaf_index: usize,
+) -> Result<(), BanksClientError> {
+    let holder_account = distribution.holder_accounts[leaf_index];
+    let merkle_proof = distribution.merkle_tree.get_proof_of_leaf(leaf_index);
+    let (receipt_account, _) = find_claim_action_receipt_pda(
+        &distribution.mint,
+        &holder_account,
+        ACTION_ID,
+        &merkle_proof,
+    );
+
+    execute_claim_distribution(
+        &mut context.banks_client,
+        distribution.mint,
+        distribution.claim_verification_config,
+        distribution.permanent_delegate,
+        distribution.mint,
+        holder_account,
+        Some(distribution.escrow_token_account),
+        receipt_account,
+        None,
+        ClaimDistributionArgs {
+            action_id: ACTION_ID,
+            amount: distribution.leaves[leaf_index].amount,
+            merkle_root: distribution.merkle_tree.get_root(),
+            leaf_index: leaf_index as u32,
+            merkle_proof: Some(merkle_proof),
+        },
+        &distribution.mint_creator,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_should_reclaim_unclaimed_remainder_after_claim_deadline() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let distribution = setup_deadline_distribution(context).await;
+
+    assert_transaction_success(claim(context, &distribution, 0).await);
+
+    warp_to_timestamp(context, distribution.claim_deadline + 1).await;
+    let result = claim(context, &distribution, 1).await;
+    assert_security_token_error(result, SecurityTokenProgramError::ClaimDeadlinePassed);
+
+    let result = execute_reclaim_unclaimed(
+        &context.banks_client,
+        distribution.mint,
+        distribution.escrow_token_account,
+        distribution.issuer_account,
+        ReclaimUnclaimedInstructionArgs {
+            action_id: ACTION_ID,
+            merkle_root: distribution.merkle_tree.get_root(),
+        },
+        &distribution.mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let escrow =
+        get_token_account_state(&mut context.banks_client, distribution.escrow_token_account).await;
+    assert_eq!(escrow.base.amount, 0);
+    let issuer =
+        get_token_account_state(&mut context.banks_client, distribution.issuer_account).await;
+    assert_eq!(issuer.base.amount, distribution.leaves[1].amount);
+}
+
+#[tokio::test]
+async fn test_should_not_reclaim_unclaimed_before_claim_deadline() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let distribution = setup_deadline_distribution(context).await;
+
+    let result = execute_reclaim_unclaimed(
+        &context.banks_client,
+        distribution.mint,
+        distribution.escrow_token_account,
+        distribution.issuer_account,
+        ReclaimUnclaimedInstructionArgs {
+            action_id: ACTION_ID,
+            merkle_root: distribution.merkle_tree.get_root(),
+        },
+        &distribution.mint_creator,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::ClaimWindowOpen);
+
+    // The claim deadline is immutable once published
+    let result = execute_create_claim_deadline(
+        &context.banks_client,
+        distribution.mint,
+        CreateClaimDeadlineInstructionArgs {
+            action_id: ACTION_ID,
+            claim_deadline: distribution.claim_deadline - 1,
+            claims_open_at: 0,
+        },
+        &distribution.mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    assert_transaction_success(claim(context, &distribution, 1).await);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod claim_deadline_tests;
+
+pub mod claim_deadline_helpers;
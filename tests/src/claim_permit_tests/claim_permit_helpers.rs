@@ -0,0 +1,92 @@
+use security_token_client::{
+    claim_tracking::claim_bitmap_page,
+    instructions::{ClaimDistribution, ClaimDistributionInstructionArgs},
+    pda::{find_claim_bitmap_pda, find_claim_deadline_pda, find_distribution_root_pda},
+    permit::{claim_permit_message, ed25519_permit_instruction},
+    types::ClaimDistributionArgs,
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::{signature::Keypair, signer::Signer, sysvar};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{create_dummy_verification_from_instruction, send_tx};
+
+/// Build and send ClaimDistribution executed by `custodian` with a claim permit signed by
+/// `permit_signer`, preceded by the Ed25519 instruction verifying the permit
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_claim_distribution_with_permit(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    verification_config: Pubkey,
+    permanent_delegate_authority: Pubkey,
+    eligible_token_account: Pubkey,
+    escrow_token_account: Pubkey,
+    receipt_account: Pubkey,
+    claim_distribution_args: ClaimDistributionArgs,
+    permit_signer: &Keypair,
+    custodian: &Keypair,
+) -> Result<(), BanksClientError> {
+    let message = claim_permit_message(
+        &mint,
+        claim_distribution_args.action_id,
+        &eligible_token_account,
+        &custodian.pubkey(),
+    );
+    let signature = permit_signer.sign_message(&message);
+    let permit_ix =
+        ed25519_permit_instruction(&permit_signer.pubkey(), signature.as_array(), &message);
+
+    let ix = ClaimDistribution {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        // ix accounts
+        payer: custodian.pubkey(),
+        permanent_delegate_authority,
+        mint_account: mint,
+        eligible_token_account,
+        escrow_token_account: Some(escrow_token_account),
+        receipt_account,
+        proof_account: None,
+        transfer_hook_program: Pubkey::from(security_token_transfer_hook::id()),
+        token_program: TOKEN_22_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+        withholding_rate_account: None,
+        tax_escrow_token_account: None,
+        distribution_payout_account: None,
+        payout_mint: None,
+        payout_escrow_token_account: None,
+        payout_token_account: None,
+        payout_token_program: None,
+        custodian: Some(custodian.pubkey()),
+        instructions_sysvar_account: Some(sysvar::instructions::ID),
+        claim_deadline_account: find_claim_deadline_pda(&mint, claim_distribution_args.action_id).0,
+        distribution_root_account: find_distribution_root_pda(
+            &mint,
+            claim_distribution_args.action_id,
+            &claim_distribution_args.merkle_root,
+        )
+        .0,
+        claim_bitmap_account: find_claim_bitmap_pda(
+            &mint,
+            claim_distribution_args.action_id,
+            &claim_distribution_args.merkle_root,
+            claim_bitmap_page(claim_distribution_args.leaf_index as usize),
+        )
+        .0,
+    }
+    .instruction(ClaimDistributionInstructionArgs {
+        claim_distribution_args,
+    });
+
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![permit_ix, dummy_ix, ix],
+        &custodian.pubkey(),
+        vec![custodian],
+    )
+    .await
+}
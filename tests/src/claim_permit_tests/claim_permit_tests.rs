@@ -0,0 +1,136 @@
+use security_token_client::{errors::SecurityTokenProgramError, types::ClaimDistributionArgs};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_merkle_tree_reference::MerkleTree;
+
+use crate::{
+    claim_permit_tests::claim_permit_helpers::execute_claim_distribution_with_permit,
+    claim_tests::{
+        claim_helpers::{
+            create_distribution_for_users, create_leaves, start_with_context_and_transfer_hook,
+        },
+        merkle_tree_helpers::Leaf,
+    },
+    helpers::{
+        assert_security_token_error, assert_transaction_success,
+        create_minimal_security_token_mint, create_spl_account, get_token_account_state,
+    },
+    receipt_tests::receipt_helpers::find_claim_action_receipt_pda,
+};
+
+const DECIMALS: u8 = 6;
+const ACTION_ID: u64 = 13;
+
+struct CustodiedDistribution {
+    mint: Pubkey,
+    custodian: Keypair,
+    holder: Keypair,
+    holder_account: Pubkey,
+    leaves: Vec<Leaf>,
+    merkle_tree: MerkleTree,
+    permanent_delegate: Pubkey,
+    escrow_token_account: Pubkey,
+    claim_verification_config: Pubkey,
+}
+
+/// Funded distribution of 100 tokens to a holder whose wallet never signs transactions
+async fn setup_custodied_distribution(context: &mut ProgramTestContext) -> CustodiedDistribution {
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let custodian = context.payer.insecure_clone();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&custodian), DECIMALS)
+            .await;
+
+    let holder = Keypair::new();
+    let holder_account = create_spl_account(context, &mint_keypair, &holder).await;
+    let other_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+
+    let leaves = create_leaves(
+        &[(&holder_account, 100u64), (&other_account, 50u64)],
+        &mint,
+        DECIMALS,
+        ACTION_ID,
+    );
+    let (merkle_tree, permanent_delegate, escrow_token_account, claim_verification_config) =
+        create_distribution_for_users(
+            context,
+            &mint_keypair,
+            mint_authority_pda,
+            &custodian,
+            ACTION_ID,
+            150,
+            DECIMALS,
+            &leaves,
+        )
+        .await;
+
+    CustodiedDistribution {
+        mint,
+        custodian,
+        holder,
+        holder_account,
+        leaves,
+        merkle_tree,
+        permanent_delegate,
+        escrow_token_account,
+        claim_verification_config,
+    }
+}
+
+async fn claim_with_permit(
+    context: &mut ProgramTestContext,
+    distribution: &CustodiedDistribution,
+    permit_signer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let merkle_proof = distribution.merkle_tree.get_proof_of_leaf(0);
+    let (receipt_account, _) = find_claim_action_receipt_pda(
+        &distribution.mint,
+        &distribution.holder_account,
+        ACTION_ID,
+        &merkle_proof,
+    );
+
+    execute_claim_distribution_with_permit(
+        &context.banks_client,
+        distribution.mint,
+        distribution.claim_verification_config,
+        distribution.permanent_delegate,
+        distribution.holder_account,
+        distribution.escrow_token_account,
+        receipt_account,
+        ClaimDistributionArgs {
+            action_id: ACTION_ID,
+            amount: distribution.leaves[0].amount,
+            merkle_root: distribution.merkle_tree.get_root(),
+            leaf_index: 0,
+            merkle_proof: Some(merkle_proof),
+        },
+        permit_signer,
+        &distribution.custodian,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_should_claim_distribution_by_custodian_with_holder_permit() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let distribution = setup_custodied_distribution(context).await;
+
+    let result = claim_with_permit(context, &distribution, &distribution.holder).await;
+    assert_transaction_success(result);
+
+    let holder =
+        get_token_account_state(&mut context.banks_client, distribution.holder_account).await;
+    assert_eq!(holder.base.amount, distribution.leaves[0].amount);
+}
+
+#[tokio::test]
+async fn test_should_not_claim_distribution_with_permit_of_other_wallet() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let distribution = setup_custodied_distribution(context).await;
+
+    let result = claim_with_permit(context, &distribution, &Keypair::new()).await;
+    assert_security_token_error(result, SecurityTokenProgramError::InvalidPermit);
+}
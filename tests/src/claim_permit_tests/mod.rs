@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod claim_permit_tests;
+
+pub mod claim_permit_helpers;
@@ -1,13 +1,15 @@
 use security_token_client::{
+    claim_tracking::{claim_bitmap_page, CLAIM_BITMAP_PAGE_LEAVES},
     instructions::{
         ClaimDistribution, ClaimDistributionInstructionArgs, CreateDistributionEscrow,
         CreateDistributionEscrowInstructionArgs, CLAIM_DISTRIBUTION_DISCRIMINATOR,
     },
-    programs::SECURITY_TOKEN_PROGRAM_ID,
+    pda::{self, find_claim_bitmap_pda, find_claim_deadline_pda, find_distribution_root_pda},
     types::{ClaimDistributionArgs, CreateDistributionEscrowArgs},
 };
 use solana_program_test::{BanksClient, BanksClientError, ProgramTestContext};
 use solana_pubkey::Pubkey;
+use solana_sdk::instruction::AccountMeta;
 use solana_sdk::{signature::Keypair, signer::Signer, sysvar};
 use spl_associated_token_account::{
     get_associated_token_address_with_program_id, ID as ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -39,6 +41,27 @@ pub async fn execute_create_distribution_escrow_account(
 ) -> Result<(), BanksClientError> {
     let payer_pubkey = payer.pubkey();
 
+    // ClaimBitmap pages: the first one is the claim_bitmap_account, the rest are remaining accounts
+    let page_count = create_distribution_escrow_args
+        .claim_bitmap_leaves
+        .div_ceil(CLAIM_BITMAP_PAGE_LEAVES);
+    let claim_bitmap_pages: Vec<Pubkey> = (0..page_count)
+        .map(|page| {
+            find_claim_bitmap_pda(
+                &security_token_mint,
+                create_distribution_escrow_args.action_id,
+                &create_distribution_escrow_args.merkle_root,
+                page,
+            )
+            .0
+        })
+        .collect();
+    let remaining_accounts: Vec<AccountMeta> = claim_bitmap_pages
+        .iter()
+        .skip(1)
+        .map(|page| AccountMeta::new(*page, false))
+        .collect();
+
     let ix = CreateDistributionEscrow {
         mint: security_token_mint,
         verification_config_or_mint_authority,
@@ -50,10 +73,14 @@ pub async fn execute_create_distribution_escrow_account(
         token_program: TOKEN_22_PROGRAM_ID,
         associated_token_account_program: ASSOCIATED_TOKEN_PROGRAM_ID,
         system_program: solana_program::system_program::id(),
+        claim_bitmap_account: claim_bitmap_pages.first().copied(),
     }
-    .instruction(CreateDistributionEscrowInstructionArgs {
-        create_distribution_escrow_args,
-    });
+    .instruction_with_remaining_accounts(
+        CreateDistributionEscrowInstructionArgs {
+            create_distribution_escrow_args,
+        },
+        &remaining_accounts,
+    );
 
     let dummy_ix = create_dummy_verification_from_instruction(&ix);
 
@@ -96,6 +123,33 @@ pub async fn execute_claim_distribution(
         transfer_hook_program: Pubkey::from(security_token_transfer_hook::id()),
         token_program: TOKEN_22_PROGRAM_ID,
         system_program: solana_program::system_program::id(),
+        withholding_rate_account: None,
+        tax_escrow_token_account: None,
+        distribution_payout_account: None,
+        payout_mint: None,
+        payout_escrow_token_account: None,
+        payout_token_account: None,
+        payout_token_program: None,
+        custodian: None,
+        instructions_sysvar_account: None,
+        claim_deadline_account: find_claim_deadline_pda(
+            &distribution_mint,
+            claim_distribution_args.action_id,
+        )
+        .0,
+        distribution_root_account: find_distribution_root_pda(
+            &distribution_mint,
+            claim_distribution_args.action_id,
+            &claim_distribution_args.merkle_root,
+        )
+        .0,
+        claim_bitmap_account: find_claim_bitmap_pda(
+            &distribution_mint,
+            claim_distribution_args.action_id,
+            &claim_distribution_args.merkle_root,
+            claim_bitmap_page(claim_distribution_args.leaf_index as usize),
+        )
+        .0,
     }
     .instruction(ClaimDistributionInstructionArgs {
         claim_distribution_args,
@@ -117,15 +171,7 @@ pub fn find_distribution_escrow_authority_pda(
     action_id: u64,
     merkle_root: &[u8; 32],
 ) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[
-            b"distribution_escrow_authority",
-            mint.as_ref(),
-            action_id.to_le_bytes().as_ref(),
-            merkle_root.as_ref(),
-        ],
-        &SECURITY_TOKEN_PROGRAM_ID,
-    )
+    pda::find_distribution_escrow_authority_pda(mint, action_id, merkle_root)
 }
 
 pub async fn create_claim_distribution_verification_config(
@@ -155,6 +201,32 @@ pub async fn create_distribution_for_users(
     distribution_ui_amount: u64,
     decimals: u8,
     leaves: &[Leaf],
+) -> (MerkleTree, Pubkey, Pubkey, Pubkey) {
+    create_distribution_with_claim_bitmap_leaves(
+        context,
+        distribution_mint_keypair,
+        mint_authority_pda,
+        mint_creator,
+        action_id,
+        distribution_ui_amount,
+        decimals,
+        leaves,
+        0,
+    )
+    .await
+}
+
+/// Same as `create_distribution_for_users`, tracking `claim_bitmap_leaves` leaves in ClaimBitmap pages
+pub async fn create_distribution_with_claim_bitmap_leaves(
+    context: &mut ProgramTestContext,
+    distribution_mint_keypair: &Keypair,
+    mint_authority_pda: Pubkey,
+    mint_creator: &Keypair,
+    action_id: u64,
+    distribution_ui_amount: u64,
+    decimals: u8,
+    leaves: &[Leaf],
+    claim_bitmap_leaves: u32,
 ) -> (MerkleTree, Pubkey, Pubkey, Pubkey) {
     let distribution_mint = &distribution_mint_keypair.pubkey();
     let tree = create_merkle_tree(&leaves);
@@ -172,6 +244,7 @@ pub async fn create_distribution_for_users(
     let create_distribution_escrow_args = CreateDistributionEscrowArgs {
         action_id,
         merkle_root,
+        claim_bitmap_leaves,
     };
 
     let result = execute_create_distribution_escrow_account(
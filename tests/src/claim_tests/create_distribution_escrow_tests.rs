@@ -50,6 +50,7 @@ async fn test_should_create_distribution_account() {
     let create_distribution_escrow_args = CreateDistributionEscrowArgs {
         action_id,
         merkle_root,
+        claim_bitmap_leaves: 0,
     };
 
     let result = execute_create_distribution_escrow_account(
@@ -109,6 +110,7 @@ async fn test_should_not_create_distribution_account_twice() {
     let create_distribution_escrow_args = CreateDistributionEscrowArgs {
         action_id,
         merkle_root,
+        claim_bitmap_leaves: 0,
     };
 
     let result = execute_create_distribution_escrow_account(
@@ -206,6 +208,7 @@ async fn test_should_not_create_distribution_account(
     let create_distribution_escrow_args = CreateDistributionEscrowArgs {
         action_id,
         merkle_root,
+        claim_bitmap_leaves: 0,
     };
 
     let result = execute_create_distribution_escrow_account(
@@ -0,0 +1,34 @@
+use security_token_client::instructions::CloseMintAuthority;
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::helpers::{find_mint_authority_pda, send_tx};
+
+/// Build and send CloseMintAuthority instruction authorized by mint authority
+pub async fn execute_close_mint_authority(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    rent_recipient: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+
+    let ix = CloseMintAuthority {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        mint_account: mint,
+        mint_authority_account: mint_authority,
+        rent_recipient,
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
@@ -0,0 +1,82 @@
+use security_token_client::errors::SecurityTokenProgramError;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    close_mint_authority_tests::close_mint_authority_helpers::execute_close_mint_authority,
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        get_account, initialize_mint_verification_and_mint_to_account,
+        start_with_context_and_accounts,
+    },
+};
+
+#[tokio::test]
+async fn test_should_close_mint_authority_of_mint_without_supply() {
+    let rent_recipient = Keypair::new();
+    let context =
+        &mut start_with_context_and_accounts(vec![(&rent_recipient, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+    let mint_authority_lamports = get_account(context, mint_authority_pda)
+        .await
+        .unwrap()
+        .lamports;
+
+    let result = execute_close_mint_authority(
+        &context.banks_client,
+        mint,
+        rent_recipient.pubkey(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_account_exists(context, mint_authority_pda, false).await;
+    let recipient = get_account(context, rent_recipient.pubkey()).await.unwrap();
+    assert_eq!(recipient.lamports, 1_000_000_000 + mint_authority_lamports);
+
+    // Without a MintAuthority the creator no longer authorizes anything
+    let result = execute_close_mint_authority(
+        &context.banks_client,
+        mint,
+        rent_recipient.pubkey(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_not_close_mint_authority_with_supply() {
+    let holder = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+    let token_account = create_spl_account(context, &mint_keypair, &holder).await;
+    initialize_mint_verification_and_mint_to_account(
+        &mint_keypair,
+        context,
+        mint_authority_pda,
+        token_account,
+        1_000,
+    )
+    .await;
+
+    let result = execute_close_mint_authority(
+        &context.banks_client,
+        mint,
+        Keypair::new().pubkey(),
+        &mint_creator,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::MintSupplyNotZero);
+    assert_account_exists(context, mint_authority_pda, true).await;
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod close_mint_authority_tests;
+
+pub mod close_mint_authority_helpers;
@@ -0,0 +1,23 @@
+use security_token_client::{
+    instructions::AttestCollateralBuilder,
+    pda::{find_collateral_attestation_pda, find_suspension_pda},
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::{instruction::Instruction, signer::Signer};
+
+/// AttestCollateral instruction of `token_account` paid by the context payer, without the
+/// holding period accounts
+pub fn attest_collateral_ix(
+    context: &ProgramTestContext,
+    mint: Pubkey,
+    token_account: Pubkey,
+) -> Instruction {
+    AttestCollateralBuilder::new()
+        .payer(context.payer.pubkey())
+        .mint(mint)
+        .token_account(token_account)
+        .suspension_account(find_suspension_pda(&token_account).0)
+        .collateral_attestation_account(find_collateral_attestation_pda(&token_account).0)
+        .instruction()
+}
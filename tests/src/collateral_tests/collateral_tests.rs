@@ -0,0 +1,104 @@
+use security_token_client::{
+    accounts::CollateralAttestation, pda::find_collateral_attestation_pda,
+};
+use solana_sdk::signature::Signer;
+
+use crate::{
+    collateral_tests::collateral_helpers::attest_collateral_ix,
+    fixtures::fixture_helpers::{Fixture, HOLDER_AMOUNT},
+    helpers::{
+        assert_transaction_failure, assert_transaction_success, get_account, mint_tokens_to,
+        send_tx,
+    },
+    view_tests::view_helpers::simulate_view,
+};
+use security_token_client::instructions::MINT_DISCRIMINATOR;
+
+async fn fetch_attestation(fixture: &mut Fixture, index: usize) -> CollateralAttestation {
+    let token_account = fixture.holders[index].token_account;
+    let account = get_account(
+        &mut fixture.context,
+        find_collateral_attestation_pda(&token_account).0,
+    )
+    .await
+    .expect("CollateralAttestation should exist");
+    CollateralAttestation::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn test_should_attest_free_balance_and_refresh() {
+    let mut fixture = Fixture::new(11).await;
+    let mint = fixture.mint.pubkey();
+    let holder = fixture.holders[0].token_account;
+    let owner = fixture.holders[0].owner.pubkey();
+
+    let ix = attest_collateral_ix(&fixture.context, mint, holder);
+    let payer = fixture.context.payer.insecure_clone();
+    let result = send_tx(
+        &fixture.context.banks_client,
+        vec![ix.clone()],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let attestation = fetch_attestation(&mut fixture, 0).await;
+    assert_eq!(attestation.mint, mint);
+    assert_eq!(attestation.token_account, holder);
+    assert_eq!(attestation.owner, owner);
+    assert_eq!(attestation.balance, HOLDER_AMOUNT);
+    assert_eq!(attestation.locked_amount, 0);
+    assert_eq!(attestation.free_balance, HOLDER_AMOUNT);
+    assert!(!attestation.frozen && !attestation.suspended && !attestation.paused);
+
+    let result = mint_tokens_to(
+        &fixture.context.banks_client,
+        500,
+        mint,
+        holder,
+        fixture.mint_authority,
+        fixture.verification_config(MINT_DISCRIMINATOR),
+        &fixture.mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // Refreshing returns the account data it writes
+    let returned: CollateralAttestation = simulate_view(&mut fixture.context, ix.clone()).await;
+    assert_eq!(returned.free_balance, HOLDER_AMOUNT + 500);
+
+    let result = send_tx(
+        &fixture.context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await;
+    assert_transaction_success(result);
+    let refreshed = fetch_attestation(&mut fixture, 0).await;
+    assert_eq!(refreshed.balance, HOLDER_AMOUNT + 500);
+    assert_eq!(refreshed.free_balance, HOLDER_AMOUNT + 500);
+    assert!(refreshed.slot >= attestation.slot);
+}
+
+#[tokio::test]
+async fn test_should_reject_attestation_of_another_token_account() {
+    let fixture = Fixture::new(12).await;
+    let mint = fixture.mint.pubkey();
+    let holder = fixture.holders[0].token_account;
+    let other = fixture.holders[1].token_account;
+
+    let mut ix = attest_collateral_ix(&fixture.context, mint, holder);
+    // CollateralAttestation PDA of another token account
+    ix.accounts[4].pubkey = find_collateral_attestation_pda(&other).0;
+    let payer = fixture.context.payer.insecure_clone();
+    let result = send_tx(
+        &fixture.context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await;
+    assert_transaction_failure(result);
+}
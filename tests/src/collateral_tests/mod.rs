@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod collateral_tests;
+
+pub mod collateral_helpers;
@@ -0,0 +1,71 @@
+use security_token_client::{
+    instructions::{
+        SetConfigAdmin, SetConfigAdminInstructionArgs, UpdateVerificationConfigBuilder,
+    },
+    pda::find_config_admin_pda,
+    types::UpdateVerificationConfigArgs,
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::helpers::{find_mint_authority_pda, send_tx};
+
+/// Build and send SetConfigAdmin instruction authorized by mint authority
+pub async fn execute_set_config_admin(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    config_admin: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (config_admin_account, _) = find_config_admin_pda(&mint);
+
+    let ix = SetConfigAdmin {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        config_admin_account,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(SetConfigAdminInstructionArgs { config_admin });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send UpdateVerificationConfig instruction signed by the config admin
+pub async fn execute_update_verification_config_by_admin(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    verification_config: Pubkey,
+    config_admin: &Keypair,
+    args: UpdateVerificationConfigArgs,
+) -> Result<(), BanksClientError> {
+    let (config_admin_account, _) = find_config_admin_pda(&mint);
+
+    let ix = UpdateVerificationConfigBuilder::new()
+        .mint(mint)
+        .verification_config_or_mint_authority(config_admin_account)
+        .instructions_sysvar_or_creator(config_admin.pubkey())
+        .config_account(verification_config)
+        .mint_account(mint)
+        .payer(config_admin.pubkey())
+        .update_verification_config_args(args)
+        .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &config_admin.pubkey(),
+        vec![config_admin],
+    )
+    .await
+}
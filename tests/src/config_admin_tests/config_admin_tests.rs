@@ -0,0 +1,175 @@
+use borsh::BorshDeserialize;
+use security_token_client::{
+    accounts::{ConfigAdmin, VerificationConfig},
+    instructions::BURN_DISCRIMINATOR,
+    pda::find_config_admin_pda,
+    types::{InitializeVerificationConfigArgs, UpdateVerificationConfigArgs},
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    config_admin_tests::config_admin_helpers::{
+        execute_set_config_admin, execute_update_verification_config_by_admin,
+    },
+    helpers::{
+        assert_account_exists, assert_transaction_failure, assert_transaction_success,
+        create_minimal_security_token_mint, find_verification_config_pda,
+        initialize_verification_config_for_payer, start_with_context_and_accounts,
+    },
+};
+
+fn burn_config_args(program_addresses: Vec<Pubkey>) -> InitializeVerificationConfigArgs {
+    InitializeVerificationConfigArgs {
+        instruction_discriminator: BURN_DISCRIMINATOR,
+        cpi_mode: false,
+        program_addresses,
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
+    }
+}
+
+async fn verification_programs(
+    context: &mut ProgramTestContext,
+    verification_config: Pubkey,
+) -> Vec<Pubkey> {
+    let account = assert_account_exists(context, verification_config, true)
+        .await
+        .unwrap();
+    VerificationConfig::try_from_slice(&account.data)
+        .unwrap()
+        .verification_programs
+}
+
+#[tokio::test]
+async fn test_config_admin_should_manage_verification_configs() {
+    let config_admin = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![(&config_admin, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+
+    let result = execute_set_config_admin(
+        &context.banks_client,
+        mint,
+        config_admin.pubkey(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    let (config_admin_pda, _) = find_config_admin_pda(&mint);
+    let account = assert_account_exists(context, config_admin_pda, true)
+        .await
+        .unwrap();
+    let config_admin_state = ConfigAdmin::from_bytes(&account.data).unwrap();
+    assert_eq!(config_admin_state.mint, mint);
+    assert_eq!(config_admin_state.admin, config_admin.pubkey());
+
+    // The admin signs through its ConfigAdmin account in place of the MintAuthority
+    let (verification_config, _) = find_verification_config_pda(mint, BURN_DISCRIMINATOR);
+    let program_1 = Pubkey::new_unique();
+    let result = initialize_verification_config_for_payer(
+        &context.banks_client,
+        &config_admin,
+        &mint_keypair,
+        config_admin_pda,
+        verification_config,
+        &burn_config_args(vec![program_1]),
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        verification_programs(context, verification_config).await,
+        vec![program_1]
+    );
+
+    let program_2 = Pubkey::new_unique();
+    let result = execute_update_verification_config_by_admin(
+        &context.banks_client,
+        mint,
+        verification_config,
+        &config_admin,
+        UpdateVerificationConfigArgs {
+            instruction_discriminator: BURN_DISCRIMINATOR,
+            cpi_mode: false,
+            offset: 1,
+            program_addresses: vec![program_2],
+            threshold: 0,
+            lookback_window: 0,
+            require_top_level: false,
+            timelock_seconds: 0,
+        },
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        verification_programs(context, verification_config).await,
+        vec![program_1, program_2]
+    );
+}
+
+#[tokio::test]
+async fn test_only_current_config_admin_should_manage_verification_configs() {
+    let config_admin = Keypair::new();
+    let other = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![
+        (&config_admin, 1_000_000_000),
+        (&other, 1_000_000_000),
+    ])
+    .await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+
+    let result = execute_set_config_admin(
+        &context.banks_client,
+        mint,
+        config_admin.pubkey(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (config_admin_pda, _) = find_config_admin_pda(&mint);
+    let (verification_config, _) = find_verification_config_pda(mint, BURN_DISCRIMINATOR);
+    let result = initialize_verification_config_for_payer(
+        &context.banks_client,
+        &other,
+        &mint_keypair,
+        config_admin_pda,
+        verification_config,
+        &burn_config_args(vec![Pubkey::new_unique()]),
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    // Removing the admin closes its account
+    let result = execute_set_config_admin(
+        &context.banks_client,
+        mint,
+        Pubkey::default(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_account_exists(context, config_admin_pda, false).await;
+
+    let result = initialize_verification_config_for_payer(
+        &context.banks_client,
+        &config_admin,
+        &mint_keypair,
+        config_admin_pda,
+        verification_config,
+        &burn_config_args(vec![Pubkey::new_unique()]),
+    )
+    .await;
+    assert_transaction_failure(result);
+    assert_account_exists(context, verification_config, false).await;
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod config_admin_tests;
+
+pub mod config_admin_helpers;
@@ -0,0 +1,122 @@
+use security_token_client::{
+    instructions::{
+        CloseConfigTemplate, CreateConfigTemplate, CreateConfigTemplateInstructionArgs,
+        SetConfigTemplate, SetConfigTemplateInstructionArgs, UpdateConfigTemplate,
+        UpdateConfigTemplateInstructionArgs,
+    },
+    pda::find_config_template_pda,
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::helpers::{find_mint_authority_pda, find_verification_config_pda, send_tx};
+
+/// Create the config template `template_id` of `issuer`, paid by the issuer
+pub async fn create_config_template(
+    context: &mut ProgramTestContext,
+    issuer: &Keypair,
+    template_id: u64,
+    program_addresses: Vec<Pubkey>,
+) -> Result<Pubkey, BanksClientError> {
+    let (config_template_pda, _) = find_config_template_pda(&issuer.pubkey(), template_id);
+
+    let ix = CreateConfigTemplate {
+        issuer: issuer.pubkey(),
+        config_template_account: config_template_pda,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction(CreateConfigTemplateInstructionArgs {
+        template_id,
+        program_addresses,
+    });
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &issuer.pubkey(),
+        vec![issuer],
+    )
+    .await?;
+    Ok(config_template_pda)
+}
+
+/// Replace the programs of the config template `template_id` of `issuer`
+pub async fn update_config_template(
+    context: &mut ProgramTestContext,
+    issuer: &Keypair,
+    template_id: u64,
+    program_addresses: Vec<Pubkey>,
+) -> Result<(), BanksClientError> {
+    let ix = UpdateConfigTemplate {
+        issuer: issuer.pubkey(),
+        config_template_account: find_config_template_pda(&issuer.pubkey(), template_id).0,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction(UpdateConfigTemplateInstructionArgs { program_addresses });
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &issuer.pubkey(),
+        vec![issuer],
+    )
+    .await
+}
+
+/// Close the config template `template_id` of `issuer`, returning rent to the issuer
+pub async fn close_config_template(
+    context: &mut ProgramTestContext,
+    issuer: &Keypair,
+    template_id: u64,
+) -> Result<(), BanksClientError> {
+    let ix = CloseConfigTemplate {
+        issuer: issuer.pubkey(),
+        config_template_account: find_config_template_pda(&issuer.pubkey(), template_id).0,
+        rent_recipient: issuer.pubkey(),
+    }
+    .instruction();
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &issuer.pubkey(),
+        vec![issuer],
+    )
+    .await
+}
+
+/// Link the `instruction_discriminator` config of `mint` to `config_template` through the
+/// mint authority of the payer, or unlink it when `None`
+pub async fn set_config_template(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    instruction_discriminator: u8,
+    config_template: Option<Pubkey>,
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let mut ix = SetConfigTemplate {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: payer.pubkey(),
+        payer: payer.pubkey(),
+        mint_account: mint,
+        verification_config: find_verification_config_pda(mint, instruction_discriminator).0,
+        config_template_account: config_template,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction(SetConfigTemplateInstructionArgs {
+        instruction_discriminator,
+    });
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
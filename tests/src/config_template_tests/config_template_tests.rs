@@ -0,0 +1,268 @@
+use borsh::BorshDeserialize;
+use security_token_client::{
+    accounts::{ConfigTemplate, VerificationConfig},
+    errors::SecurityTokenProgramError,
+    instructions::{MintBuilder, TrimVerificationConfigBuilder, MINT_DISCRIMINATOR},
+    pda::find_config_template_pda,
+    programs::SECURITY_TOKEN_PROGRAM_ID,
+    types::{InitializeVerificationConfigArgs, TrimVerificationConfigArgs},
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    instruction::AccountMeta,
+    signature::{Keypair, Signer},
+    sysvar,
+};
+
+use crate::{
+    config_template_tests::config_template_helpers::{
+        close_config_template, create_config_template, set_config_template, update_config_template,
+    },
+    helpers::{
+        assert_account_exists, assert_custom_error, assert_instruction_error,
+        assert_security_token_error, assert_transaction_success,
+        create_minimal_security_token_mint, create_spl_account, find_mint_authority_pda,
+        find_verification_config_pda, get_default_verification_programs,
+        initialize_verification_config, send_tx, start_with_context_and_accounts,
+    },
+    verification_tests::{
+        cpi_mode_tests::mint_dummy_program_processor,
+        verification_helpers::failing_dummy_program_processor,
+    },
+};
+
+const TEMPLATE_ID: u64 = 1;
+
+/// Mint 1000 tokens in CPI mode, passing `remaining_accounts` after the instruction accounts
+async fn mint_in_cpi_mode(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    destination: Pubkey,
+    remaining_accounts: &[Pubkey],
+) -> Result<(), BanksClientError> {
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &context.payer.pubkey());
+    let mut mint_builder = MintBuilder::new();
+    mint_builder
+        .mint(mint)
+        .verification_config(find_verification_config_pda(mint, MINT_DISCRIMINATOR).0)
+        .instructions_sysvar(sysvar::instructions::ID)
+        .destination(destination)
+        .mint_account(mint)
+        .mint_authority(mint_authority_pda)
+        .amount(1000);
+    for account in remaining_accounts {
+        mint_builder.add_remaining_account(AccountMeta::new_readonly(*account, false));
+    }
+
+    send_tx(
+        &context.banks_client,
+        vec![mint_builder.instruction()],
+        &context.payer.pubkey(),
+        vec![&context.payer],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_should_verify_with_config_template_programs() {
+    let passing_program = Pubkey::new_unique();
+    let failing_program = Pubkey::new_unique();
+
+    let mut pt = ProgramTest::new("security_token_program", SECURITY_TOKEN_PROGRAM_ID, None);
+    pt.prefer_bpf(false);
+    pt.add_program(
+        "passing_program",
+        passing_program,
+        processor!(mint_dummy_program_processor),
+    );
+    pt.add_program(
+        "failing_program",
+        failing_program,
+        processor!(failing_dummy_program_processor),
+    );
+    let context = &mut pt.start_with_context().await;
+    let issuer = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, None, 6).await;
+    let (verification_config_pda, _) = find_verification_config_pda(mint, MINT_DISCRIMINATOR);
+    initialize_verification_config(
+        &mint_keypair,
+        context,
+        mint_authority_pda,
+        verification_config_pda,
+        &InitializeVerificationConfigArgs {
+            instruction_discriminator: MINT_DISCRIMINATOR,
+            cpi_mode: true,
+            program_addresses: vec![passing_program],
+            threshold: 0,
+            lookback_window: 0,
+            require_top_level: false,
+            timelock_seconds: 0,
+        },
+    )
+    .await;
+    let destination = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+
+    let config_template =
+        create_config_template(context, &issuer, TEMPLATE_ID, vec![failing_program])
+            .await
+            .unwrap();
+    let template_account = assert_account_exists(context, config_template, true)
+        .await
+        .unwrap();
+    let template = ConfigTemplate::from_bytes(&template_account.data).unwrap();
+    assert_eq!(template.issuer, issuer.pubkey());
+    assert_eq!(template.template_id, TEMPLATE_ID);
+    assert_eq!(template.verification_programs, vec![failing_program]);
+
+    let result =
+        set_config_template(context, mint, MINT_DISCRIMINATOR, Some(config_template)).await;
+    assert_transaction_success(result);
+
+    // The own programs of the config override the template programs
+    let result = mint_in_cpi_mode(
+        context,
+        mint,
+        destination,
+        &[passing_program, config_template],
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = mint_in_cpi_mode(context, mint, destination, &[passing_program]).await;
+    assert_security_token_error(result, SecurityTokenProgramError::ConfigTemplateMismatch);
+
+    // Without own programs the config follows the template
+    let trim_ix = TrimVerificationConfigBuilder::new()
+        .mint(mint)
+        .verification_config_or_mint_authority(mint_authority_pda)
+        .instructions_sysvar_or_creator(issuer.pubkey())
+        .payer(issuer.pubkey())
+        .config_account(verification_config_pda)
+        .mint_account(mint)
+        .rent_recipient(issuer.pubkey())
+        .trim_verification_config_args(TrimVerificationConfigArgs {
+            instruction_discriminator: MINT_DISCRIMINATOR,
+            size: 0,
+            close: false,
+        })
+        .instruction();
+    let result = send_tx(
+        &context.banks_client,
+        vec![trim_ix],
+        &issuer.pubkey(),
+        vec![&issuer],
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = mint_in_cpi_mode(
+        context,
+        mint,
+        destination,
+        &[failing_program, config_template],
+    )
+    .await;
+    assert_custom_error(result, 0x1111);
+
+    let result = update_config_template(context, &issuer, TEMPLATE_ID, vec![passing_program]).await;
+    assert_transaction_success(result);
+
+    let result = mint_in_cpi_mode(
+        context,
+        mint,
+        destination,
+        &[passing_program, config_template],
+    )
+    .await;
+    assert_transaction_success(result);
+}
+
+#[tokio::test]
+async fn test_should_link_and_unlink_config_template() {
+    let other_issuer = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![(&other_issuer, 1_000_000_000)]).await;
+    let issuer = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, None, 6).await;
+    let (verification_config_pda, _) = find_verification_config_pda(mint, MINT_DISCRIMINATOR);
+    initialize_verification_config(
+        &mint_keypair,
+        context,
+        mint_authority_pda,
+        verification_config_pda,
+        &InitializeVerificationConfigArgs {
+            instruction_discriminator: MINT_DISCRIMINATOR,
+            cpi_mode: false,
+            program_addresses: get_default_verification_programs(),
+            threshold: 0,
+            lookback_window: 0,
+            require_top_level: false,
+            timelock_seconds: 0,
+        },
+    )
+    .await;
+    let standalone_config = assert_account_exists(context, verification_config_pda, true)
+        .await
+        .unwrap();
+
+    // Linking needs the template issuer as payer
+    let foreign_template = create_config_template(
+        context,
+        &other_issuer,
+        TEMPLATE_ID,
+        get_default_verification_programs(),
+    )
+    .await
+    .unwrap();
+    let result =
+        set_config_template(context, mint, MINT_DISCRIMINATOR, Some(foreign_template)).await;
+    assert_instruction_error(result, "MissingRequiredSignature");
+
+    let config_template = create_config_template(
+        context,
+        &issuer,
+        TEMPLATE_ID,
+        get_default_verification_programs(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        config_template,
+        find_config_template_pda(&issuer.pubkey(), TEMPLATE_ID).0
+    );
+
+    let result =
+        set_config_template(context, mint, MINT_DISCRIMINATOR, Some(config_template)).await;
+    assert_transaction_success(result);
+    let linked_config = assert_account_exists(context, verification_config_pda, true)
+        .await
+        .unwrap();
+    assert_eq!(linked_config.data.len(), standalone_config.data.len() + 32);
+    assert_eq!(
+        &linked_config.data[standalone_config.data.len()..],
+        config_template.as_ref()
+    );
+
+    let result = close_config_template(context, &issuer, TEMPLATE_ID).await;
+    assert_transaction_success(result);
+    assert_account_exists(context, config_template, false).await;
+
+    let result = set_config_template(context, mint, MINT_DISCRIMINATOR, None).await;
+    assert_transaction_success(result);
+    let unlinked_config = assert_account_exists(context, verification_config_pda, true)
+        .await
+        .unwrap();
+    let config = VerificationConfig::try_from_slice(&unlinked_config.data).unwrap();
+    assert_eq!(
+        config.verification_programs,
+        get_default_verification_programs()
+    );
+}
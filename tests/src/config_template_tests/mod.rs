@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod config_template_tests;
+
+pub mod config_template_helpers;
@@ -29,6 +29,42 @@ pub async fn execute_convert(
     payer: &Keypair,
     action_id: u64,
     amount_to_convert: u64,
+) -> Result<(), BanksClientError> {
+    execute_convert_with_oracle(
+        banks_client,
+        verification_config_pda,
+        mint_from,
+        mint_to,
+        token_account_from,
+        token_account_to,
+        mint_authority,
+        permanent_delegate,
+        rate_account,
+        receipt_account,
+        payer,
+        action_id,
+        amount_to_convert,
+        None,
+    )
+    .await
+}
+
+/// Build and send Convert instruction, passing the oracle of an oracle priced rate
+pub async fn execute_convert_with_oracle(
+    banks_client: &BanksClient,
+    verification_config_pda: Pubkey,
+    mint_from: Pubkey,
+    mint_to: Pubkey,
+    token_account_from: Pubkey,
+    token_account_to: Pubkey,
+    mint_authority: Pubkey,
+    permanent_delegate: Pubkey,
+    rate_account: Pubkey,
+    receipt_account: Pubkey,
+    payer: &Keypair,
+    action_id: u64,
+    amount_to_convert: u64,
+    oracle: Option<Pubkey>,
 ) -> Result<(), BanksClientError> {
     let convert_args = ConvertArgs {
         action_id,
@@ -49,6 +85,7 @@ pub async fn execute_convert(
         token_program: Pubkey::from_str_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"),
         system_program: solana_program::system_program::id(),
         payer: payer.pubkey(),
+        oracle,
     }
     .instruction(ConvertInstructionArgs { convert_args });
 
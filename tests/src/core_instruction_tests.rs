@@ -140,6 +140,8 @@ async fn test_initialize_mint_with_all_extensions() {
             new_multiplier_effective_timestamp: 0,
             new_multiplier: [1u8; 8].into(),
         }),
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(&mint_keypair, &mut context, mint_authority_pda, &mint_args).await;
@@ -395,6 +397,8 @@ async fn test_update_metadata() {
             additional_metadata: encoded,
         }),
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(&mint_keypair, &mut context, mint_authority_pda, &mint_args).await;
@@ -403,6 +407,10 @@ async fn test_update_metadata() {
         instruction_discriminator: UPDATE_METADATA_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: get_default_verification_programs(),
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     initialize_verification_config(
@@ -549,6 +557,8 @@ async fn test_initialize_mint_with_different_decimals() {
             ix_metadata_pointer: None, // No metadata pointer for this test
             ix_metadata: None,
             ix_scaled_ui_amount: None, // No scaled UI amount for this test
+            ix_transfer_fee: None,
+            ix_default_account_state: None,
         };
 
         initialize_mint(&mint_keypair, &mut context, mint_authority_pda, &mint_args).await;
@@ -572,6 +582,65 @@ async fn test_initialize_mint_with_different_decimals() {
     }
 }
 
+#[tokio::test]
+async fn test_initialize_mint_with_sponsoring_payer() {
+    let mut pt = ProgramTest::new("security_token_program", SECURITY_TOKEN_PROGRAM_ID, None);
+    pt.prefer_bpf(true);
+
+    let context = pt.start_with_context().await;
+
+    // The creator holds no lamports, the payer funds the mint and the MintAuthority account
+    let creator = solana_sdk::signature::Keypair::new();
+    let mint_keypair = solana_sdk::signature::Keypair::new();
+    let (mint_authority_pda, _bump) =
+        find_mint_authority_pda(&mint_keypair.pubkey(), &creator.pubkey());
+    let (freeze_authority_pda, _bump) = find_mint_freeze_authority_pda(&mint_keypair.pubkey());
+
+    let ix = InitializeMintBuilder::new()
+        .mint(mint_keypair.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(mint_authority_pda)
+        .creator(Some(creator.pubkey()))
+        .initialize_mint_args(InitializeMintArgs {
+            ix_mint: MintArgs {
+                decimals: 6,
+                mint_authority: creator.pubkey(),
+                freeze_authority: freeze_authority_pda,
+            },
+            ix_metadata_pointer: None,
+            ix_metadata: None,
+            ix_scaled_ui_amount: None,
+            ix_transfer_fee: None,
+            ix_default_account_state: None,
+        })
+        .instruction();
+
+    let result = send_tx(
+        &context.banks_client,
+        vec![ix],
+        &context.payer.pubkey(),
+        vec![&context.payer, &mint_keypair, &creator],
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let mint_authority_account = context
+        .banks_client
+        .get_account(mint_authority_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint_authority = MintAuthority::from_bytes(&mint_authority_account.data).unwrap();
+    assert_eq!(mint_authority.mint, mint_keypair.pubkey());
+    assert_eq!(mint_authority.mint_creator, creator.pubkey());
+    assert!(context
+        .banks_client
+        .get_account(creator.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+}
+
 #[tokio::test]
 async fn test_initialize_mint_error_cases() {
     let mut pt = ProgramTest::new("security_token_program", SECURITY_TOKEN_PROGRAM_ID, None);
@@ -599,6 +668,8 @@ async fn test_initialize_mint_error_cases() {
                 ix_metadata_pointer: None, // No metadata pointer for this test
                 ix_metadata: None,
                 ix_scaled_ui_amount: None, // No scaled UI amount for this test
+                ix_transfer_fee: None,
+                ix_default_account_state: None,
             })
             .instruction();
 
@@ -653,6 +724,8 @@ async fn test_initialize_mint_error_cases() {
                 ix_metadata_pointer: None, // No metadata pointer for this test
                 ix_metadata: None,
                 ix_scaled_ui_amount: None, // No scaled UI amount for this test
+                ix_transfer_fee: None,
+                ix_default_account_state: None,
             })
             .instruction();
 
@@ -720,6 +793,8 @@ async fn test_verification_config() {
             additional_metadata: vec![],
         }),
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(&mint_keypair, &mut context, mint_authority_pda, &mint_args).await;
@@ -737,6 +812,10 @@ async fn test_verification_config() {
         instruction_discriminator: UPDATE_METADATA_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: verification_programs.clone(),
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     initialize_verification_config(
@@ -794,6 +873,10 @@ async fn test_verification_config() {
         cpi_mode: false,
         program_addresses: new_verification_programs.clone(),
         offset,
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     let update_config_ix = UpdateVerificationConfigBuilder::new()
@@ -862,6 +945,10 @@ async fn test_verification_config() {
         cpi_mode: false,
         program_addresses: [Pubkey::new_unique(), Pubkey::new_unique()].to_vec(),
         offset: 4, // Current len is 3
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     let update_config_ix = UpdateVerificationConfigBuilder::new()
@@ -898,9 +985,10 @@ async fn test_verification_config() {
         .mint(mint_keypair.pubkey())
         .verification_config_or_mint_authority(mint_authority_pda)
         .instructions_sysvar_or_creator(context.payer.pubkey())
+        .payer(context.payer.pubkey())
         .config_account(verification_config_pda)
         .mint_account(mint_keypair.pubkey())
-        .recipient(context.payer.pubkey())
+        .rent_recipient(context.payer.pubkey())
         .trim_verification_config_args(trim_verification_config_args)
         .instruction();
 
@@ -972,9 +1060,10 @@ async fn test_verification_config() {
         .mint(mint_keypair.pubkey())
         .verification_config_or_mint_authority(mint_authority_pda)
         .instructions_sysvar_or_creator(context.payer.pubkey())
+        .payer(context.payer.pubkey())
         .config_account(verification_config_pda)
         .mint_account(mint_keypair.pubkey())
-        .recipient(context.payer.pubkey())
+        .rent_recipient(context.payer.pubkey())
         .trim_verification_config_args(close_verification_config_args)
         .instruction();
 
@@ -1045,6 +1134,8 @@ async fn test_metadata_pointer_validation() {
             }),
             ix_metadata: None, // But no metadata provided
             ix_scaled_ui_amount: None,
+            ix_transfer_fee: None,
+            ix_default_account_state: None,
         };
 
         let ix = InitializeMintBuilder::new()
@@ -1094,6 +1185,8 @@ async fn test_metadata_pointer_validation() {
                 additional_metadata: vec![],
             }),
             ix_scaled_ui_amount: None,
+            ix_transfer_fee: None,
+            ix_default_account_state: None,
         };
 
         let ix = InitializeMintBuilder::new()
@@ -1138,6 +1231,8 @@ async fn test_metadata_pointer_validation() {
         }),
         ix_metadata: None, // No metadata - VALID for external storage
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(&mint_keypair, &mut context, mint_authority_pda, &mint_args).await;
@@ -1198,6 +1293,8 @@ async fn test_initialize_verification_config_rejects_empty_vector() {
         ix_metadata_pointer: None,
         ix_metadata: None,
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(&mint_keypair, &mut context, mint_authority_pda, &mint_args).await;
@@ -1210,6 +1307,10 @@ async fn test_initialize_verification_config_rejects_empty_vector() {
         instruction_discriminator: MINT_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: vec![], // Empty vector - should be rejected
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     let ix = InitializeVerificationConfigBuilder::new()
@@ -1255,6 +1356,8 @@ async fn test_update_verification_config_rejects_resulting_empty_vector() {
         ix_metadata_pointer: None,
         ix_metadata: None,
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(&mint_keypair, &mut context, mint_authority_pda, &mint_args).await;
@@ -1270,6 +1373,10 @@ async fn test_update_verification_config_rejects_resulting_empty_vector() {
         instruction_discriminator: UPDATE_METADATA_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: verification_programs.clone(),
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     initialize_verification_config(
@@ -1292,9 +1399,10 @@ async fn test_update_verification_config_rejects_resulting_empty_vector() {
         .mint(mint_keypair.pubkey())
         .verification_config_or_mint_authority(mint_authority_pda)
         .instructions_sysvar_or_creator(context.payer.pubkey())
+        .payer(context.payer.pubkey())
         .config_account(verification_config_pda)
         .mint_account(mint_keypair.pubkey())
-        .recipient(context.payer.pubkey())
+        .rent_recipient(context.payer.pubkey())
         .trim_verification_config_args(trim_verification_config_args)
         .instruction();
 
@@ -1333,6 +1441,8 @@ async fn test_mint_fails_with_empty_verification_config() {
         ix_metadata_pointer: None,
         ix_metadata: None,
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(
@@ -1350,6 +1460,10 @@ async fn test_mint_fails_with_empty_verification_config() {
         instruction_discriminator: MINT_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: vec![], // Empty vector
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     let init_ix = InitializeVerificationConfigBuilder::new()
@@ -1404,6 +1518,8 @@ async fn test_transfer_fails_with_empty_verification_config() {
         ix_metadata_pointer: None,
         ix_metadata: None,
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(
@@ -1422,6 +1538,10 @@ async fn test_transfer_fails_with_empty_verification_config() {
         instruction_discriminator: TRANSFER_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: vec![], // Empty vector - should be rejected
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     let init_ix = InitializeVerificationConfigBuilder::new()
@@ -1452,6 +1572,10 @@ async fn test_transfer_fails_with_empty_verification_config() {
         instruction_discriminator: TRANSFER_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: vec![program_1],
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     initialize_verification_config(
@@ -1474,6 +1598,10 @@ async fn test_transfer_fails_with_empty_verification_config() {
         instruction_discriminator: MINT_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: get_default_verification_programs(), // Valid non-empty vector
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     initialize_verification_config(
@@ -1517,9 +1645,10 @@ async fn test_transfer_fails_with_empty_verification_config() {
         .mint(mint_keypair.pubkey())
         .verification_config_or_mint_authority(mint_authority_pda)
         .instructions_sysvar_or_creator(context.payer.pubkey())
+        .payer(context.payer.pubkey())
         .config_account(verification_config_pda)
         .mint_account(mint_keypair.pubkey())
-        .recipient(context.payer.pubkey())
+        .rent_recipient(context.payer.pubkey())
         .trim_verification_config_args(trim_verification_config_args)
         .instruction();
     let dummy_trim_ix = create_dummy_verification_from_instruction(&trim_config_ix);
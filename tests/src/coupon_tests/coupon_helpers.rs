@@ -0,0 +1,142 @@
+use security_token_client::{
+    instructions::{
+        ClaimCoupon, CreateAccrualConfig, CreateAccrualConfigInstructionArgs,
+        TRANSFER_DISCRIMINATOR,
+    },
+    pda::{
+        find_accrual_config_pda, find_associated_token_address_with_program, find_coupon_claim_pda,
+    },
+    types::CreateAccrualConfigArgs,
+};
+use solana_program_test::{BanksClient, BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    clock::Clock,
+    signature::{Keypair, Signer},
+    sysvar,
+};
+use spl_associated_token_account::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
+use spl_transfer_hook_interface::get_extra_account_metas_address;
+
+use crate::helpers::{
+    create_dummy_verification_from_instruction, find_mint_authority_pda, find_transfer_hook_pda,
+    find_verification_config_pda, send_tx,
+};
+
+/// Coupon vault of the mint, owned by its AccrualConfig PDA
+pub fn find_coupon_vault(
+    mint: &Pubkey,
+    payout_mint: &Pubkey,
+    payout_token_program: &Pubkey,
+) -> Pubkey {
+    let (accrual_config, _) = find_accrual_config_pda(mint);
+    find_associated_token_address_with_program(&accrual_config, payout_mint, payout_token_program).0
+}
+
+/// Move the bank clock to `unix_timestamp`
+pub async fn warp_to_timestamp(context: &mut ProgramTestContext, unix_timestamp: i64) {
+    // Advance the slot as well so follow-up transactions get a fresh blockhash
+    let slot = context.banks_client.get_root_slot().await.unwrap();
+    context.warp_to_slot(slot + 100).unwrap();
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    context.set_sysvar(&clock);
+}
+
+/// Current unix timestamp of the bank clock
+pub async fn current_timestamp(context: &mut ProgramTestContext) -> i64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp
+}
+
+/// Build and send CreateAccrualConfig instruction authorized by mint authority
+pub async fn execute_create_accrual_config(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    payout_mint: Pubkey,
+    payout_token_program: Pubkey,
+    args: CreateAccrualConfigArgs,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (accrual_config_account, _) = find_accrual_config_pda(&mint);
+    let (transfer_verification_config, _) =
+        find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+    let (transfer_hook_pda, _) = find_transfer_hook_pda(&mint);
+
+    let ix = CreateAccrualConfig {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        accrual_config_account,
+        payout_mint,
+        coupon_vault: find_coupon_vault(&mint, &payout_mint, &payout_token_program),
+        payout_token_program,
+        associated_token_account_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+        transfer_verification_config,
+        account_metas_pda: get_extra_account_metas_address(&mint, &transfer_hook_program),
+        transfer_hook_pda,
+        transfer_hook_program,
+    }
+    .instruction(CreateAccrualConfigInstructionArgs {
+        create_accrual_config_args: args,
+    });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send ClaimCoupon instruction
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_claim_coupon(
+    banks_client: &BanksClient,
+    verification_config: Pubkey,
+    mint: Pubkey,
+    token_account: Pubkey,
+    payout_mint: Pubkey,
+    payout_token_program: Pubkey,
+    payout_token_account: Pubkey,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (accrual_config_account, _) = find_accrual_config_pda(&mint);
+    let (coupon_claim_account, _) = find_coupon_claim_pda(&token_account);
+
+    let ix = ClaimCoupon {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        payer: payer.pubkey(),
+        mint_account: mint,
+        accrual_config_account,
+        token_account,
+        coupon_claim_account,
+        payout_mint,
+        coupon_vault: find_coupon_vault(&mint, &payout_mint, &payout_token_program),
+        payout_token_account,
+        payout_token_program,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction();
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
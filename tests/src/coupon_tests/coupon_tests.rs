@@ -0,0 +1,423 @@
+use rstest::rstest;
+use security_token_client::{
+    accounts::{AccrualConfig, CouponClaim},
+    errors::SecurityTokenProgramError,
+    instructions::{CLAIM_COUPON_DISCRIMINATOR, TRANSFER_DISCRIMINATOR},
+    pda::{find_accrual_config_pda, find_coupon_claim_pda, TOKEN_PROGRAM_ID},
+    types::{CreateAccrualConfigArgs, DayCountConvention},
+};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::{
+    coupon_tests::coupon_helpers::{
+        current_timestamp, execute_claim_coupon, execute_create_accrual_config, warp_to_timestamp,
+    },
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        create_verification_config, get_default_verification_programs, get_token_account_state,
+        setup_transfer_verified_mint, start_with_transfer_hook,
+    },
+    position_limit_tests::position_limit_helpers::transfer,
+    wrap_tests::wrap_helpers::{create_underlying_mint, create_underlying_token_account},
+};
+
+const DECIMALS: u8 = 6;
+/// 30 day coupon period
+const PERIOD_SECONDS: u32 = 30 * 86_400;
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    get_token_account_state(&mut context.banks_client, token_account)
+        .await
+        .base
+        .amount
+}
+
+/// 5% Actual/360 coupon on 100 payout tokens principal per debt token
+fn accrual_args(start_timestamp: i64) -> CreateAccrualConfigArgs {
+    CreateAccrualConfigArgs {
+        rate_bps: 500,
+        day_count: 1,
+        period_seconds: PERIOD_SECONDS,
+        start_timestamp,
+        principal_per_token: 100_000_000,
+    }
+}
+
+#[rstest]
+#[case::legacy_spl_token(TOKEN_PROGRAM_ID)]
+#[case::token_2022(TOKEN_22_PROGRAM_ID)]
+#[tokio::test]
+async fn test_should_accrue_and_claim_coupons(#[case] payout_token_program: Pubkey) {
+    let holder = Keypair::new();
+    let context = &mut start_with_transfer_hook(&[&holder]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    // 10 debt tokens
+    let (mint_keypair, mint_authority_pda, token_account) =
+        setup_transfer_verified_mint(context, &holder, 10_000_000).await;
+    let mint = mint_keypair.pubkey();
+    let claim_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        CLAIM_COUPON_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+
+    let payout_mint = create_underlying_mint(context, &payout_token_program, DECIMALS).await;
+    let payout_token_account = create_underlying_token_account(
+        context,
+        &payout_mint,
+        &payout_token_program,
+        &holder.pubkey(),
+        DECIMALS,
+        0,
+    )
+    .await;
+
+    let start = current_timestamp(context).await;
+    let result = execute_create_accrual_config(
+        &context.banks_client,
+        mint,
+        payout_mint,
+        payout_token_program,
+        accrual_args(start),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (accrual_config_pda, _) = find_accrual_config_pda(&mint);
+    let account = assert_account_exists(context, accrual_config_pda, true)
+        .await
+        .unwrap();
+    let accrual_config = AccrualConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(accrual_config.payout_mint, payout_mint);
+    assert_eq!(accrual_config.day_count, DayCountConvention::Actual360);
+
+    // Issuer funds the coupon vault
+    let coupon_vault = create_underlying_token_account(
+        context,
+        &payout_mint,
+        &payout_token_program,
+        &accrual_config_pda,
+        DECIMALS,
+        100_000_000,
+    )
+    .await;
+
+    let claim = |banks_client: BanksClient, payout_token_account: Pubkey| {
+        let holder = holder.insecure_clone();
+        async move {
+            execute_claim_coupon(
+                &banks_client,
+                claim_verification_config,
+                mint,
+                token_account,
+                payout_mint,
+                payout_token_program,
+                payout_token_account,
+                &holder,
+            )
+            .await
+        }
+    };
+
+    // First claim takes the balance snapshot without paying
+    assert_transaction_success(claim(context.banks_client.clone(), payout_token_account).await);
+    let (coupon_claim_pda, _) = find_coupon_claim_pda(&token_account);
+    let account = assert_account_exists(context, coupon_claim_pda, true)
+        .await
+        .unwrap();
+    let coupon_claim = CouponClaim::from_bytes(&account.data).unwrap();
+    assert_eq!(coupon_claim.balance_snapshot, 10_000_000);
+    assert_eq!(coupon_claim.last_claimed_period, 0);
+    assert_eq!(token_amount(context, payout_token_account).await, 0);
+
+    // Two completed periods: 1000 principal * 5% * 60/360
+    warp_to_timestamp(context, start + 2 * i64::from(PERIOD_SECONDS) + 100).await;
+    assert_transaction_success(claim(context.banks_client.clone(), payout_token_account).await);
+    assert_eq!(token_amount(context, payout_token_account).await, 8_333_333);
+
+    // Coupons are only paid to accounts of the holder
+    let other_owner = Keypair::new();
+    let other_payout_token_account = create_underlying_token_account(
+        context,
+        &payout_mint,
+        &payout_token_program,
+        &other_owner.pubkey(),
+        DECIMALS,
+        0,
+    )
+    .await;
+    warp_to_timestamp(context, start + 3 * i64::from(PERIOD_SECONDS)).await;
+    assert_transaction_failure(
+        claim(context.banks_client.clone(), other_payout_token_account).await,
+    );
+
+    assert_transaction_success(claim(context.banks_client.clone(), payout_token_account).await);
+    assert_eq!(
+        token_amount(context, payout_token_account).await,
+        8_333_333 + 4_166_666
+    );
+    assert_eq!(
+        token_amount(context, coupon_vault).await,
+        100_000_000 - 8_333_333 - 4_166_666
+    );
+}
+
+#[tokio::test]
+async fn test_create_accrual_config_validation() {
+    let context = &mut start_with_transfer_hook(&[]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), DECIMALS)
+            .await;
+    create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        TRANSFER_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+    let payout_mint = create_underlying_mint(context, &TOKEN_PROGRAM_ID, DECIMALS).await;
+    let start = current_timestamp(context).await;
+
+    let mut zero_rate = accrual_args(start);
+    zero_rate.rate_bps = 0;
+    let result = execute_create_accrual_config(
+        &context.banks_client,
+        mint,
+        payout_mint,
+        TOKEN_PROGRAM_ID,
+        zero_rate,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    // Coupons cannot be paid in the debt token itself
+    let result = execute_create_accrual_config(
+        &context.banks_client,
+        mint,
+        mint,
+        TOKEN_22_PROGRAM_ID,
+        accrual_args(start),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let result = execute_create_accrual_config(
+        &context.banks_client,
+        mint,
+        payout_mint,
+        TOKEN_PROGRAM_ID,
+        accrual_args(start),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    warp_to_timestamp(context, start + 1).await;
+    let result = execute_create_accrual_config(
+        &context.banks_client,
+        mint,
+        payout_mint,
+        TOKEN_PROGRAM_ID,
+        accrual_args(start),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_not_claim_coupons_twice_on_shuttled_tokens() {
+    let holder = Keypair::new();
+    let other_holder = Keypair::new();
+    let context = &mut start_with_transfer_hook(&[&holder, &other_holder]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let (mint_keypair, mint_authority_pda, token_account) =
+        setup_transfer_verified_mint(context, &holder, 10_000_000).await;
+    let mint = mint_keypair.pubkey();
+    let other_token_account = create_spl_account(context, &mint_keypair, &other_holder).await;
+    let claim_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        CLAIM_COUPON_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+
+    let payout_mint = create_underlying_mint(context, &TOKEN_PROGRAM_ID, DECIMALS).await;
+    let mut payout_token_accounts = vec![];
+    for owner in [&holder, &other_holder] {
+        let payout_token_account = create_underlying_token_account(
+            context,
+            &payout_mint,
+            &TOKEN_PROGRAM_ID,
+            &owner.pubkey(),
+            DECIMALS,
+            0,
+        )
+        .await;
+        payout_token_accounts.push(payout_token_account);
+    }
+
+    let start = current_timestamp(context).await;
+    let result = execute_create_accrual_config(
+        &context.banks_client,
+        mint,
+        payout_mint,
+        TOKEN_PROGRAM_ID,
+        accrual_args(start),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    let (accrual_config_pda, _) = find_accrual_config_pda(&mint);
+    create_underlying_token_account(
+        context,
+        &payout_mint,
+        &TOKEN_PROGRAM_ID,
+        &accrual_config_pda,
+        DECIMALS,
+        100_000_000,
+    )
+    .await;
+
+    let claim = |banks_client: BanksClient, token_account: Pubkey, payout_token_account: Pubkey| {
+        let payer = mint_creator.insecure_clone();
+        async move {
+            execute_claim_coupon(
+                &banks_client,
+                claim_verification_config,
+                mint,
+                token_account,
+                payout_mint,
+                TOKEN_PROGRAM_ID,
+                payout_token_account,
+                &payer,
+            )
+            .await
+        }
+    };
+
+    // Both token accounts start accruing, the other one without tokens
+    for (token_account, payout_token_account) in [
+        (token_account, payout_token_accounts[0]),
+        (other_token_account, payout_token_accounts[1]),
+    ] {
+        let result = claim(
+            context.banks_client.clone(),
+            token_account,
+            payout_token_account,
+        )
+        .await;
+        assert_transaction_success(result);
+    }
+
+    // One completed period: 1000 principal * 5% * 30/360
+    warp_to_timestamp(context, start + i64::from(PERIOD_SECONDS) + 100).await;
+    let result = claim(
+        context.banks_client.clone(),
+        token_account,
+        payout_token_accounts[0],
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        token_amount(context, payout_token_accounts[0]).await,
+        4_166_666
+    );
+
+    // The recipient has an unclaimed period, its snapshot must not take in the claimed tokens
+    let result = transfer(
+        context,
+        mint,
+        token_account,
+        other_token_account,
+        &holder,
+        10_000_000,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::CouponClaimOutdated);
+
+    let result = claim(
+        context.banks_client.clone(),
+        other_token_account,
+        payout_token_accounts[1],
+    )
+    .await;
+    assert_transaction_success(result);
+    let result = transfer(
+        context,
+        mint,
+        token_account,
+        other_token_account,
+        &holder,
+        10_000_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // Claiming again in the same period pays nothing on the received tokens
+    let result = claim(
+        context.banks_client.clone(),
+        other_token_account,
+        payout_token_accounts[1],
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(token_amount(context, payout_token_accounts[1]).await, 0);
+
+    // The sender has an unclaimed period too once the next one completes
+    warp_to_timestamp(context, start + 2 * i64::from(PERIOD_SECONDS) + 100).await;
+    let result = transfer(
+        context,
+        mint,
+        other_token_account,
+        token_account,
+        &other_holder,
+        10_000_000,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::CouponClaimOutdated);
+
+    // Each period paid the coupon of the 10 tokens once
+    for (token_account, payout_token_account) in [
+        (token_account, payout_token_accounts[0]),
+        (other_token_account, payout_token_accounts[1]),
+    ] {
+        let result = claim(
+            context.banks_client.clone(),
+            token_account,
+            payout_token_account,
+        )
+        .await;
+        assert_transaction_success(result);
+    }
+    assert_eq!(
+        token_amount(context, payout_token_accounts[0]).await,
+        4_166_666
+    );
+    assert_eq!(
+        token_amount(context, payout_token_accounts[1]).await,
+        4_166_666
+    );
+}
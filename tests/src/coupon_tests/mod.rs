@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod coupon_tests;
+
+pub mod coupon_helpers;
@@ -0,0 +1,107 @@
+use security_token_client::{
+    instructions::{
+        ThawBuilder, UpdateDefaultAccountState, UpdateDefaultAccountStateInstructionArgs,
+    },
+    types::{DefaultAccountStateArgs, InitializeMintArgs, MintArgs},
+};
+use solana_program_test::{BanksClient, BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::{signature::Keypair, signer::Signer, sysvar};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{
+    create_dummy_verification_from_instruction, find_mint_authority_pda,
+    find_mint_freeze_authority_pda, initialize_mint_for_creator, send_tx,
+};
+
+/// Create a security token mint whose new token accounts start in `state`
+pub async fn create_default_account_state_security_token_mint(
+    context: &mut ProgramTestContext,
+    mint_keypair: &Keypair,
+    state: u8,
+) -> Pubkey {
+    let mint_creator = context.payer.insecure_clone();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (freeze_authority_pda, _) = find_mint_freeze_authority_pda(&mint);
+
+    let mint_args = InitializeMintArgs {
+        ix_mint: MintArgs {
+            decimals: 6,
+            mint_authority: mint_creator.pubkey(),
+            freeze_authority: freeze_authority_pda,
+        },
+        ix_metadata_pointer: None,
+        ix_metadata: None,
+        ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: Some(DefaultAccountStateArgs { state }),
+    };
+
+    initialize_mint_for_creator(
+        context,
+        mint_keypair,
+        mint_authority_pda,
+        &mint_creator,
+        &mint_args,
+    )
+    .await;
+
+    mint_authority_pda
+}
+
+/// Build and send UpdateDefaultAccountState
+pub async fn execute_update_default_account_state(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    verification_config: Pubkey,
+    state: u8,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let ix = UpdateDefaultAccountState {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        freeze_authority: find_mint_freeze_authority_pda(&mint).0,
+        mint_account: mint,
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction(UpdateDefaultAccountStateInstructionArgs { state });
+
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
+
+/// Build and send Thaw for `token_account`
+pub async fn execute_thaw(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    verification_config: Pubkey,
+    token_account: Pubkey,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let ix = ThawBuilder::new()
+        .mint(mint)
+        .verification_config(verification_config)
+        .mint_account(mint)
+        .freeze_authority(find_mint_freeze_authority_pda(&mint).0)
+        .token_account(token_account)
+        .instruction();
+
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
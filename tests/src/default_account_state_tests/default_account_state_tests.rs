@@ -0,0 +1,157 @@
+use security_token_client::instructions::{
+    THAW_DISCRIMINATOR, UPDATE_DEFAULT_ACCOUNT_STATE_DISCRIMINATOR,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::{
+    extension::{default_account_state::DefaultAccountState, BaseStateWithExtensions},
+    state::AccountState,
+};
+
+use crate::{
+    claim_tests::claim_helpers::start_with_context_and_transfer_hook,
+    default_account_state_tests::default_account_state_helpers::{
+        create_default_account_state_security_token_mint, execute_thaw,
+        execute_update_default_account_state,
+    },
+    helpers::{
+        assert_instruction_error, assert_transaction_success, create_spl_account,
+        create_verification_config, get_default_verification_programs, get_mint_state,
+        get_token_account_state,
+    },
+};
+
+const INITIALIZED: u8 = 1;
+const FROZEN: u8 = 2;
+
+struct Setup {
+    mint_keypair: Keypair,
+    thaw_verification_config: Pubkey,
+    update_verification_config: Pubkey,
+}
+
+/// Mint whose new token accounts start frozen
+async fn setup_mint(context: &mut ProgramTestContext) -> Setup {
+    let mint_keypair = Keypair::new();
+    let mint_authority_pda =
+        create_default_account_state_security_token_mint(context, &mint_keypair, FROZEN).await;
+    let mut verification_configs = vec![];
+    for discriminator in [
+        THAW_DISCRIMINATOR,
+        UPDATE_DEFAULT_ACCOUNT_STATE_DISCRIMINATOR,
+    ] {
+        let verification_config = create_verification_config(
+            context,
+            &mint_keypair,
+            mint_authority_pda,
+            discriminator,
+            get_default_verification_programs(),
+            None,
+        )
+        .await;
+        verification_configs.push(verification_config);
+    }
+    Setup {
+        mint_keypair,
+        thaw_verification_config: verification_configs[0],
+        update_verification_config: verification_configs[1],
+    }
+}
+
+async fn account_state(context: &mut ProgramTestContext, token_account: Pubkey) -> AccountState {
+    get_token_account_state(&mut context.banks_client, token_account)
+        .await
+        .base
+        .state
+}
+
+async fn default_account_state(context: &mut ProgramTestContext, mint: Pubkey) -> u8 {
+    get_mint_state(&mut context.banks_client, mint)
+        .await
+        .get_extension::<DefaultAccountState>()
+        .expect("DefaultAccountState extension should be present")
+        .state
+}
+
+#[tokio::test]
+async fn test_new_accounts_should_start_frozen_until_thawed() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let mint = setup.mint_keypair.pubkey();
+    let payer = context.payer.insecure_clone();
+    assert_eq!(default_account_state(context, mint).await, FROZEN);
+
+    let holder_account = create_spl_account(context, &setup.mint_keypair, &Keypair::new()).await;
+    assert_eq!(
+        account_state(context, holder_account).await,
+        AccountState::Frozen
+    );
+
+    // Issuer thaws the account once the holder passed KYC
+    let result = execute_thaw(
+        &context.banks_client,
+        mint,
+        setup.thaw_verification_config,
+        holder_account,
+        &payer,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        account_state(context, holder_account).await,
+        AccountState::Initialized
+    );
+}
+
+#[tokio::test]
+async fn test_should_update_default_account_state() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let mint = setup.mint_keypair.pubkey();
+    let payer = context.payer.insecure_clone();
+    let frozen_account = create_spl_account(context, &setup.mint_keypair, &Keypair::new()).await;
+
+    let result = execute_update_default_account_state(
+        &context.banks_client,
+        mint,
+        setup.update_verification_config,
+        INITIALIZED,
+        &payer,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(default_account_state(context, mint).await, INITIALIZED);
+
+    // Only accounts created after the update start initialized
+    let open_account = create_spl_account(context, &setup.mint_keypair, &Keypair::new()).await;
+    assert_eq!(
+        account_state(context, open_account).await,
+        AccountState::Initialized
+    );
+    assert_eq!(
+        account_state(context, frozen_account).await,
+        AccountState::Frozen
+    );
+}
+
+#[tokio::test]
+async fn test_update_default_account_state_should_reject_uninitialized() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let payer = context.payer.insecure_clone();
+
+    let result = execute_update_default_account_state(
+        &context.banks_client,
+        setup.mint_keypair.pubkey(),
+        setup.update_verification_config,
+        0,
+        &payer,
+    )
+    .await;
+    assert_instruction_error(result, "InvalidArgument");
+    assert_eq!(
+        default_account_state(context, setup.mint_keypair.pubkey()).await,
+        FROZEN
+    );
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod default_account_state_tests;
+
+pub mod default_account_state_helpers;
@@ -0,0 +1,156 @@
+use security_token_client::{
+    claim_tracking::claim_bitmap_page,
+    instructions::{
+        ClaimDistribution, ClaimDistributionInstructionArgs, CreateDistributionPayout,
+        CreateDistributionPayoutInstructionArgs,
+    },
+    pda::{
+        find_claim_bitmap_pda, find_claim_deadline_pda, find_distribution_payout_pda,
+        find_distribution_root_pda,
+    },
+    types::ClaimDistributionArgs,
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    sysvar,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id, ID as ASSOCIATED_TOKEN_PROGRAM_ID,
+};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{
+    create_dummy_verification_from_instruction, find_mint_authority_pda,
+    find_permanent_delegate_pda, send_tx,
+};
+
+/// Payout escrow token account of the DistributionPayout PDA
+pub fn find_payout_escrow_token_account(
+    distribution_payout: &Pubkey,
+    payout_mint: &Pubkey,
+    payout_token_program: &Pubkey,
+) -> Pubkey {
+    get_associated_token_address_with_program_id(
+        distribution_payout,
+        payout_mint,
+        payout_token_program,
+    )
+}
+
+/// Build and send CreateDistributionPayout instruction authorized by mint authority
+pub async fn execute_create_distribution_payout(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    payout_mint: Pubkey,
+    payout_token_program: Pubkey,
+    args: CreateDistributionPayoutInstructionArgs,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (distribution_payout_account, _) =
+        find_distribution_payout_pda(&mint, args.action_id, &payout_mint);
+
+    let ix = CreateDistributionPayout {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        distribution_payout_account,
+        payout_mint,
+        payout_escrow_token_account: find_payout_escrow_token_account(
+            &distribution_payout_account,
+            &payout_mint,
+            &payout_token_program,
+        ),
+        payout_token_program,
+        associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(args);
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send ClaimDistribution instruction paid in the payout mint of a DistributionPayout
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_claim_distribution_in_payout_mint(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    verification_config: Pubkey,
+    eligible_token_account: Pubkey,
+    receipt_account: Pubkey,
+    payout_mint: Pubkey,
+    payout_token_program: Pubkey,
+    payout_token_account: Pubkey,
+    claim_distribution_args: ClaimDistributionArgs,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (permanent_delegate_authority, _) = find_permanent_delegate_pda(&mint);
+    let (distribution_payout_account, _) =
+        find_distribution_payout_pda(&mint, claim_distribution_args.action_id, &payout_mint);
+
+    let ix = ClaimDistribution {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        permanent_delegate_authority,
+        payer: payer.pubkey(),
+        mint_account: mint,
+        eligible_token_account,
+        escrow_token_account: None,
+        receipt_account,
+        proof_account: None,
+        transfer_hook_program: Pubkey::from(security_token_transfer_hook::id()),
+        token_program: TOKEN_22_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+        withholding_rate_account: None,
+        tax_escrow_token_account: None,
+        distribution_payout_account: Some(distribution_payout_account),
+        payout_mint: Some(payout_mint),
+        payout_escrow_token_account: Some(find_payout_escrow_token_account(
+            &distribution_payout_account,
+            &payout_mint,
+            &payout_token_program,
+        )),
+        payout_token_account: Some(payout_token_account),
+        payout_token_program: Some(payout_token_program),
+        custodian: None,
+        instructions_sysvar_account: None,
+        claim_deadline_account: find_claim_deadline_pda(&mint, claim_distribution_args.action_id).0,
+        distribution_root_account: find_distribution_root_pda(
+            &mint,
+            claim_distribution_args.action_id,
+            &claim_distribution_args.merkle_root,
+        )
+        .0,
+        claim_bitmap_account: find_claim_bitmap_pda(
+            &mint,
+            claim_distribution_args.action_id,
+            &claim_distribution_args.merkle_root,
+            claim_bitmap_page(claim_distribution_args.leaf_index as usize),
+        )
+        .0,
+    }
+    .instruction(ClaimDistributionInstructionArgs {
+        claim_distribution_args,
+    });
+
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
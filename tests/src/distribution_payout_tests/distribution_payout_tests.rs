@@ -0,0 +1,222 @@
+use security_token_client::{
+    accounts::DistributionPayout,
+    instructions::CreateDistributionPayoutInstructionArgs,
+    pda::{find_distribution_payout_pda, TOKEN_PROGRAM_ID},
+    types::ClaimDistributionArgs,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_merkle_tree_reference::MerkleTree;
+
+use crate::{
+    claim_tests::{
+        claim_helpers::{
+            create_distribution_for_users, create_leaves, start_with_context_and_transfer_hook,
+        },
+        merkle_tree_helpers::Leaf,
+    },
+    distribution_payout_tests::distribution_payout_helpers::{
+        execute_claim_distribution_in_payout_mint, execute_create_distribution_payout,
+    },
+    helpers::{
+        assert_account_exists, assert_transaction_failure, assert_transaction_success,
+        create_minimal_security_token_mint, create_spl_account, get_token_account_state,
+    },
+    receipt_tests::receipt_helpers::find_claim_action_receipt_pda,
+    wrap_tests::wrap_helpers::{create_underlying_mint, create_underlying_token_account},
+};
+
+const DECIMALS: u8 = 6;
+const ACTION_ID: u64 = 9;
+const RATE_NUMERATOR: u64 = 3;
+const RATE_DENOMINATOR: u64 = 2;
+
+struct PayoutDistribution {
+    mint: Pubkey,
+    mint_creator: Keypair,
+    holder: Keypair,
+    holder_account: Pubkey,
+    leaves: Vec<Leaf>,
+    merkle_tree: MerkleTree,
+    claim_verification_config: Pubkey,
+    payout_mint: Pubkey,
+}
+
+/// Distribution of 100 base units to the holder, with a funded payout escrow in a legacy SPL mint
+async fn setup_payout_distribution(context: &mut ProgramTestContext) -> PayoutDistribution {
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let mint_creator = context.payer.insecure_clone();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), DECIMALS)
+            .await;
+    let holder = Keypair::new();
+    let holder_account = create_spl_account(context, &mint_keypair, &holder).await;
+
+    let leaves = create_leaves(
+        &[(&holder_account, 100u64), (&Pubkey::new_unique(), 50u64)],
+        &mint,
+        DECIMALS,
+        ACTION_ID,
+    );
+    let (merkle_tree, _, _, claim_verification_config) = create_distribution_for_users(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        &mint_creator,
+        ACTION_ID,
+        150,
+        DECIMALS,
+        &leaves,
+    )
+    .await;
+
+    let payout_mint = create_underlying_mint(context, &TOKEN_PROGRAM_ID, DECIMALS).await;
+    let result = execute_create_distribution_payout(
+        &context.banks_client,
+        mint,
+        payout_mint,
+        TOKEN_PROGRAM_ID,
+        CreateDistributionPayoutInstructionArgs {
+            action_id: ACTION_ID,
+            merkle_root: merkle_tree.get_root(),
+            rate_numerator: RATE_NUMERATOR,
+            rate_denominator: RATE_DENOMINATOR,
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (distribution_payout_pda, _) = find_distribution_payout_pda(&mint, ACTION_ID, &payout_mint);
+    let account = assert_account_exists(context, distribution_payout_pda, true)
+        .await
+        .unwrap();
+    let distribution_payout = DistributionPayout::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution_payout.payout_mint, payout_mint);
+    assert_eq!(distribution_payout.merkle_root, merkle_tree.get_root());
+
+    // Issuer funds the payout escrow
+    create_underlying_token_account(
+        context,
+        &payout_mint,
+        &TOKEN_PROGRAM_ID,
+        &distribution_payout_pda,
+        DECIMALS,
+        1_000_000_000,
+    )
+    .await;
+
+    PayoutDistribution {
+        mint,
+        mint_creator,
+        holder,
+        holder_account,
+        leaves,
+        merkle_tree,
+        claim_verification_config,
+        payout_mint,
+    }
+}
+
+#[tokio::test]
+async fn test_should_claim_distribution_in_payout_mint_at_published_rate() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let distribution = setup_payout_distribution(context).await;
+    let payout_token_account = create_underlying_token_account(
+        context,
+        &distribution.payout_mint,
+        &TOKEN_PROGRAM_ID,
+        &distribution.holder.pubkey(),
+        DECIMALS,
+        0,
+    )
+    .await;
+
+    let amount = distribution.leaves[0].amount;
+    let merkle_proof = distribution.merkle_tree.get_proof_of_leaf(0);
+    let (receipt_account, _) = find_claim_action_receipt_pda(
+        &distribution.mint,
+        &distribution.holder_account,
+        ACTION_ID,
+        &merkle_proof,
+    );
+
+    let result = execute_claim_distribution_in_payout_mint(
+        &context.banks_client,
+        distribution.mint,
+        distribution.claim_verification_config,
+        distribution.holder_account,
+        receipt_account,
+        distribution.payout_mint,
+        TOKEN_PROGRAM_ID,
+        payout_token_account,
+        ClaimDistributionArgs {
+            action_id: ACTION_ID,
+            amount,
+            merkle_root: distribution.merkle_tree.get_root(),
+            leaf_index: 0,
+            merkle_proof: Some(merkle_proof),
+        },
+        &distribution.mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let payout = get_token_account_state(&mut context.banks_client, payout_token_account).await;
+    assert_eq!(
+        payout.base.amount,
+        amount * RATE_NUMERATOR / RATE_DENOMINATOR
+    );
+    let holder =
+        get_token_account_state(&mut context.banks_client, distribution.holder_account).await;
+    assert_eq!(holder.base.amount, 0);
+    assert_account_exists(context, receipt_account, true)
+        .await
+        .expect("Receipt account should be created");
+}
+
+#[tokio::test]
+async fn test_should_not_pay_out_distribution_to_other_owner() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let distribution = setup_payout_distribution(context).await;
+    let other_payout_token_account = create_underlying_token_account(
+        context,
+        &distribution.payout_mint,
+        &TOKEN_PROGRAM_ID,
+        &Keypair::new().pubkey(),
+        DECIMALS,
+        0,
+    )
+    .await;
+
+    let merkle_proof = distribution.merkle_tree.get_proof_of_leaf(0);
+    let (receipt_account, _) = find_claim_action_receipt_pda(
+        &distribution.mint,
+        &distribution.holder_account,
+        ACTION_ID,
+        &merkle_proof,
+    );
+
+    let result = execute_claim_distribution_in_payout_mint(
+        &context.banks_client,
+        distribution.mint,
+        distribution.claim_verification_config,
+        distribution.holder_account,
+        receipt_account,
+        distribution.payout_mint,
+        TOKEN_PROGRAM_ID,
+        other_payout_token_account,
+        ClaimDistributionArgs {
+            action_id: ACTION_ID,
+            amount: distribution.leaves[0].amount,
+            merkle_root: distribution.merkle_tree.get_root(),
+            leaf_index: 0,
+            merkle_proof: Some(merkle_proof),
+        },
+        &distribution.mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+}
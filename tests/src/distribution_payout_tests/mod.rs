@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod distribution_payout_tests;
+
+pub mod distribution_payout_helpers;
@@ -0,0 +1,85 @@
+use security_token_client::{
+    instructions::{AmendDistributionRoot, AmendDistributionRootInstructionArgs},
+    pda::{find_claim_deadline_pda, find_distribution_root_pda},
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id, ID as ASSOCIATED_TOKEN_PROGRAM_ID,
+};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::{
+    claim_tests::claim_helpers::find_distribution_escrow_authority_pda,
+    helpers::{find_mint_authority_pda, find_permanent_delegate_pda, send_tx},
+};
+
+/// Escrow token account of the distribution with the given merkle root
+pub fn find_distribution_escrow_token_account(
+    mint: &Pubkey,
+    action_id: u64,
+    merkle_root: &[u8; 32],
+) -> Pubkey {
+    let (distribution_escrow_authority, _) =
+        find_distribution_escrow_authority_pda(mint, action_id, merkle_root);
+    get_associated_token_address_with_program_id(
+        &distribution_escrow_authority,
+        mint,
+        &TOKEN_22_PROGRAM_ID,
+    )
+}
+
+/// Build and send AmendDistributionRoot instruction authorized by mint authority.
+/// Replacing moves the escrow of the old root into the escrow of the new root.
+pub async fn execute_amend_distribution_root(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    args: AmendDistributionRootInstructionArgs,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (permanent_delegate_authority, _) = find_permanent_delegate_pda(&mint);
+    let (claim_deadline_account, _) = find_claim_deadline_pda(&mint, args.action_id);
+    let amended_root = if args.supplemental {
+        args.new_merkle_root
+    } else {
+        args.merkle_root
+    };
+    let (distribution_root_account, _) =
+        find_distribution_root_pda(&mint, args.action_id, &amended_root);
+    let (new_escrow_authority, _) =
+        find_distribution_escrow_authority_pda(&mint, args.action_id, &args.new_merkle_root);
+    let escrow_token_account = (!args.supplemental)
+        .then(|| find_distribution_escrow_token_account(&mint, args.action_id, &args.merkle_root));
+    let new_escrow_token_account =
+        find_distribution_escrow_token_account(&mint, args.action_id, &args.new_merkle_root);
+
+    let ix = AmendDistributionRoot {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        permanent_delegate_authority,
+        mint_account: mint,
+        claim_deadline_account,
+        distribution_root_account,
+        escrow_token_account,
+        new_escrow_authority,
+        new_escrow_token_account: Some(new_escrow_token_account),
+        distribution_payout_account: None,
+        transfer_hook_program: Pubkey::from(security_token_transfer_hook::id()),
+        token_program: TOKEN_22_PROGRAM_ID,
+        associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(args);
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
@@ -0,0 +1,384 @@
+use security_token_client::{
+    accounts::DistributionRoot,
+    errors::SecurityTokenProgramError,
+    instructions::{
+        AmendDistributionRootInstructionArgs, CreateClaimDeadlineInstructionArgs,
+        MINT_DISCRIMINATOR,
+    },
+    pda::{find_distribution_root_pda, find_root_claim_receipt_pda},
+    types::{ClaimDistributionArgs, DistributionRootStatus},
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_merkle_tree_reference::MerkleTree;
+
+use crate::{
+    claim_deadline_tests::claim_deadline_helpers::execute_create_claim_deadline,
+    claim_tests::{
+        claim_helpers::{
+            create_distribution_for_users, create_leaves, execute_claim_distribution,
+            start_with_context_and_transfer_hook,
+        },
+        merkle_tree_helpers::{create_merkle_tree, Leaf},
+    },
+    coupon_tests::coupon_helpers::{current_timestamp, warp_to_timestamp},
+    distribution_root_tests::distribution_root_helpers::{
+        execute_amend_distribution_root, find_distribution_escrow_token_account,
+    },
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        find_verification_config_pda, from_ui_amount, get_token_account_state, mint_tokens_to,
+    },
+    receipt_tests::receipt_helpers::find_claim_action_receipt_pda,
+};
+
+const DECIMALS: u8 = 6;
+const ACTION_ID: u64 = 12;
+const CLAIM_WINDOW: i64 = 86_400;
+
+struct AmendableDistribution {
+    mint: Pubkey,
+    mint_authority_pda: Pubkey,
+    mint_creator: Keypair,
+    holder_accounts: Vec<Pubkey>,
+    merkle_tree: MerkleTree,
+    permanent_delegate: Pubkey,
+    escrow_token_account: Pubkey,
+    claim_verification_config: Pubkey,
+    claims_open_at: i64,
+}
+
+/// Funded distribution of 100 and 50 tokens to two holders, claimable for one day
+/// from `claims_open_in` seconds on
+async fn setup_amendable_distribution(
+    context: &mut ProgramTestContext,
+    claims_open_in: i64,
+) -> AmendableDistribution {
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let mint_creator = context.payer.insecure_clone();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), DECIMALS)
+            .await;
+
+    let mut holder_accounts = Vec::new();
+    for _ in 0..2 {
+        holder_accounts.push(create_spl_account(context, &mint_keypair, &Keypair::new()).await);
+    }
+
+    let leaves = create_leaves(
+        &[(&holder_accounts[0], 100u64), (&holder_accounts[1], 50u64)],
+        &mint,
+        DECIMALS,
+        ACTION_ID,
+    );
+    let (merkle_tree, permanent_delegate, escrow_token_account, claim_verification_config) =
+        create_distribution_for_users(
+            context,
+            &mint_keypair,
+            mint_authority_pda,
+            &mint_creator,
+            ACTION_ID,
+            150,
+            DECIMALS,
+            &leaves,
+        )
+        .await;
+
+    let claims_open_at = current_timestamp(context).await + claims_open_in;
+    let result = execute_create_claim_deadline(
+        &context.banks_client,
+        mint,
+        CreateClaimDeadlineInstructionArgs {
+            action_id: ACTION_ID,
+            claim_deadline: claims_open_at + CLAIM_WINDOW,
+            claims_open_at,
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    AmendableDistribution {
+        mint,
+        mint_authority_pda,
+        mint_creator,
+        holder_accounts,
+        merkle_tree,
+        permanent_delegate,
+        escrow_token_account,
+        claim_verification_config,
+        claims_open_at,
+    }
+}
+
+async fn claim(
+    context: &mut ProgramTestContext,
+    distribution: &AmendableDistribution,
+    merkle_tree: &MerkleTree,
+    leaves: &[Leaf],
+    leaf_index: usize,
+    receipt_account: Pubkey,
+) -> Result<(), BanksClientError> {
+    let merkle_root = merkle_tree.get_root();
+    execute_claim_distribution(
+        &mut context.banks_client,
+        distribution.mint,
+        distribution.claim_verification_config,
+        distribution.permanent_delegate,
+        distribution.mint,
+        leaves[leaf_index].eligible_token_account,
+        Some(find_distribution_escrow_token_account(
+            &distribution.mint,
+            ACTION_ID,
+            &merkle_root,
+        )),
+        receipt_account,
+        None,
+        ClaimDistributionArgs {
+            action_id: ACTION_ID,
+            amount: leaves[leaf_index].amount,
+            merkle_root,
+            leaf_index: leaf_index as u32,
+            merkle_proof: Some(merkle_tree.get_proof_of_leaf(leaf_index)),
+        },
+        &distribution.mint_creator,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_should_replace_distribution_root_before_claim_window_opens() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let distribution = setup_amendable_distribution(context, CLAIM_WINDOW).await;
+    let mint = distribution.mint;
+    let merkle_root = distribution.merkle_tree.get_root();
+
+    // Corrected eligibility list of the same total
+    let leaves = create_leaves(
+        &[
+            (&distribution.holder_accounts[0], 90u64),
+            (&distribution.holder_accounts[1], 60u64),
+        ],
+        &mint,
+        DECIMALS,
+        ACTION_ID,
+    );
+    let merkle_tree = create_merkle_tree(&leaves);
+    let new_merkle_root = merkle_tree.get_root();
+
+    let result = execute_amend_distribution_root(
+        &context.banks_client,
+        mint,
+        AmendDistributionRootInstructionArgs {
+            action_id: ACTION_ID,
+            merkle_root,
+            new_merkle_root,
+            supplemental: false,
+        },
+        &distribution.mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (distribution_root_pda, _) = find_distribution_root_pda(&mint, ACTION_ID, &merkle_root);
+    let account = assert_account_exists(context, distribution_root_pda, true)
+        .await
+        .unwrap();
+    let state = DistributionRoot::from_bytes(&account.data).unwrap();
+    assert_eq!(state.status, DistributionRootStatus::Superseded);
+
+    let escrow =
+        get_token_account_state(&mut context.banks_client, distribution.escrow_token_account).await;
+    assert_eq!(escrow.base.amount, 0);
+    let new_escrow_token_account =
+        find_distribution_escrow_token_account(&mint, ACTION_ID, &new_merkle_root);
+    let new_escrow =
+        get_token_account_state(&mut context.banks_client, new_escrow_token_account).await;
+    assert_eq!(new_escrow.base.amount, from_ui_amount(150, DECIMALS));
+
+    warp_to_timestamp(context, distribution.claims_open_at).await;
+
+    // The superseded root can no longer be claimed against
+    let old_leaves = create_leaves(
+        &[
+            (&distribution.holder_accounts[0], 100u64),
+            (&distribution.holder_accounts[1], 50u64),
+        ],
+        &mint,
+        DECIMALS,
+        ACTION_ID,
+    );
+    let (receipt_account, _) = find_claim_action_receipt_pda(
+        &mint,
+        &distribution.holder_accounts[0],
+        ACTION_ID,
+        &distribution.merkle_tree.get_proof_of_leaf(0),
+    );
+    let result = claim(
+        context,
+        &distribution,
+        &distribution.merkle_tree,
+        &old_leaves,
+        0,
+        receipt_account,
+    )
+    .await;
+    assert_security_token_error(
+        result,
+        SecurityTokenProgramError::DistributionRootSuperseded,
+    );
+
+    let (receipt_account, _) = find_claim_action_receipt_pda(
+        &mint,
+        &distribution.holder_accounts[0],
+        ACTION_ID,
+        &merkle_tree.get_proof_of_leaf(0),
+    );
+    let result = claim(
+        context,
+        &distribution,
+        &merkle_tree,
+        &leaves,
+        0,
+        receipt_account,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let holder =
+        get_token_account_state(&mut context.banks_client, distribution.holder_accounts[0]).await;
+    assert_eq!(holder.base.amount, from_ui_amount(90, DECIMALS));
+}
+
+#[tokio::test]
+async fn test_should_not_replace_distribution_root_after_claim_window_opens() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let distribution = setup_amendable_distribution(context, 0).await;
+
+    let leaves = create_leaves(
+        &[(&distribution.holder_accounts[0], 150u64)],
+        &distribution.mint,
+        DECIMALS,
+        ACTION_ID,
+    );
+    let result = execute_amend_distribution_root(
+        &context.banks_client,
+        distribution.mint,
+        AmendDistributionRootInstructionArgs {
+            action_id: ACTION_ID,
+            merkle_root: distribution.merkle_tree.get_root(),
+            new_merkle_root: create_merkle_tree(&leaves).get_root(),
+            supplemental: false,
+        },
+        &distribution.mint_creator,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::ClaimWindowStarted);
+}
+
+#[tokio::test]
+async fn test_should_claim_supplemental_distribution_root_after_original_claim() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let distribution = setup_amendable_distribution(context, 0).await;
+    let mint = distribution.mint;
+    let holder_account = distribution.holder_accounts[0];
+
+    let original_leaves = create_leaves(
+        &[
+            (&holder_account, 100u64),
+            (&distribution.holder_accounts[1], 50u64),
+        ],
+        &mint,
+        DECIMALS,
+        ACTION_ID,
+    );
+    let (receipt_account, _) = find_claim_action_receipt_pda(
+        &mint,
+        &holder_account,
+        ACTION_ID,
+        &distribution.merkle_tree.get_proof_of_leaf(0),
+    );
+    let result = claim(
+        context,
+        &distribution,
+        &distribution.merkle_tree,
+        &original_leaves,
+        0,
+        receipt_account,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // Supplemental list topping up the first holder
+    let leaves = create_leaves(
+        &[
+            (&holder_account, 20u64),
+            (&distribution.holder_accounts[1], 10u64),
+        ],
+        &mint,
+        DECIMALS,
+        ACTION_ID,
+    );
+    let merkle_tree = create_merkle_tree(&leaves);
+    let new_merkle_root = merkle_tree.get_root();
+    let result = execute_amend_distribution_root(
+        &context.banks_client,
+        mint,
+        AmendDistributionRootInstructionArgs {
+            action_id: ACTION_ID,
+            merkle_root: distribution.merkle_tree.get_root(),
+            new_merkle_root,
+            supplemental: true,
+        },
+        &distribution.mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (mint_verification_config, _) = find_verification_config_pda(mint, MINT_DISCRIMINATOR);
+    let result = mint_tokens_to(
+        &context.banks_client,
+        from_ui_amount(30, DECIMALS),
+        mint,
+        find_distribution_escrow_token_account(&mint, ACTION_ID, &new_merkle_root),
+        distribution.mint_authority_pda,
+        mint_verification_config,
+        &distribution.mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // Receipts of the supplemental root are scoped to it
+    let proof = merkle_tree.get_proof_of_leaf(0);
+    let (receipt_account, _) =
+        find_claim_action_receipt_pda(&mint, &holder_account, ACTION_ID, &proof);
+    let result = claim(
+        context,
+        &distribution,
+        &merkle_tree,
+        &leaves,
+        0,
+        receipt_account,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let (receipt_account, _) =
+        find_root_claim_receipt_pda(&mint, &holder_account, ACTION_ID, &proof, &new_merkle_root);
+    let result = claim(
+        context,
+        &distribution,
+        &merkle_tree,
+        &leaves,
+        0,
+        receipt_account,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let holder = get_token_account_state(&mut context.banks_client, holder_account).await;
+    assert_eq!(holder.base.amount, from_ui_amount(120, DECIMALS));
+}
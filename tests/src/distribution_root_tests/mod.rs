@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod distribution_root_tests;
+
+pub mod distribution_root_helpers;
@@ -0,0 +1,116 @@
+use security_token_client::{
+    instructions::{
+        AnchorDocument, AnchorDocumentInstructionArgs, RemoveDocument,
+        RemoveDocumentInstructionArgs, UpdateDocument, UpdateDocumentInstructionArgs,
+    },
+    pda::find_document_registry_pda,
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use crate::helpers::{find_mint_authority_pda, send_tx};
+
+/// Anchor a document of `mint` through the mint authority of the payer
+pub async fn anchor_document(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    name: &str,
+    uri: &str,
+    sha256: [u8; 32],
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let mut ix = AnchorDocument {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: payer.pubkey(),
+        payer: payer.pubkey(),
+        mint_account: mint,
+        document_registry_account: find_document_registry_pda(&mint).0,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction(AnchorDocumentInstructionArgs {
+        name: name.to_string(),
+        uri: uri.to_string(),
+        sha256,
+    });
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
+
+/// Replace the URI and hash of an anchored document of `mint`
+pub async fn update_document(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    name: &str,
+    uri: &str,
+    sha256: [u8; 32],
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let mut ix = UpdateDocument {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: payer.pubkey(),
+        payer: payer.pubkey(),
+        mint_account: mint,
+        document_registry_account: find_document_registry_pda(&mint).0,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction(UpdateDocumentInstructionArgs {
+        name: name.to_string(),
+        uri: uri.to_string(),
+        sha256,
+    });
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
+
+/// Remove an anchored document of `mint`
+pub async fn remove_document(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    name: &str,
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let mut ix = RemoveDocument {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: payer.pubkey(),
+        payer: payer.pubkey(),
+        mint_account: mint,
+        document_registry_account: find_document_registry_pda(&mint).0,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction(RemoveDocumentInstructionArgs {
+        name: name.to_string(),
+    });
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
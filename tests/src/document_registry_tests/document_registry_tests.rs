@@ -0,0 +1,153 @@
+use security_token_client::{
+    accounts::DocumentRegistry,
+    document_registry::{decode_documents, AnchoredDocument},
+    errors::SecurityTokenProgramError,
+    pda::find_document_registry_pda,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use crate::{
+    document_registry_tests::document_registry_helpers::{
+        anchor_document, remove_document, update_document,
+    },
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, start_with_context,
+    },
+    metadata_schema_tests::metadata_schema_helpers::create_mint_with_metadata,
+};
+
+const PROSPECTUS_HASH: [u8; 32] = [0xab; 32];
+const AMENDED_PROSPECTUS_HASH: [u8; 32] = [0xcd; 32];
+const SUBSCRIPTION_HASH: [u8; 32] = [0xef; 32];
+
+async fn anchored_documents(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+) -> Vec<AnchoredDocument> {
+    let (registry_pda, _) = find_document_registry_pda(&mint);
+    let account = assert_account_exists(context, registry_pda, true)
+        .await
+        .unwrap();
+    let registry = DocumentRegistry::from_bytes(&account.data).unwrap();
+    assert_eq!(registry.mint, mint);
+    decode_documents(&registry.documents).unwrap()
+}
+
+#[tokio::test]
+async fn test_should_anchor_documents() {
+    let context = &mut start_with_context().await;
+    let mint = create_mint_with_metadata(context).await.pubkey();
+
+    let result = anchor_document(
+        context,
+        mint,
+        "prospectus",
+        "ipfs://prospectus",
+        PROSPECTUS_HASH,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = anchor_document(
+        context,
+        mint,
+        "subscription",
+        "https://example.com/subscription.pdf",
+        SUBSCRIPTION_HASH,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let documents = anchored_documents(context, mint).await;
+    assert_eq!(
+        documents,
+        vec![
+            AnchoredDocument {
+                name: "prospectus".to_string(),
+                uri: "ipfs://prospectus".to_string(),
+                sha256: PROSPECTUS_HASH,
+            },
+            AnchoredDocument {
+                name: "subscription".to_string(),
+                uri: "https://example.com/subscription.pdf".to_string(),
+                sha256: SUBSCRIPTION_HASH,
+            },
+        ]
+    );
+
+    // Names are unique, UpdateDocument replaces an anchored document
+    let result = anchor_document(
+        context,
+        mint,
+        "prospectus",
+        "ipfs://other",
+        AMENDED_PROSPECTUS_HASH,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::DocumentAlreadyAnchored);
+
+    // The all-zero hash is rejected
+    let result = anchor_document(context, mint, "terms", "ipfs://terms", [0; 32]).await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_update_and_remove_documents() {
+    let context = &mut start_with_context().await;
+    let mint = create_mint_with_metadata(context).await.pubkey();
+
+    let result = update_document(
+        context,
+        mint,
+        "prospectus",
+        "ipfs://amended",
+        AMENDED_PROSPECTUS_HASH,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let result = anchor_document(
+        context,
+        mint,
+        "prospectus",
+        "ipfs://prospectus",
+        PROSPECTUS_HASH,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = update_document(
+        context,
+        mint,
+        "prospectus",
+        "ipfs://amended-prospectus",
+        AMENDED_PROSPECTUS_HASH,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let documents = anchored_documents(context, mint).await;
+    assert_eq!(documents.len(), 1);
+    assert_eq!(documents[0].uri, "ipfs://amended-prospectus");
+    assert_eq!(documents[0].sha256, AMENDED_PROSPECTUS_HASH);
+
+    let result = update_document(
+        context,
+        mint,
+        "subscription",
+        "ipfs://subscription",
+        SUBSCRIPTION_HASH,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::DocumentNotFound);
+
+    let result = remove_document(context, mint, "subscription").await;
+    assert_security_token_error(result, SecurityTokenProgramError::DocumentNotFound);
+
+    let result = remove_document(context, mint, "prospectus").await;
+    assert_transaction_success(result);
+    assert!(anchored_documents(context, mint).await.is_empty());
+}
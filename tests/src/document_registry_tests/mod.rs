@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod document_registry_tests;
+
+pub mod document_registry_helpers;
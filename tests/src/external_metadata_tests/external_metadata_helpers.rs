@@ -0,0 +1,219 @@
+use security_token_client::{
+    instructions::{InitializeMintBuilder, UpdateMetadataBuilder},
+    metadata_schema::encode_additional_metadata,
+    types::{
+        InitializeMintArgs, MetadataPointerArgs, MintArgs, TokenMetadataArgs, UpdateMetadataArgs,
+    },
+};
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program_test::{processor, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_discriminator::SplDiscriminate;
+use spl_token_metadata_interface::borsh::{to_vec, BorshDeserialize};
+use spl_token_metadata_interface::instruction::TokenMetadataInstruction;
+use spl_token_metadata_interface::state::TokenMetadata;
+
+use crate::helpers::{
+    add_dummy_verification_program, find_mint_authority_pda, find_mint_freeze_authority_pda,
+    initialize_program, send_tx,
+};
+
+/// Program id of the test metadata program
+pub const EXTERNAL_METADATA_PROGRAM_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+/// Space allocated for external metadata accounts before InitializeMint
+pub const EXTERNAL_METADATA_ACCOUNT_LEN: usize = 256;
+
+const TLV_HEADER_LEN: usize = 8 + 4;
+
+/// Minimal metadata program implementing the token metadata interface. Stores the
+/// TokenMetadata as the only type-length-value entry of the metadata account
+pub fn external_metadata_processor(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    let [metadata_info, update_authority_info, rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let token_metadata = match TokenMetadataInstruction::unpack(input)? {
+        TokenMetadataInstruction::Initialize(data) => {
+            let [mint_info, mint_authority_info, ..] = rest else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            if !mint_authority_info.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            TokenMetadata {
+                update_authority: Some(*update_authority_info.key).try_into()?,
+                mint: *mint_info.key,
+                name: data.name,
+                symbol: data.symbol,
+                uri: data.uri,
+                additional_metadata: vec![],
+            }
+        }
+        instruction => {
+            let mut token_metadata = read_external_metadata(&metadata_info.try_borrow_data()?)
+                .ok_or(ProgramError::UninitializedAccount)?;
+            let authority: Option<Pubkey> = token_metadata.update_authority.into();
+            if !update_authority_info.is_signer || authority != Some(*update_authority_info.key) {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            match instruction {
+                TokenMetadataInstruction::UpdateField(data) => {
+                    token_metadata.update(data.field, data.value)
+                }
+                TokenMetadataInstruction::RemoveKey(data) => {
+                    token_metadata.remove_key(&data.key);
+                }
+                TokenMetadataInstruction::UpdateAuthority(data) => {
+                    token_metadata.update_authority = data.new_authority
+                }
+                _ => return Err(ProgramError::InvalidInstructionData),
+            }
+            token_metadata
+        }
+    };
+
+    let value = to_vec(&token_metadata).map_err(|_| ProgramError::InvalidAccountData)?;
+    let entry_len = TLV_HEADER_LEN + value.len();
+    if metadata_info.data_len() < entry_len {
+        metadata_info.resize(entry_len)?;
+    }
+    let mut data = metadata_info.try_borrow_mut_data()?;
+    data[..8].copy_from_slice(TokenMetadata::SPL_DISCRIMINATOR_SLICE);
+    data[8..TLV_HEADER_LEN].copy_from_slice(&(value.len() as u32).to_le_bytes());
+    data[TLV_HEADER_LEN..entry_len].copy_from_slice(&value);
+    Ok(())
+}
+
+/// Read the TokenMetadata written by the test metadata program
+pub fn read_external_metadata(data: &[u8]) -> Option<TokenMetadata> {
+    if data.get(..8)? != TokenMetadata::SPL_DISCRIMINATOR_SLICE {
+        return None;
+    }
+    let len = u32::from_le_bytes(data.get(8..TLV_HEADER_LEN)?.try_into().ok()?) as usize;
+    TokenMetadata::try_from_slice(data.get(TLV_HEADER_LEN..TLV_HEADER_LEN + len)?).ok()
+}
+
+/// Start a context with the test metadata program and an empty metadata account
+/// owned by it
+pub async fn start_with_external_metadata_account(metadata_account: Pubkey) -> ProgramTestContext {
+    let mut pt = initialize_program();
+    pt.prefer_bpf(false);
+    add_dummy_verification_program(&mut pt);
+    pt.add_program(
+        "external_metadata_program",
+        EXTERNAL_METADATA_PROGRAM_ID,
+        processor!(external_metadata_processor),
+    );
+    pt.add_account(
+        metadata_account,
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![0; EXTERNAL_METADATA_ACCOUNT_LEN],
+            owner: EXTERNAL_METADATA_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    pt.start_with_context().await
+}
+
+fn token_metadata_args(name: &str, additional_metadata: &[(&str, &str)]) -> TokenMetadataArgs {
+    TokenMetadataArgs {
+        name: name.to_string(),
+        symbol: "EXT".to_string(),
+        uri: "https://example.com/ext.json".to_string(),
+        additional_metadata: encode_additional_metadata(additional_metadata),
+    }
+}
+
+/// Initialize a mint whose MetadataPointer targets `metadata_account`, passing the
+/// external metadata accounts unless `metadata_program` is None
+pub async fn initialize_mint_with_external_metadata(
+    context: &mut ProgramTestContext,
+    mint_keypair: &Keypair,
+    metadata_account: Pubkey,
+    metadata_program: Option<Pubkey>,
+    additional_metadata: &[(&str, &str)],
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint_keypair.pubkey(), &payer.pubkey());
+    let (freeze_authority_pda, _) = find_mint_freeze_authority_pda(&mint_keypair.pubkey());
+
+    let ix = InitializeMintBuilder::new()
+        .mint(mint_keypair.pubkey())
+        .payer(payer.pubkey())
+        .authority(mint_authority_pda)
+        .external_metadata_account(metadata_program.map(|_| metadata_account))
+        .metadata_program(metadata_program)
+        .initialize_mint_args(InitializeMintArgs {
+            ix_mint: MintArgs {
+                decimals: 6,
+                mint_authority: payer.pubkey(),
+                freeze_authority: freeze_authority_pda,
+            },
+            ix_metadata_pointer: Some(MetadataPointerArgs {
+                authority: payer.pubkey(),
+                metadata_address: metadata_account,
+            }),
+            ix_metadata: Some(token_metadata_args("External Token", additional_metadata)),
+            ix_scaled_ui_amount: None,
+            ix_transfer_fee: None,
+            ix_default_account_state: None,
+        })
+        .instruction();
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer, mint_keypair],
+    )
+    .await
+}
+
+/// Update the external metadata of `mint` through the mint authority
+pub async fn update_external_metadata(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    metadata_account: Pubkey,
+    metadata_program: Pubkey,
+    name: &str,
+    additional_metadata: &[(&str, &str)],
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let mut ix = UpdateMetadataBuilder::new()
+        .mint(mint)
+        .verification_config_or_mint_authority(mint_authority_pda)
+        .instructions_sysvar_or_creator(payer.pubkey())
+        .mint_authority(mint_authority_pda)
+        .payer(payer.pubkey())
+        .mint_account(mint)
+        .external_metadata_account(Some(metadata_account))
+        .metadata_program(Some(metadata_program))
+        .update_metadata_args(UpdateMetadataArgs {
+            metadata: token_metadata_args(name, additional_metadata),
+        })
+        .instruction();
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
@@ -0,0 +1,148 @@
+use security_token_client::errors::SecurityTokenProgramError;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::{
+    external_metadata_tests::external_metadata_helpers::{
+        initialize_mint_with_external_metadata, read_external_metadata,
+        start_with_external_metadata_account, update_external_metadata,
+        EXTERNAL_METADATA_PROGRAM_ID,
+    },
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, find_mint_authority_pda,
+    },
+};
+
+#[tokio::test]
+async fn test_should_initialize_and_update_external_metadata() {
+    let metadata_account = Pubkey::new_unique();
+    let context = &mut start_with_external_metadata_account(metadata_account).await;
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+
+    let result = initialize_mint_with_external_metadata(
+        context,
+        &mint_keypair,
+        metadata_account,
+        Some(EXTERNAL_METADATA_PROGRAM_ID),
+        &[("isin", "US0378331005"), ("issuer", "Hoodies Inc")],
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &context.payer.pubkey());
+    let account = assert_account_exists(context, metadata_account, true)
+        .await
+        .unwrap();
+    assert_eq!(account.owner, EXTERNAL_METADATA_PROGRAM_ID);
+    let metadata = read_external_metadata(&account.data).unwrap();
+    assert_eq!(metadata.mint, mint);
+    assert_eq!(
+        Option::<Pubkey>::from(metadata.update_authority),
+        Some(mint_authority_pda)
+    );
+    assert_eq!(metadata.name, "External Token");
+    assert_eq!(metadata.additional_metadata.len(), 2);
+
+    let result = update_external_metadata(
+        context,
+        mint,
+        metadata_account,
+        EXTERNAL_METADATA_PROGRAM_ID,
+        "Renamed External Token",
+        &[("isin", "US0378331005"), ("jurisdiction", "US")],
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let account = assert_account_exists(context, metadata_account, true)
+        .await
+        .unwrap();
+    let metadata = read_external_metadata(&account.data).unwrap();
+    assert_eq!(metadata.name, "Renamed External Token");
+    assert_eq!(
+        metadata.additional_metadata,
+        vec![
+            ("isin".to_string(), "US0378331005".to_string()),
+            ("jurisdiction".to_string(), "US".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_should_not_initialize_external_metadata_without_metadata_program() {
+    let metadata_account = Pubkey::new_unique();
+    let context = &mut start_with_external_metadata_account(metadata_account).await;
+
+    let result = initialize_mint_with_external_metadata(
+        context,
+        &Keypair::new(),
+        metadata_account,
+        None,
+        &[],
+    )
+    .await;
+    assert_security_token_error(
+        result,
+        SecurityTokenProgramError::ExternalMetadataForbidsData,
+    );
+}
+
+#[tokio::test]
+async fn test_should_not_initialize_external_metadata_with_invalid_program() {
+    let metadata_account = Pubkey::new_unique();
+    let context = &mut start_with_external_metadata_account(metadata_account).await;
+
+    // SPL Token 2022 only holds metadata in mints
+    let result = initialize_mint_with_external_metadata(
+        context,
+        &Keypair::new(),
+        metadata_account,
+        Some(spl_token_2022::ID),
+        &[],
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::InvalidMetadataProgram);
+
+    // The metadata account must be owned by the metadata program
+    let result = initialize_mint_with_external_metadata(
+        context,
+        &Keypair::new(),
+        Pubkey::new_unique(),
+        Some(EXTERNAL_METADATA_PROGRAM_ID),
+        &[],
+    )
+    .await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_not_update_external_metadata_of_other_account() {
+    let metadata_account = Pubkey::new_unique();
+    let context = &mut start_with_external_metadata_account(metadata_account).await;
+    let mint_keypair = Keypair::new();
+
+    let result = initialize_mint_with_external_metadata(
+        context,
+        &mint_keypair,
+        metadata_account,
+        Some(EXTERNAL_METADATA_PROGRAM_ID),
+        &[],
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = update_external_metadata(
+        context,
+        mint_keypair.pubkey(),
+        Pubkey::new_unique(),
+        EXTERNAL_METADATA_PROGRAM_ID,
+        "Other",
+        &[],
+    )
+    .await;
+    assert_transaction_failure(result);
+}
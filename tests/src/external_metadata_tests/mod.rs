@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod external_metadata_tests;
+
+pub mod external_metadata_helpers;
@@ -0,0 +1,346 @@
+//! Deterministic test world.
+//!
+//! `Fixture::new(seed)` starts a program test with the security token program, the transfer
+//! hook and the dummy verification program, and provisions:
+//! - a mint with metadata pointer, metadata and scaled UI amount extensions,
+//! - an introspection mode verification config for every instruction, verified by the dummy
+//!   verification program,
+//! - funded holders with token balances,
+//! - an active distribution of `DISTRIBUTION_ACTION_ID` with an escrow holding every leaf,
+//! - a static rate for `RATE_ACTION_ID`.
+//!
+//! Every keypair is derived from the seed, so keys and PDAs are the same on every run.
+
+use std::collections::BTreeMap;
+
+use security_token_client::{
+    discriminators::SecurityTokenInstructionDiscriminators,
+    instructions::MINT_DISCRIMINATOR,
+    types::{
+        CreateDistributionEscrowArgs, CreateRateArgs, InitializeMintArgs,
+        InitializeVerificationConfigArgs, MetadataPointerArgs, MintArgs, RateConfig,
+        ScaledUiAmountConfigArgs, TokenMetadataArgs,
+    },
+};
+use solana_keccak_hasher::hashv;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    account::Account,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    signer::keypair::keypair_from_seed,
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_merkle_tree_reference::MerkleTree;
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::{
+    claim_tests::{
+        claim_helpers::{
+            execute_create_distribution_escrow_account, find_distribution_escrow_authority_pda,
+        },
+        merkle_tree_helpers::{create_merkle_tree, Leaf},
+    },
+    helpers::{
+        add_dummy_verification_program, assert_transaction_success, create_spl_account,
+        find_mint_authority_pda, find_mint_freeze_authority_pda, find_permanent_delegate_pda,
+        find_verification_config_pda, get_default_verification_programs,
+        initialize_mint_for_creator, initialize_program, initialize_verification_config_for_payer,
+        mint_tokens_to,
+    },
+    rate_tests::rate_helpers::create_rate_account,
+};
+
+pub const FIXTURE_DECIMALS: u8 = 6;
+pub const FIXTURE_HOLDERS: usize = 3;
+/// Raw balance minted to every holder
+pub const HOLDER_AMOUNT: u64 = 1_000_000_000;
+/// Raw amount every holder can claim from the distribution
+pub const DISTRIBUTION_LEAF_AMOUNT: u64 = 10_000_000;
+pub const DISTRIBUTION_ACTION_ID: u64 = 1;
+pub const RATE_ACTION_ID: u64 = 2;
+/// Lamports of the mint creator and every holder
+const FUNDED_LAMPORTS: u64 = 100_000_000_000;
+
+/// Holder of the fixture mint
+pub struct FixtureHolder {
+    pub owner: Keypair,
+    pub token_account: Pubkey,
+    pub amount: u64,
+}
+
+/// Distribution of `DISTRIBUTION_ACTION_ID` to every holder
+pub struct FixtureDistribution {
+    pub action_id: u64,
+    pub leaves: Vec<Leaf>,
+    pub tree: MerkleTree,
+    pub escrow_authority: Pubkey,
+    pub escrow_token_account: Pubkey,
+}
+
+/// Provisioned test world, see the module docs
+pub struct Fixture {
+    pub context: ProgramTestContext,
+    pub seed: u64,
+    pub mint: Keypair,
+    pub mint_creator: Keypair,
+    pub mint_authority: Pubkey,
+    pub freeze_authority: Pubkey,
+    pub permanent_delegate: Pubkey,
+    /// Verification config of every instruction, by instruction discriminator
+    pub verification_configs: BTreeMap<u8, Pubkey>,
+    pub holders: Vec<FixtureHolder>,
+    pub distribution: FixtureDistribution,
+    /// Static 1:1 rate from the mint to itself
+    pub rate: Pubkey,
+}
+
+/// Keypair derived from `seed` and `label`
+pub fn fixture_keypair(seed: u64, label: &[u8]) -> Keypair {
+    let secret = hashv(&[b"fixture", &seed.to_le_bytes(), label]);
+    keypair_from_seed(secret.as_ref()).expect("32 byte seed")
+}
+
+fn holder_label(index: usize) -> Vec<u8> {
+    [b"holder".as_ref(), &(index as u64).to_le_bytes()].concat()
+}
+
+fn funded_account() -> Account {
+    Account {
+        lamports: FUNDED_LAMPORTS,
+        data: vec![],
+        owner: solana_system_interface::program::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+impl Fixture {
+    pub async fn new(seed: u64) -> Self {
+        let mint = fixture_keypair(seed, b"mint");
+        let mint_creator = fixture_keypair(seed, b"mint_creator");
+        let owners: Vec<Keypair> = (0..FIXTURE_HOLDERS)
+            .map(|index| fixture_keypair(seed, &holder_label(index)))
+            .collect();
+
+        let mut pt = initialize_program();
+        pt.prefer_bpf(false);
+        pt.add_program(
+            "security_token_transfer_hook",
+            Pubkey::from(security_token_transfer_hook::id()),
+            None,
+        );
+        add_dummy_verification_program(&mut pt);
+        for keypair in std::iter::once(&mint_creator).chain(&owners) {
+            pt.add_account(keypair.pubkey(), funded_account());
+        }
+        let mut context = pt.start_with_context().await;
+
+        let (mint_authority, _) = find_mint_authority_pda(&mint.pubkey(), &mint_creator.pubkey());
+        let (freeze_authority, _) = find_mint_freeze_authority_pda(&mint.pubkey());
+        let (permanent_delegate, _) = find_permanent_delegate_pda(&mint.pubkey());
+
+        let mint_args = InitializeMintArgs {
+            ix_mint: MintArgs {
+                decimals: FIXTURE_DECIMALS,
+                mint_authority: mint_creator.pubkey(),
+                freeze_authority,
+            },
+            ix_metadata_pointer: Some(MetadataPointerArgs {
+                authority: mint_creator.pubkey(),
+                metadata_address: mint.pubkey(),
+            }),
+            ix_metadata: Some(TokenMetadataArgs {
+                name: "Fixture Token".to_string(),
+                symbol: "FIX".to_string(),
+                uri: "https://example.com/fixture.json".to_string(),
+                additional_metadata: vec![],
+            }),
+            ix_scaled_ui_amount: Some(ScaledUiAmountConfigArgs {
+                authority: mint_authority,
+                multiplier: 1f64.to_le_bytes(),
+                new_multiplier_effective_timestamp: 0,
+                new_multiplier: 1f64.to_le_bytes(),
+            }),
+            ix_transfer_fee: None,
+            ix_default_account_state: None,
+        };
+        initialize_mint_for_creator(
+            &mut context,
+            &mint,
+            mint_authority,
+            &mint_creator,
+            &mint_args,
+        )
+        .await;
+
+        let mut verification_configs = BTreeMap::new();
+        for discriminator in SecurityTokenInstructionDiscriminators::ALL {
+            let discriminator = discriminator as u8;
+            let (config, _) = find_verification_config_pda(mint.pubkey(), discriminator);
+            let args = InitializeVerificationConfigArgs {
+                instruction_discriminator: discriminator,
+                cpi_mode: false,
+                program_addresses: get_default_verification_programs(),
+                threshold: 0,
+                lookback_window: 0,
+                require_top_level: false,
+                timelock_seconds: 0,
+            };
+            let result = initialize_verification_config_for_payer(
+                &context.banks_client,
+                &mint_creator,
+                &mint,
+                mint_authority,
+                config,
+                &args,
+            )
+            .await;
+            assert_transaction_success(result);
+            verification_configs.insert(discriminator, config);
+        }
+        let mint_config = verification_configs[&MINT_DISCRIMINATOR];
+
+        let mut holders = Vec::with_capacity(FIXTURE_HOLDERS);
+        for owner in owners {
+            let token_account = create_spl_account(&mut context, &mint, &owner).await;
+            let result = mint_tokens_to(
+                &context.banks_client,
+                HOLDER_AMOUNT,
+                mint.pubkey(),
+                token_account,
+                mint_authority,
+                mint_config,
+                &mint_creator,
+            )
+            .await;
+            assert_transaction_success(result);
+            holders.push(FixtureHolder {
+                owner,
+                token_account,
+                amount: HOLDER_AMOUNT,
+            });
+        }
+
+        let distribution = create_fixture_distribution(
+            &mut context,
+            &mint,
+            &mint_creator,
+            mint_authority,
+            mint_config,
+            &holders,
+        )
+        .await;
+
+        let (rate, result) = create_rate_account(
+            &mut context,
+            mint.pubkey(),
+            mint_authority,
+            mint_creator.pubkey(),
+            mint.pubkey(),
+            mint.pubkey(),
+            CreateRateArgs {
+                action_id: RATE_ACTION_ID,
+                rate: RateConfig {
+                    rounding: 0,
+                    numerator: 1,
+                    denominator: 1,
+                },
+            },
+            Some(&mint_creator),
+        )
+        .await;
+        assert_transaction_success(result);
+
+        Self {
+            context,
+            seed,
+            mint,
+            mint_creator,
+            mint_authority,
+            freeze_authority,
+            permanent_delegate,
+            verification_configs,
+            holders,
+            distribution,
+            rate,
+        }
+    }
+
+    /// Verification config of the instruction with `discriminator`
+    pub fn verification_config(&self, discriminator: u8) -> Pubkey {
+        self.verification_configs[&discriminator]
+    }
+}
+
+async fn create_fixture_distribution(
+    context: &mut ProgramTestContext,
+    mint: &Keypair,
+    mint_creator: &Keypair,
+    mint_authority: Pubkey,
+    mint_config: Pubkey,
+    holders: &[FixtureHolder],
+) -> FixtureDistribution {
+    let leaves: Vec<Leaf> = holders
+        .iter()
+        .map(|holder| {
+            Leaf::new(
+                holder.token_account,
+                mint.pubkey(),
+                DISTRIBUTION_ACTION_ID,
+                DISTRIBUTION_LEAF_AMOUNT,
+            )
+        })
+        .collect();
+    let tree = create_merkle_tree(&leaves);
+    let merkle_root = tree.get_root();
+
+    let (escrow_authority, _) = find_distribution_escrow_authority_pda(
+        &mint.pubkey(),
+        DISTRIBUTION_ACTION_ID,
+        &merkle_root,
+    );
+    let escrow_token_account = get_associated_token_address_with_program_id(
+        &escrow_authority,
+        &mint.pubkey(),
+        &TOKEN_22_PROGRAM_ID,
+    );
+
+    let result = execute_create_distribution_escrow_account(
+        &context.banks_client,
+        mint.pubkey(),
+        mint_authority,
+        mint_creator.pubkey(),
+        escrow_authority,
+        mint.pubkey(),
+        escrow_token_account,
+        CreateDistributionEscrowArgs {
+            action_id: DISTRIBUTION_ACTION_ID,
+            merkle_root,
+            claim_bitmap_leaves: 0,
+        },
+        mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = mint_tokens_to(
+        &context.banks_client,
+        DISTRIBUTION_LEAF_AMOUNT * leaves.len() as u64,
+        mint.pubkey(),
+        escrow_token_account,
+        mint_authority,
+        mint_config,
+        mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    FixtureDistribution {
+        action_id: DISTRIBUTION_ACTION_ID,
+        leaves,
+        tree,
+        escrow_authority,
+        escrow_token_account,
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    fixtures::fixture_helpers::{
+        Fixture, DISTRIBUTION_LEAF_AMOUNT, FIXTURE_HOLDERS, HOLDER_AMOUNT,
+    },
+    helpers::{assert_account_exists, get_token_account_state},
+};
+use security_token_client::{
+    discriminators::SecurityTokenInstructionDiscriminators, instructions::TRANSFER_DISCRIMINATOR,
+};
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn test_fixture_is_deterministic() {
+    let first = Fixture::new(7).await;
+    let second = Fixture::new(7).await;
+    let other = Fixture::new(8).await;
+
+    assert_eq!(first.mint.pubkey(), second.mint.pubkey());
+    assert_eq!(first.mint_authority, second.mint_authority);
+    assert_eq!(first.verification_configs, second.verification_configs);
+    assert_eq!(
+        first.distribution.escrow_token_account,
+        second.distribution.escrow_token_account
+    );
+    assert_eq!(first.rate, second.rate);
+    for (first, second) in first.holders.iter().zip(&second.holders) {
+        assert_eq!(first.owner.pubkey(), second.owner.pubkey());
+        assert_eq!(first.token_account, second.token_account);
+    }
+
+    assert_ne!(first.mint.pubkey(), other.mint.pubkey());
+}
+
+#[tokio::test]
+async fn test_fixture_provisions_the_world() {
+    let mut fixture = Fixture::new(1).await;
+
+    assert_eq!(
+        fixture.verification_configs.len(),
+        SecurityTokenInstructionDiscriminators::ALL.len()
+    );
+    let transfer_config = fixture.verification_config(TRANSFER_DISCRIMINATOR);
+    assert_account_exists(&mut fixture.context, transfer_config, true)
+        .await
+        .unwrap();
+    assert_account_exists(&mut fixture.context, fixture.rate, true)
+        .await
+        .unwrap();
+
+    assert_eq!(fixture.holders.len(), FIXTURE_HOLDERS);
+    for holder in &fixture.holders {
+        let token_account =
+            get_token_account_state(&mut fixture.context.banks_client, holder.token_account).await;
+        assert_eq!(token_account.base.amount, HOLDER_AMOUNT);
+        assert_eq!(token_account.base.owner, holder.owner.pubkey());
+    }
+
+    let escrow = get_token_account_state(
+        &mut fixture.context.banks_client,
+        fixture.distribution.escrow_token_account,
+    )
+    .await;
+    assert_eq!(
+        escrow.base.amount,
+        DISTRIBUTION_LEAF_AMOUNT * FIXTURE_HOLDERS as u64
+    );
+}
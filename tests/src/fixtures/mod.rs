@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod fixture_tests;
+
+pub mod fixture_helpers;
@@ -0,0 +1,97 @@
+use security_token_client::{
+    instructions::{Freeze, FreezeInstructionArgs, Thaw, ThawExpired},
+    pda::{find_agent_pda, find_freeze_expiry_pda},
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{find_mint_freeze_authority_pda, send_tx};
+
+/// Build and send Freeze instruction with `auto_thaw_at` signed by an agent,
+/// the agent pays for the FreezeExpiry account
+pub async fn execute_freeze_with_expiry_by_agent(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    token_account: Pubkey,
+    agent: &Keypair,
+    auto_thaw_at: i64,
+) -> Result<(), BanksClientError> {
+    let (agent_account, _) = find_agent_pda(&mint, &agent.pubkey());
+    let (freeze_authority, _) = find_mint_freeze_authority_pda(&mint);
+    let (freeze_expiry_account, _) = find_freeze_expiry_pda(&token_account);
+
+    let mut ix = Freeze {
+        mint,
+        verification_config: agent_account,
+        instructions_sysvar: agent.pubkey(),
+        freeze_authority,
+        mint_account: mint,
+        token_account,
+        token_program: TOKEN_22_PROGRAM_ID,
+        payer: Some(agent.pubkey()),
+        freeze_expiry_account: Some(freeze_expiry_account),
+        system_program: Some(solana_program::system_program::id()),
+    }
+    .instruction(FreezeInstructionArgs {
+        auto_thaw_at: Some(auto_thaw_at),
+    });
+    // Agent signs in place of the instructions sysvar
+    ix.accounts[2].is_signer = true;
+
+    send_tx(banks_client, vec![ix], &agent.pubkey(), vec![agent]).await
+}
+
+/// Build and send Thaw instruction signed by an agent, closing the FreezeExpiry paid by the agent
+pub async fn execute_thaw_with_expiry_by_agent(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    token_account: Pubkey,
+    agent: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (agent_account, _) = find_agent_pda(&mint, &agent.pubkey());
+    let (freeze_authority, _) = find_mint_freeze_authority_pda(&mint);
+    let (freeze_expiry_account, _) = find_freeze_expiry_pda(&token_account);
+
+    let mut ix = Thaw {
+        mint,
+        verification_config: agent_account,
+        instructions_sysvar: agent.pubkey(),
+        freeze_authority,
+        mint_account: mint,
+        token_account,
+        token_program: TOKEN_22_PROGRAM_ID,
+        freeze_expiry_account: Some(freeze_expiry_account),
+        rent_destination: Some(agent.pubkey()),
+    }
+    .instruction();
+    // Agent signs in place of the instructions sysvar
+    ix.accounts[2].is_signer = true;
+
+    send_tx(banks_client, vec![ix], &agent.pubkey(), vec![agent]).await
+}
+
+/// Build and send permissionless ThawExpired instruction
+pub async fn execute_thaw_expired(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    token_account: Pubkey,
+    rent_destination: Pubkey,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (freeze_authority, _) = find_mint_freeze_authority_pda(&mint);
+    let (freeze_expiry_account, _) = find_freeze_expiry_pda(&token_account);
+
+    let ix = ThawExpired {
+        mint_account: mint,
+        freeze_authority,
+        token_account,
+        freeze_expiry_account,
+        rent_destination,
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction();
+
+    send_tx(banks_client, vec![ix], &payer.pubkey(), vec![payer]).await
+}
@@ -0,0 +1,173 @@
+use security_token_client::{
+    accounts::FreezeExpiry, agent::FREEZE, errors::SecurityTokenProgramError,
+    pda::find_freeze_expiry_pda,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::state::AccountState;
+
+use crate::{
+    agent_tests::agent_helpers::execute_create_agent_account,
+    coupon_tests::coupon_helpers::{current_timestamp, warp_to_timestamp},
+    freeze_expiry_tests::freeze_expiry_helpers::{
+        execute_freeze_with_expiry_by_agent, execute_thaw_expired,
+        execute_thaw_with_expiry_by_agent,
+    },
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        get_token_account_state, start_with_context_and_accounts,
+    },
+};
+
+const FREEZE_DURATION: i64 = 72 * 60 * 60;
+
+/// Create mint with a token account of `holder` and register `agent` with freeze scope
+async fn setup_mint(
+    context: &mut ProgramTestContext,
+    holder: &Keypair,
+    agent: &Keypair,
+) -> (Pubkey, Pubkey) {
+    let mint_creator = context.payer.insecure_clone();
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+    let token_account = create_spl_account(context, &mint_keypair, holder).await;
+
+    let result = execute_create_agent_account(
+        &context.banks_client,
+        mint,
+        agent.pubkey(),
+        FREEZE,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    (mint, token_account)
+}
+
+#[tokio::test]
+async fn test_anyone_should_thaw_expired_freeze() {
+    let agent = Keypair::new();
+    let holder = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![
+        (&agent, 1_000_000_000),
+        (&holder, 1_000_000_000),
+    ])
+    .await;
+    let (mint, token_account) = setup_mint(context, &holder, &agent).await;
+
+    let auto_thaw_at = current_timestamp(context).await + FREEZE_DURATION;
+    let result = execute_freeze_with_expiry_by_agent(
+        &context.banks_client,
+        mint,
+        token_account,
+        &agent,
+        auto_thaw_at,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let token_account_state =
+        get_token_account_state(&mut context.banks_client, token_account).await;
+    assert_eq!(token_account_state.base.state, AccountState::Frozen);
+
+    let (freeze_expiry_pda, _) = find_freeze_expiry_pda(&token_account);
+    let account = assert_account_exists(context, freeze_expiry_pda, true)
+        .await
+        .unwrap();
+    let freeze_expiry = FreezeExpiry::from_bytes(&account.data).unwrap();
+    assert_eq!(freeze_expiry.mint, mint);
+    assert_eq!(freeze_expiry.token_account, token_account);
+    assert_eq!(freeze_expiry.payer, agent.pubkey());
+    assert_eq!(freeze_expiry.auto_thaw_at, auto_thaw_at);
+
+    let result = execute_thaw_expired(
+        &context.banks_client,
+        mint,
+        token_account,
+        agent.pubkey(),
+        &holder,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::FreezeNotExpired);
+
+    warp_to_timestamp(context, auto_thaw_at).await;
+
+    let result = execute_thaw_expired(
+        &context.banks_client,
+        mint,
+        token_account,
+        agent.pubkey(),
+        &holder,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let token_account_state =
+        get_token_account_state(&mut context.banks_client, token_account).await;
+    assert_eq!(token_account_state.base.state, AccountState::Initialized);
+    assert_account_exists(context, freeze_expiry_pda, false).await;
+}
+
+#[tokio::test]
+async fn test_thaw_should_close_freeze_expiry() {
+    let agent = Keypair::new();
+    let holder = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![
+        (&agent, 1_000_000_000),
+        (&holder, 1_000_000_000),
+    ])
+    .await;
+    let (mint, token_account) = setup_mint(context, &holder, &agent).await;
+
+    let auto_thaw_at = current_timestamp(context).await + FREEZE_DURATION;
+    let result = execute_freeze_with_expiry_by_agent(
+        &context.banks_client,
+        mint,
+        token_account,
+        &agent,
+        auto_thaw_at,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result =
+        execute_thaw_with_expiry_by_agent(&context.banks_client, mint, token_account, &agent).await;
+    assert_transaction_success(result);
+
+    let token_account_state =
+        get_token_account_state(&mut context.banks_client, token_account).await;
+    assert_eq!(token_account_state.base.state, AccountState::Initialized);
+    let (freeze_expiry_pda, _) = find_freeze_expiry_pda(&token_account);
+    assert_account_exists(context, freeze_expiry_pda, false).await;
+}
+
+#[tokio::test]
+async fn test_should_not_freeze_with_auto_thaw_in_the_past() {
+    let agent = Keypair::new();
+    let holder = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![
+        (&agent, 1_000_000_000),
+        (&holder, 1_000_000_000),
+    ])
+    .await;
+    let (mint, token_account) = setup_mint(context, &holder, &agent).await;
+
+    let auto_thaw_at = current_timestamp(context).await;
+    let result = execute_freeze_with_expiry_by_agent(
+        &context.banks_client,
+        mint,
+        token_account,
+        &agent,
+        auto_thaw_at,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let token_account_state =
+        get_token_account_state(&mut context.banks_client, token_account).await;
+    assert_eq!(token_account_state.base.state, AccountState::Initialized);
+}
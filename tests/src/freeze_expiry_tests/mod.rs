@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod freeze_expiry_tests;
+
+pub mod freeze_expiry_helpers;
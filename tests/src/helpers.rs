@@ -1,8 +1,11 @@
 use security_token_client::{
     errors::SecurityTokenProgramError,
+    halt::with_program_config,
     instructions::{
-        InitializeMintBuilder, InitializeVerificationConfigBuilder, MintBuilder, MINT_DISCRIMINATOR,
+        InitializeMintBuilder, InitializeVerificationConfigBuilder, MintBuilder,
+        MINT_DISCRIMINATOR, TRANSFER_DISCRIMINATOR,
     },
+    pda,
     programs::SECURITY_TOKEN_PROGRAM_ID,
     types::{InitializeMintArgs, InitializeVerificationConfigArgs, MintArgs},
 };
@@ -247,7 +250,7 @@ pub async fn initialize_mint_for_creator(
 
     let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let transaction = solana_sdk::transaction::Transaction::new_signed_with_payer(
-        &[ix],
+        &[with_program_config(ix)],
         Some(payer),
         &[&mint_creator, &mint_keypair],
         recent_blockhash,
@@ -357,6 +360,10 @@ pub async fn initialize_mint_verification_and_mint_to_account(
         instruction_discriminator: MINT_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: get_default_verification_programs(),
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
     initialize_verification_config(
         &mint_keypair,
@@ -381,7 +388,7 @@ pub async fn initialize_mint_verification_and_mint_to_account(
     let dummy_mint_ix = create_dummy_verification_from_instruction(&mint_ix);
 
     let mint_transaction = solana_sdk::transaction::Transaction::new_signed_with_payer(
-        &[dummy_mint_ix, mint_ix],
+        &[dummy_mint_ix, with_program_config(mint_ix)],
         Some(&context.payer.pubkey()),
         &[&context.payer],
         recent_blockhash,
@@ -411,6 +418,10 @@ pub async fn create_verification_config(
         instruction_discriminator,
         program_addresses,
         cpi_mode: false,
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
     let payer = owner.unwrap_or(&context.payer);
     let result = initialize_verification_config_for_payer(
@@ -482,6 +493,62 @@ pub async fn start_with_context_and_accounts(
     pt.start_with_context().await
 }
 
+/// Start the program with the transfer hook, `funded` keypairs get 1 SOL
+pub async fn start_with_transfer_hook(funded: &[&Keypair]) -> ProgramTestContext {
+    let mut pt = initialize_program();
+    pt.add_program(
+        "security_token_transfer_hook",
+        Pubkey::from(security_token_transfer_hook::id()),
+        None,
+    );
+    for keypair in funded {
+        pt.add_account(
+            keypair.pubkey(),
+            Account {
+                lamports: 1_000_000_000,
+                data: vec![],
+                owner: solana_system_interface::program::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+    pt.prefer_bpf(false);
+    add_dummy_verification_program(&mut pt);
+    pt.start_with_context().await
+}
+
+/// Create mint with transfer verification config and mint `supply` to `source_owner`.
+/// Returns the mint keypair, the mint authority PDA and the source token account
+pub async fn setup_transfer_verified_mint(
+    context: &mut ProgramTestContext,
+    source_owner: &Keypair,
+    supply: u64,
+) -> (Keypair, Pubkey, Pubkey) {
+    let mint_keypair = Keypair::new();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, None, 6).await;
+    create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        TRANSFER_DISCRIMINATOR,
+        get_default_verification_programs(),
+        None,
+    )
+    .await;
+    let source_account = create_spl_account(context, &mint_keypair, source_owner).await;
+    initialize_mint_verification_and_mint_to_account(
+        &mint_keypair,
+        context,
+        mint_authority_pda,
+        source_account,
+        supply,
+    )
+    .await;
+    (mint_keypair, mint_authority_pda, source_account)
+}
+
 pub async fn send_tx(
     banks_client: &BanksClient,
     ixs: Vec<solana_sdk::instruction::Instruction>,
@@ -489,6 +556,8 @@ pub async fn send_tx(
     signers: Vec<&Keypair>,
 ) -> Result<(), BanksClientError> {
     let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    // Haltable instructions take the ProgramConfig after all their other accounts
+    let ixs: Vec<Instruction> = ixs.into_iter().map(with_program_config).collect();
 
     let transaction = solana_sdk::transaction::Transaction::new_signed_with_payer(
         &ixs,
@@ -501,61 +570,31 @@ pub async fn send_tx(
 }
 
 pub fn find_mint_authority_pda(mint: &Pubkey, creator: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[b"mint.authority", &mint.to_bytes(), &creator.to_bytes()],
-        &SECURITY_TOKEN_PROGRAM_ID,
-    )
+    pda::find_mint_authority_pda(mint, creator)
 }
 
 pub fn find_mint_freeze_authority_pda(mint: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[b"mint.freeze_authority", &mint.to_bytes()],
-        &SECURITY_TOKEN_PROGRAM_ID,
-    )
+    pda::find_freeze_authority_pda(mint)
 }
 
 pub fn find_permanent_delegate_pda(mint: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[b"mint.permanent_delegate", mint.as_ref()],
-        &SECURITY_TOKEN_PROGRAM_ID,
-    )
+    pda::find_permanent_delegate_pda(mint)
 }
 
 pub fn find_transfer_hook_pda(mint: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[b"mint.transfer_hook", &mint.as_ref()],
-        &SECURITY_TOKEN_PROGRAM_ID,
-    )
+    pda::find_transfer_hook_pda(mint)
 }
 
 pub fn find_verification_config_pda(mint: Pubkey, instruction_discriminator: u8) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[
-            b"verification_config",
-            &mint.as_ref(),
-            &[instruction_discriminator],
-        ],
-        &SECURITY_TOKEN_PROGRAM_ID,
-    )
+    pda::find_verification_config_pda(&mint, instruction_discriminator)
 }
 
 pub fn find_mint_pause_authority_pda(mint: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[b"mint.pause_authority", mint.as_ref()],
-        &SECURITY_TOKEN_PROGRAM_ID,
-    )
+    pda::find_pause_authority_pda(mint)
 }
 
 pub fn find_rate_pda(action_id: u64, mint_pubkey1: &Pubkey, mint_pubkey2: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[
-            b"rate",
-            action_id.to_le_bytes().as_ref(),
-            mint_pubkey1.as_ref(),
-            mint_pubkey2.as_ref(),
-        ],
-        &SECURITY_TOKEN_PROGRAM_ID,
-    )
+    pda::find_rate_pda(action_id, mint_pubkey1, mint_pubkey2)
 }
 
 /// Create a minimal security token mint without metadata and scaled amount
@@ -582,6 +621,8 @@ pub async fn create_minimal_security_token_mint(
         ix_metadata_pointer: None,
         ix_metadata: None,
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint_for_creator(
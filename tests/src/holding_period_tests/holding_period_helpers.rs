@@ -0,0 +1,145 @@
+use security_token_client::{
+    instructions::{
+        CloseHoldingPeriod, CreateHoldingPeriod, CreateHoldingPeriodInstructionArgs, MintBuilder,
+        UnlockLot, MINT_DISCRIMINATOR, TRANSFER_DISCRIMINATOR,
+    },
+    pda::{find_holding_lot_pda, find_holding_period_pda, find_restricted_holding_pda},
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_transfer_hook_interface::get_extra_account_metas_address;
+
+use crate::helpers::{
+    create_dummy_verification_from_instruction, find_mint_authority_pda, find_transfer_hook_pda,
+    find_verification_config_pda, send_tx,
+};
+
+/// Build and send CreateHoldingPeriod instruction authorized by mint authority
+pub async fn execute_create_holding_period(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    holding_period: u64,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (holding_period_account, _) = find_holding_period_pda(&mint);
+    let (transfer_verification_config, _) =
+        find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+    let (transfer_hook_pda, _) = find_transfer_hook_pda(&mint);
+
+    let ix = CreateHoldingPeriod {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        holding_period_account,
+        transfer_verification_config,
+        system_program: solana_program::system_program::id(),
+        account_metas_pda: get_extra_account_metas_address(&mint, &transfer_hook_program),
+        transfer_hook_pda,
+        transfer_hook_program,
+    }
+    .instruction(CreateHoldingPeriodInstructionArgs { holding_period });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send CloseHoldingPeriod instruction authorized by mint authority
+pub async fn execute_close_holding_period(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (holding_period_account, _) = find_holding_period_pda(&mint);
+    let (transfer_verification_config, _) =
+        find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+    let (transfer_hook_pda, _) = find_transfer_hook_pda(&mint);
+
+    let ix = CloseHoldingPeriod {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        holding_period_account,
+        transfer_verification_config,
+        rent_recipient: mint_creator.pubkey(),
+        system_program: solana_program::system_program::id(),
+        account_metas_pda: get_extra_account_metas_address(&mint, &transfer_hook_program),
+        transfer_hook_pda,
+        transfer_hook_program,
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send Mint instruction recording the minted tokens as restricted lot `lot_id`
+pub async fn execute_mint_restricted_lot(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    mint_authority: Pubkey,
+    destination: Pubkey,
+    lot_id: u64,
+    amount: u64,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (verification_config, _) = find_verification_config_pda(mint, MINT_DISCRIMINATOR);
+    let mint_ix = MintBuilder::new()
+        .mint(mint)
+        .verification_config(verification_config)
+        .mint_account(mint)
+        .mint_authority(mint_authority)
+        .destination(destination)
+        .lot_payer(Some(payer.pubkey()))
+        .holding_period_account(Some(find_holding_period_pda(&mint).0))
+        .restricted_holding_account(Some(find_restricted_holding_pda(&destination).0))
+        .holding_lot_account(Some(find_holding_lot_pda(&destination, lot_id).0))
+        .system_program(Some(solana_program::system_program::id()))
+        .amount(amount)
+        .instruction();
+    let verification_ix = create_dummy_verification_from_instruction(&mint_ix);
+
+    send_tx(
+        banks_client,
+        vec![verification_ix, mint_ix],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
+
+/// Build and send UnlockLot instruction, anyone can crank it
+pub async fn execute_unlock_lot(
+    banks_client: &BanksClient,
+    token_account: Pubkey,
+    lot_id: u64,
+    rent_destination: Pubkey,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let ix = UnlockLot {
+        restricted_holding_account: find_restricted_holding_pda(&token_account).0,
+        holding_lot_account: find_holding_lot_pda(&token_account, lot_id).0,
+        rent_destination,
+    }
+    .instruction();
+
+    send_tx(banks_client, vec![ix], &payer.pubkey(), vec![payer]).await
+}
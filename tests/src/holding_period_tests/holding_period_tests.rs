@@ -0,0 +1,263 @@
+use security_token_client::{
+    accounts::{HoldingLot, HoldingPeriod, RestrictedHolding},
+    errors::SecurityTokenProgramError,
+    pda::{find_holding_lot_pda, find_holding_period_pda, find_restricted_holding_pda},
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    coupon_tests::coupon_helpers::{current_timestamp, warp_to_timestamp},
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_success,
+        create_spl_account, setup_transfer_verified_mint, start_with_transfer_hook,
+    },
+    holding_period_tests::holding_period_helpers::{
+        execute_close_holding_period, execute_create_holding_period, execute_mint_restricted_lot,
+        execute_unlock_lot,
+    },
+    position_limit_tests::position_limit_helpers::transfer,
+};
+
+const SUPPLY: u64 = 1_000_000;
+const HOLDING_PERIOD: u64 = 180 * 24 * 60 * 60;
+
+#[tokio::test]
+async fn test_should_lock_restricted_lot_until_holding_period_elapses() {
+    let context = &mut start_with_transfer_hook(&[]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let source_owner = Keypair::new();
+    let (mint_keypair, mint_authority, source_account) =
+        setup_transfer_verified_mint(context, &source_owner, SUPPLY).await;
+    let mint = mint_keypair.pubkey();
+    let banks_client = context.banks_client.clone();
+
+    let result =
+        execute_create_holding_period(&banks_client, mint, HOLDING_PERIOD, &mint_creator).await;
+    assert_transaction_success(result);
+
+    let investor = Keypair::new();
+    let investor_account = create_spl_account(context, &mint_keypair, &investor).await;
+    let outside_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+
+    // Private placement: 1_000 restricted tokens
+    let acquired_timestamp = current_timestamp(context).await;
+    let result = execute_mint_restricted_lot(
+        &banks_client,
+        mint,
+        mint_authority,
+        investor_account,
+        0,
+        1_000,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (restricted_holding_pda, _) = find_restricted_holding_pda(&investor_account);
+    let account = assert_account_exists(context, restricted_holding_pda, true)
+        .await
+        .unwrap();
+    let restricted_holding = RestrictedHolding::from_bytes(&account.data).unwrap();
+    assert_eq!(restricted_holding.mint, mint);
+    assert_eq!(restricted_holding.locked_amount, 1_000);
+    assert_eq!(restricted_holding.next_lot_id, 1);
+
+    let (holding_lot_pda, _) = find_holding_lot_pda(&investor_account, 0);
+    let account = assert_account_exists(context, holding_lot_pda, true)
+        .await
+        .unwrap();
+    let holding_lot = HoldingLot::from_bytes(&account.data).unwrap();
+    assert_eq!(holding_lot.amount, 1_000);
+    assert_eq!(holding_lot.payer, mint_creator.pubkey());
+    assert!(holding_lot.acquired_timestamp >= acquired_timestamp);
+    assert_eq!(
+        holding_lot.unlock_timestamp,
+        holding_lot.acquired_timestamp + HOLDING_PERIOD as i64
+    );
+
+    let result = transfer(
+        context,
+        mint,
+        investor_account,
+        outside_account,
+        &investor,
+        1,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::HoldingPeriodNotElapsed);
+
+    // Unrestricted tokens received on top of the lot can be sold
+    let result = transfer(
+        context,
+        mint,
+        source_account,
+        investor_account,
+        &source_owner,
+        500,
+    )
+    .await;
+    assert_transaction_success(result);
+    let result = transfer(
+        context,
+        mint,
+        investor_account,
+        outside_account,
+        &investor,
+        500,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_unlock_lot(
+        &banks_client,
+        investor_account,
+        0,
+        mint_creator.pubkey(),
+        &investor_payer(context).await,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::HoldingPeriodNotElapsed);
+
+    warp_to_timestamp(context, holding_lot.unlock_timestamp).await;
+
+    // The lock holds until the lot is unlocked
+    let result = transfer(
+        context,
+        mint,
+        investor_account,
+        outside_account,
+        &investor,
+        1,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::HoldingPeriodNotElapsed);
+
+    // Anyone cranks the unlock, the rent goes back to the lot payer
+    let result = execute_unlock_lot(
+        &banks_client,
+        investor_account,
+        0,
+        mint_creator.pubkey(),
+        &investor_payer(context).await,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_account_exists(context, holding_lot_pda, false).await;
+    let account = assert_account_exists(context, restricted_holding_pda, true)
+        .await
+        .unwrap();
+    let restricted_holding = RestrictedHolding::from_bytes(&account.data).unwrap();
+    assert_eq!(restricted_holding.locked_amount, 0);
+
+    let result = transfer(
+        context,
+        mint,
+        investor_account,
+        outside_account,
+        &investor,
+        1_000,
+    )
+    .await;
+    assert_transaction_success(result);
+}
+
+#[tokio::test]
+async fn test_holding_period_lifecycle() {
+    let context = &mut start_with_transfer_hook(&[]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let source_owner = Keypair::new();
+    let (mint_keypair, mint_authority, _source_account) =
+        setup_transfer_verified_mint(context, &source_owner, SUPPLY).await;
+    let mint = mint_keypair.pubkey();
+    let banks_client = context.banks_client.clone();
+
+    let result = execute_create_holding_period(&banks_client, mint, 0, &mint_creator).await;
+    assert!(result.is_err());
+
+    let result =
+        execute_create_holding_period(&banks_client, mint, HOLDING_PERIOD, &mint_creator).await;
+    assert_transaction_success(result);
+
+    let (holding_period_pda, _) = find_holding_period_pda(&mint);
+    let account = assert_account_exists(context, holding_period_pda, true)
+        .await
+        .unwrap();
+    let holding_period = HoldingPeriod::from_bytes(&account.data).unwrap();
+    assert_eq!(holding_period.mint, mint);
+    assert_eq!(holding_period.holding_period, HOLDING_PERIOD);
+
+    let investor = Keypair::new();
+    let investor_account = create_spl_account(context, &mint_keypair, &investor).await;
+    let outside_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+
+    // Lots are numbered in order
+    let result = execute_mint_restricted_lot(
+        &banks_client,
+        mint,
+        mint_authority,
+        investor_account,
+        1,
+        100,
+        &mint_creator,
+    )
+    .await;
+    assert!(result.is_err());
+    let result = execute_mint_restricted_lot(
+        &banks_client,
+        mint,
+        mint_authority,
+        investor_account,
+        0,
+        100,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = transfer(
+        context,
+        mint,
+        investor_account,
+        outside_account,
+        &investor,
+        100,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::HoldingPeriodNotElapsed);
+
+    // Without a holding period the transfer hook stops enforcing locked lots
+    let result = execute_close_holding_period(&banks_client, mint, &mint_creator).await;
+    assert_transaction_success(result);
+    assert_account_exists(context, holding_period_pda, false).await;
+
+    let result = transfer(
+        context,
+        mint,
+        investor_account,
+        outside_account,
+        &investor,
+        100,
+    )
+    .await;
+    assert_transaction_success(result);
+}
+
+/// Fee payer unrelated to the issuer and the lot
+async fn investor_payer(context: &mut ProgramTestContext) -> Keypair {
+    let payer = Keypair::new();
+    let ix = solana_sdk::system_instruction::transfer(
+        &context.payer.pubkey(),
+        &payer.pubkey(),
+        1_000_000_000,
+    );
+    let result = crate::helpers::send_tx(
+        &context.banks_client,
+        vec![ix],
+        &context.payer.pubkey(),
+        vec![&context.payer],
+    )
+    .await;
+    assert_transaction_success(result);
+    payer
+}
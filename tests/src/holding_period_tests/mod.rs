@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod holding_period_tests;
+
+pub mod holding_period_helpers;
@@ -0,0 +1,138 @@
+use security_token_client::{
+    instructions::{
+        AddIdentityWallet, CloseIdentityAccount, CreateIdentityAccount,
+        CreateIdentityAccountInstructionArgs, RemoveIdentityWallet,
+    },
+    pda::{find_identity_pda, find_identity_wallet_pda},
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::helpers::{find_mint_authority_pda, send_tx};
+
+/// Build and send CreateIdentityAccount instruction authorized by mint authority
+pub async fn execute_create_identity_account(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    investor_id: [u8; 32],
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (identity_account, _) = find_identity_pda(&mint, &investor_id);
+
+    let ix = CreateIdentityAccount {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        identity_account,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(CreateIdentityAccountInstructionArgs { investor_id });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send AddIdentityWallet instruction authorized by mint authority
+pub async fn execute_add_identity_wallet(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    investor_id: [u8; 32],
+    token_account: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (identity_account, _) = find_identity_pda(&mint, &investor_id);
+    let (identity_wallet_account, _) = find_identity_wallet_pda(&token_account);
+
+    let ix = AddIdentityWallet {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        identity_account,
+        token_account,
+        identity_wallet_account,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send RemoveIdentityWallet instruction authorized by mint authority
+pub async fn execute_remove_identity_wallet(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    investor_id: [u8; 32],
+    token_account: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (identity_account, _) = find_identity_pda(&mint, &investor_id);
+    let (identity_wallet_account, _) = find_identity_wallet_pda(&token_account);
+
+    let ix = RemoveIdentityWallet {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        rent_recipient: mint_creator.pubkey(),
+        mint_account: mint,
+        identity_account,
+        token_account,
+        identity_wallet_account,
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send CloseIdentityAccount instruction authorized by mint authority
+pub async fn execute_close_identity_account(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    investor_id: [u8; 32],
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (identity_account, _) = find_identity_pda(&mint, &investor_id);
+
+    let ix = CloseIdentityAccount {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        mint_account: mint,
+        identity_account,
+        rent_recipient: mint_creator.pubkey(),
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
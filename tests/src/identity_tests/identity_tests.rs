@@ -0,0 +1,198 @@
+use security_token_client::{
+    accounts::Identity,
+    errors::SecurityTokenProgramError,
+    identity::investor_id,
+    pda::{find_identity_pda, find_identity_wallet_pda},
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        start_with_context_and_accounts,
+    },
+    identity_tests::identity_helpers::{
+        execute_add_identity_wallet, execute_close_identity_account,
+        execute_create_identity_account, execute_remove_identity_wallet,
+    },
+};
+
+#[tokio::test]
+async fn test_should_link_wallets_to_identity() {
+    let wallet_1 = Keypair::new();
+    let wallet_2 = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![
+        (&wallet_1, 1_000_000_000),
+        (&wallet_2, 1_000_000_000),
+    ])
+    .await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+    let token_account_1 = create_spl_account(context, &mint_keypair, &wallet_1).await;
+    let token_account_2 = create_spl_account(context, &mint_keypair, &wallet_2).await;
+
+    let investor = investor_id("did:example:investor");
+    let result =
+        execute_create_identity_account(&context.banks_client, mint, investor, &mint_creator).await;
+    assert_transaction_success(result);
+
+    for token_account in [token_account_1, token_account_2] {
+        let result = execute_add_identity_wallet(
+            &context.banks_client,
+            mint,
+            investor,
+            token_account,
+            &mint_creator,
+        )
+        .await;
+        assert_transaction_success(result);
+    }
+
+    let (identity_account, _) = find_identity_pda(&mint, &investor);
+    let account = assert_account_exists(context, identity_account, true)
+        .await
+        .unwrap();
+    let identity = Identity::from_bytes(&account.data).unwrap();
+    assert_eq!(identity.investor_id, investor);
+    assert_eq!(identity.wallets, vec![token_account_1, token_account_2]);
+
+    let (identity_wallet, _) = find_identity_wallet_pda(&token_account_1);
+    assert_account_exists(context, identity_wallet, true).await;
+
+    let result = execute_remove_identity_wallet(
+        &context.banks_client,
+        mint,
+        investor,
+        token_account_1,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_account_exists(context, identity_wallet, false).await;
+
+    let account = assert_account_exists(context, identity_account, true)
+        .await
+        .unwrap();
+    let identity = Identity::from_bytes(&account.data).unwrap();
+    assert_eq!(identity.wallets, vec![token_account_2]);
+}
+
+#[tokio::test]
+async fn test_should_not_link_wallet_to_two_identities() {
+    let wallet = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![(&wallet, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+    let token_account = create_spl_account(context, &mint_keypair, &wallet).await;
+
+    let investor_1 = investor_id("did:example:investor-1");
+    let investor_2 = investor_id("did:example:investor-2");
+    for investor in [investor_1, investor_2] {
+        let result =
+            execute_create_identity_account(&context.banks_client, mint, investor, &mint_creator)
+                .await;
+        assert_transaction_success(result);
+    }
+
+    let result = execute_add_identity_wallet(
+        &context.banks_client,
+        mint,
+        investor_1,
+        token_account,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_add_identity_wallet(
+        &context.banks_client,
+        mint,
+        investor_2,
+        token_account,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_not_link_token_account_of_another_mint() {
+    let wallet = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![(&wallet, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let other_mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+    create_minimal_security_token_mint(context, &other_mint_keypair, Some(&mint_creator), 6).await;
+    let other_token_account = create_spl_account(context, &other_mint_keypair, &wallet).await;
+
+    let investor = investor_id("did:example:investor");
+    let result =
+        execute_create_identity_account(&context.banks_client, mint, investor, &mint_creator).await;
+    assert_transaction_success(result);
+
+    let result = execute_add_identity_wallet(
+        &context.banks_client,
+        mint,
+        investor,
+        other_token_account,
+        &mint_creator,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::InvalidIdentityWallet);
+}
+
+#[tokio::test]
+async fn test_should_not_close_identity_with_linked_wallets() {
+    let wallet = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![(&wallet, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+    let token_account = create_spl_account(context, &mint_keypair, &wallet).await;
+
+    let investor = investor_id("did:example:investor");
+    let result =
+        execute_create_identity_account(&context.banks_client, mint, investor, &mint_creator).await;
+    assert_transaction_success(result);
+    let result = execute_add_identity_wallet(
+        &context.banks_client,
+        mint,
+        investor,
+        token_account,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result =
+        execute_close_identity_account(&context.banks_client, mint, investor, &mint_creator).await;
+    assert_transaction_failure(result);
+
+    let result = execute_remove_identity_wallet(
+        &context.banks_client,
+        mint,
+        investor,
+        token_account,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result =
+        execute_close_identity_account(&context.banks_client, mint, investor, &mint_creator).await;
+    assert_transaction_success(result);
+    let (identity_account, _) = find_identity_pda(&mint, &investor);
+    assert_account_exists(context, identity_account, false).await;
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod identity_tests;
+
+pub mod identity_helpers;
@@ -29,3 +29,165 @@ pub mod receipt_tests;
 
 #[cfg(test)]
 pub mod claim_tests;
+
+#[cfg(test)]
+pub mod wrap_tests;
+
+#[cfg(test)]
+pub mod agent_tests;
+
+#[cfg(test)]
+pub mod identity_tests;
+
+#[cfg(test)]
+pub mod position_limit_tests;
+
+#[cfg(test)]
+pub mod transfer_acceptance_tests;
+
+#[cfg(test)]
+pub mod transfer_approval_tests;
+
+#[cfg(test)]
+pub mod coupon_tests;
+
+#[cfg(test)]
+pub mod maturity_tests;
+
+#[cfg(test)]
+pub mod nav_oracle_tests;
+
+#[cfg(test)]
+pub mod subscription_tests;
+
+#[cfg(test)]
+pub mod auction_tests;
+
+#[cfg(test)]
+pub mod holding_period_tests;
+
+#[cfg(test)]
+pub mod suspension_tests;
+
+#[cfg(test)]
+pub mod freeze_expiry_tests;
+
+#[cfg(test)]
+pub mod vesting_tests;
+
+#[cfg(test)]
+pub mod withholding_tests;
+
+#[cfg(test)]
+pub mod distribution_payout_tests;
+
+#[cfg(test)]
+pub mod claim_deadline_tests;
+
+#[cfg(test)]
+pub mod distribution_root_tests;
+
+#[cfg(test)]
+pub mod claim_permit_tests;
+
+#[cfg(test)]
+pub mod transfer_permit_tests;
+
+#[cfg(test)]
+pub mod session_key_tests;
+
+#[cfg(test)]
+pub mod recovery_tests;
+
+#[cfg(test)]
+pub mod claim_bitmap_tests;
+
+#[cfg(test)]
+pub mod migration_tests;
+
+#[cfg(test)]
+pub mod metadata_schema_tests;
+
+#[cfg(test)]
+pub mod metadata_freeze_tests;
+
+#[cfg(test)]
+pub mod external_metadata_tests;
+
+#[cfg(test)]
+pub mod config_template_tests;
+
+#[cfg(test)]
+pub mod can_transfer_tests;
+
+#[cfg(test)]
+pub mod view_tests;
+
+#[cfg(test)]
+pub mod fixtures;
+
+#[cfg(test)]
+pub mod collateral_tests;
+
+#[cfg(test)]
+pub mod reserve_tests;
+
+#[cfg(test)]
+pub mod sub_account_tests;
+
+#[cfg(test)]
+pub mod succession_tests;
+
+#[cfg(test)]
+pub mod translation_tests;
+
+#[cfg(test)]
+pub mod audit_tests;
+
+#[cfg(test)]
+pub mod transfer_fee_tests;
+
+#[cfg(test)]
+pub mod default_account_state_tests;
+
+#[cfg(test)]
+pub mod memo_transfer_tests;
+
+#[cfg(test)]
+pub mod scaled_ui_amount_tests;
+
+#[cfg(test)]
+pub mod remove_metadata_field_tests;
+
+#[cfg(test)]
+pub mod document_registry_tests;
+
+#[cfg(test)]
+pub mod mint_creator_transfer_tests;
+
+#[cfg(test)]
+pub mod mint_authority_multisig_tests;
+
+#[cfg(test)]
+pub mod permanent_delegate_tests;
+
+#[cfg(test)]
+pub mod pause_delegate_tests;
+
+#[cfg(test)]
+pub mod close_mint_authority_tests;
+
+#[cfg(test)]
+pub mod config_admin_tests;
+
+#[cfg(test)]
+pub mod verification_config_timelock_tests;
+
+#[cfg(test)]
+pub mod program_config_tests;
+
+#[cfg(test)]
+pub mod allowlist_tests;
+
+#[cfg(test)]
+pub mod scenario_tests;
@@ -0,0 +1,154 @@
+use security_token_client::{
+    instructions::{
+        CreateMaturity, CreateMaturityInstructionArgs, RedeemAtMaturity,
+        RedeemAtMaturityInstructionArgs, WithdrawUnclaimedRedemption,
+    },
+    pda::{
+        find_associated_token_address_with_program, find_maturity_pda,
+        find_permanent_delegate_config_pda,
+    },
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    sysvar,
+};
+use spl_associated_token_account::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{
+    create_dummy_verification_from_instruction, find_mint_authority_pda,
+    find_permanent_delegate_pda, send_tx,
+};
+
+/// Redemption vault of the mint, owned by its Maturity PDA
+pub fn find_redemption_vault(
+    mint: &Pubkey,
+    payout_mint: &Pubkey,
+    payout_token_program: &Pubkey,
+) -> Pubkey {
+    let (maturity, _) = find_maturity_pda(mint);
+    find_associated_token_address_with_program(&maturity, payout_mint, payout_token_program).0
+}
+
+/// Build and send CreateMaturity instruction authorized by mint authority
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_create_maturity(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    payout_mint: Pubkey,
+    payout_token_program: Pubkey,
+    maturity_timestamp: i64,
+    redemption_cutoff: i64,
+    principal_per_token: u64,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (maturity_account, _) = find_maturity_pda(&mint);
+
+    let ix = CreateMaturity {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        maturity_account,
+        payout_mint,
+        redemption_vault: find_redemption_vault(&mint, &payout_mint, &payout_token_program),
+        payout_token_program,
+        associated_token_account_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(CreateMaturityInstructionArgs {
+        maturity_timestamp,
+        redemption_cutoff,
+        principal_per_token,
+    });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send RedeemAtMaturity instruction
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_redeem_at_maturity(
+    banks_client: &BanksClient,
+    verification_config: Pubkey,
+    mint: Pubkey,
+    token_account: Pubkey,
+    payout_mint: Pubkey,
+    payout_token_program: Pubkey,
+    payout_token_account: Pubkey,
+    owner: &Keypair,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let (maturity_account, _) = find_maturity_pda(&mint);
+    let (permanent_delegate, _) = find_permanent_delegate_pda(&mint);
+
+    let ix = RedeemAtMaturity {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        permanent_delegate,
+        owner: owner.pubkey(),
+        mint_account: mint,
+        maturity_account,
+        token_account,
+        payout_mint,
+        redemption_vault: find_redemption_vault(&mint, &payout_mint, &payout_token_program),
+        payout_token_account,
+        payout_token_program,
+        token_program: TOKEN_22_PROGRAM_ID,
+        permanent_delegate_config_account: find_permanent_delegate_config_pda(&mint).0,
+    }
+    .instruction(RedeemAtMaturityInstructionArgs { amount });
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &owner.pubkey(),
+        vec![owner],
+    )
+    .await
+}
+
+/// Build and send WithdrawUnclaimedRedemption instruction authorized by mint authority
+pub async fn execute_withdraw_unclaimed_redemption(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    payout_mint: Pubkey,
+    payout_token_program: Pubkey,
+    destination: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (maturity_account, _) = find_maturity_pda(&mint);
+
+    let ix = WithdrawUnclaimedRedemption {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        mint_account: mint,
+        maturity_account,
+        payout_mint,
+        redemption_vault: find_redemption_vault(&mint, &payout_mint, &payout_token_program),
+        destination,
+        payout_token_program,
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
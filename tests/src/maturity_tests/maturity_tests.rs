@@ -0,0 +1,245 @@
+use security_token_client::{
+    accounts::Maturity, errors::SecurityTokenProgramError,
+    instructions::REDEEM_AT_MATURITY_DISCRIMINATOR, pda::find_maturity_pda, pda::TOKEN_PROGRAM_ID,
+};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    coupon_tests::coupon_helpers::{current_timestamp, warp_to_timestamp},
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        create_verification_config, get_default_verification_programs, get_token_account_state,
+        initialize_mint_verification_and_mint_to_account, start_with_context_and_accounts,
+    },
+    maturity_tests::maturity_helpers::{
+        execute_create_maturity, execute_redeem_at_maturity, execute_withdraw_unclaimed_redemption,
+        find_redemption_vault,
+    },
+    wrap_tests::wrap_helpers::{create_underlying_mint, create_underlying_token_account},
+};
+
+const DECIMALS: u8 = 6;
+/// Par of 100 payout tokens per debt token
+const PRINCIPAL_PER_TOKEN: u64 = 100_000_000;
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    get_token_account_state(&mut context.banks_client, token_account)
+        .await
+        .base
+        .amount
+}
+
+#[tokio::test]
+async fn test_should_redeem_at_maturity_and_withdraw_unclaimed_funds() {
+    let holder = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![(&holder, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let payout_token_program = TOKEN_PROGRAM_ID;
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), DECIMALS)
+            .await;
+    let redeem_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        REDEEM_AT_MATURITY_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+
+    // 10 debt tokens
+    let token_account = create_spl_account(context, &mint_keypair, &holder).await;
+    initialize_mint_verification_and_mint_to_account(
+        &mint_keypair,
+        context,
+        mint_authority_pda,
+        token_account,
+        10_000_000,
+    )
+    .await;
+
+    let payout_mint = create_underlying_mint(context, &payout_token_program, DECIMALS).await;
+    let payout_token_account = create_underlying_token_account(
+        context,
+        &payout_mint,
+        &payout_token_program,
+        &holder.pubkey(),
+        DECIMALS,
+        0,
+    )
+    .await;
+
+    let now = current_timestamp(context).await;
+    let maturity_timestamp = now + 1_000;
+    let redemption_cutoff = now + 10_000;
+    let result = execute_create_maturity(
+        &context.banks_client,
+        mint,
+        payout_mint,
+        payout_token_program,
+        maturity_timestamp,
+        redemption_cutoff,
+        PRINCIPAL_PER_TOKEN,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (maturity_pda, _) = find_maturity_pda(&mint);
+    let account = assert_account_exists(context, maturity_pda, true)
+        .await
+        .unwrap();
+    let maturity = Maturity::from_bytes(&account.data).unwrap();
+    assert_eq!(maturity.redemption_cutoff, redemption_cutoff);
+
+    // Issuer pre-funds the full principal
+    let redemption_vault = create_underlying_token_account(
+        context,
+        &payout_mint,
+        &payout_token_program,
+        &maturity_pda,
+        DECIMALS,
+        1_000_000_000,
+    )
+    .await;
+    assert_eq!(
+        redemption_vault,
+        find_redemption_vault(&mint, &payout_mint, &payout_token_program)
+    );
+
+    let redeem = |banks_client: BanksClient, amount: u64| {
+        let holder = holder.insecure_clone();
+        async move {
+            execute_redeem_at_maturity(
+                &banks_client,
+                redeem_verification_config,
+                mint,
+                token_account,
+                payout_mint,
+                payout_token_program,
+                payout_token_account,
+                &holder,
+                amount,
+            )
+            .await
+        }
+    };
+
+    let result = redeem(context.banks_client.clone(), 4_000_000).await;
+    assert_security_token_error(result, SecurityTokenProgramError::RedemptionNotOpen);
+
+    let issuer_payout_account = create_underlying_token_account(
+        context,
+        &payout_mint,
+        &payout_token_program,
+        &mint_creator.pubkey(),
+        DECIMALS,
+        0,
+    )
+    .await;
+    let result = execute_withdraw_unclaimed_redemption(
+        &context.banks_client,
+        mint,
+        payout_mint,
+        payout_token_program,
+        issuer_payout_account,
+        &mint_creator,
+    )
+    .await;
+    assert_security_token_error(
+        result,
+        SecurityTokenProgramError::RedemptionCutoffNotReached,
+    );
+
+    warp_to_timestamp(context, maturity_timestamp).await;
+    let result = redeem(context.banks_client.clone(), 4_000_000).await;
+    assert_transaction_success(result);
+    assert_eq!(token_amount(context, token_account).await, 6_000_000);
+    assert_eq!(
+        token_amount(context, payout_token_account).await,
+        400_000_000
+    );
+
+    // More than the holder owns cannot be redeemed
+    let result = redeem(context.banks_client.clone(), 7_000_000).await;
+    assert_transaction_failure(result);
+
+    warp_to_timestamp(context, redemption_cutoff).await;
+    let result = redeem(context.banks_client.clone(), 1_000_000).await;
+    assert_security_token_error(result, SecurityTokenProgramError::RedemptionNotOpen);
+
+    let result = execute_withdraw_unclaimed_redemption(
+        &context.banks_client,
+        mint,
+        payout_mint,
+        payout_token_program,
+        issuer_payout_account,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        token_amount(context, issuer_payout_account).await,
+        600_000_000
+    );
+    assert_eq!(token_amount(context, redemption_vault).await, 0);
+}
+
+#[tokio::test]
+async fn test_create_maturity_validation() {
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), DECIMALS).await;
+    let payout_mint = create_underlying_mint(context, &TOKEN_PROGRAM_ID, DECIMALS).await;
+    let now = current_timestamp(context).await;
+
+    // Cutoff must be after maturity
+    let result = execute_create_maturity(
+        &context.banks_client,
+        mint,
+        payout_mint,
+        TOKEN_PROGRAM_ID,
+        now + 1_000,
+        now + 1_000,
+        PRINCIPAL_PER_TOKEN,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let result = execute_create_maturity(
+        &context.banks_client,
+        mint,
+        payout_mint,
+        TOKEN_PROGRAM_ID,
+        now + 1_000,
+        now + 2_000,
+        0,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let result = execute_create_maturity(
+        &context.banks_client,
+        mint,
+        payout_mint,
+        TOKEN_PROGRAM_ID,
+        now + 1_000,
+        now + 2_000,
+        PRINCIPAL_PER_TOKEN,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod maturity_tests;
+
+pub mod maturity_helpers;
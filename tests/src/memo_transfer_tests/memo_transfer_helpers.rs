@@ -0,0 +1,86 @@
+use security_token_client::instructions::TransferBuilder;
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use spl_token_2022::{
+    extension::{memo_transfer::instruction::enable_required_transfer_memos, ExtensionType},
+    instruction::reallocate,
+    ID as TOKEN_22_PROGRAM_ID,
+};
+
+use crate::helpers::{
+    create_dummy_verification_from_instruction, find_permanent_delegate_pda, send_tx,
+};
+
+/// SPL Memo program loaded by the program test
+pub const MEMO_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// Holder requires memos on incoming transfers to `token_account`
+pub async fn enable_required_memos(
+    banks_client: &BanksClient,
+    token_account: Pubkey,
+    owner: &Keypair,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let reallocate_ix = reallocate(
+        &TOKEN_22_PROGRAM_ID,
+        &token_account,
+        &payer.pubkey(),
+        &owner.pubkey(),
+        &[],
+        &[ExtensionType::MemoTransfer],
+    )
+    .expect("reallocate ix");
+    let enable_ix =
+        enable_required_transfer_memos(&TOKEN_22_PROGRAM_ID, &token_account, &owner.pubkey(), &[])
+            .expect("enable required transfer memos ix");
+
+    send_tx(
+        banks_client,
+        vec![reallocate_ix, enable_ix],
+        &payer.pubkey(),
+        vec![payer, owner],
+    )
+    .await
+}
+
+/// Build and send Transfer, passing the memo program along with a memo
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_transfer(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    verification_config: Pubkey,
+    from_token_account: Pubkey,
+    to_token_account: Pubkey,
+    amount: u64,
+    memo: Option<&str>,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let mut builder = TransferBuilder::new();
+    builder
+        .mint(mint)
+        .verification_config(verification_config)
+        .permanent_delegate_authority(find_permanent_delegate_pda(&mint).0)
+        .mint_account(mint)
+        .from_token_account(from_token_account)
+        .to_token_account(to_token_account)
+        .transfer_hook_program(Pubkey::from(security_token_transfer_hook::id()))
+        .amount(amount);
+    if let Some(memo) = memo {
+        builder
+            .memo_program(Some(MEMO_PROGRAM_ID))
+            .memo(memo.to_string());
+    }
+    let ix = builder.instruction();
+
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
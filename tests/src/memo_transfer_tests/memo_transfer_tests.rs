@@ -0,0 +1,159 @@
+use security_token_client::instructions::TRANSFER_DISCRIMINATOR;
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::extension::{memo_transfer::MemoTransfer, BaseStateWithExtensions};
+
+use crate::{
+    claim_tests::claim_helpers::start_with_context_and_transfer_hook,
+    helpers::{
+        assert_instruction_error, assert_transaction_success, create_minimal_security_token_mint,
+        create_spl_account, create_verification_config, get_default_verification_programs,
+        get_token_account_state, initialize_mint_verification_and_mint_to_account,
+    },
+    memo_transfer_tests::memo_transfer_helpers::{enable_required_memos, execute_transfer},
+};
+
+const SUPPLY: u64 = 1_000_000;
+const TRANSFER_AMOUNT: u64 = 100_000;
+/// Token-2022 `TokenError::NoMemo`
+const NO_MEMO_ERROR: &str = "Custom(36)";
+
+struct Setup {
+    mint_keypair: Keypair,
+    transfer_verification_config: Pubkey,
+    holder_account: Pubkey,
+    recipient_account: Pubkey,
+}
+
+/// Mint with a funded holder and a recipient requiring memos on incoming transfers
+async fn setup_mint(context: &mut ProgramTestContext) -> Setup {
+    let mint_keypair = Keypair::new();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, None, 6).await;
+    let transfer_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        TRANSFER_DISCRIMINATOR,
+        get_default_verification_programs(),
+        None,
+    )
+    .await;
+    let holder_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+    let recipient = Keypair::new();
+    let recipient_account = create_spl_account(context, &mint_keypair, &recipient).await;
+    initialize_mint_verification_and_mint_to_account(
+        &mint_keypair,
+        context,
+        mint_authority_pda,
+        holder_account,
+        SUPPLY,
+    )
+    .await;
+
+    let payer = context.payer.insecure_clone();
+    let result =
+        enable_required_memos(&context.banks_client, recipient_account, &recipient, &payer).await;
+    assert_transaction_success(result);
+
+    Setup {
+        mint_keypair,
+        transfer_verification_config,
+        holder_account,
+        recipient_account,
+    }
+}
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    get_token_account_state(&mut context.banks_client, token_account)
+        .await
+        .base
+        .amount
+}
+
+#[tokio::test]
+async fn test_transfer_with_memo_should_satisfy_required_memos() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let payer = context.payer.insecure_clone();
+    let state = get_token_account_state(&mut context.banks_client, setup.recipient_account).await;
+    assert!(bool::from(
+        state
+            .get_extension::<MemoTransfer>()
+            .expect("MemoTransfer extension should be present")
+            .require_incoming_transfer_memos
+    ));
+
+    let result = execute_transfer(
+        &context.banks_client,
+        setup.mint_keypair.pubkey(),
+        setup.transfer_verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        TRANSFER_AMOUNT,
+        Some("travel rule reference 42"),
+        &payer,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        token_amount(context, setup.recipient_account).await,
+        TRANSFER_AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_without_memo_should_fail_for_required_memos() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let payer = context.payer.insecure_clone();
+
+    let result = execute_transfer(
+        &context.banks_client,
+        setup.mint_keypair.pubkey(),
+        setup.transfer_verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        TRANSFER_AMOUNT,
+        None,
+        &payer,
+    )
+    .await;
+    assert_instruction_error(result, NO_MEMO_ERROR);
+    assert_eq!(token_amount(context, setup.recipient_account).await, 0);
+}
+
+#[tokio::test]
+async fn test_transfer_with_memo_to_account_without_requirement_should_succeed() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let payer = context.payer.insecure_clone();
+
+    // Memos are accepted by any destination, the recipient sends back to the holder
+    let result = execute_transfer(
+        &context.banks_client,
+        setup.mint_keypair.pubkey(),
+        setup.transfer_verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        TRANSFER_AMOUNT,
+        Some("outbound"),
+        &payer,
+    )
+    .await;
+    assert_transaction_success(result);
+    let result = execute_transfer(
+        &context.banks_client,
+        setup.mint_keypair.pubkey(),
+        setup.transfer_verification_config,
+        setup.recipient_account,
+        setup.holder_account,
+        TRANSFER_AMOUNT,
+        Some("inbound"),
+        &payer,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(token_amount(context, setup.holder_account).await, SUPPLY);
+}
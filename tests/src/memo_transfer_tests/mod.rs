@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod memo_transfer_tests;
+
+pub mod memo_transfer_helpers;
@@ -0,0 +1,37 @@
+use security_token_client::{instructions::FreezeMetadata, pda::find_metadata_freeze_pda};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use crate::helpers::{find_mint_authority_pda, send_tx};
+
+/// Freeze the metadata of `mint` through the mint authority of the payer
+pub async fn freeze_metadata(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let mut ix = FreezeMetadata {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: payer.pubkey(),
+        mint_authority: mint_authority_pda,
+        payer: payer.pubkey(),
+        mint_account: mint,
+        metadata_freeze_account: find_metadata_freeze_pda(&mint).0,
+        token_program: spl_token_2022::ID,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction();
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
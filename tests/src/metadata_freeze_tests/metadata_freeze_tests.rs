@@ -0,0 +1,68 @@
+use security_token_client::{
+    accounts::MetadataFreeze, errors::SecurityTokenProgramError, pda::find_metadata_freeze_pda,
+};
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::Mint;
+use spl_token_metadata_interface::state::TokenMetadata;
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, start_with_context,
+    },
+    metadata_freeze_tests::metadata_freeze_helpers::freeze_metadata,
+    metadata_schema_tests::metadata_schema_helpers::{
+        create_mint_with_metadata, update_metadata_with_schema,
+    },
+};
+
+#[tokio::test]
+async fn test_should_freeze_metadata() {
+    let context = &mut start_with_context().await;
+    let mint_keypair = create_mint_with_metadata(context).await;
+    let mint = mint_keypair.pubkey();
+
+    let result = freeze_metadata(context, mint).await;
+    assert_transaction_success(result);
+
+    let mint_account = assert_account_exists(context, mint, true).await.unwrap();
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_account.data).unwrap();
+    let metadata = mint_state
+        .get_variable_len_extension::<TokenMetadata>()
+        .unwrap();
+    assert_eq!(
+        Option::<solana_pubkey::Pubkey>::from(metadata.update_authority),
+        None
+    );
+
+    let (freeze_pda, bump) = find_metadata_freeze_pda(&mint);
+    let freeze_account = assert_account_exists(context, freeze_pda, true)
+        .await
+        .unwrap();
+    let freeze = MetadataFreeze::from_bytes(&freeze_account.data).unwrap();
+    assert_eq!(freeze.mint, mint);
+    assert_eq!(freeze.bump, bump);
+
+    let result = update_metadata_with_schema(context, &mint_keypair, &[("type", "bond")]).await;
+    assert_security_token_error(result, SecurityTokenProgramError::MetadataFrozen);
+
+    let result = freeze_metadata(context, mint).await;
+    assert_security_token_error(result, SecurityTokenProgramError::MetadataFrozen);
+}
+
+#[tokio::test]
+async fn test_should_not_freeze_mint_without_metadata() {
+    let context = &mut start_with_context().await;
+    let mint_keypair = Keypair::new();
+    create_minimal_security_token_mint(context, &mint_keypair, None, 6).await;
+
+    let result = freeze_metadata(context, mint_keypair.pubkey()).await;
+    assert_transaction_failure(result);
+    assert_account_exists(
+        context,
+        find_metadata_freeze_pda(&mint_keypair.pubkey()).0,
+        false,
+    )
+    .await;
+}
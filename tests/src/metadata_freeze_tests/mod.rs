@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod metadata_freeze_tests;
+
+pub mod metadata_freeze_helpers;
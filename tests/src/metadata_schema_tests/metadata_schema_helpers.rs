@@ -0,0 +1,182 @@
+use security_token_client::{
+    instructions::{
+        CloseMetadataSchema, CreateMetadataSchema, CreateMetadataSchemaInstructionArgs,
+        UpdateMetadataBuilder, UpdateMetadataSchema, UpdateMetadataSchemaInstructionArgs,
+    },
+    metadata_schema::{
+        encode_additional_metadata, encode_metadata_schema_fields, MetadataSchemaField,
+    },
+    pda::find_metadata_schema_pda,
+    types::{
+        InitializeMintArgs, MetadataPointerArgs, MintArgs, TokenMetadataArgs, UpdateMetadataArgs,
+    },
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::helpers::{
+    find_mint_authority_pda, find_mint_freeze_authority_pda, initialize_mint, send_tx,
+};
+
+/// Create a mint with embedded metadata, the payer being the mint creator
+pub async fn create_mint_with_metadata(context: &mut ProgramTestContext) -> Keypair {
+    let mint_keypair = Keypair::new();
+    let (mint_authority_pda, _) =
+        find_mint_authority_pda(&mint_keypair.pubkey(), &context.payer.pubkey());
+    let (freeze_authority_pda, _) = find_mint_freeze_authority_pda(&mint_keypair.pubkey());
+
+    let mint_args = InitializeMintArgs {
+        ix_mint: MintArgs {
+            decimals: 6,
+            mint_authority: context.payer.pubkey(),
+            freeze_authority: freeze_authority_pda,
+        },
+        ix_metadata_pointer: Some(MetadataPointerArgs {
+            authority: context.payer.pubkey(),
+            metadata_address: mint_keypair.pubkey(),
+        }),
+        ix_metadata: Some(TokenMetadataArgs {
+            name: "Schema Token".to_string(),
+            symbol: "SCHM".to_string(),
+            uri: "https://example.com".to_string(),
+            additional_metadata: encode_additional_metadata(&[("type", "security")]),
+        }),
+        ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
+    };
+    initialize_mint(&mint_keypair, context, mint_authority_pda, &mint_args).await;
+    mint_keypair
+}
+
+/// Create the metadata schema of `mint` through the mint authority of the payer
+pub async fn create_metadata_schema(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    fields: &[MetadataSchemaField],
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let mut ix = CreateMetadataSchema {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: payer.pubkey(),
+        payer: payer.pubkey(),
+        mint_account: mint,
+        metadata_schema_account: find_metadata_schema_pda(&mint).0,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction(CreateMetadataSchemaInstructionArgs {
+        fields: encode_metadata_schema_fields(fields),
+    });
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
+
+/// Replace the fields of the metadata schema of `mint`
+pub async fn update_metadata_schema(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    fields: &[MetadataSchemaField],
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let mut ix = UpdateMetadataSchema {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: payer.pubkey(),
+        payer: payer.pubkey(),
+        mint_account: mint,
+        metadata_schema_account: find_metadata_schema_pda(&mint).0,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction(UpdateMetadataSchemaInstructionArgs {
+        fields: encode_metadata_schema_fields(fields),
+    });
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
+
+/// Close the metadata schema of `mint`, returning rent to `rent_recipient`
+pub async fn close_metadata_schema(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    rent_recipient: Pubkey,
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let mut ix = CloseMetadataSchema {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: payer.pubkey(),
+        mint_account: mint,
+        metadata_schema_account: find_metadata_schema_pda(&mint).0,
+        rent_recipient,
+    }
+    .instruction();
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
+
+/// Update the metadata of `mint_keypair` through the mint authority, passing its schema PDA
+pub async fn update_metadata_with_schema(
+    context: &mut ProgramTestContext,
+    mint_keypair: &Keypair,
+    additional_metadata: &[(&str, &str)],
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let mut ix = UpdateMetadataBuilder::new()
+        .mint(mint)
+        .verification_config_or_mint_authority(mint_authority_pda)
+        .instructions_sysvar_or_creator(payer.pubkey())
+        .mint_authority(mint_authority_pda)
+        .payer(payer.pubkey())
+        .mint_account(mint)
+        .metadata_schema_account(Some(find_metadata_schema_pda(&mint).0))
+        .update_metadata_args(UpdateMetadataArgs {
+            metadata: TokenMetadataArgs {
+                name: "Schema Token".to_string(),
+                symbol: "SCHM".to_string(),
+                uri: "https://example.com".to_string(),
+                additional_metadata: encode_additional_metadata(additional_metadata),
+            },
+        })
+        .instruction();
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
@@ -0,0 +1,154 @@
+use security_token_client::{
+    accounts::MetadataSchema,
+    errors::SecurityTokenProgramError,
+    metadata_schema::{decode_metadata_schema_fields, MetadataSchemaField},
+    pda::find_metadata_schema_pda,
+    types::MetadataFieldFormat,
+};
+use solana_sdk::signature::Signer;
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, start_with_context,
+    },
+    metadata_schema_tests::metadata_schema_helpers::{
+        close_metadata_schema, create_metadata_schema, create_mint_with_metadata,
+        update_metadata_schema, update_metadata_with_schema,
+    },
+};
+
+const ISIN: &str = "US0378331005";
+const LEI: &str = "5493001KJTIIGC8Y1R12";
+
+fn schema_fields() -> Vec<MetadataSchemaField> {
+    vec![
+        MetadataSchemaField::new("isin", MetadataFieldFormat::Isin),
+        MetadataSchemaField::new("jurisdiction", MetadataFieldFormat::CountryCode),
+    ]
+}
+
+#[tokio::test]
+async fn test_should_create_metadata_schema() {
+    let context = &mut start_with_context().await;
+    let mint_keypair = create_mint_with_metadata(context).await;
+    let mint = mint_keypair.pubkey();
+
+    let result = create_metadata_schema(context, mint, &schema_fields()).await;
+    assert_transaction_success(result);
+
+    let (schema_pda, bump) = find_metadata_schema_pda(&mint);
+    let account = assert_account_exists(context, schema_pda, true)
+        .await
+        .unwrap();
+    let schema = MetadataSchema::from_bytes(&account.data).unwrap();
+    assert_eq!(schema.mint, mint);
+    assert_eq!(schema.bump, bump);
+    assert_eq!(
+        decode_metadata_schema_fields(&schema.fields),
+        Some(schema_fields())
+    );
+
+    // Duplicate keys are rejected
+    let other_mint = create_mint_with_metadata(context).await;
+    let duplicate = vec![
+        MetadataSchemaField::new("isin", MetadataFieldFormat::Isin),
+        MetadataSchemaField::new("isin", MetadataFieldFormat::Text),
+    ];
+    let result = create_metadata_schema(context, other_mint.pubkey(), &duplicate).await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_enforce_schema_on_update_metadata() {
+    let context = &mut start_with_context().await;
+    let mint_keypair = create_mint_with_metadata(context).await;
+
+    // Without a schema any metadata is accepted
+    let result = update_metadata_with_schema(context, &mint_keypair, &[("type", "bond")]).await;
+    assert_transaction_success(result);
+
+    let result = create_metadata_schema(context, mint_keypair.pubkey(), &schema_fields()).await;
+    assert_transaction_success(result);
+
+    let result = update_metadata_with_schema(context, &mint_keypair, &[("isin", ISIN)]).await;
+    assert_security_token_error(result, SecurityTokenProgramError::MissingMetadataField);
+
+    let result = update_metadata_with_schema(
+        context,
+        &mint_keypair,
+        &[("isin", "US0378331006"), ("jurisdiction", "US")],
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::MalformedMetadataField);
+
+    let result = update_metadata_with_schema(
+        context,
+        &mint_keypair,
+        &[("isin", ISIN), ("jurisdiction", "us")],
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::MalformedMetadataField);
+
+    let result = update_metadata_with_schema(
+        context,
+        &mint_keypair,
+        &[("type", "bond"), ("isin", ISIN), ("jurisdiction", "US")],
+    )
+    .await;
+    assert_transaction_success(result);
+}
+
+#[tokio::test]
+async fn test_should_update_and_close_metadata_schema() {
+    let context = &mut start_with_context().await;
+    let mint_keypair = create_mint_with_metadata(context).await;
+    let mint = mint_keypair.pubkey();
+
+    let result = create_metadata_schema(context, mint, &schema_fields()).await;
+    assert_transaction_success(result);
+
+    let mut fields = schema_fields();
+    fields.push(MetadataSchemaField::new(
+        "issuer_lei",
+        MetadataFieldFormat::Lei,
+    ));
+    let result = update_metadata_schema(context, mint, &fields).await;
+    assert_transaction_success(result);
+
+    let (schema_pda, _) = find_metadata_schema_pda(&mint);
+    let account = assert_account_exists(context, schema_pda, true)
+        .await
+        .unwrap();
+    let schema = MetadataSchema::from_bytes(&account.data).unwrap();
+    assert_eq!(decode_metadata_schema_fields(&schema.fields), Some(fields));
+
+    let result = update_metadata_with_schema(
+        context,
+        &mint_keypair,
+        &[("isin", ISIN), ("jurisdiction", "US")],
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::MissingMetadataField);
+
+    let result = update_metadata_with_schema(
+        context,
+        &mint_keypair,
+        &[("isin", ISIN), ("jurisdiction", "US"), ("issuer_lei", LEI)],
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // The closed account cannot receive its own rent
+    let result = close_metadata_schema(context, mint, schema_pda).await;
+    assert_transaction_failure(result);
+
+    let rent_recipient = context.payer.pubkey();
+    let result = close_metadata_schema(context, mint, rent_recipient).await;
+    assert_transaction_success(result);
+    assert_account_exists(context, schema_pda, false).await;
+
+    // Without the schema required fields are no longer checked
+    let result = update_metadata_with_schema(context, &mint_keypair, &[("type", "bond")]).await;
+    assert_transaction_success(result);
+}
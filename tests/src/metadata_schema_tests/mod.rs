@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod metadata_schema_tests;
+
+pub mod metadata_schema_helpers;
@@ -0,0 +1,36 @@
+use security_token_client::migration::{
+    create_mint_migration_instruction, migrate_balances_instructions, MigrationHolder,
+    MintMigrationPlan,
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::helpers::send_tx;
+
+/// Build and send CreateMintMigration signed by the mint creator `payer`
+pub async fn execute_create_mint_migration(
+    banks_client: &BanksClient,
+    plan: &MintMigrationPlan,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let ix = create_mint_migration_instruction(plan, &payer.pubkey());
+    send_tx(banks_client, vec![ix], &payer.pubkey(), vec![payer]).await
+}
+
+/// Build and send a single MigrateBalances batch signed by the mint creator `payer`
+pub async fn execute_migrate_balances(
+    banks_client: &BanksClient,
+    plan: &MintMigrationPlan,
+    holders: &[MigrationHolder],
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let mut instructions = migrate_balances_instructions(plan, &payer.pubkey(), holders);
+    assert_eq!(instructions.len(), 1, "holders must fit into one batch");
+    send_tx(
+        banks_client,
+        vec![instructions.remove(0)],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
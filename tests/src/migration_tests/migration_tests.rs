@@ -0,0 +1,276 @@
+use security_token_client::{
+    accounts::MintMigration,
+    errors::SecurityTokenProgramError,
+    migration::{create_mint_migration_instruction, MigrationHolder, MintMigrationPlan},
+    pda::{find_migration_receipt_pda, find_mint_migration_pda},
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        find_mint_authority_pda, get_mint_state, get_token_account_state,
+        initialize_mint_verification_and_mint_to_account, send_tx, start_with_context,
+    },
+    migration_tests::migration_helpers::{execute_create_mint_migration, execute_migrate_balances},
+    mint_authority_multisig_tests::mint_authority_multisig_helpers::{
+        send_signed_by, set_mint_authority_multisig,
+    },
+};
+
+const ACTION_ID: u64 = 1;
+const BALANCE: u64 = 1_000_000;
+
+struct Setup {
+    mint_from: Keypair,
+    mint_to: Keypair,
+    holder: Keypair,
+    holder_account: Pubkey,
+    plan: MintMigrationPlan,
+}
+
+/// Create both mints with the same creator and fund a holder on the old mint
+async fn setup_mints(context: &mut ProgramTestContext) -> Setup {
+    let mint_from = Keypair::new();
+    let mint_to = Keypair::new();
+    let (mint_from_authority, _) =
+        create_minimal_security_token_mint(context, &mint_from, None, 6).await;
+    create_minimal_security_token_mint(context, &mint_to, None, 6).await;
+
+    let holder = Keypair::new();
+    let holder_account = create_spl_account(context, &mint_from, &holder).await;
+    initialize_mint_verification_and_mint_to_account(
+        &mint_from,
+        context,
+        mint_from_authority,
+        holder_account,
+        BALANCE,
+    )
+    .await;
+
+    let plan = MintMigrationPlan {
+        mint_from: mint_from.pubkey(),
+        mint_to: mint_to.pubkey(),
+        creator: context.payer.pubkey(),
+        action_id: ACTION_ID,
+    };
+    Setup {
+        mint_from,
+        mint_to,
+        holder,
+        holder_account,
+        plan,
+    }
+}
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    get_token_account_state(&mut context.banks_client, token_account)
+        .await
+        .base
+        .amount
+}
+
+async fn mint_migration(context: &mut ProgramTestContext, mint_from: Pubkey) -> MintMigration {
+    let (mint_migration_pda, _) = find_mint_migration_pda(&mint_from);
+    let account = assert_account_exists(context, mint_migration_pda, true)
+        .await
+        .unwrap();
+    MintMigration::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn test_should_migrate_balances_to_new_mint() {
+    let context = &mut start_with_context().await;
+    let setup = setup_mints(context).await;
+    let payer = context.payer.insecure_clone();
+
+    let result = execute_create_mint_migration(&context.banks_client, &setup.plan, &payer).await;
+    assert_transaction_success(result);
+
+    let holder_from = setup.holder_account;
+    let holder_to = create_spl_account(context, &setup.mint_to, &setup.holder).await;
+    // A holder with an empty account is migrated without minting
+    let empty_holder = Keypair::new();
+    let empty_from = create_spl_account(context, &setup.mint_from, &empty_holder).await;
+    let empty_to = create_spl_account(context, &setup.mint_to, &empty_holder).await;
+
+    let holders = [
+        MigrationHolder {
+            token_account_from: holder_from,
+            token_account_to: holder_to,
+        },
+        MigrationHolder {
+            token_account_from: empty_from,
+            token_account_to: empty_to,
+        },
+    ];
+    let result =
+        execute_migrate_balances(&context.banks_client, &setup.plan, &holders, &payer).await;
+    assert_transaction_success(result);
+
+    assert_eq!(token_amount(context, holder_from).await, 0);
+    assert_eq!(token_amount(context, holder_to).await, BALANCE);
+    assert_eq!(token_amount(context, empty_to).await, 0);
+    assert_eq!(
+        get_mint_state(&mut context.banks_client, setup.mint_from.pubkey())
+            .await
+            .base
+            .supply,
+        0
+    );
+
+    let migration = mint_migration(context, setup.mint_from.pubkey()).await;
+    assert_eq!(migration.mint_from, setup.mint_from.pubkey());
+    assert_eq!(migration.mint_to, setup.mint_to.pubkey());
+    assert_eq!(migration.action_id, ACTION_ID);
+    assert_eq!(migration.migrated_accounts, 2);
+    assert_eq!(migration.migrated_amount, BALANCE);
+
+    for holder in holders {
+        let (receipt_pda, _) = find_migration_receipt_pda(
+            &setup.mint_to.pubkey(),
+            &holder.token_account_from,
+            ACTION_ID,
+        );
+        assert_account_exists(context, receipt_pda, true).await;
+    }
+
+    // Receipts prevent migrating a token account twice
+    let result =
+        execute_migrate_balances(&context.banks_client, &setup.plan, &holders[..1], &payer).await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_not_migrate_to_account_of_other_owner() {
+    let context = &mut start_with_context().await;
+    let setup = setup_mints(context).await;
+    let payer = context.payer.insecure_clone();
+
+    let result = execute_create_mint_migration(&context.banks_client, &setup.plan, &payer).await;
+    assert_transaction_success(result);
+
+    let holder_from = setup.holder_account;
+    let other_to = create_spl_account(context, &setup.mint_to, &Keypair::new()).await;
+    let holders = [MigrationHolder {
+        token_account_from: holder_from,
+        token_account_to: other_to,
+    }];
+    let result =
+        execute_migrate_balances(&context.banks_client, &setup.plan, &holders, &payer).await;
+    assert_transaction_failure(result);
+
+    assert_eq!(token_amount(context, holder_from).await, BALANCE);
+    assert_eq!(
+        mint_migration(context, setup.mint_from.pubkey())
+            .await
+            .migrated_accounts,
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_should_not_create_migration_between_mints_of_different_creators() {
+    let context = &mut start_with_context().await;
+    let payer = context.payer.insecure_clone();
+
+    // Mint of another issuer
+    let other_creator = Keypair::new();
+    let fund_creator_ix = solana_sdk::system_instruction::transfer(
+        &payer.pubkey(),
+        &other_creator.pubkey(),
+        1_000_000_000,
+    );
+    let result = send_tx(
+        &context.banks_client,
+        vec![fund_creator_ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await;
+    assert_transaction_success(result);
+    let mint_from = Keypair::new();
+    let (other_mint_authority, _) =
+        create_minimal_security_token_mint(context, &mint_from, Some(&other_creator), 6).await;
+
+    let mint_to = Keypair::new();
+    create_minimal_security_token_mint(context, &mint_to, None, 6).await;
+
+    let plan = MintMigrationPlan {
+        mint_from: mint_from.pubkey(),
+        mint_to: mint_to.pubkey(),
+        creator: payer.pubkey(),
+        action_id: ACTION_ID,
+    };
+    let mut ix = create_mint_migration_instruction(&plan, &payer.pubkey());
+    // mint_from_authority
+    ix.accounts[6].pubkey = other_mint_authority;
+    assert_ne!(
+        other_mint_authority,
+        find_mint_authority_pda(&mint_from.pubkey(), &payer.pubkey()).0
+    );
+    let result = send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await;
+    assert_security_token_error(
+        result,
+        SecurityTokenProgramError::MintMigrationCreatorMismatch,
+    );
+
+    let (mint_migration_pda, _) = find_mint_migration_pda(&mint_from.pubkey());
+    assert_account_exists(context, mint_migration_pda, false).await;
+}
+
+#[tokio::test]
+async fn test_should_require_multisig_of_source_mint_to_create_migration() {
+    let context = &mut start_with_context().await;
+    let setup = setup_mints(context).await;
+    let creator = context.payer.insecure_clone();
+    let officers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let officer_keys: Vec<Pubkey> = officers.iter().map(|kp| kp.pubkey()).collect();
+
+    let result = set_mint_authority_multisig(
+        context,
+        setup.mint_from.pubkey(),
+        creator.pubkey(),
+        &[&creator],
+        2,
+        officer_keys.clone(),
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // The creator alone controls the new mint and cannot burn the multisig mint's holders
+    let result = execute_create_mint_migration(&context.banks_client, &setup.plan, &creator).await;
+    assert_security_token_error(
+        result,
+        SecurityTokenProgramError::MintMigrationCreatorMismatch,
+    );
+
+    let result = set_mint_authority_multisig(
+        context,
+        setup.mint_to.pubkey(),
+        creator.pubkey(),
+        &[&creator],
+        2,
+        officer_keys,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let ix = create_mint_migration_instruction(&setup.plan, &creator.pubkey());
+    let result = send_signed_by(context, ix.clone(), &[&officers[0]]).await;
+    assert_transaction_failure(result);
+    let result = send_signed_by(context, ix, &[&officers[0], &officers[2]]).await;
+    assert_transaction_success(result);
+
+    let migration = mint_migration(context, setup.mint_from.pubkey()).await;
+    assert_eq!(migration.mint_to, setup.mint_to.pubkey());
+}
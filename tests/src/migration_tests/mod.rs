@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod migration_tests;
+
+pub mod migration_helpers;
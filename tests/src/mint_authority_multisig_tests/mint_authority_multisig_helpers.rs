@@ -0,0 +1,57 @@
+use security_token_client::{
+    instructions::{SetMintAuthorityMultisig, SetMintAuthorityMultisigInstructionArgs},
+    verification::with_multisig_signers,
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+
+use crate::helpers::{find_mint_authority_pda, send_tx};
+
+/// Send the mint authority mode `ix` signed by `authorities`, the creator or signers of the
+/// multisig of the mint
+pub async fn send_signed_by(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    authorities: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let keys: Vec<Pubkey> = authorities.iter().map(|kp| kp.pubkey()).collect();
+    let ix = with_multisig_signers(ix, &keys).unwrap();
+
+    let mut signers = vec![&payer];
+    for authority in authorities {
+        if !signers.iter().any(|s| s.pubkey() == authority.pubkey()) {
+            signers.push(authority);
+        }
+    }
+    send_tx(&context.banks_client, vec![ix], &payer.pubkey(), signers).await
+}
+
+/// Set the multisig of `mint` to `threshold` of `signers`, authorized by `authorities`
+pub async fn set_mint_authority_multisig(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    original_creator: Pubkey,
+    authorities: &[&Keypair],
+    threshold: u8,
+    signers: Vec<Pubkey>,
+) -> Result<(), BanksClientError> {
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &original_creator);
+
+    let ix = SetMintAuthorityMultisig {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: original_creator,
+        payer: context.payer.pubkey(),
+        mint_account: mint,
+        mint_authority_account: mint_authority_pda,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction(SetMintAuthorityMultisigInstructionArgs { threshold, signers });
+
+    send_signed_by(context, ix, authorities).await
+}
@@ -0,0 +1,201 @@
+use security_token_client::{
+    accounts::MintAuthority,
+    instructions::{AnchorDocument, AnchorDocumentInstructionArgs},
+    pda::find_document_registry_pda,
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_transaction_failure, assert_transaction_success,
+        find_mint_authority_pda, start_with_context,
+    },
+    metadata_schema_tests::metadata_schema_helpers::create_mint_with_metadata,
+    mint_authority_multisig_tests::mint_authority_multisig_helpers::{
+        send_signed_by, set_mint_authority_multisig,
+    },
+};
+
+/// Anchor a document of `mint` signed by `authorities`, a mint authority operation to check
+/// who controls the mint
+async fn anchor_document_signed_by(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    original_creator: Pubkey,
+    authorities: &[&Keypair],
+    name: &str,
+) -> Result<(), BanksClientError> {
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &original_creator);
+
+    let ix = AnchorDocument {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: original_creator,
+        payer: context.payer.pubkey(),
+        mint_account: mint,
+        document_registry_account: find_document_registry_pda(&mint).0,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction(AnchorDocumentInstructionArgs {
+        name: name.to_string(),
+        uri: format!("ipfs://{name}"),
+        sha256: [0xab; 32],
+    });
+
+    send_signed_by(context, ix, authorities).await
+}
+
+async fn mint_authority_multisig(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    original_creator: Pubkey,
+) -> (usize, Option<(u8, Vec<Pubkey>)>) {
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &original_creator);
+    let account = assert_account_exists(context, mint_authority_pda, true)
+        .await
+        .unwrap();
+    let mint_authority = MintAuthority::from_bytes(&account.data).unwrap();
+    (
+        account.data.len(),
+        mint_authority.decode_multisig(&account.data),
+    )
+}
+
+#[tokio::test]
+async fn test_should_require_multisig_threshold() {
+    let context = &mut start_with_context().await;
+    let mint = create_mint_with_metadata(context).await.pubkey();
+    let creator = context.payer.insecure_clone();
+    let officers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let officer_keys: Vec<Pubkey> = officers.iter().map(|kp| kp.pubkey()).collect();
+
+    let result = set_mint_authority_multisig(
+        context,
+        mint,
+        creator.pubkey(),
+        &[&creator],
+        2,
+        officer_keys.clone(),
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        mint_authority_multisig(context, mint, creator.pubkey()).await,
+        (135 + 3 * 32, Some((2, officer_keys.clone())))
+    );
+
+    // The creator key alone no longer controls the mint
+    let result =
+        anchor_document_signed_by(context, mint, creator.pubkey(), &[&creator], "prospectus").await;
+    assert_transaction_failure(result);
+
+    // Below the threshold
+    let result = anchor_document_signed_by(
+        context,
+        mint,
+        creator.pubkey(),
+        &[&officers[0]],
+        "prospectus",
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    // A signer outside the multisig does not count
+    let result = anchor_document_signed_by(
+        context,
+        mint,
+        creator.pubkey(),
+        &[&officers[0], &creator],
+        "prospectus",
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    // The same signer twice does not count
+    let result = anchor_document_signed_by(
+        context,
+        mint,
+        creator.pubkey(),
+        &[&officers[1], &officers[1]],
+        "prospectus",
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let result = anchor_document_signed_by(
+        context,
+        mint,
+        creator.pubkey(),
+        &[&officers[2], &officers[0]],
+        "prospectus",
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // The multisig hands the control back to the creator
+    let result = set_mint_authority_multisig(
+        context,
+        mint,
+        creator.pubkey(),
+        &[&officers[0], &officers[1]],
+        0,
+        vec![],
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        mint_authority_multisig(context, mint, creator.pubkey()).await,
+        (66, None)
+    );
+
+    let result =
+        anchor_document_signed_by(context, mint, creator.pubkey(), &[&creator], "terms").await;
+    assert_transaction_success(result);
+}
+
+#[tokio::test]
+async fn test_should_reject_invalid_multisig() {
+    let context = &mut start_with_context().await;
+    let mint = create_mint_with_metadata(context).await.pubkey();
+    let creator = context.payer.insecure_clone();
+    let signer = Pubkey::new_unique();
+    let other_signer = Pubkey::new_unique();
+
+    let invalid = [
+        (3, vec![signer, other_signer]),
+        (0, vec![signer]),
+        (1, vec![signer, signer]),
+        (1, vec![Pubkey::default()]),
+        (1, (0..12).map(|_| Pubkey::new_unique()).collect()),
+    ];
+    for (threshold, signers) in invalid {
+        let result = set_mint_authority_multisig(
+            context,
+            mint,
+            creator.pubkey(),
+            &[&creator],
+            threshold,
+            signers,
+        )
+        .await;
+        assert_transaction_failure(result);
+    }
+
+    // Only the creator can set the multisig
+    let result = set_mint_authority_multisig(
+        context,
+        mint,
+        creator.pubkey(),
+        &[&Keypair::new()],
+        1,
+        vec![signer],
+    )
+    .await;
+    assert_transaction_failure(result);
+    assert_eq!(
+        mint_authority_multisig(context, mint, creator.pubkey()).await,
+        (66, None)
+    );
+}
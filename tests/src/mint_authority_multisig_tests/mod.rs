@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod mint_authority_multisig_tests;
+
+pub mod mint_authority_multisig_helpers;
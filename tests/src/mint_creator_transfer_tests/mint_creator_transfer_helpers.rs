@@ -0,0 +1,66 @@
+use security_token_client::instructions::{
+    AcceptMintCreatorTransfer, ProposeMintCreatorTransfer,
+    ProposeMintCreatorTransferInstructionArgs,
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::helpers::{find_mint_authority_pda, send_tx};
+
+/// Propose `new_creator` as creator of `mint`, signed by `creator` through the MintAuthority
+/// PDA derived from `original_creator`
+pub async fn propose_mint_creator_transfer(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    original_creator: Pubkey,
+    creator: &Keypair,
+    new_creator: Pubkey,
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &original_creator);
+
+    let mut ix = ProposeMintCreatorTransfer {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: creator.pubkey(),
+        payer: payer.pubkey(),
+        mint_account: mint,
+        mint_authority_account: mint_authority_pda,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction(ProposeMintCreatorTransferInstructionArgs { new_creator });
+    ix.accounts[2].is_signer = true;
+
+    let mut signers = vec![&payer];
+    if creator.pubkey() != payer.pubkey() {
+        signers.push(creator);
+    }
+    send_tx(&context.banks_client, vec![ix], &payer.pubkey(), signers).await
+}
+
+/// Accept the creator transfer of `mint` as `new_creator`
+pub async fn accept_mint_creator_transfer(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    original_creator: Pubkey,
+    new_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &original_creator);
+
+    let ix = AcceptMintCreatorTransfer {
+        new_creator: new_creator.pubkey(),
+        mint,
+        mint_authority_account: mint_authority_pda,
+    }
+    .instruction();
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer, new_creator],
+    )
+    .await
+}
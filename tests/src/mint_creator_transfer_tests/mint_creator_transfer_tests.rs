@@ -0,0 +1,152 @@
+use security_token_client::{
+    accounts::MintAuthority,
+    instructions::{AnchorDocument, AnchorDocumentInstructionArgs},
+    pda::find_document_registry_pda,
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_transaction_failure, assert_transaction_success,
+        find_mint_authority_pda, send_tx, start_with_context,
+    },
+    metadata_schema_tests::metadata_schema_helpers::create_mint_with_metadata,
+    mint_creator_transfer_tests::mint_creator_transfer_helpers::{
+        accept_mint_creator_transfer, propose_mint_creator_transfer,
+    },
+};
+
+/// Anchor a document of `mint` signed by `creator` through the MintAuthority PDA of the
+/// original creator, a mint authority operation to check who controls the mint
+async fn anchor_document_as(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    original_creator: Pubkey,
+    creator: &Keypair,
+    name: &str,
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &original_creator);
+
+    let mut ix = AnchorDocument {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: creator.pubkey(),
+        payer: payer.pubkey(),
+        mint_account: mint,
+        document_registry_account: find_document_registry_pda(&mint).0,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction(AnchorDocumentInstructionArgs {
+        name: name.to_string(),
+        uri: format!("ipfs://{name}"),
+        sha256: [0xab; 32],
+    });
+    ix.accounts[2].is_signer = true;
+
+    let mut signers = vec![&payer];
+    if creator.pubkey() != payer.pubkey() {
+        signers.push(creator);
+    }
+    send_tx(&context.banks_client, vec![ix], &payer.pubkey(), signers).await
+}
+
+async fn mint_authority_controllers(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    original_creator: Pubkey,
+) -> (usize, Pubkey, Option<Pubkey>) {
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &original_creator);
+    let account = assert_account_exists(context, mint_authority_pda, true)
+        .await
+        .unwrap();
+    let mint_authority = MintAuthority::from_bytes(&account.data).unwrap();
+    assert_eq!(mint_authority.mint_creator, original_creator);
+    let (controller, pending_controller) = mint_authority.decode_controllers(&account.data);
+    (account.data.len(), controller, pending_controller)
+}
+
+#[tokio::test]
+async fn test_should_transfer_mint_creator() {
+    let context = &mut start_with_context().await;
+    let mint = create_mint_with_metadata(context).await.pubkey();
+    let creator = context.payer.insecure_clone();
+    let new_creator = Keypair::new();
+
+    let result = propose_mint_creator_transfer(
+        context,
+        mint,
+        creator.pubkey(),
+        &creator,
+        new_creator.pubkey(),
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        mint_authority_controllers(context, mint, creator.pubkey()).await,
+        (130, creator.pubkey(), Some(new_creator.pubkey()))
+    );
+
+    // The creator keeps control until the transfer is accepted
+    let result = anchor_document_as(context, mint, creator.pubkey(), &creator, "prospectus").await;
+    assert_transaction_success(result);
+
+    let result =
+        accept_mint_creator_transfer(context, mint, creator.pubkey(), &Keypair::new()).await;
+    assert_transaction_failure(result);
+
+    let result = accept_mint_creator_transfer(context, mint, creator.pubkey(), &new_creator).await;
+    assert_transaction_success(result);
+    assert_eq!(
+        mint_authority_controllers(context, mint, creator.pubkey()).await,
+        (130, new_creator.pubkey(), None)
+    );
+
+    // The previous creator lost control, the new creator signs with the same MintAuthority PDA
+    let result = anchor_document_as(context, mint, creator.pubkey(), &creator, "terms").await;
+    assert_transaction_failure(result);
+    let result = anchor_document_as(context, mint, creator.pubkey(), &new_creator, "terms").await;
+    assert_transaction_success(result);
+
+    // Nothing is pending anymore
+    let result = accept_mint_creator_transfer(context, mint, creator.pubkey(), &new_creator).await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_cancel_mint_creator_transfer() {
+    let context = &mut start_with_context().await;
+    let mint = create_mint_with_metadata(context).await.pubkey();
+    let creator = context.payer.insecure_clone();
+    let new_creator = Keypair::new();
+
+    // Proposing the current creator is rejected
+    let result =
+        propose_mint_creator_transfer(context, mint, creator.pubkey(), &creator, creator.pubkey())
+            .await;
+    assert_transaction_failure(result);
+
+    let result = propose_mint_creator_transfer(
+        context,
+        mint,
+        creator.pubkey(),
+        &creator,
+        new_creator.pubkey(),
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result =
+        propose_mint_creator_transfer(context, mint, creator.pubkey(), &creator, Pubkey::default())
+            .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        mint_authority_controllers(context, mint, creator.pubkey()).await,
+        (66, creator.pubkey(), None)
+    );
+
+    let result = accept_mint_creator_transfer(context, mint, creator.pubkey(), &new_creator).await;
+    assert_transaction_failure(result);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod mint_creator_transfer_tests;
+
+pub mod mint_creator_transfer_helpers;
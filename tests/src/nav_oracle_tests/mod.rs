@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod nav_oracle_tests;
+
+pub mod nav_oracle_helpers;
@@ -0,0 +1,144 @@
+use security_token_client::{
+    instructions::{CreateNavOracle, CreateNavOracleInstructionArgs, UpdateMultiplierFromOracle},
+    pda::{find_nav_oracle_pda, find_scaled_ui_amount_authority_pda},
+    types::{CreateNavOracleArgs, InitializeMintArgs, MintArgs, ScaledUiAmountConfigArgs},
+};
+use solana_program_test::{BanksClient, BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    signature::{Keypair, Signer},
+    sysvar,
+};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{
+    create_dummy_verification_from_instruction, find_mint_authority_pda,
+    find_mint_freeze_authority_pda, initialize_mint_for_creator, send_tx,
+};
+
+/// Offset of the value in the mock oracle account data, behind an 8 byte header
+pub const ORACLE_VALUE_OFFSET: u32 = 8;
+
+/// Create a security token mint whose ScaledUiAmount authority is the program PDA
+pub async fn create_scaled_security_token_mint(
+    context: &mut ProgramTestContext,
+    mint_keypair: &Keypair,
+    mint_creator: &Keypair,
+    decimals: u8,
+) -> Pubkey {
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (freeze_authority_pda, _) = find_mint_freeze_authority_pda(&mint);
+    let (scaled_ui_amount_authority, _) = find_scaled_ui_amount_authority_pda(&mint);
+
+    let mint_args = InitializeMintArgs {
+        ix_mint: MintArgs {
+            decimals,
+            mint_authority: mint_creator.pubkey(),
+            freeze_authority: freeze_authority_pda,
+        },
+        ix_metadata_pointer: None,
+        ix_metadata: None,
+        ix_scaled_ui_amount: Some(ScaledUiAmountConfigArgs {
+            authority: scaled_ui_amount_authority,
+            multiplier: 1f64.to_le_bytes(),
+            new_multiplier_effective_timestamp: 0,
+            new_multiplier: 1f64.to_le_bytes(),
+        }),
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
+    };
+
+    initialize_mint_for_creator(
+        context,
+        mint_keypair,
+        mint_authority_pda,
+        mint_creator,
+        &mint_args,
+    )
+    .await;
+
+    mint_authority_pda
+}
+
+/// Write `value` into the mock oracle account
+pub fn set_oracle_value(context: &mut ProgramTestContext, oracle: &Pubkey, value: u64) {
+    let mut data = vec![0u8; ORACLE_VALUE_OFFSET as usize + 8];
+    data[ORACLE_VALUE_OFFSET as usize..].copy_from_slice(&value.to_le_bytes());
+    let account = Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: Pubkey::new_unique(),
+        executable: false,
+        rent_epoch: 0,
+    };
+    context.set_account(oracle, &AccountSharedData::from(account));
+}
+
+/// Build and send CreateNavOracle instruction authorized by mint authority
+pub async fn execute_create_nav_oracle(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    oracle: Pubkey,
+    args: CreateNavOracleArgs,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (nav_oracle_account, _) = find_nav_oracle_pda(&mint);
+
+    let ix = CreateNavOracle {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        nav_oracle_account,
+        oracle,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(CreateNavOracleInstructionArgs {
+        create_nav_oracle_args: args,
+    });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send UpdateMultiplierFromOracle instruction
+pub async fn execute_update_multiplier_from_oracle(
+    banks_client: &BanksClient,
+    verification_config: Pubkey,
+    mint: Pubkey,
+    oracle: Pubkey,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (nav_oracle_account, _) = find_nav_oracle_pda(&mint);
+    let (scaled_ui_amount_authority, _) = find_scaled_ui_amount_authority_pda(&mint);
+
+    let ix = UpdateMultiplierFromOracle {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        mint_account: mint,
+        nav_oracle_account,
+        oracle,
+        scaled_ui_amount_authority,
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction();
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
@@ -0,0 +1,185 @@
+use security_token_client::{
+    accounts::NavOracle, errors::SecurityTokenProgramError,
+    instructions::UPDATE_MULTIPLIER_FROM_ORACLE_DISCRIMINATOR, pda::find_nav_oracle_pda,
+    types::CreateNavOracleArgs,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::extension::{scaled_ui_amount::ScaledUiAmountConfig, BaseStateWithExtensions};
+
+use crate::{
+    coupon_tests::coupon_helpers::{current_timestamp, warp_to_timestamp},
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_verification_config, get_default_verification_programs,
+        get_mint_state, start_with_context_and_accounts,
+    },
+    nav_oracle_tests::nav_oracle_helpers::{
+        create_scaled_security_token_mint, execute_create_nav_oracle,
+        execute_update_multiplier_from_oracle, set_oracle_value, ORACLE_VALUE_OFFSET,
+    },
+};
+
+const MIN_UPDATE_INTERVAL: u32 = 3_600;
+
+/// NAV between 0.5 and 2.0 with 6 decimals
+fn nav_oracle_args() -> CreateNavOracleArgs {
+    CreateNavOracleArgs {
+        value_offset: ORACLE_VALUE_OFFSET,
+        value_decimals: 6,
+        min_value: 500_000,
+        max_value: 2_000_000,
+        min_update_interval: MIN_UPDATE_INTERVAL,
+    }
+}
+
+async fn current_multiplier(context: &mut ProgramTestContext, mint: Pubkey) -> f64 {
+    let mint_state = get_mint_state(&mut context.banks_client, mint).await;
+    let scaled_ui_amount = mint_state
+        .get_extension::<ScaledUiAmountConfig>()
+        .expect("ScaledUiAmount extension should be accessible");
+    f64::from(scaled_ui_amount.new_multiplier)
+}
+
+#[tokio::test]
+async fn test_should_update_multiplier_from_oracle_within_bounds_and_frequency() {
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let mint_authority_pda =
+        create_scaled_security_token_mint(context, &mint_keypair, &mint_creator, 6).await;
+    let update_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        UPDATE_MULTIPLIER_FROM_ORACLE_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+
+    let oracle = Pubkey::new_unique();
+    set_oracle_value(context, &oracle, 1_250_000);
+
+    let result = execute_create_nav_oracle(
+        &context.banks_client,
+        mint,
+        oracle,
+        nav_oracle_args(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (nav_oracle_pda, _) = find_nav_oracle_pda(&mint);
+    let account = assert_account_exists(context, nav_oracle_pda, true)
+        .await
+        .unwrap();
+    let nav_oracle = NavOracle::from_bytes(&account.data).unwrap();
+    assert_eq!(nav_oracle.oracle, oracle);
+    assert_eq!(nav_oracle.last_update_timestamp, 0);
+
+    let result = execute_update_multiplier_from_oracle(
+        &context.banks_client,
+        update_verification_config,
+        mint,
+        oracle,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(current_multiplier(context, mint).await, 1.25);
+
+    // Too early for the next update
+    set_oracle_value(context, &oracle, 1_300_000);
+    let result = execute_update_multiplier_from_oracle(
+        &context.banks_client,
+        update_verification_config,
+        mint,
+        oracle,
+        &mint_creator,
+    )
+    .await;
+    assert_security_token_error(
+        result,
+        SecurityTokenProgramError::MultiplierUpdateTooFrequent,
+    );
+
+    let now = current_timestamp(context).await;
+    warp_to_timestamp(context, now + i64::from(MIN_UPDATE_INTERVAL)).await;
+
+    // Oracle value above the bounds is rejected
+    set_oracle_value(context, &oracle, 2_500_000);
+    let result = execute_update_multiplier_from_oracle(
+        &context.banks_client,
+        update_verification_config,
+        mint,
+        oracle,
+        &mint_creator,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::OracleValueOutOfBounds);
+
+    // Only the whitelisted oracle can drive the multiplier
+    let other_oracle = Pubkey::new_unique();
+    set_oracle_value(context, &other_oracle, 1_300_000);
+    let result = execute_update_multiplier_from_oracle(
+        &context.banks_client,
+        update_verification_config,
+        mint,
+        other_oracle,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    set_oracle_value(context, &oracle, 1_300_000);
+    let result = execute_update_multiplier_from_oracle(
+        &context.banks_client,
+        update_verification_config,
+        mint,
+        oracle,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(current_multiplier(context, mint).await, 1.3);
+}
+
+#[tokio::test]
+async fn test_create_nav_oracle_validation() {
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_scaled_security_token_mint(context, &mint_keypair, &mint_creator, 6).await;
+    let oracle = Pubkey::new_unique();
+
+    // Min value above max value
+    let result = execute_create_nav_oracle(
+        &context.banks_client,
+        mint,
+        oracle,
+        CreateNavOracleArgs {
+            min_value: 3_000_000,
+            ..nav_oracle_args()
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let result = execute_create_nav_oracle(
+        &context.banks_client,
+        mint,
+        oracle,
+        nav_oracle_args(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+}
@@ -1,3 +1,4 @@
+use security_token_client::discriminators::TransferHookRuleFlags;
 use security_token_client::instructions::{
     BurnBuilder, FreezeBuilder, MintBuilder, PauseBuilder, ResumeBuilder, ThawBuilder,
     TransferBuilder, TrimVerificationConfigBuilder, UpdateVerificationConfigBuilder,
@@ -5,6 +6,7 @@ use security_token_client::instructions::{
     RESUME_DISCRIMINATOR, THAW_DISCRIMINATOR, TRANSFER_DISCRIMINATOR,
 };
 use security_token_client::programs::SECURITY_TOKEN_PROGRAM_ID;
+use security_token_client::travel_rule::memo_hash;
 use security_token_client::types::{
     InitializeMintArgs, InitializeVerificationConfigArgs, MintArgs, TrimVerificationConfigArgs,
     UpdateVerificationConfigArgs,
@@ -70,6 +72,8 @@ async fn test_basic_t22_operations() {
         ix_metadata_pointer: None,
         ix_metadata: None,
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(
@@ -98,6 +102,10 @@ async fn test_basic_t22_operations() {
             instruction_discriminator: discriminator,
             cpi_mode: false,
             program_addresses: get_default_verification_programs(),
+            threshold: 0,
+            lookback_window: 0,
+            require_top_level: false,
+            timelock_seconds: 0,
         };
 
         initialize_verification_config(
@@ -260,6 +268,8 @@ async fn test_t22_extension_operations() {
         ix_metadata_pointer: None,
         ix_metadata: None,
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(
@@ -279,6 +289,10 @@ async fn test_t22_extension_operations() {
         instruction_discriminator: PAUSE_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: get_default_verification_programs(),
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
     initialize_verification_config(
         &mint_keypair,
@@ -322,6 +336,10 @@ async fn test_t22_extension_operations() {
         instruction_discriminator: RESUME_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: get_default_verification_programs(),
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     initialize_verification_config(
@@ -396,6 +414,8 @@ async fn test_t22_transfer_operations() {
         ix_metadata_pointer: None,
         ix_metadata: None,
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(
@@ -410,6 +430,10 @@ async fn test_t22_transfer_operations() {
         instruction_discriminator: TRANSFER_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: get_default_verification_programs(),
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     initialize_verification_config(
@@ -458,6 +482,34 @@ async fn test_t22_transfer_operations() {
     let destination_account_state =
         get_token_account_state(&mut context.banks_client, destination_account).await;
     assert_eq!(destination_account_state.base.amount, 100_000);
+
+    // Transfer referencing an off-chain travel-rule payload
+    let transfer_with_memo_ix = TransferBuilder::new()
+        .mint(mint_keypair.pubkey())
+        .verification_config(verification_config_pda)
+        .permanent_delegate_authority(permanent_delegate_pda)
+        .mint_account(mint_keypair.pubkey())
+        .from_token_account(source_account)
+        .to_token_account(destination_account)
+        .transfer_hook_program(Pubkey::from(security_token_transfer_hook::id()))
+        .amount(50_000)
+        .memo_hash(memo_hash(b"encrypted travel rule payload"))
+        .instruction();
+
+    let dummy_transfer_with_memo_ix =
+        create_dummy_verification_from_instruction(&transfer_with_memo_ix);
+
+    let result = send_tx(
+        &context.banks_client,
+        vec![dummy_transfer_with_memo_ix, transfer_with_memo_ix],
+        &context.payer.pubkey(),
+        vec![&context.payer],
+    )
+    .await;
+    assert_transaction_success(result);
+    let destination_account_state =
+        get_token_account_state(&mut context.banks_client, destination_account).await;
+    assert_eq!(destination_account_state.base.amount, 150_000);
 }
 
 fn dummy_program_1_processor(
@@ -529,6 +581,8 @@ async fn test_p2p_transfer_direct_spl() {
         ix_metadata_pointer: None,
         ix_metadata: None,
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(
@@ -543,6 +597,10 @@ async fn test_p2p_transfer_direct_spl() {
         instruction_discriminator: TRANSFER_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: vec![dummy_program_1_id, dummy_program_2_id],
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     initialize_verification_config(
@@ -692,6 +750,8 @@ async fn test_transfer_hook_extra_account_metas_init_update_trim() {
         ix_metadata_pointer: None,
         ix_metadata: None,
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(
@@ -717,6 +777,10 @@ async fn test_transfer_hook_extra_account_metas_init_update_trim() {
             instruction_discriminator: TRANSFER_DISCRIMINATOR,
             cpi_mode: false,
             program_addresses: vec![program_address_1, program_address_2],
+            threshold: 0,
+            lookback_window: 0,
+            require_top_level: false,
+            timelock_seconds: 0,
         },
     )
     .await;
@@ -737,8 +801,8 @@ async fn test_transfer_hook_extra_account_metas_init_update_trim() {
         ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&tlv_state)
             .expect("extra meta list should deserialize");
 
-    // Must be 3 accounts: verification config + 2 program addresses
-    assert_eq!(extra_metas_data.data().len(), 3);
+    // Must be 8 accounts: verification config + 2 program addresses + rules marker + 4 transfer rule accounts
+    assert_eq!(extra_metas_data.data().len(), 8);
     // Verify the metas are correct
     let metas = extra_metas_data
         .data()
@@ -751,6 +815,13 @@ async fn test_transfer_hook_extra_account_metas_init_update_trim() {
     );
     assert_eq!(Pubkey::from(metas[1].address_config), program_address_1);
     assert_eq!(Pubkey::from(metas[2].address_config), program_address_2);
+    // Suspensions and sub-account ledgers are enforced for every mint
+    assert_eq!(
+        TransferHookRuleFlags::from_marker(&metas[3].address_config),
+        Some(TransferHookRuleFlags::new(
+            TransferHookRuleFlags::SUSPENSION | TransferHookRuleFlags::SUB_ACCOUNT_LEDGER
+        ))
+    );
 
     let program_address_3 = Pubkey::new_unique();
     let update_verification_config_args = UpdateVerificationConfigArgs {
@@ -758,6 +829,10 @@ async fn test_transfer_hook_extra_account_metas_init_update_trim() {
         cpi_mode: false,
         offset: 2,
         program_addresses: vec![program_address_3],
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     let account_metas_pda = get_extra_account_metas_address(
@@ -806,8 +881,8 @@ async fn test_transfer_hook_extra_account_metas_init_update_trim() {
         ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&tlv_state)
             .expect("extra meta list should deserialize");
 
-    // Must be 4 accounts: verification config + 3 program addresses
-    assert_eq!(extra_metas_data.data().len(), 4);
+    // Must be 9 accounts: verification config + 3 program addresses + rules marker + 4 transfer rule accounts
+    assert_eq!(extra_metas_data.data().len(), 9);
     // Verify the metas are correct
     let metas = extra_metas_data
         .data()
@@ -850,9 +925,10 @@ async fn test_transfer_hook_extra_account_metas_init_update_trim() {
         .mint(mint_keypair.pubkey())
         .verification_config_or_mint_authority(mint_authority_pda)
         .instructions_sysvar_or_creator(context.payer.pubkey())
+        .payer(context.payer.pubkey())
         .config_account(verification_config_pda)
         .mint_account(mint_keypair.pubkey())
-        .recipient(context.payer.pubkey())
+        .rent_recipient(context.payer.pubkey())
         .trim_verification_config_args(trim_verification_config_args)
         .account_metas_pda(Some(account_metas_pda))
         .transfer_hook_pda(Some(transfer_hook_pda))
@@ -885,8 +961,8 @@ async fn test_transfer_hook_extra_account_metas_init_update_trim() {
         ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&tlv_state)
             .expect("extra meta list should deserialize");
 
-    // Must be 2 accounts
-    assert_eq!(extra_metas_data.data().len(), 2);
+    // Must be 7 accounts: verification config + 1 program address + rules marker + 4 transfer rule accounts
+    assert_eq!(extra_metas_data.data().len(), 7);
     // Verify the metas are correct
     let metas = extra_metas_data
         .data()
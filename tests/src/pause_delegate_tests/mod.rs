@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod pause_delegate_tests;
+
+pub mod pause_delegate_helpers;
@@ -0,0 +1,82 @@
+use security_token_client::{
+    instructions::{Pause, Resume, SetPauseDelegate, SetPauseDelegateInstructionArgs},
+    pda::find_pause_delegate_pda,
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{find_mint_authority_pda, find_mint_pause_authority_pda, send_tx};
+
+/// Build and send SetPauseDelegate instruction authorized by mint authority
+pub async fn execute_set_pause_delegate(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    delegate: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (pause_delegate_account, _) = find_pause_delegate_pda(&mint);
+
+    let ix = SetPauseDelegate {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        pause_delegate_account,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(SetPauseDelegateInstructionArgs { delegate });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send Pause instruction signed by the pause delegate
+pub async fn execute_pause_by_delegate(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    delegate: &Keypair,
+) -> Result<(), BanksClientError> {
+    let mut ix = Pause {
+        mint,
+        verification_config: find_pause_delegate_pda(&mint).0,
+        instructions_sysvar: delegate.pubkey(),
+        pause_authority: find_mint_pause_authority_pda(&mint).0,
+        mint_account: mint,
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction();
+    // Pause delegate signs in place of the instructions sysvar
+    ix.accounts[2].is_signer = true;
+
+    send_tx(banks_client, vec![ix], &delegate.pubkey(), vec![delegate]).await
+}
+
+/// Build and send Resume instruction signed by the pause delegate
+pub async fn execute_resume_by_delegate(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    delegate: &Keypair,
+) -> Result<(), BanksClientError> {
+    let mut ix = Resume {
+        mint,
+        verification_config: find_pause_delegate_pda(&mint).0,
+        instructions_sysvar: delegate.pubkey(),
+        pause_authority: find_mint_pause_authority_pda(&mint).0,
+        mint_account: mint,
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction();
+    // Pause delegate signs in place of the instructions sysvar
+    ix.accounts[2].is_signer = true;
+
+    send_tx(banks_client, vec![ix], &delegate.pubkey(), vec![delegate]).await
+}
@@ -0,0 +1,118 @@
+use security_token_client::{accounts::PauseDelegate, pda::find_pause_delegate_pda};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_pod::primitives::PodBool;
+use spl_token_2022::extension::{pausable::PausableConfig, BaseStateWithExtensions};
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_transaction_failure, assert_transaction_success,
+        create_minimal_security_token_mint, get_mint_state, start_with_context_and_accounts,
+    },
+    pause_delegate_tests::pause_delegate_helpers::{
+        execute_pause_by_delegate, execute_resume_by_delegate, execute_set_pause_delegate,
+    },
+};
+
+async fn is_paused(context: &mut ProgramTestContext, mint: Pubkey) -> bool {
+    let mint_state = get_mint_state(&mut context.banks_client, mint).await;
+    let pausable = mint_state.get_extension::<PausableConfig>().unwrap();
+    pausable.paused == PodBool(1)
+}
+
+#[tokio::test]
+async fn test_pause_delegate_should_pause_and_resume() {
+    let delegate = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![(&delegate, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+
+    let result = execute_set_pause_delegate(
+        &context.banks_client,
+        mint,
+        delegate.pubkey(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    let (pause_delegate_pda, _) = find_pause_delegate_pda(&mint);
+    let account = assert_account_exists(context, pause_delegate_pda, true)
+        .await
+        .unwrap();
+    let pause_delegate = PauseDelegate::from_bytes(&account.data).unwrap();
+    assert_eq!(pause_delegate.mint, mint);
+    assert_eq!(pause_delegate.delegate, delegate.pubkey());
+
+    // No VerificationConfig exists for Pause and Resume
+    let result = execute_pause_by_delegate(&context.banks_client, mint, &delegate).await;
+    assert_transaction_success(result);
+    assert!(is_paused(context, mint).await);
+
+    let result = execute_resume_by_delegate(&context.banks_client, mint, &delegate).await;
+    assert_transaction_success(result);
+    assert!(!is_paused(context, mint).await);
+}
+
+#[tokio::test]
+async fn test_replaced_pause_delegate_should_not_pause() {
+    let delegate = Keypair::new();
+    let next_delegate = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![
+        (&delegate, 1_000_000_000),
+        (&next_delegate, 1_000_000_000),
+    ])
+    .await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+
+    let result = execute_set_pause_delegate(
+        &context.banks_client,
+        mint,
+        delegate.pubkey(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // Only the named delegate can sign
+    let result = execute_pause_by_delegate(&context.banks_client, mint, &next_delegate).await;
+    assert_transaction_failure(result);
+
+    let result = execute_set_pause_delegate(
+        &context.banks_client,
+        mint,
+        next_delegate.pubkey(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_pause_by_delegate(&context.banks_client, mint, &delegate).await;
+    assert_transaction_failure(result);
+    let result = execute_pause_by_delegate(&context.banks_client, mint, &next_delegate).await;
+    assert_transaction_success(result);
+    assert!(is_paused(context, mint).await);
+
+    // Removing the delegate closes its account
+    let result = execute_set_pause_delegate(
+        &context.banks_client,
+        mint,
+        Pubkey::default(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    let (pause_delegate_pda, _) = find_pause_delegate_pda(&mint);
+    assert_account_exists(context, pause_delegate_pda, false).await;
+
+    let result = execute_resume_by_delegate(&context.banks_client, mint, &next_delegate).await;
+    assert_transaction_failure(result);
+    assert!(is_paused(context, mint).await);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod permanent_delegate_tests;
+
+pub mod permanent_delegate_helpers;
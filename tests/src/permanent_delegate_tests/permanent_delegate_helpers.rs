@@ -0,0 +1,114 @@
+use security_token_client::{
+    instructions::{
+        DisablePermanentDelegate, RotatePermanentDelegate, RotatePermanentDelegateInstructionArgs,
+        TRANSFER_DISCRIMINATOR,
+    },
+    pda::find_permanent_delegate_config_pda,
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+use spl_transfer_hook_interface::get_extra_account_metas_address;
+
+use crate::helpers::{
+    find_mint_authority_pda, find_permanent_delegate_pda, find_transfer_hook_pda,
+    find_verification_config_pda, send_tx,
+};
+
+/// Index of the permanent_delegate account after the verification overhead and payer
+const PERMANENT_DELEGATE_INDEX: usize = 5;
+
+/// Current permanent delegate account, the program PDA until the first rotation
+fn permanent_delegate_key(mint: &Pubkey, current_delegate: Option<&Keypair>) -> Pubkey {
+    current_delegate
+        .map(|delegate| delegate.pubkey())
+        .unwrap_or_else(|| find_permanent_delegate_pda(mint).0)
+}
+
+/// Send `ix` signed by the mint creator and the rotated delegate, if any
+async fn send_with_delegate(
+    banks_client: &BanksClient,
+    mut ix: Instruction,
+    mint_creator: &Keypair,
+    current_delegate: Option<&Keypair>,
+) -> Result<(), BanksClientError> {
+    let mut signers = vec![mint_creator];
+    if let Some(delegate) = current_delegate {
+        ix.accounts[PERMANENT_DELEGATE_INDEX].is_signer = true;
+        signers.push(delegate);
+    }
+    send_tx(banks_client, vec![ix], &mint_creator.pubkey(), signers).await
+}
+
+/// Build and send RotatePermanentDelegate instruction authorized by mint authority
+pub async fn execute_rotate_permanent_delegate(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    mint_creator: &Keypair,
+    current_delegate: Option<&Keypair>,
+    new_delegate: Pubkey,
+) -> Result<(), BanksClientError> {
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (permanent_delegate_config_account, _) = find_permanent_delegate_config_pda(&mint);
+    let (transfer_verification_config, _) =
+        find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+    let (transfer_hook_pda, _) = find_transfer_hook_pda(&mint);
+
+    let ix = RotatePermanentDelegate {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        permanent_delegate: permanent_delegate_key(&mint, current_delegate),
+        permanent_delegate_config_account,
+        transfer_verification_config,
+        token_program: TOKEN_22_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+        account_metas_pda: get_extra_account_metas_address(&mint, &transfer_hook_program),
+        transfer_hook_pda,
+        transfer_hook_program,
+    }
+    .instruction(RotatePermanentDelegateInstructionArgs { new_delegate });
+
+    send_with_delegate(banks_client, ix, mint_creator, current_delegate).await
+}
+
+/// Build and send DisablePermanentDelegate instruction authorized by mint authority
+pub async fn execute_disable_permanent_delegate(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    mint_creator: &Keypair,
+    current_delegate: Option<&Keypair>,
+) -> Result<(), BanksClientError> {
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (permanent_delegate_config_account, _) = find_permanent_delegate_config_pda(&mint);
+    let (transfer_verification_config, _) =
+        find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+    let (transfer_hook_pda, _) = find_transfer_hook_pda(&mint);
+
+    let ix = DisablePermanentDelegate {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        permanent_delegate: permanent_delegate_key(&mint, current_delegate),
+        permanent_delegate_config_account,
+        transfer_verification_config,
+        token_program: TOKEN_22_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+        account_metas_pda: get_extra_account_metas_address(&mint, &transfer_hook_program),
+        transfer_hook_pda,
+        transfer_hook_program,
+    }
+    .instruction();
+
+    send_with_delegate(banks_client, ix, mint_creator, current_delegate).await
+}
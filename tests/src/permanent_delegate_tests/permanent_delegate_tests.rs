@@ -0,0 +1,295 @@
+use security_token_client::{
+    accounts::PermanentDelegateConfig, errors::SecurityTokenProgramError,
+    pda::find_permanent_delegate_config_pda,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::extension::{permanent_delegate::PermanentDelegate, BaseStateWithExtensions};
+use spl_transfer_hook_interface::get_extra_account_metas_address;
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_spl_account, find_permanent_delegate_pda,
+        get_mint_state, get_token_account_state, setup_transfer_verified_mint,
+        start_with_transfer_hook,
+    },
+    permanent_delegate_tests::permanent_delegate_helpers::{
+        execute_disable_permanent_delegate, execute_rotate_permanent_delegate,
+    },
+    position_limit_tests::position_limit_helpers::transfer,
+};
+
+const SUPPLY: u64 = 1_000_000;
+
+struct Setup {
+    mint: Pubkey,
+    source_account: Pubkey,
+    destination_account: Pubkey,
+}
+
+/// Create mint with a transfer verification config and mint the whole supply to `source_owner`
+async fn setup_mint(context: &mut ProgramTestContext, source_owner: &Keypair) -> Setup {
+    let (mint_keypair, _, source_account) =
+        setup_transfer_verified_mint(context, source_owner, SUPPLY).await;
+    let destination_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+    Setup {
+        mint: mint_keypair.pubkey(),
+        source_account,
+        destination_account,
+    }
+}
+
+async fn mint_permanent_delegate(context: &mut ProgramTestContext, mint: Pubkey) -> Option<Pubkey> {
+    let mint_state = get_mint_state(&mut context.banks_client, mint).await;
+    let permanent_delegate = mint_state.get_extension::<PermanentDelegate>().unwrap();
+    Option::<Pubkey>::from(permanent_delegate.delegate)
+}
+
+async fn permanent_delegate_config(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+) -> PermanentDelegateConfig {
+    let (permanent_delegate_config_pda, _) = find_permanent_delegate_config_pda(&mint);
+    let account = assert_account_exists(context, permanent_delegate_config_pda, true)
+        .await
+        .unwrap();
+    PermanentDelegateConfig::from_bytes(&account.data).unwrap()
+}
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    get_token_account_state(&mut context.banks_client, token_account)
+        .await
+        .base
+        .amount
+}
+
+#[tokio::test]
+async fn test_should_rotate_permanent_delegate_to_custodian() {
+    let source_owner = Keypair::new();
+    let custodian = Keypair::new();
+    let next_custodian = Keypair::new();
+    let context =
+        &mut start_with_transfer_hook(&[&source_owner, &custodian, &next_custodian]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let setup = setup_mint(context, &source_owner).await;
+    let mint = setup.mint;
+
+    let result = execute_rotate_permanent_delegate(
+        &context.banks_client,
+        mint,
+        &mint_creator,
+        None,
+        custodian.pubkey(),
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        mint_permanent_delegate(context, mint).await,
+        Some(custodian.pubkey())
+    );
+    let config = permanent_delegate_config(context, mint).await;
+    assert_eq!(config.mint, mint);
+    assert_eq!(config.delegate, custodian.pubkey());
+
+    // The custodian moves holder tokens through the transfer hook
+    let result = transfer(
+        context,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &custodian,
+        100_000,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        token_amount(context, setup.destination_account).await,
+        100_000
+    );
+
+    // Later rotations need the signature of the current delegate
+    let result = execute_rotate_permanent_delegate(
+        &context.banks_client,
+        mint,
+        &mint_creator,
+        None,
+        next_custodian.pubkey(),
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let result = execute_rotate_permanent_delegate(
+        &context.banks_client,
+        mint,
+        &mint_creator,
+        Some(&custodian),
+        next_custodian.pubkey(),
+    )
+    .await;
+    assert_transaction_success(result);
+    let config = permanent_delegate_config(context, mint).await;
+    assert_eq!(config.delegate, next_custodian.pubkey());
+
+    // The previous custodian lost the delegation
+    let result = transfer(
+        context,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &custodian,
+        100_000,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let result = transfer(
+        context,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &next_custodian,
+        100_000,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        token_amount(context, setup.source_account).await,
+        SUPPLY - 200_000
+    );
+}
+
+#[tokio::test]
+async fn test_should_reject_invalid_new_delegate() {
+    let source_owner = Keypair::new();
+    let context = &mut start_with_transfer_hook(&[&source_owner]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let setup = setup_mint(context, &source_owner).await;
+    let mint = setup.mint;
+    let (permanent_delegate_pda, _) = find_permanent_delegate_pda(&mint);
+
+    for new_delegate in [Pubkey::default(), permanent_delegate_pda] {
+        let result = execute_rotate_permanent_delegate(
+            &context.banks_client,
+            mint,
+            &mint_creator,
+            None,
+            new_delegate,
+        )
+        .await;
+        assert_transaction_failure(result);
+    }
+    let (permanent_delegate_config_pda, _) = find_permanent_delegate_config_pda(&mint);
+    assert_account_exists(context, permanent_delegate_config_pda, false).await;
+}
+
+#[tokio::test]
+async fn test_should_disable_permanent_delegate() {
+    let source_owner = Keypair::new();
+    let custodian = Keypair::new();
+    let context = &mut start_with_transfer_hook(&[&source_owner, &custodian]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let setup = setup_mint(context, &source_owner).await;
+    let mint = setup.mint;
+
+    let result = execute_rotate_permanent_delegate(
+        &context.banks_client,
+        mint,
+        &mint_creator,
+        None,
+        custodian.pubkey(),
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_disable_permanent_delegate(
+        &context.banks_client,
+        mint,
+        &mint_creator,
+        Some(&custodian),
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(mint_permanent_delegate(context, mint).await, None);
+    let config = permanent_delegate_config(context, mint).await;
+    assert_eq!(config.delegate, Pubkey::default());
+
+    let result = transfer(
+        context,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &custodian,
+        100_000,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    // Holders keep transferring through verification
+    let result = transfer(
+        context,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &source_owner,
+        100_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // The delegate cannot be brought back
+    let result = execute_rotate_permanent_delegate(
+        &context.banks_client,
+        mint,
+        &mint_creator,
+        None,
+        custodian.pubkey(),
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::PermanentDelegateDisabled);
+
+    let result =
+        execute_disable_permanent_delegate(&context.banks_client, mint, &mint_creator, None).await;
+    assert_security_token_error(result, SecurityTokenProgramError::PermanentDelegateDisabled);
+}
+
+#[tokio::test]
+async fn test_should_disable_program_permanent_delegate() {
+    let source_owner = Keypair::new();
+    let context = &mut start_with_transfer_hook(&[&source_owner]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let setup = setup_mint(context, &source_owner).await;
+    let mint = setup.mint;
+
+    let result =
+        execute_disable_permanent_delegate(&context.banks_client, mint, &mint_creator, None).await;
+    assert_transaction_success(result);
+    assert_eq!(mint_permanent_delegate(context, mint).await, None);
+    let config = permanent_delegate_config(context, mint).await;
+    assert_eq!(config.mint, mint);
+    assert_eq!(config.delegate, Pubkey::default());
+
+    // The transfer hook reads the renounced delegate from the extra account metas
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let account_metas_pda = get_extra_account_metas_address(&mint, &transfer_hook_program);
+    let account_metas = assert_account_exists(context, account_metas_pda, true)
+        .await
+        .unwrap();
+    let (permanent_delegate_config_pda, _) = find_permanent_delegate_config_pda(&mint);
+    assert!(account_metas
+        .data
+        .windows(32)
+        .any(|address| address == permanent_delegate_config_pda.as_ref()));
+
+    let result = transfer(
+        context,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &source_owner,
+        100_000,
+    )
+    .await;
+    assert_transaction_success(result);
+}
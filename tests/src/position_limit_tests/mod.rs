@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod position_limit_tests;
+
+pub mod position_limit_helpers;
@@ -0,0 +1,175 @@
+use security_token_client::{
+    instructions::{
+        ClosePositionLimit, CreatePositionLimit, CreatePositionLimitInstructionArgs,
+        UpdatePositionLimit, UpdatePositionLimitInstructionArgs, TRANSFER_DISCRIMINATOR,
+    },
+    pda::find_position_limit_pda,
+};
+use solana_program_test::{BanksClient, BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+use spl_transfer_hook_interface::{
+    get_extra_account_metas_address, offchain::add_extra_account_metas_for_execute,
+};
+
+use crate::helpers::{
+    find_mint_authority_pda, find_transfer_hook_pda, find_verification_config_pda, send_tx,
+};
+
+/// Build and send CreatePositionLimit instruction authorized by mint authority
+pub async fn execute_create_position_limit(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    max_ownership_bps: u16,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (position_limit_account, _) = find_position_limit_pda(&mint);
+    let (transfer_verification_config, _) =
+        find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+    let (transfer_hook_pda, _) = find_transfer_hook_pda(&mint);
+
+    let ix = CreatePositionLimit {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        position_limit_account,
+        transfer_verification_config,
+        system_program: solana_program::system_program::id(),
+        account_metas_pda: get_extra_account_metas_address(&mint, &transfer_hook_program),
+        transfer_hook_pda,
+        transfer_hook_program,
+    }
+    .instruction(CreatePositionLimitInstructionArgs { max_ownership_bps });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send UpdatePositionLimit instruction authorized by mint authority
+pub async fn execute_update_position_limit(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    max_ownership_bps: u16,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (position_limit_account, _) = find_position_limit_pda(&mint);
+
+    let ix = UpdatePositionLimit {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        mint_account: mint,
+        position_limit_account,
+    }
+    .instruction(UpdatePositionLimitInstructionArgs { max_ownership_bps });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send ClosePositionLimit instruction authorized by mint authority
+pub async fn execute_close_position_limit(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (position_limit_account, _) = find_position_limit_pda(&mint);
+    let (transfer_verification_config, _) =
+        find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+    let (transfer_hook_pda, _) = find_transfer_hook_pda(&mint);
+
+    let ix = ClosePositionLimit {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        position_limit_account,
+        transfer_verification_config,
+        rent_recipient: mint_creator.pubkey(),
+        system_program: solana_program::system_program::id(),
+        account_metas_pda: get_extra_account_metas_address(&mint, &transfer_hook_program),
+        transfer_hook_pda,
+        transfer_hook_program,
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Token-2022 transfer with extra accounts resolved from the transfer hook account metas
+pub async fn transfer(
+    context: &ProgramTestContext,
+    mint: Pubkey,
+    source_account: Pubkey,
+    destination_account: Pubkey,
+    owner: &Keypair,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let mut ix = spl_token_2022::instruction::transfer_checked(
+        &TOKEN_22_PROGRAM_ID,
+        &source_account,
+        &mint,
+        &destination_account,
+        &owner.pubkey(),
+        &[],
+        amount,
+        6,
+    )
+    .expect("SPL transfer ix");
+
+    let banks_client = context.banks_client.clone();
+    add_extra_account_metas_for_execute(
+        &mut ix,
+        &Pubkey::from(security_token_transfer_hook::id()),
+        &source_account,
+        &mint,
+        &destination_account,
+        &owner.pubkey(),
+        amount,
+        |address| {
+            let banks_client = banks_client.clone();
+            async move {
+                banks_client
+                    .get_account(address)
+                    .await
+                    .map(|account| Some(account.map(|account| account.data).unwrap_or_default()))
+                    .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+            }
+        },
+    )
+    .await
+    .expect("add extra metas");
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &context.payer.pubkey(),
+        vec![&context.payer, owner],
+    )
+    .await
+}
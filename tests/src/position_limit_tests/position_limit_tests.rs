@@ -0,0 +1,161 @@
+use security_token_client::{
+    accounts::PositionLimit, errors::SecurityTokenProgramError, identity::investor_id,
+    pda::find_position_limit_pda,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_spl_account, setup_transfer_verified_mint,
+        start_with_transfer_hook,
+    },
+    identity_tests::identity_helpers::{
+        execute_add_identity_wallet, execute_create_identity_account,
+    },
+    position_limit_tests::position_limit_helpers::{
+        execute_close_position_limit, execute_create_position_limit, execute_update_position_limit,
+        transfer,
+    },
+};
+
+const SUPPLY: u64 = 1_000_000;
+
+#[tokio::test]
+async fn test_should_limit_aggregate_investor_position() {
+    let context = &mut start_with_transfer_hook(&[]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let source_owner = Keypair::new();
+    let (mint_keypair, _, source_account) =
+        setup_transfer_verified_mint(context, &source_owner, SUPPLY).await;
+    let mint = mint_keypair.pubkey();
+
+    // Investor holds two token accounts linked to the same identity
+    let investor_wallet_1 = Keypair::new();
+    let investor_wallet_2 = Keypair::new();
+    let investor_account_1 = create_spl_account(context, &mint_keypair, &investor_wallet_1).await;
+    let investor_account_2 = create_spl_account(context, &mint_keypair, &investor_wallet_2).await;
+    let investor = investor_id("did:example:investor");
+    let result =
+        execute_create_identity_account(&context.banks_client, mint, investor, &mint_creator).await;
+    assert_transaction_success(result);
+    for token_account in [investor_account_1, investor_account_2] {
+        let result = execute_add_identity_wallet(
+            &context.banks_client,
+            mint,
+            investor,
+            token_account,
+            &mint_creator,
+        )
+        .await;
+        assert_transaction_success(result);
+    }
+
+    // 25% of the supply
+    let result =
+        execute_create_position_limit(&context.banks_client, mint, 2_500, &mint_creator).await;
+    assert_transaction_success(result);
+
+    let result = transfer(
+        context,
+        mint,
+        source_account,
+        investor_account_1,
+        &source_owner,
+        200_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // 200_000 + 100_000 across both wallets exceeds 25%
+    let result = transfer(
+        context,
+        mint,
+        source_account,
+        investor_account_2,
+        &source_owner,
+        100_000,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::PositionLimitExceeded);
+
+    let result = transfer(
+        context,
+        mint,
+        source_account,
+        investor_account_2,
+        &source_owner,
+        50_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // Raised to 50%
+    let result =
+        execute_update_position_limit(&context.banks_client, mint, 5_000, &mint_creator).await;
+    assert_transaction_success(result);
+
+    let result = transfer(
+        context,
+        mint,
+        source_account,
+        investor_account_2,
+        &source_owner,
+        100_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // Without a limit transfers to token accounts outside the registry are allowed again
+    let result = execute_close_position_limit(&context.banks_client, mint, &mint_creator).await;
+    assert_transaction_success(result);
+
+    let other_owner = Keypair::new();
+    let other_account = create_spl_account(context, &mint_keypair, &other_owner).await;
+    let result = transfer(
+        context,
+        mint,
+        source_account,
+        other_account,
+        &source_owner,
+        100_000,
+    )
+    .await;
+    assert_transaction_success(result);
+}
+
+#[tokio::test]
+async fn test_position_limit_lifecycle() {
+    let context = &mut start_with_transfer_hook(&[]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let source_owner = Keypair::new();
+    let (mint_keypair, _, _) = setup_transfer_verified_mint(context, &source_owner, SUPPLY).await;
+    let mint = mint_keypair.pubkey();
+
+    let result = execute_create_position_limit(&context.banks_client, mint, 0, &mint_creator).await;
+    assert_transaction_failure(result);
+
+    let result =
+        execute_create_position_limit(&context.banks_client, mint, 1_000, &mint_creator).await;
+    assert_transaction_success(result);
+
+    let (position_limit_pda, _) = find_position_limit_pda(&mint);
+    let account = assert_account_exists(context, position_limit_pda, true)
+        .await
+        .unwrap();
+    let position_limit = PositionLimit::from_bytes(&account.data).unwrap();
+    assert_eq!(position_limit.mint, mint);
+    assert_eq!(position_limit.max_ownership_bps, 1_000);
+
+    let result =
+        execute_update_position_limit(&context.banks_client, mint, 10_001, &mint_creator).await;
+    assert_transaction_failure(result);
+
+    let result =
+        execute_update_position_limit(&context.banks_client, mint, 10_000, &mint_creator).await;
+    assert_transaction_success(result);
+
+    let result = execute_close_position_limit(&context.banks_client, mint, &mint_creator).await;
+    assert_transaction_success(result);
+    assert_account_exists(context, position_limit_pda, false).await;
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod program_config_tests;
+
+pub mod program_config_helpers;
@@ -0,0 +1,101 @@
+use security_token_client::{
+    instructions::{
+        SetEmergencyAuthority, SetEmergencyAuthorityInstructionArgs, SetProgramHalt,
+        SetProgramHaltInstructionArgs,
+    },
+    pda::{find_program_config_pda, find_program_data_address, BPF_LOADER_UPGRADEABLE_ID},
+};
+use solana_program_test::{BanksClient, BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    account::Account,
+    signature::{Keypair, Signer},
+};
+
+use crate::helpers::{add_dummy_verification_program, initialize_program, send_tx};
+
+/// Start the program with a ProgramData account naming `upgrade_authority` and fund `signers`
+pub async fn start_with_upgrade_authority(
+    upgrade_authority: &Keypair,
+    signers: &[&Keypair],
+) -> ProgramTestContext {
+    let mut pt = initialize_program();
+
+    // UpgradeableLoaderState::ProgramData { slot, upgrade_authority_address }
+    let mut data = 3u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(upgrade_authority.pubkey().as_ref());
+    pt.add_account(
+        find_program_data_address().0,
+        Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: BPF_LOADER_UPGRADEABLE_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    for signer in std::iter::once(&upgrade_authority).chain(signers) {
+        pt.add_account(
+            signer.pubkey(),
+            Account {
+                lamports: 1_000_000_000,
+                data: vec![],
+                owner: solana_system_interface::program::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+    pt.prefer_bpf(false);
+    add_dummy_verification_program(&mut pt);
+    pt.start_with_context().await
+}
+
+/// Build and send SetEmergencyAuthority instruction signed by the upgrade authority
+pub async fn execute_set_emergency_authority(
+    banks_client: &BanksClient,
+    upgrade_authority: &Keypair,
+    emergency_authority: Pubkey,
+) -> Result<(), BanksClientError> {
+    let ix = SetEmergencyAuthority {
+        payer: upgrade_authority.pubkey(),
+        upgrade_authority: upgrade_authority.pubkey(),
+        program_data: find_program_data_address().0,
+        program_config_account: find_program_config_pda().0,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(SetEmergencyAuthorityInstructionArgs {
+        emergency_authority,
+    });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &upgrade_authority.pubkey(),
+        vec![upgrade_authority],
+    )
+    .await
+}
+
+/// Build and send SetProgramHalt instruction signed by the emergency authority
+pub async fn execute_set_program_halt(
+    banks_client: &BanksClient,
+    emergency_authority: &Keypair,
+    halted: bool,
+) -> Result<(), BanksClientError> {
+    let ix = SetProgramHalt {
+        emergency_authority: emergency_authority.pubkey(),
+        program_config_account: find_program_config_pda().0,
+    }
+    .instruction(SetProgramHaltInstructionArgs { halted });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &emergency_authority.pubkey(),
+        vec![emergency_authority],
+    )
+    .await
+}
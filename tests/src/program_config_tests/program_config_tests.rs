@@ -0,0 +1,224 @@
+use security_token_client::{
+    accounts::ProgramConfig,
+    errors::SecurityTokenProgramError,
+    instructions::{SetConfigAdmin, SetConfigAdminInstructionArgs},
+    pda::{find_config_admin_pda, find_program_config_pda},
+};
+use security_token_program::constants::PROGRAM_CONFIG_ADDRESS;
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, find_mint_authority_pda,
+        send_tx,
+    },
+    program_config_tests::program_config_helpers::{
+        execute_set_emergency_authority, execute_set_program_halt, start_with_upgrade_authority,
+    },
+};
+
+async fn program_config(context: &mut ProgramTestContext) -> ProgramConfig {
+    let account = assert_account_exists(context, find_program_config_pda().0, true)
+        .await
+        .unwrap();
+    ProgramConfig::from_bytes(&account.data).unwrap()
+}
+
+fn set_config_admin_instruction(mint: Pubkey, mint_creator: &Keypair) -> Instruction {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    SetConfigAdmin {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        config_admin_account: find_config_admin_pda(&mint).0,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(SetConfigAdminInstructionArgs {
+        config_admin: Pubkey::new_unique(),
+    })
+}
+
+/// SetConfigAdmin, a haltable instruction, with the ProgramConfig appended by send_tx
+async fn execute_haltable_instruction(
+    context: &ProgramTestContext,
+    mint: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    send_tx(
+        &context.banks_client,
+        vec![set_config_admin_instruction(mint, mint_creator)],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// SetConfigAdmin sent as built, without the ProgramConfig
+async fn execute_haltable_instruction_without_program_config(
+    context: &ProgramTestContext,
+    mint: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[set_config_admin_instruction(mint, mint_creator)],
+        Some(&mint_creator.pubkey()),
+        &[mint_creator],
+        recent_blockhash,
+    );
+    context.banks_client.process_transaction(transaction).await
+}
+
+#[test]
+fn test_program_config_address_matches_pda() {
+    assert_eq!(
+        Pubkey::from(PROGRAM_CONFIG_ADDRESS),
+        find_program_config_pda().0
+    );
+}
+
+#[tokio::test]
+async fn test_emergency_authority_should_halt_and_resume_program() {
+    let upgrade_authority = Keypair::new();
+    let emergency_authority = Keypair::new();
+    let context =
+        &mut start_with_upgrade_authority(&upgrade_authority, &[&emergency_authority]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+
+    let result = execute_set_emergency_authority(
+        &context.banks_client,
+        &upgrade_authority,
+        emergency_authority.pubkey(),
+    )
+    .await;
+    assert_transaction_success(result);
+    let config = program_config(context).await;
+    assert_eq!(config.emergency_authority, emergency_authority.pubkey());
+    assert!(!config.halted);
+
+    // Not halted: the trailing ProgramConfig is checked and stripped
+    let result = execute_haltable_instruction(context, mint, &mint_creator).await;
+    assert_transaction_success(result);
+
+    let result = execute_set_program_halt(&context.banks_client, &emergency_authority, true).await;
+    assert_transaction_success(result);
+    assert!(program_config(context).await.halted);
+
+    let result = execute_haltable_instruction(context, mint, &mint_creator).await;
+    assert_security_token_error(result, SecurityTokenProgramError::ProgramHalted);
+
+    let result = execute_set_program_halt(&context.banks_client, &emergency_authority, false).await;
+    assert_transaction_success(result);
+    assert!(!program_config(context).await.halted);
+
+    let result = execute_haltable_instruction(context, mint, &mint_creator).await;
+    assert_transaction_success(result);
+}
+
+#[tokio::test]
+async fn test_only_upgrade_authority_should_set_emergency_authority() {
+    let upgrade_authority = Keypair::new();
+    let context = &mut start_with_upgrade_authority(&upgrade_authority, &[]).await;
+    let other = context.payer.insecure_clone();
+
+    let result =
+        execute_set_emergency_authority(&context.banks_client, &other, other.pubkey()).await;
+    assert_transaction_failure(result);
+
+    let result = execute_set_program_halt(&context.banks_client, &other, true).await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_only_emergency_authority_should_halt_program() {
+    let upgrade_authority = Keypair::new();
+    let emergency_authority = Keypair::new();
+    let context = &mut start_with_upgrade_authority(&upgrade_authority, &[]).await;
+    let other = context.payer.insecure_clone();
+
+    let result = execute_set_emergency_authority(
+        &context.banks_client,
+        &upgrade_authority,
+        emergency_authority.pubkey(),
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_set_program_halt(&context.banks_client, &other, true).await;
+    assert_transaction_failure(result);
+    assert!(!program_config(context).await.halted);
+
+    // Replacing the emergency authority keeps the halt state
+    let result =
+        execute_set_emergency_authority(&context.banks_client, &upgrade_authority, other.pubkey())
+            .await;
+    assert_transaction_success(result);
+    let result = execute_set_program_halt(&context.banks_client, &other, true).await;
+    assert_transaction_success(result);
+    let result = execute_set_emergency_authority(
+        &context.banks_client,
+        &upgrade_authority,
+        emergency_authority.pubkey(),
+    )
+    .await;
+    assert_transaction_success(result);
+    let config = program_config(context).await;
+    assert_eq!(config.emergency_authority, emergency_authority.pubkey());
+    assert!(config.halted);
+}
+
+#[tokio::test]
+async fn test_haltable_instruction_should_require_program_config() {
+    let upgrade_authority = Keypair::new();
+    let emergency_authority = Keypair::new();
+    let context =
+        &mut start_with_upgrade_authority(&upgrade_authority, &[&emergency_authority]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+
+    // Required before the ProgramConfig exists
+    let result =
+        execute_haltable_instruction_without_program_config(context, mint, &mint_creator).await;
+    assert_security_token_error(result, SecurityTokenProgramError::ProgramConfigRequired);
+
+    let result = execute_set_emergency_authority(
+        &context.banks_client,
+        &upgrade_authority,
+        emergency_authority.pubkey(),
+    )
+    .await;
+    assert_transaction_success(result);
+    let result = execute_set_program_halt(&context.banks_client, &emergency_authority, true).await;
+    assert_transaction_success(result);
+
+    // Leaving the ProgramConfig out doesn't get around the halt
+    let result =
+        execute_haltable_instruction_without_program_config(context, mint, &mint_creator).await;
+    assert_security_token_error(result, SecurityTokenProgramError::ProgramConfigRequired);
+    let result = execute_haltable_instruction(context, mint, &mint_creator).await;
+    assert_security_token_error(result, SecurityTokenProgramError::ProgramHalted);
+
+    let result = execute_set_program_halt(&context.banks_client, &emergency_authority, false).await;
+    assert_transaction_success(result);
+    let result =
+        execute_haltable_instruction_without_program_config(context, mint, &mint_creator).await;
+    assert_security_token_error(result, SecurityTokenProgramError::ProgramConfigRequired);
+    let result = execute_haltable_instruction(context, mint, &mint_creator).await;
+    assert_transaction_success(result);
+}
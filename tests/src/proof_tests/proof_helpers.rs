@@ -4,7 +4,7 @@ use security_token_client::{
         UpdateProofAccountInstructionArgs, CREATE_PROOF_ACCOUNT_DISCRIMINATOR,
         UPDATE_PROOF_ACCOUNT_DISCRIMINATOR,
     },
-    programs::SECURITY_TOKEN_PROGRAM_ID,
+    pda,
     types::{CreateProofArgs, UpdateProofArgs},
 };
 use solana_program_test::{BanksClient, BanksClientError};
@@ -86,14 +86,7 @@ pub async fn execute_update_proof_account(
 }
 
 pub fn find_proof_pda(token_account: &Pubkey, action_id: u64) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[
-            b"proof",
-            token_account.as_ref(),
-            action_id.to_le_bytes().as_ref(),
-        ],
-        &SECURITY_TOKEN_PROGRAM_ID,
-    )
+    pda::find_proof_pda(token_account, action_id)
 }
 
 pub async fn create_create_proof_account_verification_config(
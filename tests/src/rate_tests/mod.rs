@@ -7,4 +7,7 @@ pub mod update_rate_account_tests;
 #[cfg(test)]
 pub mod close_rate_account_tests;
 
+#[cfg(test)]
+pub mod rate_oracle_tests;
+
 pub mod rate_helpers;
@@ -1,10 +1,15 @@
 use security_token_client::{
     instructions::{
         CloseRateAccount, CloseRateAccountInstructionArgs, CreateRateAccount,
-        CreateRateAccountInstructionArgs, UpdateRateAccount, UpdateRateAccountInstructionArgs,
+        CreateRateAccountInstructionArgs, RemoveRateOracle, RemoveRateOracleInstructionArgs,
+        SetRateOracle, SetRateOracleInstructionArgs, UpdateRateAccount,
+        UpdateRateAccountInstructionArgs,
     },
     programs::SECURITY_TOKEN_PROGRAM_ID,
-    types::{CloseRateArgs, CreateRateArgs, Rounding, UpdateRateArgs},
+    types::{
+        CloseRateArgs, CreateRateArgs, RemoveRateOracleArgs, Rounding, SetRateOracleArgs,
+        UpdateRateArgs,
+    },
 };
 use solana_program_test::*;
 use solana_pubkey::Pubkey;
@@ -78,7 +83,7 @@ pub async fn close_rate_account(
         rate_account: rate_pda,
         mint_from,
         mint_to,
-        destination: destination_pubkey,
+        rent_recipient: destination_pubkey,
     }
     .instruction(CloseRateAccountInstructionArgs { close_rate_args });
 
@@ -122,6 +127,74 @@ pub async fn update_rate_account(
     .await
 }
 
+pub async fn set_rate_oracle(
+    context: &mut solana_program_test::ProgramTestContext,
+    security_token_mint: Pubkey,
+    verification_config_or_mint_authority: Pubkey,
+    instructions_sysvar_or_creator: Pubkey,
+    mint_from: Pubkey,
+    mint_to: Pubkey,
+    oracle: Pubkey,
+    set_rate_oracle_args: SetRateOracleArgs,
+) -> Result<(), BanksClientError> {
+    let (rate_pda, _bump) = find_rate_pda(set_rate_oracle_args.action_id, &mint_from, &mint_to);
+
+    let set_rate_oracle_ix = SetRateOracle {
+        mint: security_token_mint,
+        verification_config_or_mint_authority,
+        instructions_sysvar_or_creator,
+        rate_account: rate_pda,
+        mint_from,
+        mint_to,
+        oracle,
+    }
+    .instruction(SetRateOracleInstructionArgs {
+        set_rate_oracle_args,
+    });
+
+    let payer = &context.payer;
+    send_tx(
+        &context.banks_client,
+        vec![set_rate_oracle_ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
+
+pub async fn remove_rate_oracle(
+    context: &mut solana_program_test::ProgramTestContext,
+    security_token_mint: Pubkey,
+    verification_config_or_mint_authority: Pubkey,
+    instructions_sysvar_or_creator: Pubkey,
+    mint_from: Pubkey,
+    mint_to: Pubkey,
+    remove_rate_oracle_args: RemoveRateOracleArgs,
+) -> Result<(), BanksClientError> {
+    let (rate_pda, _bump) = find_rate_pda(remove_rate_oracle_args.action_id, &mint_from, &mint_to);
+
+    let remove_rate_oracle_ix = RemoveRateOracle {
+        mint: security_token_mint,
+        verification_config_or_mint_authority,
+        instructions_sysvar_or_creator,
+        rate_account: rate_pda,
+        mint_from,
+        mint_to,
+    }
+    .instruction(RemoveRateOracleInstructionArgs {
+        remove_rate_oracle_args,
+    });
+
+    let payer = &context.payer;
+    send_tx(
+        &context.banks_client,
+        vec![remove_rate_oracle_ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
+
 pub fn calculate_rate_amount(
     numerator: u8,
     denominator: u8,
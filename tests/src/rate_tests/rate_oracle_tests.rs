@@ -0,0 +1,348 @@
+use borsh::BorshDeserialize;
+use security_token_client::{
+    accounts::Rate,
+    errors::SecurityTokenProgramError,
+    types::{CreateRateArgs, RateConfig, RemoveRateOracleArgs, Rounding, SetRateOracleArgs},
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use crate::{
+    convert_tests::convert_helpers::{build_creator_resources, execute_convert_with_oracle},
+    helpers::{
+        assert_security_token_error, assert_transaction_success, find_permanent_delegate_pda,
+        from_ui_amount, get_token_account_state, start_with_context,
+    },
+    nav_oracle_tests::nav_oracle_helpers::{set_oracle_value, ORACLE_VALUE_OFFSET},
+    rate_tests::rate_helpers::{create_rate_account, remove_rate_oracle, set_rate_oracle},
+    receipt_tests::receipt_helpers::find_common_action_receipt_pda,
+};
+
+const DECIMALS: u8 = 6;
+
+/// Price between 0.5 and 2.0 with 6 decimals
+fn set_rate_oracle_args(action_id: u64) -> SetRateOracleArgs {
+    SetRateOracleArgs {
+        action_id,
+        value_offset: ORACLE_VALUE_OFFSET,
+        value_decimals: 6,
+        min_value: 500_000,
+        max_value: 2_000_000,
+    }
+}
+
+async fn get_rate(context: &mut ProgramTestContext, rate_pda: Pubkey) -> Rate {
+    Rate::try_from_slice(
+        &context
+            .banks_client
+            .get_account(rate_pda)
+            .await
+            .unwrap()
+            .unwrap()
+            .data,
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_should_convert_at_oracle_price() {
+    let context = &mut start_with_context().await;
+    let mint_creator = context.payer.insecure_clone();
+    let creator = mint_creator.pubkey();
+
+    let (_, _, _, mint_from, _, _, _, token_account_from) =
+        build_creator_resources(context, &mint_creator, DECIMALS).await;
+    let (_, _, _, mint_to, mint_authority_to, convert_verification_config, _, token_account_to) =
+        build_creator_resources(context, &mint_creator, DECIMALS).await;
+
+    let action_id = 11u64;
+    let (rate_pda, result) = create_rate_account(
+        context,
+        mint_to,
+        mint_authority_to,
+        creator,
+        mint_from,
+        mint_to,
+        CreateRateArgs {
+            action_id,
+            rate: RateConfig {
+                rounding: Rounding::Down as u8,
+                numerator: 1,
+                denominator: 1,
+            },
+        },
+        None,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let oracle = Pubkey::new_unique();
+    set_oracle_value(context, &oracle, 1_500_000);
+    let result = set_rate_oracle(
+        context,
+        mint_to,
+        mint_authority_to,
+        creator,
+        mint_from,
+        mint_to,
+        oracle,
+        set_rate_oracle_args(action_id),
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let rate = get_rate(context, rate_pda).await;
+    assert_eq!(rate.oracle, oracle);
+    assert_eq!(rate.oracle_value_offset, ORACLE_VALUE_OFFSET);
+    assert_eq!(rate.oracle_value_decimals, 6);
+    assert_eq!(rate.oracle_min_value, 500_000);
+    assert_eq!(rate.oracle_max_value, 2_000_000);
+
+    let (permanent_delegate_from, _) = find_permanent_delegate_pda(&mint_from);
+    let (receipt_pda, _) = find_common_action_receipt_pda(&mint_to, action_id);
+    let amount_to_convert = from_ui_amount(100, DECIMALS);
+    let initial_amount_to = get_token_account_state(&mut context.banks_client, token_account_to)
+        .await
+        .base
+        .amount;
+
+    let banks_client = context.banks_client.clone();
+    let convert = |oracle: Option<Pubkey>| {
+        let banks_client = banks_client.clone();
+        let payer = mint_creator.insecure_clone();
+        async move {
+            execute_convert_with_oracle(
+                &banks_client,
+                convert_verification_config,
+                mint_from,
+                mint_to,
+                token_account_from,
+                token_account_to,
+                mint_authority_to,
+                permanent_delegate_from,
+                rate_pda,
+                receipt_pda,
+                &payer,
+                action_id,
+                amount_to_convert,
+                oracle,
+            )
+            .await
+        }
+    };
+
+    // Oracle priced rate can't fall back to the static ratio
+    assert!(convert(None).await.is_err());
+    assert!(convert(Some(Pubkey::new_unique())).await.is_err());
+
+    set_oracle_value(context, &oracle, 2_000_001);
+    assert_security_token_error(
+        convert(Some(oracle)).await,
+        SecurityTokenProgramError::OracleValueOutOfBounds,
+    );
+
+    set_oracle_value(context, &oracle, 1_500_000);
+    context.get_new_latest_blockhash().await.unwrap();
+    assert_transaction_success(convert(Some(oracle)).await);
+
+    let amount_to = get_token_account_state(&mut context.banks_client, token_account_to)
+        .await
+        .base
+        .amount;
+    assert_eq!(amount_to - initial_amount_to, from_ui_amount(150, DECIMALS));
+}
+
+#[tokio::test]
+async fn test_should_remove_rate_oracle() {
+    let context = &mut start_with_context().await;
+    let mint_creator = context.payer.insecure_clone();
+    let creator = mint_creator.pubkey();
+
+    let (_, _, _, mint_from, _, _, _, token_account_from) =
+        build_creator_resources(context, &mint_creator, DECIMALS).await;
+    let (_, _, _, mint_to, mint_authority_to, convert_verification_config, _, token_account_to) =
+        build_creator_resources(context, &mint_creator, DECIMALS).await;
+
+    let action_id = 12u64;
+    let (rate_pda, result) = create_rate_account(
+        context,
+        mint_to,
+        mint_authority_to,
+        creator,
+        mint_from,
+        mint_to,
+        CreateRateArgs {
+            action_id,
+            rate: RateConfig {
+                rounding: Rounding::Down as u8,
+                numerator: 2,
+                denominator: 1,
+            },
+        },
+        None,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let oracle = Pubkey::new_unique();
+    set_oracle_value(context, &oracle, 1_500_000);
+    let result = set_rate_oracle(
+        context,
+        mint_to,
+        mint_authority_to,
+        creator,
+        mint_from,
+        mint_to,
+        oracle,
+        set_rate_oracle_args(action_id),
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = remove_rate_oracle(
+        context,
+        mint_to,
+        mint_authority_to,
+        creator,
+        mint_from,
+        mint_to,
+        RemoveRateOracleArgs { action_id },
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(get_rate(context, rate_pda).await.oracle, Pubkey::default());
+
+    // Static 2/1 ratio applies again
+    let (permanent_delegate_from, _) = find_permanent_delegate_pda(&mint_from);
+    let (receipt_pda, _) = find_common_action_receipt_pda(&mint_to, action_id);
+    let initial_amount_to = get_token_account_state(&mut context.banks_client, token_account_to)
+        .await
+        .base
+        .amount;
+    let result = execute_convert_with_oracle(
+        &context.banks_client,
+        convert_verification_config,
+        mint_from,
+        mint_to,
+        token_account_from,
+        token_account_to,
+        mint_authority_to,
+        permanent_delegate_from,
+        rate_pda,
+        receipt_pda,
+        &mint_creator,
+        action_id,
+        from_ui_amount(100, DECIMALS),
+        None,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let amount_to = get_token_account_state(&mut context.banks_client, token_account_to)
+        .await
+        .base
+        .amount;
+    assert_eq!(amount_to - initial_amount_to, from_ui_amount(200, DECIMALS));
+}
+
+#[tokio::test]
+async fn test_set_rate_oracle_validation() {
+    let context = &mut start_with_context().await;
+    let mint_creator = context.payer.insecure_clone();
+    let creator = mint_creator.pubkey();
+
+    let (_, _, _, mint_from, _, _, _, _) =
+        build_creator_resources(context, &mint_creator, DECIMALS).await;
+    let (_, _, _, mint_to, mint_authority_to, _, _, _) =
+        build_creator_resources(context, &mint_creator, DECIMALS).await;
+
+    let rate_config = RateConfig {
+        rounding: Rounding::Down as u8,
+        numerator: 1,
+        denominator: 1,
+    };
+    let conversion_action_id = 13u64;
+    let (_, result) = create_rate_account(
+        context,
+        mint_to,
+        mint_authority_to,
+        creator,
+        mint_from,
+        mint_to,
+        CreateRateArgs {
+            action_id: conversion_action_id,
+            rate: rate_config.clone(),
+        },
+        None,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let split_action_id = 14u64;
+    let (_, result) = create_rate_account(
+        context,
+        mint_to,
+        mint_authority_to,
+        creator,
+        mint_to,
+        mint_to,
+        CreateRateArgs {
+            action_id: split_action_id,
+            rate: rate_config,
+        },
+        None,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let oracle = Pubkey::new_unique();
+
+    // Split rates keep their static ratio
+    let result = set_rate_oracle(
+        context,
+        mint_to,
+        mint_authority_to,
+        creator,
+        mint_to,
+        mint_to,
+        oracle,
+        set_rate_oracle_args(split_action_id),
+    )
+    .await;
+    assert!(result.is_err(), "Split rate should not accept an oracle");
+
+    // Default pubkey can't be an oracle
+    let result = set_rate_oracle(
+        context,
+        mint_to,
+        mint_authority_to,
+        creator,
+        mint_from,
+        mint_to,
+        Pubkey::default(),
+        set_rate_oracle_args(conversion_action_id),
+    )
+    .await;
+    assert!(result.is_err(), "Default oracle should be invalid");
+
+    // Bounds must be ordered
+    let result = set_rate_oracle(
+        context,
+        mint_to,
+        mint_authority_to,
+        creator,
+        mint_from,
+        mint_to,
+        oracle,
+        SetRateOracleArgs {
+            min_value: 2_000_001,
+            ..set_rate_oracle_args(conversion_action_id)
+        },
+    )
+    .await;
+    assert!(
+        result.is_err(),
+        "Min value above max value should be invalid"
+    );
+}
@@ -131,6 +131,7 @@ async fn test_should_close_claim_receipt_proof_argument() {
         CloseClaimReceiptArgs {
             action_id,
             merkle_proof: Some(merkle_proof.clone()),
+            merkle_root: None,
         },
     )
     .await;
@@ -163,6 +164,7 @@ async fn test_should_close_claim_receipt_proof_argument() {
         CloseClaimReceiptArgs {
             action_id,
             merkle_proof: Some(merkle_proof.clone()),
+            merkle_root: None,
         },
     )
     .await;
@@ -311,6 +313,7 @@ async fn test_should_close_claim_receipt_proof_account() {
         CloseClaimReceiptArgs {
             action_id,
             merkle_proof: None,
+            merkle_root: None,
         },
     )
     .await;
@@ -342,6 +345,7 @@ async fn test_should_close_claim_receipt_proof_account() {
         CloseClaimReceiptArgs {
             action_id,
             merkle_proof: None,
+            merkle_root: None,
         },
     )
     .await;
@@ -459,6 +463,7 @@ async fn test_should_not_close_not_owned_receipt_account() {
         CloseClaimReceiptArgs {
             action_id,
             merkle_proof: Some(merkle_proof0.clone()),
+            merkle_root: None,
         },
     )
     .await;
@@ -480,6 +485,7 @@ async fn test_should_not_close_not_owned_receipt_account() {
         CloseClaimReceiptArgs {
             action_id,
             merkle_proof: Some(merkle_proof1.clone()),
+            merkle_root: None,
         },
     )
     .await;
@@ -501,6 +507,7 @@ async fn test_should_not_close_not_owned_receipt_account() {
         CloseClaimReceiptArgs {
             action_id,
             merkle_proof: Some(merkle_proof0.clone()),
+            merkle_root: None,
         },
     )
     .await;
@@ -523,6 +530,7 @@ async fn test_should_not_close_not_owned_receipt_account() {
         CloseClaimReceiptArgs {
             action_id,
             merkle_proof: Some(merkle_proof0.clone()),
+            merkle_root: None,
         },
     )
     .await;
@@ -540,6 +548,7 @@ async fn test_should_not_close_not_owned_receipt_account() {
         CloseClaimReceiptArgs {
             action_id,
             merkle_proof: Some(merkle_proof1.clone()),
+            merkle_root: None,
         },
     )
     .await;
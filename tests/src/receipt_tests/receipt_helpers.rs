@@ -3,10 +3,9 @@ use security_token_client::{
         CloseActionReceiptAccount, CloseActionReceiptAccountInstructionArgs,
         CloseClaimReceiptAccount, CloseClaimReceiptAccountInstructionArgs,
     },
-    programs::SECURITY_TOKEN_PROGRAM_ID,
+    pda,
     types::{CloseActionReceiptArgs, CloseClaimReceiptArgs},
 };
-use solana_keccak_hasher::hashv;
 use solana_program_test::*;
 use solana_pubkey::Pubkey;
 use solana_sdk::signature::{Keypair, Signer};
@@ -29,7 +28,7 @@ pub async fn close_action_receipt_account(
         instructions_sysvar_or_creator,
         receipt_account,
         mint_account,
-        destination: destination.pubkey(),
+        rent_recipient: destination.pubkey(),
     }
     .instruction(CloseActionReceiptAccountInstructionArgs {
         close_action_receipt_args,
@@ -64,7 +63,7 @@ pub async fn close_claim_receipt_account(
         mint_account,
         eligible_token_account,
         proof_account,
-        destination: destination.pubkey(),
+        rent_recipient: destination.pubkey(),
     }
     .instruction(CloseClaimReceiptAccountInstructionArgs {
         close_claim_receipt_args,
@@ -80,10 +79,7 @@ pub async fn close_claim_receipt_account(
 }
 
 pub fn find_common_action_receipt_pda(mint: &Pubkey, action_id: u64) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[b"receipt", &mint.as_ref(), &action_id.to_le_bytes()],
-        &SECURITY_TOKEN_PROGRAM_ID,
-    )
+    pda::find_receipt_pda(mint, action_id)
 }
 
 pub fn find_claim_action_receipt_pda(
@@ -92,20 +88,5 @@ pub fn find_claim_action_receipt_pda(
     action_id: u64,
     proof: &Vec<[u8; 32]>,
 ) -> (Pubkey, u8) {
-    let proof_data = proof
-        .iter()
-        .flat_map(|proof_node| *proof_node)
-        .collect::<Vec<u8>>();
-    let proof_hash = hashv(&[&proof_data]).to_bytes();
-
-    Pubkey::find_program_address(
-        &[
-            b"receipt",
-            mint.as_ref(),
-            token_account.as_ref(),
-            action_id.to_le_bytes().as_ref(),
-            proof_hash.as_ref(),
-        ],
-        &SECURITY_TOKEN_PROGRAM_ID,
-    )
+    pda::find_claim_receipt_pda(mint, token_account, action_id, proof)
 }
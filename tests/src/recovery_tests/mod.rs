@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod recovery_tests;
+
+pub mod recovery_helpers;
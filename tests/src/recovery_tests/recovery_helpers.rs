@@ -0,0 +1,54 @@
+use security_token_client::{
+    instructions::{RecoverTokens, RecoverTokensInstructionArgs},
+    pda::{find_recovery_receipt_pda, find_suspension_pda},
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::{signature::Keypair, signer::Signer, sysvar};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{
+    create_dummy_verification_from_instruction, find_permanent_delegate_pda, send_tx,
+};
+
+/// Build and send RecoverTokens sweeping `source_token_account` to `recovery_token_account`
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_recover_tokens(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    verification_config: Pubkey,
+    source_token_account: Pubkey,
+    source_owner: Pubkey,
+    recovery_token_account: Pubkey,
+    recovery_id: u64,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let ix = RecoverTokens {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        // ix accounts
+        payer: payer.pubkey(),
+        permanent_delegate_authority: find_permanent_delegate_pda(&mint).0,
+        mint_account: mint,
+        source_token_account,
+        source_owner,
+        recovery_token_account,
+        suspension_account: find_suspension_pda(&source_token_account).0,
+        recovery_receipt_account: find_recovery_receipt_pda(&mint, recovery_id).0,
+        transfer_hook_program: Pubkey::from(security_token_transfer_hook::id()),
+        token_program: TOKEN_22_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(RecoverTokensInstructionArgs { recovery_id });
+
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
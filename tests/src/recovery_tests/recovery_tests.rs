@@ -0,0 +1,216 @@
+use security_token_client::{
+    accounts::RecoveryReceipt,
+    agent::SUSPEND,
+    errors::SecurityTokenProgramError,
+    instructions::{RECOVER_TOKENS_DISCRIMINATOR, TRANSFER_DISCRIMINATOR},
+    pda::find_recovery_receipt_pda,
+    types::RecoveryReason,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    agent_tests::agent_helpers::execute_create_agent_account,
+    claim_tests::claim_helpers::start_with_context_and_transfer_hook,
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_success,
+        create_minimal_security_token_mint, create_spl_account, create_verification_config,
+        get_default_verification_programs, get_token_account_state,
+        initialize_mint_verification_and_mint_to_account, send_tx,
+    },
+    position_limit_tests::position_limit_helpers::transfer,
+    recovery_tests::recovery_helpers::execute_recover_tokens,
+    suspension_tests::suspension_helpers::execute_suspend_by_agent,
+};
+
+const SUPPLY: u64 = 1_000_000;
+const MISDELIVERED: u64 = 2_500;
+
+struct Setup {
+    mint_keypair: Keypair,
+    holder: Keypair,
+    holder_account: Pubkey,
+    recovery_account: Pubkey,
+    verification_config: Pubkey,
+}
+
+/// Mint the whole supply to a holder and open the issuer recovery account
+async fn setup_mint(context: &mut ProgramTestContext) -> Setup {
+    let mint_keypair = Keypair::new();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, None, 6).await;
+    let mut verification_configs = vec![];
+    for discriminator in [TRANSFER_DISCRIMINATOR, RECOVER_TOKENS_DISCRIMINATOR] {
+        let verification_config = create_verification_config(
+            context,
+            &mint_keypair,
+            mint_authority_pda,
+            discriminator,
+            get_default_verification_programs(),
+            None,
+        )
+        .await;
+        verification_configs.push(verification_config);
+    }
+    let holder = Keypair::new();
+    let holder_account = create_spl_account(context, &mint_keypair, &holder).await;
+    let recovery_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+    initialize_mint_verification_and_mint_to_account(
+        &mint_keypair,
+        context,
+        mint_authority_pda,
+        holder_account,
+        SUPPLY,
+    )
+    .await;
+    Setup {
+        mint_keypair,
+        holder,
+        holder_account,
+        recovery_account,
+        verification_config: verification_configs[1],
+    }
+}
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    get_token_account_state(&mut context.banks_client, token_account)
+        .await
+        .base
+        .amount
+}
+
+async fn recovery_receipt(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    recovery_id: u64,
+) -> RecoveryReceipt {
+    let (recovery_receipt_pda, _) = find_recovery_receipt_pda(&mint, recovery_id);
+    let account = assert_account_exists(context, recovery_receipt_pda, true)
+        .await
+        .unwrap();
+    RecoveryReceipt::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn test_should_recover_tokens_sent_to_mint_owned_account() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let mint = setup.mint_keypair.pubkey();
+    let payer = context.payer.insecure_clone();
+
+    // Holder sends tokens to the ATA of the mint address instead of a wallet
+    let misdelivered_account =
+        create_spl_account(context, &setup.mint_keypair, &setup.mint_keypair).await;
+    let result = transfer(
+        context,
+        mint,
+        setup.holder_account,
+        misdelivered_account,
+        &setup.holder,
+        MISDELIVERED,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_recover_tokens(
+        &context.banks_client,
+        mint,
+        setup.verification_config,
+        misdelivered_account,
+        mint,
+        setup.recovery_account,
+        1,
+        &payer,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    assert_eq!(token_amount(context, misdelivered_account).await, 0);
+    assert_eq!(
+        token_amount(context, setup.recovery_account).await,
+        MISDELIVERED
+    );
+
+    let receipt = recovery_receipt(context, mint, 1).await;
+    assert_eq!(receipt.mint, mint);
+    assert_eq!(receipt.source_token_account, misdelivered_account);
+    assert_eq!(receipt.source_owner, mint);
+    assert_eq!(receipt.recovery_token_account, setup.recovery_account);
+    assert_eq!(receipt.amount, MISDELIVERED);
+    assert_eq!(receipt.reason, RecoveryReason::ProgramOwner);
+    assert_eq!(receipt.recovery_id, 1);
+}
+
+#[tokio::test]
+async fn test_should_not_recover_tokens_from_wallet_owned_account() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let mint = setup.mint_keypair.pubkey();
+    let payer = context.payer.insecure_clone();
+
+    let result = execute_recover_tokens(
+        &context.banks_client,
+        mint,
+        setup.verification_config,
+        setup.holder_account,
+        setup.holder.pubkey(),
+        setup.recovery_account,
+        1,
+        &payer,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::AccountNotRecoverable);
+
+    assert_eq!(token_amount(context, setup.holder_account).await, SUPPLY);
+    let (recovery_receipt_pda, _) = find_recovery_receipt_pda(&mint, 1);
+    assert_account_exists(context, recovery_receipt_pda, false).await;
+}
+
+#[tokio::test]
+async fn test_should_recover_tokens_from_suspended_account() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let mint = setup.mint_keypair.pubkey();
+    let payer = context.payer.insecure_clone();
+
+    let agent = Keypair::new();
+    let fund_agent_ix =
+        solana_sdk::system_instruction::transfer(&payer.pubkey(), &agent.pubkey(), 1_000_000_000);
+    let result = send_tx(
+        &context.banks_client,
+        vec![fund_agent_ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await;
+    assert_transaction_success(result);
+    let result =
+        execute_create_agent_account(&context.banks_client, mint, agent.pubkey(), SUSPEND, &payer)
+            .await;
+    assert_transaction_success(result);
+    let result =
+        execute_suspend_by_agent(&context.banks_client, mint, setup.holder_account, &agent).await;
+    assert_transaction_success(result);
+
+    let result = execute_recover_tokens(
+        &context.banks_client,
+        mint,
+        setup.verification_config,
+        setup.holder_account,
+        setup.holder.pubkey(),
+        setup.recovery_account,
+        7,
+        &payer,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    assert_eq!(token_amount(context, setup.holder_account).await, 0);
+    assert_eq!(token_amount(context, setup.recovery_account).await, SUPPLY);
+
+    let receipt = recovery_receipt(context, mint, 7).await;
+    assert_eq!(receipt.source_owner, setup.holder.pubkey());
+    assert_eq!(receipt.amount, SUPPLY);
+    assert_eq!(receipt.reason, RecoveryReason::Suspended);
+}
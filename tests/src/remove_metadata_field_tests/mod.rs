@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod remove_metadata_field_tests;
+
+pub mod remove_metadata_field_helpers;
@@ -0,0 +1,38 @@
+use security_token_client::{
+    instructions::RemoveMetadataFieldBuilder, pda::find_metadata_schema_pda,
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use crate::helpers::{find_mint_authority_pda, send_tx};
+
+/// Remove the additional metadata field `key` of `mint` through the mint authority of the payer,
+/// passing its schema PDA
+pub async fn remove_metadata_field(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    key: &str,
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let mut ix = RemoveMetadataFieldBuilder::new()
+        .mint(mint)
+        .verification_config_or_mint_authority(mint_authority_pda)
+        .instructions_sysvar_or_creator(payer.pubkey())
+        .mint_authority(mint_authority_pda)
+        .mint_account(mint)
+        .metadata_schema_account(Some(find_metadata_schema_pda(&mint).0))
+        .key(key.to_string())
+        .instruction();
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
@@ -0,0 +1,92 @@
+use security_token_client::{
+    errors::SecurityTokenProgramError, metadata_schema::MetadataSchemaField,
+    types::MetadataFieldFormat,
+};
+use solana_sdk::signature::Signer;
+
+use crate::{
+    helpers::{
+        assert_security_token_error, assert_transaction_failure, assert_transaction_success,
+        start_with_context,
+    },
+    metadata_freeze_tests::metadata_freeze_helpers::freeze_metadata,
+    metadata_schema_tests::metadata_schema_helpers::{
+        create_metadata_schema, create_mint_with_metadata, update_metadata_with_schema,
+    },
+    remove_metadata_field_tests::remove_metadata_field_helpers::remove_metadata_field,
+    translation_tests::translation_helpers::get_additional_metadata_value,
+};
+
+const ISIN: &str = "US0378331005";
+
+#[tokio::test]
+async fn test_should_remove_metadata_field() {
+    let context = &mut start_with_context().await;
+    let mint_keypair = create_mint_with_metadata(context).await;
+    let mint = mint_keypair.pubkey();
+
+    let result =
+        update_metadata_with_schema(context, &mint_keypair, &[("type", "bond"), ("isin", ISIN)])
+            .await;
+    assert_transaction_success(result);
+
+    let result = remove_metadata_field(context, mint, "type").await;
+    assert_transaction_success(result);
+
+    assert_eq!(
+        get_additional_metadata_value(context, mint, "type").await,
+        None
+    );
+    assert_eq!(
+        get_additional_metadata_value(context, mint, "isin").await,
+        Some(ISIN.to_string())
+    );
+
+    // Removing a field the metadata does not have fails
+    let result = remove_metadata_field(context, mint, "type").await;
+    assert_transaction_failure(result);
+
+    let result = remove_metadata_field(context, mint, "").await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_not_remove_field_required_by_schema() {
+    let context = &mut start_with_context().await;
+    let mint_keypair = create_mint_with_metadata(context).await;
+    let mint = mint_keypair.pubkey();
+
+    let fields = vec![MetadataSchemaField::new("isin", MetadataFieldFormat::Isin)];
+    let result = create_metadata_schema(context, mint, &fields).await;
+    assert_transaction_success(result);
+    let result =
+        update_metadata_with_schema(context, &mint_keypair, &[("type", "bond"), ("isin", ISIN)])
+            .await;
+    assert_transaction_success(result);
+
+    let result = remove_metadata_field(context, mint, "isin").await;
+    assert_security_token_error(result, SecurityTokenProgramError::MissingMetadataField);
+
+    let result = remove_metadata_field(context, mint, "type").await;
+    assert_transaction_success(result);
+    assert_eq!(
+        get_additional_metadata_value(context, mint, "isin").await,
+        Some(ISIN.to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_should_not_remove_field_of_frozen_metadata() {
+    let context = &mut start_with_context().await;
+    let mint = create_mint_with_metadata(context).await.pubkey();
+
+    let result = freeze_metadata(context, mint).await;
+    assert_transaction_success(result);
+
+    let result = remove_metadata_field(context, mint, "type").await;
+    assert_security_token_error(result, SecurityTokenProgramError::MetadataFrozen);
+    assert_eq!(
+        get_additional_metadata_value(context, mint, "type").await,
+        Some("security".to_string())
+    );
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod reserve_tests;
+
+pub mod reserve_helpers;
@@ -0,0 +1,25 @@
+use security_token_client::{
+    instructions::PublishReserveAttestationBuilder, pda::find_reserve_attestation_pda,
+};
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+
+/// PublishReserveAttestation instruction of `custodian` over `omnibus_accounts`
+pub fn publish_reserve_attestation_ix(
+    mint: Pubkey,
+    custodian: Pubkey,
+    liability_hash: [u8; 32],
+    omnibus_accounts: &[Pubkey],
+) -> Instruction {
+    let remaining_accounts: Vec<AccountMeta> = omnibus_accounts
+        .iter()
+        .map(|account| AccountMeta::new_readonly(*account, false))
+        .collect();
+    PublishReserveAttestationBuilder::new()
+        .custodian(custodian)
+        .mint(mint)
+        .reserve_attestation_account(find_reserve_attestation_pda(&mint, &custodian).0)
+        .liability_hash(liability_hash)
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
@@ -0,0 +1,86 @@
+use security_token_client::{
+    accounts::ReserveAttestation,
+    pda::find_reserve_attestation_pda,
+    reserve::{verify_reserve_attestation, Liability, LiabilityTree, ReserveDiscrepancy},
+};
+use solana_sdk::signature::Signer;
+
+use crate::{
+    fixtures::fixture_helpers::{Fixture, HOLDER_AMOUNT},
+    helpers::{assert_transaction_failure, assert_transaction_success, get_account, send_tx},
+    reserve_tests::reserve_helpers::publish_reserve_attestation_ix,
+};
+
+#[tokio::test]
+async fn test_should_publish_and_verify_reserve_attestation() {
+    let mut fixture = Fixture::new(21).await;
+    let mint = fixture.mint.pubkey();
+    let custodian = fixture.holders[0].owner.insecure_clone();
+    let omnibus = fixture.holders[0].token_account;
+
+    let liabilities = LiabilityTree::new(
+        mint,
+        vec![
+            Liability::new([1; 32], HOLDER_AMOUNT / 2),
+            Liability::new([2; 32], HOLDER_AMOUNT / 4),
+        ],
+    );
+    let ix =
+        publish_reserve_attestation_ix(mint, custodian.pubkey(), liabilities.root(), &[omnibus]);
+    let result = send_tx(
+        &fixture.context.banks_client,
+        vec![ix],
+        &custodian.pubkey(),
+        vec![&custodian],
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let account = get_account(
+        &mut fixture.context,
+        find_reserve_attestation_pda(&mint, &custodian.pubkey()).0,
+    )
+    .await
+    .expect("ReserveAttestation should exist");
+    let attestation = ReserveAttestation::from_bytes(&account.data).unwrap();
+    assert_eq!(attestation.custodian, custodian.pubkey());
+    assert_eq!(attestation.token_accounts, vec![omnibus]);
+    assert_eq!(attestation.balances, vec![HOLDER_AMOUNT]);
+    assert_eq!(attestation.total_balance, HOLDER_AMOUNT);
+
+    let report =
+        verify_reserve_attestation(&attestation, &[Some(HOLDER_AMOUNT)], Some(&liabilities));
+    assert!(report.is_verified());
+
+    let report = verify_reserve_attestation(&attestation, &[Some(HOLDER_AMOUNT - 1)], None);
+    assert_eq!(
+        report.discrepancies,
+        vec![ReserveDiscrepancy::BalanceChanged {
+            token_account: omnibus,
+            attested: HOLDER_AMOUNT,
+            current: HOLDER_AMOUNT - 1,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_should_reject_reserve_accounts_of_another_owner() {
+    let fixture = Fixture::new(22).await;
+    let mint = fixture.mint.pubkey();
+    let custodian = fixture.holders[0].owner.insecure_clone();
+    let own = fixture.holders[0].token_account;
+    let other = fixture.holders[1].token_account;
+
+    for omnibus_accounts in [vec![own, other], vec![own, own], vec![]] {
+        let ix =
+            publish_reserve_attestation_ix(mint, custodian.pubkey(), [7; 32], &omnibus_accounts);
+        let result = send_tx(
+            &fixture.context.banks_client,
+            vec![ix],
+            &custodian.pubkey(),
+            vec![&custodian],
+        )
+        .await;
+        assert_transaction_failure(result);
+    }
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod scaled_ui_amount_tests;
+
+pub mod scaled_ui_amount_helpers;
@@ -0,0 +1,81 @@
+use security_token_client::{
+    instructions::UpdateScaledUiAmountMultiplierBuilder,
+    types::{InitializeMintArgs, MintArgs, ScaledUiAmountConfigArgs},
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::helpers::{
+    find_mint_authority_pda, find_mint_freeze_authority_pda, initialize_mint_for_creator, send_tx,
+};
+
+/// Create a security token mint whose ScaledUiAmount authority is its mint authority PDA
+pub async fn create_issuer_scaled_security_token_mint(
+    context: &mut ProgramTestContext,
+    mint_keypair: &Keypair,
+    mint_creator: &Keypair,
+) -> Pubkey {
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (freeze_authority_pda, _) = find_mint_freeze_authority_pda(&mint);
+
+    let mint_args = InitializeMintArgs {
+        ix_mint: MintArgs {
+            decimals: 6,
+            mint_authority: mint_creator.pubkey(),
+            freeze_authority: freeze_authority_pda,
+        },
+        ix_metadata_pointer: None,
+        ix_metadata: None,
+        ix_scaled_ui_amount: Some(ScaledUiAmountConfigArgs {
+            authority: mint_authority_pda,
+            multiplier: 1f64.to_le_bytes(),
+            new_multiplier_effective_timestamp: 0,
+            new_multiplier: 1f64.to_le_bytes(),
+        }),
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
+    };
+
+    initialize_mint_for_creator(
+        context,
+        mint_keypair,
+        mint_authority_pda,
+        mint_creator,
+        &mint_args,
+    )
+    .await;
+
+    mint_authority_pda
+}
+
+/// Update the ScaledUiAmount multiplier of `mint` signed by its creator
+pub async fn execute_update_scaled_ui_amount_multiplier(
+    context: &ProgramTestContext,
+    mint: Pubkey,
+    mint_creator: &Keypair,
+    multiplier: f64,
+    effective_timestamp: i64,
+) -> Result<(), BanksClientError> {
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+
+    let mut ix = UpdateScaledUiAmountMultiplierBuilder::new()
+        .mint(mint)
+        .verification_config_or_mint_authority(mint_authority_pda)
+        .instructions_sysvar_or_creator(mint_creator.pubkey())
+        .mint_authority(mint_authority_pda)
+        .mint_account(mint)
+        .multiplier(multiplier.to_le_bytes())
+        .effective_timestamp(effective_timestamp)
+        .instruction();
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
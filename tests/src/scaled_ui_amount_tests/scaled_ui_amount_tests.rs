@@ -0,0 +1,100 @@
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::extension::{scaled_ui_amount::ScaledUiAmountConfig, BaseStateWithExtensions};
+
+use crate::{
+    coupon_tests::coupon_helpers::current_timestamp,
+    helpers::{
+        assert_instruction_error, assert_transaction_failure, assert_transaction_success,
+        get_mint_state, start_with_context_and_accounts,
+    },
+    nav_oracle_tests::nav_oracle_helpers::create_scaled_security_token_mint,
+    scaled_ui_amount_tests::scaled_ui_amount_helpers::{
+        create_issuer_scaled_security_token_mint, execute_update_scaled_ui_amount_multiplier,
+    },
+};
+
+/// Current multiplier, next multiplier and its effective timestamp
+async fn scaled_ui_amount(context: &mut ProgramTestContext, mint: Pubkey) -> (f64, f64, i64) {
+    let mint_state = get_mint_state(&mut context.banks_client, mint).await;
+    let config = mint_state
+        .get_extension::<ScaledUiAmountConfig>()
+        .expect("ScaledUiAmount extension should be accessible");
+    (
+        f64::from(config.multiplier),
+        f64::from(config.new_multiplier),
+        i64::from(config.new_multiplier_effective_timestamp),
+    )
+}
+
+#[tokio::test]
+async fn test_should_update_multiplier_immediately() {
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_issuer_scaled_security_token_mint(context, &mint_keypair, &mint_creator).await;
+    let now = current_timestamp(context).await;
+
+    // 2-for-1 split
+    let result =
+        execute_update_scaled_ui_amount_multiplier(context, mint, &mint_creator, 2.0, now).await;
+    assert_transaction_success(result);
+    assert_eq!(scaled_ui_amount(context, mint).await, (2.0, 2.0, now));
+}
+
+#[tokio::test]
+async fn test_should_schedule_multiplier_at_effective_timestamp() {
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_issuer_scaled_security_token_mint(context, &mint_keypair, &mint_creator).await;
+    let effective_timestamp = current_timestamp(context).await + 86_400;
+
+    let result = execute_update_scaled_ui_amount_multiplier(
+        context,
+        mint,
+        &mint_creator,
+        1.5,
+        effective_timestamp,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        scaled_ui_amount(context, mint).await,
+        (1.0, 1.5, effective_timestamp)
+    );
+}
+
+#[tokio::test]
+async fn test_update_multiplier_should_reject_invalid_multiplier() {
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_issuer_scaled_security_token_mint(context, &mint_keypair, &mint_creator).await;
+
+    for multiplier in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        let result =
+            execute_update_scaled_ui_amount_multiplier(context, mint, &mint_creator, multiplier, 0)
+                .await;
+        assert_instruction_error(result, "InvalidArgument");
+    }
+}
+
+#[tokio::test]
+async fn test_update_multiplier_should_fail_for_oracle_driven_mint() {
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    // ScaledUiAmount authority is the ScaledUiAmountAuthority PDA, not the mint authority PDA
+    create_scaled_security_token_mint(context, &mint_keypair, &mint_creator, 6).await;
+
+    let result =
+        execute_update_scaled_ui_amount_multiplier(context, mint, &mint_creator, 2.0, 0).await;
+    assert_transaction_failure(result);
+    assert_eq!(scaled_ui_amount(context, mint).await.1, 1.0);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod scenario_tests;
+
+pub mod scenario_helpers;
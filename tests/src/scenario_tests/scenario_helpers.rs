@@ -0,0 +1,411 @@
+//! Given / When / Then scenarios for compliance rules.
+//!
+//! A scenario describes the transfer verification config of a fresh mint, its holders and a
+//! sequence of transfers with their expected outcome, without touching the banks client:
+//!
+//! ```ignore
+//! Scenario::given(TransferRules::introspection([Approve, Approve]).threshold(1))
+//!     .holder("alice", 1_000)
+//!     .holder("bob", 0)
+//!     .when_transfer("alice", "bob", 400)
+//!     .verified_by([1])
+//!     .then_expect(Outcome::Approved)
+//!     .then_balance("bob", 400)
+//!     .run()
+//!     .await;
+//! ```
+//!
+//! Transfers go through the Transfer instruction of the security token program. By default the
+//! caller invokes every verification program of the config, `verified_by` restricts the
+//! invocation to a subset: the verification instructions placed before the transfer in
+//! introspection mode, the trailing program accounts in CPI mode.
+
+use security_token_client::{
+    errors::SecurityTokenProgramError,
+    instructions::{TransferBuilder, TRANSFER_DISCRIMINATOR},
+    types::InitializeVerificationConfigArgs,
+};
+use solana_program_test::{processor, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::{
+    helpers::{
+        add_dummy_verification_program, assert_custom_error, assert_security_token_error,
+        assert_transaction_success, create_dummy_verification_from_instruction,
+        create_minimal_security_token_mint, create_mint_verification_config, create_spl_account,
+        dummy_verification_processor, find_permanent_delegate_pda, find_verification_config_pda,
+        get_default_verification_programs, get_token_account_state, initialize_program,
+        initialize_verification_config, mint_tokens_to, send_tx,
+    },
+    verification_tests::verification_helpers::failing_dummy_program_processor,
+};
+
+pub const SCENARIO_DECIMALS: u8 = 6;
+
+/// Error code returned by a rejecting verification program
+pub const REJECTION_CODE: u32 = 0x1111;
+
+/// How the security token program checks the verification programs of the transfer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationMode {
+    /// The verification programs are called by the caller before the transfer
+    Introspection,
+    /// The security token program calls the verification programs
+    Cpi,
+}
+
+/// Decision of a verification program, whatever the transfer is
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Approve,
+    Reject,
+}
+
+/// Transfer verification config of the scenario mint
+#[derive(Clone, Debug)]
+pub struct TransferRules {
+    pub mode: VerificationMode,
+    /// Verification programs of the config, in config order
+    pub programs: Vec<Verdict>,
+    /// `0` requires every program, `k` any `k` of them
+    pub threshold: u8,
+    pub lookback_window: u8,
+    pub require_top_level: bool,
+}
+
+impl TransferRules {
+    pub fn introspection(programs: impl Into<Vec<Verdict>>) -> Self {
+        Self::new(VerificationMode::Introspection, programs)
+    }
+
+    pub fn cpi(programs: impl Into<Vec<Verdict>>) -> Self {
+        Self::new(VerificationMode::Cpi, programs)
+    }
+
+    fn new(mode: VerificationMode, programs: impl Into<Vec<Verdict>>) -> Self {
+        Self {
+            mode,
+            programs: programs.into(),
+            threshold: 0,
+            lookback_window: 0,
+            require_top_level: false,
+        }
+    }
+
+    pub fn threshold(mut self, threshold: u8) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn lookback_window(mut self, lookback_window: u8) -> Self {
+        self.lookback_window = lookback_window;
+        self
+    }
+}
+
+/// Expected outcome of a transfer
+#[derive(Clone, Debug)]
+pub enum Outcome {
+    Approved,
+    /// Rejected by the security token program
+    Rejected(SecurityTokenProgramError),
+    /// Rejected with a custom error code of another program, e.g. `REJECTION_CODE`
+    RejectedWithCode(u32),
+}
+
+impl Outcome {
+    fn assert(self, result: Result<(), BanksClientError>) {
+        match self {
+            Outcome::Approved => assert_transaction_success(result),
+            Outcome::Rejected(error) => assert_security_token_error(result, error),
+            Outcome::RejectedWithCode(code) => assert_custom_error(result, code),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Step {
+    Transfer {
+        from: &'static str,
+        to: &'static str,
+        amount: u64,
+        /// Indices into `TransferRules::programs`, `None` for every program
+        verified_by: Option<Vec<usize>>,
+        expect: Outcome,
+    },
+    Balance {
+        holder: &'static str,
+        amount: u64,
+    },
+}
+
+/// Scenario built with `given`, run with `run`, see the module docs
+#[derive(Clone, Debug)]
+pub struct Scenario {
+    rules: TransferRules,
+    holders: Vec<(&'static str, u64)>,
+    steps: Vec<Step>,
+}
+
+impl Scenario {
+    pub fn given(rules: TransferRules) -> Self {
+        Self {
+            rules,
+            holders: Vec::new(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Holder `name` with a token account holding `amount` raw tokens
+    pub fn holder(mut self, name: &'static str, amount: u64) -> Self {
+        self.holders.push((name, amount));
+        self
+    }
+
+    /// Transfer of `amount` from `from` to `to`, expected to be approved unless `then_expect`
+    /// says otherwise
+    pub fn when_transfer(mut self, from: &'static str, to: &'static str, amount: u64) -> Self {
+        self.steps.push(Step::Transfer {
+            from,
+            to,
+            amount,
+            verified_by: None,
+            expect: Outcome::Approved,
+        });
+        self
+    }
+
+    /// Restrict the verification programs invoked for the last transfer
+    pub fn verified_by(mut self, programs: impl Into<Vec<usize>>) -> Self {
+        match self.steps.last_mut() {
+            Some(Step::Transfer { verified_by, .. }) => *verified_by = Some(programs.into()),
+            _ => panic!("verified_by must follow when_transfer"),
+        }
+        self
+    }
+
+    /// Expected outcome of the last transfer
+    pub fn then_expect(mut self, outcome: Outcome) -> Self {
+        match self.steps.last_mut() {
+            Some(Step::Transfer { expect, .. }) => *expect = outcome,
+            _ => panic!("then_expect must follow when_transfer"),
+        }
+        self
+    }
+
+    /// Expected balance of `holder` once the previous steps ran
+    pub fn then_balance(mut self, holder: &'static str, amount: u64) -> Self {
+        self.steps.push(Step::Balance { holder, amount });
+        self
+    }
+
+    /// Provision the mint and holders, then run every step in order
+    pub async fn run(self) {
+        let mut world = ScenarioWorld::new(&self.rules, &self.holders).await;
+        for step in self.steps {
+            match step {
+                Step::Transfer {
+                    from,
+                    to,
+                    amount,
+                    verified_by,
+                    expect,
+                } => {
+                    let result = world
+                        .transfer(&self.rules, from, to, amount, verified_by)
+                        .await;
+                    expect.assert(result);
+                }
+                Step::Balance { holder, amount } => {
+                    let token_account = world.token_account(holder);
+                    let state =
+                        get_token_account_state(&mut world.context.banks_client, token_account)
+                            .await;
+                    assert_eq!(state.base.amount, amount, "balance of {holder}");
+                }
+            }
+        }
+    }
+}
+
+/// Mint and holders provisioned for a scenario
+struct ScenarioWorld {
+    context: ProgramTestContext,
+    mint: Pubkey,
+    transfer_config: Pubkey,
+    verification_programs: Vec<Pubkey>,
+    token_accounts: Vec<(&'static str, Pubkey)>,
+}
+
+impl ScenarioWorld {
+    async fn new(rules: &TransferRules, holders: &[(&'static str, u64)]) -> Self {
+        let verification_programs: Vec<Pubkey> = rules
+            .programs
+            .iter()
+            .map(|_| Pubkey::new_unique())
+            .collect();
+
+        let mut pt = initialize_program();
+        pt.prefer_bpf(false);
+        pt.add_program(
+            "security_token_transfer_hook",
+            Pubkey::from(security_token_transfer_hook::id()),
+            None,
+        );
+        add_dummy_verification_program(&mut pt);
+        for (index, (program, verdict)) in verification_programs
+            .iter()
+            .zip(&rules.programs)
+            .enumerate()
+        {
+            let name = Box::leak(format!("scenario_verification_program_{index}").into_boxed_str());
+            match verdict {
+                Verdict::Approve => {
+                    pt.add_program(name, *program, processor!(dummy_verification_processor))
+                }
+                Verdict::Reject => {
+                    pt.add_program(name, *program, processor!(failing_dummy_program_processor))
+                }
+            }
+        }
+        let mut context = pt.start_with_context().await;
+
+        let mint_keypair = Keypair::new();
+        let (mint_authority, _) = create_minimal_security_token_mint(
+            &mut context,
+            &mint_keypair,
+            None,
+            SCENARIO_DECIMALS,
+        )
+        .await;
+        let mint = mint_keypair.pubkey();
+
+        // Minting is always verified by the dummy program, the scenario is about transfers
+        let mint_config = create_mint_verification_config(
+            &mut context,
+            &mint_keypair,
+            mint_authority,
+            get_default_verification_programs(),
+            None,
+        )
+        .await;
+
+        let (transfer_config, _) = find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+        initialize_verification_config(
+            &mint_keypair,
+            &mut context,
+            mint_authority,
+            transfer_config,
+            &InitializeVerificationConfigArgs {
+                instruction_discriminator: TRANSFER_DISCRIMINATOR,
+                cpi_mode: rules.mode == VerificationMode::Cpi,
+                program_addresses: verification_programs.clone(),
+                threshold: rules.threshold,
+                lookback_window: rules.lookback_window,
+                require_top_level: rules.require_top_level,
+                timelock_seconds: 0,
+            },
+        )
+        .await;
+
+        let mut token_accounts = Vec::with_capacity(holders.len());
+        for (name, amount) in holders {
+            let owner = Keypair::new();
+            let token_account = create_spl_account(&mut context, &mint_keypair, &owner).await;
+            if *amount > 0 {
+                let result = mint_tokens_to(
+                    &context.banks_client,
+                    *amount,
+                    mint,
+                    token_account,
+                    mint_authority,
+                    mint_config,
+                    &context.payer,
+                )
+                .await;
+                assert_transaction_success(result);
+            }
+            token_accounts.push((*name, token_account));
+        }
+
+        Self {
+            context,
+            mint,
+            transfer_config,
+            verification_programs,
+            token_accounts,
+        }
+    }
+
+    fn token_account(&self, holder: &str) -> Pubkey {
+        self.token_accounts
+            .iter()
+            .find(|(name, _)| *name == holder)
+            .map(|(_, token_account)| *token_account)
+            .unwrap_or_else(|| panic!("unknown holder {holder}"))
+    }
+
+    async fn transfer(
+        &mut self,
+        rules: &TransferRules,
+        from: &str,
+        to: &str,
+        amount: u64,
+        verified_by: Option<Vec<usize>>,
+    ) -> Result<(), BanksClientError> {
+        let invoked: Vec<Pubkey> = match verified_by {
+            Some(indices) => indices
+                .into_iter()
+                .map(|index| self.verification_programs[index])
+                .collect(),
+            None => self.verification_programs.clone(),
+        };
+
+        let mut builder = TransferBuilder::new();
+        builder
+            .mint(self.mint)
+            .verification_config(self.transfer_config)
+            .permanent_delegate_authority(find_permanent_delegate_pda(&self.mint).0)
+            .mint_account(self.mint)
+            .from_token_account(self.token_account(from))
+            .to_token_account(self.token_account(to))
+            .transfer_hook_program(Pubkey::from(security_token_transfer_hook::id()))
+            .amount(amount);
+
+        let instructions = match rules.mode {
+            VerificationMode::Introspection => {
+                let transfer_ix = builder.instruction();
+                let verification_ix = create_dummy_verification_from_instruction(&transfer_ix);
+                let mut instructions: Vec<Instruction> = invoked
+                    .into_iter()
+                    .map(|program_id| Instruction {
+                        program_id,
+                        ..verification_ix.clone()
+                    })
+                    .collect();
+                instructions.push(transfer_ix);
+                instructions
+            }
+            VerificationMode::Cpi => {
+                for program_id in invoked {
+                    builder.add_remaining_account(AccountMeta::new_readonly(program_id, false));
+                }
+                vec![builder.instruction()]
+            }
+        };
+
+        // Fresh blockhash, so repeating a transfer in a scenario is not deduplicated
+        self.context.get_new_latest_blockhash().await.unwrap();
+        send_tx(
+            &self.context.banks_client,
+            instructions,
+            &self.context.payer.pubkey(),
+            vec![&self.context.payer],
+        )
+        .await
+    }
+}
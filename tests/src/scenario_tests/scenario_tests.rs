@@ -0,0 +1,142 @@
+use crate::scenario_tests::scenario_helpers::{
+    Outcome, Scenario, TransferRules, Verdict::Approve, Verdict::Reject, REJECTION_CODE,
+};
+use security_token_client::errors::SecurityTokenProgramError;
+use spl_token_2022::error::TokenError;
+
+/// One transfer of 400 from alice (1_000) to bob (0) under `rules`, invoking the
+/// `verified_by` programs (every program when `None`)
+struct TransferCase {
+    name: &'static str,
+    rules: TransferRules,
+    verified_by: Option<&'static [usize]>,
+    expect: Outcome,
+}
+
+fn transfer_cases() -> Vec<TransferCase> {
+    use Outcome::*;
+    use SecurityTokenProgramError::VerificationProgramNotFound;
+
+    vec![
+        // Introspection mode, every program required
+        TransferCase {
+            name: "introspection, all of 2, both called",
+            rules: TransferRules::introspection([Approve, Approve]),
+            verified_by: None,
+            expect: Approved,
+        },
+        TransferCase {
+            name: "introspection, all of 2, one called",
+            rules: TransferRules::introspection([Approve, Approve]),
+            verified_by: Some(&[0]),
+            expect: Rejected(VerificationProgramNotFound),
+        },
+        TransferCase {
+            name: "introspection, all of 2, one rejecting",
+            rules: TransferRules::introspection([Approve, Reject]),
+            verified_by: None,
+            expect: RejectedWithCode(REJECTION_CODE),
+        },
+        TransferCase {
+            name: "introspection, all of 2, calls outside the lookback window",
+            rules: TransferRules::introspection([Approve, Approve]).lookback_window(1),
+            verified_by: None,
+            expect: Rejected(VerificationProgramNotFound),
+        },
+        // Introspection mode, K of N
+        TransferCase {
+            name: "introspection, 1 of 2, second called",
+            rules: TransferRules::introspection([Approve, Approve]).threshold(1),
+            verified_by: Some(&[1]),
+            expect: Approved,
+        },
+        TransferCase {
+            name: "introspection, 1 of 2, rejecting program skipped",
+            rules: TransferRules::introspection([Approve, Reject]).threshold(1),
+            verified_by: Some(&[0]),
+            expect: Approved,
+        },
+        TransferCase {
+            name: "introspection, 2 of 3, one called",
+            rules: TransferRules::introspection([Approve, Approve, Approve]).threshold(2),
+            verified_by: Some(&[2]),
+            expect: Rejected(VerificationProgramNotFound),
+        },
+        // CPI mode, every program required
+        TransferCase {
+            name: "cpi, all of 2",
+            rules: TransferRules::cpi([Approve, Approve]),
+            verified_by: None,
+            expect: Approved,
+        },
+        TransferCase {
+            name: "cpi, all of 2, one rejecting",
+            rules: TransferRules::cpi([Reject, Approve]),
+            verified_by: None,
+            expect: RejectedWithCode(REJECTION_CODE),
+        },
+        // CPI mode, K of N
+        TransferCase {
+            name: "cpi, 1 of 2, approving program picked",
+            rules: TransferRules::cpi([Reject, Approve]).threshold(1),
+            verified_by: Some(&[1]),
+            expect: Approved,
+        },
+        TransferCase {
+            name: "cpi, 1 of 2, rejecting program picked",
+            rules: TransferRules::cpi([Reject, Approve]).threshold(1),
+            verified_by: Some(&[0]),
+            expect: RejectedWithCode(REJECTION_CODE),
+        },
+        TransferCase {
+            name: "cpi, 2 of 3, same program picked twice",
+            rules: TransferRules::cpi([Approve, Approve, Approve]).threshold(2),
+            verified_by: Some(&[1, 1]),
+            expect: Rejected(VerificationProgramNotFound),
+        },
+    ]
+}
+
+#[tokio::test]
+async fn test_transfer_verification_mode_scenarios() {
+    for case in transfer_cases() {
+        println!("Scenario: {}", case.name);
+        let approved = matches!(case.expect, Outcome::Approved);
+
+        let mut scenario = Scenario::given(case.rules)
+            .holder("alice", 1_000)
+            .holder("bob", 0)
+            .when_transfer("alice", "bob", 400);
+        if let Some(verified_by) = case.verified_by {
+            scenario = scenario.verified_by(verified_by);
+        }
+        scenario
+            .then_expect(case.expect)
+            .then_balance("alice", if approved { 600 } else { 1_000 })
+            .then_balance("bob", if approved { 400 } else { 0 })
+            .run()
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_transfer_sequence_scenario() {
+    Scenario::given(TransferRules::cpi([Approve, Approve]).threshold(1))
+        .holder("alice", 1_000)
+        .holder("bob", 0)
+        .holder("carol", 0)
+        .when_transfer("alice", "bob", 700)
+        .verified_by([0])
+        .when_transfer("bob", "carol", 200)
+        .verified_by([1])
+        .when_transfer("carol", "alice", 300)
+        .verified_by([0])
+        .then_expect(Outcome::RejectedWithCode(
+            TokenError::InsufficientFunds as u32,
+        ))
+        .then_balance("alice", 300)
+        .then_balance("bob", 500)
+        .then_balance("carol", 200)
+        .run()
+        .await;
+}
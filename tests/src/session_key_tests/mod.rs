@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod session_key_tests;
+
+pub mod session_key_helpers;
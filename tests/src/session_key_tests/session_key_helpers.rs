@@ -0,0 +1,113 @@
+use security_token_client::{
+    instructions::{
+        CloseSessionKey, CreateSessionKey, CreateSessionKeyInstructionArgs, CreateWithholdingRate,
+        CreateWithholdingRateInstructionArgs,
+    },
+    pda::{find_session_key_pda, find_withholding_rate_pda},
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::{
+    helpers::{find_mint_authority_pda, send_tx},
+    withholding_tests::withholding_helpers::find_tax_escrow_token_account,
+};
+
+/// Build and send CreateSessionKey instruction authorized by mint authority
+pub async fn execute_create_session_key(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    session_key: Pubkey,
+    args: CreateSessionKeyInstructionArgs,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (session_key_account, _) = find_session_key_pda(&mint, &session_key);
+
+    let ix = CreateSessionKey {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        session_key_account,
+        session_key,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(args);
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send CloseSessionKey instruction authorized by mint authority
+pub async fn execute_close_session_key(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    session_key: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (session_key_account, _) = find_session_key_pda(&mint, &session_key);
+
+    let ix = CloseSessionKey {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        mint_account: mint,
+        session_key_account,
+        rent_recipient: mint_creator.pubkey(),
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send CreateWithholdingRate instruction signed by a session key
+pub async fn execute_create_withholding_rate_by_session_key(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    args: CreateWithholdingRateInstructionArgs,
+    session_key: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (session_key_account, _) = find_session_key_pda(&mint, &session_key.pubkey());
+    let (withholding_rate_account, _) = find_withholding_rate_pda(&mint, args.jurisdiction);
+
+    let mut ix = CreateWithholdingRate {
+        mint,
+        verification_config_or_mint_authority: session_key_account,
+        instructions_sysvar_or_creator: session_key.pubkey(),
+        payer: session_key.pubkey(),
+        mint_account: mint,
+        withholding_rate_account,
+        tax_escrow_token_account: find_tax_escrow_token_account(&withholding_rate_account, &mint),
+        system_program: solana_program::system_program::id(),
+        token_program: TOKEN_22_PROGRAM_ID,
+        associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+    }
+    .instruction(args);
+    // Session key signs in place of the mint creator
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &session_key.pubkey(),
+        vec![session_key],
+    )
+    .await
+}
@@ -0,0 +1,226 @@
+use rstest::rstest;
+use security_token_client::{
+    errors::SecurityTokenProgramError,
+    instructions::{
+        CreateSessionKeyInstructionArgs, CreateWithholdingRateInstructionArgs,
+        CREATE_AGENT_ACCOUNT_DISCRIMINATOR, CREATE_HOLDING_PERIOD_DISCRIMINATOR,
+        CREATE_WITHHOLDING_RATE_DISCRIMINATOR, MIGRATE_BALANCES_DISCRIMINATOR,
+        RECLAIM_UNCLAIMED_DISCRIMINATOR, WITHDRAW_UNCLAIMED_REDEMPTION_DISCRIMINATOR,
+    },
+    pda::{find_session_key_pda, find_withholding_rate_pda},
+    session_key::allowed_instructions,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    coupon_tests::coupon_helpers::current_timestamp,
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint,
+        start_with_context_and_accounts,
+    },
+    session_key_tests::session_key_helpers::{
+        execute_close_session_key, execute_create_session_key,
+        execute_create_withholding_rate_by_session_key,
+    },
+};
+
+const SESSION_DURATION: i64 = 3_600;
+const JURISDICTION: u16 = 840;
+
+/// Start with a funded session key and a mint created by the context payer
+async fn setup(session_key: &Keypair) -> (ProgramTestContext, Pubkey, Keypair) {
+    let mut context = start_with_context_and_accounts(vec![(session_key, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let mint_keypair = Keypair::new();
+    create_minimal_security_token_mint(&mut context, &mint_keypair, Some(&mint_creator), 6).await;
+    (context, mint_keypair.pubkey(), mint_creator)
+}
+
+async fn create_session_key(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    session_key: Pubkey,
+    discriminators: &[u8],
+    mint_creator: &Keypair,
+) {
+    let expiry = current_timestamp(context).await + SESSION_DURATION;
+    let result = execute_create_session_key(
+        &context.banks_client,
+        mint,
+        session_key,
+        CreateSessionKeyInstructionArgs {
+            allowed_instructions: allowed_instructions(discriminators),
+            max_amount: 1_000,
+            expiry,
+        },
+        mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+}
+
+fn withholding_rate_args() -> CreateWithholdingRateInstructionArgs {
+    CreateWithholdingRateInstructionArgs {
+        jurisdiction: JURISDICTION,
+        rate_bps: 3_000,
+    }
+}
+
+#[tokio::test]
+async fn test_session_key_should_execute_allowed_instruction() {
+    let session_key = Keypair::new();
+    let (mut context, mint, mint_creator) = setup(&session_key).await;
+    create_session_key(
+        &mut context,
+        mint,
+        session_key.pubkey(),
+        &[CREATE_WITHHOLDING_RATE_DISCRIMINATOR],
+        &mint_creator,
+    )
+    .await;
+    let (session_key_account, _) = find_session_key_pda(&mint, &session_key.pubkey());
+    assert_account_exists(&mut context, session_key_account, true).await;
+
+    let result = execute_create_withholding_rate_by_session_key(
+        &context.banks_client,
+        mint,
+        withholding_rate_args(),
+        &session_key,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (withholding_rate_account, _) = find_withholding_rate_pda(&mint, JURISDICTION);
+    assert_account_exists(&mut context, withholding_rate_account, true).await;
+}
+
+#[tokio::test]
+async fn test_session_key_should_not_execute_instruction_outside_allowed_set() {
+    let session_key = Keypair::new();
+    let (mut context, mint, mint_creator) = setup(&session_key).await;
+    create_session_key(
+        &mut context,
+        mint,
+        session_key.pubkey(),
+        &[CREATE_HOLDING_PERIOD_DISCRIMINATOR],
+        &mint_creator,
+    )
+    .await;
+
+    let result = execute_create_withholding_rate_by_session_key(
+        &context.banks_client,
+        mint,
+        withholding_rate_args(),
+        &session_key,
+    )
+    .await;
+    assert_security_token_error(
+        result,
+        SecurityTokenProgramError::SessionKeyPermissionDenied,
+    );
+}
+
+#[tokio::test]
+async fn test_closed_session_key_should_not_execute_instructions() {
+    let session_key = Keypair::new();
+    let (mut context, mint, mint_creator) = setup(&session_key).await;
+    create_session_key(
+        &mut context,
+        mint,
+        session_key.pubkey(),
+        &[CREATE_WITHHOLDING_RATE_DISCRIMINATOR],
+        &mint_creator,
+    )
+    .await;
+
+    let result = execute_close_session_key(
+        &context.banks_client,
+        mint,
+        session_key.pubkey(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    let (session_key_account, _) = find_session_key_pda(&mint, &session_key.pubkey());
+    assert_account_exists(&mut context, session_key_account, false).await;
+
+    let result = execute_create_withholding_rate_by_session_key(
+        &context.banks_client,
+        mint,
+        withholding_rate_args(),
+        &session_key,
+    )
+    .await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_not_delegate_agent_management_to_session_key() {
+    let session_key = Keypair::new();
+    let (mut context, mint, mint_creator) = setup(&session_key).await;
+    let expiry = current_timestamp(&mut context).await + SESSION_DURATION;
+
+    let result = execute_create_session_key(
+        &context.banks_client,
+        mint,
+        session_key.pubkey(),
+        CreateSessionKeyInstructionArgs {
+            allowed_instructions: allowed_instructions(&[CREATE_AGENT_ACCOUNT_DISCRIMINATOR]),
+            max_amount: 1_000,
+            expiry,
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+}
+
+/// The amounts moved by these instructions are read from accounts, max_amount can't cap them
+#[rstest]
+#[case::withdraw_unclaimed_redemption(WITHDRAW_UNCLAIMED_REDEMPTION_DISCRIMINATOR)]
+#[case::reclaim_unclaimed(RECLAIM_UNCLAIMED_DISCRIMINATOR)]
+#[case::migrate_balances(MIGRATE_BALANCES_DISCRIMINATOR)]
+#[tokio::test]
+async fn test_should_not_delegate_uncapped_transfers_to_session_key(#[case] discriminator: u8) {
+    let session_key = Keypair::new();
+    let (mut context, mint, mint_creator) = setup(&session_key).await;
+    let expiry = current_timestamp(&mut context).await + SESSION_DURATION;
+
+    let result = execute_create_session_key(
+        &context.banks_client,
+        mint,
+        session_key.pubkey(),
+        CreateSessionKeyInstructionArgs {
+            allowed_instructions: allowed_instructions(&[discriminator]),
+            max_amount: 1_000,
+            expiry,
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_not_create_expired_session_key() {
+    let session_key = Keypair::new();
+    let (mut context, mint, mint_creator) = setup(&session_key).await;
+    let expiry = current_timestamp(&mut context).await;
+
+    let result = execute_create_session_key(
+        &context.banks_client,
+        mint,
+        session_key.pubkey(),
+        CreateSessionKeyInstructionArgs {
+            allowed_instructions: allowed_instructions(&[CREATE_WITHHOLDING_RATE_DISCRIMINATOR]),
+            max_amount: 1_000,
+            expiry,
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::SessionKeyExpired);
+}
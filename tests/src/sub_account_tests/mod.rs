@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod sub_account_tests;
+
+pub mod sub_account_helpers;
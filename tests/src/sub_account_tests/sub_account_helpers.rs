@@ -0,0 +1,77 @@
+use security_token_client::{
+    instructions::{
+        CloseSubAccountLedgerBuilder, CreateSubAccountLedgerBuilder, CreditSubAccountBuilder,
+        DebitSubAccountBuilder,
+    },
+    pda::{find_sub_account_ledger_pda, find_sub_account_pda},
+};
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::Instruction;
+
+/// CreateSubAccountLedger instruction of the omnibus `token_account` owned by `custodian`
+pub fn create_sub_account_ledger_ix(
+    mint: Pubkey,
+    custodian: Pubkey,
+    token_account: Pubkey,
+) -> Instruction {
+    CreateSubAccountLedgerBuilder::new()
+        .custodian(custodian)
+        .mint(mint)
+        .token_account(token_account)
+        .sub_account_ledger_account(find_sub_account_ledger_pda(&token_account).0)
+        .instruction()
+}
+
+/// CreditSubAccount instruction recording `amount` for `beneficial_owner`
+pub fn credit_sub_account_ix(
+    mint: Pubkey,
+    custodian: Pubkey,
+    token_account: Pubkey,
+    beneficial_owner: [u8; 32],
+    amount: u64,
+) -> Instruction {
+    let ledger = find_sub_account_ledger_pda(&token_account).0;
+    CreditSubAccountBuilder::new()
+        .custodian(custodian)
+        .mint(mint)
+        .token_account(token_account)
+        .sub_account_ledger_account(ledger)
+        .sub_account(find_sub_account_pda(&ledger, &beneficial_owner).0)
+        .beneficial_owner(beneficial_owner)
+        .amount(amount)
+        .instruction()
+}
+
+/// DebitSubAccount instruction releasing `amount` of `beneficial_owner`
+pub fn debit_sub_account_ix(
+    mint: Pubkey,
+    custodian: Pubkey,
+    token_account: Pubkey,
+    beneficial_owner: [u8; 32],
+    amount: u64,
+) -> Instruction {
+    let ledger = find_sub_account_ledger_pda(&token_account).0;
+    DebitSubAccountBuilder::new()
+        .custodian(custodian)
+        .mint(mint)
+        .token_account(token_account)
+        .sub_account_ledger_account(ledger)
+        .sub_account(find_sub_account_pda(&ledger, &beneficial_owner).0)
+        .beneficial_owner(beneficial_owner)
+        .amount(amount)
+        .instruction()
+}
+
+/// CloseSubAccountLedger instruction of the omnibus `token_account`
+pub fn close_sub_account_ledger_ix(
+    mint: Pubkey,
+    custodian: Pubkey,
+    token_account: Pubkey,
+) -> Instruction {
+    CloseSubAccountLedgerBuilder::new()
+        .custodian(custodian)
+        .mint(mint)
+        .token_account(token_account)
+        .sub_account_ledger_account(find_sub_account_ledger_pda(&token_account).0)
+        .instruction()
+}
@@ -0,0 +1,160 @@
+use security_token_client::{
+    accounts::{SubAccount, SubAccountLedger},
+    errors::SecurityTokenProgramError,
+    pda::{find_sub_account_ledger_pda, find_sub_account_pda},
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_success,
+        create_spl_account, send_tx, setup_transfer_verified_mint, start_with_transfer_hook,
+    },
+    position_limit_tests::position_limit_helpers::transfer,
+    sub_account_tests::sub_account_helpers::{
+        close_sub_account_ledger_ix, create_sub_account_ledger_ix, credit_sub_account_ix,
+        debit_sub_account_ix,
+    },
+};
+
+const SUPPLY: u64 = 1_000_000;
+const ALICE: [u8; 32] = [1; 32];
+const BOB: [u8; 32] = [2; 32];
+
+/// Start with the transfer hook and mint `SUPPLY` to the omnibus account of `custodian`
+async fn setup_omnibus(custodian: &Keypair) -> (ProgramTestContext, Keypair, Pubkey) {
+    let mut context = start_with_transfer_hook(&[custodian]).await;
+    let (mint_keypair, _, omnibus) =
+        setup_transfer_verified_mint(&mut context, custodian, SUPPLY).await;
+    (context, mint_keypair, omnibus)
+}
+
+#[tokio::test]
+async fn test_should_keep_omnibus_balance_above_sub_balances() {
+    let custodian = Keypair::new();
+    let (mut context, mint_keypair, omnibus) = setup_omnibus(&custodian).await;
+    let mint = mint_keypair.pubkey();
+    let banks_client = context.banks_client.clone();
+    let outside_account = create_spl_account(&mut context, &mint_keypair, &Keypair::new()).await;
+
+    let ixs = vec![
+        create_sub_account_ledger_ix(mint, custodian.pubkey(), omnibus),
+        credit_sub_account_ix(mint, custodian.pubkey(), omnibus, ALICE, 400_000),
+        credit_sub_account_ix(mint, custodian.pubkey(), omnibus, BOB, 200_000),
+    ];
+    let result = send_tx(&banks_client, ixs, &custodian.pubkey(), vec![&custodian]).await;
+    assert_transaction_success(result);
+
+    let (ledger_pda, _) = find_sub_account_ledger_pda(&omnibus);
+    let account = assert_account_exists(&mut context, ledger_pda, true)
+        .await
+        .unwrap();
+    let ledger = SubAccountLedger::from_bytes(&account.data).unwrap();
+    assert_eq!(ledger.token_account, omnibus);
+    assert_eq!(ledger.total_balance, 600_000);
+    assert_eq!(ledger.sub_account_count, 2);
+
+    // Only the 400_000 unallocated tokens can leave the omnibus account
+    let result = transfer(
+        &context,
+        mint,
+        omnibus,
+        outside_account,
+        &custodian,
+        400_001,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::SubAccountBalanceExceeded);
+    let result = transfer(
+        &context,
+        mint,
+        omnibus,
+        outside_account,
+        &custodian,
+        400_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // Bob withdraws: debit then transfer
+    let ix = debit_sub_account_ix(mint, custodian.pubkey(), omnibus, BOB, 200_000);
+    let result = send_tx(
+        &banks_client,
+        vec![ix],
+        &custodian.pubkey(),
+        vec![&custodian],
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_account_exists(
+        &mut context,
+        find_sub_account_pda(&ledger_pda, &BOB).0,
+        false,
+    )
+    .await;
+    let result = transfer(
+        &context,
+        mint,
+        omnibus,
+        outside_account,
+        &custodian,
+        200_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let account = assert_account_exists(
+        &mut context,
+        find_sub_account_pda(&ledger_pda, &ALICE).0,
+        true,
+    )
+    .await
+    .unwrap();
+    let sub_account = SubAccount::from_bytes(&account.data).unwrap();
+    assert_eq!(sub_account.ledger, ledger_pda);
+    assert_eq!(sub_account.balance, 400_000);
+}
+
+#[tokio::test]
+async fn test_should_reject_uncovered_credit_and_close_of_non_empty_ledger() {
+    let custodian = Keypair::new();
+    let (context, mint_keypair, omnibus) = setup_omnibus(&custodian).await;
+    let mint = mint_keypair.pubkey();
+    let banks_client = context.banks_client.clone();
+
+    let ixs = vec![
+        create_sub_account_ledger_ix(mint, custodian.pubkey(), omnibus),
+        credit_sub_account_ix(mint, custodian.pubkey(), omnibus, ALICE, SUPPLY),
+    ];
+    let result = send_tx(&banks_client, ixs, &custodian.pubkey(), vec![&custodian]).await;
+    assert_transaction_success(result);
+
+    let ix = credit_sub_account_ix(mint, custodian.pubkey(), omnibus, BOB, 1);
+    let result = send_tx(
+        &banks_client,
+        vec![ix],
+        &custodian.pubkey(),
+        vec![&custodian],
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::SubAccountBalanceExceeded);
+
+    let ix = close_sub_account_ledger_ix(mint, custodian.pubkey(), omnibus);
+    let result = send_tx(
+        &banks_client,
+        vec![ix],
+        &custodian.pubkey(),
+        vec![&custodian],
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::SubAccountLedgerNotEmpty);
+
+    let ixs = vec![
+        debit_sub_account_ix(mint, custodian.pubkey(), omnibus, ALICE, SUPPLY),
+        close_sub_account_ledger_ix(mint, custodian.pubkey(), omnibus),
+    ];
+    let result = send_tx(&banks_client, ixs, &custodian.pubkey(), vec![&custodian]).await;
+    assert_transaction_success(result);
+}
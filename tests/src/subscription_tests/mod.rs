@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod subscription_tests;
+
+pub mod subscription_helpers;
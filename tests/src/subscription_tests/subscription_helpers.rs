@@ -0,0 +1,219 @@
+use security_token_client::{
+    instructions::{
+        Allocate, AllocateInstructionArgs, Commit, CommitInstructionArgs, OpenSubscription,
+        OpenSubscriptionInstructionArgs, Settle,
+    },
+    pda::{
+        find_associated_token_address_with_program, find_subscription_commitment_pda,
+        find_subscription_pda,
+    },
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    sysvar,
+};
+use spl_associated_token_account::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{
+    create_dummy_verification_from_instruction, find_mint_authority_pda, send_tx,
+};
+
+/// Payment vault of the subscription, owned by its Subscription PDA
+pub fn find_payment_vault(
+    mint: &Pubkey,
+    subscription_id: u64,
+    payment_mint: &Pubkey,
+    payment_token_program: &Pubkey,
+) -> Pubkey {
+    let (subscription, _) = find_subscription_pda(mint, subscription_id);
+    find_associated_token_address_with_program(&subscription, payment_mint, payment_token_program).0
+}
+
+/// Build and send OpenSubscription instruction authorized by mint authority
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_open_subscription(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    subscription_id: u64,
+    payment_mint: Pubkey,
+    payment_token_program: Pubkey,
+    proceeds_account: Pubkey,
+    price_per_token: u64,
+    close_timestamp: i64,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (subscription_account, _) = find_subscription_pda(&mint, subscription_id);
+
+    let ix = OpenSubscription {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        subscription_account,
+        payment_mint,
+        payment_vault: find_payment_vault(
+            &mint,
+            subscription_id,
+            &payment_mint,
+            &payment_token_program,
+        ),
+        proceeds_account,
+        payment_token_program,
+        associated_token_account_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(OpenSubscriptionInstructionArgs {
+        subscription_id,
+        price_per_token,
+        close_timestamp,
+    });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send Commit instruction paid and signed by the investor
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_commit(
+    banks_client: &BanksClient,
+    verification_config: Pubkey,
+    mint: Pubkey,
+    subscription_id: u64,
+    payment_mint: Pubkey,
+    payment_token_program: Pubkey,
+    investor_payment_account: Pubkey,
+    investor: &Keypair,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let (subscription_account, _) = find_subscription_pda(&mint, subscription_id);
+    let (commitment_account, _) =
+        find_subscription_commitment_pda(&subscription_account, &investor.pubkey());
+
+    let ix = Commit {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        payer: investor.pubkey(),
+        investor: investor.pubkey(),
+        mint_account: mint,
+        subscription_account,
+        commitment_account,
+        payment_mint,
+        payment_vault: find_payment_vault(
+            &mint,
+            subscription_id,
+            &payment_mint,
+            &payment_token_program,
+        ),
+        investor_payment_account,
+        payment_token_program,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(CommitInstructionArgs { amount });
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &investor.pubkey(),
+        vec![investor],
+    )
+    .await
+}
+
+/// Build and send Allocate instruction authorized by mint authority
+pub async fn execute_allocate(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    subscription_id: u64,
+    investor: Pubkey,
+    allocation: u64,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (subscription_account, _) = find_subscription_pda(&mint, subscription_id);
+    let (commitment_account, _) =
+        find_subscription_commitment_pda(&subscription_account, &investor);
+
+    let ix = Allocate {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        mint_account: mint,
+        subscription_account,
+        commitment_account,
+    }
+    .instruction(AllocateInstructionArgs { allocation });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send Settle instruction
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_settle(
+    banks_client: &BanksClient,
+    verification_config: Pubkey,
+    mint_authority: Pubkey,
+    mint: Pubkey,
+    subscription_id: u64,
+    investor: Pubkey,
+    token_account: Pubkey,
+    payment_mint: Pubkey,
+    payment_token_program: Pubkey,
+    proceeds_account: Pubkey,
+    investor_payment_account: Pubkey,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (subscription_account, _) = find_subscription_pda(&mint, subscription_id);
+    let (commitment_account, _) =
+        find_subscription_commitment_pda(&subscription_account, &investor);
+
+    let ix = Settle {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        mint_authority,
+        investor,
+        mint_account: mint,
+        subscription_account,
+        commitment_account,
+        token_account,
+        payment_mint,
+        payment_vault: find_payment_vault(
+            &mint,
+            subscription_id,
+            &payment_mint,
+            &payment_token_program,
+        ),
+        proceeds_account,
+        investor_payment_account,
+        payment_token_program,
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction();
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
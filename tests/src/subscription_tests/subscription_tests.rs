@@ -0,0 +1,323 @@
+use security_token_client::{
+    accounts::Subscription,
+    errors::SecurityTokenProgramError,
+    instructions::{COMMIT_DISCRIMINATOR, SETTLE_DISCRIMINATOR},
+    pda::{find_subscription_commitment_pda, find_subscription_pda, TOKEN_PROGRAM_ID},
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    coupon_tests::coupon_helpers::{current_timestamp, warp_to_timestamp},
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        create_verification_config, get_default_verification_programs, get_token_account_state,
+        start_with_context_and_accounts,
+    },
+    subscription_tests::subscription_helpers::{
+        execute_allocate, execute_commit, execute_open_subscription, execute_settle,
+        find_payment_vault,
+    },
+    wrap_tests::wrap_helpers::{create_underlying_mint, create_underlying_token_account},
+};
+
+const DECIMALS: u8 = 6;
+const SUBSCRIPTION_ID: u64 = 1;
+/// 2 payment tokens per security token
+const PRICE_PER_TOKEN: u64 = 2_000_000;
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    get_token_account_state(&mut context.banks_client, token_account)
+        .await
+        .base
+        .amount
+}
+
+#[tokio::test]
+async fn test_should_commit_allocate_and_settle_subscription() {
+    let investor_a = Keypair::new();
+    let investor_b = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![
+        (&investor_a, 1_000_000_000),
+        (&investor_b, 1_000_000_000),
+    ])
+    .await;
+    let mint_creator = context.payer.insecure_clone();
+    let payment_token_program = TOKEN_PROGRAM_ID;
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), DECIMALS)
+            .await;
+    let commit_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        COMMIT_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+    let settle_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        SETTLE_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+
+    let payment_mint = create_underlying_mint(context, &payment_token_program, DECIMALS).await;
+    let proceeds_account = create_underlying_token_account(
+        context,
+        &payment_mint,
+        &payment_token_program,
+        &mint_creator.pubkey(),
+        DECIMALS,
+        0,
+    )
+    .await;
+    let mut investor_payment_accounts = vec![];
+    for investor in [&investor_a, &investor_b] {
+        investor_payment_accounts.push(
+            create_underlying_token_account(
+                context,
+                &payment_mint,
+                &payment_token_program,
+                &investor.pubkey(),
+                DECIMALS,
+                100_000_000,
+            )
+            .await,
+        );
+    }
+
+    let close_timestamp = current_timestamp(context).await + 1_000;
+    let result = execute_open_subscription(
+        &context.banks_client,
+        mint,
+        SUBSCRIPTION_ID,
+        payment_mint,
+        payment_token_program,
+        proceeds_account,
+        PRICE_PER_TOKEN,
+        close_timestamp,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // 100 and 50 payment tokens committed
+    for (investor, investor_payment_account, amount) in [
+        (&investor_a, investor_payment_accounts[0], 100_000_000),
+        (&investor_b, investor_payment_accounts[1], 50_000_000),
+    ] {
+        let result = execute_commit(
+            &context.banks_client,
+            commit_verification_config,
+            mint,
+            SUBSCRIPTION_ID,
+            payment_mint,
+            payment_token_program,
+            investor_payment_account,
+            investor,
+            amount,
+        )
+        .await;
+        assert_transaction_success(result);
+    }
+    let payment_vault = find_payment_vault(
+        &mint,
+        SUBSCRIPTION_ID,
+        &payment_mint,
+        &payment_token_program,
+    );
+    assert_eq!(token_amount(context, payment_vault).await, 150_000_000);
+
+    // Allocations wait for the raise to close
+    let result = execute_allocate(
+        &context.banks_client,
+        mint,
+        SUBSCRIPTION_ID,
+        investor_a.pubkey(),
+        40_000_000,
+        &mint_creator,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::SubscriptionNotClosed);
+
+    warp_to_timestamp(context, close_timestamp).await;
+    let result = execute_commit(
+        &context.banks_client,
+        commit_verification_config,
+        mint,
+        SUBSCRIPTION_ID,
+        payment_mint,
+        payment_token_program,
+        investor_payment_accounts[1],
+        &investor_b,
+        1_000_000,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::SubscriptionClosed);
+
+    let result = execute_allocate(
+        &context.banks_client,
+        mint,
+        SUBSCRIPTION_ID,
+        investor_a.pubkey(),
+        40_000_000,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // 30 tokens cost 60 payment tokens, above the 50 committed
+    let result = execute_allocate(
+        &context.banks_client,
+        mint,
+        SUBSCRIPTION_ID,
+        investor_b.pubkey(),
+        30_000_000,
+        &mint_creator,
+    )
+    .await;
+    assert_security_token_error(
+        result,
+        SecurityTokenProgramError::AllocationExceedsCommitment,
+    );
+    let result = execute_allocate(
+        &context.banks_client,
+        mint,
+        SUBSCRIPTION_ID,
+        investor_b.pubkey(),
+        20_000_000,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (subscription_pda, _) = find_subscription_pda(&mint, SUBSCRIPTION_ID);
+    let account = assert_account_exists(context, subscription_pda, true)
+        .await
+        .unwrap();
+    let subscription = Subscription::from_bytes(&account.data).unwrap();
+    assert_eq!(subscription.total_committed, 150_000_000);
+    assert_eq!(subscription.total_allocated, 60_000_000);
+
+    for (investor, investor_payment_account, allocation, refund) in [
+        (
+            &investor_a,
+            investor_payment_accounts[0],
+            40_000_000,
+            20_000_000,
+        ),
+        (
+            &investor_b,
+            investor_payment_accounts[1],
+            20_000_000,
+            10_000_000,
+        ),
+    ] {
+        let token_account = create_spl_account(context, &mint_keypair, investor).await;
+        let result = execute_settle(
+            &context.banks_client,
+            settle_verification_config,
+            mint_authority_pda,
+            mint,
+            SUBSCRIPTION_ID,
+            investor.pubkey(),
+            token_account,
+            payment_mint,
+            payment_token_program,
+            proceeds_account,
+            investor_payment_account,
+            &mint_creator,
+        )
+        .await;
+        assert_transaction_success(result);
+
+        assert_eq!(token_amount(context, token_account).await, allocation);
+        assert_eq!(
+            token_amount(context, investor_payment_account).await,
+            refund
+        );
+        let (commitment_pda, _) =
+            find_subscription_commitment_pda(&subscription_pda, &investor.pubkey());
+        assert_account_exists(context, commitment_pda, false).await;
+    }
+
+    assert_eq!(token_amount(context, proceeds_account).await, 120_000_000);
+    assert_eq!(token_amount(context, payment_vault).await, 0);
+}
+
+#[tokio::test]
+async fn test_open_subscription_validation() {
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), DECIMALS).await;
+    let payment_mint = create_underlying_mint(context, &TOKEN_PROGRAM_ID, DECIMALS).await;
+    let other_mint = create_underlying_mint(context, &TOKEN_PROGRAM_ID, DECIMALS).await;
+    let proceeds_account = create_underlying_token_account(
+        context,
+        &payment_mint,
+        &TOKEN_PROGRAM_ID,
+        &mint_creator.pubkey(),
+        DECIMALS,
+        0,
+    )
+    .await;
+    let other_proceeds_account = create_underlying_token_account(
+        context,
+        &other_mint,
+        &TOKEN_PROGRAM_ID,
+        &mint_creator.pubkey(),
+        DECIMALS,
+        0,
+    )
+    .await;
+    let now = current_timestamp(context).await;
+
+    for (proceeds_account, price_per_token, close_timestamp) in [
+        // Already closed
+        (proceeds_account, PRICE_PER_TOKEN, now),
+        (proceeds_account, 0, now + 1_000),
+        // Proceeds must be paid in the payment mint
+        (other_proceeds_account, PRICE_PER_TOKEN, now + 1_000),
+    ] {
+        let result = execute_open_subscription(
+            &context.banks_client,
+            mint,
+            SUBSCRIPTION_ID,
+            payment_mint,
+            TOKEN_PROGRAM_ID,
+            proceeds_account,
+            price_per_token,
+            close_timestamp,
+            &mint_creator,
+        )
+        .await;
+        assert_transaction_failure(result);
+    }
+
+    let result = execute_open_subscription(
+        &context.banks_client,
+        mint,
+        SUBSCRIPTION_ID,
+        payment_mint,
+        TOKEN_PROGRAM_ID,
+        proceeds_account,
+        PRICE_PER_TOKEN,
+        now + 1_000,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod succession_tests;
+
+pub mod succession_helpers;
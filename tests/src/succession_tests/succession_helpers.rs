@@ -0,0 +1,143 @@
+use security_token_client::{
+    instructions::{
+        ClaimSuccession, CloseSuccessionPolicy, CreateSuccessionPolicy,
+        CreateSuccessionPolicyInstructionArgs, CreateWithholdingRate,
+        CreateWithholdingRateInstructionArgs, Heartbeat,
+    },
+    pda::{find_succession_policy_pda, find_withholding_rate_pda},
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::{
+    helpers::{find_mint_authority_pda, send_tx},
+    withholding_tests::withholding_helpers::find_tax_escrow_token_account,
+};
+
+/// Build and send CreateSuccessionPolicy instruction authorized by mint authority
+pub async fn execute_create_succession_policy(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    successor: Pubkey,
+    heartbeat_period: i64,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+
+    let ix = CreateSuccessionPolicy {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        succession_policy_account: find_succession_policy_pda(&mint).0,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(CreateSuccessionPolicyInstructionArgs {
+        successor,
+        heartbeat_period,
+    });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send Heartbeat instruction authorized by mint authority
+pub async fn execute_heartbeat(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+
+    let ix = Heartbeat {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        mint_account: mint,
+        succession_policy_account: find_succession_policy_pda(&mint).0,
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send ClaimSuccession instruction signed by `successor`
+pub async fn execute_claim_succession(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    successor: &Keypair,
+) -> Result<(), BanksClientError> {
+    let ix = ClaimSuccession {
+        successor: successor.pubkey(),
+        mint,
+        succession_policy_account: find_succession_policy_pda(&mint).0,
+    }
+    .instruction();
+
+    send_tx(banks_client, vec![ix], &successor.pubkey(), vec![successor]).await
+}
+
+/// Build and send CloseSuccessionPolicy instruction, authorized by `authority` through
+/// `authority_account` (mint authority or succession policy PDA)
+pub async fn execute_close_succession_policy(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    authority_account: Pubkey,
+    authority: &Keypair,
+) -> Result<(), BanksClientError> {
+    let mut ix = CloseSuccessionPolicy {
+        mint,
+        verification_config_or_mint_authority: authority_account,
+        instructions_sysvar_or_creator: authority.pubkey(),
+        mint_account: mint,
+        succession_policy_account: find_succession_policy_pda(&mint).0,
+        rent_recipient: authority.pubkey(),
+    }
+    .instruction();
+    ix.accounts[2].is_signer = true;
+
+    send_tx(banks_client, vec![ix], &authority.pubkey(), vec![authority]).await
+}
+
+/// Build and send CreateWithholdingRate instruction signed by the successor of the mint
+pub async fn execute_create_withholding_rate_by_successor(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    args: CreateWithholdingRateInstructionArgs,
+    successor: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (withholding_rate_account, _) = find_withholding_rate_pda(&mint, args.jurisdiction);
+
+    let mut ix = CreateWithholdingRate {
+        mint,
+        verification_config_or_mint_authority: find_succession_policy_pda(&mint).0,
+        instructions_sysvar_or_creator: successor.pubkey(),
+        payer: successor.pubkey(),
+        mint_account: mint,
+        withholding_rate_account,
+        tax_escrow_token_account: find_tax_escrow_token_account(&withholding_rate_account, &mint),
+        system_program: solana_program::system_program::id(),
+        token_program: TOKEN_22_PROGRAM_ID,
+        associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+    }
+    .instruction(args);
+    // Successor signs in place of the mint creator
+    ix.accounts[2].is_signer = true;
+
+    send_tx(banks_client, vec![ix], &successor.pubkey(), vec![successor]).await
+}
@@ -0,0 +1,115 @@
+use security_token_client::{
+    accounts::SuccessionPolicy, errors::SecurityTokenProgramError,
+    instructions::CreateWithholdingRateInstructionArgs, pda::find_succession_policy_pda,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    coupon_tests::coupon_helpers::{current_timestamp, warp_to_timestamp},
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_success,
+        create_minimal_security_token_mint, find_mint_authority_pda,
+        start_with_context_and_accounts,
+    },
+    succession_tests::succession_helpers::{
+        execute_claim_succession, execute_close_succession_policy,
+        execute_create_succession_policy, execute_create_withholding_rate_by_successor,
+        execute_heartbeat,
+    },
+};
+
+const HEARTBEAT_PERIOD: i64 = 30 * 24 * 60 * 60;
+
+/// Start with a funded successor and a mint with a succession policy naming it
+async fn setup(successor: &Keypair) -> (ProgramTestContext, Pubkey, Keypair, i64) {
+    let mut context = start_with_context_and_accounts(vec![(successor, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(&mut context, &mint_keypair, Some(&mint_creator), 6).await;
+
+    let created_at = current_timestamp(&mut context).await;
+    let result = execute_create_succession_policy(
+        &context.banks_client,
+        mint,
+        successor.pubkey(),
+        HEARTBEAT_PERIOD,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    (context, mint, mint_creator, created_at)
+}
+
+fn withholding_rate_args() -> CreateWithholdingRateInstructionArgs {
+    CreateWithholdingRateInstructionArgs {
+        jurisdiction: 840,
+        rate_bps: 3_000,
+    }
+}
+
+#[tokio::test]
+async fn test_successor_should_claim_authority_after_missed_heartbeats() {
+    let successor = Keypair::new();
+    let (mut context, mint, mint_creator, created_at) = setup(&successor).await;
+
+    // Not claimed yet: the successor has no authority
+    let result = execute_create_withholding_rate_by_successor(
+        &context.banks_client,
+        mint,
+        withholding_rate_args(),
+        &successor,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::SuccessionNotClaimable);
+    let result = execute_claim_succession(&context.banks_client, mint, &successor).await;
+    assert_security_token_error(result, SecurityTokenProgramError::SuccessionNotClaimable);
+
+    warp_to_timestamp(&mut context, created_at + HEARTBEAT_PERIOD + 1).await;
+    let result = execute_claim_succession(&context.banks_client, mint, &successor).await;
+    assert_transaction_success(result);
+
+    let account = assert_account_exists(&mut context, find_succession_policy_pda(&mint).0, true)
+        .await
+        .unwrap();
+    let policy = SuccessionPolicy::from_bytes(&account.data).unwrap();
+    assert_eq!(policy.successor, successor.pubkey());
+    assert!(policy.claimed_at > created_at);
+
+    let result = execute_create_withholding_rate_by_successor(
+        &context.banks_client,
+        mint,
+        withholding_rate_args(),
+        &successor,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // A late heartbeat does not take the authority back
+    let result = execute_heartbeat(&context.banks_client, mint, &mint_creator).await;
+    assert_security_token_error(result, SecurityTokenProgramError::SuccessionClaimed);
+}
+
+#[tokio::test]
+async fn test_heartbeat_should_restart_heartbeat_period() {
+    let successor = Keypair::new();
+    let (mut context, mint, mint_creator, created_at) = setup(&successor).await;
+
+    warp_to_timestamp(&mut context, created_at + HEARTBEAT_PERIOD / 2).await;
+    let result = execute_heartbeat(&context.banks_client, mint, &mint_creator).await;
+    assert_transaction_success(result);
+
+    warp_to_timestamp(&mut context, created_at + HEARTBEAT_PERIOD + 1).await;
+    let result = execute_claim_succession(&context.banks_client, mint, &successor).await;
+    assert_security_token_error(result, SecurityTokenProgramError::SuccessionNotClaimable);
+
+    // The mint creator revokes the successor
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let result =
+        execute_close_succession_policy(&context.banks_client, mint, mint_authority, &mint_creator)
+            .await;
+    assert_transaction_success(result);
+    assert_account_exists(&mut context, find_succession_policy_pda(&mint).0, false).await;
+}
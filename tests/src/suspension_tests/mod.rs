@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod suspension_tests;
+
+pub mod suspension_helpers;
@@ -0,0 +1,61 @@
+use security_token_client::{
+    instructions::{Suspend, Unsuspend},
+    pda::{find_agent_pda, find_suspension_pda},
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::helpers::send_tx;
+
+/// Build and send Suspend instruction signed by an agent, the agent pays for the Suspension account
+pub async fn execute_suspend_by_agent(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    token_account: Pubkey,
+    agent: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (agent_account, _) = find_agent_pda(&mint, &agent.pubkey());
+    let (suspension_account, _) = find_suspension_pda(&token_account);
+
+    let mut ix = Suspend {
+        mint,
+        verification_config: agent_account,
+        instructions_sysvar: agent.pubkey(),
+        payer: agent.pubkey(),
+        mint_account: mint,
+        token_account,
+        suspension_account,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction();
+    // Agent signs in place of the instructions sysvar
+    ix.accounts[2].is_signer = true;
+
+    send_tx(banks_client, vec![ix], &agent.pubkey(), vec![agent]).await
+}
+
+/// Build and send Unsuspend instruction signed by an agent, rent returns to the agent
+pub async fn execute_unsuspend_by_agent(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    token_account: Pubkey,
+    agent: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (agent_account, _) = find_agent_pda(&mint, &agent.pubkey());
+    let (suspension_account, _) = find_suspension_pda(&token_account);
+
+    let mut ix = Unsuspend {
+        mint,
+        verification_config: agent_account,
+        instructions_sysvar: agent.pubkey(),
+        mint_account: mint,
+        suspension_account,
+        rent_destination: agent.pubkey(),
+    }
+    .instruction();
+    // Agent signs in place of the instructions sysvar
+    ix.accounts[2].is_signer = true;
+
+    send_tx(banks_client, vec![ix], &agent.pubkey(), vec![agent]).await
+}
@@ -0,0 +1,159 @@
+use security_token_client::{
+    accounts::Suspension,
+    agent::{FREEZE, SUSPEND},
+    errors::SecurityTokenProgramError,
+    pda::find_suspension_pda,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::state::AccountState;
+
+use crate::{
+    agent_tests::agent_helpers::execute_create_agent_account,
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_spl_account, get_token_account_state,
+        setup_transfer_verified_mint, start_with_transfer_hook,
+    },
+    position_limit_tests::position_limit_helpers::transfer,
+    suspension_tests::suspension_helpers::{execute_suspend_by_agent, execute_unsuspend_by_agent},
+};
+
+const SUPPLY: u64 = 1_000_000;
+
+/// Create mint with transfer verification config, mint supply to `source_owner`
+/// and register `agent` with `permissions`
+async fn setup_mint(
+    context: &mut ProgramTestContext,
+    source_owner: &Keypair,
+    agent: &Keypair,
+    permissions: u8,
+) -> (Keypair, Pubkey) {
+    let mint_creator = context.payer.insecure_clone();
+    let (mint_keypair, _, source_account) =
+        setup_transfer_verified_mint(context, source_owner, SUPPLY).await;
+
+    let result = execute_create_agent_account(
+        &context.banks_client,
+        mint_keypair.pubkey(),
+        agent.pubkey(),
+        permissions,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    (mint_keypair, source_account)
+}
+
+#[tokio::test]
+async fn test_suspended_account_should_not_send_or_receive_until_unsuspended() {
+    let agent = Keypair::new();
+    let context = &mut start_with_transfer_hook(&[&agent]).await;
+    let source_owner = Keypair::new();
+    let (mint_keypair, source_account) = setup_mint(context, &source_owner, &agent, SUSPEND).await;
+    let mint = mint_keypair.pubkey();
+    let investor = Keypair::new();
+    let investor_account = create_spl_account(context, &mint_keypair, &investor).await;
+
+    let result = transfer(
+        context,
+        mint,
+        source_account,
+        investor_account,
+        &source_owner,
+        1_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result =
+        execute_suspend_by_agent(&context.banks_client, mint, investor_account, &agent).await;
+    assert_transaction_success(result);
+
+    let (suspension_pda, _) = find_suspension_pda(&investor_account);
+    let account = assert_account_exists(context, suspension_pda, true)
+        .await
+        .unwrap();
+    let suspension = Suspension::from_bytes(&account.data).unwrap();
+    assert_eq!(suspension.mint, mint);
+    assert_eq!(suspension.token_account, investor_account);
+    assert_eq!(suspension.payer, agent.pubkey());
+
+    // Suspension does not touch the Token-2022 freeze state
+    let token_account_state =
+        get_token_account_state(&mut context.banks_client, investor_account).await;
+    assert_eq!(token_account_state.base.state, AccountState::Initialized);
+
+    let result = transfer(
+        context,
+        mint,
+        investor_account,
+        source_account,
+        &investor,
+        1,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::AccountSuspended);
+
+    let result = transfer(
+        context,
+        mint,
+        source_account,
+        investor_account,
+        &source_owner,
+        1,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::AccountSuspended);
+
+    let result =
+        execute_unsuspend_by_agent(&context.banks_client, mint, investor_account, &agent).await;
+    assert_transaction_success(result);
+    assert_account_exists(context, suspension_pda, false).await;
+
+    let result = transfer(
+        context,
+        mint,
+        investor_account,
+        source_account,
+        &investor,
+        1_000,
+    )
+    .await;
+    assert_transaction_success(result);
+}
+
+#[tokio::test]
+async fn test_agent_without_suspend_scope_should_not_suspend() {
+    let agent = Keypair::new();
+    let context = &mut start_with_transfer_hook(&[&agent]).await;
+    let source_owner = Keypair::new();
+    let (mint_keypair, source_account) = setup_mint(context, &source_owner, &agent, FREEZE).await;
+    let mint = mint_keypair.pubkey();
+
+    let result =
+        execute_suspend_by_agent(&context.banks_client, mint, source_account, &agent).await;
+    assert_security_token_error(result, SecurityTokenProgramError::AgentPermissionDenied);
+
+    let (suspension_pda, _) = find_suspension_pda(&source_account);
+    assert_account_exists(context, suspension_pda, false).await;
+}
+
+#[tokio::test]
+async fn test_should_not_unsuspend_account_without_suspension() {
+    let agent = Keypair::new();
+    let context = &mut start_with_transfer_hook(&[&agent]).await;
+    let source_owner = Keypair::new();
+    let (mint_keypair, source_account) = setup_mint(context, &source_owner, &agent, SUSPEND).await;
+
+    let result = execute_unsuspend_by_agent(
+        &context.banks_client,
+        mint_keypair.pubkey(),
+        source_account,
+        &agent,
+    )
+    .await;
+    assert_transaction_failure(result);
+}
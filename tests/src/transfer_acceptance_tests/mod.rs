@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod transfer_acceptance_tests;
+
+pub mod transfer_acceptance_helpers;
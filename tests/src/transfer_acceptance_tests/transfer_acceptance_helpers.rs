@@ -0,0 +1,256 @@
+use security_token_client::{
+    instructions::{
+        AcceptTransfer, CancelTransfer, CloseTransferAcceptance, CreateTransferAcceptance,
+        OfferTransfer, OfferTransferInstructionArgs, TRANSFER_DISCRIMINATOR,
+    },
+    pda::{
+        find_pending_transfer_pda, find_suspension_pda, find_transfer_acceptance_pda,
+        find_transfer_escrow_authority_pda,
+    },
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    sysvar,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id, ID as ASSOCIATED_TOKEN_PROGRAM_ID,
+};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+use spl_transfer_hook_interface::get_extra_account_metas_address;
+
+use crate::helpers::{
+    create_dummy_verification_from_instruction, find_mint_authority_pda,
+    find_permanent_delegate_pda, find_transfer_hook_pda, find_verification_config_pda, send_tx,
+};
+
+/// Token-2022 escrow token account holding pending transfers of `mint`
+pub fn find_transfer_escrow_token_account(mint: &Pubkey) -> Pubkey {
+    let (escrow_authority, _) = find_transfer_escrow_authority_pda(mint);
+    get_associated_token_address_with_program_id(&escrow_authority, mint, &TOKEN_22_PROGRAM_ID)
+}
+
+/// Build and send CreateTransferAcceptance instruction authorized by mint authority
+pub async fn execute_create_transfer_acceptance(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (transfer_acceptance_account, _) = find_transfer_acceptance_pda(&mint);
+    let (transfer_verification_config, _) =
+        find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+    let (escrow_authority, _) = find_transfer_escrow_authority_pda(&mint);
+    let (transfer_hook_pda, _) = find_transfer_hook_pda(&mint);
+
+    let ix = CreateTransferAcceptance {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        transfer_acceptance_account,
+        transfer_verification_config,
+        escrow_authority,
+        escrow_token_account: find_transfer_escrow_token_account(&mint),
+        system_program: solana_program::system_program::id(),
+        token_program: TOKEN_22_PROGRAM_ID,
+        associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+        account_metas_pda: get_extra_account_metas_address(&mint, &transfer_hook_program),
+        transfer_hook_pda,
+        transfer_hook_program,
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send CloseTransferAcceptance instruction authorized by mint authority
+pub async fn execute_close_transfer_acceptance(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (transfer_acceptance_account, _) = find_transfer_acceptance_pda(&mint);
+    let (transfer_verification_config, _) =
+        find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+    let (transfer_hook_pda, _) = find_transfer_hook_pda(&mint);
+
+    let ix = CloseTransferAcceptance {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        transfer_acceptance_account,
+        transfer_verification_config,
+        rent_recipient: mint_creator.pubkey(),
+        system_program: solana_program::system_program::id(),
+        account_metas_pda: get_extra_account_metas_address(&mint, &transfer_hook_program),
+        transfer_hook_pda,
+        transfer_hook_program,
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send OfferTransfer instruction, `owner` pays for the pending transfer account
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_offer_transfer(
+    banks_client: &BanksClient,
+    verification_config: Pubkey,
+    mint: Pubkey,
+    source_token_account: Pubkey,
+    destination_token_account: Pubkey,
+    owner: &Keypair,
+    transfer_id: u64,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let (pending_transfer_account, _) = find_pending_transfer_pda(
+        &source_token_account,
+        &destination_token_account,
+        transfer_id,
+    );
+    let (permanent_delegate, _) = find_permanent_delegate_pda(&mint);
+
+    let ix = OfferTransfer {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        payer: owner.pubkey(),
+        owner: owner.pubkey(),
+        mint_account: mint,
+        source_token_account,
+        destination_token_account,
+        pending_transfer_account,
+        escrow_token_account: find_transfer_escrow_token_account(&mint),
+        permanent_delegate,
+        transfer_hook_program: Pubkey::from(security_token_transfer_hook::id()),
+        token_program: TOKEN_22_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+        source_suspension: find_suspension_pda(&source_token_account).0,
+        destination_suspension: find_suspension_pda(&destination_token_account).0,
+    }
+    .instruction(OfferTransferInstructionArgs {
+        transfer_id,
+        amount,
+    });
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &owner.pubkey(),
+        vec![owner],
+    )
+    .await
+}
+
+/// Build and send AcceptTransfer instruction signed by the destination owner
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_accept_transfer(
+    banks_client: &BanksClient,
+    verification_config: Pubkey,
+    mint: Pubkey,
+    source_token_account: Pubkey,
+    destination_token_account: Pubkey,
+    owner: &Keypair,
+    rent_destination: Pubkey,
+    transfer_id: u64,
+) -> Result<(), BanksClientError> {
+    let (pending_transfer_account, _) = find_pending_transfer_pda(
+        &source_token_account,
+        &destination_token_account,
+        transfer_id,
+    );
+    let (permanent_delegate, _) = find_permanent_delegate_pda(&mint);
+
+    let ix = AcceptTransfer {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        owner: owner.pubkey(),
+        mint_account: mint,
+        pending_transfer_account,
+        destination_token_account,
+        escrow_token_account: find_transfer_escrow_token_account(&mint),
+        rent_destination,
+        permanent_delegate,
+        transfer_hook_program: Pubkey::from(security_token_transfer_hook::id()),
+        token_program: TOKEN_22_PROGRAM_ID,
+        destination_suspension: find_suspension_pda(&destination_token_account).0,
+    }
+    .instruction();
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &owner.pubkey(),
+        vec![owner],
+    )
+    .await
+}
+
+/// Build and send CancelTransfer instruction signed by the source or destination owner
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_cancel_transfer(
+    banks_client: &BanksClient,
+    verification_config: Pubkey,
+    mint: Pubkey,
+    source_token_account: Pubkey,
+    destination_token_account: Pubkey,
+    authority: &Keypair,
+    rent_destination: Pubkey,
+    transfer_id: u64,
+) -> Result<(), BanksClientError> {
+    let (pending_transfer_account, _) = find_pending_transfer_pda(
+        &source_token_account,
+        &destination_token_account,
+        transfer_id,
+    );
+    let (permanent_delegate, _) = find_permanent_delegate_pda(&mint);
+
+    let ix = CancelTransfer {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        authority: authority.pubkey(),
+        mint_account: mint,
+        pending_transfer_account,
+        source_token_account,
+        destination_token_account,
+        escrow_token_account: find_transfer_escrow_token_account(&mint),
+        rent_destination,
+        permanent_delegate,
+        transfer_hook_program: Pubkey::from(security_token_transfer_hook::id()),
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction();
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &authority.pubkey(),
+        vec![authority],
+    )
+    .await
+}
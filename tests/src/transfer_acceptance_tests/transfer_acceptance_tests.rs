@@ -0,0 +1,268 @@
+use security_token_client::{
+    accounts::{PendingTransfer, TransferAcceptance},
+    errors::SecurityTokenProgramError,
+    instructions::{
+        ACCEPT_TRANSFER_DISCRIMINATOR, CANCEL_TRANSFER_DISCRIMINATOR, OFFER_TRANSFER_DISCRIMINATOR,
+        TRANSFER_DISCRIMINATOR,
+    },
+    pda::{find_pending_transfer_pda, find_transfer_acceptance_pda},
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        create_verification_config, get_default_verification_programs, get_token_account_state,
+        initialize_mint_verification_and_mint_to_account, start_with_transfer_hook,
+    },
+    position_limit_tests::position_limit_helpers::transfer,
+    transfer_acceptance_tests::transfer_acceptance_helpers::{
+        execute_accept_transfer, execute_cancel_transfer, execute_close_transfer_acceptance,
+        execute_create_transfer_acceptance, execute_offer_transfer,
+        find_transfer_escrow_token_account,
+    },
+};
+
+const SUPPLY: u64 = 1_000_000;
+
+struct Setup {
+    mint_keypair: Keypair,
+    source_account: Pubkey,
+    destination_account: Pubkey,
+    offer_verification_config: Pubkey,
+    accept_verification_config: Pubkey,
+    cancel_verification_config: Pubkey,
+}
+
+/// Create mint with transfer, offer, accept and cancel verification configs
+/// and mint the whole supply to `source_owner`
+async fn setup_mint(
+    context: &mut ProgramTestContext,
+    source_owner: &Keypair,
+    destination_owner: &Keypair,
+) -> Setup {
+    let mint_keypair = Keypair::new();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, None, 6).await;
+    let mut verification_configs = vec![];
+    for discriminator in [
+        TRANSFER_DISCRIMINATOR,
+        OFFER_TRANSFER_DISCRIMINATOR,
+        ACCEPT_TRANSFER_DISCRIMINATOR,
+        CANCEL_TRANSFER_DISCRIMINATOR,
+    ] {
+        let verification_config = create_verification_config(
+            context,
+            &mint_keypair,
+            mint_authority_pda,
+            discriminator,
+            get_default_verification_programs(),
+            None,
+        )
+        .await;
+        verification_configs.push(verification_config);
+    }
+    let source_account = create_spl_account(context, &mint_keypair, source_owner).await;
+    let destination_account = create_spl_account(context, &mint_keypair, destination_owner).await;
+    initialize_mint_verification_and_mint_to_account(
+        &mint_keypair,
+        context,
+        mint_authority_pda,
+        source_account,
+        SUPPLY,
+    )
+    .await;
+    Setup {
+        mint_keypair,
+        source_account,
+        destination_account,
+        offer_verification_config: verification_configs[1],
+        accept_verification_config: verification_configs[2],
+        cancel_verification_config: verification_configs[3],
+    }
+}
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    get_token_account_state(&mut context.banks_client, token_account)
+        .await
+        .base
+        .amount
+}
+
+#[tokio::test]
+async fn test_should_hold_transfer_until_accepted() {
+    let source_owner = Keypair::new();
+    let destination_owner = Keypair::new();
+    let context = &mut start_with_transfer_hook(&[&source_owner, &destination_owner]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let setup = setup_mint(context, &source_owner, &destination_owner).await;
+    let mint = setup.mint_keypair.pubkey();
+
+    let result =
+        execute_create_transfer_acceptance(&context.banks_client, mint, &mint_creator).await;
+    assert_transaction_success(result);
+    let (transfer_acceptance_pda, _) = find_transfer_acceptance_pda(&mint);
+    let account = assert_account_exists(context, transfer_acceptance_pda, true)
+        .await
+        .unwrap();
+    let transfer_acceptance = TransferAcceptance::from_bytes(&account.data).unwrap();
+    assert_eq!(transfer_acceptance.mint, mint);
+
+    // Direct transfers are rejected while the mode is enabled
+    let result = transfer(
+        context,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &source_owner,
+        100_000,
+    )
+    .await;
+    assert_security_token_error(
+        result,
+        SecurityTokenProgramError::TransferAcceptanceRequired,
+    );
+
+    let result = execute_offer_transfer(
+        &context.banks_client,
+        setup.offer_verification_config,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &source_owner,
+        1,
+        100_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let escrow_token_account = find_transfer_escrow_token_account(&mint);
+    let (pending_transfer_pda, _) =
+        find_pending_transfer_pda(&setup.source_account, &setup.destination_account, 1);
+    let account = assert_account_exists(context, pending_transfer_pda, true)
+        .await
+        .unwrap();
+    let pending_transfer = PendingTransfer::from_bytes(&account.data).unwrap();
+    assert_eq!(pending_transfer.amount, 100_000);
+    assert_eq!(pending_transfer.payer, source_owner.pubkey());
+    assert_eq!(token_amount(context, escrow_token_account).await, 100_000);
+    assert_eq!(token_amount(context, setup.destination_account).await, 0);
+
+    // Only the destination owner can accept
+    let result = execute_accept_transfer(
+        &context.banks_client,
+        setup.accept_verification_config,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &source_owner,
+        source_owner.pubkey(),
+        1,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let result = execute_accept_transfer(
+        &context.banks_client,
+        setup.accept_verification_config,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &destination_owner,
+        source_owner.pubkey(),
+        1,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_account_exists(context, pending_transfer_pda, false).await;
+    assert_eq!(token_amount(context, escrow_token_account).await, 0);
+    assert_eq!(
+        token_amount(context, setup.destination_account).await,
+        100_000
+    );
+}
+
+#[tokio::test]
+async fn test_should_return_cancelled_transfer() {
+    let source_owner = Keypair::new();
+    let destination_owner = Keypair::new();
+    let context = &mut start_with_transfer_hook(&[&source_owner, &destination_owner]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let setup = setup_mint(context, &source_owner, &destination_owner).await;
+    let mint = setup.mint_keypair.pubkey();
+
+    let result =
+        execute_create_transfer_acceptance(&context.banks_client, mint, &mint_creator).await;
+    assert_transaction_success(result);
+
+    for transfer_id in [1, 2] {
+        let result = execute_offer_transfer(
+            &context.banks_client,
+            setup.offer_verification_config,
+            mint,
+            setup.source_account,
+            setup.destination_account,
+            &source_owner,
+            transfer_id,
+            50_000,
+        )
+        .await;
+        assert_transaction_success(result);
+    }
+    assert_eq!(
+        token_amount(context, setup.source_account).await,
+        SUPPLY - 100_000
+    );
+
+    // Sender withdraws the first offer, recipient declines the second one
+    for (transfer_id, authority) in [(1, &source_owner), (2, &destination_owner)] {
+        let result = execute_cancel_transfer(
+            &context.banks_client,
+            setup.cancel_verification_config,
+            mint,
+            setup.source_account,
+            setup.destination_account,
+            authority,
+            source_owner.pubkey(),
+            transfer_id,
+        )
+        .await;
+        assert_transaction_success(result);
+    }
+    assert_eq!(token_amount(context, setup.source_account).await, SUPPLY);
+    assert_eq!(token_amount(context, setup.destination_account).await, 0);
+
+    let result = execute_cancel_transfer(
+        &context.banks_client,
+        setup.cancel_verification_config,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &source_owner,
+        source_owner.pubkey(),
+        1,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    // Direct transfers are allowed again once the mode is disabled
+    let result =
+        execute_close_transfer_acceptance(&context.banks_client, mint, &mint_creator).await;
+    assert_transaction_success(result);
+    let (transfer_acceptance_pda, _) = find_transfer_acceptance_pda(&mint);
+    assert_account_exists(context, transfer_acceptance_pda, false).await;
+
+    let result = transfer(
+        context,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &source_owner,
+        100_000,
+    )
+    .await;
+    assert_transaction_success(result);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod transfer_approval_tests;
+
+pub mod transfer_approval_helpers;
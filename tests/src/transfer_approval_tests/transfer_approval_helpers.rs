@@ -0,0 +1,266 @@
+use security_token_client::{
+    instructions::{
+        ApproveTransfer, CloseTransferApproval, CreateTransferApproval,
+        CreateTransferApprovalInstructionArgs, RejectTransfer, RequestTransfer,
+        RequestTransferInstructionArgs, UpdateTransferApproval,
+        UpdateTransferApprovalInstructionArgs, TRANSFER_DISCRIMINATOR,
+    },
+    pda::{
+        find_suspension_pda, find_transfer_approval_pda, find_transfer_escrow_authority_pda,
+        find_transfer_request_pda,
+    },
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    sysvar,
+};
+use spl_associated_token_account::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+use spl_transfer_hook_interface::get_extra_account_metas_address;
+
+use crate::{
+    helpers::{
+        create_dummy_verification_from_instruction, find_mint_authority_pda,
+        find_permanent_delegate_pda, find_transfer_hook_pda, find_verification_config_pda, send_tx,
+    },
+    transfer_acceptance_tests::transfer_acceptance_helpers::find_transfer_escrow_token_account,
+};
+
+/// Build and send CreateTransferApproval instruction authorized by mint authority
+pub async fn execute_create_transfer_approval(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    threshold: u64,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (transfer_approval_account, _) = find_transfer_approval_pda(&mint);
+    let (transfer_verification_config, _) =
+        find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+    let (escrow_authority, _) = find_transfer_escrow_authority_pda(&mint);
+    let (transfer_hook_pda, _) = find_transfer_hook_pda(&mint);
+
+    let ix = CreateTransferApproval {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        transfer_approval_account,
+        transfer_verification_config,
+        escrow_authority,
+        escrow_token_account: find_transfer_escrow_token_account(&mint),
+        system_program: solana_program::system_program::id(),
+        token_program: TOKEN_22_PROGRAM_ID,
+        associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+        account_metas_pda: get_extra_account_metas_address(&mint, &transfer_hook_program),
+        transfer_hook_pda,
+        transfer_hook_program,
+    }
+    .instruction(CreateTransferApprovalInstructionArgs { threshold });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send UpdateTransferApproval instruction authorized by mint authority
+pub async fn execute_update_transfer_approval(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    threshold: u64,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (transfer_approval_account, _) = find_transfer_approval_pda(&mint);
+
+    let ix = UpdateTransferApproval {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        mint_account: mint,
+        transfer_approval_account,
+    }
+    .instruction(UpdateTransferApprovalInstructionArgs { threshold });
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send CloseTransferApproval instruction authorized by mint authority
+pub async fn execute_close_transfer_approval(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (transfer_approval_account, _) = find_transfer_approval_pda(&mint);
+    let (transfer_verification_config, _) =
+        find_verification_config_pda(mint, TRANSFER_DISCRIMINATOR);
+    let (transfer_hook_pda, _) = find_transfer_hook_pda(&mint);
+
+    let ix = CloseTransferApproval {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        transfer_approval_account,
+        transfer_verification_config,
+        rent_recipient: mint_creator.pubkey(),
+        system_program: solana_program::system_program::id(),
+        account_metas_pda: get_extra_account_metas_address(&mint, &transfer_hook_program),
+        transfer_hook_pda,
+        transfer_hook_program,
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send RequestTransfer instruction, `owner` pays for the transfer request account
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_request_transfer(
+    banks_client: &BanksClient,
+    verification_config: Pubkey,
+    mint: Pubkey,
+    source_token_account: Pubkey,
+    destination_token_account: Pubkey,
+    owner: &Keypair,
+    request_id: u64,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let (transfer_request_account, _) = find_transfer_request_pda(
+        &source_token_account,
+        &destination_token_account,
+        request_id,
+    );
+    let (permanent_delegate, _) = find_permanent_delegate_pda(&mint);
+
+    let ix = RequestTransfer {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        payer: owner.pubkey(),
+        owner: owner.pubkey(),
+        mint_account: mint,
+        source_token_account,
+        destination_token_account,
+        transfer_request_account,
+        escrow_token_account: find_transfer_escrow_token_account(&mint),
+        permanent_delegate,
+        transfer_hook_program: Pubkey::from(security_token_transfer_hook::id()),
+        token_program: TOKEN_22_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+        source_suspension: find_suspension_pda(&source_token_account).0,
+        destination_suspension: find_suspension_pda(&destination_token_account).0,
+    }
+    .instruction(RequestTransferInstructionArgs { request_id, amount });
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &owner.pubkey(),
+        vec![owner],
+    )
+    .await
+}
+
+/// Build and send ApproveTransfer instruction signed by `reviewer`,
+/// authorized through `authority_account` (mint authority or agent PDA)
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_approve_transfer(
+    banks_client: &BanksClient,
+    authority_account: Pubkey,
+    reviewer: &Keypair,
+    mint: Pubkey,
+    source_token_account: Pubkey,
+    destination_token_account: Pubkey,
+    rent_destination: Pubkey,
+    request_id: u64,
+) -> Result<(), BanksClientError> {
+    let (transfer_request_account, _) = find_transfer_request_pda(
+        &source_token_account,
+        &destination_token_account,
+        request_id,
+    );
+    let (permanent_delegate, _) = find_permanent_delegate_pda(&mint);
+
+    let mut ix = ApproveTransfer {
+        mint,
+        verification_config_or_mint_authority: authority_account,
+        instructions_sysvar_or_creator: reviewer.pubkey(),
+        mint_account: mint,
+        transfer_request_account,
+        destination_token_account,
+        escrow_token_account: find_transfer_escrow_token_account(&mint),
+        rent_destination,
+        permanent_delegate,
+        transfer_hook_program: Pubkey::from(security_token_transfer_hook::id()),
+        token_program: TOKEN_22_PROGRAM_ID,
+        destination_suspension: find_suspension_pda(&destination_token_account).0,
+    }
+    .instruction();
+    ix.accounts[2].is_signer = true;
+
+    send_tx(banks_client, vec![ix], &reviewer.pubkey(), vec![reviewer]).await
+}
+
+/// Build and send RejectTransfer instruction signed by `reviewer`,
+/// authorized through `authority_account` (mint authority or agent PDA)
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_reject_transfer(
+    banks_client: &BanksClient,
+    authority_account: Pubkey,
+    reviewer: &Keypair,
+    mint: Pubkey,
+    source_token_account: Pubkey,
+    destination_token_account: Pubkey,
+    rent_destination: Pubkey,
+    request_id: u64,
+) -> Result<(), BanksClientError> {
+    let (transfer_request_account, _) = find_transfer_request_pda(
+        &source_token_account,
+        &destination_token_account,
+        request_id,
+    );
+    let (permanent_delegate, _) = find_permanent_delegate_pda(&mint);
+
+    let mut ix = RejectTransfer {
+        mint,
+        verification_config_or_mint_authority: authority_account,
+        instructions_sysvar_or_creator: reviewer.pubkey(),
+        mint_account: mint,
+        transfer_request_account,
+        source_token_account,
+        escrow_token_account: find_transfer_escrow_token_account(&mint),
+        rent_destination,
+        permanent_delegate,
+        transfer_hook_program: Pubkey::from(security_token_transfer_hook::id()),
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction();
+    ix.accounts[2].is_signer = true;
+
+    send_tx(banks_client, vec![ix], &reviewer.pubkey(), vec![reviewer]).await
+}
@@ -0,0 +1,312 @@
+use security_token_client::{
+    accounts::{TransferApproval, TransferRequest},
+    agent::TRANSFER_APPROVAL,
+    errors::SecurityTokenProgramError,
+    instructions::{REQUEST_TRANSFER_DISCRIMINATOR, TRANSFER_DISCRIMINATOR},
+    pda::{find_agent_pda, find_transfer_approval_pda, find_transfer_request_pda},
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    agent_tests::agent_helpers::execute_create_agent_account,
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        create_verification_config, find_mint_authority_pda, get_default_verification_programs,
+        get_token_account_state, initialize_mint_verification_and_mint_to_account,
+        start_with_transfer_hook,
+    },
+    position_limit_tests::position_limit_helpers::transfer,
+    transfer_acceptance_tests::transfer_acceptance_helpers::find_transfer_escrow_token_account,
+    transfer_approval_tests::transfer_approval_helpers::{
+        execute_approve_transfer, execute_close_transfer_approval,
+        execute_create_transfer_approval, execute_reject_transfer, execute_request_transfer,
+        execute_update_transfer_approval,
+    },
+};
+
+const SUPPLY: u64 = 1_000_000;
+const THRESHOLD: u64 = 100_000;
+
+struct Setup {
+    mint_keypair: Keypair,
+    source_account: Pubkey,
+    destination_account: Pubkey,
+    request_verification_config: Pubkey,
+}
+
+/// Create mint with transfer and request verification configs, a transfer approval
+/// and mint the whole supply to `source_owner`
+async fn setup_mint(
+    context: &mut ProgramTestContext,
+    source_owner: &Keypair,
+    destination_owner: &Keypair,
+) -> Setup {
+    let mint_creator = context.payer.insecure_clone();
+    let mint_keypair = Keypair::new();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, None, 6).await;
+    create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        TRANSFER_DISCRIMINATOR,
+        get_default_verification_programs(),
+        None,
+    )
+    .await;
+    let request_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        REQUEST_TRANSFER_DISCRIMINATOR,
+        get_default_verification_programs(),
+        None,
+    )
+    .await;
+    let source_account = create_spl_account(context, &mint_keypair, source_owner).await;
+    let destination_account = create_spl_account(context, &mint_keypair, destination_owner).await;
+    initialize_mint_verification_and_mint_to_account(
+        &mint_keypair,
+        context,
+        mint_authority_pda,
+        source_account,
+        SUPPLY,
+    )
+    .await;
+
+    let result = execute_create_transfer_approval(
+        &context.banks_client,
+        mint_keypair.pubkey(),
+        THRESHOLD,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    Setup {
+        mint_keypair,
+        source_account,
+        destination_account,
+        request_verification_config,
+    }
+}
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    get_token_account_state(&mut context.banks_client, token_account)
+        .await
+        .base
+        .amount
+}
+
+#[tokio::test]
+async fn test_should_queue_transfers_above_threshold() {
+    let source_owner = Keypair::new();
+    let destination_owner = Keypair::new();
+    let context = &mut start_with_transfer_hook(&[&source_owner, &destination_owner]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let setup = setup_mint(context, &source_owner, &destination_owner).await;
+    let mint = setup.mint_keypair.pubkey();
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+
+    let result = transfer(
+        context,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &source_owner,
+        THRESHOLD,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = transfer(
+        context,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &source_owner,
+        THRESHOLD + 1,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::TransferApprovalRequired);
+
+    for request_id in [1, 2] {
+        let result = execute_request_transfer(
+            &context.banks_client,
+            setup.request_verification_config,
+            mint,
+            setup.source_account,
+            setup.destination_account,
+            &source_owner,
+            request_id,
+            200_000,
+        )
+        .await;
+        assert_transaction_success(result);
+    }
+
+    let escrow_token_account = find_transfer_escrow_token_account(&mint);
+    let (transfer_request_pda, _) =
+        find_transfer_request_pda(&setup.source_account, &setup.destination_account, 1);
+    let account = assert_account_exists(context, transfer_request_pda, true)
+        .await
+        .unwrap();
+    let transfer_request = TransferRequest::from_bytes(&account.data).unwrap();
+    assert_eq!(transfer_request.amount, 200_000);
+    assert_eq!(transfer_request.payer, source_owner.pubkey());
+    assert_eq!(token_amount(context, escrow_token_account).await, 400_000);
+
+    let result = execute_approve_transfer(
+        &context.banks_client,
+        mint_authority,
+        &mint_creator,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        source_owner.pubkey(),
+        1,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_account_exists(context, transfer_request_pda, false).await;
+    assert_eq!(
+        token_amount(context, setup.destination_account).await,
+        THRESHOLD + 200_000
+    );
+
+    let result = execute_reject_transfer(
+        &context.banks_client,
+        mint_authority,
+        &mint_creator,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        source_owner.pubkey(),
+        2,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(token_amount(context, escrow_token_account).await, 0);
+    assert_eq!(
+        token_amount(context, setup.source_account).await,
+        SUPPLY - THRESHOLD - 200_000
+    );
+}
+
+#[tokio::test]
+async fn test_should_approve_by_compliance_agent() {
+    let source_owner = Keypair::new();
+    let destination_owner = Keypair::new();
+    let compliance_officer = Keypair::new();
+    let context =
+        &mut start_with_transfer_hook(&[&source_owner, &destination_owner, &compliance_officer])
+            .await;
+    let mint_creator = context.payer.insecure_clone();
+    let setup = setup_mint(context, &source_owner, &destination_owner).await;
+    let mint = setup.mint_keypair.pubkey();
+    let (agent_account, _) = find_agent_pda(&mint, &compliance_officer.pubkey());
+
+    let result = execute_request_transfer(
+        &context.banks_client,
+        setup.request_verification_config,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &source_owner,
+        1,
+        200_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // Requesters cannot approve their own transfers
+    let result = execute_approve_transfer(
+        &context.banks_client,
+        agent_account,
+        &source_owner,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        source_owner.pubkey(),
+        1,
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let result = execute_create_agent_account(
+        &context.banks_client,
+        mint,
+        compliance_officer.pubkey(),
+        TRANSFER_APPROVAL,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_approve_transfer(
+        &context.banks_client,
+        agent_account,
+        &compliance_officer,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        source_owner.pubkey(),
+        1,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        token_amount(context, setup.destination_account).await,
+        200_000
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_approval_lifecycle() {
+    let source_owner = Keypair::new();
+    let destination_owner = Keypair::new();
+    let context = &mut start_with_transfer_hook(&[&source_owner, &destination_owner]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let setup = setup_mint(context, &source_owner, &destination_owner).await;
+    let mint = setup.mint_keypair.pubkey();
+
+    let (transfer_approval_pda, _) = find_transfer_approval_pda(&mint);
+    let result =
+        execute_update_transfer_approval(&context.banks_client, mint, 500_000, &mint_creator).await;
+    assert_transaction_success(result);
+    let account = assert_account_exists(context, transfer_approval_pda, true)
+        .await
+        .unwrap();
+    let transfer_approval = TransferApproval::from_bytes(&account.data).unwrap();
+    assert_eq!(transfer_approval.mint, mint);
+    assert_eq!(transfer_approval.threshold, 500_000);
+
+    let result = transfer(
+        context,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &source_owner,
+        500_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_close_transfer_approval(&context.banks_client, mint, &mint_creator).await;
+    assert_transaction_success(result);
+    assert_account_exists(context, transfer_approval_pda, false).await;
+
+    let result = transfer(
+        context,
+        mint,
+        setup.source_account,
+        setup.destination_account,
+        &source_owner,
+        500_000,
+    )
+    .await;
+    assert_transaction_success(result);
+}
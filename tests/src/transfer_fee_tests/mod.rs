@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod transfer_fee_tests;
+
+pub mod transfer_fee_helpers;
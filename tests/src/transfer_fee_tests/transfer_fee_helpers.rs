@@ -0,0 +1,154 @@
+use security_token_client::{
+    instructions::{
+        HarvestWithheldTokensToMint, SetTransferFee, SetTransferFeeInstructionArgs,
+        WithdrawWithheldTokensFromAccounts,
+    },
+    pda::find_transfer_fee_authority_pda,
+    types::{InitializeMintArgs, MintArgs, TransferFeeConfigArgs},
+};
+use solana_program_test::{BanksClient, BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::{instruction::AccountMeta, signature::Keypair, signer::Signer, sysvar};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{
+    create_dummy_verification_from_instruction, find_mint_authority_pda,
+    find_mint_freeze_authority_pda, initialize_mint_for_creator, send_tx,
+};
+
+/// Create a security token mint withholding `transfer_fee` on transfers
+pub async fn create_transfer_fee_security_token_mint(
+    context: &mut ProgramTestContext,
+    mint_keypair: &Keypair,
+    decimals: u8,
+    transfer_fee: TransferFeeConfigArgs,
+) -> Pubkey {
+    let mint_creator = context.payer.insecure_clone();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (freeze_authority_pda, _) = find_mint_freeze_authority_pda(&mint);
+
+    let mint_args = InitializeMintArgs {
+        ix_mint: MintArgs {
+            decimals,
+            mint_authority: mint_creator.pubkey(),
+            freeze_authority: freeze_authority_pda,
+        },
+        ix_metadata_pointer: None,
+        ix_metadata: None,
+        ix_scaled_ui_amount: None,
+        ix_transfer_fee: Some(transfer_fee),
+        ix_default_account_state: None,
+    };
+
+    initialize_mint_for_creator(
+        context,
+        mint_keypair,
+        mint_authority_pda,
+        &mint_creator,
+        &mint_args,
+    )
+    .await;
+
+    mint_authority_pda
+}
+
+/// Build and send SetTransferFee
+pub async fn execute_set_transfer_fee(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    verification_config: Pubkey,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let ix = SetTransferFee {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        transfer_fee_authority: find_transfer_fee_authority_pda(&mint).0,
+        mint_account: mint,
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction(SetTransferFeeInstructionArgs {
+        transfer_fee_basis_points,
+        maximum_fee,
+    });
+
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
+
+/// Build and send WithdrawWithheldTokensFromAccounts draining `sources` into `destination_account`
+pub async fn execute_withdraw_withheld_tokens_from_accounts(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    verification_config: Pubkey,
+    destination_account: Pubkey,
+    sources: &[Pubkey],
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let source_metas: Vec<AccountMeta> = sources
+        .iter()
+        .map(|source| AccountMeta::new(*source, false))
+        .collect();
+    let ix = WithdrawWithheldTokensFromAccounts {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        transfer_fee_authority: find_transfer_fee_authority_pda(&mint).0,
+        mint_account: mint,
+        destination_account,
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction_with_remaining_accounts(&source_metas);
+
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
+
+/// Build and send HarvestWithheldTokensToMint sweeping `sources` into the mint
+pub async fn execute_harvest_withheld_tokens_to_mint(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    verification_config: Pubkey,
+    sources: &[Pubkey],
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let source_metas: Vec<AccountMeta> = sources
+        .iter()
+        .map(|source| AccountMeta::new(*source, false))
+        .collect();
+    let ix = HarvestWithheldTokensToMint {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        mint_account: mint,
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction_with_remaining_accounts(&source_metas);
+
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &payer.pubkey(),
+        vec![payer],
+    )
+    .await
+}
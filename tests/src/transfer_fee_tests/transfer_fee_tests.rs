@@ -0,0 +1,279 @@
+use security_token_client::{
+    instructions::{
+        HARVEST_WITHHELD_TOKENS_TO_MINT_DISCRIMINATOR, SET_TRANSFER_FEE_DISCRIMINATOR,
+        TRANSFER_DISCRIMINATOR, WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_DISCRIMINATOR,
+    },
+    pda::find_transfer_fee_authority_pda,
+    types::TransferFeeConfigArgs,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::extension::{
+    transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+    BaseStateWithExtensions,
+};
+
+use crate::{
+    claim_tests::claim_helpers::start_with_context_and_transfer_hook,
+    helpers::{
+        assert_instruction_error, assert_transaction_success, create_spl_account,
+        create_verification_config, get_default_verification_programs, get_mint_state,
+        get_token_account_state, initialize_mint_verification_and_mint_to_account,
+    },
+    position_limit_tests::position_limit_helpers::transfer,
+    transfer_fee_tests::transfer_fee_helpers::{
+        create_transfer_fee_security_token_mint, execute_harvest_withheld_tokens_to_mint,
+        execute_set_transfer_fee, execute_withdraw_withheld_tokens_from_accounts,
+    },
+};
+
+const SUPPLY: u64 = 1_000_000;
+const TRANSFER_AMOUNT: u64 = 10_000;
+const TRANSFER_FEE_BASIS_POINTS: u16 = 100;
+const MAXIMUM_FEE: u64 = 5_000;
+/// Fee withheld on a transfer of `TRANSFER_AMOUNT`
+const WITHHELD_FEE: u64 = 100;
+
+struct Setup {
+    mint_keypair: Keypair,
+    holder: Keypair,
+    holder_account: Pubkey,
+    recipient_account: Pubkey,
+    treasury_account: Pubkey,
+    set_fee_verification_config: Pubkey,
+    withdraw_verification_config: Pubkey,
+    harvest_verification_config: Pubkey,
+}
+
+/// Mint the whole supply of a transfer fee mint to a holder
+async fn setup_mint(context: &mut ProgramTestContext) -> Setup {
+    let mint_keypair = Keypair::new();
+    let mint_authority_pda = create_transfer_fee_security_token_mint(
+        context,
+        &mint_keypair,
+        6,
+        TransferFeeConfigArgs {
+            transfer_fee_basis_points: TRANSFER_FEE_BASIS_POINTS,
+            maximum_fee: MAXIMUM_FEE,
+        },
+    )
+    .await;
+    let mut verification_configs = vec![];
+    for discriminator in [
+        TRANSFER_DISCRIMINATOR,
+        SET_TRANSFER_FEE_DISCRIMINATOR,
+        WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_DISCRIMINATOR,
+        HARVEST_WITHHELD_TOKENS_TO_MINT_DISCRIMINATOR,
+    ] {
+        let verification_config = create_verification_config(
+            context,
+            &mint_keypair,
+            mint_authority_pda,
+            discriminator,
+            get_default_verification_programs(),
+            None,
+        )
+        .await;
+        verification_configs.push(verification_config);
+    }
+    let holder = Keypair::new();
+    let holder_account = create_spl_account(context, &mint_keypair, &holder).await;
+    let recipient_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+    let treasury_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+    initialize_mint_verification_and_mint_to_account(
+        &mint_keypair,
+        context,
+        mint_authority_pda,
+        holder_account,
+        SUPPLY,
+    )
+    .await;
+    Setup {
+        mint_keypair,
+        holder,
+        holder_account,
+        recipient_account,
+        treasury_account,
+        set_fee_verification_config: verification_configs[1],
+        withdraw_verification_config: verification_configs[2],
+        harvest_verification_config: verification_configs[3],
+    }
+}
+
+/// Transfer `TRANSFER_AMOUNT` from the holder to the recipient
+async fn transfer_to_recipient(context: &mut ProgramTestContext, setup: &Setup) {
+    let result = transfer(
+        context,
+        setup.mint_keypair.pubkey(),
+        setup.holder_account,
+        setup.recipient_account,
+        &setup.holder,
+        TRANSFER_AMOUNT,
+    )
+    .await;
+    assert_transaction_success(result);
+}
+
+async fn token_amounts(context: &mut ProgramTestContext, token_account: Pubkey) -> (u64, u64) {
+    let state = get_token_account_state(&mut context.banks_client, token_account).await;
+    let withheld_amount = state
+        .get_extension::<TransferFeeAmount>()
+        .expect("TransferFeeAmount extension should be present")
+        .withheld_amount;
+    (state.base.amount, u64::from(withheld_amount))
+}
+
+async fn transfer_fee_config(context: &mut ProgramTestContext, mint: Pubkey) -> TransferFeeConfig {
+    *get_mint_state(&mut context.banks_client, mint)
+        .await
+        .get_extension::<TransferFeeConfig>()
+        .expect("TransferFeeConfig extension should be present")
+}
+
+#[tokio::test]
+async fn test_should_initialize_mint_with_transfer_fee() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let mint = setup.mint_keypair.pubkey();
+    let (transfer_fee_authority, _) = find_transfer_fee_authority_pda(&mint);
+
+    let config = transfer_fee_config(context, mint).await;
+    assert_eq!(
+        Option::<Pubkey>::from(config.transfer_fee_config_authority),
+        Some(transfer_fee_authority)
+    );
+    assert_eq!(
+        Option::<Pubkey>::from(config.withdraw_withheld_authority),
+        Some(transfer_fee_authority)
+    );
+    assert_eq!(
+        u16::from(config.newer_transfer_fee.transfer_fee_basis_points),
+        TRANSFER_FEE_BASIS_POINTS
+    );
+    assert_eq!(
+        u64::from(config.newer_transfer_fee.maximum_fee),
+        MAXIMUM_FEE
+    );
+
+    transfer_to_recipient(context, &setup).await;
+    assert_eq!(
+        token_amounts(context, setup.recipient_account).await,
+        (TRANSFER_AMOUNT - WITHHELD_FEE, WITHHELD_FEE)
+    );
+}
+
+#[tokio::test]
+async fn test_should_set_transfer_fee() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let mint = setup.mint_keypair.pubkey();
+    let payer = context.payer.insecure_clone();
+
+    let result = execute_set_transfer_fee(
+        &context.banks_client,
+        mint,
+        setup.set_fee_verification_config,
+        250,
+        1_000,
+        &payer,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // The new fee only takes effect two epochs later
+    let config = transfer_fee_config(context, mint).await;
+    assert_eq!(
+        u16::from(config.newer_transfer_fee.transfer_fee_basis_points),
+        250
+    );
+    assert_eq!(u64::from(config.newer_transfer_fee.maximum_fee), 1_000);
+    assert_eq!(
+        u16::from(config.older_transfer_fee.transfer_fee_basis_points),
+        TRANSFER_FEE_BASIS_POINTS
+    );
+}
+
+#[tokio::test]
+async fn test_should_withdraw_withheld_tokens_to_treasury() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let mint = setup.mint_keypair.pubkey();
+    let payer = context.payer.insecure_clone();
+    transfer_to_recipient(context, &setup).await;
+
+    let result = execute_withdraw_withheld_tokens_from_accounts(
+        &context.banks_client,
+        mint,
+        setup.withdraw_verification_config,
+        setup.treasury_account,
+        &[setup.recipient_account],
+        &payer,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    assert_eq!(
+        token_amounts(context, setup.recipient_account).await,
+        (TRANSFER_AMOUNT - WITHHELD_FEE, 0)
+    );
+    assert_eq!(
+        token_amounts(context, setup.treasury_account).await,
+        (WITHHELD_FEE, 0)
+    );
+}
+
+#[tokio::test]
+async fn test_should_harvest_withheld_tokens_to_mint() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let mint = setup.mint_keypair.pubkey();
+    let payer = context.payer.insecure_clone();
+    transfer_to_recipient(context, &setup).await;
+
+    let result = execute_harvest_withheld_tokens_to_mint(
+        &context.banks_client,
+        mint,
+        setup.harvest_verification_config,
+        &[setup.recipient_account],
+        &payer,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    assert_eq!(
+        token_amounts(context, setup.recipient_account).await,
+        (TRANSFER_AMOUNT - WITHHELD_FEE, 0)
+    );
+    let config = transfer_fee_config(context, mint).await;
+    assert_eq!(u64::from(config.withheld_amount), WITHHELD_FEE);
+}
+
+#[tokio::test]
+async fn test_withheld_tokens_should_require_sources() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let mint = setup.mint_keypair.pubkey();
+    let payer = context.payer.insecure_clone();
+
+    let result = execute_withdraw_withheld_tokens_from_accounts(
+        &context.banks_client,
+        mint,
+        setup.withdraw_verification_config,
+        setup.treasury_account,
+        &[],
+        &payer,
+    )
+    .await;
+    assert_instruction_error(result, "InvalidArgument");
+
+    let result = execute_harvest_withheld_tokens_to_mint(
+        &context.banks_client,
+        mint,
+        setup.harvest_verification_config,
+        &[],
+        &payer,
+    )
+    .await;
+    assert_instruction_error(result, "InvalidArgument");
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod transfer_permit_tests;
+
+pub mod transfer_permit_helpers;
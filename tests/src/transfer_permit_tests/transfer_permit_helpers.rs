@@ -0,0 +1,165 @@
+use security_token_client::{
+    instructions::{TransferWithPermit, TransferWithPermitInstructionArgs},
+    pda::{find_account_delegate_pda, find_permit_nonce_pda},
+    permit::{
+        approve_permit_delegate_instruction, ed25519_permit_instruction, transfer_permit_message,
+    },
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::{instruction::AccountMeta, signature::Keypair, signer::Signer, sysvar};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+use spl_transfer_hook_interface::{
+    get_extra_account_metas_address, offchain::add_extra_account_metas_for_execute,
+};
+
+use crate::helpers::{create_dummy_verification_from_instruction, send_tx};
+
+/// Approve the account delegate PDA of `token_account` to move `amount` tokens with permits
+pub async fn execute_approve_permit_delegate(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    token_account: Pubkey,
+    amount: u64,
+    holder: &Keypair,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let ix =
+        approve_permit_delegate_instruction(&token_account, &mint, &holder.pubkey(), amount, 6);
+
+    send_tx(banks_client, vec![ix], &payer.pubkey(), vec![payer, holder]).await
+}
+
+/// Extra accounts the transfer hook resolves for a transfer from `from_token_account`
+/// to `to_token_account` by `authority`, without the hook program and the
+/// ExtraAccountMetaList PDA
+async fn transfer_hook_extra_accounts(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    from_token_account: Pubkey,
+    to_token_account: Pubkey,
+    authority: Pubkey,
+    amount: u64,
+) -> Vec<AccountMeta> {
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let mut ix = spl_token_2022::instruction::transfer_checked(
+        &TOKEN_22_PROGRAM_ID,
+        &from_token_account,
+        &mint,
+        &to_token_account,
+        &authority,
+        &[],
+        amount,
+        6,
+    )
+    .expect("SPL transfer ix");
+
+    add_extra_account_metas_for_execute(
+        &mut ix,
+        &transfer_hook_program,
+        &from_token_account,
+        &mint,
+        &to_token_account,
+        &authority,
+        amount,
+        |address| {
+            let banks_client = banks_client.clone();
+            async move {
+                banks_client
+                    .get_account(address)
+                    .await
+                    .map(|account| Some(account.map(|account| account.data).unwrap_or_default()))
+                    .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+            }
+        },
+    )
+    .await
+    .expect("add extra metas");
+
+    let account_metas_pda = get_extra_account_metas_address(&mint, &transfer_hook_program);
+    ix.accounts
+        .into_iter()
+        .skip(4)
+        .filter(|meta| meta.pubkey != transfer_hook_program && meta.pubkey != account_metas_pda)
+        .map(|meta| AccountMeta {
+            is_signer: false,
+            ..meta
+        })
+        .collect()
+}
+
+/// Build and send TransferWithPermit submitted by `relayer` with a transfer permit signed by
+/// `permit_signer`, preceded by the Ed25519 instruction verifying the permit
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_transfer_with_permit(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    verification_config: Pubkey,
+    from_token_account: Pubkey,
+    to_token_account: Pubkey,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+    permit_signer: &Keypair,
+    relayer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let message = transfer_permit_message(
+        &mint,
+        &from_token_account,
+        &to_token_account,
+        amount,
+        nonce,
+        expiry,
+    );
+    let signature = permit_signer.sign_message(&message);
+    let permit_ix =
+        ed25519_permit_instruction(&permit_signer.pubkey(), signature.as_array(), &message);
+
+    let transfer_hook_program = Pubkey::from(security_token_transfer_hook::id());
+    let (account_delegate_authority, _) = find_account_delegate_pda(&from_token_account);
+    let extra_accounts = transfer_hook_extra_accounts(
+        banks_client,
+        mint,
+        from_token_account,
+        to_token_account,
+        account_delegate_authority,
+        amount,
+    )
+    .await;
+
+    let ix = TransferWithPermit {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        // ix accounts
+        payer: relayer.pubkey(),
+        account_delegate_authority,
+        mint_account: mint,
+        from_token_account,
+        to_token_account,
+        permit_nonce_account: find_permit_nonce_pda(&from_token_account).0,
+        instructions_sysvar_account: sysvar::instructions::ID,
+        transfer_hook_program,
+        token_program: TOKEN_22_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+        account_metas_pda: get_extra_account_metas_address(&mint, &transfer_hook_program),
+    }
+    .instruction_with_remaining_accounts(
+        TransferWithPermitInstructionArgs {
+            amount,
+            nonce,
+            expiry,
+        },
+        &extra_accounts,
+    );
+
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![permit_ix, dummy_ix, ix],
+        &relayer.pubkey(),
+        vec![relayer],
+    )
+    .await
+}
@@ -0,0 +1,329 @@
+use security_token_client::{
+    accounts::PermitNonce,
+    agent::SUSPEND,
+    errors::SecurityTokenProgramError,
+    instructions::{TRANSFER_DISCRIMINATOR, TRANSFER_WITH_PERMIT_DISCRIMINATOR},
+    pda::find_permit_nonce_pda,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    agent_tests::agent_helpers::execute_create_agent_account,
+    allowlist_tests::allowlist_helpers::{execute_add_to_allowlist, execute_set_allowlist_mode},
+    claim_tests::claim_helpers::start_with_context_and_transfer_hook,
+    coupon_tests::coupon_helpers::current_timestamp,
+    helpers::{
+        assert_security_token_error, assert_transaction_success,
+        create_minimal_security_token_mint, create_spl_account, create_verification_config,
+        get_default_verification_programs, get_token_account_state,
+        initialize_mint_verification_and_mint_to_account, start_with_transfer_hook,
+    },
+    suspension_tests::suspension_helpers::execute_suspend_by_agent,
+    transfer_permit_tests::transfer_permit_helpers::{
+        execute_approve_permit_delegate, execute_transfer_with_permit,
+    },
+};
+
+const SUPPLY: u64 = 1_000_000;
+const EXPIRY_WINDOW: i64 = 3_600;
+
+struct Setup {
+    mint: Pubkey,
+    holder: Keypair,
+    recipient: Keypair,
+    holder_account: Pubkey,
+    recipient_account: Pubkey,
+    verification_config: Pubkey,
+}
+
+/// Mint the whole supply to a holder whose wallet only signs the approval of the
+/// account delegate PDA, every transfer is relayed with a permit
+async fn setup_mint(context: &mut ProgramTestContext) -> Setup {
+    let mint_keypair = Keypair::new();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, None, 6).await;
+    let mut verification_configs = vec![];
+    for discriminator in [TRANSFER_DISCRIMINATOR, TRANSFER_WITH_PERMIT_DISCRIMINATOR] {
+        let verification_config = create_verification_config(
+            context,
+            &mint_keypair,
+            mint_authority_pda,
+            discriminator,
+            get_default_verification_programs(),
+            None,
+        )
+        .await;
+        verification_configs.push(verification_config);
+    }
+    let holder = Keypair::new();
+    let holder_account = create_spl_account(context, &mint_keypair, &holder).await;
+    let recipient = Keypair::new();
+    let recipient_account = create_spl_account(context, &mint_keypair, &recipient).await;
+    initialize_mint_verification_and_mint_to_account(
+        &mint_keypair,
+        context,
+        mint_authority_pda,
+        holder_account,
+        SUPPLY,
+    )
+    .await;
+    let payer = context.payer.insecure_clone();
+    let result = execute_approve_permit_delegate(
+        &context.banks_client,
+        mint_keypair.pubkey(),
+        holder_account,
+        SUPPLY,
+        &holder,
+        &payer,
+    )
+    .await;
+    assert_transaction_success(result);
+    Setup {
+        mint: mint_keypair.pubkey(),
+        holder,
+        recipient,
+        holder_account,
+        recipient_account,
+        verification_config: verification_configs[1],
+    }
+}
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    get_token_account_state(&mut context.banks_client, token_account)
+        .await
+        .base
+        .amount
+}
+
+#[tokio::test]
+async fn test_should_transfer_with_holder_permit_submitted_by_relayer() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let relayer = context.payer.insecure_clone();
+    let expiry = current_timestamp(context).await + EXPIRY_WINDOW;
+
+    for (nonce, amount) in [(0u64, 400u64), (1, 100)] {
+        let result = execute_transfer_with_permit(
+            &context.banks_client,
+            setup.mint,
+            setup.verification_config,
+            setup.holder_account,
+            setup.recipient_account,
+            amount,
+            nonce,
+            expiry,
+            &setup.holder,
+            &relayer,
+        )
+        .await;
+        assert_transaction_success(result);
+    }
+
+    assert_eq!(
+        token_amount(context, setup.holder_account).await,
+        SUPPLY - 500
+    );
+    assert_eq!(token_amount(context, setup.recipient_account).await, 500);
+
+    let (permit_nonce_pda, _) = find_permit_nonce_pda(&setup.holder_account);
+    let account = context
+        .banks_client
+        .get_account(permit_nonce_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let permit_nonce = PermitNonce::from_bytes(&account.data).unwrap();
+    assert_eq!(permit_nonce.token_account, setup.holder_account);
+    assert_eq!(permit_nonce.next_nonce, 2);
+}
+
+#[tokio::test]
+async fn test_should_not_transfer_with_consumed_permit_nonce() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let relayer = context.payer.insecure_clone();
+    let expiry = current_timestamp(context).await + EXPIRY_WINDOW;
+
+    let result = execute_transfer_with_permit(
+        &context.banks_client,
+        setup.mint,
+        setup.verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        400,
+        0,
+        expiry,
+        &setup.holder,
+        &relayer,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_transfer_with_permit(
+        &context.banks_client,
+        setup.mint,
+        setup.verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        300,
+        0,
+        expiry,
+        &setup.holder,
+        &relayer,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::InvalidPermitNonce);
+    assert_eq!(token_amount(context, setup.recipient_account).await, 400);
+}
+
+#[tokio::test]
+async fn test_should_not_transfer_with_expired_permit() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let relayer = context.payer.insecure_clone();
+    let expiry = current_timestamp(context).await - 1;
+
+    let result = execute_transfer_with_permit(
+        &context.banks_client,
+        setup.mint,
+        setup.verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        400,
+        0,
+        expiry,
+        &setup.holder,
+        &relayer,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::PermitExpired);
+}
+
+#[tokio::test]
+async fn test_should_not_transfer_with_permit_of_other_wallet() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let relayer = context.payer.insecure_clone();
+    let expiry = current_timestamp(context).await + EXPIRY_WINDOW;
+
+    let result = execute_transfer_with_permit(
+        &context.banks_client,
+        setup.mint,
+        setup.verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        400,
+        0,
+        expiry,
+        &Keypair::new(),
+        &relayer,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::InvalidPermit);
+    assert_eq!(token_amount(context, setup.holder_account).await, SUPPLY);
+}
+
+#[tokio::test]
+async fn test_should_not_transfer_with_permit_from_suspended_account() {
+    let agent = Keypair::new();
+    let context = &mut start_with_transfer_hook(&[&agent]).await;
+    let setup = setup_mint(context).await;
+    let relayer = context.payer.insecure_clone();
+    let expiry = current_timestamp(context).await + EXPIRY_WINDOW;
+
+    let result = execute_create_agent_account(
+        &context.banks_client,
+        setup.mint,
+        agent.pubkey(),
+        SUSPEND,
+        &relayer,
+    )
+    .await;
+    assert_transaction_success(result);
+    let result = execute_suspend_by_agent(
+        &context.banks_client,
+        setup.mint,
+        setup.holder_account,
+        &agent,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_transfer_with_permit(
+        &context.banks_client,
+        setup.mint,
+        setup.verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        400,
+        0,
+        expiry,
+        &setup.holder,
+        &relayer,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::AccountSuspended);
+    assert_eq!(token_amount(context, setup.holder_account).await, SUPPLY);
+}
+
+#[tokio::test]
+async fn test_should_not_transfer_with_permit_to_non_allowlisted_recipient() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let setup = setup_mint(context).await;
+    let relayer = context.payer.insecure_clone();
+    let expiry = current_timestamp(context).await + EXPIRY_WINDOW;
+
+    let result =
+        execute_set_allowlist_mode(&context.banks_client, setup.mint, true, &relayer).await;
+    assert_transaction_success(result);
+    let result = execute_add_to_allowlist(
+        &context.banks_client,
+        setup.mint,
+        setup.holder.pubkey(),
+        &relayer,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_transfer_with_permit(
+        &context.banks_client,
+        setup.mint,
+        setup.verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        400,
+        0,
+        expiry,
+        &setup.holder,
+        &relayer,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::WalletNotAllowlisted);
+
+    let result = execute_add_to_allowlist(
+        &context.banks_client,
+        setup.mint,
+        setup.recipient.pubkey(),
+        &relayer,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_transfer_with_permit(
+        &context.banks_client,
+        setup.mint,
+        setup.verification_config,
+        setup.holder_account,
+        setup.recipient_account,
+        400,
+        0,
+        expiry,
+        &setup.holder,
+        &relayer,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(token_amount(context, setup.recipient_account).await, 400);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod translation_tests;
+
+pub mod translation_helpers;
@@ -0,0 +1,130 @@
+use security_token_client::{
+    instructions::{
+        CloseMetadataTranslator, CreateMetadataTranslator, UpdateTranslation,
+        UpdateTranslationInstructionArgs,
+    },
+    pda::find_metadata_translator_pda,
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::Mint;
+use spl_token_metadata_interface::state::TokenMetadata;
+
+use crate::helpers::{assert_account_exists, find_mint_authority_pda, send_tx};
+
+/// Register `translator` as metadata translator of `mint` through the mint authority of the payer
+pub async fn create_metadata_translator(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    translator: Pubkey,
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let mut ix = CreateMetadataTranslator {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: payer.pubkey(),
+        payer: payer.pubkey(),
+        mint_account: mint,
+        metadata_translator_account: find_metadata_translator_pda(&mint, &translator).0,
+        translator,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction();
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
+
+/// Set (or remove with an empty `value`) the translation `key` of `mint` signed by `translator`,
+/// using the MetadataTranslator account of `registered_translator`
+pub async fn update_translation(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    translator: &Keypair,
+    registered_translator: Pubkey,
+    key: &str,
+    value: &str,
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let ix = UpdateTranslation {
+        translator: translator.pubkey(),
+        payer: payer.pubkey(),
+        mint,
+        mint_authority: mint_authority_pda,
+        metadata_translator_account: find_metadata_translator_pda(&mint, &registered_translator).0,
+        token_program: spl_token_2022::ID,
+        system_program: solana_system_interface::program::ID,
+    }
+    .instruction(UpdateTranslationInstructionArgs {
+        key: key.to_string(),
+        value: value.to_string(),
+    });
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer, translator],
+    )
+    .await
+}
+
+/// Revoke `translator` of `mint`, returning rent to `rent_recipient`
+pub async fn close_metadata_translator(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    translator: Pubkey,
+    rent_recipient: Pubkey,
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let (mint_authority_pda, _) = find_mint_authority_pda(&mint, &payer.pubkey());
+
+    let mut ix = CloseMetadataTranslator {
+        mint,
+        verification_config_or_mint_authority: mint_authority_pda,
+        instructions_sysvar_or_creator: payer.pubkey(),
+        mint_account: mint,
+        metadata_translator_account: find_metadata_translator_pda(&mint, &translator).0,
+        rent_recipient,
+    }
+    .instruction();
+    ix.accounts[2].is_signer = true;
+
+    send_tx(
+        &context.banks_client,
+        vec![ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await
+}
+
+/// Additional metadata entry `key` of the metadata stored in `mint`
+pub async fn get_additional_metadata_value(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    key: &str,
+) -> Option<String> {
+    let mint_account = assert_account_exists(context, mint, true).await.unwrap();
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_account.data).unwrap();
+    let metadata = mint_state
+        .get_variable_len_extension::<TokenMetadata>()
+        .unwrap();
+    metadata
+        .additional_metadata
+        .into_iter()
+        .find(|(existing_key, _)| existing_key == key)
+        .map(|(_, value)| value)
+}
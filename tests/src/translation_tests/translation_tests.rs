@@ -0,0 +1,136 @@
+use security_token_client::{
+    accounts::MetadataTranslator, errors::SecurityTokenProgramError,
+    pda::find_metadata_translator_pda,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, start_with_context,
+    },
+    metadata_freeze_tests::metadata_freeze_helpers::freeze_metadata,
+    metadata_schema_tests::metadata_schema_helpers::create_mint_with_metadata,
+    translation_tests::translation_helpers::{
+        close_metadata_translator, create_metadata_translator, get_additional_metadata_value,
+        update_translation,
+    },
+};
+
+#[tokio::test]
+async fn test_translator_should_maintain_i18n_entries() {
+    let context = &mut start_with_context().await;
+    let mint = create_mint_with_metadata(context).await.pubkey();
+    let translator = Keypair::new();
+
+    let result = create_metadata_translator(context, mint, translator.pubkey()).await;
+    assert_transaction_success(result);
+
+    let (translator_pda, bump) = find_metadata_translator_pda(&mint, &translator.pubkey());
+    let translator_account = assert_account_exists(context, translator_pda, true)
+        .await
+        .unwrap();
+    let translator_state = MetadataTranslator::from_bytes(&translator_account.data).unwrap();
+    assert_eq!(translator_state.mint, mint);
+    assert_eq!(translator_state.translator, translator.pubkey());
+    assert_eq!(translator_state.bump, bump);
+
+    let key = "i18n.fr.description";
+    let result = update_translation(
+        context,
+        mint,
+        &translator,
+        translator.pubkey(),
+        key,
+        "Obligation à taux fixe",
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        get_additional_metadata_value(context, mint, key).await,
+        Some("Obligation à taux fixe".to_string())
+    );
+
+    // Non-localized entries stay under the sole control of the mint creator
+    let result =
+        update_translation(context, mint, &translator, translator.pubkey(), "type", "x").await;
+    assert_security_token_error(result, SecurityTokenProgramError::InvalidTranslationKey);
+    assert_eq!(
+        get_additional_metadata_value(context, mint, "type").await,
+        Some("security".to_string())
+    );
+
+    let result = update_translation(context, mint, &translator, translator.pubkey(), key, "").await;
+    assert_transaction_success(result);
+    assert_eq!(
+        get_additional_metadata_value(context, mint, key).await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_should_not_update_translation_without_translator() {
+    let context = &mut start_with_context().await;
+    let mint = create_mint_with_metadata(context).await.pubkey();
+    let translator = Keypair::new();
+    let impostor = Keypair::new();
+
+    let result = create_metadata_translator(context, mint, translator.pubkey()).await;
+    assert_transaction_success(result);
+
+    let result = update_translation(
+        context,
+        mint,
+        &impostor,
+        translator.pubkey(),
+        "i18n.de.description",
+        "Anleihe",
+    )
+    .await;
+    assert_transaction_failure(result);
+
+    let rent_recipient = Keypair::new().pubkey();
+    let result =
+        close_metadata_translator(context, mint, translator.pubkey(), rent_recipient).await;
+    assert_transaction_success(result);
+    assert_account_exists(
+        context,
+        find_metadata_translator_pda(&mint, &translator.pubkey()).0,
+        false,
+    )
+    .await;
+
+    let result = update_translation(
+        context,
+        mint,
+        &translator,
+        translator.pubkey(),
+        "i18n.de.description",
+        "Anleihe",
+    )
+    .await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_not_update_translation_of_frozen_metadata() {
+    let context = &mut start_with_context().await;
+    let mint = create_mint_with_metadata(context).await.pubkey();
+    let translator = Keypair::new();
+
+    let result = create_metadata_translator(context, mint, translator.pubkey()).await;
+    assert_transaction_success(result);
+    let result = freeze_metadata(context, mint).await;
+    assert_transaction_success(result);
+
+    let result = update_translation(
+        context,
+        mint,
+        &translator,
+        translator.pubkey(),
+        "i18n.es.description",
+        "Bono",
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::MetadataFrozen);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod verification_config_timelock_tests;
+
+pub mod verification_config_timelock_helpers;
@@ -0,0 +1,108 @@
+use security_token_client::{
+    instructions::{
+        ApplyPendingConfigBuilder, CancelPendingConfigBuilder, UpdateVerificationConfigBuilder,
+    },
+    pda::find_pending_verification_config_pda,
+    types::UpdateVerificationConfigArgs,
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::helpers::{find_mint_authority_pda, find_verification_config_pda, send_tx};
+
+/// Build and send UpdateVerificationConfig instruction staging the update of a timelocked config
+pub async fn execute_stage_verification_config_update(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    mint_creator: &Keypair,
+    args: UpdateVerificationConfigArgs,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (verification_config, _) =
+        find_verification_config_pda(mint, args.instruction_discriminator);
+    let (pending_config_account, _) =
+        find_pending_verification_config_pda(&mint, args.instruction_discriminator);
+
+    let ix = UpdateVerificationConfigBuilder::new()
+        .mint(mint)
+        .verification_config_or_mint_authority(mint_authority)
+        .instructions_sysvar_or_creator(mint_creator.pubkey())
+        .payer(mint_creator.pubkey())
+        .mint_account(mint)
+        .config_account(verification_config)
+        .pending_config_account(Some(pending_config_account))
+        .update_verification_config_args(args)
+        .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send ApplyPendingConfig instruction authorized by mint authority
+pub async fn execute_apply_pending_config(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    instruction_discriminator: u8,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (verification_config, _) = find_verification_config_pda(mint, instruction_discriminator);
+    let (pending_config_account, _) =
+        find_pending_verification_config_pda(&mint, instruction_discriminator);
+
+    let ix = ApplyPendingConfigBuilder::new()
+        .mint(mint)
+        .verification_config_or_mint_authority(mint_authority)
+        .instructions_sysvar_or_creator(mint_creator.pubkey())
+        .payer(mint_creator.pubkey())
+        .mint_account(mint)
+        .config_account(verification_config)
+        .pending_config_account(pending_config_account)
+        .rent_recipient(mint_creator.pubkey())
+        .instruction_discriminator(instruction_discriminator)
+        .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send CancelPendingConfig instruction authorized by mint authority
+pub async fn execute_cancel_pending_config(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    instruction_discriminator: u8,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (pending_config_account, _) =
+        find_pending_verification_config_pda(&mint, instruction_discriminator);
+
+    let ix = CancelPendingConfigBuilder::new()
+        .mint(mint)
+        .verification_config_or_mint_authority(mint_authority)
+        .instructions_sysvar_or_creator(mint_creator.pubkey())
+        .mint_account(mint)
+        .pending_config_account(pending_config_account)
+        .rent_recipient(mint_creator.pubkey())
+        .instruction_discriminator(instruction_discriminator)
+        .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
@@ -0,0 +1,225 @@
+use security_token_client::{
+    accounts::PendingVerificationConfig,
+    errors::SecurityTokenProgramError,
+    instructions::{TrimVerificationConfigBuilder, BURN_DISCRIMINATOR},
+    pda::find_pending_verification_config_pda,
+    types::{
+        InitializeVerificationConfigArgs, TrimVerificationConfigArgs, UpdateVerificationConfigArgs,
+    },
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    coupon_tests::coupon_helpers::{current_timestamp, warp_to_timestamp},
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_success,
+        create_minimal_security_token_mint, find_mint_authority_pda, find_verification_config_pda,
+        initialize_verification_config_for_payer, send_tx, start_with_context_and_accounts,
+    },
+    verification_config_timelock_tests::verification_config_timelock_helpers::{
+        execute_apply_pending_config, execute_cancel_pending_config,
+        execute_stage_verification_config_update,
+    },
+};
+
+const TIMELOCK_SECONDS: u64 = 86_400;
+
+/// Create a mint with a Burn config of one program and a one day timelock
+async fn setup_timelocked_config(
+    context: &mut ProgramTestContext,
+    mint_creator: &Keypair,
+) -> (Pubkey, Pubkey) {
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(mint_creator), 6).await;
+
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (verification_config, _) = find_verification_config_pda(mint, BURN_DISCRIMINATOR);
+    let result = initialize_verification_config_for_payer(
+        &context.banks_client,
+        mint_creator,
+        &mint_keypair,
+        mint_authority,
+        verification_config,
+        &InitializeVerificationConfigArgs {
+            instruction_discriminator: BURN_DISCRIMINATOR,
+            cpi_mode: false,
+            program_addresses: vec![Pubkey::new_unique()],
+            threshold: 0,
+            lookback_window: 0,
+            require_top_level: false,
+            timelock_seconds: TIMELOCK_SECONDS,
+        },
+    )
+    .await;
+    assert_transaction_success(result);
+
+    (mint, verification_config)
+}
+
+fn add_program_args(program: Pubkey) -> UpdateVerificationConfigArgs {
+    UpdateVerificationConfigArgs {
+        instruction_discriminator: BURN_DISCRIMINATOR,
+        cpi_mode: false,
+        offset: 1,
+        program_addresses: vec![program],
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: TIMELOCK_SECONDS,
+    }
+}
+
+#[tokio::test]
+async fn test_timelocked_update_should_apply_after_delay() {
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let (mint, verification_config) = setup_timelocked_config(context, &mint_creator).await;
+    let config_len = assert_account_exists(context, verification_config, true)
+        .await
+        .unwrap()
+        .data
+        .len();
+
+    let program_2 = Pubkey::new_unique();
+    let now = current_timestamp(context).await;
+    let result = execute_stage_verification_config_update(
+        &context.banks_client,
+        mint,
+        &mint_creator,
+        add_program_args(program_2),
+    )
+    .await;
+    assert_transaction_success(result);
+
+    // The config stays unchanged until the update is applied
+    let (pending_config_account, _) =
+        find_pending_verification_config_pda(&mint, BURN_DISCRIMINATOR);
+    let account = assert_account_exists(context, pending_config_account, true)
+        .await
+        .unwrap();
+    let pending = PendingVerificationConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(pending.mint, mint);
+    assert_eq!(pending.activates_at, now + TIMELOCK_SECONDS as i64);
+    assert_eq!(pending.verification_programs.len(), 2);
+    assert_eq!(pending.verification_programs[1], program_2);
+    let account = assert_account_exists(context, verification_config, true)
+        .await
+        .unwrap();
+    assert_eq!(account.data.len(), config_len);
+
+    // A second update can't be staged while one is pending
+    let result = execute_stage_verification_config_update(
+        &context.banks_client,
+        mint,
+        &mint_creator,
+        add_program_args(Pubkey::new_unique()),
+    )
+    .await;
+    assert!(result.is_err());
+
+    let result = execute_apply_pending_config(
+        &context.banks_client,
+        mint,
+        BURN_DISCRIMINATOR,
+        &mint_creator,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::PendingConfigNotActive);
+
+    warp_to_timestamp(context, pending.activates_at).await;
+    let result = execute_apply_pending_config(
+        &context.banks_client,
+        mint,
+        BURN_DISCRIMINATOR,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    assert_account_exists(context, pending_config_account, false).await;
+    let account = assert_account_exists(context, verification_config, true)
+        .await
+        .unwrap();
+    assert_eq!(account.data.len(), config_len + 32);
+    assert!(account
+        .data
+        .windows(32)
+        .any(|window| window == program_2.as_ref()));
+}
+
+#[tokio::test]
+async fn test_cancel_pending_config_should_keep_config() {
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let (mint, verification_config) = setup_timelocked_config(context, &mint_creator).await;
+    let config_data = assert_account_exists(context, verification_config, true)
+        .await
+        .unwrap()
+        .data;
+
+    let result = execute_stage_verification_config_update(
+        &context.banks_client,
+        mint,
+        &mint_creator,
+        add_program_args(Pubkey::new_unique()),
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_cancel_pending_config(
+        &context.banks_client,
+        mint,
+        BURN_DISCRIMINATOR,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (pending_config_account, _) =
+        find_pending_verification_config_pda(&mint, BURN_DISCRIMINATOR);
+    assert_account_exists(context, pending_config_account, false).await;
+    let account = assert_account_exists(context, verification_config, true)
+        .await
+        .unwrap();
+    assert_eq!(account.data, config_data);
+}
+
+#[tokio::test]
+async fn test_trim_timelocked_config_should_fail() {
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let (mint, verification_config) = setup_timelocked_config(context, &mint_creator).await;
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+
+    // Closing the config would drop its rules without waiting for the timelock
+    let ix = TrimVerificationConfigBuilder::new()
+        .mint(mint)
+        .verification_config_or_mint_authority(mint_authority)
+        .instructions_sysvar_or_creator(mint_creator.pubkey())
+        .payer(mint_creator.pubkey())
+        .config_account(verification_config)
+        .mint_account(mint)
+        .rent_recipient(Pubkey::new_unique())
+        .trim_verification_config_args(TrimVerificationConfigArgs {
+            instruction_discriminator: BURN_DISCRIMINATOR,
+            size: 0,
+            close: true,
+        })
+        .instruction();
+
+    let result = send_tx(
+        &context.banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![&mint_creator],
+    )
+    .await;
+    assert_security_token_error(
+        result,
+        SecurityTokenProgramError::VerificationConfigTimelocked,
+    );
+    assert_account_exists(context, verification_config, true).await;
+}
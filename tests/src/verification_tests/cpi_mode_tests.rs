@@ -1,14 +1,15 @@
 use crate::{
     helpers::{
-        assert_custom_error, assert_transaction_failure, assert_transaction_success,
-        create_minimal_security_token_mint, create_spl_account, find_verification_config_pda,
-        initialize_verification_config, send_tx,
+        assert_custom_error, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        find_verification_config_pda, initialize_verification_config, send_tx,
     },
     verification_tests::verification_helpers::failing_dummy_program_processor,
 };
 use borsh::BorshDeserialize;
 use security_token_client::{
     accounts::VerificationConfig,
+    errors::SecurityTokenProgramError,
     instructions::{MintBuilder, MINT_DISCRIMINATOR},
     programs::SECURITY_TOKEN_PROGRAM_ID,
     types::InitializeVerificationConfigArgs,
@@ -68,6 +69,10 @@ async fn test_mint_cpi_mode() {
         instruction_discriminator: MINT_DISCRIMINATOR,
         cpi_mode: true,
         program_addresses: verification_program_ids.clone(),
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     initialize_verification_config(
@@ -153,6 +158,10 @@ async fn test_mint_cpi_mode_error_cases() {
         instruction_discriminator: MINT_DISCRIMINATOR,
         cpi_mode: true,
         program_addresses: vec![dummy_program_1, dummy_program_2],
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     initialize_verification_config(
@@ -215,3 +224,112 @@ async fn test_mint_cpi_mode_error_cases() {
     // Transaction should fail with custom error from failing dummy program
     assert_custom_error(result, 0x1111);
 }
+
+#[tokio::test]
+async fn test_mint_cpi_mode_with_threshold() {
+    let mut pt = ProgramTest::new("security_token_program", SECURITY_TOKEN_PROGRAM_ID, None);
+    pt.prefer_bpf(false);
+
+    let passing_program = Pubkey::new_unique();
+    let failing_program = Pubkey::new_unique();
+
+    pt.add_program(
+        "dummy_program_1",
+        passing_program,
+        processor!(mint_dummy_program_processor),
+    );
+    pt.add_program(
+        "dummy_program_2",
+        failing_program,
+        processor!(failing_dummy_program_processor),
+    );
+
+    let mint_keypair = Keypair::new();
+    let source_owner = Keypair::new();
+    let mut context = pt.start_with_context().await;
+
+    let (mint_authority_pda, _freeze_authority_pda) =
+        create_minimal_security_token_mint(&mut context, &mint_keypair, None, 6).await;
+
+    let (verification_config_pda, _) =
+        find_verification_config_pda(mint_keypair.pubkey(), MINT_DISCRIMINATOR);
+
+    // ANY of the two programs has to pass
+    let initialize_verification_config_args = InitializeVerificationConfigArgs {
+        instruction_discriminator: MINT_DISCRIMINATOR,
+        cpi_mode: true,
+        program_addresses: vec![passing_program, failing_program],
+        threshold: 1,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
+    };
+
+    initialize_verification_config(
+        &mint_keypair,
+        &mut context,
+        mint_authority_pda,
+        verification_config_pda,
+        &initialize_verification_config_args,
+    )
+    .await;
+
+    let config_account = context
+        .banks_client
+        .get_account(verification_config_pda)
+        .await
+        .unwrap()
+        .expect("VerificationConfig should exist");
+    let verification_config = VerificationConfig::try_from_slice(&config_account.data)
+        .expect("Should be able to deserialize VerificationConfig");
+    assert_eq!(verification_config.threshold, 1);
+
+    let destination_ata = create_spl_account(&mut context, &mint_keypair, &source_owner).await;
+
+    let mint_ix_with_program = |program_id: Pubkey| {
+        MintBuilder::new()
+            .mint(mint_keypair.pubkey())
+            .verification_config(verification_config_pda)
+            .instructions_sysvar(sysvar::instructions::ID)
+            .destination(destination_ata)
+            .mint_account(mint_keypair.pubkey())
+            .mint_authority(mint_authority_pda)
+            .amount(1000)
+            .add_remaining_account(solana_sdk::instruction::AccountMeta::new_readonly(
+                program_id, false,
+            ))
+            .instruction()
+    };
+
+    // The selected program is the only one invoked
+    let result = send_tx(
+        &context.banks_client,
+        vec![mint_ix_with_program(failing_program)],
+        &context.payer.pubkey(),
+        vec![&context.payer],
+    )
+    .await;
+    assert_custom_error(result, 0x1111);
+
+    // Programs outside the config can't be selected
+    let result = send_tx(
+        &context.banks_client,
+        vec![mint_ix_with_program(Pubkey::new_unique())],
+        &context.payer.pubkey(),
+        vec![&context.payer],
+    )
+    .await;
+    assert_security_token_error(
+        result,
+        SecurityTokenProgramError::VerificationProgramNotFound,
+    );
+
+    let result = send_tx(
+        &context.banks_client,
+        vec![mint_ix_with_program(passing_program)],
+        &context.payer.pubkey(),
+        vec![&context.payer],
+    )
+    .await;
+    assert_transaction_success(result);
+}
@@ -39,7 +39,10 @@ struct VerificationTestContext {
 }
 
 #[fixture]
-async fn verification_test_setup() -> VerificationTestContext {
+async fn verification_test_setup(
+    #[default(0)] lookback_window: u8,
+    #[default(false)] require_top_level: bool,
+) -> VerificationTestContext {
     let dummy_program_1_id = Pubkey::new_unique();
     let dummy_program_2_id = Pubkey::new_unique();
 
@@ -75,6 +78,8 @@ async fn verification_test_setup() -> VerificationTestContext {
         ix_metadata_pointer: None,
         ix_metadata: None,
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(
@@ -93,6 +98,10 @@ async fn verification_test_setup() -> VerificationTestContext {
         instruction_discriminator: UPDATE_METADATA_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: verification_programs,
+        threshold: 0,
+        lookback_window,
+        require_top_level,
+        timelock_seconds: 0,
     };
 
     initialize_verification_config(
@@ -330,6 +339,76 @@ async fn test_verify_with_system_instructions_succeeds(
     assert_transaction_success(result);
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_verify_within_lookback_window(
+    #[future]
+    #[with(2, true)]
+    verification_test_setup: VerificationTestContext,
+) {
+    let setup = verification_test_setup.await;
+    let account_for_verification_1 = Keypair::new();
+    let account_for_verification_2 = Keypair::new();
+
+    let verification_accounts = vec![
+        AccountMeta::new_readonly(account_for_verification_1.pubkey(), false),
+        AccountMeta::new_readonly(account_for_verification_2.pubkey(), false),
+    ];
+    let verification_call = |program_id: Pubkey| Instruction {
+        program_id,
+        accounts: verification_accounts.clone(),
+        data: vec![UPDATE_METADATA_DISCRIMINATOR, 1u8],
+    };
+    let system_transfer = || {
+        system_instruction::transfer(
+            &setup.context.payer.pubkey(),
+            &setup.mint_keypair.pubkey(),
+            1,
+        )
+    };
+    let verify_ix = VerifyBuilder::new()
+        .mint(setup.mint_keypair.pubkey())
+        .verification_config(setup.verification_config_pda)
+        .verify_args(VerifyArgs {
+            ix: UPDATE_METADATA_DISCRIMINATOR,
+            instruction_data: vec![1u8],
+        })
+        .add_remaining_accounts(&verification_accounts)
+        .instruction();
+
+    // The first verification call is outside the 2 instructions before Verify
+    let result = send_tx(
+        &setup.context.banks_client,
+        vec![
+            verification_call(setup.dummy_program_1_id),
+            system_transfer(),
+            verification_call(setup.dummy_program_2_id),
+            verify_ix.clone(),
+        ],
+        &setup.context.payer.pubkey(),
+        vec![&setup.context.payer],
+    )
+    .await;
+    assert_security_token_error(
+        result,
+        SecurityTokenProgramError::VerificationProgramNotFound,
+    );
+
+    let result = send_tx(
+        &setup.context.banks_client,
+        vec![
+            system_transfer(),
+            verification_call(setup.dummy_program_1_id),
+            verification_call(setup.dummy_program_2_id),
+            verify_ix,
+        ],
+        &setup.context.payer.pubkey(),
+        vec![&setup.context.payer],
+    )
+    .await;
+    assert_transaction_success(result);
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_verify_with_correct_accounts_but_wrong_data_fails(
@@ -443,6 +522,8 @@ async fn test_update_metadata_under_verification() {
             additional_metadata: vec![],
         }),
         ix_scaled_ui_amount: None,
+        ix_transfer_fee: None,
+        ix_default_account_state: None,
     };
 
     initialize_mint(
@@ -460,6 +541,10 @@ async fn test_update_metadata_under_verification() {
         instruction_discriminator: UPDATE_METADATA_DISCRIMINATOR,
         cpi_mode: false,
         program_addresses: verification_programs,
+        threshold: 0,
+        lookback_window: 0,
+        require_top_level: false,
+        timelock_seconds: 0,
     };
 
     initialize_verification_config(
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod vesting_tests;
+
+pub mod vesting_helpers;
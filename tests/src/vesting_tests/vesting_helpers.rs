@@ -0,0 +1,80 @@
+use security_token_client::{
+    instructions::{CreateVesting, CreateVestingInstructionArgs, ReleaseVested},
+    pda::{find_suspension_pda, find_transfer_escrow_authority_pda, find_vesting_pda},
+};
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::{
+    helpers::{find_mint_authority_pda, find_permanent_delegate_pda, send_tx},
+    transfer_acceptance_tests::transfer_acceptance_helpers::find_transfer_escrow_token_account,
+};
+
+/// Build and send CreateVesting instruction authorized by mint authority
+pub async fn execute_create_vesting(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    beneficiary_token_account: Pubkey,
+    args: CreateVestingInstructionArgs,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (vesting_account, _) = find_vesting_pda(&beneficiary_token_account, args.vesting_id);
+    let (escrow_authority, _) = find_transfer_escrow_authority_pda(&mint);
+
+    let ix = CreateVesting {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_authority,
+        mint_account: mint,
+        beneficiary_token_account,
+        vesting_account,
+        escrow_authority,
+        escrow_token_account: find_transfer_escrow_token_account(&mint),
+        system_program: solana_program::system_program::id(),
+        token_program: TOKEN_22_PROGRAM_ID,
+        associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+    }
+    .instruction(args);
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Build and send permissionless ReleaseVested instruction
+pub async fn execute_release_vested(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    beneficiary_token_account: Pubkey,
+    vesting_id: u64,
+    rent_destination: Pubkey,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (vesting_account, _) = find_vesting_pda(&beneficiary_token_account, vesting_id);
+    let (permanent_delegate_authority, _) = find_permanent_delegate_pda(&mint);
+
+    let ix = ReleaseVested {
+        mint_account: mint,
+        vesting_account,
+        beneficiary_token_account,
+        escrow_token_account: find_transfer_escrow_token_account(&mint),
+        beneficiary_suspension: find_suspension_pda(&beneficiary_token_account).0,
+        rent_destination,
+        permanent_delegate_authority,
+        transfer_hook_program: Pubkey::from(security_token_transfer_hook::id()),
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction();
+
+    send_tx(banks_client, vec![ix], &payer.pubkey(), vec![payer]).await
+}
@@ -0,0 +1,140 @@
+use security_token_client::{
+    accounts::Vesting, errors::SecurityTokenProgramError,
+    instructions::CreateVestingInstructionArgs, pda::find_vesting_pda,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    coupon_tests::coupon_helpers::{current_timestamp, warp_to_timestamp},
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        get_token_account_state, start_with_transfer_hook,
+    },
+    transfer_acceptance_tests::transfer_acceptance_helpers::find_transfer_escrow_token_account,
+    vesting_tests::vesting_helpers::{execute_create_vesting, execute_release_vested},
+};
+
+const TOTAL_AMOUNT: u64 = 1_000;
+const VESTING_DURATION: i64 = 1_000;
+
+#[tokio::test]
+async fn test_anyone_should_release_vested_tokens_continuously() {
+    let context = &mut start_with_transfer_hook(&[]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, None, 6).await;
+    let beneficiary_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+    let cranker = context.payer.insecure_clone();
+
+    let start_timestamp = current_timestamp(context).await + 10;
+    let result = execute_create_vesting(
+        &context.banks_client,
+        mint,
+        beneficiary_account,
+        CreateVestingInstructionArgs {
+            vesting_id: 0,
+            total_amount: TOTAL_AMOUNT,
+            start_timestamp,
+            end_timestamp: start_timestamp + VESTING_DURATION,
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (vesting_pda, _) = find_vesting_pda(&beneficiary_account, 0);
+    let account = assert_account_exists(context, vesting_pda, true)
+        .await
+        .unwrap();
+    let vesting = Vesting::from_bytes(&account.data).unwrap();
+    assert_eq!(vesting.mint, mint);
+    assert_eq!(vesting.beneficiary, beneficiary_account);
+    assert_eq!(vesting.payer, mint_creator.pubkey());
+    assert_eq!(vesting.total_amount, TOTAL_AMOUNT);
+    assert_eq!(vesting.released_amount, 0);
+
+    let escrow_account = find_transfer_escrow_token_account(&mint);
+    let escrow = get_token_account_state(&mut context.banks_client, escrow_account).await;
+    assert_eq!(escrow.base.amount, TOTAL_AMOUNT);
+
+    let result = execute_release_vested(
+        &context.banks_client,
+        mint,
+        beneficiary_account,
+        0,
+        mint_creator.pubkey(),
+        &cranker,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::NothingVested);
+
+    for (elapsed, expected_balance) in [(250, 250), (600, 600), (VESTING_DURATION, TOTAL_AMOUNT)] {
+        warp_to_timestamp(context, start_timestamp + elapsed).await;
+        let result = execute_release_vested(
+            &context.banks_client,
+            mint,
+            beneficiary_account,
+            0,
+            mint_creator.pubkey(),
+            &cranker,
+        )
+        .await;
+        assert_transaction_success(result);
+
+        let beneficiary =
+            get_token_account_state(&mut context.banks_client, beneficiary_account).await;
+        assert_eq!(beneficiary.base.amount, expected_balance);
+    }
+
+    let escrow = get_token_account_state(&mut context.banks_client, escrow_account).await;
+    assert_eq!(escrow.base.amount, 0);
+    // Fully released grant is closed
+    assert_account_exists(context, vesting_pda, false).await;
+}
+
+#[tokio::test]
+async fn test_should_not_release_to_other_token_account() {
+    let context = &mut start_with_transfer_hook(&[]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    create_minimal_security_token_mint(context, &mint_keypair, None, 6).await;
+    let beneficiary_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+    let other_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+
+    let start_timestamp = current_timestamp(context).await;
+    let result = execute_create_vesting(
+        &context.banks_client,
+        mint,
+        beneficiary_account,
+        CreateVestingInstructionArgs {
+            vesting_id: 3,
+            total_amount: TOTAL_AMOUNT,
+            start_timestamp,
+            end_timestamp: start_timestamp + VESTING_DURATION,
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    warp_to_timestamp(context, start_timestamp + VESTING_DURATION).await;
+
+    // The vesting PDA is derived from the beneficiary, another token account cannot claim it
+    let (vesting_pda, _) = find_vesting_pda(&beneficiary_account, 3);
+    let result = execute_release_vested(
+        &context.banks_client,
+        mint,
+        other_account,
+        3,
+        mint_creator.pubkey(),
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+    assert_account_exists(context, vesting_pda, true).await;
+
+    let other = get_token_account_state(&mut context.banks_client, other_account).await;
+    assert_eq!(other.base.amount, 0);
+}
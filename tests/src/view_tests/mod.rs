@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod view_tests;
+
+pub mod view_helpers;
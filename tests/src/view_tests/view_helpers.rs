@@ -0,0 +1,29 @@
+use borsh::BorshDeserialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transaction};
+
+/// Simulate a view instruction and decode the result it returns
+pub async fn simulate_view<T: BorshDeserialize>(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+) -> T {
+    let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        recent_blockhash,
+    );
+    let simulation = context
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    assert!(simulation.result.unwrap().is_ok());
+
+    let return_data = simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .expect("View instructions set return data");
+    T::try_from_slice(&return_data.data).expect("Should deserialize the view result")
+}
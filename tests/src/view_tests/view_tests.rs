@@ -0,0 +1,99 @@
+use security_token_client::{
+    instructions::{GetEffectiveRateBuilder, GetHolderStatusBuilder},
+    pda::find_suspension_pda,
+    types::{CreateRateArgs, EffectiveRate, HolderStatus, RateConfig, Rounding},
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    helpers::{
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        initialize_mint_verification_and_mint_to_account, start_with_context,
+    },
+    rate_tests::rate_helpers::create_rate_account,
+    view_tests::view_helpers::simulate_view,
+};
+
+#[tokio::test]
+async fn test_should_return_holder_status() {
+    let mut context = start_with_context().await;
+    let mint_keypair = Keypair::new();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(&mut context, &mint_keypair, None, 6).await;
+    let holder_account = create_spl_account(&mut context, &mint_keypair, &Keypair::new()).await;
+    initialize_mint_verification_and_mint_to_account(
+        &mint_keypair,
+        &mut context,
+        mint_authority_pda,
+        holder_account,
+        1_000,
+    )
+    .await;
+
+    let ix = GetHolderStatusBuilder::new()
+        .mint(mint_keypair.pubkey())
+        .token_account(holder_account)
+        .suspension_account(find_suspension_pda(&holder_account).0)
+        .instruction();
+    let status: HolderStatus = simulate_view(&mut context, ix).await;
+
+    assert_eq!(
+        status,
+        HolderStatus {
+            balance: 1_000,
+            frozen: false,
+            suspended: false,
+            locked_amount: 0,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_should_return_static_effective_rate() {
+    let mut context = start_with_context().await;
+    let mint_keypair = Keypair::new();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(&mut context, &mint_keypair, None, 6).await;
+    let mint = mint_keypair.pubkey();
+    let action_id = 7u64;
+
+    let create_rate_args = CreateRateArgs {
+        action_id,
+        rate: RateConfig {
+            rounding: Rounding::Down as u8,
+            numerator: 3,
+            denominator: 2,
+        },
+    };
+    let payer = context.payer.pubkey();
+    let (rate_pda, result) = create_rate_account(
+        &mut context,
+        mint,
+        mint_authority_pda,
+        payer,
+        mint,
+        mint,
+        create_rate_args,
+        None,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let ix = GetEffectiveRateBuilder::new()
+        .mint_from(mint)
+        .mint_to(mint)
+        .rate_account(rate_pda)
+        .action_id(action_id)
+        .instruction();
+    let rate: EffectiveRate = simulate_view(&mut context, ix).await;
+
+    assert_eq!(
+        rate,
+        EffectiveRate {
+            rounding: Rounding::Down,
+            numerator: 3,
+            denominator: 2,
+            from_oracle: false,
+        }
+    );
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod withholding_tests;
+
+pub mod withholding_helpers;
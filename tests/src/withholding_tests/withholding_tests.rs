@@ -0,0 +1,193 @@
+use security_token_client::{
+    instructions::CreateWithholdingRateInstructionArgs, pda::find_withholding_rate_pda,
+    types::ClaimDistributionArgs,
+};
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    claim_tests::claim_helpers::{
+        create_distribution_for_users, create_leaves, start_with_context_and_transfer_hook,
+    },
+    helpers::{
+        assert_account_exists, assert_transaction_failure, assert_transaction_success,
+        create_minimal_security_token_mint, create_spl_account, get_token_account_state,
+    },
+    receipt_tests::receipt_helpers::find_claim_action_receipt_pda,
+    withholding_tests::withholding_helpers::{
+        execute_claim_distribution_with_withholding, execute_create_withholding_rate,
+        find_tax_escrow_token_account,
+    },
+};
+
+const JURISDICTION: u16 = 840;
+const RATE_BPS: u16 = 3_000;
+
+#[tokio::test]
+async fn test_should_withhold_tax_at_jurisdiction_rate_on_claim() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let mint_creator = context.payer.insecure_clone();
+    let decimals = 6u8;
+    let action_id = 7u64;
+
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), decimals)
+            .await;
+    let holder_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+
+    let result = execute_create_withholding_rate(
+        &context.banks_client,
+        mint,
+        CreateWithholdingRateInstructionArgs {
+            jurisdiction: JURISDICTION,
+            rate_bps: RATE_BPS,
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let leaves = create_leaves(
+        &[(&holder_account, 100u64), (&Pubkey::new_unique(), 50u64)],
+        &mint,
+        decimals,
+        action_id,
+    );
+    let (merkle_tree, _, distribution_escrow_token_account, claim_verification_config) =
+        create_distribution_for_users(
+            context,
+            &mint_keypair,
+            mint_authority_pda,
+            &mint_creator,
+            action_id,
+            150,
+            decimals,
+            &leaves,
+        )
+        .await;
+
+    let amount = leaves[0].amount;
+    let merkle_proof = merkle_tree.get_proof_of_leaf(0);
+    let (receipt_account, _) =
+        find_claim_action_receipt_pda(&mint, &holder_account, action_id, &merkle_proof);
+
+    let result = execute_claim_distribution_with_withholding(
+        &context.banks_client,
+        mint,
+        claim_verification_config,
+        holder_account,
+        Some(distribution_escrow_token_account),
+        receipt_account,
+        JURISDICTION,
+        ClaimDistributionArgs {
+            action_id,
+            amount,
+            merkle_root: merkle_tree.get_root(),
+            leaf_index: 0,
+            merkle_proof: Some(merkle_proof),
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let withheld_amount = amount * RATE_BPS as u64 / 10_000;
+    let net_amount = amount - withheld_amount;
+
+    let holder = get_token_account_state(&mut context.banks_client, holder_account).await;
+    assert_eq!(holder.base.amount, net_amount);
+
+    let (withholding_rate_account, _) = find_withholding_rate_pda(&mint, JURISDICTION);
+    let tax_escrow_account = find_tax_escrow_token_account(&withholding_rate_account, &mint);
+    let tax_escrow = get_token_account_state(&mut context.banks_client, tax_escrow_account).await;
+    assert_eq!(tax_escrow.base.amount, withheld_amount);
+
+    // Receipt records net amount, withheld amount and jurisdiction after the discriminator
+    let receipt = assert_account_exists(context, receipt_account, true)
+        .await
+        .unwrap();
+    assert_eq!(receipt.data.len(), 19);
+    assert_eq!(
+        u64::from_le_bytes(receipt.data[1..9].try_into().unwrap()),
+        net_amount
+    );
+    assert_eq!(
+        u64::from_le_bytes(receipt.data[9..17].try_into().unwrap()),
+        withheld_amount
+    );
+    assert_eq!(
+        u16::from_le_bytes(receipt.data[17..19].try_into().unwrap()),
+        JURISDICTION
+    );
+}
+
+#[tokio::test]
+async fn test_should_not_withhold_tax_on_external_settlement() {
+    let context = &mut start_with_context_and_transfer_hook().await;
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let mint_creator = context.payer.insecure_clone();
+    let decimals = 6u8;
+    let action_id = 8u64;
+
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), decimals)
+            .await;
+    let holder_account = create_spl_account(context, &mint_keypair, &Keypair::new()).await;
+
+    let result = execute_create_withholding_rate(
+        &context.banks_client,
+        mint,
+        CreateWithholdingRateInstructionArgs {
+            jurisdiction: JURISDICTION,
+            rate_bps: RATE_BPS,
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let leaves = create_leaves(
+        &[(&holder_account, 100u64), (&Pubkey::new_unique(), 50u64)],
+        &mint,
+        decimals,
+        action_id,
+    );
+    let (merkle_tree, _, _, claim_verification_config) = create_distribution_for_users(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        &mint_creator,
+        action_id,
+        150,
+        decimals,
+        &leaves,
+    )
+    .await;
+
+    let merkle_proof = merkle_tree.get_proof_of_leaf(0);
+    let (receipt_account, _) =
+        find_claim_action_receipt_pda(&mint, &holder_account, action_id, &merkle_proof);
+
+    let result = execute_claim_distribution_with_withholding(
+        &context.banks_client,
+        mint,
+        claim_verification_config,
+        holder_account,
+        None,
+        receipt_account,
+        JURISDICTION,
+        ClaimDistributionArgs {
+            action_id,
+            amount: leaves[0].amount,
+            merkle_root: merkle_tree.get_root(),
+            leaf_index: 0,
+            merkle_proof: Some(merkle_proof),
+        },
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_failure(result);
+}
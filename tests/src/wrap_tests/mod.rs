@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod wrap_tests;
+
+pub mod wrap_helpers;
@@ -0,0 +1,246 @@
+use security_token_client::{
+    instructions::{
+        CreateWrapVault, UnwrapToken, UnwrapTokenInstructionArgs, WrapToken,
+        WrapTokenInstructionArgs,
+    },
+    pda::{find_permanent_delegate_config_pda, find_wrap_vault_pda, find_wrap_vault_token_account},
+};
+use solana_program_test::{BanksClient, BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    program_pack::Pack,
+    signature::{Keypair, Signer},
+    sysvar,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent, ID as ASSOCIATED_TOKEN_PROGRAM_ID,
+};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::helpers::{
+    assert_transaction_success, create_dummy_verification_from_instruction,
+    find_mint_authority_pda, find_permanent_delegate_pda, send_tx,
+};
+
+/// Create plain (extension free) mint owned by `token_program` with payer as mint authority
+pub async fn create_underlying_mint(
+    context: &mut ProgramTestContext,
+    token_program: &Pubkey,
+    decimals: u8,
+) -> Pubkey {
+    let mint_keypair = Keypair::new();
+    let payer = context.payer.insecure_clone();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let space = spl_token_2022::state::Mint::LEN;
+
+    let create_account_ix = solana_system_interface::instruction::create_account(
+        &payer.pubkey(),
+        &mint_keypair.pubkey(),
+        rent.minimum_balance(space),
+        space as u64,
+        token_program,
+    );
+    let initialize_mint_ix = spl_token_2022::instruction::initialize_mint2(
+        token_program,
+        &mint_keypair.pubkey(),
+        &payer.pubkey(),
+        None,
+        decimals,
+    )
+    .unwrap();
+
+    let result = send_tx(
+        &context.banks_client,
+        vec![create_account_ix, initialize_mint_ix],
+        &payer.pubkey(),
+        vec![&payer, &mint_keypair],
+    )
+    .await;
+    assert_transaction_success(result);
+
+    mint_keypair.pubkey()
+}
+
+/// Create associated token account of `owner` for underlying mint and mint `amount` to it
+pub async fn create_underlying_token_account(
+    context: &mut ProgramTestContext,
+    underlying_mint: &Pubkey,
+    token_program: &Pubkey,
+    owner: &Pubkey,
+    decimals: u8,
+    amount: u64,
+) -> Pubkey {
+    let payer = context.payer.insecure_clone();
+    let token_account =
+        get_associated_token_address_with_program_id(owner, underlying_mint, token_program);
+
+    let create_ata_ix = create_associated_token_account_idempotent(
+        &payer.pubkey(),
+        owner,
+        underlying_mint,
+        token_program,
+    );
+    let mint_to_ix = spl_token_2022::instruction::mint_to_checked(
+        token_program,
+        underlying_mint,
+        &token_account,
+        &payer.pubkey(),
+        &[],
+        amount,
+        decimals,
+    )
+    .unwrap();
+
+    let result = send_tx(
+        &context.banks_client,
+        vec![create_ata_ix, mint_to_ix],
+        &payer.pubkey(),
+        vec![&payer],
+    )
+    .await;
+    assert_transaction_success(result);
+
+    token_account
+}
+
+/// Build and send CreateWrapVault instruction authorized by mint authority
+pub async fn execute_create_wrap_vault(
+    banks_client: &BanksClient,
+    mint: Pubkey,
+    underlying_mint: Pubkey,
+    underlying_token_program: Pubkey,
+    mint_creator: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (mint_authority, _) = find_mint_authority_pda(&mint, &mint_creator.pubkey());
+    let (wrap_vault, _) = find_wrap_vault_pda(&mint, &underlying_mint);
+    let (vault_token_account, _) =
+        find_wrap_vault_token_account(&mint, &underlying_mint, &underlying_token_program);
+
+    let ix = CreateWrapVault {
+        mint,
+        verification_config_or_mint_authority: mint_authority,
+        instructions_sysvar_or_creator: mint_creator.pubkey(),
+        payer: mint_creator.pubkey(),
+        mint_account: mint,
+        underlying_mint,
+        wrap_vault,
+        vault_token_account,
+        underlying_token_program,
+        associated_token_account_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction();
+
+    send_tx(
+        banks_client,
+        vec![ix],
+        &mint_creator.pubkey(),
+        vec![mint_creator],
+    )
+    .await
+}
+
+/// Accounts of a holder wrapping `underlying_mint` into `mint` and unwrapping it back
+pub struct WrapAccounts {
+    pub mint: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub underlying_token_program: Pubkey,
+    pub underlying_token_account: Pubkey,
+    pub token_account: Pubkey,
+}
+
+/// Build and send WrapToken instruction
+pub async fn execute_wrap(
+    banks_client: &BanksClient,
+    verification_config: Pubkey,
+    mint_authority: Pubkey,
+    accounts: &WrapAccounts,
+    owner: &Keypair,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let &WrapAccounts {
+        mint,
+        underlying_mint,
+        underlying_token_program,
+        underlying_token_account,
+        token_account,
+    } = accounts;
+    let (wrap_vault, _) = find_wrap_vault_pda(&mint, &underlying_mint);
+    let (vault_token_account, _) =
+        find_wrap_vault_token_account(&mint, &underlying_mint, &underlying_token_program);
+
+    let ix = WrapToken {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        mint_authority,
+        wrap_vault,
+        owner: owner.pubkey(),
+        mint_account: mint,
+        underlying_mint,
+        underlying_token_account,
+        vault_token_account,
+        token_account,
+        underlying_token_program,
+        token_program: TOKEN_22_PROGRAM_ID,
+    }
+    .instruction(WrapTokenInstructionArgs { amount });
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &owner.pubkey(),
+        vec![owner],
+    )
+    .await
+}
+
+/// Build and send UnwrapToken instruction
+pub async fn execute_unwrap(
+    banks_client: &BanksClient,
+    verification_config: Pubkey,
+    accounts: &WrapAccounts,
+    owner: &Keypair,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let &WrapAccounts {
+        mint,
+        underlying_mint,
+        underlying_token_program,
+        underlying_token_account,
+        token_account,
+    } = accounts;
+    let (wrap_vault, _) = find_wrap_vault_pda(&mint, &underlying_mint);
+    let (vault_token_account, _) =
+        find_wrap_vault_token_account(&mint, &underlying_mint, &underlying_token_program);
+    let (permanent_delegate, _) = find_permanent_delegate_pda(&mint);
+
+    let ix = UnwrapToken {
+        mint,
+        verification_config,
+        instructions_sysvar: sysvar::instructions::ID,
+        permanent_delegate,
+        wrap_vault,
+        owner: owner.pubkey(),
+        mint_account: mint,
+        underlying_mint,
+        token_account,
+        vault_token_account,
+        underlying_token_account,
+        underlying_token_program,
+        token_program: TOKEN_22_PROGRAM_ID,
+        permanent_delegate_config_account: find_permanent_delegate_config_pda(&mint).0,
+    }
+    .instruction(UnwrapTokenInstructionArgs { amount });
+    let dummy_ix = create_dummy_verification_from_instruction(&ix);
+
+    send_tx(
+        banks_client,
+        vec![dummy_ix, ix],
+        &owner.pubkey(),
+        vec![owner],
+    )
+    .await
+}
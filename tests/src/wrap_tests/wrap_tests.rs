@@ -0,0 +1,426 @@
+use rstest::rstest;
+use security_token_client::{
+    errors::SecurityTokenProgramError,
+    instructions::{UNWRAP_TOKEN_DISCRIMINATOR, WRAP_TOKEN_DISCRIMINATOR},
+    pda::{find_wrap_vault_pda, find_wrap_vault_token_account, TOKEN_PROGRAM_ID},
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token_2022::ID as TOKEN_22_PROGRAM_ID;
+
+use crate::{
+    helpers::{
+        assert_account_exists, assert_security_token_error, assert_transaction_failure,
+        assert_transaction_success, create_minimal_security_token_mint, create_spl_account,
+        create_verification_config, get_default_verification_programs, get_token_account_state,
+        start_with_context_and_accounts,
+    },
+    wrap_tests::wrap_helpers::{
+        create_underlying_mint, create_underlying_token_account, execute_create_wrap_vault,
+        execute_unwrap, execute_wrap, WrapAccounts,
+    },
+};
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    get_token_account_state(&mut context.banks_client, token_account)
+        .await
+        .base
+        .amount
+}
+
+#[rstest]
+#[case::legacy_spl_token(TOKEN_PROGRAM_ID)]
+#[case::token_2022(TOKEN_22_PROGRAM_ID)]
+#[tokio::test]
+async fn test_should_wrap_and_unwrap(#[case] underlying_token_program: Pubkey) {
+    let holder = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![(&holder, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let decimals = 6u8;
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), decimals)
+            .await;
+
+    let underlying_mint =
+        create_underlying_mint(context, &underlying_token_program, decimals).await;
+    let initial_amount = 1_000_000u64;
+    let underlying_token_account = create_underlying_token_account(
+        context,
+        &underlying_mint,
+        &underlying_token_program,
+        &holder.pubkey(),
+        decimals,
+        initial_amount,
+    )
+    .await;
+    let token_account = create_spl_account(context, &mint_keypair, &holder).await;
+
+    let result = execute_create_wrap_vault(
+        &context.banks_client,
+        mint,
+        underlying_mint,
+        underlying_token_program,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    let (wrap_vault, _) = find_wrap_vault_pda(&mint, &underlying_mint);
+    assert_account_exists(context, wrap_vault, true).await;
+
+    let wrap_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        WRAP_TOKEN_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+    let unwrap_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        UNWRAP_TOKEN_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+
+    let accounts = WrapAccounts {
+        mint,
+        underlying_mint,
+        underlying_token_program,
+        underlying_token_account,
+        token_account,
+    };
+    let wrap_amount = 600_000u64;
+    let result = execute_wrap(
+        &context.banks_client,
+        wrap_verification_config,
+        mint_authority_pda,
+        &accounts,
+        &holder,
+        wrap_amount,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let (vault_token_account, _) =
+        find_wrap_vault_token_account(&mint, &underlying_mint, &underlying_token_program);
+    assert_eq!(token_amount(context, token_account).await, wrap_amount);
+    assert_eq!(
+        token_amount(context, vault_token_account).await,
+        wrap_amount
+    );
+    assert_eq!(
+        token_amount(context, underlying_token_account).await,
+        initial_amount - wrap_amount
+    );
+
+    let unwrap_amount = 250_000u64;
+    let result = execute_unwrap(
+        &context.banks_client,
+        unwrap_verification_config,
+        &accounts,
+        &holder,
+        unwrap_amount,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    assert_eq!(
+        token_amount(context, token_account).await,
+        wrap_amount - unwrap_amount
+    );
+    assert_eq!(
+        token_amount(context, vault_token_account).await,
+        wrap_amount - unwrap_amount
+    );
+    assert_eq!(
+        token_amount(context, underlying_token_account).await,
+        initial_amount - wrap_amount + unwrap_amount
+    );
+}
+
+#[tokio::test]
+async fn test_should_not_create_wrap_vault_with_decimals_mismatch() {
+    let context = &mut start_with_context_and_accounts(vec![]).await;
+    let mint_creator = context.payer.insecure_clone();
+
+    let mint_keypair = Keypair::new();
+    create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), 6).await;
+    let underlying_mint = create_underlying_mint(context, &TOKEN_PROGRAM_ID, 9).await;
+
+    let result = execute_create_wrap_vault(
+        &context.banks_client,
+        mint_keypair.pubkey(),
+        underlying_mint,
+        TOKEN_PROGRAM_ID,
+        &mint_creator,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::InvalidUnderlyingMint);
+}
+
+#[tokio::test]
+async fn test_should_not_wrap_without_wrap_vault() {
+    let holder = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![(&holder, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let decimals = 6u8;
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), decimals)
+            .await;
+    let underlying_mint = create_underlying_mint(context, &TOKEN_PROGRAM_ID, decimals).await;
+    let underlying_token_account = create_underlying_token_account(
+        context,
+        &underlying_mint,
+        &TOKEN_PROGRAM_ID,
+        &holder.pubkey(),
+        decimals,
+        1_000,
+    )
+    .await;
+    let token_account = create_spl_account(context, &mint_keypair, &holder).await;
+    let wrap_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        WRAP_TOKEN_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+
+    let result = execute_wrap(
+        &context.banks_client,
+        wrap_verification_config,
+        mint_authority_pda,
+        &WrapAccounts {
+            mint,
+            underlying_mint,
+            underlying_token_program: TOKEN_PROGRAM_ID,
+            underlying_token_account,
+            token_account,
+        },
+        &holder,
+        1_000,
+    )
+    .await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_not_unwrap_tokens_of_another_owner() {
+    let holder = Keypair::new();
+    let attacker = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![
+        (&holder, 1_000_000_000),
+        (&attacker, 1_000_000_000),
+    ])
+    .await;
+    let mint_creator = context.payer.insecure_clone();
+    let decimals = 6u8;
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), decimals)
+            .await;
+    let underlying_mint = create_underlying_mint(context, &TOKEN_PROGRAM_ID, decimals).await;
+    let underlying_token_account = create_underlying_token_account(
+        context,
+        &underlying_mint,
+        &TOKEN_PROGRAM_ID,
+        &holder.pubkey(),
+        decimals,
+        1_000,
+    )
+    .await;
+    let attacker_underlying_token_account = create_underlying_token_account(
+        context,
+        &underlying_mint,
+        &TOKEN_PROGRAM_ID,
+        &attacker.pubkey(),
+        decimals,
+        0,
+    )
+    .await;
+    let token_account = create_spl_account(context, &mint_keypair, &holder).await;
+
+    let result = execute_create_wrap_vault(
+        &context.banks_client,
+        mint,
+        underlying_mint,
+        TOKEN_PROGRAM_ID,
+        &mint_creator,
+    )
+    .await;
+    assert_transaction_success(result);
+    let wrap_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        WRAP_TOKEN_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+    let unwrap_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        UNWRAP_TOKEN_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+    let accounts = WrapAccounts {
+        mint,
+        underlying_mint,
+        underlying_token_program: TOKEN_PROGRAM_ID,
+        underlying_token_account,
+        token_account,
+    };
+    let result = execute_wrap(
+        &context.banks_client,
+        wrap_verification_config,
+        mint_authority_pda,
+        &accounts,
+        &holder,
+        1_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_unwrap(
+        &context.banks_client,
+        unwrap_verification_config,
+        &WrapAccounts {
+            underlying_token_account: attacker_underlying_token_account,
+            ..accounts
+        },
+        &attacker,
+        1_000,
+    )
+    .await;
+    assert_transaction_failure(result);
+}
+
+#[tokio::test]
+async fn test_should_not_unwrap_from_another_wrap_vault() {
+    let holder = Keypair::new();
+    let context = &mut start_with_context_and_accounts(vec![(&holder, 1_000_000_000)]).await;
+    let mint_creator = context.payer.insecure_clone();
+    let decimals = 6u8;
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let (mint_authority_pda, _) =
+        create_minimal_security_token_mint(context, &mint_keypair, Some(&mint_creator), decimals)
+            .await;
+    let token_account = create_spl_account(context, &mint_keypair, &holder).await;
+
+    let mut vaults = Vec::new();
+    for _ in 0..2 {
+        let underlying_mint = create_underlying_mint(context, &TOKEN_PROGRAM_ID, decimals).await;
+        let underlying_token_account = create_underlying_token_account(
+            context,
+            &underlying_mint,
+            &TOKEN_PROGRAM_ID,
+            &holder.pubkey(),
+            decimals,
+            1_000,
+        )
+        .await;
+        let result = execute_create_wrap_vault(
+            &context.banks_client,
+            mint,
+            underlying_mint,
+            TOKEN_PROGRAM_ID,
+            &mint_creator,
+        )
+        .await;
+        assert_transaction_success(result);
+        vaults.push(WrapAccounts {
+            mint,
+            underlying_mint,
+            underlying_token_program: TOKEN_PROGRAM_ID,
+            underlying_token_account,
+            token_account,
+        });
+    }
+    let [wrapped_vault, other_vault] = [&vaults[0], &vaults[1]];
+
+    // The other vault holds underlying tokens sent to it directly, none wrapped
+    let (other_wrap_vault, _) = find_wrap_vault_pda(&mint, &other_vault.underlying_mint);
+    create_underlying_token_account(
+        context,
+        &other_vault.underlying_mint,
+        &TOKEN_PROGRAM_ID,
+        &other_wrap_vault,
+        decimals,
+        1_000,
+    )
+    .await;
+
+    let wrap_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        WRAP_TOKEN_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+    let unwrap_verification_config = create_verification_config(
+        context,
+        &mint_keypair,
+        mint_authority_pda,
+        UNWRAP_TOKEN_DISCRIMINATOR,
+        get_default_verification_programs(),
+        Some(&mint_creator),
+    )
+    .await;
+    let result = execute_wrap(
+        &context.banks_client,
+        wrap_verification_config,
+        mint_authority_pda,
+        wrapped_vault,
+        &holder,
+        1_000,
+    )
+    .await;
+    assert_transaction_success(result);
+
+    let result = execute_unwrap(
+        &context.banks_client,
+        unwrap_verification_config,
+        other_vault,
+        &holder,
+        1_000,
+    )
+    .await;
+    assert_security_token_error(result, SecurityTokenProgramError::WrapVaultSupplyExceeded);
+
+    let result = execute_unwrap(
+        &context.banks_client,
+        unwrap_verification_config,
+        wrapped_vault,
+        &holder,
+        1_000,
+    )
+    .await;
+    assert_transaction_success(result);
+    assert_eq!(
+        token_amount(context, wrapped_vault.underlying_token_account).await,
+        1_000
+    );
+}
@@ -4,13 +4,17 @@
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{Seed, Signer},
+    log::sol_log_data,
     program_error::ProgramError,
     pubkey::{checked_create_program_address, find_program_address, Pubkey},
-    sysvars::{rent::Rent, Sysvar},
+    sysvars::{clock::Clock, instructions::Instructions, rent::Rent, Sysvar},
     ProgramResult,
 };
 use pinocchio_pubkey::{declare_id, pubkey};
 use pinocchio_system::instructions::{Allocate, Assign};
+use security_token_core::{
+    SecurityTokenDiscriminators, SecurityTokenInstructionDiscriminators, TransferHookRuleFlags,
+};
 use solana_pubkey::Pubkey as SolanaPubkey;
 use spl_discriminator::SplDiscriminate;
 use spl_pod::slice::PodSlice;
@@ -26,9 +30,55 @@ const PERMANENT_DELEGATE_SEED: &[u8] = b"mint.permanent_delegate";
 const TRANSFER_HOOK_SEED: &[u8] = b"mint.transfer_hook";
 const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
 const VERIFICATION_CONFIG_SEED: &[u8] = b"verification_config";
-const TRANSFER_DISCRIMINATOR: u8 = 12; // Security Token transfer instruction discriminator
-const TRANSFER_VERIFICATION_CONFIG_DISCRIMINATOR: u8 = 1; // Account discriminator for Security Token verification config
+const TRANSFER_DISCRIMINATOR: u8 = SecurityTokenInstructionDiscriminators::Transfer as u8;
+const TRANSFER_VERIFICATION_CONFIG_DISCRIMINATOR: u8 =
+    SecurityTokenDiscriminators::VerificationConfigDiscriminator as u8;
 const MAX_VERIFICATION_PROGRAMS: usize = 10;
+const POSITION_LIMIT_SEED: &[u8] = b"position_limit";
+const IDENTITY_WALLET_SEED: &[u8] = b"identity_wallet";
+const IDENTITY_DISCRIMINATOR: u8 = SecurityTokenDiscriminators::IdentityDiscriminator as u8;
+const IDENTITY_WALLET_DISCRIMINATOR: u8 =
+    SecurityTokenDiscriminators::IdentityWalletDiscriminator as u8;
+const POSITION_LIMIT_DISCRIMINATOR: u8 =
+    SecurityTokenDiscriminators::PositionLimitDiscriminator as u8;
+const IDENTITY_WALLETS_OFFSET: usize = 70; // Identity layout: [0] discriminator, [1-32] mint, [33-64] investor_id, [65] bump, [66-69] count, [70..] wallets
+const BPS_DENOMINATOR: u128 = 10_000;
+const POSITION_LIMIT_EXCEEDED_ERROR: u32 = 11; // Security Token PositionLimitExceeded error code
+const TRANSFER_ACCEPTANCE_DISCRIMINATOR: u8 =
+    SecurityTokenDiscriminators::TransferAcceptanceDiscriminator as u8;
+const TRANSFER_ACCEPTANCE_REQUIRED_ERROR: u32 = 12; // Security Token TransferAcceptanceRequired error code
+const TRANSFER_APPROVAL_DISCRIMINATOR: u8 =
+    SecurityTokenDiscriminators::TransferApprovalDiscriminator as u8;
+const TRANSFER_APPROVAL_REQUIRED_ERROR: u32 = 13; // Security Token TransferApprovalRequired error code
+const PERMANENT_DELEGATE_CONFIG_DISCRIMINATOR: u8 =
+    SecurityTokenDiscriminators::PermanentDelegateConfigDiscriminator as u8;
+const RESTRICTED_HOLDING_SEED: &[u8] = b"restricted_holding";
+const HOLDING_PERIOD_DISCRIMINATOR: u8 =
+    SecurityTokenDiscriminators::HoldingPeriodDiscriminator as u8;
+const RESTRICTED_HOLDING_DISCRIMINATOR: u8 =
+    SecurityTokenDiscriminators::RestrictedHoldingDiscriminator as u8;
+const HOLDING_PERIOD_NOT_ELAPSED_ERROR: u32 = 24; // Security Token HoldingPeriodNotElapsed error code
+const SUSPENSION_DISCRIMINATOR: u8 = SecurityTokenDiscriminators::SuspensionDiscriminator as u8;
+const ACCOUNT_SUSPENDED_ERROR: u32 = 25; // Security Token AccountSuspended error code
+const SUB_ACCOUNT_LEDGER_SEED: &[u8] = b"sub_account_ledger";
+const SUB_ACCOUNT_LEDGER_DISCRIMINATOR: u8 =
+    SecurityTokenDiscriminators::SubAccountLedgerDiscriminator as u8;
+const SUB_ACCOUNT_BALANCE_EXCEEDED_ERROR: u32 = 54; // Security Token SubAccountBalanceExceeded error code
+const ALLOWLIST_ENTRY_SEED: &[u8] = b"allowlist_entry";
+const ALLOWLIST_ENTRY_DISCRIMINATOR: u8 =
+    SecurityTokenDiscriminators::AllowlistEntryDiscriminator as u8;
+const WALLET_NOT_ALLOWLISTED_ERROR: u32 = 66; // Security Token WalletNotAllowlisted error code
+const ACCRUAL_CONFIG_SEED: &[u8] = b"accrual_config";
+const ACCRUAL_CONFIG_DISCRIMINATOR: u8 =
+    SecurityTokenDiscriminators::AccrualConfigDiscriminator as u8;
+const COUPON_CLAIM_SEED: &[u8] = b"coupon_claim";
+const COUPON_CLAIM_DISCRIMINATOR: u8 = SecurityTokenDiscriminators::CouponClaimDiscriminator as u8;
+const COUPON_CLAIM_OUTDATED_ERROR: u32 = 69; // Security Token CouponClaimOutdated error code
+const TRANSFER_EVENT_DISCRIMINATOR: u8 = 0; // Security Token TransferEvent discriminator
+const VERIFICATION_PROGRAM_NOT_FOUND_ERROR: u32 = 1; // Security Token VerificationProgramNotFound error code
+const TRANSFER_EVENT_LEN: usize = 138; // Layout: [0] discriminator, [1-32] mint, [33-64] from, [65-96] to, [97-104] amount, [105] memo flag, [106-137] memo hash
+const VERIFICATION_EVENT_DISCRIMINATOR: u8 = 1; // Security Token VerificationEvent discriminator
+const VERIFICATION_EVENT_MIN_LEN: usize = 45; // Layout: [0] discriminator, [1-32] mint, [33] instruction discriminator, [34] cpi_mode, [35] threshold, [36-43] reason, [44] count, [45..] programs
 
 // NOTE: Replace with the finalized program ID generated for the transfer hook deployment.
 declare_id!("HookXqLKgPaNrHBJ9Jui7oQZz93vMbtA88JjsLa8bmfL");
@@ -63,46 +113,288 @@ pub fn process_instruction(
 }
 
 fn process_execute(accounts: &[AccountInfo], rest: &[u8]) -> ProgramResult {
-    let [_from, mint, _to, authority, extra_accounts @ ..] = accounts else {
+    let [from, mint, to, authority, extra_accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    if is_permanent_delegate_transfer(mint, authority, extra_accounts)? {
+    let (permanent_delegate_pda, _bump) = find_program_address(
+        &[PERMANENT_DELEGATE_SEED, mint.key().as_ref()],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    );
+    // NOTE: Permanent delegate with no extra accounts means security token program call
+    if authority.key() == &permanent_delegate_pda && extra_accounts.is_empty() {
         return Ok(());
     }
 
-    let verification_programs = load_verification_programs(mint, extra_accounts)?;
+    let (verification_programs, threshold, lookback_window) =
+        load_verification_programs(mint, extra_accounts)?;
+    let uses_instructions_sysvar = threshold != 0 && !verification_programs.is_empty();
+    // [0] - validate_state_pubkey, [1] - verification_config_pda, then verification programs,
+    // followed by the instructions sysvar when a threshold is set and the rules marker
+    let rules_marker_index =
+        2 + verification_programs.len() + usize::from(uses_instructions_sysvar);
+    let rule_accounts = RuleAccounts::load(extra_accounts, rules_marker_index)?;
+
+    if let Some(permanent_delegate_config) = rule_accounts.permanent_delegate_config {
+        if is_permanent_delegate_transfer(mint, authority, permanent_delegate_config)? {
+            return Ok(());
+        }
+    }
 
     if verification_programs.is_empty() {
         //TODO fix return Ok(());
         return Err(ProgramError::InvalidAccountData);
     }
+
+    if let Some(transfer_acceptance) = rule_accounts.transfer_acceptance {
+        load_rule_marker(mint, transfer_acceptance, TRANSFER_ACCEPTANCE_DISCRIMINATOR)?;
+        return Err(ProgramError::Custom(TRANSFER_ACCEPTANCE_REQUIRED_ERROR));
+    }
+
     let amount = rest
         .get(..8)
         .and_then(|slice| slice.try_into().ok())
         .map(u64::from_le_bytes)
         .ok_or(ProgramError::InvalidInstructionData)?;
-    execute_verification_programs(&verification_programs, accounts, amount)?;
+    if let Some(transfer_approval) = rule_accounts.transfer_approval {
+        enforce_transfer_approval(mint, transfer_approval, amount)?;
+    }
+
+    if let Some(accounts) = rule_accounts.suspension {
+        enforce_suspension(mint, from, to, accounts)?;
+    }
+    if let Some(accounts) = rule_accounts.sub_account_ledger {
+        enforce_sub_account_ledger(mint, from, accounts)?;
+    }
+    if let Some(accounts) = rule_accounts.allowlist {
+        enforce_allowlist(mint, from, to, accounts)?;
+    }
+    if let Some(accounts) = rule_accounts.holding_period {
+        enforce_holding_period(mint, from, accounts)?;
+    }
+    if let Some(accounts) = rule_accounts.coupon_accrual {
+        enforce_coupon_claims(mint, from, to, accounts)?;
+    }
+    if let Some(accounts) = rule_accounts.position_limit {
+        enforce_position_limit(mint, to, accounts)?;
+    }
+    let (consulted_programs, verification) = if threshold == 0 {
+        // A failing verification program aborts the transaction, the event is only logged on success
+        execute_verification_programs(&verification_programs, accounts, amount)?;
+        (verification_programs, Ok(()))
+    } else {
+        let instructions_sysvar = extra_accounts
+            .get(2 + verification_programs.len())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let verified_programs = find_verification_calls(
+            &verification_programs,
+            threshold,
+            lookback_window,
+            instructions_sysvar,
+            &accounts[..4],
+            amount,
+        )?;
+        let verification = if verified_programs.len() < threshold as usize {
+            Err(ProgramError::Custom(VERIFICATION_PROGRAM_NOT_FOUND_ERROR))
+        } else {
+            Ok(())
+        };
+        (verified_programs, verification)
+    };
+    emit_verification_event(mint, threshold, &consulted_programs, &verification);
+    verification?;
+    emit_transfer_event(mint, from, to, amount);
     Ok(())
 }
 
+/// Log a Security Token VerificationEvent for a holder transfer, failed verifications included.
+/// Without a threshold the hook invokes the programs (CPI mode), otherwise it looks for their calls.
+fn emit_verification_event(
+    mint: &AccountInfo,
+    threshold: u8,
+    consulted_programs: &[[u8; 32]],
+    verification: &ProgramResult,
+) {
+    let reason = verification
+        .as_ref()
+        .err()
+        .map_or(0, |error| u64::from(*error));
+    let mut data = Vec::with_capacity(VERIFICATION_EVENT_MIN_LEN + consulted_programs.len() * 32);
+    data.push(VERIFICATION_EVENT_DISCRIMINATOR);
+    data.extend_from_slice(mint.key());
+    data.push(TRANSFER_DISCRIMINATOR);
+    data.push((threshold == 0) as u8);
+    data.push(threshold);
+    data.extend_from_slice(&reason.to_le_bytes());
+    data.push(consulted_programs.len() as u8);
+    for program in consulted_programs {
+        data.extend_from_slice(program);
+    }
+    sol_log_data(&[&data]);
+}
+
+/// Log a Security Token TransferEvent for a holder transfer.
+/// Token-2022 Execute data carries no travel-rule memo, so the memo hash stays absent.
+fn emit_transfer_event(mint: &AccountInfo, from: &AccountInfo, to: &AccountInfo, amount: u64) {
+    let mut data = [0u8; TRANSFER_EVENT_LEN];
+    data[0] = TRANSFER_EVENT_DISCRIMINATOR;
+    data[1..33].copy_from_slice(mint.key());
+    data[33..65].copy_from_slice(from.key());
+    data[65..97].copy_from_slice(to.key());
+    data[97..105].copy_from_slice(&amount.to_le_bytes());
+    sol_log_data(&[&data]);
+}
+
+/// Rule accounts following the rules marker, split by the rules enabled for the mint
+struct RuleAccounts<'a> {
+    suspension: Option<&'a [AccountInfo]>,
+    sub_account_ledger: Option<&'a [AccountInfo]>,
+    allowlist: Option<&'a [AccountInfo]>,
+    holding_period: Option<&'a [AccountInfo]>,
+    coupon_accrual: Option<&'a [AccountInfo]>,
+    position_limit: Option<&'a [AccountInfo]>,
+    transfer_approval: Option<&'a AccountInfo>,
+    transfer_acceptance: Option<&'a AccountInfo>,
+    permanent_delegate_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> RuleAccounts<'a> {
+    /// Read the enabled rules from the marker at `rules_marker_index` of the extra accounts.
+    ///
+    /// Extra account metas without a known marker or with accounts left over fail, so a
+    /// layout the hook does not understand never skips a rule.
+    fn load(
+        extra_accounts: &'a [AccountInfo],
+        rules_marker_index: usize,
+    ) -> Result<Self, ProgramError> {
+        let (marker, rule_accounts) = extra_accounts
+            .get(rules_marker_index..)
+            .and_then(<[AccountInfo]>::split_first)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let rules = TransferHookRuleFlags::from_marker(marker.key())
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        let markers = [
+            TransferHookRuleFlags::TRANSFER_APPROVAL,
+            TransferHookRuleFlags::TRANSFER_ACCEPTANCE,
+            TransferHookRuleFlags::PERMANENT_DELEGATE_CONFIG,
+        ]
+        .into_iter()
+        .filter(|flag| rules.contains(*flag))
+        .count();
+        let markers_start = rule_accounts
+            .len()
+            .checked_sub(markers)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let (mut rule_accounts, mut marker_accounts) = rule_accounts.split_at(markers_start);
+
+        let mut take = |flag: u32, len: usize| -> Result<_, ProgramError> {
+            if !rules.contains(flag) {
+                return Ok(None);
+            }
+            if rule_accounts.len() < len {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            }
+            let (accounts, rest) = rule_accounts.split_at(len);
+            rule_accounts = rest;
+            Ok(Some(accounts))
+        };
+        let suspension = take(TransferHookRuleFlags::SUSPENSION, 3)?;
+        let sub_account_ledger = take(TransferHookRuleFlags::SUB_ACCOUNT_LEDGER, 1)?;
+        let allowlist = take(TransferHookRuleFlags::ALLOWLIST, 3)?;
+        let holding_period = take(TransferHookRuleFlags::HOLDING_PERIOD, 3)?;
+        let coupon_accrual = take(TransferHookRuleFlags::COUPON_ACCRUAL, 4)?;
+        // The position limit accounts end with the wallet slots of the Identity
+        let position_limit = if rules.contains(TransferHookRuleFlags::POSITION_LIMIT) {
+            Some(core::mem::take(&mut rule_accounts))
+        } else {
+            None
+        };
+        if !rule_accounts.is_empty() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut take_marker = |flag: u32| {
+            if !rules.contains(flag) {
+                return None;
+            }
+            let (account, rest) = marker_accounts.split_first()?;
+            marker_accounts = rest;
+            Some(account)
+        };
+        Ok(Self {
+            suspension,
+            sub_account_ledger,
+            allowlist,
+            holding_period,
+            coupon_accrual,
+            position_limit,
+            transfer_approval: take_marker(TransferHookRuleFlags::TRANSFER_APPROVAL),
+            transfer_acceptance: take_marker(TransferHookRuleFlags::TRANSFER_ACCEPTANCE),
+            permanent_delegate_config: take_marker(
+                TransferHookRuleFlags::PERMANENT_DELEGATE_CONFIG,
+            ),
+        })
+    }
+}
+
+/// Transfers of the permanent delegate skip verification.
+/// Once rotated away from the program PDA, the delegate recorded in the PermanentDelegateConfig
+/// of the mint is the only one; a renounced delegate (default pubkey) matches no authority.
 fn is_permanent_delegate_transfer(
     mint: &AccountInfo,
     authority: &AccountInfo,
-    extra_accounts: &[AccountInfo],
+    permanent_delegate_config: &AccountInfo,
 ) -> Result<bool, ProgramError> {
-    let (permanent_delegate_pda, _bump) = find_program_address(
-        &[PERMANENT_DELEGATE_SEED, mint.key().as_ref()],
-        &SECURITY_TOKEN_PROGRAM_ID,
-    );
-    // NOTE: Permanent delegate with no extra accounts means security token program call
-    Ok(authority.key() == &permanent_delegate_pda && extra_accounts.is_empty())
+    let data = load_rule_marker(
+        mint,
+        permanent_delegate_config,
+        PERMANENT_DELEGATE_CONFIG_DISCRIMINATOR,
+    )?;
+    // Layout: [0] discriminator, [1-32] mint, [33-64] delegate, [65] bump
+    let delegate = data.get(33..65).ok_or(ProgramError::InvalidAccountData)?;
+    Ok(delegate != [0u8; 32].as_slice() && delegate == authority.key().as_ref())
 }
 
+/// Borrow data of the account marking an enabled transfer rule of the mint
+fn load_rule_marker<'a>(
+    mint: &AccountInfo,
+    account: &'a AccountInfo,
+    discriminator: u8,
+) -> Result<pinocchio::account_info::Ref<'a, [u8]>, ProgramError> {
+    let data = load_security_token_account(account, discriminator)?;
+    // Layout: [0] discriminator, [1-32] mint, ...
+    if data.get(1..33) != Some(mint.key().as_ref()) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(data)
+}
+
+/// Transfers above the TransferApproval threshold go through the security token program approval queue.
+fn enforce_transfer_approval(
+    mint: &AccountInfo,
+    transfer_approval: &AccountInfo,
+    amount: u64,
+) -> ProgramResult {
+    let data = load_rule_marker(mint, transfer_approval, TRANSFER_APPROVAL_DISCRIMINATOR)?;
+    // Layout: [0] discriminator, [1-32] mint, [33-40] threshold, [41] bump
+    let threshold = data
+        .get(33..41)
+        .and_then(|slice| slice.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    if amount > threshold {
+        return Err(ProgramError::Custom(TRANSFER_APPROVAL_REQUIRED_ERROR));
+    }
+    Ok(())
+}
+
+/// Load the verification programs, the number of them that must pass (0 requiring all) and the
+/// number of instructions before the transfer searched for their calls (0 searching all)
 fn load_verification_programs(
     mint: &AccountInfo,
     extra_accounts: &[AccountInfo],
-) -> Result<Vec<[u8; 32]>, ProgramError> {
+) -> Result<(Vec<[u8; 32]>, u8, u8), ProgramError> {
     // [0] - validate_state_pubkey (added by Token-2022)
     // [1] - verification_config_pda
     if extra_accounts.len() < 2 {
@@ -153,27 +445,445 @@ fn load_verification_programs(
         return Err(ProgramError::InvalidAccountData);
     }
 
-    let verification_programs_data = &config_data[8..];
-
-    if verification_programs_data.len() % 32 != 0 {
-        return Err(ProgramError::InvalidAccountData);
-    }
-
-    let verification_programs_count = verification_programs_data.len() / 32;
+    let verification_programs_count = config_data[4..8]
+        .try_into()
+        .map(u32::from_le_bytes)
+        .map_err(|_| ProgramError::InvalidAccountData)?
+        as usize;
 
     // Anti CPI DDOS
     if verification_programs_count > MAX_VERIFICATION_PROGRAMS {
         return Err(ProgramError::InvalidAccountData);
     }
 
-    verification_programs_data
+    let verification_programs_data = config_data
+        .get(8..8 + verification_programs_count * 32)
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    // Layout after the programs: [threshold], [lookback_window], [require_top_level], the optional
+    // 32-byte config template and the optional 8-byte timelock, configs written before them require
+    // all programs anywhere in the transaction
+    let settings_start = 8 + verification_programs_data.len();
+    let (threshold, lookback_window) = match config_data.len() - settings_start {
+        1 | 33 => (config_data[settings_start], 0),
+        3 | 11 | 35 | 43 => (config_data[settings_start], config_data[settings_start + 1]),
+        0 | 32 => (0, 0),
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    let verification_programs = verification_programs_data
         .chunks_exact(32)
         .map(|chunk| {
             chunk
                 .try_into()
                 .map_err(|_| ProgramError::InvalidAccountData)
         })
-        .collect()
+        .collect::<Result<Vec<[u8; 32]>, ProgramError>>()?;
+
+    Ok((verification_programs, threshold, lookback_window))
+}
+
+/// Check neither the source nor the destination token account is suspended.
+///
+/// Accounts are the security token program and the Suspension PDAs of the source and destination,
+/// which are empty unless the token account is suspended.
+fn enforce_suspension(
+    mint: &AccountInfo,
+    source: &AccountInfo,
+    destination: &AccountInfo,
+    suspension_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [program, source_suspension, destination_suspension] = suspension_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if program.key() != &SECURITY_TOKEN_PROGRAM_ID {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    for (suspension, token_account) in [
+        (source_suspension, source),
+        (destination_suspension, destination),
+    ] {
+        if suspension.data_is_empty() {
+            continue;
+        }
+        let data = load_security_token_account(suspension, SUSPENSION_DISCRIMINATOR)?;
+        // Layout: [0] discriminator, [1-32] mint, [33-64] token_account, [65-96] payer,
+        // [97-104] suspended_at, [105] bump
+        if data.get(1..33) != Some(mint.key().as_ref())
+            || data.get(33..65) != Some(token_account.key().as_ref())
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        return Err(ProgramError::Custom(ACCOUNT_SUSPENDED_ERROR));
+    }
+    Ok(())
+}
+
+/// Check an omnibus source keeps the balances recorded for its beneficial owners.
+///
+/// The account is the SubAccountLedger PDA of the source, which is empty unless the source is
+/// a custodian omnibus token account. The balance is read after Token-2022 moved the tokens.
+fn enforce_sub_account_ledger(
+    mint: &AccountInfo,
+    source: &AccountInfo,
+    sub_account_ledger_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [ledger] = sub_account_ledger_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if ledger.data_is_empty() {
+        return Ok(());
+    }
+    let data = load_security_token_account(ledger, SUB_ACCOUNT_LEDGER_DISCRIMINATOR)?;
+    // Layout: [0] discriminator, [1-32] mint, [33-64] token_account, [65-72] total_balance,
+    // [73-76] sub_account_count, [77] bump
+    if data.len() < 78
+        || &data[1..33] != mint.key().as_ref()
+        || &data[33..65] != source.key().as_ref()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let ledger_pda = checked_create_program_address(
+        &[SUB_ACCOUNT_LEDGER_SEED, source.key().as_ref(), &[data[77]]],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )?;
+    if ledger.key() != &ledger_pda {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let total_balance = u64::from_le_bytes(
+        data[65..73]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?,
+    );
+
+    if token_account_amount(source, mint)? < total_balance {
+        return Err(ProgramError::Custom(SUB_ACCOUNT_BALANCE_EXCEEDED_ERROR));
+    }
+    Ok(())
+}
+
+/// Check the owners of the source and destination token accounts are allowlisted.
+///
+/// Accounts are present only when the allowlist mode is enabled for the mint: security token
+/// program and the AllowlistEntry PDAs of the source and destination owners, which are empty
+/// unless the owner is allowlisted.
+fn enforce_allowlist(
+    mint: &AccountInfo,
+    source: &AccountInfo,
+    destination: &AccountInfo,
+    allowlist_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [program, source_entry, destination_entry] = allowlist_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if program.key() != &SECURITY_TOKEN_PROGRAM_ID {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    for (entry, token_account) in [(source_entry, source), (destination_entry, destination)] {
+        if entry.data_is_empty() {
+            return Err(ProgramError::Custom(WALLET_NOT_ALLOWLISTED_ERROR));
+        }
+        let owner = token_account_owner(token_account)?;
+        let data = load_security_token_account(entry, ALLOWLIST_ENTRY_DISCRIMINATOR)?;
+        // Layout: [0] discriminator, [1-32] mint, [33-64] wallet, [65] bump
+        if data.len() < 66 || &data[1..33] != mint.key().as_ref() || &data[33..65] != owner.as_ref()
+        {
+            return Err(ProgramError::Custom(WALLET_NOT_ALLOWLISTED_ERROR));
+        }
+        let entry_pda = checked_create_program_address(
+            &[
+                ALLOWLIST_ENTRY_SEED,
+                mint.key().as_ref(),
+                owner.as_ref(),
+                &[data[65]],
+            ],
+            &SECURITY_TOKEN_PROGRAM_ID,
+        )?;
+        if entry.key() != &entry_pda {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+    Ok(())
+}
+
+/// Check the source keeps the locked amount of its restricted lots.
+///
+/// Accounts are present only when a holding period is set for the mint: security token program,
+/// HoldingPeriod and the source RestrictedHolding, which is empty until the source acquires a lot.
+/// The balance is read after Token-2022 moved the tokens.
+fn enforce_holding_period(
+    mint: &AccountInfo,
+    source: &AccountInfo,
+    holding_period_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [program, holding_period, restricted_holding] = holding_period_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if program.key() != &SECURITY_TOKEN_PROGRAM_ID {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let data = load_security_token_account(holding_period, HOLDING_PERIOD_DISCRIMINATOR)?;
+    // Layout: [0] discriminator, [1-32] mint, [33-40] holding_period, [41] bump
+    if data.get(1..33) != Some(mint.key().as_ref()) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    drop(data);
+
+    if restricted_holding.data_is_empty() {
+        return Ok(());
+    }
+    let data = load_security_token_account(restricted_holding, RESTRICTED_HOLDING_DISCRIMINATOR)?;
+    // Layout: [0] discriminator, [1-32] mint, [33-64] token_account, [65-72] locked_amount,
+    // [73-80] next_lot_id, [81] bump
+    if data.len() < 82
+        || &data[1..33] != mint.key().as_ref()
+        || &data[33..65] != source.key().as_ref()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let restricted_holding_pda = checked_create_program_address(
+        &[RESTRICTED_HOLDING_SEED, source.key().as_ref(), &[data[81]]],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )?;
+    if restricted_holding.key() != &restricted_holding_pda {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let locked_amount = u64::from_le_bytes(
+        data[65..73]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?,
+    );
+
+    if token_account_amount(source, mint)? < locked_amount {
+        return Err(ProgramError::Custom(HOLDING_PERIOD_NOT_ELAPSED_ERROR));
+    }
+    Ok(())
+}
+
+/// Check the coupon claims of the source and destination cover every completed coupon period.
+///
+/// Accounts are present only when coupons accrue for the mint: security token program,
+/// AccrualConfig and the CouponClaim PDAs of the source and destination, which are empty until
+/// the first claim of the token account. Claims accrue on the balance snapshot of the last
+/// claim, so a balance may only change once its periods are claimed.
+fn enforce_coupon_claims(
+    mint: &AccountInfo,
+    source: &AccountInfo,
+    destination: &AccountInfo,
+    coupon_accrual_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [program, accrual_config, source_claim, destination_claim] = coupon_accrual_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if program.key() != &SECURITY_TOKEN_PROGRAM_ID {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let data = load_security_token_account(accrual_config, ACCRUAL_CONFIG_DISCRIMINATOR)?;
+    // Layout: [0] discriminator, [1-32] mint, [33-64] payout_mint, [65-66] rate_bps,
+    // [67] day_count, [68-71] period_seconds, [72-79] start_timestamp,
+    // [80-87] principal_per_token, [88] bump
+    if data.len() < 89 || &data[1..33] != mint.key().as_ref() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let accrual_config_pda = checked_create_program_address(
+        &[ACCRUAL_CONFIG_SEED, mint.key().as_ref(), &[data[88]]],
+        &SECURITY_TOKEN_PROGRAM_ID,
+    )?;
+    if accrual_config.key() != &accrual_config_pda {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let period_seconds = u32::from_le_bytes(
+        data[68..72]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?,
+    );
+    let start_timestamp = i64::from_le_bytes(
+        data[72..80]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?,
+    );
+    drop(data);
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let completed_periods = if timestamp <= start_timestamp || period_seconds == 0 {
+        0
+    } else {
+        timestamp.abs_diff(start_timestamp) / u64::from(period_seconds)
+    };
+
+    for (coupon_claim, token_account) in [(source_claim, source), (destination_claim, destination)]
+    {
+        if coupon_claim.data_is_empty() {
+            continue;
+        }
+        let data = load_security_token_account(coupon_claim, COUPON_CLAIM_DISCRIMINATOR)?;
+        // Layout: [0] discriminator, [1-32] mint, [33-64] token_account, [65-72] balance_snapshot,
+        // [73-80] last_claimed_period, [81] bump
+        if data.len() < 82
+            || &data[1..33] != mint.key().as_ref()
+            || &data[33..65] != token_account.key().as_ref()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let coupon_claim_pda = checked_create_program_address(
+            &[COUPON_CLAIM_SEED, token_account.key().as_ref(), &[data[81]]],
+            &SECURITY_TOKEN_PROGRAM_ID,
+        )?;
+        if coupon_claim.key() != &coupon_claim_pda {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let last_claimed_period = u64::from_le_bytes(
+            data[73..81]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+        if last_claimed_period < completed_periods {
+            return Err(ProgramError::Custom(COUPON_CLAIM_OUTDATED_ERROR));
+        }
+    }
+    Ok(())
+}
+
+/// Check the destination investor holdings stay within the mint position limit.
+///
+/// Accounts are present only when a position limit is set for the mint:
+/// security token program, PositionLimit, destination IdentityWallet, Identity and its wallet slots.
+/// Balances are read after Token-2022 moved the tokens, so the transferred amount is included.
+fn enforce_position_limit(
+    mint: &AccountInfo,
+    destination: &AccountInfo,
+    position_limit_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [program, position_limit, identity_wallet, identity, wallets @ ..] =
+        position_limit_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if program.key() != &SECURITY_TOKEN_PROGRAM_ID {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let max_ownership_bps = {
+        let data = load_security_token_account(position_limit, POSITION_LIMIT_DISCRIMINATOR)?;
+        // Layout: [0] discriminator, [1-32] mint, [33-34] max_ownership_bps, [35] bump
+        if data.len() < 36 || &data[1..33] != mint.key().as_ref() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let position_limit_pda = checked_create_program_address(
+            &[POSITION_LIMIT_SEED, mint.key().as_ref(), &[data[35]]],
+            &SECURITY_TOKEN_PROGRAM_ID,
+        )?;
+        if position_limit.key() != &position_limit_pda {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        u16::from_le_bytes([data[33], data[34]])
+    };
+
+    {
+        let data = load_security_token_account(identity_wallet, IDENTITY_WALLET_DISCRIMINATOR)?;
+        // Layout: [0] discriminator, [1-32] identity, [33] bump
+        if data.len() < 34 || &data[1..33] != identity.key().as_ref() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let identity_wallet_pda = checked_create_program_address(
+            &[
+                IDENTITY_WALLET_SEED,
+                destination.key().as_ref(),
+                &[data[33]],
+            ],
+            &SECURITY_TOKEN_PROGRAM_ID,
+        )?;
+        if identity_wallet.key() != &identity_wallet_pda {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    let identity_data = load_security_token_account(identity, IDENTITY_DISCRIMINATOR)?;
+    if identity_data.len() < IDENTITY_WALLETS_OFFSET || &identity_data[1..33] != mint.key().as_ref()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let wallets_count = u32::from_le_bytes(
+        identity_data[IDENTITY_WALLETS_OFFSET - 4..IDENTITY_WALLETS_OFFSET]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?,
+    ) as usize;
+    if wallets_count > wallets.len() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut holdings: u128 = 0;
+    for (slot, wallet) in wallets.iter().take(wallets_count).enumerate() {
+        let offset = IDENTITY_WALLETS_OFFSET + slot * 32;
+        if identity_data.get(offset..offset + 32) != Some(wallet.key().as_ref()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        holdings += token_account_amount(wallet, mint)? as u128;
+    }
+
+    let supply = {
+        let mint_data = mint.try_borrow_data()?;
+        // Mint layout: [0-35] mint authority, [36-43] supply
+        mint_data
+            .get(36..44)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidAccountData)?
+    };
+
+    if holdings * BPS_DENOMINATOR > supply as u128 * max_ownership_bps as u128 {
+        return Err(ProgramError::Custom(POSITION_LIMIT_EXCEEDED_ERROR));
+    }
+    Ok(())
+}
+
+/// Borrow data of an initialized Security Token program account with the given discriminator
+fn load_security_token_account(
+    account: &AccountInfo,
+    discriminator: u8,
+) -> Result<pinocchio::account_info::Ref<'_, [u8]>, ProgramError> {
+    if !account.is_owned_by(&SECURITY_TOKEN_PROGRAM_ID) {
+        return Err(ProgramError::IllegalOwner);
+    }
+    let data = account.try_borrow_data()?;
+    if data.first() != Some(&discriminator) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(data)
+}
+
+/// Token amount of a linked wallet, closed token accounts hold nothing
+fn token_account_amount(wallet: &AccountInfo, mint: &AccountInfo) -> Result<u64, ProgramError> {
+    if wallet.data_is_empty() {
+        return Ok(0);
+    }
+    if !wallet.is_owned_by(&pinocchio_token_2022::ID) {
+        return Err(ProgramError::IllegalOwner);
+    }
+    let data = wallet.try_borrow_data()?;
+    // Token account layout: [0-31] mint, [32-63] owner, [64-71] amount
+    if data.get(..32) != Some(mint.key().as_ref()) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    data.get(64..72)
+        .and_then(|slice| slice.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Owner of a token account
+fn token_account_owner(token_account: &AccountInfo) -> Result<Pubkey, ProgramError> {
+    if !token_account.is_owned_by(&pinocchio_token_2022::ID) {
+        return Err(ProgramError::IllegalOwner);
+    }
+    let data = token_account.try_borrow_data()?;
+    // Token account layout: [0-31] mint, [32-63] owner, [64-71] amount
+    data.get(32..64)
+        .and_then(|slice| slice.try_into().ok())
+        .ok_or(ProgramError::InvalidAccountData)
 }
 
 fn execute_verification_programs(
@@ -209,6 +919,62 @@ fn execute_verification_programs(
     Ok(())
 }
 
+/// Find the distinct verification programs called before the transfer, up to `threshold` of them.
+///
+/// Failing CPIs abort the transaction, so with a threshold the verification programs are called
+/// as earlier instructions of the transaction, with the transfer data and the Execute accounts
+/// (source, mint, destination, authority) first, within the `lookback_window` instructions before it
+/// when the window is set.
+fn find_verification_calls(
+    verification_programs: &[[u8; 32]],
+    threshold: u8,
+    lookback_window: u8,
+    instructions_sysvar: &AccountInfo,
+    transfer_accounts: &[AccountInfo],
+    amount: u64,
+) -> Result<Vec<[u8; 32]>, ProgramError> {
+    let mut instruction_data = [0u8; 9];
+    instruction_data[0] = TRANSFER_DISCRIMINATOR;
+    instruction_data[1..9].copy_from_slice(&amount.to_le_bytes());
+
+    let instructions = Instructions::try_from(instructions_sysvar)?;
+    let current_index = instructions.load_current_index() as usize;
+
+    let first_index = match lookback_window {
+        0 => 0,
+        lookback_window => current_index.saturating_sub(lookback_window as usize),
+    };
+
+    let mut verified_programs: Vec<[u8; 32]> = Vec::with_capacity(threshold as usize);
+    for index in (first_index..current_index).rev() {
+        if verified_programs.len() >= threshold as usize {
+            break;
+        }
+        let instruction = instructions.load_instruction_at(index)?;
+        let program_id = instruction.get_program_id();
+        if !verification_programs.contains(program_id)
+            || verified_programs.contains(program_id)
+            || instruction.get_instruction_data() != instruction_data
+        {
+            continue;
+        }
+        let accounts_match =
+            transfer_accounts
+                .iter()
+                .enumerate()
+                .all(|(account_index, account)| {
+                    instruction
+                        .get_account_meta_at(account_index)
+                        .is_ok_and(|meta| &meta.key == account.key())
+                });
+        if accounts_match {
+            verified_programs.push(*program_id);
+        }
+    }
+
+    Ok(verified_programs)
+}
+
 /// Validate common account checks for extra account meta list operations
 fn validate_extra_account_meta_accounts(
     program_id: &Pubkey,
@@ -383,3 +1149,15 @@ fn process_update_extra_account_meta_list(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discriminators_match_deployed_hooks() {
+        // The extra account metas of deployed mints resolve the Transfer config PDA with these
+        assert_eq!(TRANSFER_VERIFICATION_CONFIG_DISCRIMINATOR, 1);
+        assert_eq!(TRANSFER_DISCRIMINATOR, 12);
+    }
+}